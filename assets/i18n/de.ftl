@@ -8,8 +8,15 @@ settings-title = Einstellungen
 settings-section-general = Allgemein
 settings-section-display = Anzeige
 settings-section-video = Video
+settings-section-editor = Editor
 settings-section-fullscreen = Vollbild
+settings-section-guides = Hilfslinien
 settings-section-ai = KI / Maschinelles Lernen
+settings-section-advanced = Erweitert
+settings-section-mouse = Maus
+settings-section-shortcuts = Tastenkürzel
+settings-section-plugins = Plugins
+settings-section-accessibility = Barrierefreiheit
 select-language-label = Sprache auswählen:
 language-name-en-US = Englisch
 language-name-fr = Französisch
@@ -24,6 +31,7 @@ viewer-zoom-label = Zoom
 viewer-zoom-input-placeholder = 100
 viewer-zoom-reset-button = Zurücksetzen
 viewer-fit-to-window-toggle = An Fenster anpassen
+viewer-lock-view-tooltip = Zoom und Position beim Bildwechsel beibehalten
 viewer-zoom-input-error-invalid = Bitte geben Sie eine gültige Zahl ein.
 viewer-zoom-step-error-invalid = Die Zoomstufe muss eine Zahl sein.
 viewer-zoom-step-error-range = Die Zoomstufe muss zwischen 1% und 200% liegen.
@@ -32,7 +40,21 @@ viewer-zoom-in-tooltip = Vergrößern
 viewer-zoom-out-tooltip = Verkleinern
 viewer-fullscreen-tooltip = Vollbild umschalten
 viewer-rotate-cw-tooltip = Im Uhrzeigersinn drehen
+viewer-export-visible-tooltip = Sichtbaren Bereich exportieren
+viewer-inspector-tooltip = Pixel-Inspektor
+viewer-inspector-copy-hint = Zum Kopieren klicken
+viewer-ruler-tooltip = Lineal
+viewer-guides-tooltip = Kompositionshilfslinien
 viewer-rotate-ccw-tooltip = Gegen Uhrzeigersinn drehen
+viewer-mirror-tooltip = Video horizontal spiegeln
+viewer-matte-background = Hintergrunddesign
+viewer-matte-black = Schwarzer Hintergrund
+viewer-matte-white = Weißer Hintergrund
+viewer-matte-gray = Grauer Hintergrund
+viewer-matte-magenta = Magenta-Hintergrund
+viewer-matte-green = Grüner Hintergrund
+viewer-alpha-straight = Direktes Alpha
+viewer-alpha-premultiplied = Vormultipliziertes Alpha
 viewer-fullscreen-disabled-unsaved = Änderungen zuerst speichern oder abbrechen
 viewer-double-click = Doppelklick
 viewer-scroll-wheel = Mausrad
@@ -48,6 +70,9 @@ filter-media-type-all = Alle
 filter-media-type-images = Nur Bilder
 filter-media-type-videos = Nur Videos
 filter-media-type-placeholder = Typ auswählen...
+filter-rating-label = Mindestbewertung
+filter-rating-any = Beliebige Bewertung
+filter-rating-min = { $stars } und mehr
 filter-date-label = Datumsfilter
 filter-date-field-label = Filtern nach
 filter-date-field-modified = Änderungsdatum
@@ -61,6 +86,9 @@ filter-date-clear = Datum löschen
 filter-tooltip-date-from = ab { $date }
 filter-tooltip-date-to = bis { $date }
 filter-tooltip-date-range = { $start } – { $end }
+quick-search-placeholder = Nach Dateinamen suchen...
+quick-search-no-results = Keine Treffer
+quick-search-hint = ↑/↓ auswählen · Enter öffnen · Esc schließen
 
 settings-zoom-step-label = Zoomstufe
 settings-zoom-step-placeholder = 10
@@ -69,10 +97,38 @@ settings-background-label = Viewer-Hintergrund
 settings-background-light = Hell
 settings-background-dark = Dunkel
 settings-background-checkerboard = Schachbrett
+settings-background-auto = Automatisch
+settings-background-auto-hint = Dunkel hinter hellen Bildern, hell hinter dunklen
 settings-theme-mode-label = Anwendungsthema
 settings-theme-system = Systemeinstellung folgen
 settings-theme-light = Hell
 settings-theme-dark = Dunkel
+settings-ui-scale-label = Skalierung der Oberfläche
+settings-ui-scale-hint = Vergrößert oder verkleinert Text, Abstände und Bedienelemente zusätzlich zur Systemskalierung. Wird beim Loslassen des Schiebereglers übernommen.
+settings-accessibility-disabled = Aus
+settings-accessibility-enabled = An
+settings-reduced-motion-label = Bewegung reduzieren
+settings-reduced-motion-hint = Ladeanzeigen stehen still, statt sich zu drehen.
+settings-high-contrast-focus-label = Fokus mit hohem Kontrast
+settings-high-contrast-focus-hint = Zeichnet einen dicken Rahmen in Textfarbe um das Eingabefeld.
+settings-large-targets-label = Größere Schaltflächen
+settings-large-targets-hint = Macht die Schaltflächen der Werkzeugleiste, des Menüs und der Videosteuerung leichter anklickbar.
+settings-always-show-fullscreen-controls-label = Vollbild-Steuerung immer anzeigen
+settings-always-show-fullscreen-controls-hint = Lässt Werkzeugleiste, Videosteuerung und Navigationspfeile im Vollbild sichtbar, statt sie nach der Overlay-Zeit auszublenden.
+settings-accent-label = Akzentfarbe
+settings-accent-hint = Für ausgewählte Schaltflächen, Schieberegler, Hervorhebungen und Benachrichtigungen. Gib eine Farbe #rrggbb für einen anderen Ton ein.
+settings-accent-custom-hint = Solange die eigene Palette unten aktiv ist, legt sie die Akzentfarbe fest.
+settings-accent-default = Standard
+settings-custom-theme-label = Eigene Palette
+settings-custom-theme-hint = Ersetzt das helle oder dunkle Design. Änderungen werden sofort angezeigt und in theme.toml neben settings.toml gespeichert.
+settings-custom-theme-disabled = Aus
+settings-custom-theme-enabled = An
+settings-palette-background = Hintergrund
+settings-palette-text = Text
+settings-palette-accent = Akzent
+settings-palette-success = Erfolg
+settings-palette-danger = Gefahr
+settings-palette-invalid = Format #rrggbb verwenden
 help-usage-heading = VERWENDUNG:
 help-options-heading = OPTIONEN:
 help-args-heading = ARGUMENTE:
@@ -83,25 +139,85 @@ help-arg-image-path = <PFAD>      Pfad zu einer Mediendatei oder einem Verzeichn
 help-example-1 = iced_lens ./foto.png
 help-example-2 = iced_lens ./meine_fotos/
 help-example-3 = iced_lens --lang fr ./bild.jpg
+help-example-4 = iced_lens verify /media/sdcard/DCIM
+help-example-5 = iced_lens convert foto.png foto.jpg --quality 85
+help-example-6 = iced_lens info clip.mp4 --json
+help-commands-heading = BEFEHLE:
+help-line-command-verify = verify [VERZ]  Jede Mediendatei in VERZ vollständig dekodieren und beschädigte auflisten
+help-line-command-convert = convert <EIN> <AUS> [--quality <1-100>]  Eine Mediendatei in das Bildformat von AUS umwandeln
+help-line-command-thumb = thumb [PFAD] [--size <px>] [--out <verz>]  JPEG-Vorschaubilder einer Datei oder aller Mediendateien eines Verzeichnisses erstellen
+help-line-command-info = info <PFAD> [--json|--csv]  Die Metadaten einer Mediendatei oder aller Mediendateien eines Ordners ausgeben
+cli-verify-summary = { $checked } Dateien geprüft, { $damaged } beschädigt
+cli-thumb-summary = { $written } Vorschaubilder erstellt, { $failed } fehlgeschlagen
+cli-info-unsupported = keine lesbaren Metadaten (nicht unterstützte oder beschädigte Datei)
 help-description = { -app-name } – Bildbetrachter
 help-line-option-i18n-dir =     --i18n-dir <pfad>  Übersetzungen aus Verzeichnis laden
+help-line-option-watch-i18n =     --watch-i18n   Übersetzungen neu laden, wenn sich ihre .ftl-Dateien ändern
 help-line-option-data-dir =     --data-dir <pfad>  Datenverzeichnis überschreiben (Zustandsdateien)
 help-line-option-config-dir =     --config-dir <pfad>  Konfigurationsverzeichnis überschreiben (settings.toml)
 settings-sort-order-label = Sortierreihenfolge für Bildnavigation
 settings-sort-alphabetical = Alphabetisch
 settings-sort-modified = Änderungsdatum
 settings-sort-created = Erstellungsdatum
+settings-sort-size = Dateigröße
+settings-sort-type = Dateityp
+settings-sort-ascending = Aufsteigend
+settings-sort-descending = Absteigend
+settings-sort-order-hint = Die alphabetische Reihenfolge vergleicht Zahlen nach ihrem Wert (img2 vor img10).
 settings-max-skip-attempts-label = Beschädigte Dateien überspringen
 settings-max-skip-attempts-hint = Maximale Anzahl beschädigter Dateien, die bei der Navigation übersprungen werden.
+settings-prefetch-cache-size-label = Vorgeladene Medien
+settings-prefetch-cache-size-hint = Dekodierte Dateien, die im Speicher bleiben, damit die nächste und vorherige sofort erscheinen. 0 schaltet das Vorladen aus.
+settings-similarity-threshold-label = Schwelle für ähnliche Bilder
+settings-similarity-threshold-hint = Mindestähnlichkeit, ab der Ähnliche finden zu einem Bild springt. Verringern, um auch anders gerahmte Aufnahmen zu finden.
 settings-persist-filters-label = Filter merken
 settings-persist-filters-hint = Filtereinstellungen zwischen Sitzungen beibehalten.
 settings-persist-filters-disabled = Aus
 settings-persist-filters-enabled = An
+settings-remember-view-label = Ansicht pro Bild merken
+settings-remember-view-hint = Zoom, Bildlaufposition und Drehung eines Bildes wiederherstellen, wenn Sie während der Sitzung zu ihm zurückkehren.
+settings-remember-view-disabled = Aus
+settings-remember-view-enabled = An
+settings-minimap-label = Übersichtskarte
+settings-minimap-hint = Das ganze Bild beim Vergrößern in einer Ecke anzeigen, mit dem sichtbaren Bereich umrandet. Zum Verschieben darin klicken oder ziehen.
+settings-minimap-disabled = Aus
+settings-minimap-enabled = An
+settings-sandboxed-decoding-label = Isolierte Dekodierung
+settings-sandboxed-decoding-hint = Bilder in einem separaten Prozess dekodieren, damit eine beschädigte oder bösartige Datei den Betrachter weder abstürzen lassen noch kompromittieren kann. Das Laden ist etwas langsamer.
+settings-sandboxed-decoding-disabled = Aus
+settings-sandboxed-decoding-enabled = An
+settings-webp-decoder-label = WebP-Decoder
+settings-webp-decoder-hint = Bibliothek für unbewegte WebP-Bilder. Kann sie eine Datei nicht lesen, wird automatisch die andere versucht.
+settings-webp-decoder-image-rs = image-rs
+settings-webp-decoder-libwebp = libwebp
 settings-overlay-timeout-label = Verzögerung für automatisches Ausblenden im Vollbildmodus
 settings-overlay-timeout-hint = Zeit bis zum Verschwinden der Steuerelemente im Vollbildmodus.
+settings-overlay-shown = Angezeigt
+settings-overlay-hidden = Ausgeblendet
+settings-overlay-filename-label = Dateiname
+settings-overlay-index-label = Position im Ordner (12/340)
+settings-overlay-clock-label = Uhr
+settings-overlay-arrows-label = Navigationspfeile
+settings-overlay-histogram-label = Histogramm
+settings-overlay-position-label = Position der Einblendung
+settings-overlay-position-hint = Wo Dateiname, Position, Uhr und Histogramm erscheinen.
+settings-overlay-position-top-left = Oben links
+settings-overlay-position-top-center = Oben
+settings-overlay-position-top-right = Oben rechts
+settings-overlay-position-bottom-left = Unten links
+settings-overlay-position-bottom-center = Unten
+settings-overlay-position-bottom-right = Unten rechts
+settings-overlay-opacity-label = Deckkraft der Einblendung
 seconds = Sekunden
 image-editor-title = Bildeditor
 image-editor-back-to-viewer = Zurück zum Viewer
+image-editor-recovery-prompt = Ungespeicherte Änderungen an diesem Bild wurden aus einer früheren Sitzung wiederhergestellt.
+image-editor-recovery-restore = Änderungen wiederherstellen
+image-editor-recovery-discard = Verwerfen
+image-editor-guides = Hilfslinien
+image-editor-zoom-fit = Einpassen
+image-editor-show-original = Original zeigen
+image-editor-compare-split = Vorher/Nachher
 image-editor-cancel = Abbrechen
 image-editor-save = Speichern
 image-editor-save-as = Speichern unter...
@@ -114,6 +230,10 @@ image-editor-rotate-left-tooltip = Bild gegen den Uhrzeigersinn drehen
 image-editor-flip-section-title = Spiegeln
 image-editor-flip-horizontal-tooltip = Bild horizontal spiegeln (links-rechts)
 image-editor-flip-vertical-tooltip = Bild vertikal spiegeln (oben-unten)
+image-editor-sidebar-collapse-tooltip = Werkzeuge ausblenden
+image-editor-sidebar-expand-tooltip = Werkzeuge einblenden
+image-editor-navigate-previous-tooltip = Vorheriges Bild
+image-editor-navigate-next-tooltip = Nächstes Bild
 image-editor-resize-section-title = Größe ändern
 image-editor-resize-scale-label = Skalierung
 image-editor-resize-dimensions-label = Zielgröße
@@ -130,10 +250,42 @@ image-editor-resize-ai-model-validating = KI-Modell wird validiert
 image-editor-resize-ai-model-error = KI-Modell-Fehler
 image-editor-resize-ai-enlargement-only = KI-Upscaling gilt nur für Vergrößerungen
 image-editor-light-section-title = Lichtanpassungen
+image-editor-auto-enhance = Automatisch verbessern
+image-editor-auto-enhance-strength-label = Stärke
+image-editor-auto-enhance-neutral = Dieses Bild ist bereits gut ausgewogen.
 image-editor-light-brightness-label = Helligkeit
 image-editor-light-contrast-label = Kontrast
+image-editor-grayscale-section-title = Schwarzweiß
+image-editor-grayscale-enable = In Schwarzweiß umwandeln
+image-editor-grayscale-preset-neutral = Neutral
+image-editor-grayscale-preset-red = Rot
+image-editor-grayscale-preset-green = Grün
+image-editor-grayscale-preset-blue = Blau
+image-editor-grayscale-red-label = Rotkanal
+image-editor-grayscale-green-label = Grünkanal
+image-editor-grayscale-blue-label = Blaukanal
+image-editor-grayscale-total = Summe: { $total } % (100 % erhält die Gesamthelligkeit)
+image-editor-sharpen-section-title = Schärfen
+image-editor-sharpen-amount-label = Stärke
+image-editor-sharpen-radius-label = Radius
+image-editor-sharpen-threshold-label = Schwellenwert
 image-editor-light-reset = Zurücksetzen
 image-editor-light-apply = Anwenden
+image-editor-light-copy = Anpassungen kopieren
+image-editor-light-paste = Anpassungen einfügen
+image-editor-light-paste-folder = Auf alle Bilder im Ordner einfügen
+image-editor-tool-filters = Filter
+image-editor-filter-black-and-white = Schwarzweiß
+image-editor-filter-noir = Film noir
+image-editor-filter-sepia = Sepia
+image-editor-filter-vignette = Vignette
+image-editor-filter-warm-film = Warmer Film
+image-editor-filter-cool-film = Kühler Film
+image-editor-filter-faded = Verblasst
+image-editor-filter-vivid = Lebendig
+image-editor-filter-strength-label = Stärke
+image-editor-filter-hint = Wählen Sie einen Look, um ihn auf dem Bild vorzuschauen.
+image-editor-filter-apply = Filter anwenden
 image-editor-crop-section-title = Zuschneiden
 image-editor-crop-ratio-label = Seitenverhältnis
 image-editor-crop-ratio-free = Frei
@@ -143,38 +295,267 @@ image-editor-crop-ratio-portrait = Hochformat (9:16)
 image-editor-crop-ratio-photo = Foto (4:3)
 image-editor-crop-ratio-photo-portrait = Foto Hochformat (3:4)
 image-editor-crop-apply = Zuschnitt anwenden
+image-editor-crop-face = Auf Gesicht zuschneiden
+image-editor-crop-face-detecting = Gesichter werden erkannt…
+image-editor-crop-face-none = Kein Gesicht in diesem Bild gefunden.
 image-editor-undo-redo-section-title = Letzte Änderung
 image-editor-undo = Rückgängig
 image-editor-redo = Wiederholen
+image-editor-history-title = Verlauf
+image-editor-history-steps-title = Schritte
+image-editor-history-original = Originalbild
+image-editor-snapshots-title = Schnappschüsse
+image-editor-snapshot-name-placeholder = Name des Schnappschusses
+image-editor-snapshot-save = Speichern
+image-editor-snapshot-delete-tooltip = Schnappschuss löschen
+image-editor-snapshot-unnamed = Nach Schritt { $step }
+image-editor-history-rotate-left = Nach links drehen
+image-editor-history-rotate-right = Nach rechts drehen
+image-editor-history-flip-horizontal = Horizontal spiegeln
+image-editor-history-flip-vertical = Vertikal spiegeln
+image-editor-history-crop = Zuschneiden
+image-editor-history-resize = Größe ändern
+image-editor-history-upscale = KI-Vergrößerung
+image-editor-history-brightness = Helligkeit
+image-editor-history-contrast = Kontrast
+image-editor-history-auto-enhance = Automatisch verbessern
+image-editor-history-black-white = Schwarzweiß
+image-editor-history-sharpen = Schärfen
+image-editor-history-look = Filter
+image-editor-history-deblur = KI-Entschärfung
+image-editor-history-background = Hintergrund entfernen
+image-editor-history-denoise = Rauschminderung
+image-editor-history-plugin = Plugin-Werkzeug
 image-editor-export-format-label = Exportformat
+image-editor-proof-label = Farbproof
+image-editor-proof-none = Keins
+image-editor-proof-convert = Farben in das Profil umrechnen
+image-editor-proof-convert-tooltip = Aus: Das Profil wird nur angehängt, die Pixelwerte bleiben erhalten. An: Die Farben werden umgerechnet, damit sie im Zielfarbraum gleich aussehen.
 media-loading = Lädt...
 settings-video-autoplay-label = Video-Autoplay
 settings-video-autoplay-enabled = Aktiviert
 settings-video-autoplay-disabled = Deaktiviert
 settings-video-autoplay-hint = Wenn aktiviert, starten Videos beim Öffnen automatisch die Wiedergabe.
+settings-video-resume-label = Videos fortsetzen
+settings-video-resume-hint = Erneut geöffnete Videos können dort weiterlaufen, wo Sie sie verlassen haben.
+settings-video-resume-ask = Fragen
+settings-video-resume-always = Immer
+settings-video-resume-never = Nie
+settings-tone-mapping-label = HDR-Tone-Mapping
+settings-tone-mapping-hint = Wie die Lichter von HDR10- und HLG-Videos in den Bereich des Bildschirms gebracht werden.
+settings-tone-mapping-clip = Abschneiden
+settings-tone-mapping-reinhard = Reinhard
+settings-tone-mapping-hable = Filmisch (Hable)
+settings-tone-mapping-aces = ACES
+settings-audio-device-label = Audioausgabe
+settings-audio-device-hint = Gerät, das den Ton von Videos wiedergibt. Mit dem Systemstandard wechselt die Wiedergabe zum neuen Standardgerät, wenn ein Gerät getrennt oder gewechselt wird.
+settings-audio-device-default = Systemstandard
+settings-audio-device-missing = { $id } (nicht verbunden)
 video-play-tooltip = Wiedergabe (Leertaste)
 video-pause-tooltip = Pause (Leertaste)
 video-mute-tooltip = Stummschalten (M)
+video-volume-boost-tooltip = Über 100 % verstärkt, für diese Datei gemerkt
 video-unmute-tooltip = Ton einschalten (M)
 video-no-audio-tooltip = Keine Audiospur
 video-loop-tooltip = Wiederholen
+video-loop-in-tooltip = Anfang der A–B-Schleife hier setzen
+video-loop-out-tooltip = Ende der A–B-Schleife hier setzen
+video-loop-clear-tooltip = A–B-Schleife entfernen
+video-export-clip = Clip exportieren
+video-export-clip-tooltip = A–B-Schleife in eine neue Videodatei exportieren
+video-export-clip-progress = Exportiere… { $percent } %
+video-export-clip-cancel-tooltip = Clip-Export abbrechen
+video-export-frames = Einzelbilder exportieren
+video-export-frames-tooltip = Einen Bereich von Einzelbildern als Bilder, GIF oder WebP-Animation exportieren
+video-frames-range-loop = A–B-Schleife
+video-frames-range-around = { $seconds } s um die Abspielposition
+video-frames-range-tooltip = Exportierter Bereich, wenn keine A–B-Schleife gesetzt ist
+video-frames-format-sequence = PNG-Sequenz
+video-frames-format-gif = Animiertes GIF
+video-frames-format-webp = Animiertes WebP
+video-frames-format-tooltip = Ausgabeformat
+video-frames-fps = { $fps } fps
+video-frames-fps-tooltip = Bilder pro Sekunde
+video-frames-scale-tooltip = Ausgabegröße
+video-frames-export = Exportieren…
+video-frames-export-tooltip = Speicherort wählen und Einzelbilder exportieren
+video-frames-cancel-tooltip = Export der Einzelbilder abbrechen
+video-frames-mode-range = Bereich
+video-frames-mode-burst = Serie
+video-frames-mode-tooltip = Einen Bereich als eine Datei oder eine Serie einzelner Bilder exportieren
+video-frames-burst-count = { $count } Bilder
+video-frames-burst-count-tooltip = Um die Abspielposition erfasste Bilder
+video-frames-burst-interval = alle { $ms } ms
+video-frames-burst-interval-tooltip = Zeit zwischen zwei Bildern
+video-frames-burst-tooltip = Einen Ordner wählen und jedes Bild dort als PNG in voller Größe speichern
 video-capture-tooltip = Aktuelles Bild aufnehmen
 video-step-forward-tooltip = Ein Bild vorwärts (.)
 video-step-backward-tooltip = Ein Bild rückwärts (,)
 video-more-tooltip = Weitere Optionen
 video-speed-down-tooltip = Geschwindigkeit verringern (J)
 video-speed-up-tooltip = Geschwindigkeit erhöhen (L)
+video-speed-selector-tooltip = Wiedergabegeschwindigkeit (Ton behält seine Tonhöhe von 0,25x bis 3x)
+video-audio-visualization-off = Audiostreifen: aus
+video-audio-visualization-waveform = Audiostreifen: Wellenform
+video-audio-visualization-vu-meter = Audiostreifen: VU-Meter
+video-audio-visualization-tooltip = Wellenform oder Live-Pegelanzeige unter der Zeitleiste anzeigen
+video-adjust-button = Anpassen
+video-adjust-tooltip = Helligkeit, Kontrast, Sättigung und Gamma des Videos
+video-adjust-brightness = Helligkeit
+video-adjust-contrast = Kontrast
+video-adjust-saturation = Sättigung
+video-adjust-gamma = Gamma
+video-adjust-reset = Zurücksetzen
+video-adjust-reset-tooltip = Ursprüngliche Farben wiederherstellen
+video-mini-player = Mini-Player
+video-mini-player-tooltip = In einem kleinen Fenster über den anderen weiterspielen
+pip-play-tooltip = Abspielen
+pip-pause-tooltip = Pause
+pip-return-tooltip = Zurück zum Hauptfenster
+pip-close-tooltip = Mini-Player schließen
+video-frame-counter = Bild { $current } / { $total }
+video-fine-scrub-frame = Fein: 1 Bild
+video-fine-scrub-tenth = Fein: 0,1 s
+video-resume-prompt = Ab { $position } fortsetzen?
+video-resume-button = Fortsetzen
+video-resume-start-over-button = Von vorne
+video-chapter-previous-tooltip = Vorheriges Kapitel (Bild↑)
+video-chapter-next-tooltip = Nächstes Kapitel (Bild↓)
+video-chapter-list-tooltip = Kapitel
+video-subtitles-off = Untertitel aus
+video-decode-hardware = GPU-Dekodierung ({ $backend })
+video-decode-software = CPU-Dekodierung
+video-cache-stats = Bild-Cache: { $rate } % Treffer, { $frames } Bilder, { $size } MB
+video-subtitles-track-tooltip = Untertitelspur
+video-subtitles-delay-tooltip = Untertitelverzögerung (Klick zum Zurücksetzen)
+video-subtitles-size-tooltip = Untertitelgröße
+video-subtitles-background = Hintergrund
+video-subtitle-size-small = Klein
+video-subtitle-size-medium = Mittel
+video-subtitle-size-large = Groß
 hud-video-no-audio = Kein Audio
 settings-audio-normalization-label = Audio-Lautstärkenormalisierung
 settings-audio-normalization-enabled = Aktiviert
 settings-audio-normalization-disabled = Deaktiviert
 settings-audio-normalization-hint = Gleicht automatisch die Audiolautstärke zwischen verschiedenen Mediendateien an, um plötzliche Lautstärkeänderungen zu vermeiden.
+settings-hardware-decoding-label = Hardware-Videodekodierung
+settings-hardware-decoding-enabled = Aktiviert
+settings-hardware-decoding-disabled = Deaktiviert
+settings-hardware-decoding-hint = Dekodiert Videos auf der Grafikkarte (VA-API, D3D11VA/DXVA2 oder VideoToolbox) für flüssige 4K-Wiedergabe und weicht auf den Prozessor aus, wenn dies nicht unterstützt wird. Gilt ab dem nächsten geöffneten Video.
+settings-file-label = Einstellungsdatei
+settings-file-hint = In einem Texteditor an settings.toml vorgenommene Änderungen werden nach wenigen Sekunden übernommen. Exportieren kopiert die Einstellungen in eine andere Datei; Importieren ersetzt sie durch die einer Datei.
+settings-file-import = Importieren…
+settings-file-export = Exportieren…
+settings-check-updates-label = Aktualisierungen
+settings-check-updates-hint = Auf GitHub nach einer neueren Version suchen. Es wird nichts heruntergeladen oder installiert.
+settings-check-updates-manual = Manuell
+settings-check-updates-weekly = Wöchentlich
+settings-check-updates-now = Jetzt prüfen
+settings-mouse-wheel-label = Mausrad über dem Medium
+settings-mouse-wheel-zoom = Zoomen
+settings-mouse-wheel-navigate = Zurück / weiter
+settings-mouse-wheel-volume = Videolautstärke
+settings-mouse-wheel-hint = Videolautstärke ändert die Lautstärke während der Videowiedergabe und zoomt Bilder.
+settings-mouse-drag-button-label = Taste zum Verschieben gezoomter Medien
+settings-mouse-drag-button-hint = Ein Doppelklick mit der linken Taste schaltet weiterhin das Vollbild um.
+settings-mouse-button-left = Links
+settings-mouse-button-middle = Mitte
+settings-mouse-button-right = Rechts
+settings-mouse-middle-click-label = Mittelklick
+settings-mouse-middle-click-toggle-fit = An Fenster anpassen umschalten
+settings-mouse-middle-click-none = Nichts
+settings-mouse-middle-click-hint = Wird ignoriert, wenn die mittlere Taste das Medium verschiebt.
+settings-mouse-navigation-buttons-label = Zurück- und Vorwärtstasten
+settings-mouse-navigation-buttons-enabled = Vorheriges / nächstes Medium
+settings-mouse-navigation-buttons-disabled = Ignoriert
+settings-mouse-navigation-buttons-hint = Die Seitentasten der Maus blättern wie die Pfeiltasten durch den Ordner.
+settings-shortcuts-hint = Klicken Sie auf ein Kürzel und drücken Sie dann die neue Taste. Esc bricht ab.
+settings-shortcuts-press-key = Taste drücken…
+settings-shortcuts-reset = Alle zurücksetzen
+settings-shortcuts-conflict = { $other } verwendet diese Taste bereits; { $action } behält sein Kürzel.
+settings-shortcuts-reserved = Diese Taste kann nicht zugewiesen werden; { $action } behält sein Kürzel.
+settings-shortcuts-group-viewer = Betrachter
+settings-shortcuts-group-video = Video
+settings-shortcuts-group-editor = Editor
+settings-plugins-hint = Plugins sind Ordner mit einer Datei plugin.toml im Plugin-Ordner. Sie laufen als eigene Programme.
+settings-plugins-empty = Keine Plugins installiert.
+settings-plugins-invalid = Ungültige plugin.toml: { $error }
+settings-plugins-hooks = Bietet: { $hooks }
+settings-plugins-hook-media-loaded = Aktion beim Öffnen eines Mediums
+settings-plugins-hook-editor-tool = Editor-Werkzeuge
+settings-plugins-hook-export-format = Exportformate
+settings-plugins-enabled = Aktiviert
+settings-plugins-disabled = Deaktiviert
+settings-plugins-open-folder = Plugin-Ordner öffnen
+settings-plugins-reload = Plugins neu laden
+shortcut-action-enter-editor = Bild bearbeiten
+shortcut-action-toggle-info-panel = Infobereich ein-/ausblenden
+shortcut-action-toggle-fullscreen = Vollbild umschalten
+shortcut-action-rotate-clockwise = Im Uhrzeigersinn drehen
+shortcut-action-rotate-counterclockwise = Gegen den Uhrzeigersinn drehen
+shortcut-action-mirror = Spiegeln
+shortcut-action-next-matte = Nächster Hintergrund
+shortcut-action-previous-matte = Vorheriger Hintergrund
+shortcut-action-toggle-alpha = Transparenz ein-/ausblenden
+shortcut-action-play-sequence = Als Sequenz abspielen
+shortcut-action-export-view = Ansicht exportieren
+shortcut-action-copy-view = Ansicht kopieren
+shortcut-action-quick-search = Schnellsuche
+shortcut-action-next-face = Zum nächsten Gesicht zoomen
+shortcut-action-previous-face = Zum vorherigen Gesicht zoomen
+shortcut-action-toggle-face-overlay = Erkannte Gesichter anzeigen
+shortcut-action-next-similar = Nächstes ähnliches Bild
+shortcut-action-previous-similar = Vorheriges ähnliches Bild
+shortcut-action-toggle-osd = Bildschirmanzeige ein-/ausblenden
+shortcut-action-toggle-pixel-inspector = Pixel-Inspektor ein- / ausschalten
+shortcut-action-toggle-ruler = Lineal ein- / ausschalten
+shortcut-action-toggle-guides = Kompositionshilfslinien ein- / ausblenden
+shortcut-action-play-pause = Wiedergabe / Pause
+shortcut-action-mute = Stumm / Ton an
+shortcut-action-step-backward = Vorheriges Einzelbild
+shortcut-action-step-forward = Nächstes Einzelbild
+shortcut-action-speed-down = Langsamer
+shortcut-action-speed-up = Schneller
+shortcut-action-loop-start = Schleifenanfang setzen
+shortcut-action-loop-end = Schleifenende setzen
+shortcut-action-loop-clear = Schleife löschen
+shortcut-action-save = Speichern
+shortcut-action-undo = Rückgängig
+shortcut-action-redo = Wiederholen
+settings-frame-cache-auto-label = Größe des Bild-Caches
+settings-frame-cache-auto-enabled = Automatisch
+settings-frame-cache-auto-disabled = Manuell
+settings-frame-cache-auto-hint = Automatisch bemisst Bild-Cache und Bildverlauf nach Videoauflösung und verfügbarem Speicher.
 settings-frame-cache-label = Keyframe-Cache-Größe (für Suche)
 settings-frame-cache-hint = Speichert Video-Keyframes zwischen, um das Scrubben in der Timeline und Sprünge zu bestimmten Zeiten zu beschleunigen. Höhere Werte speichern mehr Keyframes für schnellere Suche. Änderungen gelten beim Öffnen eines neuen Videos.
 settings-frame-history-label = Frame-Verlaufsgröße (für Rückwärtsgehen)
 settings-frame-history-hint = Speichert kürzlich angezeigte Bilder, um bildweises Rückwärtsgehen zu ermöglichen. Wird nur beim manuellen Durchblättern verwendet, nicht während der normalen Wiedergabe.
 settings-keyboard-seek-step-label = Tastatur-Suchschritt
 settings-keyboard-seek-step-hint = Zeitsprung beim Verwenden der Pfeiltasten während der Videowiedergabe.
+settings-sequence-fps-label = Bildrate für Bildsequenzen
+settings-sequence-fps-hint = Geschwindigkeit beim Abspielen nummerierter Bilder (frame_0001.png, frame_0002.png…) als Video mit S.
+settings-frame-name-template-label = Dateinamenvorlage für Einzelbilder
+settings-frame-name-template-hint = Standardname für aufgenommene Einzelbilder. Platzhalter: {"{name}"}, {"{date}"}, {"{index}"}, {"{timestamp}"}, {"{w}"}, {"{h}"}.
+settings-save-policy-label = Beim Speichern
+settings-save-policy-hint = Wohin Speichern bearbeitete Bilder schreibt. Kopien lassen das Original unverändert; aufgenommene Einzelbilder werden im selben Ordner vorgeschlagen.
+settings-save-policy-overwrite = Überschreiben
+settings-save-policy-copy = Nummerierte Kopie
+settings-save-policy-subfolder = Unterordner
+settings-edited-folder-label = Name des Unterordners
+settings-edited-folder-hint = Wird beim Speichern neben dem Originalbild angelegt.
+settings-guides-thirds-label = Drittelregel
+settings-guides-thirds-hint = Zwei Linien je Seite, bei einem und zwei Dritteln.
+settings-guides-golden-ratio-label = Goldener Schnitt
+settings-guides-golden-ratio-hint = Zwei Linien je Seite, an den goldenen Schnitten (38,2 % und 61,8 %).
+settings-guides-safe-areas-label = Sichere Bereiche
+settings-guides-safe-areas-hint = Gestrichelte Rahmen für Action-Safe (93 %) und Title-Safe (90 %).
+settings-guides-pixel-grid-label = Pixelraster
+settings-guides-pixel-grid-hint = Umrandet jedes Pixel ab 800 % Zoom.
+settings-guides-horizontal-label = Eigene horizontale Linien
+settings-guides-vertical-label = Eigene vertikale Linien
+settings-guides-positions-hint = Positionen in Prozent vom oberen oder linken Rand, durch Kommas getrennt.
+settings-guides-positions-invalid = Zahlen von 0 bis 100 eingeben, z. B. 25, 50, 75.
+settings-sequence-fps-value = { $fps } fps
 megabytes = MB
 error-load-video-heading = Dieses Video konnte nicht abgespielt werden.
 error-load-video-general = Beim Laden des Videos ist ein Fehler aufgetreten.
@@ -189,9 +570,35 @@ error-video-seek-timeout = Die Suche ist abgelaufen. Die Zielposition liegt mög
 
 # Navigation bar
 menu-settings = Einstellungen
+menu-open-in-new-window = In neuem Fenster öffnen…
 menu-help = Hilfe
 menu-about = Über
+menu-verify-folder = Ordner prüfen
+menu-share-over-lan = Im lokalen Netzwerk teilen
+menu-stop-sharing = Freigabe beenden
+menu-strip-metadata = Alle Metadaten entfernen
+menu-always-on-top = Immer im Vordergrund
+menu-borderless = Rahmenloses Fenster
+menu-compact-mode = Kompaktmodus (Esc zum Verlassen)
+menu-open-with = Öffnen mit / Senden an
+share-panel-title = Im lokalen Netzwerk teilen
+share-panel-hint = { $count } Dateien werden geteilt. Scanne den Code oder öffne diese Adresse auf einem Gerät im selben Netzwerk:
+share-panel-read-only = Besucher können die Dateien ansehen und herunterladen, aber nicht ändern.
+share-panel-copy = Adresse kopieren
+share-panel-stop = Freigabe beenden
+share-panel-close = Schließen
+diagnostics-title = Diagnose
+diagnostics-decode-fps = Dekodierte Bilder pro Sekunde
+diagnostics-dropped-frames = Verworfene Bilder
+diagnostics-frame-queue = Bildwarteschlange
+diagnostics-frame-cache = Treffer im Videobild-Cache
+diagnostics-prefetch-cache = Treffer im Vorlade-Cache
+diagnostics-memory = Belegter Speicher
+diagnostics-recent-loads = Letzte Ladezeiten
+diagnostics-load-failed = nach { $ms } ms fehlgeschlagen
+diagnostics-unavailable = —
 navbar-edit-button = Bearbeiten
+navbar-menu-tooltip = Menü
 
 # Help screen
 help-title = Hilfe
@@ -219,7 +626,7 @@ help-viewer-tool-fit-desc = Skaliert das Bild automatisch, um vollständig in da
 help-viewer-tool-fullscreen = Vollbild
 help-viewer-tool-fullscreen-desc = Immersive Ansicht mit automatisch ausblendbaren Steuerelementen (Verzögerung in Einstellungen konfigurierbar).
 help-viewer-tool-filter = Filter
-help-viewer-tool-filter-desc = Nur passende Dateien anzeigen. Filtern nach Medientyp, Ausrichtung oder Datumsbereich.
+help-viewer-tool-filter-desc = Nur passende Dateien anzeigen. Filtern nach Medientyp, Ausrichtung, Datumsbereich oder Mindestbewertung.
 help-viewer-tool-delete = Löschen
 help-viewer-tool-delete-desc = Aktuelle Datei dauerhaft entfernen (wird in den Systempapierkorbverschoben, falls verfügbar).
 
@@ -230,6 +637,26 @@ help-viewer-key-exit-fullscreen = Vollbildmodus verlassen
 help-viewer-key-info = Dateiinformationsbereich umschalten
 help-viewer-key-rotate-cw = Im Uhrzeigersinn drehen
 help-viewer-key-rotate-ccw = Gegen den Uhrzeigersinn drehen
+help-viewer-key-mirror = Video horizontal spiegeln
+help-viewer-key-matte = Hintergrundfarbe hinter transparenten Bereichen wechseln
+help-viewer-key-alpha = Vorschau zwischen direktem/vormultipliziertem Alpha umschalten
+help-viewer-key-sequence = Nummerierte Bildsequenz als Video abspielen
+help-viewer-key-export-view = Ansicht wie angezeigt als PNG-Datei speichern
+help-viewer-key-copy-view = Ansicht wie angezeigt in die Zwischenablage kopieren
+help-viewer-key-quick-search = Dateien im aktuellen Ordner nach Namen suchen
+help-viewer-key-quick-search-cycle = Zum nächsten / vorherigen Suchtreffer springen
+help-viewer-key-capture-day = Zum nächsten / vorherigen Aufnahmetag springen
+help-viewer-key-face-zoom = Auf das nächste / vorherige erkannte Gesicht zoomen
+help-viewer-key-face-overlay = Erkannte Gesichter umrahmen
+help-viewer-key-similar = Ähnliche finden: zum nächsten / vorherigen ähnlichen Bild springen (Serienaufnahmen)
+help-viewer-key-osd = Bildschirmanzeige ein- / ausblenden
+help-viewer-key-pixel-inspector = Pixel-Inspektor: Farbe unter dem Mauszeiger, Klick zum Kopieren
+help-viewer-key-ruler = Lineal: über das Bild ziehen, um Abstand und Winkel zu messen
+help-viewer-key-ruler-undo = Letzte Messung entfernen (Lineal aktiv)
+help-viewer-key-guides = Kompositionshilfslinien ein- / ausblenden
+help-viewer-key-layout = Zu Arbeitsbereich-Layout 1–3 wechseln (Sichtung, Präsentation, benutzerdefiniert)
+help-viewer-key-layout-save = Aktuelle Bereiche in Layout 1–3 speichern
+help-viewer-key-diagnostics = Leistungsdiagnose ein- / ausblenden
 
 help-mouse-title = Mausinteraktionen
 help-viewer-mouse-doubleclick = Doppelklick auf Bild/Video zum Umschalten des Vollbildmodus
@@ -247,7 +674,7 @@ help-video-tool-playback-desc = Starten oder stoppen Sie die Videowiedergabe mit
 help-video-tool-timeline = Zeitleiste
 help-video-tool-timeline-desc = Klicken Sie irgendwo auf die Fortschrittsleiste, um zu dieser Position zu springen.
 help-video-tool-volume = Lautstärke
-help-video-tool-volume-desc = Ziehen Sie den Lautstärkeregler (0-150%) oder klicken Sie auf das Lautsprechersymbol zum Stummschalten/Einschalten.
+help-video-tool-volume-desc = Ziehen Sie den Lautstärkeregler (0-200%) oder klicken Sie auf das Lautsprechersymbol zum Stummschalten/Einschalten.
 help-video-tool-loop = Wiederholen
 help-video-tool-loop-desc = Aktivieren, um das Video automatisch neu zu starten, wenn es endet.
 help-video-tool-stepping = Bildweises Vor- und Zurückgehen
@@ -258,11 +685,15 @@ help-video-tool-capture-desc = Speichern Sie das aktuelle Videobild als Bilddate
 help-video-key-playpause = Video wiedergeben oder pausieren
 help-video-key-mute = Ton stummschalten umschalten
 help-video-key-seek = Rückwärts/Vorwärts suchen (während der Wiedergabe)
+help-video-key-fine-seek = Feinsuche: ein Bild bei Pause, 0,1 s während der Wiedergabe (Umschalt beim Ziehen der Zeitleiste halten, um langsam zu scrubben)
 help-video-key-volume = Lautstärke erhöhen/verringern
 help-video-key-step-back = Ein Bild zurück (wenn pausiert)
 help-video-key-step-forward = Ein Bild vor (wenn pausiert)
 help-video-key-speed-down = Wiedergabegeschwindigkeit verringern
 help-video-key-speed-up = Wiedergabegeschwindigkeit erhöhen
+help-video-key-chapter = Zum nächsten / vorherigen Kapitel springen (Videos mit Kapiteln)
+help-video-key-loop-points = Anfang / Ende der A–B-Schleife an der aktuellen Position setzen
+help-video-key-loop-clear = A–B-Schleife entfernen
 
 # ─────────────────────────────────────────────────────────────────────────────
 # Image Editor Section
@@ -293,9 +724,22 @@ help-editor-resize-ai-upscale = KI-Upscaling: Verwenden Sie Real-ESRGAN für sch
 
 help-editor-light-title = Licht
 help-editor-light-desc = Passen Sie die Helligkeit und den Kontrast Ihres Bildes fein an.
+help-editor-light-auto = Automatisch verbessern: analysiert das Histogramm und korrigiert Tonwerte, Kontrast und Weißabgleich in einem Schritt; die Stärke mischt die Korrektur mit dem Original
 help-editor-light-brightness = Helligkeit: Gesamtbild aufhellen oder abdunkeln
 help-editor-light-contrast = Kontrast: Differenz zwischen hellen und dunklen Bereichen erhöhen oder verringern
+help-editor-light-grayscale = Schwarzweiß: ein Kanalmixer bestimmt, wie stark jede Farbe zum Grau beiträgt; die Vorgaben Rot, Grün und Blau ahmen die Farbfilter der Schwarzweißfotografie nach
+help-editor-light-sharpen = Schärfen: eine Unscharfmaskierung, deren Radius die Größe der verstärkten Kanten bestimmt und deren Schwellenwert glatte Flächen und Rauschen verschont
+help-editor-light-copy = Anpassungen kopieren/einfügen: kopiert Drehungen, Spiegelungen, Zuschnitte, Größenänderungen und Lichtanpassungen des Bildes, die auch nach einem Neustart erhalten bleiben; beim Einfügen werden sie auf ein anderes Bild angewendet, wobei Zuschnitte und Größenänderungen an dessen Größe angepasst werden, oder auf alle Bilder im Ordner, nach einer Bestätigung als Kopien gespeichert, die die Originale nie ersetzen
 help-editor-light-preview = Änderungen werden in Echtzeit vor dem Anwenden angezeigt
+help-editor-filters-title = Filter
+help-editor-filters-desc = Geben Sie Ihrem Bild mit einem Klick einen Look: Schwarzweiß, Sepia, Vignette, Filmtöne und mehr.
+help-editor-filters-preview = Klicken Sie auf einen Look für die Vorschau und erneut, um ihn zu entfernen
+help-editor-filters-strength = Die Stärke mischt den Look mit dem Originalbild
+help-editor-filters-undo = Ein angewendeter Look ist ein einzelner Schritt, den Sie rückgängig machen können
+help-editor-history-title = Verlauf
+help-editor-history-desc = Kehren Sie zu einem beliebigen früheren Stand Ihrer Bearbeitung zurück, nicht nur einen Schritt.
+help-editor-history-steps = Klicken Sie auf einen Schritt, um zu ihm zurückzukehren; spätere Schritte bleiben aufgelistet und wiederherstellbar, bis Sie erneut bearbeiten
+help-editor-history-snapshots = Speichern Sie einen Schnappschuss, um den aktuellen Stand zu benennen („nach Zuschnitt“) und später dorthin zurückzuspringen; Schnappschüsse gelten, bis das Bild gespeichert wird
 
 help-editor-save-title = Speichern
 help-editor-save-overwrite = Speichern: Überschreibt die Originaldatei
@@ -305,10 +749,15 @@ help-editor-key-save = Aktuelle Änderungen speichern
 help-editor-key-undo = Letzte Änderung rückgängig machen
 help-editor-key-redo = Rückgängig gemachte Änderung wiederholen
 help-editor-key-cancel = Alle Änderungen abbrechen und beenden
+help-editor-key-show-original = Originalbild anzeigen, solange die Taste gedrückt ist
 
 help-editor-mouse-title = Maussteuerung
 help-editor-mouse-wheel = Bild vergrößern oder verkleinern
 help-editor-mouse-drag = Bild verschieben wenn vergrößert
+help-editor-mouse-space-drag = Das Bild über den Zuschneidegriffen und dem Verfeinerungspinsel verschieben
+help-editor-mouse-space-drag-label = Leertaste + Ziehen
+help-editor-mouse-fit = Die Schaltfläche Einpassen wechselt die Leinwand zwischen Fensteranpassung und 100 %
+help-editor-compare = Halten Sie Original zeigen gedrückt, um das unveränderte Bild zu sehen, oder teilen Sie die Leinwand mit Vorher/Nachher: links vom Trenner das Original, rechts Ihre Bearbeitungen
 
 # ─────────────────────────────────────────────────────────────────────────────
 # Frame Capture Section
@@ -378,16 +827,73 @@ about-third-party-details = Siehe THIRD_PARTY_LICENSES.md für Details
 about-section-links = Links
 about-link-repository = Quellcode
 about-link-issues = Probleme melden
+about-section-troubleshooting = Fehlerbehebung
+about-troubleshooting-hint = Hängen Sie beim Melden eines Problems den Diagnosebericht an: Er enthält die Versionen von System, Grafikkarte und FFmpeg sowie die letzten Protokollzeilen.
+about-view-logs-button = Protokolle anzeigen
+about-copy-report-button = Diagnosebericht kopieren
+about-missing-translations-button = Fehlende Übersetzungen
+logs-title = Protokolle
+logs-folder-unavailable = Der Protokollordner konnte nicht ermittelt werden.
+logs-refresh = Aktualisieren
+logs-open-folder = Protokollordner öffnen
+logs-copy-report = Diagnosebericht kopieren
+logs-empty = Es wurde noch nichts protokolliert.
+translations-title = Fehlende Übersetzungen
+translations-summary = { $count } Schlüssel von { $locale } werden auf Englisch angezeigt. Die Übersetzungen werden aus { $dir } geladen.
+translations-copy-keys = Schlüssel kopieren
+translations-complete = Alle Schlüssel sind übersetzt.
+welcome-title = Willkommen bei IcedLens
+welcome-preferences-intro = Wähle Sprache und Design. Alles lässt sich später in den Einstellungen ändern.
+welcome-default-viewer-title = Standard-Bildbetrachter
+welcome-default-viewer-intro = Bilder per Doppelklick im Dateimanager in IcedLens öffnen.
+welcome-default-viewer-button = Als Standard-Bildbetrachter festlegen
+welcome-default-viewer-working = Wird registriert…
+welcome-default-viewer-done = IcedLens öffnet jetzt deine Bilder.
+welcome-default-viewer-confirm = Wähle IcedLens für Bilder in den soeben geöffneten Einstellungen für Standard-Apps.
+welcome-default-viewer-failed = IcedLens konnte nicht registriert werden: { $error }
+welcome-tour-progress = Tipp { $current } von { $total }
+welcome-tour-try = Probier es aus: drücke { $key }
+welcome-tour-tried = Gut gemacht!
+welcome-tour-footer = Alle Tastenkürzel stehen in der Hilfe und lassen sich in den Einstellungen ändern.
+welcome-tour-navigate-title = Ordner durchblättern
+welcome-tour-navigate-body = Zeigt die nächste oder vorherige Datei des Ordners. Das Mausrad zoomt, Ziehen verschiebt.
+welcome-tour-fullscreen-title = Vollbild
+welcome-tour-fullscreen-body = Die Steuerelemente verschwinden nach kurzer Zeit; bewege die Maus, um sie zurückzuholen. Esc beendet den Vollbildmodus.
+welcome-tour-info-title = Details anzeigen
+welcome-tour-info-body = Der Infobereich zeigt Kamera-, Belichtungs- und Videometadaten und bearbeitet Titel, Stichwörter und Daten.
+welcome-tour-editor-title = Bild bearbeiten
+welcome-tour-editor-body = Drehen, zuschneiden, Größe ändern und Licht anpassen. Erst beim Speichern wird etwas geschrieben.
+welcome-tour-video-title = Videos abspielen
+welcome-tour-video-body = Videos abspielen und pausieren. Im Pausenmodus springen Komma und Punkt Bild für Bild.
+welcome-skip = Überspringen
+welcome-back = Zurück
+welcome-next = Weiter
+welcome-finish = IcedLens verwenden
 
 # Notifications
 notification-save-success = Bild erfolgreich gespeichert
+notification-save-copy-success = Bild gespeichert als { $file }
 notification-save-error = Fehler beim Speichern des Bildes
 notification-frame-capture-success = Bild erfolgreich aufgenommen
 notification-frame-capture-error = Fehler beim Aufnehmen des Bildes
 notification-delete-success = Datei erfolgreich gelöscht
 notification-delete-error = Fehler beim Löschen der Datei
 notification-config-save-error = Fehler beim Speichern der Einstellungen
+notification-theme-save-error = Design-Datei konnte nicht gespeichert werden
+notification-config-merged = In einem anderen Fenster geänderte Einstellungen wurden beibehalten
 notification-config-load-error = Fehler beim Laden der Einstellungen, verwende Standardwerte
+notification-config-reloaded = Einstellungen aus settings.toml neu geladen
+notification-translations-reloaded = Übersetzungen neu geladen, { $count } Schlüssel fehlen
+notification-config-reload-error = settings.toml ist ungültig, Änderungen nicht übernommen: { $error }
+notification-settings-imported = Einstellungen importiert
+notification-settings-import-error = Einstellungen konnten nicht importiert werden: { $error }
+notification-settings-exported = Einstellungen exportiert
+notification-settings-export-error = Einstellungen konnten nicht exportiert werden: { $error }
+notification-update-available = IcedLens { $version } ist verfügbar.
+    { $notes }
+notification-update-download = Download-Seite öffnen
+notification-update-none = IcedLens { $version } ist aktuell.
+notification-update-check-error = Suche nach Aktualisierungen fehlgeschlagen: { $error }
 notification-state-parse-error = Fehler beim Lesen des Anwendungszustands, verwende Standardwerte
 notification-state-read-error = Fehler beim Öffnen der Zustandsdatei
 notification-state-path-error = Anwendungsdatenpfad kann nicht bestimmt werden
@@ -400,6 +906,18 @@ notification-editor-create-error = Fehler beim Öffnen des Bildeditors
 notification-editor-load-error = Fehler beim Laden des Bildes zur Bearbeitung
 notification-video-editing-unsupported = Videobearbeitung wird noch nicht unterstützt
 
+# Einheiten
+unit-bytes = { $value } B
+unit-kilobytes = { $value } KB
+unit-megabytes = { $value } MB
+unit-gigabytes = { $value } GB
+unit-bits-per-second = { $value } bit/s
+unit-kilobits-per-second = { $value } kbit/s
+unit-megabits-per-second = { $value } Mbit/s
+unit-frames-per-second = { $value } fps
+unit-nits = { $value } Nits
+unit-nits-range = { $min }–{ $max } Nits
+
 # Metadata panel
 metadata-panel-title = Dateiinformationen
 metadata-panel-close = Panel schließen
@@ -413,6 +931,7 @@ metadata-section-gps = Standort
 metadata-label-dimensions = Abmessungen
 metadata-label-file-size = Dateigröße
 metadata-label-format = Format
+metadata-label-decoder = Decoder
 metadata-label-date-taken = Aufnahmedatum
 metadata-label-camera = Kamera
 metadata-label-exposure = Belichtung
@@ -420,11 +939,49 @@ metadata-label-aperture = Blende
 metadata-label-iso = ISO
 metadata-label-focal-length = Brennweite
 metadata-label-gps = Koordinaten
+metadata-label-gps-dms = GMS
+metadata-open-map-button = In OpenStreetMap öffnen
+metadata-all-tags-title = Alle Tags ({ $count })
+metadata-all-tags-filter-placeholder = Tags filtern…
+metadata-all-tags-no-match = Kein Tag entspricht dem Filter
+metadata-all-tags-copy-tooltip = Klicken, um den Wert zu kopieren
+metadata-batch-title = Stapelbearbeitung
+metadata-stats-title = Ordnerstatistik
+metadata-stats-refresh = Statistik neu berechnen
+metadata-export-title = Metadaten exportieren
+metadata-export-hint = Als JSON oder CSV speichern (je nach Dateiendung) für Kataloge und Skripte
+metadata-export-file = Diese Datei
+metadata-export-folder = Ganzer Ordner
+metadata-stats-loading = Kameradaten werden gelesen…
+metadata-stats-summary = { $count } Dateien, { $exif } mit Kameradaten
+metadata-stats-empty = Keine Kameradaten in diesem Ordner gefunden
+metadata-stats-cameras = Kameras
+metadata-stats-lenses = Objektive
+metadata-stats-focal-lengths = Brennweite (KB-äquiv.)
+metadata-stats-iso = ISO
+metadata-batch-hint = Gilt für die { $count } Dateien des Ordners (oder des aktiven Filters). Leere Felder bleiben unverändert; Videos werden übersprungen.
+metadata-batch-creator-label = Urheber festlegen
+metadata-batch-copyright-label = Copyright festlegen
+metadata-batch-keywords-label = Schlüsselwörter hinzufügen (durch Kommas getrennt)
+metadata-batch-strip-gps = GPS-Standort entfernen
+metadata-batch-apply-button = Auf { $count } Dateien anwenden
+metadata-batch-confirm-warning = { $count } Originaldateien werden geändert. Fortfahren?
+metadata-batch-confirm-button = Anwenden
+metadata-batch-running = Metadaten werden geschrieben…
 metadata-label-codec = Codec
 metadata-label-bitrate = Bitrate
 metadata-label-duration = Dauer
 metadata-label-fps = Bildrate
 metadata-value-unknown = Unbekannt
+metadata-value-decoder-fallback = { $decoder } (Ausweichlösung)
+metadata-label-bit-depth = Farbtiefe
+metadata-value-bit-depth = { $bits } Bit
+metadata-label-hdr = HDR
+metadata-value-hdr-pq = HDR10 (PQ)
+metadata-value-hdr-hlg = HLG
+metadata-label-mastering-display = Mastering-Display
+metadata-label-max-cll = Max. Inhaltshelligkeit (MaxCLL)
+metadata-label-max-fall = Max. Bilddurchschnitt (MaxFALL)
 
 # Metadaten-Bearbeitung
 metadata-edit-button = Bearbeiten
@@ -433,7 +990,28 @@ metadata-cancel-button = Abbrechen
 metadata-save-button = Speichern
 metadata-save-as-button = Speichern unter...
 metadata-save-warning = Speichern ändert die Originaldatei
+metadata-gps-strip-pending = Der Standort wird beim Speichern aus der Datei entfernt.
+metadata-strip-button = Alle Metadaten entfernen
+metadata-strip-dialog-title = Alle Metadaten entfernen
+metadata-strip-dialog-description = Die folgenden Metadaten werden entfernt. Die Bilddaten bleiben unverändert.
+metadata-strip-dialog-overwrite = Datei überschreiben
+metadata-strip-dialog-copy = Bereinigte Kopie speichern
+metadata-strip-dialog-cancel = Abbrechen
+recipe-batch-dialog-title = Auf alle Bilder im Ordner einfügen
+recipe-batch-dialog-description = Die kopierten Anpassungen werden auf { $count } Bilder angewendet.
+recipe-batch-dialog-copy = Jedes Ergebnis wird als nummerierte Kopie neben dem Original gespeichert. Die Originale werden nicht verändert.
+recipe-batch-dialog-subfolder = Die Ergebnisse werden im Unterordner „{ $folder }“ gespeichert und ersetzen dort frühere bearbeitete Versionen. Die Originale werden nicht verändert.
+recipe-batch-dialog-skipped = Animierte Bilder und nicht schreibbare Formate werden übersprungen.
+recipe-batch-dialog-paste = Einfügen
+recipe-batch-dialog-cancel = Abbrechen
+metadata-strip-block-exif = EXIF (Kamera, Datum, GPS-Position)
+metadata-strip-block-xmp = XMP (Titel, Autor, Schlüsselwörter)
+metadata-strip-block-iptc = IPTC (Bildunterschrift, Copyright)
+metadata-strip-block-thumbnail = Eingebettetes Vorschaubild
+metadata-strip-block-comment = Kommentare und Textanmerkungen
 metadata-label-make = Hersteller
+metadata-remove-field-tooltip = Feld entfernen
+metadata-remove-keyword-tooltip = Schlagwort entfernen
 metadata-label-model = Modell
 metadata-label-focal-length-35mm = Brennweite (35mm)
 metadata-label-flash = Blitz
@@ -455,7 +1033,47 @@ metadata-validation-invalid-number = Ungültige Zahl
 # Metadaten-Benachrichtigungen
 notification-metadata-save-success = Metadaten erfolgreich gespeichert
 notification-metadata-save-error = Fehler beim Speichern der Metadaten
+notification-metadata-batch-started = Metadaten von { $count } Dateien werden aktualisiert…
+notification-metadata-batch-success = Metadaten in { $updated } Dateien aktualisiert ({ $skipped } übersprungen)
+notification-metadata-batch-partial = Metadaten in { $updated } Dateien aktualisiert, { $failed } fehlgeschlagen (erste: { $filename })
+notification-metadata-exported = Metadaten von { $count } Dateien exportiert
+notification-metadata-export-error = Metadaten konnten nicht exportiert werden: { $error }
+notification-plugins-reloaded = { $count } Plugins geladen
+notification-plugins-folder-error = Plugin-Ordner konnte nicht geöffnet werden: { $error }
+notification-logs-folder-error = Der Protokollordner konnte nicht geöffnet werden: { $error }
+notification-diagnostic-report-copied = Diagnosebericht in die Zwischenablage kopiert
+notification-plugin-tool-error = Plugin-Werkzeug fehlgeschlagen: { $error }
+notification-plugin-export-success = { $filename } exportiert
+notification-plugin-export-error = Plugin-Export fehlgeschlagen: { $error }
+notification-open-with-done = { $name }: fertig
+notification-open-with-output = { $name }: { $output }
+notification-open-with-error = { $name } fehlgeschlagen: { $error }
+notification-verify-started = { $count } Dateien werden geprüft…
+notification-verify-clean = Alle { $count } Dateien sind intakt
+notification-verify-damaged = { $damaged } von { $count } Dateien sind beschädigt (Liste in die Zwischenablage kopiert)
+notification-share-empty = In diesem Ordner gibt es keine Datei zum Teilen
+notification-share-error = Teilen auf Port { $port } nicht möglich: { $error }
+notification-share-stopped = Freigabe beendet
+notification-share-url-copied = Adresse in die Zwischenablage kopiert
+notification-strip-success = Alle Metadaten entfernt
+notification-layout-applied = Layout: { $name }
+notification-layout-saved = Layout „{ $name }“ gespeichert
+layout-name-culling = Sichtung
+layout-name-presentation = Präsentation
+layout-name-custom = Benutzerdefiniert
+notification-strip-copy-success = Bereinigte Kopie gespeichert als { $filename }
+notification-strip-error = Metadaten konnten nicht entfernt werden: { $error }
+notification-strip-nothing = Diese Datei enthält keine entfernbaren Metadaten
+notification-strip-unsupported = Das Entfernen von Metadaten wird für dieses Dateiformat nicht unterstützt
 notification-metadata-validation-error = Bitte beheben Sie die Validierungsfehler vor dem Speichern
+notification-open-url-error = Link konnte nicht geöffnet werden: { $error }
+notification-copied-to-clipboard = In die Zwischenablage kopiert
+notification-color-copied = Farbe { $color } in die Zwischenablage kopiert
+notification-adjustments-copied = Anpassungen kopiert (Schritte: { $count })
+notification-dismiss-tooltip = Schließen
+notification-recipe-batch-started = Anpassungen werden auf { $count } Bilder angewendet…
+notification-recipe-batch-success = Anpassungen auf { $updated } Bilder angewendet ({ $skipped } übersprungen)
+notification-recipe-batch-partial = Anpassungen auf { $updated } Bilder angewendet, { $failed } fehlgeschlagen (erstes: { $filename })
 
 # Metadaten progressive Offenlegung
 metadata-add-field = Metadatenfeld hinzufügen...
@@ -467,6 +1085,7 @@ metadata-label-dc-title = Titel
 metadata-label-dc-creator = Ersteller
 metadata-label-dc-description = Beschreibung
 metadata-label-dc-subject = Schlagwörter
+metadata-keyword-input-placeholder = Schlagwort eingeben und Enter drücken
 metadata-label-dc-rights = Urheberrecht
 
 navbar-info-button = Info
@@ -477,11 +1096,37 @@ empty-state-subtitle = Dateien hier ablegen oder klicken zum Öffnen
 empty-state-button = Datei öffnen
 empty-state-drop-hint = Bilder oder Videos hier hinziehen
 
+# File dialog filters
+dialog-filter-all-media = Alle Medien
+dialog-filter-images = Bilder
+dialog-filter-videos = Videos
+dialog-filter-image-format = { $format }-Bild
+dialog-filter-video-format = { $format }-Video
+
 # Additional notifications
 notification-empty-dir = Keine unterstützten Mediendateien in diesem Ordner gefunden
 notification-load-error-io = Datei konnte nicht geöffnet werden. Prüfen Sie, ob sie existiert und Sie Zugriffsrechte haben.
 notification-load-error-svg = SVG konnte nicht gerendert werden. Die Datei ist möglicherweise fehlerhaft.
 notification-load-error-video = Video konnte nicht abgespielt werden. Das Format wird möglicherweise nicht unterstützt.
+notification-image-sequence-not-found = Dieses Bild gehört zu keiner nummerierten Sequenz.
+notification-capture-day = { $date } — { $count } Dateien
+notification-similar-indexing = Bilder dieses Ordners werden verglichen…
+notification-similar-found = { $count } ähnliche Bilder
+notification-similar-none = Kein ähnliches Bild in diesem Ordner
+notification-face-model-downloading = Gesichtserkennungsmodell wird heruntergeladen…
+notification-no-faces-found = Keine Gesichter in diesem Bild gefunden.
+notification-clip-exported = Clip gespeichert unter { $path }
+notification-clip-exported-reencoded = Clip neu kodiert und gespeichert unter { $path } (ohne Ton)
+notification-clip-export-failed = Clip konnte nicht exportiert werden: { $message }
+notification-frames-exported = { $count } Einzelbilder gespeichert unter { $path }
+notification-mini-player-error = Der Mini-Player konnte nicht geöffnet werden
+notification-visible-area-exported = Sichtbarer Bereich gespeichert unter { $path }
+notification-visible-area-export-error = Sichtbarer Bereich konnte nicht exportiert werden
+notification-view-exported = Ansicht gespeichert unter { $path }
+notification-view-export-error = Die Ansicht konnte nicht exportiert werden
+notification-view-copied = Ansicht in die Zwischenablage kopiert
+notification-frames-export-failed = Einzelbilder konnten nicht exportiert werden: { $message }
+notification-face-detection-error = Gesichtserkennung fehlgeschlagen: { $error }
 notification-load-error-timeout = Laden hat zu lange gedauert. Die Datei ist möglicherweise zu groß oder das System ist ausgelastet.
 notification-skipped-corrupted-files = Übersprungen: { $files }
 notification-skipped-and-others = +{ $count } weitere
@@ -515,6 +1160,19 @@ settings-upscale-status-error = Fehler: { $message }
 settings-upscale-status-not-downloaded = Modell nicht heruntergeladen
 settings-upscale-enabled = Aktiviert
 settings-upscale-disabled = Deaktiviert
+settings-enable-background-removal-label = KI-Hintergrundentfernung
+settings-enable-background-removal-hint = Aktiviert die KI-Hintergrundentfernung mit dem Segmentierungsmodell U²-Net (~176 MB Download).
+settings-background-removal-model-url-label = Modell-URL
+settings-background-removal-model-url-placeholder = https://github.com/...
+settings-background-removal-model-url-hint = URL, von der das U²-Net-ONNX-Modell geladen wird.
+settings-background-removal-status-label = Modellstatus
+settings-background-removal-status-downloading = Modell wird heruntergeladen ({ $progress } %)...
+settings-background-removal-status-validating = Modell wird überprüft...
+settings-background-removal-status-ready = Modell bereit
+settings-background-removal-status-error = Fehler: { $message }
+settings-background-removal-status-not-downloaded = Modell nicht heruntergeladen
+settings-background-removal-enabled = Aktiviert
+settings-background-removal-disabled = Deaktiviert
 
 # KI-Editor-Werkzeug
 image-editor-tool-deblur = KI-Entunschärfung
@@ -528,12 +1186,49 @@ image-editor-deblur-validating = Modell wird validiert, bitte warten...
 image-editor-deblur-downloading = Modell wird heruntergeladen ({ $progress }%)...
 image-editor-deblur-error = Fehler: { $error }
 image-editor-deblur-already-applied = Entunschärfung bereits angewendet. Verwenden Sie Rückgängig, um bei Bedarf zurückzusetzen.
+image-editor-tool-background = KI-Hintergrundentfernung
+image-editor-background-remove = Hintergrund entfernen
+image-editor-background-apply = Freistellung anwenden
+image-editor-background-processing = Hintergrund wird entfernt...
+image-editor-background-model-not-ready = Aktivieren Sie zuerst die KI-Hintergrundentfernung in den Einstellungen
+image-editor-background-validating = Modell wird überprüft, bitte warten...
+image-editor-background-downloading = Modell wird heruntergeladen ({ $progress } %)...
+image-editor-background-error = Fehler: { $error }
+image-editor-background-refine-hint = Malen Sie über das Bild, um die Kanten zu verfeinern, und wenden Sie dann an.
+image-editor-background-brush-keep = Behalten
+image-editor-background-brush-erase = Löschen
+image-editor-background-brush-size = Pinselgröße
+image-editor-background-transparent-export-hint = Als PNG oder WebP speichern, um die Transparenz zu behalten; JPEG füllt sie weiß.
+image-editor-tool-denoise = Entrauschen
+image-editor-denoise-method-label = Methode
+image-editor-denoise-method-bilateral = Bilateral
+image-editor-denoise-method-nlmeans = NL-means
+image-editor-denoise-method-model = KI-Modell
+image-editor-denoise-strength-label = Stärke
+image-editor-denoise-detail-label = Detailerhalt
+image-editor-denoise-preview-hint = Die Vorschau wird auf einer verkleinerten Kopie berechnet; Anwenden bearbeitet das ganze Bild.
+image-editor-denoise-model-hint = Das Modell hat keine Vorschau; das Ergebnis erscheint nach dem Anwenden.
+image-editor-denoise-apply = Entrauschen anwenden
+image-editor-denoise-processing = Rauschen wird reduziert...
+image-editor-plugins-section-title = Plugins
+image-editor-plugins-running = Plugin läuft…
+image-editor-plugins-export = Als { $format } exportieren
 
 # KI-Hilfeabschnitt
 help-editor-deblur-title = KI-Entunschärfung
 help-editor-deblur-desc = Verwenden Sie KI, um unscharfe Bilder mit dem neuronalen Netzwerk NAFNet zu schärfen.
 help-editor-deblur-enable = Aktivieren unter Einstellungen → KI / Maschinelles Lernen (lädt ~92 MB Modell herunter)
 help-editor-deblur-lossless = Für beste Qualität als verlustfreies WebP oder PNG exportieren
+help-editor-background-title = KI-Hintergrundentfernung
+help-editor-background-desc = Stellen Sie das Motiv mit dem Segmentierungsmodell U²-Net frei, das auf Ihrem Computer läuft.
+help-editor-background-enable = In Einstellungen → KI / Maschinelles Lernen aktivieren (lädt ein Modell mit ~176 MB)
+help-editor-background-refine = Verfeinern Sie die Kanten vor dem Anwenden mit den Pinseln Behalten und Löschen
+help-editor-background-export = Als PNG oder WebP speichern, um den transparenten Hintergrund zu behalten
+help-editor-denoise-title = Entrauschen
+help-editor-denoise-desc = Verringern Sie das Rauschen von Fotos bei wenig Licht.
+help-editor-denoise-methods = Bilateral ist schnell; NL-means ist langsamer, erhält aber mehr Struktur
+help-editor-denoise-sliders = Erhöhen Sie die Stärke für weniger Rauschen und den Detailerhalt für feine Kanten
+help-editor-denoise-model = Setzen Sie denoise_model_url im Abschnitt [ai] von settings.toml, um ein KI-Modell hinzuzufügen
 
 # KI-Benachrichtigungen
 notification-deblur-success = Bild erfolgreich entschärft
@@ -551,3 +1246,9 @@ notification-upscale-download-error = Herunterladen des Hochskalierungs-Modells
 notification-upscale-validation-error = Modellvalidierung fehlgeschlagen: { $error }
 notification-upscale-resize-success = Bild mit KI-Hochskalierung vergrößert
 notification-upscale-resize-error = KI-Hochskalierung fehlgeschlagen: { $error }
+notification-background-ready = KI-Hintergrundentfernung ist einsatzbereit
+notification-background-download-error = Herunterladen des Hintergrundentfernungsmodells fehlgeschlagen: { $error }
+notification-background-validation-error = Modellüberprüfung fehlgeschlagen: { $error }
+notification-background-removal-error = Hintergrundentfernung fehlgeschlagen: { $error }
+notification-denoise-model-downloading = Entrauschungsmodell wird heruntergeladen…
+notification-denoise-error = Entrauschen fehlgeschlagen: { $error }
@@ -8,8 +8,15 @@ settings-title = Settings
 settings-section-general = General
 settings-section-display = Display
 settings-section-video = Video
+settings-section-editor = Editor
 settings-section-fullscreen = Fullscreen
+settings-section-guides = Guides
 settings-section-ai = AI / Machine Learning
+settings-section-advanced = Advanced
+settings-section-mouse = Mouse
+settings-section-shortcuts = Keyboard shortcuts
+settings-section-plugins = Plugins
+settings-section-accessibility = Accessibility
 select-language-label = Select Language:
 language-name-en-US = English
 language-name-fr = French
@@ -24,6 +31,7 @@ viewer-zoom-label = Zoom
 viewer-zoom-input-placeholder = 100
 viewer-zoom-reset-button = Reset
 viewer-fit-to-window-toggle = Fit to window
+viewer-lock-view-tooltip = Lock zoom and position when changing image
 viewer-zoom-input-error-invalid = Please enter a valid number.
 viewer-zoom-step-error-invalid = The zoom step must be a number.
 viewer-zoom-step-error-range = The zoom step must be between 1% and 200%.
@@ -32,7 +40,21 @@ viewer-zoom-in-tooltip = Zoom in
 viewer-zoom-out-tooltip = Zoom out
 viewer-fullscreen-tooltip = Toggle fullscreen
 viewer-rotate-cw-tooltip = Rotate clockwise
+viewer-export-visible-tooltip = Export visible area
+viewer-inspector-tooltip = Pixel inspector
+viewer-inspector-copy-hint = Click to copy
+viewer-ruler-tooltip = Ruler
+viewer-guides-tooltip = Composition guides
 viewer-rotate-ccw-tooltip = Rotate counter-clockwise
+viewer-mirror-tooltip = Flip video horizontally
+viewer-matte-background = Background theme
+viewer-matte-black = Black matte
+viewer-matte-white = White matte
+viewer-matte-gray = Gray matte
+viewer-matte-magenta = Magenta matte
+viewer-matte-green = Green matte
+viewer-alpha-straight = Straight alpha
+viewer-alpha-premultiplied = Premultiplied alpha
 viewer-fullscreen-disabled-unsaved = Save or cancel metadata changes first
 viewer-double-click = Double-click
 viewer-scroll-wheel = Scroll wheel
@@ -48,6 +70,9 @@ filter-media-type-all = All
 filter-media-type-images = Images only
 filter-media-type-videos = Videos only
 filter-media-type-placeholder = Select type...
+filter-rating-label = Minimum rating
+filter-rating-any = Any rating
+filter-rating-min = { $stars } and up
 filter-date-label = Date filter
 filter-date-field-label = Filter by
 filter-date-field-modified = Modified date
@@ -61,6 +86,9 @@ filter-date-clear = Clear date
 filter-tooltip-date-from = from { $date }
 filter-tooltip-date-to = to { $date }
 filter-tooltip-date-range = { $start } – { $end }
+quick-search-placeholder = Search by filename...
+quick-search-no-results = No matches
+quick-search-hint = ↑/↓ select · Enter open · Esc close
 
 settings-zoom-step-label = Zoom step
 settings-zoom-step-placeholder = 10
@@ -69,10 +97,38 @@ settings-background-label = Viewer background
 settings-background-light = Light
 settings-background-dark = Dark
 settings-background-checkerboard = Checkerboard
+settings-background-auto = Auto
+settings-background-auto-hint = Dark behind bright images, light behind dark ones
 settings-theme-mode-label = Application theme
 settings-theme-system = Match system
 settings-theme-light = Light
 settings-theme-dark = Dark
+settings-ui-scale-label = Interface scale
+settings-ui-scale-hint = Enlarges or shrinks text, spacing and controls, on top of the system scale. Applied when the slider is released.
+settings-accessibility-disabled = Off
+settings-accessibility-enabled = On
+settings-reduced-motion-label = Reduced motion
+settings-reduced-motion-hint = Loading spinners stand still instead of rotating.
+settings-high-contrast-focus-label = High-contrast focus
+settings-high-contrast-focus-hint = Draws a thick outline in the text color around the field being typed in.
+settings-large-targets-label = Larger buttons
+settings-large-targets-hint = Makes the toolbar, menu and video control buttons easier to click.
+settings-always-show-fullscreen-controls-label = Always show fullscreen controls
+settings-always-show-fullscreen-controls-hint = Keeps the toolbar, video controls and navigation arrows visible in fullscreen instead of hiding them after the overlay timeout.
+settings-accent-label = Accent color
+settings-accent-hint = Used for selected buttons, sliders, highlights and notifications. Enter a #rrggbb color for another shade.
+settings-accent-custom-hint = The custom palette below sets the accent while it is on.
+settings-accent-default = Default
+settings-custom-theme-label = Custom palette
+settings-custom-theme-hint = Replaces the light or dark theme. Changes are previewed right away and saved in theme.toml next to settings.toml.
+settings-custom-theme-disabled = Off
+settings-custom-theme-enabled = On
+settings-palette-background = Background
+settings-palette-text = Text
+settings-palette-accent = Accent
+settings-palette-success = Success
+settings-palette-danger = Danger
+settings-palette-invalid = Use the #rrggbb format
 help-usage-heading = USAGE:
 help-options-heading = OPTIONS:
 help-args-heading = ARGS:
@@ -83,25 +139,85 @@ help-arg-image-path = <PATH>      Path to a media file or directory to open
 help-example-1 = iced_lens ./photo.png
 help-example-2 = iced_lens ./my_photos/
 help-example-3 = iced_lens --lang fr ./image.jpg
+help-example-4 = iced_lens verify /media/sdcard/DCIM
+help-example-5 = iced_lens convert photo.png photo.jpg --quality 85
+help-example-6 = iced_lens info clip.mp4 --json
+help-commands-heading = COMMANDS:
+help-line-command-verify = verify [DIR]  Fully decode every media file of DIR and list damaged ones
+help-line-command-convert = convert <IN> <OUT> [--quality <1-100>]  Convert a media file to the image format of OUT
+help-line-command-thumb = thumb [PATH] [--size <px>] [--out <dir>]  Write JPEG thumbnails of a file or of every media file of a directory
+help-line-command-info = info <PATH> [--json|--csv]  Print the metadata of a media file or of every media file of a directory
+cli-verify-summary = { $checked } files checked, { $damaged } damaged
+cli-thumb-summary = { $written } thumbnails written, { $failed } failed
+cli-info-unsupported = no readable metadata (unsupported or damaged file)
 help-description = { -app-name } – Image Viewer
 help-line-option-i18n-dir =     --i18n-dir <path>  Load translations from directory
+help-line-option-watch-i18n =     --watch-i18n   Reload translations when their .ftl files change
 help-line-option-data-dir =     --data-dir <path>  Override data directory (state files)
 help-line-option-config-dir =     --config-dir <path>  Override config directory (settings.toml)
 settings-sort-order-label = Image navigation sort order
 settings-sort-alphabetical = Alphabetical
 settings-sort-modified = Modified date
 settings-sort-created = Created date
+settings-sort-size = File size
+settings-sort-type = File type
+settings-sort-ascending = Ascending
+settings-sort-descending = Descending
+settings-sort-order-hint = Alphabetical order compares numbers by value (img2 before img10).
 settings-max-skip-attempts-label = Auto-skip corrupted files
 settings-max-skip-attempts-hint = Maximum consecutive corrupted files to skip during navigation.
+settings-prefetch-cache-size-label = Preloaded media
+settings-prefetch-cache-size-hint = Decoded files kept in memory so the next and previous ones show instantly. 0 turns preloading off.
+settings-similarity-threshold-label = Similar image threshold
+settings-similarity-threshold-hint = Minimum resemblance for Find similar to jump to an image. Lower it to also match reframed shots.
 settings-persist-filters-label = Remember filters
 settings-persist-filters-hint = Keep filter settings between sessions.
 settings-persist-filters-disabled = Off
 settings-persist-filters-enabled = On
+settings-remember-view-label = Remember view per image
+settings-remember-view-hint = Restore the zoom, scroll position and rotation of an image when coming back to it during the session.
+settings-remember-view-disabled = Off
+settings-remember-view-enabled = On
+settings-minimap-label = Minimap
+settings-minimap-hint = Show the whole image in a corner while zoomed in, with the visible part outlined. Click or drag in it to move around.
+settings-minimap-disabled = Off
+settings-minimap-enabled = On
+settings-sandboxed-decoding-label = Sandboxed decoding
+settings-sandboxed-decoding-hint = Decode images in a separate process, so a damaged or malicious file can't crash or compromise the viewer. Loading is slightly slower.
+settings-sandboxed-decoding-disabled = Off
+settings-sandboxed-decoding-enabled = On
+settings-webp-decoder-label = WebP decoder
+settings-webp-decoder-hint = Library used for still WebP images. If it can't read a file, the other one is tried automatically.
+settings-webp-decoder-image-rs = image-rs
+settings-webp-decoder-libwebp = libwebp
 settings-overlay-timeout-label = Fullscreen overlay auto-hide delay
 settings-overlay-timeout-hint = Time before controls disappear when in fullscreen mode.
+settings-overlay-shown = Shown
+settings-overlay-hidden = Hidden
+settings-overlay-filename-label = File name
+settings-overlay-index-label = Position in folder (12/340)
+settings-overlay-clock-label = Clock
+settings-overlay-arrows-label = Navigation arrows
+settings-overlay-histogram-label = Histogram
+settings-overlay-position-label = Overlay position
+settings-overlay-position-hint = Where the file name, position, clock and histogram appear.
+settings-overlay-position-top-left = Top left
+settings-overlay-position-top-center = Top
+settings-overlay-position-top-right = Top right
+settings-overlay-position-bottom-left = Bottom left
+settings-overlay-position-bottom-center = Bottom
+settings-overlay-position-bottom-right = Bottom right
+settings-overlay-opacity-label = Overlay opacity
 seconds = seconds
 image-editor-title = Image Editor
 image-editor-back-to-viewer = Back to Viewer
+image-editor-recovery-prompt = Unsaved edits of this image were recovered from a previous session.
+image-editor-recovery-restore = Restore edits
+image-editor-recovery-discard = Discard
+image-editor-guides = Guides
+image-editor-zoom-fit = Fit
+image-editor-show-original = Show original
+image-editor-compare-split = Before/After
 image-editor-cancel = Cancel
 image-editor-save = Save
 image-editor-save-as = Save As...
@@ -114,6 +230,10 @@ image-editor-rotate-left-tooltip = Rotate image counter-clockwise
 image-editor-flip-section-title = Flip
 image-editor-flip-horizontal-tooltip = Flip image horizontally (mirror left-right)
 image-editor-flip-vertical-tooltip = Flip image vertically (mirror top-bottom)
+image-editor-sidebar-collapse-tooltip = Hide the tools
+image-editor-sidebar-expand-tooltip = Show the tools
+image-editor-navigate-previous-tooltip = Previous image
+image-editor-navigate-next-tooltip = Next image
 image-editor-resize-section-title = Resize
 image-editor-resize-scale-label = Scale
 image-editor-resize-dimensions-label = Target size
@@ -130,10 +250,42 @@ image-editor-resize-ai-model-validating = Validating AI model
 image-editor-resize-ai-model-error = AI model error
 image-editor-resize-ai-enlargement-only = AI upscaling only applies to enlargements
 image-editor-light-section-title = Light Adjustments
+image-editor-auto-enhance = Auto enhance
+image-editor-auto-enhance-strength-label = Strength
+image-editor-auto-enhance-neutral = This image is already well balanced.
 image-editor-light-brightness-label = Brightness
 image-editor-light-contrast-label = Contrast
+image-editor-grayscale-section-title = Black & White
+image-editor-grayscale-enable = Convert to black & white
+image-editor-grayscale-preset-neutral = Neutral
+image-editor-grayscale-preset-red = Red
+image-editor-grayscale-preset-green = Green
+image-editor-grayscale-preset-blue = Blue
+image-editor-grayscale-red-label = Red channel
+image-editor-grayscale-green-label = Green channel
+image-editor-grayscale-blue-label = Blue channel
+image-editor-grayscale-total = Total: { $total } % (100 % keeps the overall brightness)
+image-editor-sharpen-section-title = Sharpen
+image-editor-sharpen-amount-label = Amount
+image-editor-sharpen-radius-label = Radius
+image-editor-sharpen-threshold-label = Threshold
 image-editor-light-reset = Reset
 image-editor-light-apply = Apply
+image-editor-light-copy = Copy adjustments
+image-editor-light-paste = Paste adjustments
+image-editor-light-paste-folder = Paste to all images in folder
+image-editor-tool-filters = Filters
+image-editor-filter-black-and-white = Black & White
+image-editor-filter-noir = Noir
+image-editor-filter-sepia = Sepia
+image-editor-filter-vignette = Vignette
+image-editor-filter-warm-film = Warm Film
+image-editor-filter-cool-film = Cool Film
+image-editor-filter-faded = Faded
+image-editor-filter-vivid = Vivid
+image-editor-filter-strength-label = Strength
+image-editor-filter-hint = Pick a look to preview it on the image.
+image-editor-filter-apply = Apply Filter
 image-editor-crop-section-title = Crop
 image-editor-crop-ratio-label = Aspect ratio
 image-editor-crop-ratio-free = Free
@@ -143,38 +295,267 @@ image-editor-crop-ratio-portrait = Portrait (9:16)
 image-editor-crop-ratio-photo = Photo (4:3)
 image-editor-crop-ratio-photo-portrait = Photo Portrait (3:4)
 image-editor-crop-apply = Apply crop
+image-editor-crop-face = Crop to face
+image-editor-crop-face-detecting = Detecting faces…
+image-editor-crop-face-none = No face found in this image.
 image-editor-undo-redo-section-title = Last modification
 image-editor-undo = Undo
 image-editor-redo = Redo
+image-editor-history-title = History
+image-editor-history-steps-title = Steps
+image-editor-history-original = Original image
+image-editor-snapshots-title = Snapshots
+image-editor-snapshot-name-placeholder = Snapshot name
+image-editor-snapshot-save = Save
+image-editor-snapshot-delete-tooltip = Delete snapshot
+image-editor-snapshot-unnamed = After step { $step }
+image-editor-history-rotate-left = Rotate left
+image-editor-history-rotate-right = Rotate right
+image-editor-history-flip-horizontal = Flip horizontally
+image-editor-history-flip-vertical = Flip vertically
+image-editor-history-crop = Crop
+image-editor-history-resize = Resize
+image-editor-history-upscale = AI upscale
+image-editor-history-brightness = Brightness
+image-editor-history-contrast = Contrast
+image-editor-history-auto-enhance = Auto enhance
+image-editor-history-black-white = Black & white
+image-editor-history-sharpen = Sharpen
+image-editor-history-look = Filter
+image-editor-history-deblur = AI deblur
+image-editor-history-background = Background removal
+image-editor-history-denoise = Denoise
+image-editor-history-plugin = Plugin tool
 image-editor-export-format-label = Export format
+image-editor-proof-label = Color proof
+image-editor-proof-none = None
+image-editor-proof-convert = Convert colors to the profile
+image-editor-proof-convert-tooltip = Off: the profile is only attached and pixel values are kept. On: colors are converted so they look the same in the target space.
 media-loading = Loading...
 settings-video-autoplay-label = Video autoplay
 settings-video-autoplay-enabled = Enabled
 settings-video-autoplay-disabled = Disabled
 settings-video-autoplay-hint = When enabled, videos start playing automatically when opened.
+settings-video-resume-label = Resume videos
+settings-video-resume-hint = Reopened videos can continue where you left them.
+settings-video-resume-ask = Ask
+settings-video-resume-always = Always
+settings-video-resume-never = Never
+settings-tone-mapping-label = HDR tone mapping
+settings-tone-mapping-hint = How the highlights of HDR10 and HLG videos are brought into the range of the screen.
+settings-tone-mapping-clip = Clip
+settings-tone-mapping-reinhard = Reinhard
+settings-tone-mapping-hable = Filmic (Hable)
+settings-tone-mapping-aces = ACES
+settings-audio-device-label = Audio output
+settings-audio-device-hint = Device that plays the sound of videos. With the system default, playback moves to the new default when a device is unplugged or changed.
+settings-audio-device-default = System default
+settings-audio-device-missing = { $id } (not connected)
 video-play-tooltip = Play (Space)
 video-pause-tooltip = Pause (Space)
 video-mute-tooltip = Mute (M)
+video-volume-boost-tooltip = Boosted above 100%, remembered for this file
 video-unmute-tooltip = Unmute (M)
 video-no-audio-tooltip = No audio track
 video-loop-tooltip = Loop
+video-loop-in-tooltip = Set A–B loop start here
+video-loop-out-tooltip = Set A–B loop end here
+video-loop-clear-tooltip = Clear A–B loop
+video-export-clip = Export clip
+video-export-clip-tooltip = Export the A–B loop region to a new video file
+video-export-clip-progress = Exporting… { $percent }%
+video-export-clip-cancel-tooltip = Cancel the clip export
+video-export-frames = Export frames
+video-export-frames-tooltip = Export a range of frames as images, a GIF or a WebP animation
+video-frames-range-loop = A–B loop
+video-frames-range-around = { $seconds } s around playhead
+video-frames-range-tooltip = Range exported when no A–B loop is set
+video-frames-format-sequence = PNG sequence
+video-frames-format-gif = Animated GIF
+video-frames-format-webp = Animated WebP
+video-frames-format-tooltip = Output format
+video-frames-fps = { $fps } fps
+video-frames-fps-tooltip = Frames per second
+video-frames-scale-tooltip = Output size
+video-frames-export = Export…
+video-frames-export-tooltip = Choose where to save the frames and export them
+video-frames-cancel-tooltip = Cancel the frame export
+video-frames-mode-range = Range
+video-frames-mode-burst = Burst
+video-frames-mode-tooltip = Export a range as one file, or a burst of separate images
+video-frames-burst-count = { $count } frames
+video-frames-burst-count-tooltip = Frames captured around the playhead
+video-frames-burst-interval = every { $ms } ms
+video-frames-burst-interval-tooltip = Time between two frames
+video-frames-burst-tooltip = Choose a folder and save each frame there as a full-size PNG
 video-capture-tooltip = Capture current frame
 video-step-forward-tooltip = Step forward one frame (.)
 video-step-backward-tooltip = Step backward one frame (,)
 video-more-tooltip = More options
 video-speed-down-tooltip = Decrease speed (J)
 video-speed-up-tooltip = Increase speed (L)
+video-speed-selector-tooltip = Playback speed (audio keeps its pitch from 0.25x to 3x)
+video-audio-visualization-off = Audio strip: off
+video-audio-visualization-waveform = Audio strip: waveform
+video-audio-visualization-vu-meter = Audio strip: VU meter
+video-audio-visualization-tooltip = Show the waveform or a live level meter under the seek bar
+video-adjust-button = Adjust
+video-adjust-tooltip = Brightness, contrast, saturation and gamma of the video
+video-adjust-brightness = Brightness
+video-adjust-contrast = Contrast
+video-adjust-saturation = Saturation
+video-adjust-gamma = Gamma
+video-adjust-reset = Reset
+video-adjust-reset-tooltip = Restore the original colors
+video-mini-player = Mini player
+video-mini-player-tooltip = Keep playing in a small window above the others
+pip-play-tooltip = Play
+pip-pause-tooltip = Pause
+pip-return-tooltip = Return to the main window
+pip-close-tooltip = Close the mini player
+video-frame-counter = Frame { $current } / { $total }
+video-fine-scrub-frame = Fine: 1 frame
+video-fine-scrub-tenth = Fine: 0.1 s
+video-resume-prompt = Resume from { $position }?
+video-resume-button = Resume
+video-resume-start-over-button = Start over
+video-chapter-previous-tooltip = Previous chapter (PgUp)
+video-chapter-next-tooltip = Next chapter (PgDn)
+video-chapter-list-tooltip = Chapters
+video-subtitles-off = Subtitles off
+video-decode-hardware = GPU decoding ({ $backend })
+video-decode-software = CPU decoding
+video-cache-stats = Frame cache: { $rate }% hits, { $frames } frames, { $size } MB
+video-subtitles-track-tooltip = Subtitle track
+video-subtitles-delay-tooltip = Subtitle delay (click to reset)
+video-subtitles-size-tooltip = Subtitle size
+video-subtitles-background = Box
+video-subtitle-size-small = Small
+video-subtitle-size-medium = Medium
+video-subtitle-size-large = Large
 hud-video-no-audio = No audio
 settings-audio-normalization-label = Audio volume normalization
 settings-audio-normalization-enabled = Enabled
 settings-audio-normalization-disabled = Disabled
 settings-audio-normalization-hint = Automatically levels audio volume between different media files to prevent sudden volume changes.
+settings-hardware-decoding-label = Hardware video decoding
+settings-hardware-decoding-enabled = Enabled
+settings-hardware-decoding-disabled = Disabled
+settings-hardware-decoding-hint = Decodes videos on the graphics card (VA-API, D3D11VA/DXVA2 or VideoToolbox) for smooth 4K playback, falling back to the processor when unsupported. Applies to the next video opened.
+settings-file-label = Settings file
+settings-file-hint = Changes made to settings.toml in a text editor apply within a few seconds. Export copies the settings to another file; import replaces them with a file’s settings.
+settings-file-import = Import…
+settings-file-export = Export…
+settings-check-updates-label = Updates
+settings-check-updates-hint = Look for a newer release on GitHub. Nothing is downloaded or installed.
+settings-check-updates-manual = Manually
+settings-check-updates-weekly = Weekly
+settings-check-updates-now = Check now
+settings-mouse-wheel-label = Mouse wheel over the media
+settings-mouse-wheel-zoom = Zoom
+settings-mouse-wheel-navigate = Previous / next
+settings-mouse-wheel-volume = Video volume
+settings-mouse-wheel-hint = Video volume changes the volume while a video plays and zooms images.
+settings-mouse-drag-button-label = Button to pan zoomed media
+settings-mouse-drag-button-hint = Double-clicking with the left button still toggles fullscreen.
+settings-mouse-button-left = Left
+settings-mouse-button-middle = Middle
+settings-mouse-button-right = Right
+settings-mouse-middle-click-label = Middle click
+settings-mouse-middle-click-toggle-fit = Toggle fit to window
+settings-mouse-middle-click-none = Nothing
+settings-mouse-middle-click-hint = Ignored when the middle button pans the media.
+settings-mouse-navigation-buttons-label = Back and forward buttons
+settings-mouse-navigation-buttons-enabled = Previous / next media
+settings-mouse-navigation-buttons-disabled = Ignored
+settings-mouse-navigation-buttons-hint = The side buttons of the mouse browse the folder like the arrow keys.
+settings-shortcuts-hint = Click a shortcut, then press the new key. Esc cancels.
+settings-shortcuts-press-key = Press a key…
+settings-shortcuts-reset = Reset all
+settings-shortcuts-conflict = { $other } already uses this key; { $action } keeps its shortcut.
+settings-shortcuts-reserved = This key can't be assigned; { $action } keeps its shortcut.
+settings-shortcuts-group-viewer = Viewer
+settings-shortcuts-group-video = Video
+settings-shortcuts-group-editor = Editor
+settings-plugins-hint = Plugins are folders with a plugin.toml file in the plugins folder. They run as separate programs.
+settings-plugins-empty = No plugins installed.
+settings-plugins-invalid = Invalid plugin.toml: { $error }
+settings-plugins-hooks = Provides: { $hooks }
+settings-plugins-hook-media-loaded = media loaded hook
+settings-plugins-hook-editor-tool = editor tools
+settings-plugins-hook-export-format = export formats
+settings-plugins-enabled = Enabled
+settings-plugins-disabled = Disabled
+settings-plugins-open-folder = Open plugins folder
+settings-plugins-reload = Reload plugins
+shortcut-action-enter-editor = Edit image
+shortcut-action-toggle-info-panel = Show / hide the info panel
+shortcut-action-toggle-fullscreen = Toggle fullscreen
+shortcut-action-rotate-clockwise = Rotate clockwise
+shortcut-action-rotate-counterclockwise = Rotate counterclockwise
+shortcut-action-mirror = Mirror
+shortcut-action-next-matte = Next background
+shortcut-action-previous-matte = Previous background
+shortcut-action-toggle-alpha = Show / hide transparency
+shortcut-action-play-sequence = Play as sequence
+shortcut-action-export-view = Export the view
+shortcut-action-copy-view = Copy the view
+shortcut-action-quick-search = Quick search
+shortcut-action-next-face = Zoom to next face
+shortcut-action-previous-face = Zoom to previous face
+shortcut-action-toggle-face-overlay = Show detected faces
+shortcut-action-next-similar = Next similar image
+shortcut-action-previous-similar = Previous similar image
+shortcut-action-toggle-osd = Show / hide the on-screen display
+shortcut-action-toggle-pixel-inspector = Toggle the pixel inspector
+shortcut-action-toggle-ruler = Toggle the ruler
+shortcut-action-toggle-guides = Show / hide the composition guides
+shortcut-action-play-pause = Play / pause
+shortcut-action-mute = Mute / unmute
+shortcut-action-step-backward = Previous frame
+shortcut-action-step-forward = Next frame
+shortcut-action-speed-down = Slow down
+shortcut-action-speed-up = Speed up
+shortcut-action-loop-start = Set loop start
+shortcut-action-loop-end = Set loop end
+shortcut-action-loop-clear = Clear loop
+shortcut-action-save = Save
+shortcut-action-undo = Undo
+shortcut-action-redo = Redo
+settings-frame-cache-auto-label = Frame cache sizing
+settings-frame-cache-auto-enabled = Automatic
+settings-frame-cache-auto-disabled = Manual
+settings-frame-cache-auto-hint = Automatic sizes the frame cache and history from the video resolution and the available memory.
 settings-frame-cache-label = Keyframe cache size (for seeking)
 settings-frame-cache-hint = Caches video keyframes to speed up timeline scrubbing and jumping to specific times. Higher values store more keyframes for faster seeking. Changes apply when opening a new video.
 settings-frame-history-label = Frame history size (for stepping back)
 settings-frame-history-hint = Stores recently displayed frames to allow stepping backward frame-by-frame. Only used when manually stepping through frames, not during normal playback.
 settings-keyboard-seek-step-label = Keyboard seek step
 settings-keyboard-seek-step-hint = Time to skip when using arrow keys during video playback.
+settings-sequence-fps-label = Image sequence frame rate
+settings-sequence-fps-hint = Speed used when playing numbered images (frame_0001.png, frame_0002.png…) as video with S.
+settings-frame-name-template-label = Frame filename template
+settings-frame-name-template-hint = Default name for captured frames. Tokens: {"{name}"}, {"{date}"}, {"{index}"}, {"{timestamp}"}, {"{w}"}, {"{h}"}.
+settings-save-policy-label = When saving
+settings-save-policy-hint = Where Save writes edited images. Copies keep the original untouched; captured frames are offered the same folder.
+settings-save-policy-overwrite = Overwrite
+settings-save-policy-copy = Numbered copy
+settings-save-policy-subfolder = Subfolder
+settings-edited-folder-label = Subfolder name
+settings-edited-folder-hint = Created next to the original image when saving.
+settings-guides-thirds-label = Rule of thirds
+settings-guides-thirds-hint = Two lines across each side, at a third and two thirds.
+settings-guides-golden-ratio-label = Golden ratio
+settings-guides-golden-ratio-hint = Two lines across each side, at the golden sections (38.2% and 61.8%).
+settings-guides-safe-areas-label = Safe areas
+settings-guides-safe-areas-hint = Dashed action-safe (93%) and title-safe (90%) frames.
+settings-guides-pixel-grid-label = Pixel grid
+settings-guides-pixel-grid-hint = Outlines each pixel from 800% zoom.
+settings-guides-horizontal-label = Custom horizontal lines
+settings-guides-vertical-label = Custom vertical lines
+settings-guides-positions-hint = Positions in percent from the top or left edge, separated by commas.
+settings-guides-positions-invalid = Enter numbers from 0 to 100, such as 25, 50, 75.
+settings-sequence-fps-value = { $fps } fps
 megabytes = MB
 error-load-video-heading = We couldn't play this video.
 error-load-video-general = Something went wrong while loading the video.
@@ -189,9 +570,35 @@ error-video-seek-timeout = Seeking timed out. The target position may be beyond
 
 # Navigation bar
 menu-settings = Settings
+menu-open-in-new-window = Open in new window…
 menu-help = Help
 menu-about = About
+menu-verify-folder = Verify folder
+menu-share-over-lan = Share over LAN
+menu-stop-sharing = Stop sharing
+menu-strip-metadata = Remove all metadata
+menu-always-on-top = Always on top
+menu-borderless = Borderless window
+menu-compact-mode = Compact mode (Esc to leave)
+menu-open-with = Open with / Send to
+share-panel-title = Share over LAN
+share-panel-hint = { $count } files are shared. Scan the code or open this address on a device connected to the same network:
+share-panel-read-only = Visitors can view and download the files, not change them.
+share-panel-copy = Copy address
+share-panel-stop = Stop sharing
+share-panel-close = Close
+diagnostics-title = Diagnostics
+diagnostics-decode-fps = Decoded frames per second
+diagnostics-dropped-frames = Dropped frames
+diagnostics-frame-queue = Frame queue
+diagnostics-frame-cache = Video frame cache hits
+diagnostics-prefetch-cache = Preload cache hits
+diagnostics-memory = Memory used
+diagnostics-recent-loads = Last load times
+diagnostics-load-failed = failed after { $ms } ms
+diagnostics-unavailable = —
 navbar-edit-button = Edit
+navbar-menu-tooltip = Menu
 
 # Help screen
 help-title = Help
@@ -221,7 +628,7 @@ help-viewer-tool-fullscreen-desc = Immersive view with auto-hiding controls (del
 help-viewer-tool-delete = Delete
 help-viewer-tool-delete-desc = Permanently remove the current file (moves to system trash if available).
 help-viewer-tool-filter = Filter
-help-viewer-tool-filter-desc = Show only matching files. Filter by media type, orientation, or date range.
+help-viewer-tool-filter-desc = Show only matching files. Filter by media type, orientation, date range, or minimum star rating.
 
 help-viewer-key-navigate = Move to previous/next file
 help-viewer-key-edit = Open image in editor
@@ -230,6 +637,26 @@ help-viewer-key-exit-fullscreen = Exit fullscreen mode
 help-viewer-key-info = Toggle file information panel
 help-viewer-key-rotate-cw = Rotate clockwise
 help-viewer-key-rotate-ccw = Rotate counter-clockwise
+help-viewer-key-mirror = Flip video horizontally
+help-viewer-key-matte = Cycle matte color behind transparent areas
+help-viewer-key-alpha = Toggle straight/premultiplied alpha preview
+help-viewer-key-sequence = Play numbered image sequence as video
+help-viewer-key-export-view = Save the view as displayed to a PNG file
+help-viewer-key-copy-view = Copy the view as displayed to the clipboard
+help-viewer-key-quick-search = Search files in the current folder by name
+help-viewer-key-quick-search-cycle = Jump to next / previous search match
+help-viewer-key-capture-day = Jump to the next / previous capture day
+help-viewer-key-face-zoom = Zoom to the next / previous detected face
+help-viewer-key-face-overlay = Outline the detected faces
+help-viewer-key-similar = Find similar: jump to the next / previous look-alike image (burst shots)
+help-viewer-key-osd = Show / hide the on-screen display
+help-viewer-key-pixel-inspector = Pixel inspector: color under the cursor, click to copy it
+help-viewer-key-ruler = Ruler: drag over the image to measure a distance and an angle
+help-viewer-key-ruler-undo = Remove the last measurement (ruler on)
+help-viewer-key-guides = Show / hide the composition guides
+help-viewer-key-layout = Switch to workspace layout 1–3 (culling, presentation, custom)
+help-viewer-key-layout-save = Save the current panels into layout 1–3
+help-viewer-key-diagnostics = Show / hide performance diagnostics
 
 help-mouse-title = Mouse Interactions
 help-viewer-mouse-doubleclick = Double-click on image/video to toggle fullscreen
@@ -247,7 +674,7 @@ help-video-tool-playback-desc = Start or stop video playback with the play butto
 help-video-tool-timeline = Timeline
 help-video-tool-timeline-desc = Click anywhere on the progress bar to jump to that position.
 help-video-tool-volume = Volume
-help-video-tool-volume-desc = Drag the volume slider (0-150%) or click the speaker icon to mute/unmute.
+help-video-tool-volume-desc = Drag the volume slider (0-200%) or click the speaker icon to mute/unmute.
 help-video-tool-loop = Loop
 help-video-tool-loop-desc = Enable to automatically restart the video when it ends.
 help-video-tool-stepping = Frame stepping
@@ -258,11 +685,15 @@ help-video-tool-capture-desc = Save the current video frame as an image file (op
 help-video-key-playpause = Play or pause the video
 help-video-key-mute = Toggle audio mute
 help-video-key-seek = Seek backward/forward (during playback)
+help-video-key-fine-seek = Fine seek: one frame when paused, 0.1 s while playing (hold Shift while dragging the timeline to scrub slowly)
 help-video-key-volume = Increase/decrease volume
 help-video-key-step-back = Step backward one frame (when paused)
 help-video-key-step-forward = Step forward one frame (when paused)
 help-video-key-speed-down = Decrease playback speed
 help-video-key-speed-up = Increase playback speed
+help-video-key-chapter = Jump to the next / previous chapter (videos with chapters)
+help-video-key-loop-points = Set the A–B loop start / end at the current position
+help-video-key-loop-clear = Clear the A–B loop
 
 # ─────────────────────────────────────────────────────────────────────────────
 # Image Editor Section
@@ -293,9 +724,22 @@ help-editor-resize-ai-upscale = AI Upscaling: Use Real-ESRGAN for sharper enlarg
 
 help-editor-light-title = Light
 help-editor-light-desc = Fine-tune the brightness and contrast of your image.
+help-editor-light-auto = Auto enhance: analyzes the histogram to correct levels, contrast and white balance in one step; Strength blends the correction with the original
 help-editor-light-brightness = Brightness: lighten or darken the overall image
 help-editor-light-contrast = Contrast: increase or decrease the difference between light and dark areas
+help-editor-light-grayscale = Black & White: a channel mixer sets how much each color contributes to the gray; the Red, Green and Blue presets imitate the color filters used with black and white film
+help-editor-light-sharpen = Sharpen: an unsharp mask whose Radius sets the size of the edges enhanced, and Threshold leaves flat areas and noise alone
+help-editor-light-copy = Copy/paste adjustments: copies the rotations, flips, crops, resizes and light adjustments of the image, kept after a restart; pasting applies them to another image, scaling crops and resizes to its size, or to all images in the folder, saved as copies that never replace the originals, after a confirmation
 help-editor-light-preview = Changes are previewed in real-time before applying
+help-editor-filters-title = Filters
+help-editor-filters-desc = Give your image a look in one click: black & white, sepia, vignette, film tones and more.
+help-editor-filters-preview = Click a look to preview it, and click it again to remove it
+help-editor-filters-strength = Strength blends the look with the original image
+help-editor-filters-undo = An applied look is a single step you can undo
+help-editor-history-title = History
+help-editor-history-desc = Go back to any earlier state of your edits, beyond a single undo.
+help-editor-history-steps = Click a step to revert to it; the later steps stay listed and can be restored until a new edit is made
+help-editor-history-snapshots = Save a snapshot to name the current state ("after crop") and jump back to it later; snapshots last until the image is saved
 
 help-editor-save-title = Saving
 help-editor-save-overwrite = Save: overwrites the original file
@@ -305,10 +749,15 @@ help-editor-key-save = Save current changes
 help-editor-key-undo = Undo last change
 help-editor-key-redo = Redo undone change
 help-editor-key-cancel = Cancel all changes and exit
+help-editor-key-show-original = Show the original image while held
 
 help-editor-mouse-title = Mouse Controls
 help-editor-mouse-wheel = Zoom in or out of the image
 help-editor-mouse-drag = Pan the image when zoomed in
+help-editor-mouse-space-drag = Pan over the crop handles and the refine brush
+help-editor-mouse-space-drag-label = Space + drag
+help-editor-mouse-fit = The Fit button switches the canvas between fit to window and 100%
+help-editor-compare = Hold Show original to see the untouched image, or use Before/After to split the canvas: the original on the left of the divider, your edits on the right
 
 # ─────────────────────────────────────────────────────────────────────────────
 # Frame Capture Section
@@ -378,16 +827,73 @@ about-third-party-details = See THIRD_PARTY_LICENSES.md for details
 about-section-links = Links
 about-link-repository = Source Code
 about-link-issues = Report Issues
+about-section-troubleshooting = Troubleshooting
+about-troubleshooting-hint = When reporting a problem, attach the diagnostic report: it lists the system, graphics card and FFmpeg versions along with the latest log lines.
+about-view-logs-button = View logs
+about-copy-report-button = Copy diagnostic report
+about-missing-translations-button = Missing translations
+logs-title = Logs
+logs-folder-unavailable = The log folder could not be determined.
+logs-refresh = Refresh
+logs-open-folder = Open log folder
+logs-copy-report = Copy diagnostic report
+logs-empty = Nothing has been logged yet.
+translations-title = Missing translations
+translations-summary = { $count } keys of { $locale } are shown in English. Translations are loaded from { $dir }.
+translations-copy-keys = Copy keys
+translations-complete = Every key is translated.
+welcome-title = Welcome to IcedLens
+welcome-preferences-intro = Choose your language and theme. Everything can be changed later in Settings.
+welcome-default-viewer-title = Default image viewer
+welcome-default-viewer-intro = Open images in IcedLens when you double-click them in your file manager.
+welcome-default-viewer-button = Set as default image viewer
+welcome-default-viewer-working = Registering…
+welcome-default-viewer-done = IcedLens now opens your images.
+welcome-default-viewer-confirm = Choose IcedLens for images in the Default apps settings that just opened.
+welcome-default-viewer-failed = Could not register IcedLens: { $error }
+welcome-tour-progress = Tip { $current } of { $total }
+welcome-tour-try = Try it: press { $key }
+welcome-tour-tried = Well done!
+welcome-tour-footer = All shortcuts are listed in Help and can be changed in Settings.
+welcome-tour-navigate-title = Browse the folder
+welcome-tour-navigate-body = Show the next or previous file of the folder. The mouse wheel zooms and dragging pans.
+welcome-tour-fullscreen-title = Go fullscreen
+welcome-tour-fullscreen-body = Controls hide after a moment; move the mouse to bring them back. Esc leaves fullscreen.
+welcome-tour-info-title = See the details
+welcome-tour-info-body = The info panel shows camera, exposure and video metadata, and edits titles, keywords and dates.
+welcome-tour-editor-title = Edit the image
+welcome-tour-editor-body = Rotate, crop, resize and adjust the light. Nothing is written until you save.
+welcome-tour-video-title = Play videos
+welcome-tour-video-body = Play and pause videos. While paused, comma and period step frame by frame.
+welcome-skip = Skip
+welcome-back = Back
+welcome-next = Next
+welcome-finish = Start using IcedLens
 
 # Notifications
 notification-save-success = Image saved successfully
+notification-save-copy-success = Image saved as { $file }
 notification-save-error = Failed to save image
 notification-frame-capture-success = Frame captured successfully
 notification-frame-capture-error = Failed to capture frame
 notification-delete-success = File deleted successfully
 notification-delete-error = Failed to delete file
 notification-config-save-error = Failed to save settings
+notification-theme-save-error = Failed to save the theme file
+notification-config-merged = Settings changed in another window were kept
 notification-config-load-error = Failed to load settings, using defaults
+notification-config-reloaded = Settings reloaded from settings.toml
+notification-translations-reloaded = Translations reloaded, { $count } keys missing
+notification-config-reload-error = settings.toml is invalid, changes not applied: { $error }
+notification-settings-imported = Settings imported
+notification-settings-import-error = Failed to import settings: { $error }
+notification-settings-exported = Settings exported
+notification-settings-export-error = Failed to export settings: { $error }
+notification-update-available = IcedLens { $version } is available.
+    { $notes }
+notification-update-download = Open the download page
+notification-update-none = IcedLens { $version } is up to date.
+notification-update-check-error = Could not check for updates: { $error }
 notification-state-parse-error = Failed to read app state, using defaults
 notification-state-read-error = Failed to open app state file
 notification-state-path-error = Cannot determine app data path
@@ -400,6 +906,18 @@ notification-editor-create-error = Failed to open image editor
 notification-editor-load-error = Failed to load image for editing
 notification-video-editing-unsupported = Video editing is not supported yet
 
+# Units
+unit-bytes = { $value } B
+unit-kilobytes = { $value } KB
+unit-megabytes = { $value } MB
+unit-gigabytes = { $value } GB
+unit-bits-per-second = { $value } bps
+unit-kilobits-per-second = { $value } kbps
+unit-megabits-per-second = { $value } Mbps
+unit-frames-per-second = { $value } fps
+unit-nits = { $value } nits
+unit-nits-range = { $min }–{ $max } nits
+
 # Metadata panel
 metadata-panel-title = File Information
 metadata-panel-close = Close panel
@@ -413,6 +931,7 @@ metadata-section-gps = Location
 metadata-label-dimensions = Dimensions
 metadata-label-file-size = File size
 metadata-label-format = Format
+metadata-label-decoder = Decoder
 metadata-label-date-taken = Date taken
 metadata-label-camera = Camera
 metadata-label-exposure = Exposure
@@ -420,11 +939,49 @@ metadata-label-aperture = Aperture
 metadata-label-iso = ISO
 metadata-label-focal-length = Focal length
 metadata-label-gps = Coordinates
+metadata-label-gps-dms = DMS
+metadata-open-map-button = Open in OpenStreetMap
+metadata-all-tags-title = All tags ({ $count })
+metadata-all-tags-filter-placeholder = Filter tags…
+metadata-all-tags-no-match = No tag matches the filter
+metadata-all-tags-copy-tooltip = Click to copy the value
+metadata-batch-title = Batch edit
+metadata-stats-title = Folder statistics
+metadata-stats-refresh = Recompute statistics
+metadata-export-title = Export metadata
+metadata-export-hint = Save as JSON or CSV (pick the extension) for catalogs and scripts
+metadata-export-file = This file
+metadata-export-folder = Whole folder
+metadata-stats-loading = Reading camera data…
+metadata-stats-summary = { $count } files, { $exif } with camera data
+metadata-stats-empty = No camera data found in this folder
+metadata-stats-cameras = Cameras
+metadata-stats-lenses = Lenses
+metadata-stats-focal-lengths = Focal length (35mm equiv.)
+metadata-stats-iso = ISO
+metadata-batch-hint = Applies to the { $count } files of the folder (or of the active filter). Empty fields are left unchanged; videos are skipped.
+metadata-batch-creator-label = Set creator
+metadata-batch-copyright-label = Set copyright
+metadata-batch-keywords-label = Add keywords (comma-separated)
+metadata-batch-strip-gps = Remove GPS location
+metadata-batch-apply-button = Apply to { $count } files
+metadata-batch-confirm-warning = This will modify { $count } original files. Continue?
+metadata-batch-confirm-button = Apply
+metadata-batch-running = Writing metadata…
 metadata-label-codec = Codec
 metadata-label-bitrate = Bit rate
 metadata-label-duration = Duration
 metadata-label-fps = Frame rate
 metadata-value-unknown = Unknown
+metadata-value-decoder-fallback = { $decoder } (fallback)
+metadata-label-bit-depth = Bit depth
+metadata-value-bit-depth = { $bits }-bit
+metadata-label-hdr = HDR
+metadata-value-hdr-pq = HDR10 (PQ)
+metadata-value-hdr-hlg = HLG
+metadata-label-mastering-display = Mastering display
+metadata-label-max-cll = Max content light (MaxCLL)
+metadata-label-max-fall = Max frame average (MaxFALL)
 
 # Metadata editing
 metadata-edit-button = Edit
@@ -433,7 +990,28 @@ metadata-cancel-button = Cancel
 metadata-save-button = Save
 metadata-save-as-button = Save As...
 metadata-save-warning = Save will modify the original file
+metadata-gps-strip-pending = The location will be removed from the file when you save.
+metadata-strip-button = Remove all metadata
+metadata-strip-dialog-title = Remove all metadata
+metadata-strip-dialog-description = The following metadata will be removed. Image data is not modified.
+metadata-strip-dialog-overwrite = Overwrite file
+metadata-strip-dialog-copy = Save clean copy
+metadata-strip-dialog-cancel = Cancel
+recipe-batch-dialog-title = Paste to all images in folder
+recipe-batch-dialog-description = The copied adjustments will be applied to { $count } images.
+recipe-batch-dialog-copy = Each result is saved as a numbered copy next to its original. Originals are not modified.
+recipe-batch-dialog-subfolder = Results are saved in the “{ $folder }” subfolder, replacing earlier edited versions there. Originals are not modified.
+recipe-batch-dialog-skipped = Animated images and formats that can't be written are skipped.
+recipe-batch-dialog-paste = Paste
+recipe-batch-dialog-cancel = Cancel
+metadata-strip-block-exif = EXIF (camera, date, GPS location)
+metadata-strip-block-xmp = XMP (title, author, keywords)
+metadata-strip-block-iptc = IPTC (caption, copyright)
+metadata-strip-block-thumbnail = Embedded thumbnail
+metadata-strip-block-comment = Comments and text annotations
 metadata-label-make = Make
+metadata-remove-field-tooltip = Remove field
+metadata-remove-keyword-tooltip = Remove keyword
 metadata-label-model = Model
 metadata-label-focal-length-35mm = Focal length (35mm)
 metadata-label-flash = Flash
@@ -455,7 +1033,47 @@ metadata-validation-invalid-number = Invalid number
 # Metadata notifications
 notification-metadata-save-success = Metadata saved successfully
 notification-metadata-save-error = Failed to save metadata
+notification-metadata-batch-started = Updating metadata of { $count } files…
+notification-metadata-batch-success = Metadata updated in { $updated } files ({ $skipped } skipped)
+notification-metadata-batch-partial = Metadata updated in { $updated } files, { $failed } failed (first: { $filename })
+notification-metadata-exported = Metadata of { $count } files exported
+notification-metadata-export-error = Could not export metadata: { $error }
+notification-plugins-reloaded = { $count } plugins loaded
+notification-plugins-folder-error = Could not open the plugins folder: { $error }
+notification-logs-folder-error = Could not open the log folder: { $error }
+notification-diagnostic-report-copied = Diagnostic report copied to the clipboard
+notification-plugin-tool-error = Plugin tool failed: { $error }
+notification-plugin-export-success = Exported { $filename }
+notification-plugin-export-error = Plugin export failed: { $error }
+notification-open-with-done = { $name }: done
+notification-open-with-output = { $name }: { $output }
+notification-open-with-error = { $name } failed: { $error }
+notification-verify-started = Verifying { $count } files…
+notification-verify-clean = All { $count } files are intact
+notification-verify-damaged = { $damaged } of { $count } files are damaged (list copied to clipboard)
+notification-share-empty = There is no file to share in this folder
+notification-share-error = Could not share on port { $port }: { $error }
+notification-share-stopped = Sharing stopped
+notification-share-url-copied = Address copied to clipboard
+notification-strip-success = All metadata removed
+notification-layout-applied = Layout: { $name }
+notification-layout-saved = Saved layout "{ $name }"
+layout-name-culling = Culling
+layout-name-presentation = Presentation
+layout-name-custom = Custom
+notification-strip-copy-success = Clean copy saved as { $filename }
+notification-strip-error = Could not remove metadata: { $error }
+notification-strip-nothing = This file contains no removable metadata
+notification-strip-unsupported = Removing metadata is not supported for this file format
 notification-metadata-validation-error = Please fix validation errors before saving
+notification-open-url-error = Could not open the link: { $error }
+notification-copied-to-clipboard = Copied to clipboard
+notification-color-copied = Color { $color } copied to clipboard
+notification-adjustments-copied = Adjustments copied (steps: { $count })
+notification-dismiss-tooltip = Dismiss
+notification-recipe-batch-started = Pasting adjustments to { $count } images…
+notification-recipe-batch-success = Adjustments pasted to { $updated } images ({ $skipped } skipped)
+notification-recipe-batch-partial = Adjustments pasted to { $updated } images, { $failed } failed (first: { $filename })
 notification-metadata-xmp-unsupported = Cannot save title, author, and description to this file format
 
 # Metadata progressive disclosure
@@ -468,6 +1086,7 @@ metadata-label-dc-title = Title
 metadata-label-dc-creator = Creator
 metadata-label-dc-description = Description
 metadata-label-dc-subject = Keywords
+metadata-keyword-input-placeholder = Add keyword and press Enter
 metadata-label-dc-rights = Copyright
 
 navbar-info-button = Info
@@ -478,11 +1097,37 @@ empty-state-subtitle = Drop files here or click to open
 empty-state-button = Open File
 empty-state-drop-hint = Drag and drop images or videos anywhere
 
+# File dialog filters
+dialog-filter-all-media = All media
+dialog-filter-images = Images
+dialog-filter-videos = Videos
+dialog-filter-image-format = { $format } image
+dialog-filter-video-format = { $format } video
+
 # Additional notifications
 notification-empty-dir = No supported media files found in this folder
 notification-load-error-io = Could not open file. Check that it exists and you have permission.
 notification-load-error-svg = Could not render SVG. The file may be malformed.
 notification-load-error-video = Could not play video. The format may be unsupported.
+notification-image-sequence-not-found = This image is not part of a numbered sequence.
+notification-capture-day = { $date } — { $count } files
+notification-similar-indexing = Comparing the images of this folder…
+notification-similar-found = { $count } similar images
+notification-similar-none = No similar image in this folder
+notification-face-model-downloading = Downloading the face detection model…
+notification-no-faces-found = No faces found in this image.
+notification-clip-exported = Clip saved to { $path }
+notification-clip-exported-reencoded = Clip re-encoded and saved to { $path } (without audio)
+notification-clip-export-failed = Could not export the clip: { $message }
+notification-frames-exported = { $count } frames saved to { $path }
+notification-mini-player-error = Could not open the mini player
+notification-visible-area-exported = Visible area saved to { $path }
+notification-visible-area-export-error = Failed to export the visible area
+notification-view-exported = View saved to { $path }
+notification-view-export-error = Failed to export the view
+notification-view-copied = View copied to the clipboard
+notification-frames-export-failed = Could not export the frames: { $message }
+notification-face-detection-error = Face detection failed: { $error }
 notification-load-error-timeout = Loading timed out. The file may be too large or the system is busy.
 notification-skipped-corrupted-files = Skipped: { $files }
 notification-skipped-and-others = +{ $count } more
@@ -516,6 +1161,19 @@ settings-upscale-status-error = Error: { $message }
 settings-upscale-status-not-downloaded = Model not downloaded
 settings-upscale-enabled = Enabled
 settings-upscale-disabled = Disabled
+settings-enable-background-removal-label = AI Background Removal
+settings-enable-background-removal-hint = Enable AI-powered background removal using the U²-Net segmentation model (~176 MB download).
+settings-background-removal-model-url-label = Model URL
+settings-background-removal-model-url-placeholder = https://github.com/...
+settings-background-removal-model-url-hint = URL to download the U²-Net ONNX model from.
+settings-background-removal-status-label = Model Status
+settings-background-removal-status-downloading = Downloading model ({ $progress }%)...
+settings-background-removal-status-validating = Validating model...
+settings-background-removal-status-ready = Model ready
+settings-background-removal-status-error = Error: { $message }
+settings-background-removal-status-not-downloaded = Model not downloaded
+settings-background-removal-enabled = Enabled
+settings-background-removal-disabled = Disabled
 
 # AI Editor tool
 image-editor-tool-deblur = AI Deblur
@@ -529,12 +1187,49 @@ image-editor-deblur-validating = Validating model, please wait...
 image-editor-deblur-downloading = Downloading model ({ $progress }%)...
 image-editor-deblur-error = Error: { $error }
 image-editor-deblur-already-applied = Deblur already applied. Use Undo to revert if needed.
+image-editor-tool-background = AI Background Removal
+image-editor-background-remove = Remove Background
+image-editor-background-apply = Apply Cut-out
+image-editor-background-processing = Removing background...
+image-editor-background-model-not-ready = Enable AI background removal in Settings first
+image-editor-background-validating = Validating model, please wait...
+image-editor-background-downloading = Downloading model ({ $progress }%)...
+image-editor-background-error = Error: { $error }
+image-editor-background-refine-hint = Paint over the image to refine the edges, then apply.
+image-editor-background-brush-keep = Keep
+image-editor-background-brush-erase = Erase
+image-editor-background-brush-size = Brush size
+image-editor-background-transparent-export-hint = Save as PNG or WebP to keep the transparency; JPEG fills it with white.
+image-editor-tool-denoise = Denoise
+image-editor-denoise-method-label = Method
+image-editor-denoise-method-bilateral = Bilateral
+image-editor-denoise-method-nlmeans = NL-means
+image-editor-denoise-method-model = AI model
+image-editor-denoise-strength-label = Strength
+image-editor-denoise-detail-label = Detail preservation
+image-editor-denoise-preview-hint = The preview is computed on a reduced copy; Apply processes the full image.
+image-editor-denoise-model-hint = The model has no preview; its result shows once applied.
+image-editor-denoise-apply = Apply Denoise
+image-editor-denoise-processing = Reducing noise...
+image-editor-plugins-section-title = Plugins
+image-editor-plugins-running = Running plugin…
+image-editor-plugins-export = Export as { $format }
 
 # AI Help section
 help-editor-deblur-title = AI Deblur
 help-editor-deblur-desc = Use AI to sharpen blurry images using the NAFNet neural network.
 help-editor-deblur-enable = Enable in Settings → AI / Machine Learning (downloads ~92 MB model)
 help-editor-deblur-lossless = For best quality, export as WebP lossless or PNG
+help-editor-background-title = AI Background Removal
+help-editor-background-desc = Cut the subject out of its background with the U²-Net segmentation model, running on your computer.
+help-editor-background-enable = Enable in Settings → AI / Machine Learning (downloads ~176 MB model)
+help-editor-background-refine = Refine the edges with the Keep and Erase brushes before applying
+help-editor-background-export = Save as PNG or WebP to keep the transparent background
+help-editor-denoise-title = Denoise
+help-editor-denoise-desc = Reduce the grain of photos taken in low light.
+help-editor-denoise-methods = Bilateral is quick; NL-means is slower but keeps more texture
+help-editor-denoise-sliders = Raise Strength to remove more noise, and Detail preservation to keep fine edges
+help-editor-denoise-model = Set denoise_model_url in the [ai] section of settings.toml to add an AI model
 
 # AI Notifications
 notification-deblur-success = Image deblurred successfully
@@ -552,3 +1247,9 @@ notification-upscale-download-error = Failed to download upscale model: { $error
 notification-upscale-validation-error = Model validation failed: { $error }
 notification-upscale-resize-success = Image resized with AI upscaling
 notification-upscale-resize-error = AI upscaling failed: { $error }
+notification-background-ready = AI Background Removal is ready to use
+notification-background-download-error = Failed to download background removal model: { $error }
+notification-background-validation-error = Model validation failed: { $error }
+notification-background-removal-error = Background removal failed: { $error }
+notification-denoise-model-downloading = Downloading the denoising model…
+notification-denoise-error = Noise reduction failed: { $error }
@@ -8,8 +8,15 @@ settings-title = Configuración
 settings-section-general = General
 settings-section-display = Visualización
 settings-section-video = Vídeo
+settings-section-editor = Editor
 settings-section-fullscreen = Pantalla completa
+settings-section-guides = Guías
 settings-section-ai = IA / Aprendizaje automático
+settings-section-advanced = Avanzado
+settings-section-mouse = Ratón
+settings-section-shortcuts = Atajos de teclado
+settings-section-plugins = Complementos
+settings-section-accessibility = Accesibilidad
 select-language-label = Seleccionar idioma:
 language-name-en-US = Inglés
 language-name-fr = Francés
@@ -24,6 +31,7 @@ viewer-zoom-label = Zoom
 viewer-zoom-input-placeholder = 100
 viewer-zoom-reset-button = Restablecer
 viewer-fit-to-window-toggle = Ajustar a ventana
+viewer-lock-view-tooltip = Mantener el zoom y la posición al cambiar de imagen
 viewer-zoom-input-error-invalid = Por favor, ingrese un número válido.
 viewer-zoom-step-error-invalid = El paso de zoom debe ser un número.
 viewer-zoom-step-error-range = El paso de zoom debe estar entre 1% y 200%.
@@ -32,7 +40,21 @@ viewer-zoom-in-tooltip = Acercar
 viewer-zoom-out-tooltip = Alejar
 viewer-fullscreen-tooltip = Alternar pantalla completa
 viewer-rotate-cw-tooltip = Rotar en sentido horario
+viewer-export-visible-tooltip = Exportar el área visible
+viewer-inspector-tooltip = Inspector de píxeles
+viewer-inspector-copy-hint = Clic para copiar
+viewer-ruler-tooltip = Regla
+viewer-guides-tooltip = Guías de composición
 viewer-rotate-ccw-tooltip = Rotar en sentido antihorario
+viewer-mirror-tooltip = Voltear el vídeo horizontalmente
+viewer-matte-background = Tema de fondo
+viewer-matte-black = Fondo negro
+viewer-matte-white = Fondo blanco
+viewer-matte-gray = Fondo gris
+viewer-matte-magenta = Fondo magenta
+viewer-matte-green = Fondo verde
+viewer-alpha-straight = Alfa directo
+viewer-alpha-premultiplied = Alfa premultiplicado
 viewer-fullscreen-disabled-unsaved = Guarde o cancele los cambios primero
 viewer-double-click = Doble clic
 viewer-scroll-wheel = Rueda del ratón
@@ -48,6 +70,9 @@ filter-media-type-all = Todos
 filter-media-type-images = Solo imágenes
 filter-media-type-videos = Solo vídeos
 filter-media-type-placeholder = Seleccionar tipo...
+filter-rating-label = Valoración mínima
+filter-rating-any = Cualquier valoración
+filter-rating-min = { $stars } o más
 filter-date-label = Filtro por fecha
 filter-date-field-label = Filtrar por
 filter-date-field-modified = Fecha de modificación
@@ -61,6 +86,9 @@ filter-date-clear = Borrar fecha
 filter-tooltip-date-from = desde { $date }
 filter-tooltip-date-to = hasta { $date }
 filter-tooltip-date-range = { $start } – { $end }
+quick-search-placeholder = Buscar por nombre de archivo...
+quick-search-no-results = Sin resultados
+quick-search-hint = ↑/↓ seleccionar · Intro abrir · Esc cerrar
 
 settings-zoom-step-label = Paso de zoom
 settings-zoom-step-placeholder = 10
@@ -69,10 +97,38 @@ settings-background-label = Fondo del visor
 settings-background-light = Claro
 settings-background-dark = Oscuro
 settings-background-checkerboard = Tablero de ajedrez
+settings-background-auto = Automático
+settings-background-auto-hint = Oscuro detrás de imágenes claras, claro detrás de imágenes oscuras
 settings-theme-mode-label = Tema de la aplicación
 settings-theme-system = Seguir el sistema
 settings-theme-light = Claro
 settings-theme-dark = Oscuro
+settings-ui-scale-label = Escala de la interfaz
+settings-ui-scale-hint = Agranda o reduce el texto, los espacios y los controles, además de la escala del sistema. Se aplica al soltar el deslizador.
+settings-accessibility-disabled = Desactivado
+settings-accessibility-enabled = Activado
+settings-reduced-motion-label = Reducir el movimiento
+settings-reduced-motion-hint = Los indicadores de carga permanecen quietos en lugar de girar.
+settings-high-contrast-focus-label = Foco de alto contraste
+settings-high-contrast-focus-hint = Dibuja un contorno grueso del color del texto alrededor del campo en el que se escribe.
+settings-large-targets-label = Botones más grandes
+settings-large-targets-hint = Hace que los botones de la barra de herramientas, del menú y de los controles de vídeo sean más fáciles de pulsar.
+settings-always-show-fullscreen-controls-label = Mostrar siempre los controles en pantalla completa
+settings-always-show-fullscreen-controls-hint = Mantiene visibles la barra de herramientas, los controles de vídeo y las flechas de navegación en pantalla completa en lugar de ocultarlos tras el tiempo de la superposición.
+settings-accent-label = Color de acento
+settings-accent-hint = Se usa en los botones seleccionados, deslizadores, resaltados y notificaciones. Introduce un color #rrggbb para otro tono.
+settings-accent-custom-hint = La paleta personalizada de abajo define el acento mientras está activada.
+settings-accent-default = Predeterminado
+settings-custom-theme-label = Paleta personalizada
+settings-custom-theme-hint = Sustituye el tema claro u oscuro. Los cambios se previsualizan al instante y se guardan en theme.toml junto a settings.toml.
+settings-custom-theme-disabled = Desactivada
+settings-custom-theme-enabled = Activada
+settings-palette-background = Fondo
+settings-palette-text = Texto
+settings-palette-accent = Acento
+settings-palette-success = Éxito
+settings-palette-danger = Peligro
+settings-palette-invalid = Usa el formato #rrggbb
 help-usage-heading = USO:
 help-options-heading = OPCIONES:
 help-args-heading = ARGUMENTOS:
@@ -83,25 +139,85 @@ help-arg-image-path = <RUTA>      Ruta a un archivo multimedia o directorio para
 help-example-1 = iced_lens ./foto.png
 help-example-2 = iced_lens ./mis_fotos/
 help-example-3 = iced_lens --lang fr ./imagen.jpg
+help-example-4 = iced_lens verify /media/sdcard/DCIM
+help-example-5 = iced_lens convert foto.png foto.jpg --quality 85
+help-example-6 = iced_lens info clip.mp4 --json
+help-commands-heading = COMANDOS:
+help-line-command-verify = verify [DIR]  Decodificar por completo cada archivo multimedia de DIR y listar los dañados
+help-line-command-convert = convert <ENTRADA> <SALIDA> [--quality <1-100>]  Convertir un archivo multimedia al formato de imagen de SALIDA
+help-line-command-thumb = thumb [RUTA] [--size <px>] [--out <dir>]  Crear miniaturas JPEG de un archivo o de cada archivo multimedia de un directorio
+help-line-command-info = info <RUTA> [--json|--csv]  Mostrar los metadatos de un archivo multimedia o de todos los archivos multimedia de una carpeta
+cli-verify-summary = { $checked } archivos comprobados, { $damaged } dañados
+cli-thumb-summary = { $written } miniaturas creadas, { $failed } fallidas
+cli-info-unsupported = no hay metadatos legibles (archivo no compatible o dañado)
 help-description = { -app-name } – Visor de imágenes
 help-line-option-i18n-dir =     --i18n-dir <ruta>  Cargar traducciones desde directorio
+help-line-option-watch-i18n =     --watch-i18n   Recargar las traducciones cuando cambian sus archivos .ftl
 help-line-option-data-dir =     --data-dir <ruta>  Anular directorio de datos (archivos de estado)
 help-line-option-config-dir =     --config-dir <ruta>  Anular directorio de configuración (settings.toml)
 settings-sort-order-label = Orden de navegación de imágenes
 settings-sort-alphabetical = Alfabético
 settings-sort-modified = Fecha de modificación
 settings-sort-created = Fecha de creación
+settings-sort-size = Tamaño del archivo
+settings-sort-type = Tipo de archivo
+settings-sort-ascending = Ascendente
+settings-sort-descending = Descendente
+settings-sort-order-hint = El orden alfabético compara los números por su valor (img2 antes que img10).
 settings-max-skip-attempts-label = Omitir archivos corruptos
 settings-max-skip-attempts-hint = Máximo de archivos corruptos consecutivos a omitir durante la navegación.
+settings-prefetch-cache-size-label = Archivos precargados
+settings-prefetch-cache-size-hint = Archivos decodificados que se mantienen en memoria para mostrar al instante el siguiente y el anterior. 0 desactiva la precarga.
+settings-similarity-threshold-label = Umbral de imágenes similares
+settings-similarity-threshold-hint = Parecido mínimo para que Buscar similar salte a una imagen. Redúzcalo para incluir también tomas reencuadradas.
 settings-persist-filters-label = Recordar filtros
 settings-persist-filters-hint = Mantener la configuración de filtros entre sesiones.
 settings-persist-filters-disabled = No
 settings-persist-filters-enabled = Sí
+settings-remember-view-label = Recordar la vista de cada imagen
+settings-remember-view-hint = Restaurar el zoom, la posición de desplazamiento y la rotación de una imagen al volver a ella durante la sesión.
+settings-remember-view-disabled = No
+settings-remember-view-enabled = Sí
+settings-minimap-label = Minimapa
+settings-minimap-hint = Mostrar la imagen completa en una esquina al ampliarla, con la parte visible enmarcada. Hacer clic o arrastrar en ella para desplazarse.
+settings-minimap-disabled = No
+settings-minimap-enabled = Sí
+settings-sandboxed-decoding-label = Decodificación aislada
+settings-sandboxed-decoding-hint = Decodificar las imágenes en un proceso separado, para que un archivo dañado o malicioso no pueda bloquear ni comprometer el visor. La carga es algo más lenta.
+settings-sandboxed-decoding-disabled = No
+settings-sandboxed-decoding-enabled = Sí
+settings-webp-decoder-label = Decodificador WebP
+settings-webp-decoder-hint = Biblioteca usada para las imágenes WebP fijas. Si no puede leer un archivo, se prueba la otra automáticamente.
+settings-webp-decoder-image-rs = image-rs
+settings-webp-decoder-libwebp = libwebp
 settings-overlay-timeout-label = Retraso de ocultación automática en pantalla completa
 settings-overlay-timeout-hint = Tiempo antes de que los controles desaparezcan en modo de pantalla completa.
+settings-overlay-shown = Visible
+settings-overlay-hidden = Oculto
+settings-overlay-filename-label = Nombre del archivo
+settings-overlay-index-label = Posición en la carpeta (12/340)
+settings-overlay-clock-label = Reloj
+settings-overlay-arrows-label = Flechas de navegación
+settings-overlay-histogram-label = Histograma
+settings-overlay-position-label = Posición de la superposición
+settings-overlay-position-hint = Dónde aparecen el nombre del archivo, la posición, el reloj y el histograma.
+settings-overlay-position-top-left = Arriba a la izquierda
+settings-overlay-position-top-center = Arriba
+settings-overlay-position-top-right = Arriba a la derecha
+settings-overlay-position-bottom-left = Abajo a la izquierda
+settings-overlay-position-bottom-center = Abajo
+settings-overlay-position-bottom-right = Abajo a la derecha
+settings-overlay-opacity-label = Opacidad de la superposición
 seconds = segundos
 image-editor-title = Editor de imágenes
 image-editor-back-to-viewer = Volver al visor
+image-editor-recovery-prompt = Se recuperaron cambios sin guardar de esta imagen de una sesión anterior.
+image-editor-recovery-restore = Restaurar cambios
+image-editor-recovery-discard = Descartar
+image-editor-guides = Guías
+image-editor-zoom-fit = Ajustar
+image-editor-show-original = Ver original
+image-editor-compare-split = Antes/Después
 image-editor-cancel = Cancelar
 image-editor-save = Guardar
 image-editor-save-as = Guardar como...
@@ -114,6 +230,10 @@ image-editor-rotate-left-tooltip = Rotar imagen en sentido antihorario
 image-editor-flip-section-title = Voltear
 image-editor-flip-horizontal-tooltip = Voltear imagen horizontalmente (espejo izquierda-derecha)
 image-editor-flip-vertical-tooltip = Voltear imagen verticalmente (espejo arriba-abajo)
+image-editor-sidebar-collapse-tooltip = Ocultar las herramientas
+image-editor-sidebar-expand-tooltip = Mostrar las herramientas
+image-editor-navigate-previous-tooltip = Imagen anterior
+image-editor-navigate-next-tooltip = Imagen siguiente
 image-editor-resize-section-title = Redimensionar
 image-editor-resize-scale-label = Escala
 image-editor-resize-dimensions-label = Tamaño objetivo
@@ -130,10 +250,42 @@ image-editor-resize-ai-model-validating = Validando modelo IA
 image-editor-resize-ai-model-error = Error del modelo IA
 image-editor-resize-ai-enlargement-only = El upscaling IA solo se aplica a ampliaciones
 image-editor-light-section-title = Ajustes de luz
+image-editor-auto-enhance = Mejora automática
+image-editor-auto-enhance-strength-label = Intensidad
+image-editor-auto-enhance-neutral = Esta imagen ya está bien equilibrada.
 image-editor-light-brightness-label = Brillo
 image-editor-light-contrast-label = Contraste
+image-editor-grayscale-section-title = Blanco y negro
+image-editor-grayscale-enable = Convertir a blanco y negro
+image-editor-grayscale-preset-neutral = Neutro
+image-editor-grayscale-preset-red = Rojo
+image-editor-grayscale-preset-green = Verde
+image-editor-grayscale-preset-blue = Azul
+image-editor-grayscale-red-label = Canal rojo
+image-editor-grayscale-green-label = Canal verde
+image-editor-grayscale-blue-label = Canal azul
+image-editor-grayscale-total = Total: { $total } % (100 % mantiene el brillo general)
+image-editor-sharpen-section-title = Enfoque
+image-editor-sharpen-amount-label = Cantidad
+image-editor-sharpen-radius-label = Radio
+image-editor-sharpen-threshold-label = Umbral
 image-editor-light-reset = Restablecer
 image-editor-light-apply = Aplicar
+image-editor-light-copy = Copiar ajustes
+image-editor-light-paste = Pegar ajustes
+image-editor-light-paste-folder = Pegar en todas las imágenes de la carpeta
+image-editor-tool-filters = Filtros
+image-editor-filter-black-and-white = Blanco y negro
+image-editor-filter-noir = Cine negro
+image-editor-filter-sepia = Sepia
+image-editor-filter-vignette = Viñeta
+image-editor-filter-warm-film = Película cálida
+image-editor-filter-cool-film = Película fría
+image-editor-filter-faded = Desvaído
+image-editor-filter-vivid = Vívido
+image-editor-filter-strength-label = Intensidad
+image-editor-filter-hint = Elige un estilo para previsualizarlo en la imagen.
+image-editor-filter-apply = Aplicar filtro
 image-editor-crop-section-title = Recortar
 image-editor-crop-ratio-label = Relación de aspecto
 image-editor-crop-ratio-free = Libre
@@ -143,38 +295,267 @@ image-editor-crop-ratio-portrait = Vertical (9:16)
 image-editor-crop-ratio-photo = Foto (4:3)
 image-editor-crop-ratio-photo-portrait = Foto vertical (3:4)
 image-editor-crop-apply = Aplicar recorte
+image-editor-crop-face = Recortar a la cara
+image-editor-crop-face-detecting = Detectando caras…
+image-editor-crop-face-none = No se encontró ninguna cara en esta imagen.
 image-editor-undo-redo-section-title = Última modificación
 image-editor-undo = Deshacer
 image-editor-redo = Rehacer
+image-editor-history-title = Historial
+image-editor-history-steps-title = Pasos
+image-editor-history-original = Imagen original
+image-editor-snapshots-title = Instantáneas
+image-editor-snapshot-name-placeholder = Nombre de la instantánea
+image-editor-snapshot-save = Guardar
+image-editor-snapshot-delete-tooltip = Eliminar la instantánea
+image-editor-snapshot-unnamed = Después del paso { $step }
+image-editor-history-rotate-left = Girar a la izquierda
+image-editor-history-rotate-right = Girar a la derecha
+image-editor-history-flip-horizontal = Voltear horizontalmente
+image-editor-history-flip-vertical = Voltear verticalmente
+image-editor-history-crop = Recorte
+image-editor-history-resize = Cambio de tamaño
+image-editor-history-upscale = Ampliación con IA
+image-editor-history-brightness = Brillo
+image-editor-history-contrast = Contraste
+image-editor-history-auto-enhance = Mejora automática
+image-editor-history-black-white = Blanco y negro
+image-editor-history-sharpen = Enfoque
+image-editor-history-look = Filtro
+image-editor-history-deblur = Desenfoque con IA
+image-editor-history-background = Eliminación del fondo
+image-editor-history-denoise = Reducción de ruido
+image-editor-history-plugin = Herramienta de complemento
 image-editor-export-format-label = Formato de exportación
+image-editor-proof-label = Prueba de color
+image-editor-proof-none = Ninguno
+image-editor-proof-convert = Convertir los colores al perfil
+image-editor-proof-convert-tooltip = Desactivado: el perfil solo se adjunta y se conservan los valores de los píxeles. Activado: los colores se convierten para verse igual en el espacio de destino.
 media-loading = Cargando...
 settings-video-autoplay-label = Reproducción automática de vídeo
 settings-video-autoplay-enabled = Activada
 settings-video-autoplay-disabled = Desactivada
 settings-video-autoplay-hint = Cuando está activada, los vídeos comienzan a reproducirse automáticamente al abrirse.
+settings-video-resume-label = Reanudar vídeos
+settings-video-resume-hint = Los vídeos que se vuelven a abrir pueden continuar donde los dejaste.
+settings-video-resume-ask = Preguntar
+settings-video-resume-always = Siempre
+settings-video-resume-never = Nunca
+settings-tone-mapping-label = Mapeo de tonos HDR
+settings-tone-mapping-hint = Cómo se llevan las altas luces de los vídeos HDR10 y HLG al rango de la pantalla.
+settings-tone-mapping-clip = Recortar
+settings-tone-mapping-reinhard = Reinhard
+settings-tone-mapping-hable = Fílmico (Hable)
+settings-tone-mapping-aces = ACES
+settings-audio-device-label = Salida de audio
+settings-audio-device-hint = Dispositivo que reproduce el sonido de los vídeos. Con el predeterminado del sistema, la reproducción pasa al nuevo predeterminado cuando se desconecta o cambia un dispositivo.
+settings-audio-device-default = Predeterminado del sistema
+settings-audio-device-missing = { $id } (no conectado)
 video-play-tooltip = Reproducir (Espacio)
 video-pause-tooltip = Pausar (Espacio)
 video-mute-tooltip = Silenciar (M)
+video-volume-boost-tooltip = Amplificado por encima del 100 %, recordado para este archivo
 video-unmute-tooltip = Activar sonido (M)
 video-no-audio-tooltip = Sin pista de audio
 video-loop-tooltip = Repetir
+video-loop-in-tooltip = Inicio del bucle A–B aquí
+video-loop-out-tooltip = Fin del bucle A–B aquí
+video-loop-clear-tooltip = Quitar el bucle A–B
+video-export-clip = Exportar clip
+video-export-clip-tooltip = Exportar el bucle A–B a un nuevo archivo de vídeo
+video-export-clip-progress = Exportando… { $percent }%
+video-export-clip-cancel-tooltip = Cancelar la exportación del clip
+video-export-frames = Exportar fotogramas
+video-export-frames-tooltip = Exportar un rango de fotogramas como imágenes, GIF o animación WebP
+video-frames-range-loop = Bucle A–B
+video-frames-range-around = { $seconds } s alrededor del cabezal
+video-frames-range-tooltip = Rango exportado si no hay bucle A–B
+video-frames-format-sequence = Secuencia PNG
+video-frames-format-gif = GIF animado
+video-frames-format-webp = WebP animado
+video-frames-format-tooltip = Formato de salida
+video-frames-fps = { $fps } fps
+video-frames-fps-tooltip = Fotogramas por segundo
+video-frames-scale-tooltip = Tamaño de salida
+video-frames-export = Exportar…
+video-frames-export-tooltip = Elegir dónde guardar los fotogramas y exportarlos
+video-frames-cancel-tooltip = Cancelar la exportación de fotogramas
+video-frames-mode-range = Rango
+video-frames-mode-burst = Ráfaga
+video-frames-mode-tooltip = Exportar un rango en un archivo o una ráfaga de imágenes separadas
+video-frames-burst-count = { $count } fotogramas
+video-frames-burst-count-tooltip = Fotogramas capturados alrededor del cabezal
+video-frames-burst-interval = cada { $ms } ms
+video-frames-burst-interval-tooltip = Tiempo entre dos fotogramas
+video-frames-burst-tooltip = Elegir una carpeta y guardar allí cada fotograma como PNG a tamaño completo
 video-capture-tooltip = Capturar fotograma actual
 video-step-forward-tooltip = Avanzar un fotograma (.)
 video-step-backward-tooltip = Retroceder un fotograma (,)
 video-more-tooltip = Más opciones
 video-speed-down-tooltip = Reducir velocidad (J)
 video-speed-up-tooltip = Aumentar velocidad (L)
+video-speed-selector-tooltip = Velocidad de reproducción (el audio conserva su tono de 0,25x a 3x)
+video-audio-visualization-off = Banda de audio: desactivada
+video-audio-visualization-waveform = Banda de audio: forma de onda
+video-audio-visualization-vu-meter = Banda de audio: vúmetro
+video-audio-visualization-tooltip = Mostrar la forma de onda o un medidor de nivel bajo la barra de búsqueda
+video-adjust-button = Ajustes
+video-adjust-tooltip = Brillo, contraste, saturación y gamma del vídeo
+video-adjust-brightness = Brillo
+video-adjust-contrast = Contraste
+video-adjust-saturation = Saturación
+video-adjust-gamma = Gamma
+video-adjust-reset = Restablecer
+video-adjust-reset-tooltip = Restaurar los colores originales
+video-mini-player = Minirreproductor
+video-mini-player-tooltip = Seguir reproduciendo en una ventana pequeña sobre las demás
+pip-play-tooltip = Reproducir
+pip-pause-tooltip = Pausa
+pip-return-tooltip = Volver a la ventana principal
+pip-close-tooltip = Cerrar el minirreproductor
+video-frame-counter = Fotograma { $current } / { $total }
+video-fine-scrub-frame = Preciso: 1 fotograma
+video-fine-scrub-tenth = Preciso: 0,1 s
+video-resume-prompt = ¿Reanudar desde { $position }?
+video-resume-button = Reanudar
+video-resume-start-over-button = Empezar de nuevo
+video-chapter-previous-tooltip = Capítulo anterior (RePág)
+video-chapter-next-tooltip = Capítulo siguiente (AvPág)
+video-chapter-list-tooltip = Capítulos
+video-subtitles-off = Subtítulos desactivados
+video-decode-hardware = Decodificación GPU ({ $backend })
+video-decode-software = Decodificación CPU
+video-cache-stats = Caché de fotogramas: { $rate } % de aciertos, { $frames } fotogramas, { $size } MB
+video-subtitles-track-tooltip = Pista de subtítulos
+video-subtitles-delay-tooltip = Retraso de subtítulos (clic para restablecer)
+video-subtitles-size-tooltip = Tamaño de subtítulos
+video-subtitles-background = Fondo
+video-subtitle-size-small = Pequeño
+video-subtitle-size-medium = Mediano
+video-subtitle-size-large = Grande
 hud-video-no-audio = Sin audio
 settings-audio-normalization-label = Normalización de volumen de audio
 settings-audio-normalization-enabled = Activada
 settings-audio-normalization-disabled = Desactivada
 settings-audio-normalization-hint = Nivela automáticamente el volumen de audio entre diferentes archivos multimedia para evitar cambios bruscos de volumen.
+settings-hardware-decoding-label = Decodificación de vídeo por hardware
+settings-hardware-decoding-enabled = Activada
+settings-hardware-decoding-disabled = Desactivada
+settings-hardware-decoding-hint = Decodifica los vídeos en la tarjeta gráfica (VA-API, D3D11VA/DXVA2 o VideoToolbox) para una reproducción 4K fluida, y recurre al procesador si no es compatible. Se aplica al siguiente vídeo que se abra.
+settings-file-label = Archivo de ajustes
+settings-file-hint = Los cambios hechos en settings.toml con un editor de texto se aplican en unos segundos. Exportar copia los ajustes a otro archivo; importar los reemplaza por los de un archivo.
+settings-file-import = Importar…
+settings-file-export = Exportar…
+settings-check-updates-label = Actualizaciones
+settings-check-updates-hint = Buscar una versión más reciente en GitHub. No se descarga ni se instala nada.
+settings-check-updates-manual = Manualmente
+settings-check-updates-weekly = Cada semana
+settings-check-updates-now = Comprobar ahora
+settings-mouse-wheel-label = Rueda sobre el medio
+settings-mouse-wheel-zoom = Zoom
+settings-mouse-wheel-navigate = Anterior / siguiente
+settings-mouse-wheel-volume = Volumen del vídeo
+settings-mouse-wheel-hint = Volumen del vídeo ajusta el volumen mientras se reproduce un vídeo y hace zoom en las imágenes.
+settings-mouse-drag-button-label = Botón para desplazar el medio ampliado
+settings-mouse-drag-button-hint = El doble clic izquierdo sigue alternando la pantalla completa.
+settings-mouse-button-left = Izquierdo
+settings-mouse-button-middle = Central
+settings-mouse-button-right = Derecho
+settings-mouse-middle-click-label = Clic central
+settings-mouse-middle-click-toggle-fit = Alternar ajuste a la ventana
+settings-mouse-middle-click-none = Nada
+settings-mouse-middle-click-hint = Se ignora cuando el botón central desplaza el medio.
+settings-mouse-navigation-buttons-label = Botones atrás y adelante
+settings-mouse-navigation-buttons-enabled = Medio anterior / siguiente
+settings-mouse-navigation-buttons-disabled = Ignorados
+settings-mouse-navigation-buttons-hint = Los botones laterales del ratón recorren la carpeta como las flechas.
+settings-shortcuts-hint = Haz clic en un atajo y pulsa la nueva tecla. Esc cancela.
+settings-shortcuts-press-key = Pulsa una tecla…
+settings-shortcuts-reset = Restablecer todo
+settings-shortcuts-conflict = { $other } ya usa esta tecla; { $action } conserva su atajo.
+settings-shortcuts-reserved = Esta tecla no se puede asignar; { $action } conserva su atajo.
+settings-shortcuts-group-viewer = Visor
+settings-shortcuts-group-video = Vídeo
+settings-shortcuts-group-editor = Editor
+settings-plugins-hint = Los complementos son carpetas con un archivo plugin.toml en la carpeta de complementos. Se ejecutan como programas independientes.
+settings-plugins-empty = No hay complementos instalados.
+settings-plugins-invalid = plugin.toml no válido: { $error }
+settings-plugins-hooks = Proporciona: { $hooks }
+settings-plugins-hook-media-loaded = acción al abrir un medio
+settings-plugins-hook-editor-tool = herramientas de edición
+settings-plugins-hook-export-format = formatos de exportación
+settings-plugins-enabled = Activado
+settings-plugins-disabled = Desactivado
+settings-plugins-open-folder = Abrir carpeta de complementos
+settings-plugins-reload = Recargar complementos
+shortcut-action-enter-editor = Editar imagen
+shortcut-action-toggle-info-panel = Mostrar / ocultar el panel de información
+shortcut-action-toggle-fullscreen = Alternar pantalla completa
+shortcut-action-rotate-clockwise = Girar en sentido horario
+shortcut-action-rotate-counterclockwise = Girar en sentido antihorario
+shortcut-action-mirror = Espejo
+shortcut-action-next-matte = Fondo siguiente
+shortcut-action-previous-matte = Fondo anterior
+shortcut-action-toggle-alpha = Mostrar / ocultar la transparencia
+shortcut-action-play-sequence = Reproducir como secuencia
+shortcut-action-export-view = Exportar la vista
+shortcut-action-copy-view = Copiar la vista
+shortcut-action-quick-search = Búsqueda rápida
+shortcut-action-next-face = Acercar a la cara siguiente
+shortcut-action-previous-face = Acercar a la cara anterior
+shortcut-action-toggle-face-overlay = Mostrar las caras detectadas
+shortcut-action-next-similar = Imagen similar siguiente
+shortcut-action-previous-similar = Imagen similar anterior
+shortcut-action-toggle-osd = Mostrar / ocultar la información en pantalla
+shortcut-action-toggle-pixel-inspector = Activar / desactivar el inspector de píxeles
+shortcut-action-toggle-ruler = Activar / desactivar la regla
+shortcut-action-toggle-guides = Mostrar / ocultar las guías de composición
+shortcut-action-play-pause = Reproducir / pausar
+shortcut-action-mute = Silenciar / activar sonido
+shortcut-action-step-backward = Fotograma anterior
+shortcut-action-step-forward = Fotograma siguiente
+shortcut-action-speed-down = Reducir velocidad
+shortcut-action-speed-up = Aumentar velocidad
+shortcut-action-loop-start = Definir inicio del bucle
+shortcut-action-loop-end = Definir fin del bucle
+shortcut-action-loop-clear = Borrar bucle
+shortcut-action-save = Guardar
+shortcut-action-undo = Deshacer
+shortcut-action-redo = Rehacer
+settings-frame-cache-auto-label = Tamaño de la caché de fotogramas
+settings-frame-cache-auto-enabled = Automático
+settings-frame-cache-auto-disabled = Manual
+settings-frame-cache-auto-hint = En modo automático, la caché y el historial de fotogramas se dimensionan según la resolución del vídeo y la memoria disponible.
 settings-frame-cache-label = Tamaño de caché de keyframes (para búsqueda)
 settings-frame-cache-hint = Almacena keyframes de vídeo para acelerar el desplazamiento por la línea de tiempo y los saltos a momentos específicos. Los valores más altos almacenan más keyframes para una navegación más rápida. Los cambios se aplican al abrir un nuevo vídeo.
 settings-frame-history-label = Tamaño del historial de fotogramas (para retroceder)
 settings-frame-history-hint = Almacena los fotogramas mostrados recientemente para permitir retroceder fotograma por fotograma. Solo se usa al navegar manualmente por los fotogramas, no durante la reproducción normal.
 settings-keyboard-seek-step-label = Paso de búsqueda con teclado
 settings-keyboard-seek-step-hint = Tiempo a saltar al usar las teclas de flecha durante la reproducción de vídeo.
+settings-sequence-fps-label = Velocidad de secuencias de imágenes
+settings-sequence-fps-hint = Velocidad usada al reproducir imágenes numeradas (frame_0001.png, frame_0002.png…) como vídeo con S.
+settings-frame-name-template-label = Plantilla de nombre de fotogramas
+settings-frame-name-template-hint = Nombre predeterminado de los fotogramas capturados. Tokens: {"{name}"}, {"{date}"}, {"{index}"}, {"{timestamp}"}, {"{w}"}, {"{h}"}.
+settings-save-policy-label = Al guardar
+settings-save-policy-hint = Dónde escribe Guardar las imágenes editadas. Las copias dejan intacto el original; los fotogramas capturados se proponen en la misma carpeta.
+settings-save-policy-overwrite = Sobrescribir
+settings-save-policy-copy = Copia numerada
+settings-save-policy-subfolder = Subcarpeta
+settings-edited-folder-label = Nombre de la subcarpeta
+settings-edited-folder-hint = Se crea junto a la imagen original al guardar.
+settings-guides-thirds-label = Regla de los tercios
+settings-guides-thirds-hint = Dos líneas en cada lado, a un tercio y a dos tercios.
+settings-guides-golden-ratio-label = Proporción áurea
+settings-guides-golden-ratio-hint = Dos líneas en cada lado, en las secciones áureas (38,2 % y 61,8 %).
+settings-guides-safe-areas-label = Áreas seguras
+settings-guides-safe-areas-hint = Marcos discontinuos de seguridad de acción (93 %) y de títulos (90 %).
+settings-guides-pixel-grid-label = Cuadrícula de píxeles
+settings-guides-pixel-grid-hint = Delimita cada píxel a partir de un zoom del 800 %.
+settings-guides-horizontal-label = Líneas horizontales personalizadas
+settings-guides-vertical-label = Líneas verticales personalizadas
+settings-guides-positions-hint = Posiciones en porcentaje desde el borde superior o izquierdo, separadas por comas.
+settings-guides-positions-invalid = Introduzca números de 0 a 100, por ejemplo 25, 50, 75.
+settings-sequence-fps-value = { $fps } fps
 megabytes = MB
 error-load-video-heading = No se pudo reproducir este vídeo.
 error-load-video-general = Ocurrió un error al cargar el vídeo.
@@ -189,9 +570,35 @@ error-video-seek-timeout = La búsqueda de posición expiró. La posición objet
 
 # Navigation bar
 menu-settings = Configuración
+menu-open-in-new-window = Abrir en una ventana nueva…
 menu-help = Ayuda
 menu-about = Acerca de
+menu-verify-folder = Verificar carpeta
+menu-share-over-lan = Compartir en la red local
+menu-stop-sharing = Dejar de compartir
+menu-strip-metadata = Eliminar todos los metadatos
+menu-always-on-top = Siempre visible
+menu-borderless = Ventana sin bordes
+menu-compact-mode = Modo compacto (Esc para salir)
+menu-open-with = Abrir con / Enviar a
+share-panel-title = Compartir en la red local
+share-panel-hint = Se comparten { $count } archivos. Escanea el código o abre esta dirección en un dispositivo conectado a la misma red:
+share-panel-read-only = Los visitantes pueden ver y descargar los archivos, no modificarlos.
+share-panel-copy = Copiar dirección
+share-panel-stop = Dejar de compartir
+share-panel-close = Cerrar
+diagnostics-title = Diagnóstico
+diagnostics-decode-fps = Fotogramas decodificados por segundo
+diagnostics-dropped-frames = Fotogramas descartados
+diagnostics-frame-queue = Cola de fotogramas
+diagnostics-frame-cache = Aciertos de la caché de fotogramas
+diagnostics-prefetch-cache = Aciertos de la caché de precarga
+diagnostics-memory = Memoria usada
+diagnostics-recent-loads = Últimos tiempos de carga
+diagnostics-load-failed = falló tras { $ms } ms
+diagnostics-unavailable = —
 navbar-edit-button = Editar
+navbar-menu-tooltip = Menú
 
 # Help screen
 help-title = Ayuda
@@ -219,7 +626,7 @@ help-viewer-tool-fit-desc = Escala automáticamente la imagen para que quepa com
 help-viewer-tool-fullscreen = Pantalla completa
 help-viewer-tool-fullscreen-desc = Vista inmersiva con controles que se ocultan automáticamente (retraso configurable en Configuración).
 help-viewer-tool-filter = Filtrar
-help-viewer-tool-filter-desc = Mostrar solo archivos coincidentes. Filtrar por tipo de medio, orientación o rango de fechas.
+help-viewer-tool-filter-desc = Mostrar solo archivos coincidentes. Filtrar por tipo de medio, orientación, rango de fechas o valoración mínima.
 help-viewer-tool-delete = Eliminar
 help-viewer-tool-delete-desc = Eliminar permanentemente el archivo actual (se mueve a la papelera del sistema si está disponible).
 
@@ -230,6 +637,26 @@ help-viewer-key-exit-fullscreen = Salir del modo de pantalla completa
 help-viewer-key-info = Alternar panel de información del archivo
 help-viewer-key-rotate-cw = Rotar en sentido horario
 help-viewer-key-rotate-ccw = Rotar en sentido antihorario
+help-viewer-key-mirror = Voltear el vídeo horizontalmente
+help-viewer-key-matte = Cambiar el color de fondo de las zonas transparentes
+help-viewer-key-alpha = Alternar vista previa de alfa directo/premultiplicado
+help-viewer-key-sequence = Reproducir la secuencia de imágenes numeradas como vídeo
+help-viewer-key-export-view = Guardar la vista tal como se muestra en un archivo PNG
+help-viewer-key-copy-view = Copiar la vista tal como se muestra al portapapeles
+help-viewer-key-quick-search = Buscar archivos de la carpeta por nombre
+help-viewer-key-quick-search-cycle = Ir al resultado de búsqueda siguiente / anterior
+help-viewer-key-capture-day = Ir al día de captura siguiente / anterior
+help-viewer-key-face-zoom = Ampliar la cara detectada siguiente / anterior
+help-viewer-key-face-overlay = Enmarcar las caras detectadas
+help-viewer-key-similar = Buscar similar: ir a la imagen parecida siguiente / anterior (ráfagas)
+help-viewer-key-osd = Mostrar / ocultar la información en pantalla
+help-viewer-key-pixel-inspector = Inspector de píxeles: color bajo el cursor, clic para copiarlo
+help-viewer-key-ruler = Regla: arrastrar sobre la imagen para medir una distancia y un ángulo
+help-viewer-key-ruler-undo = Quitar la última medida (regla activa)
+help-viewer-key-guides = Mostrar / ocultar las guías de composición
+help-viewer-key-layout = Cambiar a la disposición 1–3 (selección, presentación, personalizada)
+help-viewer-key-layout-save = Guardar los paneles actuales en la disposición 1–3
+help-viewer-key-diagnostics = Mostrar / ocultar el diagnóstico de rendimiento
 
 help-mouse-title = Interacciones con el ratón
 help-viewer-mouse-doubleclick = Doble clic en imagen/vídeo para alternar pantalla completa
@@ -247,7 +674,7 @@ help-video-tool-playback-desc = Inicie o detenga la reproducción de vídeo con
 help-video-tool-timeline = Línea de tiempo
 help-video-tool-timeline-desc = Haga clic en cualquier lugar de la barra de progreso para saltar a esa posición.
 help-video-tool-volume = Volumen
-help-video-tool-volume-desc = Arrastre el control deslizante de volumen (0-150%) o haga clic en el icono del altavoz para silenciar/activar sonido.
+help-video-tool-volume-desc = Arrastre el control deslizante de volumen (0-200%) o haga clic en el icono del altavoz para silenciar/activar sonido.
 help-video-tool-loop = Repetir
 help-video-tool-loop-desc = Active para reiniciar automáticamente el vídeo cuando termine.
 help-video-tool-stepping = Navegación por fotogramas
@@ -258,11 +685,15 @@ help-video-tool-capture-desc = Guarde el fotograma actual del vídeo como archiv
 help-video-key-playpause = Reproducir o pausar el vídeo
 help-video-key-mute = Alternar silencio de audio
 help-video-key-seek = Buscar atrás/adelante (durante la reproducción)
+help-video-key-fine-seek = Búsqueda precisa: un fotograma en pausa, 0,1 s durante la reproducción (mantén Mayús al arrastrar la línea de tiempo para recorrerla despacio)
 help-video-key-volume = Aumentar/disminuir volumen
 help-video-key-step-back = Retroceder un fotograma (cuando esté en pausa)
 help-video-key-step-forward = Avanzar un fotograma (cuando esté en pausa)
 help-video-key-speed-down = Reducir velocidad de reproducción
 help-video-key-speed-up = Aumentar velocidad de reproducción
+help-video-key-chapter = Ir al capítulo siguiente / anterior (vídeos con capítulos)
+help-video-key-loop-points = Fijar el inicio / fin del bucle A–B en la posición actual
+help-video-key-loop-clear = Quitar el bucle A–B
 
 # ─────────────────────────────────────────────────────────────────────────────
 # Image Editor Section
@@ -293,9 +724,22 @@ help-editor-resize-ai-upscale = Ampliación IA: Use Real-ESRGAN para ampliacione
 
 help-editor-light-title = Luz
 help-editor-light-desc = Ajuste finamente el brillo y el contraste de su imagen.
+help-editor-light-auto = Mejora automática: analiza el histograma para corregir niveles, contraste y balance de blancos en un paso; la intensidad mezcla la corrección con el original
 help-editor-light-brightness = Brillo: aclare u oscurezca la imagen en general
 help-editor-light-contrast = Contraste: aumente o disminuya la diferencia entre áreas claras y oscuras
+help-editor-light-grayscale = Blanco y negro: un mezclador de canales fija cuánto aporta cada color al gris; los ajustes Rojo, Verde y Azul imitan los filtros de color de la película en blanco y negro
+help-editor-light-sharpen = Enfoque: una máscara de enfoque cuyo radio fija el tamaño de los bordes realzados y cuyo umbral respeta las zonas lisas y el ruido
+help-editor-light-copy = Copiar/pegar ajustes: copia los giros, volteos, recortes, cambios de tamaño y ajustes de luz de la imagen, conservados tras reiniciar; al pegar se aplican a otra imagen, adaptando recortes y cambios de tamaño a su tamaño, o a todas las imágenes de la carpeta, guardadas como copias que nunca reemplazan los originales, tras una confirmación
 help-editor-light-preview = Los cambios se previsualizan en tiempo real antes de aplicarlos
+help-editor-filters-title = Filtros
+help-editor-filters-desc = Da un estilo a tu imagen con un clic: blanco y negro, sepia, viñeta, tonos de película y más.
+help-editor-filters-preview = Haz clic en un estilo para previsualizarlo y otra vez para quitarlo
+help-editor-filters-strength = La intensidad mezcla el estilo con la imagen original
+help-editor-filters-undo = Un estilo aplicado es un solo paso que se puede deshacer
+help-editor-history-title = Historial
+help-editor-history-desc = Vuelva a cualquier estado anterior de sus ediciones, más allá de deshacer un solo paso.
+help-editor-history-steps = Haga clic en un paso para volver a él; los pasos posteriores siguen en la lista y pueden restaurarse hasta que haga una nueva edición
+help-editor-history-snapshots = Guarde una instantánea para nombrar el estado actual («tras el recorte») y volver a él más tarde; las instantáneas duran hasta que se guarda la imagen
 
 help-editor-save-title = Guardar
 help-editor-save-overwrite = Guardar: sobrescribe el archivo original
@@ -305,10 +749,15 @@ help-editor-key-save = Guardar cambios actuales
 help-editor-key-undo = Deshacer último cambio
 help-editor-key-redo = Rehacer cambio deshecho
 help-editor-key-cancel = Cancelar todos los cambios y salir
+help-editor-key-show-original = Mostrar la imagen original mientras se mantiene pulsada
 
 help-editor-mouse-title = Controles del ratón
 help-editor-mouse-wheel = Ampliar o reducir la imagen
 help-editor-mouse-drag = Mover la imagen cuando está ampliada
+help-editor-mouse-space-drag = Desplazar la imagen sobre los tiradores de recorte y el pincel de refinado
+help-editor-mouse-space-drag-label = Espacio + arrastrar
+help-editor-mouse-fit = El botón Ajustar alterna el lienzo entre ajustar a la ventana y 100 %
+help-editor-compare = Mantenga pulsado Ver original para ver la imagen sin cambios, o use Antes/Después para dividir el lienzo: el original a la izquierda del divisor y sus ediciones a la derecha
 
 # ─────────────────────────────────────────────────────────────────────────────
 # Frame Capture Section
@@ -378,16 +827,73 @@ about-third-party-details = Ver THIRD_PARTY_LICENSES.md para más detalles
 about-section-links = Enlaces
 about-link-repository = Código fuente
 about-link-issues = Reportar problemas
+about-section-troubleshooting = Solución de problemas
+about-troubleshooting-hint = Al informar de un problema, adjunte el informe de diagnóstico: indica las versiones del sistema, la tarjeta gráfica y FFmpeg junto con las últimas líneas del registro.
+about-view-logs-button = Ver registros
+about-copy-report-button = Copiar informe de diagnóstico
+about-missing-translations-button = Traducciones que faltan
+logs-title = Registros
+logs-folder-unavailable = No se pudo determinar la carpeta de registros.
+logs-refresh = Actualizar
+logs-open-folder = Abrir carpeta de registros
+logs-copy-report = Copiar informe de diagnóstico
+logs-empty = Aún no se ha registrado nada.
+translations-title = Traducciones que faltan
+translations-summary = { $count } claves de { $locale } se muestran en inglés. Las traducciones se cargan desde { $dir }.
+translations-copy-keys = Copiar las claves
+translations-complete = Todas las claves están traducidas.
+welcome-title = Bienvenido a IcedLens
+welcome-preferences-intro = Elige tu idioma y tu tema. Todo se puede cambiar más tarde en Ajustes.
+welcome-default-viewer-title = Visor de imágenes predeterminado
+welcome-default-viewer-intro = Abre las imágenes en IcedLens al hacer doble clic en ellas en tu gestor de archivos.
+welcome-default-viewer-button = Establecer como visor predeterminado
+welcome-default-viewer-working = Registrando…
+welcome-default-viewer-done = IcedLens abre ahora tus imágenes.
+welcome-default-viewer-confirm = Elige IcedLens para las imágenes en la configuración de Aplicaciones predeterminadas que se acaba de abrir.
+welcome-default-viewer-failed = No se pudo registrar IcedLens: { $error }
+welcome-tour-progress = Consejo { $current } de { $total }
+welcome-tour-try = Pruébalo: pulsa { $key }
+welcome-tour-tried = ¡Bien hecho!
+welcome-tour-footer = Todos los atajos aparecen en Ayuda y se pueden cambiar en Ajustes.
+welcome-tour-navigate-title = Recorrer la carpeta
+welcome-tour-navigate-body = Muestra el archivo siguiente o anterior de la carpeta. La rueda del ratón hace zoom y arrastrar desplaza.
+welcome-tour-fullscreen-title = Pantalla completa
+welcome-tour-fullscreen-body = Los controles se ocultan tras un momento; mueve el ratón para mostrarlos. Esc sale de la pantalla completa.
+welcome-tour-info-title = Ver los detalles
+welcome-tour-info-body = El panel de información muestra los metadatos de cámara, exposición y vídeo, y edita títulos, palabras clave y fechas.
+welcome-tour-editor-title = Editar la imagen
+welcome-tour-editor-body = Gira, recorta, cambia el tamaño y ajusta la luz. No se escribe nada hasta que guardes.
+welcome-tour-video-title = Reproducir vídeos
+welcome-tour-video-body = Reproduce y pausa los vídeos. En pausa, la coma y el punto avanzan fotograma a fotograma.
+welcome-skip = Omitir
+welcome-back = Atrás
+welcome-next = Siguiente
+welcome-finish = Empezar a usar IcedLens
 
 # Notifications
 notification-save-success = Imagen guardada exitosamente
+notification-save-copy-success = Imagen guardada como { $file }
 notification-save-error = Error al guardar la imagen
 notification-frame-capture-success = Fotograma capturado exitosamente
 notification-frame-capture-error = Error al capturar fotograma
 notification-delete-success = Archivo eliminado exitosamente
 notification-delete-error = Error al eliminar archivo
 notification-config-save-error = Error al guardar la configuración
+notification-theme-save-error = No se pudo guardar el archivo de tema
+notification-config-merged = Se conservó la configuración cambiada en otra ventana
 notification-config-load-error = Error al cargar la configuración, usando valores predeterminados
+notification-config-reloaded = Ajustes recargados desde settings.toml
+notification-translations-reloaded = Traducciones recargadas, faltan { $count } claves
+notification-config-reload-error = settings.toml no es válido, cambios no aplicados: { $error }
+notification-settings-imported = Ajustes importados
+notification-settings-import-error = No se pudieron importar los ajustes: { $error }
+notification-settings-exported = Ajustes exportados
+notification-settings-export-error = No se pudieron exportar los ajustes: { $error }
+notification-update-available = IcedLens { $version } está disponible.
+    { $notes }
+notification-update-download = Abrir la página de descarga
+notification-update-none = IcedLens { $version } está actualizado.
+notification-update-check-error = No se pudieron buscar actualizaciones: { $error }
 notification-state-parse-error = Error al leer el estado de la aplicación, usando valores predeterminados
 notification-state-read-error = Error al abrir el archivo de estado de la aplicación
 notification-state-path-error = No se puede determinar la ruta de datos de la aplicación
@@ -400,6 +906,18 @@ notification-editor-create-error = Error al abrir el editor de imágenes
 notification-editor-load-error = Error al cargar la imagen para editar
 notification-video-editing-unsupported = La edición de vídeo aún no es compatible
 
+# Unidades
+unit-bytes = { $value } B
+unit-kilobytes = { $value } KB
+unit-megabytes = { $value } MB
+unit-gigabytes = { $value } GB
+unit-bits-per-second = { $value } bps
+unit-kilobits-per-second = { $value } kbps
+unit-megabits-per-second = { $value } Mbps
+unit-frames-per-second = { $value } fps
+unit-nits = { $value } nits
+unit-nits-range = { $min }–{ $max } nits
+
 # Metadata panel
 metadata-panel-title = Información del archivo
 metadata-panel-close = Cerrar panel
@@ -413,6 +931,7 @@ metadata-section-gps = Ubicación
 metadata-label-dimensions = Dimensiones
 metadata-label-file-size = Tamaño de archivo
 metadata-label-format = Formato
+metadata-label-decoder = Decodificador
 metadata-label-date-taken = Fecha de captura
 metadata-label-camera = Cámara
 metadata-label-exposure = Exposición
@@ -420,11 +939,49 @@ metadata-label-aperture = Apertura
 metadata-label-iso = ISO
 metadata-label-focal-length = Distancia focal
 metadata-label-gps = Coordenadas
+metadata-label-gps-dms = GMS
+metadata-open-map-button = Abrir en OpenStreetMap
+metadata-all-tags-title = Todas las etiquetas ({ $count })
+metadata-all-tags-filter-placeholder = Filtrar etiquetas…
+metadata-all-tags-no-match = Ninguna etiqueta coincide con el filtro
+metadata-all-tags-copy-tooltip = Haz clic para copiar el valor
+metadata-batch-title = Edición por lotes
+metadata-stats-title = Estadísticas de la carpeta
+metadata-stats-refresh = Recalcular estadísticas
+metadata-export-title = Exportar metadatos
+metadata-export-hint = Guardar como JSON o CSV (según la extensión) para catálogos y scripts
+metadata-export-file = Este archivo
+metadata-export-folder = Toda la carpeta
+metadata-stats-loading = Leyendo datos de la cámara…
+metadata-stats-summary = { $count } archivos, { $exif } con datos de cámara
+metadata-stats-empty = No se encontraron datos de cámara en esta carpeta
+metadata-stats-cameras = Cámaras
+metadata-stats-lenses = Objetivos
+metadata-stats-focal-lengths = Distancia focal (equiv. 35 mm)
+metadata-stats-iso = ISO
+metadata-batch-hint = Se aplica a los { $count } archivos de la carpeta (o del filtro activo). Los campos vacíos no se modifican; los vídeos se omiten.
+metadata-batch-creator-label = Establecer autor
+metadata-batch-copyright-label = Establecer copyright
+metadata-batch-keywords-label = Añadir palabras clave (separadas por comas)
+metadata-batch-strip-gps = Eliminar ubicación GPS
+metadata-batch-apply-button = Aplicar a { $count } archivos
+metadata-batch-confirm-warning = Se modificarán { $count } archivos originales. ¿Continuar?
+metadata-batch-confirm-button = Aplicar
+metadata-batch-running = Escribiendo metadatos…
 metadata-label-codec = Códec
 metadata-label-bitrate = Tasa de bits
 metadata-label-duration = Duración
 metadata-label-fps = Fotogramas por segundo
 metadata-value-unknown = Desconocido
+metadata-value-decoder-fallback = { $decoder } (alternativo)
+metadata-label-bit-depth = Profundidad de bits
+metadata-value-bit-depth = { $bits } bits
+metadata-label-hdr = HDR
+metadata-value-hdr-pq = HDR10 (PQ)
+metadata-value-hdr-hlg = HLG
+metadata-label-mastering-display = Pantalla de masterización
+metadata-label-max-cll = Luz máx. del contenido (MaxCLL)
+metadata-label-max-fall = Media máx. por fotograma (MaxFALL)
 
 # Edición de metadatos
 metadata-edit-button = Editar
@@ -433,7 +990,28 @@ metadata-cancel-button = Cancelar
 metadata-save-button = Guardar
 metadata-save-as-button = Guardar como...
 metadata-save-warning = Guardar modificará el archivo original
+metadata-gps-strip-pending = La ubicación se eliminará del archivo al guardar.
+metadata-strip-button = Eliminar todos los metadatos
+metadata-strip-dialog-title = Eliminar todos los metadatos
+metadata-strip-dialog-description = Se eliminarán los siguientes metadatos. Los datos de la imagen no se modifican.
+metadata-strip-dialog-overwrite = Sobrescribir archivo
+metadata-strip-dialog-copy = Guardar copia limpia
+metadata-strip-dialog-cancel = Cancelar
+recipe-batch-dialog-title = Pegar en todas las imágenes de la carpeta
+recipe-batch-dialog-description = Los ajustes copiados se aplicarán a { $count } imágenes.
+recipe-batch-dialog-copy = Cada resultado se guarda como copia numerada junto a su original. Los originales no se modifican.
+recipe-batch-dialog-subfolder = Los resultados se guardan en la subcarpeta «{ $folder }», reemplazando las versiones editadas anteriores. Los originales no se modifican.
+recipe-batch-dialog-skipped = Se omiten las imágenes animadas y los formatos que no se pueden escribir.
+recipe-batch-dialog-paste = Pegar
+recipe-batch-dialog-cancel = Cancelar
+metadata-strip-block-exif = EXIF (cámara, fecha, ubicación GPS)
+metadata-strip-block-xmp = XMP (título, autor, palabras clave)
+metadata-strip-block-iptc = IPTC (leyenda, copyright)
+metadata-strip-block-thumbnail = Miniatura incrustada
+metadata-strip-block-comment = Comentarios y anotaciones de texto
 metadata-label-make = Marca
+metadata-remove-field-tooltip = Quitar el campo
+metadata-remove-keyword-tooltip = Quitar la palabra clave
 metadata-label-model = Modelo
 metadata-label-focal-length-35mm = Distancia focal (35mm)
 metadata-label-flash = Flash
@@ -455,7 +1033,47 @@ metadata-validation-invalid-number = Número inválido
 # Notificaciones de metadatos
 notification-metadata-save-success = Metadatos guardados correctamente
 notification-metadata-save-error = Error al guardar los metadatos
+notification-metadata-batch-started = Actualizando los metadatos de { $count } archivos…
+notification-metadata-batch-success = Metadatos actualizados en { $updated } archivos ({ $skipped } omitidos)
+notification-metadata-batch-partial = Metadatos actualizados en { $updated } archivos, { $failed } fallidos (primero: { $filename })
+notification-metadata-exported = Metadatos de { $count } archivos exportados
+notification-metadata-export-error = No se pudieron exportar los metadatos: { $error }
+notification-plugins-reloaded = { $count } complementos cargados
+notification-plugins-folder-error = No se pudo abrir la carpeta de complementos: { $error }
+notification-logs-folder-error = No se pudo abrir la carpeta de registros: { $error }
+notification-diagnostic-report-copied = Informe de diagnóstico copiado al portapapeles
+notification-plugin-tool-error = La herramienta del complemento falló: { $error }
+notification-plugin-export-success = { $filename } exportado
+notification-plugin-export-error = La exportación del complemento falló: { $error }
+notification-open-with-done = { $name }: terminado
+notification-open-with-output = { $name }: { $output }
+notification-open-with-error = { $name } falló: { $error }
+notification-verify-started = Verificando { $count } archivos…
+notification-verify-clean = Los { $count } archivos están intactos
+notification-verify-damaged = { $damaged } de { $count } archivos están dañados (lista copiada al portapapeles)
+notification-share-empty = No hay ningún archivo que compartir en esta carpeta
+notification-share-error = No se pudo compartir en el puerto { $port }: { $error }
+notification-share-stopped = Se dejó de compartir
+notification-share-url-copied = Dirección copiada al portapapeles
+notification-strip-success = Se eliminaron todos los metadatos
+notification-layout-applied = Disposición: { $name }
+notification-layout-saved = Disposición «{ $name }» guardada
+layout-name-culling = Selección
+layout-name-presentation = Presentación
+layout-name-custom = Personalizada
+notification-strip-copy-success = Copia limpia guardada como { $filename }
+notification-strip-error = No se pudieron eliminar los metadatos: { $error }
+notification-strip-nothing = Este archivo no contiene metadatos que eliminar
+notification-strip-unsupported = No se admite eliminar metadatos en este formato de archivo
 notification-metadata-validation-error = Por favor corrija los errores de validación antes de guardar
+notification-open-url-error = No se pudo abrir el enlace: { $error }
+notification-copied-to-clipboard = Copiado al portapapeles
+notification-color-copied = Color { $color } copiado al portapapeles
+notification-adjustments-copied = Ajustes copiados (pasos: { $count })
+notification-dismiss-tooltip = Descartar
+notification-recipe-batch-started = Pegando los ajustes en { $count } imágenes…
+notification-recipe-batch-success = Ajustes pegados en { $updated } imágenes ({ $skipped } omitidas)
+notification-recipe-batch-partial = Ajustes pegados en { $updated } imágenes, { $failed } con errores (primera: { $filename })
 
 # Divulgación progresiva de metadatos
 metadata-add-field = Añadir campo de metadatos...
@@ -467,6 +1085,7 @@ metadata-label-dc-title = Título
 metadata-label-dc-creator = Creador
 metadata-label-dc-description = Descripción
 metadata-label-dc-subject = Palabras clave
+metadata-keyword-input-placeholder = Añade una palabra clave y pulsa Intro
 metadata-label-dc-rights = Derechos de autor
 
 navbar-info-button = Info
@@ -477,11 +1096,37 @@ empty-state-subtitle = Arrastra archivos aquí o haz clic para abrir
 empty-state-button = Abrir archivo
 empty-state-drop-hint = Arrastra y suelta imágenes o vídeos en cualquier lugar
 
+# File dialog filters
+dialog-filter-all-media = Todos los medios
+dialog-filter-images = Imágenes
+dialog-filter-videos = Vídeos
+dialog-filter-image-format = Imagen { $format }
+dialog-filter-video-format = Vídeo { $format }
+
 # Additional notifications
 notification-empty-dir = No se encontraron archivos multimedia compatibles en esta carpeta
 notification-load-error-io = No se pudo abrir el archivo. Verifica que existe y tienes permisos.
 notification-load-error-svg = No se pudo renderizar el SVG. El archivo puede estar malformado.
 notification-load-error-video = No se pudo reproducir el vídeo. El formato puede no ser compatible.
+notification-image-sequence-not-found = Esta imagen no forma parte de una secuencia numerada.
+notification-capture-day = { $date } — { $count } archivos
+notification-similar-indexing = Comparando las imágenes de esta carpeta…
+notification-similar-found = { $count } imágenes similares
+notification-similar-none = Ninguna imagen similar en esta carpeta
+notification-face-model-downloading = Descargando el modelo de detección de caras…
+notification-no-faces-found = No se encontraron caras en esta imagen.
+notification-clip-exported = Clip guardado en { $path }
+notification-clip-exported-reencoded = Clip recodificado y guardado en { $path } (sin audio)
+notification-clip-export-failed = No se pudo exportar el clip: { $message }
+notification-frames-exported = { $count } fotogramas guardados en { $path }
+notification-mini-player-error = No se pudo abrir el minirreproductor
+notification-visible-area-exported = Área visible guardada en { $path }
+notification-visible-area-export-error = No se pudo exportar el área visible
+notification-view-exported = Vista guardada en { $path }
+notification-view-export-error = No se pudo exportar la vista
+notification-view-copied = Vista copiada al portapapeles
+notification-frames-export-failed = No se pudieron exportar los fotogramas: { $message }
+notification-face-detection-error = Error en la detección de caras: { $error }
 notification-load-error-timeout = La carga ha expirado. El archivo puede ser demasiado grande o el sistema está ocupado.
 notification-skipped-corrupted-files = Omitidos: { $files }
 notification-skipped-and-others = +{ $count } más
@@ -515,6 +1160,19 @@ settings-upscale-status-error = Error: { $message }
 settings-upscale-status-not-downloaded = Modelo no descargado
 settings-upscale-enabled = Habilitado
 settings-upscale-disabled = Deshabilitado
+settings-enable-background-removal-label = Eliminación de fondo con IA
+settings-enable-background-removal-hint = Activa la eliminación de fondo con IA mediante el modelo de segmentación U²-Net (~176 MB de descarga).
+settings-background-removal-model-url-label = URL del modelo
+settings-background-removal-model-url-placeholder = https://github.com/...
+settings-background-removal-model-url-hint = URL desde la que descargar el modelo ONNX U²-Net.
+settings-background-removal-status-label = Estado del modelo
+settings-background-removal-status-downloading = Descargando modelo ({ $progress } %)...
+settings-background-removal-status-validating = Validando modelo...
+settings-background-removal-status-ready = Modelo listo
+settings-background-removal-status-error = Error: { $message }
+settings-background-removal-status-not-downloaded = Modelo no descargado
+settings-background-removal-enabled = Activado
+settings-background-removal-disabled = Desactivado
 
 # Herramienta de desenfoque del editor
 image-editor-tool-deblur = Desenfoque IA
@@ -528,12 +1186,49 @@ image-editor-deblur-validating = Validando modelo, por favor espera...
 image-editor-deblur-downloading = Descargando modelo ({ $progress }%)...
 image-editor-deblur-error = Error: { $error }
 image-editor-deblur-already-applied = Desenfoque ya aplicado. Usa Deshacer para revertir si es necesario.
+image-editor-tool-background = Eliminación de fondo con IA
+image-editor-background-remove = Eliminar fondo
+image-editor-background-apply = Aplicar recorte
+image-editor-background-processing = Eliminando el fondo...
+image-editor-background-model-not-ready = Active primero la eliminación de fondo con IA en Ajustes
+image-editor-background-validating = Validando modelo, espere...
+image-editor-background-downloading = Descargando modelo ({ $progress } %)...
+image-editor-background-error = Error: { $error }
+image-editor-background-refine-hint = Pinte sobre la imagen para refinar los bordes y luego aplique.
+image-editor-background-brush-keep = Conservar
+image-editor-background-brush-erase = Borrar
+image-editor-background-brush-size = Tamaño del pincel
+image-editor-background-transparent-export-hint = Guarde como PNG o WebP para conservar la transparencia; JPEG la rellena de blanco.
+image-editor-tool-denoise = Reducción de ruido
+image-editor-denoise-method-label = Método
+image-editor-denoise-method-bilateral = Bilateral
+image-editor-denoise-method-nlmeans = NL-means
+image-editor-denoise-method-model = Modelo IA
+image-editor-denoise-strength-label = Intensidad
+image-editor-denoise-detail-label = Conservación de detalles
+image-editor-denoise-preview-hint = La vista previa se calcula sobre una copia reducida; Aplicar procesa la imagen completa.
+image-editor-denoise-model-hint = El modelo no tiene vista previa; su resultado se muestra al aplicarlo.
+image-editor-denoise-apply = Aplicar reducción
+image-editor-denoise-processing = Reduciendo ruido...
+image-editor-plugins-section-title = Complementos
+image-editor-plugins-running = Ejecutando complemento…
+image-editor-plugins-export = Exportar como { $format }
 
 # Sección de ayuda de IA
 help-editor-deblur-title = Desenfoque IA
 help-editor-deblur-desc = Usa IA para mejorar la nitidez de imágenes borrosas con la red neuronal NAFNet.
 help-editor-deblur-enable = Habilitar en Configuración → IA / Aprendizaje automático (descarga modelo de ~92 MB)
 help-editor-deblur-lossless = Para mejor calidad, exportar como WebP sin pérdida o PNG
+help-editor-background-title = Eliminación de fondo con IA
+help-editor-background-desc = Recorte el sujeto de su fondo con el modelo de segmentación U²-Net, que se ejecuta en su equipo.
+help-editor-background-enable = Actívelo en Ajustes → IA / Aprendizaje automático (descarga un modelo de ~176 MB)
+help-editor-background-refine = Refine los bordes con los pinceles Conservar y Borrar antes de aplicar
+help-editor-background-export = Guarde como PNG o WebP para conservar el fondo transparente
+help-editor-denoise-title = Reducción de ruido
+help-editor-denoise-desc = Reduce el grano de las fotos tomadas con poca luz.
+help-editor-denoise-methods = Bilateral es rápido; NL-means es más lento pero conserva más textura
+help-editor-denoise-sliders = Sube la intensidad para quitar más ruido y la conservación de detalles para mantener los bordes finos
+help-editor-denoise-model = Define denoise_model_url en la sección [ai] de settings.toml para añadir un modelo IA
 
 # Notificaciones de IA
 notification-deblur-success = Imagen desenfocada con éxito
@@ -551,3 +1246,9 @@ notification-upscale-download-error = Error al descargar el modelo de escalado:
 notification-upscale-validation-error = Error en la validación del modelo: { $error }
 notification-upscale-resize-success = Imagen redimensionada con escalado IA
 notification-upscale-resize-error = Error en el escalado IA: { $error }
+notification-background-ready = La eliminación de fondo con IA está lista
+notification-background-download-error = Error al descargar el modelo de eliminación de fondo: { $error }
+notification-background-validation-error = Error al validar el modelo: { $error }
+notification-background-removal-error = Error al eliminar el fondo: { $error }
+notification-denoise-model-downloading = Descargando el modelo de reducción de ruido…
+notification-denoise-error = Error en la reducción de ruido: { $error }
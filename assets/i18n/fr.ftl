@@ -8,8 +8,15 @@ settings-title = Paramètres
 settings-section-general = Général
 settings-section-display = Affichage
 settings-section-video = Vidéo
+settings-section-editor = Éditeur
 settings-section-fullscreen = Plein écran
+settings-section-guides = Repères
 settings-section-ai = IA / Apprentissage automatique
+settings-section-advanced = Avancé
+settings-section-mouse = Souris
+settings-section-shortcuts = Raccourcis clavier
+settings-section-plugins = Extensions
+settings-section-accessibility = Accessibilité
 select-language-label = Sélectionner la langue :
 language-name-en-US = Anglais
 language-name-fr = Français
@@ -24,6 +31,7 @@ viewer-zoom-label = Zoom
 viewer-zoom-input-placeholder = 100
 viewer-zoom-reset-button = Réinitialiser
 viewer-fit-to-window-toggle = Adapter à la fenêtre
+viewer-lock-view-tooltip = Garder le zoom et la position en changeant d'image
 viewer-zoom-input-error-invalid = Veuillez saisir un nombre valide.
 viewer-zoom-step-error-invalid = L'incrément de zoom doit être un nombre.
 viewer-zoom-step-error-range = L'incrément de zoom doit être compris entre 1 % et 200 %.
@@ -32,7 +40,21 @@ viewer-zoom-in-tooltip = Zoom avant
 viewer-zoom-out-tooltip = Zoom arrière
 viewer-fullscreen-tooltip = Basculer en plein écran
 viewer-rotate-cw-tooltip = Rotation horaire
+viewer-export-visible-tooltip = Exporter la zone visible
+viewer-inspector-tooltip = Inspecteur de pixels
+viewer-inspector-copy-hint = Cliquer pour copier
+viewer-ruler-tooltip = Règle
+viewer-guides-tooltip = Repères de composition
 viewer-rotate-ccw-tooltip = Rotation anti-horaire
+viewer-mirror-tooltip = Retourner la vidéo horizontalement
+viewer-matte-background = Thème d'arrière-plan
+viewer-matte-black = Fond noir
+viewer-matte-white = Fond blanc
+viewer-matte-gray = Fond gris
+viewer-matte-magenta = Fond magenta
+viewer-matte-green = Fond vert
+viewer-alpha-straight = Alpha direct
+viewer-alpha-premultiplied = Alpha prémultiplié
 viewer-fullscreen-disabled-unsaved = Enregistrez ou annulez d'abord les modifications
 viewer-double-click = Double-clic
 viewer-scroll-wheel = Molette
@@ -48,6 +70,9 @@ filter-media-type-all = Tous
 filter-media-type-images = Images uniquement
 filter-media-type-videos = Vidéos uniquement
 filter-media-type-placeholder = Sélectionner...
+filter-rating-label = Note minimale
+filter-rating-any = Toutes les notes
+filter-rating-min = { $stars } et plus
 filter-date-label = Filtre par date
 filter-date-field-label = Filtrer par
 filter-date-field-modified = Date de modification
@@ -61,6 +86,9 @@ filter-date-clear = Effacer la date
 filter-tooltip-date-from = à partir du { $date }
 filter-tooltip-date-to = jusqu'au { $date }
 filter-tooltip-date-range = { $start } – { $end }
+quick-search-placeholder = Rechercher par nom de fichier...
+quick-search-no-results = Aucun résultat
+quick-search-hint = ↑/↓ sélectionner · Entrée ouvrir · Échap fermer
 
 settings-zoom-step-label = Incrément de zoom
 settings-zoom-step-placeholder = 10
@@ -69,10 +97,38 @@ settings-background-label = Fond de la visionneuse
 settings-background-light = Clair
 settings-background-dark = Sombre
 settings-background-checkerboard = Damier
+settings-background-auto = Auto
+settings-background-auto-hint = Sombre derrière les images claires, clair derrière les images sombres
 settings-theme-mode-label = Thème de l'application
 settings-theme-system = Suivre le système
 settings-theme-light = Clair
 settings-theme-dark = Sombre
+settings-ui-scale-label = Échelle de l'interface
+settings-ui-scale-hint = Agrandit ou réduit le texte, les espacements et les contrôles, en plus de l'échelle du système. Appliquée au relâchement du curseur.
+settings-accessibility-disabled = Désactivé
+settings-accessibility-enabled = Activé
+settings-reduced-motion-label = Réduire les animations
+settings-reduced-motion-hint = Les indicateurs de chargement restent immobiles au lieu de tourner.
+settings-high-contrast-focus-label = Focus à contraste élevé
+settings-high-contrast-focus-hint = Dessine un contour épais de la couleur du texte autour du champ en cours de saisie.
+settings-large-targets-label = Boutons plus grands
+settings-large-targets-hint = Rend les boutons de la barre d'outils, du menu et des contrôles vidéo plus faciles à cliquer.
+settings-always-show-fullscreen-controls-label = Toujours afficher les contrôles en plein écran
+settings-always-show-fullscreen-controls-hint = Garde la barre d'outils, les contrôles vidéo et les flèches de navigation visibles en plein écran au lieu de les masquer après le délai de l'overlay.
+settings-accent-label = Couleur d'accentuation
+settings-accent-hint = Utilisée pour les boutons sélectionnés, les curseurs, les surlignages et les notifications. Saisissez une couleur #rrggbb pour une autre teinte.
+settings-accent-custom-hint = La palette personnalisée ci-dessous définit l'accent tant qu'elle est activée.
+settings-accent-default = Par défaut
+settings-custom-theme-label = Palette personnalisée
+settings-custom-theme-hint = Remplace le thème clair ou sombre. Les changements sont prévisualisés immédiatement et enregistrés dans theme.toml à côté de settings.toml.
+settings-custom-theme-disabled = Désactivée
+settings-custom-theme-enabled = Activée
+settings-palette-background = Arrière-plan
+settings-palette-text = Texte
+settings-palette-accent = Accent
+settings-palette-success = Succès
+settings-palette-danger = Danger
+settings-palette-invalid = Utilisez le format #rrggbb
 help-usage-heading = UTILISATION :
 help-options-heading = OPTIONS :
 help-args-heading = ARGUMENTS :
@@ -83,25 +139,85 @@ help-arg-image-path = <CHEMIN>      Chemin vers un fichier média ou un réperto
 help-example-1 = iced_lens ./photo.png
 help-example-2 = iced_lens ./mes_photos/
 help-example-3 = iced_lens --lang fr ./image.jpg
+help-example-4 = iced_lens verify /media/sdcard/DCIM
+help-example-5 = iced_lens convert photo.png photo.jpg --quality 85
+help-example-6 = iced_lens info clip.mp4 --json
+help-commands-heading = COMMANDES :
+help-line-command-verify = verify [RÉP]  Décoder entièrement chaque fichier média de RÉP et lister les fichiers endommagés
+help-line-command-convert = convert <ENTRÉE> <SORTIE> [--quality <1-100>]  Convertir un fichier média au format d’image de SORTIE
+help-line-command-thumb = thumb [CHEMIN] [--size <px>] [--out <rép>]  Créer des miniatures JPEG d’un fichier ou de chaque fichier média d’un répertoire
+help-line-command-info = info <CHEMIN> [--json|--csv]  Afficher les métadonnées d’un fichier média ou de tous les fichiers médias d’un dossier
+cli-verify-summary = { $checked } fichiers vérifiés, { $damaged } endommagés
+cli-thumb-summary = { $written } miniatures créées, { $failed } échecs
+cli-info-unsupported = aucune métadonnée lisible (fichier non pris en charge ou endommagé)
 help-description = { -app-name } – Visionneuse d'images
 help-line-option-i18n-dir =     --i18n-dir <chemin>  Charger les traductions depuis un dossier
+help-line-option-watch-i18n =     --watch-i18n   Recharger les traductions quand leurs fichiers .ftl changent
 help-line-option-data-dir =     --data-dir <chemin>  Remplacer le répertoire de données (fichiers d'état)
 help-line-option-config-dir =     --config-dir <chemin>  Remplacer le répertoire de config (settings.toml)
 settings-sort-order-label = Ordre de tri pour la navigation
 settings-sort-alphabetical = Alphabétique
 settings-sort-modified = Date de modification
 settings-sort-created = Date de création
+settings-sort-size = Taille du fichier
+settings-sort-type = Type de fichier
+settings-sort-ascending = Croissant
+settings-sort-descending = Décroissant
+settings-sort-order-hint = L'ordre alphabétique compare les nombres par valeur (img2 avant img10).
 settings-max-skip-attempts-label = Ignorer les fichiers corrompus
 settings-max-skip-attempts-hint = Nombre max de fichiers corrompus à ignorer lors de la navigation.
+settings-prefetch-cache-size-label = Médias préchargés
+settings-prefetch-cache-size-hint = Fichiers décodés gardés en mémoire pour afficher instantanément le suivant et le précédent. 0 désactive le préchargement.
+settings-similarity-threshold-label = Seuil d'images similaires
+settings-similarity-threshold-hint = Ressemblance minimale pour que Trouver similaire saute vers une image. Baissez-le pour inclure aussi les photos recadrées.
 settings-persist-filters-label = Mémoriser les filtres
 settings-persist-filters-hint = Conserver les paramètres de filtrage entre les sessions.
 settings-persist-filters-disabled = Non
 settings-persist-filters-enabled = Oui
+settings-remember-view-label = Mémoriser la vue de chaque image
+settings-remember-view-hint = Restaurer le zoom, la position de défilement et la rotation d'une image quand on y revient pendant la session.
+settings-remember-view-disabled = Non
+settings-remember-view-enabled = Oui
+settings-minimap-label = Mini-carte
+settings-minimap-hint = Afficher l'image entière dans un coin quand elle est agrandie, avec la partie visible encadrée. Cliquer ou glisser dedans pour se déplacer.
+settings-minimap-disabled = Non
+settings-minimap-enabled = Oui
+settings-sandboxed-decoding-label = Décodage isolé
+settings-sandboxed-decoding-hint = Décoder les images dans un processus séparé, afin qu'un fichier endommagé ou malveillant ne puisse ni planter ni compromettre la visionneuse. Le chargement est un peu plus lent.
+settings-sandboxed-decoding-disabled = Non
+settings-sandboxed-decoding-enabled = Oui
+settings-webp-decoder-label = Décodeur WebP
+settings-webp-decoder-hint = Bibliothèque utilisée pour les images WebP fixes. Si elle ne peut pas lire un fichier, l'autre est essayée automatiquement.
+settings-webp-decoder-image-rs = image-rs
+settings-webp-decoder-libwebp = libwebp
 settings-overlay-timeout-label = Délai de masquage automatique en plein écran
 settings-overlay-timeout-hint = Durée avant la disparition des contrôles en mode plein écran.
+settings-overlay-shown = Affiché
+settings-overlay-hidden = Masqué
+settings-overlay-filename-label = Nom du fichier
+settings-overlay-index-label = Position dans le dossier (12/340)
+settings-overlay-clock-label = Horloge
+settings-overlay-arrows-label = Flèches de navigation
+settings-overlay-histogram-label = Histogramme
+settings-overlay-position-label = Position de l'incrustation
+settings-overlay-position-hint = Emplacement du nom du fichier, de la position, de l'horloge et de l'histogramme.
+settings-overlay-position-top-left = En haut à gauche
+settings-overlay-position-top-center = En haut
+settings-overlay-position-top-right = En haut à droite
+settings-overlay-position-bottom-left = En bas à gauche
+settings-overlay-position-bottom-center = En bas
+settings-overlay-position-bottom-right = En bas à droite
+settings-overlay-opacity-label = Opacité de l'incrustation
 seconds = secondes
 image-editor-title = Éditeur d'image
 image-editor-back-to-viewer = Retour
+image-editor-recovery-prompt = Des modifications non enregistrées de cette image ont été récupérées d’une session précédente.
+image-editor-recovery-restore = Restaurer les modifications
+image-editor-recovery-discard = Ignorer
+image-editor-guides = Repères
+image-editor-zoom-fit = Ajuster
+image-editor-show-original = Voir l'original
+image-editor-compare-split = Avant/Après
 image-editor-cancel = Annuler
 image-editor-save = Enregistrer
 image-editor-save-as = Enregistrer sous...
@@ -114,6 +230,10 @@ image-editor-rotate-left-tooltip = Tourner l'image dans le sens antihoraire
 image-editor-flip-section-title = Retournement
 image-editor-flip-horizontal-tooltip = Retourner l'image horizontalement (miroir gauche-droite)
 image-editor-flip-vertical-tooltip = Retourner l'image verticalement (miroir haut-bas)
+image-editor-sidebar-collapse-tooltip = Masquer les outils
+image-editor-sidebar-expand-tooltip = Afficher les outils
+image-editor-navigate-previous-tooltip = Image précédente
+image-editor-navigate-next-tooltip = Image suivante
 image-editor-resize-section-title = Redimensionner
 image-editor-resize-scale-label = Échelle
 image-editor-resize-dimensions-label = Dimensions cibles
@@ -130,10 +250,42 @@ image-editor-resize-ai-model-validating = Validation du modèle IA
 image-editor-resize-ai-model-error = Erreur du modèle IA
 image-editor-resize-ai-enlargement-only = L'upscaling IA ne s'applique qu'aux agrandissements
 image-editor-light-section-title = Ajustements de lumière
+image-editor-auto-enhance = Amélioration auto
+image-editor-auto-enhance-strength-label = Intensité
+image-editor-auto-enhance-neutral = Cette image est déjà bien équilibrée.
 image-editor-light-brightness-label = Luminosité
 image-editor-light-contrast-label = Contraste
+image-editor-grayscale-section-title = Noir et blanc
+image-editor-grayscale-enable = Convertir en noir et blanc
+image-editor-grayscale-preset-neutral = Neutre
+image-editor-grayscale-preset-red = Rouge
+image-editor-grayscale-preset-green = Vert
+image-editor-grayscale-preset-blue = Bleu
+image-editor-grayscale-red-label = Canal rouge
+image-editor-grayscale-green-label = Canal vert
+image-editor-grayscale-blue-label = Canal bleu
+image-editor-grayscale-total = Total : { $total } % (100 % conserve la luminosité globale)
+image-editor-sharpen-section-title = Netteté
+image-editor-sharpen-amount-label = Intensité
+image-editor-sharpen-radius-label = Rayon
+image-editor-sharpen-threshold-label = Seuil
 image-editor-light-reset = Réinitialiser
 image-editor-light-apply = Appliquer
+image-editor-light-copy = Copier les réglages
+image-editor-light-paste = Coller les réglages
+image-editor-light-paste-folder = Coller sur toutes les images du dossier
+image-editor-tool-filters = Filtres
+image-editor-filter-black-and-white = Noir et blanc
+image-editor-filter-noir = Film noir
+image-editor-filter-sepia = Sépia
+image-editor-filter-vignette = Vignettage
+image-editor-filter-warm-film = Pellicule chaude
+image-editor-filter-cool-film = Pellicule froide
+image-editor-filter-faded = Délavé
+image-editor-filter-vivid = Éclatant
+image-editor-filter-strength-label = Intensité
+image-editor-filter-hint = Choisissez un rendu pour le prévisualiser sur l'image.
+image-editor-filter-apply = Appliquer le filtre
 image-editor-crop-section-title = Rogner
 image-editor-crop-ratio-label = Ratio d'aspect
 image-editor-crop-ratio-free = Libre
@@ -143,38 +295,267 @@ image-editor-crop-ratio-portrait = Portrait (9:16)
 image-editor-crop-ratio-photo = Photo (4:3)
 image-editor-crop-ratio-photo-portrait = Photo Portrait (3:4)
 image-editor-crop-apply = Appliquer le rognage
+image-editor-crop-face = Recadrer sur un visage
+image-editor-crop-face-detecting = Détection des visages…
+image-editor-crop-face-none = Aucun visage trouvé dans cette image.
 image-editor-undo-redo-section-title = Dernière modification
 image-editor-undo = Annuler
 image-editor-redo = Rétablir
+image-editor-history-title = Historique
+image-editor-history-steps-title = Étapes
+image-editor-history-original = Image d'origine
+image-editor-snapshots-title = Instantanés
+image-editor-snapshot-name-placeholder = Nom de l'instantané
+image-editor-snapshot-save = Enregistrer
+image-editor-snapshot-delete-tooltip = Supprimer l’instantané
+image-editor-snapshot-unnamed = Après l'étape { $step }
+image-editor-history-rotate-left = Rotation à gauche
+image-editor-history-rotate-right = Rotation à droite
+image-editor-history-flip-horizontal = Retournement horizontal
+image-editor-history-flip-vertical = Retournement vertical
+image-editor-history-crop = Recadrage
+image-editor-history-resize = Redimensionnement
+image-editor-history-upscale = Agrandissement IA
+image-editor-history-brightness = Luminosité
+image-editor-history-contrast = Contraste
+image-editor-history-auto-enhance = Amélioration automatique
+image-editor-history-black-white = Noir et blanc
+image-editor-history-sharpen = Netteté
+image-editor-history-look = Filtre
+image-editor-history-deblur = Défloutage IA
+image-editor-history-background = Suppression de l'arrière-plan
+image-editor-history-denoise = Réduction du bruit
+image-editor-history-plugin = Outil d'extension
 image-editor-export-format-label = Format d'export
+image-editor-proof-label = Épreuve couleur
+image-editor-proof-none = Aucun
+image-editor-proof-convert = Convertir les couleurs vers le profil
+image-editor-proof-convert-tooltip = Désactivé : le profil est seulement joint et les valeurs des pixels sont conservées. Activé : les couleurs sont converties pour rester identiques dans l'espace cible.
 media-loading = Chargement...
 settings-video-autoplay-label = Lecture automatique des vidéos
 settings-video-autoplay-enabled = Activée
 settings-video-autoplay-disabled = Désactivée
 settings-video-autoplay-hint = Lorsque activée, les vidéos démarrent automatiquement à l'ouverture.
+settings-video-resume-label = Reprise des vidéos
+settings-video-resume-hint = Les vidéos rouvertes peuvent reprendre là où vous les avez laissées.
+settings-video-resume-ask = Demander
+settings-video-resume-always = Toujours
+settings-video-resume-never = Jamais
+settings-tone-mapping-label = Mappage tonal HDR
+settings-tone-mapping-hint = Comment les hautes lumières des vidéos HDR10 et HLG sont ramenées dans la plage de l’écran.
+settings-tone-mapping-clip = Écrêter
+settings-tone-mapping-reinhard = Reinhard
+settings-tone-mapping-hable = Filmique (Hable)
+settings-tone-mapping-aces = ACES
+settings-audio-device-label = Sortie audio
+settings-audio-device-hint = Périphérique qui joue le son des vidéos. Avec celui par défaut du système, la lecture passe au nouveau périphérique par défaut quand un périphérique est débranché ou changé.
+settings-audio-device-default = Par défaut du système
+settings-audio-device-missing = { $id } (non connecté)
 video-play-tooltip = Lecture (Espace)
 video-pause-tooltip = Pause (Espace)
 video-mute-tooltip = Couper le son (M)
+video-volume-boost-tooltip = Amplifié au-delà de 100 %, mémorisé pour ce fichier
 video-unmute-tooltip = Remettre le son (M)
 video-no-audio-tooltip = Aucune piste audio
 video-loop-tooltip = Boucle
+video-loop-in-tooltip = Début de la boucle A–B ici
+video-loop-out-tooltip = Fin de la boucle A–B ici
+video-loop-clear-tooltip = Supprimer la boucle A–B
+video-export-clip = Exporter l'extrait
+video-export-clip-tooltip = Exporter la boucle A–B dans un nouveau fichier vidéo
+video-export-clip-progress = Export… { $percent } %
+video-export-clip-cancel-tooltip = Annuler l'export de l'extrait
+video-export-frames = Exporter des images
+video-export-frames-tooltip = Exporter une plage d'images en fichiers, en GIF ou en animation WebP
+video-frames-range-loop = Boucle A–B
+video-frames-range-around = { $seconds } s autour de la tête de lecture
+video-frames-range-tooltip = Plage exportée en l'absence de boucle A–B
+video-frames-format-sequence = Séquence PNG
+video-frames-format-gif = GIF animé
+video-frames-format-webp = WebP animé
+video-frames-format-tooltip = Format de sortie
+video-frames-fps = { $fps } i/s
+video-frames-fps-tooltip = Images par seconde
+video-frames-scale-tooltip = Taille de sortie
+video-frames-export = Exporter…
+video-frames-export-tooltip = Choisir où enregistrer les images et les exporter
+video-frames-cancel-tooltip = Annuler l'export des images
+video-frames-mode-range = Plage
+video-frames-mode-burst = Rafale
+video-frames-mode-tooltip = Exporter une plage en un fichier, ou une rafale d’images séparées
+video-frames-burst-count = { $count } images
+video-frames-burst-count-tooltip = Images capturées autour de la tête de lecture
+video-frames-burst-interval = toutes les { $ms } ms
+video-frames-burst-interval-tooltip = Temps entre deux images
+video-frames-burst-tooltip = Choisir un dossier et y enregistrer chaque image en PNG pleine taille
 video-capture-tooltip = Capturer l'image actuelle
 video-step-forward-tooltip = Avancer d'une image (.)
 video-step-backward-tooltip = Reculer d'une image (,)
 video-more-tooltip = Plus d'options
 video-speed-down-tooltip = Réduire la vitesse (J)
 video-speed-up-tooltip = Augmenter la vitesse (L)
+video-speed-selector-tooltip = Vitesse de lecture (le son garde sa hauteur de 0,25x à 3x)
+video-audio-visualization-off = Bande audio : désactivée
+video-audio-visualization-waveform = Bande audio : forme d'onde
+video-audio-visualization-vu-meter = Bande audio : VU-mètre
+video-audio-visualization-tooltip = Afficher la forme d'onde ou un indicateur de niveau sous la barre de lecture
+video-adjust-button = Réglages
+video-adjust-tooltip = Luminosité, contraste, saturation et gamma de la vidéo
+video-adjust-brightness = Luminosité
+video-adjust-contrast = Contraste
+video-adjust-saturation = Saturation
+video-adjust-gamma = Gamma
+video-adjust-reset = Réinitialiser
+video-adjust-reset-tooltip = Rétablir les couleurs d'origine
+video-mini-player = Mini-lecteur
+video-mini-player-tooltip = Continuer la lecture dans une petite fenêtre au premier plan
+pip-play-tooltip = Lecture
+pip-pause-tooltip = Pause
+pip-return-tooltip = Revenir à la fenêtre principale
+pip-close-tooltip = Fermer le mini-lecteur
+video-frame-counter = Image { $current } / { $total }
+video-fine-scrub-frame = Précis : 1 image
+video-fine-scrub-tenth = Précis : 0,1 s
+video-resume-prompt = Reprendre à { $position } ?
+video-resume-button = Reprendre
+video-resume-start-over-button = Recommencer
+video-chapter-previous-tooltip = Chapitre précédent (PgUp)
+video-chapter-next-tooltip = Chapitre suivant (PgDn)
+video-chapter-list-tooltip = Chapitres
+video-subtitles-off = Sous-titres désactivés
+video-decode-hardware = Décodage GPU ({ $backend })
+video-decode-software = Décodage CPU
+video-cache-stats = Cache d'images : { $rate } % de succès, { $frames } images, { $size } Mo
+video-subtitles-track-tooltip = Piste de sous-titres
+video-subtitles-delay-tooltip = Décalage des sous-titres (cliquer pour réinitialiser)
+video-subtitles-size-tooltip = Taille des sous-titres
+video-subtitles-background = Fond
+video-subtitle-size-small = Petit
+video-subtitle-size-medium = Moyen
+video-subtitle-size-large = Grand
 hud-video-no-audio = Pas de son
 settings-audio-normalization-label = Normalisation du volume audio
 settings-audio-normalization-enabled = Activée
 settings-audio-normalization-disabled = Désactivée
 settings-audio-normalization-hint = Nivelle automatiquement le volume entre les différents médias pour éviter les changements brusques de volume.
+settings-hardware-decoding-label = Décodage vidéo matériel
+settings-hardware-decoding-enabled = Activé
+settings-hardware-decoding-disabled = Désactivé
+settings-hardware-decoding-hint = Décode les vidéos sur la carte graphique (VA-API, D3D11VA/DXVA2 ou VideoToolbox) pour une lecture 4K fluide, avec repli sur le processeur si non pris en charge. S'applique à la prochaine vidéo ouverte.
+settings-file-label = Fichier de paramètres
+settings-file-hint = Les modifications de settings.toml faites dans un éditeur de texte s’appliquent en quelques secondes. L’export copie les paramètres dans un autre fichier ; l’import les remplace par ceux d’un fichier.
+settings-file-import = Importer…
+settings-file-export = Exporter…
+settings-check-updates-label = Mises à jour
+settings-check-updates-hint = Rechercher une version plus récente sur GitHub. Rien n’est téléchargé ni installé.
+settings-check-updates-manual = Manuellement
+settings-check-updates-weekly = Chaque semaine
+settings-check-updates-now = Vérifier maintenant
+settings-mouse-wheel-label = Molette au-dessus du média
+settings-mouse-wheel-zoom = Zoom
+settings-mouse-wheel-navigate = Précédent / suivant
+settings-mouse-wheel-volume = Volume de la vidéo
+settings-mouse-wheel-hint = Volume de la vidéo règle le volume pendant la lecture d'une vidéo et zoome les images.
+settings-mouse-drag-button-label = Bouton pour déplacer le média zoomé
+settings-mouse-drag-button-hint = Le double-clic gauche bascule toujours le plein écran.
+settings-mouse-button-left = Gauche
+settings-mouse-button-middle = Milieu
+settings-mouse-button-right = Droit
+settings-mouse-middle-click-label = Clic du milieu
+settings-mouse-middle-click-toggle-fit = Basculer l'ajustement à la fenêtre
+settings-mouse-middle-click-none = Rien
+settings-mouse-middle-click-hint = Ignoré quand le bouton du milieu déplace le média.
+settings-mouse-navigation-buttons-label = Boutons précédent et suivant
+settings-mouse-navigation-buttons-enabled = Média précédent / suivant
+settings-mouse-navigation-buttons-disabled = Ignorés
+settings-mouse-navigation-buttons-hint = Les boutons latéraux de la souris parcourent le dossier comme les flèches.
+settings-shortcuts-hint = Cliquez sur un raccourci, puis appuyez sur la nouvelle touche. Échap annule.
+settings-shortcuts-press-key = Appuyez sur une touche…
+settings-shortcuts-reset = Tout réinitialiser
+settings-shortcuts-conflict = { $other } utilise déjà cette touche ; { $action } garde son raccourci.
+settings-shortcuts-reserved = Cette touche ne peut pas être attribuée ; { $action } garde son raccourci.
+settings-shortcuts-group-viewer = Visionneuse
+settings-shortcuts-group-video = Vidéo
+settings-shortcuts-group-editor = Éditeur
+settings-plugins-hint = Les extensions sont des dossiers contenant un fichier plugin.toml dans le dossier des extensions. Elles s'exécutent comme des programmes séparés.
+settings-plugins-empty = Aucune extension installée.
+settings-plugins-invalid = plugin.toml invalide : { $error }
+settings-plugins-hooks = Fournit : { $hooks }
+settings-plugins-hook-media-loaded = action à l'ouverture d'un média
+settings-plugins-hook-editor-tool = outils d'édition
+settings-plugins-hook-export-format = formats d'export
+settings-plugins-enabled = Activée
+settings-plugins-disabled = Désactivée
+settings-plugins-open-folder = Ouvrir le dossier des extensions
+settings-plugins-reload = Recharger les extensions
+shortcut-action-enter-editor = Modifier l'image
+shortcut-action-toggle-info-panel = Afficher / masquer le panneau d'informations
+shortcut-action-toggle-fullscreen = Basculer le plein écran
+shortcut-action-rotate-clockwise = Pivoter dans le sens horaire
+shortcut-action-rotate-counterclockwise = Pivoter dans le sens antihoraire
+shortcut-action-mirror = Miroir
+shortcut-action-next-matte = Fond suivant
+shortcut-action-previous-matte = Fond précédent
+shortcut-action-toggle-alpha = Afficher / masquer la transparence
+shortcut-action-play-sequence = Lire en séquence
+shortcut-action-export-view = Exporter la vue
+shortcut-action-copy-view = Copier la vue
+shortcut-action-quick-search = Recherche rapide
+shortcut-action-next-face = Zoomer sur le visage suivant
+shortcut-action-previous-face = Zoomer sur le visage précédent
+shortcut-action-toggle-face-overlay = Afficher les visages détectés
+shortcut-action-next-similar = Image similaire suivante
+shortcut-action-previous-similar = Image similaire précédente
+shortcut-action-toggle-osd = Afficher / masquer l'affichage à l'écran
+shortcut-action-toggle-pixel-inspector = Activer / désactiver l'inspecteur de pixels
+shortcut-action-toggle-ruler = Activer / désactiver la règle
+shortcut-action-toggle-guides = Afficher / masquer les repères de composition
+shortcut-action-play-pause = Lecture / pause
+shortcut-action-mute = Couper / rétablir le son
+shortcut-action-step-backward = Image précédente
+shortcut-action-step-forward = Image suivante
+shortcut-action-speed-down = Ralentir
+shortcut-action-speed-up = Accélérer
+shortcut-action-loop-start = Définir le début de la boucle
+shortcut-action-loop-end = Définir la fin de la boucle
+shortcut-action-loop-clear = Effacer la boucle
+shortcut-action-save = Enregistrer
+shortcut-action-undo = Annuler
+shortcut-action-redo = Rétablir
+settings-frame-cache-auto-label = Taille du cache d'images
+settings-frame-cache-auto-enabled = Automatique
+settings-frame-cache-auto-disabled = Manuelle
+settings-frame-cache-auto-hint = En mode automatique, le cache et l'historique d'images sont dimensionnés selon la résolution de la vidéo et la mémoire disponible.
 settings-frame-cache-label = Taille du cache de keyframes (pour la navigation)
 settings-frame-cache-hint = Met en cache les images-clés (keyframes) pour accélérer la navigation dans la timeline et les sauts à un moment précis. Des valeurs plus élevées stockent plus de keyframes pour une navigation plus fluide. Les changements s'appliquent à l'ouverture d'une nouvelle vidéo.
 settings-frame-history-label = Taille de l'historique (pour reculer image par image)
 settings-frame-history-hint = Conserve les images récemment affichées pour permettre de reculer image par image. Utilisée uniquement lors du défilement manuel des images, pas pendant la lecture normale.
 settings-keyboard-seek-step-label = Pas de navigation au clavier
 settings-keyboard-seek-step-hint = Durée à sauter avec les touches fléchées pendant la lecture vidéo.
+settings-sequence-fps-label = Fréquence des séquences d'images
+settings-sequence-fps-hint = Vitesse utilisée pour lire des images numérotées (frame_0001.png, frame_0002.png…) comme une vidéo avec S.
+settings-frame-name-template-label = Modèle de nom des images capturées
+settings-frame-name-template-hint = Nom par défaut des images capturées. Jetons : {"{name}"}, {"{date}"}, {"{index}"}, {"{timestamp}"}, {"{w}"}, {"{h}"}.
+settings-save-policy-label = À l’enregistrement
+settings-save-policy-hint = Où l’enregistrement écrit les images modifiées. Les copies laissent l’original intact ; les images capturées sont proposées dans le même dossier.
+settings-save-policy-overwrite = Écraser
+settings-save-policy-copy = Copie numérotée
+settings-save-policy-subfolder = Sous-dossier
+settings-edited-folder-label = Nom du sous-dossier
+settings-edited-folder-hint = Créé à côté de l’image originale lors de l’enregistrement.
+settings-guides-thirds-label = Règle des tiers
+settings-guides-thirds-hint = Deux lignes sur chaque côté, au tiers et aux deux tiers.
+settings-guides-golden-ratio-label = Nombre d'or
+settings-guides-golden-ratio-hint = Deux lignes sur chaque côté, aux sections dorées (38,2 % et 61,8 %).
+settings-guides-safe-areas-label = Zones de sécurité
+settings-guides-safe-areas-hint = Cadres pointillés de sécurité action (93 %) et titre (90 %).
+settings-guides-pixel-grid-label = Grille de pixels
+settings-guides-pixel-grid-hint = Entoure chaque pixel à partir d'un zoom de 800 %.
+settings-guides-horizontal-label = Lignes horizontales personnalisées
+settings-guides-vertical-label = Lignes verticales personnalisées
+settings-guides-positions-hint = Positions en pourcentage depuis le bord supérieur ou gauche, séparées par des virgules.
+settings-guides-positions-invalid = Saisissez des nombres de 0 à 100, par exemple 25, 50, 75.
+settings-sequence-fps-value = { $fps } i/s
 megabytes = Mo
 error-load-video-heading = Impossible de lire cette vidéo.
 error-load-video-general = Une erreur est survenue lors du chargement de la vidéo.
@@ -189,9 +570,35 @@ error-video-seek-timeout = La recherche de position a expiré. La position cible
 
 # Barre de navigation
 menu-settings = Paramètres
+menu-open-in-new-window = Ouvrir dans une nouvelle fenêtre…
 menu-help = Aide
 menu-about = À propos
+menu-verify-folder = Vérifier le dossier
+menu-share-over-lan = Partager sur le réseau local
+menu-stop-sharing = Arrêter le partage
+menu-strip-metadata = Supprimer toutes les métadonnées
+menu-always-on-top = Toujours au premier plan
+menu-borderless = Fenêtre sans bordure
+menu-compact-mode = Mode compact (Échap pour quitter)
+menu-open-with = Ouvrir avec / Envoyer vers
+share-panel-title = Partager sur le réseau local
+share-panel-hint = { $count } fichiers sont partagés. Scannez le code ou ouvrez cette adresse sur un appareil connecté au même réseau :
+share-panel-read-only = Les visiteurs peuvent voir et télécharger les fichiers, pas les modifier.
+share-panel-copy = Copier l’adresse
+share-panel-stop = Arrêter le partage
+share-panel-close = Fermer
+diagnostics-title = Diagnostics
+diagnostics-decode-fps = Images décodées par seconde
+diagnostics-dropped-frames = Images sautées
+diagnostics-frame-queue = File d’images
+diagnostics-frame-cache = Succès du cache d’images vidéo
+diagnostics-prefetch-cache = Succès du cache de préchargement
+diagnostics-memory = Mémoire utilisée
+diagnostics-recent-loads = Derniers temps de chargement
+diagnostics-load-failed = échec après { $ms } ms
+diagnostics-unavailable = —
 navbar-edit-button = Éditer
+navbar-menu-tooltip = Menu
 
 # Écran d'aide
 help-title = Aide
@@ -221,7 +628,7 @@ help-viewer-tool-fullscreen-desc = Vue immersive avec masquage automatique des c
 help-viewer-tool-delete = Supprimer
 help-viewer-tool-delete-desc = Supprime définitivement le fichier (déplacé vers la corbeille si disponible).
 help-viewer-tool-filter = Filtre
-help-viewer-tool-filter-desc = Afficher uniquement les fichiers correspondants. Filtrer par type, orientation ou plage de dates.
+help-viewer-tool-filter-desc = Afficher uniquement les fichiers correspondants. Filtrer par type, orientation, plage de dates ou note minimale.
 
 help-viewer-key-navigate = Passer au fichier précédent/suivant
 help-viewer-key-edit = Ouvrir l'image dans l'éditeur
@@ -230,6 +637,26 @@ help-viewer-key-exit-fullscreen = Quitter le mode plein écran
 help-viewer-key-info = Afficher/masquer le panneau d'informations
 help-viewer-key-rotate-cw = Rotation horaire
 help-viewer-key-rotate-ccw = Rotation antihoraire
+help-viewer-key-mirror = Retourner la vidéo horizontalement
+help-viewer-key-matte = Changer la couleur de fond des zones transparentes
+help-viewer-key-alpha = Basculer l'aperçu alpha direct/prémultiplié
+help-viewer-key-sequence = Lire la séquence d'images numérotées comme une vidéo
+help-viewer-key-export-view = Enregistrer la vue telle qu'affichée en PNG
+help-viewer-key-copy-view = Copier la vue telle qu'affichée dans le presse-papiers
+help-viewer-key-quick-search = Rechercher un fichier du dossier par son nom
+help-viewer-key-quick-search-cycle = Aller au résultat de recherche suivant / précédent
+help-viewer-key-capture-day = Aller au jour de prise de vue suivant / précédent
+help-viewer-key-face-zoom = Zoomer sur le visage détecté suivant / précédent
+help-viewer-key-face-overlay = Encadrer les visages détectés
+help-viewer-key-similar = Trouver similaire : aller à l'image ressemblante suivante / précédente (rafales)
+help-viewer-key-osd = Afficher / masquer l'affichage à l'écran
+help-viewer-key-pixel-inspector = Inspecteur de pixels : couleur sous le curseur, cliquer pour la copier
+help-viewer-key-ruler = Règle : faire glisser sur l'image pour mesurer une distance et un angle
+help-viewer-key-ruler-undo = Supprimer la dernière mesure (règle active)
+help-viewer-key-guides = Afficher / masquer les repères de composition
+help-viewer-key-layout = Passer à la disposition 1–3 (tri, présentation, personnalisée)
+help-viewer-key-layout-save = Enregistrer les panneaux actuels dans la disposition 1–3
+help-viewer-key-diagnostics = Afficher / masquer les diagnostics de performance
 
 help-mouse-title = Interactions souris
 help-viewer-mouse-doubleclick = Double-clic sur l'image/vidéo pour basculer en plein écran
@@ -247,7 +674,7 @@ help-video-tool-playback-desc = Démarrez ou arrêtez la lecture avec le bouton
 help-video-tool-timeline = Timeline
 help-video-tool-timeline-desc = Cliquez n'importe où sur la barre de progression pour sauter à cette position.
 help-video-tool-volume = Volume
-help-video-tool-volume-desc = Glissez le curseur de volume (0-150%) ou cliquez sur l'icône haut-parleur pour couper/remettre le son.
+help-video-tool-volume-desc = Glissez le curseur de volume (0-200%) ou cliquez sur l'icône haut-parleur pour couper/remettre le son.
 help-video-tool-loop = Boucle
 help-video-tool-loop-desc = Activez pour redémarrer automatiquement la vidéo à la fin.
 help-video-tool-stepping = Navigation image par image
@@ -258,11 +685,15 @@ help-video-tool-capture-desc = Enregistrez l'image vidéo actuelle comme fichier
 help-video-key-playpause = Lire ou mettre en pause la vidéo
 help-video-key-mute = Activer/désactiver le son
 help-video-key-seek = Avancer/reculer dans la vidéo (pendant la lecture)
+help-video-key-fine-seek = Déplacement précis : une image en pause, 0,1 s en lecture (maintenez Maj en faisant glisser la barre de lecture pour la parcourir lentement)
 help-video-key-volume = Augmenter/diminuer le volume
 help-video-key-step-back = Reculer d'une image (en pause)
 help-video-key-step-forward = Avancer d'une image (en pause)
 help-video-key-speed-down = Réduire la vitesse de lecture
 help-video-key-speed-up = Augmenter la vitesse de lecture
+help-video-key-chapter = Aller au chapitre suivant / précédent (vidéos avec chapitres)
+help-video-key-loop-points = Définir le début / la fin de la boucle A–B à la position actuelle
+help-video-key-loop-clear = Supprimer la boucle A–B
 
 # ─────────────────────────────────────────────────────────────────────────────
 # Section Éditeur d'images
@@ -293,9 +724,22 @@ help-editor-resize-ai-upscale = Upscaling IA : Utilisez Real-ESRGAN pour des agr
 
 help-editor-light-title = Lumière
 help-editor-light-desc = Ajustez la luminosité et le contraste de votre image.
+help-editor-light-auto = Amélioration auto : analyse l'histogramme pour corriger niveaux, contraste et balance des blancs en une étape ; l'intensité mélange la correction avec l'original
 help-editor-light-brightness = Luminosité : éclaircir ou assombrir l'image
 help-editor-light-contrast = Contraste : augmenter ou réduire la différence entre zones claires et sombres
+help-editor-light-grayscale = Noir et blanc : un mélangeur de couches règle la part de chaque couleur dans le gris ; les préréglages Rouge, Vert et Bleu imitent les filtres colorés de la photo argentique
+help-editor-light-sharpen = Netteté : un masque flou dont le rayon règle la taille des contours renforcés, et le seuil épargne les zones unies et le bruit
+help-editor-light-copy = Copier/coller les réglages : copie les rotations, retournements, recadrages, redimensionnements et réglages de lumière de l’image, conservés après un redémarrage ; le collage les applique à une autre image, en adaptant recadrages et redimensionnements à sa taille, ou à toutes les images du dossier, enregistrées en copies qui ne remplacent jamais les originaux, après confirmation
 help-editor-light-preview = Les modifications sont prévisualisées en temps réel avant application
+help-editor-filters-title = Filtres
+help-editor-filters-desc = Donnez un style à votre image en un clic : noir et blanc, sépia, vignettage, tons pellicule, etc.
+help-editor-filters-preview = Cliquez sur un rendu pour le prévisualiser, et à nouveau pour le retirer
+help-editor-filters-strength = L'intensité mélange le rendu avec l'image d'origine
+help-editor-filters-undo = Un rendu appliqué est une seule étape, annulable
+help-editor-history-title = Historique
+help-editor-history-desc = Revenez à n'importe quel état antérieur de vos retouches, au-delà d'une simple annulation.
+help-editor-history-steps = Cliquez sur une étape pour y revenir ; les étapes suivantes restent listées et peuvent être rétablies jusqu'à la prochaine retouche
+help-editor-history-snapshots = Enregistrez un instantané pour nommer l'état actuel (« après recadrage ») et y revenir plus tard ; les instantanés durent jusqu'à l'enregistrement de l'image
 
 help-editor-save-title = Enregistrement
 help-editor-save-overwrite = Enregistrer : écrase le fichier original
@@ -305,10 +749,15 @@ help-editor-key-save = Enregistrer les modifications
 help-editor-key-undo = Annuler la dernière modification
 help-editor-key-redo = Rétablir la modification annulée
 help-editor-key-cancel = Annuler toutes les modifications et quitter
+help-editor-key-show-original = Afficher l'image d'origine tant que la touche est maintenue
 
 help-editor-mouse-title = Contrôles souris
 help-editor-mouse-wheel = Zoomer ou dézoomer l'image
 help-editor-mouse-drag = Déplacer l'image lorsque zoomée
+help-editor-mouse-space-drag = Déplacer l'image par-dessus les poignées de recadrage et le pinceau d'affinage
+help-editor-mouse-space-drag-label = Espace + glisser
+help-editor-mouse-fit = Le bouton Ajuster bascule le canevas entre l'ajustement à la fenêtre et 100 %
+help-editor-compare = Maintenez Voir l'original pour afficher l'image intacte, ou utilisez Avant/Après pour partager le canevas : l'original à gauche du séparateur, vos retouches à droite
 
 # ─────────────────────────────────────────────────────────────────────────────
 # Section Capture d'image vidéo
@@ -378,16 +827,73 @@ about-third-party-details = Voir THIRD_PARTY_LICENSES.md pour les détails
 about-section-links = Liens
 about-link-repository = Code source
 about-link-issues = Signaler un problème
+about-section-troubleshooting = Dépannage
+about-troubleshooting-hint = Pour signaler un problème, joignez le rapport de diagnostic : il indique les versions du système, de la carte graphique et de FFmpeg ainsi que les dernières lignes du journal.
+about-view-logs-button = Voir les journaux
+about-copy-report-button = Copier le rapport de diagnostic
+about-missing-translations-button = Traductions manquantes
+logs-title = Journaux
+logs-folder-unavailable = Le dossier des journaux n’a pas pu être déterminé.
+logs-refresh = Actualiser
+logs-open-folder = Ouvrir le dossier des journaux
+logs-copy-report = Copier le rapport de diagnostic
+logs-empty = Rien n’a encore été journalisé.
+translations-title = Traductions manquantes
+translations-summary = { $count } clés de { $locale } sont affichées en anglais. Les traductions sont chargées depuis { $dir }.
+translations-copy-keys = Copier les clés
+translations-complete = Toutes les clés sont traduites.
+welcome-title = Bienvenue dans IcedLens
+welcome-preferences-intro = Choisissez votre langue et votre thème. Tout se modifie plus tard dans les paramètres.
+welcome-default-viewer-title = Visionneuse d’images par défaut
+welcome-default-viewer-intro = Ouvrez les images dans IcedLens en double-cliquant dessus dans votre gestionnaire de fichiers.
+welcome-default-viewer-button = Définir comme visionneuse par défaut
+welcome-default-viewer-working = Enregistrement…
+welcome-default-viewer-done = IcedLens ouvre désormais vos images.
+welcome-default-viewer-confirm = Choisissez IcedLens pour les images dans les paramètres Applications par défaut qui viennent de s’ouvrir.
+welcome-default-viewer-failed = Impossible d’enregistrer IcedLens : { $error }
+welcome-tour-progress = Astuce { $current } sur { $total }
+welcome-tour-try = Essayez : appuyez sur { $key }
+welcome-tour-tried = Bien joué !
+welcome-tour-footer = Tous les raccourcis sont listés dans l’aide et se modifient dans les paramètres.
+welcome-tour-navigate-title = Parcourir le dossier
+welcome-tour-navigate-body = Affiche le fichier suivant ou précédent du dossier. La molette zoome et le glisser déplace l’image.
+welcome-tour-fullscreen-title = Passer en plein écran
+welcome-tour-fullscreen-body = Les commandes se masquent après un instant ; bougez la souris pour les faire revenir. Échap quitte le plein écran.
+welcome-tour-info-title = Voir les détails
+welcome-tour-info-body = Le panneau d’informations affiche les métadonnées d’appareil, d’exposition et vidéo, et modifie titres, mots-clés et dates.
+welcome-tour-editor-title = Modifier l’image
+welcome-tour-editor-body = Pivotez, recadrez, redimensionnez et réglez la lumière. Rien n’est écrit avant l’enregistrement.
+welcome-tour-video-title = Lire les vidéos
+welcome-tour-video-body = Lit et met en pause les vidéos. En pause, la virgule et le point avancent image par image.
+welcome-skip = Passer
+welcome-back = Retour
+welcome-next = Suivant
+welcome-finish = Commencer avec IcedLens
 
 # Notifications
 notification-save-success = Image enregistrée avec succès
+notification-save-copy-success = Image enregistrée sous { $file }
 notification-save-error = Échec de l'enregistrement de l'image
 notification-frame-capture-success = Image capturée avec succès
 notification-frame-capture-error = Échec de la capture d'image
 notification-delete-success = Fichier supprimé avec succès
 notification-delete-error = Échec de la suppression du fichier
 notification-config-save-error = Échec de l'enregistrement des paramètres
+notification-theme-save-error = Impossible d'enregistrer le fichier de thème
+notification-config-merged = Les paramètres modifiés dans une autre fenêtre ont été conservés
 notification-config-load-error = Échec du chargement des paramètres, valeurs par défaut utilisées
+notification-config-reloaded = Paramètres rechargés depuis settings.toml
+notification-translations-reloaded = Traductions rechargées, { $count } clés manquantes
+notification-config-reload-error = settings.toml est invalide, modifications non appliquées : { $error }
+notification-settings-imported = Paramètres importés
+notification-settings-import-error = Échec de l’import des paramètres : { $error }
+notification-settings-exported = Paramètres exportés
+notification-settings-export-error = Échec de l’export des paramètres : { $error }
+notification-update-available = IcedLens { $version } est disponible.
+    { $notes }
+notification-update-download = Ouvrir la page de téléchargement
+notification-update-none = IcedLens { $version } est à jour.
+notification-update-check-error = Impossible de vérifier les mises à jour : { $error }
 notification-state-parse-error = Échec de lecture de l'état, valeurs par défaut utilisées
 notification-state-read-error = Impossible d'ouvrir le fichier d'état
 notification-state-path-error = Impossible de déterminer le chemin des données
@@ -400,6 +906,18 @@ notification-editor-create-error = Impossible d'ouvrir l'éditeur d'images
 notification-editor-load-error = Impossible de charger l'image pour l'édition
 notification-video-editing-unsupported = L'édition vidéo n'est pas encore supportée
 
+# Unités
+unit-bytes = { $value } o
+unit-kilobytes = { $value } Ko
+unit-megabytes = { $value } Mo
+unit-gigabytes = { $value } Go
+unit-bits-per-second = { $value } bit/s
+unit-kilobits-per-second = { $value } kbit/s
+unit-megabits-per-second = { $value } Mbit/s
+unit-frames-per-second = { $value } i/s
+unit-nits = { $value } nits
+unit-nits-range = { $min }–{ $max } nits
+
 # Panneau de métadonnées
 metadata-panel-title = Informations du fichier
 metadata-panel-close = Fermer le panneau
@@ -413,6 +931,7 @@ metadata-section-gps = Localisation
 metadata-label-dimensions = Dimensions
 metadata-label-file-size = Taille du fichier
 metadata-label-format = Format
+metadata-label-decoder = Décodeur
 metadata-label-date-taken = Date de prise de vue
 metadata-label-camera = Appareil
 metadata-label-exposure = Exposition
@@ -420,11 +939,49 @@ metadata-label-aperture = Ouverture
 metadata-label-iso = ISO
 metadata-label-focal-length = Focale
 metadata-label-gps = Coordonnées
+metadata-label-gps-dms = DMS
+metadata-open-map-button = Ouvrir dans OpenStreetMap
+metadata-all-tags-title = Toutes les balises ({ $count })
+metadata-all-tags-filter-placeholder = Filtrer les balises…
+metadata-all-tags-no-match = Aucune balise ne correspond au filtre
+metadata-all-tags-copy-tooltip = Cliquer pour copier la valeur
+metadata-batch-title = Modification par lot
+metadata-stats-title = Statistiques du dossier
+metadata-stats-refresh = Recalculer les statistiques
+metadata-export-title = Exporter les métadonnées
+metadata-export-hint = Enregistrer en JSON ou CSV (selon l’extension) pour les catalogues et les scripts
+metadata-export-file = Ce fichier
+metadata-export-folder = Tout le dossier
+metadata-stats-loading = Lecture des données de prise de vue…
+metadata-stats-summary = { $count } fichiers, dont { $exif } avec données de prise de vue
+metadata-stats-empty = Aucune donnée de prise de vue dans ce dossier
+metadata-stats-cameras = Appareils
+metadata-stats-lenses = Objectifs
+metadata-stats-focal-lengths = Focale (équiv. 35 mm)
+metadata-stats-iso = ISO
+metadata-batch-hint = S'applique aux { $count } fichiers du dossier (ou du filtre actif). Les champs vides ne sont pas modifiés ; les vidéos sont ignorées.
+metadata-batch-creator-label = Définir l'auteur
+metadata-batch-copyright-label = Définir le copyright
+metadata-batch-keywords-label = Ajouter des mots-clés (séparés par des virgules)
+metadata-batch-strip-gps = Supprimer la position GPS
+metadata-batch-apply-button = Appliquer à { $count } fichiers
+metadata-batch-confirm-warning = { $count } fichiers originaux vont être modifiés. Continuer ?
+metadata-batch-confirm-button = Appliquer
+metadata-batch-running = Écriture des métadonnées…
 metadata-label-codec = Codec
 metadata-label-bitrate = Débit
 metadata-label-duration = Durée
 metadata-label-fps = Images/seconde
 metadata-value-unknown = Inconnu
+metadata-value-decoder-fallback = { $decoder } (solution de repli)
+metadata-label-bit-depth = Profondeur
+metadata-value-bit-depth = { $bits } bits
+metadata-label-hdr = HDR
+metadata-value-hdr-pq = HDR10 (PQ)
+metadata-value-hdr-hlg = HLG
+metadata-label-mastering-display = Écran de mastering
+metadata-label-max-cll = Lumière max. du contenu (MaxCLL)
+metadata-label-max-fall = Moyenne max. par image (MaxFALL)
 
 # Édition des métadonnées
 metadata-edit-button = Éditer
@@ -433,7 +990,28 @@ metadata-cancel-button = Annuler
 metadata-save-button = Enregistrer
 metadata-save-as-button = Enregistrer sous...
 metadata-save-warning = Enregistrer modifiera le fichier original
+metadata-gps-strip-pending = La position sera supprimée du fichier à l'enregistrement.
+metadata-strip-button = Supprimer toutes les métadonnées
+metadata-strip-dialog-title = Supprimer toutes les métadonnées
+metadata-strip-dialog-description = Les métadonnées suivantes seront supprimées. Les données de l'image ne sont pas modifiées.
+metadata-strip-dialog-overwrite = Écraser le fichier
+metadata-strip-dialog-copy = Enregistrer une copie nettoyée
+metadata-strip-dialog-cancel = Annuler
+recipe-batch-dialog-title = Coller sur toutes les images du dossier
+recipe-batch-dialog-description = Les réglages copiés seront appliqués à { $count } images.
+recipe-batch-dialog-copy = Chaque résultat est enregistré en copie numérotée à côté de son original. Les originaux ne sont pas modifiés.
+recipe-batch-dialog-subfolder = Les résultats sont enregistrés dans le sous-dossier « { $folder } », en remplaçant les versions modifiées qui s’y trouvent. Les originaux ne sont pas modifiés.
+recipe-batch-dialog-skipped = Les images animées et les formats impossibles à écrire sont ignorés.
+recipe-batch-dialog-paste = Coller
+recipe-batch-dialog-cancel = Annuler
+metadata-strip-block-exif = EXIF (appareil, date, position GPS)
+metadata-strip-block-xmp = XMP (titre, auteur, mots-clés)
+metadata-strip-block-iptc = IPTC (légende, copyright)
+metadata-strip-block-thumbnail = Miniature intégrée
+metadata-strip-block-comment = Commentaires et annotations texte
 metadata-label-make = Marque
+metadata-remove-field-tooltip = Retirer le champ
+metadata-remove-keyword-tooltip = Retirer le mot-clé
 metadata-label-model = Modèle
 metadata-label-focal-length-35mm = Focale (35mm)
 metadata-label-flash = Flash
@@ -455,7 +1033,47 @@ metadata-validation-invalid-number = Nombre invalide
 # Notifications de métadonnées
 notification-metadata-save-success = Métadonnées enregistrées avec succès
 notification-metadata-save-error = Impossible d'enregistrer les métadonnées
+notification-metadata-batch-started = Mise à jour des métadonnées de { $count } fichiers…
+notification-metadata-batch-success = Métadonnées mises à jour dans { $updated } fichiers ({ $skipped } ignorés)
+notification-metadata-batch-partial = Métadonnées mises à jour dans { $updated } fichiers, { $failed } en échec (premier : { $filename })
+notification-metadata-exported = Métadonnées de { $count } fichiers exportées
+notification-metadata-export-error = Impossible d’exporter les métadonnées : { $error }
+notification-plugins-reloaded = { $count } extensions chargées
+notification-plugins-folder-error = Impossible d'ouvrir le dossier des extensions : { $error }
+notification-logs-folder-error = Impossible d’ouvrir le dossier des journaux : { $error }
+notification-diagnostic-report-copied = Rapport de diagnostic copié dans le presse-papiers
+notification-plugin-tool-error = L'outil de l'extension a échoué : { $error }
+notification-plugin-export-success = { $filename } exporté
+notification-plugin-export-error = L'export de l'extension a échoué : { $error }
+notification-open-with-done = { $name } : terminé
+notification-open-with-output = { $name } : { $output }
+notification-open-with-error = { $name } a échoué : { $error }
+notification-verify-started = Vérification de { $count } fichiers…
+notification-verify-clean = Les { $count } fichiers sont intacts
+notification-verify-damaged = { $damaged } fichiers sur { $count } sont endommagés (liste copiée dans le presse-papiers)
+notification-share-empty = Aucun fichier à partager dans ce dossier
+notification-share-error = Impossible de partager sur le port { $port } : { $error }
+notification-share-stopped = Partage arrêté
+notification-share-url-copied = Adresse copiée dans le presse-papiers
+notification-strip-success = Toutes les métadonnées ont été supprimées
+notification-layout-applied = Disposition : { $name }
+notification-layout-saved = Disposition « { $name } » enregistrée
+layout-name-culling = Tri
+layout-name-presentation = Présentation
+layout-name-custom = Personnalisée
+notification-strip-copy-success = Copie nettoyée enregistrée sous { $filename }
+notification-strip-error = Impossible de supprimer les métadonnées : { $error }
+notification-strip-nothing = Ce fichier ne contient aucune métadonnée à supprimer
+notification-strip-unsupported = La suppression des métadonnées n'est pas prise en charge pour ce format
 notification-metadata-validation-error = Veuillez corriger les erreurs de validation avant d'enregistrer
+notification-open-url-error = Impossible d'ouvrir le lien : { $error }
+notification-copied-to-clipboard = Copié dans le presse-papiers
+notification-color-copied = Couleur { $color } copiée dans le presse-papiers
+notification-adjustments-copied = Réglages copiés (étapes : { $count })
+notification-dismiss-tooltip = Fermer
+notification-recipe-batch-started = Collage des réglages sur { $count } images…
+notification-recipe-batch-success = Réglages collés sur { $updated } images ({ $skipped } ignorées)
+notification-recipe-batch-partial = Réglages collés sur { $updated } images, { $failed } en échec (première : { $filename })
 notification-metadata-xmp-unsupported = Impossible d'enregistrer le titre, l'auteur et la description dans ce format de fichier
 
 # Divulgation progressive des métadonnées
@@ -468,6 +1086,7 @@ metadata-label-dc-title = Titre
 metadata-label-dc-creator = Créateur
 metadata-label-dc-description = Description
 metadata-label-dc-subject = Mots-clés
+metadata-keyword-input-placeholder = Ajouter un mot-clé puis Entrée
 metadata-label-dc-rights = Droits d'auteur
 
 navbar-info-button = Info
@@ -478,11 +1097,37 @@ empty-state-subtitle = Déposez des fichiers ici ou cliquez pour ouvrir
 empty-state-button = Ouvrir un fichier
 empty-state-drop-hint = Glissez-déposez des images ou vidéos n'importe où
 
+# File dialog filters
+dialog-filter-all-media = Tous les médias
+dialog-filter-images = Images
+dialog-filter-videos = Vidéos
+dialog-filter-image-format = Image { $format }
+dialog-filter-video-format = Vidéo { $format }
+
 # Additional notifications
 notification-empty-dir = Aucun fichier média compatible trouvé dans ce dossier
 notification-load-error-io = Impossible d'ouvrir le fichier. Vérifiez qu'il existe et que vous avez les permissions.
 notification-load-error-svg = Impossible de rendre le SVG. Le fichier est peut-être malformé.
 notification-load-error-video = Impossible de lire la vidéo. Le format n'est peut-être pas supporté.
+notification-image-sequence-not-found = Cette image ne fait pas partie d'une séquence numérotée.
+notification-capture-day = { $date } — { $count } fichiers
+notification-similar-indexing = Comparaison des images du dossier…
+notification-similar-found = { $count } images similaires
+notification-similar-none = Aucune image similaire dans ce dossier
+notification-face-model-downloading = Téléchargement du modèle de détection de visages…
+notification-no-faces-found = Aucun visage trouvé dans cette image.
+notification-clip-exported = Extrait enregistré dans { $path }
+notification-clip-exported-reencoded = Extrait réencodé et enregistré dans { $path } (sans audio)
+notification-clip-export-failed = Impossible d'exporter l'extrait : { $message }
+notification-frames-exported = { $count } images enregistrées dans { $path }
+notification-mini-player-error = Impossible d'ouvrir le mini-lecteur
+notification-visible-area-exported = Zone visible enregistrée dans { $path }
+notification-visible-area-export-error = Échec de l'export de la zone visible
+notification-view-exported = Vue enregistrée dans { $path }
+notification-view-export-error = Échec de l'export de la vue
+notification-view-copied = Vue copiée dans le presse-papiers
+notification-frames-export-failed = Impossible d'exporter les images : { $message }
+notification-face-detection-error = Échec de la détection de visages : { $error }
 notification-load-error-timeout = Le chargement a expiré. Le fichier est peut-être trop volumineux ou le système est occupé.
 notification-skipped-corrupted-files = Ignorés : { $files }
 notification-skipped-and-others = +{ $count } autres
@@ -516,6 +1161,19 @@ settings-upscale-status-error = Erreur : { $message }
 settings-upscale-status-not-downloaded = Modèle non téléchargé
 settings-upscale-enabled = Activé
 settings-upscale-disabled = Désactivé
+settings-enable-background-removal-label = Suppression d'arrière-plan IA
+settings-enable-background-removal-hint = Active la suppression d'arrière-plan par IA avec le modèle de segmentation U²-Net (~176 Mo à télécharger).
+settings-background-removal-model-url-label = URL du modèle
+settings-background-removal-model-url-placeholder = https://github.com/...
+settings-background-removal-model-url-hint = URL de téléchargement du modèle ONNX U²-Net.
+settings-background-removal-status-label = État du modèle
+settings-background-removal-status-downloading = Téléchargement du modèle ({ $progress } %)...
+settings-background-removal-status-validating = Validation du modèle...
+settings-background-removal-status-ready = Modèle prêt
+settings-background-removal-status-error = Erreur : { $message }
+settings-background-removal-status-not-downloaded = Modèle non téléchargé
+settings-background-removal-enabled = Activé
+settings-background-removal-disabled = Désactivé
 
 # Outil défloutage de l'éditeur
 image-editor-tool-deblur = Défloutage IA
@@ -529,12 +1187,49 @@ image-editor-deblur-validating = Validation du modèle en cours...
 image-editor-deblur-downloading = Téléchargement du modèle ({ $progress }%)...
 image-editor-deblur-error = Erreur : { $error }
 image-editor-deblur-already-applied = Défloutage déjà appliqué. Utilisez Annuler pour revenir en arrière si nécessaire.
+image-editor-tool-background = Suppression d'arrière-plan IA
+image-editor-background-remove = Supprimer l'arrière-plan
+image-editor-background-apply = Appliquer le détourage
+image-editor-background-processing = Suppression de l'arrière-plan...
+image-editor-background-model-not-ready = Activez d'abord la suppression d'arrière-plan IA dans les Paramètres
+image-editor-background-validating = Validation du modèle, veuillez patienter...
+image-editor-background-downloading = Téléchargement du modèle ({ $progress } %)...
+image-editor-background-error = Erreur : { $error }
+image-editor-background-refine-hint = Peignez sur l'image pour affiner les contours, puis appliquez.
+image-editor-background-brush-keep = Garder
+image-editor-background-brush-erase = Effacer
+image-editor-background-brush-size = Taille du pinceau
+image-editor-background-transparent-export-hint = Enregistrez en PNG ou WebP pour garder la transparence ; le JPEG la remplit de blanc.
+image-editor-tool-denoise = Réduction du bruit
+image-editor-denoise-method-label = Méthode
+image-editor-denoise-method-bilateral = Bilatéral
+image-editor-denoise-method-nlmeans = NL-means
+image-editor-denoise-method-model = Modèle IA
+image-editor-denoise-strength-label = Intensité
+image-editor-denoise-detail-label = Préservation des détails
+image-editor-denoise-preview-hint = L'aperçu est calculé sur une copie réduite ; Appliquer traite l'image entière.
+image-editor-denoise-model-hint = Le modèle n'a pas d'aperçu ; son résultat s'affiche une fois appliqué.
+image-editor-denoise-apply = Appliquer la réduction
+image-editor-denoise-processing = Réduction du bruit...
+image-editor-plugins-section-title = Extensions
+image-editor-plugins-running = Extension en cours…
+image-editor-plugins-export = Exporter en { $format }
 
 # Section d'aide IA
 help-editor-deblur-title = Défloutage IA
 help-editor-deblur-desc = Utilisez l'IA pour améliorer la netteté des images floues avec le réseau neuronal NAFNet.
 help-editor-deblur-enable = À activer dans Paramètres → IA / Apprentissage automatique (télécharge un modèle de ~92 Mo)
 help-editor-deblur-lossless = Pour une meilleure qualité, exportez en WebP sans perte ou PNG
+help-editor-background-title = Suppression d'arrière-plan IA
+help-editor-background-desc = Détourez le sujet de son arrière-plan avec le modèle de segmentation U²-Net, exécuté sur votre ordinateur.
+help-editor-background-enable = Activez dans Paramètres → IA / Apprentissage automatique (télécharge un modèle de ~176 Mo)
+help-editor-background-refine = Affinez les contours avec les pinceaux Garder et Effacer avant d'appliquer
+help-editor-background-export = Enregistrez en PNG ou WebP pour garder l'arrière-plan transparent
+help-editor-denoise-title = Réduction du bruit
+help-editor-denoise-desc = Réduisez le grain des photos prises en basse lumière.
+help-editor-denoise-methods = Bilatéral est rapide ; NL-means est plus lent mais garde plus de texture
+help-editor-denoise-sliders = Augmentez l'intensité pour retirer plus de bruit, et la préservation des détails pour garder les contours fins
+help-editor-denoise-model = Définissez denoise_model_url dans la section [ai] de settings.toml pour ajouter un modèle IA
 
 # Notifications IA
 notification-deblur-success = Image défloutée avec succès
@@ -552,3 +1247,9 @@ notification-upscale-download-error = Échec du téléchargement du modèle upsc
 notification-upscale-validation-error = Échec de la validation du modèle : { $error }
 notification-upscale-resize-success = Image redimensionnée avec l'agrandissement IA
 notification-upscale-resize-error = Échec de l'agrandissement IA : { $error }
+notification-background-ready = La suppression d'arrière-plan IA est prête
+notification-background-download-error = Échec du téléchargement du modèle de suppression d'arrière-plan : { $error }
+notification-background-validation-error = Échec de la validation du modèle : { $error }
+notification-background-removal-error = Échec de la suppression d'arrière-plan : { $error }
+notification-denoise-model-downloading = Téléchargement du modèle de réduction du bruit…
+notification-denoise-error = Échec de la réduction du bruit : { $error }
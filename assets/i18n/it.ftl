@@ -8,8 +8,15 @@ settings-title = Impostazioni
 settings-section-general = Generale
 settings-section-display = Visualizzazione
 settings-section-video = Video
+settings-section-editor = Editor
 settings-section-fullscreen = Schermo intero
+settings-section-guides = Guide
 settings-section-ai = IA / Apprendimento automatico
+settings-section-advanced = Avanzate
+settings-section-mouse = Mouse
+settings-section-shortcuts = Scorciatoie da tastiera
+settings-section-plugins = Plugin
+settings-section-accessibility = Accessibilità
 select-language-label = Seleziona lingua:
 language-name-en-US = Inglese
 language-name-fr = Francese
@@ -24,6 +31,7 @@ viewer-zoom-label = Zoom
 viewer-zoom-input-placeholder = 100
 viewer-zoom-reset-button = Ripristina
 viewer-fit-to-window-toggle = Adatta alla finestra
+viewer-lock-view-tooltip = Mantieni zoom e posizione cambiando immagine
 viewer-zoom-input-error-invalid = Inserisci un numero valido.
 viewer-zoom-step-error-invalid = Il passo dello zoom deve essere un numero.
 viewer-zoom-step-error-range = Il passo dello zoom deve essere compreso tra 1% e 200%.
@@ -32,7 +40,21 @@ viewer-zoom-in-tooltip = Ingrandisci
 viewer-zoom-out-tooltip = Riduci
 viewer-fullscreen-tooltip = Attiva/disattiva schermo intero
 viewer-rotate-cw-tooltip = Ruota in senso orario
+viewer-export-visible-tooltip = Esporta l'area visibile
+viewer-inspector-tooltip = Ispettore pixel
+viewer-inspector-copy-hint = Clic per copiare
+viewer-ruler-tooltip = Righello
+viewer-guides-tooltip = Guide di composizione
 viewer-rotate-ccw-tooltip = Ruota in senso antiorario
+viewer-mirror-tooltip = Capovolgi il video orizzontalmente
+viewer-matte-background = Tema di sfondo
+viewer-matte-black = Sfondo nero
+viewer-matte-white = Sfondo bianco
+viewer-matte-gray = Sfondo grigio
+viewer-matte-magenta = Sfondo magenta
+viewer-matte-green = Sfondo verde
+viewer-alpha-straight = Alfa diretto
+viewer-alpha-premultiplied = Alfa premoltiplicato
 viewer-fullscreen-disabled-unsaved = Salva o annulla prima le modifiche
 viewer-double-click = Doppio clic
 viewer-scroll-wheel = Rotella del mouse
@@ -48,6 +70,9 @@ filter-media-type-all = Tutti
 filter-media-type-images = Solo immagini
 filter-media-type-videos = Solo video
 filter-media-type-placeholder = Seleziona tipo...
+filter-rating-label = Valutazione minima
+filter-rating-any = Qualsiasi valutazione
+filter-rating-min = { $stars } e oltre
 filter-date-label = Filtro per data
 filter-date-field-label = Filtra per
 filter-date-field-modified = Data di modifica
@@ -61,6 +86,9 @@ filter-date-clear = Cancella data
 filter-tooltip-date-from = da { $date }
 filter-tooltip-date-to = a { $date }
 filter-tooltip-date-range = { $start } – { $end }
+quick-search-placeholder = Cerca per nome file...
+quick-search-no-results = Nessun risultato
+quick-search-hint = ↑/↓ seleziona · Invio apri · Esc chiudi
 
 settings-zoom-step-label = Passo dello zoom
 settings-zoom-step-placeholder = 10
@@ -69,10 +97,38 @@ settings-background-label = Sfondo del visualizzatore
 settings-background-light = Chiaro
 settings-background-dark = Scuro
 settings-background-checkerboard = Scacchiera
+settings-background-auto = Automatico
+settings-background-auto-hint = Scuro dietro le immagini chiare, chiaro dietro quelle scure
 settings-theme-mode-label = Tema dell'applicazione
 settings-theme-system = Segui il sistema
 settings-theme-light = Chiaro
 settings-theme-dark = Scuro
+settings-ui-scale-label = Scala dell'interfaccia
+settings-ui-scale-hint = Ingrandisce o riduce testo, spaziature e controlli, oltre alla scala di sistema. Applicata al rilascio del cursore.
+settings-accessibility-disabled = Disattivato
+settings-accessibility-enabled = Attivato
+settings-reduced-motion-label = Riduci il movimento
+settings-reduced-motion-hint = Gli indicatori di caricamento restano fermi invece di ruotare.
+settings-high-contrast-focus-label = Focus ad alto contrasto
+settings-high-contrast-focus-hint = Disegna un bordo spesso del colore del testo attorno al campo in cui si scrive.
+settings-large-targets-label = Pulsanti più grandi
+settings-large-targets-hint = Rende più facili da cliccare i pulsanti della barra degli strumenti, del menu e dei controlli video.
+settings-always-show-fullscreen-controls-label = Mostra sempre i controlli a schermo intero
+settings-always-show-fullscreen-controls-hint = Mantiene visibili la barra degli strumenti, i controlli video e le frecce di navigazione a schermo intero invece di nasconderli dopo il timeout dell'overlay.
+settings-accent-label = Colore di accento
+settings-accent-hint = Usato per pulsanti selezionati, cursori, evidenziazioni e notifiche. Inserisci un colore #rrggbb per un'altra tonalità.
+settings-accent-custom-hint = Finché è attiva, la tavolozza personalizzata qui sotto definisce l'accento.
+settings-accent-default = Predefinito
+settings-custom-theme-label = Tavolozza personalizzata
+settings-custom-theme-hint = Sostituisce il tema chiaro o scuro. Le modifiche sono visibili subito e salvate in theme.toml accanto a settings.toml.
+settings-custom-theme-disabled = Disattivata
+settings-custom-theme-enabled = Attivata
+settings-palette-background = Sfondo
+settings-palette-text = Testo
+settings-palette-accent = Accento
+settings-palette-success = Successo
+settings-palette-danger = Pericolo
+settings-palette-invalid = Usa il formato #rrggbb
 help-usage-heading = USO:
 help-options-heading = OPZIONI:
 help-args-heading = ARGOMENTI:
@@ -83,25 +139,85 @@ help-arg-image-path = <PERCORSO>      Percorso di un file multimediale o directo
 help-example-1 = iced_lens ./foto.png
 help-example-2 = iced_lens ./le_mie_foto/
 help-example-3 = iced_lens --lang fr ./immagine.jpg
+help-example-4 = iced_lens verify /media/sdcard/DCIM
+help-example-5 = iced_lens convert foto.png foto.jpg --quality 85
+help-example-6 = iced_lens info clip.mp4 --json
+help-commands-heading = COMANDI:
+help-line-command-verify = verify [DIR]  Decodifica completamente ogni file multimediale di DIR ed elenca quelli danneggiati
+help-line-command-convert = convert <INGRESSO> <USCITA> [--quality <1-100>]  Converte un file multimediale nel formato immagine di USCITA
+help-line-command-thumb = thumb [PERCORSO] [--size <px>] [--out <dir>]  Crea miniature JPEG di un file o di ogni file multimediale di una cartella
+help-line-command-info = info <PERCORSO> [--json|--csv]  Mostra i metadati di un file multimediale o di tutti i file multimediali di una cartella
+cli-verify-summary = { $checked } file verificati, { $damaged } danneggiati
+cli-thumb-summary = { $written } miniature create, { $failed } non riuscite
+cli-info-unsupported = nessun metadato leggibile (file non supportato o danneggiato)
 help-description = { -app-name } – Visualizzatore di immagini
 help-line-option-i18n-dir =     --i18n-dir <percorso>  Carica le traduzioni dalla directory
+help-line-option-watch-i18n =     --watch-i18n   Ricarica le traduzioni quando i loro file .ftl cambiano
 help-line-option-data-dir =     --data-dir <percorso>  Sovrascrivi directory dei dati (file di stato)
 help-line-option-config-dir =     --config-dir <percorso>  Sovrascrivi directory di configurazione (settings.toml)
 settings-sort-order-label = Ordine di navigazione delle immagini
 settings-sort-alphabetical = Alfabetico
 settings-sort-modified = Data di modifica
 settings-sort-created = Data di creazione
+settings-sort-size = Dimensione file
+settings-sort-type = Tipo di file
+settings-sort-ascending = Crescente
+settings-sort-descending = Decrescente
+settings-sort-order-hint = L'ordine alfabetico confronta i numeri per valore (img2 prima di img10).
 settings-max-skip-attempts-label = Salta file corrotti
 settings-max-skip-attempts-hint = Numero massimo di file corrotti consecutivi da saltare durante la navigazione.
+settings-prefetch-cache-size-label = File precaricati
+settings-prefetch-cache-size-hint = File decodificati tenuti in memoria per mostrare subito il successivo e il precedente. 0 disattiva il precaricamento.
+settings-similarity-threshold-label = Soglia immagini simili
+settings-similarity-threshold-hint = Somiglianza minima perché Trova simili passi a un'immagine. Abbassala per includere anche scatti inquadrati diversamente.
 settings-persist-filters-label = Ricorda filtri
 settings-persist-filters-hint = Mantieni le impostazioni dei filtri tra le sessioni.
 settings-persist-filters-disabled = No
 settings-persist-filters-enabled = Sì
+settings-remember-view-label = Ricorda la vista di ogni immagine
+settings-remember-view-hint = Ripristina lo zoom, la posizione di scorrimento e la rotazione di un'immagine quando ci si torna durante la sessione.
+settings-remember-view-disabled = No
+settings-remember-view-enabled = Sì
+settings-minimap-label = Minimappa
+settings-minimap-hint = Mostra l'immagine intera in un angolo quando è ingrandita, con la parte visibile evidenziata. Fai clic o trascina al suo interno per spostarti.
+settings-minimap-disabled = No
+settings-minimap-enabled = Sì
+settings-sandboxed-decoding-label = Decodifica isolata
+settings-sandboxed-decoding-hint = Decodifica le immagini in un processo separato, così un file danneggiato o dannoso non può bloccare né compromettere il visualizzatore. Il caricamento è leggermente più lento.
+settings-sandboxed-decoding-disabled = No
+settings-sandboxed-decoding-enabled = Sì
+settings-webp-decoder-label = Decoder WebP
+settings-webp-decoder-hint = Libreria usata per le immagini WebP statiche. Se non riesce a leggere un file, viene provata automaticamente l'altra.
+settings-webp-decoder-image-rs = image-rs
+settings-webp-decoder-libwebp = libwebp
 settings-overlay-timeout-label = Ritardo di scomparsa automatica a schermo intero
 settings-overlay-timeout-hint = Tempo prima che i controlli scompaiano in modalità a schermo intero.
+settings-overlay-shown = Visibile
+settings-overlay-hidden = Nascosto
+settings-overlay-filename-label = Nome del file
+settings-overlay-index-label = Posizione nella cartella (12/340)
+settings-overlay-clock-label = Orologio
+settings-overlay-arrows-label = Frecce di navigazione
+settings-overlay-histogram-label = Istogramma
+settings-overlay-position-label = Posizione della sovrapposizione
+settings-overlay-position-hint = Dove compaiono nome del file, posizione, orologio e istogramma.
+settings-overlay-position-top-left = In alto a sinistra
+settings-overlay-position-top-center = In alto
+settings-overlay-position-top-right = In alto a destra
+settings-overlay-position-bottom-left = In basso a sinistra
+settings-overlay-position-bottom-center = In basso
+settings-overlay-position-bottom-right = In basso a destra
+settings-overlay-opacity-label = Opacità della sovrapposizione
 seconds = secondi
 image-editor-title = Editor di immagini
 image-editor-back-to-viewer = Torna al visualizzatore
+image-editor-recovery-prompt = Sono state recuperate modifiche non salvate di questa immagine da una sessione precedente.
+image-editor-recovery-restore = Ripristina modifiche
+image-editor-recovery-discard = Scarta
+image-editor-guides = Guide
+image-editor-zoom-fit = Adatta
+image-editor-show-original = Mostra originale
+image-editor-compare-split = Prima/Dopo
 image-editor-cancel = Annulla
 image-editor-save = Salva
 image-editor-save-as = Salva come...
@@ -114,6 +230,10 @@ image-editor-rotate-left-tooltip = Ruota l'immagine in senso antiorario
 image-editor-flip-section-title = Capovolgi
 image-editor-flip-horizontal-tooltip = Capovolgi l'immagine orizzontalmente (specchio sinistra-destra)
 image-editor-flip-vertical-tooltip = Capovolgi l'immagine verticalmente (specchio alto-basso)
+image-editor-sidebar-collapse-tooltip = Nascondi gli strumenti
+image-editor-sidebar-expand-tooltip = Mostra gli strumenti
+image-editor-navigate-previous-tooltip = Immagine precedente
+image-editor-navigate-next-tooltip = Immagine successiva
 image-editor-resize-section-title = Ridimensiona
 image-editor-resize-scale-label = Scala
 image-editor-resize-dimensions-label = Dimensione target
@@ -130,10 +250,42 @@ image-editor-resize-ai-model-validating = Validazione del modello IA
 image-editor-resize-ai-model-error = Errore del modello IA
 image-editor-resize-ai-enlargement-only = L'upscaling IA si applica solo agli ingrandimenti
 image-editor-light-section-title = Regolazioni di luce
+image-editor-auto-enhance = Miglioramento automatico
+image-editor-auto-enhance-strength-label = Intensità
+image-editor-auto-enhance-neutral = Questa immagine è già ben bilanciata.
 image-editor-light-brightness-label = Luminosità
 image-editor-light-contrast-label = Contrasto
+image-editor-grayscale-section-title = Bianco e nero
+image-editor-grayscale-enable = Converti in bianco e nero
+image-editor-grayscale-preset-neutral = Neutro
+image-editor-grayscale-preset-red = Rosso
+image-editor-grayscale-preset-green = Verde
+image-editor-grayscale-preset-blue = Blu
+image-editor-grayscale-red-label = Canale rosso
+image-editor-grayscale-green-label = Canale verde
+image-editor-grayscale-blue-label = Canale blu
+image-editor-grayscale-total = Totale: { $total } % (100 % mantiene la luminosità complessiva)
+image-editor-sharpen-section-title = Nitidezza
+image-editor-sharpen-amount-label = Quantità
+image-editor-sharpen-radius-label = Raggio
+image-editor-sharpen-threshold-label = Soglia
 image-editor-light-reset = Ripristina
 image-editor-light-apply = Applica
+image-editor-light-copy = Copia regolazioni
+image-editor-light-paste = Incolla regolazioni
+image-editor-light-paste-folder = Incolla su tutte le immagini della cartella
+image-editor-tool-filters = Filtri
+image-editor-filter-black-and-white = Bianco e nero
+image-editor-filter-noir = Noir
+image-editor-filter-sepia = Seppia
+image-editor-filter-vignette = Vignettatura
+image-editor-filter-warm-film = Pellicola calda
+image-editor-filter-cool-film = Pellicola fredda
+image-editor-filter-faded = Sbiadito
+image-editor-filter-vivid = Vivace
+image-editor-filter-strength-label = Intensità
+image-editor-filter-hint = Scegli uno stile per vederne l'anteprima sull'immagine.
+image-editor-filter-apply = Applica filtro
 image-editor-crop-section-title = Ritaglia
 image-editor-crop-ratio-label = Proporzioni
 image-editor-crop-ratio-free = Libero
@@ -143,38 +295,267 @@ image-editor-crop-ratio-portrait = Verticale (9:16)
 image-editor-crop-ratio-photo = Foto (4:3)
 image-editor-crop-ratio-photo-portrait = Foto verticale (3:4)
 image-editor-crop-apply = Applica ritaglio
+image-editor-crop-face = Ritaglia sul volto
+image-editor-crop-face-detecting = Rilevamento dei volti…
+image-editor-crop-face-none = Nessun volto trovato in questa immagine.
 image-editor-undo-redo-section-title = Ultima modifica
 image-editor-undo = Annulla
 image-editor-redo = Ripeti
+image-editor-history-title = Cronologia
+image-editor-history-steps-title = Passaggi
+image-editor-history-original = Immagine originale
+image-editor-snapshots-title = Istantanee
+image-editor-snapshot-name-placeholder = Nome dell'istantanea
+image-editor-snapshot-save = Salva
+image-editor-snapshot-delete-tooltip = Elimina l'istantanea
+image-editor-snapshot-unnamed = Dopo il passaggio { $step }
+image-editor-history-rotate-left = Ruota a sinistra
+image-editor-history-rotate-right = Ruota a destra
+image-editor-history-flip-horizontal = Capovolgi orizzontalmente
+image-editor-history-flip-vertical = Capovolgi verticalmente
+image-editor-history-crop = Ritaglio
+image-editor-history-resize = Ridimensionamento
+image-editor-history-upscale = Ingrandimento IA
+image-editor-history-brightness = Luminosità
+image-editor-history-contrast = Contrasto
+image-editor-history-auto-enhance = Miglioramento automatico
+image-editor-history-black-white = Bianco e nero
+image-editor-history-sharpen = Nitidezza
+image-editor-history-look = Filtro
+image-editor-history-deblur = Rimozione sfocatura IA
+image-editor-history-background = Rimozione dello sfondo
+image-editor-history-denoise = Riduzione del rumore
+image-editor-history-plugin = Strumento plugin
 image-editor-export-format-label = Formato di esportazione
+image-editor-proof-label = Prova colore
+image-editor-proof-none = Nessuno
+image-editor-proof-convert = Converti i colori nel profilo
+image-editor-proof-convert-tooltip = Disattivato: il profilo viene solo allegato e i valori dei pixel restano invariati. Attivato: i colori vengono convertiti per apparire uguali nello spazio di destinazione.
 media-loading = Caricamento...
 settings-video-autoplay-label = Riproduzione automatica video
 settings-video-autoplay-enabled = Attivata
 settings-video-autoplay-disabled = Disattivata
 settings-video-autoplay-hint = Quando è attivata, i video iniziano a essere riprodotti automaticamente all'apertura.
+settings-video-resume-label = Riprendi i video
+settings-video-resume-hint = I video riaperti possono continuare da dove li hai lasciati.
+settings-video-resume-ask = Chiedi
+settings-video-resume-always = Sempre
+settings-video-resume-never = Mai
+settings-tone-mapping-label = Tone mapping HDR
+settings-tone-mapping-hint = Come le alte luci dei video HDR10 e HLG vengono riportate nella gamma dello schermo.
+settings-tone-mapping-clip = Taglia
+settings-tone-mapping-reinhard = Reinhard
+settings-tone-mapping-hable = Filmico (Hable)
+settings-tone-mapping-aces = ACES
+settings-audio-device-label = Uscita audio
+settings-audio-device-hint = Dispositivo che riproduce l’audio dei video. Con quello predefinito di sistema, la riproduzione passa al nuovo predefinito quando un dispositivo viene scollegato o cambiato.
+settings-audio-device-default = Predefinito di sistema
+settings-audio-device-missing = { $id } (non collegato)
 video-play-tooltip = Riproduci (Spazio)
 video-pause-tooltip = Pausa (Spazio)
 video-mute-tooltip = Silenzia (M)
+video-volume-boost-tooltip = Amplificato oltre il 100%, ricordato per questo file
 video-unmute-tooltip = Attiva audio (M)
 video-no-audio-tooltip = Nessuna traccia audio
 video-loop-tooltip = Ripeti
+video-loop-in-tooltip = Inizio del loop A–B qui
+video-loop-out-tooltip = Fine del loop A–B qui
+video-loop-clear-tooltip = Rimuovi il loop A–B
+video-export-clip = Esporta clip
+video-export-clip-tooltip = Esporta il loop A–B in un nuovo file video
+video-export-clip-progress = Esportazione… { $percent }%
+video-export-clip-cancel-tooltip = Annulla l'esportazione della clip
+video-export-frames = Esporta fotogrammi
+video-export-frames-tooltip = Esporta un intervallo di fotogrammi come immagini, GIF o animazione WebP
+video-frames-range-loop = Loop A–B
+video-frames-range-around = { $seconds } s attorno alla testina
+video-frames-range-tooltip = Intervallo esportato senza loop A–B
+video-frames-format-sequence = Sequenza PNG
+video-frames-format-gif = GIF animata
+video-frames-format-webp = WebP animato
+video-frames-format-tooltip = Formato di uscita
+video-frames-fps = { $fps } fps
+video-frames-fps-tooltip = Fotogrammi al secondo
+video-frames-scale-tooltip = Dimensione di uscita
+video-frames-export = Esporta…
+video-frames-export-tooltip = Scegli dove salvare i fotogrammi ed esportali
+video-frames-cancel-tooltip = Annulla l'esportazione dei fotogrammi
+video-frames-mode-range = Intervallo
+video-frames-mode-burst = Raffica
+video-frames-mode-tooltip = Esporta un intervallo in un file o una raffica di immagini separate
+video-frames-burst-count = { $count } fotogrammi
+video-frames-burst-count-tooltip = Fotogrammi catturati attorno alla testina
+video-frames-burst-interval = ogni { $ms } ms
+video-frames-burst-interval-tooltip = Tempo tra due fotogrammi
+video-frames-burst-tooltip = Scegli una cartella e salva ogni fotogramma come PNG a dimensione piena
 video-capture-tooltip = Cattura fotogramma corrente
 video-step-forward-tooltip = Avanza di un fotogramma (.)
 video-step-backward-tooltip = Indietreggia di un fotogramma (,)
 video-more-tooltip = Altre opzioni
 video-speed-down-tooltip = Diminuisci velocità (J)
 video-speed-up-tooltip = Aumenta velocità (L)
+video-speed-selector-tooltip = Velocità di riproduzione (l'audio mantiene l'intonazione da 0,25x a 3x)
+video-audio-visualization-off = Striscia audio: disattivata
+video-audio-visualization-waveform = Striscia audio: forma d'onda
+video-audio-visualization-vu-meter = Striscia audio: VU meter
+video-audio-visualization-tooltip = Mostra la forma d'onda o un indicatore di livello sotto la barra di avanzamento
+video-adjust-button = Regola
+video-adjust-tooltip = Luminosità, contrasto, saturazione e gamma del video
+video-adjust-brightness = Luminosità
+video-adjust-contrast = Contrasto
+video-adjust-saturation = Saturazione
+video-adjust-gamma = Gamma
+video-adjust-reset = Ripristina
+video-adjust-reset-tooltip = Ripristina i colori originali
+video-mini-player = Mini lettore
+video-mini-player-tooltip = Continua la riproduzione in una piccola finestra sopra le altre
+pip-play-tooltip = Riproduci
+pip-pause-tooltip = Pausa
+pip-return-tooltip = Torna alla finestra principale
+pip-close-tooltip = Chiudi il mini lettore
+video-frame-counter = Fotogramma { $current } / { $total }
+video-fine-scrub-frame = Fine: 1 fotogramma
+video-fine-scrub-tenth = Fine: 0,1 s
+video-resume-prompt = Riprendere da { $position }?
+video-resume-button = Riprendi
+video-resume-start-over-button = Ricomincia
+video-chapter-previous-tooltip = Capitolo precedente (PagSu)
+video-chapter-next-tooltip = Capitolo successivo (PagGiù)
+video-chapter-list-tooltip = Capitoli
+video-subtitles-off = Sottotitoli disattivati
+video-decode-hardware = Decodifica GPU ({ $backend })
+video-decode-software = Decodifica CPU
+video-cache-stats = Cache dei fotogrammi: { $rate }% di successi, { $frames } fotogrammi, { $size } MB
+video-subtitles-track-tooltip = Traccia sottotitoli
+video-subtitles-delay-tooltip = Ritardo sottotitoli (clic per azzerare)
+video-subtitles-size-tooltip = Dimensione sottotitoli
+video-subtitles-background = Sfondo
+video-subtitle-size-small = Piccola
+video-subtitle-size-medium = Media
+video-subtitle-size-large = Grande
 hud-video-no-audio = Nessun audio
 settings-audio-normalization-label = Normalizzazione del volume audio
 settings-audio-normalization-enabled = Attivata
 settings-audio-normalization-disabled = Disattivata
 settings-audio-normalization-hint = Livella automaticamente il volume audio tra diversi file multimediali per evitare cambiamenti improvvisi di volume.
+settings-hardware-decoding-label = Decodifica video hardware
+settings-hardware-decoding-enabled = Attivata
+settings-hardware-decoding-disabled = Disattivata
+settings-hardware-decoding-hint = Decodifica i video sulla scheda grafica (VA-API, D3D11VA/DXVA2 o VideoToolbox) per una riproduzione 4K fluida, ripiegando sul processore se non supportata. Si applica al prossimo video aperto.
+settings-file-label = File delle impostazioni
+settings-file-hint = Le modifiche a settings.toml fatte in un editor di testo si applicano in pochi secondi. Esporta copia le impostazioni in un altro file; Importa le sostituisce con quelle di un file.
+settings-file-import = Importa…
+settings-file-export = Esporta…
+settings-check-updates-label = Aggiornamenti
+settings-check-updates-hint = Cerca una versione più recente su GitHub. Non viene scaricato né installato nulla.
+settings-check-updates-manual = Manualmente
+settings-check-updates-weekly = Ogni settimana
+settings-check-updates-now = Controlla ora
+settings-mouse-wheel-label = Rotella sopra il contenuto
+settings-mouse-wheel-zoom = Zoom
+settings-mouse-wheel-navigate = Precedente / successivo
+settings-mouse-wheel-volume = Volume del video
+settings-mouse-wheel-hint = Volume del video regola il volume durante la riproduzione di un video e ingrandisce le immagini.
+settings-mouse-drag-button-label = Pulsante per spostare il contenuto ingrandito
+settings-mouse-drag-button-hint = Il doppio clic sinistro attiva ancora lo schermo intero.
+settings-mouse-button-left = Sinistro
+settings-mouse-button-middle = Centrale
+settings-mouse-button-right = Destro
+settings-mouse-middle-click-label = Clic centrale
+settings-mouse-middle-click-toggle-fit = Attiva / disattiva adatta alla finestra
+settings-mouse-middle-click-none = Niente
+settings-mouse-middle-click-hint = Ignorato quando il pulsante centrale sposta il contenuto.
+settings-mouse-navigation-buttons-label = Pulsanti indietro e avanti
+settings-mouse-navigation-buttons-enabled = Contenuto precedente / successivo
+settings-mouse-navigation-buttons-disabled = Ignorati
+settings-mouse-navigation-buttons-hint = I pulsanti laterali del mouse scorrono la cartella come i tasti freccia.
+settings-shortcuts-hint = Fai clic su una scorciatoia, poi premi il nuovo tasto. Esc annulla.
+settings-shortcuts-press-key = Premi un tasto…
+settings-shortcuts-reset = Ripristina tutto
+settings-shortcuts-conflict = { $other } usa già questo tasto; { $action } mantiene la sua scorciatoia.
+settings-shortcuts-reserved = Questo tasto non può essere assegnato; { $action } mantiene la sua scorciatoia.
+settings-shortcuts-group-viewer = Visualizzatore
+settings-shortcuts-group-video = Video
+settings-shortcuts-group-editor = Editor
+settings-plugins-hint = I plugin sono cartelle con un file plugin.toml nella cartella dei plugin. Vengono eseguiti come programmi separati.
+settings-plugins-empty = Nessun plugin installato.
+settings-plugins-invalid = plugin.toml non valido: { $error }
+settings-plugins-hooks = Fornisce: { $hooks }
+settings-plugins-hook-media-loaded = azione all'apertura di un media
+settings-plugins-hook-editor-tool = strumenti dell'editor
+settings-plugins-hook-export-format = formati di esportazione
+settings-plugins-enabled = Attivo
+settings-plugins-disabled = Disattivato
+settings-plugins-open-folder = Apri cartella dei plugin
+settings-plugins-reload = Ricarica plugin
+shortcut-action-enter-editor = Modifica immagine
+shortcut-action-toggle-info-panel = Mostra / nascondi il pannello informazioni
+shortcut-action-toggle-fullscreen = Attiva / disattiva schermo intero
+shortcut-action-rotate-clockwise = Ruota in senso orario
+shortcut-action-rotate-counterclockwise = Ruota in senso antiorario
+shortcut-action-mirror = Specchia
+shortcut-action-next-matte = Sfondo successivo
+shortcut-action-previous-matte = Sfondo precedente
+shortcut-action-toggle-alpha = Mostra / nascondi la trasparenza
+shortcut-action-play-sequence = Riproduci come sequenza
+shortcut-action-export-view = Esporta la vista
+shortcut-action-copy-view = Copia la vista
+shortcut-action-quick-search = Ricerca rapida
+shortcut-action-next-face = Zoom sul volto successivo
+shortcut-action-previous-face = Zoom sul volto precedente
+shortcut-action-toggle-face-overlay = Mostra i volti rilevati
+shortcut-action-next-similar = Immagine simile successiva
+shortcut-action-previous-similar = Immagine simile precedente
+shortcut-action-toggle-osd = Mostra / nascondi le informazioni a schermo
+shortcut-action-toggle-pixel-inspector = Attiva / disattiva l'ispettore pixel
+shortcut-action-toggle-ruler = Attiva / disattiva il righello
+shortcut-action-toggle-guides = Mostra / nascondi le guide di composizione
+shortcut-action-play-pause = Riproduci / pausa
+shortcut-action-mute = Disattiva / attiva audio
+shortcut-action-step-backward = Fotogramma precedente
+shortcut-action-step-forward = Fotogramma successivo
+shortcut-action-speed-down = Rallenta
+shortcut-action-speed-up = Accelera
+shortcut-action-loop-start = Imposta inizio del ciclo
+shortcut-action-loop-end = Imposta fine del ciclo
+shortcut-action-loop-clear = Cancella ciclo
+shortcut-action-save = Salva
+shortcut-action-undo = Annulla
+shortcut-action-redo = Ripeti
+settings-frame-cache-auto-label = Dimensione della cache dei fotogrammi
+settings-frame-cache-auto-enabled = Automatica
+settings-frame-cache-auto-disabled = Manuale
+settings-frame-cache-auto-hint = In modalità automatica, cache e cronologia dei fotogrammi sono dimensionate in base alla risoluzione del video e alla memoria disponibile.
 settings-frame-cache-label = Dimensione cache keyframe (per la ricerca)
 settings-frame-cache-hint = Memorizza i keyframe video per velocizzare lo scrubbing della timeline e i salti a momenti specifici. Valori più alti memorizzano più keyframe per una navigazione più veloce. Le modifiche si applicano all'apertura di un nuovo video.
 settings-frame-history-label = Dimensione cronologia fotogrammi (per tornare indietro)
 settings-frame-history-hint = Memorizza i fotogrammi visualizzati di recente per consentire di tornare indietro fotogramma per fotogramma. Utilizzata solo durante la navigazione manuale dei fotogrammi, non durante la riproduzione normale.
 settings-keyboard-seek-step-label = Passo di ricerca da tastiera
 settings-keyboard-seek-step-hint = Tempo da saltare quando si usano i tasti freccia durante la riproduzione video.
+settings-sequence-fps-label = Frequenza sequenze di immagini
+settings-sequence-fps-hint = Velocità usata per riprodurre immagini numerate (frame_0001.png, frame_0002.png…) come video con S.
+settings-frame-name-template-label = Modello nome fotogrammi
+settings-frame-name-template-hint = Nome predefinito dei fotogrammi catturati. Token: {"{name}"}, {"{date}"}, {"{index}"}, {"{timestamp}"}, {"{w}"}, {"{h}"}.
+settings-save-policy-label = Al salvataggio
+settings-save-policy-hint = Dove Salva scrive le immagini modificate. Le copie lasciano intatto l’originale; i fotogrammi catturati vengono proposti nella stessa cartella.
+settings-save-policy-overwrite = Sovrascrivi
+settings-save-policy-copy = Copia numerata
+settings-save-policy-subfolder = Sottocartella
+settings-edited-folder-label = Nome della sottocartella
+settings-edited-folder-hint = Viene creata accanto all’immagine originale al salvataggio.
+settings-guides-thirds-label = Regola dei terzi
+settings-guides-thirds-hint = Due linee su ogni lato, a un terzo e a due terzi.
+settings-guides-golden-ratio-label = Sezione aurea
+settings-guides-golden-ratio-hint = Due linee su ogni lato, alle sezioni auree (38,2% e 61,8%).
+settings-guides-safe-areas-label = Aree di sicurezza
+settings-guides-safe-areas-hint = Cornici tratteggiate di sicurezza azione (93%) e titoli (90%).
+settings-guides-pixel-grid-label = Griglia dei pixel
+settings-guides-pixel-grid-hint = Delimita ogni pixel a partire da uno zoom dell'800%.
+settings-guides-horizontal-label = Linee orizzontali personalizzate
+settings-guides-vertical-label = Linee verticali personalizzate
+settings-guides-positions-hint = Posizioni in percentuale dal bordo superiore o sinistro, separate da virgole.
+settings-guides-positions-invalid = Inserire numeri da 0 a 100, ad esempio 25, 50, 75.
+settings-sequence-fps-value = { $fps } fps
 megabytes = MB
 error-load-video-heading = Impossibile riprodurre questo video.
 error-load-video-general = Si è verificato un errore durante il caricamento del video.
@@ -189,9 +570,35 @@ error-video-seek-timeout = La ricerca della posizione è scaduta. La posizione d
 
 # Navigation bar
 menu-settings = Impostazioni
+menu-open-in-new-window = Apri in una nuova finestra…
 menu-help = Aiuto
 menu-about = Informazioni
+menu-verify-folder = Verifica cartella
+menu-share-over-lan = Condividi sulla rete locale
+menu-stop-sharing = Interrompi la condivisione
+menu-strip-metadata = Rimuovi tutti i metadati
+menu-always-on-top = Sempre in primo piano
+menu-borderless = Finestra senza bordi
+menu-compact-mode = Modalità compatta (Esc per uscire)
+menu-open-with = Apri con / Invia a
+share-panel-title = Condividi sulla rete locale
+share-panel-hint = { $count } file condivisi. Scansiona il codice o apri questo indirizzo su un dispositivo connesso alla stessa rete:
+share-panel-read-only = I visitatori possono vedere e scaricare i file, non modificarli.
+share-panel-copy = Copia indirizzo
+share-panel-stop = Interrompi la condivisione
+share-panel-close = Chiudi
+diagnostics-title = Diagnostica
+diagnostics-decode-fps = Fotogrammi decodificati al secondo
+diagnostics-dropped-frames = Fotogrammi scartati
+diagnostics-frame-queue = Coda fotogrammi
+diagnostics-frame-cache = Successi cache fotogrammi
+diagnostics-prefetch-cache = Successi cache di precaricamento
+diagnostics-memory = Memoria usata
+diagnostics-recent-loads = Ultimi tempi di caricamento
+diagnostics-load-failed = fallito dopo { $ms } ms
+diagnostics-unavailable = —
 navbar-edit-button = Modifica
+navbar-menu-tooltip = Menu
 
 # Help screen
 help-title = Aiuto
@@ -219,7 +626,7 @@ help-viewer-tool-fit-desc = Ridimensiona automaticamente l'immagine per adattarl
 help-viewer-tool-fullscreen = Schermo intero
 help-viewer-tool-fullscreen-desc = Vista immersiva con controlli che si nascondono automaticamente (ritardo configurabile nelle Impostazioni).
 help-viewer-tool-filter = Filtra
-help-viewer-tool-filter-desc = Mostra solo file corrispondenti. Filtra per tipo di media, orientamento o intervallo di date.
+help-viewer-tool-filter-desc = Mostra solo file corrispondenti. Filtra per tipo di media, orientamento, intervallo di date o valutazione minima.
 help-viewer-tool-delete = Elimina
 help-viewer-tool-delete-desc = Rimuovi permanentemente il file corrente (spostato nel cestino di sistema, se disponibile).
 
@@ -230,6 +637,26 @@ help-viewer-key-exit-fullscreen = Esci dalla modalità a schermo intero
 help-viewer-key-info = Attiva/disattiva pannello informazioni file
 help-viewer-key-rotate-cw = Ruota in senso orario
 help-viewer-key-rotate-ccw = Ruota in senso antiorario
+help-viewer-key-mirror = Capovolgi il video orizzontalmente
+help-viewer-key-matte = Cambia il colore di sfondo delle aree trasparenti
+help-viewer-key-alpha = Alterna anteprima alfa diretto/premoltiplicato
+help-viewer-key-sequence = Riproduci la sequenza di immagini numerate come video
+help-viewer-key-export-view = Salva la vista così come appare in un file PNG
+help-viewer-key-copy-view = Copia la vista così come appare negli appunti
+help-viewer-key-quick-search = Cerca file nella cartella per nome
+help-viewer-key-quick-search-cycle = Vai al risultato di ricerca successivo / precedente
+help-viewer-key-capture-day = Vai al giorno di scatto successivo / precedente
+help-viewer-key-face-zoom = Ingrandisci il volto rilevato successivo / precedente
+help-viewer-key-face-overlay = Evidenzia i volti rilevati
+help-viewer-key-similar = Trova simili: vai all'immagine somigliante successiva / precedente (raffiche)
+help-viewer-key-osd = Mostra / nascondi le informazioni a schermo
+help-viewer-key-pixel-inspector = Ispettore pixel: colore sotto il cursore, clic per copiarlo
+help-viewer-key-ruler = Righello: trascinare sull'immagine per misurare una distanza e un angolo
+help-viewer-key-ruler-undo = Rimuovi l'ultima misura (righello attivo)
+help-viewer-key-guides = Mostra / nascondi le guide di composizione
+help-viewer-key-layout = Passa al layout 1–3 (selezione, presentazione, personalizzato)
+help-viewer-key-layout-save = Salva i pannelli attuali nel layout 1–3
+help-viewer-key-diagnostics = Mostra / nascondi la diagnostica delle prestazioni
 
 help-mouse-title = Interazioni con il mouse
 help-viewer-mouse-doubleclick = Doppio clic su immagine/video per attivare/disattivare schermo intero
@@ -247,7 +674,7 @@ help-video-tool-playback-desc = Avvia o interrompi la riproduzione video con il
 help-video-tool-timeline = Timeline
 help-video-tool-timeline-desc = Fai clic ovunque sulla barra di progresso per saltare a quella posizione.
 help-video-tool-volume = Volume
-help-video-tool-volume-desc = Trascina il cursore del volume (0-150%) o fai clic sull'icona dell'altoparlante per silenziare/attivare l'audio.
+help-video-tool-volume-desc = Trascina il cursore del volume (0-200%) o fai clic sull'icona dell'altoparlante per silenziare/attivare l'audio.
 help-video-tool-loop = Ripeti
 help-video-tool-loop-desc = Attiva per riavviare automaticamente il video quando termina.
 help-video-tool-stepping = Navigazione fotogramma per fotogramma
@@ -258,11 +685,15 @@ help-video-tool-capture-desc = Salva il fotogramma video corrente come file imma
 help-video-key-playpause = Riproduci o metti in pausa il video
 help-video-key-mute = Attiva/disattiva silenziamento audio
 help-video-key-seek = Cerca indietro/avanti (durante la riproduzione)
+help-video-key-fine-seek = Ricerca fine: un fotogramma in pausa, 0,1 s durante la riproduzione (tieni premuto Maiusc trascinando la timeline per scorrerla lentamente)
 help-video-key-volume = Aumenta/diminuisci volume
 help-video-key-step-back = Indietreggia di un fotogramma (quando in pausa)
 help-video-key-step-forward = Avanza di un fotogramma (quando in pausa)
 help-video-key-speed-down = Diminuisci velocità di riproduzione
 help-video-key-speed-up = Aumenta velocità di riproduzione
+help-video-key-chapter = Vai al capitolo successivo / precedente (video con capitoli)
+help-video-key-loop-points = Imposta inizio / fine del loop A–B alla posizione corrente
+help-video-key-loop-clear = Rimuovi il loop A–B
 
 # ─────────────────────────────────────────────────────────────────────────────
 # Image Editor Section
@@ -293,9 +724,22 @@ help-editor-resize-ai-upscale = Upscaling IA: Usa Real-ESRGAN per ingrandimenti
 
 help-editor-light-title = Luce
 help-editor-light-desc = Regola finemente la luminosità e il contrasto della tua immagine.
+help-editor-light-auto = Miglioramento automatico: analizza l'istogramma per correggere livelli, contrasto e bilanciamento del bianco in un solo passaggio; l'intensità fonde la correzione con l'originale
 help-editor-light-brightness = Luminosità: schiarisci o scurisci l'immagine complessiva
 help-editor-light-contrast = Contrasto: aumenta o diminuisci la differenza tra aree chiare e scure
+help-editor-light-grayscale = Bianco e nero: un miscelatore di canali regola il contributo di ogni colore al grigio; i preset Rosso, Verde e Blu imitano i filtri colorati della pellicola in bianco e nero
+help-editor-light-sharpen = Nitidezza: una maschera di contrasto il cui raggio regola la dimensione dei bordi accentuati e la cui soglia risparmia le zone uniformi e il rumore
+help-editor-light-copy = Copia/incolla regolazioni: copia rotazioni, capovolgimenti, ritagli, ridimensionamenti e regolazioni della luce dell'immagine, mantenuti dopo un riavvio; incollando vengono applicati a un'altra immagine, adattando ritagli e ridimensionamenti alle sue dimensioni, o a tutte le immagini della cartella, salvate come copie che non sostituiscono mai gli originali, dopo una conferma
 help-editor-light-preview = Le modifiche vengono visualizzate in anteprima in tempo reale prima dell'applicazione
+help-editor-filters-title = Filtri
+help-editor-filters-desc = Dai uno stile alla tua immagine con un clic: bianco e nero, seppia, vignettatura, toni pellicola e altro.
+help-editor-filters-preview = Fai clic su uno stile per l'anteprima e di nuovo per rimuoverlo
+help-editor-filters-strength = L'intensità fonde lo stile con l'immagine originale
+help-editor-filters-undo = Uno stile applicato è un unico passaggio annullabile
+help-editor-history-title = Cronologia
+help-editor-history-desc = Torna a qualsiasi stato precedente delle modifiche, oltre il singolo annullamento.
+help-editor-history-steps = Fai clic su un passaggio per tornarvi; i passaggi successivi restano elencati e ripristinabili fino alla modifica successiva
+help-editor-history-snapshots = Salva un'istantanea per dare un nome allo stato attuale («dopo il ritaglio») e tornarvi in seguito; le istantanee durano fino al salvataggio dell'immagine
 
 help-editor-save-title = Salvataggio
 help-editor-save-overwrite = Salva: sovrascrive il file originale
@@ -305,10 +749,15 @@ help-editor-key-save = Salva le modifiche correnti
 help-editor-key-undo = Annulla l'ultima modifica
 help-editor-key-redo = Ripeti la modifica annullata
 help-editor-key-cancel = Annulla tutte le modifiche ed esci
+help-editor-key-show-original = Mostrare l'immagine originale finché il tasto è premuto
 
 help-editor-mouse-title = Controlli del mouse
 help-editor-mouse-wheel = Ingrandisci o riduci l'immagine
 help-editor-mouse-drag = Sposta l'immagine quando ingrandita
+help-editor-mouse-space-drag = Spostare l'immagine sopra le maniglie di ritaglio e il pennello di rifinitura
+help-editor-mouse-space-drag-label = Spazio + trascina
+help-editor-mouse-fit = Il pulsante Adatta alterna la tela tra adatta alla finestra e 100%
+help-editor-compare = Tieni premuto Mostra originale per vedere l'immagine intatta, oppure usa Prima/Dopo per dividere la tela: l'originale a sinistra del divisore, le modifiche a destra
 
 # ─────────────────────────────────────────────────────────────────────────────
 # Frame Capture Section
@@ -378,16 +827,73 @@ about-third-party-details = Vedi THIRD_PARTY_LICENSES.md per i dettagli
 about-section-links = Collegamenti
 about-link-repository = Codice sorgente
 about-link-issues = Segnala problemi
+about-section-troubleshooting = Risoluzione dei problemi
+about-troubleshooting-hint = Quando segnali un problema, allega il rapporto diagnostico: elenca le versioni di sistema, scheda grafica e FFmpeg insieme alle ultime righe del registro.
+about-view-logs-button = Visualizza registri
+about-copy-report-button = Copia rapporto diagnostico
+about-missing-translations-button = Traduzioni mancanti
+logs-title = Registri
+logs-folder-unavailable = Impossibile determinare la cartella dei registri.
+logs-refresh = Aggiorna
+logs-open-folder = Apri cartella dei registri
+logs-copy-report = Copia rapporto diagnostico
+logs-empty = Non è stato ancora registrato nulla.
+translations-title = Traduzioni mancanti
+translations-summary = { $count } chiavi di { $locale } sono mostrate in inglese. Le traduzioni sono caricate da { $dir }.
+translations-copy-keys = Copia le chiavi
+translations-complete = Tutte le chiavi sono tradotte.
+welcome-title = Benvenuto in IcedLens
+welcome-preferences-intro = Scegli lingua e tema. Tutto si può cambiare più tardi nelle Impostazioni.
+welcome-default-viewer-title = Visualizzatore di immagini predefinito
+welcome-default-viewer-intro = Apri le immagini in IcedLens facendo doppio clic nel tuo gestore di file.
+welcome-default-viewer-button = Imposta come visualizzatore predefinito
+welcome-default-viewer-working = Registrazione…
+welcome-default-viewer-done = IcedLens ora apre le tue immagini.
+welcome-default-viewer-confirm = Scegli IcedLens per le immagini nelle impostazioni App predefinite appena aperte.
+welcome-default-viewer-failed = Impossibile registrare IcedLens: { $error }
+welcome-tour-progress = Suggerimento { $current } di { $total }
+welcome-tour-try = Prova: premi { $key }
+welcome-tour-tried = Ben fatto!
+welcome-tour-footer = Tutte le scorciatoie sono elencate nella Guida e si possono cambiare nelle Impostazioni.
+welcome-tour-navigate-title = Sfogliare la cartella
+welcome-tour-navigate-body = Mostra il file successivo o precedente della cartella. La rotella ingrandisce e il trascinamento sposta.
+welcome-tour-fullscreen-title = Schermo intero
+welcome-tour-fullscreen-body = I controlli si nascondono dopo un attimo; muovi il mouse per farli riapparire. Esc esce dallo schermo intero.
+welcome-tour-info-title = Vedere i dettagli
+welcome-tour-info-body = Il pannello informazioni mostra i metadati di fotocamera, esposizione e video, e modifica titoli, parole chiave e date.
+welcome-tour-editor-title = Modificare l’immagine
+welcome-tour-editor-body = Ruota, ritaglia, ridimensiona e regola la luce. Nulla viene scritto finché non salvi.
+welcome-tour-video-title = Riprodurre i video
+welcome-tour-video-body = Riproduci e metti in pausa i video. In pausa, virgola e punto avanzano fotogramma per fotogramma.
+welcome-skip = Salta
+welcome-back = Indietro
+welcome-next = Avanti
+welcome-finish = Inizia a usare IcedLens
 
 # Notifications
 notification-save-success = Immagine salvata con successo
+notification-save-copy-success = Immagine salvata come { $file }
 notification-save-error = Errore nel salvataggio dell'immagine
 notification-frame-capture-success = Fotogramma catturato con successo
 notification-frame-capture-error = Errore nella cattura del fotogramma
 notification-delete-success = File eliminato con successo
 notification-delete-error = Errore nell'eliminazione del file
 notification-config-save-error = Errore nel salvataggio delle impostazioni
+notification-theme-save-error = Impossibile salvare il file del tema
+notification-config-merged = Le impostazioni modificate in un'altra finestra sono state mantenute
 notification-config-load-error = Errore nel caricamento delle impostazioni, uso dei valori predefiniti
+notification-config-reloaded = Impostazioni ricaricate da settings.toml
+notification-translations-reloaded = Traduzioni ricaricate, { $count } chiavi mancanti
+notification-config-reload-error = settings.toml non è valido, modifiche non applicate: { $error }
+notification-settings-imported = Impostazioni importate
+notification-settings-import-error = Impossibile importare le impostazioni: { $error }
+notification-settings-exported = Impostazioni esportate
+notification-settings-export-error = Impossibile esportare le impostazioni: { $error }
+notification-update-available = IcedLens { $version } è disponibile.
+    { $notes }
+notification-update-download = Apri la pagina di download
+notification-update-none = IcedLens { $version } è aggiornato.
+notification-update-check-error = Impossibile cercare aggiornamenti: { $error }
 notification-state-parse-error = Errore nella lettura dello stato dell'applicazione, uso dei valori predefiniti
 notification-state-read-error = Errore nell'apertura del file di stato dell'applicazione
 notification-state-path-error = Impossibile determinare il percorso dei dati dell'applicazione
@@ -400,6 +906,18 @@ notification-editor-create-error = Errore nell'apertura dell'editor di immagini
 notification-editor-load-error = Errore nel caricamento dell'immagine per la modifica
 notification-video-editing-unsupported = La modifica video non è ancora supportata
 
+# Unità
+unit-bytes = { $value } B
+unit-kilobytes = { $value } KB
+unit-megabytes = { $value } MB
+unit-gigabytes = { $value } GB
+unit-bits-per-second = { $value } bps
+unit-kilobits-per-second = { $value } kbps
+unit-megabits-per-second = { $value } Mbps
+unit-frames-per-second = { $value } fps
+unit-nits = { $value } nit
+unit-nits-range = { $min }–{ $max } nit
+
 # Metadata panel
 metadata-panel-title = Informazioni file
 metadata-panel-close = Chiudi pannello
@@ -413,6 +931,7 @@ metadata-section-gps = Posizione
 metadata-label-dimensions = Dimensioni
 metadata-label-file-size = Dimensione file
 metadata-label-format = Formato
+metadata-label-decoder = Decoder
 metadata-label-date-taken = Data di acquisizione
 metadata-label-camera = Fotocamera
 metadata-label-exposure = Esposizione
@@ -420,11 +939,49 @@ metadata-label-aperture = Apertura
 metadata-label-iso = ISO
 metadata-label-focal-length = Lunghezza focale
 metadata-label-gps = Coordinate
+metadata-label-gps-dms = GMS
+metadata-open-map-button = Apri in OpenStreetMap
+metadata-all-tags-title = Tutti i tag ({ $count })
+metadata-all-tags-filter-placeholder = Filtra i tag…
+metadata-all-tags-no-match = Nessun tag corrisponde al filtro
+metadata-all-tags-copy-tooltip = Fai clic per copiare il valore
+metadata-batch-title = Modifica in blocco
+metadata-stats-title = Statistiche della cartella
+metadata-stats-refresh = Ricalcola statistiche
+metadata-export-title = Esporta metadati
+metadata-export-hint = Salva come JSON o CSV (in base all’estensione) per cataloghi e script
+metadata-export-file = Questo file
+metadata-export-folder = Intera cartella
+metadata-stats-loading = Lettura dei dati della fotocamera…
+metadata-stats-summary = { $count } file, { $exif } con dati della fotocamera
+metadata-stats-empty = Nessun dato della fotocamera in questa cartella
+metadata-stats-cameras = Fotocamere
+metadata-stats-lenses = Obiettivi
+metadata-stats-focal-lengths = Lunghezza focale (equiv. 35 mm)
+metadata-stats-iso = ISO
+metadata-batch-hint = Si applica ai { $count } file della cartella (o del filtro attivo). I campi vuoti restano invariati; i video vengono ignorati.
+metadata-batch-creator-label = Imposta autore
+metadata-batch-copyright-label = Imposta copyright
+metadata-batch-keywords-label = Aggiungi parole chiave (separate da virgole)
+metadata-batch-strip-gps = Rimuovi posizione GPS
+metadata-batch-apply-button = Applica a { $count } file
+metadata-batch-confirm-warning = Verranno modificati { $count } file originali. Continuare?
+metadata-batch-confirm-button = Applica
+metadata-batch-running = Scrittura dei metadati…
 metadata-label-codec = Codec
 metadata-label-bitrate = Bitrate
 metadata-label-duration = Durata
 metadata-label-fps = Fotogrammi al secondo
 metadata-value-unknown = Sconosciuto
+metadata-value-decoder-fallback = { $decoder } (ripiego)
+metadata-label-bit-depth = Profondità di bit
+metadata-value-bit-depth = { $bits } bit
+metadata-label-hdr = HDR
+metadata-value-hdr-pq = HDR10 (PQ)
+metadata-value-hdr-hlg = HLG
+metadata-label-mastering-display = Display di mastering
+metadata-label-max-cll = Luce max. del contenuto (MaxCLL)
+metadata-label-max-fall = Media max. per fotogramma (MaxFALL)
 
 # Modifica metadati
 metadata-edit-button = Modifica
@@ -433,7 +990,28 @@ metadata-cancel-button = Annulla
 metadata-save-button = Salva
 metadata-save-as-button = Salva con nome...
 metadata-save-warning = Salva modificherà il file originale
+metadata-gps-strip-pending = La posizione verrà rimossa dal file al salvataggio.
+metadata-strip-button = Rimuovi tutti i metadati
+metadata-strip-dialog-title = Rimuovi tutti i metadati
+metadata-strip-dialog-description = I seguenti metadati verranno rimossi. I dati dell'immagine non vengono modificati.
+metadata-strip-dialog-overwrite = Sovrascrivi file
+metadata-strip-dialog-copy = Salva copia pulita
+metadata-strip-dialog-cancel = Annulla
+recipe-batch-dialog-title = Incolla su tutte le immagini della cartella
+recipe-batch-dialog-description = Le regolazioni copiate verranno applicate a { $count } immagini.
+recipe-batch-dialog-copy = Ogni risultato viene salvato come copia numerata accanto all'originale. Gli originali non vengono modificati.
+recipe-batch-dialog-subfolder = I risultati vengono salvati nella sottocartella «{ $folder }», sostituendo le versioni modificate precedenti. Gli originali non vengono modificati.
+recipe-batch-dialog-skipped = Le immagini animate e i formati non scrivibili vengono saltati.
+recipe-batch-dialog-paste = Incolla
+recipe-batch-dialog-cancel = Annulla
+metadata-strip-block-exif = EXIF (fotocamera, data, posizione GPS)
+metadata-strip-block-xmp = XMP (titolo, autore, parole chiave)
+metadata-strip-block-iptc = IPTC (didascalia, copyright)
+metadata-strip-block-thumbnail = Miniatura incorporata
+metadata-strip-block-comment = Commenti e annotazioni di testo
 metadata-label-make = Marca
+metadata-remove-field-tooltip = Rimuovi il campo
+metadata-remove-keyword-tooltip = Rimuovi la parola chiave
 metadata-label-model = Modello
 metadata-label-focal-length-35mm = Lunghezza focale (35mm)
 metadata-label-flash = Flash
@@ -455,7 +1033,47 @@ metadata-validation-invalid-number = Numero non valido
 # Notifiche metadati
 notification-metadata-save-success = Metadati salvati con successo
 notification-metadata-save-error = Errore nel salvataggio dei metadati
+notification-metadata-batch-started = Aggiornamento dei metadati di { $count } file…
+notification-metadata-batch-success = Metadati aggiornati in { $updated } file ({ $skipped } ignorati)
+notification-metadata-batch-partial = Metadati aggiornati in { $updated } file, { $failed } non riusciti (primo: { $filename })
+notification-metadata-exported = Metadati di { $count } file esportati
+notification-metadata-export-error = Impossibile esportare i metadati: { $error }
+notification-plugins-reloaded = { $count } plugin caricati
+notification-plugins-folder-error = Impossibile aprire la cartella dei plugin: { $error }
+notification-logs-folder-error = Impossibile aprire la cartella dei registri: { $error }
+notification-diagnostic-report-copied = Rapporto diagnostico copiato negli appunti
+notification-plugin-tool-error = Strumento del plugin non riuscito: { $error }
+notification-plugin-export-success = { $filename } esportato
+notification-plugin-export-error = Esportazione del plugin non riuscita: { $error }
+notification-open-with-done = { $name }: completato
+notification-open-with-output = { $name }: { $output }
+notification-open-with-error = { $name } non riuscito: { $error }
+notification-verify-started = Verifica di { $count } file…
+notification-verify-clean = Tutti i { $count } file sono integri
+notification-verify-damaged = { $damaged } file su { $count } sono danneggiati (elenco copiato negli appunti)
+notification-share-empty = Nessun file da condividere in questa cartella
+notification-share-error = Impossibile condividere sulla porta { $port }: { $error }
+notification-share-stopped = Condivisione interrotta
+notification-share-url-copied = Indirizzo copiato negli appunti
+notification-strip-success = Tutti i metadati sono stati rimossi
+notification-layout-applied = Layout: { $name }
+notification-layout-saved = Layout «{ $name }» salvato
+layout-name-culling = Selezione
+layout-name-presentation = Presentazione
+layout-name-custom = Personalizzato
+notification-strip-copy-success = Copia pulita salvata come { $filename }
+notification-strip-error = Impossibile rimuovere i metadati: { $error }
+notification-strip-nothing = Questo file non contiene metadati rimovibili
+notification-strip-unsupported = La rimozione dei metadati non è supportata per questo formato di file
 notification-metadata-validation-error = Correggi gli errori di validazione prima di salvare
+notification-open-url-error = Impossibile aprire il collegamento: { $error }
+notification-copied-to-clipboard = Copiato negli appunti
+notification-color-copied = Colore { $color } copiato negli appunti
+notification-adjustments-copied = Regolazioni copiate (passaggi: { $count })
+notification-dismiss-tooltip = Chiudi
+notification-recipe-batch-started = Applicazione delle regolazioni a { $count } immagini…
+notification-recipe-batch-success = Regolazioni incollate su { $updated } immagini ({ $skipped } saltate)
+notification-recipe-batch-partial = Regolazioni incollate su { $updated } immagini, { $failed } non riuscite (prima: { $filename })
 
 # Divulgazione progressiva dei metadati
 metadata-add-field = Aggiungi campo metadati...
@@ -467,6 +1085,7 @@ metadata-label-dc-title = Titolo
 metadata-label-dc-creator = Creatore
 metadata-label-dc-description = Descrizione
 metadata-label-dc-subject = Parole chiave
+metadata-keyword-input-placeholder = Aggiungi una parola chiave e premi Invio
 metadata-label-dc-rights = Copyright
 
 navbar-info-button = Info
@@ -477,11 +1096,37 @@ empty-state-subtitle = Trascina i file qui o clicca per aprire
 empty-state-button = Apri file
 empty-state-drop-hint = Trascina e rilascia immagini o video ovunque
 
+# File dialog filters
+dialog-filter-all-media = Tutti i contenuti
+dialog-filter-images = Immagini
+dialog-filter-videos = Video
+dialog-filter-image-format = Immagine { $format }
+dialog-filter-video-format = Video { $format }
+
 # Additional notifications
 notification-empty-dir = Nessun file multimediale supportato trovato in questa cartella
 notification-load-error-io = Impossibile aprire il file. Verifica che esista e di avere i permessi necessari.
 notification-load-error-svg = Impossibile renderizzare il SVG. Il file potrebbe essere malformato.
 notification-load-error-video = Impossibile riprodurre il video. Il formato potrebbe non essere supportato.
+notification-image-sequence-not-found = Questa immagine non fa parte di una sequenza numerata.
+notification-capture-day = { $date } — { $count } file
+notification-similar-indexing = Confronto delle immagini della cartella…
+notification-similar-found = { $count } immagini simili
+notification-similar-none = Nessuna immagine simile in questa cartella
+notification-face-model-downloading = Download del modello di rilevamento volti…
+notification-no-faces-found = Nessun volto trovato in questa immagine.
+notification-clip-exported = Clip salvata in { $path }
+notification-clip-exported-reencoded = Clip ricodificata e salvata in { $path } (senza audio)
+notification-clip-export-failed = Impossibile esportare la clip: { $message }
+notification-frames-exported = { $count } fotogrammi salvati in { $path }
+notification-mini-player-error = Impossibile aprire il mini lettore
+notification-visible-area-exported = Area visibile salvata in { $path }
+notification-visible-area-export-error = Impossibile esportare l'area visibile
+notification-view-exported = Vista salvata in { $path }
+notification-view-export-error = Impossibile esportare la vista
+notification-view-copied = Vista copiata negli appunti
+notification-frames-export-failed = Impossibile esportare i fotogrammi: { $message }
+notification-face-detection-error = Rilevamento volti non riuscito: { $error }
 notification-load-error-timeout = Caricamento scaduto. Il file potrebbe essere troppo grande o il sistema è occupato.
 notification-skipped-corrupted-files = Saltati: { $files }
 notification-skipped-and-others = +{ $count } altri
@@ -515,6 +1160,19 @@ settings-upscale-status-error = Errore: { $message }
 settings-upscale-status-not-downloaded = Modello non scaricato
 settings-upscale-enabled = Abilitato
 settings-upscale-disabled = Disabilitato
+settings-enable-background-removal-label = Rimozione sfondo IA
+settings-enable-background-removal-hint = Abilita la rimozione dello sfondo con IA tramite il modello di segmentazione U²-Net (~176 MB da scaricare).
+settings-background-removal-model-url-label = URL del modello
+settings-background-removal-model-url-placeholder = https://github.com/...
+settings-background-removal-model-url-hint = URL da cui scaricare il modello ONNX U²-Net.
+settings-background-removal-status-label = Stato del modello
+settings-background-removal-status-downloading = Download del modello ({ $progress }%)...
+settings-background-removal-status-validating = Convalida del modello...
+settings-background-removal-status-ready = Modello pronto
+settings-background-removal-status-error = Errore: { $message }
+settings-background-removal-status-not-downloaded = Modello non scaricato
+settings-background-removal-enabled = Attivato
+settings-background-removal-disabled = Disattivato
 
 # Strumento di sfocatura dell'editor
 image-editor-tool-deblur = Sfocatura IA
@@ -528,12 +1186,49 @@ image-editor-deblur-validating = Validazione modello in corso, attendere...
 image-editor-deblur-downloading = Download modello ({ $progress }%)...
 image-editor-deblur-error = Errore: { $error }
 image-editor-deblur-already-applied = Sfocatura già applicata a questa immagine
+image-editor-tool-background = Rimozione sfondo IA
+image-editor-background-remove = Rimuovi sfondo
+image-editor-background-apply = Applica scontorno
+image-editor-background-processing = Rimozione dello sfondo...
+image-editor-background-model-not-ready = Abilita prima la rimozione sfondo IA nelle Impostazioni
+image-editor-background-validating = Convalida del modello, attendere...
+image-editor-background-downloading = Download del modello ({ $progress }%)...
+image-editor-background-error = Errore: { $error }
+image-editor-background-refine-hint = Dipingi sull'immagine per rifinire i bordi, poi applica.
+image-editor-background-brush-keep = Mantieni
+image-editor-background-brush-erase = Cancella
+image-editor-background-brush-size = Dimensione pennello
+image-editor-background-transparent-export-hint = Salva in PNG o WebP per mantenere la trasparenza; il JPEG la riempie di bianco.
+image-editor-tool-denoise = Riduzione rumore
+image-editor-denoise-method-label = Metodo
+image-editor-denoise-method-bilateral = Bilaterale
+image-editor-denoise-method-nlmeans = NL-means
+image-editor-denoise-method-model = Modello IA
+image-editor-denoise-strength-label = Intensità
+image-editor-denoise-detail-label = Conservazione dei dettagli
+image-editor-denoise-preview-hint = L'anteprima è calcolata su una copia ridotta; Applica elabora l'immagine intera.
+image-editor-denoise-model-hint = Il modello non ha anteprima; il risultato appare una volta applicato.
+image-editor-denoise-apply = Applica riduzione
+image-editor-denoise-processing = Riduzione del rumore...
+image-editor-plugins-section-title = Plugin
+image-editor-plugins-running = Plugin in esecuzione…
+image-editor-plugins-export = Esporta come { $format }
 
 # Sezione di aiuto IA
 help-editor-deblur-title = Sfocatura IA
 help-editor-deblur-desc = Usa l'IA per migliorare la nitidezza delle immagini sfocate con la rete neurale NAFNet.
 help-editor-deblur-enable = Abilita in Impostazioni → IA / Apprendimento automatico (scarica modello di ~92 MB)
 help-editor-deblur-lossless = Per una qualità migliore, esporta come WebP lossless o PNG
+help-editor-background-title = Rimozione sfondo IA
+help-editor-background-desc = Scontorna il soggetto dallo sfondo con il modello di segmentazione U²-Net, eseguito sul tuo computer.
+help-editor-background-enable = Abilita in Impostazioni → IA / Apprendimento automatico (scarica un modello di ~176 MB)
+help-editor-background-refine = Rifinisci i bordi con i pennelli Mantieni e Cancella prima di applicare
+help-editor-background-export = Salva in PNG o WebP per mantenere lo sfondo trasparente
+help-editor-denoise-title = Riduzione rumore
+help-editor-denoise-desc = Riduci la grana delle foto scattate con poca luce.
+help-editor-denoise-methods = Bilaterale è veloce; NL-means è più lento ma conserva più texture
+help-editor-denoise-sliders = Aumenta l'intensità per rimuovere più rumore e la conservazione dei dettagli per mantenere i bordi fini
+help-editor-denoise-model = Imposta denoise_model_url nella sezione [ai] di settings.toml per aggiungere un modello IA
 
 # Notifiche IA
 notification-deblur-success = Immagine sfocata con successo
@@ -551,3 +1246,9 @@ notification-upscale-download-error = Impossibile scaricare il modello di upscal
 notification-upscale-validation-error = Validazione del modello fallita: { $error }
 notification-upscale-resize-success = Immagine ridimensionata con upscaling IA
 notification-upscale-resize-error = Errore di upscaling IA: { $error }
+notification-background-ready = La rimozione sfondo IA è pronta
+notification-background-download-error = Download del modello di rimozione sfondo non riuscito: { $error }
+notification-background-validation-error = Convalida del modello non riuscita: { $error }
+notification-background-removal-error = Rimozione dello sfondo non riuscita: { $error }
+notification-denoise-model-downloading = Download del modello di riduzione del rumore…
+notification-denoise-error = Riduzione del rumore non riuscita: { $error }
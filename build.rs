@@ -381,6 +381,9 @@ fn needs_light_variant(name: &str) -> bool {
             | "triangle_bar_right"
             | "camera"
             | "ellipsis_horizontal"
+            | "double_triangle_bar_left"
+            | "double_triangle_bar_right"
+            | "bullet_list"
             // Navbar
             | "hamburger"
             // Viewer toolbar
@@ -392,11 +395,14 @@ fn needs_light_variant(name: &str) -> bool {
             | "fullscreen"
             | "trash"
             | "funnel"
+            | "ruler"
+            | "grid"
             // HUD indicators
             | "crosshair"
             | "magnifier"
             | "video_camera"
             | "video_camera_audio"
+            | "image"
             // Notifications
             | "warning"
             | "checkmark"
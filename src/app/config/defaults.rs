@@ -11,6 +11,8 @@
 //! - **Volume**: Audio playback volume settings
 //! - **Frame Cache**: Video frame caching for seek performance
 //! - **Playback Speed**: Video playback speed control
+//! - **Image Sequence**: Frame rate for numbered image sequences played as video
+//! - **Interface Scale**: Scale of the whole interface
 
 // ==========================================================================
 // Zoom Defaults
@@ -47,18 +49,30 @@ pub const MIN_OVERLAY_TIMEOUT_SECS: u32 = 1;
 /// Maximum overlay timeout (in seconds).
 pub const MAX_OVERLAY_TIMEOUT_SECS: u32 = 30;
 
+/// Default opacity of the fullscreen info overlay (in percent).
+pub const DEFAULT_OVERLAY_OPACITY_PERCENT: u32 = 100;
+
+/// Minimum opacity of the fullscreen info overlay (in percent).
+pub const MIN_OVERLAY_OPACITY_PERCENT: u32 = 20;
+
+/// Maximum opacity of the fullscreen info overlay (in percent).
+pub const MAX_OVERLAY_OPACITY_PERCENT: u32 = 100;
+
 // ==========================================================================
 // Volume Defaults
 // ==========================================================================
 
-/// Default video playback volume (0.0 to 1.5, where 1.0 = 100%).
+/// Default video playback volume (0.0 to 2.0, where 1.0 = 100%).
 pub const DEFAULT_VOLUME: f32 = 0.8;
 
 /// Minimum volume level.
 pub const MIN_VOLUME: f32 = 0.0;
 
-/// Maximum volume level (1.5 = 150% amplification).
-pub const MAX_VOLUME: f32 = 1.5;
+/// Volume at which audio plays unchanged (100%); above it, audio is boosted.
+pub const UNITY_VOLUME: f32 = 1.0;
+
+/// Maximum volume level (2.0 = 200% boost).
+pub const MAX_VOLUME: f32 = 2.0;
 
 /// Volume adjustment step per key press (5%).
 pub const VOLUME_STEP: f32 = 0.05;
@@ -102,6 +116,14 @@ pub const MIN_FRAME_HISTORY_MB: u32 = 32;
 /// Maximum frame history size in megabytes.
 pub const MAX_FRAME_HISTORY_MB: u32 = 512;
 
+// ==========================================================================
+// Export Defaults
+// ==========================================================================
+
+/// Default filename template for captured video frames.
+/// See `media::naming` for the available tokens.
+pub const DEFAULT_FRAME_NAME_TEMPLATE: &str = "{name}_frame_{timestamp}";
+
 // ==========================================================================
 // AI/Deblur Defaults
 // ==========================================================================
@@ -110,6 +132,14 @@ pub const MAX_FRAME_HISTORY_MB: u32 = 512;
 pub const DEFAULT_DEBLUR_MODEL_URL: &str =
     "https://huggingface.co/opencv/deblurring_nafnet/resolve/main/deblurring_nafnet_2025may.onnx";
 
+// ==========================================================================
+// AI/Face Detection Defaults
+// ==========================================================================
+
+/// Default URL for downloading the `UltraFace` RFB-320 ONNX face detection model.
+pub const DEFAULT_FACE_MODEL_URL: &str =
+    "https://github.com/onnx/models/raw/main/validated/vision/body_analysis/ultraface/models/version-RFB-320.onnx";
+
 // ==========================================================================
 // AI/Upscale Defaults
 // ==========================================================================
@@ -119,6 +149,14 @@ pub const DEFAULT_DEBLUR_MODEL_URL: &str =
 pub const DEFAULT_UPSCALE_MODEL_URL: &str =
     "https://huggingface.co/CountFloyd/deepfake/resolve/main/real_esrgan_x4.onnx";
 
+// ==========================================================================
+// AI/Background Removal Defaults
+// ==========================================================================
+
+/// Default URL for downloading the U²-Net ONNX segmentation model.
+pub const DEFAULT_BACKGROUND_MODEL_URL: &str =
+    "https://github.com/danielgatis/rembg/releases/download/v0.0.0/u2net.onnx";
+
 // ==========================================================================
 // Resize Scale Defaults (Image Editor)
 // ==========================================================================
@@ -147,6 +185,60 @@ pub const MIN_MAX_SKIP_ATTEMPTS: u32 = 1;
 /// Maximum max skip attempts (prevent excessive loops).
 pub const MAX_MAX_SKIP_ATTEMPTS: u32 = 20;
 
+/// Default number of decoded media kept for instant navigation: the one on
+/// screen and its next and previous neighbors.
+pub const DEFAULT_PREFETCH_CACHE_SIZE: u32 = 3;
+
+/// Minimum prefetch cache size (0 disables prefetching).
+pub const MIN_PREFETCH_CACHE_SIZE: u32 = 0;
+
+/// Maximum prefetch cache size (each entry holds a full decoded image).
+pub const MAX_PREFETCH_CACHE_SIZE: u32 = 10;
+
+// ==========================================================================
+// Interface Scale Defaults
+// ==========================================================================
+
+/// Default interface scale (percent), on top of the system scale factor.
+pub const DEFAULT_UI_SCALE_PERCENT: u32 = 100;
+
+/// Minimum interface scale (percent).
+pub const MIN_UI_SCALE_PERCENT: u32 = 75;
+
+/// Maximum interface scale (percent), for low-vision setups.
+pub const MAX_UI_SCALE_PERCENT: u32 = 200;
+
+/// Step of the interface scale slider (percent).
+pub const UI_SCALE_STEP_PERCENT: u32 = 5;
+
+// ==========================================================================
+// Similar-Image Navigation Defaults
+// ==========================================================================
+
+/// Default minimum similarity (percent) for "Find similar" to match a file.
+/// 90% tolerates small shifts between burst shots of the same scene.
+pub const DEFAULT_SIMILARITY_THRESHOLD: u32 = 90;
+
+/// Minimum similarity threshold (below this, unrelated images match).
+pub const MIN_SIMILARITY_THRESHOLD: u32 = 50;
+
+/// Maximum similarity threshold (100 = identical perceptual hashes only).
+pub const MAX_SIMILARITY_THRESHOLD: u32 = 100;
+
+// ==========================================================================
+// Editor Defaults
+// ==========================================================================
+
+/// Default name of the subfolder edited copies are saved into.
+pub const DEFAULT_EDITED_FOLDER: &str = "Edited";
+
+// ==========================================================================
+// LAN Sharing Defaults
+// ==========================================================================
+
+/// Default port of the "Share over LAN" gallery server.
+pub const DEFAULT_SHARE_PORT: u16 = 8765;
+
 // ==========================================================================
 // Playback Speed Defaults
 // ==========================================================================
@@ -163,12 +255,44 @@ pub const MAX_PLAYBACK_SPEED: f64 = 8.0;
 /// Playback speed presets for the speed control buttons.
 /// Ordered from slowest to fastest. Users cycle through these with J and L keys.
 pub const PLAYBACK_SPEED_PRESETS: &[f64] = &[
-    0.1, 0.15, 0.2, 0.25, 0.33, 0.5, 0.75, 1.0, 1.25, 1.5, 2.0, 4.0, 8.0,
+    0.1, 0.15, 0.2, 0.25, 0.33, 0.5, 0.75, 1.0, 1.25, 1.5, 2.0, 3.0, 4.0, 8.0,
 ];
 
+/// Speeds offered by the speed selector in the video controls.
+/// Audio stays audible (time-stretched, at its original pitch) for all of them.
+pub const PLAYBACK_SPEED_SELECTOR_CHOICES: &[f64] =
+    &[0.25, 0.5, 0.75, 1.0, 1.25, 1.5, 1.75, 2.0, 2.5, 3.0];
+
 /// Speed threshold above which audio is automatically muted.
-/// At speeds > 2x, audio becomes distorted and unintelligible.
-pub const PLAYBACK_SPEED_AUTO_MUTE_THRESHOLD: f64 = 2.0;
+/// Up to 3x, audio is time-stretched and speech stays intelligible.
+pub const PLAYBACK_SPEED_AUTO_MUTE_THRESHOLD: f64 = 3.0;
+
+/// Speed threshold below which audio is automatically muted.
+/// Below 0.25x, stretched audio turns into a smeared drone.
+pub const PLAYBACK_SPEED_AUTO_MUTE_LOW_THRESHOLD: f64 = 0.25;
+
+// ==========================================================================
+// Image Sequence Defaults
+// ==========================================================================
+
+/// Default frame rate for playing numbered image sequences as video.
+pub const DEFAULT_SEQUENCE_FPS: u32 = 24;
+
+/// Minimum image sequence frame rate.
+pub const MIN_SEQUENCE_FPS: u32 = 1;
+
+/// Maximum image sequence frame rate.
+pub const MAX_SEQUENCE_FPS: u32 = 120;
+
+// ==========================================================================
+// Subtitle Defaults
+// ==========================================================================
+
+/// Step of the subtitle delay buttons, in milliseconds.
+pub const SUBTITLE_DELAY_STEP_MS: i32 = 100;
+
+/// Maximum subtitle delay in either direction, in milliseconds.
+pub const MAX_SUBTITLE_DELAY_MS: i32 = 60_000;
 
 // ==========================================================================
 // Compile-time Validation
@@ -189,6 +313,9 @@ const _: () = {
     assert!(MAX_OVERLAY_TIMEOUT_SECS >= MIN_OVERLAY_TIMEOUT_SECS);
     assert!(DEFAULT_OVERLAY_TIMEOUT_SECS >= MIN_OVERLAY_TIMEOUT_SECS);
     assert!(DEFAULT_OVERLAY_TIMEOUT_SECS <= MAX_OVERLAY_TIMEOUT_SECS);
+    assert!(MIN_OVERLAY_OPACITY_PERCENT > 0);
+    assert!(DEFAULT_OVERLAY_OPACITY_PERCENT >= MIN_OVERLAY_OPACITY_PERCENT);
+    assert!(DEFAULT_OVERLAY_OPACITY_PERCENT <= MAX_OVERLAY_OPACITY_PERCENT);
 
     // Frame cache validation
     assert!(MIN_FRAME_CACHE_MB > 0);
@@ -202,12 +329,22 @@ const _: () = {
     assert!(DEFAULT_FRAME_HISTORY_MB >= MIN_FRAME_HISTORY_MB);
     assert!(DEFAULT_FRAME_HISTORY_MB <= MAX_FRAME_HISTORY_MB);
 
+    // Subtitle delay validation
+    assert!(SUBTITLE_DELAY_STEP_MS > 0);
+    assert!(MAX_SUBTITLE_DELAY_MS >= SUBTITLE_DELAY_STEP_MS);
+
     // Keyboard seek step validation
     assert!(MIN_KEYBOARD_SEEK_STEP_SECS > 0.0);
     assert!(MAX_KEYBOARD_SEEK_STEP_SECS >= MIN_KEYBOARD_SEEK_STEP_SECS);
     assert!(DEFAULT_KEYBOARD_SEEK_STEP_SECS >= MIN_KEYBOARD_SEEK_STEP_SECS);
     assert!(DEFAULT_KEYBOARD_SEEK_STEP_SECS <= MAX_KEYBOARD_SEEK_STEP_SECS);
 
+    // Image sequence frame rate validation
+    assert!(MIN_SEQUENCE_FPS > 0);
+    assert!(MAX_SEQUENCE_FPS >= MIN_SEQUENCE_FPS);
+    assert!(DEFAULT_SEQUENCE_FPS >= MIN_SEQUENCE_FPS);
+    assert!(DEFAULT_SEQUENCE_FPS <= MAX_SEQUENCE_FPS);
+
     // Playback speed validation
     assert!(MIN_PLAYBACK_SPEED > 0.0);
     assert!(MAX_PLAYBACK_SPEED > MIN_PLAYBACK_SPEED);
@@ -215,6 +352,8 @@ const _: () = {
     assert!(DEFAULT_PLAYBACK_SPEED <= MAX_PLAYBACK_SPEED);
     assert!(PLAYBACK_SPEED_AUTO_MUTE_THRESHOLD > 1.0);
     assert!(PLAYBACK_SPEED_AUTO_MUTE_THRESHOLD <= MAX_PLAYBACK_SPEED);
+    assert!(PLAYBACK_SPEED_AUTO_MUTE_LOW_THRESHOLD < 1.0);
+    assert!(PLAYBACK_SPEED_AUTO_MUTE_LOW_THRESHOLD >= MIN_PLAYBACK_SPEED);
 
     // Ensure presets array is not empty
     assert!(!PLAYBACK_SPEED_PRESETS.is_empty());
@@ -256,4 +395,20 @@ const _: () = {
     assert!(MAX_MAX_SKIP_ATTEMPTS >= MIN_MAX_SKIP_ATTEMPTS);
     assert!(DEFAULT_MAX_SKIP_ATTEMPTS >= MIN_MAX_SKIP_ATTEMPTS);
     assert!(DEFAULT_MAX_SKIP_ATTEMPTS <= MAX_MAX_SKIP_ATTEMPTS);
+    assert!(MAX_PREFETCH_CACHE_SIZE >= MIN_PREFETCH_CACHE_SIZE);
+    assert!(DEFAULT_PREFETCH_CACHE_SIZE >= MIN_PREFETCH_CACHE_SIZE);
+    assert!(DEFAULT_PREFETCH_CACHE_SIZE <= MAX_PREFETCH_CACHE_SIZE);
+
+    // Interface scale validation
+    assert!(MIN_UI_SCALE_PERCENT > 0);
+    assert!(MAX_UI_SCALE_PERCENT > MIN_UI_SCALE_PERCENT);
+    assert!(DEFAULT_UI_SCALE_PERCENT >= MIN_UI_SCALE_PERCENT);
+    assert!(DEFAULT_UI_SCALE_PERCENT <= MAX_UI_SCALE_PERCENT);
+    assert!((MAX_UI_SCALE_PERCENT - MIN_UI_SCALE_PERCENT) % UI_SCALE_STEP_PERCENT == 0);
+
+    // Similarity threshold validation
+    assert!(MIN_SIMILARITY_THRESHOLD > 0);
+    assert!(MAX_SIMILARITY_THRESHOLD <= 100);
+    assert!(DEFAULT_SIMILARITY_THRESHOLD >= MIN_SIMILARITY_THRESHOLD);
+    assert!(DEFAULT_SIMILARITY_THRESHOLD <= MAX_SIMILARITY_THRESHOLD);
 };
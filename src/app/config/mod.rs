@@ -5,11 +5,19 @@
 //! # Configuration Sections
 //!
 //! The configuration is organized into logical sections:
-//! - `[general]` - Language and theme mode
+//! - `[general]` - Language, theme, accent color and interface scale
 //! - `[display]` - Viewer display settings (zoom, background, sorting)
 //! - `[video]` - Video playback settings (volume, caching, seek step)
 //! - `[fullscreen]` - Fullscreen overlay settings
+//! - `[accessibility]` - Reduced motion, focus outlines and hit targets
 //! - `[ai]` - AI/Machine Learning settings (deblurring model)
+//! - `[input]` - Mouse buttons and wheel in the viewer
+//! - `[shortcuts]` - Keyboard shortcuts changed by the user
+//! - `[share]` - "Share over LAN" gallery server
+//! - `[plugins]` - Installed plugins turned off by the user
+//! - `[editor]` - Where the image editor saves edited images
+//! - `[updates]` - Weekly check for a newer release
+//! - `[[open_with]]` - External commands of the "Open with / Send to" menu
 //!
 //! # Path Resolution
 //!
@@ -18,6 +26,14 @@
 //! 2. Set `ICED_LENS_CONFIG_DIR` environment variable
 //! 3. Falls back to platform-specific config directory
 //!
+//! # Concurrent Writers
+//!
+//! Several windows or instances may save `settings.toml`. Saves hold an
+//! advisory lock on a `settings.toml.lock` file next to it and replace the
+//! file atomically. [`save_merged()`] also detects changes written by someone
+//! else since the caller's last load and keeps them for every section the
+//! caller did not change itself.
+//!
 //! # Migration
 //!
 //! Old flat config files (pre-0.3.0) are automatically migrated to the new
@@ -46,14 +62,19 @@ pub use defaults::*;
 
 use crate::app::paths;
 use crate::error::{Error, Result};
+use crate::media::decoders::WebpDecoder;
 use crate::media::filter::MediaFilter;
 use crate::ui::theming::ThemeMode;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 const CONFIG_FILE: &str = "settings.toml";
 
+/// Suggested name for settings exported to another file.
+pub const EXPORT_FILE_NAME: &str = "iced_lens-settings.toml";
+
 // =============================================================================
 // Enums (shared between sections)
 // =============================================================================
@@ -65,15 +86,283 @@ pub enum BackgroundTheme {
     #[default]
     Dark,
     Checkerboard,
+    /// Dark behind bright images and light behind dark ones.
+    Auto,
+}
+
+impl BackgroundTheme {
+    /// Average luminance above which an image counts as bright.
+    pub const AUTO_BRIGHT_THRESHOLD: f32 = 0.5;
+
+    /// Resolves [`BackgroundTheme::Auto`] from the displayed image's average
+    /// luminance (0.0–1.0); other themes are returned unchanged.
+    ///
+    /// Falls back to dark while the luminance is unknown.
+    #[must_use]
+    pub fn resolve(self, luminance: Option<f32>) -> Self {
+        match (self, luminance) {
+            (Self::Auto, Some(luminance)) if luminance < Self::AUTO_BRIGHT_THRESHOLD => Self::Light,
+            (Self::Auto, _) => Self::Dark,
+            (theme, _) => theme,
+        }
+    }
+}
+
+/// Text size of video subtitles.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum SubtitleSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl SubtitleSize {
+    /// Returns the font size in logical pixels.
+    #[must_use]
+    pub fn font_size(self) -> f32 {
+        match self {
+            Self::Small => 20.0,
+            Self::Medium => 28.0,
+            Self::Large => 38.0,
+        }
+    }
+
+    /// Returns the next size, wrapping around after the largest.
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            Self::Small => Self::Medium,
+            Self::Medium => Self::Large,
+            Self::Large => Self::Small,
+        }
+    }
+
+    /// Returns the i18n key of the size name.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            Self::Small => "video-subtitle-size-small",
+            Self::Medium => "video-subtitle-size-medium",
+            Self::Large => "video-subtitle-size-large",
+        }
+    }
+}
+
+/// Audio visualization shown under the video seek bar.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum AudioVisualization {
+    #[default]
+    Off,
+    /// Loudness of the whole track, analyzed in the background.
+    Waveform,
+    /// Live level of the audio being played.
+    VuMeter,
+}
+
+impl AudioVisualization {
+    /// Returns the next mode, wrapping around after the last.
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Waveform,
+            Self::Waveform => Self::VuMeter,
+            Self::VuMeter => Self::Off,
+        }
+    }
+
+    /// Returns the i18n key of the mode name.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            Self::Off => "video-audio-visualization-off",
+            Self::Waveform => "video-audio-visualization-waveform",
+            Self::VuMeter => "video-audio-visualization-vu-meter",
+        }
+    }
+}
+
+/// Curve bringing the highlights of HDR video into the display's range.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ToneMapping {
+    /// Cut everything above SDR white.
+    Clip,
+    /// Soft roll-off that keeps the midtones.
+    Reinhard,
+    /// Filmic curve with a gentle shoulder.
+    #[default]
+    Hable,
+    /// Contrasty filmic curve of the ACES reference transform.
+    Aces,
+}
+
+impl ToneMapping {
+    /// All operators, in the order shown in the settings.
+    pub const ALL: [Self; 4] = [Self::Clip, Self::Reinhard, Self::Hable, Self::Aces];
+
+    /// Returns the i18n key of the operator name.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            Self::Clip => "settings-tone-mapping-clip",
+            Self::Reinhard => "settings-tone-mapping-reinhard",
+            Self::Hable => "settings-tone-mapping-hable",
+            Self::Aces => "settings-tone-mapping-aces",
+        }
+    }
+}
+
+/// Where the fullscreen info overlay (file name, index, clock, histogram)
+/// sits on the screen.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum OverlayPosition {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomCenter,
+    BottomRight,
+}
+
+impl OverlayPosition {
+    /// All positions, in the order shown in the settings.
+    pub const ALL: [Self; 6] = [
+        Self::TopLeft,
+        Self::TopCenter,
+        Self::TopRight,
+        Self::BottomLeft,
+        Self::BottomCenter,
+        Self::BottomRight,
+    ];
+
+    /// Returns the i18n key of the position name.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            Self::TopLeft => "settings-overlay-position-top-left",
+            Self::TopCenter => "settings-overlay-position-top-center",
+            Self::TopRight => "settings-overlay-position-top-right",
+            Self::BottomLeft => "settings-overlay-position-bottom-left",
+            Self::BottomCenter => "settings-overlay-position-bottom-center",
+            Self::BottomRight => "settings-overlay-position-bottom-right",
+        }
+    }
+
+    /// Returns true for the positions along the top edge.
+    #[must_use]
+    pub fn is_top(self) -> bool {
+        matches!(self, Self::TopLeft | Self::TopCenter | Self::TopRight)
+    }
+}
+
+/// What happens when a video is reopened where it was left.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ResumePlayback {
+    /// Offer to resume from the remembered position.
+    #[default]
+    Ask,
+    /// Resume from the remembered position without asking.
+    Always,
+    /// Always start from the beginning; positions aren't remembered.
+    Never,
+}
+
+/// Where the editor saves edited images.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum SavePolicy {
+    /// Replace the original file.
+    #[default]
+    Overwrite,
+    /// Save a copy next to the original with a numbered suffix
+    /// (`img_edit1.jpg`, `img_edit2.jpg`, …).
+    Copy,
+    /// Save a copy into a subfolder of the original's folder.
+    Subfolder,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "kebab-case")]
 pub enum SortOrder {
+    /// By filename, with numbers compared by value (`img2` before `img10`).
     #[default]
     Alphabetical,
     ModifiedDate,
     CreatedDate,
+    /// By file size on disk.
+    FileSize,
+    /// By file extension, then by filename.
+    FileType,
+}
+
+/// What the mouse wheel does over the media in the viewer.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum WheelAction {
+    #[default]
+    Zoom,
+    /// Go to the previous (up) or next (down) media.
+    Navigate,
+    /// Change the volume over videos; images are zoomed.
+    Volume,
+}
+
+/// What a middle click does over the media in the viewer.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum MiddleClickAction {
+    /// Switch between fit to window and the current zoom.
+    #[default]
+    ToggleFit,
+    None,
+}
+
+/// Mouse button that pans zoomed media in the viewer.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum DragButton {
+    #[default]
+    Left,
+    Middle,
+    Right,
+}
+
+/// Direction applied on top of the [`SortOrder`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// Sort order and direction used when scanning a directory.
+///
+/// Converts from a bare [`SortOrder`] (ascending) so callers that don't care
+/// about direction can keep passing the order alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MediaSort {
+    pub order: SortOrder,
+    pub direction: SortDirection,
+}
+
+impl MediaSort {
+    #[must_use]
+    pub fn new(order: SortOrder, direction: SortDirection) -> Self {
+        Self { order, direction }
+    }
+}
+
+impl From<SortOrder> for MediaSort {
+    fn from(order: SortOrder) -> Self {
+        Self::new(order, SortDirection::Ascending)
+    }
 }
 
 // =============================================================================
@@ -93,6 +382,18 @@ pub struct GeneralConfig {
         deserialize_with = "deserialize_theme_mode"
     )]
     pub theme_mode: ThemeMode,
+
+    /// Accent color of the interface as `#rrggbb`; the brand blue if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent_color: Option<String>,
+
+    /// Whether the custom palette of the theme file replaces the light or
+    /// dark theme; see [`crate::ui::theming::CustomPalette`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_theme: Option<bool>,
+    /// Scale of the whole interface in percent (75–200).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ui_scale_percent: Option<u32>,
 }
 
 impl Default for GeneralConfig {
@@ -100,6 +401,9 @@ impl Default for GeneralConfig {
         Self {
             language: None,
             theme_mode: default_theme_mode(),
+            accent_color: None,
+            custom_theme: None,
+            ui_scale_percent: None,
         }
     }
 }
@@ -126,6 +430,10 @@ pub struct DisplayConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sort_order: Option<SortOrder>,
 
+    /// Whether media are sorted ascending or descending.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_direction: Option<SortDirection>,
+
     /// Maximum number of corrupted files to auto-skip during navigation.
     /// When navigating (next/prev), if media fails to load, auto-skip to next.
     #[serde(
@@ -134,11 +442,49 @@ pub struct DisplayConfig {
     )]
     pub max_skip_attempts: Option<u32>,
 
+    /// Number of decoded media kept so that the next and previous files
+    /// show instantly (0 disables prefetching).
+    #[serde(
+        default = "default_prefetch_cache_size",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub prefetch_cache_size: Option<u32>,
+
     /// Whether to persist media filters across sessions.
     /// When enabled, the current filter is saved and restored on restart.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub persist_filters: Option<bool>,
 
+    /// Whether images are decoded in a separate helper process, so a decoder
+    /// crash on a malicious or damaged file can't take down the viewer.
+    #[serde(
+        default = "default_sandboxed_decoding",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sandboxed_decoding: Option<bool>,
+
+    /// Library preferred for still WebP images (the other one is tried if
+    /// it fails).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webp_decoder: Option<WebpDecoder>,
+
+    /// Minimum similarity (percent) for "Find similar" to jump to a file.
+    #[serde(
+        default = "default_similarity_threshold",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub similarity_threshold: Option<u32>,
+
+    /// Whether the zoom, scroll position and rotation of each image are
+    /// restored when coming back to it during the session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remember_view_per_image: Option<bool>,
+
+    /// Whether a minimap of the image is shown while zoomed in past the
+    /// window.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_minimap: Option<bool>,
+
     /// Persisted media filter (only saved when `persist_filters` is true).
     /// Uses the [`MediaFilter`] structure for filtering by media type and date range.
     #[serde(default, skip_serializing_if = "skip_serializing_filter")]
@@ -152,13 +498,31 @@ impl Default for DisplayConfig {
             zoom_step: Some(DEFAULT_ZOOM_STEP_PERCENT),
             background_theme: Some(BackgroundTheme::default()),
             sort_order: Some(SortOrder::default()),
+            sort_direction: Some(SortDirection::default()),
             max_skip_attempts: Some(DEFAULT_MAX_SKIP_ATTEMPTS),
+            prefetch_cache_size: Some(DEFAULT_PREFETCH_CACHE_SIZE),
             persist_filters: Some(false),
+            sandboxed_decoding: Some(false),
+            webp_decoder: Some(WebpDecoder::default()),
+            similarity_threshold: Some(DEFAULT_SIMILARITY_THRESHOLD),
+            remember_view_per_image: Some(false),
+            show_minimap: Some(true),
             filter: None,
         }
     }
 }
 
+impl DisplayConfig {
+    /// Returns the configured sort order and direction, with defaults for unset values.
+    #[must_use]
+    pub fn media_sort(&self) -> MediaSort {
+        MediaSort::new(
+            self.sort_order.unwrap_or_default(),
+            self.sort_direction.unwrap_or_default(),
+        )
+    }
+}
+
 /// Video playback settings.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct VideoConfig {
@@ -199,12 +563,65 @@ pub struct VideoConfig {
     )]
     pub frame_history_mb: Option<u32>,
 
+    /// Size the frame cache and history from the video resolution and the
+    /// available memory, ignoring `frame_cache_mb` and `frame_history_mb`.
+    #[serde(
+        default = "default_frame_cache_auto",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub frame_cache_auto: Option<bool>,
+
     /// Keyboard seek step in seconds (arrow keys).
     #[serde(
         default = "default_keyboard_seek_step_secs",
         skip_serializing_if = "Option::is_none"
     )]
     pub keyboard_seek_step_secs: Option<f64>,
+
+    /// Frame rate for playing numbered image sequences as video.
+    #[serde(
+        default = "default_sequence_fps",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sequence_fps: Option<u32>,
+
+    /// Filename template for captured frames (see `media::naming`).
+    #[serde(
+        default = "default_frame_name_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub frame_name_template: Option<String>,
+
+    /// Text size of subtitles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle_size: Option<SubtitleSize>,
+
+    /// Whether subtitles are drawn on a translucent box.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle_background: Option<bool>,
+
+    /// Decode videos on the GPU when supported, falling back to software.
+    #[serde(
+        default = "default_hardware_decoding",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hardware_decoding: Option<bool>,
+
+    /// Audio visualization strip under the seek bar.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_visualization: Option<AudioVisualization>,
+
+    /// Whether reopened videos resume where they were left.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resume_playback: Option<ResumePlayback>,
+
+    /// Tone mapping curve of HDR videos.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tone_mapping: Option<ToneMapping>,
+
+    /// Id of the audio output device, `None` for the system default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_device: Option<String>,
 }
 
 impl Default for VideoConfig {
@@ -217,7 +634,17 @@ impl Default for VideoConfig {
             audio_normalization: default_audio_normalization(),
             frame_cache_mb: default_frame_cache_mb(),
             frame_history_mb: default_frame_history_mb(),
+            frame_cache_auto: default_frame_cache_auto(),
             keyboard_seek_step_secs: default_keyboard_seek_step_secs(),
+            sequence_fps: default_sequence_fps(),
+            frame_name_template: default_frame_name_template(),
+            subtitle_size: Some(SubtitleSize::default()),
+            subtitle_background: Some(true),
+            hardware_decoding: default_hardware_decoding(),
+            audio_visualization: Some(AudioVisualization::default()),
+            resume_playback: Some(ResumePlayback::default()),
+            tone_mapping: Some(ToneMapping::default()),
+            audio_device: None,
         }
     }
 }
@@ -231,21 +658,57 @@ pub struct FullscreenConfig {
         skip_serializing_if = "Option::is_none"
     )]
     pub overlay_timeout_secs: Option<u32>,
+
+    /// Shows the file name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_filename: Option<bool>,
+
+    /// Shows the position in the directory ("12/340").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_index: Option<bool>,
+
+    /// Shows the time of day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_clock: Option<bool>,
+
+    /// Shows the previous/next arrows.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_arrows: Option<bool>,
+
+    /// Shows a luminance histogram of the media.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_histogram: Option<bool>,
+
+    /// Where the file name, index, clock and histogram are shown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overlay_position: Option<OverlayPosition>,
+
+    /// Opacity of the file name, index, clock and histogram (20–100%).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overlay_opacity_percent: Option<u32>,
 }
 
 impl Default for FullscreenConfig {
     fn default() -> Self {
         Self {
             overlay_timeout_secs: Some(DEFAULT_OVERLAY_TIMEOUT_SECS),
+            show_filename: Some(false),
+            show_index: Some(true),
+            show_clock: Some(false),
+            show_arrows: Some(true),
+            show_histogram: Some(false),
+            overlay_position: Some(OverlayPosition::default()),
+            overlay_opacity_percent: Some(DEFAULT_OVERLAY_OPACITY_PERCENT),
         }
     }
 }
 
 /// AI/Machine Learning settings.
 ///
-/// Note: The `enable_deblur` and `enable_upscale` states are stored in persistent
-/// application state, not in configuration, as they are managed by the application
-/// (download/validation) rather than being user preferences.
+/// Note: The `enable_deblur`, `enable_upscale` and `enable_background_removal`
+/// states are stored in persistent application state, not in configuration, as
+/// they are managed by the application (download/validation) rather than being
+/// user preferences.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AiConfig {
     /// URL for downloading the `NAFNet` ONNX model.
@@ -261,6 +724,18 @@ pub struct AiConfig {
         skip_serializing_if = "Option::is_none"
     )]
     pub upscale_model_url: Option<String>,
+
+    /// URL for downloading the U²-Net ONNX background removal model.
+    #[serde(
+        default = "default_background_model_url",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub background_model_url: Option<String>,
+
+    /// URL for downloading an ONNX denoising model, offered by the denoise
+    /// tool when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub denoise_model_url: Option<String>,
 }
 
 impl Default for AiConfig {
@@ -268,10 +743,207 @@ impl Default for AiConfig {
         Self {
             deblur_model_url: default_deblur_model_url(),
             upscale_model_url: default_upscale_model_url(),
+            background_model_url: default_background_model_url(),
+            denoise_model_url: None,
+        }
+    }
+}
+
+/// Mouse settings of the viewer.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InputConfig {
+    /// Whether the back and forward mouse buttons go to the previous and
+    /// next media.
+    #[serde(
+        default = "default_navigation_buttons",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub navigation_buttons: Option<bool>,
+
+    /// Action of the middle button.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub middle_click: Option<MiddleClickAction>,
+
+    /// Action of the wheel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wheel: Option<WheelAction>,
+
+    /// Button that pans zoomed media.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drag_button: Option<DragButton>,
+}
+
+impl Default for InputConfig {
+    fn default() -> Self {
+        Self {
+            navigation_buttons: Some(true),
+            middle_click: Some(MiddleClickAction::default()),
+            wheel: Some(WheelAction::default()),
+            drag_button: Some(DragButton::default()),
+        }
+    }
+}
+
+/// Keyboard shortcuts changed by the user.
+///
+/// Maps action names to keys, such as `rotate-clockwise = "T"`. Actions left
+/// out keep their default key; see [`crate::ui::keymap`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ShortcutsConfig {
+    #[serde(flatten)]
+    pub bindings: BTreeMap<String, String>,
+}
+
+/// Settings of the "Share over LAN" gallery server.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShareConfig {
+    /// TCP port the gallery is served on.
+    #[serde(
+        default = "default_share_port",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub port: Option<u16>,
+
+    /// Whether videos are shared along with the images.
+    #[serde(
+        default = "default_share_videos",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub include_videos: Option<bool>,
+}
+
+impl Default for ShareConfig {
+    fn default() -> Self {
+        Self {
+            port: default_share_port(),
+            include_videos: default_share_videos(),
+        }
+    }
+}
+
+/// Accessibility options; see [`crate::ui::accessibility`]. All are off by
+/// default.
+#[allow(clippy::struct_field_names)] // Field names match the TOML keys
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AccessibilityConfig {
+    /// Stops the spinner and other animations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reduced_motion: Option<bool>,
+
+    /// Draws a thick, high-contrast outline around the focused field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub high_contrast_focus: Option<bool>,
+
+    /// Makes toolbar and video control buttons larger.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub large_targets: Option<bool>,
+
+    /// Keeps the controls of the fullscreen viewer visible instead of hiding
+    /// them after the overlay timeout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub always_show_fullscreen_controls: Option<bool>,
+}
+
+/// Plugins found in the plugins directory that the user turned off; see
+/// [`crate::app::plugins`]. Plugins are on by default once installed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct PluginsConfig {
+    /// Directory names of the disabled plugins.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled: Vec<String>,
+}
+
+/// Image editor settings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EditorConfig {
+    /// Where Save writes edited images and captured frames.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub save_policy: Option<SavePolicy>,
+
+    /// Subfolder used by [`SavePolicy::Subfolder`].
+    #[serde(
+        default = "default_edited_folder",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub edited_folder: Option<String>,
+}
+
+impl Default for EditorConfig {
+    fn default() -> Self {
+        Self {
+            save_policy: Some(SavePolicy::default()),
+            edited_folder: default_edited_folder(),
+        }
+    }
+}
+
+/// Composition guides drawn over the viewer and editor canvas.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GuidesConfig {
+    /// Rule-of-thirds grid.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thirds: Option<bool>,
+
+    /// Golden ratio lines.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub golden_ratio: Option<bool>,
+
+    /// Outline of each pixel at high zoom.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pixel_grid: Option<bool>,
+
+    /// Action-safe and title-safe frames.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub safe_areas: Option<bool>,
+
+    /// Horizontal guide lines, in percent of the height from the top.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub horizontal: Vec<f32>,
+
+    /// Vertical guide lines, in percent of the width from the left.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vertical: Vec<f32>,
+}
+
+impl Default for GuidesConfig {
+    fn default() -> Self {
+        Self {
+            thirds: Some(true),
+            golden_ratio: Some(false),
+            pixel_grid: Some(true),
+            safe_areas: Some(false),
+            horizontal: Vec::new(),
+            vertical: Vec::new(),
         }
     }
 }
 
+/// Update checks, used by builds with the `update-check` feature.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdatesConfig {
+    /// Whether a newer release is looked for once a week. Off by default, so
+    /// nothing is sent over the network unless asked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check_weekly: Option<bool>,
+}
+
+/// External command of the "Open with / Send to" menu, such as an image
+/// editor or a script; see [`crate::app::open_with`] for its placeholders.
+///
+/// ```toml
+/// [[open_with]]
+/// name = "Open in GIMP"
+/// command = ["gimp", "{path}"]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OpenWithCommand {
+    /// Label of the menu item.
+    pub name: String,
+
+    /// Program and its arguments.
+    pub command: Vec<String>,
+}
+
 // =============================================================================
 // Main Config Struct (Sectioned)
 // =============================================================================
@@ -295,9 +967,45 @@ pub struct Config {
     #[serde(default)]
     pub fullscreen: FullscreenConfig,
 
+    /// Accessibility options.
+    #[serde(default)]
+    pub accessibility: AccessibilityConfig,
+
     /// AI/Machine Learning settings.
     #[serde(default)]
     pub ai: AiConfig,
+
+    /// Keyboard shortcuts.
+    #[serde(default)]
+    pub shortcuts: ShortcutsConfig,
+
+    /// Mouse buttons and wheel.
+    #[serde(default)]
+    pub input: InputConfig,
+
+    /// "Share over LAN" gallery server.
+    #[serde(default)]
+    pub share: ShareConfig,
+
+    /// Installed plugins turned off by the user.
+    #[serde(default)]
+    pub plugins: PluginsConfig,
+
+    /// Image editor settings.
+    #[serde(default)]
+    pub editor: EditorConfig,
+
+    /// Composition guides.
+    #[serde(default)]
+    pub guides: GuidesConfig,
+
+    /// Update checks.
+    #[serde(default)]
+    pub updates: UpdatesConfig,
+
+    /// External commands of the "Open with / Send to" menu.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub open_with: Vec<OpenWithCommand>,
 }
 
 // =============================================================================
@@ -348,14 +1056,24 @@ impl From<LegacyConfig> for Config {
             general: GeneralConfig {
                 language: legacy.language,
                 theme_mode: legacy.theme_mode,
+                accent_color: None,
+                custom_theme: None,
+                ui_scale_percent: None,
             },
             display: DisplayConfig {
                 fit_to_window: legacy.fit_to_window,
                 zoom_step: legacy.zoom_step,
                 background_theme: legacy.background_theme,
                 sort_order: legacy.sort_order,
+                sort_direction: None,
                 max_skip_attempts: Some(DEFAULT_MAX_SKIP_ATTEMPTS),
+                prefetch_cache_size: Some(DEFAULT_PREFETCH_CACHE_SIZE),
                 persist_filters: Some(false),
+                sandboxed_decoding: Some(false),
+                webp_decoder: Some(WebpDecoder::default()),
+                similarity_threshold: Some(DEFAULT_SIMILARITY_THRESHOLD),
+                remember_view_per_image: Some(false),
+                show_minimap: Some(true),
                 filter: None,
             },
             video: VideoConfig {
@@ -366,12 +1084,32 @@ impl From<LegacyConfig> for Config {
                 audio_normalization: legacy.audio_normalization,
                 frame_cache_mb: legacy.frame_cache_mb,
                 frame_history_mb: legacy.frame_history_mb,
+                frame_cache_auto: default_frame_cache_auto(),
                 keyboard_seek_step_secs: legacy.keyboard_seek_step_secs,
+                sequence_fps: default_sequence_fps(),
+                frame_name_template: default_frame_name_template(),
+                subtitle_size: Some(SubtitleSize::default()),
+                subtitle_background: Some(true),
+                hardware_decoding: default_hardware_decoding(),
+                audio_visualization: Some(AudioVisualization::default()),
+                resume_playback: Some(ResumePlayback::default()),
+                tone_mapping: Some(ToneMapping::default()),
+                audio_device: None,
             },
             fullscreen: FullscreenConfig {
                 overlay_timeout_secs: legacy.overlay_timeout_secs,
+                ..FullscreenConfig::default()
             },
             ai: AiConfig::default(),
+            shortcuts: ShortcutsConfig::default(),
+            input: InputConfig::default(),
+            share: ShareConfig::default(),
+            plugins: PluginsConfig::default(),
+            editor: EditorConfig::default(),
+            guides: GuidesConfig::default(),
+            updates: UpdatesConfig::default(),
+            accessibility: AccessibilityConfig::default(),
+            open_with: Vec::new(),
         }
     }
 }
@@ -416,11 +1154,31 @@ fn default_frame_history_mb() -> Option<u32> {
     Some(DEFAULT_FRAME_HISTORY_MB)
 }
 
+#[allow(clippy::unnecessary_wraps)]
+fn default_frame_cache_auto() -> Option<bool> {
+    Some(true)
+}
+
 #[allow(clippy::unnecessary_wraps)]
 fn default_keyboard_seek_step_secs() -> Option<f64> {
     Some(DEFAULT_KEYBOARD_SEEK_STEP_SECS)
 }
 
+#[allow(clippy::unnecessary_wraps)]
+fn default_sequence_fps() -> Option<u32> {
+    Some(DEFAULT_SEQUENCE_FPS)
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn default_hardware_decoding() -> Option<bool> {
+    Some(true)
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn default_frame_name_template() -> Option<String> {
+    Some(DEFAULT_FRAME_NAME_TEMPLATE.to_string())
+}
+
 #[allow(clippy::unnecessary_wraps)]
 fn default_overlay_timeout_secs() -> Option<u32> {
     Some(DEFAULT_OVERLAY_TIMEOUT_SECS)
@@ -431,6 +1189,41 @@ fn default_max_skip_attempts() -> Option<u32> {
     Some(DEFAULT_MAX_SKIP_ATTEMPTS)
 }
 
+#[allow(clippy::unnecessary_wraps)]
+fn default_prefetch_cache_size() -> Option<u32> {
+    Some(DEFAULT_PREFETCH_CACHE_SIZE)
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn default_sandboxed_decoding() -> Option<bool> {
+    Some(false)
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn default_similarity_threshold() -> Option<u32> {
+    Some(DEFAULT_SIMILARITY_THRESHOLD)
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn default_navigation_buttons() -> Option<bool> {
+    Some(true)
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn default_share_port() -> Option<u16> {
+    Some(DEFAULT_SHARE_PORT)
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn default_share_videos() -> Option<bool> {
+    Some(true)
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn default_edited_folder() -> Option<String> {
+    Some(DEFAULT_EDITED_FOLDER.to_string())
+}
+
 /// Skip serializing filter if None or if no filter is active.
 #[allow(clippy::ref_option_ref, clippy::ref_option)] // Serde requires this signature
 fn skip_serializing_filter(filter: &Option<MediaFilter>) -> bool {
@@ -450,6 +1243,11 @@ fn default_upscale_model_url() -> Option<String> {
     Some(DEFAULT_UPSCALE_MODEL_URL.to_string())
 }
 
+#[allow(clippy::unnecessary_wraps)]
+fn default_background_model_url() -> Option<String> {
+    Some(DEFAULT_BACKGROUND_MODEL_URL.to_string())
+}
+
 fn deserialize_theme_mode<'de, D>(deserializer: D) -> std::result::Result<ThemeMode, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -477,6 +1275,12 @@ fn get_config_path_with_override(base_dir: Option<PathBuf>) -> Option<PathBuf> {
     })
 }
 
+/// Returns the path of `settings.toml`, if the config directory is known.
+#[must_use]
+pub fn config_path() -> Option<PathBuf> {
+    get_config_path_with_override(None)
+}
+
 // =============================================================================
 // Load Functions
 // =============================================================================
@@ -577,8 +1381,150 @@ pub fn save_to_path(config: &Config, path: &Path) -> Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
+    let _lock = lock_config_file(path)?;
+    write_atomically(config, path)
+}
+
+/// Writes `config` to a file outside the config directory, e.g. to carry
+/// the settings over to another computer. Read it back with
+/// [`load_from_path()`].
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be serialized or written.
+pub fn export_to_path(config: &Config, path: &Path) -> Result<()> {
+    write_atomically(config, path)
+}
+
+/// How [`save_merged()`] wrote the configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveOutcome {
+    /// The file was unchanged since the last load and was overwritten.
+    Written,
+    /// The file was changed by someone else; their changes were kept in the
+    /// sections the caller did not modify.
+    MergedExternalChanges,
+}
+
+/// Saves `config` to the default path without discarding changes made by
+/// other windows or instances since `base` was loaded.
+///
+/// Returns the configuration actually written, which becomes the caller's
+/// new `base`.
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be serialized or written to disk.
+pub fn save_merged(base: &Config, config: &Config) -> Result<(Config, SaveOutcome)> {
+    save_merged_with_override(base, config, None)
+}
+
+/// Saves `config` to a custom directory, merging external changes (see
+/// [`save_merged()`]).
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be serialized or written to disk.
+pub fn save_merged_with_override(
+    base: &Config,
+    config: &Config,
+    base_dir: Option<PathBuf>,
+) -> Result<(Config, SaveOutcome)> {
+    match get_config_path_with_override(base_dir) {
+        Some(path) => save_merged_to_path(base, config, &path),
+        None => Ok((config.clone(), SaveOutcome::Written)),
+    }
+}
+
+/// Saves `config` to a specific path, merging external changes (see
+/// [`save_merged()`]).
+///
+/// # Errors
+///
+/// Returns an error if parent directories cannot be created, the configuration
+/// cannot be serialized, or the file cannot be written.
+pub fn save_merged_to_path(
+    base: &Config,
+    config: &Config,
+    path: &Path,
+) -> Result<(Config, SaveOutcome)> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let _lock = lock_config_file(path)?;
+
+    // An unreadable file has nothing worth keeping
+    let on_disk = path.exists().then(|| load_from_path(path).ok()).flatten();
+    let (merged, outcome) = match on_disk {
+        Some(theirs) if theirs != *base => (
+            merge_sections(base, config, &theirs),
+            SaveOutcome::MergedExternalChanges,
+        ),
+        _ => (config.clone(), SaveOutcome::Written),
+    };
+
+    write_atomically(&merged, path)?;
+    Ok((merged, outcome))
+}
+
+/// Three-way merge at section granularity: sections changed from `base` in
+/// `ours` win, all other sections are taken from `theirs`.
+fn merge_sections(base: &Config, ours: &Config, theirs: &Config) -> Config {
+    fn pick<T: PartialEq + Clone>(base: &T, ours: &T, theirs: &T) -> T {
+        if ours == base {
+            theirs.clone()
+        } else {
+            ours.clone()
+        }
+    }
+
+    Config {
+        general: pick(&base.general, &ours.general, &theirs.general),
+        display: pick(&base.display, &ours.display, &theirs.display),
+        video: pick(&base.video, &ours.video, &theirs.video),
+        fullscreen: pick(&base.fullscreen, &ours.fullscreen, &theirs.fullscreen),
+        accessibility: pick(
+            &base.accessibility,
+            &ours.accessibility,
+            &theirs.accessibility,
+        ),
+        ai: pick(&base.ai, &ours.ai, &theirs.ai),
+        shortcuts: pick(&base.shortcuts, &ours.shortcuts, &theirs.shortcuts),
+        input: pick(&base.input, &ours.input, &theirs.input),
+        share: pick(&base.share, &ours.share, &theirs.share),
+        plugins: pick(&base.plugins, &ours.plugins, &theirs.plugins),
+        editor: pick(&base.editor, &ours.editor, &theirs.editor),
+        guides: pick(&base.guides, &ours.guides, &theirs.guides),
+        updates: pick(&base.updates, &ours.updates, &theirs.updates),
+        open_with: pick(&base.open_with, &ours.open_with, &theirs.open_with),
+    }
+}
+
+/// Takes the advisory lock guarding `path` until the returned file is dropped.
+///
+/// The lock lives on a separate file because the config file itself is
+/// replaced on every save.
+fn lock_config_file(path: &Path) -> Result<fs::File> {
+    let mut lock_path = path.as_os_str().to_owned();
+    lock_path.push(".lock");
+    let lock_file = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(PathBuf::from(lock_path))?;
+    lock_file.lock()?;
+    Ok(lock_file)
+}
+
+/// Writes `config` to a temporary file and renames it over `path`, so
+/// readers never see a partially written file.
+fn write_atomically(config: &Config, path: &Path) -> Result<()> {
     let content = toml::to_string_pretty(config).map_err(Error::from)?;
-    fs::write(path, content)?;
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    let temp_path = PathBuf::from(temp_path);
+    fs::write(&temp_path, content)?;
+    fs::rename(&temp_path, path)?;
     Ok(())
 }
 
@@ -598,14 +1544,24 @@ mod tests {
             general: GeneralConfig {
                 language: Some("fr".to_string()),
                 theme_mode: ThemeMode::Light,
+                accent_color: Some("#d1458a".to_string()),
+                custom_theme: Some(true),
+                ui_scale_percent: Some(150),
             },
             display: DisplayConfig {
                 fit_to_window: Some(false),
                 zoom_step: Some(5.0),
                 background_theme: Some(BackgroundTheme::Light),
                 sort_order: Some(SortOrder::Alphabetical),
+                sort_direction: Some(SortDirection::Ascending),
                 max_skip_attempts: Some(DEFAULT_MAX_SKIP_ATTEMPTS),
+                prefetch_cache_size: Some(DEFAULT_PREFETCH_CACHE_SIZE),
                 persist_filters: Some(false),
+                sandboxed_decoding: Some(false),
+                webp_decoder: Some(WebpDecoder::default()),
+                similarity_threshold: Some(DEFAULT_SIMILARITY_THRESHOLD),
+                remember_view_per_image: Some(false),
+                show_minimap: Some(true),
                 filter: None,
             },
             video: VideoConfig {
@@ -616,12 +1572,36 @@ mod tests {
                 audio_normalization: Some(true),
                 frame_cache_mb: Some(DEFAULT_FRAME_CACHE_MB),
                 frame_history_mb: Some(DEFAULT_FRAME_HISTORY_MB),
+                frame_cache_auto: Some(true),
                 keyboard_seek_step_secs: Some(DEFAULT_KEYBOARD_SEEK_STEP_SECS),
+                sequence_fps: Some(DEFAULT_SEQUENCE_FPS),
+                frame_name_template: Some(DEFAULT_FRAME_NAME_TEMPLATE.to_string()),
+                subtitle_size: Some(SubtitleSize::Medium),
+                subtitle_background: Some(true),
+                hardware_decoding: Some(true),
+                audio_visualization: Some(AudioVisualization::default()),
+                resume_playback: Some(ResumePlayback::default()),
+                tone_mapping: Some(ToneMapping::default()),
+                audio_device: None,
             },
             fullscreen: FullscreenConfig {
                 overlay_timeout_secs: Some(DEFAULT_OVERLAY_TIMEOUT_SECS),
+                ..FullscreenConfig::default()
             },
             ai: AiConfig::default(),
+            shortcuts: ShortcutsConfig::default(),
+            input: InputConfig::default(),
+            share: ShareConfig::default(),
+            plugins: PluginsConfig::default(),
+            editor: EditorConfig::default(),
+            guides: GuidesConfig::default(),
+            updates: UpdatesConfig::default(),
+            accessibility: AccessibilityConfig {
+                reduced_motion: Some(true),
+                large_targets: Some(true),
+                ..AccessibilityConfig::default()
+            },
+            open_with: Vec::new(),
         };
         let temp_dir = tempdir().expect("failed to create temp dir");
         let config_path = temp_dir.path().join("nested").join("settings.toml");
@@ -633,6 +1613,7 @@ mod tests {
         assert_eq!(loaded.display.fit_to_window, config.display.fit_to_window);
         assert_eq!(loaded.display.zoom_step, config.display.zoom_step);
         assert_eq!(loaded.general.theme_mode, config.general.theme_mode);
+        assert_eq!(loaded.accessibility, config.accessibility);
     }
 
     #[test]
@@ -647,6 +1628,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn auto_background_contrasts_with_image_luminance() {
+        assert_eq!(
+            BackgroundTheme::Auto.resolve(Some(0.8)),
+            BackgroundTheme::Dark
+        );
+        assert_eq!(
+            BackgroundTheme::Auto.resolve(Some(0.2)),
+            BackgroundTheme::Light
+        );
+        assert_eq!(BackgroundTheme::Auto.resolve(None), BackgroundTheme::Dark);
+        assert_eq!(
+            BackgroundTheme::Checkerboard.resolve(Some(0.2)),
+            BackgroundTheme::Checkerboard
+        );
+    }
+
     #[test]
     fn save_to_path_creates_parent_directories() {
         let temp_dir = tempdir().expect("failed to create temp dir");
@@ -656,14 +1654,24 @@ mod tests {
             general: GeneralConfig {
                 language: Some("en-US".to_string()),
                 theme_mode: ThemeMode::System,
+                accent_color: None,
+                custom_theme: None,
+                ui_scale_percent: None,
             },
             display: DisplayConfig {
                 fit_to_window: Some(false),
                 zoom_step: Some(7.5),
                 background_theme: Some(BackgroundTheme::Checkerboard),
                 sort_order: Some(SortOrder::CreatedDate),
+                sort_direction: Some(SortDirection::Ascending),
                 max_skip_attempts: Some(DEFAULT_MAX_SKIP_ATTEMPTS),
+                prefetch_cache_size: Some(DEFAULT_PREFETCH_CACHE_SIZE),
                 persist_filters: Some(false),
+                sandboxed_decoding: Some(false),
+                webp_decoder: Some(WebpDecoder::default()),
+                similarity_threshold: Some(DEFAULT_SIMILARITY_THRESHOLD),
+                remember_view_per_image: Some(false),
+                show_minimap: Some(true),
                 filter: None,
             },
             video: VideoConfig {
@@ -674,12 +1682,32 @@ mod tests {
                 audio_normalization: Some(false),
                 frame_cache_mb: Some(128),
                 frame_history_mb: Some(DEFAULT_FRAME_HISTORY_MB),
+                frame_cache_auto: Some(true),
                 keyboard_seek_step_secs: Some(DEFAULT_KEYBOARD_SEEK_STEP_SECS),
+                sequence_fps: Some(DEFAULT_SEQUENCE_FPS),
+                frame_name_template: Some(DEFAULT_FRAME_NAME_TEMPLATE.to_string()),
+                subtitle_size: Some(SubtitleSize::Medium),
+                subtitle_background: Some(true),
+                hardware_decoding: Some(true),
+                audio_visualization: Some(AudioVisualization::default()),
+                resume_playback: Some(ResumePlayback::default()),
+                tone_mapping: Some(ToneMapping::default()),
+                audio_device: None,
             },
             fullscreen: FullscreenConfig {
                 overlay_timeout_secs: Some(DEFAULT_OVERLAY_TIMEOUT_SECS),
+                ..FullscreenConfig::default()
             },
             ai: AiConfig::default(),
+            shortcuts: ShortcutsConfig::default(),
+            input: InputConfig::default(),
+            share: ShareConfig::default(),
+            plugins: PluginsConfig::default(),
+            editor: EditorConfig::default(),
+            guides: GuidesConfig::default(),
+            updates: UpdatesConfig::default(),
+            accessibility: AccessibilityConfig::default(),
+            open_with: Vec::new(),
         };
 
         save_to_path(&config, &config_path).expect("save should create directories");
@@ -722,6 +1750,38 @@ mod tests {
         assert_eq!(loaded.display.sort_order, Some(SortOrder::ModifiedDate));
     }
 
+    #[test]
+    fn save_and_load_preserves_new_sort_order_and_direction() {
+        let config = Config {
+            display: DisplayConfig {
+                sort_order: Some(SortOrder::FileSize),
+                sort_direction: Some(SortDirection::Descending),
+                ..DisplayConfig::default()
+            },
+            ..Config::default()
+        };
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let config_path = temp_dir.path().join("settings.toml");
+
+        save_to_path(&config, &config_path).expect("failed to save config");
+        let loaded = load_from_path(&config_path).expect("failed to load config");
+
+        assert_eq!(
+            loaded.display.media_sort(),
+            MediaSort::new(SortOrder::FileSize, SortDirection::Descending)
+        );
+    }
+
+    #[test]
+    fn media_sort_defaults_to_ascending_when_direction_missing() {
+        let display = DisplayConfig {
+            sort_order: Some(SortOrder::FileType),
+            sort_direction: None,
+            ..DisplayConfig::default()
+        };
+        assert_eq!(display.media_sort(), MediaSort::from(SortOrder::FileType));
+    }
+
     #[test]
     fn sort_order_default_is_alphabetical() {
         assert_eq!(SortOrder::default(), SortOrder::Alphabetical);
@@ -742,6 +1802,7 @@ mod tests {
         let config = Config {
             fullscreen: FullscreenConfig {
                 overlay_timeout_secs: Some(5),
+                ..FullscreenConfig::default()
             },
             ..Config::default()
         };
@@ -785,6 +1846,49 @@ mod tests {
         assert_eq!(loaded.video.audio_normalization, Some(false));
     }
 
+    #[test]
+    fn hardware_decoding_defaults_to_true() {
+        let config: Config = toml::from_str("[video]\nautoplay = true\n").expect("parse");
+        assert_eq!(config.video.hardware_decoding, Some(true));
+    }
+
+    #[test]
+    fn shortcuts_section_maps_actions_to_keys() {
+        let config: Config =
+            toml::from_str("[shortcuts]\nrotate-clockwise = \"T\"\nsave = \"Ctrl+W\"\n")
+                .expect("parse");
+        assert_eq!(config.shortcuts.bindings.len(), 2);
+        assert_eq!(
+            config.shortcuts.bindings.get("save").map(String::as_str),
+            Some("Ctrl+W")
+        );
+        assert!(Config::default().shortcuts.bindings.is_empty());
+    }
+
+    #[test]
+    fn input_section_reads_mouse_mapping() {
+        let config: Config =
+            toml::from_str("[input]\nwheel = \"navigate\"\ndrag_button = \"middle\"\n")
+                .expect("parse");
+        assert_eq!(config.input.wheel, Some(WheelAction::Navigate));
+        assert_eq!(config.input.drag_button, Some(DragButton::Middle));
+        // Unset values keep their defaults
+        assert_eq!(config.input.navigation_buttons, Some(true));
+        assert_eq!(config.input.middle_click, None);
+    }
+
+    #[test]
+    fn editor_section_reads_save_policy() {
+        let config: Config =
+            toml::from_str("[editor]\nsave_policy = \"subfolder\"\n").expect("parse");
+        assert_eq!(config.editor.save_policy, Some(SavePolicy::Subfolder));
+        // Unset values keep their defaults
+        assert_eq!(
+            config.editor.edited_folder.as_deref(),
+            Some(DEFAULT_EDITED_FOLDER)
+        );
+    }
+
     #[test]
     fn audio_normalization_defaults_to_true() {
         let config = Config::default();
@@ -795,7 +1899,8 @@ mod tests {
     fn volume_constants_are_valid() {
         assert_eq!(DEFAULT_VOLUME, 0.8);
         assert_eq!(MIN_VOLUME, 0.0);
-        assert_eq!(MAX_VOLUME, 1.5); // 150% amplification
+        assert_eq!(UNITY_VOLUME, 1.0);
+        assert_eq!(MAX_VOLUME, 2.0); // 200% boost
         let step = VOLUME_STEP;
         let default = DEFAULT_VOLUME;
         let min = MIN_VOLUME;
@@ -816,14 +1921,24 @@ mod tests {
             general: GeneralConfig {
                 language: Some("de".to_string()),
                 theme_mode: ThemeMode::Dark,
+                accent_color: None,
+                custom_theme: None,
+                ui_scale_percent: None,
             },
             display: DisplayConfig {
                 fit_to_window: Some(false),
                 zoom_step: Some(15.0),
                 background_theme: Some(BackgroundTheme::Light),
                 sort_order: Some(SortOrder::CreatedDate),
+                sort_direction: Some(SortDirection::Descending),
                 max_skip_attempts: Some(10),
+                prefetch_cache_size: Some(5),
                 persist_filters: Some(false),
+                sandboxed_decoding: Some(true),
+                webp_decoder: Some(WebpDecoder::Libwebp),
+                similarity_threshold: Some(DEFAULT_SIMILARITY_THRESHOLD),
+                remember_view_per_image: Some(false),
+                show_minimap: Some(true),
                 filter: None,
             },
             video: VideoConfig {
@@ -834,12 +1949,32 @@ mod tests {
                 audio_normalization: Some(false),
                 frame_cache_mb: Some(256),
                 frame_history_mb: Some(64),
+                frame_cache_auto: Some(false),
                 keyboard_seek_step_secs: Some(5.0),
+                sequence_fps: Some(30),
+                frame_name_template: Some("{name}_{index}".to_string()),
+                subtitle_size: Some(SubtitleSize::Large),
+                subtitle_background: Some(false),
+                hardware_decoding: Some(false),
+                audio_visualization: Some(AudioVisualization::Waveform),
+                resume_playback: Some(ResumePlayback::Always),
+                tone_mapping: Some(ToneMapping::Aces),
+                audio_device: Some("alsa:hw:1,0".to_string()),
             },
             fullscreen: FullscreenConfig {
                 overlay_timeout_secs: Some(7),
+                ..FullscreenConfig::default()
             },
             ai: AiConfig::default(),
+            shortcuts: ShortcutsConfig::default(),
+            input: InputConfig::default(),
+            share: ShareConfig::default(),
+            plugins: PluginsConfig::default(),
+            editor: EditorConfig::default(),
+            guides: GuidesConfig::default(),
+            updates: UpdatesConfig::default(),
+            accessibility: AccessibilityConfig::default(),
+            open_with: Vec::new(),
         };
 
         save_with_override(&config, Some(base_dir.clone())).expect("save should succeed");
@@ -852,6 +1987,18 @@ mod tests {
         assert_eq!(loaded.general.language, Some("de".to_string()));
         assert_eq!(loaded.display.zoom_step, Some(15.0));
         assert_eq!(loaded.general.theme_mode, ThemeMode::Dark);
+        assert_eq!(loaded.video.hardware_decoding, Some(false));
+        assert_eq!(
+            loaded.video.audio_visualization,
+            Some(AudioVisualization::Waveform)
+        );
+        assert_eq!(loaded.video.resume_playback, Some(ResumePlayback::Always));
+        assert_eq!(loaded.video.tone_mapping, Some(ToneMapping::Aces));
+        assert_eq!(loaded.video.audio_device.as_deref(), Some("alsa:hw:1,0"));
+        assert_eq!(loaded.video.frame_cache_auto, Some(false));
+        assert_eq!(loaded.display.prefetch_cache_size, Some(5));
+        assert_eq!(loaded.display.sandboxed_decoding, Some(true));
+        assert_eq!(loaded.display.webp_decoder, Some(WebpDecoder::Libwebp));
     }
 
     #[test]
@@ -923,6 +2070,47 @@ mod tests {
         assert!(nested_dir.join("settings.toml").exists());
     }
 
+    #[test]
+    fn save_merged_keeps_external_changes_in_untouched_sections() {
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let path = temp_dir.path().join("settings.toml");
+
+        save_to_path(&Config::default(), &path).expect("initial save");
+        let base = load_from_path(&path).expect("load");
+
+        // Another instance changes the language
+        let mut theirs = base.clone();
+        theirs.general.language = Some("de".to_string());
+        save_to_path(&theirs, &path).expect("external save");
+
+        // This instance only changed the volume
+        let mut ours = base.clone();
+        ours.video.volume = Some(0.3);
+        let (written, outcome) = save_merged_to_path(&base, &ours, &path).expect("merged save");
+
+        assert_eq!(outcome, SaveOutcome::MergedExternalChanges);
+        assert_eq!(written.general.language, Some("de".to_string()));
+        assert_eq!(written.video.volume, Some(0.3));
+        assert_eq!(load_from_path(&path).expect("load"), written);
+    }
+
+    #[test]
+    fn save_merged_overwrites_unchanged_file() {
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let path = temp_dir.path().join("settings.toml");
+
+        save_to_path(&Config::default(), &path).expect("initial save");
+        let base = load_from_path(&path).expect("load");
+
+        let mut ours = base.clone();
+        ours.general.language = Some("it".to_string());
+        let (written, outcome) = save_merged_to_path(&base, &ours, &path).expect("merged save");
+
+        assert_eq!(outcome, SaveOutcome::Written);
+        assert_eq!(written, ours);
+        assert!(!temp_dir.path().join("settings.toml.tmp").exists());
+    }
+
     // =========================================================================
     // Migration Tests
     // =========================================================================
@@ -1121,6 +2309,7 @@ zoom_step = 25.0
         let active_filter = MediaFilter {
             media_type: MediaTypeFilter::ImagesOnly,
             date_range: None,
+            rating: None,
         };
 
         let config = Config {
@@ -1143,4 +2332,28 @@ zoom_step = 25.0
         let config = Config::default();
         assert_eq!(config.display.persist_filters, Some(false));
     }
+
+    #[test]
+    fn exported_settings_import_unchanged() {
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let export_path = temp_dir.path().join(EXPORT_FILE_NAME);
+        let config = Config {
+            general: GeneralConfig {
+                language: Some("de".to_string()),
+                ..GeneralConfig::default()
+            },
+            ..Config::default()
+        };
+
+        export_to_path(&config, &export_path).expect("export config");
+
+        assert_eq!(load_from_path(&export_path).expect("import config"), config);
+        assert!(
+            !temp_dir
+                .path()
+                .join(format!("{EXPORT_FILE_NAME}.lock"))
+                .exists(),
+            "exports should not leave a lock file behind"
+        );
+    }
 }
@@ -0,0 +1,610 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Journal of unsaved editor changes, to recover them after a crash.
+//!
+//! While an image has unsaved edits, the transformations applied in the
+//! editor and the pending adjustment sliders are written to a journal in the
+//! `journals` folder of the data directory, named after a hash of the image
+//! path. Saving or discarding the edits removes it. When the image is opened
+//! in the editor again and a journal was left behind, the editor offers to
+//! restore the edits.
+//!
+//! A journal remembers the size and modification time of its image: if the
+//! file changed since, the edits no longer apply and the journal is dropped.
+//! AI and plugin results are stored as PNG images since they can't be
+//! recomputed, which makes those journals larger: write them off the UI
+//! thread.
+//!
+//! The edit recipe copied in the editor is saved with the same steps, so it
+//! can still be pasted after a restart.
+
+use super::paths;
+use crate::media::auto_enhance::AutoEnhanceParams;
+use crate::media::looks::LookParams;
+use crate::ui::image_editor::{AdjustmentRecipe, RecoveredEdits, Transformation};
+use image_rs::{DynamicImage, ImageFormat};
+use serde::de::{self, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufReader, BufWriter, Cursor};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::SystemTime;
+
+/// Name of the journal folder, in the data directory.
+const JOURNALS_DIR: &str = "journals";
+
+/// Extension of journal files.
+const JOURNAL_EXTENSION: &str = "cbor";
+
+/// Name of the copied edit recipe file, in the data directory.
+const RECIPE_FILE: &str = "edit-recipe.cbor";
+
+/// Journal of the unsaved edits of one image.
+#[derive(Debug, Serialize, Deserialize)]
+struct Journal {
+    source: PathBuf,
+    size: u64,
+    modified: Option<SystemTime>,
+    steps: Vec<Step>,
+    brightness: i32,
+    contrast: i32,
+}
+
+/// Edit recipe copied in the editor.
+#[derive(Debug, Serialize, Deserialize)]
+struct RecipeFile {
+    /// Size of the image the recipe was copied from.
+    size: (u32, u32),
+    steps: Vec<Step>,
+}
+
+/// Serializable form of a [`Transformation`].
+#[derive(Debug, Serialize, Deserialize)]
+enum Step {
+    RotateLeft,
+    RotateRight,
+    FlipHorizontal,
+    FlipVertical,
+    Crop {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    },
+    Resize {
+        width: u32,
+        height: u32,
+    },
+    AdjustBrightness {
+        value: i32,
+    },
+    AdjustContrast {
+        value: i32,
+    },
+    AutoEnhance {
+        params: AutoEnhanceParams,
+        strength: u32,
+    },
+    ChannelMix {
+        weights: [i32; 3],
+    },
+    Sharpen {
+        amount: u32,
+        radius: f32,
+        threshold: u8,
+    },
+    Look {
+        params: LookParams,
+        strength: u32,
+    },
+    Upscale(Png),
+    Deblur(Png),
+    RemoveBackground(Png),
+    Denoise(Png),
+    Plugin(Png),
+}
+
+impl Step {
+    fn from_transformation(transformation: &Transformation) -> io::Result<Self> {
+        Ok(match transformation {
+            Transformation::RotateLeft => Self::RotateLeft,
+            Transformation::RotateRight => Self::RotateRight,
+            Transformation::FlipHorizontal => Self::FlipHorizontal,
+            Transformation::FlipVertical => Self::FlipVertical,
+            Transformation::Crop { rect } => Self::Crop {
+                x: rect.x,
+                y: rect.y,
+                width: rect.width,
+                height: rect.height,
+            },
+            Transformation::Resize { width, height } => Self::Resize {
+                width: *width,
+                height: *height,
+            },
+            Transformation::AdjustBrightness { value } => Self::AdjustBrightness { value: *value },
+            Transformation::AdjustContrast { value } => Self::AdjustContrast { value: *value },
+            Transformation::AutoEnhance { params, strength } => Self::AutoEnhance {
+                params: *params,
+                strength: *strength,
+            },
+            Transformation::ChannelMix { weights } => Self::ChannelMix { weights: *weights },
+            Transformation::Sharpen {
+                amount,
+                radius,
+                threshold,
+            } => Self::Sharpen {
+                amount: *amount,
+                radius: *radius,
+                threshold: *threshold,
+            },
+            Transformation::Look { params, strength } => Self::Look {
+                params: *params,
+                strength: *strength,
+            },
+            Transformation::UpscaleResize { result } => Self::Upscale(Png::encode(result)?),
+            Transformation::Deblur { result } => Self::Deblur(Png::encode(result)?),
+            Transformation::RemoveBackground { result } => {
+                Self::RemoveBackground(Png::encode(result)?)
+            }
+            Transformation::Denoise { result } => Self::Denoise(Png::encode(result)?),
+            Transformation::PluginTool { result } => Self::Plugin(Png::encode(result)?),
+        })
+    }
+
+    fn into_transformation(self) -> Option<Transformation> {
+        Some(match self {
+            Self::RotateLeft => Transformation::RotateLeft,
+            Self::RotateRight => Transformation::RotateRight,
+            Self::FlipHorizontal => Transformation::FlipHorizontal,
+            Self::FlipVertical => Transformation::FlipVertical,
+            Self::Crop {
+                x,
+                y,
+                width,
+                height,
+            } => Transformation::Crop {
+                rect: iced::Rectangle {
+                    x,
+                    y,
+                    width,
+                    height,
+                },
+            },
+            Self::Resize { width, height } => Transformation::Resize { width, height },
+            Self::AdjustBrightness { value } => Transformation::AdjustBrightness { value },
+            Self::AdjustContrast { value } => Transformation::AdjustContrast { value },
+            Self::AutoEnhance { params, strength } => {
+                Transformation::AutoEnhance { params, strength }
+            }
+            Self::ChannelMix { weights } => Transformation::ChannelMix { weights },
+            Self::Sharpen {
+                amount,
+                radius,
+                threshold,
+            } => Transformation::Sharpen {
+                amount,
+                radius,
+                threshold,
+            },
+            Self::Look { params, strength } => Transformation::Look { params, strength },
+            Self::Upscale(png) => Transformation::UpscaleResize {
+                result: Box::new(png.decode()?),
+            },
+            Self::Deblur(png) => Transformation::Deblur {
+                result: Box::new(png.decode()?),
+            },
+            Self::RemoveBackground(png) => Transformation::RemoveBackground {
+                result: Box::new(png.decode()?),
+            },
+            Self::Denoise(png) => Transformation::Denoise {
+                result: Box::new(png.decode()?),
+            },
+            Self::Plugin(png) => Transformation::PluginTool {
+                result: Box::new(png.decode()?),
+            },
+        })
+    }
+}
+
+/// PNG-encoded image, stored as a CBOR byte string.
+struct Png(Vec<u8>);
+
+impl Png {
+    fn encode(image: &DynamicImage) -> io::Result<Self> {
+        let mut bytes = Cursor::new(Vec::new());
+        image
+            .write_to(&mut bytes, ImageFormat::Png)
+            .map_err(io::Error::other)?;
+        Ok(Self(bytes.into_inner()))
+    }
+
+    fn decode(&self) -> Option<DynamicImage> {
+        image_rs::load_from_memory_with_format(&self.0, ImageFormat::Png).ok()
+    }
+}
+
+impl fmt::Debug for Png {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Png({} bytes)", self.0.len())
+    }
+}
+
+impl Serialize for Png {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Png {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PngVisitor;
+
+        impl<'de> Visitor<'de> for PngVisitor {
+            type Value = Png;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("PNG bytes")
+            }
+
+            fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Png, E> {
+                Ok(Png(bytes.to_vec()))
+            }
+
+            fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Png, E> {
+                Ok(Png(bytes))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Png, A::Error> {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                Ok(Png(bytes))
+            }
+        }
+
+        deserializer.deserialize_byte_buf(PngVisitor)
+    }
+}
+
+/// Returns the journal file of `source`, if the data directory is known.
+fn journal_path(source: &Path, base_dir: Option<PathBuf>) -> Option<PathBuf> {
+    let hash = blake3::hash(source.as_os_str().as_encoded_bytes());
+    paths::get_app_data_dir_with_override(base_dir).map(|dir| {
+        dir.join(JOURNALS_DIR)
+            .join(format!("{}.{JOURNAL_EXTENSION}", hash.to_hex()))
+    })
+}
+
+/// Returns the size and modification time of `source`.
+fn file_stamp(source: &Path) -> io::Result<(u64, Option<SystemTime>)> {
+    let metadata = fs::metadata(source)?;
+    Ok((metadata.len(), metadata.modified().ok()))
+}
+
+/// Writes the journal of `source` with the applied `transformations` and the
+/// pending `brightness` and `contrast` slider values.
+///
+/// `change` numbers the journal changes of the session: a write finishing
+/// after a newer change was made is dropped. The journal is written next to
+/// its final name and then renamed, so a crash while writing leaves the
+/// previous journal intact.
+///
+/// # Errors
+///
+/// Returns an error if the data directory is unknown, `source` can't be
+/// read, an image can't be encoded, or the journal can't be written.
+pub fn write(
+    source: &Path,
+    transformations: &[Transformation],
+    (brightness, contrast): (i32, i32),
+    change: u64,
+    base_dir: Option<PathBuf>,
+) -> io::Result<()> {
+    let path = journal_path(source, base_dir)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no data directory"))?;
+    let (size, modified) = file_stamp(source)?;
+    let journal = Journal {
+        source: source.to_path_buf(),
+        size,
+        modified,
+        steps: transformations
+            .iter()
+            .map(Step::from_transformation)
+            .collect::<io::Result<_>>()?,
+        brightness,
+        contrast,
+    };
+
+    let mut latest = latest_changes();
+    if !claim_change(&mut latest, &path, change) {
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let partial = path.with_extension("partial");
+    let writer = BufWriter::new(fs::File::create(&partial)?);
+    ciborium::into_writer(&journal, writer).map_err(io::Error::other)?;
+    fs::rename(&partial, &path)
+}
+
+/// Latest change applied to each journal file. Holding the lock also keeps
+/// two writes of the same journal from interleaving.
+static LATEST_CHANGES: Mutex<BTreeMap<PathBuf, u64>> = Mutex::new(BTreeMap::new());
+
+fn latest_changes() -> MutexGuard<'static, BTreeMap<PathBuf, u64>> {
+    LATEST_CHANGES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Records `change` as the latest change of the journal at `path`, unless a
+/// newer one was applied already.
+fn claim_change(latest: &mut BTreeMap<PathBuf, u64>, path: &Path, change: u64) -> bool {
+    let applied = latest.entry(path.to_path_buf()).or_default();
+    if change < *applied {
+        return false;
+    }
+    *applied = change;
+    true
+}
+
+/// Reads the journal of `source`, if one was left behind.
+///
+/// A journal that can't be read, belongs to another path with the same hash,
+/// or predates a change of `source` is deleted and `None` returned.
+#[must_use]
+pub fn read(source: &Path, base_dir: Option<PathBuf>) -> Option<RecoveredEdits> {
+    let path = journal_path(source, base_dir)?;
+    let file = fs::File::open(&path).ok()?;
+    let journal: Option<Journal> = ciborium::from_reader(BufReader::new(file)).ok();
+
+    let recovered = journal
+        .filter(|journal| {
+            journal.source == source
+                && file_stamp(source).is_ok_and(|stamp| stamp == (journal.size, journal.modified))
+        })
+        .and_then(|journal| {
+            let transformations = journal
+                .steps
+                .into_iter()
+                .map(Step::into_transformation)
+                .collect::<Option<Vec<_>>>()?;
+            Some(RecoveredEdits {
+                transformations,
+                brightness: journal.brightness,
+                contrast: journal.contrast,
+            })
+        });
+    if recovered.is_none() {
+        let _ = fs::remove_file(&path);
+    }
+    recovered
+}
+
+/// Deletes the journal of `source`, if any.
+///
+/// `change` numbers the journal changes of the session, as for [`write`].
+///
+/// # Errors
+///
+/// Returns an error if the journal exists but can't be deleted.
+pub fn remove(source: &Path, change: u64, base_dir: Option<PathBuf>) -> io::Result<()> {
+    let Some(path) = journal_path(source, base_dir) else {
+        return Ok(());
+    };
+    let mut latest = latest_changes();
+    if !claim_change(&mut latest, &path, change) {
+        return Ok(());
+    }
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Saves the edit recipe copied in the editor, replacing the previous one.
+///
+/// # Errors
+///
+/// Returns an error if the data directory is unknown or the file can't be
+/// written.
+pub fn write_recipe(recipe: &AdjustmentRecipe, base_dir: Option<PathBuf>) -> io::Result<()> {
+    let dir = paths::get_app_data_dir_with_override(base_dir)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no data directory"))?;
+    let file = RecipeFile {
+        size: recipe.size(),
+        steps: recipe
+            .steps()
+            .iter()
+            .map(Step::from_transformation)
+            .collect::<io::Result<_>>()?,
+    };
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(RECIPE_FILE);
+    let partial = path.with_extension("partial");
+    let writer = BufWriter::new(fs::File::create(&partial)?);
+    ciborium::into_writer(&file, writer).map_err(io::Error::other)?;
+    fs::rename(&partial, &path)
+}
+
+/// Reads the edit recipe copied in a previous session, if any.
+#[must_use]
+pub fn read_recipe(base_dir: Option<PathBuf>) -> Option<AdjustmentRecipe> {
+    let path = paths::get_app_data_dir_with_override(base_dir)?.join(RECIPE_FILE);
+    let file = fs::File::open(path).ok()?;
+    let recipe: RecipeFile = ciborium::from_reader(BufReader::new(file)).ok()?;
+    let steps = recipe
+        .steps
+        .into_iter()
+        .map(Step::into_transformation)
+        .collect::<Option<Vec<_>>>()?;
+    Some(AdjustmentRecipe::new(recipe.size, &steps)).filter(|recipe| !recipe.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image_rs::{Rgba, RgbaImage};
+
+    fn source_image(dir: &Path) -> PathBuf {
+        let path = dir.join("photo.png");
+        RgbaImage::from_pixel(4, 3, Rgba([10, 20, 30, 255]))
+            .save(&path)
+            .expect("write png");
+        path
+    }
+
+    #[test]
+    fn journal_round_trips_steps_and_sliders() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let source = source_image(dir.path());
+        let base_dir = Some(dir.path().join("data"));
+        let result = DynamicImage::ImageRgba8(RgbaImage::from_pixel(2, 2, Rgba([1, 2, 3, 4])));
+        let steps = [
+            Transformation::RotateLeft,
+            Transformation::Crop {
+                rect: iced::Rectangle {
+                    x: 1.0,
+                    y: 0.0,
+                    width: 2.0,
+                    height: 3.0,
+                },
+            },
+            Transformation::AdjustContrast { value: -20 },
+            Transformation::Deblur {
+                result: Box::new(result.clone()),
+            },
+        ];
+
+        write(&source, &steps, (15, 0), 1, base_dir.clone()).expect("write journal");
+        let recovered = read(&source, base_dir).expect("journal");
+
+        assert_eq!(recovered.brightness, 15);
+        assert_eq!(recovered.contrast, 0);
+        assert_eq!(recovered.transformations.len(), 4);
+        match &recovered.transformations[1] {
+            Transformation::Crop { rect } => {
+                assert_eq!(
+                    *rect,
+                    iced::Rectangle::new((1.0, 0.0).into(), (2.0, 3.0).into())
+                );
+            }
+            other => panic!("unexpected step {other:?}"),
+        }
+        match &recovered.transformations[3] {
+            Transformation::Deblur { result: restored } => {
+                assert_eq!(restored.to_rgba8(), result.to_rgba8());
+            }
+            other => panic!("unexpected step {other:?}"),
+        }
+    }
+
+    #[test]
+    fn journal_of_a_changed_file_is_dropped() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let source = source_image(dir.path());
+        let base_dir = Some(dir.path().join("data"));
+        write(
+            &source,
+            &[Transformation::FlipVertical],
+            (0, 0),
+            1,
+            base_dir.clone(),
+        )
+        .expect("write journal");
+
+        RgbaImage::from_pixel(8, 8, Rgba([0, 0, 0, 255]))
+            .save(&source)
+            .expect("rewrite png");
+
+        assert!(read(&source, base_dir.clone()).is_none());
+        let path = journal_path(&source, base_dir).expect("journal path");
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn removed_journal_is_not_offered() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let source = source_image(dir.path());
+        let base_dir = Some(dir.path().join("data"));
+        write(
+            &source,
+            &[Transformation::RotateRight],
+            (0, 0),
+            1,
+            base_dir.clone(),
+        )
+        .expect("write journal");
+
+        remove(&source, 2, base_dir.clone()).expect("remove journal");
+        assert!(read(&source, base_dir.clone()).is_none());
+        // Removing a missing journal is not an error
+        remove(&source, 3, base_dir).expect("remove missing journal");
+    }
+
+    #[test]
+    fn late_write_does_not_replace_a_newer_change() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let source = source_image(dir.path());
+        let base_dir = Some(dir.path().join("data"));
+        write(
+            &source,
+            &[Transformation::RotateLeft],
+            (0, 0),
+            2,
+            base_dir.clone(),
+        )
+        .expect("write journal");
+
+        write(
+            &source,
+            &[Transformation::RotateLeft, Transformation::FlipVertical],
+            (0, 0),
+            1,
+            base_dir.clone(),
+        )
+        .expect("late write");
+
+        let recovered = read(&source, base_dir).expect("journal");
+        assert_eq!(recovered.transformations.len(), 1);
+    }
+
+    #[test]
+    fn recipe_round_trips_through_the_data_directory() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let base_dir = Some(dir.path().join("data"));
+        assert!(read_recipe(base_dir.clone()).is_none());
+
+        let crop = iced::Rectangle {
+            x: 2.0,
+            y: 3.0,
+            width: 20.0,
+            height: 10.0,
+        };
+        let recipe = AdjustmentRecipe::new(
+            (40, 30),
+            &[
+                Transformation::FlipHorizontal,
+                Transformation::Crop { rect: crop },
+                Transformation::AdjustContrast { value: 15 },
+            ],
+        );
+        write_recipe(&recipe, base_dir.clone()).expect("write recipe");
+
+        let read = read_recipe(base_dir).expect("recipe");
+        assert_eq!(read.size(), (40, 30));
+        assert_eq!(read.len(), 3);
+        assert!(matches!(
+            read.steps()[1],
+            Transformation::Crop { rect } if rect == crop
+        ));
+        assert!(matches!(
+            read.steps()[2],
+            Transformation::AdjustContrast { value: 15 }
+        ));
+    }
+}
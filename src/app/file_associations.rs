@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Registering IcedLens as the default image viewer of the current user.
+//!
+//! On Linux, a desktop entry starting the running executable is written to
+//! the user's `applications` folder and made the default for image MIME
+//! types with `xdg-mime`. On Windows, the image extensions are associated
+//! with a program id under `HKEY_CURRENT_USER`; Windows only lets the user
+//! pick the default app, so its Default apps page is opened afterwards.
+//! Nothing is changed system-wide.
+
+use std::io;
+use std::path::{Path, PathBuf};
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+use std::process::Command;
+
+/// Name of the desktop entry written on Linux.
+#[cfg(target_os = "linux")]
+const DESKTOP_FILE_NAME: &str = "iced_lens.desktop";
+
+/// Image MIME types associated on Linux.
+#[cfg(target_os = "linux")]
+const IMAGE_MIME_TYPES: &[&str] = &[
+    "image/jpeg",
+    "image/png",
+    "image/gif",
+    "image/webp",
+    "image/tiff",
+    "image/bmp",
+    "image/x-icon",
+    "image/svg+xml",
+];
+
+/// Program id the image extensions are associated with on Windows.
+#[cfg(target_os = "windows")]
+const PROG_ID: &str = "IcedLens.Image";
+
+/// What is left to do once the associations are registered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Registration {
+    /// IcedLens is now the default image viewer.
+    Done,
+    /// The system settings were opened for the user to confirm the choice.
+    ConfirmInSettings,
+}
+
+/// Returns true if this platform has a registration helper.
+#[must_use]
+pub const fn is_supported() -> bool {
+    cfg!(any(target_os = "linux", target_os = "windows"))
+}
+
+/// Registers IcedLens as the default image viewer of the current user.
+///
+/// Runs external programs: call it off the UI thread.
+///
+/// # Errors
+///
+/// Returns an error if the platform is not supported, a file can't be
+/// written or a registration command fails.
+pub fn register_default_viewer() -> io::Result<Registration> {
+    register(&executable()?)
+}
+
+/// Returns the program opened files are passed to: the AppImage itself when
+/// running from one, as its mount point changes on each start.
+fn executable() -> io::Result<PathBuf> {
+    match std::env::var_os("APPIMAGE") {
+        Some(appimage) => Ok(PathBuf::from(appimage)),
+        None => std::env::current_exe(),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn register(executable: &Path) -> io::Result<Registration> {
+    let dir = dirs::data_dir()
+        .map(|dir| dir.join("applications"))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no data directory"))?;
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join(DESKTOP_FILE_NAME), desktop_entry(executable))?;
+
+    run(Command::new("xdg-mime")
+        .arg("default")
+        .arg(DESKTOP_FILE_NAME)
+        .args(IMAGE_MIME_TYPES))?;
+    Ok(Registration::Done)
+}
+
+/// Returns the desktop entry starting `executable` with the opened files.
+#[cfg(target_os = "linux")]
+fn desktop_entry(executable: &Path) -> String {
+    // Quoted arguments escape `"`, `` ` ``, `$` and `\`, the entry format
+    // escapes `\` once more, and a literal `%` is written `%%` so it isn't
+    // read as a field code
+    let mut quoted = String::from("\"");
+    for c in executable.to_string_lossy().chars() {
+        match c {
+            '"' | '`' | '$' => {
+                quoted.push_str("\\\\");
+                quoted.push(c);
+            }
+            '\\' => quoted.push_str("\\\\\\\\"),
+            '%' => quoted.push_str("%%"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+
+    format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name=IcedLens\n\
+         GenericName=Media Viewer\n\
+         Icon=page.codeberg.Bawycle.IcedLens\n\
+         Exec={quoted} %F\n\
+         Terminal=false\n\
+         NoDisplay=true\n\
+         MimeType={};\n",
+        IMAGE_MIME_TYPES.join(";")
+    )
+}
+
+#[cfg(target_os = "windows")]
+fn register(executable: &Path) -> io::Result<Registration> {
+    let classes = r"HKCU\Software\Classes";
+    let open_command = format!("\"{}\" \"%1\"", executable.display());
+
+    run(Command::new("reg").args([
+        "add",
+        &format!(r"{classes}\{PROG_ID}"),
+        "/ve",
+        "/d",
+        "IcedLens",
+        "/f",
+    ]))?;
+    run(Command::new("reg").args([
+        "add",
+        &format!(r"{classes}\{PROG_ID}\shell\open\command"),
+        "/ve",
+        "/d",
+        &open_command,
+        "/f",
+    ]))?;
+    for extension in crate::media::extensions::IMAGE_EXTENSIONS {
+        run(Command::new("reg").args([
+            "add",
+            &format!(r"{classes}\.{extension}\OpenWithProgids"),
+            "/v",
+            PROG_ID,
+            "/t",
+            "REG_NONE",
+            "/f",
+        ]))?;
+    }
+
+    // Explorer exits with a failure status even when the page opens
+    Command::new("explorer")
+        .arg("ms-settings:defaultapps")
+        .spawn()?;
+    Ok(Registration::ConfirmInSettings)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn register(_executable: &Path) -> io::Result<Registration> {
+    Err(io::ErrorKind::Unsupported.into())
+}
+
+/// Runs `command`, turning a failure status into an error with its output.
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+fn run(command: &mut Command) -> io::Result<()> {
+    let output = command.output()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(io::Error::other(format!(
+            "{} ({})",
+            stderr.trim(),
+            output.status
+        )))
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn desktop_entry_quotes_the_executable() {
+        let entry = desktop_entry(Path::new("/opt/Iced Lens/$bin/iced_lens"));
+
+        assert!(entry.contains("Exec=\"/opt/Iced Lens/\\\\$bin/iced_lens\" %F\n"));
+        assert!(entry.contains("MimeType=image/jpeg;image/png;"));
+        assert!(entry.ends_with(";\n"));
+
+        let entry = desktop_entry(Path::new("/opt/100%/iced_lens"));
+        assert!(entry.contains("Exec=\"/opt/100%%/iced_lens\" %F\n"));
+    }
+}
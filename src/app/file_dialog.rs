@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Localized file-type filters for the native open and save dialogs.
+//!
+//! Native dialogs preselect the first filter they are given, so the filter
+//! to preselect is always listed first. `rfd` does not report which filter
+//! the user picked, so the open dialog remembers the kind of the last opened
+//! file (image or video) instead.
+
+use crate::i18n::fluent::I18n;
+use crate::media::extensions::{
+    ALL_MEDIA_EXTENSIONS, IMAGE_EXTENSIONS, IMAGE_FORMAT_GROUPS, VIDEO_EXTENSIONS,
+    VIDEO_FORMAT_GROUPS,
+};
+use crate::media::{detect_media_type, MediaType};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Filter presets of the open dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OpenFilter {
+    #[default]
+    AllMedia,
+    Images,
+    Videos,
+}
+
+impl OpenFilter {
+    /// All presets in their default display order.
+    pub const ALL: [OpenFilter; 3] = [OpenFilter::AllMedia, OpenFilter::Images, OpenFilter::Videos];
+
+    /// Returns the i18n key of the preset's name.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            OpenFilter::AllMedia => "dialog-filter-all-media",
+            OpenFilter::Images => "dialog-filter-images",
+            OpenFilter::Videos => "dialog-filter-videos",
+        }
+    }
+
+    /// Returns the extensions matched by the preset.
+    #[must_use]
+    pub fn extensions(self) -> &'static [&'static str] {
+        match self {
+            OpenFilter::AllMedia => ALL_MEDIA_EXTENSIONS,
+            OpenFilter::Images => IMAGE_EXTENSIONS,
+            OpenFilter::Videos => VIDEO_EXTENSIONS,
+        }
+    }
+
+    /// Returns the preset to preselect after opening `path`.
+    #[must_use]
+    pub fn for_opened_file(path: &Path) -> Self {
+        match detect_media_type(path) {
+            Some(MediaType::Image) => OpenFilter::Images,
+            Some(MediaType::Video) => OpenFilter::Videos,
+            None => OpenFilter::AllMedia,
+        }
+    }
+}
+
+/// A named filter ready to pass to `rfd::AsyncFileDialog::add_filter`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialogFilter {
+    pub name: String,
+    pub extensions: &'static [&'static str],
+}
+
+/// Returns the open dialog filters: presets first (`last` on top), then one
+/// filter per format.
+#[must_use]
+pub fn open_filters(i18n: &I18n, last: OpenFilter) -> Vec<DialogFilter> {
+    let presets = std::iter::once(last)
+        .chain(OpenFilter::ALL.into_iter().filter(move |f| *f != last))
+        .map(|filter| DialogFilter {
+            name: i18n.tr(filter.i18n_key()),
+            extensions: filter.extensions(),
+        });
+
+    presets
+        .chain(format_filters(
+            i18n,
+            IMAGE_FORMAT_GROUPS,
+            "dialog-filter-image-format",
+        ))
+        .chain(format_filters(
+            i18n,
+            VIDEO_FORMAT_GROUPS,
+            "dialog-filter-video-format",
+        ))
+        .collect()
+}
+
+/// Returns the save dialog filters for `formats`, with the format matching
+/// `preferred_extension` first so the dialog preselects it.
+#[must_use]
+pub fn save_filters(
+    i18n: &I18n,
+    formats: &'static [(&'static str, &'static [&'static str])],
+    preferred_extension: Option<&str>,
+) -> Vec<DialogFilter> {
+    let mut filters: Vec<DialogFilter> =
+        format_filters(i18n, formats, "dialog-filter-image-format").collect();
+    if let Some(preferred) = preferred_extension.map(str::to_ascii_lowercase) {
+        if let Some(index) = filters
+            .iter()
+            .position(|filter| filter.extensions.contains(&preferred.as_str()))
+        {
+            let filter = filters.remove(index);
+            filters.insert(0, filter);
+        }
+    }
+    filters
+}
+
+/// Builds one localized filter per format group.
+fn format_filters<'a>(
+    i18n: &'a I18n,
+    groups: &'static [(&'static str, &'static [&'static str])],
+    key: &'static str,
+) -> impl Iterator<Item = DialogFilter> + 'a {
+    groups
+        .iter()
+        .map(move |&(format, extensions)| DialogFilter {
+            name: i18n.tr_with_args(key, &[("format", format)]),
+            extensions,
+        })
+}
+
+/// Adds `filters` to a dialog, in order.
+#[must_use]
+pub fn with_filters(
+    dialog: rfd::AsyncFileDialog,
+    filters: &[DialogFilter],
+) -> rfd::AsyncFileDialog {
+    filters.iter().fold(dialog, |dialog, filter| {
+        dialog.add_filter(&filter.name, filter.extensions)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::media::extensions::IMAGE_SAVE_FILTERS;
+
+    #[test]
+    fn open_filters_list_last_preset_first() {
+        let i18n = I18n::default();
+        let filters = open_filters(&i18n, OpenFilter::Videos);
+
+        assert_eq!(filters[0].extensions, VIDEO_EXTENSIONS);
+        assert_eq!(filters[1].extensions, ALL_MEDIA_EXTENSIONS);
+        assert_eq!(filters[2].extensions, IMAGE_EXTENSIONS);
+        assert_eq!(
+            filters.len(),
+            3 + IMAGE_FORMAT_GROUPS.len() + VIDEO_FORMAT_GROUPS.len()
+        );
+    }
+
+    #[test]
+    fn save_filters_preselect_the_preferred_format() {
+        let i18n = I18n::default();
+
+        let filters = save_filters(&i18n, IMAGE_SAVE_FILTERS, Some("WEBP"));
+        assert_eq!(filters[0].extensions, &["webp"]);
+        assert_eq!(filters.len(), IMAGE_SAVE_FILTERS.len());
+
+        let filters = save_filters(&i18n, IMAGE_SAVE_FILTERS, Some("xyz"));
+        assert_eq!(filters[0].extensions, IMAGE_SAVE_FILTERS[0].1);
+    }
+
+    #[test]
+    fn opened_file_kind_selects_preset() {
+        assert_eq!(
+            OpenFilter::for_opened_file(Path::new("clip.MP4")),
+            OpenFilter::Videos
+        );
+        assert_eq!(
+            OpenFilter::for_opened_file(Path::new("photo.jpg")),
+            OpenFilter::Images
+        );
+        assert_eq!(
+            OpenFilter::for_opened_file(Path::new("notes.txt")),
+            OpenFilter::AllMedia
+        );
+    }
+}
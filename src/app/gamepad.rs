@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Gamepad input, behind the `gamepad` feature.
+//!
+//! Buttons of any controller seen by `gilrs` browse the viewer, which is
+//! handy when reviewing photos on a TV from the couch:
+//!
+//! | Button                          | Command           |
+//! |---------------------------------|-------------------|
+//! | D-pad right, right bumper       | Next media        |
+//! | D-pad left, left bumper         | Previous media    |
+//! | South (A / Cross)               | Play / pause      |
+//! | Start, North (Y / Triangle)     | Toggle fullscreen |
+//!
+//! Controllers are polled on a blocking thread for as long as the
+//! subscription is active. Without a usable input backend the subscription
+//! ends quietly.
+//!
+//! Presentation remotes are keyboards to the system, not controllers, so
+//! `gilrs` never sees them.
+
+use crate::ui::viewer::{component, controls};
+use gilrs::{Button, EventType, Gilrs};
+use iced::futures::SinkExt;
+use iced::Subscription;
+use std::time::Duration;
+
+/// How long the polling thread waits for an event before checking whether
+/// the subscription is still alive.
+const POLL_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// A viewer command sent by a controller button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Next,
+    Previous,
+    PlayPause,
+    ToggleFullscreen,
+}
+
+impl Command {
+    /// Returns the command of `button`, if it has one.
+    #[must_use]
+    pub fn from_button(button: Button) -> Option<Self> {
+        match button {
+            Button::DPadRight | Button::RightTrigger => Some(Self::Next),
+            Button::DPadLeft | Button::LeftTrigger => Some(Self::Previous),
+            Button::South => Some(Self::PlayPause),
+            Button::Start | Button::North => Some(Self::ToggleFullscreen),
+            _ => None,
+        }
+    }
+
+    /// Returns the viewer message running the command.
+    #[must_use]
+    pub fn viewer_message(self) -> component::Message {
+        match self {
+            Self::Next => component::Message::NavigateNext,
+            Self::Previous => component::Message::NavigatePrevious,
+            Self::PlayPause => component::Message::TogglePlayback,
+            Self::ToggleFullscreen => {
+                component::Message::Controls(controls::Message::ToggleFullscreen)
+            }
+        }
+    }
+}
+
+/// Emits the commands of the buttons pressed on any connected controller.
+pub fn subscription() -> Subscription<Command> {
+    Subscription::run(command_stream)
+}
+
+fn command_stream() -> impl iced::futures::Stream<Item = Command> {
+    iced::stream::channel(16, |mut output| async move {
+        let (command_tx, mut command_rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::task::spawn_blocking(move || {
+            let mut gilrs = match Gilrs::new() {
+                Ok(gilrs) => gilrs,
+                Err(err) => {
+                    tracing::warn!("Gamepad input unavailable: {err}");
+                    return;
+                }
+            };
+            while !command_tx.is_closed() {
+                let Some(event) = gilrs.next_event_blocking(Some(POLL_TIMEOUT)) else {
+                    continue;
+                };
+                if let EventType::ButtonPressed(button, _) = event.event {
+                    if let Some(command) = Command::from_button(button) {
+                        let _ = command_tx.send(command);
+                    }
+                }
+            }
+        });
+
+        while let Some(command) = command_rx.recv().await {
+            if output.send(command).await.is_err() {
+                break;
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shoulder_buttons_and_dpad_navigate() {
+        assert_eq!(Command::from_button(Button::DPadRight), Some(Command::Next));
+        assert_eq!(
+            Command::from_button(Button::LeftTrigger),
+            Some(Command::Previous)
+        );
+        assert_eq!(
+            Command::from_button(Button::Start),
+            Some(Command::ToggleFullscreen)
+        );
+        assert_eq!(Command::from_button(Button::LeftThumb), None);
+    }
+
+    #[test]
+    fn commands_map_to_viewer_messages() {
+        assert!(matches!(
+            Command::Next.viewer_message(),
+            component::Message::NavigateNext
+        ));
+        assert!(matches!(
+            Command::Previous.viewer_message(),
+            component::Message::NavigatePrevious
+        ));
+        assert!(matches!(
+            Command::PlayPause.viewer_message(),
+            component::Message::TogglePlayback
+        ));
+        assert!(matches!(
+            Command::ToggleFullscreen.viewer_message(),
+            component::Message::Controls(controls::Message::ToggleFullscreen)
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Writing direction of the interface language.
+//!
+//! Right-to-left languages such as Arabic or Hebrew mirror the layout: the
+//! "previous" arrow and key move to the right, side panels swap sides and
+//! rows read from right to left. Text itself is shaped and aligned by the
+//! text engine from its script.
+
+use iced::alignment::Horizontal;
+use unic_langid::LanguageIdentifier;
+
+/// Languages written from right to left.
+const RTL_LANGUAGES: &[&str] = &["ar", "ckb", "dv", "fa", "he", "ps", "sd", "ug", "ur", "yi"];
+
+/// Direction in which the interface reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutDirection {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    /// Returns the direction of `locale`'s language.
+    #[must_use]
+    pub fn for_locale(locale: &LanguageIdentifier) -> Self {
+        if RTL_LANGUAGES.contains(&locale.language.as_str()) {
+            Self::RightToLeft
+        } else {
+            Self::LeftToRight
+        }
+    }
+
+    #[must_use]
+    pub fn is_rtl(self) -> bool {
+        self == Self::RightToLeft
+    }
+
+    /// Returns `start` and `end` in left-to-right screen order: unchanged
+    /// for left-to-right languages, swapped for right-to-left ones.
+    #[must_use]
+    pub fn order<T>(self, start: T, end: T) -> (T, T) {
+        match self {
+            Self::LeftToRight => (start, end),
+            Self::RightToLeft => (end, start),
+        }
+    }
+
+    /// Screen side where reading starts.
+    #[must_use]
+    pub fn start(self) -> Horizontal {
+        match self {
+            Self::LeftToRight => Horizontal::Left,
+            Self::RightToLeft => Horizontal::Right,
+        }
+    }
+
+    /// Screen side where reading ends.
+    #[must_use]
+    pub fn end(self) -> Horizontal {
+        match self {
+            Self::LeftToRight => Horizontal::Right,
+            Self::RightToLeft => Horizontal::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arabic_and_hebrew_read_right_to_left() {
+        for tag in ["ar", "he-IL", "fa"] {
+            let locale: LanguageIdentifier = tag.parse().expect("valid locale");
+            assert!(LayoutDirection::for_locale(&locale).is_rtl(), "{tag}");
+        }
+        let french: LanguageIdentifier = "fr".parse().expect("valid locale");
+        assert!(!LayoutDirection::for_locale(&french).is_rtl());
+    }
+
+    #[test]
+    fn right_to_left_swaps_sides() {
+        let rtl = LayoutDirection::RightToLeft;
+        assert_eq!(rtl.order("previous", "next"), ("next", "previous"));
+        assert_eq!(rtl.start(), Horizontal::Right);
+        assert_eq!(LayoutDirection::LeftToRight.order(1, 2), (1, 2));
+    }
+}
@@ -27,8 +27,12 @@
 //! assert_eq!(i18n.current_locale().to_string(), "en-US");
 //! ```
 
+use super::direction::LayoutDirection;
+use super::format;
 use crate::config::Config;
+use chrono::{NaiveDate, NaiveDateTime};
 use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use fluent_syntax::ast;
 use std::collections::HashMap;
 use std::fs;
 use unic_langid::LanguageIdentifier;
@@ -37,6 +41,11 @@ pub struct I18n {
     bundles: HashMap<LanguageIdentifier, FluentBundle<FluentResource>>,
     pub available_locales: Vec<LanguageIdentifier>,
     current_locale: LanguageIdentifier,
+    /// Directory the `.ftl` files are loaded from.
+    dir: String,
+    /// Message keys of the English translation, which other languages are
+    /// checked against.
+    reference_keys: Vec<String>,
 }
 
 impl Default for I18n {
@@ -47,12 +56,110 @@ impl Default for I18n {
 
 const TRANSLATIONS_DIR: &str = "assets/i18n/";
 
+/// Language of the reference translation, used for missing keys.
+const DEFAULT_LOCALE: &str = "en-US";
+
+/// Bundles read from a translations directory.
+struct Translations {
+    bundles: HashMap<LanguageIdentifier, FluentBundle<FluentResource>>,
+    locales: Vec<LanguageIdentifier>,
+    reference_keys: Vec<String>,
+}
+
+/// Reads every `<locale>.ftl` file of `dir`. Files that can't be read or
+/// parsed are skipped with a warning.
+fn load_translations(dir: &str) -> Translations {
+    let mut bundles = HashMap::new();
+    let mut locales = Vec::new();
+    let mut reference_keys = Vec::new();
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        tracing::warn!("Failed to read translations directory: {dir}");
+        return Translations {
+            bundles,
+            locales,
+            reference_keys,
+        };
+    };
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        let filename = match path.file_name().and_then(|s| s.to_str()) {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+
+        let locale = match filename.strip_suffix(".ftl") {
+            Some(locale_str) => {
+                if let Ok(locale) = locale_str.parse::<LanguageIdentifier>() {
+                    locale
+                } else {
+                    tracing::warn!("Invalid locale in FTL filename '{filename}'; skipping");
+                    continue;
+                }
+            }
+            None => continue,
+        };
+
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) => {
+                tracing::warn!("Failed to read FTL file '{}': {}", path.display(), err);
+                continue;
+            }
+        };
+
+        let resource = match FluentResource::try_new(content) {
+            Ok(resource) => resource,
+            Err(errors) => {
+                tracing::warn!(
+                    "Failed to parse FTL file '{}': {:?}",
+                    path.display(),
+                    errors
+                );
+                continue;
+            }
+        };
+
+        let keys: Vec<String> = resource
+            .entries()
+            .filter_map(|entry| match entry {
+                ast::Entry::Message(message) => Some(message.id.name.to_string()),
+                _ => None,
+            })
+            .collect();
+
+        let mut bundle = FluentBundle::new(vec![locale.clone()]);
+        if let Err(errors) = bundle.add_resource(resource) {
+            tracing::warn!("Failed to add resource for locale '{locale}': {errors:?}");
+            continue;
+        }
+
+        if locale.to_string() == DEFAULT_LOCALE {
+            reference_keys = keys;
+        }
+        bundles.insert(locale.clone(), bundle);
+        locales.push(locale);
+    }
+
+    locales.sort_by_key(std::string::ToString::to_string);
+    reference_keys.sort();
+    Translations {
+        bundles,
+        locales,
+        reference_keys,
+    }
+}
+
 fn pick_dir(override_dir: Option<String>) -> String {
     if let Some(dir) = override_dir {
         if std::path::Path::new(&dir).is_dir() {
             return dir;
         }
-        eprintln!("Provided i18n directory does not exist or is not a directory: {dir}");
+        tracing::warn!("Provided i18n directory does not exist or is not a directory: {dir}");
     }
 
     // On Windows, when launched via file association, the working directory is the
@@ -79,80 +186,56 @@ impl I18n {
     /// Panics if the default locale bundle cannot be parsed. This indicates
     /// a bug in the bundled translation files.
     pub fn new(cli_lang: Option<String>, cli_dir: Option<String>, config: &Config) -> Self {
-        let mut bundles = HashMap::new();
-        let mut available_locales = Vec::new();
-
         let dir = pick_dir(cli_dir);
-        if let Ok(entries) = fs::read_dir(&dir) {
-            for entry in entries.filter_map(Result::ok) {
-                let path = entry.path();
-                if !path.is_file() {
-                    continue;
-                }
-
-                let filename = match path.file_name().and_then(|s| s.to_str()) {
-                    Some(name) => name.to_string(),
-                    None => continue,
-                };
-
-                let locale = match filename.strip_suffix(".ftl") {
-                    Some(locale_str) => {
-                        if let Ok(locale) = locale_str.parse::<LanguageIdentifier>() {
-                            locale
-                        } else {
-                            eprintln!("Invalid locale in FTL filename '{filename}'; skipping");
-                            continue;
-                        }
-                    }
-                    None => continue,
-                };
-
-                let content = match fs::read_to_string(&path) {
-                    Ok(content) => content,
-                    Err(err) => {
-                        eprintln!("Failed to read FTL file '{}': {}", path.display(), err);
-                        continue;
-                    }
-                };
-
-                let resource = match FluentResource::try_new(content) {
-                    Ok(resource) => resource,
-                    Err(errors) => {
-                        eprintln!(
-                            "Failed to parse FTL file '{}': {:?}",
-                            path.display(),
-                            errors
-                        );
-                        continue;
-                    }
-                };
-
-                let mut bundle = FluentBundle::new(vec![locale.clone()]);
-                if let Err(errors) = bundle.add_resource(resource) {
-                    eprintln!("Failed to add resource for locale '{locale}': {errors:?}");
-                    continue;
-                }
+        let translations = load_translations(&dir);
 
-                bundles.insert(locale.clone(), bundle);
-                available_locales.push(locale);
-            }
-        } else {
-            eprintln!("Failed to read translations directory: {dir}");
-        }
-
-        available_locales.sort_by_key(std::string::ToString::to_string);
-
-        let default_locale: LanguageIdentifier = "en-US".parse().unwrap();
+        let default_locale: LanguageIdentifier = DEFAULT_LOCALE.parse().unwrap();
         let current_locale =
-            resolve_locale(cli_lang, config, &available_locales).unwrap_or(default_locale);
+            resolve_locale(cli_lang, config, &translations.locales).unwrap_or(default_locale);
 
         Self {
-            bundles,
-            available_locales,
+            bundles: translations.bundles,
+            available_locales: translations.locales,
             current_locale,
+            dir,
+            reference_keys: translations.reference_keys,
         }
     }
 
+    /// Returns the directory the translations are loaded from.
+    #[must_use]
+    pub fn dir(&self) -> &str {
+        &self.dir
+    }
+
+    /// Loads the `.ftl` files again, for translators editing them while the
+    /// app runs. The current language is kept if it is still available.
+    pub fn reload(&mut self) {
+        let translations = load_translations(&self.dir);
+        self.bundles = translations.bundles;
+        self.available_locales = translations.locales;
+        self.reference_keys = translations.reference_keys;
+        if !self.bundles.contains_key(&self.current_locale) {
+            if let Ok(default_locale) = DEFAULT_LOCALE.parse() {
+                self.current_locale = default_locale;
+            }
+        }
+    }
+
+    /// Returns the keys of the English translation missing from the current
+    /// language, which are shown in English instead.
+    #[must_use]
+    pub fn missing_translations(&self) -> Vec<&str> {
+        let Some(bundle) = self.bundles.get(&self.current_locale) else {
+            return Vec::new();
+        };
+        self.reference_keys
+            .iter()
+            .filter(|key| !bundle.has_message(key))
+            .map(String::as_str)
+            .collect()
+    }
+
     pub fn set_locale(&mut self, locale: LanguageIdentifier) {
         if self.bundles.contains_key(&locale) {
             self.current_locale = locale;
@@ -183,7 +266,16 @@ impl I18n {
     /// ```
     #[must_use]
     pub fn tr_with_args(&self, key: &str, args: &[(&str, &str)]) -> String {
-        if let Some(bundle) = self.bundles.get(&self.current_locale) {
+        // Keys missing from the current language are shown in English
+        let bundle = self
+            .bundles
+            .get(&self.current_locale)
+            .filter(|bundle| bundle.has_message(key))
+            .or_else(|| {
+                let default_locale: LanguageIdentifier = DEFAULT_LOCALE.parse().ok()?;
+                self.bundles.get(&default_locale)
+            });
+        if let Some(bundle) = bundle {
             if let Some(msg) = bundle.get_message(key) {
                 if let Some(pattern) = msg.value() {
                     let mut errors = vec![];
@@ -212,6 +304,47 @@ impl I18n {
     pub fn current_locale(&self) -> &LanguageIdentifier {
         &self.current_locale
     }
+
+    /// Returns the direction the interface reads in for the current locale.
+    #[must_use]
+    pub fn layout_direction(&self) -> LayoutDirection {
+        LayoutDirection::for_locale(&self.current_locale)
+    }
+
+    /// Formats `value` with `decimals` fraction digits in the conventions of
+    /// the current locale.
+    #[must_use]
+    pub fn format_number(&self, value: f64, decimals: usize) -> String {
+        format::format_decimal(&self.current_locale, value, decimals)
+    }
+
+    /// Formats a file size with a translated unit, such as `1,21 Mo`.
+    #[must_use]
+    pub fn format_file_size(&self, bytes: u64) -> String {
+        let (value, decimals, unit) = format::scale_file_size(bytes);
+        let value = self.format_number(value, decimals);
+        self.tr_with_args(unit.i18n_key(), &[("value", &value)])
+    }
+
+    /// Formats a bitrate with a translated unit, such as `5,50 Mbit/s`.
+    #[must_use]
+    pub fn format_bitrate(&self, bits_per_sec: u64) -> String {
+        let (value, decimals, unit) = format::scale_bitrate(bits_per_sec);
+        let value = self.format_number(value, decimals);
+        self.tr_with_args(unit.i18n_key(), &[("value", &value)])
+    }
+
+    /// Formats a date and time in the conventions of the current locale.
+    #[must_use]
+    pub fn format_datetime(&self, datetime: &NaiveDateTime) -> String {
+        format::format_datetime(&self.current_locale, datetime)
+    }
+
+    /// Formats a date in the conventions of the current locale.
+    #[must_use]
+    pub fn format_date(&self, date: NaiveDate) -> String {
+        format::format_date(&self.current_locale, date)
+    }
 }
 
 fn resolve_locale(
@@ -363,6 +496,65 @@ mod tests {
         assert!(no_args.starts_with("MISSING:")); // Expected: missing variables cause error
     }
 
+    #[test]
+    fn file_sizes_use_the_locale_separators_and_units() {
+        let dir = tempdir().expect("temp dir");
+        std::fs::write(
+            dir.path().join("en-US.ftl"),
+            "unit-kilobytes = { $value } KB\n",
+        )
+        .expect("write ftl");
+        std::fs::write(
+            dir.path().join("fr.ftl"),
+            "unit-kilobytes = { $value } Ko\n",
+        )
+        .expect("write ftl");
+
+        let mut i18n = I18n::new(
+            Some("fr".to_string()),
+            Some(dir.path().display().to_string()),
+            &Config::default(),
+        );
+        let size = i18n.format_file_size(1536);
+        assert!(size.contains("1,5") && size.ends_with("Ko"));
+
+        i18n.set_locale("en-US".parse().unwrap());
+        let size = i18n.format_file_size(1536);
+        assert!(size.contains("1.5") && size.ends_with("KB"));
+    }
+
+    #[test]
+    fn missing_keys_fall_back_to_english_and_are_reported() {
+        let dir = tempdir().expect("temp dir");
+        std::fs::write(
+            dir.path().join("en-US.ftl"),
+            "window-title = Viewer\nsettings-title = Settings\n",
+        )
+        .expect("write ftl");
+        std::fs::write(dir.path().join("fr.ftl"), "window-title = Visionneuse\n")
+            .expect("write ftl");
+
+        let mut i18n = I18n::new(
+            Some("fr".to_string()),
+            Some(dir.path().display().to_string()),
+            &Config::default(),
+        );
+        assert_eq!(i18n.tr("window-title"), "Visionneuse");
+        assert_eq!(i18n.tr("settings-title"), "Settings");
+        assert_eq!(i18n.missing_translations(), vec!["settings-title"]);
+
+        // Translators see their edits after a reload
+        std::fs::write(
+            dir.path().join("fr.ftl"),
+            "window-title = Visionneuse\nsettings-title = Paramètres\n",
+        )
+        .expect("write ftl");
+        i18n.reload();
+        assert_eq!(i18n.current_locale().to_string(), "fr");
+        assert_eq!(i18n.tr("settings-title"), "Paramètres");
+        assert!(i18n.missing_translations().is_empty());
+    }
+
     #[test]
     fn test_set_locale_ignores_unknown_language() {
         let mut i18n = I18n::new(None, None, &Config::default());
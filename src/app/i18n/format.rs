@@ -0,0 +1,275 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Locale conventions for numbers and dates.
+//!
+//! Numbers use the decimal and grouping separators of the language
+//! (`1,234.5` in English, `1 234,5` in French, `1.234,5` in German) and dates
+//! the formats of the matching chrono locale. Unit names are translated in
+//! the `.ftl` files (`unit-*` messages); [`crate::i18n::fluent::I18n`] puts
+//! both together.
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use unic_langid::LanguageIdentifier;
+
+/// Separators of a language's numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct NumberSymbols {
+    decimal: char,
+    group: char,
+    /// Integer digits needed before thousands are grouped: Spanish writes
+    /// `1234` but `12.345`.
+    min_grouping_digits: usize,
+}
+
+const ENGLISH_SYMBOLS: NumberSymbols = NumberSymbols {
+    decimal: '.',
+    group: ',',
+    min_grouping_digits: 1,
+};
+
+fn number_symbols(locale: &LanguageIdentifier) -> NumberSymbols {
+    match locale.language.as_str() {
+        // Narrow no-break space, as recommended for French
+        "fr" => NumberSymbols {
+            decimal: ',',
+            group: '\u{202F}',
+            min_grouping_digits: 1,
+        },
+        "de" | "it" => NumberSymbols {
+            decimal: ',',
+            group: '.',
+            min_grouping_digits: 1,
+        },
+        "es" => NumberSymbols {
+            decimal: ',',
+            group: '.',
+            min_grouping_digits: 2,
+        },
+        _ => ENGLISH_SYMBOLS,
+    }
+}
+
+/// Returns the chrono locale whose date formats match `locale`.
+fn chrono_locale(locale: &LanguageIdentifier) -> chrono::Locale {
+    match locale.language.as_str() {
+        "fr" => chrono::Locale::fr_FR,
+        "de" => chrono::Locale::de_DE,
+        "es" => chrono::Locale::es_ES,
+        "it" => chrono::Locale::it_IT,
+        _ => chrono::Locale::en_US,
+    }
+}
+
+/// Formats `value` with `decimals` fraction digits and grouped thousands.
+#[must_use]
+pub fn format_decimal(locale: &LanguageIdentifier, value: f64, decimals: usize) -> String {
+    let symbols = number_symbols(locale);
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = formatted
+        .split_once('.')
+        .map_or((formatted.as_str(), None), |(integer, fraction)| {
+            (integer, Some(fraction))
+        });
+
+    let mut result = String::new();
+    // No sign for values that round to zero
+    if value < 0.0 && formatted.bytes().any(|b| matches!(b, b'1'..=b'9')) {
+        result.push('-');
+    }
+    result.push_str(&group_digits(integer, symbols));
+    if let Some(fraction) = fraction {
+        result.push(symbols.decimal);
+        result.push_str(fraction);
+    }
+    result
+}
+
+/// Formats `value` with grouped thousands.
+#[must_use]
+pub fn format_integer(locale: &LanguageIdentifier, value: u64) -> String {
+    group_digits(&value.to_string(), number_symbols(locale))
+}
+
+fn group_digits(digits: &str, symbols: NumberSymbols) -> String {
+    if digits.len() < 3 + symbols.min_grouping_digits {
+        return digits.to_string();
+    }
+    let mut grouped =
+        String::with_capacity(digits.len() + digits.len() / 3 * symbols.group.len_utf8());
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(symbols.group);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// Formats a date and time, such as `15/03/2024 14:30:00` in French.
+#[must_use]
+pub fn format_datetime(locale: &LanguageIdentifier, datetime: &NaiveDateTime) -> String {
+    // The time zone is only there to reach the localized formatter, the
+    // format has no zone field
+    Utc.from_utc_datetime(datetime)
+        .format_localized("%x %X", chrono_locale(locale))
+        .to_string()
+}
+
+/// Formats a date, such as `15.03.2024` in German.
+#[must_use]
+pub fn format_date(locale: &LanguageIdentifier, date: NaiveDate) -> String {
+    Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN))
+        .format_localized("%x", chrono_locale(locale))
+        .to_string()
+}
+
+/// Units of [`scale_file_size`], named by the `unit-*` messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteUnit {
+    Bytes,
+    Kilobytes,
+    Megabytes,
+    Gigabytes,
+}
+
+impl ByteUnit {
+    /// Returns the i18n key of the unit, with a `$value` argument.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            Self::Bytes => "unit-bytes",
+            Self::Kilobytes => "unit-kilobytes",
+            Self::Megabytes => "unit-megabytes",
+            Self::Gigabytes => "unit-gigabytes",
+        }
+    }
+}
+
+/// Picks the unit of a file size in binary multiples, with the value and
+/// the fraction digits it is shown with.
+///
+/// Precision loss from u64 to f64 is acceptable: at most two decimals are
+/// shown.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn scale_file_size(bytes: u64) -> (f64, usize, ByteUnit) {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        (bytes as f64 / GB as f64, 2, ByteUnit::Gigabytes)
+    } else if bytes >= MB {
+        (bytes as f64 / MB as f64, 2, ByteUnit::Megabytes)
+    } else if bytes >= KB {
+        (bytes as f64 / KB as f64, 1, ByteUnit::Kilobytes)
+    } else {
+        (bytes as f64, 0, ByteUnit::Bytes)
+    }
+}
+
+/// Units of [`scale_bitrate`], named by the `unit-*` messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitrateUnit {
+    BitsPerSecond,
+    Kilobits,
+    Megabits,
+}
+
+impl BitrateUnit {
+    /// Returns the i18n key of the unit, with a `$value` argument.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            Self::BitsPerSecond => "unit-bits-per-second",
+            Self::Kilobits => "unit-kilobits-per-second",
+            Self::Megabits => "unit-megabits-per-second",
+        }
+    }
+}
+
+/// Picks the unit of a bitrate in decimal multiples, with the value and the
+/// fraction digits it is shown with.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn scale_bitrate(bits_per_sec: u64) -> (f64, usize, BitrateUnit) {
+    const KBPS: u64 = 1000;
+    const MBPS: u64 = KBPS * 1000;
+
+    if bits_per_sec >= MBPS {
+        (bits_per_sec as f64 / MBPS as f64, 2, BitrateUnit::Megabits)
+    } else if bits_per_sec >= KBPS {
+        (bits_per_sec as f64 / KBPS as f64, 0, BitrateUnit::Kilobits)
+    } else {
+        (bits_per_sec as f64, 0, BitrateUnit::BitsPerSecond)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locale(tag: &str) -> LanguageIdentifier {
+        tag.parse().expect("valid locale")
+    }
+
+    #[test]
+    fn english_numbers() {
+        let en = locale("en-US");
+        assert_eq!(format_decimal(&en, 1234.5, 1), "1,234.5");
+        assert_eq!(format_decimal(&en, -0.04, 1), "0.0");
+        assert_eq!(format_integer(&en, 1_234_567), "1,234,567");
+        assert_eq!(format_integer(&en, 999), "999");
+    }
+
+    #[test]
+    fn french_numbers() {
+        let fr = locale("fr");
+        assert_eq!(format_decimal(&fr, 1234.5, 1), "1\u{202F}234,5");
+        assert_eq!(format_decimal(&fr, -2.25, 2), "-2,25");
+    }
+
+    #[test]
+    fn german_and_italian_numbers() {
+        assert_eq!(format_decimal(&locale("de"), 1234.5, 1), "1.234,5");
+        assert_eq!(format_integer(&locale("it"), 1_234_567), "1.234.567");
+    }
+
+    #[test]
+    fn spanish_groups_from_five_digits() {
+        let es = locale("es");
+        assert_eq!(format_integer(&es, 1234), "1234");
+        assert_eq!(format_integer(&es, 12_345), "12.345");
+        assert_eq!(format_decimal(&es, 1234.5, 1), "1234,5");
+    }
+
+    #[test]
+    fn dates_follow_the_locale() {
+        let datetime = NaiveDate::from_ymd_opt(2024, 3, 15)
+            .and_then(|date| date.and_hms_opt(14, 30, 0))
+            .expect("valid date");
+        assert_eq!(
+            format_datetime(&locale("fr"), &datetime),
+            "15/03/2024 14:30:00"
+        );
+        assert_eq!(
+            format_datetime(&locale("de"), &datetime),
+            "15.03.2024 14:30:00"
+        );
+        assert!(format_datetime(&locale("en-US"), &datetime).starts_with("03/15/2024"));
+        assert_eq!(format_date(&locale("it"), datetime.date()), "15/03/2024");
+    }
+
+    #[test]
+    fn file_sizes_use_binary_units() {
+        assert_eq!(scale_file_size(512), (512.0, 0, ByteUnit::Bytes));
+        assert_eq!(scale_file_size(1536), (1.5, 1, ByteUnit::Kilobytes));
+        assert_eq!(scale_file_size(3 * 1024 * 1024).2, ByteUnit::Megabytes);
+    }
+
+    #[test]
+    fn bitrates_use_decimal_units() {
+        assert_eq!(scale_bitrate(800).2, BitrateUnit::BitsPerSecond);
+        assert_eq!(scale_bitrate(128_000), (128.0, 0, BitrateUnit::Kilobits));
+        assert_eq!(scale_bitrate(5_500_000), (5.5, 2, BitrateUnit::Megabits));
+    }
+}
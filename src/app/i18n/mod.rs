@@ -10,5 +10,11 @@
 //! - Dynamic loading of `.ftl` translation files
 //! - Runtime language switching
 //! - Fallback to default locale when translations are missing
+//! - Numbers, units and dates formatted with the conventions of the locale
+//! - Mirrored layout for right-to-left languages
+//! - Reloading of edited `.ftl` files and a report of missing keys, for
+//!   translators
 
+pub mod direction;
 pub mod fluent;
+pub mod format;
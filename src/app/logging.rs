@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Log files and diagnostic reports.
+//!
+//! Messages are written with the `tracing` macros. [`init`] sends them to
+//! stderr and to a log file in the `logs` folder next to `settings.toml`,
+//! rotated daily and keeping the last [`MAX_LOG_FILES`] days. The log viewer
+//! reads them back with [`read_recent`], and [`diagnostic_report`] sums up
+//! the system for bug reports.
+//!
+//! The `RUST_LOG` environment variable overrides the default filter, e.g.
+//! `RUST_LOG=iced_lens=debug`.
+
+use super::paths;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter};
+
+/// Name of the log folder, in the config directory.
+pub const LOGS_DIR: &str = "logs";
+
+/// Number of daily log files kept.
+pub const MAX_LOG_FILES: usize = 7;
+
+/// Number of lines shown by the log viewer.
+pub const VIEWED_LINES: usize = 500;
+
+/// Number of log lines appended to the diagnostic report.
+const REPORT_LOG_LINES: usize = 50;
+
+/// Log file names are `iced_lens.<date>.log`.
+const LOG_FILE_PREFIX: &str = "iced_lens";
+const LOG_FILE_SUFFIX: &str = "log";
+
+/// Filter used when `RUST_LOG` is not set: the application's own messages,
+/// and only problems from the GPU and windowing stacks, which are verbose.
+const DEFAULT_FILTER: &str = "info,wgpu_core=warn,wgpu_hal=warn,naga=warn,cosmic_text=warn";
+
+/// Application version from Cargo.toml.
+const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Returns the log folder, if the config directory is known.
+#[must_use]
+pub fn log_dir() -> Option<PathBuf> {
+    paths::get_app_config_dir().map(|dir| dir.join(LOGS_DIR))
+}
+
+/// Starts logging to stderr and to the rotated log files.
+///
+/// Lines are written to the file from a background thread, which flushes
+/// them when the returned guard is dropped: keep it alive until exit. Logs
+/// go to stderr only if the log folder can't be created.
+#[must_use = "buffered log lines are lost when the guard is dropped"]
+pub fn init() -> Option<WorkerGuard> {
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER));
+
+    let (file_layer, guard) = match log_dir().and_then(|dir| file_appender(&dir).ok()) {
+        Some(appender) => {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            (
+                Some(fmt::layer().with_ansi(false).with_writer(writer)),
+                Some(guard),
+            )
+        }
+        None => (None, None),
+    };
+
+    // Fails only if a subscriber is already set, e.g. by a test harness
+    let _ = tracing_subscriber::registry()
+        .with(filter)
+        .with(fmt::layer().with_writer(io::stderr))
+        .with(file_layer)
+        .try_init();
+    guard
+}
+
+fn file_appender(dir: &Path) -> Result<RollingFileAppender, tracing_appender::rolling::InitError> {
+    RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(LOG_FILE_PREFIX)
+        .filename_suffix(LOG_FILE_SUFFIX)
+        .max_log_files(MAX_LOG_FILES)
+        .build(dir)
+}
+
+/// Returns the log files of `dir`, newest first.
+fn log_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name.starts_with(LOG_FILE_PREFIX)
+                        && name.ends_with(&format!(".{LOG_FILE_SUFFIX}"))
+                })
+        })
+        .collect();
+    // Dates in the names are ISO 8601, so names sort chronologically
+    files.sort_unstable_by(|a, b| b.cmp(a));
+    files
+}
+
+/// Returns the last `max_lines` lines logged in `dir`, oldest first, across
+/// log files.
+#[must_use]
+pub fn read_recent(dir: &Path, max_lines: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for file in log_files(dir) {
+        let Ok(content) = fs::read_to_string(&file) else {
+            continue;
+        };
+        let remaining = max_lines - lines.len();
+        lines.extend(content.lines().rev().take(remaining).map(str::to_owned));
+        if lines.len() == max_lines {
+            break;
+        }
+    }
+    lines.reverse();
+    lines
+}
+
+/// Returns a plain-text summary of the application, system, GPU and FFmpeg
+/// versions followed by the latest log lines, to paste into bug reports.
+///
+/// Listing GPU adapters can take a moment: call this off the UI thread.
+#[must_use]
+pub fn diagnostic_report() -> String {
+    let mut report = format!(
+        "IcedLens {APP_VERSION}\nOS: {} ({})\n",
+        os_description(),
+        std::env::consts::ARCH
+    );
+
+    let instance = wgpu::Instance::default();
+    let adapters = instance.enumerate_adapters(wgpu::Backends::all());
+    if adapters.is_empty() {
+        report.push_str("GPU: none found\n");
+    }
+    for adapter in adapters {
+        let info = adapter.get_info();
+        report.push_str(&format!(
+            "GPU: {} ({:?}, {:?}, driver {} {})\n",
+            info.name, info.device_type, info.backend, info.driver, info.driver_info
+        ));
+    }
+
+    report.push_str(&format!(
+        "FFmpeg: libavutil {}, libavcodec {}, libavformat {}\n",
+        ffmpeg_version(ffmpeg_next::util::version()),
+        ffmpeg_version(ffmpeg_next::codec::version()),
+        ffmpeg_version(ffmpeg_next::format::version()),
+    ));
+
+    if let Some(dir) = log_dir() {
+        report.push_str(&format!("Logs: {}\n", dir.display()));
+        let lines = read_recent(&dir, REPORT_LOG_LINES);
+        if !lines.is_empty() {
+            report.push_str("\nLatest log lines:\n");
+            for line in lines {
+                report.push_str(&line);
+                report.push('\n');
+            }
+        }
+    }
+    report
+}
+
+/// Formats an FFmpeg library version (`major << 16 | minor << 8 | micro`).
+fn ffmpeg_version(version: u32) -> String {
+    format!(
+        "{}.{}.{}",
+        version >> 16,
+        (version >> 8) & 0xff,
+        version & 0xff
+    )
+}
+
+/// Returns the name and version of the operating system, where known.
+fn os_description() -> String {
+    #[cfg(target_os = "linux")]
+    if let Some(name) = fs::read_to_string("/etc/os-release")
+        .ok()
+        .as_deref()
+        .and_then(os_release_name)
+    {
+        return name;
+    }
+    std::env::consts::OS.to_string()
+}
+
+/// Returns the `PRETTY_NAME` of an `os-release` file.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn os_release_name(os_release: &str) -> Option<String> {
+    let value = os_release
+        .lines()
+        .find_map(|line| line.strip_prefix("PRETTY_NAME="))?;
+    Some(value.trim().trim_matches('"').to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_lines_span_rotated_files() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        fs::write(dir.path().join("iced_lens.2026-10-14.log"), "a\nb\nc\n").expect("write log");
+        fs::write(dir.path().join("iced_lens.2026-10-15.log"), "d\ne\n").expect("write log");
+        fs::write(dir.path().join("notes.txt"), "x\n").expect("write file");
+
+        assert_eq!(read_recent(dir.path(), 3), ["c", "d", "e"]);
+        assert_eq!(read_recent(dir.path(), 10), ["a", "b", "c", "d", "e"]);
+        assert!(read_recent(&dir.path().join("missing"), 10).is_empty());
+    }
+
+    #[test]
+    fn ffmpeg_versions_are_split_into_components() {
+        assert_eq!(ffmpeg_version((60 << 16) | (3 << 8) | 100), "60.3.100");
+    }
+
+    #[test]
+    fn os_release_pretty_name_is_unquoted() {
+        let os_release = "NAME=\"Linux Mint\"\nPRETTY_NAME=\"Linux Mint 22.1\"\nID=linuxmint\n";
+        assert_eq!(
+            os_release_name(os_release).as_deref(),
+            Some("Linux Mint 22.1")
+        );
+        assert_eq!(os_release_name("ID=arch\n"), None);
+    }
+}
@@ -2,18 +2,31 @@
 //! Top-level messages and runtime flags for the application.
 
 use crate::error::Error;
+use crate::media::capture_day::CaptureDayIndex;
+use crate::media::face_detection::FaceBox;
 use crate::media::frame_export::ExportableFrame;
-use crate::media::MediaData;
+use crate::media::integrity::VerifyReport;
+use crate::media::metadata_batch::BatchSummary;
+use crate::media::metadata_strip::StripDestination;
+use crate::media::shoot_stats::ShootStats;
+use crate::media::similarity::SimilarityIndex;
+use crate::media::view_export::Destination;
+use crate::media::{LoadOptions, MediaData, ScanId};
 use crate::ui::about;
 use crate::ui::help;
 use crate::ui::image_editor;
+use crate::ui::logs;
 use crate::ui::metadata_panel;
 use crate::ui::navbar;
 use crate::ui::notifications;
+use crate::ui::pip;
 use crate::ui::settings;
-use crate::ui::viewer::component;
+use crate::ui::share_panel;
+use crate::ui::translations;
+use crate::ui::viewer::{component, NavigationDirection};
+use crate::ui::welcome;
 use std::path::PathBuf;
-use std::time::Instant;
+use std::time::{Instant, SystemTime};
 
 use super::Screen;
 
@@ -26,31 +39,130 @@ pub enum Message {
     Settings(settings::Message),
     ImageEditor(image_editor::Message),
     Navbar(navbar::Message),
+    /// Message from the "Share over LAN" address panel.
+    SharePanel(share_panel::Message),
     Help(help::Message),
     About(about::Message),
+    /// Message from the log viewer screen.
+    Logs(logs::Message),
+    /// Message from the missing translations report.
+    Translations(translations::Message),
+    /// Diagnostic report built in the background, to copy to the clipboard.
+    DiagnosticReportReady(String),
+    /// Message from the first-run welcome screen.
+    Welcome(welcome::Message),
+    /// The "set as default image viewer" helper finished.
+    DefaultViewerRegistered(Result<crate::app::file_associations::Registration, String>),
     MetadataPanel(metadata_panel::Message),
     Notification(notifications::NotificationMessage),
+    /// Message from the mini player window.
+    MiniPlayer(pip::Message),
+    /// Button pressed on a gamepad.
+    #[cfg(feature = "gamepad")]
+    Gamepad(super::gamepad::Command),
+    /// The weekly update check may be due.
+    #[cfg(feature = "update-check")]
+    UpdateCheckDue,
+    /// The update check finished; `manual` when it was asked from the settings.
+    #[cfg(feature = "update-check")]
+    UpdateCheckCompleted {
+        result: Result<Option<super::update_check::Release>, String>,
+        manual: bool,
+    },
+    /// Message for the viewer of an additional window.
+    ViewerWindow {
+        window: iced::window::Id,
+        message: component::Message,
+    },
     ImageEditorLoaded(Result<MediaData, Error>),
     SaveAsDialogResult(Option<PathBuf>),
     FrameCaptureDialogResult {
         path: Option<PathBuf>,
         frame: Option<ExportableFrame>,
     },
+    /// Destination chosen for the exported visible area of the viewer.
+    VisibleAreaDialogResult {
+        path: Option<PathBuf>,
+        frame: ExportableFrame,
+    },
+    /// Screenshot of the window taken to export the viewer as displayed.
+    ViewScreenshotTaken {
+        screenshot: iced::window::Screenshot,
+        /// Viewer pane to keep, in logical pixels (`None` keeps the window).
+        bounds: Option<iced::Rectangle>,
+        destination: Destination,
+    },
+    /// Destination chosen for the exported view.
+    ViewExportDialogResult {
+        path: Option<PathBuf>,
+        frame: ExportableFrame,
+    },
     /// Open the image editor with a captured video frame.
     OpenImageEditorWithFrame {
         frame: ExportableFrame,
         video_path: PathBuf,
         position_secs: f64,
     },
+    /// Open the image editor with a video frame decoded at the source
+    /// resolution and bit depth.
+    OpenImageEditorWithImage {
+        image: image_rs::DynamicImage,
+        video_path: PathBuf,
+        position_secs: f64,
+    },
     Tick(Instant), // Periodic tick for overlay auto-hide
+    /// The window gained or lost focus; the app re-checks whether it is minimized.
+    WindowFocusChanged(iced::window::Id),
+    /// Result of the minimized-state query (`None` if the platform can't tell).
+    WindowMinimized(Option<bool>),
+    /// Tab or Shift+Tab: move the keyboard focus to the next or previous
+    /// text field, in layout order.
+    MoveFocus {
+        backwards: bool,
+    },
+    /// F12: show or hide the diagnostics overlay.
+    ToggleDiagnostics,
     /// Trigger the open file dialog from the empty state.
     OpenFileDialog,
     /// Result from the open file dialog.
     OpenFileDialogResult(Option<PathBuf>),
+    /// File chosen to open in a new viewer window.
+    OpenInNewWindowDialogResult(Option<PathBuf>),
     /// A file was dropped on the window.
     FileDropped(PathBuf),
     /// Result from the metadata Save As dialog.
     MetadataSaveAsDialogResult(Option<PathBuf>),
+    /// `settings.toml` was modified on disk.
+    ConfigFileChanged,
+    /// A `.ftl` file of the translations directory was modified
+    /// (`--watch-i18n`).
+    TranslationsChanged,
+    /// File chosen to import the settings from.
+    SettingsImportDialogResult(Option<PathBuf>),
+    /// File chosen to export the settings to.
+    SettingsExportDialogResult(Option<PathBuf>),
+    /// A batch metadata edit finished writing all files.
+    MetadataBatchCompleted(BatchSummary),
+    /// The user answered the "Paste to all images in folder" confirmation
+    /// dialog, with the images to edit (`None` when cancelled).
+    RecipeBatchDialogResult(Option<Vec<PathBuf>>),
+    /// Pasting the copied edit recipe onto the folder finished.
+    RecipeBatchCompleted(BatchSummary),
+    /// The user answered the "Remove all metadata" confirmation dialog
+    /// (`None` when cancelled).
+    StripMetadataDialogResult {
+        path: PathBuf,
+        destination: Option<StripDestination>,
+    },
+    /// A metadata export finished, with the number of files exported.
+    MetadataExported(Result<usize, String>),
+    /// Camera and lens statistics of a folder were computed.
+    ShootStatsComputed {
+        directory: PathBuf,
+        stats: ShootStats,
+    },
+    /// The folder integrity check finished.
+    VerifyCompleted(VerifyReport),
     /// Progress update during deblur model download (0.0 - 1.0).
     DeblurDownloadProgress(f32),
     /// Result from deblur model download.
@@ -63,6 +175,17 @@ pub enum Message {
     },
     /// Result from applying AI deblur to an image.
     DeblurApplyCompleted(Result<Box<image_rs::DynamicImage>, String>),
+    /// Result from running a plugin editor tool on the edited image.
+    PluginToolCompleted(Result<Box<image_rs::DynamicImage>, String>),
+    /// Result from exporting the edited image with a plugin, with the file written.
+    PluginExportCompleted(Result<PathBuf, String>),
+    /// An "Open with / Send to" command exited, with what it printed.
+    OpenWithCompleted {
+        name: String,
+        result: Result<String, String>,
+    },
+    /// Faces detected in the editor's crop base image.
+    EditorFacesDetected(Result<Vec<FaceBox>, String>),
     /// Progress update during upscale model download (0.0 - 1.0).
     UpscaleDownloadProgress(f32),
     /// Result from upscale model download.
@@ -75,8 +198,50 @@ pub enum Message {
     },
     /// Result from applying AI upscale resize to an image.
     UpscaleResizeCompleted(Result<Box<image_rs::DynamicImage>, String>),
+    /// Progress update during background removal model download (0.0 - 1.0).
+    BackgroundDownloadProgress(f32),
+    /// Result from background removal model download.
+    BackgroundDownloadCompleted(Result<(), String>),
+    /// Result from background removal model validation.
+    /// The boolean indicates whether this is a startup validation (true) vs user-initiated (false).
+    BackgroundValidationCompleted {
+        result: Result<(), String>,
+        is_startup: bool,
+    },
+    /// Foreground mask computed for the image in the editor.
+    BackgroundRemovalCompleted(Result<Box<image_rs::GrayImage>, String>),
+    /// Result from denoising the edited image at full resolution.
+    DenoiseCompleted(Result<Box<image_rs::DynamicImage>, String>),
     /// Window close was requested (user clicked X or pressed Alt+F4).
     WindowCloseRequested(iced::window::Id),
+    /// Capture-day index built in the background for a pending day jump.
+    CaptureDaysIndexed {
+        index: CaptureDayIndex,
+        direction: NavigationDirection,
+    },
+    /// Perceptual hash index built in the background for a pending
+    /// "Find similar" jump.
+    SimilarityIndexed {
+        index: SimilarityIndex,
+        direction: NavigationDirection,
+    },
+    /// Sorted snapshot of the media found so far by a background directory
+    /// scan; `complete` is set on the last one.
+    DirectoryScanned {
+        scan: ScanId,
+        files: Vec<PathBuf>,
+        complete: bool,
+    },
+    /// Background scan of a directory failed.
+    DirectoryScanFailed(ScanId),
+    /// Neighbor of the current media decoded in the background (`None` if
+    /// it failed to load).
+    MediaPrefetched {
+        path: PathBuf,
+        modified: Option<SystemTime>,
+        options: LoadOptions,
+        media: Option<MediaData>,
+    },
 }
 
 /// Runtime flags passed in from the CLI or launcher to tweak startup behavior.
@@ -88,6 +253,8 @@ pub struct Flags {
     pub file_path: Option<String>,
     /// Optional directory containing Fluent `.ftl` files for custom builds.
     pub i18n_dir: Option<String>,
+    /// Reload the `.ftl` files when they change (`--watch-i18n`).
+    pub watch_i18n: bool,
     /// Optional data directory override (for state files).
     /// Takes precedence over `ICED_LENS_DATA_DIR` environment variable.
     pub data_dir: Option<String>,
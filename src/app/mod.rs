@@ -8,34 +8,60 @@
 //! it is easy to audit user-facing behavior.
 
 pub mod config;
+pub mod edit_journal;
+pub mod file_associations;
+pub mod file_dialog;
+#[cfg(feature = "gamepad")]
+mod gamepad;
 pub mod i18n;
+pub mod logging;
 mod message;
+mod open_with;
 pub mod paths;
 pub mod persisted_state;
 mod persistence;
+pub mod plugins;
+pub mod recipe_batch;
+pub mod resume_positions;
 mod screen;
+pub mod share_server;
 mod subscription;
 mod update;
+#[cfg(feature = "update-check")]
+mod update_check;
 mod view;
+mod viewer_window;
+pub mod volume_boosts;
+pub mod window_mode;
+pub mod workspace;
 
 pub use message::{Flags, Message};
 pub use screen::Screen;
 
 use crate::media::metadata::MediaMetadata;
 use crate::media::{self, MaxSkipAttempts, MediaData, MediaNavigator};
+use crate::ui::accessibility;
+use crate::ui::guides;
 use crate::ui::help;
-use crate::ui::image_editor::{self, State as ImageEditorState};
-use crate::ui::metadata_panel::MetadataEditorState;
+use crate::ui::image_editor::{self, AdjustmentRecipe, State as ImageEditorState};
+use crate::ui::keyboard_layout::KeyboardLayout;
+use crate::ui::metadata_panel::{
+    BatchEditState, MetadataEditorState, ShootStatsState, TagBrowserState,
+};
 use crate::ui::notifications;
-use crate::ui::settings::{State as SettingsState, StateConfig as SettingsConfig};
+use crate::ui::settings::{self, State as SettingsState, StateConfig as SettingsConfig};
 use crate::ui::state::zoom::{MAX_ZOOM_STEP_PERCENT, MIN_ZOOM_STEP_PERCENT};
 use crate::ui::theming::ThemeMode;
-use crate::ui::viewer::component;
-use crate::video_player::{create_lufs_cache, SharedLufsCache};
+use crate::ui::viewer::{component, fullscreen_overlay};
+use crate::ui::welcome;
+use crate::video_player::{create_lufs_cache, LufsCache, SharedLufsCache};
 use i18n::fluent::I18n;
 use iced::{window, Element, Subscription, Task, Theme};
 use std::fmt;
 
+/// File in the app data directory holding loudness measurements of videos.
+const LUFS_CACHE_FILE: &str = "lufs_cache.cbor";
+
 /// Root Iced application state that bridges UI components, localization, and
 /// persisted preferences.
 // Allow excessive bools: these represent orthogonal application states
@@ -53,6 +79,12 @@ pub struct App {
     window_id: Option<window::Id>,
     /// Current window size for drop zone calculations.
     window_size: Option<iced::Size>,
+    /// Key labels learned from key presses, shown in the help screen.
+    keyboard_layout: KeyboardLayout,
+    /// Whether the window is minimized (periodic ticks are paused).
+    window_minimized: bool,
+    /// Figures of the diagnostics overlay (F12), while it is shown.
+    diagnostics: Option<crate::diagnostics::Snapshot>,
     theme_mode: ThemeMode,
     /// Whether videos should auto-play when loaded.
     video_autoplay: bool,
@@ -64,6 +96,9 @@ pub struct App {
     frame_cache_mb: crate::video_player::FrameCacheMb,
     /// Frame history size in MB for backward frame stepping.
     frame_history_mb: crate::video_player::FrameHistoryMb,
+    /// Configuration as last loaded from or saved to disk; the base that
+    /// saves compare against to keep changes made by other instances.
+    config_base: config::Config,
     /// Whether the hamburger menu is open.
     menu_open: bool,
     /// Whether the info panel is open.
@@ -72,6 +107,24 @@ pub struct App {
     current_metadata: Option<MediaMetadata>,
     /// State for metadata editing mode.
     metadata_editor_state: Option<MetadataEditorState>,
+    /// State of the "All tags" browser in the metadata panel.
+    metadata_tag_browser: TagBrowserState,
+    /// State of the batch edit section in the metadata panel.
+    metadata_batch: BatchEditState,
+    /// State of the folder statistics section in the metadata panel.
+    shoot_stats: ShootStatsState,
+    /// True while a folder integrity check runs in the background.
+    verify_running: bool,
+    /// Gallery server of "Share over LAN", while the folder is shared.
+    share_server: Option<share_server::ShareServer>,
+    /// Address panel of "Share over LAN", while shown.
+    share_panel: Option<share_panel::State>,
+    /// Edit recipe copied in the editor, kept across sessions to paste onto other images.
+    edit_clipboard: Option<AdjustmentRecipe>,
+    /// Installed plugins and their hooks.
+    plugins: plugins::Registry,
+    /// External commands of the "Open with / Send to" menu.
+    open_with: Vec<config::OpenWithCommand>,
     /// Help screen state (tracks expanded sections).
     help_state: help::State,
     /// Persisted application state (last save directory, etc.).
@@ -82,6 +135,26 @@ pub struct App {
     shutting_down: bool,
     /// Cancellation token for background tasks (shared with async tasks).
     cancellation_token: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    /// Picture-in-picture window playing a video, if open.
+    mini_player: Option<crate::ui::pip::State>,
+    /// Number of mini players opened so far.
+    mini_player_sessions: u64,
+    /// Decoded neighbors of the current media, for instant navigation.
+    prefetch: media::prefetch::Cache,
+    /// Log lines shown by the log viewer screen.
+    logs: crate::ui::logs::State,
+    /// Page of the first-run welcome screen.
+    welcome: crate::ui::welcome::State,
+    /// Image and history revision of the editor last written to the edit journal.
+    journaled_edits: Option<(std::path::PathBuf, u64)>,
+    /// Number of edit journal changes so far, to order background writes.
+    journal_changes: u64,
+    /// Additional windows opened with "Open in new window".
+    viewer_windows: Vec<viewer_window::ViewerWindow>,
+    /// Number of viewer windows opened so far.
+    viewer_windows_opened: u64,
+    /// Whether the `.ftl` files are reloaded when they change (`--watch-i18n`).
+    watch_i18n: bool,
 }
 
 impl fmt::Debug for App {
@@ -113,6 +186,8 @@ pub fn window_settings_with_locale() -> window::Settings {
         size: iced::Size::new(WINDOW_DEFAULT_WIDTH, WINDOW_DEFAULT_HEIGHT),
         min_size: Some(iced::Size::new(MIN_WINDOW_WIDTH, MIN_WINDOW_HEIGHT)),
         icon,
+        // Closing the main window also closes the mini player, see `App::update`
+        exit_on_close_request: false,
         ..window::Settings::default()
     }
 }
@@ -137,14 +212,23 @@ pub fn run(flags: Flags) -> iced::Result {
             .borrow_mut()
             .take()
             .expect("Boot function called more than once");
-        App::new(flags)
+        let (mut app, task) = App::new(flags);
+        // The main window is opened here, as a daemon starts without windows
+        let (main_window, open) = window::open(
+            app.persisted
+                .window_mode
+                .window_settings(window_settings_with_locale()),
+        );
+        app.window_id = Some(main_window);
+        (app, Task::batch([open.discard(), task]))
     };
 
-    iced::application(boot, App::update, App::view)
-        .title(App::title)
-        .theme(App::theme)
+    // A daemon, as the mini player opens a second window
+    iced::daemon(boot, App::update, App::view_window)
+        .title(App::window_title)
+        .theme(App::window_theme)
+        .scale_factor(App::window_scale_factor)
         .font(iced_aw::ICED_AW_FONT_BYTES)
-        .window(window_settings_with_locale())
         .subscription(App::subscription)
         .run()
 }
@@ -161,21 +245,44 @@ impl Default for App {
             fullscreen: false,
             window_id: None,
             window_size: None,
+            keyboard_layout: KeyboardLayout::default(),
+            window_minimized: false,
+            diagnostics: None,
             theme_mode: ThemeMode::System,
             video_autoplay: false,
             audio_normalization: true, // Enabled by default - normalizes audio volume between media files
             lufs_cache: create_lufs_cache(),
             frame_cache_mb: crate::video_player::FrameCacheMb::default(),
             frame_history_mb: crate::video_player::FrameHistoryMb::default(),
+            config_base: config::Config::default(),
             menu_open: false,
             info_panel_open: false,
             current_metadata: None,
             metadata_editor_state: None,
+            metadata_tag_browser: TagBrowserState::default(),
+            metadata_batch: BatchEditState::default(),
+            shoot_stats: ShootStatsState::default(),
+            verify_running: false,
+            share_server: None,
+            share_panel: None,
+            edit_clipboard: None,
+            plugins: plugins::Registry::default(),
+            open_with: Vec::new(),
             help_state: help::State::new(),
             persisted: persisted_state::AppState::default(),
             notifications: notifications::Manager::new(),
             shutting_down: false,
             cancellation_token: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            mini_player: None,
+            mini_player_sessions: 0,
+            prefetch: media::prefetch::Cache::default(),
+            logs: crate::ui::logs::State::default(),
+            welcome: crate::ui::welcome::State::default(),
+            journaled_edits: None,
+            journal_changes: 0,
+            viewer_windows: Vec::new(),
+            viewer_windows_opened: 0,
+            watch_i18n: false,
         }
     }
 }
@@ -187,73 +294,38 @@ impl App {
     // Refactoring would risk breaking initialization order and add indirection.
     #[allow(clippy::too_many_lines)]
     fn new(flags: Flags) -> (Self, Task<Message>) {
+        // Checked before anything is saved: no settings yet means a first launch
+        let first_launch = !config::config_path().is_some_and(|path| path.exists());
         let (config, config_warning) = config::load();
 
         let i18n = I18n::new(flags.lang.clone(), flags.i18n_dir.clone(), &config);
 
         let mut app = App {
             i18n,
+            config_base: config.clone(),
+            watch_i18n: flags.watch_i18n,
             ..Self::default()
         };
+        app.viewer.apply_locale(&app.i18n);
 
-        app.theme_mode = config.general.theme_mode;
-
-        if let Some(step) = config.display.zoom_step {
-            let clamped = clamp_zoom_step(step);
-            app.viewer.set_zoom_step_percent(clamped);
-        }
-
-        match config.display.fit_to_window {
-            Some(true) | None => app.viewer.enable_fit_to_window(),
-            Some(false) => app.viewer.disable_fit_to_window(),
-        }
-
-        let theme = config.display.background_theme.unwrap_or_default();
-        let sort_order = config.display.sort_order.unwrap_or_default();
-        let overlay_timeout_secs = config
-            .fullscreen
-            .overlay_timeout_secs
-            .unwrap_or(config::DEFAULT_OVERLAY_TIMEOUT_SECS);
-        let video_autoplay = config.video.autoplay.unwrap_or(false);
-        let audio_normalization = config.video.audio_normalization.unwrap_or(true);
-        let keyboard_seek_step_secs = config
-            .video
-            .keyboard_seek_step_secs
-            .unwrap_or(config::DEFAULT_KEYBOARD_SEEK_STEP_SECS);
-        let frame_cache_mb = crate::video_player::FrameCacheMb::new(
-            config
-                .video
-                .frame_cache_mb
-                .unwrap_or(config::DEFAULT_FRAME_CACHE_MB),
-        );
-        let frame_history_mb = crate::video_player::FrameHistoryMb::new(
-            config
-                .video
-                .frame_history_mb
-                .unwrap_or(config::DEFAULT_FRAME_HISTORY_MB),
-        );
-        app.frame_cache_mb = frame_cache_mb;
-        app.frame_history_mb = frame_history_mb;
         // Load application state (last save directory, deblur enabled, etc.)
         let (app_state, state_warning) = persisted_state::AppState::load();
 
         // Read AI settings before moving app_state (enable flags come from persisted state)
         let enable_deblur = app_state.enable_deblur;
         let enable_upscale = app_state.enable_upscale;
+        let enable_background_removal = app_state.enable_background_removal;
 
         // Move app_state (no clone needed since we've already extracted the values we need)
         app.persisted = app_state;
-        let deblur_model_url = config
-            .ai
-            .deblur_model_url
-            .clone()
-            .unwrap_or_else(|| config::DEFAULT_DEBLUR_MODEL_URL.to_string());
-
-        let upscale_model_url = config
-            .ai
-            .upscale_model_url
-            .clone()
-            .unwrap_or_else(|| config::DEFAULT_UPSCALE_MODEL_URL.to_string());
+
+        // Keep loudness measurements across sessions
+        if let Some(dir) = paths::get_app_data_dir() {
+            app.lufs_cache = std::sync::Arc::new(LufsCache::load_from(dir.join(LUFS_CACHE_FILE)));
+        }
+
+        // Keep the copied edit recipe across sessions
+        app.edit_clipboard = edit_journal::read_recipe(None);
 
         // Check if the deblur model needs validation at startup
         // If enable_deblur is true and model exists, we need to validate it before making it available
@@ -276,58 +348,43 @@ impl App {
                 )
             };
 
-        let max_skip_attempts = config
-            .display
-            .max_skip_attempts
-            .unwrap_or(config::DEFAULT_MAX_SKIP_ATTEMPTS);
-        let persist_filters = config.display.persist_filters.unwrap_or(false);
-        app.settings = SettingsState::new(SettingsConfig {
-            zoom_step_percent: app.viewer.zoom_step_percent(),
-            background_theme: theme,
-            sort_order,
-            overlay_timeout_secs,
-            theme_mode: config.general.theme_mode,
-            video_autoplay,
-            audio_normalization,
-            frame_cache_mb: frame_cache_mb.value(),
-            frame_history_mb: frame_history_mb.value(),
-            keyboard_seek_step_secs,
-            max_skip_attempts,
-            enable_deblur,
-            deblur_model_url,
-            deblur_model_status,
-            enable_upscale,
-            upscale_model_url,
-            upscale_model_status,
-            persist_filters,
-        });
-        app.video_autoplay = video_autoplay;
-        app.audio_normalization = audio_normalization;
-        app.viewer.set_video_autoplay(video_autoplay);
-        app.viewer
-            .set_keyboard_seek_step(crate::video_player::KeyboardSeekStep::new(
-                keyboard_seek_step_secs,
-            ));
+        // Check if the background removal model needs validation at startup
+        let (background_model_status, needs_background_startup_validation) =
+            if enable_background_removal && media::background_removal::is_model_downloaded() {
+                (
+                    crate::media::background_removal::BackgroundModelStatus::Validating,
+                    true,
+                )
+            } else {
+                (
+                    crate::media::background_removal::BackgroundModelStatus::NotDownloaded,
+                    false,
+                )
+            };
 
-        // Apply video playback preferences from config
-        if let Some(volume) = config.video.volume {
-            app.viewer.set_video_volume(volume);
-        }
-        if let Some(muted) = config.video.muted {
-            app.viewer.set_video_muted(muted);
-        }
-        if let Some(loop_enabled) = config.video.loop_enabled {
-            app.viewer.set_video_loop(loop_enabled);
+        app.settings.set_enable_deblur(enable_deblur);
+        app.settings.set_deblur_model_status(deblur_model_status);
+        app.settings.set_enable_upscale(enable_upscale);
+        app.settings.set_upscale_model_status(upscale_model_status);
+        app.settings
+            .set_enable_background_removal(enable_background_removal);
+        app.settings
+            .set_background_model_status(background_model_status);
+        app.apply_config(&config);
+
+        match config.display.fit_to_window {
+            Some(true) | None => app.viewer.enable_fit_to_window(),
+            Some(false) => app.viewer.disable_fit_to_window(),
         }
+        let sort = config.display.media_sort();
 
-        // Apply display preferences from config
-        if let Some(max_skip) = config.display.max_skip_attempts {
-            app.viewer
-                .set_max_skip_attempts(MaxSkipAttempts::new(max_skip));
+        // Files opened from a file manager are shown right away instead
+        if first_launch && !app.persisted.welcome_done && flags.file_path.is_none() {
+            app.screen = Screen::Welcome;
         }
 
         // Restore persisted filter if enabled
-        if persist_filters {
+        if app.settings.persist_filters() {
             if let Some(filter) = config.display.filter {
                 app.media_navigator.set_filter(filter);
             }
@@ -343,13 +400,14 @@ impl App {
                 .push(notifications::Notification::warning(&key));
         }
 
+        let mut scan_task = None;
         let task = if let Some(path_str) = flags.file_path {
             let path = std::path::PathBuf::from(&path_str);
 
             // Determine if path is a directory or a file and resolve the media path
             let resolved_path = if path.is_dir() {
                 // Directory path: scan for media files and select the first one
-                match app.media_navigator.scan_from_directory(&path, sort_order) {
+                match app.media_navigator.scan_from_directory(&path, sort) {
                     Ok(Some(first_media)) => Some(first_media),
                     Ok(None) => {
                         // No media files found in directory - start without media
@@ -363,16 +421,11 @@ impl App {
                     }
                 }
             } else {
-                // File path: use existing behavior
-                if app
+                // File path: show it right away and scan its directory in the background
+                scan_task = app
                     .media_navigator
-                    .scan_directory(&path, sort_order)
-                    .is_err()
-                {
-                    app.notifications.push(notifications::Notification::warning(
-                        "notification-scan-dir-error",
-                    ));
-                }
+                    .begin_scan(&path)
+                    .map(|scan| update::scan_directory_in_background(scan, path.clone(), sort));
                 Some(path)
             };
 
@@ -389,9 +442,15 @@ impl App {
 
                 // Load the media
                 let path_string = media_path.to_string_lossy().into_owned();
-                Task::perform(async move { media::load_media(&path_string) }, |result| {
-                    Message::Viewer(component::Message::MediaLoaded(result))
-                })
+                let load_options = app.settings.load_options();
+                Task::batch([
+                    Task::perform(
+                        async move { media::load_media_with(&path_string, load_options) },
+                        |result| Message::Viewer(component::Message::MediaLoaded(result)),
+                    ),
+                    update::load_preview(media_path),
+                    scan_task.unwrap_or_else(Task::none),
+                ])
             } else {
                 Task::none()
             }
@@ -458,12 +517,338 @@ impl App {
             Task::none()
         };
 
+        // If background removal was enabled and model exists, start validation in background
+        let background_validation_task = if needs_background_startup_validation {
+            let cancel_token = app.cancellation_token.clone();
+            Task::perform(
+                async move {
+                    tokio::task::spawn_blocking(move || {
+                        let mut manager =
+                            media::background_removal::BackgroundRemovalManager::new();
+                        manager.load_session(Some(&cancel_token))?;
+                        media::background_removal::validate_model(
+                            &mut manager,
+                            Some(&cancel_token),
+                        )?;
+                        Ok::<(), media::background_removal::BackgroundRemovalError>(())
+                    })
+                    .await
+                    .map_err(|e| {
+                        media::background_removal::BackgroundRemovalError::InferenceFailed(
+                            e.to_string(),
+                        )
+                    })?
+                },
+                |result: media::background_removal::BackgroundRemovalResult<()>| match result {
+                    Ok(()) => Message::BackgroundValidationCompleted {
+                        result: Ok(()),
+                        is_startup: true,
+                    },
+                    Err(e) => Message::BackgroundValidationCompleted {
+                        result: Err(e.to_string()),
+                        is_startup: true,
+                    },
+                },
+            )
+        } else {
+            Task::none()
+        };
+
         // Combine tasks
-        let combined_task = Task::batch([task, deblur_validation_task, upscale_validation_task]);
+        let combined_task = Task::batch([
+            task,
+            deblur_validation_task,
+            upscale_validation_task,
+            background_validation_task,
+        ]);
 
         (app, combined_task)
     }
 
+    /// Applies the preferences of `config` to the running application, at
+    /// startup and when `settings.toml` is changed or imported.
+    ///
+    /// AI enable flags and model statuses are kept: they come from the
+    /// persisted state, not from the configuration.
+    // Allow too_many_lines: one assignment per preference, in config order.
+    #[allow(clippy::too_many_lines)]
+    fn apply_config(&mut self, config: &config::Config) {
+        self.theme_mode = config.general.theme_mode;
+
+        if let Some(step) = config.display.zoom_step {
+            self.viewer.set_zoom_step_percent(clamp_zoom_step(step));
+        }
+
+        let video_autoplay = config.video.autoplay.unwrap_or(false);
+        let audio_normalization = config.video.audio_normalization.unwrap_or(true);
+        let keyboard_seek_step_secs = config
+            .video
+            .keyboard_seek_step_secs
+            .unwrap_or(config::DEFAULT_KEYBOARD_SEEK_STEP_SECS);
+        let sequence_fps = crate::video_player::SequenceFps::new(
+            config
+                .video
+                .sequence_fps
+                .unwrap_or(config::DEFAULT_SEQUENCE_FPS),
+        );
+        let frame_cache_mb = crate::video_player::FrameCacheMb::new(
+            config
+                .video
+                .frame_cache_mb
+                .unwrap_or(config::DEFAULT_FRAME_CACHE_MB),
+        );
+        let frame_history_mb = crate::video_player::FrameHistoryMb::new(
+            config
+                .video
+                .frame_history_mb
+                .unwrap_or(config::DEFAULT_FRAME_HISTORY_MB),
+        );
+        self.frame_cache_mb = frame_cache_mb;
+        self.frame_history_mb = frame_history_mb;
+
+        self.settings = SettingsState::new(SettingsConfig {
+            zoom_step_percent: self.viewer.zoom_step_percent(),
+            background_theme: config.display.background_theme.unwrap_or_default(),
+            sort_order: config.display.sort_order.unwrap_or_default(),
+            sort_direction: config.display.sort_direction.unwrap_or_default(),
+            overlay_timeout_secs: config
+                .fullscreen
+                .overlay_timeout_secs
+                .unwrap_or(config::DEFAULT_OVERLAY_TIMEOUT_SECS),
+            fullscreen_overlay: fullscreen_overlay::Options::from_config(&config.fullscreen),
+            theme_mode: config.general.theme_mode,
+            accent_color: config
+                .general
+                .accent_color
+                .as_deref()
+                .and_then(crate::ui::theming::parse_hex),
+            custom_theme: config.general.custom_theme.unwrap_or(false),
+            custom_palette: persistence::load_custom_palette(),
+            ui_scale_percent: config
+                .general
+                .ui_scale_percent
+                .unwrap_or(config::DEFAULT_UI_SCALE_PERCENT),
+            accessibility: accessibility::Options::from_config(&config.accessibility),
+            video_autoplay,
+            audio_normalization,
+            hardware_decoding: config.video.hardware_decoding.unwrap_or(true),
+            resume_playback: config.video.resume_playback.unwrap_or_default(),
+            tone_mapping: config.video.tone_mapping.unwrap_or_default(),
+            audio_device: config.video.audio_device.clone(),
+            frame_cache_mb: frame_cache_mb.value(),
+            frame_history_mb: frame_history_mb.value(),
+            frame_cache_auto: config.video.frame_cache_auto.unwrap_or(true),
+            keyboard_seek_step_secs,
+            sequence_fps: sequence_fps.value(),
+            frame_name_template: config
+                .video
+                .frame_name_template
+                .clone()
+                .unwrap_or_else(|| config::DEFAULT_FRAME_NAME_TEMPLATE.to_string()),
+            save_policy: config.editor.save_policy.unwrap_or_default(),
+            edited_folder: config
+                .editor
+                .edited_folder
+                .clone()
+                .unwrap_or_else(|| config::DEFAULT_EDITED_FOLDER.to_string()),
+            guides: guides::Options::from_config(&config.guides),
+            check_updates_weekly: config.updates.check_weekly.unwrap_or(false),
+            max_skip_attempts: config
+                .display
+                .max_skip_attempts
+                .unwrap_or(config::DEFAULT_MAX_SKIP_ATTEMPTS),
+            prefetch_cache_size: config
+                .display
+                .prefetch_cache_size
+                .unwrap_or(config::DEFAULT_PREFETCH_CACHE_SIZE),
+            similarity_threshold: config
+                .display
+                .similarity_threshold
+                .unwrap_or(config::DEFAULT_SIMILARITY_THRESHOLD),
+            enable_deblur: self.settings.enable_deblur(),
+            deblur_model_url: config
+                .ai
+                .deblur_model_url
+                .clone()
+                .unwrap_or_else(|| config::DEFAULT_DEBLUR_MODEL_URL.to_string()),
+            deblur_model_status: self.settings.deblur_model_status().clone(),
+            enable_upscale: self.settings.enable_upscale(),
+            upscale_model_url: config
+                .ai
+                .upscale_model_url
+                .clone()
+                .unwrap_or_else(|| config::DEFAULT_UPSCALE_MODEL_URL.to_string()),
+            upscale_model_status: self.settings.upscale_model_status().clone(),
+            enable_background_removal: self.settings.enable_background_removal(),
+            background_model_url: config
+                .ai
+                .background_model_url
+                .clone()
+                .unwrap_or_else(|| config::DEFAULT_BACKGROUND_MODEL_URL.to_string()),
+            background_model_status: self.settings.background_model_status().clone(),
+            persist_filters: config.display.persist_filters.unwrap_or(false),
+            remember_view_per_image: config.display.remember_view_per_image.unwrap_or(false),
+            show_minimap: config.display.show_minimap.unwrap_or(true),
+            sandboxed_decoding: config.display.sandboxed_decoding.unwrap_or(false),
+            webp_decoder: config.display.webp_decoder.unwrap_or_default(),
+            mouse_mapping: component::MouseMapping {
+                navigation_buttons: config.input.navigation_buttons.unwrap_or(true),
+                middle_click: config.input.middle_click.unwrap_or_default(),
+                wheel: config.input.wheel.unwrap_or_default(),
+                drag_button: config.input.drag_button.unwrap_or_default(),
+            },
+            keymap: crate::ui::keymap::Keymap::from_config(&config.shortcuts),
+        });
+        accessibility::set(self.settings.accessibility());
+        crate::ui::state::zoom::set_interface_scale(self.settings.ui_scale_percent());
+        crate::video_player::audio_output::set_preferred_device(
+            self.settings.audio_device().map(str::to_string),
+        );
+        self.prefetch
+            .set_capacity(self.settings.prefetch_cache_size() as usize);
+        self.plugins = plugins::Registry::load(&config.plugins.disabled);
+        self.settings
+            .set_plugins(update::plugin_entries(&self.plugins));
+        self.open_with = config.open_with.clone();
+        self.video_autoplay = video_autoplay;
+        self.audio_normalization = audio_normalization;
+        self.viewer.set_video_autoplay(video_autoplay);
+        self.viewer
+            .set_remember_view(self.settings.remember_view_per_image());
+        self.viewer.set_show_minimap(self.settings.show_minimap());
+        self.viewer
+            .set_keyboard_seek_step(crate::video_player::KeyboardSeekStep::new(
+                keyboard_seek_step_secs,
+            ));
+        self.viewer.set_sequence_fps(sequence_fps);
+        self.viewer.set_mouse_mapping(self.settings.mouse_mapping());
+        self.viewer.set_tone_mapping(self.settings.tone_mapping());
+
+        // Apply video playback preferences from config
+        if let Some(volume) = config.video.volume {
+            self.viewer.set_video_volume(volume);
+        }
+        if let Some(muted) = config.video.muted {
+            self.viewer.set_video_muted(muted);
+        }
+        if let Some(loop_enabled) = config.video.loop_enabled {
+            self.viewer.set_video_loop(loop_enabled);
+        }
+        if let Some(size) = config.video.subtitle_size {
+            self.viewer.set_subtitle_size(size);
+        }
+        if let Some(background) = config.video.subtitle_background {
+            self.viewer.set_subtitle_background(background);
+        }
+        if let Some(mode) = config.video.audio_visualization {
+            self.viewer.set_audio_visualization(mode);
+        }
+
+        // Apply display preferences from config
+        if let Some(max_skip) = config.display.max_skip_attempts {
+            self.viewer
+                .set_max_skip_attempts(MaxSkipAttempts::new(max_skip));
+        }
+    }
+
+    /// Applies changes made to `settings.toml` outside the application.
+    ///
+    /// Files written by this instance equal `config_base` and are skipped.
+    /// An invalid file is reported and the running settings are kept until
+    /// it is fixed.
+    fn reload_config_file(&mut self) -> Task<Message> {
+        let Some(path) = config::config_path().filter(|path| path.exists()) else {
+            return Task::none();
+        };
+        match config::load_from_path(&path) {
+            Ok(config) if config == self.config_base => {}
+            Ok(config) => {
+                self.apply_loaded_config(config);
+                self.notifications.push(notifications::Notification::info(
+                    "notification-config-reloaded",
+                ));
+            }
+            Err(error) => {
+                tracing::warn!("Invalid settings file {}: {error}", path.display());
+                self.notifications.push(
+                    notifications::Notification::warning("notification-config-reload-error")
+                        .with_arg("error", error.to_string()),
+                );
+            }
+        }
+        Task::none()
+    }
+
+    /// Loads the edited `.ftl` files and redraws every screen with them.
+    fn reload_translations(&mut self) -> Task<Message> {
+        self.i18n.reload();
+        self.apply_locale_change();
+        self.notifications.push(
+            notifications::Notification::info("notification-translations-reloaded")
+                .with_arg("count", self.i18n.missing_translations().len().to_string()),
+        );
+        Task::none()
+    }
+
+    /// Replaces the settings with those of a file chosen by the user.
+    fn import_settings(&mut self, path: &std::path::Path) -> Task<Message> {
+        let config = match config::load_from_path(path) {
+            Ok(config) => config,
+            Err(error) => {
+                self.notifications.push(
+                    notifications::Notification::error("notification-settings-import-error")
+                        .with_arg("error", error.to_string()),
+                );
+                return Task::none();
+            }
+        };
+        // Saved first, so the file watcher finds nothing new to apply
+        if config::save(&config).is_err() {
+            self.notifications
+                .push(notifications::Notification::warning(
+                    "notification-config-save-error",
+                ));
+            return Task::none();
+        }
+        self.apply_loaded_config(config);
+        self.notifications
+            .push(notifications::Notification::success(
+                "notification-settings-imported",
+            ));
+        Task::none()
+    }
+
+    /// Writes the current settings to a file chosen by the user.
+    fn export_settings(&mut self, path: &std::path::Path) -> Task<Message> {
+        match config::export_to_path(&self.config_base, path) {
+            Ok(()) => self
+                .notifications
+                .push(notifications::Notification::success(
+                    "notification-settings-exported",
+                )),
+            Err(error) => self.notifications.push(
+                notifications::Notification::error("notification-settings-export-error")
+                    .with_arg("error", error.to_string()),
+            ),
+        }
+        Task::none()
+    }
+
+    /// Applies a configuration loaded while running, including its language,
+    /// and makes it the base of later saves.
+    fn apply_loaded_config(&mut self, config: config::Config) {
+        let locale: Option<unic_langid::LanguageIdentifier> = config
+            .general
+            .language
+            .as_deref()
+            .and_then(|language| language.parse().ok());
+        if let Some(locale) = locale.filter(|locale| locale != self.i18n.current_locale()) {
+            self.i18n.set_locale(locale);
+        }
+        self.apply_config(&config);
+        self.config_base = config;
+    }
+
     fn title(&self) -> String {
         let app_name = self.i18n.tr("window-title");
 
@@ -479,6 +864,26 @@ impl App {
         }
     }
 
+    /// Returns the title of `window`: the main window, a viewer window or
+    /// the mini player.
+    fn window_title(&self, window: window::Id) -> String {
+        if let Some(viewer_window) = self.viewer_window(window) {
+            return viewer_window.title(&self.i18n);
+        }
+        match &self.mini_player {
+            Some(mini_player) if mini_player.window() == window => {
+                format!("{} - {}", mini_player.title(), self.i18n.tr("window-title"))
+            }
+            _ => self.title(),
+        }
+    }
+
+    fn viewer_window(&self, window: window::Id) -> Option<&viewer_window::ViewerWindow> {
+        self.viewer_windows
+            .iter()
+            .find(|viewer_window| viewer_window.window() == window)
+    }
+
     /// Gets the display title for the current context.
     ///
     /// Priority order:
@@ -547,25 +952,48 @@ impl App {
     }
 
     fn theme(&self) -> Theme {
-        match self.theme_mode {
-            ThemeMode::Light => Theme::Light,
-            ThemeMode::Dark | ThemeMode::System => Theme::Dark,
-        }
+        self.settings.app_theme()
+    }
+
+    fn window_theme(&self, _window: window::Id) -> Theme {
+        self.theme()
+    }
+
+    /// Applies the interface scale to every window, on top of the system
+    /// scale factor.
+    ///
+    /// Media zoom divides it out again (see
+    /// [`crate::ui::state::zoom::media_scale`]), so only the interface grows.
+    fn window_scale_factor(&self, _window: window::Id) -> f32 {
+        self.settings.ui_scale_percent() as f32 / 100.0
     }
 
     fn subscription(&self) -> Subscription<Message> {
         let event_sub = subscription::create_event_subscription(self.screen);
-        let tick_sub = subscription::create_tick_subscription(
-            self.fullscreen,
-            self.viewer.is_loading_media(),
-            self.notifications.has_notifications(),
-        );
+        let overlay_hide_delay =
+            crate::ui::state::OverlayTimeout::new(self.settings.overlay_timeout_secs())
+                .as_duration();
+        let tick_sub = subscription::create_tick_subscription(subscription::TickNeeds {
+            window_minimized: self.window_minimized,
+            overlay_hide_pending: ((self.fullscreen || self.persisted.window_mode.compact)
+                && self.viewer.is_overlay_hide_pending(overlay_hide_delay))
+                || self
+                    .viewer_windows
+                    .iter()
+                    .any(|viewer_window| viewer_window.is_overlay_hide_pending(overlay_hide_delay)),
+            is_loading: self.viewer.is_loading_media(),
+            has_notifications: self.notifications.has_notifications(),
+            diagnostics_visible: self.diagnostics.is_some(),
+            clock_visible: self.fullscreen && self.settings.fullscreen_overlay().clock,
+        });
         let video_sub = subscription::create_video_subscription(
             &self.viewer,
             Some(self.lufs_cache.clone()),
             self.audio_normalization,
             self.frame_cache_mb.value(),
             self.settings.frame_history_mb(),
+            self.settings.frame_cache_auto(),
+            self.settings.hardware_decoding(),
         );
 
         // Editor subscription for spinner animation during deblur processing
@@ -576,13 +1004,85 @@ impl App {
                 editor.subscription().map(Message::ImageEditor)
             });
 
-        Subscription::batch([event_sub, tick_sub, video_sub, editor_sub])
+        let mini_player_sub = subscription::create_mini_player_subscription(
+            self.mini_player.as_ref(),
+            Some(self.lufs_cache.clone()),
+            self.audio_normalization,
+            self.frame_cache_mb.value(),
+            self.settings.frame_history_mb(),
+            self.settings.hardware_decoding(),
+        );
+
+        let viewer_window_subs = self.viewer_windows.iter().map(|viewer_window| {
+            viewer_window.subscription(Some(self.lufs_cache.clone()), &self.settings)
+        });
+
+        #[cfg(feature = "gamepad")]
+        let gamepad_sub = gamepad::subscription().map(Message::Gamepad);
+        #[cfg(not(feature = "gamepad"))]
+        let gamepad_sub = Subscription::none();
+
+        let config_watch_sub = subscription::create_config_watch_subscription();
+        let translations_watch_sub = if self.watch_i18n {
+            subscription::create_translations_watch_subscription(self.i18n.dir())
+        } else {
+            Subscription::none()
+        };
+
+        #[cfg(feature = "update-check")]
+        let update_check_sub = if self.settings.check_updates_weekly() {
+            update_check::subscription().map(|()| Message::UpdateCheckDue)
+        } else {
+            Subscription::none()
+        };
+        #[cfg(not(feature = "update-check"))]
+        let update_check_sub = Subscription::none();
+
+        Subscription::batch(
+            [
+                event_sub,
+                tick_sub,
+                config_watch_sub,
+                translations_watch_sub,
+                video_sub,
+                editor_sub,
+                mini_player_sub,
+                gamepad_sub,
+                update_check_sub,
+            ]
+            .into_iter()
+            .chain(viewer_window_subs),
+        )
     }
 
     // Allow too_many_lines: match dispatcher inherent to Elm architecture.
     // Length comes from number of message variants, not from complexity.
     #[allow(clippy::too_many_lines)]
     fn update(&mut self, message: Message) -> Task<Message> {
+        let Some(message) = self.apply_keymap(message) else {
+            return Task::none();
+        };
+
+        // Events of the viewer windows go to their own viewer
+        if let Some(message) = self.route_viewer_window_event(&message) {
+            return message.map_or_else(Task::none, |message| self.update(message));
+        }
+
+        // Events of the mini player window are handled by its own widgets
+        if let Some(mini_player) = &self.mini_player {
+            let window = match &message {
+                Message::Viewer(component::Message::RawEvent { window, .. })
+                | Message::ImageEditor(image_editor::Message::RawEvent { window, .. }) => {
+                    Some(*window)
+                }
+                Message::WindowFocusChanged(window) => Some(*window),
+                _ => None,
+            };
+            if window == Some(mini_player.window()) {
+                return Task::none();
+            }
+        }
+
         // Track window size from resize events before creating context
         // (must be done before borrowing self.window_size)
         if let Message::Viewer(component::Message::RawEvent {
@@ -592,7 +1092,30 @@ impl App {
         {
             self.window_size = Some(*size);
         }
+        // Learn the key labels of the current layout for the help screen
+        if let Message::Viewer(component::Message::RawEvent {
+            event:
+                iced::event::Event::Keyboard(iced::keyboard::Event::KeyPressed {
+                    key,
+                    physical_key,
+                    ..
+                }),
+            ..
+        })
+        | Message::ImageEditor(image_editor::Message::RawEvent {
+            event:
+                iced::event::Event::Keyboard(iced::keyboard::Event::KeyPressed {
+                    key,
+                    physical_key,
+                    ..
+                }),
+            ..
+        }) = &message
+        {
+            self.keyboard_layout.observe(key, *physical_key);
+        }
 
+        let previous_locale = self.i18n.current_locale().clone();
         let mut ctx = update::UpdateContext {
             i18n: &mut self.i18n,
             screen: &mut self.screen,
@@ -606,16 +1129,32 @@ impl App {
             theme_mode: &mut self.theme_mode,
             video_autoplay: &mut self.video_autoplay,
             audio_normalization: &mut self.audio_normalization,
+            lufs_cache: &self.lufs_cache,
             menu_open: &mut self.menu_open,
             info_panel_open: &mut self.info_panel_open,
             current_metadata: &mut self.current_metadata,
             metadata_editor_state: &mut self.metadata_editor_state,
+            metadata_tag_browser: &mut self.metadata_tag_browser,
+            metadata_batch: &mut self.metadata_batch,
+            shoot_stats: &mut self.shoot_stats,
+            verify_running: &mut self.verify_running,
+            share_server: &mut self.share_server,
+            share_panel: &mut self.share_panel,
+            plugins: &mut self.plugins,
+            open_with: &self.open_with,
+            edit_clipboard: &mut self.edit_clipboard,
             help_state: &mut self.help_state,
             persisted: &mut self.persisted,
+            config_base: &mut self.config_base,
             notifications: &mut self.notifications,
+            mini_player: &mut self.mini_player,
+            mini_player_sessions: &mut self.mini_player_sessions,
+            prefetch: &mut self.prefetch,
+            logs: &mut self.logs,
+            welcome: &mut self.welcome,
         };
 
-        match message {
+        let task = match message {
             Message::Viewer(viewer_message) => {
                 update::handle_viewer_message(&mut ctx, viewer_message)
             }
@@ -629,16 +1168,60 @@ impl App {
             Message::Navbar(navbar_message) => {
                 update::handle_navbar_message(&mut ctx, navbar_message)
             }
+            Message::SharePanel(panel_message) => {
+                update::handle_share_panel_message(&mut ctx, panel_message)
+            }
             Message::Help(help_message) => update::handle_help_message(&mut ctx, help_message),
             Message::About(about_message) => update::handle_about_message(&mut ctx, &about_message),
+            Message::Logs(logs_message) => update::handle_logs_message(&mut ctx, &logs_message),
+            Message::Translations(translations_message) => {
+                update::handle_translations_message(&mut ctx, &translations_message)
+            }
+            Message::Welcome(welcome_message) => {
+                update::handle_welcome_message(&mut ctx, welcome_message)
+            }
+            Message::DefaultViewerRegistered(result) => {
+                self.welcome.set_association_result(result);
+                Task::none()
+            }
+            Message::DiagnosticReportReady(report) => {
+                ctx.notifications.push(notifications::Notification::success(
+                    "notification-diagnostic-report-copied",
+                ));
+                iced::clipboard::write(report)
+            }
             Message::MetadataPanel(panel_message) => {
                 update::handle_metadata_panel_message(&mut ctx, panel_message)
             }
             Message::Notification(notification_message) => {
-                self.notifications.handle_message(&notification_message);
-                Task::none()
+                update::handle_notification_message(&mut ctx, &notification_message)
+            }
+            Message::ViewerWindow { window, message } => {
+                self.handle_viewer_window_message(window, message)
+            }
+            Message::OpenInNewWindowDialogResult(path) => {
+                path.map_or_else(Task::none, |path| self.open_viewer_window(path))
+            }
+            Message::MiniPlayer(mini_player_message) => {
+                update::handle_mini_player_message(&mut ctx, mini_player_message)
+            }
+            #[cfg(feature = "gamepad")]
+            Message::Gamepad(command) => self.handle_gamepad_command(command),
+            #[cfg(feature = "update-check")]
+            Message::UpdateCheckDue => update::handle_update_check_due(&mut ctx),
+            #[cfg(feature = "update-check")]
+            Message::UpdateCheckCompleted { result, manual } => {
+                update::handle_update_check_completed(&mut ctx, result, manual)
             }
             Message::ImageEditorLoaded(result) => self.handle_image_editor_loaded(result),
+            Message::ConfigFileChanged => self.reload_config_file(),
+            Message::TranslationsChanged => self.reload_translations(),
+            Message::SettingsImportDialogResult(path) => {
+                path.map_or_else(Task::none, |path| self.import_settings(&path))
+            }
+            Message::SettingsExportDialogResult(path) => {
+                path.map_or_else(Task::none, |path| self.export_settings(&path))
+            }
             Message::Tick(_instant) => {
                 // Periodic tick for overlay auto-hide - just trigger a view refresh
                 // The view() function will check elapsed time and hide controls if needed
@@ -653,8 +1236,33 @@ impl App {
                 // Tick notification manager to handle auto-dismiss
                 self.notifications.tick();
 
+                if self.diagnostics.is_some() {
+                    self.diagnostics = Some(crate::diagnostics::snapshot());
+                }
+
+                Task::none()
+            }
+            Message::ToggleDiagnostics => {
+                self.diagnostics = match self.diagnostics {
+                    Some(_) => None,
+                    None => Some(crate::diagnostics::snapshot()),
+                };
+                Task::none()
+            }
+            Message::WindowFocusChanged(id) => {
+                window::is_minimized(id).map(Message::WindowMinimized)
+            }
+            Message::WindowMinimized(minimized) => {
+                self.window_minimized = minimized.unwrap_or(false);
                 Task::none()
             }
+            Message::MoveFocus { backwards } => {
+                if backwards {
+                    iced::widget::operation::focus_previous()
+                } else {
+                    iced::widget::operation::focus_next()
+                }
+            }
             Message::SaveAsDialogResult(path_opt) => {
                 if let Some(path) = path_opt {
                     // User selected a path, save the image there
@@ -718,6 +1326,33 @@ impl App {
                 }
                 Task::none()
             }
+            Message::VisibleAreaDialogResult { path, frame } => {
+                if let Some(path) = path {
+                    self.save_exported_frame(
+                        &path,
+                        &frame,
+                        "notification-visible-area-exported",
+                        "notification-visible-area-export-error",
+                    );
+                }
+                Task::none()
+            }
+            Message::ViewScreenshotTaken {
+                screenshot,
+                bounds,
+                destination,
+            } => update::handle_view_screenshot(&mut ctx, &screenshot, bounds, destination),
+            Message::ViewExportDialogResult { path, frame } => {
+                if let Some(path) = path {
+                    self.save_exported_frame(
+                        &path,
+                        &frame,
+                        "notification-view-exported",
+                        "notification-view-export-error",
+                    );
+                }
+                Task::none()
+            }
             Message::OpenImageEditorWithFrame {
                 frame,
                 video_path,
@@ -736,13 +1371,61 @@ impl App {
                 }
                 Task::none()
             }
-            Message::OpenFileDialog => {
-                update::handle_open_file_dialog(self.persisted.last_open_directory.clone())
+            Message::OpenImageEditorWithImage {
+                image,
+                video_path,
+                position_secs,
+            } => {
+                match ImageEditorState::from_captured_image(image, video_path, position_secs) {
+                    Ok(state) => {
+                        self.image_editor = Some(state);
+                        self.screen = Screen::ImageEditor;
+                    }
+                    Err(_) => {
+                        self.notifications.push(notifications::Notification::error(
+                            "notification-editor-frame-error",
+                        ));
+                    }
+                }
+                Task::none()
             }
+            Message::OpenFileDialog => update::handle_open_file_dialog(&self.i18n, &self.persisted),
             Message::OpenFileDialogResult(path) => {
                 update::handle_open_file_dialog_result(&mut ctx, path)
             }
             Message::FileDropped(path) => update::handle_file_dropped(&mut ctx, path),
+            Message::CaptureDaysIndexed { index, direction } => {
+                ctx.media_navigator.set_capture_days(index);
+                update::handle_navigate_capture_day(&mut ctx, direction)
+            }
+            Message::SimilarityIndexed { index, direction } => {
+                ctx.media_navigator.set_similarity(index);
+                update::handle_find_similar(&mut ctx, direction)
+            }
+            Message::DirectoryScanned {
+                scan,
+                files,
+                complete,
+            } => {
+                ctx.media_navigator.apply_scan(&scan, files, complete);
+                Task::none()
+            }
+            Message::DirectoryScanFailed(scan) => {
+                ctx.media_navigator.abort_scan(&scan);
+                ctx.notifications.push(notifications::Notification::warning(
+                    "notification-scan-dir-error",
+                ));
+                Task::none()
+            }
+            Message::MediaPrefetched {
+                path,
+                modified,
+                options,
+                media,
+            } => {
+                update::handle_media_prefetched(&mut ctx, path, modified, options, media);
+                Task::none()
+            }
             Message::MetadataSaveAsDialogResult(path_opt) => {
                 if let Some(path) = path_opt {
                     self.handle_metadata_save_as(&path)
@@ -750,6 +1433,36 @@ impl App {
                     Task::none()
                 }
             }
+            Message::MetadataBatchCompleted(summary) => {
+                update::handle_metadata_batch_completed(&mut ctx, &summary)
+            }
+            Message::RecipeBatchDialogResult(paths) => match paths {
+                Some(paths) => update::handle_recipe_batch_confirmed(&mut ctx, paths),
+                None => Task::none(),
+            },
+            Message::RecipeBatchCompleted(summary) => {
+                update::handle_recipe_batch_completed(&mut ctx, &summary)
+            }
+            Message::StripMetadataDialogResult { path, destination } => match destination {
+                Some(destination) => {
+                    update::handle_strip_metadata_confirmed(&mut ctx, &path, destination)
+                }
+                None => Task::none(),
+            },
+            Message::MetadataExported(result) => update::handle_metadata_exported(&mut ctx, result),
+            Message::PluginToolCompleted(result) => {
+                update::handle_plugin_tool_completed(&mut ctx, result)
+            }
+            Message::PluginExportCompleted(result) => {
+                update::handle_plugin_export_completed(&mut ctx, result)
+            }
+            Message::OpenWithCompleted { name, result } => {
+                update::handle_open_with_completed(&mut ctx, name, result)
+            }
+            Message::ShootStatsComputed { directory, stats } => {
+                update::handle_shoot_stats_computed(&mut ctx, directory, stats)
+            }
+            Message::VerifyCompleted(report) => update::handle_verify_completed(&mut ctx, &report),
             Message::DeblurDownloadProgress(progress) => {
                 self.settings
                     .set_deblur_model_status(media::deblur::ModelStatus::Downloading { progress });
@@ -762,6 +1475,7 @@ impl App {
                 self.handle_deblur_validation_completed(result, is_startup)
             }
             Message::DeblurApplyCompleted(result) => self.handle_deblur_apply_completed(result),
+            Message::EditorFacesDetected(result) => self.handle_editor_faces_detected(result),
             Message::UpscaleDownloadProgress(progress) => {
                 self.settings.set_upscale_model_status(
                     media::upscale::UpscaleModelStatus::Downloading { progress },
@@ -775,16 +1489,276 @@ impl App {
                 self.handle_upscale_validation_completed(result, is_startup)
             }
             Message::UpscaleResizeCompleted(result) => self.handle_upscale_resize_completed(result),
+            Message::BackgroundDownloadProgress(progress) => {
+                self.settings.set_background_model_status(
+                    media::background_removal::BackgroundModelStatus::Downloading { progress },
+                );
+                Task::none()
+            }
+            Message::BackgroundDownloadCompleted(result) => {
+                self.handle_background_download_completed(result)
+            }
+            Message::BackgroundValidationCompleted { result, is_startup } => {
+                self.handle_background_validation_completed(result, is_startup)
+            }
+            Message::BackgroundRemovalCompleted(result) => {
+                self.handle_background_removal_completed(result)
+            }
+            Message::DenoiseCompleted(result) => update::handle_denoise_completed(&mut ctx, result),
             Message::WindowCloseRequested(id) => {
+                if let Some(index) = self
+                    .viewer_windows
+                    .iter()
+                    .position(|viewer_window| viewer_window.window() == id)
+                {
+                    // Dropping the viewer ends its video subscription
+                    self.viewer_windows.remove(index);
+                    return window::close(id);
+                }
+                if ctx
+                    .mini_player
+                    .as_ref()
+                    .is_some_and(|mini_player| mini_player.window() == id)
+                {
+                    return update::close_mini_player(&mut ctx);
+                }
+                update::remember_video_position(&mut ctx);
+                let close_mini_player = update::close_mini_player(&mut ctx);
                 // Mark app as shutting down to cancel background tasks
                 self.shutting_down = true;
                 // Signal cancellation to background tasks
                 self.cancellation_token
                     .store(true, std::sync::atomic::Ordering::SeqCst);
-                // Close the window
-                window::close(id)
+                // Close the window and exit, as the daemon outlives its windows
+                Task::batch([close_mini_player, window::close(id).chain(iced::exit())])
             }
+        };
+        if self.i18n.current_locale() != &previous_locale {
+            self.apply_locale_change();
         }
+        Task::batch([task, self.sync_edit_journal()])
+    }
+
+    /// Refreshes everything that keeps text or layout of the previous
+    /// language, whichever message changed it (settings, welcome screen or
+    /// an edited `settings.toml`).
+    ///
+    /// Screens translating on every render need nothing; notifications
+    /// resolve their message and translated arguments when drawn.
+    fn apply_locale_change(&mut self) {
+        self.viewer.apply_locale(&self.i18n);
+        for viewer_window in &mut self.viewer_windows {
+            viewer_window.apply_locale(&self.i18n);
+        }
+    }
+
+    /// Writes the editor's applied history to the edit journal when it
+    /// changed, or deletes the journal once nothing is left to recover.
+    ///
+    /// Pending slider values are journaled along with the next history
+    /// change. Nothing is written while recovered edits are being offered.
+    fn sync_edit_journal(&mut self) -> Task<Message> {
+        let Some(editor) = self.image_editor.as_ref() else {
+            return Task::none();
+        };
+        let Some(path) = editor.image_path() else {
+            return Task::none();
+        };
+        if editor.has_recovery_offer() {
+            return Task::none();
+        }
+        let journaled = (path.to_path_buf(), editor.history_revision());
+        if self.journaled_edits.as_ref() == Some(&journaled) {
+            return Task::none();
+        }
+
+        self.journal_changes += 1;
+        let change = self.journal_changes;
+        let source = journaled.0.clone();
+        let transformations = editor.applied_transformations().to_vec();
+        let pending = editor.pending_adjustment();
+        self.journaled_edits = Some(journaled);
+
+        Task::future(async move {
+            let result = tokio::task::spawn_blocking(move || {
+                if transformations.is_empty() {
+                    edit_journal::remove(&source, change, None)
+                } else {
+                    edit_journal::write(&source, &transformations, pending, change, None)
+                }
+            })
+            .await;
+            if let Ok(Err(err)) = result {
+                tracing::warn!("Failed to update the edit journal: {err}");
+            }
+        })
+        .discard()
+    }
+
+    /// Saves an exported frame to `path` and reports the outcome with the
+    /// given notification keys.
+    fn save_exported_frame(
+        &mut self,
+        path: &std::path::Path,
+        frame: &crate::media::frame_export::ExportableFrame,
+        success_key: &str,
+        error_key: &str,
+    ) {
+        let format = crate::media::frame_export::ExportFormat::from_path(path);
+        match frame.save_to_file(path, format) {
+            Ok(()) => {
+                self.notifications.push(
+                    notifications::Notification::success(success_key)
+                        .with_arg("path", path.display().to_string()),
+                );
+
+                // Remember the save directory for next time
+                self.persisted.set_last_save_directory_from_file(path);
+                if let Some(key) = self.persisted.save() {
+                    self.notifications
+                        .push(notifications::Notification::warning(&key));
+                }
+
+                // Rescan directory if saved in the same folder as current media
+                persistence::rescan_directory_if_same(&mut self.media_navigator, path);
+            }
+            Err(_err) => {
+                self.notifications
+                    .push(notifications::Notification::error(error_key));
+            }
+        }
+    }
+
+    /// Runs a controller command in the main viewer.
+    #[cfg(feature = "gamepad")]
+    fn handle_gamepad_command(&mut self, command: gamepad::Command) -> Task<Message> {
+        if self.screen != Screen::Viewer {
+            return Task::none();
+        }
+        self.update(Message::Viewer(command.viewer_message()))
+    }
+
+    /// Runs key presses through the keymap.
+    ///
+    /// While the settings screen waits for a new shortcut, the next key press
+    /// sets it, and the welcome screen's tour takes all key presses. Otherwise
+    /// the user's bindings are translated into the default keys the viewer
+    /// and editor handlers match. Returns `None` for presses that must be
+    /// dropped.
+    fn apply_keymap(&self, message: Message) -> Option<Message> {
+        use crate::ui::keymap::{Binding, Scope};
+        use iced::event::Event;
+
+        match message {
+            Message::Viewer(component::Message::RawEvent {
+                window,
+                event: Event::Keyboard(event),
+            }) => {
+                let capture: Option<fn(Binding) -> Message> = match self.screen {
+                    Screen::Settings if self.settings.is_capturing_shortcut() => Some(|binding| {
+                        Message::Settings(settings::Message::ShortcutKeyPressed(binding))
+                    }),
+                    Screen::Welcome => {
+                        Some(|binding| Message::Welcome(welcome::Message::KeyPressed(binding)))
+                    }
+                    _ => None,
+                };
+                if let Some(capture) = capture {
+                    if let iced::keyboard::Event::KeyPressed {
+                        key,
+                        modified_key,
+                        physical_key,
+                        modifiers,
+                        ..
+                    } = &event
+                    {
+                        // Modifiers alone wait for the rest of the combination
+                        return Binding::from_key_press(
+                            key,
+                            modified_key,
+                            *physical_key,
+                            *modifiers,
+                        )
+                        .map(capture);
+                    }
+                }
+                let event = self.settings.keymap().translate(Scope::Viewer, &event)?;
+                Some(Message::Viewer(component::Message::RawEvent {
+                    window,
+                    event: Event::Keyboard(event),
+                }))
+            }
+            Message::ImageEditor(image_editor::Message::RawEvent {
+                window,
+                event: Event::Keyboard(event),
+            }) => {
+                let event = self.settings.keymap().translate(Scope::Editor, &event)?;
+                Some(Message::ImageEditor(image_editor::Message::RawEvent {
+                    window,
+                    event: Event::Keyboard(event),
+                }))
+            }
+            message => Some(message),
+        }
+    }
+
+    /// Returns where an event of a viewer window goes, or `None` when it
+    /// belongs to the main window. `Some(None)` drops the event.
+    fn route_viewer_window_event(&self, message: &Message) -> Option<Option<Message>> {
+        let (window, event) = match message {
+            Message::Viewer(component::Message::RawEvent { window, event })
+            | Message::ImageEditor(image_editor::Message::RawEvent { window, event }) => {
+                (*window, Some(event))
+            }
+            // Only the main window pauses ticks when minimized
+            Message::WindowFocusChanged(window) => (*window, None),
+            _ => return None,
+        };
+        self.viewer_window(window)?;
+        Some(event.map(|event| Message::ViewerWindow {
+            window,
+            message: component::Message::RawEvent {
+                window,
+                event: event.clone(),
+            },
+        }))
+    }
+
+    /// Opens `path` in a new viewer window.
+    fn open_viewer_window(&mut self, path: std::path::PathBuf) -> Task<Message> {
+        let (window, open) = window::open(window_settings_with_locale());
+        self.viewer_windows_opened += 1;
+        let mut viewer_window = viewer_window::ViewerWindow::new(
+            window,
+            self.viewer_windows_opened,
+            &self.settings,
+            &self.viewer,
+        );
+        let load = viewer_window.open(path, &self.settings);
+        self.viewer_windows.push(viewer_window);
+        Task::batch([open.discard(), load])
+    }
+
+    fn handle_viewer_window_message(
+        &mut self,
+        window: window::Id,
+        message: component::Message,
+    ) -> Task<Message> {
+        let Some(viewer_window) = self
+            .viewer_windows
+            .iter_mut()
+            .find(|viewer_window| viewer_window.window() == window)
+        else {
+            return Task::none();
+        };
+        viewer_window.update(
+            message,
+            viewer_window::Env {
+                i18n: &self.i18n,
+                settings: &self.settings,
+                notifications: &mut self.notifications,
+            },
+        )
     }
 
     /// Handles the result of applying AI deblur to an image.
@@ -818,6 +1792,49 @@ impl App {
         Task::none()
     }
 
+    /// Hands the faces detected for the crop tool to the editor.
+    fn handle_editor_faces_detected(
+        &mut self,
+        result: Result<Vec<media::face_detection::FaceBox>, String>,
+    ) -> Task<Message> {
+        if let Err(error) = &result {
+            self.notifications.push(
+                notifications::Notification::error("notification-face-detection-error")
+                    .with_arg("error", error.clone()),
+            );
+        }
+        if let Some(editor) = self.image_editor.as_mut() {
+            editor.apply_face_detection_result(result);
+        }
+        Task::none()
+    }
+
+    /// Hands the foreground mask computed for the image to the editor, which
+    /// shows the cut-out for refining.
+    fn handle_background_removal_completed(
+        &mut self,
+        result: Result<Box<image_rs::GrayImage>, String>,
+    ) -> Task<Message> {
+        // Ignore results if shutting down
+        if self.shutting_down {
+            return Task::none();
+        }
+
+        if let Some(editor) = self.image_editor.as_mut() {
+            match result {
+                Ok(mask) => editor.apply_background_mask(*mask),
+                Err(e) => {
+                    editor.background_removal_failed();
+                    self.notifications.push(
+                        notifications::Notification::error("notification-background-removal-error")
+                            .with_arg("error", e),
+                    );
+                }
+            }
+        }
+        Task::none()
+    }
+
     /// Handles the result of applying AI upscale resize to an image.
     fn handle_upscale_resize_completed(
         &mut self,
@@ -1115,6 +2132,123 @@ impl App {
         Task::none()
     }
 
+    /// Handles the result of background removal model download.
+    fn handle_background_download_completed(
+        &mut self,
+        result: Result<(), String>,
+    ) -> Task<Message> {
+        // Don't start validation if shutting down
+        if self.shutting_down {
+            return Task::none();
+        }
+
+        match result {
+            Ok(()) => {
+                // Download succeeded - start validation
+                self.settings.set_background_model_status(
+                    media::background_removal::BackgroundModelStatus::Validating,
+                );
+
+                // Start validation task using spawn_blocking for CPU-intensive ONNX inference
+                let cancel_token = self.cancellation_token.clone();
+                Task::perform(
+                    async move {
+                        tokio::task::spawn_blocking(move || {
+                            let mut manager =
+                                media::background_removal::BackgroundRemovalManager::new();
+                            manager.load_session(Some(&cancel_token))?;
+                            media::background_removal::validate_model(
+                                &mut manager,
+                                Some(&cancel_token),
+                            )?;
+                            Ok::<(), media::background_removal::BackgroundRemovalError>(())
+                        })
+                        .await
+                        .map_err(|e| {
+                            media::background_removal::BackgroundRemovalError::InferenceFailed(
+                                e.to_string(),
+                            )
+                        })?
+                    },
+                    |result: media::background_removal::BackgroundRemovalResult<()>| match result {
+                        Ok(()) => Message::BackgroundValidationCompleted {
+                            result: Ok(()),
+                            is_startup: false,
+                        },
+                        Err(e) => Message::BackgroundValidationCompleted {
+                            result: Err(e.to_string()),
+                            is_startup: false,
+                        },
+                    },
+                )
+            }
+            Err(e) => {
+                // Download failed
+                self.settings.set_background_model_status(
+                    media::background_removal::BackgroundModelStatus::Error(e.clone()),
+                );
+                self.notifications.push(
+                    notifications::Notification::error("notification-background-download-error")
+                        .with_arg("error", e),
+                );
+                Task::none()
+            }
+        }
+    }
+
+    /// Handles the result of background removal model validation.
+    fn handle_background_validation_completed(
+        &mut self,
+        result: Result<(), String>,
+        is_startup: bool,
+    ) -> Task<Message> {
+        // Ignore validation results if the app is shutting down
+        if self.shutting_down {
+            return Task::none();
+        }
+
+        match result {
+            Ok(()) => {
+                // Validation succeeded - enable background removal and persist state
+                self.settings.set_background_model_status(
+                    media::background_removal::BackgroundModelStatus::Ready,
+                );
+                self.settings.set_enable_background_removal(true);
+                self.persisted.enable_background_removal = true;
+                if let Some(key) = self.persisted.save() {
+                    self.notifications
+                        .push(notifications::Notification::warning(&key));
+                }
+                // Only show success notification for user-initiated activation, not startup
+                if !is_startup {
+                    self.notifications
+                        .push(notifications::Notification::success(
+                            "notification-background-ready",
+                        ));
+                }
+            }
+            Err(e) => {
+                // Validation failed - reset enable_background_removal, delete the model and show error
+                self.settings.set_background_model_status(
+                    media::background_removal::BackgroundModelStatus::Error(e.clone()),
+                );
+                self.settings.set_enable_background_removal(false);
+                self.persisted.enable_background_removal = false;
+                if let Some(key) = self.persisted.save() {
+                    self.notifications
+                        .push(notifications::Notification::warning(&key));
+                }
+                // Delete the invalid model file
+                let _ = std::fs::remove_file(media::background_removal::get_model_path());
+                self.notifications.push(
+                    notifications::Notification::error("notification-background-validation-error")
+                        .with_arg("error", e),
+                );
+            }
+        }
+        Task::none()
+    }
+
     /// Handles async image loading result for the editor.
     // Allow too_many_lines: sequential async result handling with navigation logic.
     // Marginal benefit from extraction (111 lines vs 100 limit).
@@ -1158,7 +2292,8 @@ impl App {
 
             // Create a new ImageEditorState with the loaded image
             match image_editor::State::new(path, &image_data) {
-                Ok(new_editor_state) => {
+                Ok(mut new_editor_state) => {
+                    update::offer_journaled_edits(&mut new_editor_state);
                     self.image_editor = Some(new_editor_state);
                 }
                 Err(_) => {
@@ -1217,8 +2352,9 @@ impl App {
                                 skipped_files,
                             });
 
+                            let load_options = self.settings.load_options();
                             Task::perform(
-                                async move { media::load_media(&path) },
+                                async move { media::load_media_with(&path, load_options) },
                                 Message::ImageEditorLoaded,
                             )
                         } else {
@@ -1251,8 +2387,7 @@ impl App {
                 LoadOrigin::DirectOpen => {
                     // This case should not happen in the editor since all loads
                     // come from navigation. Kept as defensive fallback.
-                    #[cfg(debug_assertions)]
-                    eprintln!("[WARN] Unexpected DirectOpen in image editor error handler");
+                    tracing::warn!("Unexpected DirectOpen in image editor error handler");
                     self.notifications.push(notifications::Notification::error(
                         "notification-load-error",
                     ));
@@ -1262,8 +2397,25 @@ impl App {
         }
     }
 
+    /// Renders `window`: the main window, a viewer window or the mini player.
+    fn view_window(&self, window: window::Id) -> Element<'_, Message> {
+        if let Some(viewer_window) = self.viewer_window(window) {
+            return viewer_window.view(&self.i18n, &self.settings);
+        }
+        match &self.mini_player {
+            Some(mini_player) if mini_player.window() == window => {
+                mini_player.view(&self.i18n).map(Message::MiniPlayer)
+            }
+            _ => self.view(),
+        }
+    }
+
     fn view(&self) -> Element<'_, Message> {
-        let is_dark_theme = self.theme_mode.is_dark();
+        let is_dark_theme = if self.settings.active_custom_palette().is_some() {
+            !crate::ui::theming::is_light(&self.theme())
+        } else {
+            self.theme_mode.is_dark()
+        };
         let is_image = matches!(
             self.current_metadata,
             Some(crate::media::metadata::MediaMetadata::Image(_))
@@ -1276,12 +2428,16 @@ impl App {
             viewer: &self.viewer,
             image_editor: self.image_editor.as_ref(),
             help_state: &self.help_state,
+            keyboard_layout: &self.keyboard_layout,
             fullscreen: self.fullscreen,
             menu_open: self.menu_open,
             info_panel_open: self.info_panel_open,
             navigation: self.media_navigator.navigation_info(),
             current_metadata: self.current_metadata.as_ref(),
             metadata_editor_state: self.metadata_editor_state.as_ref(),
+            metadata_tag_browser: &self.metadata_tag_browser,
+            metadata_batch: &self.metadata_batch,
+            shoot_stats: &self.shoot_stats,
             current_media_path: self.media_navigator.current_media_path(),
             is_image,
             notifications: &self.notifications,
@@ -1289,9 +2445,21 @@ impl App {
             deblur_model_status: self.settings.deblur_model_status(),
             upscale_model_status: self.settings.upscale_model_status(),
             enable_upscale: self.persisted.enable_upscale,
+            background_model_status: self.settings.background_model_status(),
+            denoise_model_available: self.config_base.ai.denoise_model_url.is_some(),
+            can_paste_adjustments: self.edit_clipboard.is_some(),
+            plugin_tools: self.plugins.tool_labels(),
+            plugin_exports: self.plugins.export_labels(),
             filter: self.media_navigator.filter(),
             total_count: self.media_navigator.navigation_info().total_count,
             filtered_count: self.media_navigator.navigation_info().filtered_count,
+            sharing: self.share_server.is_some(),
+            open_with: &self.open_with,
+            window_mode: self.persisted.window_mode,
+            share_panel: self.share_panel.as_ref(),
+            logs: &self.logs,
+            welcome: &self.welcome,
+            diagnostics: self.diagnostics.as_ref(),
         })
     }
 }
@@ -1407,6 +2575,36 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "gamepad")]
+    #[test]
+    fn gamepad_fullscreen_command_toggles_the_viewer_window() {
+        let mut app = App {
+            window_id: Some(window::Id::unique()),
+            ..App::default()
+        };
+
+        let _ = app.handle_gamepad_command(gamepad::Command::ToggleFullscreen);
+        assert!(app.fullscreen);
+
+        let _ = app.handle_gamepad_command(gamepad::Command::ToggleFullscreen);
+        assert!(!app.fullscreen);
+    }
+
+    #[cfg(feature = "gamepad")]
+    #[test]
+    fn gamepad_commands_are_ignored_outside_the_viewer() {
+        let mut app = App {
+            screen: Screen::Settings,
+            window_id: Some(window::Id::unique()),
+            ..App::default()
+        };
+
+        let _ = app.handle_gamepad_command(gamepad::Command::ToggleFullscreen);
+
+        assert_eq!(app.screen, Screen::Settings);
+        assert!(!app.fullscreen);
+    }
+
     #[test]
     fn zoom_step_changes_commit_when_leaving_settings() {
         with_temp_config_dir(|_| {
@@ -1735,6 +2933,7 @@ mod tests {
             let settings_state = SettingsState::default();
             let mut notifs = notifications::Manager::new();
             let nav = MediaNavigator::default();
+            let mut config_base = config::Config::default();
             let mut ctx = persistence::PreferencesContext {
                 viewer: &viewer,
                 settings: &settings_state,
@@ -1746,6 +2945,7 @@ mod tests {
                 keyboard_seek_step_secs: config::DEFAULT_KEYBOARD_SEEK_STEP_SECS,
                 notifications: &mut notifs,
                 media_navigator: &nav,
+                config_base: &mut config_base,
             };
             let _ = persistence::persist_preferences(&mut ctx);
             // Test passes if we reach here without panicking
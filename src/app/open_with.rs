@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: MPL-2.0
+//! "Open with / Send to" commands: external programs run on the current
+//! file, such as an image editor or an exiftool script.
+//!
+//! Commands are defined in `settings.toml` (see
+//! [`crate::config::OpenWithCommand`]) as a program and its arguments, in
+//! which these placeholders are replaced:
+//!
+//! - `{path}` - full path of the file
+//! - `{dir}` - directory of the file
+//! - `{name}` - file name with its extension
+//! - `{stem}` - file name without its extension
+//!
+//! Commands run in the directory of the file, off the UI thread. What they
+//! print is shown in a notification once they exit.
+
+use crate::config::OpenWithCommand;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Longest output shown in the notification, in characters.
+const MAX_OUTPUT_CHARS: usize = 300;
+
+/// Returns the program and arguments of `command` for the file `path`.
+#[must_use]
+pub fn expand(command: &OpenWithCommand, path: &Path) -> Vec<String> {
+    let text = |part: Option<&std::ffi::OsStr>| {
+        part.map(|part| part.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    };
+    let vars = [
+        ("path", path.to_string_lossy().into_owned()),
+        ("dir", text(path.parent().map(Path::as_os_str))),
+        ("name", text(path.file_name())),
+        ("stem", text(path.file_stem())),
+    ];
+    command
+        .command
+        .iter()
+        .map(|arg| substitute(arg, &vars))
+        .collect()
+}
+
+/// Replaces the placeholders of `arg` in a single pass, so braces in the
+/// substituted values are never expanded again.
+pub(crate) fn substitute(arg: &str, vars: &[(&str, String)]) -> String {
+    let mut expanded = String::with_capacity(arg.len());
+    let mut rest = arg;
+    while let Some(start) = rest.find('{') {
+        expanded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let value = rest.find('}').and_then(|end| {
+            let name = &rest[1..end];
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| (value, end))
+        });
+        if let Some((value, end)) = value {
+            expanded.push_str(value);
+            rest = &rest[end + 1..];
+        } else {
+            expanded.push('{');
+            rest = &rest[1..];
+        }
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+/// Runs `command` on the file `path` and returns what it printed, trimmed
+/// to [`MAX_OUTPUT_CHARS`].
+///
+/// This blocks until the program exits and should run off the UI thread.
+///
+/// # Errors
+///
+/// Returns an error if the program cannot be started or exits with a
+/// failure status, with what it printed when there is any.
+pub fn run(command: &OpenWithCommand, path: &Path) -> Result<String, String> {
+    let args = expand(command, path);
+    let Some((program, args)) = args.split_first() else {
+        return Err("empty command".to_string());
+    };
+
+    let mut process = Command::new(program);
+    process.args(args).stdin(Stdio::null());
+    if let Some(dir) = path.parent().filter(|dir| dir.is_dir()) {
+        process.current_dir(dir);
+    }
+    let output = process.output().map_err(|e| format!("{program}: {e}"))?;
+
+    let mut printed = String::from_utf8_lossy(&output.stdout).into_owned();
+    printed.push_str(&String::from_utf8_lossy(&output.stderr));
+    let printed = shorten(printed.trim());
+
+    if output.status.success() {
+        Ok(printed)
+    } else if printed.is_empty() {
+        Err(output.status.to_string())
+    } else {
+        Err(printed)
+    }
+}
+
+/// Keeps the end of `text`, where programs usually print their result.
+fn shorten(text: &str) -> String {
+    let count = text.chars().count();
+    if count <= MAX_OUTPUT_CHARS {
+        return text.to_string();
+    }
+    let tail: String = text.chars().skip(count - MAX_OUTPUT_CHARS).collect();
+    format!("…{tail}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(args: &[&str]) -> OpenWithCommand {
+        OpenWithCommand {
+            name: "Test".to_string(),
+            command: args.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn expand_replaces_placeholders_inside_arguments() {
+        let path = Path::new("/photos/trip/beach.jpg");
+        let args = expand(
+            &command(&[
+                "tool",
+                "--in={path}",
+                "{dir}/out/{stem}.png",
+                "{name}",
+                "{other}",
+            ]),
+            path,
+        );
+        assert_eq!(
+            args,
+            [
+                "tool",
+                "--in=/photos/trip/beach.jpg",
+                "/photos/trip/out/beach.png",
+                "beach.jpg",
+                "{other}",
+            ]
+        );
+    }
+
+    #[test]
+    fn expand_does_not_reexpand_placeholders_in_the_path() {
+        let path = Path::new("/photos/{name}/a{stem}.jpg");
+        let args = expand(
+            &command(&["tool", "{path}", "{dir}", "{name}", "{stem}"]),
+            path,
+        );
+        assert_eq!(
+            args,
+            [
+                "tool",
+                "/photos/{name}/a{stem}.jpg",
+                "/photos/{name}",
+                "a{stem}.jpg",
+                "a{stem}",
+            ]
+        );
+    }
+
+    #[test]
+    fn long_output_keeps_its_end() {
+        let text = format!("{}end", "x".repeat(MAX_OUTPUT_CHARS));
+        let short = shorten(&text);
+        assert!(short.starts_with('…'));
+        assert!(short.ends_with("end"));
+        assert_eq!(short.chars().count(), MAX_OUTPUT_CHARS + 1);
+        assert_eq!(shorten("done"), "done");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_captures_output_and_failures() {
+        let path = std::env::temp_dir().join("open_with_test.jpg");
+        assert_eq!(
+            run(&command(&["echo", "sent {name}"]), &path),
+            Ok("sent open_with_test.jpg".to_string())
+        );
+        assert_eq!(
+            run(&command(&["sh", "-c", "echo broken >&2; exit 3"]), &path),
+            Err("broken".to_string())
+        );
+        assert!(run(&command(&[]), &path).is_err());
+        assert!(run(&command(&["iced-lens-no-such-program"]), &path).is_err());
+    }
+}
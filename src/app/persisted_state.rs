@@ -16,11 +16,17 @@
 //! 2. Set `ICED_LENS_DATA_DIR` environment variable
 //! 3. Falls back to platform-specific data directory
 
+use super::file_dialog::OpenFilter;
 use super::paths;
+use super::resume_positions::ResumePositions;
+use super::volume_boosts::VolumeBoosts;
+use super::window_mode::WindowMode;
+use super::workspace::{WorkspaceLayout, LAYOUT_SLOTS};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::{BufReader, BufWriter};
 use std::path::PathBuf;
+use std::time::SystemTime;
 
 /// State file name within the app data directory.
 const STATE_FILE: &str = "state.cbor";
@@ -41,6 +47,10 @@ pub struct AppState {
     #[serde(default)]
     pub last_open_directory: Option<PathBuf>,
 
+    /// Filter preselected in the open dialog, from the last opened file.
+    #[serde(default)]
+    pub last_open_filter: OpenFilter,
+
     /// Whether AI deblurring is enabled.
     /// This is application-managed state, not a user preference.
     /// The value depends on whether the model has been successfully downloaded and validated.
@@ -52,9 +62,50 @@ pub struct AppState {
     /// The value depends on whether the model has been successfully downloaded and validated.
     #[serde(default)]
     pub enable_upscale: bool,
+
+    /// Whether AI background removal is enabled.
+    /// This is application-managed state, not a user preference.
+    /// The value depends on whether the model has been successfully downloaded and validated.
+    #[serde(default)]
+    pub enable_background_removal: bool,
+
+    /// Workspace layouts saved by the user, per slot.
+    /// Empty slots fall back to [`WorkspaceLayout::builtin`].
+    #[serde(default)]
+    pub workspace_layouts: [Option<WorkspaceLayout>; LAYOUT_SLOTS],
+
+    /// Where playback of recently watched videos stopped.
+    #[serde(default)]
+    pub video_resume_positions: ResumePositions,
+
+    /// Volumes above 100% chosen for recently watched videos.
+    #[serde(default)]
+    pub video_volume_boosts: VolumeBoosts,
+
+    /// Whether the welcome screen was completed or skipped.
+    #[serde(default)]
+    pub welcome_done: bool,
+
+    /// When the last update check succeeded, for the weekly check.
+    #[serde(default)]
+    pub last_update_check: Option<SystemTime>,
+
+    /// Always-on-top, borderless and compact modes of the main window.
+    #[serde(default)]
+    pub window_mode: WindowMode,
 }
 
 impl AppState {
+    /// Returns the layout of `slot`: the saved one, or the built-in default.
+    #[must_use]
+    pub fn workspace_layout(&self, slot: usize) -> WorkspaceLayout {
+        self.workspace_layouts
+            .get(slot)
+            .copied()
+            .flatten()
+            .unwrap_or_else(|| WorkspaceLayout::builtin(slot))
+    }
+
     /// Loads application state from the default location.
     ///
     /// Returns a tuple of (state, `optional_warning`). If loading fails, returns
@@ -193,6 +244,7 @@ impl AppState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
     use tempfile::tempdir;
 
     #[test]
@@ -248,8 +300,20 @@ mod tests {
         let original = AppState {
             last_save_directory: Some(PathBuf::from("/home/user/documents")),
             last_open_directory: Some(PathBuf::from("/home/user/pictures")),
+            last_open_filter: OpenFilter::Videos,
             enable_deblur: false,
             enable_upscale: false,
+            enable_background_removal: false,
+            workspace_layouts: Default::default(),
+            video_resume_positions: ResumePositions::default(),
+            video_volume_boosts: VolumeBoosts::default(),
+            welcome_done: true,
+            last_update_check: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)),
+            window_mode: WindowMode {
+                always_on_top: true,
+                borderless: false,
+                compact: true,
+            },
         };
 
         // Write to CBOR
@@ -268,6 +332,7 @@ mod tests {
 
         assert_eq!(original.last_save_directory, loaded.last_save_directory);
         assert_eq!(original.last_open_directory, loaded.last_open_directory);
+        assert_eq!(original.last_open_filter, loaded.last_open_filter);
     }
 
     #[test]
@@ -289,8 +354,20 @@ mod tests {
         let original = AppState {
             last_save_directory: Some(PathBuf::from("/test/save/directory")),
             last_open_directory: Some(PathBuf::from("/test/open/directory")),
+            last_open_filter: OpenFilter::default(),
             enable_deblur: true,
             enable_upscale: false,
+            enable_background_removal: false,
+            workspace_layouts: [
+                None,
+                Some(WorkspaceLayout {
+                    info_panel_open: true,
+                    osd_visible: false,
+                }),
+                None,
+            ],
+            video_resume_positions: ResumePositions::default(),
+            video_volume_boosts: VolumeBoosts::default(),
         };
 
         // Save to custom directory
@@ -307,6 +384,20 @@ mod tests {
         assert_eq!(original, loaded);
     }
 
+    #[test]
+    fn workspace_layout_falls_back_to_builtin() {
+        let mut state = AppState::default();
+        assert_eq!(state.workspace_layout(1), WorkspaceLayout::builtin(1));
+
+        let saved = WorkspaceLayout {
+            info_panel_open: true,
+            osd_visible: true,
+        };
+        state.workspace_layouts[1] = Some(saved);
+        assert_eq!(state.workspace_layout(1), saved);
+        assert_eq!(state.workspace_layout(0), WorkspaceLayout::builtin(0));
+    }
+
     #[test]
     fn load_from_empty_directory_returns_default() {
         let temp_dir = tempdir().expect("create temp dir");
@@ -344,8 +435,13 @@ mod tests {
         let state_a = AppState {
             last_save_directory: Some(PathBuf::from("/path/a")),
             last_open_directory: None,
+            last_open_filter: OpenFilter::default(),
             enable_deblur: false,
             enable_upscale: false,
+            enable_background_removal: false,
+            workspace_layouts: Default::default(),
+            video_resume_positions: ResumePositions::default(),
+            video_volume_boosts: VolumeBoosts::default(),
         };
         let _ = state_a.save_to(Some(temp_dir_a.path().to_path_buf()));
 
@@ -354,8 +450,13 @@ mod tests {
         let state_b = AppState {
             last_save_directory: Some(PathBuf::from("/path/b")),
             last_open_directory: None,
+            last_open_filter: OpenFilter::default(),
             enable_deblur: true,
             enable_upscale: true,
+            enable_background_removal: false,
+            workspace_layouts: Default::default(),
+            video_resume_positions: ResumePositions::default(),
+            video_volume_boosts: VolumeBoosts::default(),
         };
         let _ = state_b.save_to(Some(temp_dir_b.path().to_path_buf()));
 
@@ -375,8 +476,13 @@ mod tests {
         let state = AppState {
             last_save_directory: Some(PathBuf::from("/test")),
             last_open_directory: None,
+            last_open_filter: OpenFilter::default(),
             enable_deblur: false,
             enable_upscale: false,
+            enable_background_removal: false,
+            workspace_layouts: Default::default(),
+            video_resume_positions: ResumePositions::default(),
+            video_volume_boosts: VolumeBoosts::default(),
         };
 
         // Save should create nested directories
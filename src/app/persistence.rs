@@ -10,7 +10,7 @@ use crate::i18n::fluent::I18n;
 use crate::media::MediaNavigator;
 use crate::ui::notifications;
 use crate::ui::settings::State as SettingsState;
-use crate::ui::theming::ThemeMode;
+use crate::ui::theming::{self, CustomPalette, ThemeMode};
 use crate::ui::viewer::component;
 use iced::Task;
 use std::path::Path;
@@ -28,6 +28,8 @@ pub struct PreferencesContext<'a> {
     pub keyboard_seek_step_secs: f64,
     pub notifications: &'a mut notifications::Manager,
     pub media_navigator: &'a MediaNavigator,
+    /// Configuration as last loaded or saved, updated after each save.
+    pub config_base: &'a mut config::Config,
 }
 
 /// Persists the current viewer + settings preferences to disk.
@@ -39,19 +41,24 @@ pub fn persist_preferences(ctx: &mut PreferencesContext<'_>) -> Task<Message> {
         return Task::none();
     }
 
-    let (mut cfg, load_warning) = config::load();
-    if let Some(key) = load_warning {
-        ctx.notifications
-            .push(notifications::Notification::warning(&key));
-    }
+    // Start from what this instance last saw on disk so that sections it
+    // leaves untouched keep changes saved by other instances
+    let mut cfg = ctx.config_base.clone();
 
     // Use image_fit_to_window() to only persist the image setting, not video
     cfg.display.fit_to_window = Some(ctx.viewer.image_fit_to_window());
     cfg.display.zoom_step = Some(ctx.viewer.zoom_step_percent());
     cfg.display.background_theme = Some(ctx.settings.background_theme());
     cfg.display.sort_order = Some(ctx.settings.sort_order());
+    cfg.display.sort_direction = Some(ctx.settings.sort_direction());
     cfg.display.max_skip_attempts = Some(ctx.settings.max_skip_attempts());
+    cfg.display.prefetch_cache_size = Some(ctx.settings.prefetch_cache_size());
+    cfg.display.similarity_threshold = Some(ctx.settings.similarity_threshold());
     cfg.display.persist_filters = Some(ctx.settings.persist_filters());
+    cfg.display.remember_view_per_image = Some(ctx.settings.remember_view_per_image());
+    cfg.display.show_minimap = Some(ctx.settings.show_minimap());
+    cfg.display.sandboxed_decoding = Some(ctx.settings.sandboxed_decoding());
+    cfg.display.webp_decoder = Some(ctx.settings.webp_decoder());
     // Save filter if persistence is enabled
     if ctx.settings.persist_filters() {
         let filter = ctx.media_navigator.filter().clone();
@@ -64,55 +71,137 @@ pub fn persist_preferences(ctx: &mut PreferencesContext<'_>) -> Task<Message> {
         cfg.display.filter = None;
     }
     cfg.fullscreen.overlay_timeout_secs = Some(ctx.settings.overlay_timeout_secs());
+    ctx.settings
+        .fullscreen_overlay()
+        .apply_to(&mut cfg.fullscreen);
     cfg.general.theme_mode = ctx.theme_mode;
+    cfg.general.accent_color = ctx.settings.accent_color().map(theming::to_hex);
+    cfg.general.custom_theme = Some(ctx.settings.custom_theme());
+    cfg.general.ui_scale_percent = Some(ctx.settings.ui_scale_percent());
     cfg.video.autoplay = Some(ctx.video_autoplay);
     cfg.video.audio_normalization = Some(ctx.audio_normalization);
+    cfg.video.hardware_decoding = Some(ctx.settings.hardware_decoding());
+    cfg.video.resume_playback = Some(ctx.settings.resume_playback());
+    cfg.video.tone_mapping = Some(ctx.settings.tone_mapping());
+    cfg.video.audio_device = ctx.settings.audio_device().map(str::to_string);
     cfg.video.frame_cache_mb = Some(ctx.frame_cache_mb);
     cfg.video.frame_history_mb = Some(ctx.frame_history_mb);
+    cfg.video.frame_cache_auto = Some(ctx.settings.frame_cache_auto());
     cfg.video.keyboard_seek_step_secs = Some(ctx.keyboard_seek_step_secs);
+    cfg.video.sequence_fps = Some(ctx.settings.sequence_fps());
+    cfg.video.frame_name_template = Some(ctx.settings.frame_name_template().to_string());
+    cfg.editor.save_policy = Some(ctx.settings.save_policy());
+    cfg.editor.edited_folder = Some(ctx.settings.edited_folder().to_string());
+    cfg.guides = ctx.settings.guides().to_config();
+    cfg.updates.check_weekly = Some(ctx.settings.check_updates_weekly());
 
     // Video playback preferences (persisted but not in Settings UI)
-    cfg.video.volume = Some(ctx.viewer.video_volume());
+    // Boosts above 100% are remembered per file, in the app state
+    cfg.video.volume = Some(ctx.viewer.base_video_volume());
     cfg.video.muted = Some(ctx.viewer.video_muted());
     cfg.video.loop_enabled = Some(ctx.viewer.video_loop());
+    cfg.video.subtitle_size = Some(ctx.viewer.subtitle_size());
+    cfg.video.subtitle_background = Some(ctx.viewer.subtitle_background());
+    cfg.video.audio_visualization = Some(ctx.viewer.audio_visualization());
 
     // AI preferences (note: enable flags are stored in AppState, not config)
     cfg.ai.deblur_model_url = Some(ctx.settings.deblur_model_url().to_string());
     cfg.ai.upscale_model_url = Some(ctx.settings.upscale_model_url().to_string());
+    cfg.ai.background_model_url = Some(ctx.settings.background_model_url().to_string());
 
-    if config::save(&cfg).is_err() {
-        ctx.notifications.push(notifications::Notification::warning(
-            "notification-config-save-error",
-        ));
+    let mouse_mapping = ctx.settings.mouse_mapping();
+    cfg.input.navigation_buttons = Some(mouse_mapping.navigation_buttons);
+    cfg.input.middle_click = Some(mouse_mapping.middle_click);
+    cfg.input.wheel = Some(mouse_mapping.wheel);
+    cfg.input.drag_button = Some(mouse_mapping.drag_button);
+
+    cfg.shortcuts = ctx.settings.keymap().to_config();
+    cfg.accessibility = ctx.settings.accessibility().to_config();
+    cfg.plugins.disabled = ctx.settings.disabled_plugins();
+
+    save_merged(&cfg, ctx.config_base, ctx.notifications);
+
+    Task::none()
+}
+
+/// Saves `cfg`, keeping external changes to sections unchanged since
+/// `config_base`, then makes the written configuration the new base.
+fn save_merged(
+    cfg: &config::Config,
+    config_base: &mut config::Config,
+    notifications: &mut notifications::Manager,
+) {
+    match config::save_merged(config_base, cfg) {
+        Ok((written, outcome)) => {
+            if outcome == config::SaveOutcome::MergedExternalChanges {
+                notifications.push(notifications::Notification::info(
+                    "notification-config-merged",
+                ));
+            }
+            *config_base = written;
+        }
+        Err(_) => {
+            notifications.push(notifications::Notification::warning(
+                "notification-config-save-error",
+            ));
+        }
     }
+}
+
+/// Returns the theme file holding the custom palette, next to `settings.toml`.
+fn theme_file_path() -> Option<std::path::PathBuf> {
+    super::paths::get_app_config_dir().map(|dir| dir.join(theming::THEME_FILE))
+}
+
+/// Loads the custom palette from the theme file, or the default palette if
+/// there is none yet or it cannot be read.
+#[must_use]
+pub fn load_custom_palette() -> CustomPalette {
+    let Some(path) = theme_file_path().filter(|path| path.exists()) else {
+        return CustomPalette::default();
+    };
+    CustomPalette::load(&path).unwrap_or_else(|error| {
+        tracing::warn!("Invalid theme file {}: {error}", path.display());
+        CustomPalette::default()
+    })
+}
 
+/// Saves the custom palette to the theme file.
+pub fn save_custom_palette(
+    palette: &CustomPalette,
+    notifications: &mut notifications::Manager,
+) -> Task<Message> {
+    if cfg!(test) {
+        return Task::none();
+    }
+
+    let saved = theme_file_path()
+        .ok_or_else(|| "no config directory".to_string())
+        .and_then(|path| palette.save(&path));
+    if saved.is_err() {
+        notifications.push(notifications::Notification::warning(
+            "notification-theme-save-error",
+        ));
+    }
     Task::none()
 }
 
-/// Applies the newly selected locale, persists it to config, and refreshes
-/// any visible error strings that depend on localization.
+/// Applies the newly selected locale and persists it to config.
+///
+/// Screens holding text of the previous language are refreshed by the app
+/// once the message is handled.
 pub fn apply_language_change(
     i18n: &mut I18n,
-    viewer: &mut component::State,
     locale: &LanguageIdentifier,
     notifications: &mut notifications::Manager,
+    config_base: &mut config::Config,
 ) -> Task<Message> {
     i18n.set_locale(locale.clone());
 
-    let (mut cfg, load_warning) = config::load();
-    if let Some(key) = load_warning {
-        notifications.push(notifications::Notification::warning(&key));
-    }
-
+    let mut cfg = config_base.clone();
     cfg.general.language = Some(locale.to_string());
+    save_merged(&cfg, config_base, notifications);
 
-    if config::save(&cfg).is_err() {
-        notifications.push(notifications::Notification::warning(
-            "notification-config-save-error",
-        ));
-    }
-
-    viewer.refresh_error_translation(i18n);
     Task::none()
 }
 
@@ -139,8 +228,8 @@ pub fn rescan_directory_if_same(media_navigator: &mut MediaNavigator, saved_path
             {
                 // Rescan the media navigator
                 let (config, _) = config::load();
-                let sort_order = config.display.sort_order.unwrap_or_default();
-                let _ = media_navigator.scan_directory(&path, sort_order);
+                let sort = config.display.media_sort();
+                let _ = media_navigator.scan_directory(&path, sort);
             }
         }
     }
@@ -0,0 +1,503 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Plugins: external programs that extend the viewer without forking it.
+//!
+//! Each plugin is a directory in the `plugins` directory next to
+//! `settings.toml`, holding a `plugin.toml` manifest and whatever programs
+//! it runs. Plugins run as separate processes, like the sandboxed decoder,
+//! so they can be written in any language and cannot crash the viewer:
+//!
+//! ```toml
+//! name = "Posterize"
+//! version = "1.0"
+//! description = "Poster look and WebP exports with ImageMagick"
+//!
+//! # Runs in the background each time a file is shown
+//! [on_media_loaded]
+//! command = ["./log.sh", "{path}"]
+//!
+//! # Button in the editor: reads {input} (PNG), writes {output} (PNG)
+//! [[editor_tool]]
+//! label = "Posterize"
+//! command = ["magick", "{input}", "-posterize", "4", "{output}"]
+//!
+//! # "Export as" button in the editor: reads {input} (PNG), writes {output}
+//! [[export_format]]
+//! label = "Lossless WebP"
+//! extension = "webp"
+//! command = ["cwebp", "-lossless", "{input}", "-o", "{output}"]
+//! ```
+//!
+//! Programs starting with `./` are looked up in the plugin directory, which
+//! is also their working directory. Placeholders are replaced inside every
+//! argument.
+
+use crate::app::{open_with, paths};
+use image_rs::DynamicImage;
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Name of the plugins directory, next to `settings.toml`.
+pub const PLUGINS_DIR: &str = "plugins";
+
+/// Name of the manifest file of a plugin.
+pub const MANIFEST_FILE: &str = "plugin.toml";
+
+/// Longest time an editor tool or export may run before it is killed.
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// How often a running command is checked for completion.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Contents of a `plugin.toml` manifest.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Manifest {
+    /// Name shown in the settings.
+    pub name: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Command run each time a media file is shown.
+    #[serde(default)]
+    pub on_media_loaded: Option<Hook>,
+    /// Tools added to the image editor.
+    #[serde(default, rename = "editor_tool")]
+    pub editor_tools: Vec<EditorTool>,
+    /// Export formats added to the image editor.
+    #[serde(default, rename = "export_format")]
+    pub export_formats: Vec<ExportFormat>,
+}
+
+/// Command run on an event. `{path}` is the file concerned.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Hook {
+    pub command: Vec<String>,
+}
+
+/// Editor tool turning the PNG `{input}` into the PNG `{output}`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EditorTool {
+    pub label: String,
+    pub command: Vec<String>,
+}
+
+/// Export format writing the PNG `{input}` to `{output}`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ExportFormat {
+    pub label: String,
+    /// Extension proposed for the exported file, without the dot.
+    pub extension: String,
+    pub command: Vec<String>,
+}
+
+/// A plugin found in the plugins directory.
+#[derive(Debug, Clone)]
+pub struct Plugin {
+    /// Name of the plugin directory, used to remember it is disabled.
+    pub id: String,
+    pub dir: PathBuf,
+    /// The manifest, or why it could not be read.
+    pub manifest: Result<Manifest, String>,
+    pub enabled: bool,
+}
+
+/// The installed plugins and the hooks of the enabled ones.
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    dir: Option<PathBuf>,
+    plugins: Vec<Plugin>,
+    tool_labels: Vec<String>,
+    export_labels: Vec<String>,
+}
+
+impl Registry {
+    /// Loads the plugins of the plugins directory, except `disabled` ones.
+    #[must_use]
+    pub fn load(disabled: &[String]) -> Self {
+        Self::discover(
+            paths::get_app_config_dir().map(|dir| dir.join(PLUGINS_DIR)),
+            disabled,
+        )
+    }
+
+    /// Loads the plugins found in `dir`, sorted by directory name.
+    #[must_use]
+    pub fn discover(dir: Option<PathBuf>, disabled: &[String]) -> Self {
+        let mut plugins: Vec<Plugin> = dir
+            .as_deref()
+            .and_then(|dir| fs::read_dir(dir).ok())
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                if !path.is_dir() {
+                    return None;
+                }
+                let id = path.file_name()?.to_string_lossy().into_owned();
+                let manifest = read_manifest(&path.join(MANIFEST_FILE));
+                Some(Plugin {
+                    enabled: !disabled.contains(&id),
+                    id,
+                    dir: path,
+                    manifest,
+                })
+            })
+            .collect();
+        plugins.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut registry = Self {
+            dir,
+            plugins,
+            ..Self::default()
+        };
+        registry.collect_labels();
+        registry
+    }
+
+    /// Returns the plugins directory, if the config directory is known.
+    #[must_use]
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    /// Returns every installed plugin, including broken and disabled ones.
+    #[must_use]
+    pub fn plugins(&self) -> &[Plugin] {
+        &self.plugins
+    }
+
+    /// Turns the plugin `id` on or off.
+    pub fn set_enabled(&mut self, id: &str, enabled: bool) {
+        if let Some(plugin) = self.plugins.iter_mut().find(|plugin| plugin.id == id) {
+            plugin.enabled = enabled;
+            self.collect_labels();
+        }
+    }
+
+    /// Returns the labels of the editor tools of enabled plugins.
+    #[must_use]
+    pub fn tool_labels(&self) -> &[String] {
+        &self.tool_labels
+    }
+
+    /// Returns the labels of the export formats of enabled plugins.
+    #[must_use]
+    pub fn export_labels(&self) -> &[String] {
+        &self.export_labels
+    }
+
+    /// Returns the plugin directory and the `index`-th editor tool, in the
+    /// order of [`Self::tool_labels`].
+    #[must_use]
+    pub fn editor_tool(&self, index: usize) -> Option<(PathBuf, EditorTool)> {
+        self.active()
+            .flat_map(|(plugin, manifest)| {
+                manifest
+                    .editor_tools
+                    .iter()
+                    .map(move |tool| (plugin.dir.clone(), tool.clone()))
+            })
+            .nth(index)
+    }
+
+    /// Returns the plugin directory and the `index`-th export format, in the
+    /// order of [`Self::export_labels`].
+    #[must_use]
+    pub fn export_format(&self, index: usize) -> Option<(PathBuf, ExportFormat)> {
+        self.active()
+            .flat_map(|(plugin, manifest)| {
+                manifest
+                    .export_formats
+                    .iter()
+                    .map(move |format| (plugin.dir.clone(), format.clone()))
+            })
+            .nth(index)
+    }
+
+    /// Starts the "on media loaded" hooks for `path` without waiting for them.
+    pub fn media_loaded(&self, path: &Path) {
+        for (plugin, manifest) in self.active() {
+            let Some(hook) = &manifest.on_media_loaded else {
+                continue;
+            };
+            let started = build_command(&plugin.dir, &hook.command, &[("path", path)])
+                .and_then(|mut command| command.spawn().map_err(|e| e.to_string()));
+            match started {
+                // Reap the process in the background so it doesn't linger as a zombie
+                Ok(mut child) => {
+                    std::thread::spawn(move || {
+                        let _ = child.wait();
+                    });
+                }
+                Err(error) => tracing::warn!("Plugin {}: {error}", plugin.id),
+            }
+        }
+    }
+
+    /// Enabled plugins with a valid manifest.
+    fn active(&self) -> impl Iterator<Item = (&Plugin, &Manifest)> {
+        self.plugins
+            .iter()
+            .filter(|plugin| plugin.enabled)
+            .filter_map(|plugin| Some((plugin, plugin.manifest.as_ref().ok()?)))
+    }
+
+    fn collect_labels(&mut self) {
+        self.tool_labels = self
+            .active()
+            .flat_map(|(_, manifest)| manifest.editor_tools.iter().map(|t| t.label.clone()))
+            .collect();
+        self.export_labels = self
+            .active()
+            .flat_map(|(_, manifest)| manifest.export_formats.iter().map(|f| f.label.clone()))
+            .collect();
+    }
+}
+
+fn read_manifest(path: &Path) -> Result<Manifest, String> {
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    toml::from_str(&content).map_err(|e| e.message().to_string())
+}
+
+/// Runs an editor tool on `image` and returns the image it produced.
+///
+/// This blocks until the tool exits and should run off the UI thread.
+///
+/// # Errors
+///
+/// Returns an error if the tool fails, times out or writes no readable image.
+pub fn run_editor_tool(
+    plugin_dir: &Path,
+    tool: &EditorTool,
+    image: &DynamicImage,
+) -> Result<DynamicImage, String> {
+    let scratch = scratch_dir()?;
+    let input = scratch.path().join("input.png");
+    let output = scratch.path().join("output.png");
+    image.save(&input).map_err(|e| e.to_string())?;
+
+    let command = build_command(
+        plugin_dir,
+        &tool.command,
+        &[("input", &input), ("output", &output)],
+    )?;
+    run_to_completion(command)?;
+    image_rs::open(&output).map_err(|e| e.to_string())
+}
+
+/// Writes `image` to `output` with an export format.
+///
+/// This blocks until the exporter exits and should run off the UI thread.
+///
+/// # Errors
+///
+/// Returns an error if the exporter fails, times out or writes nothing.
+pub fn run_export(
+    plugin_dir: &Path,
+    format: &ExportFormat,
+    image: &DynamicImage,
+    output: &Path,
+) -> Result<(), String> {
+    let scratch = scratch_dir()?;
+    let input = scratch.path().join("input.png");
+    image.save(&input).map_err(|e| e.to_string())?;
+
+    let command = build_command(
+        plugin_dir,
+        &format.command,
+        &[("input", &input), ("output", output)],
+    )?;
+    run_to_completion(command)?;
+    if output.exists() {
+        Ok(())
+    } else {
+        Err(format!("{} was not written", output.display()))
+    }
+}
+
+/// Builds the command of `template`, replacing each `{name}` of `vars`.
+fn build_command(
+    plugin_dir: &Path,
+    template: &[String],
+    vars: &[(&str, &Path)],
+) -> Result<Command, String> {
+    let vars: Vec<_> = vars
+        .iter()
+        .map(|(name, value)| (*name, value.to_string_lossy().into_owned()))
+        .collect();
+    let expand = |arg: &str| open_with::substitute(arg, &vars);
+
+    let (program, args) = template
+        .split_first()
+        .ok_or_else(|| "empty command".to_string())?;
+    let program = if program.starts_with("./") {
+        plugin_dir.join(program).into_os_string()
+    } else {
+        expand(program).into()
+    };
+
+    let mut command = Command::new(program);
+    command
+        .args(args.iter().map(|arg| expand(arg)))
+        .current_dir(plugin_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    Ok(command)
+}
+
+/// Runs `command`, killing it after [`COMMAND_TIMEOUT`].
+fn run_to_completion(mut command: Command) -> Result<(), String> {
+    let mut child = command.spawn().map_err(|e| e.to_string())?;
+    let deadline = Instant::now() + COMMAND_TIMEOUT;
+    loop {
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            return if status.success() {
+                Ok(())
+            } else {
+                Err(format!("the plugin exited with {status}"))
+            };
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err("the plugin timed out".to_string());
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Creates the temporary directory for the files exchanged with a plugin,
+/// removed on drop.
+///
+/// The name is random and the directory is created atomically, private to
+/// the user, so another process cannot plant or read files in it.
+fn scratch_dir() -> Result<tempfile::TempDir, String> {
+    tempfile::Builder::new()
+        .prefix("iced_lens-plugin-")
+        .tempdir()
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const MANIFEST: &str = r#"
+name = "Posterize"
+version = "1.0"
+
+[on_media_loaded]
+command = ["./log.sh", "{path}"]
+
+[[editor_tool]]
+label = "Posterize"
+command = ["magick", "{input}", "-posterize", "4", "{output}"]
+
+[[export_format]]
+label = "Lossless WebP"
+extension = "webp"
+command = ["cwebp", "-lossless", "{input}", "-o", "{output}"]
+"#;
+
+    fn install(root: &Path, id: &str, manifest: &str) {
+        let dir = root.join(id);
+        fs::create_dir_all(&dir).expect("create plugin dir");
+        fs::write(dir.join(MANIFEST_FILE), manifest).expect("write manifest");
+    }
+
+    #[test]
+    fn discover_reads_manifests_and_reports_broken_ones() {
+        let root = tempdir().expect("temp dir");
+        install(root.path(), "b-posterize", MANIFEST);
+        install(root.path(), "a-broken", "name = ");
+        fs::write(root.path().join("stray.txt"), "").expect("write file");
+
+        let registry = Registry::discover(Some(root.path().to_path_buf()), &[]);
+        let ids: Vec<&str> = registry.plugins().iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, ["a-broken", "b-posterize"]);
+        assert!(registry.plugins()[0].manifest.is_err());
+
+        let manifest = registry.plugins()[1].manifest.as_ref().expect("valid");
+        assert_eq!(manifest.name, "Posterize");
+        assert!(manifest.on_media_loaded.is_some());
+        assert_eq!(registry.tool_labels(), ["Posterize"]);
+        assert_eq!(registry.export_labels(), ["Lossless WebP"]);
+        let (dir, format) = registry.export_format(0).expect("export format");
+        assert_eq!(dir, root.path().join("b-posterize"));
+        assert_eq!(format.extension, "webp");
+        assert!(registry.editor_tool(1).is_none());
+    }
+
+    #[test]
+    fn disabled_plugins_contribute_nothing() {
+        let root = tempdir().expect("temp dir");
+        install(root.path(), "posterize", MANIFEST);
+
+        let mut registry =
+            Registry::discover(Some(root.path().to_path_buf()), &["posterize".to_string()]);
+        assert!(!registry.plugins()[0].enabled);
+        assert!(registry.tool_labels().is_empty());
+        assert!(registry.editor_tool(0).is_none());
+
+        registry.set_enabled("posterize", true);
+        assert_eq!(registry.tool_labels(), ["Posterize"]);
+    }
+
+    #[test]
+    fn commands_expand_placeholders_and_local_programs() {
+        let dir = Path::new("/plugins/posterize");
+        let template = vec![
+            "./run.sh".to_string(),
+            "--in={input}".to_string(),
+            "{output}".to_string(),
+        ];
+        let command = build_command(
+            dir,
+            &template,
+            &[
+                ("input", Path::new("/tmp/in.png")),
+                ("output", Path::new("/tmp/out.png")),
+            ],
+        )
+        .expect("command");
+        assert_eq!(command.get_program(), dir.join("./run.sh").as_os_str());
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(args, ["--in=/tmp/in.png", "/tmp/out.png"]);
+        assert_eq!(command.get_current_dir(), Some(dir));
+
+        assert!(build_command(dir, &[], &[]).is_err());
+    }
+
+    #[test]
+    fn substituted_paths_are_not_expanded_again() {
+        let dir = Path::new("/plugins/posterize");
+        let template = vec!["tool".to_string(), "{input}".to_string()];
+        let command = build_command(
+            dir,
+            &template,
+            &[
+                ("input", Path::new("/tmp/{output}.png")),
+                ("output", Path::new("/tmp/out.png")),
+            ],
+        )
+        .expect("command");
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(args, ["/tmp/{output}.png"]);
+    }
+
+    #[test]
+    fn scratch_dirs_are_unique_and_removed_on_drop() {
+        let first = scratch_dir().expect("scratch dir");
+        let second = scratch_dir().expect("scratch dir");
+        assert_ne!(first.path(), second.path());
+
+        let path = first.path().to_path_buf();
+        drop(first);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Pasting a copied edit recipe onto a series of images.
+//!
+//! Each image is decoded, the recipe's crops and resizes are scaled to its
+//! size, and the steps are replayed. The result is written where the save
+//! policy says, like a Save from the editor, except that originals are never
+//! replaced: under the overwrite policy the results go to the edited
+//! subfolder. As for batch metadata edits, per-file errors are collected
+//! instead of stopping at the first failure.
+
+use crate::config::SavePolicy;
+use crate::media::convert;
+use crate::media::metadata_batch::{BatchFailure, BatchSummary};
+use crate::media::{self, naming};
+use crate::ui::image_editor::AdjustmentRecipe;
+use image_rs::ImageFormat;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Returns the save policy used by the batch for the configured `policy`.
+///
+/// A batch can't be reviewed image by image, so it never overwrites the
+/// originals.
+#[must_use]
+pub fn batch_policy(policy: SavePolicy) -> SavePolicy {
+    match policy {
+        SavePolicy::Overwrite | SavePolicy::Subfolder => SavePolicy::Subfolder,
+        SavePolicy::Copy => SavePolicy::Copy,
+    }
+}
+
+/// Applies `recipe` to every image in `paths` and saves the results.
+///
+/// Animated images and files in a format that can't be written are skipped.
+#[must_use]
+pub fn apply_to_files(
+    paths: &[PathBuf],
+    recipe: &AdjustmentRecipe,
+    policy: SavePolicy,
+    edited_folder: &str,
+) -> BatchSummary {
+    let policy = batch_policy(policy);
+    let mut summary = BatchSummary::default();
+    for path in paths {
+        if !ImageFormat::from_path(path).is_ok_and(|format| format.writing_enabled())
+            || media::is_animated(path).unwrap_or(false)
+        {
+            summary.skipped += 1;
+            continue;
+        }
+        match apply_to_file(path, recipe, policy, edited_folder) {
+            Ok(()) => summary.updated += 1,
+            Err(error) => summary.failures.push(BatchFailure {
+                path: path.clone(),
+                error,
+            }),
+        }
+    }
+    summary
+}
+
+fn apply_to_file(
+    path: &Path,
+    recipe: &AdjustmentRecipe,
+    policy: SavePolicy,
+    edited_folder: &str,
+) -> Result<(), String> {
+    let image = convert::decode(path).map_err(|err| err.to_string())?;
+    let edited = recipe
+        .steps_for((image.width(), image.height()))
+        .iter()
+        .fold(image, |image, step| step.apply(&image));
+
+    let target = naming::save_target(policy, path, edited_folder);
+    if policy == SavePolicy::Subfolder {
+        if let Some(folder) = target.parent() {
+            fs::create_dir_all(folder).map_err(|err| err.to_string())?;
+        }
+    }
+
+    // Write next to the target and rename, so an edited version already in
+    // the subfolder is never left truncated
+    let partial = partial_path(&target);
+    let written = convert::encode(&edited, &partial, convert::DEFAULT_JPEG_QUALITY)
+        .map_err(|err| err.to_string())
+        .and_then(|()| fs::rename(&partial, &target).map_err(|err| err.to_string()));
+    if written.is_err() {
+        let _ = fs::remove_file(&partial);
+    }
+    written
+}
+
+/// Hidden file the result is written to before being renamed to `target`.
+///
+/// The extension is kept so the encoder picks the same format.
+fn partial_path(target: &Path) -> PathBuf {
+    let stem = target
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let extension = target
+        .extension()
+        .map(|ext| ext.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    target.with_file_name(format!(".{stem}.partial.{extension}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::image_editor::Transformation;
+    use image_rs::{Rgba, RgbaImage};
+
+    fn write_image(path: &Path, width: u32, height: u32) {
+        RgbaImage::from_pixel(width, height, Rgba([10, 20, 30, 255]))
+            .save(path)
+            .expect("write image");
+    }
+
+    #[test]
+    fn recipe_is_pasted_into_the_edited_subfolder() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let small = dir.path().join("small.png");
+        let large = dir.path().join("large.png");
+        let vector = dir.path().join("logo.svg");
+        write_image(&small, 4, 2);
+        write_image(&large, 8, 4);
+        std::fs::write(&vector, "<svg/>").expect("write svg");
+
+        let recipe = AdjustmentRecipe::new((4, 2), &[Transformation::RotateRight]);
+        let paths = [small.clone(), large.clone(), vector];
+        let summary = apply_to_files(&paths, &recipe, SavePolicy::Subfolder, "edited");
+
+        assert_eq!(summary.updated, 2);
+        assert_eq!(summary.skipped, 1);
+        assert!(summary.failures.is_empty());
+        let rotated = image_rs::open(dir.path().join("edited").join("large.png")).expect("open");
+        assert_eq!((rotated.width(), rotated.height()), (4, 8));
+        // Originals are left alone
+        let original = image_rs::open(&large).expect("open");
+        assert_eq!((original.width(), original.height()), (8, 4));
+    }
+
+    #[test]
+    fn overwrite_policy_writes_to_the_subfolder_instead() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let photo = dir.path().join("photo.png");
+        write_image(&photo, 4, 2);
+
+        let recipe = AdjustmentRecipe::new((4, 2), &[Transformation::RotateRight]);
+        let summary = apply_to_files(
+            std::slice::from_ref(&photo),
+            &recipe,
+            SavePolicy::Overwrite,
+            "edited",
+        );
+
+        assert_eq!(summary.updated, 1);
+        let original = image_rs::open(&photo).expect("open");
+        assert_eq!((original.width(), original.height()), (4, 2));
+        let rotated = image_rs::open(dir.path().join("edited").join("photo.png")).expect("open");
+        assert_eq!((rotated.width(), rotated.height()), (2, 4));
+        // No partial file is left behind
+        let entries = std::fs::read_dir(dir.path().join("edited")).expect("read dir");
+        assert_eq!(entries.count(), 1);
+    }
+
+    #[test]
+    fn animated_images_are_skipped() {
+        use image_rs::codecs::gif::GifEncoder;
+        use image_rs::Frame;
+
+        let dir = tempfile::tempdir().expect("temp dir");
+        let animation = dir.path().join("animation.gif");
+        let file = std::fs::File::create(&animation).expect("create gif");
+        let mut encoder = GifEncoder::new(file);
+        for shade in [0, 255] {
+            encoder
+                .encode_frame(Frame::new(RgbaImage::from_pixel(
+                    2,
+                    2,
+                    Rgba([shade, shade, shade, 255]),
+                )))
+                .expect("encode frame");
+        }
+        drop(encoder);
+
+        let recipe = AdjustmentRecipe::new((2, 2), &[Transformation::FlipHorizontal]);
+        let summary = apply_to_files(&[animation], &recipe, SavePolicy::Copy, "edited");
+
+        assert_eq!(summary.updated, 0);
+        assert_eq!(summary.skipped, 1);
+    }
+}
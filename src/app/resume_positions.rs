@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Last playback position of recently watched videos.
+//!
+//! Positions are keyed by path and file size, so a file replaced by another
+//! one with the same name starts from the beginning. They live in
+//! [`AppState`](super::persisted_state::AppState).
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Number of videos whose position is remembered; the least recently
+/// watched ones are forgotten first.
+const MAX_ENTRIES: usize = 200;
+
+/// Positions within this many seconds of the start or the end aren't worth
+/// resuming: the video was barely started or already finished.
+pub const MIN_RESUME_MARGIN_SECS: f64 = 10.0;
+
+/// Remembered position of one video.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ResumeEntry {
+    path: PathBuf,
+    size: u64,
+    position_secs: f64,
+}
+
+/// Playback positions of recently watched videos, most recent first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResumePositions {
+    entries: Vec<ResumeEntry>,
+}
+
+impl ResumePositions {
+    /// Returns where playback of the file at `path` stopped, if remembered.
+    #[must_use]
+    pub fn get(&self, path: &Path, size: u64) -> Option<f64> {
+        self.entries
+            .iter()
+            .find(|entry| entry.path == path && entry.size == size)
+            .map(|entry| entry.position_secs)
+    }
+
+    /// Remembers where playback of the file at `path` stopped.
+    ///
+    /// Positions near the start or the end forget the file instead, so
+    /// finished videos start over. Returns true if anything changed.
+    pub fn remember(
+        &mut self,
+        path: &Path,
+        size: u64,
+        position_secs: f64,
+        duration_secs: f64,
+    ) -> bool {
+        let existing = self.entries.iter().position(|entry| entry.path == path);
+        let worth_resuming = position_secs >= MIN_RESUME_MARGIN_SECS
+            && position_secs <= duration_secs - MIN_RESUME_MARGIN_SECS;
+        if !worth_resuming {
+            return existing.map(|index| self.entries.remove(index)).is_some();
+        }
+
+        let entry = ResumeEntry {
+            path: path.to_path_buf(),
+            size,
+            position_secs,
+        };
+        if existing == Some(0) && self.entries[0] == entry {
+            return false;
+        }
+        if let Some(index) = existing {
+            self.entries.remove(index);
+        }
+        self.entries.insert(0, entry);
+        self.entries.truncate(MAX_ENTRIES);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remembers_position_by_path_and_size() {
+        let mut positions = ResumePositions::default();
+        let path = Path::new("/videos/talk.mp4");
+        assert!(positions.remember(path, 1000, 754.0, 3600.0));
+        assert_eq!(positions.get(path, 1000), Some(754.0));
+        // Same name, different file
+        assert_eq!(positions.get(path, 2000), None);
+        // Unchanged position doesn't need saving
+        assert!(!positions.remember(path, 1000, 754.0, 3600.0));
+    }
+
+    #[test]
+    fn positions_near_either_end_forget_the_file() {
+        let mut positions = ResumePositions::default();
+        let path = Path::new("/videos/talk.mp4");
+        positions.remember(path, 1000, 754.0, 3600.0);
+        assert!(positions.remember(path, 1000, 3595.0, 3600.0));
+        assert_eq!(positions.get(path, 1000), None);
+        assert!(!positions.remember(path, 1000, 2.0, 3600.0));
+    }
+
+    #[test]
+    fn forgets_least_recent_videos_first() {
+        let mut positions = ResumePositions::default();
+        for i in 0..=MAX_ENTRIES {
+            let path = PathBuf::from(format!("/videos/{i}.mp4"));
+            positions.remember(&path, 1, 60.0, 600.0);
+        }
+        assert_eq!(positions.entries.len(), MAX_ENTRIES);
+        assert_eq!(positions.get(Path::new("/videos/0.mp4"), 1), None);
+        assert_eq!(positions.get(Path::new("/videos/1.mp4"), 1), Some(60.0));
+    }
+}
@@ -9,4 +9,8 @@ pub enum Screen {
     ImageEditor,
     Help,
     About,
+    Logs,
+    /// Missing translations report.
+    Translations,
+    Welcome,
 }
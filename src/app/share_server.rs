@@ -0,0 +1,491 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Read-only HTTP gallery of the current folder, for "Share over LAN".
+//!
+//! The server answers plain HTTP/1.1 `GET` and `HEAD` requests on a
+//! background thread: `/` lists the shared files as an HTML page and
+//! `/media/<n>` sends the n-th file. Files are addressed by their position in
+//! the list given at start, so request paths never reach the file system and
+//! nothing outside that list can be read.
+//!
+//! Byte ranges are honored so phones can seek in videos. The server stops
+//! when its [`ShareServer`] handle is dropped.
+
+use crate::media::extensions::VIDEO_EXTENSIONS;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::net::{IpAddr, Ipv4Addr, TcpListener, TcpStream, UdpSocket};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// How long the accept loop sleeps when no client is waiting.
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Time a client gets to send its request or accept more data.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Upper bound on the size of a request head, to ignore garbage quickly.
+const MAX_REQUEST_HEAD_BYTES: u64 = 16 * 1024;
+
+/// Size of the chunks files are sent in.
+const CHUNK_BYTES: usize = 64 * 1024;
+
+/// A running share server. Dropping it stops the server.
+#[derive(Debug)]
+pub struct ShareServer {
+    url: String,
+    port: u16,
+    file_count: usize,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ShareServer {
+    /// Starts serving `files` on every interface on `port` (0 picks a free
+    /// port), with `title` as the gallery heading.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the port cannot be bound, for example because it
+    /// is already in use.
+    pub fn start(title: String, files: Vec<PathBuf>, port: u16) -> io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::UNSPECIFIED, port))?;
+        listener.set_nonblocking(true)?;
+        let port = listener.local_addr()?.port();
+
+        let file_count = files.len();
+        let stop = Arc::new(AtomicBool::new(false));
+        let gallery = Arc::new(Gallery { title, files });
+        let thread = thread::spawn({
+            let stop = Arc::clone(&stop);
+            move || accept_loop(&listener, &gallery, &stop)
+        });
+
+        Ok(Self {
+            url: format!("http://{}:{port}/", lan_address()),
+            port,
+            file_count,
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    /// Returns the address other devices of the network can open.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the port the server listens on.
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Returns the number of shared files.
+    #[must_use]
+    pub fn file_count(&self) -> usize {
+        self.file_count
+    }
+}
+
+impl Drop for ShareServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Returns the address of the interface used to reach other hosts, falling
+/// back to localhost when the machine has no network.
+fn lan_address() -> IpAddr {
+    // Connecting a UDP socket sends nothing, it only selects a route
+    UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))
+        .and_then(|socket| {
+            socket.connect((Ipv4Addr::new(8, 8, 8, 8), 80))?;
+            socket.local_addr()
+        })
+        .map_or(IpAddr::V4(Ipv4Addr::LOCALHOST), |address| address.ip())
+}
+
+/// The shared files and the page listing them.
+struct Gallery {
+    title: String,
+    files: Vec<PathBuf>,
+}
+
+fn accept_loop(listener: &TcpListener, gallery: &Arc<Gallery>, stop: &Arc<AtomicBool>) {
+    while !stop.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                let gallery = Arc::clone(gallery);
+                let stop = Arc::clone(stop);
+                thread::spawn(move || {
+                    if let Err(err) = handle_client(stream, &gallery, &stop) {
+                        // Clients hanging up mid-transfer is routine
+                        if !matches!(
+                            err.kind(),
+                            io::ErrorKind::BrokenPipe | io::ErrorKind::ConnectionReset
+                        ) {
+                            tracing::warn!("Share server: {err}");
+                        }
+                    }
+                });
+            }
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                thread::sleep(ACCEPT_POLL_INTERVAL);
+            }
+            Err(err) => {
+                tracing::warn!("Share server: {err}");
+                thread::sleep(ACCEPT_POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+/// What a request path points to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Route {
+    Index,
+    Media(usize),
+    NotFound,
+}
+
+/// Resolves a request path, ignoring any query string.
+fn route(path: &str) -> Route {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    if path == "/" {
+        return Route::Index;
+    }
+    path.strip_prefix("/media/")
+        .and_then(|index| index.parse().ok())
+        .map_or(Route::NotFound, Route::Media)
+}
+
+/// Parses a `Range: bytes=start-end` header value against a file of `len`
+/// bytes into an inclusive byte range.
+fn parse_range(value: &str, len: u64) -> Option<(u64, u64)> {
+    let (start, end) = value.trim().strip_prefix("bytes=")?.split_once('-')?;
+    let (start, end) = if start.is_empty() {
+        // Suffix range: the last `end` bytes
+        let suffix: u64 = end.parse().ok()?;
+        (len.checked_sub(suffix.min(len))?, len.checked_sub(1)?)
+    } else {
+        let start: u64 = start.parse().ok()?;
+        let end = if end.is_empty() {
+            len.checked_sub(1)?
+        } else {
+            end.parse::<u64>().ok()?.min(len.checked_sub(1)?)
+        };
+        (start, end)
+    };
+    (start <= end && end < len).then_some((start, end))
+}
+
+fn handle_client(stream: TcpStream, gallery: &Gallery, stop: &AtomicBool) -> io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+
+    let mut reader = BufReader::new((&stream).take(MAX_REQUEST_HEAD_BYTES));
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut range = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("range") {
+                range = Some(value.trim().to_string());
+            }
+        }
+    }
+
+    let mut stream = &stream;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return write_text(&mut stream, "400 Bad Request", "Bad request");
+    };
+    let head_only = match method {
+        "GET" => false,
+        "HEAD" => true,
+        _ => return write_text(&mut stream, "405 Method Not Allowed", "Read-only gallery"),
+    };
+
+    match route(path) {
+        Route::Index => {
+            let page = gallery_page(gallery);
+            write_head(
+                &mut stream,
+                "200 OK",
+                "text/html; charset=utf-8",
+                page.len() as u64,
+                &[],
+            )?;
+            if !head_only {
+                stream.write_all(page.as_bytes())?;
+            }
+            Ok(())
+        }
+        Route::Media(index) => match gallery.files.get(index) {
+            Some(path) => send_file(&mut stream, path, range.as_deref(), head_only, stop),
+            None => write_text(&mut stream, "404 Not Found", "Not found"),
+        },
+        Route::NotFound => write_text(&mut stream, "404 Not Found", "Not found"),
+    }
+}
+
+fn send_file(
+    stream: &mut impl Write,
+    path: &Path,
+    range: Option<&str>,
+    head_only: bool,
+    stop: &AtomicBool,
+) -> io::Result<()> {
+    let Ok(mut file) = File::open(path) else {
+        return write_text(stream, "404 Not Found", "Not found");
+    };
+    let len = file.metadata()?.len();
+    let content_type = content_type(path);
+
+    let (status, start, end, content_range) = match range {
+        Some(range) => match parse_range(range, len) {
+            Some((start, end)) => (
+                "206 Partial Content",
+                start,
+                end,
+                Some(format!("bytes {start}-{end}/{len}")),
+            ),
+            None => {
+                let unsatisfiable = format!("bytes */{len}");
+                write_head(
+                    stream,
+                    "416 Range Not Satisfiable",
+                    "text/plain",
+                    0,
+                    &[("Content-Range", &unsatisfiable)],
+                )?;
+                return Ok(());
+            }
+        },
+        None => ("200 OK", 0, len.saturating_sub(1), None),
+    };
+    let body_len = if len == 0 { 0 } else { end - start + 1 };
+
+    let mut headers = vec![
+        ("Accept-Ranges", "bytes"),
+        // Shared SVGs must not run scripts when opened directly
+        ("Content-Security-Policy", "sandbox"),
+    ];
+    if let Some(content_range) = &content_range {
+        headers.push(("Content-Range", content_range));
+    }
+    write_head(stream, status, content_type, body_len, &headers)?;
+    if head_only || body_len == 0 {
+        return Ok(());
+    }
+
+    file.seek(SeekFrom::Start(start))?;
+    let mut remaining = body_len;
+    let mut buffer = vec![0; CHUNK_BYTES];
+    while remaining > 0 && !stop.load(Ordering::Relaxed) {
+        let want = usize::try_from(remaining).map_or(CHUNK_BYTES, |left| left.min(CHUNK_BYTES));
+        let read = file.read(&mut buffer[..want])?;
+        if read == 0 {
+            break;
+        }
+        stream.write_all(&buffer[..read])?;
+        remaining -= read as u64;
+    }
+    Ok(())
+}
+
+fn write_head(
+    stream: &mut impl Write,
+    status: &str,
+    content_type: &str,
+    content_length: u64,
+    extra_headers: &[(&str, &str)],
+) -> io::Result<()> {
+    let mut head = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {content_length}\r\nConnection: close\r\nX-Content-Type-Options: nosniff\r\n"
+    );
+    for (name, value) in extra_headers {
+        let _ = write!(head, "{name}: {value}\r\n");
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes())
+}
+
+fn write_text(stream: &mut impl Write, status: &str, body: &str) -> io::Result<()> {
+    write_head(
+        stream,
+        status,
+        "text/plain; charset=utf-8",
+        body.len() as u64,
+        &[],
+    )?;
+    stream.write_all(body.as_bytes())
+}
+
+/// Returns the MIME type browsers need to display `path`.
+fn content_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+    match extension.as_str() {
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "bmp" => "image/bmp",
+        "tif" | "tiff" => "image/tiff",
+        "ico" => "image/x-icon",
+        "svg" => "image/svg+xml",
+        "mp4" | "m4v" => "video/mp4",
+        "mov" => "video/quicktime",
+        "webm" => "video/webm",
+        "mkv" => "video/x-matroska",
+        "avi" => "video/x-msvideo",
+        _ => "application/octet-stream",
+    }
+}
+
+fn is_video(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| VIDEO_EXTENSIONS.contains(&extension.to_lowercase().as_str()))
+}
+
+/// Escapes text for use in HTML content and attribute values.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Builds the HTML page listing every shared file as a thumbnail grid.
+fn gallery_page(gallery: &Gallery) -> String {
+    let title = escape_html(&gallery.title);
+    let mut page = format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\">\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\
+         <title>{title}</title><style>\
+         body{{margin:0;background:#111;color:#eee;font-family:sans-serif}}\
+         h1{{font-size:1.1em;margin:0;padding:12px}}\
+         main{{display:grid;grid-template-columns:repeat(auto-fill,minmax(140px,1fr));gap:4px;padding:4px}}\
+         img,video{{display:block;width:100%;aspect-ratio:1;object-fit:cover;background:#222}}\
+         </style></head><body><h1>{title}</h1><main>"
+    );
+    for (index, path) in gallery.files.iter().enumerate() {
+        let name = escape_html(
+            &path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+        );
+        if is_video(path) {
+            let _ = write!(
+                page,
+                "<video src=\"/media/{index}\" title=\"{name}\" controls preload=\"metadata\"></video>"
+            );
+        } else {
+            let _ = write!(
+                page,
+                "<a href=\"/media/{index}\"><img src=\"/media/{index}\" alt=\"{name}\" title=\"{name}\" loading=\"lazy\"></a>"
+            );
+        }
+    }
+    page.push_str("</main></body></html>");
+    page
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::SocketAddr;
+    use tempfile::tempdir;
+
+    fn get(port: u16, path: &str) -> String {
+        let mut stream = TcpStream::connect(SocketAddr::from((Ipv4Addr::LOCALHOST, port)))
+            .expect("connect to share server");
+        write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").expect("send request");
+        let mut response = String::new();
+        stream.read_to_string(&mut response).expect("read response");
+        response
+    }
+
+    #[test]
+    fn routes_only_index_and_numbered_media() {
+        assert_eq!(route("/"), Route::Index);
+        assert_eq!(route("/?refresh=1"), Route::Index);
+        assert_eq!(route("/media/3"), Route::Media(3));
+        assert_eq!(route("/media/../../etc/passwd"), Route::NotFound);
+        assert_eq!(route("/media/-1"), Route::NotFound);
+        assert_eq!(route("/photo.jpg"), Route::NotFound);
+    }
+
+    #[test]
+    fn parses_byte_ranges() {
+        assert_eq!(parse_range("bytes=0-99", 1000), Some((0, 99)));
+        assert_eq!(parse_range("bytes=500-", 1000), Some((500, 999)));
+        assert_eq!(parse_range("bytes=-100", 1000), Some((900, 999)));
+        assert_eq!(parse_range("bytes=900-5000", 1000), Some((900, 999)));
+        assert_eq!(parse_range("bytes=1000-", 1000), None);
+        assert_eq!(parse_range("bytes=5-2", 1000), None);
+        assert_eq!(parse_range("items=0-1", 1000), None);
+    }
+
+    #[test]
+    fn gallery_page_escapes_file_names() {
+        let page = gallery_page(&Gallery {
+            title: "Trip <2024>".to_string(),
+            files: vec![PathBuf::from("a&b.jpg"), PathBuf::from("clip.MP4")],
+        });
+        assert!(page.contains("<h1>Trip &lt;2024&gt;</h1>"));
+        assert!(page.contains("<img src=\"/media/0\" alt=\"a&amp;b.jpg\""));
+        assert!(page.contains("<video src=\"/media/1\""));
+    }
+
+    #[test]
+    fn serves_listed_files_and_nothing_else() {
+        let dir = tempdir().expect("temp dir");
+        let shared = dir.path().join("shared.png");
+        std::fs::write(&shared, b"not really a png").expect("write shared file");
+        std::fs::write(dir.path().join("secret.txt"), b"secret").expect("write other file");
+
+        let server =
+            ShareServer::start("Photos".to_string(), vec![shared], 0).expect("start share server");
+        let port = server.port();
+        assert!(server.url().ends_with(&format!(":{port}/")));
+
+        let index = get(port, "/");
+        assert!(index.starts_with("HTTP/1.1 200 OK"));
+        assert!(index.contains("/media/0"));
+
+        let file = get(port, "/media/0");
+        assert!(file.contains("Content-Type: image/png"));
+        assert!(file.ends_with("not really a png"));
+
+        assert!(get(port, "/media/1").starts_with("HTTP/1.1 404"));
+        assert!(get(port, "/secret.txt").starts_with("HTTP/1.1 404"));
+    }
+}
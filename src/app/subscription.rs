@@ -4,10 +4,24 @@
 //! This module handles routing of native events (keyboard, mouse, window)
 //! to the appropriate screen components based on the current application state.
 
-use super::{Message, Screen};
-use crate::ui::viewer::component;
+use super::{config, Message, Screen};
+use crate::ui::pip;
+use crate::ui::viewer::{component, quick_search};
 use crate::video_player::SharedLufsCache;
-use iced::{event, time, Subscription};
+use iced::{event, keyboard, time, Subscription};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Tick interval while an on-screen change is imminent (overlay auto-hide).
+const ACTIVE_TICK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Tick interval when only coarse timers are pending (notification
+/// auto-dismiss, loading timeout, diagnostics refresh, fullscreen clock).
+const IDLE_TICK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How often `settings.toml` and watched `.ftl` files are checked for
+/// changes made outside the app.
+const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(2);
 
 /// Creates the appropriate event subscription based on the current screen.
 ///
@@ -25,6 +39,15 @@ pub fn create_event_subscription(screen: Screen) -> Subscription<Message> {
             if let event::Event::Window(iced::window::Event::CloseRequested) = &event {
                 return Some(Message::WindowCloseRequested(window_id));
             }
+            if let Some(message) = focus_change_message(&event, window_id) {
+                return Some(message);
+            }
+            if let Some(message) = focus_traversal_message(&event) {
+                return Some(message);
+            }
+            if let Some(message) = diagnostics_toggle_message(&event) {
+                return Some(message);
+            }
 
             // File drop is only handled on Viewer screen
 
@@ -88,6 +111,15 @@ pub fn create_event_subscription(screen: Screen) -> Subscription<Message> {
                 if let event::Event::Window(iced::window::Event::CloseRequested) = &event {
                     return Some(Message::WindowCloseRequested(window_id));
                 }
+                if let Some(message) = focus_change_message(&event, window_id) {
+                    return Some(message);
+                }
+                if let Some(message) = focus_traversal_message(&event) {
+                    return Some(message);
+                }
+                if let Some(message) = diagnostics_toggle_message(&event) {
+                    return Some(message);
+                }
 
                 // Handle file drop only on Viewer screen
                 if let event::Event::Window(iced::window::Event::FileDropped(path)) = &event {
@@ -107,6 +139,22 @@ pub fn create_event_subscription(screen: Screen) -> Subscription<Message> {
                     }));
                 }
 
+                // The quick-search input captures Escape to unfocus itself;
+                // close the overlay anyway (no-op when it isn't open)
+                if matches!(status, event::Status::Captured)
+                    && matches!(
+                        event,
+                        event::Event::Keyboard(iced::keyboard::Event::KeyPressed {
+                            key: iced::keyboard::Key::Named(iced::keyboard::key::Named::Escape),
+                            ..
+                        })
+                    )
+                {
+                    return Some(Message::Viewer(component::Message::QuickSearch(
+                        quick_search::Message::Close,
+                    )));
+                }
+
                 match status {
                     event::Status::Ignored => Some(Message::Viewer(component::Message::RawEvent {
                         window: window_id,
@@ -116,7 +164,12 @@ pub fn create_event_subscription(screen: Screen) -> Subscription<Message> {
                 }
             })
         }
-        Screen::Settings | Screen::Help | Screen::About => {
+        Screen::Settings
+        | Screen::Help
+        | Screen::About
+        | Screen::Logs
+        | Screen::Translations
+        | Screen::Welcome => {
             // In settings/help/about screens, only route non-wheel events to viewer
             // (wheel events are used by scrollable content)
             event::listen_with(|event, status, window_id| {
@@ -124,6 +177,9 @@ pub fn create_event_subscription(screen: Screen) -> Subscription<Message> {
                 if let event::Event::Window(iced::window::Event::CloseRequested) = &event {
                     return Some(Message::WindowCloseRequested(window_id));
                 }
+                if let Some(message) = focus_change_message(&event, window_id) {
+                    return Some(message);
+                }
 
                 // File drop is only handled on Viewer screen
 
@@ -147,20 +203,173 @@ pub fn create_event_subscription(screen: Screen) -> Subscription<Message> {
     }
 }
 
-/// Creates a periodic tick subscription for overlay auto-hide, loading timeout,
-/// and notification auto-dismiss.
-pub fn create_tick_subscription(
-    fullscreen: bool,
-    is_loading: bool,
-    has_notifications: bool,
-) -> Subscription<Message> {
-    if fullscreen || is_loading || has_notifications {
-        time::every(std::time::Duration::from_millis(100)).map(Message::Tick)
+/// Maps window focus changes to [`Message::WindowFocusChanged`].
+///
+/// Minimizing and restoring a window changes its focus, so the app re-checks
+/// the minimized state on these events.
+fn focus_change_message(event: &event::Event, window_id: iced::window::Id) -> Option<Message> {
+    match event {
+        event::Event::Window(iced::window::Event::Focused | iced::window::Event::Unfocused) => {
+            Some(Message::WindowFocusChanged(window_id))
+        }
+        _ => None,
+    }
+}
+
+/// Maps Tab and Shift+Tab to [`Message::MoveFocus`], so the text fields of
+/// the viewer and editor can be reached from the keyboard. The settings
+/// screen keeps Tab so it can be recorded as a shortcut.
+fn focus_traversal_message(event: &event::Event) -> Option<Message> {
+    match event {
+        event::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(keyboard::key::Named::Tab),
+            modifiers,
+            ..
+        }) if !modifiers.command() && !modifiers.alt() => Some(Message::MoveFocus {
+            backwards: modifiers.shift(),
+        }),
+        _ => None,
+    }
+}
+
+/// Maps F12 without modifiers to [`Message::ToggleDiagnostics`].
+fn diagnostics_toggle_message(event: &event::Event) -> Option<Message> {
+    match event {
+        event::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(keyboard::key::Named::F12),
+            modifiers,
+            repeat: false,
+            ..
+        }) if modifiers.is_empty() => Some(Message::ToggleDiagnostics),
+        _ => None,
+    }
+}
+
+/// What currently depends on the periodic tick.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TickNeeds {
+    /// The window is minimized: nothing on screen can change.
+    pub window_minimized: bool,
+    /// Fullscreen overlay controls are visible and waiting to auto-hide.
+    pub overlay_hide_pending: bool,
+    /// Media is loading (checked against the loading timeout).
+    pub is_loading: bool,
+    /// Notifications are shown or queued (auto-dismiss).
+    pub has_notifications: bool,
+    /// The diagnostics overlay is shown and refreshes its figures.
+    pub diagnostics_visible: bool,
+    /// The fullscreen overlay shows the clock.
+    pub clock_visible: bool,
+}
+
+/// Returns the tick interval for `needs`, or `None` when nothing needs it.
+///
+/// Ticking is what wakes the app when idle, so a static image in a
+/// window with no pending timers gets no ticks at all.
+#[must_use]
+pub fn tick_interval(needs: TickNeeds) -> Option<Duration> {
+    if needs.window_minimized {
+        // Timers catch up once the window is restored
+        None
+    } else if needs.overlay_hide_pending {
+        Some(ACTIVE_TICK_INTERVAL)
+    } else if needs.is_loading
+        || needs.has_notifications
+        || needs.diagnostics_visible
+        || needs.clock_visible
+    {
+        Some(IDLE_TICK_INTERVAL)
     } else {
-        Subscription::none()
+        None
     }
 }
 
+/// Creates a periodic tick subscription for overlay auto-hide, loading timeout,
+/// and notification auto-dismiss, at the rate given by [`tick_interval`].
+pub fn create_tick_subscription(needs: TickNeeds) -> Subscription<Message> {
+    tick_interval(needs).map_or_else(Subscription::none, |interval| {
+        time::every(interval).map(Message::Tick)
+    })
+}
+
+/// Watches `settings.toml` for changes made in a text editor or by another
+/// instance.
+///
+/// The modification time is polled from a background task rather than with
+/// ticks, so the app is only woken when the file actually changed.
+pub fn create_config_watch_subscription() -> Subscription<Message> {
+    Subscription::run(config_file_changes)
+}
+
+fn config_file_changes() -> impl iced::futures::Stream<Item = Message> {
+    iced::stream::channel(1, |mut output| async move {
+        use iced::futures::SinkExt;
+
+        let Some(path) = config::config_path() else {
+            return;
+        };
+        let modified = |path: &std::path::Path| {
+            std::fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        };
+
+        let mut last_modified = modified(&path);
+        loop {
+            tokio::time::sleep(CONFIG_POLL_INTERVAL).await;
+            let current = modified(&path);
+            if current != last_modified {
+                last_modified = current;
+                if output.send(Message::ConfigFileChanged).await.is_err() {
+                    break;
+                }
+            }
+        }
+    })
+}
+
+/// Watches the `.ftl` files of `dir`, so translators see their edits without
+/// restarting.
+pub fn create_translations_watch_subscription(dir: &str) -> Subscription<Message> {
+    Subscription::run_with(PathBuf::from(dir), translation_file_changes)
+}
+
+fn translation_file_changes(dir: &PathBuf) -> impl iced::futures::Stream<Item = Message> {
+    let dir = dir.clone();
+    iced::stream::channel(1, move |mut output| async move {
+        use iced::futures::SinkExt;
+
+        // Latest modification among the files, and their count so that
+        // added or removed languages are noticed too
+        let snapshot = |dir: &std::path::Path| {
+            let files: Vec<_> = std::fs::read_dir(dir)
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "ftl"))
+                .collect();
+            let latest = files
+                .iter()
+                .filter_map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+                .max();
+            (files.len(), latest)
+        };
+
+        let mut last_snapshot = snapshot(&dir);
+        loop {
+            tokio::time::sleep(CONFIG_POLL_INTERVAL).await;
+            let current = snapshot(&dir);
+            if current != last_snapshot {
+                last_snapshot = current;
+                if output.send(Message::TranslationsChanged).await.is_err() {
+                    break;
+                }
+            }
+        }
+    })
+}
+
 /// Creates the video playback subscription with LUFS cache for audio normalization.
 pub fn create_video_subscription(
     viewer: &component::State,
@@ -168,8 +377,133 @@ pub fn create_video_subscription(
     audio_normalization: bool,
     frame_cache_mb: u32,
     history_mb: u32,
+    frame_cache_auto: bool,
+    hardware_decoding: bool,
 ) -> Subscription<Message> {
     viewer
-        .subscription(lufs_cache, audio_normalization, frame_cache_mb, history_mb)
+        .subscription(
+            lufs_cache,
+            audio_normalization,
+            frame_cache_mb,
+            history_mb,
+            frame_cache_auto,
+            hardware_decoding,
+        )
         .map(Message::Viewer)
 }
+
+/// Creates the decoder subscription of the mini player, if open.
+pub fn create_mini_player_subscription(
+    mini_player: Option<&pip::State>,
+    lufs_cache: Option<SharedLufsCache>,
+    audio_normalization: bool,
+    frame_cache_mb: u32,
+    history_mb: u32,
+    hardware_decoding: bool,
+) -> Subscription<Message> {
+    let Some(mini_player) = mini_player else {
+        return Subscription::none();
+    };
+    let cache_config = crate::video_player::CacheConfig::new(
+        (frame_cache_mb as usize) * 1024 * 1024,
+        crate::video_player::frame_cache::DEFAULT_MAX_FRAMES,
+    );
+    mini_player
+        .subscription(
+            lufs_cache,
+            audio_normalization,
+            cache_config,
+            history_mb,
+            hardware_decoding,
+        )
+        .map(Message::MiniPlayer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::keyboard::key::{Named, NativeCode, Physical};
+    use iced::keyboard::{Key, Location, Modifiers};
+
+    fn tab(modifiers: Modifiers) -> event::Event {
+        key_press(Named::Tab, modifiers)
+    }
+
+    fn key_press(named: Named, modifiers: Modifiers) -> event::Event {
+        event::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: Key::Named(named),
+            modified_key: Key::Named(named),
+            physical_key: Physical::Unidentified(NativeCode::Unidentified),
+            location: Location::Standard,
+            modifiers,
+            text: None,
+            repeat: false,
+        })
+    }
+
+    #[test]
+    fn tab_moves_focus_and_shift_tab_moves_it_back() {
+        assert!(matches!(
+            focus_traversal_message(&tab(Modifiers::empty())),
+            Some(Message::MoveFocus { backwards: false })
+        ));
+        assert!(matches!(
+            focus_traversal_message(&tab(Modifiers::SHIFT)),
+            Some(Message::MoveFocus { backwards: true })
+        ));
+        assert!(focus_traversal_message(&tab(Modifiers::COMMAND)).is_none());
+    }
+
+    #[test]
+    fn f12_toggles_diagnostics() {
+        assert!(matches!(
+            diagnostics_toggle_message(&key_press(Named::F12, Modifiers::empty())),
+            Some(Message::ToggleDiagnostics)
+        ));
+        assert!(diagnostics_toggle_message(&key_press(Named::F12, Modifiers::CTRL)).is_none());
+        assert!(diagnostics_toggle_message(&key_press(Named::F11, Modifiers::empty())).is_none());
+    }
+
+    #[test]
+    fn idle_static_image_does_not_tick() {
+        assert_eq!(tick_interval(TickNeeds::default()), None);
+    }
+
+    #[test]
+    fn overlay_auto_hide_ticks_fastest() {
+        let needs = TickNeeds {
+            overlay_hide_pending: true,
+            has_notifications: true,
+            ..TickNeeds::default()
+        };
+        assert_eq!(tick_interval(needs), Some(ACTIVE_TICK_INTERVAL));
+
+        let needs = TickNeeds {
+            has_notifications: true,
+            ..TickNeeds::default()
+        };
+        assert_eq!(tick_interval(needs), Some(IDLE_TICK_INTERVAL));
+    }
+
+    #[test]
+    fn minimized_window_never_ticks() {
+        let needs = TickNeeds {
+            window_minimized: true,
+            overlay_hide_pending: true,
+            is_loading: true,
+            has_notifications: true,
+            diagnostics_visible: true,
+            clock_visible: true,
+        };
+        assert_eq!(tick_interval(needs), None);
+    }
+
+    #[test]
+    fn fullscreen_clock_ticks_at_idle_rate() {
+        let needs = TickNeeds {
+            clock_visible: true,
+            ..TickNeeds::default()
+        };
+        assert_eq!(tick_interval(needs), Some(IDLE_TICK_INTERVAL));
+    }
+}
@@ -4,27 +4,53 @@
 //! This module contains the main `update` function and all specialized
 //! message handlers for different parts of the application.
 
-use super::{notifications, persistence, Message, Screen};
+use super::persisted_state::AppState;
+use super::resume_positions::MIN_RESUME_MARGIN_SECS;
+use super::share_server::ShareServer;
+#[cfg(feature = "update-check")]
+use super::update_check;
+use super::workspace::{slot_i18n_key, WorkspaceLayout, LAYOUT_SLOTS};
+use super::{file_dialog, notifications, persistence, Message, Screen};
+use super::{open_with, plugins};
 use crate::config;
 use crate::i18n::fluent::I18n;
+use crate::media::clip_export::{CancelFlag, ClipExportStatus, ClipMethod, ClipRequest};
+use crate::media::frame_encoder::SequenceOptions;
+use crate::media::frame_export::FramesExportStatus;
 use crate::media::metadata::MediaMetadata;
 use crate::media::{
-    self, frame_export::ExportableFrame, MaxSkipAttempts, MediaData, MediaNavigator,
+    self, capture_day::CaptureDayIndex, frame_export::ExportableFrame, similarity::SimilarityIndex,
+    MaxSkipAttempts, MediaData, MediaNavigator,
 };
 use crate::ui::about::{self, Event as AboutEvent};
+use crate::ui::accessibility;
 use crate::ui::design_tokens::sizing;
 use crate::ui::help::{self, Event as HelpEvent};
 use crate::ui::image_editor::{self, Event as ImageEditorEvent, State as ImageEditorState};
-use crate::ui::metadata_panel::{self, Event as MetadataPanelEvent, MetadataEditorState};
+use crate::ui::logs::{self, Event as LogsEvent};
+use crate::ui::metadata_panel::{
+    self, Event as MetadataPanelEvent, ExportScope, MetadataEditorState,
+};
 use crate::ui::navbar::{self, Event as NavbarEvent};
+use crate::ui::pip;
 use crate::ui::settings::{self, Event as SettingsEvent, State as SettingsState};
+use crate::ui::share_panel;
 use crate::ui::theming::ThemeMode;
+use crate::ui::translations::{self, Event as TranslationsEvent};
 use crate::ui::viewer::{component, filter_dropdown};
-use crate::video_player::KeyboardSeekStep;
+use crate::ui::welcome::{self, Event as WelcomeEvent};
+use crate::video_player::{KeyboardSeekStep, SequenceFps, SharedLufsCache};
 // Re-export NavigationDirection from viewer component (single source of truth)
 pub use crate::ui::viewer::NavigationDirection;
 use iced::{window, Point, Size, Task};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+#[cfg(feature = "update-check")]
+use std::time::{Duration, SystemTime};
+
+/// How long the "update available" notification stays, to leave time to
+/// read the release notes.
+#[cfg(feature = "update-check")]
+const UPDATE_NOTIFICATION_DURATION: Duration = Duration::from_secs(60);
 
 /// Navigation mode determines which media types to include.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -113,13 +139,30 @@ pub struct UpdateContext<'a> {
     pub theme_mode: &'a mut ThemeMode,
     pub video_autoplay: &'a mut bool,
     pub audio_normalization: &'a mut bool,
+    pub lufs_cache: &'a SharedLufsCache,
     pub menu_open: &'a mut bool,
     pub info_panel_open: &'a mut bool,
     pub current_metadata: &'a mut Option<MediaMetadata>,
     pub metadata_editor_state: &'a mut Option<MetadataEditorState>,
+    pub metadata_tag_browser: &'a mut metadata_panel::TagBrowserState,
+    pub metadata_batch: &'a mut metadata_panel::BatchEditState,
+    pub shoot_stats: &'a mut metadata_panel::ShootStatsState,
+    pub verify_running: &'a mut bool,
+    pub share_server: &'a mut Option<ShareServer>,
+    pub share_panel: &'a mut Option<share_panel::State>,
+    pub plugins: &'a mut plugins::Registry,
+    pub open_with: &'a [config::OpenWithCommand],
+    pub edit_clipboard: &'a mut Option<image_editor::AdjustmentRecipe>,
     pub help_state: &'a mut help::State,
-    pub persisted: &'a mut super::persisted_state::AppState,
+    pub persisted: &'a mut AppState,
+    pub config_base: &'a mut config::Config,
     pub notifications: &'a mut notifications::Manager,
+    pub mini_player: &'a mut Option<pip::State>,
+    /// Number of mini players opened so far, to tell their decoders apart.
+    pub mini_player_sessions: &'a mut u64,
+    pub prefetch: &'a mut media::prefetch::Cache,
+    pub logs: &'a mut logs::State,
+    pub welcome: &'a mut welcome::State,
 }
 
 impl UpdateContext<'_> {
@@ -137,6 +180,7 @@ impl UpdateContext<'_> {
             keyboard_seek_step_secs: self.settings.keyboard_seek_step_secs(),
             notifications: self.notifications,
             media_navigator: self.media_navigator,
+            config_base: self.config_base,
         }
     }
 }
@@ -153,6 +197,27 @@ pub fn handle_viewer_message(
     // Check if this is a successful MediaLoaded message to extract metadata
     let is_successful_load = matches!(&message, component::Message::MediaLoaded(Ok(_)));
 
+    // Keep the media about to be shown, so navigating back to it is instant
+    if let (component::Message::MediaLoaded(Ok(media)), Some(path)) =
+        (&message, ctx.viewer.current_media_path.as_ref())
+    {
+        ctx.prefetch.insert(
+            path.clone(),
+            media::prefetch::modified_time(path),
+            ctx.settings.load_options(),
+            media.clone(),
+        );
+    }
+
+    // Remember where the current video was left before it's replaced
+    if matches!(
+        &message,
+        component::Message::MediaLoaded(_) | component::Message::ClearMedia
+    ) {
+        remember_video_position(ctx);
+    }
+
+    let volume_before = ctx.viewer.video_volume();
     let (effect, task) = ctx.viewer.handle_message(message, ctx.i18n);
 
     // Handle successful media load
@@ -178,8 +243,13 @@ pub fn handle_viewer_message(
             *ctx.current_metadata = None;
         }
 
+        offer_video_resume(ctx);
+        apply_volume_boost(ctx);
+
         // Clear any stale load error notifications (UX: state consistency)
         ctx.notifications.clear_load_errors();
+    } else if (ctx.viewer.video_volume() - volume_before).abs() > f32::EPSILON {
+        remember_volume_boost(ctx);
     }
 
     let viewer_task = task.map(Message::Viewer);
@@ -200,11 +270,16 @@ pub fn handle_viewer_message(
             }
         }
         component::Effect::ExitFullscreen => {
-            update_fullscreen_mode(ctx.fullscreen, ctx.window_id.as_ref(), false)
+            if !*ctx.fullscreen && ctx.persisted.window_mode.compact {
+                // Esc is the way out of compact mode, which hides the menu
+                handle_toggle_compact_mode(ctx)
+            } else {
+                update_fullscreen_mode(ctx.fullscreen, ctx.window_id.as_ref(), false)
+            }
         }
         component::Effect::OpenSettings => {
             *ctx.screen = Screen::Settings;
-            Task::none()
+            list_audio_devices()
         }
         component::Effect::EnterEditor => handle_screen_switch(ctx, Screen::ImageEditor),
         component::Effect::NavigateNext => handle_navigate_next(ctx),
@@ -213,15 +288,14 @@ pub fn handle_viewer_message(
             frame,
             video_path,
             position_secs,
-        } => handle_capture_frame(frame, video_path, position_secs),
+            full_decode,
+        } => handle_capture_frame(frame, video_path, position_secs, full_decode),
         component::Effect::RequestDelete => handle_delete_current_media(ctx),
         component::Effect::ToggleInfoPanel => {
             *ctx.info_panel_open = !*ctx.info_panel_open;
             Task::none()
         }
-        component::Effect::OpenFileDialog => {
-            handle_open_file_dialog(ctx.persisted.last_open_directory.clone())
-        }
+        component::Effect::OpenFileDialog => handle_open_file_dialog(ctx.i18n, ctx.persisted),
         component::Effect::ShowErrorNotification { key, args } => {
             let mut notification = notifications::Notification::error(key);
             for (arg_key, arg_value) in args {
@@ -250,6 +324,8 @@ pub fn handle_viewer_message(
         } => {
             // Confirm navigation position in MediaNavigator
             ctx.media_navigator.confirm_navigation(&path);
+            ctx.plugins.media_loaded(&path);
+            let prefetch = Task::batch([prefetch_neighbors(ctx), prefetch_loudness(ctx)]);
 
             // Show notification if any files were skipped during navigation
             if !skipped_files.is_empty() {
@@ -260,14 +336,592 @@ pub fn handle_viewer_message(
                         .auto_dismiss(std::time::Duration::from_secs(8)),
                 );
             }
-            Task::none()
+            prefetch
         }
         component::Effect::FilterChanged(filter_msg) => handle_filter_changed(ctx, filter_msg),
+        component::Effect::OpenQuickSearch => {
+            // The overlay is only rendered on the viewer screen
+            if matches!(ctx.screen, Screen::Viewer) {
+                let candidates = ctx.media_navigator.media_paths();
+                ctx.viewer
+                    .open_quick_search(candidates)
+                    .map(Message::Viewer)
+            } else {
+                Task::none()
+            }
+        }
+        component::Effect::JumpToMedia(path) => handle_jump_to_media(ctx, path),
+        component::Effect::NavigateCaptureDay(direction) => {
+            handle_navigate_capture_day(ctx, direction)
+        }
+        component::Effect::FindSimilar(direction) => handle_find_similar(ctx, direction),
+        component::Effect::DetectFaces => handle_detect_faces(ctx),
+        component::Effect::ApplyLayout(slot) => handle_apply_layout(ctx, slot),
+        component::Effect::SaveLayout(slot) => handle_save_layout(ctx, slot),
+        component::Effect::ExportClip {
+            video_path,
+            start_secs,
+            end_secs,
+            cancel,
+        } => handle_export_clip(ctx, video_path, start_secs, end_secs, cancel),
+        component::Effect::ClipExported { path, method } => {
+            let key = match method {
+                ClipMethod::StreamCopy => "notification-clip-exported",
+                ClipMethod::Reencode => "notification-clip-exported-reencoded",
+            };
+            ctx.notifications.push(
+                notifications::Notification::success(key)
+                    .with_arg("path", path.display().to_string()),
+            );
+            Task::none()
+        }
+        component::Effect::ExportFrames {
+            video_path,
+            start_secs,
+            end_secs,
+            options,
+            cancel,
+        } => handle_export_frames(ctx, video_path, (start_secs, end_secs), options, cancel),
+        component::Effect::CaptureBurst {
+            video_path,
+            timestamps,
+            cancel,
+        } => handle_capture_burst(ctx, video_path, timestamps, cancel),
+        component::Effect::FramesExported { path, frames } => {
+            ctx.notifications.push(
+                notifications::Notification::success("notification-frames-exported")
+                    .with_arg("count", frames.to_string())
+                    .with_arg("path", path.display().to_string()),
+            );
+            Task::none()
+        }
+        component::Effect::ExportVisibleArea { frame, source_path } => {
+            handle_export_visible_area(ctx, frame, &source_path)
+        }
+        component::Effect::OpenMiniPlayer => handle_open_mini_player(ctx),
+        component::Effect::ExportView(destination) => handle_export_view(ctx, destination),
+        component::Effect::CopyColor(color) => {
+            ctx.notifications.push(
+                notifications::Notification::info("notification-color-copied")
+                    .with_arg("color", color.clone()),
+            );
+            iced::clipboard::write(color)
+        }
         component::Effect::None => Task::none(),
     };
     Task::batch([viewer_task, side_effect])
 }
 
+/// Opens the mini player window with the current video, replacing the
+/// mini player already open.
+fn handle_open_mini_player(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    let Some(handoff) = ctx.viewer.mini_player_handoff() else {
+        return Task::none();
+    };
+    let close_previous = close_mini_player(ctx);
+
+    let (window, open) = window::open(window::Settings {
+        size: pip::WINDOW_SIZE,
+        min_size: Some(pip::MIN_WINDOW_SIZE),
+        decorations: false,
+        level: window::Level::AlwaysOnTop,
+        icon: crate::icon::load_window_icon(),
+        exit_on_close_request: false,
+        ..window::Settings::default()
+    });
+    *ctx.mini_player_sessions += 1;
+    match pip::State::new(
+        window,
+        handoff,
+        *ctx.mini_player_sessions,
+        ctx.viewer.video_volume(),
+        ctx.viewer.video_muted(),
+    ) {
+        Ok(mini_player) => {
+            *ctx.mini_player = Some(mini_player);
+            Task::batch([close_previous, open.discard()])
+        }
+        Err(_) => {
+            ctx.notifications.push(notifications::Notification::error(
+                "notification-mini-player-error",
+            ));
+            close_previous
+        }
+    }
+}
+
+/// Handles messages from the mini player window.
+pub fn handle_mini_player_message(
+    ctx: &mut UpdateContext<'_>,
+    message: pip::Message,
+) -> Task<Message> {
+    let Some(mini_player) = ctx.mini_player.as_mut() else {
+        return Task::none();
+    };
+    match mini_player.update(message) {
+        pip::Event::None => Task::none(),
+        pip::Event::DragWindow => window::drag(mini_player.window()),
+        pip::Event::Close => close_mini_player(ctx),
+        pip::Event::Failed(message) => {
+            let error = crate::error::VideoError::from_message(&message);
+            let mut notification = notifications::Notification::error(error.i18n_key());
+            for (arg_key, arg_value) in error.i18n_args() {
+                notification = notification.with_arg(arg_key, arg_value);
+            }
+            ctx.notifications.push(notification);
+            close_mini_player(ctx)
+        }
+        pip::Event::ReturnToMain {
+            path,
+            position_secs,
+            playing,
+        } => {
+            let close = close_mini_player(ctx);
+            let focus = ctx.window_id.map_or_else(Task::none, window::gain_focus);
+            if ctx
+                .viewer
+                .continue_video(path.clone(), position_secs, playing)
+            {
+                Task::batch([close, focus])
+            } else {
+                Task::batch([close, focus, load_media_from_path(ctx, path)])
+            }
+        }
+    }
+}
+
+/// Stops the mini player and closes its window, if open.
+pub fn close_mini_player(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    match ctx.mini_player.take() {
+        Some(mut mini_player) => {
+            mini_player.stop();
+            window::close(mini_player.window())
+        }
+        None => Task::none(),
+    }
+}
+
+/// Takes a screenshot of the main window to export the viewer as displayed.
+fn handle_export_view(
+    ctx: &UpdateContext<'_>,
+    destination: media::view_export::Destination,
+) -> Task<Message> {
+    let Some(window) = *ctx.window_id else {
+        return Task::none();
+    };
+    // The viewport is the whole window in fullscreen, overlays included
+    let bounds = if *ctx.fullscreen {
+        None
+    } else {
+        ctx.viewer.viewport.bounds
+    };
+    window::screenshot(window).map(move |screenshot| Message::ViewScreenshotTaken {
+        screenshot,
+        bounds,
+        destination,
+    })
+}
+
+/// Saves or copies the viewer pane of a window screenshot.
+pub fn handle_view_screenshot(
+    ctx: &mut UpdateContext<'_>,
+    screenshot: &window::Screenshot,
+    bounds: Option<iced::Rectangle>,
+    destination: media::view_export::Destination,
+) -> Task<Message> {
+    let Some(frame) = media::view_export::from_screenshot(screenshot, bounds) else {
+        ctx.notifications.push(notifications::Notification::error(
+            "notification-view-export-error",
+        ));
+        return Task::none();
+    };
+    match destination {
+        media::view_export::Destination::File => {
+            let stem = ctx
+                .viewer
+                .current_media_path
+                .as_ref()
+                .and_then(|path| path.file_stem())
+                .map_or_else(|| "view".into(), |stem| stem.to_string_lossy().into_owned());
+            ask_save_path(ctx, format!("{stem}_view.png")).map(move |path| {
+                Message::ViewExportDialogResult {
+                    path,
+                    frame: frame.clone(),
+                }
+            })
+        }
+        media::view_export::Destination::Clipboard => {
+            let notification = match media::view_export::copy_to_clipboard(&frame) {
+                Ok(()) => notifications::Notification::info("notification-view-copied"),
+                Err(_) => notifications::Notification::error("notification-view-export-error"),
+            };
+            ctx.notifications.push(notification);
+            Task::none()
+        }
+    }
+}
+
+/// Asks where to save the visible area of the current image.
+fn handle_export_visible_area(
+    ctx: &UpdateContext<'_>,
+    frame: ExportableFrame,
+    source_path: &std::path::Path,
+) -> Task<Message> {
+    let stem = source_path
+        .file_stem()
+        .map_or_else(|| "image".into(), |stem| stem.to_string_lossy());
+    ask_save_path(ctx, format!("{stem}_visible.png")).map(move |path| {
+        Message::VisibleAreaDialogResult {
+            path,
+            frame: frame.clone(),
+        }
+    })
+}
+
+/// Asks where to save a clip of the video, then exports it in the background.
+/// Progress and the result are reported to the viewer.
+fn handle_export_clip(
+    ctx: &mut UpdateContext<'_>,
+    video_path: PathBuf,
+    start_secs: f64,
+    end_secs: f64,
+    cancel: CancelFlag,
+) -> Task<Message> {
+    let filename = media::clip_export::default_clip_filename(&video_path);
+
+    ask_save_path(ctx, filename)
+        .then(move |output| match output {
+            Some(output) => {
+                let request = ClipRequest {
+                    input: video_path.clone(),
+                    output,
+                    start_secs,
+                    end_secs,
+                };
+                let cancel = cancel.clone();
+                Task::stream(export_stream(
+                    ClipExportStatus::Progress,
+                    ClipExportStatus::Failed,
+                    move |progress| match media::clip_export::export_clip(
+                        &request, &cancel, progress,
+                    ) {
+                        Ok(Some(method)) => ClipExportStatus::Finished {
+                            path: request.output,
+                            method,
+                        },
+                        Ok(None) => ClipExportStatus::Cancelled,
+                        Err(e) => ClipExportStatus::Failed(e.to_string()),
+                    },
+                ))
+            }
+            None => Task::done(ClipExportStatus::Cancelled),
+        })
+        .map(|status| Message::Viewer(component::Message::ClipExport(status)))
+}
+
+/// Asks where to save frames of the video, then exports the range in the
+/// background. Progress and the result are reported to the viewer.
+fn handle_export_frames(
+    ctx: &mut UpdateContext<'_>,
+    video_path: PathBuf,
+    (start_secs, end_secs): (f64, f64),
+    options: SequenceOptions,
+    cancel: CancelFlag,
+) -> Task<Message> {
+    let filename = media::frame_export::default_frames_filename(&video_path, &options);
+
+    ask_save_path(ctx, filename)
+        .then(move |output| match output {
+            Some(output) => {
+                let request = ClipRequest {
+                    input: video_path.clone(),
+                    output,
+                    start_secs,
+                    end_secs,
+                };
+                let cancel = cancel.clone();
+                Task::stream(export_stream(
+                    FramesExportStatus::Progress,
+                    FramesExportStatus::Failed,
+                    move |progress| match media::frame_export::export_frame_range(
+                        &request, options, &cancel, progress,
+                    ) {
+                        Ok(Some(frames)) => FramesExportStatus::Finished {
+                            path: request.output,
+                            frames,
+                        },
+                        Ok(None) => FramesExportStatus::Cancelled,
+                        Err(e) => FramesExportStatus::Failed(e.to_string()),
+                    },
+                ))
+            }
+            None => Task::done(FramesExportStatus::Cancelled),
+        })
+        .map(|status| Message::Viewer(component::Message::FramesExport(status)))
+}
+
+/// Asks for a folder, then writes the burst frames there in the background,
+/// named from the frame name template.
+fn handle_capture_burst(
+    ctx: &mut UpdateContext<'_>,
+    video_path: PathBuf,
+    timestamps: Vec<f64>,
+    cancel: CancelFlag,
+) -> Task<Message> {
+    let name_template = ctx.settings.frame_name_template().to_string();
+    let initial_directory = ctx
+        .persisted
+        .last_save_directory
+        .clone()
+        .or_else(|| video_path.parent().map(Path::to_path_buf));
+
+    Task::perform(
+        async move {
+            let mut dialog = rfd::AsyncFileDialog::new();
+            if let Some(dir) = initial_directory.filter(|dir| dir.exists()) {
+                dialog = dialog.set_directory(&dir);
+            }
+            dialog.pick_folder().await.map(|h| h.path().to_path_buf())
+        },
+        std::convert::identity,
+    )
+    .then(move |folder| match folder {
+        Some(folder) => {
+            let request = media::frame_export::BurstRequest {
+                input: video_path.clone(),
+                folder,
+                timestamps: timestamps.clone(),
+                name_template: name_template.clone(),
+                format: media::frame_export::ExportFormat::Png,
+            };
+            let cancel = cancel.clone();
+            Task::stream(export_stream(
+                FramesExportStatus::Progress,
+                FramesExportStatus::Failed,
+                move |progress| match media::frame_export::export_burst(&request, &cancel, progress)
+                {
+                    Ok(Some(frames)) => FramesExportStatus::Finished {
+                        path: request.folder,
+                        frames,
+                    },
+                    Ok(None) => FramesExportStatus::Cancelled,
+                    Err(e) => FramesExportStatus::Failed(e.to_string()),
+                },
+            ))
+        }
+        None => Task::done(FramesExportStatus::Cancelled),
+    })
+    .map(|status| Message::Viewer(component::Message::FramesExport(status)))
+}
+
+/// Opens a save dialog suggesting `filename` in the last save directory.
+fn ask_save_path(ctx: &UpdateContext<'_>, filename: String) -> Task<Option<PathBuf>> {
+    let last_save_directory = ctx.persisted.last_save_directory.clone();
+
+    Task::perform(
+        async move {
+            let mut dialog = rfd::AsyncFileDialog::new().set_file_name(&filename);
+            if let Some(dir) = last_save_directory {
+                if dir.exists() {
+                    dialog = dialog.set_directory(&dir);
+                }
+            }
+            dialog.save_file().await.map(|h| h.path().to_path_buf())
+        },
+        std::convert::identity,
+    )
+}
+
+/// Runs an export on a blocking thread, streaming its progress (in whole
+/// percent steps) and then the status it returns.
+fn export_stream<S: Send + 'static>(
+    progress_status: fn(f32) -> S,
+    failed_status: fn(String) -> S,
+    export: impl FnOnce(&mut dyn FnMut(f32)) -> S + Send + 'static,
+) -> impl iced::futures::Stream<Item = S> {
+    iced::stream::channel(100, move |mut output| async move {
+        use iced::futures::SinkExt;
+
+        let (progress_tx, mut progress_rx) = tokio::sync::mpsc::unbounded_channel();
+        let export = tokio::task::spawn_blocking(move || {
+            let mut last_percent = None;
+            export(&mut |progress| {
+                // Only report whole percent changes, progress comes per packet
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let percent = (progress * 100.0) as u32;
+                if last_percent != Some(percent) {
+                    last_percent = Some(percent);
+                    let _ = progress_tx.send(progress);
+                }
+            })
+        });
+
+        // The progress channel closes when the export returns
+        while let Some(progress) = progress_rx.recv().await {
+            let _ = output.send(progress_status(progress)).await;
+        }
+        let status = export
+            .await
+            .unwrap_or_else(|e| failed_status(e.to_string()));
+        let _ = output.send(status).await;
+    })
+}
+
+/// Remembers where the current video was left, so reopening it can resume there.
+pub fn remember_video_position(ctx: &mut UpdateContext<'_>) {
+    if ctx.settings.resume_playback() == config::ResumePlayback::Never {
+        return;
+    }
+    let Some((path, position_secs, duration_secs)) = ctx.viewer.video_resume_point() else {
+        return;
+    };
+    let Ok(file) = std::fs::metadata(&path) else {
+        return;
+    };
+    // An unanswered prompt keeps the old position unless playback got somewhere
+    let position_secs = match ctx.viewer.resume_offer() {
+        Some(offered) if position_secs < MIN_RESUME_MARGIN_SECS => offered,
+        _ => position_secs,
+    };
+    let changed = ctx.persisted.video_resume_positions.remember(
+        &path,
+        file.len(),
+        position_secs,
+        duration_secs,
+    );
+    if changed {
+        if let Some(key) = ctx.persisted.save() {
+            ctx.notifications
+                .push(notifications::Notification::warning(&key));
+        }
+    }
+}
+
+/// Resumes the video that was just loaded where it was left, asking first
+/// unless the user chose to always resume.
+fn offer_video_resume(ctx: &mut UpdateContext<'_>) {
+    let mode = ctx.settings.resume_playback();
+    if mode == config::ResumePlayback::Never {
+        return;
+    }
+    let Some((path, _, _)) = ctx.viewer.video_resume_point() else {
+        return;
+    };
+    let Ok(file) = std::fs::metadata(&path) else {
+        return;
+    };
+    if let Some(position_secs) = ctx.persisted.video_resume_positions.get(&path, file.len()) {
+        ctx.viewer
+            .resume_video_from(position_secs, mode == config::ResumePlayback::Ask);
+    }
+}
+
+/// Plays the video that was just loaded at the volume boost chosen for it,
+/// or at the base volume if it has none.
+fn apply_volume_boost(ctx: &mut UpdateContext<'_>) {
+    let boost = ctx.viewer.current_media_path.as_ref().and_then(|path| {
+        let file = std::fs::metadata(path).ok()?;
+        ctx.persisted.video_volume_boosts.get(path, file.len())
+    });
+    ctx.viewer.apply_volume_boost(boost);
+}
+
+/// Remembers the volume of the current video if it is boosted above 100%,
+/// or forgets its boost otherwise.
+fn remember_volume_boost(ctx: &mut UpdateContext<'_>) {
+    let Some(path) = ctx.viewer.current_media_path.clone() else {
+        return;
+    };
+    let Ok(file) = std::fs::metadata(&path) else {
+        return;
+    };
+    let changed =
+        ctx.persisted
+            .video_volume_boosts
+            .remember(&path, file.len(), ctx.viewer.video_volume());
+    if changed {
+        if let Some(key) = ctx.persisted.save() {
+            ctx.notifications
+                .push(notifications::Notification::warning(&key));
+        }
+    }
+}
+
+/// Keeps the main window above other windows, or stops doing so.
+fn handle_toggle_always_on_top(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    let mode = &mut ctx.persisted.window_mode;
+    mode.always_on_top = !mode.always_on_top;
+    let level = mode.level();
+    save_window_mode(ctx);
+    ctx.window_id
+        .map_or_else(Task::none, |window| window::set_level(window, level))
+}
+
+/// Shows or hides the title bar and borders of the main window.
+fn handle_toggle_borderless(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    let mode = &mut ctx.persisted.window_mode;
+    mode.borderless = !mode.borderless;
+    save_window_mode(ctx);
+    // The window opened with decorations matching the saved mode, so
+    // toggling keeps them in step
+    ctx.window_id
+        .map_or_else(Task::none, window::toggle_decorations)
+}
+
+/// Hides all chrome but the media, or brings it back.
+fn handle_toggle_compact_mode(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    let mode = &mut ctx.persisted.window_mode;
+    mode.compact = !mode.compact;
+    if mode.compact {
+        *ctx.info_panel_open = false;
+    }
+    save_window_mode(ctx);
+    Task::none()
+}
+
+fn save_window_mode(ctx: &mut UpdateContext<'_>) {
+    if let Some(key) = ctx.persisted.save() {
+        ctx.notifications
+            .push(notifications::Notification::warning(&key));
+    }
+}
+
+/// Switches the panels to the workspace layout of `slot`.
+fn handle_apply_layout(ctx: &mut UpdateContext<'_>, slot: usize) -> Task<Message> {
+    if slot >= LAYOUT_SLOTS {
+        return Task::none();
+    }
+    let layout = ctx.persisted.workspace_layout(slot);
+    *ctx.info_panel_open = layout.info_panel_open;
+    ctx.viewer.set_osd_visible(layout.osd_visible);
+    ctx.notifications.push(
+        notifications::Notification::info("notification-layout-applied")
+            .with_translated_arg("name", slot_i18n_key(slot)),
+    );
+    Task::none()
+}
+
+/// Saves the current panel setup into layout `slot`.
+fn handle_save_layout(ctx: &mut UpdateContext<'_>, slot: usize) -> Task<Message> {
+    let Some(entry) = ctx.persisted.workspace_layouts.get_mut(slot) else {
+        return Task::none();
+    };
+    *entry = Some(WorkspaceLayout {
+        info_panel_open: *ctx.info_panel_open,
+        osd_visible: ctx.viewer.osd_visible(),
+    });
+    if let Some(key) = ctx.persisted.save() {
+        ctx.notifications
+            .push(notifications::Notification::warning(&key));
+    } else {
+        ctx.notifications.push(
+            notifications::Notification::success("notification-layout-saved")
+                .with_translated_arg("name", slot_i18n_key(slot)),
+        );
+    }
+    Task::none()
+}
+
 /// Handles screen transitions.
 pub fn handle_screen_switch(ctx: &mut UpdateContext<'_>, target: Screen) -> Task<Message> {
     // Guard: cannot enter ImageEditor when metadata editor has unsaved changes
@@ -323,10 +977,10 @@ pub fn handle_screen_switch(ctx: &mut UpdateContext<'_>, target: Screen) -> Task
 
             // Synchronize media_navigator with viewer state before entering editor
             let (config, _) = config::load();
-            let sort_order = config.display.sort_order.unwrap_or_default();
+            let sort = config.display.media_sort();
             if ctx
                 .media_navigator
-                .scan_directory(&image_path, sort_order)
+                .scan_directory(&image_path, sort)
                 .is_err()
             {
                 ctx.notifications.push(notifications::Notification::warning(
@@ -335,7 +989,8 @@ pub fn handle_screen_switch(ctx: &mut UpdateContext<'_>, target: Screen) -> Task
             }
 
             match ImageEditorState::new(image_path, &image_data) {
-                Ok(state) => {
+                Ok(mut state) => {
+                    offer_journaled_edits(&mut state);
                     *ctx.image_editor = Some(state);
                     *ctx.screen = target;
                 }
@@ -359,9 +1014,25 @@ pub fn handle_screen_switch(ctx: &mut UpdateContext<'_>, target: Screen) -> Task
     }
 
     *ctx.screen = target;
+    if matches!(target, Screen::Settings) {
+        return list_audio_devices();
+    }
     Task::none()
 }
 
+/// Lists the audio output devices for the settings screen, off the UI thread
+/// since some audio backends are slow to enumerate.
+fn list_audio_devices() -> Task<Message> {
+    Task::perform(
+        async {
+            tokio::task::spawn_blocking(crate::video_player::audio_output::output_devices)
+                .await
+                .unwrap_or_default()
+        },
+        |devices| Message::Settings(settings::Message::AudioDevicesListed(devices)),
+    )
+}
+
 /// Handles settings component messages.
 #[allow(clippy::too_many_lines)]
 pub fn handle_settings_message(
@@ -379,26 +1050,82 @@ pub fn handle_settings_message(
             *ctx.screen = Screen::Viewer;
             persistence::persist_preferences(&mut ctx.preferences_context())
         }
-        SettingsEvent::LanguageSelected(locale) => {
-            persistence::apply_language_change(ctx.i18n, ctx.viewer, &locale, ctx.notifications)
-        }
+        SettingsEvent::LanguageSelected(locale) => persistence::apply_language_change(
+            ctx.i18n,
+            &locale,
+            ctx.notifications,
+            ctx.config_base,
+        ),
         SettingsEvent::ZoomStepChanged(value) => {
             ctx.viewer.set_zoom_step_percent(value);
             persistence::persist_preferences(&mut ctx.preferences_context())
         }
+        SettingsEvent::ToneMappingChanged(operator) => {
+            ctx.viewer.set_tone_mapping(operator);
+            persistence::persist_preferences(&mut ctx.preferences_context())
+        }
+        SettingsEvent::AudioDeviceChanged(id) => {
+            // Playing videos move to the new device on their next device check
+            crate::video_player::audio_output::set_preferred_device(id);
+            persistence::persist_preferences(&mut ctx.preferences_context())
+        }
         SettingsEvent::BackgroundThemeSelected(_)
-        | SettingsEvent::SortOrderSelected(_)
         | SettingsEvent::OverlayTimeoutChanged(_)
+        | SettingsEvent::FullscreenOverlayChanged(_)
+        | SettingsEvent::HardwareDecodingChanged(_)
+        | SettingsEvent::ResumePlaybackChanged(_)
         | SettingsEvent::FrameCacheMbChanged(_)
         | SettingsEvent::FrameHistoryMbChanged(_)
+        | SettingsEvent::FrameCacheAutoChanged(_)
+        | SettingsEvent::SandboxedDecodingChanged(_)
+        | SettingsEvent::WebpDecoderChanged(_)
+        | SettingsEvent::FrameNameTemplateChanged(_)
+        | SettingsEvent::SavePolicyChanged(_)
+        | SettingsEvent::EditedFolderChanged(_)
+        | SettingsEvent::GuidesChanged(_)
+        | SettingsEvent::CheckUpdatesWeeklyChanged(_)
         | SettingsEvent::DeblurModelUrlChanged(_)
-        | SettingsEvent::UpscaleModelUrlChanged(_) => {
+        | SettingsEvent::UpscaleModelUrlChanged(_)
+        | SettingsEvent::BackgroundModelUrlChanged(_)
+        | SettingsEvent::SimilarityThresholdChanged(_)
+        | SettingsEvent::ShortcutsChanged => {
+            persistence::persist_preferences(&mut ctx.preferences_context())
+        }
+        SettingsEvent::SortOrderSelected(_) | SettingsEvent::SortDirectionSelected(_) => {
+            // Re-sort the open directory now rather than on the next scan
+            if let Some(path) = ctx
+                .media_navigator
+                .current_media_path()
+                .map(std::path::Path::to_path_buf)
+            {
+                let sort = config::MediaSort::new(
+                    ctx.settings.sort_order(),
+                    ctx.settings.sort_direction(),
+                );
+                let _ = ctx.media_navigator.scan_directory(&path, sort);
+            }
             persistence::persist_preferences(&mut ctx.preferences_context())
         }
         SettingsEvent::ThemeModeSelected(mode) => {
             *ctx.theme_mode = mode;
             persistence::persist_preferences(&mut ctx.preferences_context())
         }
+        SettingsEvent::AccentColorChanged(_) | SettingsEvent::CustomThemeChanged(_) => {
+            // The theme is rebuilt from the settings state on the next render
+            persistence::persist_preferences(&mut ctx.preferences_context())
+        }
+        SettingsEvent::UiScaleChanged(percent) => {
+            // The windows pick the new scale factor up on the next render
+            crate::ui::state::zoom::set_interface_scale(percent);
+            persistence::persist_preferences(&mut ctx.preferences_context())
+        }
+        SettingsEvent::AccessibilityChanged(options) => {
+            accessibility::set(options);
+            persistence::persist_preferences(&mut ctx.preferences_context())
+        }
+        SettingsEvent::CustomPaletteChanged(palette) => {
+            persistence::save_custom_palette(&palette, ctx.notifications)
+        }
         SettingsEvent::VideoAutoplayChanged(enabled) => {
             *ctx.video_autoplay = enabled;
             ctx.viewer.set_video_autoplay(enabled);
@@ -413,11 +1140,31 @@ pub fn handle_settings_message(
                 .set_keyboard_seek_step(KeyboardSeekStep::new(step));
             persistence::persist_preferences(&mut ctx.preferences_context())
         }
+        SettingsEvent::RememberViewPerImageChanged(enabled) => {
+            ctx.viewer.set_remember_view(enabled);
+            persistence::persist_preferences(&mut ctx.preferences_context())
+        }
+        SettingsEvent::ShowMinimapChanged(enabled) => {
+            ctx.viewer.set_show_minimap(enabled);
+            persistence::persist_preferences(&mut ctx.preferences_context())
+        }
+        SettingsEvent::MouseMappingChanged(mapping) => {
+            ctx.viewer.set_mouse_mapping(mapping);
+            persistence::persist_preferences(&mut ctx.preferences_context())
+        }
+        SettingsEvent::SequenceFpsChanged(fps) => {
+            ctx.viewer.set_sequence_fps(SequenceFps::new(fps));
+            persistence::persist_preferences(&mut ctx.preferences_context())
+        }
         SettingsEvent::MaxSkipAttemptsChanged(attempts) => {
             ctx.viewer
                 .set_max_skip_attempts(MaxSkipAttempts::new(attempts));
             persistence::persist_preferences(&mut ctx.preferences_context())
         }
+        SettingsEvent::PrefetchCacheSizeChanged(size) => {
+            ctx.prefetch.set_capacity(size as usize);
+            persistence::persist_preferences(&mut ctx.preferences_context())
+        }
         // AI settings events
         SettingsEvent::RequestEnableDeblur => {
             use iced::futures::channel::{mpsc, oneshot};
@@ -631,23 +1378,218 @@ pub fn handle_settings_message(
             let _ = std::fs::remove_file(crate::media::upscale::get_model_path());
             Task::none()
         }
-        SettingsEvent::PersistFiltersChanged(_enabled) => {
-            // Setting is already updated in settings state, just persist to config
-            persistence::persist_preferences(&mut ctx.preferences_context())
-        }
-    }
-}
-
-/// Handles image editor component messages.
-pub fn handle_editor_message(
-    ctx: &mut UpdateContext<'_>,
-    message: image_editor::Message,
-) -> Task<Message> {
-    let Some(editor_state) = ctx.image_editor.as_mut() else {
-        return Task::none();
-    };
+        // AI Background removal settings events
+        SettingsEvent::RequestEnableBackgroundRemoval => {
+            use iced::futures::channel::{mpsc, oneshot};
+            use iced::futures::stream;
+            use iced::futures::StreamExt;
 
-    match editor_state.update(message) {
+            // Start the download/validation process
+            ctx.settings.set_background_model_status(
+                crate::media::background_removal::BackgroundModelStatus::Downloading {
+                    progress: 0.0,
+                },
+            );
+
+            let url = ctx.settings.background_model_url().to_string();
+
+            // Channels for progress and result
+            let (progress_tx, progress_rx) = mpsc::channel::<f32>(100);
+            let (result_tx, result_rx) = oneshot::channel::<Result<u64, String>>();
+
+            // Spawn the download task
+            let url_clone = url.clone();
+            tokio::spawn(async move {
+                let mut progress_tx = progress_tx;
+                let download_result =
+                    crate::media::background_removal::download_model(&url_clone, |progress| {
+                        let _ = progress_tx.try_send(progress);
+                    })
+                    .await;
+
+                let _ = result_tx.send(download_result.map_err(|e| e.to_string()));
+            });
+
+            // State for the stream
+            #[allow(clippy::items_after_statements)]
+            enum BackgroundDownloadPhase {
+                ReceivingProgress {
+                    progress_rx: mpsc::Receiver<f32>,
+                    result_rx: oneshot::Receiver<Result<u64, String>>,
+                },
+                WaitingForResult {
+                    result_rx: oneshot::Receiver<Result<u64, String>>,
+                },
+                Completed,
+            }
+
+            let download_stream = stream::unfold(
+                BackgroundDownloadPhase::ReceivingProgress {
+                    progress_rx,
+                    result_rx,
+                },
+                |phase| async move {
+                    match phase {
+                        BackgroundDownloadPhase::ReceivingProgress {
+                            mut progress_rx,
+                            result_rx,
+                        } => match progress_rx.next().await {
+                            Some(progress) => Some((
+                                Message::BackgroundDownloadProgress(progress),
+                                BackgroundDownloadPhase::ReceivingProgress {
+                                    progress_rx,
+                                    result_rx,
+                                },
+                            )),
+                            None => Some((
+                                Message::BackgroundDownloadProgress(1.0),
+                                BackgroundDownloadPhase::WaitingForResult { result_rx },
+                            )),
+                        },
+                        BackgroundDownloadPhase::WaitingForResult { result_rx } => {
+                            match result_rx.await {
+                                Ok(Ok(_bytes)) => Some((
+                                    Message::BackgroundDownloadCompleted(Ok(())),
+                                    BackgroundDownloadPhase::Completed,
+                                )),
+                                Ok(Err(e)) => Some((
+                                    Message::BackgroundDownloadCompleted(Err(e)),
+                                    BackgroundDownloadPhase::Completed,
+                                )),
+                                Err(_) => Some((
+                                    Message::BackgroundDownloadCompleted(Err(
+                                        "Download task cancelled".to_string(),
+                                    )),
+                                    BackgroundDownloadPhase::Completed,
+                                )),
+                            }
+                        }
+                        BackgroundDownloadPhase::Completed => None,
+                    }
+                },
+            );
+
+            Task::stream(download_stream)
+        }
+        SettingsEvent::DisableBackgroundRemoval => {
+            ctx.persisted.enable_background_removal = false;
+            if let Some(key) = ctx.persisted.save() {
+                ctx.notifications
+                    .push(notifications::Notification::warning(&key));
+            }
+            let _ = std::fs::remove_file(crate::media::background_removal::get_model_path());
+            Task::none()
+        }
+        SettingsEvent::PersistFiltersChanged(_enabled) => {
+            // Setting is already updated in settings state, just persist to config
+            persistence::persist_preferences(&mut ctx.preferences_context())
+        }
+        SettingsEvent::PluginToggled { id, enabled } => {
+            ctx.plugins.set_enabled(&id, enabled);
+            persistence::persist_preferences(&mut ctx.preferences_context())
+        }
+        SettingsEvent::OpenPluginsFolder => {
+            let opened = match ctx.plugins.dir() {
+                Some(dir) => {
+                    std::fs::create_dir_all(dir).and_then(|()| open_url(&dir.to_string_lossy()))
+                }
+                None => Err(std::io::ErrorKind::NotFound.into()),
+            };
+            if let Err(error) = opened {
+                ctx.notifications.push(
+                    notifications::Notification::error("notification-plugins-folder-error")
+                        .with_arg("error", error.to_string()),
+                );
+            }
+            Task::none()
+        }
+        SettingsEvent::ReloadPlugins => {
+            *ctx.plugins = plugins::Registry::load(&ctx.settings.disabled_plugins());
+            ctx.settings.set_plugins(plugin_entries(ctx.plugins));
+            ctx.notifications.push(
+                notifications::Notification::info("notification-plugins-reloaded")
+                    .with_arg("count", ctx.plugins.plugins().len().to_string()),
+            );
+            Task::none()
+        }
+        SettingsEvent::ImportSettings => Task::perform(
+            async {
+                rfd::AsyncFileDialog::new()
+                    .add_filter("TOML", &["toml"])
+                    .pick_file()
+                    .await
+                    .map(|handle| handle.path().to_path_buf())
+            },
+            Message::SettingsImportDialogResult,
+        ),
+        #[cfg(feature = "update-check")]
+        SettingsEvent::CheckForUpdates => check_for_updates(true),
+        #[cfg(not(feature = "update-check"))]
+        SettingsEvent::CheckForUpdates => Task::none(),
+        SettingsEvent::ExportSettings => Task::perform(
+            async {
+                rfd::AsyncFileDialog::new()
+                    .add_filter("TOML", &["toml"])
+                    .set_file_name(config::EXPORT_FILE_NAME)
+                    .save_file()
+                    .await
+                    .map(|handle| handle.path().to_path_buf())
+            },
+            Message::SettingsExportDialogResult,
+        ),
+    }
+}
+
+/// Describes the installed plugins for the settings screen.
+pub fn plugin_entries(registry: &plugins::Registry) -> Vec<settings::PluginEntry> {
+    registry
+        .plugins()
+        .iter()
+        .map(|plugin| match &plugin.manifest {
+            Ok(manifest) => {
+                let mut hooks = Vec::new();
+                if manifest.on_media_loaded.is_some() {
+                    hooks.push("settings-plugins-hook-media-loaded");
+                }
+                if !manifest.editor_tools.is_empty() {
+                    hooks.push("settings-plugins-hook-editor-tool");
+                }
+                if !manifest.export_formats.is_empty() {
+                    hooks.push("settings-plugins-hook-export-format");
+                }
+                settings::PluginEntry {
+                    id: plugin.id.clone(),
+                    name: manifest.name.clone(),
+                    version: manifest.version.clone(),
+                    description: manifest.description.clone(),
+                    hooks,
+                    error: None,
+                    enabled: plugin.enabled,
+                }
+            }
+            Err(error) => settings::PluginEntry {
+                id: plugin.id.clone(),
+                name: plugin.id.clone(),
+                version: None,
+                description: None,
+                hooks: Vec::new(),
+                error: Some(error.clone()),
+                enabled: plugin.enabled,
+            },
+        })
+        .collect()
+}
+
+/// Handles image editor component messages.
+pub fn handle_editor_message(
+    ctx: &mut UpdateContext<'_>,
+    message: image_editor::Message,
+) -> Task<Message> {
+    let Some(editor_state) = ctx.image_editor.as_mut() else {
+        return Task::none();
+    };
+
+    match editor_state.update(message) {
         ImageEditorEvent::None => Task::none(),
         ImageEditorEvent::ExitEditor => {
             // Get the image source before dropping the editor
@@ -664,8 +1606,9 @@ pub fn handle_editor_message(
                     ctx.viewer.start_loading();
 
                     // Reload the image in the viewer to show any saved changes
+                    let load_options = ctx.settings.load_options();
                     Task::perform(
-                        async move { media::load_media(&current_media_path) },
+                        async move { media::load_media_with(&current_media_path, load_options) },
                         |result| Message::Viewer(component::Message::MediaLoaded(result)),
                     )
                 }
@@ -678,37 +1621,49 @@ pub fn handle_editor_message(
         ImageEditorEvent::NavigateNext => handle_editor_navigate_next(ctx),
         ImageEditorEvent::NavigatePrevious => handle_editor_navigate_previous(ctx),
         ImageEditorEvent::SaveRequested { path, overwrite: _ } => {
-            // Save the edited image
-            if let Some(editor) = ctx.image_editor.as_mut() {
-                match editor.save_image(&path) {
-                    Ok(()) => {
-                        ctx.notifications.push(notifications::Notification::success(
-                            "notification-save-success",
-                        ));
-                    }
-                    Err(_err) => {
-                        ctx.notifications.push(notifications::Notification::error(
-                            "notification-save-error",
-                        ));
-                    }
-                }
-            }
+            handle_editor_save(ctx, &path);
             Task::none()
         }
         ImageEditorEvent::SaveAsRequested => {
             let editor_state = ctx.image_editor.as_ref().expect("editor state exists");
             let last_dir = ctx.persisted.last_save_directory.clone();
-            handle_save_as_dialog(editor_state, last_dir)
+            handle_save_as_dialog(ctx.i18n, editor_state, last_dir, ctx.settings)
         }
         ImageEditorEvent::DeblurRequested => handle_deblur_request(ctx),
+        ImageEditorEvent::BackgroundRemovalRequested => handle_background_removal_request(ctx),
+        ImageEditorEvent::DenoiseRequested(params) => handle_denoise_request(ctx, params),
+        ImageEditorEvent::PluginToolRequested(index) => handle_plugin_tool_request(ctx, index),
+        ImageEditorEvent::PluginExportRequested(index) => handle_plugin_export_request(ctx, index),
         ImageEditorEvent::DeblurCancelRequested => {
             // Cancel is handled by the editor state itself (sets cancel_requested flag)
             // The actual inference task will check this flag and stop
             Task::none()
         }
+        ImageEditorEvent::FaceDetectionRequested => handle_editor_face_detection(ctx),
         ImageEditorEvent::UpscaleResizeRequested { width, height } => {
             handle_upscale_resize_request(ctx, width, height)
         }
+        ImageEditorEvent::AdjustmentsCopied(recipe) => {
+            let count = recipe.len().to_string();
+            if let Err(err) = super::edit_journal::write_recipe(&recipe, None) {
+                tracing::warn!("Failed to save the copied edit recipe: {err}");
+            }
+            *ctx.edit_clipboard = Some(recipe);
+            ctx.notifications.push(
+                notifications::Notification::info("notification-adjustments-copied")
+                    .with_arg("count", count),
+            );
+            Task::none()
+        }
+        ImageEditorEvent::PasteAdjustmentsRequested => {
+            if let (Some(editor), Some(recipe)) =
+                (ctx.image_editor.as_mut(), ctx.edit_clipboard.as_ref())
+            {
+                editor.paste_adjustments(recipe);
+            }
+            Task::none()
+        }
+        ImageEditorEvent::PasteAdjustmentsToFolderRequested => handle_recipe_batch_request(ctx),
         ImageEditorEvent::ScrollTo { x, y } => {
             use iced::widget::scrollable::RelativeOffset;
             use iced::widget::{operation, Id};
@@ -747,6 +1702,208 @@ fn handle_deblur_request(ctx: &mut UpdateContext<'_>) -> Task<Message> {
     )
 }
 
+/// Computes the foreground mask of the image in the editor in the background.
+fn handle_background_removal_request(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    let Some(editor_state) = ctx.image_editor.as_ref() else {
+        return Task::none();
+    };
+    let working_image = editor_state.working_image().clone();
+
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                let mut manager = crate::media::background_removal::BackgroundRemovalManager::new();
+                manager.load_session(None)?;
+                manager.segment(&working_image)
+            })
+            .await
+            .map_err(|e| {
+                crate::media::background_removal::BackgroundRemovalError::InferenceFailed(
+                    e.to_string(),
+                )
+            })?
+        },
+        |result: crate::media::background_removal::BackgroundRemovalResult<image_rs::GrayImage>| {
+            Message::BackgroundRemovalCompleted(result.map(Box::new).map_err(|e| e.to_string()))
+        },
+    )
+}
+
+/// Denoises the current image at full resolution in the background.
+///
+/// The denoising model is downloaded on first use.
+fn handle_denoise_request(
+    ctx: &mut UpdateContext<'_>,
+    params: media::denoise::DenoiseParams,
+) -> Task<Message> {
+    let Some(editor_state) = ctx.image_editor.as_mut() else {
+        return Task::none();
+    };
+    let working_image = editor_state.working_image().clone();
+    let model_url = ctx.config_base.ai.denoise_model_url.clone();
+    if params.method == media::denoise::DenoiseMethod::Model {
+        if model_url.is_none() {
+            editor_state.denoise_failed();
+            return Task::none();
+        }
+        if !media::denoise::is_model_downloaded() {
+            ctx.notifications.push(notifications::Notification::info(
+                "notification-denoise-model-downloading",
+            ));
+        }
+    }
+
+    Task::perform(
+        async move { denoise_image(working_image, params, model_url).await },
+        |result: media::denoise::DenoiseResult<image_rs::DynamicImage>| {
+            Message::DenoiseCompleted(result.map(Box::new).map_err(|e| e.to_string()))
+        },
+    )
+}
+
+/// Denoises `image` with the classical filters or the model, downloading
+/// the model first if needed.
+async fn denoise_image(
+    image: image_rs::DynamicImage,
+    params: media::denoise::DenoiseParams,
+    model_url: Option<String>,
+) -> media::denoise::DenoiseResult<image_rs::DynamicImage> {
+    let use_model = params.method == media::denoise::DenoiseMethod::Model;
+    if use_model && !media::denoise::is_model_downloaded() {
+        let url = model_url.ok_or(media::denoise::DenoiseError::ModelNotConfigured)?;
+        media::denoise::download_model(&url).await?;
+    }
+    // Use spawn_blocking: the filters and the ONNX inference are CPU-bound
+    tokio::task::spawn_blocking(move || {
+        if use_model {
+            media::denoise::DenoiseModel::load()?.denoise(&image)
+        } else {
+            Ok(media::denoise::denoise(&image, &params))
+        }
+    })
+    .await
+    .map_err(|e| media::denoise::DenoiseError::InferenceFailed(e.to_string()))?
+}
+
+/// Applies the denoised image, or reports why denoising failed.
+pub fn handle_denoise_completed(
+    ctx: &mut UpdateContext<'_>,
+    result: Result<Box<image_rs::DynamicImage>, String>,
+) -> Task<Message> {
+    let Some(editor_state) = ctx.image_editor.as_mut() else {
+        return Task::none();
+    };
+    match result {
+        Ok(image) => editor_state.apply_denoise_result(*image),
+        Err(error) => {
+            editor_state.denoise_failed();
+            ctx.notifications.push(
+                notifications::Notification::error("notification-denoise-error")
+                    .with_arg("error", error),
+            );
+        }
+    }
+    Task::none()
+}
+
+/// Runs a plugin editor tool on the current image in the background.
+fn handle_plugin_tool_request(ctx: &mut UpdateContext<'_>, index: usize) -> Task<Message> {
+    let Some(editor_state) = ctx.image_editor.as_mut() else {
+        return Task::none();
+    };
+    let Some((plugin_dir, tool)) = ctx.plugins.editor_tool(index) else {
+        editor_state.plugin_tool_failed();
+        return Task::none();
+    };
+    let working_image = editor_state.working_image().clone();
+
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                plugins::run_editor_tool(&plugin_dir, &tool, &working_image).map(Box::new)
+            })
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()))
+        },
+        Message::PluginToolCompleted,
+    )
+}
+
+/// Applies the image written by a plugin editor tool.
+pub fn handle_plugin_tool_completed(
+    ctx: &mut UpdateContext<'_>,
+    result: Result<Box<image_rs::DynamicImage>, String>,
+) -> Task<Message> {
+    let Some(editor_state) = ctx.image_editor.as_mut() else {
+        return Task::none();
+    };
+    match result {
+        Ok(image) => editor_state.apply_plugin_tool_result(*image),
+        Err(error) => {
+            editor_state.plugin_tool_failed();
+            ctx.notifications.push(
+                notifications::Notification::error("notification-plugin-tool-error")
+                    .with_arg("error", error),
+            );
+        }
+    }
+    Task::none()
+}
+
+/// Asks where to export the current image, then writes it with a plugin
+/// export format in the background.
+fn handle_plugin_export_request(ctx: &mut UpdateContext<'_>, index: usize) -> Task<Message> {
+    let Some(editor_state) = ctx.image_editor.as_ref() else {
+        return Task::none();
+    };
+    let Some((plugin_dir, format)) = ctx.plugins.export_format(index) else {
+        return Task::none();
+    };
+    let stem = editor_state
+        .image_path()
+        .and_then(Path::file_stem)
+        .map_or_else(|| "image".into(), |stem| stem.to_string_lossy());
+    let filename = format!("{stem}.{}", format.extension);
+    let image = editor_state.working_image().clone();
+
+    ask_save_path(ctx, filename).then(move |output| match output {
+        Some(output) => {
+            let (plugin_dir, format, image) = (plugin_dir.clone(), format.clone(), image.clone());
+            Task::perform(
+                async move {
+                    tokio::task::spawn_blocking(move || {
+                        plugins::run_export(&plugin_dir, &format, &image, &output).map(|()| output)
+                    })
+                    .await
+                    .unwrap_or_else(|e| Err(e.to_string()))
+                },
+                Message::PluginExportCompleted,
+            )
+        }
+        None => Task::none(),
+    })
+}
+
+/// Reports the result of a plugin export.
+pub fn handle_plugin_export_completed(
+    ctx: &mut UpdateContext<'_>,
+    result: Result<PathBuf, String>,
+) -> Task<Message> {
+    let notification = match result {
+        Ok(path) => notifications::Notification::success("notification-plugin-export-success")
+            .with_arg(
+                "filename",
+                path.file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+            ),
+        Err(error) => notifications::Notification::error("notification-plugin-export-error")
+            .with_arg("error", error),
+    };
+    ctx.notifications.push(notification);
+    Task::none()
+}
+
 /// Handles resize request that may use AI upscaling.
 /// - If AI upscaling is enabled and model is ready: run async AI inference
 /// - Otherwise: fall back to standard Lanczos resize
@@ -795,22 +1952,75 @@ fn handle_upscale_resize_request(
     }
 }
 
+/// Saves the edited image of `path` where the save policy says: over it, as
+/// a numbered copy next to it, or into the edited subfolder.
+fn handle_editor_save(ctx: &mut UpdateContext<'_>, path: &Path) {
+    use crate::media::naming;
+
+    let Some(editor) = ctx.image_editor.as_mut() else {
+        return;
+    };
+    let policy = ctx.settings.save_policy();
+    let target = naming::save_target(policy, path, ctx.settings.edited_folder());
+    if policy == config::SavePolicy::Subfolder {
+        if let Some(folder) = target.parent() {
+            if let Err(err) = std::fs::create_dir_all(folder) {
+                tracing::warn!("Failed to create {}: {err}", folder.display());
+                ctx.notifications.push(notifications::Notification::error(
+                    "notification-save-error",
+                ));
+                return;
+            }
+        }
+    }
+
+    match editor.save_image(&target) {
+        Ok(()) if target == path => {
+            ctx.notifications.push(notifications::Notification::success(
+                "notification-save-success",
+            ));
+        }
+        Ok(()) => {
+            let file_name = target
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            ctx.notifications.push(
+                notifications::Notification::success("notification-save-copy-success")
+                    .with_arg("file", file_name),
+            );
+            persistence::rescan_directory_if_same(ctx.media_navigator, &target);
+        }
+        Err(_err) => {
+            ctx.notifications.push(notifications::Notification::error(
+                "notification-save-error",
+            ));
+        }
+    }
+}
+
 /// Handles Save As dialog request.
+///
+/// All save formats are offered, with the selected export format preselected.
+/// Captured frames start in the folder the save policy points to, under a
+/// name that doesn't replace an existing file, unless the policy overwrites.
 fn handle_save_as_dialog(
+    i18n: &I18n,
     editor_state: &ImageEditorState,
     last_save_directory: Option<PathBuf>,
+    settings: &SettingsState,
 ) -> Task<Message> {
-    use crate::media::frame_export::{generate_default_filename, ExportFormat};
+    use crate::media::frame_export::generate_filename;
+    use crate::media::naming::{self, NamingContext};
 
     let image_source = editor_state.image_source().clone();
     let export_format = editor_state.export_format();
 
-    // Get filter based on selected export format
-    let (filter_name, filter_ext): (&str, Vec<&str>) = match export_format {
-        ExportFormat::Png => ("PNG Image", vec!["png"]),
-        ExportFormat::Jpeg => ("JPEG Image", vec!["jpg", "jpeg"]),
-        ExportFormat::WebP => ("WebP Image", vec!["webp"]),
-    };
+    let filters = file_dialog::save_filters(
+        i18n,
+        crate::media::extensions::IMAGE_SAVE_FILTERS,
+        Some(export_format.extension()),
+    );
 
     // Generate filename based on image source, with selected format extension
     let filename = match &image_source {
@@ -822,17 +2032,51 @@ fn handle_save_as_dialog(
         image_editor::ImageSource::CapturedFrame {
             video_path,
             position_secs,
-        } => generate_default_filename(video_path, *position_secs, export_format),
+        } => {
+            let image = editor_state.working_image();
+            let naming = NamingContext::new(video_path)
+                .with_timestamp(*position_secs)
+                .with_dimensions(image.width(), image.height());
+            generate_filename(settings.frame_name_template(), &naming, export_format)
+        }
+    };
+
+    let policy_directory = match (&image_source, settings.save_policy()) {
+        (image_editor::ImageSource::File(_), _) | (_, config::SavePolicy::Overwrite) => None,
+        (image_editor::ImageSource::CapturedFrame { video_path, .. }, config::SavePolicy::Copy) => {
+            video_path.parent().map(Path::to_path_buf)
+        }
+        (
+            image_editor::ImageSource::CapturedFrame { video_path, .. },
+            config::SavePolicy::Subfolder,
+        ) => {
+            let folder = naming::edited_folder_of(video_path, settings.edited_folder());
+            // The dialog can only open a folder that exists
+            if let Err(err) = std::fs::create_dir_all(&folder) {
+                tracing::warn!("Failed to create {}: {err}", folder.display());
+            }
+            Some(folder)
+        }
     };
+    let filename = policy_directory
+        .as_ref()
+        .and_then(|dir| {
+            naming::unique_path(&dir.join(&filename))
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+        })
+        .unwrap_or(filename);
+    let initial_directory = policy_directory.or(last_save_directory);
 
     Task::perform(
         async move {
-            let mut dialog = rfd::AsyncFileDialog::new()
-                .set_file_name(&filename)
-                .add_filter(filter_name, &filter_ext);
+            let mut dialog = file_dialog::with_filters(
+                rfd::AsyncFileDialog::new().set_file_name(&filename),
+                &filters,
+            );
 
-            // Use last save directory if available
-            if let Some(dir) = last_save_directory {
+            // Use the policy folder or the last save directory if available
+            if let Some(dir) = initial_directory {
                 if dir.exists() {
                     dialog = dialog.set_directory(&dir);
                 }
@@ -888,11 +2132,23 @@ pub fn handle_navbar_message(
             *ctx.screen = Screen::About;
             Task::none()
         }
+        NavbarEvent::OpenInNewWindow => {
+            pick_media_file(ctx.i18n, ctx.persisted).map(Message::OpenInNewWindowDialogResult)
+        }
+        NavbarEvent::VerifyFolder => handle_verify_folder(ctx),
+        NavbarEvent::ShareOverLan => handle_share_over_lan(ctx),
+        NavbarEvent::StopSharing => handle_stop_sharing(ctx),
+        NavbarEvent::StripMetadata => handle_strip_metadata_request(ctx),
+        NavbarEvent::OpenWith(index) => handle_open_with(ctx, index),
         NavbarEvent::EnterEditor => handle_screen_switch(ctx, Screen::ImageEditor),
         NavbarEvent::ToggleInfoPanel => {
             *ctx.info_panel_open = !*ctx.info_panel_open;
             Task::none()
         }
+        NavbarEvent::ToggleAlwaysOnTop => handle_toggle_always_on_top(ctx),
+        NavbarEvent::ToggleBorderless => handle_toggle_borderless(ctx),
+        NavbarEvent::ToggleCompactMode => handle_toggle_compact_mode(ctx),
+        NavbarEvent::DragWindow => ctx.window_id.map_or_else(Task::none, window::drag),
         NavbarEvent::FilterChanged(filter_msg) => {
             // Route filter messages: local ones to viewer, filter changes to handler
             match filter_msg {
@@ -919,6 +2175,50 @@ pub fn handle_navbar_message(
     }
 }
 
+/// Runs an "Open with / Send to" command on the current file in the background.
+fn handle_open_with(ctx: &mut UpdateContext<'_>, index: usize) -> Task<Message> {
+    let (Some(command), Some(path)) = (
+        ctx.open_with.get(index).cloned(),
+        ctx.media_navigator
+            .current_media_path()
+            .map(Path::to_path_buf),
+    ) else {
+        return Task::none();
+    };
+    let name = command.name.clone();
+
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || open_with::run(&command, &path))
+                .await
+                .unwrap_or_else(|e| Err(e.to_string()))
+        },
+        move |result| Message::OpenWithCompleted { name, result },
+    )
+}
+
+/// Shows what an "Open with / Send to" command printed.
+pub fn handle_open_with_completed(
+    ctx: &mut UpdateContext<'_>,
+    name: String,
+    result: Result<String, String>,
+) -> Task<Message> {
+    let notification = match result {
+        Ok(output) if output.is_empty() => {
+            notifications::Notification::success("notification-open-with-done")
+                .with_arg("name", name)
+        }
+        Ok(output) => notifications::Notification::info("notification-open-with-output")
+            .with_arg("name", name)
+            .with_arg("output", output),
+        Err(error) => notifications::Notification::error("notification-open-with-error")
+            .with_arg("name", name)
+            .with_arg("error", error),
+    };
+    ctx.notifications.push(notification);
+    Task::none()
+}
+
 /// Handles help screen messages.
 pub fn handle_help_message(ctx: &mut UpdateContext<'_>, message: help::Message) -> Task<Message> {
     match help::update(ctx.help_state, message) {
@@ -941,7 +2241,198 @@ pub fn handle_about_message(
             *ctx.screen = Screen::Viewer;
             Task::none()
         }
+        AboutEvent::ViewLogs => {
+            *ctx.logs = logs::State::load();
+            *ctx.screen = Screen::Logs;
+            Task::none()
+        }
+        AboutEvent::ViewMissingTranslations => {
+            *ctx.screen = Screen::Translations;
+            Task::none()
+        }
+        AboutEvent::CopyDiagnosticReport => copy_diagnostic_report(),
+    }
+}
+
+/// Handles log viewer messages.
+pub fn handle_logs_message(ctx: &mut UpdateContext<'_>, message: &logs::Message) -> Task<Message> {
+    match logs::update(message) {
+        LogsEvent::BackToViewer => {
+            *ctx.screen = Screen::Viewer;
+            Task::none()
+        }
+        LogsEvent::Refresh => {
+            *ctx.logs = logs::State::load();
+            Task::none()
+        }
+        LogsEvent::OpenFolder => {
+            let opened = ctx.logs.dir().map(|dir| {
+                std::fs::create_dir_all(dir).and_then(|()| open_url(&dir.to_string_lossy()))
+            });
+            if let Some(Err(error)) = opened {
+                ctx.notifications.push(
+                    notifications::Notification::error("notification-logs-folder-error")
+                        .with_arg("error", error.to_string()),
+                );
+            }
+            Task::none()
+        }
+        LogsEvent::CopyDiagnosticReport => copy_diagnostic_report(),
+    }
+}
+
+/// Handles missing translations report messages.
+pub fn handle_translations_message(
+    ctx: &mut UpdateContext<'_>,
+    message: &translations::Message,
+) -> Task<Message> {
+    match translations::update(message) {
+        TranslationsEvent::BackToViewer => {
+            *ctx.screen = Screen::Viewer;
+            Task::none()
+        }
+        TranslationsEvent::CopyKeys => {
+            iced::clipboard::write(ctx.i18n.missing_translations().join("\n"))
+        }
+    }
+}
+
+/// Handles welcome screen messages. Language and theme go through the
+/// settings so they are saved like any other preference.
+pub fn handle_welcome_message(
+    ctx: &mut UpdateContext<'_>,
+    message: welcome::Message,
+) -> Task<Message> {
+    match ctx.welcome.update(message, ctx.settings.keymap()) {
+        WelcomeEvent::None => Task::none(),
+        WelcomeEvent::LanguageSelected(locale) => {
+            handle_settings_message(ctx, settings::Message::LanguageSelected(locale))
+        }
+        WelcomeEvent::ThemeModeSelected(mode) => {
+            handle_settings_message(ctx, settings::Message::ThemeModeSelected(mode))
+        }
+        WelcomeEvent::SetDefaultViewer => Task::perform(
+            async {
+                tokio::task::spawn_blocking(crate::app::file_associations::register_default_viewer)
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .map_err(|e| e.to_string())
+            },
+            Message::DefaultViewerRegistered,
+        ),
+        WelcomeEvent::Finished => {
+            ctx.persisted.welcome_done = true;
+            if let Some(key) = ctx.persisted.save() {
+                ctx.notifications
+                    .push(notifications::Notification::warning(&key));
+            }
+            *ctx.screen = Screen::Viewer;
+            Task::none()
+        }
+    }
+}
+
+/// Offers to restore the edits journaled for the image of `editor` by a
+/// previous session, if any.
+pub fn offer_journaled_edits(editor: &mut ImageEditorState) {
+    if let Some(edits) = editor
+        .image_path()
+        .and_then(|path| super::edit_journal::read(path, None))
+    {
+        editor.offer_recovery(edits);
+    }
+}
+
+/// Builds the diagnostic report in the background, to be copied to the
+/// clipboard once ready.
+fn copy_diagnostic_report() -> Task<Message> {
+    Task::perform(
+        async {
+            tokio::task::spawn_blocking(super::logging::diagnostic_report)
+                .await
+                .unwrap_or_default()
+        },
+        Message::DiagnosticReportReady,
+    )
+}
+
+/// Handles notification messages, opening the links of the toasts.
+pub fn handle_notification_message(
+    ctx: &mut UpdateContext<'_>,
+    message: &notifications::NotificationMessage,
+) -> Task<Message> {
+    if let notifications::NotificationMessage::OpenLink(url) = message {
+        if let Err(error) = open_url(url) {
+            ctx.notifications.push(
+                notifications::Notification::error("notification-open-url-error")
+                    .with_arg("error", error.to_string()),
+            );
+        }
+    }
+    ctx.notifications.handle_message(message);
+    Task::none()
+}
+
+/// Starts an update check; `manual` when it was asked from the settings.
+#[cfg(feature = "update-check")]
+fn check_for_updates(manual: bool) -> Task<Message> {
+    Task::perform(update_check::check(), move |result| {
+        Message::UpdateCheckCompleted { result, manual }
+    })
+}
+
+/// Runs the weekly update check if it is turned on and due.
+#[cfg(feature = "update-check")]
+pub fn handle_update_check_due(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    let due = update_check::is_due(ctx.persisted.last_update_check, SystemTime::now());
+    if ctx.settings.check_updates_weekly() && due {
+        check_for_updates(false)
+    } else {
+        Task::none()
+    }
+}
+
+/// Shows the result of an update check. Failed background checks are only
+/// logged, and retried on the next tick.
+#[cfg(feature = "update-check")]
+pub fn handle_update_check_completed(
+    ctx: &mut UpdateContext<'_>,
+    result: Result<Option<update_check::Release>, String>,
+    manual: bool,
+) -> Task<Message> {
+    match result {
+        Ok(release) => {
+            ctx.persisted.last_update_check = Some(SystemTime::now());
+            if let Some(key) = ctx.persisted.save() {
+                ctx.notifications
+                    .push(notifications::Notification::warning(&key));
+            }
+            if let Some(release) = release {
+                ctx.notifications.push(
+                    notifications::Notification::info("notification-update-available")
+                        .with_arg("version", release.version)
+                        .with_arg("notes", release.notes)
+                        .with_link("notification-update-download", release.url)
+                        .auto_dismiss(UPDATE_NOTIFICATION_DURATION),
+                );
+            } else if manual {
+                ctx.notifications.push(
+                    notifications::Notification::success("notification-update-none")
+                        .with_arg("version", env!("CARGO_PKG_VERSION")),
+                );
+            }
+        }
+        Err(error) => {
+            tracing::warn!("Update check failed: {error}");
+            if manual {
+                ctx.notifications.push(
+                    notifications::Notification::error("notification-update-check-error")
+                        .with_arg("error", error),
+                );
+            }
+        }
     }
+    Task::none()
 }
 
 /// Handles metadata panel messages.
@@ -991,79 +2482,720 @@ pub fn handle_metadata_panel_message(
                     return Task::none();
                 }
 
-                // Write metadata using little_exif
-                match crate::media::metadata_writer::write_exif(
-                    &path,
-                    editor_state.editable_metadata(),
-                ) {
-                    Ok(()) => {
-                        // Refresh metadata display
-                        *ctx.current_metadata = crate::media::metadata::extract_metadata(&path);
+                // Write metadata using little_exif
+                match crate::media::metadata_writer::write_exif(
+                    &path,
+                    editor_state.editable_metadata(),
+                ) {
+                    Ok(()) => {
+                        // Refresh metadata display
+                        *ctx.current_metadata = crate::media::metadata::extract_metadata(&path);
+
+                        // Exit edit mode
+                        *ctx.metadata_editor_state = None;
+
+                        // Show success notification
+                        ctx.notifications.push(notifications::Notification::success(
+                            "notification-metadata-save-success",
+                        ));
+                    }
+                    Err(_e) => {
+                        // Show error notification
+                        ctx.notifications.push(notifications::Notification::error(
+                            "notification-metadata-save-error",
+                        ));
+                    }
+                }
+            }
+            Task::none()
+        }
+        MetadataPanelEvent::SaveAsRequested => {
+            // Validate all fields before showing dialog
+            if let Some(editor_state) = ctx.metadata_editor_state.as_mut() {
+                if !editor_state.validate_all() {
+                    ctx.notifications.push(notifications::Notification::error(
+                        "notification-metadata-validation-error",
+                    ));
+                    return Task::none();
+                }
+            }
+
+            // Open Save As dialog, preselecting the current file's format
+            let current_extension = ctx
+                .media_navigator
+                .current_media_path()
+                .and_then(|path| path.extension())
+                .and_then(|ext| ext.to_str());
+            let filters = file_dialog::save_filters(
+                ctx.i18n,
+                crate::media::extensions::IMAGE_SAVE_FILTERS,
+                current_extension,
+            );
+            let dialog = file_dialog::with_filters(
+                rfd::AsyncFileDialog::new().set_title("Save Image As"),
+                &filters,
+            );
+
+            // Set initial directory from app state
+            let dialog = if let Some(dir) = ctx.persisted.last_save_directory.as_ref() {
+                dialog.set_directory(dir)
+            } else {
+                dialog
+            };
+
+            // Set initial filename from current path (use media_navigator as source of truth)
+            let dialog = if let Some(path) = ctx.media_navigator.current_media_path() {
+                if let Some(filename) = path.file_name().and_then(|n| n.to_str()) {
+                    dialog.set_file_name(filename)
+                } else {
+                    dialog
+                }
+            } else {
+                dialog
+            };
+
+            Task::perform(
+                async move {
+                    dialog
+                        .save_file()
+                        .await
+                        .map(|handle| handle.path().to_path_buf())
+                },
+                Message::MetadataSaveAsDialogResult,
+            )
+        }
+        MetadataPanelEvent::AllTagsToggled => {
+            ctx.metadata_tag_browser.expanded = !ctx.metadata_tag_browser.expanded;
+            Task::none()
+        }
+        MetadataPanelEvent::TagFilterChanged(filter) => {
+            ctx.metadata_tag_browser.filter = filter;
+            Task::none()
+        }
+        MetadataPanelEvent::CopyToClipboard(value) => {
+            ctx.notifications.push(notifications::Notification::info(
+                "notification-copied-to-clipboard",
+            ));
+            iced::clipboard::write(value)
+        }
+        MetadataPanelEvent::Batch(message) => {
+            if !ctx.metadata_batch.update(message) {
+                return Task::none();
+            }
+            let changes = ctx.metadata_batch.changes();
+            let paths = ctx.media_navigator.filtered_media_paths();
+            ctx.notifications.push(
+                notifications::Notification::info("notification-metadata-batch-started")
+                    .with_arg("count", paths.len().to_string()),
+            );
+            Task::perform(
+                async move {
+                    tokio::task::spawn_blocking(move || {
+                        crate::media::metadata_batch::apply_to_files(&paths, &changes)
+                    })
+                    .await
+                    .unwrap_or_default()
+                },
+                Message::MetadataBatchCompleted,
+            )
+        }
+        MetadataPanelEvent::ShootStatsToggled => {
+            ctx.shoot_stats.expanded = !ctx.shoot_stats.expanded;
+            match current_directory(ctx) {
+                Some(directory)
+                    if ctx.shoot_stats.expanded && ctx.shoot_stats.is_stale(&directory) =>
+                {
+                    start_shoot_stats(ctx, directory)
+                }
+                _ => Task::none(),
+            }
+        }
+        MetadataPanelEvent::ShootStatsRefreshRequested => match current_directory(ctx) {
+            Some(directory) => start_shoot_stats(ctx, directory),
+            None => Task::none(),
+        },
+        MetadataPanelEvent::StripMetadataRequested => handle_strip_metadata_request(ctx),
+        MetadataPanelEvent::ExportMetadataRequested(scope) => handle_export_metadata(ctx, scope),
+        MetadataPanelEvent::OpenUrlRequested(url) => {
+            if let Err(error) = open_url(&url) {
+                ctx.notifications.push(
+                    notifications::Notification::error("notification-open-url-error")
+                        .with_arg("error", error.to_string()),
+                );
+            }
+            Task::none()
+        }
+    }
+}
+
+/// Asks where to save the metadata of the current file or folder, then
+/// exports it in the background as JSON or CSV depending on the extension.
+fn handle_export_metadata(ctx: &mut UpdateContext<'_>, scope: ExportScope) -> Task<Message> {
+    let Some(current) = ctx
+        .media_navigator
+        .current_media_path()
+        .map(Path::to_path_buf)
+    else {
+        return Task::none();
+    };
+    let (paths, name) = match scope {
+        ExportScope::CurrentFile => (vec![current.clone()], current.file_stem()),
+        ExportScope::Folder => (
+            ctx.media_navigator.filtered_media_paths(),
+            current.parent().and_then(Path::file_name),
+        ),
+    };
+    let name = name.map_or_else(|| "media".into(), |name| name.to_string_lossy());
+
+    ask_save_path(ctx, format!("{name}_metadata.json")).then(move |output| match output {
+        Some(output) => {
+            let paths = paths.clone();
+            Task::perform(
+                async move {
+                    tokio::task::spawn_blocking(move || {
+                        media::metadata_export::export(&paths, &output).map_err(|e| e.to_string())
+                    })
+                    .await
+                    .unwrap_or_else(|e| Err(e.to_string()))
+                },
+                Message::MetadataExported,
+            )
+        }
+        None => Task::none(),
+    })
+}
+
+/// Reports the result of a metadata export.
+pub fn handle_metadata_exported(
+    ctx: &mut UpdateContext<'_>,
+    result: Result<usize, String>,
+) -> Task<Message> {
+    let notification = match result {
+        Ok(count) => notifications::Notification::success("notification-metadata-exported")
+            .with_arg("count", count.to_string()),
+        Err(error) => notifications::Notification::error("notification-metadata-export-error")
+            .with_arg("error", error),
+    };
+    ctx.notifications.push(notification);
+    Task::none()
+}
+
+/// Returns the directory of the current media.
+fn current_directory(ctx: &UpdateContext<'_>) -> Option<PathBuf> {
+    ctx.media_navigator
+        .current_media_path()
+        .and_then(std::path::Path::parent)
+        .map(std::path::Path::to_path_buf)
+}
+
+/// Computes the camera and lens statistics of the current folder in the background.
+fn start_shoot_stats(ctx: &mut UpdateContext<'_>, directory: PathBuf) -> Task<Message> {
+    if ctx.shoot_stats.loading {
+        return Task::none();
+    }
+    ctx.shoot_stats.loading = true;
+    let paths = ctx.media_navigator.media_paths();
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                crate::media::shoot_stats::ShootStats::collect(&paths)
+            })
+            .await
+            .unwrap_or_default()
+        },
+        move |stats| Message::ShootStatsComputed { directory, stats },
+    )
+}
+
+/// Stores freshly computed folder statistics.
+pub fn handle_shoot_stats_computed(
+    ctx: &mut UpdateContext<'_>,
+    directory: PathBuf,
+    stats: crate::media::shoot_stats::ShootStats,
+) -> Task<Message> {
+    ctx.shoot_stats.loading = false;
+    ctx.shoot_stats.stats = Some(stats);
+    ctx.shoot_stats.directory = Some(directory);
+
+    // The user moved to another folder while computing
+    match current_directory(ctx) {
+        Some(current) if ctx.shoot_stats.expanded && ctx.shoot_stats.is_stale(&current) => {
+            start_shoot_stats(ctx, current)
+        }
+        _ => Task::none(),
+    }
+}
+
+/// Asks for confirmation before removing all metadata from the current image.
+///
+/// The dialog lists the blocks found in the file and offers to overwrite it
+/// or to write a cleaned copy next to it.
+fn handle_strip_metadata_request(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    use crate::media::metadata_strip::{self, StripDestination};
+
+    let Some(path) = ctx
+        .media_navigator
+        .current_media_path()
+        .map(std::path::Path::to_path_buf)
+    else {
+        return Task::none();
+    };
+    if !metadata_strip::is_format_supported(&path) {
+        ctx.notifications.push(notifications::Notification::warning(
+            "notification-strip-unsupported",
+        ));
+        return Task::none();
+    }
+
+    let blocks = metadata_strip::detect_blocks(&path);
+    if blocks.is_empty() {
+        ctx.notifications.push(notifications::Notification::info(
+            "notification-strip-nothing",
+        ));
+        return Task::none();
+    }
+
+    let block_list: String = blocks
+        .iter()
+        .map(|block| format!("\n• {}", ctx.i18n.tr(block.i18n_key())))
+        .collect();
+    let description = format!(
+        "{}\n{}",
+        ctx.i18n.tr("metadata-strip-dialog-description"),
+        block_list
+    );
+    let overwrite = ctx.i18n.tr("metadata-strip-dialog-overwrite");
+    let copy = ctx.i18n.tr("metadata-strip-dialog-copy");
+    let cancel = ctx.i18n.tr("metadata-strip-dialog-cancel");
+
+    let dialog = rfd::AsyncMessageDialog::new()
+        .set_level(rfd::MessageLevel::Warning)
+        .set_title(ctx.i18n.tr("metadata-strip-dialog-title"))
+        .set_description(description)
+        .set_buttons(rfd::MessageButtons::YesNoCancelCustom(
+            overwrite.clone(),
+            copy.clone(),
+            cancel,
+        ));
+
+    Task::perform(
+        async move {
+            match dialog.show().await {
+                rfd::MessageDialogResult::Yes => Some(StripDestination::InPlace),
+                rfd::MessageDialogResult::No => Some(StripDestination::CleanCopy),
+                rfd::MessageDialogResult::Custom(label) if label == overwrite => {
+                    Some(StripDestination::InPlace)
+                }
+                rfd::MessageDialogResult::Custom(label) if label == copy => {
+                    Some(StripDestination::CleanCopy)
+                }
+                _ => None,
+            }
+        },
+        move |destination| Message::StripMetadataDialogResult { path, destination },
+    )
+}
+
+/// Removes all metadata after the user confirmed, in place or to a copy.
+pub fn handle_strip_metadata_confirmed(
+    ctx: &mut UpdateContext<'_>,
+    path: &std::path::Path,
+    destination: crate::media::metadata_strip::StripDestination,
+) -> Task<Message> {
+    use crate::media::metadata_strip::{self, StripDestination};
+
+    let target = destination.resolve(path);
+    if let Err(error) = metadata_strip::strip_metadata(path, &target) {
+        ctx.notifications.push(
+            notifications::Notification::error("notification-strip-error")
+                .with_arg("error", error.to_string()),
+        );
+        return Task::none();
+    }
+
+    match destination {
+        StripDestination::InPlace => {
+            // Pending edits refer to metadata that no longer exists
+            *ctx.metadata_editor_state = None;
+            if ctx.media_navigator.current_media_path() == Some(path) {
+                *ctx.current_metadata = crate::media::metadata::extract_metadata(path);
+            }
+            ctx.notifications.push(notifications::Notification::success(
+                "notification-strip-success",
+            ));
+        }
+        StripDestination::CleanCopy => {
+            let filename = target
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            ctx.notifications.push(
+                notifications::Notification::success("notification-strip-copy-success")
+                    .with_arg("filename", filename),
+            );
+        }
+    }
+    Task::none()
+}
+
+/// Starts a background integrity check of every file in the current folder.
+fn handle_verify_folder(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    if *ctx.verify_running {
+        return Task::none();
+    }
+    let paths = ctx.media_navigator.media_paths();
+    if paths.is_empty() {
+        return Task::none();
+    }
+
+    *ctx.verify_running = true;
+    ctx.notifications.push(
+        notifications::Notification::info("notification-verify-started")
+            .with_arg("count", paths.len().to_string()),
+    );
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                crate::media::integrity::verify_files(&paths, |_, _| {})
+            })
+            .await
+            .unwrap_or_default()
+        },
+        Message::VerifyCompleted,
+    )
+}
+
+/// Serves the current folder as a gallery on the local network, or shows
+/// its address again when it is already shared.
+fn handle_share_over_lan(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    if let Some(server) = ctx.share_server.as_ref() {
+        *ctx.share_panel = Some(share_panel::State::new(
+            server.url().to_string(),
+            server.file_count(),
+        ));
+        return Task::none();
+    }
+
+    let (config, _) = config::load();
+    let include_videos = config.share.include_videos.unwrap_or(true);
+    let files: Vec<PathBuf> = ctx
+        .media_navigator
+        .filtered_media_paths()
+        .into_iter()
+        .filter(|path| {
+            include_videos || media::detect_media_type(path) == Some(media::MediaType::Image)
+        })
+        .collect();
+    let Some(first) = files.first() else {
+        ctx.notifications.push(notifications::Notification::warning(
+            "notification-share-empty",
+        ));
+        return Task::none();
+    };
+
+    let title = first.parent().and_then(Path::file_name).map_or_else(
+        || "IcedLens".to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+    let port = config.share.port.unwrap_or(config::DEFAULT_SHARE_PORT);
+    match ShareServer::start(title, files, port) {
+        Ok(server) => {
+            *ctx.share_panel = Some(share_panel::State::new(
+                server.url().to_string(),
+                server.file_count(),
+            ));
+            *ctx.share_server = Some(server);
+        }
+        Err(err) => {
+            ctx.notifications.push(
+                notifications::Notification::error("notification-share-error")
+                    .with_arg("port", port.to_string())
+                    .with_arg("error", err.to_string()),
+            );
+        }
+    }
+    Task::none()
+}
+
+/// Stops serving the folder on the local network.
+fn handle_stop_sharing(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    *ctx.share_panel = None;
+    if ctx.share_server.take().is_some() {
+        ctx.notifications.push(notifications::Notification::info(
+            "notification-share-stopped",
+        ));
+    }
+    Task::none()
+}
+
+/// Handles messages of the "Share over LAN" address panel.
+pub fn handle_share_panel_message(
+    ctx: &mut UpdateContext<'_>,
+    message: share_panel::Message,
+) -> Task<Message> {
+    match message {
+        share_panel::Message::Close => {
+            *ctx.share_panel = None;
+            Task::none()
+        }
+        share_panel::Message::StopSharing => handle_stop_sharing(ctx),
+        share_panel::Message::CopyUrl => match ctx.share_panel.as_ref() {
+            Some(panel) => {
+                ctx.notifications.push(notifications::Notification::success(
+                    "notification-share-url-copied",
+                ));
+                iced::clipboard::write(panel.url().to_string())
+            }
+            None => Task::none(),
+        },
+        share_panel::Message::ConsumeClick => Task::none(),
+    }
+}
+
+/// Reports the result of a folder integrity check.
+///
+/// The list of damaged files is copied to the clipboard so it can be kept
+/// before the card is formatted.
+pub fn handle_verify_completed(
+    ctx: &mut UpdateContext<'_>,
+    report: &crate::media::integrity::VerifyReport,
+) -> Task<Message> {
+    *ctx.verify_running = false;
+
+    if report.is_clean() {
+        ctx.notifications.push(
+            notifications::Notification::success("notification-verify-clean")
+                .with_arg("count", report.checked.to_string()),
+        );
+        return Task::none();
+    }
+
+    let list: String = report
+        .damaged
+        .iter()
+        .map(|damaged| format!("{}\t{}\n", damaged.path.display(), damaged.reason))
+        .collect();
+    ctx.notifications.push(
+        notifications::Notification::warning("notification-verify-damaged")
+            .with_arg("damaged", report.damaged.len().to_string())
+            .with_arg("count", report.checked.to_string()),
+    );
+    iced::clipboard::write(list)
+}
+
+/// Reports the outcome of a batch metadata edit and refreshes the panel.
+pub fn handle_metadata_batch_completed(
+    ctx: &mut UpdateContext<'_>,
+    summary: &crate::media::metadata_batch::BatchSummary,
+) -> Task<Message> {
+    ctx.metadata_batch.finish();
 
-                        // Exit edit mode
-                        *ctx.metadata_editor_state = None;
+    for failure in &summary.failures {
+        tracing::warn!(
+            "Batch metadata edit failed for '{}': {}",
+            failure.path.display(),
+            failure.error
+        );
+    }
 
-                        // Show success notification
-                        ctx.notifications.push(notifications::Notification::success(
-                            "notification-metadata-save-success",
-                        ));
-                    }
-                    Err(_e) => {
-                        // Show error notification
-                        ctx.notifications.push(notifications::Notification::error(
-                            "notification-metadata-save-error",
-                        ));
-                    }
-                }
-            }
-            Task::none()
+    let notification = if let Some(first) = summary.failures.first() {
+        let filename = first
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        notifications::Notification::warning("notification-metadata-batch-partial")
+            .with_arg("updated", summary.updated.to_string())
+            .with_arg("failed", summary.failures.len().to_string())
+            .with_arg("filename", filename)
+    } else {
+        notifications::Notification::success("notification-metadata-batch-success")
+            .with_arg("updated", summary.updated.to_string())
+            .with_arg("skipped", summary.skipped.to_string())
+    };
+    ctx.notifications.push(notification);
+
+    // The current file was likely rewritten: show its new metadata
+    if let Some(path) = ctx.media_navigator.current_media_path() {
+        *ctx.current_metadata = crate::media::metadata::extract_metadata(path);
+    }
+    Task::none()
+}
+
+/// Asks for confirmation before pasting the copied edit recipe onto the other
+/// images of the folder, stating how many and where they will be saved.
+fn handle_recipe_batch_request(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    use super::recipe_batch;
+
+    if ctx.edit_clipboard.is_none() {
+        return Task::none();
+    }
+    let current = ctx
+        .media_navigator
+        .current_media_path()
+        .map(Path::to_path_buf);
+    let paths: Vec<PathBuf> = ctx
+        .media_navigator
+        .filtered_media_paths()
+        .into_iter()
+        .filter(|path| Some(path) != current.as_ref())
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| {
+                    media::IMAGE_EXTENSIONS
+                        .iter()
+                        .any(|known| known.eq_ignore_ascii_case(ext))
+                })
+        })
+        .collect();
+    let Some(first) = paths.first() else {
+        return Task::none();
+    };
+
+    let count = paths.len().to_string();
+    let destination = match recipe_batch::batch_policy(ctx.settings.save_policy()) {
+        config::SavePolicy::Copy => ctx.i18n.tr("recipe-batch-dialog-copy"),
+        _ => {
+            let folder =
+                crate::media::naming::edited_folder_of(first, ctx.settings.edited_folder());
+            let folder = folder
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            ctx.i18n.tr_with_args(
+                "recipe-batch-dialog-subfolder",
+                &[("folder", folder.as_str())],
+            )
         }
-        MetadataPanelEvent::SaveAsRequested => {
-            // Validate all fields before showing dialog
-            if let Some(editor_state) = ctx.metadata_editor_state.as_mut() {
-                if !editor_state.validate_all() {
-                    ctx.notifications.push(notifications::Notification::error(
-                        "notification-metadata-validation-error",
-                    ));
-                    return Task::none();
-                }
-            }
+    };
+    let description = format!(
+        "{}\n\n{}\n{}",
+        ctx.i18n.tr_with_args(
+            "recipe-batch-dialog-description",
+            &[("count", count.as_str())]
+        ),
+        destination,
+        ctx.i18n.tr("recipe-batch-dialog-skipped")
+    );
+    let paste = ctx.i18n.tr("recipe-batch-dialog-paste");
+    let cancel = ctx.i18n.tr("recipe-batch-dialog-cancel");
+
+    let dialog = rfd::AsyncMessageDialog::new()
+        .set_level(rfd::MessageLevel::Warning)
+        .set_title(ctx.i18n.tr("recipe-batch-dialog-title"))
+        .set_description(description)
+        .set_buttons(rfd::MessageButtons::OkCancelCustom(paste.clone(), cancel));
 
-            // Open Save As dialog
-            let mut dialog = rfd::AsyncFileDialog::new().set_title("Save Image As");
-            for (name, exts) in crate::media::extensions::IMAGE_SAVE_FILTERS {
-                dialog = dialog.add_filter(*name, exts);
+    Task::perform(
+        async move {
+            match dialog.show().await {
+                rfd::MessageDialogResult::Ok => true,
+                rfd::MessageDialogResult::Custom(label) => label == paste,
+                _ => false,
             }
+        },
+        move |confirmed| Message::RecipeBatchDialogResult(confirmed.then_some(paths)),
+    )
+}
 
-            // Set initial directory from app state
-            let dialog = if let Some(dir) = ctx.persisted.last_save_directory.as_ref() {
-                dialog.set_directory(dir)
-            } else {
-                dialog
-            };
+/// Pastes the copied edit recipe onto `paths` after the user confirmed.
+///
+/// The originals are kept: see [`super::recipe_batch::batch_policy`].
+pub fn handle_recipe_batch_confirmed(
+    ctx: &mut UpdateContext<'_>,
+    paths: Vec<PathBuf>,
+) -> Task<Message> {
+    let Some(recipe) = ctx.edit_clipboard.clone() else {
+        return Task::none();
+    };
+    let policy = ctx.settings.save_policy();
+    let edited_folder = ctx.settings.edited_folder().to_string();
+    ctx.notifications.push(
+        notifications::Notification::info("notification-recipe-batch-started")
+            .with_arg("count", paths.len().to_string()),
+    );
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                super::recipe_batch::apply_to_files(&paths, &recipe, policy, &edited_folder)
+            })
+            .await
+            .unwrap_or_default()
+        },
+        Message::RecipeBatchCompleted,
+    )
+}
 
-            // Set initial filename from current path (use media_navigator as source of truth)
-            let dialog = if let Some(path) = ctx.media_navigator.current_media_path() {
-                if let Some(filename) = path.file_name().and_then(|n| n.to_str()) {
-                    dialog.set_file_name(filename)
-                } else {
-                    dialog
-                }
-            } else {
-                dialog
-            };
+/// Reports the outcome of pasting the edit recipe onto the folder.
+pub fn handle_recipe_batch_completed(
+    ctx: &mut UpdateContext<'_>,
+    summary: &crate::media::metadata_batch::BatchSummary,
+) -> Task<Message> {
+    for failure in &summary.failures {
+        tracing::warn!(
+            "Pasting the edit recipe failed for '{}': {}",
+            failure.path.display(),
+            failure.error
+        );
+    }
 
-            Task::perform(
-                async move {
-                    dialog
-                        .save_file()
-                        .await
-                        .map(|handle| handle.path().to_path_buf())
-                },
-                Message::MetadataSaveAsDialogResult,
-            )
+    let notification = if let Some(first) = summary.failures.first() {
+        let filename = first
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        notifications::Notification::warning("notification-recipe-batch-partial")
+            .with_arg("updated", summary.updated.to_string())
+            .with_arg("failed", summary.failures.len().to_string())
+            .with_arg("filename", filename)
+    } else {
+        notifications::Notification::success("notification-recipe-batch-success")
+            .with_arg("updated", summary.updated.to_string())
+            .with_arg("skipped", summary.skipped.to_string())
+    };
+    ctx.notifications.push(notification);
+
+    // Numbered copies are added next to the originals
+    let policy = super::recipe_batch::batch_policy(ctx.settings.save_policy());
+    if policy == config::SavePolicy::Copy {
+        if let Some(path) = ctx
+            .media_navigator
+            .current_media_path()
+            .map(Path::to_path_buf)
+        {
+            persistence::rescan_directory_if_same(ctx.media_navigator, &path);
         }
     }
+    Task::none()
+}
+
+/// Opens a URL with the platform's default handler (usually the web browser).
+fn open_url(url: &str) -> std::io::Result<()> {
+    use std::process::Command;
+
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        // `cmd /C start` would split the URL on `&`
+        let mut command = Command::new("rundll32");
+        command.args(["url.dll,FileProtocolHandler", url]);
+        command
+    };
+    #[cfg(target_os = "macos")]
+    let mut command = {
+        let mut command = Command::new("open");
+        command.arg(url);
+        command
+    };
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let mut command = {
+        let mut command = Command::new("xdg-open");
+        command.arg(url);
+        command
+    };
+
+    command.spawn().map(|_| ())
 }
 
 /// Unified navigation handler for viewer and editor.
@@ -1096,10 +3228,8 @@ where
             .map(std::path::Path::to_path_buf)
         {
             let (config, _) = config::load();
-            let sort_order = config.display.sort_order.unwrap_or_default();
-            let _ = ctx
-                .media_navigator
-                .scan_directory(&current_path, sort_order);
+            let sort = config.display.media_sort();
+            let _ = ctx.media_navigator.scan_directory(&current_path, sort);
         }
     }
 
@@ -1129,8 +3259,21 @@ where
         // Set loading state via encapsulated method
         ctx.viewer.start_loading();
 
+        // Show prefetched media right away
+        let load_options = ctx.settings.load_options();
+        if let Some(media) = ctx.prefetch.get(&path, load_options) {
+            return Task::done(on_loaded(Ok(media)));
+        }
+
         // Load the media with the provided callback
-        Task::perform(async move { media::load_media(&path) }, on_loaded)
+        let preview = load_preview(path.clone());
+        Task::batch([
+            Task::perform(
+                async move { media::load_media_with(&path, load_options) },
+                on_loaded,
+            ),
+            preview,
+        ])
     } else {
         Task::none()
     }
@@ -1176,6 +3319,278 @@ pub fn handle_navigate_previous(ctx: &mut UpdateContext<'_>) -> Task<Message> {
     )
 }
 
+/// Handles a jump to a specific file of the current directory (quick search).
+///
+/// Loads as a direct open: a file that fails to load shows an error instead of
+/// auto-skipping to a neighbor the user didn't ask for.
+fn handle_jump_to_media(ctx: &mut UpdateContext<'_>, path: PathBuf) -> Task<Message> {
+    if ctx.viewer.current_media_path.as_ref() == Some(&path) {
+        return Task::none();
+    }
+
+    ctx.viewer
+        .set_load_origin(crate::ui::viewer::LoadOrigin::DirectOpen);
+    ctx.viewer.current_media_path = Some(path.clone());
+    ctx.viewer.start_loading();
+
+    let load_options = ctx.settings.load_options();
+    if let Some(media) = ctx.prefetch.get(&path, load_options) {
+        return Task::done(Message::Viewer(component::Message::MediaLoaded(Ok(media))));
+    }
+    let preview = load_preview(path.clone());
+    Task::batch([
+        Task::perform(
+            async move { media::load_media_with(&path, load_options) },
+            |result| Message::Viewer(component::Message::MediaLoaded(result)),
+        ),
+        preview,
+    ])
+}
+
+/// Decodes the next and previous media in the background, so navigating to
+/// them is instant. Files already cached or being decoded are skipped.
+fn prefetch_neighbors(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    let options = ctx.settings.load_options();
+    let neighbors = [
+        ctx.media_navigator.peek_next_filtered(),
+        ctx.media_navigator.peek_previous_filtered(),
+    ];
+    Task::batch(
+        neighbors
+            .into_iter()
+            .flatten()
+            .filter(|path| ctx.prefetch.begin(path, options))
+            .map(|path| {
+                Task::perform(
+                    async move {
+                        let modified = media::prefetch::modified_time(&path);
+                        let media = media::load_media_with(&path, options).ok();
+                        (path, modified, media)
+                    },
+                    move |(path, modified, media)| Message::MediaPrefetched {
+                        path,
+                        modified,
+                        options,
+                        media,
+                    },
+                )
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Number of upcoming videos whose loudness is measured ahead of playback.
+const LOUDNESS_LOOKAHEAD: usize = 4;
+
+/// Measures the loudness of the next videos in the background when audio
+/// normalization is on, so they play at the right level from the start.
+fn prefetch_loudness(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    if !*ctx.audio_normalization {
+        return Task::none();
+    }
+    let current = ctx.media_navigator.current_media_path();
+    // Small directories wrap around to the file playing now
+    let upcoming: Vec<PathBuf> = (0..LOUDNESS_LOOKAHEAD)
+        .filter_map(|skip| ctx.media_navigator.peek_nth_next_filtered(skip))
+        .filter(|path| Some(path.as_path()) != current)
+        .collect();
+
+    Task::batch(
+        upcoming
+            .into_iter()
+            .filter(|path| media::detect_media_type(path) == Some(media::MediaType::Video))
+            .map(|path| {
+                let cache = ctx.lufs_cache.clone();
+                Task::future(crate::video_player::preanalyze_loudness(cache, path)).discard()
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Stores media decoded by [`prefetch_neighbors`].
+pub fn handle_media_prefetched(
+    ctx: &mut UpdateContext<'_>,
+    path: PathBuf,
+    modified: Option<std::time::SystemTime>,
+    options: media::LoadOptions,
+    media: Option<MediaData>,
+) {
+    match media {
+        Some(media) => ctx.prefetch.insert(path, modified, options, media),
+        None => ctx.prefetch.cancel(&path),
+    }
+}
+
+/// Reads the quick preview of `path` off the UI thread. The viewer shows it
+/// until the full decode of a large image is ready.
+pub(crate) fn load_preview(path: PathBuf) -> Task<Message> {
+    Task::future(async move { media::preview::load_preview(&path).map(|image| (path, image)) })
+        .and_then(|(path, image)| {
+            Task::done(Message::Viewer(component::Message::PreviewLoaded {
+                path,
+                image,
+            }))
+        })
+}
+
+/// Handles a jump to the first media of the next/previous capture day.
+///
+/// Capture dates are read from every file of the directory, so the index is
+/// built in the background on the first jump and reused until the next rescan.
+pub fn handle_navigate_capture_day(
+    ctx: &mut UpdateContext<'_>,
+    direction: NavigationDirection,
+) -> Task<Message> {
+    let Some(index) = ctx.media_navigator.capture_days() else {
+        let paths = ctx.media_navigator.media_paths();
+        return Task::perform(async move { CaptureDayIndex::build(paths) }, move |index| {
+            Message::CaptureDaysIndexed { index, direction }
+        });
+    };
+    let Some(current) = ctx.viewer.current_media_path.as_deref() else {
+        return Task::none();
+    };
+
+    let target = match direction {
+        NavigationDirection::Next => index.next_day_start(current),
+        NavigationDirection::Previous => index.previous_day_start(current),
+    };
+    let Some(target) = target.map(std::path::Path::to_path_buf) else {
+        return Task::none();
+    };
+
+    // Announce the day like a gallery section header
+    if let Some(day) = index.day_of(&target) {
+        ctx.notifications.push(
+            notifications::Notification::info("notification-capture-day")
+                .with_arg("date", ctx.i18n.format_date(day.date))
+                .with_arg("count", day.count.to_string()),
+        );
+    }
+
+    handle_jump_to_media(ctx, target)
+}
+
+/// Handles a jump to the next/previous image similar to the current one.
+///
+/// Every image of the directory is decoded to hash it, so the index is built
+/// in the background on the first jump and reused until the next rescan.
+pub fn handle_find_similar(
+    ctx: &mut UpdateContext<'_>,
+    direction: NavigationDirection,
+) -> Task<Message> {
+    let Some(index) = ctx.media_navigator.similarity() else {
+        ctx.notifications.push(notifications::Notification::info(
+            "notification-similar-indexing",
+        ));
+        let paths = ctx.media_navigator.media_paths();
+        return Task::perform(async move { SimilarityIndex::build(paths) }, move |index| {
+            Message::SimilarityIndexed { index, direction }
+        });
+    };
+    let Some(current) = ctx.viewer.current_media_path.as_deref() else {
+        return Task::none();
+    };
+
+    let threshold = ctx.settings.similarity_threshold();
+    let target = match direction {
+        NavigationDirection::Next => index.next_similar(current, threshold),
+        NavigationDirection::Previous => index.previous_similar(current, threshold),
+    };
+    let Some(target) = target.map(std::path::Path::to_path_buf) else {
+        ctx.notifications.push(notifications::Notification::info(
+            "notification-similar-none",
+        ));
+        return Task::none();
+    };
+
+    ctx.notifications.push(
+        notifications::Notification::info("notification-similar-found")
+            .with_arg("count", index.similar_count(current, threshold).to_string()),
+    );
+
+    handle_jump_to_media(ctx, target)
+}
+
+/// Runs face detection on the current image in the background.
+///
+/// The small detection model is downloaded on first use.
+pub fn handle_detect_faces(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    let (Some(path), Some(MediaData::Image(image))) =
+        (ctx.viewer.current_media_path.clone(), ctx.viewer.media())
+    else {
+        return Task::none();
+    };
+    // Cheap clone: pixels are shared behind an Arc
+    let image = image.clone();
+    notify_face_model_download(ctx);
+
+    Task::perform(
+        async move {
+            let rgba = image_rs::RgbaImage::from_raw(
+                image.width,
+                image.height,
+                image.rgba_bytes().to_vec(),
+            )
+            .ok_or_else(|| {
+                media::face_detection::FaceDetectionError::PreprocessingFailed(
+                    "Invalid image buffer".to_string(),
+                )
+            })?;
+            detect_faces(image_rs::DynamicImage::ImageRgba8(rgba)).await
+        },
+        move |result: media::face_detection::FaceDetectionResult<_>| {
+            Message::Viewer(component::Message::FacesDetected {
+                path,
+                result: result.map_err(|e| e.to_string()),
+            })
+        },
+    )
+}
+
+/// Runs face detection on the editor's crop base image in the background,
+/// for framing the crop rectangle on a face.
+fn handle_editor_face_detection(ctx: &mut UpdateContext<'_>) -> Task<Message> {
+    let Some(image) = ctx
+        .image_editor
+        .as_ref()
+        .and_then(|editor| editor.crop_base_image())
+        .cloned()
+    else {
+        return Task::none();
+    };
+    notify_face_model_download(ctx);
+
+    Task::perform(detect_faces(image), |result| {
+        Message::EditorFacesDetected(result.map_err(|e| e.to_string()))
+    })
+}
+
+/// Tells the user the face detection model is being fetched, on first use.
+fn notify_face_model_download(ctx: &mut UpdateContext<'_>) {
+    if !media::face_detection::is_model_downloaded() {
+        ctx.notifications.push(notifications::Notification::info(
+            "notification-face-model-downloading",
+        ));
+    }
+}
+
+/// Detects faces in `image`, downloading the model first if needed.
+async fn detect_faces(
+    image: image_rs::DynamicImage,
+) -> media::face_detection::FaceDetectionResult<Vec<media::face_detection::FaceBox>> {
+    if !media::face_detection::is_model_downloaded() {
+        media::face_detection::download_model(config::DEFAULT_FACE_MODEL_URL).await?;
+    }
+    // Use spawn_blocking to avoid blocking the tokio runtime during ONNX inference
+    tokio::task::spawn_blocking(move || {
+        let mut detector = media::face_detection::FaceDetector::load()?;
+        detector.detect(&image)
+    })
+    .await
+    .map_err(|e| media::face_detection::FaceDetectionError::InferenceFailed(e.to_string()))?
+}
+
 /// Handles retry navigation after a failed load (auto-skip).
 ///
 /// Continues navigation in the same direction, preserving skip context
@@ -1286,8 +3701,8 @@ pub fn handle_delete_current_media(ctx: &mut UpdateContext<'_>) -> Task<Message>
                 .unwrap_or_else(|| current_path.clone());
 
             let (config, _) = config::load();
-            let sort_order = config.display.sort_order.unwrap_or_default();
-            let _ = ctx.media_navigator.scan_directory(&scan_seed, sort_order);
+            let sort = config.display.media_sort();
+            let _ = ctx.media_navigator.scan_directory(&scan_seed, sort);
 
             if let Some(next_path) = next_candidate {
                 // Navigate to the next media
@@ -1298,9 +3713,15 @@ pub fn handle_delete_current_media(ctx: &mut UpdateContext<'_>) -> Task<Message>
                 // Set loading state via encapsulated method
                 ctx.viewer.start_loading();
 
-                Task::perform(async move { media::load_media(&next_path) }, |result| {
-                    Message::Viewer(component::Message::MediaLoaded(result))
-                })
+                let load_options = ctx.settings.load_options();
+                let preview = load_preview(next_path.clone());
+                Task::batch([
+                    Task::perform(
+                        async move { media::load_media_with(&next_path, load_options) },
+                        |result| Message::Viewer(component::Message::MediaLoaded(result)),
+                    ),
+                    preview,
+                ])
             } else {
                 // No more media in directory - send ClearMedia message to viewer
                 // This is event-driven: the viewer handles its own state clearing
@@ -1318,16 +3739,46 @@ pub fn handle_delete_current_media(ctx: &mut UpdateContext<'_>) -> Task<Message>
     }
 }
 
-/// Handles frame capture: opens the editor with the captured frame.
+/// Handles frame capture: opens the editor with the frame decoded again at
+/// the source resolution and bit depth when possible, or with the displayed
+/// frame otherwise.
 pub fn handle_capture_frame(
     frame: ExportableFrame,
     video_path: PathBuf,
     position_secs: f64,
+    full_decode: Option<media::frame_export::FrameOrientation>,
 ) -> Task<Message> {
-    Task::done(Message::OpenImageEditorWithFrame {
-        frame,
-        video_path,
-        position_secs,
+    let Some(orientation) = full_decode else {
+        return Task::done(Message::OpenImageEditorWithFrame {
+            frame,
+            video_path,
+            position_secs,
+        });
+    };
+
+    Task::future(async move {
+        let path = video_path.clone();
+        let decoded = tokio::task::spawn_blocking(move || {
+            media::frame_export::decode_frame_at(&path, position_secs)
+        })
+        .await;
+        match decoded {
+            Ok(Ok(image)) => Message::OpenImageEditorWithImage {
+                image: orientation.apply(image),
+                video_path,
+                position_secs,
+            },
+            result => {
+                if let Ok(Err(err)) = result {
+                    tracing::warn!("Failed to decode the frame at full resolution: {err}");
+                }
+                Message::OpenImageEditorWithFrame {
+                    frame,
+                    video_path,
+                    position_secs,
+                }
+            }
+        }
     })
 }
 
@@ -1369,11 +3820,19 @@ fn update_fullscreen_mode(
 }
 
 /// Handles the open file dialog request from empty state.
-pub fn handle_open_file_dialog(last_directory: Option<PathBuf>) -> Task<Message> {
+pub fn handle_open_file_dialog(i18n: &I18n, persisted: &AppState) -> Task<Message> {
+    pick_media_file(i18n, persisted).map(Message::OpenFileDialogResult)
+}
+
+/// Asks for a media file to open, starting in the last opened directory.
+///
+/// The filter matching the last opened file is preselected.
+fn pick_media_file(i18n: &I18n, persisted: &AppState) -> Task<Option<PathBuf>> {
+    let filters = file_dialog::open_filters(i18n, persisted.last_open_filter);
+    let last_directory = persisted.last_open_directory.clone();
     Task::perform(
         async move {
-            let mut dialog = rfd::AsyncFileDialog::new()
-                .add_filter("Media", crate::media::extensions::ALL_MEDIA_EXTENSIONS);
+            let mut dialog = file_dialog::with_filters(rfd::AsyncFileDialog::new(), &filters);
 
             if let Some(dir) = last_directory {
                 if dir.exists() {
@@ -1383,7 +3842,7 @@ pub fn handle_open_file_dialog(last_directory: Option<PathBuf>) -> Task<Message>
 
             dialog.pick_file().await.map(|h| h.path().to_path_buf())
         },
-        Message::OpenFileDialogResult,
+        std::convert::identity,
     )
 }
 
@@ -1397,6 +3856,9 @@ pub fn handle_open_file_dialog_result(
         return Task::none();
     };
 
+    // Saved together with last_open_directory once the media loads
+    ctx.persisted.last_open_filter = file_dialog::OpenFilter::for_opened_file(&path);
+
     // Load the media (last_open_directory is updated on successful load)
     load_media_from_path(ctx, path)
 }
@@ -1426,18 +3888,14 @@ pub fn handle_file_dropped(ctx: &mut UpdateContext<'_>, path: PathBuf) -> Task<M
     if path.is_dir() {
         // Scan directory for media and load the first file
         let (config, _) = config::load();
-        let sort_order = config.display.sort_order.unwrap_or_default();
-        if ctx
-            .media_navigator
-            .scan_from_directory(&path, sort_order)
-            .is_ok()
-        {
+        let sort = config.display.media_sort();
+        if ctx.media_navigator.scan_from_directory(&path, sort).is_ok() {
             if let Some(first_path) = ctx
                 .media_navigator
                 .current_media_path()
                 .map(std::path::Path::to_path_buf)
             {
-                return load_media_from_path(ctx, first_path);
+                return load_scanned_media(ctx, first_path);
             }
         }
         // No media found in directory
@@ -1453,11 +3911,20 @@ pub fn handle_file_dropped(ctx: &mut UpdateContext<'_>, path: PathBuf) -> Task<M
 
 /// Internal helper to load media from a path.
 fn load_media_from_path(ctx: &mut UpdateContext<'_>, path: PathBuf) -> Task<Message> {
-    // Scan the directory for navigation
+    // Scan the directory for navigation in the background
     let (config, _) = config::load();
-    let sort_order = config.display.sort_order.unwrap_or_default();
-    let _ = ctx.media_navigator.scan_directory(&path, sort_order);
+    let sort = config.display.media_sort();
+    let scan = ctx
+        .media_navigator
+        .begin_scan(&path)
+        .map_or_else(Task::none, |scan| {
+            scan_directory_in_background(scan, path.clone(), sort)
+        });
+    Task::batch([load_scanned_media(ctx, path), scan])
+}
 
+/// Loads `path`, already in the navigator's media list, into the viewer.
+fn load_scanned_media(ctx: &mut UpdateContext<'_>, path: PathBuf) -> Task<Message> {
     // Set up viewer state
     ctx.viewer.current_media_path = Some(path.clone());
 
@@ -1465,9 +3932,54 @@ fn load_media_from_path(ctx: &mut UpdateContext<'_>, path: PathBuf) -> Task<Mess
     ctx.viewer.start_loading();
 
     // Load the media
-    Task::perform(async move { media::load_media(&path) }, |result| {
-        Message::Viewer(component::Message::MediaLoaded(result))
-    })
+    let load_options = ctx.settings.load_options();
+    let preview = load_preview(path.clone());
+    Task::batch([
+        Task::perform(
+            async move { media::load_media_with(&path, load_options) },
+            |result| Message::Viewer(component::Message::MediaLoaded(result)),
+        ),
+        preview,
+    ])
+}
+
+/// Runs `scan` off the UI thread, streaming sorted snapshots of the media
+/// found so far to the navigator. `seed` is the file being opened.
+pub(crate) fn scan_directory_in_background(
+    scan: media::ScanId,
+    seed: PathBuf,
+    sort: config::MediaSort,
+) -> Task<Message> {
+    Task::stream(iced::stream::channel(4, move |mut output| async move {
+        use iced::futures::SinkExt;
+
+        let (snapshot_tx, mut snapshot_rx) = tokio::sync::mpsc::unbounded_channel();
+        let scan_directory = scan.directory.clone();
+        let scanner = tokio::task::spawn_blocking(move || {
+            crate::directory_scanner::scan_incremental(
+                &scan_directory,
+                Some(&seed),
+                sort,
+                &mut |files, complete| {
+                    let _ = snapshot_tx.send((files, complete));
+                },
+            )
+        });
+
+        // The snapshot channel closes when the scan returns
+        while let Some((files, complete)) = snapshot_rx.recv().await {
+            let _ = output
+                .send(Message::DirectoryScanned {
+                    scan: scan.clone(),
+                    files,
+                    complete,
+                })
+                .await;
+        }
+        if !matches!(scanner.await, Ok(Ok(()))) {
+            let _ = output.send(Message::DirectoryScanFailed(scan)).await;
+        }
+    }))
 }
 
 /// Handles filter dropdown messages from the viewer.
@@ -1526,6 +4038,9 @@ fn handle_filter_changed(
                 }
             }
         }
+        filter_dropdown::Message::RatingChanged(rating) => {
+            filter.rating = rating;
+        }
         filter_dropdown::Message::ResetFilters => {
             filter = MediaFilter::default();
         }
@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Checking for a newer release, behind the `update-check` feature.
+//!
+//! The latest release is read from the GitHub API of the release mirror and
+//! compared with the running version. Nothing is downloaded or installed:
+//! the user gets a notification with the release notes and a link to the
+//! release page.
+//!
+//! Checks run when asked from the settings, or weekly when the user turned
+//! the background check on. Builds without the feature make no network
+//! request at all.
+
+use iced::futures::SinkExt;
+use iced::Subscription;
+use serde::Deserialize;
+use std::time::{Duration, SystemTime};
+
+/// Latest published release of the GitHub mirror (drafts and pre-releases
+/// are left out by the API).
+const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/Bawycle/iced_lens/releases/latest";
+
+/// Time between two background checks.
+pub const CHECK_INTERVAL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// How often the background check looks whether a check is due.
+const DUE_POLL_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Give up on slow networks rather than keep a request open.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Release notes longer than this are cut in the notification.
+const NOTES_MAX_CHARS: usize = 300;
+
+/// A release newer than the running version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Release {
+    /// Version without the `v` prefix of the tag, such as `0.7.0`.
+    pub version: String,
+    /// Start of the release notes.
+    pub notes: String,
+    /// Release page with the downloads.
+    pub url: String,
+}
+
+/// Fields of the GitHub release object read by the check.
+#[derive(Debug, Deserialize)]
+struct ApiRelease {
+    tag_name: String,
+    #[serde(default)]
+    body: Option<String>,
+    html_url: String,
+}
+
+/// Fetches the latest release and returns it if it is newer than this build.
+///
+/// # Errors
+///
+/// Returns an error message if the request fails or the answer can't be read.
+pub async fn check() -> Result<Option<Release>, String> {
+    let client = reqwest::Client::builder()
+        .user_agent(concat!("IcedLens/", env!("CARGO_PKG_VERSION")))
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let response = client
+        .get(LATEST_RELEASE_URL)
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !response.status().is_success() {
+        return Err(format!("HTTP status: {}", response.status()));
+    }
+
+    let body = response.bytes().await.map_err(|e| e.to_string())?;
+    let release: ApiRelease = serde_json::from_slice(&body).map_err(|e| e.to_string())?;
+    Ok(newer_release(release, env!("CARGO_PKG_VERSION")))
+}
+
+/// Returns `release` if its tag names a version above `current`.
+fn newer_release(release: ApiRelease, current: &str) -> Option<Release> {
+    let latest = parse_version(&release.tag_name)?;
+    if parse_version(current).is_some_and(|current| latest <= current) {
+        return None;
+    }
+    Some(Release {
+        version: release.tag_name.trim_start_matches('v').to_string(),
+        notes: excerpt(release.body.as_deref().unwrap_or_default()),
+        url: release.html_url,
+    })
+}
+
+/// Parses `v1.2.3` or `1.2` into comparable parts, ignoring any pre-release
+/// or build suffix.
+fn parse_version(tag: &str) -> Option<[u64; 3]> {
+    let core = tag
+        .trim()
+        .trim_start_matches('v')
+        .split(['-', '+'])
+        .next()?;
+    let mut parts = [0; 3];
+    for (i, part) in core.split('.').enumerate() {
+        *parts.get_mut(i)? = part.parse().ok()?;
+    }
+    Some(parts)
+}
+
+/// Returns the start of `notes`, cut at a line end when possible.
+fn excerpt(notes: &str) -> String {
+    let notes = notes.trim();
+    if notes.chars().count() <= NOTES_MAX_CHARS {
+        return notes.to_string();
+    }
+    let cut: String = notes.chars().take(NOTES_MAX_CHARS).collect();
+    let cut = cut.rfind('\n').map_or(cut.as_str(), |end| &cut[..end]);
+    format!("{}…", cut.trim_end())
+}
+
+/// Returns true if no check ran during the last [`CHECK_INTERVAL`].
+#[must_use]
+pub fn is_due(last_check: Option<SystemTime>, now: SystemTime) -> bool {
+    last_check.is_none_or(|last| {
+        // A clock set back also makes the check due
+        !matches!(now.duration_since(last), Ok(elapsed) if elapsed < CHECK_INTERVAL)
+    })
+}
+
+/// Emits right away, then every hour, so the caller can run the weekly check
+/// when [`is_due`] says so, including in sessions left open for days.
+pub fn subscription() -> Subscription<()> {
+    Subscription::run(due_ticks)
+}
+
+fn due_ticks() -> impl iced::futures::Stream<Item = ()> {
+    iced::stream::channel(1, |mut output| async move {
+        loop {
+            if output.send(()).await.is_err() {
+                break;
+            }
+            tokio::time::sleep(DUE_POLL_INTERVAL).await;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_release(tag: &str, body: &str) -> ApiRelease {
+        ApiRelease {
+            tag_name: tag.to_string(),
+            body: Some(body.to_string()),
+            html_url: format!("https://github.com/Bawycle/iced_lens/releases/tag/{tag}"),
+        }
+    }
+
+    #[test]
+    fn only_newer_versions_are_reported() {
+        let release = newer_release(api_release("v0.7.0", "Notes"), "0.6.0").expect("newer");
+        assert_eq!(release.version, "0.7.0");
+        assert_eq!(release.notes, "Notes");
+
+        assert!(newer_release(api_release("v0.6.0", ""), "0.6.0").is_none());
+        assert!(newer_release(api_release("0.5.10", ""), "0.6.0").is_none());
+        assert!(newer_release(api_release("v0.10.0", ""), "0.9.1").is_some());
+        assert!(newer_release(api_release("nightly", ""), "0.6.0").is_none());
+    }
+
+    #[test]
+    fn versions_ignore_suffixes() {
+        assert_eq!(parse_version("v1.2.3-rc.1"), Some([1, 2, 3]));
+        assert_eq!(parse_version("1.2"), Some([1, 2, 0]));
+        assert_eq!(parse_version("1.2.3.4"), None);
+    }
+
+    #[test]
+    fn long_notes_are_cut_at_a_line_end() {
+        let notes = format!("First line\n{}", "x".repeat(NOTES_MAX_CHARS));
+        assert_eq!(excerpt(&notes), "First line…");
+    }
+
+    #[test]
+    fn checks_are_due_weekly() {
+        let now = SystemTime::now();
+        assert!(is_due(None, now));
+        assert!(!is_due(Some(now - Duration::from_secs(60)), now));
+        assert!(is_due(Some(now - CHECK_INTERVAL), now));
+        assert!(is_due(Some(now + Duration::from_secs(60)), now));
+    }
+}
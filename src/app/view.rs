@@ -4,23 +4,36 @@
 //! This module handles the `view()` function that renders the current screen
 //! based on application state.
 
+use super::window_mode::WindowMode;
 use super::{Message, Screen};
 use crate::config;
 use crate::i18n::fluent::I18n;
+use crate::media::background_removal::BackgroundModelStatus;
 use crate::media::deblur::ModelStatus;
 use crate::media::metadata::MediaMetadata;
 use crate::media::navigator::NavigationInfo;
 use crate::media::upscale::UpscaleModelStatus;
 use crate::ui::about::{self, ViewContext as AboutViewContext};
-use crate::ui::design_tokens::spacing;
+use crate::ui::design_tokens::{sizing, spacing};
+use crate::ui::diagnostics_overlay;
 use crate::ui::help::{self, ViewContext as HelpViewContext};
 use crate::ui::image_editor::{self, State as ImageEditorState};
-use crate::ui::metadata_panel::{self, MetadataEditorState, PanelContext as MetadataPanelContext};
+use crate::ui::keyboard_layout::KeyboardLayout;
+use crate::ui::keymap::Keymap;
+use crate::ui::logs;
+use crate::ui::metadata_panel::{
+    self, BatchEditState, MetadataEditorState, PanelContext as MetadataPanelContext,
+    ShootStatsState, TagBrowserState,
+};
 use crate::ui::navbar::{self, ViewContext as NavbarViewContext};
 use crate::ui::notifications::{Manager as NotificationManager, Toast};
 use crate::ui::settings::{State as SettingsState, ViewContext as SettingsViewContext};
-use crate::ui::viewer::{component, filter_dropdown};
+use crate::ui::share_panel;
+use crate::ui::translations;
+use crate::ui::viewer::{component, filter_dropdown, quick_search};
+use crate::ui::welcome;
 use iced::{
+    alignment::Horizontal,
     widget::{mouse_area, Container, Row, Stack, Text},
     Element, Length,
 };
@@ -36,6 +49,8 @@ pub struct ViewContext<'a> {
     pub viewer: &'a component::State,
     pub image_editor: Option<&'a ImageEditorState>,
     pub help_state: &'a crate::ui::help::State,
+    /// Key labels of the current keyboard layout, for the help screen.
+    pub keyboard_layout: &'a KeyboardLayout,
     pub fullscreen: bool,
     pub menu_open: bool,
     pub info_panel_open: bool,
@@ -45,6 +60,11 @@ pub struct ViewContext<'a> {
     pub current_metadata: Option<&'a MediaMetadata>,
     /// Metadata editor state when in edit mode.
     pub metadata_editor_state: Option<&'a MetadataEditorState>,
+    /// State of the "All tags" browser in the metadata panel.
+    pub metadata_tag_browser: &'a TagBrowserState,
+    pub metadata_batch: &'a BatchEditState,
+    /// State of the folder statistics section in the metadata panel.
+    pub shoot_stats: &'a ShootStatsState,
     /// Current media path for save operations.
     /// Uses `media_navigator` as single source of truth.
     pub current_media_path: Option<&'a std::path::Path>,
@@ -60,12 +80,36 @@ pub struct ViewContext<'a> {
     pub upscale_model_status: &'a UpscaleModelStatus,
     /// Whether AI upscaling is enabled for resize operations.
     pub enable_upscale: bool,
+    /// Current status of the AI background removal model.
+    pub background_model_status: &'a BackgroundModelStatus,
+    /// Whether a denoising model URL is set.
+    pub denoise_model_available: bool,
+    /// True if the editor session clipboard holds adjustments.
+    pub can_paste_adjustments: bool,
+    /// Labels of the editor tools added by plugins.
+    pub plugin_tools: &'a [String],
+    /// Labels of the export formats added by plugins.
+    pub plugin_exports: &'a [String],
     /// Current media filter (from navigator).
     pub filter: &'a crate::media::filter::MediaFilter,
     /// Total count of media files in directory.
     pub total_count: usize,
     /// Filtered count of media files.
     pub filtered_count: usize,
+    /// Whether the folder is being shared over the local network.
+    pub sharing: bool,
+    /// External commands of the "Open with / Send to" menu.
+    pub open_with: &'a [config::OpenWithCommand],
+    /// Always-on-top, borderless and compact modes of the main window.
+    pub window_mode: WindowMode,
+    /// Address panel of "Share over LAN", while shown.
+    pub share_panel: Option<&'a share_panel::State>,
+    /// Lines shown by the log viewer.
+    pub logs: &'a logs::State,
+    /// Page of the first-run welcome screen.
+    pub welcome: &'a welcome::State,
+    /// Figures of the diagnostics overlay, while it is shown.
+    pub diagnostics: Option<&'a crate::diagnostics::Snapshot>,
 }
 
 /// Context required to render the viewer screen.
@@ -81,6 +125,9 @@ struct ViewerViewContext<'a> {
     navigation: NavigationInfo,
     current_metadata: Option<&'a MediaMetadata>,
     metadata_editor_state: Option<&'a MetadataEditorState>,
+    metadata_tag_browser: &'a TagBrowserState,
+    metadata_batch: &'a BatchEditState,
+    shoot_stats: &'a ShootStatsState,
     current_media_path: Option<&'a std::path::Path>,
     is_image: bool,
     is_dark_theme: bool,
@@ -89,6 +136,9 @@ struct ViewerViewContext<'a> {
     total_count: usize,
     /// Filtered count of media files.
     filtered_count: usize,
+    sharing: bool,
+    open_with: &'a [config::OpenWithCommand],
+    window_mode: WindowMode,
 }
 
 /// Renders the current application view based on the active screen.
@@ -108,25 +158,59 @@ pub fn view(ctx: ViewContext<'_>) -> Element<'_, Message> {
             navigation: ctx.navigation,
             current_metadata: ctx.current_metadata,
             metadata_editor_state: ctx.metadata_editor_state,
+            metadata_tag_browser: ctx.metadata_tag_browser,
+            metadata_batch: ctx.metadata_batch,
+            shoot_stats: ctx.shoot_stats,
             current_media_path: ctx.current_media_path,
             is_image: ctx.is_image,
             is_dark_theme: ctx.is_dark_theme,
             filter: ctx.filter,
             total_count: ctx.total_count,
             filtered_count: ctx.filtered_count,
+            sharing: ctx.sharing,
+            open_with: ctx.open_with,
+            window_mode: ctx.window_mode,
         }),
         Screen::Settings => view_settings(ctx.settings, ctx.i18n),
         Screen::ImageEditor => view_image_editor(
             ctx.image_editor,
+            &image_editor::ViewContext {
+                i18n: ctx.i18n,
+                background_theme: ctx.settings.background_theme(),
+                is_dark_theme: ctx.is_dark_theme,
+                deblur_model_status: ctx.deblur_model_status,
+                upscale_model_status: ctx.upscale_model_status,
+                enable_upscale: ctx.enable_upscale,
+                background_model_status: ctx.background_model_status,
+                denoise_model_available: ctx.denoise_model_available,
+                can_paste_adjustments: ctx.can_paste_adjustments,
+                plugin_tools: ctx.plugin_tools,
+                plugin_exports: ctx.plugin_exports,
+                guides: ctx.settings.guides(),
+            },
+        ),
+        Screen::Help => view_help(
+            ctx.help_state,
+            ctx.keyboard_layout,
+            ctx.settings.keymap(),
             ctx.i18n,
-            ctx.settings,
             ctx.is_dark_theme,
-            ctx.deblur_model_status,
-            ctx.upscale_model_status,
-            ctx.enable_upscale,
         ),
-        Screen::Help => view_help(ctx.help_state, ctx.i18n, ctx.is_dark_theme),
         Screen::About => view_about(ctx.i18n),
+        Screen::Logs => logs::view(logs::ViewContext {
+            i18n: ctx.i18n,
+            state: ctx.logs,
+        })
+        .map(Message::Logs),
+        Screen::Translations => translations::view(translations::ViewContext { i18n: ctx.i18n })
+            .map(Message::Translations),
+        Screen::Welcome => welcome::view(welcome::ViewContext {
+            i18n: ctx.i18n,
+            state: ctx.welcome,
+            theme_mode: ctx.settings.theme_mode(),
+            keymap: ctx.settings.keymap(),
+        })
+        .map(Message::Welcome),
     };
 
     let main_content = Container::new(current_view)
@@ -178,6 +262,38 @@ pub fn view(ctx: ViewContext<'_>) -> Element<'_, Message> {
         None
     };
 
+    // Build quick-search overlay (Viewer screen only, also available in fullscreen)
+    let quick_search_overlay: Option<Element<'_, Message>> = if matches!(ctx.screen, Screen::Viewer)
+    {
+        quick_search::view_panel(quick_search::ViewContext {
+            i18n: ctx.i18n,
+            state: ctx.viewer.quick_search_state(),
+        })
+        .map(|panel| {
+            let mapped_panel =
+                panel.map(|msg| Message::Viewer(component::Message::QuickSearch(msg)));
+
+            // Wrap panel in mouse_area to prevent clicks from closing the overlay
+            let panel_with_click_guard = mouse_area(mapped_panel).on_press(Message::Viewer(
+                component::Message::QuickSearch(quick_search::Message::ConsumeClick),
+            ));
+
+            // Centered horizontally, near the top like a command palette
+            Container::new(panel_with_click_guard)
+                .width(Length::Fill)
+                .align_x(Horizontal::Center)
+                .padding(iced::Padding {
+                    top: spacing::XXL,
+                    right: 0.0,
+                    bottom: 0.0,
+                    left: 0.0,
+                })
+                .into()
+        })
+    } else {
+        None
+    };
+
     // Stack the main content with overlays
     let mut stack = Stack::new()
         .width(Length::Fill)
@@ -199,6 +315,57 @@ pub fn view(ctx: ViewContext<'_>) -> Element<'_, Message> {
         stack = stack.push(panel);
     }
 
+    // Add click-outside overlay and quick-search panel if open
+    if let Some(panel) = quick_search_overlay {
+        let click_outside = mouse_area(
+            Container::new(Text::new(""))
+                .width(Length::Fill)
+                .height(Length::Fill),
+        )
+        .on_press(Message::Viewer(component::Message::QuickSearch(
+            quick_search::Message::Close,
+        )));
+        stack = stack.push(click_outside);
+        stack = stack.push(panel);
+    }
+
+    // Add click-outside overlay and share panel if shown
+    if let Some(state) = ctx
+        .share_panel
+        .filter(|_| matches!(ctx.screen, Screen::Viewer))
+    {
+        let panel = share_panel::view_panel(share_panel::ViewContext {
+            i18n: ctx.i18n,
+            state,
+        })
+        .map(Message::SharePanel);
+        let panel_with_click_guard =
+            mouse_area(panel).on_press(Message::SharePanel(share_panel::Message::ConsumeClick));
+        let click_outside = mouse_area(
+            Container::new(Text::new(""))
+                .width(Length::Fill)
+                .height(Length::Fill),
+        )
+        .on_press(Message::SharePanel(share_panel::Message::Close));
+        stack = stack.push(click_outside);
+        stack = stack.push(Container::new(panel_with_click_guard).center(Length::Fill));
+    }
+
+    if let Some(snapshot) = ctx.diagnostics {
+        stack = stack.push(
+            Container::new(diagnostics_overlay::view(ctx.i18n, snapshot))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(iced::Padding {
+                    top: sizing::NAVBAR_HEIGHT + spacing::SM,
+                    right: spacing::SM,
+                    bottom: spacing::SM,
+                    left: spacing::SM,
+                })
+                .align_x(Horizontal::Right),
+        );
+    }
+
     stack.push(toast_overlay).into()
 }
 
@@ -223,10 +390,17 @@ fn view_viewer(ctx: ViewerViewContext<'_>) -> Element<'_, Message> {
             i18n: ctx.i18n,
             background_theme: ctx.settings.background_theme(),
             is_fullscreen: ctx.fullscreen,
+            compact: ctx.window_mode.compact,
             overlay_hide_delay: overlay_timeout.as_duration(),
+            fullscreen_overlay: ctx.settings.fullscreen_overlay(),
             navigation: ctx.navigation,
             metadata_editor_has_changes,
             filter: ctx.filter,
+            dots_per_inch: match ctx.current_metadata {
+                Some(MediaMetadata::Image(metadata)) => metadata.dots_per_inch,
+                _ => None,
+            },
+            guides: ctx.settings.guides(),
         })
         .map(Message::Viewer);
 
@@ -240,6 +414,11 @@ fn view_viewer(ctx: ViewerViewContext<'_>) -> Element<'_, Message> {
                 current_path: ctx.current_media_path,
                 editor_state: ctx.metadata_editor_state,
                 is_image: ctx.is_image,
+                tag_browser: Some(ctx.metadata_tag_browser),
+                batch: Some(ctx.metadata_batch),
+                batch_file_count: ctx.filtered_count,
+                shoot_stats: Some(ctx.shoot_stats),
+                decoder: ctx.viewer.media_decoder(),
             })
             .map(Message::MetadataPanel),
         )
@@ -247,8 +426,9 @@ fn view_viewer(ctx: ViewerViewContext<'_>) -> Element<'_, Message> {
         None
     };
 
-    // In fullscreen mode, don't show the navbar but show metadata panel as overlay
-    if ctx.fullscreen {
+    // In fullscreen and compact modes, don't show the navbar but show metadata
+    // panel as overlay
+    if ctx.fullscreen || ctx.window_mode.compact {
         if let Some(panel) = metadata_panel {
             // Fullscreen with metadata panel: overlay on right side
             let panel_container = Container::new(panel)
@@ -259,10 +439,15 @@ fn view_viewer(ctx: ViewerViewContext<'_>) -> Element<'_, Message> {
                 .width(Length::Fill)
                 .height(Length::Fill);
 
-            // Use Row to push content (panel floats on right)
+            // Use Row to push content (panel floats on the reading end:
+            // right, or left in right-to-left languages)
+            let (left, right) = ctx
+                .i18n
+                .layout_direction()
+                .order(Element::from(viewer_container), panel_container.into());
             Row::new()
-                .push(viewer_container)
-                .push(panel_container)
+                .push(left)
+                .push(right)
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .into()
@@ -283,6 +468,9 @@ fn view_viewer(ctx: ViewerViewContext<'_>) -> Element<'_, Message> {
             filter_dropdown: ctx.viewer.filter_dropdown_state(),
             total_count: ctx.total_count,
             filtered_count: ctx.filtered_count,
+            sharing: ctx.sharing,
+            open_with: ctx.open_with,
+            window_mode: ctx.window_mode,
         })
         .map(Message::Navbar);
 
@@ -297,9 +485,14 @@ fn view_viewer(ctx: ViewerViewContext<'_>) -> Element<'_, Message> {
                 .width(Length::Fill)
                 .height(Length::Fill);
 
+            // The panel is on the left in right-to-left languages
+            let (left, right) = ctx
+                .i18n
+                .layout_direction()
+                .order(Element::from(viewer_container), panel_container.into());
             Row::new()
-                .push(viewer_container)
-                .push(panel_container)
+                .push(left)
+                .push(right)
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .into()
@@ -324,24 +517,10 @@ fn view_settings<'a>(settings: &'a SettingsState, i18n: &'a I18n) -> Element<'a,
 
 fn view_image_editor<'a>(
     image_editor: Option<&'a ImageEditorState>,
-    i18n: &'a I18n,
-    settings: &'a SettingsState,
-    is_dark_theme: bool,
-    deblur_model_status: &'a ModelStatus,
-    upscale_model_status: &'a UpscaleModelStatus,
-    enable_upscale: bool,
+    editor_ctx: &image_editor::ViewContext<'a>,
 ) -> Element<'a, Message> {
     if let Some(editor_state) = image_editor {
-        editor_state
-            .view(&image_editor::ViewContext {
-                i18n,
-                background_theme: settings.background_theme(),
-                is_dark_theme,
-                deblur_model_status,
-                upscale_model_status,
-                enable_upscale,
-            })
-            .map(Message::ImageEditor)
+        editor_state.view(editor_ctx).map(Message::ImageEditor)
     } else {
         // Fallback if editor state is missing
         Container::new(Text::new("Editor error"))
@@ -353,6 +532,8 @@ fn view_image_editor<'a>(
 
 fn view_help<'a>(
     help_state: &'a crate::ui::help::State,
+    keyboard_layout: &'a KeyboardLayout,
+    keymap: &'a Keymap,
     i18n: &'a I18n,
     is_dark_theme: bool,
 ) -> Element<'a, Message> {
@@ -360,6 +541,8 @@ fn view_help<'a>(
         i18n,
         state: help_state,
         is_dark_theme,
+        keyboard_layout,
+        keymap,
     })
     .map(Message::Help)
 }
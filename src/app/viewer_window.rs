@@ -0,0 +1,319 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Additional viewer windows, to look at two files at once.
+//!
+//! "Open in new window" shows a file in a window of its own, with its own
+//! viewer and navigator: browsing the folder there doesn't move the main
+//! window. These windows share the settings, the notifications and the LUFS
+//! cache of the application. Editing, deleting and exporting stay in the main
+//! window, so the effects for them are ignored here.
+
+use super::config::MediaSort;
+use super::Message;
+use crate::i18n::fluent::I18n;
+use crate::media::{self, MediaData, MediaNavigator};
+use crate::ui::notifications;
+use crate::ui::settings::State as SettingsState;
+use crate::ui::viewer::{component, LoadOrigin, NavigationDirection};
+use crate::video_player::{KeyboardSeekStep, SequenceFps, SharedLufsCache};
+use iced::{window, Element, Subscription, Task};
+use std::path::PathBuf;
+
+/// Playback session IDs of viewer window `n` start at `n << SESSION_ID_SHIFT`,
+/// so its video never shares a subscription with another window's.
+const SESSION_ID_SHIFT: u32 = 48;
+
+/// A viewer in a window of its own.
+pub struct ViewerWindow {
+    window: window::Id,
+    viewer: component::State,
+    media_navigator: MediaNavigator,
+    fullscreen: bool,
+}
+
+/// Shared application state a viewer window reads or reports to.
+pub struct Env<'a> {
+    pub i18n: &'a I18n,
+    pub settings: &'a SettingsState,
+    pub notifications: &'a mut notifications::Manager,
+}
+
+impl ViewerWindow {
+    /// Creates the viewer of `window`, set up like the main viewer.
+    ///
+    /// `number` tells the windows opened during the session apart.
+    #[must_use]
+    pub fn new(
+        window: window::Id,
+        number: u64,
+        settings: &SettingsState,
+        main: &component::State,
+    ) -> Self {
+        let mut viewer = component::State::new();
+        viewer.set_playback_session_base(number << SESSION_ID_SHIFT);
+        viewer.set_zoom_step_percent(settings.zoom_step_percent());
+        if main.fit_to_window() {
+            viewer.enable_fit_to_window();
+        } else {
+            viewer.disable_fit_to_window();
+        }
+        viewer.set_video_autoplay(settings.video_autoplay());
+        viewer.set_remember_view(settings.remember_view_per_image());
+        viewer.set_show_minimap(settings.show_minimap());
+        viewer.set_video_volume(main.video_volume());
+        viewer.set_video_muted(main.video_muted());
+        viewer.set_layout_direction(main.layout_direction());
+        viewer.set_keyboard_seek_step(KeyboardSeekStep::new(settings.keyboard_seek_step_secs()));
+        viewer.set_sequence_fps(SequenceFps::new(settings.sequence_fps()));
+        viewer.set_mouse_mapping(settings.mouse_mapping());
+        viewer.set_tone_mapping(settings.tone_mapping());
+        viewer.set_max_skip_attempts(media::MaxSkipAttempts::new(settings.max_skip_attempts()));
+
+        Self {
+            window,
+            viewer,
+            media_navigator: MediaNavigator::new(),
+            fullscreen: false,
+        }
+    }
+
+    /// Returns the window of this viewer.
+    #[must_use]
+    pub fn window(&self) -> window::Id {
+        self.window
+    }
+
+    /// Returns the window title: the file name of the current media.
+    #[must_use]
+    pub fn title(&self, i18n: &I18n) -> String {
+        let app_name = i18n.tr("window-title");
+        match self
+            .media_navigator
+            .current_media_path()
+            .and_then(|path| path.file_name())
+        {
+            Some(name) => format!("{} - {app_name}", name.to_string_lossy()),
+            None => app_name,
+        }
+    }
+
+    /// Returns whether a periodic tick is needed to hide the fullscreen overlay.
+    #[must_use]
+    pub fn is_overlay_hide_pending(&self, delay: std::time::Duration) -> bool {
+        self.fullscreen && self.viewer.is_overlay_hide_pending(delay)
+    }
+
+    /// Scans the folder of `path` and loads it.
+    pub fn open(&mut self, path: PathBuf, settings: &SettingsState) -> Task<Message> {
+        let _ = self
+            .media_navigator
+            .scan_directory(&path, media_sort(settings));
+        self.viewer.current_media_path = Some(path.clone());
+        self.viewer.start_loading();
+        self.load(path, settings)
+    }
+
+    /// Plays the videos of this window while it is open.
+    pub fn subscription(
+        &self,
+        lufs_cache: Option<SharedLufsCache>,
+        settings: &SettingsState,
+    ) -> Subscription<Message> {
+        self.viewer
+            .subscription(
+                lufs_cache,
+                settings.audio_normalization(),
+                settings.frame_cache_mb(),
+                settings.frame_history_mb(),
+                settings.frame_cache_auto(),
+                settings.hardware_decoding(),
+            )
+            .with(self.window)
+            .map(|(window, message)| Message::ViewerWindow { window, message })
+    }
+
+    /// Processes a viewer message of this window.
+    pub fn update(&mut self, message: component::Message, env: Env<'_>) -> Task<Message> {
+        let (effect, task) = self.viewer.handle_message(message, env.i18n);
+        let window = self.window;
+        let viewer_task = task.map(move |message| Message::ViewerWindow { window, message });
+
+        let side_effect = match effect {
+            component::Effect::ToggleFullscreen => self.set_fullscreen(!self.fullscreen),
+            component::Effect::ExitFullscreen => self.set_fullscreen(false),
+            component::Effect::NavigateNext => {
+                self.viewer.set_navigation_origin(NavigationDirection::Next);
+                self.navigate(NavigationDirection::Next, 0, env.settings)
+            }
+            component::Effect::NavigatePrevious => {
+                self.viewer
+                    .set_navigation_origin(NavigationDirection::Previous);
+                self.navigate(NavigationDirection::Previous, 0, env.settings)
+            }
+            component::Effect::RetryNavigation {
+                direction,
+                skip_attempts,
+                skipped_files,
+            } => {
+                self.viewer.set_load_origin(LoadOrigin::Navigation {
+                    direction,
+                    skip_attempts,
+                    skipped_files,
+                });
+                self.navigate(direction, skip_attempts as usize, env.settings)
+            }
+            component::Effect::ConfirmNavigation {
+                path,
+                skipped_files,
+            } => {
+                self.media_navigator.confirm_navigation(&path);
+                if !skipped_files.is_empty() {
+                    push_skipped_files(env.i18n, env.notifications, &skipped_files);
+                }
+                Task::none()
+            }
+            component::Effect::ShowSkippedFilesNotification { skipped_files } => {
+                push_skipped_files(env.i18n, env.notifications, &skipped_files);
+                Task::none()
+            }
+            component::Effect::ShowErrorNotification { key, args } => {
+                let mut notification = notifications::Notification::error(key);
+                for (arg_key, arg_value) in args {
+                    notification = notification.with_arg(arg_key, arg_value);
+                }
+                env.notifications.push(notification);
+                Task::none()
+            }
+            component::Effect::OpenQuickSearch => {
+                let candidates = self.media_navigator.media_paths();
+                self.viewer
+                    .open_quick_search(candidates)
+                    .map(move |message| Message::ViewerWindow { window, message })
+            }
+            component::Effect::JumpToMedia(path) => {
+                if self.viewer.current_media_path.as_ref() == Some(&path) {
+                    Task::none()
+                } else {
+                    self.viewer.set_load_origin(LoadOrigin::DirectOpen);
+                    self.viewer.current_media_path = Some(path.clone());
+                    self.viewer.start_loading();
+                    self.load(path, env.settings)
+                }
+            }
+            component::Effect::CopyColor(color) => {
+                env.notifications.push(
+                    notifications::Notification::info("notification-color-copied")
+                        .with_arg("color", color.clone()),
+                );
+                iced::clipboard::write(color)
+            }
+            // Handled by the main window only
+            _ => Task::none(),
+        };
+
+        Task::batch([viewer_task, side_effect])
+    }
+
+    /// Refreshes the viewer after a change of interface language.
+    pub fn apply_locale(&mut self, i18n: &I18n) {
+        self.viewer.apply_locale(i18n);
+    }
+
+    /// Renders the viewer.
+    pub fn view<'a>(&'a self, i18n: &'a I18n, settings: &'a SettingsState) -> Element<'a, Message> {
+        let overlay_timeout =
+            crate::ui::state::OverlayTimeout::new(settings.overlay_timeout_secs());
+        let window = self.window;
+        self.viewer
+            .view(component::ViewEnv {
+                i18n,
+                background_theme: settings.background_theme(),
+                is_fullscreen: self.fullscreen,
+                compact: false,
+                overlay_hide_delay: overlay_timeout.as_duration(),
+                fullscreen_overlay: settings.fullscreen_overlay(),
+                navigation: self.media_navigator.navigation_info(),
+                metadata_editor_has_changes: false,
+                filter: self.media_navigator.filter(),
+                // Metadata is only read for the main window
+                dots_per_inch: None,
+                guides: settings.guides(),
+            })
+            .map(move |message| Message::ViewerWindow { window, message })
+    }
+
+    /// Loads the `skip_count`-th media after the current one in `direction`.
+    ///
+    /// The navigator position is only confirmed once the media has loaded.
+    fn navigate(
+        &mut self,
+        direction: NavigationDirection,
+        skip_count: usize,
+        settings: &SettingsState,
+    ) -> Task<Message> {
+        if skip_count == 0 {
+            if let Some(current_path) = self
+                .media_navigator
+                .current_media_path()
+                .map(std::path::Path::to_path_buf)
+            {
+                let _ = self
+                    .media_navigator
+                    .scan_directory(&current_path, media_sort(settings));
+            }
+        }
+        let next_path = match direction {
+            NavigationDirection::Next => self.media_navigator.peek_nth_next_filtered(skip_count),
+            NavigationDirection::Previous => {
+                self.media_navigator.peek_nth_previous_filtered(skip_count)
+            }
+        };
+        let Some(path) = next_path else {
+            return Task::none();
+        };
+        self.viewer.current_media_path = Some(path.clone());
+        self.viewer.start_loading();
+        self.load(path, settings)
+    }
+
+    fn load(&self, path: PathBuf, settings: &SettingsState) -> Task<Message> {
+        let window = self.window;
+        let load_options = settings.load_options();
+        Task::perform(
+            async move { media::load_media_with(&path, load_options) },
+            move |result: Result<MediaData, crate::error::Error>| Message::ViewerWindow {
+                window,
+                message: component::Message::MediaLoaded(result),
+            },
+        )
+    }
+
+    fn set_fullscreen(&mut self, fullscreen: bool) -> Task<Message> {
+        if self.fullscreen == fullscreen {
+            return Task::none();
+        }
+        self.fullscreen = fullscreen;
+        let mode = if fullscreen {
+            window::Mode::Fullscreen
+        } else {
+            window::Mode::Windowed
+        };
+        window::set_mode(self.window, mode)
+    }
+}
+
+fn media_sort(settings: &SettingsState) -> MediaSort {
+    MediaSort::new(settings.sort_order(), settings.sort_direction())
+}
+
+fn push_skipped_files(
+    i18n: &I18n,
+    notifications: &mut notifications::Manager,
+    skipped_files: &[String],
+) {
+    let files_text = super::update::format_skipped_files_message(i18n, skipped_files);
+    notifications.push(
+        notifications::Notification::warning("notification-skipped-corrupted-files")
+            .with_arg("files", files_text)
+            .auto_dismiss(std::time::Duration::from_secs(8)),
+    );
+}
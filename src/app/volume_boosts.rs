@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Volume boosts of quiet videos.
+//!
+//! A volume above 100% is usually only wanted for the file that needed it,
+//! so it is remembered for that file and the others keep the base volume.
+//! Boosts are keyed by path and file size like resume positions, and live in
+//! [`AppState`](super::persisted_state::AppState).
+
+use crate::config::UNITY_VOLUME;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Number of videos whose boost is remembered; the least recently boosted
+/// ones are forgotten first.
+const MAX_ENTRIES: usize = 200;
+
+/// Remembered boost of one video.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct BoostEntry {
+    path: PathBuf,
+    size: u64,
+    volume: f32,
+}
+
+/// Volumes above 100% of recently boosted videos, most recent first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VolumeBoosts {
+    entries: Vec<BoostEntry>,
+}
+
+impl VolumeBoosts {
+    /// Returns the volume chosen for the file at `path`, if it was boosted.
+    #[must_use]
+    pub fn get(&self, path: &Path, size: u64) -> Option<f32> {
+        self.entries
+            .iter()
+            .find(|entry| entry.path == path && entry.size == size)
+            .map(|entry| entry.volume)
+    }
+
+    /// Remembers the volume chosen for the file at `path`.
+    ///
+    /// Volumes of 100% or less forget the file instead, since they are the
+    /// base volume of every video. Returns true if anything changed.
+    pub fn remember(&mut self, path: &Path, size: u64, volume: f32) -> bool {
+        let existing = self.entries.iter().position(|entry| entry.path == path);
+        if volume <= UNITY_VOLUME {
+            return existing.map(|index| self.entries.remove(index)).is_some();
+        }
+
+        let entry = BoostEntry {
+            path: path.to_path_buf(),
+            size,
+            volume,
+        };
+        if existing == Some(0) && self.entries[0] == entry {
+            return false;
+        }
+        if let Some(index) = existing {
+            self.entries.remove(index);
+        }
+        self.entries.insert(0, entry);
+        self.entries.truncate(MAX_ENTRIES);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remembers_boost_by_path_and_size() {
+        let mut boosts = VolumeBoosts::default();
+        let path = Path::new("/videos/quiet.mp4");
+        assert!(boosts.remember(path, 1000, 1.6));
+        assert_eq!(boosts.get(path, 1000), Some(1.6));
+        // Same name, different file
+        assert_eq!(boosts.get(path, 2000), None);
+        // Unchanged boost doesn't need saving
+        assert!(!boosts.remember(path, 1000, 1.6));
+    }
+
+    #[test]
+    fn unboosted_volumes_forget_the_file() {
+        let mut boosts = VolumeBoosts::default();
+        let path = Path::new("/videos/quiet.mp4");
+        boosts.remember(path, 1000, 1.6);
+        assert!(boosts.remember(path, 1000, UNITY_VOLUME));
+        assert_eq!(boosts.get(path, 1000), None);
+        assert!(!boosts.remember(path, 1000, 0.5));
+    }
+
+    #[test]
+    fn forgets_least_recent_boosts_first() {
+        let mut boosts = VolumeBoosts::default();
+        for i in 0..=MAX_ENTRIES {
+            let path = PathBuf::from(format!("/videos/{i}.mp4"));
+            boosts.remember(&path, 1, 1.5);
+        }
+        assert_eq!(boosts.entries.len(), MAX_ENTRIES);
+        assert_eq!(boosts.get(Path::new("/videos/0.mp4"), 1), None);
+        assert_eq!(boosts.get(Path::new("/videos/1.mp4"), 1), Some(1.5));
+    }
+}
@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Chrome of the main window: always on top, borderless and compact.
+//!
+//! The three modes are toggled from the navbar menu and live in
+//! [`AppState`](super::persisted_state::AppState), so the window opens the
+//! way it was left. Compact mode hides the navbar and the viewer toolbars,
+//! leaving only the media; the toolbars come back over it when the mouse
+//! moves, as in fullscreen, and Esc leaves the mode.
+
+use iced::window;
+use serde::{Deserialize, Serialize};
+
+/// Window chrome chosen by the user, all off by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowMode {
+    /// The window stays above other windows.
+    #[serde(default)]
+    pub always_on_top: bool,
+    /// The window has no title bar or borders.
+    #[serde(default)]
+    pub borderless: bool,
+    /// Only the media is shown.
+    #[serde(default)]
+    pub compact: bool,
+}
+
+impl WindowMode {
+    /// Returns the stacking level of the window.
+    #[must_use]
+    pub fn level(self) -> window::Level {
+        if self.always_on_top {
+            window::Level::AlwaysOnTop
+        } else {
+            window::Level::Normal
+        }
+    }
+
+    /// Returns `settings` for a window opened in this mode.
+    #[must_use]
+    pub fn window_settings(self, settings: window::Settings) -> window::Settings {
+        window::Settings {
+            decorations: !self.borderless,
+            level: self.level(),
+            ..settings
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_opens_in_the_saved_mode() {
+        let mode = WindowMode {
+            always_on_top: true,
+            borderless: true,
+            compact: false,
+        };
+        let settings = mode.window_settings(window::Settings::default());
+        assert!(!settings.decorations);
+        assert_eq!(settings.level, window::Level::AlwaysOnTop);
+
+        let settings = WindowMode::default().window_settings(window::Settings::default());
+        assert!(settings.decorations);
+        assert_eq!(settings.level, window::Level::Normal);
+    }
+}
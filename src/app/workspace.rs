@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Workspace layouts: named snapshots of the viewer's panel setup.
+//!
+//! Each slot starts with a built-in layout ("culling", "presentation",
+//! "custom") that the user can overwrite with the current setup. Saved
+//! layouts live in [`AppState`](super::persisted_state::AppState).
+
+use serde::{Deserialize, Serialize};
+
+/// Number of layout slots, bound to `Ctrl+1` … `Ctrl+3`.
+pub const LAYOUT_SLOTS: usize = 3;
+
+/// Panel setup captured by a layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceLayout {
+    /// Whether the info panel is open.
+    pub info_panel_open: bool,
+    /// Whether the on-screen display (position, zoom, media indicators) is shown.
+    pub osd_visible: bool,
+}
+
+impl WorkspaceLayout {
+    /// Returns the built-in layout of `slot`, used until the user saves one.
+    #[must_use]
+    pub fn builtin(slot: usize) -> Self {
+        match slot {
+            // Culling: metadata at hand, indicators visible
+            0 => Self {
+                info_panel_open: true,
+                osd_visible: true,
+            },
+            // Presentation: nothing but the media
+            1 => Self {
+                info_panel_open: false,
+                osd_visible: false,
+            },
+            _ => Self {
+                info_panel_open: false,
+                osd_visible: true,
+            },
+        }
+    }
+}
+
+/// Returns the i18n key of the name of `slot`.
+#[must_use]
+pub fn slot_i18n_key(slot: usize) -> &'static str {
+    match slot {
+        0 => "layout-name-culling",
+        1 => "layout-name-presentation",
+        _ => "layout-name-custom",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_layouts_differ_per_slot() {
+        assert!(WorkspaceLayout::builtin(0).info_panel_open);
+        assert!(!WorkspaceLayout::builtin(1).osd_visible);
+        assert_ne!(WorkspaceLayout::builtin(1), WorkspaceLayout::builtin(2));
+        assert_ne!(slot_i18n_key(0), slot_i18n_key(1));
+    }
+}
@@ -0,0 +1,264 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Runtime performance diagnostics, shown in the overlay toggled with F12.
+//!
+//! Subsystems report into a process-wide store from whatever thread they
+//! run on: the video decoder its frames, drops and output queue depth, the
+//! video frame cache and the prefetch cache their lookups, and media loading
+//! its durations. [`snapshot`] reads everything at once for display.
+//!
+//! Reporting is a short mutex-guarded update, cheap enough to stay on while
+//! the overlay is hidden.
+
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Period over which the decode frame rate is measured.
+const FPS_WINDOW: Duration = Duration::from_secs(1);
+
+/// Number of media loads kept for display.
+pub const LOAD_HISTORY: usize = 5;
+
+/// A cache whose lookups are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cache {
+    /// Decoded keyframes of the video being played.
+    VideoFrames,
+    /// Decoded neighbors of the current media.
+    Prefetch,
+}
+
+/// Hits and misses of a cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheCounters {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheCounters {
+    /// Returns the share of lookups that hit, in percent, or `None` before
+    /// the first lookup.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Lookup counts stay far below 2^52
+    pub fn hit_rate(&self) -> Option<f64> {
+        let total = self.hits + self.misses;
+        (total > 0).then(|| self.hits as f64 * 100.0 / total as f64)
+    }
+}
+
+/// Duration of one media load.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadTime {
+    pub file_name: String,
+    pub duration: Duration,
+    pub succeeded: bool,
+}
+
+/// Diagnostics at one point in time.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Snapshot {
+    /// Video frames decoded over the last second.
+    pub decode_fps: usize,
+    /// Video frames skipped to catch up with the audio.
+    pub dropped_frames: u64,
+    /// Decoded frames waiting to be displayed.
+    pub frame_queue_depth: usize,
+    pub video_frame_cache: CacheCounters,
+    pub prefetch_cache: CacheCounters,
+    /// Resident memory of the process, where the platform reports it.
+    pub resident_memory_bytes: Option<u64>,
+    /// Latest media loads, newest first.
+    pub recent_loads: Vec<LoadTime>,
+}
+
+#[derive(Debug)]
+struct Store {
+    frame_times: VecDeque<Instant>,
+    dropped_frames: u64,
+    frame_queue_depth: usize,
+    video_frame_cache: CacheCounters,
+    prefetch_cache: CacheCounters,
+    recent_loads: VecDeque<LoadTime>,
+}
+
+impl Store {
+    const fn new() -> Self {
+        Self {
+            frame_times: VecDeque::new(),
+            dropped_frames: 0,
+            frame_queue_depth: 0,
+            video_frame_cache: CacheCounters { hits: 0, misses: 0 },
+            prefetch_cache: CacheCounters { hits: 0, misses: 0 },
+            recent_loads: VecDeque::new(),
+        }
+    }
+
+    fn record_frame(&mut self, now: Instant) {
+        self.frame_times.push_back(now);
+        self.prune_frames(now);
+    }
+
+    fn prune_frames(&mut self, now: Instant) {
+        while self
+            .frame_times
+            .front()
+            .is_some_and(|time| now.duration_since(*time) > FPS_WINDOW)
+        {
+            self.frame_times.pop_front();
+        }
+    }
+
+    fn record_load(&mut self, load: LoadTime) {
+        self.recent_loads.push_front(load);
+        self.recent_loads.truncate(LOAD_HISTORY);
+    }
+
+    fn snapshot(&mut self, now: Instant) -> Snapshot {
+        self.prune_frames(now);
+        Snapshot {
+            decode_fps: self.frame_times.len(),
+            dropped_frames: self.dropped_frames,
+            frame_queue_depth: self.frame_queue_depth,
+            video_frame_cache: self.video_frame_cache,
+            prefetch_cache: self.prefetch_cache,
+            resident_memory_bytes: resident_memory_bytes(),
+            recent_loads: self.recent_loads.iter().cloned().collect(),
+        }
+    }
+}
+
+static STORE: Mutex<Store> = Mutex::new(Store::new());
+
+fn store() -> MutexGuard<'static, Store> {
+    // Diagnostics are best effort: a panic elsewhere must not silence them
+    STORE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Records a video frame sent for display.
+pub fn record_frame_decoded() {
+    store().record_frame(Instant::now());
+}
+
+/// Records a video frame skipped to catch up with the audio.
+pub fn record_frame_dropped() {
+    store().dropped_frames += 1;
+}
+
+/// Sets the number of decoded frames waiting to be displayed.
+pub fn set_frame_queue_depth(depth: usize) {
+    store().frame_queue_depth = depth;
+}
+
+/// Sets the counters of the video frame cache, which keeps its own totals.
+pub fn set_video_frame_cache(hits: u64, misses: u64) {
+    store().video_frame_cache = CacheCounters { hits, misses };
+}
+
+/// Records a lookup in `cache`.
+pub fn record_cache_lookup(cache: Cache, hit: bool) {
+    let mut store = store();
+    let counters = match cache {
+        Cache::VideoFrames => &mut store.video_frame_cache,
+        Cache::Prefetch => &mut store.prefetch_cache,
+    };
+    if hit {
+        counters.hits += 1;
+    } else {
+        counters.misses += 1;
+    }
+}
+
+/// Records how long loading the media at `path` took.
+pub fn record_load(path: &Path, duration: Duration, succeeded: bool) {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    store().record_load(LoadTime {
+        file_name,
+        duration,
+        succeeded,
+    });
+}
+
+/// Returns the current diagnostics.
+#[must_use]
+pub fn snapshot() -> Snapshot {
+    store().snapshot(Instant::now())
+}
+
+/// Returns the resident memory of the process, read from `/proc` on Linux.
+fn resident_memory_bytes() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        parse_vm_rss(&status)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Parses the `VmRSS` line (in kB) of a `/proc/<pid>/status` file.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_vm_rss(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kilobytes: u64 = line
+        .trim_start_matches("VmRSS:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_rate_counts_frames_of_the_last_second() {
+        let mut store = Store::new();
+        let start = Instant::now();
+        for i in 0..30 {
+            store.record_frame(start + Duration::from_millis(i * 50));
+        }
+        // Frames 0..=9 (0-450 ms) are older than one second at 1.5 s
+        let snapshot = store.snapshot(start + Duration::from_millis(1500));
+        assert_eq!(snapshot.decode_fps, 20);
+        assert_eq!(store.snapshot(start + Duration::from_secs(5)).decode_fps, 0);
+    }
+
+    #[test]
+    fn load_history_keeps_the_newest_loads() {
+        let mut store = Store::new();
+        for i in 0..=LOAD_HISTORY {
+            store.record_load(LoadTime {
+                file_name: format!("{i}.jpg"),
+                duration: Duration::from_millis(10),
+                succeeded: true,
+            });
+        }
+        let loads = store.snapshot(Instant::now()).recent_loads;
+        assert_eq!(loads.len(), LOAD_HISTORY);
+        assert_eq!(loads[0].file_name, format!("{LOAD_HISTORY}.jpg"));
+        assert_eq!(loads[LOAD_HISTORY - 1].file_name, "1.jpg");
+    }
+
+    #[test]
+    fn hit_rate_needs_lookups() {
+        assert_eq!(CacheCounters::default().hit_rate(), None);
+        let counters = CacheCounters { hits: 3, misses: 1 };
+        assert_eq!(counters.hit_rate(), Some(75.0));
+    }
+
+    #[test]
+    fn vm_rss_is_read_in_kilobytes() {
+        let status = "Name:\ticed_lens\nVmPeak:\t  900000 kB\nVmRSS:\t  123456 kB\nThreads:\t12\n";
+        assert_eq!(parse_vm_rss(status), Some(123_456 * 1024));
+        assert_eq!(parse_vm_rss("Name:\ticed_lens\n"), None);
+    }
+}
@@ -3,13 +3,22 @@
 //!
 //! This module scans a directory for supported media formats (images and videos),
 //! filters them, and sorts them according to the configured sort order.
+//!
+//! Large directories can be scanned incrementally with [`scan_incremental`],
+//! which reports sorted snapshots of the files found so far, so navigation
+//! works before the scan of a 100k-file folder is over.
 
-use crate::config::SortOrder;
+use crate::config::{MediaSort, SortDirection, SortOrder};
 use crate::error::Result;
 use crate::media;
 use lexical_sort::natural_lexical_cmp;
 use std::path::{Path, PathBuf};
 
+/// Number of media files found before an incremental scan reports its first
+/// partial result. Later results are reported each time the count doubles,
+/// which keeps the total sorting work close to a single sort.
+pub const FIRST_SCAN_PAGE: usize = 500;
+
 /// Represents a list of media files (images and videos) in a directory with navigation capabilities.
 #[derive(Debug, Clone, PartialEq)]
 pub struct MediaList {
@@ -35,7 +44,7 @@ impl MediaList {
     ///
     /// Returns an error if the parent directory cannot be read or file metadata
     /// cannot be accessed during sorting.
-    pub fn scan_directory(current_file: &Path, sort_order: SortOrder) -> Result<Self> {
+    pub fn scan_directory(current_file: &Path, sort: impl Into<MediaSort>) -> Result<Self> {
         let parent = current_file
             .parent()
             .ok_or_else(|| crate::error::Error::Io("No parent directory".into()))?;
@@ -51,7 +60,7 @@ impl MediaList {
             }
         }
 
-        sort_media_files(&mut media_files, sort_order);
+        sort_media_files(&mut media_files, sort.into());
 
         // Find current file in the list (may be None if file was deleted)
         let current_index = media_files.iter().position(|p| p == current_file);
@@ -69,7 +78,7 @@ impl MediaList {
     ///
     /// Returns an error if the directory cannot be read or file metadata
     /// cannot be accessed during sorting.
-    pub fn scan_directory_direct(directory: &Path, sort_order: SortOrder) -> Result<Self> {
+    pub fn scan_directory_direct(directory: &Path, sort: impl Into<MediaSort>) -> Result<Self> {
         let mut media_files = Vec::new();
 
         for entry in std::fs::read_dir(directory)? {
@@ -81,7 +90,7 @@ impl MediaList {
             }
         }
 
-        sort_media_files(&mut media_files, sort_order);
+        sort_media_files(&mut media_files, sort.into());
 
         // Set current_index to first file if any exist
         let current_index = if media_files.is_empty() {
@@ -96,6 +105,18 @@ impl MediaList {
         })
     }
 
+    /// Creates a list from already sorted `media_files`, positioned on
+    /// `current` if it is one of them.
+    #[must_use]
+    pub fn from_sorted(media_files: Vec<PathBuf>, current: Option<&Path>) -> Self {
+        let current_index =
+            current.and_then(|current| media_files.iter().position(|p| p == current));
+        Self {
+            media_files,
+            current_index,
+        }
+    }
+
     /// Returns the first media file in the list, if any.
     pub fn first(&self) -> Option<&Path> {
         self.media_files.first().map(std::path::PathBuf::as_path)
@@ -217,25 +238,67 @@ impl Default for MediaList {
     }
 }
 
+/// Scans `directory` for supported media files, calling `on_snapshot` with
+/// the sorted list of the files found so far and whether the scan is over.
+///
+/// `seed` (the file being opened) is part of every snapshot, so it can be
+/// shown and navigated from before the scan reaches it. The last call always
+/// has the complete list.
+///
+/// This performs blocking I/O and should run off the UI thread.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be read. Snapshots reported
+/// before the error remain valid but incomplete.
+pub fn scan_incremental(
+    directory: &Path,
+    seed: Option<&Path>,
+    sort: impl Into<MediaSort>,
+    on_snapshot: &mut dyn FnMut(Vec<PathBuf>, bool),
+) -> Result<()> {
+    let sort = sort.into();
+    let mut media_files: Vec<PathBuf> = seed.map(Path::to_path_buf).into_iter().collect();
+    let mut next_snapshot = FIRST_SCAN_PAGE;
+
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        if Some(path.as_path()) == seed || !path.is_file() || !is_supported_media(&path) {
+            continue;
+        }
+        media_files.push(path);
+
+        if media_files.len() >= next_snapshot {
+            let mut snapshot = media_files.clone();
+            sort_media_files(&mut snapshot, sort);
+            on_snapshot(snapshot, false);
+            next_snapshot *= 2;
+        }
+    }
+
+    sort_media_files(&mut media_files, sort);
+    on_snapshot(media_files, true);
+    Ok(())
+}
+
 /// Checks if a file has a supported media extension (images or videos).
 fn is_supported_media(path: &Path) -> bool {
     media::detect_media_type(path).is_some()
 }
 
-/// Sorts a list of media file paths according to the specified sort order.
+/// Sorts a list of media file paths according to the specified sort order and direction.
 ///
-/// For alphabetical sorting, uses natural lexical sorting which:
+/// Filenames are compared with natural lexical sorting, which:
 /// - Is case-insensitive ('a' == 'A')
 /// - Handles numbers naturally ('file2' < 'file10')
 /// - Treats accented characters as their base ASCII equivalent ('é' ≈ 'e')
-fn sort_media_files(media_files: &mut [PathBuf], sort_order: SortOrder) {
-    match sort_order {
+///
+/// Size and type orders fall back to the filename so equal keys stay stable
+/// across rescans.
+fn sort_media_files(media_files: &mut [PathBuf], sort: MediaSort) {
+    match sort.order {
         SortOrder::Alphabetical => {
-            media_files.sort_by(|a, b| {
-                let a_name = a.file_name().and_then(|n| n.to_str()).unwrap_or("");
-                let b_name = b.file_name().and_then(|n| n.to_str()).unwrap_or("");
-                natural_lexical_cmp(a_name, b_name)
-            });
+            media_files.sort_by(|a, b| natural_lexical_cmp(file_name(a), file_name(b)));
         }
         SortOrder::ModifiedDate => {
             media_files.sort_by(|a, b| {
@@ -263,9 +326,37 @@ fn sort_media_files(media_files: &mut [PathBuf], sort_order: SortOrder) {
                 a_time.cmp(&b_time)
             });
         }
+        SortOrder::FileSize => {
+            // Name first, then a stable size sort keeps equal sizes in name order.
+            // The cached key stats each file once instead of O(n log n) times.
+            media_files.sort_by(|a, b| natural_lexical_cmp(file_name(a), file_name(b)));
+            media_files.sort_by_cached_key(|path| path.metadata().map_or(0, |m| m.len()));
+        }
+        SortOrder::FileType => {
+            media_files.sort_by(|a, b| {
+                extension(a)
+                    .cmp(&extension(b))
+                    .then_with(|| natural_lexical_cmp(file_name(a), file_name(b)))
+            });
+        }
+    }
+
+    if sort.direction == SortDirection::Descending {
+        media_files.reverse();
     }
 }
 
+fn file_name(path: &Path) -> &str {
+    path.file_name().and_then(|n| n.to_str()).unwrap_or("")
+}
+
+fn extension(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -319,6 +410,63 @@ mod tests {
         assert_eq!(list.media_files[2], img_c);
     }
 
+    #[test]
+    fn scan_directory_sorts_numbers_naturally() {
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let img10 = create_test_image(temp_dir.path(), "img10.jpg");
+        let img2 = create_test_image(temp_dir.path(), "img2.jpg");
+        let img1 = create_test_image(temp_dir.path(), "img1.jpg");
+
+        let list = MediaList::scan_directory(&img1, SortOrder::Alphabetical)
+            .expect("failed to scan directory");
+
+        assert_eq!(list.media_files, vec![img1, img2, img10]);
+    }
+
+    #[test]
+    fn scan_directory_sorts_by_file_size_then_name() {
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let large = temp_dir.path().join("a.jpg");
+        fs::write(&large, vec![0u8; 64]).expect("failed to write test file");
+        let small_b = temp_dir.path().join("b.jpg");
+        fs::write(&small_b, b"x").expect("failed to write test file");
+        let small_c = temp_dir.path().join("c.jpg");
+        fs::write(&small_c, b"y").expect("failed to write test file");
+
+        let list = MediaList::scan_directory(&large, SortOrder::FileSize)
+            .expect("failed to scan directory");
+
+        assert_eq!(list.media_files, vec![small_b, small_c, large]);
+    }
+
+    #[test]
+    fn scan_directory_sorts_by_file_type_then_name() {
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let png = create_test_image(temp_dir.path(), "a.png");
+        let jpg_upper = create_test_image(temp_dir.path(), "b.JPG");
+        let jpg_lower = create_test_image(temp_dir.path(), "c.jpg");
+        let mp4 = create_test_video(temp_dir.path(), "d.mp4");
+
+        let list =
+            MediaList::scan_directory(&png, SortOrder::FileType).expect("failed to scan directory");
+
+        assert_eq!(list.media_files, vec![jpg_upper, jpg_lower, mp4, png]);
+    }
+
+    #[test]
+    fn scan_directory_descending_reverses_order() {
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let img_a = create_test_image(temp_dir.path(), "a.jpg");
+        let img_b = create_test_image(temp_dir.path(), "b.jpg");
+        let img_c = create_test_image(temp_dir.path(), "c.jpg");
+
+        let sort = MediaSort::new(SortOrder::Alphabetical, SortDirection::Descending);
+        let list = MediaList::scan_directory(&img_a, sort).expect("failed to scan directory");
+
+        assert_eq!(list.media_files, vec![img_c, img_b, img_a]);
+        assert_eq!(list.current_index(), Some(2));
+    }
+
     #[test]
     fn next_wraps_around_to_first() {
         let temp_dir = tempdir().expect("failed to create temp dir");
@@ -529,4 +677,44 @@ mod tests {
 
         assert_eq!(list.first(), Some(img_a.as_path()));
     }
+
+    #[test]
+    fn scan_incremental_reports_growing_sorted_snapshots() {
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let count = FIRST_SCAN_PAGE * 2 + 10;
+        for i in 0..count {
+            create_test_image(temp_dir.path(), &format!("img{i}.jpg"));
+        }
+        create_test_image(temp_dir.path(), "notes.txt");
+        let seed = temp_dir.path().join("img7.jpg");
+
+        let mut snapshots = Vec::new();
+        scan_incremental(
+            temp_dir.path(),
+            Some(&seed),
+            SortOrder::Alphabetical,
+            &mut |files, complete| snapshots.push((files, complete)),
+        )
+        .expect("failed to scan directory");
+
+        let sizes: Vec<usize> = snapshots.iter().map(|(files, _)| files.len()).collect();
+        assert_eq!(sizes, [FIRST_SCAN_PAGE, FIRST_SCAN_PAGE * 2, count]);
+        assert_eq!(
+            snapshots
+                .iter()
+                .map(|(_, complete)| *complete)
+                .collect::<Vec<_>>(),
+            [false, false, true]
+        );
+        for (files, _) in &snapshots {
+            assert!(files.contains(&seed), "the seed is in every snapshot");
+            assert!(files.windows(2).all(|pair| natural_lexical_cmp(
+                file_name(&pair[0]),
+                file_name(&pair[1])
+            )
+            .is_le()));
+        }
+        let list = MediaList::from_sorted(snapshots.pop().expect("final snapshot").0, Some(&seed));
+        assert_eq!(list.current(), Some(seed.as_path()));
+    }
 }
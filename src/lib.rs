@@ -7,6 +7,7 @@
 #![doc(html_root_url = "https://docs.rs/iced_lens/0.1.0")]
 
 pub mod app;
+pub mod diagnostics;
 pub mod directory_scanner;
 pub mod error;
 pub mod icon;
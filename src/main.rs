@@ -4,11 +4,53 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use iced_lens::app::{self, Flags};
+use iced_lens::media::convert;
+use iced_lens::media::decoders::DecoderPreferences;
+use iced_lens::media::sandbox;
+use std::path::{Path, PathBuf};
 
 /// Application run mode derived from CLI arguments.
 pub enum RunMode {
     Normal(Flags),
     Help(Option<String>, Option<String>), // (lang, i18n_dir)
+    /// `iced_lens verify <dir>`: check media files for corruption and exit.
+    Verify {
+        dir: PathBuf,
+        lang: Option<String>,
+        i18n_dir: Option<String>,
+    },
+    /// `iced_lens convert <input> <output> [--quality <1-100>]`: convert a
+    /// media file to the image format of `output` and exit.
+    Convert {
+        input: PathBuf,
+        output: PathBuf,
+        quality: u8,
+    },
+    /// `iced_lens thumb <path> [--size <px>] [--out <dir>]`: write JPEG
+    /// thumbnails of a file or of every media file of a directory and exit.
+    Thumb {
+        input: PathBuf,
+        size: u32,
+        out: Option<PathBuf>,
+        lang: Option<String>,
+        i18n_dir: Option<String>,
+    },
+    /// `iced_lens info <path> [--json|--csv]`: print the metadata of a media
+    /// file, or of every media file of a directory, and exit.
+    Info {
+        path: PathBuf,
+        json: bool,
+        csv: bool,
+        lang: Option<String>,
+        i18n_dir: Option<String>,
+    },
+    /// `iced_lens decode-helper [--webp-decoder <name>] <input> <output>`:
+    /// sandboxed image decoding (internal, started by the viewer).
+    DecodeHelper {
+        input: PathBuf,
+        output: PathBuf,
+        decoders: DecoderPreferences,
+    },
 }
 
 fn parse_run_mode(mut args: pico_args::Arguments) -> Result<RunMode, pico_args::Error> {
@@ -16,18 +58,83 @@ fn parse_run_mode(mut args: pico_args::Arguments) -> Result<RunMode, pico_args::
     let i18n_dir = args.opt_value_from_str("--i18n-dir")?;
     let data_dir = args.opt_value_from_str("--data-dir")?;
     let config_dir = args.opt_value_from_str("--config-dir")?;
+    let webp_decoder = args.opt_value_from_str(sandbox::WEBP_DECODER_OPTION)?;
+    let quality: Option<u8> = args.opt_value_from_str("--quality")?;
+    let size: Option<u32> = args.opt_value_from_str("--size")?;
+    let out: Option<PathBuf> = args.opt_value_from_str("--out")?;
+    let watch_i18n = args.contains("--watch-i18n");
+    let json = args.contains("--json");
+    let csv = args.contains("--csv");
     if args.contains("--help") || args.contains("-h") {
         return Ok(RunMode::Help(lang, i18n_dir));
     }
-    let file_path = args
+    let mut free = args
         .finish()
         .into_iter()
-        .next()
-        .and_then(|s| s.into_string().ok());
+        .filter_map(|s| s.into_string().ok());
+    let file_path = free.next();
+    if file_path.as_deref() == Some(sandbox::HELPER_COMMAND) {
+        if let (Some(input), Some(output)) = (free.next(), free.next()) {
+            return Ok(RunMode::DecodeHelper {
+                input: PathBuf::from(input),
+                output: PathBuf::from(output),
+                decoders: DecoderPreferences {
+                    webp: webp_decoder.unwrap_or_default(),
+                },
+            });
+        }
+        return Err(pico_args::Error::MissingArgument);
+    }
+    if file_path.as_deref() == Some("verify") {
+        let dir = free
+            .next()
+            .map_or_else(|| PathBuf::from("."), PathBuf::from);
+        return Ok(RunMode::Verify {
+            dir,
+            lang,
+            i18n_dir,
+        });
+    }
+    match file_path.as_deref() {
+        Some("convert") => {
+            return match (free.next(), free.next()) {
+                (Some(input), Some(output)) => Ok(RunMode::Convert {
+                    input: PathBuf::from(input),
+                    output: PathBuf::from(output),
+                    quality: quality.unwrap_or(convert::DEFAULT_JPEG_QUALITY),
+                }),
+                _ => Err(pico_args::Error::MissingArgument),
+            };
+        }
+        Some("thumb") => {
+            let input = free
+                .next()
+                .map_or_else(|| PathBuf::from("."), PathBuf::from);
+            return Ok(RunMode::Thumb {
+                input,
+                size: size.unwrap_or(convert::DEFAULT_THUMBNAIL_SIZE),
+                out,
+                lang,
+                i18n_dir,
+            });
+        }
+        Some("info") => {
+            let path = free.next().ok_or(pico_args::Error::MissingArgument)?;
+            return Ok(RunMode::Info {
+                path: PathBuf::from(path),
+                json,
+                csv,
+                lang,
+                i18n_dir,
+            });
+        }
+        _ => {}
+    }
     Ok(RunMode::Normal(Flags {
         lang,
         file_path,
         i18n_dir,
+        watch_i18n,
         data_dir,
         config_dir,
     }))
@@ -42,39 +149,346 @@ fn main() -> iced::Result {
             println!("{}", help_text(&i18n));
             Ok(())
         }
+        RunMode::Verify {
+            dir,
+            lang,
+            i18n_dir,
+        } => {
+            let (config, _) = iced_lens::config::load();
+            let i18n = iced_lens::i18n::fluent::I18n::new(lang, i18n_dir, &config);
+            std::process::exit(run_verify(&dir, &i18n));
+        }
+        RunMode::Convert {
+            input,
+            output,
+            quality,
+        } => std::process::exit(run_convert(&input, &output, quality)),
+        RunMode::Thumb {
+            input,
+            size,
+            out,
+            lang,
+            i18n_dir,
+        } => {
+            let (config, _) = iced_lens::config::load();
+            let i18n = iced_lens::i18n::fluent::I18n::new(lang, i18n_dir, &config);
+            std::process::exit(run_thumb(&input, size, out.as_deref(), &i18n));
+        }
+        RunMode::Info {
+            path,
+            json,
+            csv,
+            lang,
+            i18n_dir,
+        } => {
+            let (config, _) = iced_lens::config::load();
+            let i18n = iced_lens::i18n::fluent::I18n::new(lang, i18n_dir, &config);
+            std::process::exit(run_info(&path, json, csv, &i18n));
+        }
+        RunMode::DecodeHelper {
+            input,
+            output,
+            decoders,
+        } => {
+            std::process::exit(sandbox::run_helper(&input, &output, decoders));
+        }
         RunMode::Normal(flags) => {
             // Initialize CLI path overrides before any config/state loading
             iced_lens::app::paths::init_cli_overrides(
                 flags.data_dir.clone(),
                 flags.config_dir.clone(),
             );
+            let _log_guard = app::logging::init();
             app::run(flags)
         }
     }
 }
+/// Verifies the media files of `dir`, prints damaged files, and returns the exit code
+/// (0 when all files are intact, 1 when some are damaged, 2 on error).
+fn run_verify(dir: &Path, i18n: &iced_lens::i18n::fluent::I18n) -> i32 {
+    use iced_lens::media::integrity;
+
+    let files = match integrity::collect_media_files(dir) {
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("{}: {e}", dir.display());
+            return 2;
+        }
+    };
+
+    let report = integrity::verify_files(&files, |done, total| {
+        eprint!("\r{done}/{total}");
+    });
+    eprintln!();
+
+    for damaged in &report.damaged {
+        println!("{}\t{}", damaged.path.display(), damaged.reason);
+    }
+
+    let checked = report.checked.to_string();
+    let damaged = report.damaged.len().to_string();
+    eprintln!(
+        "{}",
+        i18n.tr_with_args(
+            "cli-verify-summary",
+            &[("checked", checked.as_str()), ("damaged", damaged.as_str())],
+        )
+    );
+
+    i32::from(!report.is_clean())
+}
+
+/// Converts `input` to the image `output` and returns the exit code
+/// (0 on success, 2 on error).
+fn run_convert(input: &Path, output: &Path, quality: u8) -> i32 {
+    match convert::convert(input, output, quality) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("{}: {e}", input.display());
+            2
+        }
+    }
+}
+
+/// Writes thumbnails of `input` (a file or a directory) to `out`, by default
+/// a `thumbnails` directory next to the originals, printing each path
+/// written. Returns the exit code (0 when all were written, 1 when some
+/// failed, 2 on error).
+fn run_thumb(
+    input: &Path,
+    size: u32,
+    out: Option<&Path>,
+    i18n: &iced_lens::i18n::fluent::I18n,
+) -> i32 {
+    let (files, default_out) = if input.is_dir() {
+        match iced_lens::media::integrity::collect_media_files(input) {
+            Ok(files) => (files, input.join(convert::THUMBNAIL_DIR)),
+            Err(e) => {
+                eprintln!("{}: {e}", input.display());
+                return 2;
+            }
+        }
+    } else {
+        let parent = input.parent().unwrap_or_else(|| Path::new("."));
+        (
+            vec![input.to_path_buf()],
+            parent.join(convert::THUMBNAIL_DIR),
+        )
+    };
+    let out = out.map_or(default_out, Path::to_path_buf);
+
+    let mut failed = 0;
+    for file in &files {
+        match convert::write_thumbnail(file, &out, size.max(1)) {
+            Ok(thumbnail) => println!("{}", thumbnail.display()),
+            Err(e) => {
+                eprintln!("{}: {e}", file.display());
+                failed += 1;
+            }
+        }
+    }
+
+    let written = (files.len() - failed).to_string();
+    let failed_count = failed.to_string();
+    eprintln!(
+        "{}",
+        i18n.tr_with_args(
+            "cli-thumb-summary",
+            &[
+                ("written", written.as_str()),
+                ("failed", failed_count.as_str()),
+            ],
+        )
+    );
+
+    i32::from(failed > 0)
+}
+
+/// Prints the metadata of `path`, as labeled lines, JSON or CSV, and returns
+/// the exit code (0 on success, 2 when the file has no readable metadata).
+/// Directories are handled by [`run_info_dir`].
+fn run_info(path: &Path, json: bool, csv: bool, i18n: &iced_lens::i18n::fluent::I18n) -> i32 {
+    use iced_lens::media::{metadata, metadata_export};
+
+    if path.is_dir() {
+        return run_info_dir(path, json, csv, i18n);
+    }
+
+    let Some(info) = metadata::extract_metadata(path) else {
+        eprintln!("{}: {}", path.display(), i18n.tr("cli-info-unsupported"));
+        return 2;
+    };
+
+    if csv {
+        print!(
+            "{}",
+            metadata_export::to_csv(&[metadata_export::Record {
+                path: path.to_string_lossy().into_owned(),
+                metadata: Some(info),
+            }])
+        );
+        return 0;
+    }
+
+    if json {
+        match serde_json::to_string_pretty(&info) {
+            Ok(text) => println!("{text}"),
+            Err(e) => {
+                eprintln!("{}: {e}", path.display());
+                return 2;
+            }
+        }
+        return 0;
+    }
+
+    print_metadata(&info, i18n);
+    0
+}
+
+/// Prints the metadata of every media file of `dir`: a JSON array or a CSV
+/// table for cataloging, or a block of labeled lines per file. Returns the
+/// exit code (0 on success, 2 when the directory can't be read).
+fn run_info_dir(dir: &Path, json: bool, csv: bool, i18n: &iced_lens::i18n::fluent::I18n) -> i32 {
+    use iced_lens::media::metadata_export;
+
+    let files = match iced_lens::media::integrity::collect_media_files(dir) {
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("{}: {e}", dir.display());
+            return 2;
+        }
+    };
+    let records = metadata_export::collect(&files);
+
+    if csv {
+        print!("{}", metadata_export::to_csv(&records));
+    } else if json {
+        match metadata_export::to_json(&records) {
+            Ok(text) => println!("{text}"),
+            Err(e) => {
+                eprintln!("{}: {e}", dir.display());
+                return 2;
+            }
+        }
+    } else {
+        for record in &records {
+            println!("{}", record.path);
+            match &record.metadata {
+                Some(info) => print_metadata(info, i18n),
+                None => eprintln!("{}: {}", record.path, i18n.tr("cli-info-unsupported")),
+            }
+            println!();
+        }
+    }
+
+    0
+}
+
+/// Prints `info` as one labeled line per known field, then every raw tag.
+fn print_metadata(
+    info: &iced_lens::media::metadata::MediaMetadata,
+    i18n: &iced_lens::i18n::fluent::I18n,
+) {
+    use iced_lens::media::metadata::{self, MediaMetadata};
+
+    let (width, height) = info.dimensions();
+    let mut lines = vec![(
+        "metadata-label-dimensions",
+        Some(format!("{width} × {height}")),
+    )];
+    lines.push((
+        "metadata-label-file-size",
+        info.file_size().map(metadata::format_file_size),
+    ));
+    match info {
+        MediaMetadata::Image(image) => {
+            let camera = match (&image.camera_make, &image.camera_model) {
+                (Some(make), Some(model)) => Some(format!("{make} {model}")),
+                (make, model) => make.clone().or_else(|| model.clone()),
+            };
+            lines.extend([
+                ("metadata-label-format", image.format.clone()),
+                ("metadata-label-date-taken", image.date_taken.clone()),
+                ("metadata-label-camera", camera),
+                ("metadata-label-exposure", image.exposure_time.clone()),
+                ("metadata-label-aperture", image.aperture.clone()),
+                ("metadata-label-iso", image.iso.clone()),
+                ("metadata-label-focal-length", image.focal_length.clone()),
+                (
+                    "metadata-label-gps",
+                    image
+                        .gps_latitude
+                        .zip(image.gps_longitude)
+                        .map(|(lat, lon)| metadata::format_gps_coordinates(lat, lon)),
+                ),
+            ]);
+        }
+        MediaMetadata::Video(video) => {
+            lines.extend([
+                ("metadata-label-format", video.container_format.clone()),
+                (
+                    "metadata-label-duration",
+                    Some(format!("{:.2} s", video.duration_secs)),
+                ),
+                ("metadata-label-fps", Some(format!("{:.3}", video.fps))),
+                ("metadata-label-codec", video.video_codec.clone()),
+                (
+                    "metadata-label-bitrate",
+                    video.video_bitrate.map(metadata::format_bitrate),
+                ),
+                (
+                    "metadata-label-hdr",
+                    video.hdr.map(|hdr| i18n.tr(hdr.transfer.i18n_key())),
+                ),
+            ]);
+        }
+    }
+
+    for (key, value) in lines {
+        if let Some(value) = value {
+            println!("{}\t{value}", i18n.tr(key));
+        }
+    }
+    if let MediaMetadata::Image(image) = info {
+        for tag in &image.all_tags {
+            println!("{}:{}\t{}", tag.group, tag.name, tag.value);
+        }
+    }
+}
+
 fn help_text(i18n: &iced_lens::i18n::fluent::I18n) -> String {
     format!(
-        "{desc}\n\n{usage}\n  iced_lens [OPTIONS] [PATH]\n\n{opts}\n  {line_help}\n  {line_lang}\n  {line_i18n_dir}\n  {line_data_dir}\n  {line_config_dir}\n\n{args}\n  {arg_path}\n\n{examples}\n  {ex1}\n  {ex2}\n  {ex3}\n",
+        "{desc}\n\n{usage}\n  iced_lens [OPTIONS] [PATH]\n\n{opts}\n  {line_help}\n  {line_lang}\n  {line_i18n_dir}\n  {line_watch_i18n}\n  {line_data_dir}\n  {line_config_dir}\n\n{args}\n  {arg_path}\n\n{commands}\n  {cmd_verify}\n  {cmd_convert}\n  {cmd_thumb}\n  {cmd_info}\n\n{examples}\n  {ex1}\n  {ex2}\n  {ex3}\n  {ex4}\n  {ex5}\n  {ex6}\n",
         desc = i18n.tr("help-description"),
         usage = i18n.tr("help-usage-heading"),
         opts = i18n.tr("help-options-heading"),
         line_help = i18n.tr("help-line-option-help"),
         line_lang = i18n.tr("help-line-option-lang"),
         line_i18n_dir = i18n.tr("help-line-option-i18n-dir"),
+        line_watch_i18n = i18n.tr("help-line-option-watch-i18n"),
         line_data_dir = i18n.tr("help-line-option-data-dir"),
         line_config_dir = i18n.tr("help-line-option-config-dir"),
         args = i18n.tr("help-args-heading"),
         arg_path = i18n.tr("help-arg-image-path"),
+        commands = i18n.tr("help-commands-heading"),
+        cmd_verify = i18n.tr("help-line-command-verify"),
+        cmd_convert = i18n.tr("help-line-command-convert"),
+        cmd_thumb = i18n.tr("help-line-command-thumb"),
+        cmd_info = i18n.tr("help-line-command-info"),
         examples = i18n.tr("help-examples-heading"),
         ex1 = i18n.tr("help-example-1"),
         ex2 = i18n.tr("help-example-2"),
         ex3 = i18n.tr("help-example-3"),
+        ex4 = i18n.tr("help-example-4"),
+        ex5 = i18n.tr("help-example-5"),
+        ex6 = i18n.tr("help-example-6"),
     )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use iced_lens::media::decoders::WebpDecoder;
     use std::ffi::OsString;
 
     #[test]
@@ -84,6 +498,7 @@ mod tests {
             OsString::from("fr"),
             OsString::from("--i18n-dir"),
             OsString::from("custom/langs"),
+            OsString::from("--watch-i18n"),
             OsString::from("image.png"),
         ];
         let mode = parse_run_mode(pico_args::Arguments::from_vec(args)).expect("parse should work");
@@ -92,8 +507,16 @@ mod tests {
                 assert_eq!(flags.lang.as_deref(), Some("fr"));
                 assert_eq!(flags.file_path.as_deref(), Some("image.png"));
                 assert_eq!(flags.i18n_dir.as_deref(), Some("custom/langs"));
+                assert!(flags.watch_i18n);
+            }
+            RunMode::Help(_, _)
+            | RunMode::Verify { .. }
+            | RunMode::Convert { .. }
+            | RunMode::Thumb { .. }
+            | RunMode::Info { .. }
+            | RunMode::DecodeHelper { .. } => {
+                panic!("expected Normal mode")
             }
-            RunMode::Help(_, _) => panic!("expected Normal mode"),
         }
     }
 
@@ -106,10 +529,18 @@ mod tests {
                 assert!(flags.lang.is_none());
                 assert!(flags.file_path.is_none());
                 assert!(flags.i18n_dir.is_none());
+                assert!(!flags.watch_i18n);
                 assert!(flags.data_dir.is_none());
                 assert!(flags.config_dir.is_none());
             }
-            RunMode::Help(_, _) => panic!("expected Normal mode"),
+            RunMode::Help(_, _)
+            | RunMode::Verify { .. }
+            | RunMode::Convert { .. }
+            | RunMode::Thumb { .. }
+            | RunMode::Info { .. }
+            | RunMode::DecodeHelper { .. } => {
+                panic!("expected Normal mode")
+            }
         }
     }
 
@@ -127,7 +558,14 @@ mod tests {
                 assert_eq!(flags.data_dir.as_deref(), Some("/custom/data"));
                 assert_eq!(flags.config_dir.as_deref(), Some("/custom/config"));
             }
-            RunMode::Help(_, _) => panic!("expected Normal mode"),
+            RunMode::Help(_, _)
+            | RunMode::Verify { .. }
+            | RunMode::Convert { .. }
+            | RunMode::Thumb { .. }
+            | RunMode::Info { .. }
+            | RunMode::DecodeHelper { .. } => {
+                panic!("expected Normal mode")
+            }
         }
     }
 
@@ -137,7 +575,14 @@ mod tests {
         let mode = parse_run_mode(pico_args::Arguments::from_vec(args)).expect("parse should work");
         match mode {
             RunMode::Help(_, _) => {}
-            RunMode::Normal(_) => panic!("expected Help mode"),
+            RunMode::Normal(_)
+            | RunMode::Verify { .. }
+            | RunMode::Convert { .. }
+            | RunMode::Thumb { .. }
+            | RunMode::Info { .. }
+            | RunMode::DecodeHelper { .. } => {
+                panic!("expected Help mode")
+            }
         }
     }
 
@@ -159,7 +604,147 @@ mod tests {
                 assert!(text.contains("UTILISATION"));
                 assert!(text.contains("OPTIONS"));
             }
-            RunMode::Normal(_) => panic!("expected Help mode"),
+            RunMode::Normal(_)
+            | RunMode::Verify { .. }
+            | RunMode::Convert { .. }
+            | RunMode::Thumb { .. }
+            | RunMode::Info { .. }
+            | RunMode::DecodeHelper { .. } => {
+                panic!("expected Help mode")
+            }
+        }
+    }
+
+    #[test]
+    fn parse_run_mode_verify_subcommand() {
+        let args = vec![
+            OsString::from("--lang"),
+            OsString::from("fr"),
+            OsString::from("verify"),
+            OsString::from("/media/card"),
+        ];
+        let mode = parse_run_mode(pico_args::Arguments::from_vec(args)).expect("parse should work");
+        match mode {
+            RunMode::Verify { dir, lang, .. } => {
+                assert_eq!(dir, PathBuf::from("/media/card"));
+                assert_eq!(lang.as_deref(), Some("fr"));
+            }
+            _ => panic!("expected Verify mode"),
+        }
+
+        let args = vec![OsString::from("verify")];
+        let mode = parse_run_mode(pico_args::Arguments::from_vec(args)).expect("parse should work");
+        assert!(matches!(mode, RunMode::Verify { dir, .. } if dir == Path::new(".")));
+    }
+
+    #[test]
+    fn parse_run_mode_convert_subcommand() {
+        let args = vec![
+            OsString::from("convert"),
+            OsString::from("in.png"),
+            OsString::from("out.jpg"),
+            OsString::from("--quality"),
+            OsString::from("85"),
+        ];
+        let mode = parse_run_mode(pico_args::Arguments::from_vec(args)).expect("parse should work");
+        match mode {
+            RunMode::Convert {
+                input,
+                output,
+                quality,
+            } => {
+                assert_eq!(input, PathBuf::from("in.png"));
+                assert_eq!(output, PathBuf::from("out.jpg"));
+                assert_eq!(quality, 85);
+            }
+            _ => panic!("expected Convert mode"),
+        }
+
+        let args = vec![OsString::from("convert"), OsString::from("in.png")];
+        assert!(parse_run_mode(pico_args::Arguments::from_vec(args)).is_err());
+    }
+
+    #[test]
+    fn parse_run_mode_thumb_and_info_subcommands() {
+        let args = vec![
+            OsString::from("thumb"),
+            OsString::from("photos/"),
+            OsString::from("--size"),
+            OsString::from("128"),
+        ];
+        let mode = parse_run_mode(pico_args::Arguments::from_vec(args)).expect("parse should work");
+        match mode {
+            RunMode::Thumb {
+                input, size, out, ..
+            } => {
+                assert_eq!(input, PathBuf::from("photos/"));
+                assert_eq!(size, 128);
+                assert!(out.is_none());
+            }
+            _ => panic!("expected Thumb mode"),
+        }
+
+        let args = vec![
+            OsString::from("info"),
+            OsString::from("clip.mp4"),
+            OsString::from("--json"),
+        ];
+        let mode = parse_run_mode(pico_args::Arguments::from_vec(args)).expect("parse should work");
+        assert!(
+            matches!(mode, RunMode::Info { path, json: true, csv: false, .. } if path == Path::new("clip.mp4"))
+        );
+
+        let args = vec![
+            OsString::from("info"),
+            OsString::from("photos/"),
+            OsString::from("--csv"),
+        ];
+        let mode = parse_run_mode(pico_args::Arguments::from_vec(args)).expect("parse should work");
+        assert!(
+            matches!(mode, RunMode::Info { path, json: false, csv: true, .. } if path == Path::new("photos/"))
+        );
+
+        let args = vec![OsString::from("info")];
+        assert!(parse_run_mode(pico_args::Arguments::from_vec(args)).is_err());
+    }
+
+    #[test]
+    fn parse_run_mode_decode_helper_subcommand() {
+        let args = vec![
+            OsString::from("decode-helper"),
+            OsString::from("/photos/untrusted.png"),
+            OsString::from("/dev/shm/out.rgba"),
+        ];
+        let mode = parse_run_mode(pico_args::Arguments::from_vec(args)).expect("parse should work");
+        match mode {
+            RunMode::DecodeHelper {
+                input,
+                output,
+                decoders,
+            } => {
+                assert_eq!(input, PathBuf::from("/photos/untrusted.png"));
+                assert_eq!(output, PathBuf::from("/dev/shm/out.rgba"));
+                assert_eq!(decoders, DecoderPreferences::default());
+            }
+            _ => panic!("expected DecodeHelper mode"),
         }
+
+        let args = vec![
+            OsString::from("decode-helper"),
+            OsString::from("--webp-decoder"),
+            OsString::from("libwebp"),
+            OsString::from("in.webp"),
+            OsString::from("out.rgba"),
+        ];
+        let mode = parse_run_mode(pico_args::Arguments::from_vec(args)).expect("parse should work");
+        match mode {
+            RunMode::DecodeHelper { decoders, .. } => {
+                assert_eq!(decoders.webp, WebpDecoder::Libwebp);
+            }
+            _ => panic!("expected DecodeHelper mode"),
+        }
+
+        let args = vec![OsString::from("decode-helper"), OsString::from("in.png")];
+        assert!(parse_run_mode(pico_args::Arguments::from_vec(args)).is_err());
     }
 }
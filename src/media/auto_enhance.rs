@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Histogram-based automatic correction for the editor's Auto enhance button.
+//!
+//! [`analyze`] derives an [`AutoEnhanceParams`] from the image statistics:
+//! gray-world channel gains for the white balance, black and white points
+//! that stretch the luminance histogram (levels and contrast), and a midtone
+//! gamma bringing the median towards middle gray. [`apply`] then maps every
+//! channel through a lookup table, so the same correction can be replayed
+//! from history without analyzing the image again.
+//!
+//! Histogram math converts between u8, u32, u64 and f32. Precision loss is
+//! acceptable for pixel counts and 8-bit channel values.
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+
+use image_rs::DynamicImage;
+use serde::{Deserialize, Serialize};
+
+/// Share of the darkest and brightest pixels ignored when picking the black
+/// and white points, so a few specular highlights do not prevent the stretch.
+const CLIP_FRACTION: f64 = 0.005;
+/// Pixels darker or brighter than this luminance are left out of the white
+/// balance estimate (shadows are noisy, highlights are often clipped).
+const BALANCE_RANGE: (f32, f32) = (16.0, 240.0);
+/// Largest correction applied to a single channel by the white balance.
+const MAX_GAIN: f32 = 1.3;
+/// Brightest black point and darkest white point the stretch may choose, so
+/// dull or low-key images are not pushed to extremes.
+const MAX_STRETCH: (u8, u8) = (64, 192);
+/// Range of the midtone gamma.
+const GAMMA_RANGE: (f32, f32) = (0.7, 1.4);
+
+/// Correction found by [`analyze`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AutoEnhanceParams {
+    /// Red, green and blue multipliers balancing the average color to gray.
+    pub gains: [f32; 3],
+    /// Luminance mapped to black.
+    pub black: u8,
+    /// Luminance mapped to white.
+    pub white: u8,
+    /// Exponent applied to the stretched values (below 1 brightens midtones).
+    pub gamma: f32,
+}
+
+impl Default for AutoEnhanceParams {
+    fn default() -> Self {
+        Self {
+            gains: [1.0; 3],
+            black: 0,
+            white: 255,
+            gamma: 1.0,
+        }
+    }
+}
+
+impl AutoEnhanceParams {
+    /// Returns whether the correction leaves the image unchanged.
+    #[must_use]
+    pub fn is_neutral(&self) -> bool {
+        let neutral = Self::default();
+        self.black == neutral.black
+            && self.white == neutral.white
+            && (self.gamma - 1.0).abs() < 0.01
+            && self.gains.iter().all(|gain| (gain - 1.0).abs() < 0.01)
+    }
+}
+
+/// Derive the automatic correction for `image` from its histogram.
+#[must_use]
+pub fn analyze(image: &DynamicImage) -> AutoEnhanceParams {
+    let rgb = image.to_rgb8();
+    let pixel_count = u64::from(rgb.width()) * u64::from(rgb.height());
+    if pixel_count == 0 {
+        return AutoEnhanceParams::default();
+    }
+
+    // White balance: the average color of the midtones should be neutral
+    let mut sums = [0.0f64; 3];
+    let mut counted = 0u64;
+    for pixel in rgb.pixels() {
+        let channels = pixel.0.map(f32::from);
+        let brightness = luma(channels);
+        if (BALANCE_RANGE.0..=BALANCE_RANGE.1).contains(&brightness) {
+            for (sum, channel) in sums.iter_mut().zip(channels) {
+                *sum += f64::from(channel);
+            }
+            counted += 1;
+        }
+    }
+    let mut gains = [1.0f32; 3];
+    if counted > 0 {
+        let means = sums.map(|sum| (sum / counted as f64) as f32);
+        let gray = (means[0] + means[1] + means[2]) / 3.0;
+        for (gain, mean) in gains.iter_mut().zip(means) {
+            if mean > 0.0 {
+                *gain = (gray / mean).clamp(1.0 / MAX_GAIN, MAX_GAIN);
+            }
+        }
+    }
+
+    // Levels: histogram of the balanced luminance
+    let mut histogram = [0u64; 256];
+    for pixel in rgb.pixels() {
+        let mut channels = pixel.0.map(f32::from);
+        for (channel, gain) in channels.iter_mut().zip(gains) {
+            *channel = (*channel * gain).min(255.0);
+        }
+        histogram[luma(channels).round() as usize] += 1;
+    }
+    let clipped = (pixel_count as f64 * CLIP_FRACTION) as u64;
+    let black = percentile(&histogram, clipped).min(MAX_STRETCH.0);
+    let white = percentile(&histogram, pixel_count.saturating_sub(clipped + 1)).max(MAX_STRETCH.1);
+
+    // Midtone gamma bringing the stretched median towards middle gray
+    let median = f32::from(percentile(&histogram, pixel_count / 2));
+    let stretched = ((median - f32::from(black)) / f32::from(white - black)).clamp(0.01, 0.99);
+    let gamma = (0.5f32.ln() / stretched.ln()).clamp(GAMMA_RANGE.0, GAMMA_RANGE.1);
+
+    AutoEnhanceParams {
+        gains,
+        black,
+        white,
+        gamma,
+    }
+}
+
+/// Apply `params` to `image`, blended with the original by `strength`
+/// percent (0 to 100).
+///
+/// The alpha channel is kept as is.
+#[must_use]
+pub fn apply(image: &DynamicImage, params: &AutoEnhanceParams, strength: u32) -> DynamicImage {
+    if strength == 0 || params.is_neutral() {
+        return image.clone();
+    }
+    let mix = strength.min(100) as f32 / 100.0;
+    let black = f32::from(params.black);
+    let range = f32::from(params.white.saturating_sub(params.black).max(1));
+    let tables = params.gains.map(|gain| {
+        let mut table = [0u8; 256];
+        for (value, entry) in table.iter_mut().enumerate() {
+            let original = value as f32;
+            let stretched = ((original * gain - black) / range).clamp(0.0, 1.0);
+            let corrected = stretched.powf(params.gamma) * 255.0;
+            *entry = (original + (corrected - original) * mix)
+                .round()
+                .clamp(0.0, 255.0) as u8;
+        }
+        table
+    });
+
+    let mut rgba = image.to_rgba8();
+    for pixel in rgba.pixels_mut() {
+        for (channel, table) in tables.iter().enumerate() {
+            pixel.0[channel] = table[usize::from(pixel.0[channel])];
+        }
+    }
+    if image.color().has_alpha() {
+        DynamicImage::ImageRgba8(rgba)
+    } else {
+        DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(rgba).to_rgb8())
+    }
+}
+
+/// Returns the Rec. 601 luminance of an RGB color.
+fn luma(rgb: [f32; 3]) -> f32 {
+    0.299 * rgb[0] + 0.587 * rgb[1] + 0.114 * rgb[2]
+}
+
+/// Returns the value below which `rank` pixels of the histogram fall.
+fn percentile(histogram: &[u64; 256], rank: u64) -> u8 {
+    let mut seen = 0;
+    for (value, count) in histogram.iter().enumerate() {
+        seen += count;
+        if seen > rank {
+            return value as u8;
+        }
+    }
+    u8::MAX
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image_rs::{Rgb, RgbImage, Rgba, RgbaImage};
+
+    /// Dull, blue-tinted gradient from 80 to 160.
+    fn flat_blue_image() -> DynamicImage {
+        DynamicImage::ImageRgb8(RgbImage::from_fn(64, 4, |x, _| {
+            let value = 80 + (x * 80 / 63) as u8;
+            Rgb([value, value, value.saturating_add(30)])
+        }))
+    }
+
+    #[test]
+    fn analysis_stretches_levels_and_cools_down_blue_cast() {
+        let params = analyze(&flat_blue_image());
+        assert!(params.black > 60, "black point is {}", params.black);
+        assert!(params.white < 200, "white point is {}", params.white);
+        assert!(params.gains[2] < 1.0 && params.gains[0] > 1.0);
+    }
+
+    #[test]
+    fn full_strength_spreads_the_histogram() {
+        let image = flat_blue_image();
+        let result = apply(&image, &analyze(&image), 100).to_rgb8();
+        let first = result.get_pixel(0, 0).0;
+        let last = result.get_pixel(63, 0).0;
+        // The stretch is limited, so a dull image is not pushed to pure black
+        assert!(first[0] < 50 && last[0] > 205, "{first:?} .. {last:?}");
+        // The blue cast is gone in the midtones
+        let [r, _, b] = result.get_pixel(32, 0).0;
+        assert!(r.abs_diff(b) < 12, "midtone is {r} / {b}");
+    }
+
+    #[test]
+    fn strength_blends_and_balanced_images_barely_change() {
+        let image = flat_blue_image();
+        let params = analyze(&image);
+        assert_eq!(apply(&image, &params, 0), image);
+        let half = apply(&image, &params, 50).to_rgb8().get_pixel(0, 0).0[0];
+        let full = apply(&image, &params, 100).to_rgb8().get_pixel(0, 0).0[0];
+        assert!(half > full && half < 80);
+
+        let gradient = DynamicImage::ImageRgb8(RgbImage::from_fn(256, 1, |x, _| {
+            Rgb([x as u8, x as u8, x as u8])
+        }));
+        let corrected = apply(&gradient, &analyze(&gradient), 100).to_rgb8();
+        for (original, corrected) in gradient.to_rgb8().pixels().zip(corrected.pixels()) {
+            assert!(original.0[0].abs_diff(corrected.0[0]) <= 2);
+        }
+    }
+
+    #[test]
+    fn alpha_is_kept() {
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_fn(8, 8, |x, _| {
+            Rgba([100 + x as u8, 110, 120, 40])
+        }));
+        let result = apply(&image, &analyze(&image), 100).to_rgba8();
+        assert_eq!(result.get_pixel(3, 3).0[3], 40);
+    }
+}
@@ -0,0 +1,467 @@
+// SPDX-License-Identifier: MPL-2.0
+//! AI-powered background removal using the U²-Net ONNX segmentation model.
+//!
+//! This module provides functionality for:
+//! - Downloading the U²-Net ONNX model from a configurable URL
+//! - Running inference to get a foreground mask of an image
+//! - Cutting out the foreground with a (refined) mask
+//!
+//! The model predicts a saliency map at 320x320; it is stretched back to
+//! the image size and used as the alpha channel, so the result keeps the
+//! full resolution of the image with soft edges.
+
+use crate::app::paths;
+use crate::media::deblur::{is_cancelled, CancellationToken};
+use image_rs::{imageops::FilterType, DynamicImage, GrayImage, Luma};
+use ndarray::Array4;
+use ort::session::{builder::GraphOptimizationLevel, Session};
+use std::path::PathBuf;
+
+/// Filename for the downloaded segmentation model in the data directory.
+const MODEL_FILENAME: &str = "u2net.onnx";
+
+/// Minimum expected model size (150 MB) to detect failed downloads.
+const MIN_MODEL_SIZE_BYTES: u64 = 150_000_000;
+
+/// Side of the square input expected by U²-Net.
+const INPUT_SIZE: u32 = 320;
+
+/// Per-channel mean used to normalize the input (`ImageNet` statistics).
+const MEAN: [f32; 3] = [0.485, 0.456, 0.406];
+
+/// Per-channel standard deviation used to normalize the input.
+const STD: [f32; 3] = [0.229, 0.224, 0.225];
+
+/// Result type for background removal operations.
+pub type BackgroundRemovalResult<T> = Result<T, BackgroundRemovalError>;
+
+/// Errors that can occur during background removal.
+#[derive(Debug, Clone)]
+pub enum BackgroundRemovalError {
+    /// Model file not found at expected path.
+    ModelNotFound,
+    /// Failed to download the model.
+    DownloadFailed(String),
+    /// ONNX inference failed.
+    InferenceFailed(String),
+    /// Model output could not be interpreted.
+    PostprocessingFailed(String),
+    /// Operation was cancelled by user.
+    Cancelled,
+    /// IO error occurred.
+    Io(String),
+    /// Model session not initialized.
+    SessionNotInitialized,
+}
+
+impl std::fmt::Display for BackgroundRemovalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackgroundRemovalError::ModelNotFound => write!(f, "Model file not found"),
+            BackgroundRemovalError::DownloadFailed(msg) => write!(f, "Download failed: {msg}"),
+            BackgroundRemovalError::InferenceFailed(msg) => write!(f, "Inference failed: {msg}"),
+            BackgroundRemovalError::PostprocessingFailed(msg) => {
+                write!(f, "Postprocessing failed: {msg}")
+            }
+            BackgroundRemovalError::Cancelled => write!(f, "Operation cancelled"),
+            BackgroundRemovalError::Io(msg) => write!(f, "IO error: {msg}"),
+            BackgroundRemovalError::SessionNotInitialized => {
+                write!(f, "ONNX session not initialized")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BackgroundRemovalError {}
+
+/// Status of the background removal model.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum BackgroundModelStatus {
+    /// Model has not been downloaded.
+    #[default]
+    NotDownloaded,
+    /// Model is currently being downloaded.
+    Downloading { progress: f32 },
+    /// Model is being validated (test inference).
+    Validating,
+    /// Model is ready for use.
+    Ready,
+    /// An error occurred.
+    Error(String),
+}
+
+/// Manager for the U²-Net segmentation model.
+///
+/// Handles model lifecycle: loading and inference.
+pub struct BackgroundRemovalManager {
+    model_path: PathBuf,
+    session: Option<Session>,
+}
+
+impl Default for BackgroundRemovalManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BackgroundRemovalManager {
+    /// Creates a new `BackgroundRemovalManager` instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            model_path: get_model_path(),
+            session: None,
+        }
+    }
+
+    /// Loads the ONNX session from the model file.
+    ///
+    /// If a cancellation token is provided and triggered, returns
+    /// `BackgroundRemovalError::Cancelled`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the model file is not found, the operation is cancelled,
+    /// or the ONNX session fails to initialize.
+    pub fn load_session(
+        &mut self,
+        cancel_token: Option<&CancellationToken>,
+    ) -> BackgroundRemovalResult<()> {
+        if cancel_token.is_some_and(is_cancelled) {
+            return Err(BackgroundRemovalError::Cancelled);
+        }
+
+        if !self.model_path.exists() {
+            return Err(BackgroundRemovalError::ModelNotFound);
+        }
+
+        let session = Session::builder()
+            .map_err(|e| BackgroundRemovalError::InferenceFailed(e.to_string()))?
+            .with_optimization_level(GraphOptimizationLevel::Level3)
+            .map_err(|e| BackgroundRemovalError::InferenceFailed(e.to_string()))?
+            .commit_from_file(&self.model_path)
+            .map_err(|e| BackgroundRemovalError::InferenceFailed(e.to_string()))?;
+
+        self.session = Some(session);
+        Ok(())
+    }
+
+    /// Checks if the ONNX session is loaded and ready.
+    #[must_use]
+    pub fn is_session_ready(&self) -> bool {
+        self.session.is_some()
+    }
+
+    /// Computes the foreground mask of an image.
+    ///
+    /// Returns a mask of the image size where 255 is foreground and 0 is
+    /// background.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session is not initialized or the ONNX
+    /// inference fails.
+    pub fn segment(&mut self, image: &DynamicImage) -> BackgroundRemovalResult<GrayImage> {
+        let session = self
+            .session
+            .as_mut()
+            .ok_or(BackgroundRemovalError::SessionNotInitialized)?;
+
+        let input_tensor = preprocess_image(image);
+        let input_tensor = input_tensor.as_standard_layout().into_owned();
+
+        let input_name = session
+            .inputs
+            .first()
+            .map_or_else(|| "input.1".to_string(), |i| i.name.clone());
+
+        let input_ref = ort::value::TensorRef::from_array_view(&input_tensor)
+            .map_err(|e| BackgroundRemovalError::InferenceFailed(e.to_string()))?;
+
+        let outputs = session
+            .run(ort::inputs![input_name.as_str() => input_ref])
+            .map_err(|e| BackgroundRemovalError::InferenceFailed(e.to_string()))?;
+
+        // The first output is the fused saliency map [1, 1, 320, 320]
+        let (_, output) = outputs.iter().next().ok_or_else(|| {
+            BackgroundRemovalError::PostprocessingFailed("No output tensor".to_string())
+        })?;
+        let (shape, data) = output
+            .try_extract_tensor::<f32>()
+            .map_err(|e: ort::Error| BackgroundRemovalError::PostprocessingFailed(e.to_string()))?;
+
+        let saliency = saliency_to_mask(shape, data)?;
+        Ok(image_rs::imageops::resize(
+            &saliency,
+            image.width(),
+            image.height(),
+            FilterType::Triangle,
+        ))
+    }
+
+    /// Deletes the model file from disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be deleted.
+    pub fn delete_model(&mut self) -> BackgroundRemovalResult<()> {
+        self.session = None;
+        if self.model_path.exists() {
+            std::fs::remove_file(&self.model_path)
+                .map_err(|e| BackgroundRemovalError::Io(e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the path where the segmentation model should be stored.
+#[must_use]
+pub fn get_model_path() -> PathBuf {
+    paths::get_app_data_dir().map_or_else(
+        || PathBuf::from(MODEL_FILENAME),
+        |mut p| {
+            p.push(MODEL_FILENAME);
+            p
+        },
+    )
+}
+
+/// Checks if the model file exists at the expected location with valid size.
+#[must_use]
+pub fn is_model_downloaded() -> bool {
+    std::fs::metadata(get_model_path()).is_ok_and(|meta| meta.len() >= MIN_MODEL_SIZE_BYTES)
+}
+
+/// Downloads the model from the specified URL.
+///
+/// Returns the number of bytes downloaded.
+///
+/// # Errors
+///
+/// Returns an error if the download fails or the file cannot be written.
+pub async fn download_model(
+    url: &str,
+    mut progress_callback: impl FnMut(f32) + Send,
+) -> BackgroundRemovalResult<u64> {
+    use futures_util::StreamExt;
+
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::limited(10))
+        .user_agent("IcedLens/0.3.0")
+        .build()
+        .map_err(|e| BackgroundRemovalError::DownloadFailed(e.to_string()))?;
+
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| BackgroundRemovalError::DownloadFailed(e.to_string()))?;
+
+    if !response.status().is_success() {
+        return Err(BackgroundRemovalError::DownloadFailed(format!(
+            "HTTP status: {}",
+            response.status()
+        )));
+    }
+
+    let total_size = response.content_length().unwrap_or(0);
+    if total_size > 0 && total_size < MIN_MODEL_SIZE_BYTES {
+        return Err(BackgroundRemovalError::DownloadFailed(format!(
+            "Response too small ({total_size} bytes), expected model file (~176 MB). URL may have changed or returned an error page."
+        )));
+    }
+
+    let model_path = get_model_path();
+    if let Some(parent) = model_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| BackgroundRemovalError::Io(e.to_string()))?;
+    }
+
+    let mut file = std::fs::File::create(&model_path)
+        .map_err(|e| BackgroundRemovalError::Io(e.to_string()))?;
+
+    let mut downloaded: u64 = 0;
+    let mut stream = response.bytes_stream();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| BackgroundRemovalError::DownloadFailed(e.to_string()))?;
+        std::io::Write::write_all(&mut file, &chunk)
+            .map_err(|e| BackgroundRemovalError::Io(e.to_string()))?;
+
+        downloaded += chunk.len() as u64;
+
+        if total_size > 0 {
+            // f64 to f32 truncation is fine for progress display (0.0-1.0 range)
+            #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+            let progress = (downloaded as f64 / total_size as f64) as f32;
+            progress_callback(progress);
+        }
+    }
+
+    if downloaded < MIN_MODEL_SIZE_BYTES {
+        let _ = std::fs::remove_file(&model_path);
+        return Err(BackgroundRemovalError::DownloadFailed(format!(
+            "Downloaded file too small ({downloaded} bytes), expected ~176 MB"
+        )));
+    }
+
+    Ok(downloaded)
+}
+
+/// Validates the model by running a test inference.
+///
+/// If a cancellation token is provided and triggered, returns
+/// `BackgroundRemovalError::Cancelled`.
+///
+/// # Errors
+///
+/// Returns an error if validation is cancelled or the model fails inference.
+pub fn validate_model(
+    manager: &mut BackgroundRemovalManager,
+    cancel_token: Option<&CancellationToken>,
+) -> BackgroundRemovalResult<()> {
+    if cancel_token.is_some_and(is_cancelled) {
+        return Err(BackgroundRemovalError::Cancelled);
+    }
+
+    let test_image = DynamicImage::ImageRgb8(image_rs::RgbImage::from_pixel(
+        64,
+        48,
+        image_rs::Rgb([128, 128, 128]),
+    ));
+    let mask = manager.segment(&test_image)?;
+
+    if mask.dimensions() != (64, 48) {
+        return Err(BackgroundRemovalError::InferenceFailed(format!(
+            "Unexpected mask size: {}x{}, expected 64x48",
+            mask.width(),
+            mask.height()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Cuts out the foreground of `image` with `mask`, which becomes its alpha
+/// channel (combined with any transparency the image already has).
+#[must_use]
+pub fn apply_mask(image: &DynamicImage, mask: &GrayImage) -> DynamicImage {
+    let mut rgba = image.to_rgba8();
+    for (pixel, Luma([alpha])) in rgba.pixels_mut().zip(mask.pixels()) {
+        pixel.0[3] = pixel.0[3].min(*alpha);
+    }
+    DynamicImage::ImageRgba8(rgba)
+}
+
+/// Preprocesses an image for U²-Net inference.
+///
+/// Resizes to 320x320 and converts to NCHW format (batch=1, channels=3),
+/// RGB order, scaled by the brightest value then normalized with the
+/// `ImageNet` mean and standard deviation.
+fn preprocess_image(img: &DynamicImage) -> Array4<f32> {
+    let resized = img
+        .resize_exact(INPUT_SIZE, INPUT_SIZE, FilterType::Triangle)
+        .to_rgb8();
+    let brightest = resized
+        .pixels()
+        .flat_map(|pixel| pixel.0)
+        .max()
+        .filter(|&value| value > 0)
+        .map_or(1.0, f32::from);
+
+    let mut tensor = Array4::<f32>::zeros((1, 3, INPUT_SIZE as usize, INPUT_SIZE as usize));
+    for (x, y, pixel) in resized.enumerate_pixels() {
+        for channel in 0..3 {
+            let value = f32::from(pixel.0[channel]) / brightest;
+            tensor[[0, channel, y as usize, x as usize]] = (value - MEAN[channel]) / STD[channel];
+        }
+    }
+    tensor
+}
+
+/// Converts the saliency map predicted by the model into a mask, stretched
+/// so the least salient pixel is 0 and the most salient one is 255.
+fn saliency_to_mask(shape: &[i64], data: &[f32]) -> BackgroundRemovalResult<GrayImage> {
+    let (height, width) = match shape {
+        [.., height, width] => (
+            u32::try_from(*height).map_err(|_| {
+                BackgroundRemovalError::PostprocessingFailed("Invalid mask height".to_string())
+            })?,
+            u32::try_from(*width).map_err(|_| {
+                BackgroundRemovalError::PostprocessingFailed("Invalid mask width".to_string())
+            })?,
+        ),
+        _ => {
+            return Err(BackgroundRemovalError::PostprocessingFailed(format!(
+                "Expected a 2D mask, got {}D",
+                shape.len()
+            )))
+        }
+    };
+    let count = width as usize * height as usize;
+    let values = data.get(..count).ok_or_else(|| {
+        BackgroundRemovalError::PostprocessingFailed("Mask tensor too small".to_string())
+    })?;
+
+    let (min, max) = values.iter().fold((f32::MAX, f32::MIN), |(min, max), &v| {
+        (min.min(v), max.max(v))
+    });
+    let range = (max - min).max(f32::EPSILON);
+
+    // Clamped to 0.0..=255.0 before the conversion
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let pixels = values
+        .iter()
+        .map(|&v| ((v - min) / range * 255.0).clamp(0.0, 255.0).round() as u8)
+        .collect();
+
+    GrayImage::from_raw(width, height, pixels).ok_or_else(|| {
+        BackgroundRemovalError::PostprocessingFailed("Failed to create mask".to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_model_path_returns_valid_path() {
+        let path = get_model_path();
+        assert!(path.to_string_lossy().contains(MODEL_FILENAME));
+    }
+
+    #[test]
+    fn test_model_status_default() {
+        assert_eq!(
+            BackgroundModelStatus::default(),
+            BackgroundModelStatus::NotDownloaded
+        );
+    }
+
+    #[test]
+    fn test_preprocess_image_creates_correct_shape() {
+        let img = DynamicImage::new_rgb8(100, 80);
+        let tensor = preprocess_image(&img);
+        assert_eq!(tensor.shape(), &[1, 3, 320, 320]);
+    }
+
+    #[test]
+    fn test_saliency_is_stretched_to_full_range() {
+        let mask = saliency_to_mask(&[1, 1, 2, 2], &[0.2, 0.4, 0.6, 0.2]).unwrap();
+        assert_eq!(mask.dimensions(), (2, 2));
+        assert_eq!(mask.into_raw(), vec![0, 128, 255, 0]);
+
+        assert!(saliency_to_mask(&[4], &[0.0; 4]).is_err());
+        assert!(saliency_to_mask(&[1, 1, 4, 4], &[0.0; 4]).is_err());
+    }
+
+    #[test]
+    fn test_apply_mask_keeps_existing_transparency() {
+        let mut image = image_rs::RgbaImage::from_pixel(2, 1, image_rs::Rgba([10, 20, 30, 255]));
+        image.put_pixel(1, 0, image_rs::Rgba([10, 20, 30, 100]));
+        let mask = GrayImage::from_raw(2, 1, vec![0, 255]).unwrap();
+
+        let result = apply_mask(&DynamicImage::ImageRgba8(image), &mask).to_rgba8();
+        assert_eq!(result.get_pixel(0, 0).0, [10, 20, 30, 0]);
+        assert_eq!(result.get_pixel(1, 0).0, [10, 20, 30, 100]);
+    }
+}
@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Capture-day grouping for the media of a directory.
+//!
+//! Groups files into "events" by the calendar day they were taken, so the
+//! viewer can hop from one day's shoot to the next regardless of how the
+//! directory is sorted. The capture day comes from EXIF `DateTimeOriginal`
+//! (or `DateTime`), falling back to the file's modification date for videos
+//! and images without EXIF.
+
+use chrono::{DateTime, Local, NaiveDate};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+/// Returns the calendar day the media at `path` was captured.
+///
+/// Returns `None` if neither EXIF nor file system dates are available.
+#[must_use]
+pub fn capture_date(path: &Path) -> Option<NaiveDate> {
+    exif_capture_date(path).or_else(|| {
+        let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+        Some(DateTime::<Local>::from(modified).date_naive())
+    })
+}
+
+/// Reads the capture date from EXIF, preferring `DateTimeOriginal`.
+fn exif_capture_date(path: &Path) -> Option<NaiveDate> {
+    let file = File::open(path).ok()?;
+    let exif = exif::Reader::new()
+        .read_from_container(&mut BufReader::new(file))
+        .ok()?;
+
+    [exif::Tag::DateTimeOriginal, exif::Tag::DateTime]
+        .into_iter()
+        .filter_map(|tag| exif.get_field(tag, exif::In::PRIMARY))
+        .find_map(|field| match &field.value {
+            exif::Value::Ascii(values) => values.first().and_then(|raw| parse_exif_date(raw)),
+            _ => None,
+        })
+}
+
+/// Parses the date part of an EXIF datetime (`YYYY:MM:DD HH:MM:SS`).
+fn parse_exif_date(raw: &[u8]) -> Option<NaiveDate> {
+    let text = std::str::from_utf8(raw).ok()?;
+    NaiveDate::parse_from_str(text.get(..10)?, "%Y:%m:%d").ok()
+}
+
+/// One capture day and the number of files taken on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaptureDay {
+    /// Calendar day of capture.
+    pub date: NaiveDate,
+    /// Number of files captured on this day.
+    pub count: usize,
+}
+
+/// Capture days of a directory's media, in navigation order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CaptureDayIndex {
+    entries: Vec<(PathBuf, Option<NaiveDate>)>,
+}
+
+impl CaptureDayIndex {
+    /// Builds the index by reading the capture date of every path.
+    ///
+    /// This touches every file, so run it off the UI thread for large folders.
+    #[must_use]
+    pub fn build(paths: Vec<PathBuf>) -> Self {
+        let entries = paths
+            .into_iter()
+            .map(|path| {
+                let date = capture_date(&path);
+                (path, date)
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Creates an index from already known dates.
+    #[must_use]
+    pub fn from_entries(entries: Vec<(PathBuf, Option<NaiveDate>)>) -> Self {
+        Self { entries }
+    }
+
+    /// Returns the capture day of `path`, if indexed and dated.
+    #[must_use]
+    pub fn date_of(&self, path: &Path) -> Option<NaiveDate> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| entry == path)
+            .and_then(|(_, date)| *date)
+    }
+
+    /// Returns all capture days in chronological order.
+    #[must_use]
+    pub fn days(&self) -> Vec<CaptureDay> {
+        let mut days: Vec<CaptureDay> = Vec::new();
+        let mut dates: Vec<NaiveDate> = self.entries.iter().filter_map(|(_, d)| *d).collect();
+        dates.sort_unstable();
+        for date in dates {
+            match days.last_mut() {
+                Some(day) if day.date == date => day.count += 1,
+                _ => days.push(CaptureDay { date, count: 1 }),
+            }
+        }
+        days
+    }
+
+    /// Returns the capture day containing `path`, with its file count.
+    #[must_use]
+    pub fn day_of(&self, path: &Path) -> Option<CaptureDay> {
+        let date = self.date_of(path)?;
+        self.days().into_iter().find(|day| day.date == date)
+    }
+
+    /// Returns the first file (in navigation order) of the next later capture day.
+    #[must_use]
+    pub fn next_day_start(&self, current: &Path) -> Option<&Path> {
+        let date = self.date_of(current)?;
+        let next = self
+            .entries
+            .iter()
+            .filter_map(|(_, d)| *d)
+            .filter(|d| *d > date)
+            .min()?;
+        self.first_of(next)
+    }
+
+    /// Returns the first file of the current capture day, or of the previous
+    /// day if `current` already is the first file of its day.
+    #[must_use]
+    pub fn previous_day_start(&self, current: &Path) -> Option<&Path> {
+        let date = self.date_of(current)?;
+        let day_start = self.first_of(date)?;
+        if day_start != current {
+            return Some(day_start);
+        }
+        let previous = self
+            .entries
+            .iter()
+            .filter_map(|(_, d)| *d)
+            .filter(|d| *d < date)
+            .max()?;
+        self.first_of(previous)
+    }
+
+    fn first_of(&self, date: NaiveDate) -> Option<&Path> {
+        self.entries
+            .iter()
+            .find(|(_, d)| *d == Some(date))
+            .map(|(path, _)| path.as_path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(2025, 5, d)
+    }
+
+    fn index() -> CaptureDayIndex {
+        // Alphabetical navigation order does not follow capture order
+        CaptureDayIndex::from_entries(vec![
+            (PathBuf::from("a.jpg"), day(3)),
+            (PathBuf::from("b.jpg"), day(1)),
+            (PathBuf::from("c.jpg"), day(3)),
+            (PathBuf::from("d.jpg"), None),
+            (PathBuf::from("e.jpg"), day(1)),
+            (PathBuf::from("f.jpg"), day(2)),
+        ])
+    }
+
+    #[test]
+    fn parse_exif_date_reads_date_part() {
+        assert_eq!(parse_exif_date(b"2025:05:03 14:30:00"), day(3));
+        assert_eq!(parse_exif_date(b"    :  :     :  :  "), None);
+        assert_eq!(parse_exif_date(b"2025"), None);
+    }
+
+    #[test]
+    fn days_are_chronological_with_counts() {
+        let days = index().days();
+        let summary: Vec<_> = days.iter().map(|d| (d.date, d.count)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (day(1).unwrap(), 2),
+                (day(2).unwrap(), 1),
+                (day(3).unwrap(), 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn next_day_start_follows_capture_order() {
+        let index = index();
+        assert_eq!(
+            index.next_day_start(Path::new("b.jpg")),
+            Some(Path::new("f.jpg"))
+        );
+        assert_eq!(
+            index.next_day_start(Path::new("f.jpg")),
+            Some(Path::new("a.jpg"))
+        );
+        assert_eq!(index.next_day_start(Path::new("c.jpg")), None);
+        assert_eq!(index.next_day_start(Path::new("d.jpg")), None);
+    }
+
+    #[test]
+    fn previous_day_start_returns_to_day_start_first() {
+        let index = index();
+        assert_eq!(
+            index.previous_day_start(Path::new("c.jpg")),
+            Some(Path::new("a.jpg"))
+        );
+        assert_eq!(
+            index.previous_day_start(Path::new("a.jpg")),
+            Some(Path::new("f.jpg"))
+        );
+        assert_eq!(index.previous_day_start(Path::new("b.jpg")), None);
+    }
+
+    #[test]
+    fn capture_date_falls_back_to_modification_date() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("clip.mp4");
+        std::fs::write(&path, b"not really a video").expect("write file");
+
+        assert_eq!(capture_date(&path), Some(Local::now().date_naive()));
+    }
+}
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Video chapters and chapter navigation.
+//!
+//! Chapters are read from the container (MP4 chapter tracks, Matroska
+//! editions) by [`crate::media::video::extract_video_metadata`].
+
+/// Going to the previous chapter within this many seconds of a chapter
+/// start jumps to the chapter before it instead of restarting the current one.
+const RESTART_GRACE_SECS: f64 = 3.0;
+
+/// A named section of a video.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chapter {
+    pub start_secs: f64,
+    pub end_secs: f64,
+    /// Title from the container metadata, if any.
+    pub title: Option<String>,
+}
+
+/// Returns the index of the chapter playing at `position_secs`.
+///
+/// `chapters` must be sorted by start time. Gaps before the first chapter
+/// have no chapter; gaps between chapters belong to the previous one.
+#[must_use]
+pub fn chapter_at(chapters: &[Chapter], position_secs: f64) -> Option<usize> {
+    chapters
+        .partition_point(|chapter| chapter.start_secs <= position_secs)
+        .checked_sub(1)
+}
+
+/// Returns the start of the chapter after the one at `position_secs`.
+#[must_use]
+pub fn next_chapter_start(chapters: &[Chapter], position_secs: f64) -> Option<f64> {
+    chapters
+        .iter()
+        .find(|chapter| chapter.start_secs > position_secs)
+        .map(|chapter| chapter.start_secs)
+}
+
+/// Returns where "previous chapter" should seek from `position_secs`: the start
+/// of the current chapter, or of the one before when close to that start.
+#[must_use]
+pub fn previous_chapter_start(chapters: &[Chapter], position_secs: f64) -> Option<f64> {
+    let current = chapter_at(chapters, position_secs)?;
+    let target = if position_secs - chapters[current].start_secs < RESTART_GRACE_SECS {
+        current.saturating_sub(1)
+    } else {
+        current
+    };
+    Some(chapters[target].start_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chapters() -> Vec<Chapter> {
+        [(0.0, 60.0), (60.0, 150.0), (150.0, 300.0)]
+            .into_iter()
+            .map(|(start_secs, end_secs)| Chapter {
+                start_secs,
+                end_secs,
+                title: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn chapter_at_finds_the_current_chapter() {
+        let chapters = chapters();
+        assert_eq!(chapter_at(&chapters, 0.0), Some(0));
+        assert_eq!(chapter_at(&chapters, 60.0), Some(1));
+        assert_eq!(chapter_at(&chapters, 299.0), Some(2));
+        assert_eq!(chapter_at(&[], 10.0), None);
+    }
+
+    #[test]
+    fn next_chapter_start_skips_to_the_following_chapter() {
+        let chapters = chapters();
+        assert_eq!(next_chapter_start(&chapters, 10.0), Some(60.0));
+        assert_eq!(next_chapter_start(&chapters, 60.0), Some(150.0));
+        assert_eq!(next_chapter_start(&chapters, 200.0), None);
+    }
+
+    #[test]
+    fn previous_chapter_start_restarts_or_goes_back() {
+        let chapters = chapters();
+        // Well into a chapter: restart it
+        assert_eq!(previous_chapter_start(&chapters, 100.0), Some(60.0));
+        // Just after a chapter start: go to the previous chapter
+        assert_eq!(previous_chapter_start(&chapters, 61.0), Some(0.0));
+        // First chapter stays on itself
+        assert_eq!(previous_chapter_start(&chapters, 1.0), Some(0.0));
+    }
+}
@@ -0,0 +1,463 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Export of a time range of a video to a new file.
+//!
+//! Clips are remuxed with stream copy whenever the output container accepts
+//! the source codecs: this is fast and lossless, but the clip starts at the
+//! last keyframe at or before the requested start. When stream copy fails,
+//! the video stream is re-encoded instead (H.264, or MPEG-4 Part 2 when no
+//! H.264 encoder is available); audio is not kept in that case.
+
+use crate::error::{Error, Result};
+use crate::media::video::init_ffmpeg;
+use ffmpeg_next::{codec, encoder, format, media, Rational};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Frame rate used when re-encoding a video that doesn't report one.
+const FALLBACK_FRAME_RATE: i32 = 30;
+
+/// Encoders tried in order when re-encoding.
+const REENCODE_CODECS: [codec::Id; 2] = [codec::Id::H264, codec::Id::MPEG4];
+
+/// How a clip was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipMethod {
+    /// Packets were copied without re-encoding.
+    StreamCopy,
+    /// The video stream was decoded and encoded again.
+    Reencode,
+}
+
+/// Shared flag to stop a running export.
+#[derive(Debug, Clone, Default)]
+pub struct CancelFlag(Arc<AtomicBool>);
+
+impl CancelFlag {
+    /// Asks the export to stop at the next packet.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true once [`cancel`](Self::cancel) was called.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+impl PartialEq for CancelFlag {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Range of a video to export.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClipRequest {
+    pub input: PathBuf,
+    pub output: PathBuf,
+    pub start_secs: f64,
+    pub end_secs: f64,
+}
+
+impl ClipRequest {
+    /// Returns how far into the clip `position_secs` is, from 0.0 to 1.0.
+    #[must_use]
+    pub fn progress_at(&self, position_secs: f64) -> f32 {
+        let length = self.end_secs - self.start_secs;
+        if length <= 0.0 {
+            return 1.0;
+        }
+        // Progress is within 0.0-1.0, f32 precision is plenty for display
+        #[allow(clippy::cast_possible_truncation)]
+        let progress = ((position_secs - self.start_secs) / length).clamp(0.0, 1.0) as f32;
+        progress
+    }
+}
+
+/// Progress and result of an export, as reported to the viewer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClipExportStatus {
+    /// Fraction of the clip written so far (0.0 to 1.0).
+    Progress(f32),
+    /// The clip was written to `path`.
+    Finished { path: PathBuf, method: ClipMethod },
+    /// The export was cancelled (or no destination was chosen).
+    Cancelled,
+    /// The export failed with this message.
+    Failed(String),
+}
+
+/// Returns the suggested file name for a clip of `video_path`,
+/// e.g. `holiday-clip.mp4`.
+#[must_use]
+pub fn default_clip_filename(video_path: &Path) -> String {
+    let stem = video_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("video");
+    match video_path.extension().and_then(|ext| ext.to_str()) {
+        Some(extension) => format!("{stem}-clip.{extension}"),
+        None => format!("{stem}-clip.mp4"),
+    }
+}
+
+/// Writes the `request` range of a video to a new file, stream-copying when
+/// possible and re-encoding otherwise. `progress` receives the fraction of
+/// the clip written so far.
+///
+/// Returns `None` if the export was cancelled. Partial output is removed on
+/// cancellation and failure.
+///
+/// # Errors
+///
+/// Returns an error if the range is empty, or if the video can be neither
+/// remuxed nor re-encoded.
+pub fn export_clip(
+    request: &ClipRequest,
+    cancel: &CancelFlag,
+    mut progress: impl FnMut(f32),
+) -> Result<Option<ClipMethod>> {
+    init_ffmpeg()?;
+    if request.end_secs <= request.start_secs {
+        return Err(Error::Io("The clip range is empty".to_string()));
+    }
+
+    let result = match remux(request, cancel, &mut progress) {
+        Ok(completed) => Ok(completed.then_some(ClipMethod::StreamCopy)),
+        Err(copy_error) => {
+            let _ = std::fs::remove_file(&request.output);
+            reencode(request, cancel, &mut progress)
+                .map(|completed| completed.then_some(ClipMethod::Reencode))
+                .map_err(|e| Error::Io(format!("{copy_error}; re-encoding failed: {e}")))
+        }
+    };
+
+    if !matches!(result, Ok(Some(_))) {
+        let _ = std::fs::remove_file(&request.output);
+    }
+    result
+}
+
+/// Copies the packets of the range to the output. Returns false if cancelled.
+fn remux(
+    request: &ClipRequest,
+    cancel: &CancelFlag,
+    progress: &mut impl FnMut(f32),
+) -> Result<bool> {
+    let mut ictx = format::input(&request.input).map_err(ffmpeg_error("open video"))?;
+    let mut octx = format::output(&request.output).map_err(ffmpeg_error("create output"))?;
+
+    // Map input streams to output streams, skipping data and attachment streams
+    let stream_count = ictx.nb_streams() as usize;
+    let mut mapping: Vec<Option<usize>> = vec![None; stream_count];
+    let mut input_time_bases = vec![Rational::new(0, 1); stream_count];
+    let mut video_index = None;
+    let mut output_count = 0;
+    for stream in ictx.streams() {
+        let medium = stream.parameters().medium();
+        if !matches!(
+            medium,
+            media::Type::Video | media::Type::Audio | media::Type::Subtitle
+        ) {
+            continue;
+        }
+        let mut output = octx
+            .add_stream(encoder::find(codec::Id::None))
+            .map_err(ffmpeg_error("add stream"))?;
+        output.set_parameters(stream.parameters());
+        // SAFETY: the parameters belong to the output stream we just added.
+        // Clearing the tag lets the muxer pick the one of its container.
+        unsafe {
+            (*output.parameters().as_mut_ptr()).codec_tag = 0;
+        }
+        mapping[stream.index()] = Some(output_count);
+        input_time_bases[stream.index()] = stream.time_base();
+        if medium == media::Type::Video && video_index.is_none() {
+            video_index = Some(stream.index());
+        }
+        output_count += 1;
+    }
+    let video_index = video_index.ok_or_else(|| Error::Io("No video stream found".to_string()))?;
+
+    octx.set_metadata(ictx.metadata().to_owned());
+    octx.write_header().map_err(ffmpeg_error("write header"))?;
+    let output_time_bases: Vec<Rational> = (0..output_count)
+        .map(|index| {
+            octx.stream(index)
+                .map_or(Rational::new(1, 1), |stream| stream.time_base())
+        })
+        .collect();
+
+    seek(&mut ictx, request.start_secs)?;
+
+    // Timestamps are shifted so the clip starts at the first video keyframe
+    let mut offset_secs: Option<f64> = None;
+    for (stream, mut packet) in ictx.packets() {
+        if cancel.is_cancelled() {
+            return Ok(false);
+        }
+        let index = stream.index();
+        let Some(output_index) = mapping.get(index).copied().flatten() else {
+            continue;
+        };
+        let time_base = input_time_bases[index];
+        let Some(position_secs) = packet
+            .pts()
+            .or(packet.dts())
+            .map(|ts| to_secs(ts, time_base))
+        else {
+            continue;
+        };
+
+        if index == video_index {
+            if offset_secs.is_none() {
+                if !packet.is_key() {
+                    continue;
+                }
+                offset_secs = Some(position_secs);
+            }
+            if position_secs >= request.end_secs {
+                break;
+            }
+            progress(request.progress_at(position_secs));
+        }
+        // Other streams start with the video
+        let Some(offset) = offset_secs else {
+            continue;
+        };
+        if position_secs < offset || position_secs >= request.end_secs {
+            continue;
+        }
+
+        let shift = from_secs(offset, time_base);
+        packet.set_pts(packet.pts().map(|ts| ts - shift));
+        packet.set_dts(packet.dts().map(|ts| ts - shift));
+        packet.rescale_ts(time_base, output_time_bases[output_index]);
+        packet.set_position(-1);
+        packet.set_stream(output_index);
+        packet
+            .write_interleaved(&mut octx)
+            .map_err(ffmpeg_error("write packet"))?;
+    }
+
+    if offset_secs.is_none() {
+        return Err(Error::Io("No keyframe found in the clip range".to_string()));
+    }
+    octx.write_trailer()
+        .map_err(ffmpeg_error("write trailer"))?;
+    Ok(true)
+}
+
+/// Decodes the video frames of the range and encodes them again.
+/// Returns false if cancelled.
+#[allow(clippy::similar_names)] // decoder vs decoded
+fn reencode(
+    request: &ClipRequest,
+    cancel: &CancelFlag,
+    progress: &mut impl FnMut(f32),
+) -> Result<bool> {
+    let mut ictx = format::input(&request.input).map_err(ffmpeg_error("open video"))?;
+    let input = ictx
+        .streams()
+        .best(media::Type::Video)
+        .ok_or_else(|| Error::Io("No video stream found".to_string()))?;
+    let video_index = input.index();
+    let input_time_base = input.time_base();
+    let frame_rate = match input.avg_frame_rate() {
+        rate if rate.numerator() > 0 && rate.denominator() > 0 => rate,
+        _ => Rational::new(FALLBACK_FRAME_RATE, 1),
+    };
+    let mut decoder = codec::context::Context::from_parameters(input.parameters())
+        .map_err(ffmpeg_error("create decoder"))?
+        .decoder()
+        .video()
+        .map_err(ffmpeg_error("create decoder"))?;
+    let (width, height) = (decoder.width(), decoder.height());
+
+    let mut octx = format::output(&request.output).map_err(ffmpeg_error("create output"))?;
+    let global_header = octx
+        .format()
+        .flags()
+        .contains(format::flag::Flags::GLOBAL_HEADER);
+    let codec = REENCODE_CODECS
+        .into_iter()
+        .find_map(encoder::find)
+        .ok_or_else(|| Error::Io("No video encoder available".to_string()))?;
+
+    let encoder_time_base = frame_rate.invert();
+    let mut settings = codec::context::Context::new_with_codec(codec)
+        .encoder()
+        .video()
+        .map_err(ffmpeg_error("create encoder"))?;
+    settings.set_width(width);
+    settings.set_height(height);
+    settings.set_format(format::Pixel::YUV420P);
+    settings.set_frame_rate(Some(frame_rate));
+    settings.set_time_base(encoder_time_base);
+    if global_header {
+        settings.set_flags(codec::flag::Flags::GLOBAL_HEADER);
+    }
+    let mut encoder = settings
+        .open_as(codec)
+        .map_err(ffmpeg_error("open encoder"))?;
+
+    let mut output = octx.add_stream(codec).map_err(ffmpeg_error("add stream"))?;
+    output.set_parameters(&encoder);
+    output.set_time_base(encoder_time_base);
+    octx.write_header().map_err(ffmpeg_error("write header"))?;
+    let output_time_base = octx
+        .stream(0)
+        .map_or(encoder_time_base, |stream| stream.time_base());
+
+    let mut scaler = ffmpeg_next::software::scaling::Context::get(
+        decoder.format(),
+        width,
+        height,
+        format::Pixel::YUV420P,
+        width,
+        height,
+        ffmpeg_next::software::scaling::Flags::BILINEAR,
+    )
+    .map_err(ffmpeg_error("create scaler"))?;
+
+    seek(&mut ictx, request.start_secs)?;
+
+    let mut frame_index: i64 = 0;
+    let mut decoded = ffmpeg_next::frame::Video::empty();
+    'packets: for (stream, packet) in ictx.packets() {
+        if cancel.is_cancelled() {
+            return Ok(false);
+        }
+        if stream.index() != video_index || decoder.send_packet(&packet).is_err() {
+            continue;
+        }
+        while decoder.receive_frame(&mut decoded).is_ok() {
+            let position_secs = decoded
+                .timestamp()
+                .map_or(request.start_secs, |ts| to_secs(ts, input_time_base));
+            if position_secs < request.start_secs {
+                continue;
+            }
+            if position_secs >= request.end_secs {
+                break 'packets;
+            }
+
+            let mut converted = ffmpeg_next::frame::Video::empty();
+            scaler
+                .run(&decoded, &mut converted)
+                .map_err(ffmpeg_error("scale frame"))?;
+            converted.set_pts(Some(frame_index));
+            frame_index += 1;
+            encoder
+                .send_frame(&converted)
+                .map_err(ffmpeg_error("encode frame"))?;
+            write_encoded(&mut encoder, &mut octx, encoder_time_base, output_time_base)?;
+            progress(request.progress_at(position_secs));
+        }
+    }
+
+    if frame_index == 0 {
+        return Err(Error::Io("No frame found in the clip range".to_string()));
+    }
+    encoder.send_eof().map_err(ffmpeg_error("flush encoder"))?;
+    write_encoded(&mut encoder, &mut octx, encoder_time_base, output_time_base)?;
+    octx.write_trailer()
+        .map_err(ffmpeg_error("write trailer"))?;
+    Ok(true)
+}
+
+/// Writes the packets the encoder has ready to the output's first stream.
+fn write_encoded(
+    encoder: &mut encoder::video::Encoder,
+    octx: &mut format::context::Output,
+    encoder_time_base: Rational,
+    output_time_base: Rational,
+) -> Result<()> {
+    let mut packet = ffmpeg_next::Packet::empty();
+    while encoder.receive_packet(&mut packet).is_ok() {
+        packet.set_stream(0);
+        packet.rescale_ts(encoder_time_base, output_time_base);
+        packet
+            .write_interleaved(octx)
+            .map_err(ffmpeg_error("write packet"))?;
+    }
+    Ok(())
+}
+
+/// Seeks the input to the keyframe at or before `position_secs`.
+pub(crate) fn seek(ictx: &mut format::context::Input, position_secs: f64) -> Result<()> {
+    // AV_TIME_BASE units (microseconds); clip positions are far below i64::MAX
+    #[allow(clippy::cast_possible_truncation)]
+    let timestamp = (position_secs.max(0.0) * 1_000_000.0) as i64;
+    ictx.seek(timestamp, ..timestamp)
+        .map_err(ffmpeg_error("seek"))
+}
+
+pub(crate) fn to_secs(timestamp: i64, time_base: Rational) -> f64 {
+    // Timestamps fit f64 in practice
+    #[allow(clippy::cast_precision_loss)]
+    let timestamp = timestamp as f64;
+    timestamp * f64::from(time_base)
+}
+
+fn from_secs(position_secs: f64, time_base: Rational) -> i64 {
+    // Positions within a video fit i64 in any time base
+    #[allow(clippy::cast_possible_truncation)]
+    let timestamp = (position_secs / f64::from(time_base)).round() as i64;
+    timestamp
+}
+
+/// Returns a mapper from an `FFmpeg` error to an [`Error::Io`] naming `action`.
+pub(crate) fn ffmpeg_error(action: &'static str) -> impl Fn(ffmpeg_next::Error) -> Error {
+    move |e| Error::Io(format!("Failed to {action}: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(start_secs: f64, end_secs: f64) -> ClipRequest {
+        ClipRequest {
+            input: PathBuf::from("in.mp4"),
+            output: PathBuf::from("out.mp4"),
+            start_secs,
+            end_secs,
+        }
+    }
+
+    #[test]
+    fn progress_is_relative_to_the_range() {
+        let request = request(10.0, 20.0);
+        assert!((request.progress_at(15.0) - 0.5).abs() < f32::EPSILON);
+        assert!(request.progress_at(5.0).abs() < f32::EPSILON);
+        assert!((request.progress_at(25.0) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn empty_ranges_are_rejected() {
+        let result = export_clip(&request(10.0, 10.0), &CancelFlag::default(), |_| {});
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn cancel_flag_is_shared_between_clones() {
+        let flag = CancelFlag::default();
+        let clone = flag.clone();
+        assert_eq!(flag, clone);
+        assert_ne!(flag, CancelFlag::default());
+
+        clone.cancel();
+        assert!(flag.is_cancelled());
+    }
+
+    #[test]
+    fn clip_filename_keeps_the_container() {
+        assert_eq!(
+            default_clip_filename(Path::new("/videos/holiday.mkv")),
+            "holiday-clip.mkv"
+        );
+        assert_eq!(default_clip_filename(Path::new("raw")), "raw-clip.mp4");
+    }
+}
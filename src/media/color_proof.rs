@@ -0,0 +1,416 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Color-managed export proofs.
+//!
+//! Images are edited and displayed as sRGB. A proof export tags the saved
+//! file with the ICC profile of a target color space (sRGB, Display P3 or
+//! Adobe RGB) and can also convert the pixels into that space, so print labs
+//! and color-managed software read the intended colors.
+//!
+//! Without conversion the profile is only *assigned*: the pixel values are
+//! kept and reinterpreted in the target space. With conversion the colors
+//! are preserved and the values change (relative colorimetric, no gamut
+//! mapping: out-of-gamut colors are clipped).
+//!
+//! The embedded profiles are small ICC v2 matrix/TRC display profiles
+//! generated on the fly.
+
+use crate::error::{Error, Result};
+use image_rs::codecs::jpeg::JpegEncoder;
+use image_rs::codecs::png::PngEncoder;
+use image_rs::codecs::webp::WebPEncoder;
+use image_rs::{DynamicImage, ImageEncoder, ImageFormat, RgbaImage};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+/// Gamma of the Adobe RGB (1998) transfer curve (563/256).
+const ADOBE_RGB_GAMMA: f32 = 2.199_218_8;
+
+/// Entries of the sampled sRGB curve embedded in profiles.
+const SRGB_CURVE_POINTS: u16 = 1024;
+
+/// Entries of the lookup table used to re-encode converted pixels.
+const ENCODE_LUT_SIZE: usize = 4096;
+
+/// PCS illuminant (D50) as XYZ.
+const D50: [f64; 3] = [0.9642, 1.0, 0.8249];
+
+/// Target color space of a proof export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofProfile {
+    Srgb,
+    DisplayP3,
+    AdobeRgb,
+}
+
+impl ProofProfile {
+    /// All profiles, in the order shown in the editor.
+    pub const ALL: [ProofProfile; 3] = [
+        ProofProfile::Srgb,
+        ProofProfile::DisplayP3,
+        ProofProfile::AdobeRgb,
+    ];
+
+    /// Returns the profile description, as embedded in the ICC data.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Srgb => "sRGB",
+            Self::DisplayP3 => "Display P3",
+            Self::AdobeRgb => "Adobe RGB (1998)",
+        }
+    }
+
+    /// Columns of the D50-adapted RGB to XYZ matrix (ICC `rXYZ`, `gXYZ`, `bXYZ`).
+    fn colorants(self) -> [[f64; 3]; 3] {
+        match self {
+            Self::Srgb => [
+                [0.436_074_7, 0.222_504_5, 0.013_932_2],
+                [0.385_064_9, 0.716_878_6, 0.097_104_5],
+                [0.143_080_4, 0.060_616_9, 0.714_173_3],
+            ],
+            Self::DisplayP3 => [
+                [0.515_102, 0.241_182, -0.001_049],
+                [0.291_965, 0.692_236, 0.041_884],
+                [0.157_153, 0.066_582, 0.784_078],
+            ],
+            Self::AdobeRgb => [
+                [0.609_755_9, 0.311_124_2, 0.019_481_1],
+                [0.205_240_1, 0.625_656_0, 0.060_890_2],
+                [0.149_224_0, 0.063_219_7, 0.744_838_7],
+            ],
+        }
+    }
+
+    /// Matrix from linear sRGB to linear values of this space (both D65).
+    fn conversion_matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            Self::Srgb => [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            Self::DisplayP3 => [
+                [0.822_462_1, 0.177_538_0, 0.0],
+                [0.033_194_1, 0.966_805_8, 0.0],
+                [0.017_082_7, 0.072_397_4, 0.910_519_9],
+            ],
+            Self::AdobeRgb => [
+                [0.715_162_7, 0.284_837_3, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.041_170_5, 0.958_829_5],
+            ],
+        }
+    }
+
+    /// Encodes a linear value (0.0 to 1.0) with the space's transfer curve.
+    fn encode(self, linear: f32) -> f32 {
+        match self {
+            Self::Srgb | Self::DisplayP3 => srgb_encode(linear),
+            Self::AdobeRgb => linear.powf(1.0 / ADOBE_RGB_GAMMA),
+        }
+    }
+
+    /// Returns the ICC profile describing this space.
+    #[must_use]
+    pub fn icc_profile(self) -> Vec<u8> {
+        let curve = match self {
+            Self::Srgb | Self::DisplayP3 => curve_tag(&srgb_curve()),
+            // u8Fixed8 gamma: 2.19921875 * 256 = 563
+            Self::AdobeRgb => curve_tag(&[563]),
+        };
+        let [red, green, blue] = self.colorants();
+        let tags: Vec<([u8; 4], Vec<u8>)> = vec![
+            (*b"desc", description_tag(self.name())),
+            (*b"cprt", text_tag("No copyright, use freely")),
+            (*b"wtpt", xyz_tag(D50)),
+            (*b"rXYZ", xyz_tag(red)),
+            (*b"gXYZ", xyz_tag(green)),
+            (*b"bXYZ", xyz_tag(blue)),
+            (*b"rTRC", curve.clone()),
+            (*b"gTRC", curve.clone()),
+            (*b"bTRC", curve),
+        ];
+        build_profile(&tags)
+    }
+}
+
+/// How the editor saves a proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProofOptions {
+    /// Profile embedded in the saved file; `None` saves without a profile.
+    pub profile: Option<ProofProfile>,
+    /// Convert the sRGB pixels into the profile's space before saving.
+    pub convert_pixels: bool,
+}
+
+/// Saves `image` to `path` in `format`, embedding the ICC profile of
+/// `profile` and converting the pixels into it when `convert_pixels` is set.
+///
+/// # Errors
+///
+/// Returns an error if the format can't carry an ICC profile (only PNG,
+/// JPEG and WebP can), or if the file can't be encoded or written.
+pub fn save_proof(
+    image: &DynamicImage,
+    path: &Path,
+    format: ImageFormat,
+    profile: ProofProfile,
+    convert_pixels: bool,
+) -> Result<()> {
+    let mut rgba = image.to_rgba8();
+    if convert_pixels {
+        convert_from_srgb(&mut rgba, profile);
+    }
+    let image = DynamicImage::ImageRgba8(rgba);
+    let icc = profile.icc_profile();
+    let writer = BufWriter::new(File::create(path)?);
+
+    match format {
+        ImageFormat::Png => write_with_profile(PngEncoder::new(writer), &image, icc),
+        // JPEG has no alpha channel
+        ImageFormat::Jpeg => write_with_profile(
+            JpegEncoder::new(writer),
+            &DynamicImage::ImageRgb8(image.to_rgb8()),
+            icc,
+        ),
+        ImageFormat::WebP => write_with_profile(WebPEncoder::new_lossless(writer), &image, icc),
+        other => Err(Error::Io(format!(
+            "{other:?} files can't embed a color profile"
+        ))),
+    }
+}
+
+fn write_with_profile(
+    mut encoder: impl ImageEncoder,
+    image: &DynamicImage,
+    icc: Vec<u8>,
+) -> Result<()> {
+    encoder
+        .set_icc_profile(icc)
+        .map_err(|e| Error::Io(format!("Failed to embed color profile: {e}")))?;
+    image
+        .write_with_encoder(encoder)
+        .map_err(|e| Error::Io(format!("Failed to save image: {e}")))
+}
+
+/// Converts sRGB pixels into `profile`'s space in place. Alpha is kept.
+pub fn convert_from_srgb(image: &mut RgbaImage, profile: ProofProfile) {
+    if profile == ProofProfile::Srgb {
+        return;
+    }
+    let decode: Vec<f32> = (0..=255u8)
+        .map(|value| srgb_decode(f32::from(value) / 255.0))
+        .collect();
+    // Table indices and encoded values are within 0..=4095 and 0..=255
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let encode: Vec<u8> = (0..ENCODE_LUT_SIZE)
+        .map(|index| {
+            let linear = index as f32 / (ENCODE_LUT_SIZE - 1) as f32;
+            (profile.encode(linear) * 255.0).round() as u8
+        })
+        .collect();
+    let matrix = profile.conversion_matrix();
+
+    for pixel in image.pixels_mut() {
+        let rgb = [
+            decode[usize::from(pixel[0])],
+            decode[usize::from(pixel[1])],
+            decode[usize::from(pixel[2])],
+        ];
+        for (channel, row) in matrix.iter().enumerate() {
+            let linear = (row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]).clamp(0.0, 1.0);
+            // Clamped to 0.0-1.0, so the index is within the table
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                clippy::cast_precision_loss
+            )]
+            let index = (linear * (ENCODE_LUT_SIZE - 1) as f32).round() as usize;
+            pixel[channel] = encode[index];
+        }
+    }
+}
+
+fn srgb_decode(value: f32) -> f32 {
+    if value <= 0.040_45 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn srgb_encode(linear: f32) -> f32 {
+    if linear <= 0.003_130_8 {
+        linear * 12.92
+    } else {
+        1.055 * linear.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Samples the sRGB decoding curve as 16-bit values.
+fn srgb_curve() -> Vec<u16> {
+    let last = f32::from(SRGB_CURVE_POINTS - 1);
+    (0..SRGB_CURVE_POINTS)
+        .map(|index| {
+            // The curve stays within 0.0-1.0
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let value = (srgb_decode(f32::from(index) / last) * 65535.0).round() as u16;
+            value
+        })
+        .collect()
+}
+
+/// Assembles an ICC v2 RGB display profile from its tags.
+fn build_profile(tags: &[([u8; 4], Vec<u8>)]) -> Vec<u8> {
+    const HEADER_LEN: usize = 128;
+    let table_len = 4 + 12 * tags.len();
+
+    let mut table = Vec::with_capacity(table_len);
+    let mut data = Vec::new();
+    table.extend_from_slice(&u32_be(tags.len()));
+    for (signature, tag) in tags {
+        let offset = HEADER_LEN + table_len + data.len();
+        table.extend_from_slice(signature);
+        table.extend_from_slice(&u32_be(offset));
+        table.extend_from_slice(&u32_be(tag.len()));
+        data.extend_from_slice(tag);
+        // Tags start on 4-byte boundaries
+        data.resize(data.len().next_multiple_of(4), 0);
+    }
+
+    let size = HEADER_LEN + table.len() + data.len();
+    let mut profile = Vec::with_capacity(size);
+    profile.extend_from_slice(&u32_be(size));
+    profile.extend_from_slice(&[0; 4]); // preferred CMM
+    profile.extend_from_slice(&[2, 0x10, 0, 0]); // version 2.1
+    profile.extend_from_slice(b"mntrRGB XYZ ");
+    profile.extend_from_slice(&[0; 12]); // creation date
+    profile.extend_from_slice(b"acsp");
+    profile.extend_from_slice(&[0; 24]); // platform, flags, device, attributes
+    profile.extend_from_slice(&[0; 4]); // perceptual intent
+    for value in D50 {
+        profile.extend_from_slice(&s15_fixed16(value));
+    }
+    profile.resize(HEADER_LEN, 0);
+    profile.extend_from_slice(&table);
+    profile.extend_from_slice(&data);
+    profile
+}
+
+fn description_tag(text: &str) -> Vec<u8> {
+    let mut tag = b"desc\0\0\0\0".to_vec();
+    tag.extend_from_slice(&u32_be(text.len() + 1));
+    tag.extend_from_slice(text.as_bytes());
+    tag.push(0);
+    // Empty Unicode and ScriptCode descriptions
+    tag.extend_from_slice(&[0; 8]);
+    tag.extend_from_slice(&[0; 3]);
+    tag.extend_from_slice(&[0; 67]);
+    tag
+}
+
+fn text_tag(text: &str) -> Vec<u8> {
+    let mut tag = b"text\0\0\0\0".to_vec();
+    tag.extend_from_slice(text.as_bytes());
+    tag.push(0);
+    tag
+}
+
+fn xyz_tag(xyz: [f64; 3]) -> Vec<u8> {
+    let mut tag = b"XYZ \0\0\0\0".to_vec();
+    for value in xyz {
+        tag.extend_from_slice(&s15_fixed16(value));
+    }
+    tag
+}
+
+fn curve_tag(points: &[u16]) -> Vec<u8> {
+    let mut tag = b"curv\0\0\0\0".to_vec();
+    tag.extend_from_slice(&u32_be(points.len()));
+    for point in points {
+        tag.extend_from_slice(&point.to_be_bytes());
+    }
+    tag
+}
+
+fn s15_fixed16(value: f64) -> [u8; 4] {
+    // Profile values are small, far within the s15.16 range
+    #[allow(clippy::cast_possible_truncation)]
+    let fixed = (value * 65536.0).round() as i32;
+    fixed.to_be_bytes()
+}
+
+fn u32_be(value: usize) -> [u8; 4] {
+    u32::try_from(value).unwrap_or(u32::MAX).to_be_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn read_u32(data: &[u8], offset: usize) -> usize {
+        u32::from_be_bytes(data[offset..offset + 4].try_into().expect("4 bytes")) as usize
+    }
+
+    #[test]
+    fn profiles_have_a_valid_layout() {
+        for profile in ProofProfile::ALL {
+            let icc = profile.icc_profile();
+            assert_eq!(read_u32(&icc, 0), icc.len());
+            assert_eq!(&icc[36..40], b"acsp");
+            assert_eq!(&icc[12..20], b"mntrRGB ");
+
+            let count = read_u32(&icc, 128);
+            assert_eq!(count, 9);
+            for index in 0..count {
+                let entry = 132 + index * 12;
+                let offset = read_u32(&icc, entry + 4);
+                let size = read_u32(&icc, entry + 8);
+                assert_eq!(offset % 4, 0);
+                assert!(offset + size <= icc.len());
+            }
+        }
+    }
+
+    #[test]
+    fn conversion_keeps_neutrals_and_extremes() {
+        let mut image = RgbaImage::from_pixel(2, 1, image_rs::Rgba([128, 128, 128, 200]));
+        image.put_pixel(1, 0, image_rs::Rgba([255, 255, 255, 255]));
+        convert_from_srgb(&mut image, ProofProfile::DisplayP3);
+        assert_eq!(image.get_pixel(0, 0).0, [128, 128, 128, 200]);
+        assert_eq!(image.get_pixel(1, 0).0, [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn conversion_moves_saturated_colors_inside_wider_gamuts() {
+        let mut image = RgbaImage::from_pixel(1, 1, image_rs::Rgba([255, 0, 0, 255]));
+        convert_from_srgb(&mut image, ProofProfile::AdobeRgb);
+        let [red, green, blue, _] = image.get_pixel(0, 0).0;
+        assert!(red < 255);
+        assert_eq!(green, 0);
+        assert_eq!(blue, 0);
+    }
+
+    #[test]
+    fn proofs_embed_the_profile() {
+        let dir = tempdir().expect("temp dir");
+        let path = dir.path().join("proof.png");
+        let image = DynamicImage::ImageRgba8(RgbaImage::new(4, 4));
+        save_proof(
+            &image,
+            &path,
+            ImageFormat::Png,
+            ProofProfile::AdobeRgb,
+            true,
+        )
+        .expect("save proof");
+
+        let bytes = std::fs::read(&path).expect("read proof");
+        assert!(bytes.windows(4).any(|chunk| chunk == b"iCCP"));
+
+        let gif = dir.path().join("proof.gif");
+        assert!(save_proof(&image, &gif, ImageFormat::Gif, ProofProfile::Srgb, false).is_err());
+    }
+}
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Image conversion and thumbnails for the command line.
+//!
+//! Backs the `convert` and `thumb` subcommands. Files are decoded with the
+//! viewer's own loaders, so SVGs, animated images and videos (their first
+//! frame) are handled the same way as on screen, and written with the `image`
+//! crate in the format given by the extension of the destination.
+
+use crate::error::{Error, Result};
+use crate::media::{load_media, ImageData, MediaData};
+use image_rs::codecs::jpeg::JpegEncoder;
+use image_rs::{DynamicImage, ImageFormat, RgbaImage};
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+/// JPEG quality used when none is given.
+pub const DEFAULT_JPEG_QUALITY: u8 = 90;
+
+/// Longest side of generated thumbnails when none is given, in pixels.
+pub const DEFAULT_THUMBNAIL_SIZE: u32 = 256;
+
+/// Quality of the JPEG thumbnails.
+const THUMBNAIL_QUALITY: u8 = 85;
+
+/// Directory created next to the originals when no thumbnail output is given.
+pub const THUMBNAIL_DIR: &str = "thumbnails";
+
+/// Decodes the image at `path`, or the first frame of a video.
+///
+/// # Errors
+///
+/// Returns an error if the file is not a supported media or can't be decoded.
+pub fn decode(path: &Path) -> Result<DynamicImage> {
+    let image = match load_media(path)? {
+        MediaData::Image(image) => image,
+        MediaData::Video(video) => video.thumbnail,
+    };
+    to_dynamic_image(&image)
+}
+
+fn to_dynamic_image(image: &ImageData) -> Result<DynamicImage> {
+    RgbaImage::from_raw(image.width, image.height, image.rgba_bytes().to_vec())
+        .map(DynamicImage::ImageRgba8)
+        .ok_or_else(|| Error::Io("Decoded image has an invalid size".to_string()))
+}
+
+/// Writes `image` to `output` in the format of its extension.
+///
+/// `quality` (1–100) applies to JPEG; other formats are written losslessly.
+///
+/// # Errors
+///
+/// Returns an error if the extension is not a supported output format or
+/// the file can't be written.
+pub fn encode(image: &DynamicImage, output: &Path, quality: u8) -> Result<()> {
+    let format = ImageFormat::from_path(output)
+        .map_err(|_| Error::Io(format!("Unsupported output format: {}", output.display())))?;
+
+    if format == ImageFormat::Jpeg {
+        let writer = BufWriter::new(File::create(output)?);
+        JpegEncoder::new_with_quality(writer, quality.clamp(1, 100))
+            .encode_image(&image.to_rgb8())
+            .map_err(|e| Error::Io(format!("Failed to encode {}: {e}", output.display())))
+    } else {
+        image
+            .save_with_format(output, format)
+            .map_err(|e| Error::Io(format!("Failed to encode {}: {e}", output.display())))
+    }
+}
+
+/// Converts the media at `input` to the image `output`.
+///
+/// # Errors
+///
+/// Returns an error if the input can't be decoded or the output written.
+pub fn convert(input: &Path, output: &Path, quality: u8) -> Result<()> {
+    encode(&decode(input)?, output, quality)
+}
+
+/// Returns where the thumbnail of `input` goes in `output_dir`.
+///
+/// The original extension is kept in the name so `a.png` and `a.jpg` get
+/// different thumbnails.
+#[must_use]
+pub fn thumbnail_path(input: &Path, output_dir: &Path) -> PathBuf {
+    let mut name = input.file_name().unwrap_or_default().to_os_string();
+    name.push(".jpg");
+    output_dir.join(name)
+}
+
+/// Writes a JPEG thumbnail of `input`, fitting in `size`×`size` pixels, to
+/// `output_dir` and returns its path. Images smaller than `size` are not
+/// enlarged.
+///
+/// # Errors
+///
+/// Returns an error if the input can't be decoded or the thumbnail written.
+pub fn write_thumbnail(input: &Path, output_dir: &Path, size: u32) -> Result<PathBuf> {
+    let image = decode(input)?;
+    let thumbnail = if image.width() > size || image.height() > size {
+        image.thumbnail(size, size)
+    } else {
+        image
+    };
+    fs::create_dir_all(output_dir)?;
+    let output = thumbnail_path(input, output_dir);
+    encode(&thumbnail, &output, THUMBNAIL_QUALITY)?;
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image_rs::Rgba;
+    use tempfile::tempdir;
+
+    fn write_test_image(path: &Path, width: u32, height: u32) {
+        RgbaImage::from_pixel(width, height, Rgba([200, 40, 40, 255]))
+            .save(path)
+            .expect("write test image");
+    }
+
+    #[test]
+    fn convert_follows_output_extension() {
+        let dir = tempdir().expect("temp dir");
+        let input = dir.path().join("in.png");
+        write_test_image(&input, 40, 30);
+
+        let output = dir.path().join("out.jpg");
+        convert(&input, &output, 85).expect("convert to JPEG");
+        let converted = image_rs::open(&output).expect("read converted image");
+        assert_eq!((converted.width(), converted.height()), (40, 30));
+        assert_eq!(
+            ImageFormat::from_path(&output).expect("known format"),
+            ImageFormat::Jpeg
+        );
+
+        assert!(convert(&input, &dir.path().join("out.xyz"), 85).is_err());
+    }
+
+    #[test]
+    fn thumbnails_fit_the_size_without_enlarging() {
+        let dir = tempdir().expect("temp dir");
+        let large = dir.path().join("large.png");
+        let small = dir.path().join("small.png");
+        write_test_image(&large, 400, 200);
+        write_test_image(&small, 50, 20);
+        let out = dir.path().join(THUMBNAIL_DIR);
+
+        let thumbnail = write_thumbnail(&large, &out, 100).expect("write thumbnail");
+        assert_eq!(thumbnail, out.join("large.png.jpg"));
+        let image = image_rs::open(&thumbnail).expect("read thumbnail");
+        assert_eq!((image.width(), image.height()), (100, 50));
+
+        let thumbnail = write_thumbnail(&small, &out, 100).expect("write thumbnail");
+        let image = image_rs::open(&thumbnail).expect("read thumbnail");
+        assert_eq!((image.width(), image.height()), (50, 20));
+    }
+}
@@ -0,0 +1,202 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Decoder backends for formats that more than one library can decode.
+//!
+//! Still WebP images can be decoded by image-rs (pure Rust, the default) or
+//! by libwebp, the reference library that also plays animated WebP. When the
+//! preferred backend rejects a file, the other one is tried before giving up.
+//! Whether videos are decoded on the GPU or the CPU is chosen separately with
+//! the hardware decoding setting.
+//!
+//! The backend that decoded an image is recorded in [`DecodeInfo`] so the
+//! info panel can show the decision for each loaded file.
+
+use crate::error::{Error, Result};
+use crate::video_player::DecodePath;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Library preferred for still WebP images.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum WebpDecoder {
+    /// Pure Rust decoder from the `image` crate.
+    #[default]
+    ImageRs,
+    /// Google's libwebp.
+    Libwebp,
+}
+
+impl WebpDecoder {
+    /// All choices, in the order shown in settings.
+    pub const ALL: [WebpDecoder; 2] = [WebpDecoder::ImageRs, WebpDecoder::Libwebp];
+
+    /// Returns the command-line name of the decoder.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ImageRs => "image-rs",
+            Self::Libwebp => "libwebp",
+        }
+    }
+
+    /// Returns the backend used when this decoder is preferred.
+    #[must_use]
+    pub fn backend(self) -> ImageDecoder {
+        match self {
+            Self::ImageRs => ImageDecoder::ImageRs,
+            Self::Libwebp => ImageDecoder::Libwebp,
+        }
+    }
+}
+
+impl FromStr for WebpDecoder {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|decoder| decoder.as_str() == s)
+            .ok_or_else(|| Error::Config(format!("Unknown WebP decoder: {s}")))
+    }
+}
+
+/// Decoder choices applied when loading media.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DecoderPreferences {
+    pub webp: WebpDecoder,
+}
+
+/// Library that decoded an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageDecoder {
+    /// The `image` crate (all raster formats).
+    ImageRs,
+    /// Google's libwebp (WebP).
+    Libwebp,
+    /// resvg (SVG).
+    Resvg,
+}
+
+impl ImageDecoder {
+    /// Returns the library name shown in diagnostics.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ImageRs => "image-rs",
+            Self::Libwebp => "libwebp",
+            Self::Resvg => "resvg",
+        }
+    }
+
+    /// Returns the other backend able to decode the same files, if any.
+    #[must_use]
+    pub fn alternative(self) -> Option<Self> {
+        match self {
+            Self::ImageRs => Some(Self::Libwebp),
+            Self::Libwebp => Some(Self::ImageRs),
+            Self::Resvg => None,
+        }
+    }
+
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::ImageRs => 0,
+            Self::Libwebp => 1,
+            Self::Resvg => 2,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::ImageRs),
+            1 => Some(Self::Libwebp),
+            2 => Some(Self::Resvg),
+            _ => None,
+        }
+    }
+}
+
+/// How an image was decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeInfo {
+    pub decoder: ImageDecoder,
+    /// The preferred backend failed and this one was used instead.
+    pub fallback: bool,
+}
+
+impl DecodeInfo {
+    /// Decoded by the preferred (or only) backend.
+    #[must_use]
+    pub fn preferred(decoder: ImageDecoder) -> Self {
+        Self {
+            decoder,
+            fallback: false,
+        }
+    }
+
+    /// Packs the info into two bytes (used by the sandboxed decode helper).
+    #[must_use]
+    pub fn to_bytes(self) -> [u8; 2] {
+        [self.decoder.to_byte(), u8::from(self.fallback)]
+    }
+
+    /// Unpacks [`to_bytes`](Self::to_bytes) output.
+    #[must_use]
+    pub fn from_bytes(bytes: [u8; 2]) -> Option<Self> {
+        Some(Self {
+            decoder: ImageDecoder::from_byte(bytes[0])?,
+            fallback: match bytes[1] {
+                0 => false,
+                1 => true,
+                _ => return None,
+            },
+        })
+    }
+}
+
+/// Backend that decoded the current media, shown in the info panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaDecoder {
+    Image(DecodeInfo),
+    Video(DecodePath),
+}
+
+/// Decodes a still WebP image with libwebp into RGBA pixels.
+///
+/// # Errors
+///
+/// Returns an error if libwebp rejects the data or it holds no frame.
+pub fn decode_webp_with_libwebp(bytes: &[u8]) -> Result<(u32, u32, Vec<u8>)> {
+    let decoder = webp_animation::Decoder::new(bytes)
+        .map_err(|e| Error::Io(format!("Failed to decode WebP: {e:?}")))?;
+    let frame = decoder
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::Io("No frames found in WebP".to_string()))?;
+    let (width, height) = frame.dimensions();
+    Ok((width, height, frame.data().to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn webp_decoder_parses_its_names() {
+        for decoder in WebpDecoder::ALL {
+            assert_eq!(decoder.as_str().parse::<WebpDecoder>().ok(), Some(decoder));
+        }
+        assert!("ffmpeg".parse::<WebpDecoder>().is_err());
+    }
+
+    #[test]
+    fn decode_info_round_trips_through_bytes() {
+        let info = DecodeInfo {
+            decoder: ImageDecoder::Libwebp,
+            fallback: true,
+        };
+        assert_eq!(DecodeInfo::from_bytes(info.to_bytes()), Some(info));
+        assert_eq!(DecodeInfo::from_bytes([7, 0]), None);
+        assert_eq!(DecodeInfo::from_bytes([0, 2]), None);
+    }
+}
@@ -0,0 +1,570 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Noise reduction for the image editor.
+//!
+//! This module provides functionality for:
+//! - Two classical filters running on the CPU: a bilateral filter, quick and
+//!   good at smoothing flat areas, and non-local means, slower but better at
+//!   keeping fine texture
+//! - An optional ONNX denoising model, set with `denoise_model_url` in the
+//!   `[ai]` section of the settings and downloaded on first use
+//! - A downscaled proxy of the image for live previews
+//!
+//! The model must be an image-to-image network taking and returning an RGB
+//! NCHW tensor with values from 0 to 1, such as `NAFNet` trained on SIDD.
+//!
+//! Pixel math converts between u8, i64 and f32. Precision loss is acceptable
+//! for image sizes and 8-bit channel values.
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+#![allow(clippy::cast_possible_wrap)]
+
+use crate::app::paths;
+use image_rs::{imageops::FilterType, DynamicImage, RgbaImage};
+use ndarray::Array4;
+use ort::session::{builder::GraphOptimizationLevel, Session};
+use std::path::PathBuf;
+
+/// Filename for the downloaded denoising model in the data directory.
+const MODEL_FILENAME: &str = "denoise.onnx";
+
+/// Minimum expected model size (1 MB) to detect failed downloads.
+const MIN_MODEL_SIZE_BYTES: u64 = 1_000_000;
+
+/// The model input is padded to a multiple of this size, as its encoder
+/// stages halve the dimensions several times.
+const MODEL_PAD_MULTIPLE: u32 = 32;
+
+/// Longest side of the proxy used for live previews, in pixels.
+pub const PROXY_MAX_SIZE: u32 = 640;
+
+/// Share of the filter strength the detail slider can hold back.
+const DETAIL_WEIGHT: f32 = 0.75;
+
+/// Search radius of non-local means, in pixels.
+const NLM_SEARCH_RADIUS: i64 = 3;
+
+/// Patch radius of non-local means, in pixels.
+const NLM_PATCH_RADIUS: i64 = 1;
+
+/// Result type for denoise operations.
+pub type DenoiseResult<T> = Result<T, DenoiseError>;
+
+/// Errors that can occur while running the denoising model.
+#[derive(Debug, Clone)]
+pub enum DenoiseError {
+    /// No model URL is set in the settings.
+    ModelNotConfigured,
+    /// Model file not found at expected path.
+    ModelNotFound,
+    /// Failed to download the model.
+    DownloadFailed(String),
+    /// ONNX inference failed.
+    InferenceFailed(String),
+    /// Model output could not be interpreted.
+    PostprocessingFailed(String),
+    /// IO error occurred.
+    Io(String),
+}
+
+impl std::fmt::Display for DenoiseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DenoiseError::ModelNotConfigured => write!(f, "No denoising model URL is set"),
+            DenoiseError::ModelNotFound => write!(f, "Model file not found"),
+            DenoiseError::DownloadFailed(msg) => write!(f, "Download failed: {msg}"),
+            DenoiseError::InferenceFailed(msg) => write!(f, "Inference failed: {msg}"),
+            DenoiseError::PostprocessingFailed(msg) => {
+                write!(f, "Postprocessing failed: {msg}")
+            }
+            DenoiseError::Io(msg) => write!(f, "IO error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for DenoiseError {}
+
+/// How noise is reduced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DenoiseMethod {
+    /// Edge-preserving blur weighting neighbors by distance and color.
+    #[default]
+    Bilateral,
+    /// Averages pixels whose surroundings look alike.
+    NonLocalMeans,
+    /// Configured ONNX denoising model.
+    Model,
+}
+
+/// Noise reduction settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DenoiseParams {
+    pub method: DenoiseMethod,
+    /// How much noise is removed, from 0 to 100.
+    pub strength: u32,
+    /// How much fine detail is protected from smoothing, from 0 to 100.
+    pub detail: u32,
+}
+
+impl Default for DenoiseParams {
+    fn default() -> Self {
+        Self {
+            method: DenoiseMethod::default(),
+            strength: 50,
+            detail: 50,
+        }
+    }
+}
+
+impl DenoiseParams {
+    /// Returns the filter strength once the detail protection is taken
+    /// out, from 0 to 1.
+    fn effective_strength(&self) -> f32 {
+        let strength = self.strength.min(100) as f32 / 100.0;
+        let detail = self.detail.min(100) as f32 / 100.0;
+        strength * (1.0 - DETAIL_WEIGHT * detail)
+    }
+}
+
+/// Reduces noise in `image` with a classical filter.
+///
+/// The alpha channel is kept as is. [`DenoiseMethod::Model`] is run with
+/// [`DenoiseModel`] instead and returns the image unchanged here.
+#[must_use]
+pub fn denoise(image: &DynamicImage, params: &DenoiseParams) -> DynamicImage {
+    if params.strength == 0 || params.method == DenoiseMethod::Model {
+        return image.clone();
+    }
+    let rgba = image.to_rgba8();
+    let filtered = match params.method {
+        DenoiseMethod::Bilateral => bilateral(&rgba, params),
+        DenoiseMethod::NonLocalMeans => non_local_means(&rgba, params),
+        DenoiseMethod::Model => unreachable!(),
+    };
+    keep_color_type(image, filtered)
+}
+
+/// Returns a copy of `image` whose longest side is at most
+/// [`PROXY_MAX_SIZE`], for live previews.
+#[must_use]
+pub fn preview_proxy(image: &DynamicImage) -> DynamicImage {
+    if image.width().max(image.height()) <= PROXY_MAX_SIZE {
+        return image.clone();
+    }
+    image.resize(PROXY_MAX_SIZE, PROXY_MAX_SIZE, FilterType::Triangle)
+}
+
+/// Returns `filtered` as RGB when `original` has no alpha channel.
+fn keep_color_type(original: &DynamicImage, filtered: RgbaImage) -> DynamicImage {
+    let filtered = DynamicImage::ImageRgba8(filtered);
+    if original.color().has_alpha() {
+        filtered
+    } else {
+        DynamicImage::ImageRgb8(filtered.to_rgb8())
+    }
+}
+
+/// Returns the pixel at (`x`, `y`), clamped to the image edges.
+fn clamped_pixel(image: &RgbaImage, x: i64, y: i64) -> [u8; 4] {
+    let x = x.clamp(0, i64::from(image.width()) - 1) as u32;
+    let y = y.clamp(0, i64::from(image.height()) - 1) as u32;
+    image.get_pixel(x, y).0
+}
+
+/// Bilateral filter: each pixel becomes the average of its neighbors,
+/// weighted down by distance and by color difference so edges stay sharp.
+fn bilateral(src: &RgbaImage, params: &DenoiseParams) -> RgbaImage {
+    let strength = params.effective_strength();
+    let radius = 1 + (params.strength.min(100) as f32 / 25.0).round() as i64;
+    let sigma_spatial = radius as f32 / 2.0 + 0.5;
+    let sigma_range = 2.0 + 48.0 * strength;
+
+    let size = (2 * radius + 1) as usize;
+    let spatial: Vec<f32> = (-radius..=radius)
+        .flat_map(|dy| (-radius..=radius).map(move |dx| (dx, dy)))
+        .map(|(dx, dy)| (-((dx * dx + dy * dy) as f32) / (2.0 * sigma_spatial.powi(2))).exp())
+        .collect();
+    // Color distance is the mean absolute channel difference (0 to 255)
+    let range: Vec<f32> = (0..=765)
+        .map(|sum| {
+            let distance = sum as f32 / 3.0;
+            (-(distance * distance) / (2.0 * sigma_range.powi(2))).exp()
+        })
+        .collect();
+
+    let mut out = src.clone();
+    for (x, y, pixel) in out.enumerate_pixels_mut() {
+        let center = src.get_pixel(x, y).0;
+        let mut sum = [0.0f32; 3];
+        let mut total = 0.0f32;
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                let neighbor = clamped_pixel(src, i64::from(x) + dx, i64::from(y) + dy);
+                let difference: usize = (0..3)
+                    .map(|c| usize::from(center[c].abs_diff(neighbor[c])))
+                    .sum();
+                let weight = spatial[(dy + radius) as usize * size + (dx + radius) as usize]
+                    * range[difference];
+                for c in 0..3 {
+                    sum[c] += weight * f32::from(neighbor[c]);
+                }
+                total += weight;
+            }
+        }
+        for c in 0..3 {
+            pixel.0[c] = (sum[c] / total).round().clamp(0.0, 255.0) as u8;
+        }
+    }
+    out
+}
+
+/// Non-local means: each pixel becomes the average of the pixels around it
+/// whose neighborhoods look alike, compared on luminance.
+fn non_local_means(src: &RgbaImage, params: &DenoiseParams) -> RgbaImage {
+    let strength = params.effective_strength();
+    let h = 1.0 + 29.0 * strength;
+    let (width, height) = (i64::from(src.width()), i64::from(src.height()));
+    let luma: Vec<f32> = src
+        .pixels()
+        .map(|p| 0.299 * f32::from(p.0[0]) + 0.587 * f32::from(p.0[1]) + 0.114 * f32::from(p.0[2]))
+        .collect();
+    let luma_at =
+        |x: i64, y: i64| luma[(y.clamp(0, height - 1) * width + x.clamp(0, width - 1)) as usize];
+    let patch_size = ((2 * NLM_PATCH_RADIUS + 1) * (2 * NLM_PATCH_RADIUS + 1)) as f32;
+
+    let mut out = src.clone();
+    for (x, y, pixel) in out.enumerate_pixels_mut() {
+        let (x, y) = (i64::from(x), i64::from(y));
+        let mut sum = [0.0f32; 3];
+        let mut total = 0.0f32;
+        for sy in -NLM_SEARCH_RADIUS..=NLM_SEARCH_RADIUS {
+            for sx in -NLM_SEARCH_RADIUS..=NLM_SEARCH_RADIUS {
+                let mut distance = 0.0f32;
+                for py in -NLM_PATCH_RADIUS..=NLM_PATCH_RADIUS {
+                    for px in -NLM_PATCH_RADIUS..=NLM_PATCH_RADIUS {
+                        let d = luma_at(x + px, y + py) - luma_at(x + sx + px, y + sy + py);
+                        distance += d * d;
+                    }
+                }
+                let weight = (-(distance / patch_size) / (h * h)).exp();
+                let neighbor = clamped_pixel(src, x + sx, y + sy);
+                for c in 0..3 {
+                    sum[c] += weight * f32::from(neighbor[c]);
+                }
+                total += weight;
+            }
+        }
+        for c in 0..3 {
+            pixel.0[c] = (sum[c] / total).round().clamp(0.0, 255.0) as u8;
+        }
+    }
+    out
+}
+
+/// Denoising model backed by an ONNX session.
+pub struct DenoiseModel {
+    session: Session,
+}
+
+impl DenoiseModel {
+    /// Loads the model from the downloaded file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the model file is missing or the ONNX session
+    /// fails to initialize.
+    pub fn load() -> DenoiseResult<Self> {
+        let model_path = get_model_path();
+        if !model_path.exists() {
+            return Err(DenoiseError::ModelNotFound);
+        }
+
+        let session = Session::builder()
+            .map_err(|e| DenoiseError::InferenceFailed(e.to_string()))?
+            .with_optimization_level(GraphOptimizationLevel::Level3)
+            .map_err(|e| DenoiseError::InferenceFailed(e.to_string()))?
+            .commit_from_file(&model_path)
+            .map_err(|e| DenoiseError::InferenceFailed(e.to_string()))?;
+
+        Ok(Self { session })
+    }
+
+    /// Runs the model on `image`, keeping its alpha channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if inference fails or the model output is malformed.
+    pub fn denoise(&mut self, image: &DynamicImage) -> DenoiseResult<DynamicImage> {
+        let rgba = image.to_rgba8();
+        let input_tensor = preprocess_image(&rgba);
+        let input_tensor = input_tensor.as_standard_layout().into_owned();
+
+        let input_name = self
+            .session
+            .inputs
+            .first()
+            .map_or_else(|| "input".to_string(), |i| i.name.clone());
+
+        let input_ref = ort::value::TensorRef::from_array_view(&input_tensor)
+            .map_err(|e| DenoiseError::InferenceFailed(e.to_string()))?;
+
+        let outputs = self
+            .session
+            .run(ort::inputs![input_name.as_str() => input_ref])
+            .map_err(|e| DenoiseError::InferenceFailed(e.to_string()))?;
+
+        let (_, output) = outputs
+            .iter()
+            .next()
+            .ok_or_else(|| DenoiseError::PostprocessingFailed("No output tensor".to_string()))?;
+        let (shape, data) = output
+            .try_extract_tensor::<f32>()
+            .map_err(|e: ort::Error| DenoiseError::PostprocessingFailed(e.to_string()))?;
+
+        let denoised = postprocess_output(shape, data, &rgba)?;
+        Ok(keep_color_type(image, denoised))
+    }
+}
+
+/// Returns the path where the denoising model should be stored.
+#[must_use]
+pub fn get_model_path() -> PathBuf {
+    paths::get_app_data_dir().map_or_else(
+        || PathBuf::from(MODEL_FILENAME),
+        |mut p| {
+            p.push(MODEL_FILENAME);
+            p
+        },
+    )
+}
+
+/// Checks if the model file exists at the expected location with valid size.
+#[must_use]
+pub fn is_model_downloaded() -> bool {
+    std::fs::metadata(get_model_path()).is_ok_and(|meta| meta.len() >= MIN_MODEL_SIZE_BYTES)
+}
+
+/// Downloads the model from the specified URL.
+///
+/// Returns the number of bytes downloaded.
+///
+/// # Errors
+///
+/// Returns an error if the download fails or the file cannot be written.
+pub async fn download_model(url: &str) -> DenoiseResult<u64> {
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::limited(10))
+        .user_agent("IcedLens/0.3.0")
+        .build()
+        .map_err(|e| DenoiseError::DownloadFailed(e.to_string()))?;
+
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| DenoiseError::DownloadFailed(e.to_string()))?;
+
+    if !response.status().is_success() {
+        return Err(DenoiseError::DownloadFailed(format!(
+            "HTTP status: {}",
+            response.status()
+        )));
+    }
+
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| DenoiseError::DownloadFailed(e.to_string()))?;
+    let downloaded = bytes.len() as u64;
+
+    if downloaded < MIN_MODEL_SIZE_BYTES {
+        return Err(DenoiseError::DownloadFailed(format!(
+            "Downloaded file too small ({downloaded} bytes), expected an ONNX model"
+        )));
+    }
+
+    let model_path = get_model_path();
+    if let Some(parent) = model_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| DenoiseError::Io(e.to_string()))?;
+    }
+    std::fs::write(&model_path, &bytes).map_err(|e| DenoiseError::Io(e.to_string()))?;
+
+    Ok(downloaded)
+}
+
+/// Returns `size` rounded up to a multiple of [`MODEL_PAD_MULTIPLE`].
+fn padded_size(size: u32) -> u32 {
+    size.div_ceil(MODEL_PAD_MULTIPLE) * MODEL_PAD_MULTIPLE
+}
+
+/// Mirrors `index` back into `0..size` for reflection padding.
+fn reflect(index: u32, size: u32) -> u32 {
+    if index < size {
+        index
+    } else {
+        (2 * size).saturating_sub(index + 1).min(size - 1)
+    }
+}
+
+/// Preprocesses an image for the denoising model.
+///
+/// Converts to NCHW format (batch=1, channels=3), RGB order, normalized to
+/// 0-1, padded by reflection to a multiple of [`MODEL_PAD_MULTIPLE`].
+fn preprocess_image(rgba: &RgbaImage) -> Array4<f32> {
+    let (width, height) = rgba.dimensions();
+    let (padded_width, padded_height) = (padded_size(width), padded_size(height));
+
+    let mut tensor = Array4::<f32>::zeros((1, 3, padded_height as usize, padded_width as usize));
+    for y in 0..padded_height {
+        for x in 0..padded_width {
+            let pixel = rgba.get_pixel(reflect(x, width), reflect(y, height)).0;
+            for channel in 0..3 {
+                tensor[[0, channel, y as usize, x as usize]] = f32::from(pixel[channel]) / 255.0;
+            }
+        }
+    }
+    tensor
+}
+
+/// Converts the model output back to an image the size of `original`,
+/// cropping the padding and keeping the original alpha channel.
+fn postprocess_output(
+    shape: &[i64],
+    data: &[f32],
+    original: &RgbaImage,
+) -> DenoiseResult<RgbaImage> {
+    let [_, channels, height, width] = shape else {
+        return Err(DenoiseError::PostprocessingFailed(format!(
+            "Expected 4D tensor, got {}D",
+            shape.len()
+        )));
+    };
+    let (width, height) = (
+        usize::try_from(*width).unwrap_or(0),
+        usize::try_from(*height).unwrap_or(0),
+    );
+    let (original_width, original_height) = original.dimensions();
+    if *channels != 3
+        || width < original_width as usize
+        || height < original_height as usize
+        || data.len() < 3 * width * height
+    {
+        return Err(DenoiseError::PostprocessingFailed(format!(
+            "Unexpected output shape {shape:?}"
+        )));
+    }
+
+    let channel_size = width * height;
+    let mut out = original.clone();
+    for (x, y, pixel) in out.enumerate_pixels_mut() {
+        let index = y as usize * width + x as usize;
+        for channel in 0..3 {
+            pixel.0[channel] = (data[channel * channel_size + index] * 255.0)
+                .round()
+                .clamp(0.0, 255.0) as u8;
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Flat gray image with deterministic ±`amplitude` noise.
+    fn noisy_gray(width: u32, height: u32, amplitude: i32) -> DynamicImage {
+        let mut state = 12_345u32;
+        let image = RgbaImage::from_fn(width, height, |_, _| {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let noise = (state >> 16) as i32 % (2 * amplitude + 1) - amplitude;
+            let value = (128 + noise) as u8;
+            image_rs::Rgba([value, value, value, 255])
+        });
+        DynamicImage::ImageRgba8(image)
+    }
+
+    fn variance(image: &DynamicImage) -> f32 {
+        let values: Vec<f32> = image
+            .to_rgb8()
+            .pixels()
+            .map(|p| f32::from(p.0[0]))
+            .collect();
+        let mean = values.iter().sum::<f32>() / values.len() as f32;
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / values.len() as f32
+    }
+
+    #[test]
+    fn classical_filters_reduce_noise() {
+        let image = noisy_gray(32, 32, 20);
+        for method in [DenoiseMethod::Bilateral, DenoiseMethod::NonLocalMeans] {
+            let params = DenoiseParams {
+                method,
+                strength: 80,
+                detail: 0,
+            };
+            let denoised = denoise(&image, &params);
+            assert!(
+                variance(&denoised) < variance(&image) / 2.0,
+                "{method:?} left too much noise"
+            );
+        }
+    }
+
+    #[test]
+    fn bilateral_keeps_strong_edges() {
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_fn(16, 8, |x, _| {
+            let value = if x < 8 { 20 } else { 230 };
+            image_rs::Rgba([value, value, value, 255])
+        }));
+        let params = DenoiseParams {
+            strength: 100,
+            ..DenoiseParams::default()
+        };
+        let denoised = denoise(&image, &params).to_rgb8();
+        assert!(denoised.get_pixel(7, 4).0[0] < 40);
+        assert!(denoised.get_pixel(8, 4).0[0] > 210);
+    }
+
+    #[test]
+    fn zero_strength_and_alpha_are_kept() {
+        let image = noisy_gray(8, 8, 10);
+        let unchanged = DenoiseParams {
+            strength: 0,
+            ..DenoiseParams::default()
+        };
+        assert_eq!(denoise(&image, &unchanged), image);
+
+        let rgb = DynamicImage::ImageRgb8(image.to_rgb8());
+        assert!(!denoise(&rgb, &DenoiseParams::default()).color().has_alpha());
+        let mut transparent = image.to_rgba8();
+        transparent.get_pixel_mut(0, 0).0[3] = 0;
+        let denoised = denoise(
+            &DynamicImage::ImageRgba8(transparent),
+            &DenoiseParams::default(),
+        );
+        assert_eq!(denoised.to_rgba8().get_pixel(0, 0).0[3], 0);
+    }
+
+    #[test]
+    fn preview_proxy_limits_the_longest_side() {
+        let proxy = preview_proxy(&DynamicImage::new_rgb8(1600, 900));
+        assert_eq!((proxy.width(), proxy.height()), (640, 360));
+        let small = preview_proxy(&DynamicImage::new_rgb8(100, 50));
+        assert_eq!((small.width(), small.height()), (100, 50));
+    }
+
+    #[test]
+    fn model_input_is_padded_by_reflection() {
+        let rgba = RgbaImage::from_fn(40, 20, |x, _| image_rs::Rgba([x as u8, 0, 0, 255]));
+        let tensor = preprocess_image(&rgba);
+        assert_eq!(tensor.shape(), &[1, 3, 32, 64]);
+        // Column 40 mirrors column 39, column 41 mirrors column 38
+        assert!((tensor[[0, 0, 0, 40]] - 39.0 / 255.0).abs() < 1e-6);
+        assert!((tensor[[0, 0, 0, 41]] - 38.0 / 255.0).abs() < 1e-6);
+
+        // Output is cropped back to the original size
+        let data: Vec<f32> = tensor.iter().copied().collect();
+        let out = postprocess_output(&[1, 3, 32, 64], &data, &rgba).unwrap();
+        assert_eq!(out, rgba);
+    }
+}
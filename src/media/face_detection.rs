@@ -0,0 +1,464 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Face detection using the `UltraFace` RFB-320 ONNX model.
+//!
+//! This module provides functionality for:
+//! - Downloading the lightweight `UltraFace` model (~1.2 MB) on first use
+//! - Detecting faces in an image and returning their bounding boxes
+//!
+//! Detection runs on a downscaled 320x240 copy of the image, so it is fast
+//! enough to run on demand from the viewer without a dedicated manager.
+
+use crate::app::paths;
+use image_rs::{imageops::FilterType, DynamicImage};
+use ndarray::Array4;
+use ort::session::{builder::GraphOptimizationLevel, Session};
+use std::path::PathBuf;
+
+/// Filename for the downloaded face detection model in the data directory.
+const MODEL_FILENAME: &str = "ultraface-rfb-320.onnx";
+
+/// Minimum expected model size (1 MB) to detect failed downloads.
+const MIN_MODEL_SIZE_BYTES: u64 = 1_000_000;
+
+/// Input width expected by the RFB-320 model.
+const INPUT_WIDTH: u32 = 320;
+
+/// Input height expected by the RFB-320 model.
+const INPUT_HEIGHT: u32 = 240;
+
+/// Minimum confidence for a candidate box to be kept.
+const SCORE_THRESHOLD: f32 = 0.7;
+
+/// Overlap above which the weaker of two boxes is suppressed.
+const IOU_THRESHOLD: f32 = 0.3;
+
+/// Result type for face detection operations.
+pub type FaceDetectionResult<T> = Result<T, FaceDetectionError>;
+
+/// Errors that can occur during face detection.
+#[derive(Debug, Clone)]
+pub enum FaceDetectionError {
+    /// Model file not found at expected path.
+    ModelNotFound,
+    /// Failed to download the model.
+    DownloadFailed(String),
+    /// ONNX inference failed.
+    InferenceFailed(String),
+    /// Image preprocessing failed.
+    PreprocessingFailed(String),
+    /// Model output could not be interpreted.
+    PostprocessingFailed(String),
+    /// IO error occurred.
+    Io(String),
+}
+
+impl std::fmt::Display for FaceDetectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FaceDetectionError::ModelNotFound => write!(f, "Model file not found"),
+            FaceDetectionError::DownloadFailed(msg) => write!(f, "Download failed: {msg}"),
+            FaceDetectionError::InferenceFailed(msg) => write!(f, "Inference failed: {msg}"),
+            FaceDetectionError::PreprocessingFailed(msg) => {
+                write!(f, "Preprocessing failed: {msg}")
+            }
+            FaceDetectionError::PostprocessingFailed(msg) => {
+                write!(f, "Postprocessing failed: {msg}")
+            }
+            FaceDetectionError::Io(msg) => write!(f, "IO error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for FaceDetectionError {}
+
+/// A detected face, in pixel coordinates of the analyzed image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FaceBox {
+    /// Left edge in pixels.
+    pub x: f32,
+    /// Top edge in pixels.
+    pub y: f32,
+    /// Width in pixels.
+    pub width: f32,
+    /// Height in pixels.
+    pub height: f32,
+    /// Detection confidence (0.0 to 1.0).
+    pub score: f32,
+}
+
+impl FaceBox {
+    /// Returns the center of the box.
+    #[must_use]
+    pub fn center(&self) -> (f32, f32) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    /// Returns this box in the coordinates of the image rotated clockwise by
+    /// `degrees` (0, 90, 180 or 270), given the unrotated image size.
+    #[must_use]
+    pub fn rotated(&self, degrees: u16, image_width: f32, image_height: f32) -> FaceBox {
+        let (x, y, width, height) = match degrees {
+            90 => (
+                image_height - self.y - self.height,
+                self.x,
+                self.height,
+                self.width,
+            ),
+            180 => (
+                image_width - self.x - self.width,
+                image_height - self.y - self.height,
+                self.width,
+                self.height,
+            ),
+            270 => (
+                self.y,
+                image_width - self.x - self.width,
+                self.height,
+                self.width,
+            ),
+            _ => (self.x, self.y, self.width, self.height),
+        };
+        FaceBox {
+            x,
+            y,
+            width,
+            height,
+            score: self.score,
+        }
+    }
+
+    /// Returns the intersection-over-union ratio with another box.
+    #[must_use]
+    pub fn iou(&self, other: &FaceBox) -> f32 {
+        let left = self.x.max(other.x);
+        let top = self.y.max(other.y);
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+
+        let intersection = (right - left).max(0.0) * (bottom - top).max(0.0);
+        let union = self.width * self.height + other.width * other.height - intersection;
+        if union <= 0.0 {
+            0.0
+        } else {
+            intersection / union
+        }
+    }
+}
+
+/// Face detector backed by an ONNX session.
+pub struct FaceDetector {
+    session: Session,
+}
+
+impl FaceDetector {
+    /// Loads the detector from the downloaded model file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the model file is missing or the ONNX session
+    /// fails to initialize.
+    pub fn load() -> FaceDetectionResult<Self> {
+        let model_path = get_model_path();
+        if !model_path.exists() {
+            return Err(FaceDetectionError::ModelNotFound);
+        }
+
+        let session = Session::builder()
+            .map_err(|e| FaceDetectionError::InferenceFailed(e.to_string()))?
+            .with_optimization_level(GraphOptimizationLevel::Level3)
+            .map_err(|e| FaceDetectionError::InferenceFailed(e.to_string()))?
+            .commit_from_file(&model_path)
+            .map_err(|e| FaceDetectionError::InferenceFailed(e.to_string()))?;
+
+        Ok(Self { session })
+    }
+
+    /// Detects faces in an image.
+    ///
+    /// Returns boxes in pixel coordinates of `image`, ordered left to right
+    /// (then top to bottom) so cycling through them follows reading order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if inference fails or the model output is malformed.
+    pub fn detect(&mut self, image: &DynamicImage) -> FaceDetectionResult<Vec<FaceBox>> {
+        let input_tensor = preprocess_image(image);
+        let input_tensor = input_tensor.as_standard_layout().into_owned();
+
+        let input_name = self
+            .session
+            .inputs
+            .first()
+            .map_or_else(|| "input".to_string(), |i| i.name.clone());
+
+        let input_ref = ort::value::TensorRef::from_array_view(&input_tensor)
+            .map_err(|e| FaceDetectionError::InferenceFailed(e.to_string()))?;
+
+        let outputs = self
+            .session
+            .run(ort::inputs![input_name.as_str() => input_ref])
+            .map_err(|e| FaceDetectionError::InferenceFailed(e.to_string()))?;
+
+        // UltraFace outputs "scores" [1, N, 2] (background, face) then "boxes" [1, N, 4]
+        let mut tensors = outputs.iter().map(|(_, value)| value);
+        let (scores, boxes) = match (tensors.next(), tensors.next()) {
+            (Some(scores), Some(boxes)) => (scores, boxes),
+            _ => {
+                return Err(FaceDetectionError::PostprocessingFailed(
+                    "Expected scores and boxes outputs".to_string(),
+                ))
+            }
+        };
+        let (_, scores) = scores
+            .try_extract_tensor::<f32>()
+            .map_err(|e: ort::Error| FaceDetectionError::PostprocessingFailed(e.to_string()))?;
+        let (_, boxes) = boxes
+            .try_extract_tensor::<f32>()
+            .map_err(|e: ort::Error| FaceDetectionError::PostprocessingFailed(e.to_string()))?;
+
+        // Precision loss is irrelevant for pixel dimensions
+        #[allow(clippy::cast_precision_loss)]
+        let faces = decode_detections(scores, boxes, image.width() as f32, image.height() as f32)?;
+        Ok(faces)
+    }
+}
+
+/// Returns the path where the face detection model should be stored.
+#[must_use]
+pub fn get_model_path() -> PathBuf {
+    paths::get_app_data_dir().map_or_else(
+        || PathBuf::from(MODEL_FILENAME),
+        |mut p| {
+            p.push(MODEL_FILENAME);
+            p
+        },
+    )
+}
+
+/// Checks if the model file exists at the expected location with valid size.
+#[must_use]
+pub fn is_model_downloaded() -> bool {
+    std::fs::metadata(get_model_path()).is_ok_and(|meta| meta.len() >= MIN_MODEL_SIZE_BYTES)
+}
+
+/// Downloads the model from the specified URL.
+///
+/// The model is small, so it is fetched in one request without progress
+/// reporting. Returns the number of bytes downloaded.
+///
+/// # Errors
+///
+/// Returns an error if the download fails or the file cannot be written.
+pub async fn download_model(url: &str) -> FaceDetectionResult<u64> {
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::limited(10))
+        .user_agent("IcedLens/0.3.0")
+        .build()
+        .map_err(|e| FaceDetectionError::DownloadFailed(e.to_string()))?;
+
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| FaceDetectionError::DownloadFailed(e.to_string()))?;
+
+    if !response.status().is_success() {
+        return Err(FaceDetectionError::DownloadFailed(format!(
+            "HTTP status: {}",
+            response.status()
+        )));
+    }
+
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| FaceDetectionError::DownloadFailed(e.to_string()))?;
+    let downloaded = bytes.len() as u64;
+
+    if downloaded < MIN_MODEL_SIZE_BYTES {
+        return Err(FaceDetectionError::DownloadFailed(format!(
+            "Downloaded file too small ({downloaded} bytes), expected ~1.2 MB"
+        )));
+    }
+
+    let model_path = get_model_path();
+    if let Some(parent) = model_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| FaceDetectionError::Io(e.to_string()))?;
+    }
+    std::fs::write(&model_path, &bytes).map_err(|e| FaceDetectionError::Io(e.to_string()))?;
+
+    Ok(downloaded)
+}
+
+/// Preprocesses an image for `UltraFace` inference.
+///
+/// Resizes to 320x240 and converts to NCHW format (batch=1, channels=3),
+/// RGB order, normalized as `(value - 127) / 128`.
+fn preprocess_image(img: &DynamicImage) -> Array4<f32> {
+    let resized = img
+        .resize_exact(INPUT_WIDTH, INPUT_HEIGHT, FilterType::Triangle)
+        .to_rgb8();
+
+    let mut tensor = Array4::<f32>::zeros((1, 3, INPUT_HEIGHT as usize, INPUT_WIDTH as usize));
+    for (x, y, pixel) in resized.enumerate_pixels() {
+        for (channel, value) in pixel.0.iter().enumerate() {
+            tensor[[0, channel, y as usize, x as usize]] = (f32::from(*value) - 127.0) / 128.0;
+        }
+    }
+    tensor
+}
+
+/// Converts raw model outputs into face boxes in image pixel coordinates.
+///
+/// `scores` holds `(background, face)` pairs and `boxes` holds normalized
+/// `(x1, y1, x2, y2)` corners, one entry per anchor.
+fn decode_detections(
+    scores: &[f32],
+    boxes: &[f32],
+    image_width: f32,
+    image_height: f32,
+) -> FaceDetectionResult<Vec<FaceBox>> {
+    if scores.len() % 2 != 0 || boxes.len() / 4 != scores.len() / 2 {
+        return Err(FaceDetectionError::PostprocessingFailed(format!(
+            "Mismatched output sizes: {} scores, {} box coordinates",
+            scores.len(),
+            boxes.len()
+        )));
+    }
+
+    let candidates = scores
+        .chunks_exact(2)
+        .zip(boxes.chunks_exact(4))
+        .filter(|(score, _)| score[1] >= SCORE_THRESHOLD)
+        .map(|(score, corners)| {
+            let x1 = corners[0].clamp(0.0, 1.0) * image_width;
+            let y1 = corners[1].clamp(0.0, 1.0) * image_height;
+            let x2 = corners[2].clamp(0.0, 1.0) * image_width;
+            let y2 = corners[3].clamp(0.0, 1.0) * image_height;
+            FaceBox {
+                x: x1,
+                y: y1,
+                width: (x2 - x1).max(0.0),
+                height: (y2 - y1).max(0.0),
+                score: score[1],
+            }
+        })
+        .filter(|face| face.width > 0.0 && face.height > 0.0)
+        .collect();
+
+    let mut faces = non_max_suppression(candidates, IOU_THRESHOLD);
+    faces.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    Ok(faces)
+}
+
+/// Keeps the highest-scoring boxes, dropping any that overlap a kept box
+/// by more than `iou_threshold`.
+fn non_max_suppression(mut candidates: Vec<FaceBox>, iou_threshold: f32) -> Vec<FaceBox> {
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+    let mut kept: Vec<FaceBox> = Vec::new();
+    for candidate in candidates {
+        if kept
+            .iter()
+            .all(|face| face.iou(&candidate) <= iou_threshold)
+        {
+            kept.push(candidate);
+        }
+    }
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn face(x: f32, y: f32, size: f32, score: f32) -> FaceBox {
+        FaceBox {
+            x,
+            y,
+            width: size,
+            height: size,
+            score,
+        }
+    }
+
+    #[test]
+    fn test_get_model_path_returns_valid_path() {
+        let path = get_model_path();
+        assert!(path.to_string_lossy().contains(MODEL_FILENAME));
+    }
+
+    #[test]
+    fn test_iou_of_identical_and_disjoint_boxes() {
+        let a = face(0.0, 0.0, 10.0, 0.9);
+        assert!((a.iou(&a) - 1.0).abs() < f32::EPSILON);
+        assert!(a.iou(&face(20.0, 20.0, 10.0, 0.9)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_rotated_follows_clockwise_rotation() {
+        // 10x5 box at the top-left of a 100x50 image
+        let original = FaceBox {
+            x: 0.0,
+            y: 0.0,
+            width: 10.0,
+            height: 5.0,
+            score: 0.9,
+        };
+
+        let quarter = original.rotated(90, 100.0, 50.0);
+        assert_eq!((quarter.x, quarter.y), (45.0, 0.0));
+        assert_eq!((quarter.width, quarter.height), (5.0, 10.0));
+
+        let half = original.rotated(180, 100.0, 50.0);
+        assert_eq!((half.x, half.y), (90.0, 45.0));
+
+        let three_quarters = original.rotated(270, 100.0, 50.0);
+        assert_eq!((three_quarters.x, three_quarters.y), (0.0, 90.0));
+        assert_eq!(original.rotated(0, 100.0, 50.0), original);
+    }
+
+    #[test]
+    fn test_non_max_suppression_keeps_best_of_overlapping_boxes() {
+        let kept = non_max_suppression(
+            vec![
+                face(0.0, 0.0, 10.0, 0.8),
+                face(1.0, 1.0, 10.0, 0.95),
+                face(50.0, 50.0, 10.0, 0.75),
+            ],
+            IOU_THRESHOLD,
+        );
+        assert_eq!(kept.len(), 2);
+        assert!((kept[0].score - 0.95).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_decode_detections_scales_filters_and_orders() {
+        // Three anchors: a weak detection, a face on the right, a face on the left
+        let scores = [0.6, 0.4, 0.1, 0.9, 0.2, 0.8];
+        let boxes = [
+            0.0, 0.0, 0.5, 0.5, //
+            0.5, 0.25, 0.75, 0.75, //
+            0.1, 0.1, 0.2, 0.3,
+        ];
+        let faces = decode_detections(&scores, &boxes, 200.0, 100.0).expect("decode");
+
+        assert_eq!(faces.len(), 2);
+        assert!((faces[0].x - 20.0).abs() < 1e-4);
+        assert!((faces[0].height - 20.0).abs() < 1e-4);
+        assert!((faces[1].x - 100.0).abs() < 1e-4);
+        assert!((faces[1].width - 50.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_decode_detections_rejects_mismatched_outputs() {
+        assert!(decode_detections(&[0.1, 0.9], &[0.0; 8], 10.0, 10.0).is_err());
+    }
+
+    #[test]
+    fn test_preprocess_image_creates_correct_shape() {
+        let img = DynamicImage::new_rgb8(64, 48);
+        let tensor = preprocess_image(&img);
+        assert_eq!(tensor.shape(), &[1, 3, 240, 320]);
+        assert!((tensor[[0, 0, 0, 0]] + 127.0 / 128.0).abs() < 1e-6);
+    }
+}
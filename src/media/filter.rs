@@ -9,6 +9,7 @@
 //!
 //! - [`MediaTypeFilter`]: Filter by media type (images, videos, or all)
 //! - [`DateRangeFilter`]: Filter by creation or modification date range
+//! - [`RatingFilter`]: Filter by minimum XMP star rating
 //!
 //! # Example
 //!
@@ -23,12 +24,13 @@
 //!         start: Some(SystemTime::UNIX_EPOCH),
 //!         end: None,
 //!     }),
+//!     rating: None,
 //! };
 //!
 //! assert!(filter.is_active());
 //! ```
 
-use crate::media::{detect_media_type, MediaType};
+use crate::media::{detect_media_type, xmp, MediaType};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use std::time::SystemTime;
@@ -154,6 +156,52 @@ impl DateRangeFilter {
     }
 }
 
+// =============================================================================
+// Rating Filter
+// =============================================================================
+
+/// Filter by minimum star rating (1 to 5).
+///
+/// Ratings are read from the XMP `xmp:Rating` property. Files without a
+/// rating (including all videos and formats without XMP support) count as
+/// unrated and never match; rejected files (rating -1) never match either.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RatingFilter(u8);
+
+impl RatingFilter {
+    /// Lowest selectable minimum rating.
+    pub const MIN_STARS: u8 = 1;
+    /// Highest selectable minimum rating.
+    pub const MAX_STARS: u8 = 5;
+
+    /// Creates a rating filter, clamping to the valid range (1-5 stars).
+    #[must_use]
+    pub fn new(min_stars: u8) -> Self {
+        Self(min_stars.clamp(Self::MIN_STARS, Self::MAX_STARS))
+    }
+
+    /// Returns the minimum number of stars.
+    #[must_use]
+    pub fn min_stars(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if the given stored rating satisfies this filter.
+    #[must_use]
+    pub fn accepts(self, rating: Option<i8>) -> bool {
+        rating.is_some_and(|r| i16::from(r) >= i16::from(self.0))
+    }
+
+    /// Returns `true` if the file's XMP rating satisfies this filter.
+    ///
+    /// Reads the file's XMP packet, so this is the most expensive check.
+    #[must_use]
+    pub fn matches(self, path: &Path) -> bool {
+        self.accepts(xmp::extract_xmp_rating(path))
+    }
+}
+
 // =============================================================================
 // Composite Media Filter
 // =============================================================================
@@ -170,6 +218,9 @@ pub struct MediaFilter {
     /// Filter by date range. `None` means no date filtering.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub date_range: Option<DateRangeFilter>,
+    /// Filter by minimum star rating. `None` means no rating filtering.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rating: Option<RatingFilter>,
 }
 
 impl MediaFilter {
@@ -184,6 +235,7 @@ impl MediaFilter {
     /// Checks are ordered from cheapest to most expensive:
     /// 1. Media type (extension check, no I/O)
     /// 2. Date range (filesystem metadata read)
+    /// 3. Rating (file content read)
     #[must_use]
     pub fn matches(&self, path: &Path) -> bool {
         // Media type filter (cheapest - extension check only)
@@ -198,6 +250,13 @@ impl MediaFilter {
             }
         }
 
+        // Rating filter (requires reading the XMP packet)
+        if let Some(rating_filter) = self.rating {
+            if !rating_filter.matches(path) {
+                return false;
+            }
+        }
+
         true
     }
 
@@ -209,6 +268,7 @@ impl MediaFilter {
                 .date_range
                 .as_ref()
                 .is_some_and(DateRangeFilter::is_active)
+            || self.rating.is_some()
     }
 
     /// Returns the number of active filter criteria.
@@ -225,6 +285,9 @@ impl MediaFilter {
         {
             count += 1;
         }
+        if self.rating.is_some() {
+            count += 1;
+        }
         count
     }
 
@@ -232,6 +295,7 @@ impl MediaFilter {
     pub fn clear(&mut self) {
         self.media_type = MediaTypeFilter::default();
         self.date_range = None;
+        self.rating = None;
     }
 }
 
@@ -392,6 +456,40 @@ mod tests {
         assert!(!filter.matches(Path::new("/nonexistent/path/file.jpg")));
     }
 
+    // -------------------------------------------------------------------------
+    // RatingFilter tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn rating_filter_clamps_to_star_range() {
+        assert_eq!(RatingFilter::new(0).min_stars(), 1);
+        assert_eq!(RatingFilter::new(3).min_stars(), 3);
+        assert_eq!(RatingFilter::new(9).min_stars(), 5);
+    }
+
+    #[test]
+    fn rating_filter_accepts_equal_or_higher() {
+        let filter = RatingFilter::new(3);
+        assert!(filter.accepts(Some(3)));
+        assert!(filter.accepts(Some(5)));
+        assert!(!filter.accepts(Some(2)));
+    }
+
+    #[test]
+    fn rating_filter_rejects_unrated_and_rejected() {
+        let filter = RatingFilter::new(1);
+        assert!(!filter.accepts(None));
+        assert!(!filter.accepts(Some(0)));
+        assert!(!filter.accepts(Some(-1)));
+    }
+
+    #[test]
+    fn rating_filter_excludes_files_without_xmp() {
+        let temp_dir = tempdir().expect("create temp dir");
+        let file = create_test_file(temp_dir.path(), "test.jpg");
+        assert!(!RatingFilter::new(1).matches(&file));
+    }
+
     // -------------------------------------------------------------------------
     // MediaFilter (composite) tests
     // -------------------------------------------------------------------------
@@ -410,6 +508,7 @@ mod tests {
         let filter = MediaFilter {
             media_type: MediaTypeFilter::ImagesOnly,
             date_range: None,
+            rating: None,
         };
         assert!(filter.is_active());
         assert_eq!(filter.active_count(), 1);
@@ -430,6 +529,7 @@ mod tests {
                 start: Some(SystemTime::UNIX_EPOCH),
                 end: None,
             }),
+            rating: None,
         };
 
         assert!(filter.is_active());
@@ -447,9 +547,11 @@ mod tests {
                 start: Some(SystemTime::UNIX_EPOCH),
                 end: None,
             }),
+            rating: Some(RatingFilter::new(4)),
         };
 
         assert!(filter.is_active());
+        assert_eq!(filter.active_count(), 3);
         filter.clear();
         assert!(!filter.is_active());
         assert_eq!(filter.active_count(), 0);
@@ -468,6 +570,7 @@ mod tests {
                 start: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1000)),
                 end: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(2000)),
             }),
+            rating: Some(RatingFilter::new(2)),
         };
 
         let serialized = toml::to_string(&filter).expect("serialize");
@@ -483,5 +586,6 @@ mod tests {
 
         // Default values should produce minimal output
         assert!(!serialized.contains("date_range"));
+        assert!(!serialized.contains("rating"));
     }
 }
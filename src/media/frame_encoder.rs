@@ -0,0 +1,276 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Encoders writing a series of video frames to disk.
+//!
+//! Frames can be written as numbered images (`name_0001.png`, …), as an
+//! animated GIF, or as an animated WebP. Frames are pushed one at a time so
+//! the whole range never has to be held in memory.
+
+use crate::error::{Error, Result};
+use crate::media::frame_export::{ExportFormat, ExportableFrame};
+use image_rs::codecs::gif::{GifEncoder, Repeat};
+use image_rs::{Delay, Frame, RgbaImage};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+/// Output of a frame range export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SequenceFormat {
+    /// One PNG file per frame.
+    ImageSequence,
+    /// Animated GIF (256 colors, widely supported).
+    #[default]
+    Gif,
+    /// Animated WebP (full color, smaller files).
+    WebP,
+}
+
+impl SequenceFormat {
+    /// All formats, in the order shown in the export panel.
+    pub const ALL: [SequenceFormat; 3] = [
+        SequenceFormat::ImageSequence,
+        SequenceFormat::Gif,
+        SequenceFormat::WebP,
+    ];
+
+    /// Returns the file extension of the output (of each image for sequences).
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            SequenceFormat::ImageSequence => ExportFormat::Png.extension(),
+            SequenceFormat::Gif => "gif",
+            SequenceFormat::WebP => "webp",
+        }
+    }
+}
+
+/// Frame rates offered for frame range exports.
+pub const FPS_CHOICES: [u32; 5] = [5, 10, 15, 24, 30];
+
+/// Output scales offered for frame range exports, in percent.
+pub const SCALE_CHOICES: [u32; 4] = [25, 50, 75, 100];
+
+/// How a frame range is sampled and written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceOptions {
+    pub format: SequenceFormat,
+    /// Frames written per second of video.
+    pub fps: u32,
+    /// Output size relative to the video, in percent.
+    pub scale_percent: u32,
+}
+
+impl Default for SequenceOptions {
+    fn default() -> Self {
+        Self {
+            format: SequenceFormat::default(),
+            fps: 10,
+            scale_percent: 50,
+        }
+    }
+}
+
+impl SequenceOptions {
+    /// Returns the output size for a video of `width`×`height` pixels,
+    /// never smaller than one pixel.
+    #[must_use]
+    pub fn scaled_size(&self, width: u32, height: u32) -> (u32, u32) {
+        let scale = |value: u32| (u64::from(value) * u64::from(self.scale_percent) / 100).max(1);
+        // Scales are at most 100%, so the result fits the input type
+        #[allow(clippy::cast_possible_truncation)]
+        let size = (scale(width) as u32, scale(height) as u32);
+        size
+    }
+}
+
+/// Returns the path of image `index` (starting at 1) of a sequence written
+/// to `output`, e.g. `clip_0001.png` for `clip.png`.
+#[must_use]
+pub fn sequence_frame_path(output: &Path, index: usize) -> PathBuf {
+    let stem = output
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("frame");
+    output.with_file_name(format!(
+        "{stem}_{index:04}.{}",
+        SequenceFormat::ImageSequence.extension()
+    ))
+}
+
+/// Writes frames to `output` in one of the [`SequenceFormat`]s.
+pub struct FrameEncoder {
+    output: PathBuf,
+    fps: u32,
+    frames: usize,
+    sink: Sink,
+}
+
+enum Sink {
+    Images,
+    Gif(GifEncoder<BufWriter<File>>),
+    WebP(webp_animation::Encoder),
+}
+
+impl FrameEncoder {
+    /// Creates an encoder for frames of `width`×`height` pixels shown at `fps`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output file or the encoder can't be created.
+    pub fn create(
+        format: SequenceFormat,
+        output: &Path,
+        fps: u32,
+        (width, height): (u32, u32),
+    ) -> Result<Self> {
+        let sink = match format {
+            SequenceFormat::ImageSequence => Sink::Images,
+            SequenceFormat::Gif => {
+                let file = File::create(output)?;
+                let mut encoder = GifEncoder::new(BufWriter::new(file));
+                encoder
+                    .set_repeat(Repeat::Infinite)
+                    .map_err(|e| Error::Io(format!("Failed to create GIF: {e}")))?;
+                Sink::Gif(encoder)
+            }
+            SequenceFormat::WebP => Sink::WebP(
+                webp_animation::Encoder::new((width, height))
+                    .map_err(|e| Error::Io(format!("Failed to create WebP: {e:?}")))?,
+            ),
+        };
+        Ok(Self {
+            output: output.to_path_buf(),
+            fps: fps.max(1),
+            frames: 0,
+            sink,
+        })
+    }
+
+    /// Appends a frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frame can't be encoded or written.
+    pub fn push(&mut self, frame: &ExportableFrame) -> Result<()> {
+        self.frames += 1;
+        match &mut self.sink {
+            Sink::Images => frame.save_to_file(
+                sequence_frame_path(&self.output, self.frames),
+                Some(ExportFormat::Png),
+            )?,
+            Sink::Gif(encoder) => {
+                let image =
+                    RgbaImage::from_raw(frame.width, frame.height, (*frame.rgba_data).clone())
+                        .ok_or_else(|| {
+                            Error::Io("Failed to create image buffer from frame data".to_string())
+                        })?;
+                let delay = Delay::from_numer_denom_ms(1000, self.fps);
+                encoder
+                    .encode_frame(Frame::from_parameters(image, 0, 0, delay))
+                    .map_err(|e| Error::Io(format!("Failed to write GIF frame: {e}")))?;
+            }
+            Sink::WebP(encoder) => {
+                let timestamp = self.timestamp_ms(self.frames - 1);
+                encoder
+                    .add_frame(&frame.rgba_data, timestamp)
+                    .map_err(|e| Error::Io(format!("Failed to write WebP frame: {e:?}")))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Finishes the output and returns the number of frames written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no frame was pushed or the output can't be written.
+    pub fn finish(self) -> Result<usize> {
+        if self.frames == 0 {
+            return Err(Error::Io("No frame found in the range".to_string()));
+        }
+        match self.sink {
+            Sink::Images => {}
+            // The GIF trailer is written when the encoder is dropped
+            Sink::Gif(encoder) => drop(encoder),
+            Sink::WebP(encoder) => {
+                let end = self.timestamp_ms(self.frames);
+                let data = encoder
+                    .finalize(end)
+                    .map_err(|e| Error::Io(format!("Failed to finish WebP: {e:?}")))?;
+                std::fs::write(&self.output, &*data)?;
+            }
+        }
+        Ok(self.frames)
+    }
+
+    /// Returns the display time of frame `index`, in milliseconds.
+    fn timestamp_ms(&self, index: usize) -> i32 {
+        let millis = index as u64 * 1000 / u64::from(self.fps);
+        i32::try_from(millis).unwrap_or(i32::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use tempfile::tempdir;
+
+    fn frame(width: u32, height: u32) -> ExportableFrame {
+        ExportableFrame::new(
+            Arc::new(vec![128u8; (width * height * 4) as usize]),
+            width,
+            height,
+        )
+    }
+
+    #[test]
+    fn scaled_size_keeps_at_least_one_pixel() {
+        let options = SequenceOptions {
+            scale_percent: 25,
+            ..SequenceOptions::default()
+        };
+        assert_eq!(options.scaled_size(1920, 1080), (480, 270));
+        assert_eq!(options.scaled_size(2, 2), (1, 1));
+    }
+
+    #[test]
+    fn sequence_frames_are_numbered() {
+        assert_eq!(
+            sequence_frame_path(Path::new("/out/clip.png"), 7),
+            PathBuf::from("/out/clip_0007.png")
+        );
+    }
+
+    #[test]
+    fn every_format_writes_its_output() {
+        let dir = tempdir().expect("temp dir");
+        for format in SequenceFormat::ALL {
+            let output = dir.path().join(format!("out.{}", format.extension()));
+            let mut encoder =
+                FrameEncoder::create(format, &output, 10, (4, 4)).expect("create encoder");
+            encoder.push(&frame(4, 4)).expect("push frame");
+            encoder.push(&frame(4, 4)).expect("push frame");
+            assert_eq!(encoder.finish().expect("finish"), 2);
+
+            let written = match format {
+                SequenceFormat::ImageSequence => sequence_frame_path(&output, 2),
+                SequenceFormat::Gif | SequenceFormat::WebP => output,
+            };
+            assert!(written.exists(), "{format:?} output missing");
+        }
+    }
+
+    #[test]
+    fn finishing_without_frames_fails() {
+        let dir = tempdir().expect("temp dir");
+        let encoder = FrameEncoder::create(
+            SequenceFormat::ImageSequence,
+            &dir.path().join("out.png"),
+            10,
+            (4, 4),
+        )
+        .expect("create encoder");
+        assert!(encoder.finish().is_err());
+    }
+}
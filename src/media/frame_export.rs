@@ -2,11 +2,20 @@
 //! Frame export functionality for video playback.
 //!
 //! This module provides functions to export video frames to various image formats
-//! (PNG, JPEG, WebP) using the `image` crate.
+//! (PNG, JPEG, WebP) using the `image` crate, to export a time range of a
+//! video as a series of frames (see [`crate::media::frame_encoder`]), and to
+//! capture a burst of frames around the playhead as separate images named
+//! from the frame name template.
 
+use crate::config::DEFAULT_FRAME_NAME_TEMPLATE;
 use crate::error::{Error, Result};
-use image_rs::{ImageBuffer, ImageFormat, Rgba};
-use std::path::Path;
+use crate::media::clip_export::{self, CancelFlag, ClipRequest};
+use crate::media::frame_encoder::{FrameEncoder, SequenceOptions};
+use crate::media::naming::{self, NamingContext};
+use crate::media::video::init_ffmpeg;
+use ffmpeg_next::{codec, format, media};
+use image_rs::{DynamicImage, ImageBuffer, ImageFormat, Rgba};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 /// Supported export formats for frame capture.
@@ -165,6 +174,508 @@ impl ExportableFrame {
     }
 }
 
+/// Progress and result of a frame range export, as reported to the viewer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FramesExportStatus {
+    /// Fraction of the range written so far (0.0 to 1.0).
+    Progress(f32),
+    /// `frames` frames were written to `path`.
+    Finished { path: PathBuf, frames: usize },
+    /// The export was cancelled (or no destination was chosen).
+    Cancelled,
+    /// The export failed with this message.
+    Failed(String),
+}
+
+/// Decodes the `request` range of a video and writes it with a
+/// [`FrameEncoder`], sampled at `options.fps` and scaled by
+/// `options.scale_percent`. `progress` receives the fraction of the range
+/// written so far.
+///
+/// Returns the number of frames written, or `None` if the export was
+/// cancelled. Partial output is removed on cancellation and failure, except
+/// for the images of a sequence already written.
+///
+/// # Errors
+///
+/// Returns an error if the range is empty, or if the video can't be decoded
+/// or the frames can't be written.
+pub fn export_frame_range(
+    request: &ClipRequest,
+    options: SequenceOptions,
+    cancel: &CancelFlag,
+    mut progress: impl FnMut(f32),
+) -> Result<Option<usize>> {
+    init_ffmpeg()?;
+    if request.end_secs <= request.start_secs {
+        return Err(Error::Io("The frame range is empty".to_string()));
+    }
+
+    let result = encode_range(request, options, cancel, &mut progress);
+    if !matches!(result, Ok(Some(_))) {
+        let _ = std::fs::remove_file(&request.output);
+    }
+    result
+}
+
+/// Decodes the frames of the range and pushes the sampled ones to an encoder.
+/// Returns `None` if cancelled.
+#[allow(clippy::similar_names)] // decoder vs decoded
+fn encode_range(
+    request: &ClipRequest,
+    options: SequenceOptions,
+    cancel: &CancelFlag,
+    progress: &mut impl FnMut(f32),
+) -> Result<Option<usize>> {
+    let VideoInput {
+        mut ictx,
+        video_index,
+        time_base,
+        mut decoder,
+    } = open_video(&request.input)?;
+    let (width, height) = (decoder.width(), decoder.height());
+
+    let (out_width, out_height) = options.scaled_size(width, height);
+    let mut scaler = ffmpeg_next::software::scaling::Context::get(
+        decoder.format(),
+        width,
+        height,
+        format::Pixel::RGBA,
+        out_width,
+        out_height,
+        ffmpeg_next::software::scaling::Flags::BILINEAR,
+    )
+    .map_err(clip_export::ffmpeg_error("create scaler"))?;
+    let mut encoder = FrameEncoder::create(
+        options.format,
+        &request.output,
+        options.fps,
+        (out_width, out_height),
+    )?;
+
+    clip_export::seek(&mut ictx, request.start_secs)?;
+
+    let interval = 1.0 / f64::from(options.fps.max(1));
+    let mut next_secs = request.start_secs;
+    let mut decoded = ffmpeg_next::frame::Video::empty();
+    let mut rgba = ffmpeg_next::frame::Video::empty();
+    'packets: for (stream, packet) in ictx.packets() {
+        if cancel.is_cancelled() {
+            return Ok(None);
+        }
+        if stream.index() != video_index || decoder.send_packet(&packet).is_err() {
+            continue;
+        }
+        while decoder.receive_frame(&mut decoded).is_ok() {
+            let position_secs = decoded
+                .timestamp()
+                .map_or(next_secs, |ts| clip_export::to_secs(ts, time_base));
+            if position_secs >= request.end_secs {
+                break 'packets;
+            }
+            // Keep the first frame at or after each sampling point
+            if position_secs < next_secs {
+                continue;
+            }
+            while next_secs <= position_secs {
+                next_secs += interval;
+            }
+
+            scaler
+                .run(&decoded, &mut rgba)
+                .map_err(clip_export::ffmpeg_error("scale frame"))?;
+            encoder.push(&packed_frame(&rgba))?;
+            progress(request.progress_at(position_secs));
+        }
+    }
+    encoder.finish().map(Some)
+}
+
+/// Demuxer and decoder of the video stream of a file.
+struct VideoInput {
+    ictx: format::context::Input,
+    video_index: usize,
+    time_base: ffmpeg_next::Rational,
+    decoder: ffmpeg_next::decoder::Video,
+}
+
+/// Opens the best video stream of `path` for decoding.
+fn open_video(path: &Path) -> Result<VideoInput> {
+    let ictx = format::input(path).map_err(clip_export::ffmpeg_error("open video"))?;
+    let (video_index, time_base, parameters) = {
+        let input = ictx
+            .streams()
+            .best(media::Type::Video)
+            .ok_or_else(|| Error::Io("No video stream found".to_string()))?;
+        (input.index(), input.time_base(), input.parameters())
+    };
+    let decoder = codec::context::Context::from_parameters(parameters)
+        .map_err(clip_export::ffmpeg_error("create decoder"))?
+        .decoder()
+        .video()
+        .map_err(clip_export::ffmpeg_error("create decoder"))?;
+    let (width, height) = (decoder.width(), decoder.height());
+    if width == 0 || height == 0 {
+        return Err(Error::Io(format!(
+            "Invalid video dimensions: {width}x{height} (possibly unsupported format)"
+        )));
+    }
+    Ok(VideoInput {
+        ictx,
+        video_index,
+        time_base,
+        decoder,
+    })
+}
+
+/// Frame counts offered for a burst capture.
+pub const BURST_COUNT_CHOICES: [u32; 4] = [5, 10, 20, 50];
+
+/// Times between two frames of a burst capture, in milliseconds.
+pub const BURST_INTERVAL_CHOICES_MS: [u32; 5] = [40, 100, 250, 500, 1000];
+
+/// Number and spacing of the frames of a burst capture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BurstOptions {
+    pub count: u32,
+    pub interval_ms: u32,
+}
+
+impl Default for BurstOptions {
+    fn default() -> Self {
+        Self {
+            count: 10,
+            interval_ms: 250,
+        }
+    }
+}
+
+impl BurstOptions {
+    /// Returns the positions of the frames, centered on `position_secs` and
+    /// shifted to stay within the video.
+    #[must_use]
+    pub fn timestamps(self, position_secs: f64, duration_secs: f64) -> Vec<f64> {
+        let interval = f64::from(self.interval_ms) / 1000.0;
+        let span = interval * f64::from(self.count.saturating_sub(1));
+        let latest_start = (duration_secs - span).max(0.0);
+        let start = (position_secs - span / 2.0).clamp(0.0, latest_start);
+        (0..self.count)
+            .map(|i| (start + interval * f64::from(i)).min(duration_secs))
+            .collect()
+    }
+}
+
+/// Frames of a burst capture and where to write them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BurstRequest {
+    pub input: PathBuf,
+    /// Folder receiving one image per frame.
+    pub folder: PathBuf,
+    /// Positions of the frames, in ascending order.
+    pub timestamps: Vec<f64>,
+    /// Frame name template (see [`crate::media::naming`]).
+    pub name_template: String,
+    pub format: ExportFormat,
+}
+
+/// Decodes the frames of a burst at the source resolution and writes each
+/// one to `request.folder`, named from the template with the frame's
+/// position and its number in the burst. Existing files are not replaced.
+/// `progress` receives the fraction of frames written so far.
+///
+/// Returns the number of frames written, or `None` if the capture was
+/// cancelled. Frames written before a cancellation are kept.
+///
+/// # Errors
+///
+/// Returns an error if the video can't be decoded or a frame can't be
+/// written.
+pub fn export_burst(
+    request: &BurstRequest,
+    cancel: &CancelFlag,
+    mut progress: impl FnMut(f32),
+) -> Result<Option<usize>> {
+    init_ffmpeg()?;
+    let Some(&first_secs) = request.timestamps.first() else {
+        return Ok(Some(0));
+    };
+    std::fs::create_dir_all(&request.folder).map_err(|e| Error::Io(e.to_string()))?;
+
+    let VideoInput {
+        mut ictx,
+        video_index,
+        time_base,
+        mut decoder,
+    } = open_video(&request.input)?;
+    let (width, height) = (decoder.width(), decoder.height());
+    let mut scaler = ffmpeg_next::software::scaling::Context::get(
+        decoder.format(),
+        width,
+        height,
+        format::Pixel::RGBA,
+        width,
+        height,
+        ffmpeg_next::software::scaling::Flags::BILINEAR,
+    )
+    .map_err(clip_export::ffmpeg_error("create scaler"))?;
+
+    clip_export::seek(&mut ictx, first_secs)?;
+
+    let mut targets = request.timestamps.iter().copied().peekable();
+    let mut written = 0;
+    let mut decoded = ffmpeg_next::frame::Video::empty();
+    let mut rgba = ffmpeg_next::frame::Video::empty();
+    'packets: for (stream, packet) in ictx.packets() {
+        if cancel.is_cancelled() {
+            return Ok(None);
+        }
+        if stream.index() != video_index || decoder.send_packet(&packet).is_err() {
+            continue;
+        }
+        while decoder.receive_frame(&mut decoded).is_ok() {
+            let Some(&target_secs) = targets.peek() else {
+                break 'packets;
+            };
+            let position_secs = decoded
+                .timestamp()
+                .map_or(target_secs, |ts| clip_export::to_secs(ts, time_base));
+            // Keep the first frame at or after each requested position
+            if position_secs < target_secs {
+                continue;
+            }
+            // A frame longer than the interval stands for every position it covers
+            while targets.next_if(|&next| next <= position_secs).is_some() {}
+
+            scaler
+                .run(&decoded, &mut rgba)
+                .map_err(clip_export::ffmpeg_error("scale frame"))?;
+            written += 1;
+            let naming = NamingContext::new(&request.input)
+                .with_index(written)
+                .with_timestamp(position_secs)
+                .with_dimensions(width, height);
+            let filename = generate_filename(&request.name_template, &naming, request.format);
+            let path = naming::unique_path(&request.folder.join(filename));
+            packed_frame(&rgba).save_to_file(&path, Some(request.format))?;
+
+            #[allow(clippy::cast_precision_loss)] // Burst sizes are small
+            let done = written as f32 / request.timestamps.len() as f32;
+            progress(done);
+        }
+    }
+    Ok(Some(written))
+}
+
+/// Copies an RGBA frame into tightly packed rows.
+fn packed_frame(frame: &ffmpeg_next::frame::Video) -> ExportableFrame {
+    let (width, height) = (frame.width(), frame.height());
+    let data = frame.data(0);
+    let stride = frame.stride(0);
+    let row_len = width as usize * 4;
+    let mut rgba = Vec::with_capacity(row_len * height as usize);
+    for y in 0..height as usize {
+        let row_start = y * stride;
+        rgba.extend_from_slice(&data[row_start..row_start + row_len]);
+    }
+    ExportableFrame::new(Arc::new(rgba), width, height)
+}
+
+/// Orientation of a paused frame as displayed by the viewer, applied to the
+/// frame decoded again at the source resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FrameOrientation {
+    /// Clockwise rotation: 0, 90, 180 or 270.
+    pub rotation_degrees: u16,
+    /// Flipped horizontally after the rotation.
+    pub mirrored: bool,
+}
+
+impl FrameOrientation {
+    /// Rotates and flips `image` to match the displayed frame.
+    #[must_use]
+    pub fn apply(self, image: DynamicImage) -> DynamicImage {
+        let rotated = match self.rotation_degrees {
+            90 => image.rotate90(),
+            180 => image.rotate180(),
+            270 => image.rotate270(),
+            _ => image,
+        };
+        if self.mirrored {
+            rotated.fliph()
+        } else {
+            rotated
+        }
+    }
+}
+
+/// Decodes the frame of `path` shown at `position_secs` at the source
+/// resolution, in software so hardware decoding can't lower its quality.
+///
+/// Sources with more than 8 bits per component (10-bit HEVC, ProRes…) give
+/// a 16-bit image; others an 8-bit one. The frame is returned as stored,
+/// before any [`FrameOrientation`].
+///
+/// # Errors
+///
+/// Returns an error if the video can't be decoded or has no frame at that
+/// position.
+pub fn decode_frame_at(path: &Path, position_secs: f64) -> Result<DynamicImage> {
+    init_ffmpeg()?;
+    let VideoInput {
+        mut ictx,
+        video_index,
+        time_base,
+        mut decoder,
+    } = open_video(path)?;
+    clip_export::seek(&mut ictx, position_secs)?;
+
+    let mut search = FrameSearch {
+        target_secs: position_secs,
+        time_base,
+        current: ffmpeg_next::frame::Video::empty(),
+        previous: None,
+    };
+    let mut found = None;
+    for (stream, packet) in ictx.packets() {
+        if stream.index() != video_index || decoder.send_packet(&packet).is_err() {
+            continue;
+        }
+        found = search.receive(&mut decoder);
+        if found.is_some() {
+            break;
+        }
+    }
+    if found.is_none() {
+        // Positions at the very end: drain the decoder, then fall back to
+        // the last frame
+        let _ = decoder.send_eof();
+        found = search
+            .receive(&mut decoder)
+            .or_else(|| search.previous.map(|(_, frame)| frame));
+    }
+    let frame = found.ok_or_else(|| Error::Io(format!("No frame at {position_secs:.3}s")))?;
+    full_depth_image(&frame)
+}
+
+/// Looks for the decoded frame nearest to a position.
+struct FrameSearch {
+    target_secs: f64,
+    time_base: ffmpeg_next::Rational,
+    current: ffmpeg_next::frame::Video,
+    /// Last frame before the target, with its position.
+    previous: Option<(f64, ffmpeg_next::frame::Video)>,
+}
+
+impl FrameSearch {
+    /// Receives the frames the decoder has ready and returns the one nearest
+    /// to the target once a frame at or after it arrives.
+    fn receive(
+        &mut self,
+        decoder: &mut ffmpeg_next::decoder::Video,
+    ) -> Option<ffmpeg_next::frame::Video> {
+        while decoder.receive_frame(&mut self.current).is_ok() {
+            let position_secs = self.current.timestamp().map_or(self.target_secs, |ts| {
+                clip_export::to_secs(ts, self.time_base)
+            });
+            let current = std::mem::replace(&mut self.current, ffmpeg_next::frame::Video::empty());
+            if position_secs >= self.target_secs {
+                return Some(match self.previous.take() {
+                    Some((previous_secs, previous))
+                        if self.target_secs - previous_secs < position_secs - self.target_secs =>
+                    {
+                        previous
+                    }
+                    _ => current,
+                });
+            }
+            self.previous = Some((position_secs, current));
+        }
+        None
+    }
+}
+
+/// Converts a decoded frame to RGBA, keeping 16 bits per component for
+/// sources deeper than 8 bits.
+fn full_depth_image(frame: &ffmpeg_next::frame::Video) -> Result<DynamicImage> {
+    let (width, height) = (frame.width(), frame.height());
+    let high_depth = frame
+        .format()
+        .descriptor()
+        .is_some_and(|descriptor| pixel_format_depth(descriptor.name()) > 8);
+    let target = if high_depth {
+        format::Pixel::RGBA64LE
+    } else {
+        format::Pixel::RGBA
+    };
+    let mut scaler = ffmpeg_next::software::scaling::Context::get(
+        frame.format(),
+        width,
+        height,
+        target,
+        width,
+        height,
+        ffmpeg_next::software::scaling::Flags::BICUBIC
+            | ffmpeg_next::software::scaling::Flags::ACCURATE_RND,
+    )
+    .map_err(clip_export::ffmpeg_error("create scaler"))?;
+    let mut rgba = ffmpeg_next::frame::Video::empty();
+    scaler
+        .run(frame, &mut rgba)
+        .map_err(clip_export::ffmpeg_error("scale frame"))?;
+
+    let image = if high_depth {
+        let data = rgba.data(0);
+        let stride = rgba.stride(0);
+        let row_len = width as usize * 8;
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+        for y in 0..height as usize {
+            let row = &data[y * stride..y * stride + row_len];
+            pixels.extend(
+                row.chunks_exact(2)
+                    .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]])),
+            );
+        }
+        ImageBuffer::<Rgba<u16>, _>::from_raw(width, height, pixels).map(DynamicImage::ImageRgba16)
+    } else {
+        packed_frame(&rgba).to_dynamic_image()
+    };
+    image.ok_or_else(|| Error::Io("Failed to convert frame to image".to_string()))
+}
+
+/// Returns the bits per component of an FFmpeg pixel format from its name:
+/// `yuv420p10le` and `p010le` are 10-bit, `rgb48le` 16-bit, `yuv420p` and
+/// `nv12` 8-bit.
+pub(crate) fn pixel_format_depth(name: &str) -> u8 {
+    let name = name
+        .strip_suffix("le")
+        .or_else(|| name.strip_suffix("be"))
+        .unwrap_or(name);
+    if ["rgb48", "bgr48", "rgba64", "bgra64"].contains(&name) {
+        return 16;
+    }
+    // Planar and semi-planar formats end with the depth after the `p`
+    // (`gbrp12`, `p010`), gray ones after `gray`
+    let depth = name
+        .strip_prefix("gray")
+        .or_else(|| name.rsplit_once('p').map(|(_, depth)| depth));
+    depth
+        .filter(|depth| !depth.is_empty() && depth.bytes().all(|b| b.is_ascii_digit()))
+        .and_then(|depth| depth.parse().ok())
+        .unwrap_or(8)
+}
+
+/// Returns the suggested file name for frames of `video_path`, e.g.
+/// `holiday-frames.gif` (or `holiday-frames.png`, numbered on export, for
+/// image sequences).
+#[must_use]
+pub fn default_frames_filename(video_path: &Path, options: &SequenceOptions) -> String {
+    let stem = video_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("video");
+    format!("{stem}-frames.{}", options.format.extension())
+}
+
 /// Generates a default filename for frame export.
 ///
 /// Format: `{video_name}_frame_{position}.{ext}`
@@ -174,33 +685,24 @@ pub fn generate_default_filename(
     position_secs: f64,
     format: ExportFormat,
 ) -> String {
-    let video_name = video_path
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("video");
-
-    // Format position as MM-SS-mmm (minutes-seconds-milliseconds)
-    // Video positions are practically bounded (years of video fit in u64 ms), so cast is safe
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    let total_ms = (position_secs.max(0.0) * 1000.0).round() as u64;
-    let minutes = total_ms / 60000;
-    let seconds = (total_ms % 60000) / 1000;
-    let millis = total_ms % 1000;
-
-    format!(
-        "{}_frame_{:02}-{:02}-{:03}.{}",
-        video_name,
-        minutes,
-        seconds,
-        millis,
-        format.extension()
+    generate_filename(
+        DEFAULT_FRAME_NAME_TEMPLATE,
+        &NamingContext::new(video_path).with_timestamp(position_secs),
+        format,
     )
 }
 
+/// Generates a frame export filename from a naming template.
+///
+/// See [`crate::media::naming`] for the supported tokens.
+#[must_use]
+pub fn generate_filename(template: &str, ctx: &NamingContext, format: ExportFormat) -> String {
+    naming::render_filename(template, ctx, format.extension())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::path::PathBuf;
 
     #[test]
     fn export_format_extensions() {
@@ -247,6 +749,39 @@ mod tests {
         assert_eq!(filename, "video_frame_00-00-000.jpg");
     }
 
+    #[test]
+    fn generate_filename_uses_template() {
+        let ctx = NamingContext::new(Path::new("clip.mp4"))
+            .with_timestamp(1.5)
+            .with_dimensions(640, 360);
+        let filename = generate_filename("{name}_{w}x{h}_{timestamp}", &ctx, ExportFormat::WebP);
+        assert_eq!(filename, "clip_640x360_00-01-500.webp");
+    }
+
+    #[test]
+    fn burst_is_centered_on_the_playhead() {
+        let options = BurstOptions {
+            count: 5,
+            interval_ms: 500,
+        };
+        assert_eq!(
+            options.timestamps(10.0, 60.0),
+            vec![9.0, 9.5, 10.0, 10.5, 11.0]
+        );
+    }
+
+    #[test]
+    fn burst_stays_within_the_video() {
+        let options = BurstOptions {
+            count: 3,
+            interval_ms: 1000,
+        };
+        assert_eq!(options.timestamps(0.2, 60.0), vec![0.0, 1.0, 2.0]);
+        assert_eq!(options.timestamps(59.9, 60.0), vec![58.0, 59.0, 60.0]);
+        // Videos shorter than the burst repeat their last frame
+        assert_eq!(options.timestamps(0.5, 1.5), vec![0.0, 1.0, 1.5]);
+    }
+
     #[test]
     fn exportable_frame_can_be_created() {
         let rgba = Arc::new(vec![255u8; 4 * 10 * 10]); // 10x10 white image
@@ -256,6 +791,63 @@ mod tests {
         assert_eq!(frame.rgba_data.len(), 400);
     }
 
+    #[test]
+    fn frames_filename_uses_the_sequence_extension() {
+        let options = SequenceOptions {
+            format: crate::media::frame_encoder::SequenceFormat::WebP,
+            ..SequenceOptions::default()
+        };
+        assert_eq!(
+            default_frames_filename(Path::new("/videos/holiday.mkv"), &options),
+            "holiday-frames.webp"
+        );
+    }
+
+    #[test]
+    fn empty_frame_ranges_are_rejected() {
+        let request = ClipRequest {
+            input: PathBuf::from("in.mp4"),
+            output: PathBuf::from("out.gif"),
+            start_secs: 4.0,
+            end_secs: 4.0,
+        };
+        let result = export_frame_range(
+            &request,
+            SequenceOptions::default(),
+            &CancelFlag::default(),
+            |_| {},
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn pixel_format_depth_reads_the_name() {
+        assert_eq!(pixel_format_depth("yuv420p"), 8);
+        assert_eq!(pixel_format_depth("nv12"), 8);
+        assert_eq!(pixel_format_depth("rgb24"), 8);
+        assert_eq!(pixel_format_depth("yuv420p10le"), 10);
+        assert_eq!(pixel_format_depth("p010le"), 10);
+        assert_eq!(pixel_format_depth("gbrp12be"), 12);
+        assert_eq!(pixel_format_depth("gray16le"), 16);
+        assert_eq!(pixel_format_depth("rgb48le"), 16);
+    }
+
+    #[test]
+    fn orientation_rotates_then_mirrors() {
+        let mut image = image_rs::RgbaImage::new(2, 1);
+        image.put_pixel(0, 0, Rgba([255, 0, 0, 255]));
+        let orientation = FrameOrientation {
+            rotation_degrees: 90,
+            mirrored: true,
+        };
+        let oriented = orientation
+            .apply(DynamicImage::ImageRgba8(image))
+            .to_rgba8();
+        assert_eq!(oriented.dimensions(), (1, 2));
+        // The left pixel goes to the top when rotated clockwise
+        assert_eq!(oriented.get_pixel(0, 0), &Rgba([255, 0, 0, 255]));
+    }
+
     #[test]
     fn export_format_default_is_png() {
         assert_eq!(ExportFormat::default(), ExportFormat::Png);
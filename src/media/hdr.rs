@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: MPL-2.0
+//! HDR video signals.
+//!
+//! HDR10 (PQ) and HLG videos store light levels far above what an SDR
+//! display shows, with the wide BT.2020 primaries. Drawn as is they look
+//! washed out, so the video shader decodes the transfer function and tone
+//! maps the highlights (see [`crate::config::ToneMapping`]). The mastering
+//! display and content light levels stored with the stream give the peak
+//! brightness the curve maps to white.
+
+use ffmpeg_next::color::{Primaries, TransferCharacteristic};
+use ffmpeg_next::ffi;
+use serde::Serialize;
+
+/// Peak brightness assumed when the stream doesn't tell, in nits: the
+/// usual mastering level of HDR10 and the reference display of HLG.
+pub const DEFAULT_PEAK_NITS: f64 = 1000.0;
+
+/// Transfer function of an HDR signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HdrTransfer {
+    /// SMPTE ST 2084 perceptual quantizer (HDR10, HDR10+, Dolby Vision base layer).
+    Pq,
+    /// ARIB STD-B67 hybrid log-gamma (broadcast, phones).
+    Hlg,
+}
+
+impl HdrTransfer {
+    /// Returns the i18n key of the format name.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            Self::Pq => "metadata-value-hdr-pq",
+            Self::Hlg => "metadata-value-hdr-hlg",
+        }
+    }
+}
+
+/// HDR properties of a video stream.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct HdrInfo {
+    pub transfer: HdrTransfer,
+    /// Whether the colors use the BT.2020 primaries.
+    pub bt2020: bool,
+    /// Minimum and maximum luminance of the mastering display, in nits.
+    pub mastering_luminance: Option<(f64, f64)>,
+    /// Brightest pixel of the content (MaxCLL), in nits.
+    pub max_cll: Option<u32>,
+    /// Brightest frame average of the content (MaxFALL), in nits.
+    pub max_fall: Option<u32>,
+}
+
+impl HdrInfo {
+    /// Returns the brightness the tone mapping brings down to display white:
+    /// the content light level, else the mastering display peak, else
+    /// [`DEFAULT_PEAK_NITS`].
+    #[must_use]
+    pub fn peak_nits(&self) -> f64 {
+        self.max_cll
+            .map(f64::from)
+            .or(self.mastering_luminance.map(|(_, max)| max))
+            .filter(|&nits| nits > 0.0)
+            .unwrap_or(DEFAULT_PEAK_NITS)
+    }
+}
+
+/// Reads the HDR properties of a video stream, or `None` for SDR video.
+pub(crate) fn read_hdr_info(
+    stream: &ffmpeg_next::format::stream::Stream,
+    decoder: &ffmpeg_next::decoder::Video,
+) -> Option<HdrInfo> {
+    let transfer = match decoder.color_transfer_characteristic() {
+        TransferCharacteristic::SMPTE2084 => HdrTransfer::Pq,
+        TransferCharacteristic::ARIB_STD_B67 => HdrTransfer::Hlg,
+        _ => return None,
+    };
+
+    // SAFETY: the codec parameters belong to the stream, which outlives this
+    // call; side data of these types holds the matching FFmpeg struct.
+    let (mastering_luminance, content_light) = unsafe {
+        let parameters = stream.parameters().as_ptr();
+        let mastering = side_data::<ffi::AVMasteringDisplayMetadata>(
+            parameters,
+            ffi::AVPacketSideDataType::AV_PKT_DATA_MASTERING_DISPLAY_METADATA,
+        )
+        .filter(|mastering| mastering.has_luminance != 0)
+        .map(|mastering| {
+            (
+                rational_value(mastering.min_luminance),
+                rational_value(mastering.max_luminance),
+            )
+        });
+        let content_light = side_data::<ffi::AVContentLightMetadata>(
+            parameters,
+            ffi::AVPacketSideDataType::AV_PKT_DATA_CONTENT_LIGHT_LEVEL,
+        )
+        .map(|light| (light.MaxCLL, light.MaxFALL));
+        (mastering, content_light)
+    };
+
+    Some(HdrInfo {
+        transfer,
+        bt2020: decoder.color_primaries() == Primaries::BT2020,
+        mastering_luminance,
+        // Zero means unknown
+        max_cll: content_light.map(|(cll, _)| cll).filter(|&nits| nits > 0),
+        max_fall: content_light.map(|(_, fall)| fall).filter(|&nits| nits > 0),
+    })
+}
+
+/// Returns the coded side data of type `kind` as a `T`, if present and large
+/// enough.
+///
+/// # Safety
+///
+/// `parameters` must be valid, and side data of type `kind` must hold a `T`.
+unsafe fn side_data<'a, T>(
+    parameters: *const ffi::AVCodecParameters,
+    kind: ffi::AVPacketSideDataType,
+) -> Option<&'a T> {
+    let side_data = ffi::av_packet_side_data_get(
+        (*parameters).coded_side_data,
+        (*parameters).nb_coded_side_data,
+        kind,
+    );
+    if side_data.is_null() || (*side_data).size < std::mem::size_of::<T>() {
+        None
+    } else {
+        Some(&*(*side_data).data.cast::<T>())
+    }
+}
+
+fn rational_value(value: ffi::AVRational) -> f64 {
+    if value.den == 0 {
+        0.0
+    } else {
+        f64::from(value.num) / f64::from(value.den)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hdr10() -> HdrInfo {
+        HdrInfo {
+            transfer: HdrTransfer::Pq,
+            bt2020: true,
+            mastering_luminance: Some((0.005, 4000.0)),
+            max_cll: Some(1500),
+            max_fall: Some(400),
+        }
+    }
+
+    #[test]
+    fn peak_prefers_the_content_light_level() {
+        assert!((hdr10().peak_nits() - 1500.0).abs() < f64::EPSILON);
+
+        let mastering_only = HdrInfo {
+            max_cll: None,
+            ..hdr10()
+        };
+        assert!((mastering_only.peak_nits() - 4000.0).abs() < f64::EPSILON);
+
+        let unknown = HdrInfo {
+            mastering_luminance: None,
+            max_cll: None,
+            ..hdr10()
+        };
+        assert!((unknown.peak_nits() - DEFAULT_PEAK_NITS).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn zero_denominators_read_as_zero() {
+        assert!(rational_value(ffi::AVRational { num: 1, den: 0 }).abs() < f64::EPSILON);
+        let nits = rational_value(ffi::AVRational {
+            num: 10_000_000,
+            den: 10_000,
+        });
+        assert!((nits - 1000.0).abs() < f64::EPSILON);
+    }
+}
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MPL-2.0
 //! Image loading and decoding from various formats (PNG, JPEG, GIF, SVG, etc.).
 
+use super::decoders::{decode_webp_with_libwebp, DecodeInfo, DecoderPreferences, ImageDecoder};
 use crate::error::{Error, Result};
 use iced::widget::image;
 use image_rs::{GenericImageView, ImageError};
@@ -10,6 +11,12 @@ use std::path::Path;
 use std::sync::Arc;
 use tiny_skia;
 
+/// Approximate number of pixels read by [`ImageData::average_luminance`].
+const LUMINANCE_SAMPLES: usize = 65_536;
+
+/// Number of buckets of [`ImageData::luminance_histogram`].
+pub const HISTOGRAM_BINS: usize = 64;
+
 #[derive(Debug, Clone)]
 pub struct ImageData {
     pub handle: image::Handle,
@@ -18,6 +25,8 @@ pub struct ImageData {
     /// Original RGBA bytes for rotation support.
     /// Stored in Arc to avoid expensive cloning.
     rgba_bytes: Arc<Vec<u8>>,
+    /// Backend that decoded the file, when loaded from disk.
+    decode_info: Option<DecodeInfo>,
 }
 
 impl ImageData {
@@ -34,6 +43,7 @@ impl ImageData {
             width,
             height,
             rgba_bytes,
+            decode_info: None,
         }
     }
 
@@ -55,14 +65,85 @@ impl ImageData {
             width,
             height,
             rgba_bytes,
+            decode_info: None,
         }
     }
 
+    /// Records which backend decoded the image.
+    #[must_use]
+    pub fn with_decode_info(mut self, info: DecodeInfo) -> Self {
+        self.decode_info = Some(info);
+        self
+    }
+
+    /// Returns which backend decoded the image, when loaded from disk.
+    #[must_use]
+    pub fn decode_info(&self) -> Option<DecodeInfo> {
+        self.decode_info
+    }
+
     /// Returns a reference to the original RGBA bytes.
     pub fn rgba_bytes(&self) -> &[u8] {
         &self.rgba_bytes
     }
 
+    /// Returns the average relative luminance (0.0–1.0) of the visible pixels.
+    ///
+    /// Large images are sampled on a regular grid of about
+    /// [`LUMINANCE_SAMPLES`] pixels. Fully transparent pixels are ignored;
+    /// returns `None` if no visible pixel was sampled.
+    #[must_use]
+    pub fn average_luminance(&self) -> Option<f32> {
+        let mut sum = 0.0_f64;
+        let mut samples = 0_u32;
+        for luma in self.sampled_luma() {
+            sum += luma;
+            samples += 1;
+        }
+
+        // The result is in 0.0..=1.0, so the f32 narrowing is harmless
+        #[allow(clippy::cast_possible_truncation)]
+        (samples > 0).then(|| (sum / f64::from(samples) / 255.0) as f32)
+    }
+
+    /// Returns the luminance histogram of the image in [`HISTOGRAM_BINS`]
+    /// buckets from black to white, over the same samples as
+    /// [`Self::average_luminance`]. Returns `None` if no visible pixel was
+    /// sampled.
+    #[must_use]
+    pub fn luminance_histogram(&self) -> Option<Vec<u32>> {
+        let mut bins = vec![0_u32; HISTOGRAM_BINS];
+        let mut visible = false;
+        for luma in self.sampled_luma() {
+            // Luma is in 0.0..=255.0, so the bucket index fits
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                clippy::cast_precision_loss
+            )]
+            let bin = (luma / 256.0 * HISTOGRAM_BINS as f64) as usize;
+            bins[bin.min(HISTOGRAM_BINS - 1)] += 1;
+            visible = true;
+        }
+        visible.then_some(bins)
+    }
+
+    /// Yields the luma (0–255) of about [`LUMINANCE_SAMPLES`] visible pixels.
+    fn sampled_luma(&self) -> impl Iterator<Item = f64> + '_ {
+        let pixel_count = self.rgba_bytes.len() / 4;
+        let step = (pixel_count / LUMINANCE_SAMPLES).max(1);
+        self.rgba_bytes
+            .chunks_exact(4)
+            .step_by(step)
+            .filter(|pixel| pixel[3] != 0)
+            .map(|pixel| {
+                // Rec. 709 luma weights applied to the gamma-encoded values
+                0.2126 * f64::from(pixel[0])
+                    + 0.7152 * f64::from(pixel[1])
+                    + 0.0722 * f64::from(pixel[2])
+            })
+    }
+
     /// Creates a rotated version of this image.
     ///
     /// The rotation is applied using 90° increments:
@@ -112,6 +193,73 @@ impl ImageData {
             width: new_width,
             height: new_height,
             rgba_bytes,
+            decode_info: None,
+        }
+    }
+
+    /// Creates a copy flipped horizontally, as seen in a mirror.
+    #[must_use]
+    pub fn mirrored(&self) -> Self {
+        let row_len = self.width as usize * 4;
+        let mut pixels = Vec::with_capacity(self.rgba_bytes.len());
+        if row_len > 0 {
+            for row in self.rgba_bytes.chunks_exact(row_len) {
+                pixels.extend(row.chunks_exact(4).rev().flatten());
+            }
+        }
+        Self::from_rgba(self.width, self.height, pixels)
+    }
+
+    /// Creates a copy of the `width`×`height` region whose top-left corner is
+    /// at (`x`, `y`).
+    ///
+    /// Returns `None` if the region is empty or extends past the image.
+    #[must_use]
+    pub fn cropped(&self, x: u32, y: u32, width: u32, height: u32) -> Option<Self> {
+        if width == 0
+            || height == 0
+            || x.checked_add(width)? > self.width
+            || y.checked_add(height)? > self.height
+        {
+            return None;
+        }
+
+        let stride = self.width as usize * 4;
+        let row_len = width as usize * 4;
+        let mut pixels = Vec::with_capacity(row_len * height as usize);
+        for row in y as usize..(y + height) as usize {
+            let start = row * stride + x as usize * 4;
+            pixels.extend_from_slice(&self.rgba_bytes[start..start + row_len]);
+        }
+        Some(Self::from_rgba(width, height, pixels))
+    }
+
+    /// Creates a copy with color channels un-premultiplied by alpha.
+    ///
+    /// Used to preview images whose pixels were stored with premultiplied
+    /// alpha: dividing color by alpha restores the intended straight color.
+    #[must_use]
+    pub fn unpremultiplied(&self) -> Self {
+        let mut pixels = self.rgba_bytes.to_vec();
+        unpremultiply_rgba(&mut pixels);
+        Self::from_rgba(self.width, self.height, pixels)
+    }
+}
+
+/// Divides the color channels of RGBA pixels by their alpha, in place.
+///
+/// Fully opaque and fully transparent pixels are left untouched (there is
+/// nothing to recover from a zero alpha). Results are rounded and clamped,
+/// since malformed premultiplied data can have color exceeding alpha.
+pub fn unpremultiply_rgba(pixels: &mut [u8]) {
+    for pixel in pixels.chunks_exact_mut(4) {
+        let alpha = u16::from(pixel[3]);
+        if alpha == 0 || alpha == 255 {
+            continue;
+        }
+        for channel in &mut pixel[..3] {
+            let value = (u16::from(*channel) * 255 + alpha / 2) / alpha;
+            *channel = u8::try_from(value).unwrap_or(u8::MAX);
         }
     }
 }
@@ -128,6 +276,16 @@ impl ImageData {
 /// - The image format is invalid or unsupported ([`Error::Io`])
 /// - For SVG files: parsing fails or dimensions are zero ([`Error::Svg`])
 pub fn load_image<P: AsRef<Path>>(path: P) -> Result<ImageData> {
+    load_image_with(path, DecoderPreferences::default())
+}
+
+/// Loads an image like [`load_image`], decoding formats with several
+/// backends (see [`decoders`](super::decoders)) with the preferred one first.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or no backend can decode it.
+pub fn load_image_with<P: AsRef<Path>>(path: P, decoders: DecoderPreferences) -> Result<ImageData> {
     let path = path.as_ref();
     let extension = path.extension().and_then(|s| s.to_str()).unwrap_or("");
 
@@ -151,23 +309,58 @@ pub fn load_image<P: AsRef<Path>>(path: P) -> Result<ImageData> {
         let rgba_pixels = pixmap.data().to_vec();
         let png_data = pixmap.encode_png().map_err(|e| Error::Svg(e.to_string()))?;
 
-        Ok(ImageData::from_encoded_with_rgba(
-            png_data,
-            width,
-            height,
-            rgba_pixels,
-        ))
+        Ok(
+            ImageData::from_encoded_with_rgba(png_data, width, height, rgba_pixels)
+                .with_decode_info(DecodeInfo::preferred(ImageDecoder::Resvg)),
+        )
     } else {
         let img_bytes = fs::read(path).map_err(|e| Error::Io(e.to_string()))?;
 
-        let img = image_rs::load_from_memory(&img_bytes).map_err(|e| Error::Io(e.to_string()))?;
+        // Only WebP has an alternative backend
+        if image_rs::guess_format(&img_bytes).ok() != Some(image_rs::ImageFormat::WebP) {
+            return decode_raster(&img_bytes, ImageDecoder::ImageRs)
+                .map(|image| image.with_decode_info(DecodeInfo::preferred(ImageDecoder::ImageRs)));
+        }
+
+        let preferred = decoders.webp.backend();
+        match decode_raster(&img_bytes, preferred) {
+            Ok(image) => Ok(image.with_decode_info(DecodeInfo::preferred(preferred))),
+            Err(preferred_error) => {
+                let Some(alternative) = preferred.alternative() else {
+                    return Err(preferred_error);
+                };
+                decode_raster(&img_bytes, alternative)
+                    .map(|image| {
+                        image.with_decode_info(DecodeInfo {
+                            decoder: alternative,
+                            fallback: true,
+                        })
+                    })
+                    // Report the preferred backend's error, the one users chose
+                    .map_err(|_| preferred_error)
+            }
+        }
+    }
+}
+
+/// Decodes raster image bytes with the given backend.
+fn decode_raster(bytes: &[u8], decoder: ImageDecoder) -> Result<ImageData> {
+    match decoder {
+        ImageDecoder::Libwebp => {
+            let (width, height, pixels) = decode_webp_with_libwebp(bytes)?;
+            Ok(ImageData::from_rgba(width, height, pixels))
+        }
+        // resvg only handles SVG, which never reaches this point
+        ImageDecoder::ImageRs | ImageDecoder::Resvg => {
+            let img = image_rs::load_from_memory(bytes).map_err(|e| Error::Io(e.to_string()))?;
 
-        let (width, height) = img.dimensions();
+            let (width, height) = img.dimensions();
 
-        let rgba_img = img.to_rgba8();
-        let pixels = rgba_img.into_vec();
+            let rgba_img = img.to_rgba8();
+            let pixels = rgba_img.into_vec();
 
-        Ok(ImageData::from_rgba(width, height, pixels))
+            Ok(ImageData::from_rgba(width, height, pixels))
+        }
     }
 }
 
@@ -187,10 +380,65 @@ impl From<String> for Error {
 mod tests {
     use super::*;
     use crate::error::Error;
+    use crate::media::decoders::WebpDecoder;
     use image_rs::{ImageError, Rgba, RgbaImage};
     use std::{fs, io};
     use tempfile::tempdir;
 
+    #[test]
+    fn average_luminance_ignores_transparent_pixels() {
+        let white = ImageData::from_rgba(2, 1, vec![255, 255, 255, 255, 0, 0, 0, 0]);
+        let luminance = white.average_luminance().expect("one visible pixel");
+        assert!((luminance - 1.0).abs() < 1e-3);
+
+        let dark = ImageData::from_rgba(1, 1, vec![20, 20, 20, 255]);
+        assert!(dark.average_luminance().expect("visible pixel") < 0.1);
+
+        let transparent = ImageData::from_rgba(1, 1, vec![255, 255, 255, 0]);
+        assert_eq!(transparent.average_luminance(), None);
+    }
+
+    #[test]
+    fn luminance_histogram_buckets_visible_pixels() {
+        let image = ImageData::from_rgba(
+            3,
+            1,
+            vec![0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255, 0],
+        );
+        let bins = image.luminance_histogram().expect("visible pixels");
+        assert_eq!(bins.len(), HISTOGRAM_BINS);
+        assert_eq!(bins[0], 1);
+        assert_eq!(bins[HISTOGRAM_BINS - 1], 1);
+        assert_eq!(bins.iter().sum::<u32>(), 2);
+
+        let transparent = ImageData::from_rgba(1, 1, vec![255, 255, 255, 0]);
+        assert_eq!(transparent.luminance_histogram(), None);
+    }
+
+    #[test]
+    fn mirrored_reverses_each_row() {
+        let pixels = (0..3 * 2).flat_map(|i| [i, i, i, 255]).collect();
+        let image = ImageData::from_rgba(3, 2, pixels);
+
+        let mirror = image.mirrored();
+        let reds: Vec<u8> = mirror.rgba_bytes().chunks_exact(4).map(|p| p[0]).collect();
+        assert_eq!(reds, vec![2, 1, 0, 5, 4, 3]);
+    }
+
+    #[test]
+    fn cropped_copies_the_requested_region() {
+        let pixels = (0..3 * 2).flat_map(|i| [i, i, i, 255]).collect();
+        let image = ImageData::from_rgba(3, 2, pixels);
+
+        let crop = image.cropped(1, 0, 2, 2).expect("region inside image");
+        assert_eq!((crop.width, crop.height), (2, 2));
+        let reds: Vec<u8> = crop.rgba_bytes().chunks_exact(4).map(|p| p[0]).collect();
+        assert_eq!(reds, vec![1, 2, 4, 5]);
+
+        assert!(image.cropped(2, 0, 2, 1).is_none());
+        assert!(image.cropped(0, 0, 0, 1).is_none());
+    }
+
     #[test]
     fn load_png_image_returns_expected_dimensions() {
         let temp_dir = tempdir().expect("failed to create temp dir");
@@ -206,6 +454,43 @@ mod tests {
         assert_eq!(data.height, 2);
     }
 
+    #[test]
+    fn webp_images_use_the_preferred_decoder() {
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let webp_path = temp_dir.path().join("sample.webp");
+        RgbaImage::from_pixel(3, 2, Rgba([0, 128, 255, 255]))
+            .save(&webp_path)
+            .expect("failed to write temporary webp");
+
+        for preferred in WebpDecoder::ALL {
+            let data = load_image_with(&webp_path, DecoderPreferences { webp: preferred })
+                .expect("webp should load successfully");
+            assert_eq!((data.width, data.height), (3, 2));
+            assert_eq!(&data.rgba_bytes()[..4], &[0, 128, 255, 255]);
+            assert_eq!(
+                data.decode_info(),
+                Some(DecodeInfo::preferred(preferred.backend()))
+            );
+        }
+
+        // Other formats only have one backend
+        let png_path = temp_dir.path().join("sample.png");
+        RgbaImage::from_pixel(1, 1, Rgba([0, 0, 0, 255]))
+            .save(&png_path)
+            .expect("failed to write temporary png");
+        let data = load_image_with(
+            &png_path,
+            DecoderPreferences {
+                webp: WebpDecoder::Libwebp,
+            },
+        )
+        .expect("png should load successfully");
+        assert_eq!(
+            data.decode_info(),
+            Some(DecodeInfo::preferred(ImageDecoder::ImageRs))
+        );
+    }
+
     #[test]
     fn load_svg_image_rasterizes_successfully() {
         let temp_dir = tempdir().expect("failed to create temp dir");
@@ -280,4 +565,31 @@ mod tests {
             other => panic!("expected Io variant from ImageError, got {other:?}"),
         }
     }
+
+    #[test]
+    fn unpremultiply_restores_straight_color() {
+        // 50% alpha with color premultiplied from 200 -> 100
+        let mut pixels = vec![100, 50, 0, 128, 10, 20, 30, 255, 7, 7, 7, 0];
+        unpremultiply_rgba(&mut pixels);
+        assert_eq!(&pixels[..4], &[199, 100, 0, 128]);
+        // Opaque and fully transparent pixels are untouched
+        assert_eq!(&pixels[4..8], &[10, 20, 30, 255]);
+        assert_eq!(&pixels[8..], &[7, 7, 7, 0]);
+    }
+
+    #[test]
+    fn unpremultiply_clamps_invalid_color() {
+        // Color above alpha is invalid premultiplied data; must not overflow
+        let mut pixels = vec![200, 0, 0, 100];
+        unpremultiply_rgba(&mut pixels);
+        assert_eq!(pixels, vec![255, 0, 0, 100]);
+    }
+
+    #[test]
+    fn unpremultiplied_preserves_dimensions() {
+        let image = ImageData::from_rgba(2, 1, vec![64, 64, 64, 128, 0, 0, 0, 255]);
+        let result = image.unpremultiplied();
+        assert_eq!((result.width, result.height), (2, 1));
+        assert_eq!(result.rgba_bytes()[0], 128);
+    }
 }
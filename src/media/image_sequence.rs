@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Numbered image sequence detection.
+//!
+//! Render farms, timelapse tools, and frame exporters write animations as one
+//! still per frame (`frame_0001.png`, `frame_0002.png`, …). This module finds
+//! the sequence a given file belongs to so it can be played back like a video.
+
+use super::extensions::IMAGE_EXTENSIONS;
+use crate::video_player::SequenceFps;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Minimum number of frames for a set of numbered files to count as a sequence.
+///
+/// Two files such as `IMG_0001.jpg` and `IMG_0002.jpg` are usually unrelated
+/// photos; requiring a few more avoids offering playback for every camera roll.
+pub const MIN_SEQUENCE_FRAMES: usize = 3;
+
+/// An ordered list of image files played back at a fixed frame rate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageSequence {
+    frames: Arc<[PathBuf]>,
+    fps: SequenceFps,
+}
+
+impl ImageSequence {
+    /// Creates a sequence from already ordered frame paths.
+    #[must_use]
+    pub fn new(frames: Vec<PathBuf>, fps: SequenceFps) -> Self {
+        Self {
+            frames: frames.into(),
+            fps,
+        }
+    }
+
+    /// Detects the numbered sequence containing `path`.
+    ///
+    /// Sibling files match when they share the same prefix and extension and
+    /// differ only by the trailing frame number of the file stem. Frames are
+    /// ordered by numeric value, so unpadded names (`f9`, `f10`) sort correctly.
+    ///
+    /// Returns `None` if `path` is not numbered, the directory cannot be read,
+    /// or fewer than [`MIN_SEQUENCE_FRAMES`] frames are found.
+    #[must_use]
+    pub fn detect<P: AsRef<Path>>(path: P, fps: SequenceFps) -> Option<Self> {
+        let path = path.as_ref();
+        let key = SequenceKey::from_path(path)?;
+        let directory = path.parent()?;
+
+        let mut numbered: Vec<(u64, PathBuf)> = std::fs::read_dir(directory)
+            .ok()?
+            .filter_map(std::result::Result::ok)
+            .map(|entry| entry.path())
+            .filter(|candidate| candidate.is_file())
+            .filter_map(|candidate| {
+                let candidate_key = SequenceKey::from_path(&candidate)?;
+                candidate_key
+                    .same_sequence(&key)
+                    .then_some((candidate_key.number, candidate))
+            })
+            .collect();
+
+        if numbered.len() < MIN_SEQUENCE_FRAMES {
+            return None;
+        }
+
+        numbered.sort_by_key(|(number, _)| *number);
+        let frames = numbered.into_iter().map(|(_, frame)| frame).collect();
+        Some(Self::new(frames, fps))
+    }
+
+    /// Returns the frame paths in playback order.
+    #[must_use]
+    pub fn frames(&self) -> &[PathBuf] {
+        &self.frames
+    }
+
+    /// Returns the number of frames.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns true if the sequence has no frames.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns the playback frame rate.
+    #[must_use]
+    pub fn fps(&self) -> SequenceFps {
+        self.fps
+    }
+
+    /// Returns the playback duration in seconds.
+    // Allow cast_precision_loss: frame counts never approach 2^52.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn duration_secs(&self) -> f64 {
+        self.frames.len() as f64 * self.fps.frame_duration_secs()
+    }
+
+    /// Returns the index of the frame shown at `position_secs`, clamped to the last frame.
+    // Allow cast_possible_truncation/cast_sign_loss: value is floored and clamped to a valid index.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
+    pub fn frame_index_at(&self, position_secs: f64) -> usize {
+        let index = (position_secs.max(0.0) * f64::from(self.fps.value())).floor() as usize;
+        index.min(self.frames.len().saturating_sub(1))
+    }
+}
+
+/// The parts of a file name that identify which sequence it belongs to.
+struct SequenceKey {
+    prefix: String,
+    extension: String,
+    number: u64,
+}
+
+impl SequenceKey {
+    /// Splits `frame_0042.png` into prefix `frame_`, number 42, and extension `png`.
+    fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        if !IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+            return None;
+        }
+
+        let stem = path.file_stem()?.to_str()?;
+        let prefix = stem.trim_end_matches(|c: char| c.is_ascii_digit());
+        let digits = &stem[prefix.len()..];
+        let number = digits.parse().ok()?;
+
+        Some(Self {
+            prefix: prefix.to_string(),
+            extension,
+            number,
+        })
+    }
+
+    fn same_sequence(&self, other: &Self) -> bool {
+        self.prefix == other.prefix && self.extension == other.extension
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn touch(dir: &Path, name: &str) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, b"").expect("write test file");
+        path
+    }
+
+    #[test]
+    fn detects_padded_sequence_in_order() {
+        let dir = tempdir().expect("temp dir");
+        let third = touch(dir.path(), "frame_0003.png");
+        let first = touch(dir.path(), "frame_0001.png");
+        let second = touch(dir.path(), "frame_0002.png");
+
+        let sequence =
+            ImageSequence::detect(&second, SequenceFps::default()).expect("sequence detected");
+        assert_eq!(sequence.frames(), &[first, second, third]);
+    }
+
+    #[test]
+    fn orders_unpadded_numbers_numerically() {
+        let dir = tempdir().expect("temp dir");
+        let nine = touch(dir.path(), "shot9.jpg");
+        let ten = touch(dir.path(), "shot10.jpg");
+        let eight = touch(dir.path(), "shot8.jpg");
+
+        let sequence =
+            ImageSequence::detect(&ten, SequenceFps::default()).expect("sequence detected");
+        assert_eq!(sequence.frames(), &[eight, nine, ten]);
+    }
+
+    #[test]
+    fn ignores_other_prefixes_and_extensions() {
+        let dir = tempdir().expect("temp dir");
+        let start = touch(dir.path(), "a_001.png");
+        touch(dir.path(), "a_002.png");
+        touch(dir.path(), "a_003.png");
+        touch(dir.path(), "a_004.jpg");
+        touch(dir.path(), "b_005.png");
+        touch(dir.path(), "a_notes.txt");
+
+        let sequence =
+            ImageSequence::detect(&start, SequenceFps::default()).expect("sequence detected");
+        assert_eq!(sequence.len(), 3);
+    }
+
+    #[test]
+    fn requires_minimum_frame_count() {
+        let dir = tempdir().expect("temp dir");
+        let start = touch(dir.path(), "IMG_0001.jpg");
+        touch(dir.path(), "IMG_0002.jpg");
+
+        assert!(ImageSequence::detect(&start, SequenceFps::default()).is_none());
+    }
+
+    #[test]
+    fn unnumbered_file_is_not_a_sequence() {
+        let dir = tempdir().expect("temp dir");
+        let start = touch(dir.path(), "cover.png");
+        touch(dir.path(), "cover1.png");
+        touch(dir.path(), "cover2.png");
+
+        assert!(ImageSequence::detect(&start, SequenceFps::default()).is_none());
+    }
+
+    #[test]
+    fn duration_and_frame_index_follow_fps() {
+        let frames = (0..50)
+            .map(|i| PathBuf::from(format!("f{i}.png")))
+            .collect();
+        let sequence = ImageSequence::new(frames, SequenceFps::new(25));
+
+        assert!((sequence.duration_secs() - 2.0).abs() < 1e-9);
+        assert_eq!(sequence.frame_index_at(0.0), 0);
+        assert_eq!(sequence.frame_index_at(1.0), 25);
+        assert_eq!(sequence.frame_index_at(-1.0), 0);
+        assert_eq!(sequence.frame_index_at(60.0), 49);
+    }
+}
@@ -234,6 +234,96 @@ pub fn crop(image: &DynamicImage, x: u32, y: u32, width: u32, height: u32) -> Op
     Some(image.crop_imm(x, y, width, height))
 }
 
+/// Sharpen the image with an unsharp mask.
+///
+/// Each channel is pushed away from a Gaussian-blurred copy of the image by
+/// `amount` percent of their difference. `radius` is the blur sigma in
+/// pixels; differences below `threshold` (0 to 255) are left alone so flat
+/// areas and fine noise are not sharpened. The alpha channel is kept as is.
+///
+/// Note: When `amount` is zero, this function returns a cloned image.
+#[must_use]
+pub fn unsharp_mask(image: &DynamicImage, amount: u32, radius: f32, threshold: u8) -> DynamicImage {
+    if amount == 0 || radius <= 0.0 {
+        return image.clone();
+    }
+    let mut rgba = image.to_rgba8();
+    let blurred = image_rs::imageops::blur(&rgba, radius);
+    // Percentages up to a few hundred are exact in f32
+    #[allow(clippy::cast_precision_loss)]
+    let amount = amount as f32 / 100.0;
+    for (pixel, blurred) in rgba.pixels_mut().zip(blurred.pixels()) {
+        for channel in 0..3 {
+            let original = f32::from(pixel.0[channel]);
+            let difference = original - f32::from(blurred.0[channel]);
+            if difference.abs() >= f32::from(threshold) {
+                // Clamped to the u8 range before the cast
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let sharpened = (original + amount * difference).round().clamp(0.0, 255.0) as u8;
+                pixel.0[channel] = sharpened;
+            }
+        }
+    }
+    if image.color().has_alpha() {
+        DynamicImage::ImageRgba8(rgba)
+    } else {
+        DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(rgba).to_rgb8())
+    }
+}
+
+/// Convert the image to grayscale by mixing its color channels.
+///
+/// Each gray value is the sum of the red, green and blue channels weighted
+/// by `weights` percent (negative weights darken what the channel brightens).
+/// Weights that do not add up to 100 make the image lighter or darker
+/// overall. The result keeps the color type and alpha channel of the input.
+#[must_use]
+pub fn channel_mix_grayscale(image: &DynamicImage, weights: [i32; 3]) -> DynamicImage {
+    let mut rgba = image.to_rgba8();
+    // Percentages of a few hundred are exact in f32
+    #[allow(clippy::cast_precision_loss)]
+    let weights = weights.map(|weight| weight as f32 / 100.0);
+    for pixel in rgba.pixels_mut() {
+        let gray = (0..3)
+            .map(|channel| f32::from(pixel.0[channel]) * weights[channel])
+            .sum::<f32>();
+        // Clamped to the u8 range before the cast
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let gray = gray.round().clamp(0.0, 255.0) as u8;
+        pixel.0[..3].fill(gray);
+    }
+    if image.color().has_alpha() {
+        DynamicImage::ImageRgba8(rgba)
+    } else {
+        DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(rgba).to_rgb8())
+    }
+}
+
+/// Composite the image over an opaque `background` color.
+///
+/// Used before saving to formats without transparency (JPEG), where
+/// transparent pixels would otherwise turn black. Images without an alpha
+/// channel are returned unchanged.
+#[must_use]
+pub fn flatten_alpha(image: &DynamicImage, background: [u8; 3]) -> DynamicImage {
+    if !image.color().has_alpha() {
+        return image.clone();
+    }
+    let mut rgba = image.to_rgba8();
+    for pixel in rgba.pixels_mut() {
+        let alpha = u16::from(pixel.0[3]);
+        for channel in 0..3 {
+            let blended = (u16::from(pixel.0[channel]) * alpha
+                + u16::from(background[channel]) * (255 - alpha)
+                + 127)
+                / 255;
+            // Weighted average of two u8 values always fits in u8
+            pixel.0[channel] = u8::try_from(blended).unwrap_or(u8::MAX);
+        }
+    }
+    DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(rgba).to_rgb8())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -311,6 +401,18 @@ mod tests {
         assert_eq!(result.height(), 8);
     }
 
+    #[test]
+    fn flatten_alpha_blends_over_background() {
+        let mut buffer = ImageBuffer::from_pixel(2, 1, image_rs::Rgba([0, 0, 0, 0]));
+        buffer.put_pixel(1, 0, image_rs::Rgba([0, 0, 0, 255]));
+        let flattened = flatten_alpha(&DynamicImage::ImageRgba8(buffer), [255, 255, 255]);
+
+        assert!(!flattened.color().has_alpha());
+        let rgb = flattened.to_rgb8();
+        assert_eq!(rgb.get_pixel(0, 0).0, [255, 255, 255]);
+        assert_eq!(rgb.get_pixel(1, 0).0, [0, 0, 0]);
+    }
+
     #[test]
     fn flip_horizontal_preserves_dimensions() {
         let img = create_test_image(8, 6);
@@ -421,6 +523,46 @@ mod tests {
         assert!(pixel[2] < 100, "Blue channel should be darker");
     }
 
+    #[test]
+    fn unsharp_mask_steepens_edges_above_threshold() {
+        let buffer = ImageBuffer::from_fn(16, 2, |x, _| {
+            let value = if x < 8 { 80 } else { 160 };
+            image_rs::Rgb([value, value, value])
+        });
+        let img = DynamicImage::ImageRgb8(buffer);
+
+        let sharpened = unsharp_mask(&img, 100, 1.0, 0).to_rgb8();
+        assert!(sharpened.get_pixel(7, 0).0[0] < 80, "dark side gets darker");
+        assert!(
+            sharpened.get_pixel(8, 0).0[0] > 160,
+            "light side gets lighter"
+        );
+        assert_eq!(sharpened.get_pixel(0, 0).0[0], 80, "flat area is unchanged");
+
+        // The edge is below a high threshold, so nothing changes
+        let untouched = unsharp_mask(&img, 100, 1.0, 100);
+        assert_eq!(untouched.to_rgb8(), img.to_rgb8());
+        assert!(!untouched.color().has_alpha());
+    }
+
+    #[test]
+    fn channel_mix_weights_each_channel() {
+        let img =
+            DynamicImage::ImageRgb8(ImageBuffer::from_pixel(2, 2, image_rs::Rgb([200, 100, 0])));
+
+        let red_only = channel_mix_grayscale(&img, [100, 0, 0]).to_rgb8();
+        assert_eq!(red_only.get_pixel(0, 0).0, [200, 200, 200]);
+
+        let mixed = channel_mix_grayscale(&img, [50, 50, 0]).to_rgb8();
+        assert_eq!(mixed.get_pixel(1, 1).0, [150, 150, 150]);
+
+        // Out of range sums are clipped, negative weights darken
+        let clipped = channel_mix_grayscale(&img, [200, 0, 0]).to_rgb8();
+        assert_eq!(clipped.get_pixel(0, 0).0[0], 255);
+        let negative = channel_mix_grayscale(&img, [0, -100, 0]).to_rgb8();
+        assert_eq!(negative.get_pixel(0, 0).0[0], 0);
+    }
+
     #[test]
     fn contrast_zero_returns_unchanged() {
         let img = create_test_image(4, 4);
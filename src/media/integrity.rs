@@ -0,0 +1,276 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Integrity verification of media files.
+//!
+//! Fully decodes each file to detect corruption and truncation, e.g. after
+//! recovering photos from a damaged memory card. Images are decoded to pixels
+//! and checked for a complete end-of-stream marker (decoders often pad
+//! truncated JPEGs with gray instead of failing). Videos are demuxed and
+//! every packet of the main video stream is decoded.
+
+use crate::directory_scanner::MediaList;
+use crate::error::Result;
+use crate::media::{detect_media_type, MediaType};
+use std::path::{Path, PathBuf};
+
+/// JPEG End Of Image marker.
+const JPEG_EOI: [u8; 2] = [0xFF, 0xD9];
+/// PNG `IEND` chunk: length (0), type, and CRC.
+const PNG_IEND: [u8; 12] = [
+    0x00, 0x00, 0x00, 0x00, b'I', b'E', b'N', b'D', 0xAE, 0x42, 0x60, 0x82,
+];
+/// Maximum trailing bytes tolerated after the end marker (some cameras pad files).
+const MAX_TRAILING_BYTES: usize = 4096;
+/// A video whose packets stop earlier than this fraction of its declared
+/// duration is reported as truncated.
+const MIN_DURATION_COVERAGE: f64 = 0.95;
+
+/// A file that failed verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DamagedFile {
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+/// Result of verifying a set of files.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifyReport {
+    /// Number of files checked.
+    pub checked: usize,
+    /// Files that are corrupt or truncated, in input order.
+    pub damaged: Vec<DamagedFile>,
+}
+
+impl VerifyReport {
+    /// Returns true if no damaged file was found.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.damaged.is_empty()
+    }
+}
+
+/// Lists the supported media files of `directory`, sorted by name.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be read.
+pub fn collect_media_files(directory: &Path) -> Result<Vec<PathBuf>> {
+    let list = MediaList::scan_directory_direct(directory, crate::config::SortOrder::Alphabetical)?;
+    Ok((0..list.len())
+        .filter_map(|i| list.get(i))
+        .map(Path::to_path_buf)
+        .collect())
+}
+
+/// Verifies every file in `paths`, calling `on_progress(done, total)` after each.
+///
+/// This function performs blocking I/O and decoding and should run off the UI thread.
+pub fn verify_files(paths: &[PathBuf], mut on_progress: impl FnMut(usize, usize)) -> VerifyReport {
+    let mut report = VerifyReport::default();
+
+    for (index, path) in paths.iter().enumerate() {
+        report.checked += 1;
+        if let Err(reason) = verify_file(path) {
+            report.damaged.push(DamagedFile {
+                path: path.clone(),
+                reason,
+            });
+        }
+        on_progress(index + 1, paths.len());
+    }
+
+    report
+}
+
+/// Verifies a single file, returning a human-readable reason if it is damaged.
+///
+/// # Errors
+///
+/// Returns the reason the file is considered damaged.
+pub fn verify_file(path: &Path) -> std::result::Result<(), String> {
+    match detect_media_type(path) {
+        Some(MediaType::Video) => verify_video(path),
+        Some(MediaType::Image) => verify_image(path),
+        None => Err("unsupported format".to_string()),
+    }
+}
+
+/// Decodes an image fully and checks its end-of-stream marker.
+fn verify_image(path: &Path) -> std::result::Result<(), String> {
+    let data = std::fs::read(path).map_err(|e| e.to_string())?;
+    if data.is_empty() {
+        return Err("empty file".to_string());
+    }
+
+    check_end_marker(path, &data)?;
+
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    if extension.eq_ignore_ascii_case("svg") {
+        return crate::media::image::load_image(path)
+            .map(|_| ())
+            .map_err(|e| e.to_string());
+    }
+
+    image_rs::load_from_memory(&data)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Checks that JPEG and PNG data ends with its format's end marker.
+fn check_end_marker(path: &Path, data: &[u8]) -> std::result::Result<(), String> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+
+    let marker: &[u8] = match extension.as_str() {
+        "jpg" | "jpeg" => &JPEG_EOI,
+        "png" => &PNG_IEND,
+        _ => return Ok(()),
+    };
+
+    let tail_start = data.len().saturating_sub(MAX_TRAILING_BYTES + marker.len());
+    if data[tail_start..]
+        .windows(marker.len())
+        .any(|window| window == marker)
+    {
+        Ok(())
+    } else {
+        Err("truncated file (end marker missing)".to_string())
+    }
+}
+
+/// Demuxes a video and decodes every packet of its main video stream.
+fn verify_video(path: &Path) -> std::result::Result<(), String> {
+    crate::media::video::init_ffmpeg().map_err(|e| e.to_string())?;
+
+    let mut ictx = ffmpeg_next::format::input(&path).map_err(|e| e.to_string())?;
+    let stream = ictx
+        .streams()
+        .best(ffmpeg_next::media::Type::Video)
+        .ok_or_else(|| "no video stream".to_string())?;
+    let stream_index = stream.index();
+    let time_base = f64::from(stream.time_base());
+    let context = ffmpeg_next::codec::context::Context::from_parameters(stream.parameters())
+        .map_err(|e| e.to_string())?;
+    let mut decoder = context.decoder().video().map_err(|e| e.to_string())?;
+
+    // Container duration is in AV_TIME_BASE units (microseconds)
+    #[allow(clippy::cast_precision_loss)]
+    let declared_secs = (ictx.duration() > 0).then(|| ictx.duration() as f64 / 1_000_000.0);
+
+    let mut frame = ffmpeg_next::frame::Video::empty();
+    let mut last_pts_secs = 0.0_f64;
+    let mut decoded_frames = 0_u64;
+
+    loop {
+        let mut packet = ffmpeg_next::Packet::empty();
+        match packet.read(&mut ictx) {
+            Ok(()) => {}
+            Err(ffmpeg_next::Error::Eof) => break,
+            Err(e) => return Err(format!("read error: {e}")),
+        }
+        if packet.stream() != stream_index {
+            continue;
+        }
+        if let Some(pts) = packet.pts().or(packet.dts()) {
+            #[allow(clippy::cast_precision_loss)]
+            let secs = pts as f64 * time_base;
+            last_pts_secs = last_pts_secs.max(secs);
+        }
+        decoder
+            .send_packet(&packet)
+            .map_err(|e| format!("corrupt video data: {e}"))?;
+        while decoder.receive_frame(&mut frame).is_ok() {
+            decoded_frames += 1;
+        }
+    }
+
+    let _ = decoder.send_eof();
+    while decoder.receive_frame(&mut frame).is_ok() {
+        decoded_frames += 1;
+    }
+
+    if decoded_frames == 0 {
+        return Err("no decodable video frame".to_string());
+    }
+    if let Some(declared) = declared_secs {
+        if last_pts_secs < declared * MIN_DURATION_COVERAGE - 1.0 {
+            return Err(format!(
+                "truncated video ({last_pts_secs:.1}s of {declared:.1}s)"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_jpeg(dir: &Path, name: &str) -> PathBuf {
+        let path = dir.join(name);
+        image_rs::RgbImage::from_pixel(32, 32, image_rs::Rgb([200, 100, 50]))
+            .save(&path)
+            .expect("write jpeg");
+        path
+    }
+
+    #[test]
+    fn intact_images_pass() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let jpeg = write_jpeg(dir.path(), "ok.jpg");
+        let png = dir.path().join("ok.png");
+        image_rs::RgbImage::from_pixel(8, 8, image_rs::Rgb([1, 2, 3]))
+            .save(&png)
+            .expect("write png");
+
+        assert_eq!(verify_file(&jpeg), Ok(()));
+        assert_eq!(verify_file(&png), Ok(()));
+    }
+
+    #[test]
+    fn truncated_jpeg_is_reported() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = write_jpeg(dir.path(), "cut.jpg");
+        let data = std::fs::read(&path).expect("read");
+        std::fs::write(&path, &data[..data.len() / 2]).expect("truncate");
+
+        assert!(verify_file(&path).is_err());
+    }
+
+    #[test]
+    fn verify_files_collects_damaged_files_and_reports_progress() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let good = write_jpeg(dir.path(), "a.jpg");
+        let empty = dir.path().join("b.png");
+        std::fs::write(&empty, b"").expect("write empty");
+
+        let mut progress = Vec::new();
+        let report = verify_files(&[good, empty.clone()], |done, total| {
+            progress.push((done, total));
+        });
+
+        assert_eq!(report.checked, 2);
+        assert_eq!(report.damaged.len(), 1);
+        assert_eq!(report.damaged[0].path, empty);
+        assert!(!report.is_clean());
+        assert_eq!(progress, vec![(1, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn collect_media_files_lists_supported_files() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        write_jpeg(dir.path(), "b.jpg");
+        write_jpeg(dir.path(), "a.jpg");
+        std::fs::write(dir.path().join("notes.txt"), b"x").expect("write txt");
+
+        let files = collect_media_files(dir.path()).expect("scan");
+        let names: Vec<_> = files
+            .iter()
+            .filter_map(|p| p.file_name()?.to_str())
+            .collect();
+        assert_eq!(names, vec!["a.jpg", "b.jpg"]);
+    }
+}
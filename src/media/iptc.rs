@@ -0,0 +1,494 @@
+// SPDX-License-Identifier: MPL-2.0
+//! IPTC-IIM metadata extraction.
+//!
+//! IPTC Information Interchange Model records are stored in JPEG files inside
+//! the Photoshop APP13 segment, as the `0x0404` image resource. Each record is
+//! a dataset tagged `0x1C`, a record number, a dataset number, and a
+//! big-endian length followed by the value.
+//!
+//! Only the application record (record 2), which carries the descriptive
+//! fields (caption, keywords, copyright, …), is decoded. [`IptcFields`] covers
+//! the subset edited by the metadata editor; [`encode_iim`] and
+//! [`replace_iptc_resource`] rebuild the binary blocks for writing.
+
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// APP13 segment signature for Photoshop image resources.
+const PHOTOSHOP_SIGNATURE: &[u8] = b"Photoshop 3.0\0";
+
+/// Signature of each Photoshop image resource block.
+const RESOURCE_SIGNATURE: &[u8; 4] = b"8BIM";
+
+/// Image resource ID of the IPTC-NAA record.
+const IPTC_RESOURCE_ID: u16 = 0x0404;
+
+/// Tag marker starting every IIM dataset.
+const DATASET_MARKER: u8 = 0x1C;
+
+/// IIM envelope record number.
+const ENVELOPE_RECORD: u8 = 1;
+
+/// IIM application record number.
+const APPLICATION_RECORD: u8 = 2;
+
+/// Envelope dataset declaring the coded character set.
+const CODED_CHARACTER_SET: u8 = 90;
+
+/// ISO 2022 escape sequence declaring UTF-8 text.
+const UTF8_ESCAPE: &[u8] = b"\x1B%G";
+
+/// Application record version dataset and the version we write.
+const RECORD_VERSION: u8 = 0;
+const RECORD_VERSION_VALUE: &[u8] = &[0, 4];
+
+/// `ObjectName` (title) dataset.
+pub const DATASET_OBJECT_NAME: u8 = 5;
+/// `Keywords` dataset, repeated once per keyword.
+pub const DATASET_KEYWORDS: u8 = 25;
+/// `By-line` (creator) dataset.
+pub const DATASET_BY_LINE: u8 = 80;
+/// `CopyrightNotice` dataset.
+pub const DATASET_COPYRIGHT_NOTICE: u8 = 116;
+/// `Caption-Abstract` (description) dataset.
+pub const DATASET_CAPTION: u8 = 120;
+
+/// Datasets managed by [`IptcFields`], with their maximum length in bytes.
+const EDITED_DATASETS: &[(u8, usize)] = &[
+    (DATASET_OBJECT_NAME, 64),
+    (DATASET_KEYWORDS, 64),
+    (DATASET_BY_LINE, 32),
+    (DATASET_COPYRIGHT_NOTICE, 128),
+    (DATASET_CAPTION, 2000),
+];
+
+/// A decoded IPTC dataset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IptcDataset {
+    /// Dataset number within the application record (e.g. 25 for keywords)
+    pub dataset: u8,
+    /// Human-readable dataset name (e.g. "Keywords")
+    pub name: String,
+    /// Dataset value decoded as UTF-8 (lossy)
+    pub value: String,
+}
+
+/// Descriptive IPTC fields edited through the metadata editor.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IptcFields {
+    /// `ObjectName` - short title
+    pub title: String,
+    /// `Caption-Abstract` - description of the content
+    pub caption: String,
+    /// `Keywords` - one entry per keyword
+    pub keywords: Vec<String>,
+    /// `By-line` - creator of the image
+    pub creator: String,
+    /// `CopyrightNotice`
+    pub copyright: String,
+}
+
+impl IptcFields {
+    /// Collects the edited fields from decoded datasets.
+    #[must_use]
+    pub fn from_datasets(datasets: &[IptcDataset]) -> Self {
+        let mut fields = Self::default();
+        for dataset in datasets {
+            let value = dataset.value.clone();
+            match dataset.dataset {
+                DATASET_OBJECT_NAME => fields.title = value,
+                DATASET_CAPTION => fields.caption = value,
+                DATASET_KEYWORDS => fields.keywords.push(value),
+                DATASET_BY_LINE => fields.creator = value,
+                DATASET_COPYRIGHT_NOTICE => fields.copyright = value,
+                _ => {}
+            }
+        }
+        fields
+    }
+
+    /// Returns true if no field has a value.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.title.is_empty()
+            && self.caption.is_empty()
+            && self.keywords.is_empty()
+            && self.creator.is_empty()
+            && self.copyright.is_empty()
+    }
+
+    /// Returns the non-empty fields as `(dataset, value)` pairs in IIM order.
+    fn datasets(&self) -> Vec<(u8, &str)> {
+        let mut datasets = vec![(DATASET_OBJECT_NAME, self.title.as_str())];
+        datasets.extend(self.keywords.iter().map(|k| (DATASET_KEYWORDS, k.as_str())));
+        datasets.push((DATASET_BY_LINE, self.creator.as_str()));
+        datasets.push((DATASET_COPYRIGHT_NOTICE, self.copyright.as_str()));
+        datasets.push((DATASET_CAPTION, self.caption.as_str()));
+        datasets.retain(|(_, value)| !value.trim().is_empty());
+        datasets
+    }
+}
+
+/// Extract the application record datasets from a JPEG file.
+///
+/// Repeated datasets (such as keywords) are returned once per occurrence, in
+/// file order. Returns an empty list if the file has no IPTC data.
+#[must_use]
+pub fn extract_iptc_from_jpeg<P: AsRef<Path>>(path: P) -> Vec<IptcDataset> {
+    read_jpeg_iptc_block(path)
+        .map(|block| parse_iim(&block))
+        .unwrap_or_default()
+}
+
+/// Read the raw IIM block from the Photoshop APP13 segment of a JPEG file.
+fn read_jpeg_iptc_block<P: AsRef<Path>>(path: P) -> Option<Vec<u8>> {
+    let file = File::open(path).ok()?;
+    let mut reader = BufReader::new(file);
+
+    let mut marker = [0u8; 2];
+    reader.read_exact(&mut marker).ok()?;
+    if marker != [0xFF, 0xD8] {
+        return None; // Not a JPEG
+    }
+
+    loop {
+        reader.read_exact(&mut marker).ok()?;
+        if marker[0] != 0xFF {
+            return None; // Invalid JPEG structure
+        }
+
+        match marker[1] {
+            // End of image or start of scan: metadata segments come before
+            0xD9 | 0xDA => return None,
+            0xD0..=0xD8 | 0x01 => {} // Markers without a length
+            marker_type => {
+                let mut len_bytes = [0u8; 2];
+                reader.read_exact(&mut len_bytes).ok()?;
+                let segment_len = u16::from_be_bytes(len_bytes) as usize;
+                if segment_len < 2 {
+                    return None;
+                }
+
+                if marker_type == 0xED {
+                    let mut segment = vec![0u8; segment_len - 2];
+                    reader.read_exact(&mut segment).ok()?;
+                    if let Some(block) = segment
+                        .strip_prefix(PHOTOSHOP_SIGNATURE)
+                        .and_then(find_iptc_resource)
+                    {
+                        return Some(block.to_vec());
+                    }
+                } else {
+                    // segment_len comes from a u16, so the cast cannot wrap
+                    #[allow(clippy::cast_possible_wrap)]
+                    let skip_bytes = (segment_len - 2) as i64;
+                    reader.seek(SeekFrom::Current(skip_bytes)).ok()?;
+                }
+            }
+        }
+    }
+}
+
+/// Find the IPTC-NAA resource in a list of Photoshop image resources.
+pub(crate) fn find_iptc_resource(mut data: &[u8]) -> Option<&[u8]> {
+    while data.len() >= 12 && data.starts_with(RESOURCE_SIGNATURE) {
+        let id = u16::from_be_bytes([data[4], data[5]]);
+
+        // Pascal string name, padded to an even length (including the length byte)
+        let name_len = usize::from(data[6]);
+        let name_total = (name_len + 1 + 1) & !1;
+        let size_offset = 6 + name_total;
+        let size_bytes = data.get(size_offset..size_offset + 4)?;
+        let size = u32::from_be_bytes([size_bytes[0], size_bytes[1], size_bytes[2], size_bytes[3]])
+            as usize;
+        let data_start = size_offset + 4;
+        let resource = data.get(data_start..data_start + size)?;
+
+        if id == IPTC_RESOURCE_ID {
+            return Some(resource);
+        }
+
+        // Resource data is padded to an even length
+        let next = data_start + size + (size & 1);
+        data = data.get(next..)?;
+    }
+    None
+}
+
+/// Parse IIM datasets, keeping those of the application record.
+fn parse_iim(data: &[u8]) -> Vec<IptcDataset> {
+    raw_datasets(data)
+        .into_iter()
+        .filter(|(record, dataset, _)| *record == APPLICATION_RECORD && *dataset != RECORD_VERSION)
+        .map(|(_, dataset, value)| IptcDataset {
+            dataset,
+            name: dataset_name(dataset),
+            value: String::from_utf8_lossy(value)
+                .trim_end_matches('\0')
+                .to_string(),
+        })
+        .collect()
+}
+
+/// Split an IIM block into `(record, dataset, value)` triples.
+fn raw_datasets(mut data: &[u8]) -> Vec<(u8, u8, &[u8])> {
+    let mut datasets = Vec::new();
+
+    while data.len() >= 5 && data[0] == DATASET_MARKER {
+        let len = usize::from(u16::from_be_bytes([data[3], data[4]]));
+        if len & 0x8000 != 0 {
+            break; // Extended datasets are not used for descriptive fields
+        }
+        let Some(value) = data.get(5..5 + len) else {
+            break;
+        };
+        datasets.push((data[1], data[2], value));
+        data = &data[5 + len..];
+    }
+
+    datasets
+}
+
+/// Encode `fields` as an IIM block, marked as UTF-8.
+///
+/// Application datasets of `existing` that the editor does not manage (city,
+/// headline, …) are kept, so saving does not drop unrelated IPTC data.
+/// Values longer than the IIM limits are truncated on a character boundary.
+#[must_use]
+pub fn encode_iim(fields: &IptcFields, existing: &[u8]) -> Vec<u8> {
+    let mut iim = Vec::new();
+    push_dataset(&mut iim, ENVELOPE_RECORD, CODED_CHARACTER_SET, UTF8_ESCAPE);
+    push_dataset(
+        &mut iim,
+        APPLICATION_RECORD,
+        RECORD_VERSION,
+        RECORD_VERSION_VALUE,
+    );
+
+    for (record, dataset, value) in raw_datasets(existing) {
+        let edited = EDITED_DATASETS.iter().any(|(d, _)| *d == dataset);
+        if record == APPLICATION_RECORD && dataset != RECORD_VERSION && !edited {
+            push_dataset(&mut iim, record, dataset, value);
+        }
+    }
+
+    for (dataset, value) in fields.datasets() {
+        let max_len = EDITED_DATASETS
+            .iter()
+            .find(|(d, _)| *d == dataset)
+            .map_or(usize::MAX, |(_, len)| *len);
+        push_dataset(
+            &mut iim,
+            APPLICATION_RECORD,
+            dataset,
+            truncate_utf8(value.trim(), max_len).as_bytes(),
+        );
+    }
+
+    iim
+}
+
+/// Append one dataset to an IIM block.
+fn push_dataset(iim: &mut Vec<u8>, record: u8, dataset: u8, value: &[u8]) {
+    // Values are bounded by EDITED_DATASETS or come from a parsed (short) dataset
+    let len = u16::try_from(value.len().min(0x7FFF)).unwrap_or(0x7FFF);
+    iim.extend_from_slice(&[DATASET_MARKER, record, dataset]);
+    iim.extend_from_slice(&len.to_be_bytes());
+    iim.extend_from_slice(&value[..usize::from(len)]);
+}
+
+/// Truncate `value` to at most `max_len` bytes without splitting a character.
+fn truncate_utf8(value: &str, max_len: usize) -> &str {
+    if value.len() <= max_len {
+        return value;
+    }
+    let mut end = max_len;
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    &value[..end]
+}
+
+/// Rebuild a list of Photoshop image resources with the IPTC resource set to `iim`.
+///
+/// Other resources are copied unchanged; the IPTC resource is replaced in
+/// place, or appended if missing.
+#[must_use]
+pub fn replace_iptc_resource(mut resources: &[u8], iim: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(resources.len() + iim.len() + 12);
+    let mut replaced = false;
+
+    while resources.len() >= 12 && resources.starts_with(RESOURCE_SIGNATURE) {
+        let id = u16::from_be_bytes([resources[4], resources[5]]);
+        let name_total = (usize::from(resources[6]) + 1 + 1) & !1;
+        let size_offset = 6 + name_total;
+        let Some(size_bytes) = resources.get(size_offset..size_offset + 4) else {
+            break;
+        };
+        let size = u32::from_be_bytes([size_bytes[0], size_bytes[1], size_bytes[2], size_bytes[3]])
+            as usize;
+        let end = (size_offset + 4 + size + (size & 1)).min(resources.len());
+
+        if id == IPTC_RESOURCE_ID {
+            push_resource(&mut output, IPTC_RESOURCE_ID, iim);
+            replaced = true;
+        } else {
+            output.extend_from_slice(&resources[..end]);
+        }
+        resources = &resources[end..];
+    }
+
+    if !replaced {
+        push_resource(&mut output, IPTC_RESOURCE_ID, iim);
+    }
+    output
+}
+
+/// Append an image resource block with an empty name.
+fn push_resource(output: &mut Vec<u8>, id: u16, data: &[u8]) {
+    output.extend_from_slice(RESOURCE_SIGNATURE);
+    output.extend_from_slice(&id.to_be_bytes());
+    output.extend_from_slice(&[0, 0]); // Empty Pascal name, padded
+    let size = u32::try_from(data.len()).unwrap_or(u32::MAX);
+    output.extend_from_slice(&size.to_be_bytes());
+    output.extend_from_slice(data);
+    if data.len() % 2 == 1 {
+        output.push(0);
+    }
+}
+
+/// Returns the IIM name of an application record dataset.
+#[must_use]
+pub fn dataset_name(dataset: u8) -> String {
+    let name = match dataset {
+        5 => "ObjectName",
+        7 => "EditStatus",
+        10 => "Urgency",
+        15 => "Category",
+        20 => "SupplementalCategories",
+        25 => "Keywords",
+        40 => "SpecialInstructions",
+        55 => "DateCreated",
+        60 => "TimeCreated",
+        65 => "OriginatingProgram",
+        80 => "By-line",
+        85 => "By-lineTitle",
+        90 => "City",
+        92 => "Sub-location",
+        95 => "Province-State",
+        100 => "Country-PrimaryLocationCode",
+        101 => "Country-PrimaryLocationName",
+        103 => "OriginalTransmissionReference",
+        105 => "Headline",
+        110 => "Credit",
+        115 => "Source",
+        116 => "CopyrightNotice",
+        118 => "Contact",
+        120 => "Caption-Abstract",
+        122 => "Writer-Editor",
+        _ => return format!("Dataset2:{dataset}"),
+    };
+    name.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dataset(number: u8, value: &[u8]) -> Vec<u8> {
+        let len = u16::try_from(value.len()).expect("short value");
+        let mut bytes = vec![DATASET_MARKER, APPLICATION_RECORD, number];
+        bytes.extend_from_slice(&len.to_be_bytes());
+        bytes.extend_from_slice(value);
+        bytes
+    }
+
+    #[test]
+    fn parse_iim_reads_application_record() {
+        let mut data = vec![DATASET_MARKER, 1, 90, 0, 3, 0x1B, 0x25, 0x47]; // envelope record
+        data.extend(dataset(0, &[0, 4])); // record version
+        data.extend(dataset(25, b"beach"));
+        data.extend(dataset(25, b"sunset"));
+        data.extend(dataset(116, "© Jane".as_bytes()));
+
+        let datasets = parse_iim(&data);
+        let summary: Vec<_> = datasets
+            .iter()
+            .map(|d| (d.name.as_str(), d.value.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Keywords", "beach"),
+                ("Keywords", "sunset"),
+                ("CopyrightNotice", "© Jane")
+            ]
+        );
+    }
+
+    #[test]
+    fn find_iptc_resource_skips_other_resources() {
+        let mut resources = Vec::new();
+        // Resource 0x03ED with an empty name and 3 bytes of data (padded to 4)
+        resources.extend_from_slice(b"8BIM\x03\xED\x00\x00\x00\x00\x00\x03abc\x00");
+        resources.extend_from_slice(b"8BIM\x04\x04\x00\x00\x00\x00\x00\x02xy");
+
+        assert_eq!(find_iptc_resource(&resources), Some(&b"xy"[..]));
+    }
+
+    #[test]
+    fn encode_iim_round_trips_fields_and_keeps_other_datasets() {
+        let mut existing = dataset(90, b"Paris");
+        existing.extend(dataset(5, b"Old title"));
+        let fields = IptcFields {
+            title: "Eiffel Tower".to_string(),
+            caption: "Seen from the Trocadéro".to_string(),
+            keywords: vec!["paris".to_string(), "tower".to_string()],
+            creator: "Jane".to_string(),
+            copyright: "© 2025 Jane".to_string(),
+        };
+
+        let iim = encode_iim(&fields, &existing);
+        let datasets = parse_iim(&iim);
+
+        assert_eq!(IptcFields::from_datasets(&datasets), fields);
+        assert!(datasets
+            .iter()
+            .any(|d| d.name == "City" && d.value == "Paris"));
+        assert!(!datasets.iter().any(|d| d.value == "Old title"));
+    }
+
+    #[test]
+    fn encode_iim_truncates_on_char_boundary() {
+        let fields = IptcFields {
+            creator: "é".repeat(20), // 40 bytes, limit is 32
+            ..IptcFields::default()
+        };
+        let datasets = parse_iim(&encode_iim(&fields, &[]));
+        assert_eq!(datasets[0].value, "é".repeat(16));
+    }
+
+    #[test]
+    fn replace_iptc_resource_keeps_other_resources() {
+        let mut resources = Vec::new();
+        resources.extend_from_slice(b"8BIM\x03\xED\x00\x00\x00\x00\x00\x03abc\x00");
+        resources.extend_from_slice(b"8BIM\x04\x04\x00\x00\x00\x00\x00\x02xy");
+
+        let replaced = replace_iptc_resource(&resources, b"new");
+        assert!(replaced.starts_with(&resources[..16]));
+        assert_eq!(find_iptc_resource(&replaced), Some(&b"new"[..]));
+
+        let appended = replace_iptc_resource(&resources[..16], b"iim");
+        assert_eq!(find_iptc_resource(&appended), Some(&b"iim"[..]));
+    }
+
+    #[test]
+    fn extract_iptc_from_jpeg_without_app13_is_empty() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("plain.jpg");
+        std::fs::write(&path, [0xFF, 0xD8, 0xFF, 0xD9]).expect("write file");
+
+        assert!(extract_iptc_from_jpeg(&path).is_empty());
+    }
+}
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: MPL-2.0
+//! One-click looks for the editor's Filters tool.
+//!
+//! A look is a fixed combination of tone and color adjustments
+//! ([`LookParams`]) applied in a single pass over the pixels: saturation,
+//! tint, warmth, contrast, faded blacks and a vignette. [`FilterPreset`]
+//! names the combinations offered in the editor.
+//!
+//! Pixel math converts between u8, u32 and f32. Precision loss is acceptable
+//! for image sizes and 8-bit channel values.
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+
+use image_rs::DynamicImage;
+use serde::{Deserialize, Serialize};
+
+/// Sepia tone, from dark brown shadows to cream highlights.
+const SEPIA_TINT: [u8; 3] = [162, 128, 90];
+
+/// Tone and color adjustments making up a look.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LookParams {
+    /// Color saturation: 0 is grayscale, 1 leaves colors unchanged.
+    pub saturation: f32,
+    /// Color the luminance is mapped onto (sepia, cyanotype, ...).
+    pub tint: Option<[u8; 3]>,
+    /// Color temperature shift, from -100 (cooler) to 100 (warmer).
+    pub warmth: i32,
+    /// Contrast around mid-gray, from -100 to 100.
+    pub contrast: i32,
+    /// How far black is lifted towards gray, from 0 to 255.
+    pub fade: u8,
+    /// How much the corners are darkened, from 0 to 1.
+    pub vignette: f32,
+}
+
+impl Default for LookParams {
+    fn default() -> Self {
+        Self {
+            saturation: 1.0,
+            tint: None,
+            warmth: 0,
+            contrast: 0,
+            fade: 0,
+            vignette: 0.0,
+        }
+    }
+}
+
+/// Looks offered in the editor's Filters tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterPreset {
+    BlackAndWhite,
+    Noir,
+    Sepia,
+    Vignette,
+    WarmFilm,
+    CoolFilm,
+    Faded,
+    Vivid,
+}
+
+impl FilterPreset {
+    /// All presets, in display order.
+    pub const ALL: [FilterPreset; 8] = [
+        FilterPreset::BlackAndWhite,
+        FilterPreset::Noir,
+        FilterPreset::Sepia,
+        FilterPreset::Vignette,
+        FilterPreset::WarmFilm,
+        FilterPreset::CoolFilm,
+        FilterPreset::Faded,
+        FilterPreset::Vivid,
+    ];
+
+    /// Returns the adjustments making up this look.
+    #[must_use]
+    pub fn params(self) -> LookParams {
+        let neutral = LookParams::default();
+        match self {
+            FilterPreset::BlackAndWhite => LookParams {
+                saturation: 0.0,
+                contrast: 10,
+                ..neutral
+            },
+            FilterPreset::Noir => LookParams {
+                saturation: 0.0,
+                contrast: 45,
+                vignette: 0.4,
+                ..neutral
+            },
+            FilterPreset::Sepia => LookParams {
+                saturation: 0.0,
+                tint: Some(SEPIA_TINT),
+                fade: 12,
+                ..neutral
+            },
+            FilterPreset::Vignette => LookParams {
+                vignette: 0.6,
+                ..neutral
+            },
+            FilterPreset::WarmFilm => LookParams {
+                saturation: 0.9,
+                warmth: 30,
+                contrast: -5,
+                fade: 20,
+                vignette: 0.2,
+                ..neutral
+            },
+            FilterPreset::CoolFilm => LookParams {
+                saturation: 0.85,
+                warmth: -25,
+                contrast: 10,
+                fade: 10,
+                ..neutral
+            },
+            FilterPreset::Faded => LookParams {
+                saturation: 0.7,
+                contrast: -15,
+                fade: 40,
+                ..neutral
+            },
+            FilterPreset::Vivid => LookParams {
+                saturation: 1.35,
+                contrast: 15,
+                ..neutral
+            },
+        }
+    }
+
+    /// Returns the i18n key of the preset name.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            FilterPreset::BlackAndWhite => "image-editor-filter-black-and-white",
+            FilterPreset::Noir => "image-editor-filter-noir",
+            FilterPreset::Sepia => "image-editor-filter-sepia",
+            FilterPreset::Vignette => "image-editor-filter-vignette",
+            FilterPreset::WarmFilm => "image-editor-filter-warm-film",
+            FilterPreset::CoolFilm => "image-editor-filter-cool-film",
+            FilterPreset::Faded => "image-editor-filter-faded",
+            FilterPreset::Vivid => "image-editor-filter-vivid",
+        }
+    }
+}
+
+/// Applies `look` to `image`, blended with the original by `strength`
+/// percent (0 to 100).
+///
+/// The alpha channel is kept as is.
+#[must_use]
+pub fn apply_look(image: &DynamicImage, look: &LookParams, strength: u32) -> DynamicImage {
+    if strength == 0 {
+        return image.clone();
+    }
+    let mix = strength.min(100) as f32 / 100.0;
+    let mut rgba = image.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    let center_x = width as f32 / 2.0;
+    let center_y = height as f32 / 2.0;
+    let contrast = 1.0 + look.contrast.clamp(-100, 100) as f32 / 100.0;
+    let warmth = look.warmth.clamp(-100, 100) as f32 / 500.0;
+    let fade = f32::from(look.fade);
+    let tint = look.tint.map(|tint| {
+        let tint = tint.map(f32::from);
+        let tint_luma = luma(tint).max(1.0);
+        tint.map(|channel| channel / tint_luma)
+    });
+
+    for (x, y, pixel) in rgba.enumerate_pixels_mut() {
+        let original = [pixel.0[0], pixel.0[1], pixel.0[2]].map(f32::from);
+        let gray = luma(original);
+        let mut rgb = original.map(|channel| gray + look.saturation * (channel - gray));
+        if let Some(tint) = tint {
+            rgb = tint.map(|channel| gray * channel);
+        }
+        rgb[0] *= 1.0 + warmth;
+        rgb[2] *= 1.0 - warmth;
+        rgb = rgb.map(|channel| (channel - 128.0) * contrast + 128.0);
+        rgb = rgb.map(|channel| fade + channel.clamp(0.0, 255.0) * (255.0 - fade) / 255.0);
+        if look.vignette > 0.0 {
+            // Elliptical distance from the center, 1 in the corners
+            let dx = (x as f32 + 0.5 - center_x) / center_x;
+            let dy = (y as f32 + 0.5 - center_y) / center_y;
+            let falloff = 1.0 - look.vignette.min(1.0) * (dx * dx + dy * dy) / 2.0;
+            rgb = rgb.map(|channel| channel * falloff);
+        }
+        for channel in 0..3 {
+            let blended = original[channel] + (rgb[channel] - original[channel]) * mix;
+            pixel.0[channel] = blended.round().clamp(0.0, 255.0) as u8;
+        }
+    }
+
+    if image.color().has_alpha() {
+        DynamicImage::ImageRgba8(rgba)
+    } else {
+        DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(rgba).to_rgb8())
+    }
+}
+
+/// Returns the Rec. 601 luminance of an RGB color.
+fn luma(rgb: [f32; 3]) -> f32 {
+    0.299 * rgb[0] + 0.587 * rgb[1] + 0.114 * rgb[2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image_rs::{Rgb, RgbImage, Rgba, RgbaImage};
+
+    fn colorful(width: u32, height: u32) -> DynamicImage {
+        DynamicImage::ImageRgb8(RgbImage::from_pixel(width, height, Rgb([200, 80, 40])))
+    }
+
+    #[test]
+    fn black_and_white_removes_color() {
+        let look = FilterPreset::BlackAndWhite.params();
+        let result = apply_look(&colorful(4, 4), &look, 100).to_rgb8();
+        let [r, g, b] = result.get_pixel(1, 1).0;
+        assert_eq!((r, r), (g, b));
+        assert!(!apply_look(&colorful(2, 2), &look, 100).color().has_alpha());
+    }
+
+    #[test]
+    fn sepia_is_warm_toned() {
+        let look = FilterPreset::Sepia.params();
+        let [r, g, b] = apply_look(&colorful(4, 4), &look, 100)
+            .to_rgb8()
+            .get_pixel(1, 1)
+            .0;
+        assert!(r > g && g > b, "sepia pixel is {r},{g},{b}");
+    }
+
+    #[test]
+    fn vignette_darkens_corners_only() {
+        let gray = DynamicImage::ImageRgb8(RgbImage::from_pixel(21, 21, Rgb([150, 150, 150])));
+        let result = apply_look(&gray, &FilterPreset::Vignette.params(), 100).to_rgb8();
+        assert_eq!(result.get_pixel(10, 10).0[0], 150);
+        assert!(result.get_pixel(0, 0).0[0] < 100);
+    }
+
+    #[test]
+    fn strength_blends_with_the_original() {
+        let image = colorful(2, 2);
+        let look = FilterPreset::BlackAndWhite.params();
+        assert_eq!(apply_look(&image, &look, 0), image);
+
+        let full = apply_look(&image, &look, 100).to_rgb8().get_pixel(0, 0).0[0];
+        let half = apply_look(&image, &look, 50).to_rgb8().get_pixel(0, 0).0[0];
+        assert!(half < 200 && half > full, "half strength red is {half}");
+    }
+
+    #[test]
+    fn alpha_is_kept() {
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(2, 2, Rgba([10, 20, 30, 77])));
+        let result = apply_look(&image, &FilterPreset::Faded.params(), 100).to_rgba8();
+        assert_eq!(result.get_pixel(0, 0).0[3], 77);
+    }
+}
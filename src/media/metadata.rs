@@ -6,13 +6,14 @@
 //! and video codec details.
 
 use crate::error::{Error, Result};
-use crate::media::xmp;
+use crate::media::{iptc, xmp};
+use serde::Serialize;
 use std::fs::{self, File};
 use std::io::BufReader;
 use std::path::Path;
 
 /// Image metadata extracted from EXIF and XMP data.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ImageMetadata {
     // File info
     /// Image width in pixels
@@ -23,6 +24,8 @@ pub struct ImageMetadata {
     pub file_size: Option<u64>,
     /// Image format (e.g., "JPEG", "PNG")
     pub format: Option<String>,
+    /// Pixel density in dots per inch, from the EXIF resolution tags
+    pub dots_per_inch: Option<f64>,
 
     // Camera info (EXIF)
     /// Camera manufacturer (e.g., "Canon", "Nikon")
@@ -67,10 +70,44 @@ pub struct ImageMetadata {
     pub dc_subject: Option<Vec<String>>,
     /// dc:rights - Copyright or license information
     pub dc_rights: Option<String>,
+
+    // Raw tags
+    /// Every EXIF, XMP, and IPTC tag found in the file, for the "All tags" view
+    pub all_tags: Vec<MetadataTag>,
+}
+
+/// Maximum length of a tag value in [`MetadataTag`]; longer values are truncated.
+const MAX_TAG_VALUE_CHARS: usize = 256;
+
+/// A single raw metadata tag.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MetadataTag {
+    /// Group the tag belongs to (e.g. "IFD0", "Exif", "GPS", "XMP", "IPTC")
+    pub group: &'static str,
+    /// Tag name (e.g. "DateTimeOriginal", "dc:title", "Keywords")
+    pub name: String,
+    /// Human-readable value
+    pub value: String,
+}
+
+impl MetadataTag {
+    fn new(group: &'static str, name: impl Into<String>, value: &str) -> Self {
+        let value = if value.chars().count() > MAX_TAG_VALUE_CHARS {
+            let truncated: String = value.chars().take(MAX_TAG_VALUE_CHARS).collect();
+            format!("{truncated}…")
+        } else {
+            value.to_string()
+        };
+        Self {
+            group,
+            name: name.into(),
+            value,
+        }
+    }
 }
 
 /// Extended video metadata with codec and format information.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ExtendedVideoMetadata {
     // Basic info (from existing VideoMetadata)
     /// Video width in pixels
@@ -97,10 +134,17 @@ pub struct ExtendedVideoMetadata {
     pub audio_bitrate: Option<u64>,
     /// File size in bytes
     pub file_size: Option<u64>,
+    /// Bits per color component of the decoded frames (8, 10, 12…)
+    pub bit_depth: Option<u8>,
+    /// HDR properties, for HDR10 and HLG videos
+    pub hdr: Option<crate::media::hdr::HdrInfo>,
 }
 
 /// Unified metadata enum for both images and videos.
-#[derive(Debug, Clone)]
+///
+/// Serializes with a `"type"` field set to `"image"` or `"video"`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
 pub enum MediaMetadata {
     /// Image metadata (boxed to reduce enum size variance)
     Image(Box<ImageMetadata>),
@@ -168,6 +212,20 @@ pub fn extract_image_metadata<P: AsRef<Path>>(path: P) -> Result<ImageMetadata>
             metadata.height = field.value.get_uint(0);
         }
 
+        // Pixel density
+        if let Some(field) = exif.get_field(exif::Tag::XResolution, exif::In::PRIMARY) {
+            if let exif::Value::Rational(rationals) = &field.value {
+                // ResolutionUnit defaults to inches when missing
+                let unit = exif
+                    .get_field(exif::Tag::ResolutionUnit, exif::In::PRIMARY)
+                    .and_then(|unit| unit.value.get_uint(0))
+                    .unwrap_or(2);
+                metadata.dots_per_inch = rationals
+                    .first()
+                    .and_then(|resolution| dots_per_inch(resolution.to_f64(), unit));
+            }
+        }
+
         // Camera info
         if let Some(field) = exif.get_field(exif::Tag::Make, exif::In::PRIMARY) {
             metadata.camera_make = Some(
@@ -236,6 +294,14 @@ pub fn extract_image_metadata<P: AsRef<Path>>(path: P) -> Result<ImageMetadata>
 
         // GPS coordinates
         extract_gps_coordinates(&exif, &mut metadata);
+
+        metadata.all_tags.extend(exif.fields().map(|field| {
+            MetadataTag::new(
+                exif_group(field),
+                field.tag.to_string(),
+                &field.display_value().with_unit(&exif).to_string(),
+            )
+        }));
     }
 
     // Try to extract XMP Dublin Core metadata
@@ -257,9 +323,82 @@ pub fn extract_image_metadata<P: AsRef<Path>>(path: P) -> Result<ImageMetadata>
         }
     }
 
+    metadata.all_tags.extend(
+        xmp::extract_xmp_properties(path)
+            .into_iter()
+            .map(|(name, value)| MetadataTag::new("XMP", name, &value)),
+    );
+    let iptc_datasets = iptc::extract_iptc_from_jpeg(path);
+    apply_iptc_fallback(
+        &mut metadata,
+        iptc::IptcFields::from_datasets(&iptc_datasets),
+    );
+    metadata.all_tags.extend(
+        iptc_datasets
+            .into_iter()
+            .map(|dataset| MetadataTag::new("IPTC", dataset.name, &dataset.value)),
+    );
+
     Ok(metadata)
 }
 
+/// Fill descriptive fields missing from XMP with their IPTC-IIM values.
+fn apply_iptc_fallback(metadata: &mut ImageMetadata, fields: iptc::IptcFields) {
+    let non_empty = |value: String| (!value.is_empty()).then_some(value);
+
+    if metadata.dc_title.is_none() {
+        metadata.dc_title = non_empty(fields.title);
+    }
+    if metadata.dc_creator.is_none() {
+        metadata.dc_creator = non_empty(fields.creator);
+    }
+    if metadata.dc_description.is_none() {
+        metadata.dc_description = non_empty(fields.caption);
+    }
+    if metadata.dc_subject.is_none() && !fields.keywords.is_empty() {
+        metadata.dc_subject = Some(fields.keywords);
+    }
+    if metadata.dc_rights.is_none() {
+        metadata.dc_rights = non_empty(fields.copyright);
+    }
+}
+
+/// Returns the display group (IFD) of an EXIF field.
+fn exif_group(field: &exif::Field) -> &'static str {
+    match field.tag.context() {
+        exif::Context::Tiff if field.ifd_num == exif::In::THUMBNAIL => "IFD1",
+        exif::Context::Tiff => "IFD0",
+        exif::Context::Exif => "Exif",
+        exif::Context::Gps => "GPS",
+        exif::Context::Interop => "Interop",
+    }
+}
+
+/// Filters tags by a case-insensitive query on group, name, or value,
+/// then groups them while keeping the order in which groups first appear.
+#[must_use]
+pub fn group_tags<'a>(
+    tags: &'a [MetadataTag],
+    query: &str,
+) -> Vec<(&'static str, Vec<&'a MetadataTag>)> {
+    let query = query.trim().to_lowercase();
+    let mut groups: Vec<(&'static str, Vec<&MetadataTag>)> = Vec::new();
+
+    for tag in tags.iter().filter(|tag| {
+        query.is_empty()
+            || tag.group.to_lowercase().contains(&query)
+            || tag.name.to_lowercase().contains(&query)
+            || tag.value.to_lowercase().contains(&query)
+    }) {
+        match groups.iter_mut().find(|(group, _)| *group == tag.group) {
+            Some((_, members)) => members.push(tag),
+            None => groups.push((tag.group, vec![tag])),
+        }
+    }
+
+    groups
+}
+
 /// Extract GPS coordinates from EXIF data.
 fn extract_gps_coordinates(exif: &exif::Exif, metadata: &mut ImageMetadata) {
     // Get latitude
@@ -285,6 +424,17 @@ fn extract_gps_coordinates(exif: &exif::Exif, metadata: &mut ImageMetadata) {
     }
 }
 
+/// Converts an EXIF resolution to dots per inch. `unit` is the EXIF
+/// `ResolutionUnit`: 2 for inches, 3 for centimeters.
+fn dots_per_inch(resolution: f64, unit: u32) -> Option<f64> {
+    let dpi = match unit {
+        2 => resolution,
+        3 => resolution * 2.54,
+        _ => return None,
+    };
+    (dpi.is_finite() && dpi > 0.0).then_some(dpi)
+}
+
 /// Parse GPS coordinate from EXIF rational values (degrees, minutes, seconds).
 fn parse_gps_coordinate(value: &exif::Value) -> Option<f64> {
     match value {
@@ -342,6 +492,11 @@ pub fn extract_extended_video_metadata<P: AsRef<Path>>(path: P) -> Result<Extend
                         .codec()
                         .map_or_else(|| "Unknown".to_string(), |c| c.name().to_string()),
                 );
+
+                metadata.bit_depth = decoder.format().descriptor().map(|descriptor| {
+                    crate::media::frame_export::pixel_format_depth(descriptor.name())
+                });
+                metadata.hdr = crate::media::hdr::read_hdr_info(&video_stream, &decoder);
             }
         }
 
@@ -457,6 +612,37 @@ pub fn format_gps_coordinates(lat: f64, lon: f64) -> String {
     )
 }
 
+/// Format GPS coordinates as degrees, minutes and seconds (e.g. `48°51′24″ N, 2°21′08″ E`).
+#[must_use]
+pub fn format_gps_dms(lat: f64, lon: f64) -> String {
+    let lat_dir = if lat >= 0.0 { "N" } else { "S" };
+    let lon_dir = if lon >= 0.0 { "E" } else { "W" };
+    format!(
+        "{} {}, {} {}",
+        format_dms(lat.abs()),
+        lat_dir,
+        format_dms(lon.abs()),
+        lon_dir
+    )
+}
+
+/// Formats an absolute coordinate as `D°MM′SS″`, rounded to the nearest second.
+// Allow cast_possible_truncation/cast_sign_loss: coordinates are absolute and at most 180°.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_dms(value: f64) -> String {
+    let total_seconds = (value * 3600.0).round() as u64;
+    let degrees = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let seconds = total_seconds % 60;
+    format!("{degrees}°{minutes:02}′{seconds:02}″")
+}
+
+/// Returns an `OpenStreetMap` URL with a marker at the given coordinates.
+#[must_use]
+pub fn map_url(lat: f64, lon: f64) -> String {
+    format!("https://www.openstreetmap.org/?mlat={lat:.6}&mlon={lon:.6}#map=15/{lat:.6}/{lon:.6}")
+}
+
 /// Extract metadata from a media file, automatically detecting the media type.
 ///
 /// Uses file extension to determine whether to extract image or video metadata.
@@ -511,6 +697,73 @@ mod tests {
         assert_eq!(format_bitrate(5_000_000), "5.00 Mbps");
     }
 
+    #[test]
+    fn dots_per_inch_converts_centimeters() {
+        assert_eq!(dots_per_inch(300.0, 2), Some(300.0));
+        let dpi = dots_per_inch(100.0, 3).expect("centimeters");
+        assert!((dpi - 254.0).abs() < 1e-9);
+        assert_eq!(dots_per_inch(72.0, 1), None);
+        assert_eq!(dots_per_inch(0.0, 2), None);
+    }
+
+    #[test]
+    fn metadata_tag_truncates_long_values() {
+        let tag = MetadataTag::new("Exif", "MakerNote", &"x".repeat(300));
+        assert_eq!(tag.value.chars().count(), MAX_TAG_VALUE_CHARS + 1);
+        assert!(tag.value.ends_with('…'));
+    }
+
+    #[test]
+    fn group_tags_filters_and_keeps_group_order() {
+        let tags = vec![
+            MetadataTag::new("IFD0", "Make", "Canon"),
+            MetadataTag::new("Exif", "LensModel", "EF 50mm"),
+            MetadataTag::new("IFD0", "Model", "EOS R5"),
+            MetadataTag::new("XMP", "dc:title", "Lens test"),
+        ];
+
+        let all = group_tags(&tags, "");
+        let names: Vec<_> = all.iter().map(|(g, t)| (*g, t.len())).collect();
+        assert_eq!(names, vec![("IFD0", 2), ("Exif", 1), ("XMP", 1)]);
+
+        let lens = group_tags(&tags, " LENS ");
+        let names: Vec<_> = lens.iter().map(|(g, t)| (*g, t.len())).collect();
+        assert_eq!(names, vec![("Exif", 1), ("XMP", 1)]);
+    }
+
+    #[test]
+    fn apply_iptc_fallback_keeps_xmp_values() {
+        let mut metadata = ImageMetadata {
+            dc_title: Some("XMP title".to_string()),
+            ..Default::default()
+        };
+        let fields = iptc::IptcFields {
+            title: "IPTC title".to_string(),
+            keywords: vec!["sea".to_string()],
+            ..Default::default()
+        };
+
+        apply_iptc_fallback(&mut metadata, fields);
+
+        assert_eq!(metadata.dc_title.as_deref(), Some("XMP title"));
+        assert_eq!(metadata.dc_subject, Some(vec!["sea".to_string()]));
+        assert_eq!(metadata.dc_creator, None);
+    }
+
+    #[test]
+    fn format_gps_dms_formats_correctly() {
+        assert_eq!(format_gps_dms(48.8566, 2.3522), "48°51′24″ N, 2°21′08″ E");
+        assert_eq!(format_gps_dms(-33.8688, -70.0), "33°52′08″ S, 70°00′00″ W");
+    }
+
+    #[test]
+    fn map_url_places_marker_at_coordinates() {
+        assert_eq!(
+            map_url(48.8566, -2.3522),
+            "https://www.openstreetmap.org/?mlat=48.856600&mlon=-2.352200#map=15/48.856600/-2.352200"
+        );
+    }
+
     #[test]
     fn format_gps_coordinates_formats_correctly() {
         assert_eq!(
@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Batch metadata editing across several files.
+//!
+//! A [`BatchMetadataChanges`] describes edits that make sense for a whole
+//! series of photos (creator, copyright, extra keywords, GPS stripping).
+//! [`apply_to_files`] merges them into each file's existing descriptive
+//! metadata and writes the result, collecting per-file errors instead of
+//! stopping at the first failure.
+
+use crate::media::metadata::{self, MediaMetadata};
+use crate::media::metadata_writer::{self, EditableMetadata};
+use std::path::{Path, PathBuf};
+
+/// Changes applied to every file of a batch. Empty fields are left untouched.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchMetadataChanges {
+    /// Replaces the creator (dc:creator / IPTC By-line) when non-empty.
+    pub creator: String,
+    /// Replaces the copyright notice (dc:rights) when non-empty.
+    pub copyright: String,
+    /// Keywords added to each file's existing keywords.
+    pub add_keywords: Vec<String>,
+    /// Removes all GPS tags.
+    pub strip_gps: bool,
+}
+
+impl BatchMetadataChanges {
+    /// Returns true if applying these changes would not modify anything.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.creator.trim().is_empty()
+            && self.copyright.trim().is_empty()
+            && self.add_keywords.iter().all(|k| k.trim().is_empty())
+            && !self.strip_gps
+    }
+
+    /// Merges the changes into the descriptive fields of an existing file.
+    ///
+    /// Only Dublin Core fields are carried over from `existing`: EXIF fields
+    /// are left empty so the writer preserves the values already in the file.
+    #[must_use]
+    pub fn apply(&self, existing: &EditableMetadata) -> EditableMetadata {
+        let mut keywords: Vec<String> = existing.keywords().into_iter().map(String::from).collect();
+        for keyword in self.add_keywords.iter().map(|k| k.trim()) {
+            if !keyword.is_empty() && !keywords.iter().any(|k| k.eq_ignore_ascii_case(keyword)) {
+                keywords.push(keyword.to_string());
+            }
+        }
+
+        EditableMetadata {
+            strip_gps: self.strip_gps,
+            dc_title: existing.dc_title.clone(),
+            dc_creator: non_empty_or(&self.creator, &existing.dc_creator),
+            dc_description: existing.dc_description.clone(),
+            dc_subject: keywords.join(", "),
+            dc_rights: non_empty_or(&self.copyright, &existing.dc_rights),
+            ..EditableMetadata::default()
+        }
+    }
+}
+
+/// A file that could not be updated, with the reason.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchFailure {
+    pub path: PathBuf,
+    pub error: String,
+}
+
+/// Outcome of a batch run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchSummary {
+    /// Number of files written successfully.
+    pub updated: usize,
+    /// Number of files skipped because their format cannot hold metadata.
+    pub skipped: usize,
+    /// Files that failed to update.
+    pub failures: Vec<BatchFailure>,
+}
+
+/// Applies `changes` to every file in `paths`.
+///
+/// Videos and other formats the writer does not support are skipped. This
+/// function performs blocking I/O and should run off the UI thread.
+#[must_use]
+pub fn apply_to_files(paths: &[PathBuf], changes: &BatchMetadataChanges) -> BatchSummary {
+    let mut summary = BatchSummary::default();
+
+    for path in paths {
+        if !metadata_writer::is_format_supported(path) {
+            summary.skipped += 1;
+            continue;
+        }
+        match apply_to_file(path, changes) {
+            Ok(()) => summary.updated += 1,
+            Err(error) => summary.failures.push(BatchFailure {
+                path: path.clone(),
+                error,
+            }),
+        }
+    }
+
+    summary
+}
+
+/// Applies `changes` to a single file.
+fn apply_to_file(path: &Path, changes: &BatchMetadataChanges) -> Result<(), String> {
+    let existing = match metadata::extract_metadata(path) {
+        Some(MediaMetadata::Image(meta)) => EditableMetadata::from_image_metadata(&meta),
+        _ => EditableMetadata::default(),
+    };
+    metadata_writer::write_exif(path, &changes.apply(&existing)).map_err(|e| e.to_string())
+}
+
+/// Returns `value` trimmed if non-empty, otherwise `fallback`.
+fn non_empty_or(value: &str, fallback: &str) -> String {
+    let value = value.trim();
+    if value.is_empty() {
+        fallback.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn existing() -> EditableMetadata {
+        EditableMetadata {
+            camera_make: "Canon".to_string(),
+            dc_title: "Harbour".to_string(),
+            dc_creator: "Alice".to_string(),
+            dc_subject: "boats, Sea".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn empty_changes_are_detected() {
+        assert!(BatchMetadataChanges::default().is_empty());
+        assert!(BatchMetadataChanges {
+            add_keywords: vec!["  ".to_string()],
+            ..Default::default()
+        }
+        .is_empty());
+        assert!(!BatchMetadataChanges {
+            strip_gps: true,
+            ..Default::default()
+        }
+        .is_empty());
+    }
+
+    #[test]
+    fn apply_merges_keywords_and_keeps_unchanged_fields() {
+        let changes = BatchMetadataChanges {
+            copyright: "© 2025 Bob".to_string(),
+            add_keywords: vec!["sea".to_string(), "summer".to_string()],
+            ..Default::default()
+        };
+
+        let result = changes.apply(&existing());
+
+        assert_eq!(result.dc_title, "Harbour");
+        assert_eq!(result.dc_creator, "Alice");
+        assert_eq!(result.dc_rights, "© 2025 Bob");
+        assert_eq!(result.dc_subject, "boats, Sea, summer");
+        assert!(
+            result.camera_make.is_empty(),
+            "EXIF is preserved by the writer"
+        );
+        assert!(!result.strip_gps);
+    }
+
+    #[test]
+    fn apply_to_files_collects_failures_and_skips_unsupported() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let good = dir.path().join("good.png");
+        image_rs::RgbImage::from_pixel(4, 4, image_rs::Rgb([10, 20, 30]))
+            .save(&good)
+            .expect("write png");
+        let video = dir.path().join("clip.mp4");
+        std::fs::write(&video, b"not a video").expect("write video");
+        let broken = dir.path().join("broken.jpg");
+        std::fs::write(&broken, b"not a jpeg").expect("write jpeg");
+
+        let changes = BatchMetadataChanges {
+            creator: "Bob".to_string(),
+            ..Default::default()
+        };
+        let summary = apply_to_files(&[good.clone(), video, broken.clone()], &changes);
+
+        assert_eq!(summary.updated, 1);
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(summary.failures.len(), 1);
+        assert_eq!(summary.failures[0].path, broken);
+
+        let xmp = crate::media::xmp::extract_xmp_from_png(&good).expect("xmp written");
+        assert_eq!(xmp.creator.as_deref(), Some("Bob"));
+    }
+}
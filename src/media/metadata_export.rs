@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Export of media metadata to JSON or CSV, for cataloging pipelines.
+//!
+//! JSON keeps everything [`MediaMetadata`] holds, including every raw tag,
+//! as an array of `{ "path": ..., "metadata": ... }` objects (`metadata` is
+//! `null` for unreadable files). CSV has one row per file with the common
+//! fields of images and videos in fixed columns, for spreadsheets.
+
+use crate::error::{Error, Result};
+use crate::media::metadata::{extract_metadata, MediaMetadata};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Columns of the CSV export, in order.
+pub const CSV_COLUMNS: &[&str] = &[
+    "path",
+    "type",
+    "width",
+    "height",
+    "file_size",
+    "format",
+    "camera_make",
+    "camera_model",
+    "date_taken",
+    "exposure_time",
+    "aperture",
+    "iso",
+    "focal_length",
+    "gps_latitude",
+    "gps_longitude",
+    "title",
+    "creator",
+    "description",
+    "keywords",
+    "rights",
+    "duration_secs",
+    "fps",
+    "video_codec",
+    "audio_codec",
+    "video_bitrate",
+    "audio_bitrate",
+];
+
+/// File format of an export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// Returns CSV for `.csv` paths and JSON for anything else.
+    #[must_use]
+    pub fn from_path(path: &Path) -> Self {
+        if path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"))
+        {
+            Self::Csv
+        } else {
+            Self::Json
+        }
+    }
+}
+
+/// Metadata of one exported file.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub path: String,
+    pub metadata: Option<MediaMetadata>,
+}
+
+/// Reads the metadata of every file of `paths`.
+///
+/// This performs blocking I/O and should run off the UI thread.
+#[must_use]
+pub fn collect(paths: &[PathBuf]) -> Vec<Record> {
+    paths
+        .iter()
+        .map(|path| Record {
+            path: path.to_string_lossy().into_owned(),
+            metadata: extract_metadata(path),
+        })
+        .collect()
+}
+
+/// Serializes `records` as a pretty-printed JSON array.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn to_json(records: &[Record]) -> Result<String> {
+    serde_json::to_string_pretty(records).map_err(|e| Error::Io(e.to_string()))
+}
+
+/// Serializes `records` as CSV with a header row of [`CSV_COLUMNS`].
+#[must_use]
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv = csv_line(CSV_COLUMNS.iter().map(ToString::to_string));
+    for record in records {
+        csv.push_str(&csv_line(csv_row(record)));
+    }
+    csv
+}
+
+/// Writes the metadata of `paths` to `output`, in the format of its
+/// extension, and returns the number of files exported.
+///
+/// # Errors
+///
+/// Returns an error if the export cannot be serialized or written.
+pub fn export(paths: &[PathBuf], output: &Path) -> Result<usize> {
+    let records = collect(paths);
+    let content = match Format::from_path(output) {
+        Format::Json => to_json(&records)?,
+        Format::Csv => to_csv(&records),
+    };
+    fs::write(output, content)?;
+    Ok(records.len())
+}
+
+/// Returns the values of `record` for [`CSV_COLUMNS`].
+fn csv_row(record: &Record) -> Vec<String> {
+    fn text<T: ToString>(value: Option<T>) -> String {
+        value.map(|value| value.to_string()).unwrap_or_default()
+    }
+
+    let mut row = vec![record.path.clone()];
+    match &record.metadata {
+        None => row.resize(CSV_COLUMNS.len(), String::new()),
+        Some(MediaMetadata::Image(image)) => {
+            row.extend([
+                "image".to_string(),
+                text(image.width),
+                text(image.height),
+                text(image.file_size),
+                text(image.format.as_ref()),
+                text(image.camera_make.as_ref()),
+                text(image.camera_model.as_ref()),
+                text(image.date_taken.as_ref()),
+                text(image.exposure_time.as_ref()),
+                text(image.aperture.as_ref()),
+                text(image.iso.as_ref()),
+                text(image.focal_length.as_ref()),
+                text(image.gps_latitude),
+                text(image.gps_longitude),
+                text(image.dc_title.as_ref()),
+                text(image.dc_creator.as_ref()),
+                text(image.dc_description.as_ref()),
+                image
+                    .dc_subject
+                    .as_ref()
+                    .map(|keywords| keywords.join("; "))
+                    .unwrap_or_default(),
+                text(image.dc_rights.as_ref()),
+            ]);
+            row.resize(CSV_COLUMNS.len(), String::new());
+        }
+        Some(MediaMetadata::Video(video)) => {
+            row.extend([
+                "video".to_string(),
+                video.width.to_string(),
+                video.height.to_string(),
+                text(video.file_size),
+                text(video.container_format.as_ref()),
+            ]);
+            // No camera, exposure, location or Dublin Core fields
+            row.resize(CSV_COLUMNS.len() - 6, String::new());
+            row.extend([
+                video.duration_secs.to_string(),
+                video.fps.to_string(),
+                text(video.video_codec.as_ref()),
+                text(video.audio_codec.as_ref()),
+                text(video.video_bitrate),
+                text(video.audio_bitrate),
+            ]);
+        }
+    }
+    row
+}
+
+/// Joins `fields` into one CSV line, quoting fields that need it.
+fn csv_line(fields: impl Iterator<Item = String>) -> String {
+    let mut line = fields
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push_str("\r\n");
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::media::metadata::{ExtendedVideoMetadata, ImageMetadata};
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                path: "trip/a.jpg".to_string(),
+                metadata: Some(MediaMetadata::Image(Box::new(ImageMetadata {
+                    width: Some(4000),
+                    height: Some(3000),
+                    camera_make: Some("Canon".to_string()),
+                    dc_title: Some("Sunset, \"golden\" hour".to_string()),
+                    dc_subject: Some(vec!["sea".to_string(), "sky".to_string()]),
+                    ..ImageMetadata::default()
+                }))),
+            },
+            Record {
+                path: "trip/b.mp4".to_string(),
+                metadata: Some(MediaMetadata::Video(ExtendedVideoMetadata {
+                    width: 1920,
+                    height: 1080,
+                    duration_secs: 12.5,
+                    video_codec: Some("H.264".to_string()),
+                    ..ExtendedVideoMetadata::default()
+                })),
+            },
+            Record {
+                path: "trip/broken.png".to_string(),
+                metadata: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn format_follows_extension() {
+        assert_eq!(Format::from_path(Path::new("out.CSV")), Format::Csv);
+        assert_eq!(Format::from_path(Path::new("out.json")), Format::Json);
+        assert_eq!(Format::from_path(Path::new("out")), Format::Json);
+    }
+
+    #[test]
+    fn csv_rows_fill_every_column_and_quote_fields() {
+        let csv = to_csv(&records());
+        let lines: Vec<&str> = csv.split("\r\n").filter(|line| !line.is_empty()).collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("path,type,width,height"));
+        assert!(lines[1].starts_with("trip/a.jpg,image,4000,3000,,,Canon,"));
+        assert!(lines[1].contains(",\"Sunset, \"\"golden\"\" hour\",,,sea; sky,"));
+        assert!(lines[2].starts_with("trip/b.mp4,video,1920,1080,"));
+        assert!(lines[2].contains(",12.5,0,H.264,"));
+        assert_eq!(
+            lines[3],
+            format!("trip/broken.png{}", ",".repeat(CSV_COLUMNS.len() - 1))
+        );
+        for record in &records() {
+            assert_eq!(csv_row(record).len(), CSV_COLUMNS.len());
+        }
+    }
+
+    #[test]
+    fn json_tags_the_media_type() {
+        let json = to_json(&records()).expect("serialize");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+        assert_eq!(value[0]["path"], "trip/a.jpg");
+        assert_eq!(value[0]["metadata"]["type"], "image");
+        assert_eq!(value[0]["metadata"]["camera_make"], "Canon");
+        assert_eq!(value[1]["metadata"]["type"], "video");
+        assert_eq!(value[1]["metadata"]["video_codec"], "H.264");
+        assert!(value[2]["metadata"].is_null());
+    }
+}
@@ -0,0 +1,472 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Removal of all metadata from image files (privacy action).
+//!
+//! Metadata blocks are dropped at the container level so image data is
+//! copied byte for byte (no re-encoding, no quality loss):
+//!
+//! - **JPEG**: APP1 (EXIF with its thumbnail, XMP), APP13 (IPTC), COM, JFXX
+//!   thumbnails and vendor APP segments. JFIF, ICC profile and Adobe APP14
+//!   segments are kept because they affect how pixels are decoded.
+//! - **PNG**: `eXIf`, `tEXt`, `zTXt`, `iTXt` (XMP) and `tIME` chunks.
+//! - **WebP**: `EXIF` and `XMP ` chunks, with the VP8X flags updated.
+//! - **TIFF**: metadata lives in the IFD next to the image structure, so the
+//!   image is decoded and re-encoded losslessly instead.
+
+use crate::error::{Error, Result};
+use std::path::{Path, PathBuf};
+
+/// A kind of metadata block found in a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetadataBlock {
+    Exif,
+    Xmp,
+    Iptc,
+    Thumbnail,
+    Comment,
+}
+
+impl MetadataBlock {
+    /// Returns the i18n key of the block's display name.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            MetadataBlock::Exif => "metadata-strip-block-exif",
+            MetadataBlock::Xmp => "metadata-strip-block-xmp",
+            MetadataBlock::Iptc => "metadata-strip-block-iptc",
+            MetadataBlock::Thumbnail => "metadata-strip-block-thumbnail",
+            MetadataBlock::Comment => "metadata-strip-block-comment",
+        }
+    }
+}
+
+/// Image extensions supported by [`strip_metadata`].
+pub const STRIP_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "webp", "tiff", "tif"];
+
+/// Returns true if metadata can be stripped from this file type.
+#[must_use]
+pub fn is_format_supported(path: &Path) -> bool {
+    extension(path).is_some_and(|ext| STRIP_EXTENSIONS.contains(&ext.as_str()))
+}
+
+/// Lists the metadata blocks present in a file, in display order.
+///
+/// Returns an empty list for unsupported or unreadable files.
+#[must_use]
+pub fn detect_blocks(path: &Path) -> Vec<MetadataBlock> {
+    let Ok(data) = std::fs::read(path) else {
+        return Vec::new();
+    };
+    let mut blocks = match extension(path).as_deref() {
+        Some("jpg" | "jpeg") => jpeg_blocks(&data),
+        Some("png") => png_blocks(&data),
+        Some("webp") => webp_blocks(&data),
+        Some("tiff" | "tif") => tiff_blocks(path),
+        _ => Vec::new(),
+    };
+    blocks.sort_by_key(|block| *block as u8);
+    blocks.dedup();
+    blocks
+}
+
+/// Writes a copy of `source` without metadata to `destination`.
+///
+/// `destination` may equal `source` for an in-place write; the file is then
+/// replaced atomically through a temporary file in the same directory.
+///
+/// # Errors
+///
+/// Returns an error if the format is unsupported, the file is malformed, or
+/// it cannot be read or written.
+pub fn strip_metadata(source: &Path, destination: &Path) -> Result<()> {
+    let data = std::fs::read(source)?;
+    let cleaned = match extension(source).as_deref() {
+        Some("jpg" | "jpeg") => strip_jpeg(&data)?,
+        Some("png") => strip_png(&data)?,
+        Some("webp") => strip_webp(&data)?,
+        Some("tiff" | "tif") => strip_tiff(&data)?,
+        _ => {
+            return Err(Error::Io(
+                "Removing metadata is not supported for this format".to_string(),
+            ))
+        }
+    };
+
+    let temp_path = destination.with_extension("iced_lens_tmp");
+    std::fs::write(&temp_path, cleaned)?;
+    std::fs::rename(&temp_path, destination).map_err(|e| {
+        let _ = std::fs::remove_file(&temp_path);
+        Error::from(e)
+    })
+}
+
+/// Where the cleaned file is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StripDestination {
+    /// Overwrite the original file.
+    InPlace,
+    /// Write a new file next to the original (see [`clean_copy_path`]).
+    CleanCopy,
+}
+
+impl StripDestination {
+    /// Returns the path the cleaned file will be written to.
+    #[must_use]
+    pub fn resolve(self, source: &Path) -> PathBuf {
+        match self {
+            StripDestination::InPlace => source.to_path_buf(),
+            StripDestination::CleanCopy => clean_copy_path(source),
+        }
+    }
+}
+
+/// Returns a free path next to `source` for a cleaned copy (`photo_clean.jpg`,
+/// then `photo_clean_2.jpg`, …).
+#[must_use]
+pub fn clean_copy_path(source: &Path) -> PathBuf {
+    let stem = source
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("image");
+    let ext = source.extension().and_then(|s| s.to_str()).unwrap_or("");
+    let dir = source.parent().unwrap_or_else(|| Path::new("."));
+
+    let mut candidate = dir.join(format!("{stem}_clean.{ext}"));
+    let mut index = 2;
+    while candidate.exists() {
+        candidate = dir.join(format!("{stem}_clean_{index}.{ext}"));
+        index += 1;
+    }
+    candidate
+}
+
+fn extension(path: &Path) -> Option<String> {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase)
+}
+
+// =============================================================================
+// JPEG
+// =============================================================================
+
+/// Iterates over JPEG marker segments before the scan data.
+///
+/// Calls `visit(marker, segment)` where `segment` includes the marker bytes.
+/// Returns the offset of the SOS marker (start of the entropy-coded data).
+fn walk_jpeg_segments(data: &[u8], mut visit: impl FnMut(u8, &[u8])) -> Result<usize> {
+    if data.len() < 4 || data[0] != 0xFF || data[1] != 0xD8 {
+        return Err(Error::Io("Not a valid JPEG file".to_string()));
+    }
+
+    let mut pos = 2;
+    while pos + 4 <= data.len() {
+        if data[pos] != 0xFF {
+            return Err(Error::Io("Invalid JPEG structure".to_string()));
+        }
+        let marker = data[pos + 1];
+        if marker == 0xFF {
+            // Fill byte before a marker
+            pos += 1;
+            continue;
+        }
+        if marker == 0xDA || marker == 0xD9 {
+            return Ok(pos);
+        }
+        let segment_len = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+        let segment_end = pos + 2 + segment_len;
+        if segment_len < 2 || segment_end > data.len() {
+            return Err(Error::Io("Invalid JPEG segment length".to_string()));
+        }
+        visit(marker, &data[pos..segment_end]);
+        pos = segment_end;
+    }
+
+    Err(Error::Io("JPEG scan data not found".to_string()))
+}
+
+/// Classifies a JPEG segment, or returns `None` if it must be kept.
+fn jpeg_segment_block(marker: u8, segment: &[u8]) -> Option<MetadataBlock> {
+    let payload = &segment[4..];
+    match marker {
+        0xE0 if payload.starts_with(b"JFXX\0") => Some(MetadataBlock::Thumbnail),
+        0xE0 => None, // JFIF header
+        0xE1 if payload.starts_with(b"Exif\0") => Some(MetadataBlock::Exif),
+        0xE1 => Some(MetadataBlock::Xmp),
+        0xE2 if payload.starts_with(b"ICC_PROFILE\0") => None,
+        0xE2 => Some(MetadataBlock::Thumbnail), // MPF preview images
+        0xED => Some(MetadataBlock::Iptc),
+        0xEE => None, // Adobe color transform
+        0xFE => Some(MetadataBlock::Comment),
+        0xE3..=0xEF => Some(MetadataBlock::Exif), // Vendor maker data
+        _ => None,
+    }
+}
+
+fn jpeg_blocks(data: &[u8]) -> Vec<MetadataBlock> {
+    let mut blocks = Vec::new();
+    let _ = walk_jpeg_segments(data, |marker, segment| {
+        if let Some(block) = jpeg_segment_block(marker, segment) {
+            blocks.push(block);
+            // EXIF embeds a thumbnail in IFD1 when it has one
+            if block == MetadataBlock::Exif && segment.windows(2).any(|w| w == [0xFF, 0xD8]) {
+                blocks.push(MetadataBlock::Thumbnail);
+            }
+        }
+    });
+    blocks
+}
+
+fn strip_jpeg(data: &[u8]) -> Result<Vec<u8>> {
+    let mut result = Vec::with_capacity(data.len());
+    result.extend_from_slice(&data[..2]);
+    let scan_start = walk_jpeg_segments(data, |marker, segment| {
+        if jpeg_segment_block(marker, segment).is_none() {
+            result.extend_from_slice(segment);
+        }
+    })?;
+    result.extend_from_slice(&data[scan_start..]);
+    Ok(result)
+}
+
+// =============================================================================
+// PNG
+// =============================================================================
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Iterates over PNG chunks, calling `visit(type, chunk)` with the full chunk.
+fn walk_png_chunks(data: &[u8], mut visit: impl FnMut(&[u8], &[u8])) -> Result<()> {
+    if !data.starts_with(PNG_SIGNATURE) {
+        return Err(Error::Io("Not a valid PNG file".to_string()));
+    }
+    let mut pos = PNG_SIGNATURE.len();
+    while pos + 12 <= data.len() {
+        let chunk_len =
+            u32::from_be_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]]) as usize;
+        let chunk_end = pos + 12 + chunk_len;
+        if chunk_end > data.len() {
+            return Err(Error::Io("Invalid PNG chunk structure".to_string()));
+        }
+        visit(&data[pos + 4..pos + 8], &data[pos..chunk_end]);
+        pos = chunk_end;
+    }
+    Ok(())
+}
+
+fn png_chunk_block(chunk_type: &[u8], chunk: &[u8]) -> Option<MetadataBlock> {
+    match chunk_type {
+        b"eXIf" => Some(MetadataBlock::Exif),
+        b"iTXt" if chunk[8..].starts_with(b"XML:com.adobe.xmp\0") => Some(MetadataBlock::Xmp),
+        b"tEXt" | b"zTXt" | b"iTXt" | b"tIME" => Some(MetadataBlock::Comment),
+        _ => None,
+    }
+}
+
+fn png_blocks(data: &[u8]) -> Vec<MetadataBlock> {
+    let mut blocks = Vec::new();
+    let _ = walk_png_chunks(data, |chunk_type, chunk| {
+        blocks.extend(png_chunk_block(chunk_type, chunk));
+    });
+    blocks
+}
+
+fn strip_png(data: &[u8]) -> Result<Vec<u8>> {
+    let mut result = Vec::with_capacity(data.len());
+    result.extend_from_slice(PNG_SIGNATURE);
+    walk_png_chunks(data, |chunk_type, chunk| {
+        if png_chunk_block(chunk_type, chunk).is_none() {
+            result.extend_from_slice(chunk);
+        }
+    })?;
+    Ok(result)
+}
+
+// =============================================================================
+// WebP
+// =============================================================================
+
+/// VP8X flag bits for EXIF and XMP presence.
+const VP8X_EXIF_FLAG: u8 = 0x08;
+const VP8X_XMP_FLAG: u8 = 0x04;
+
+/// Iterates over RIFF chunks of a WebP file, calling `visit(fourcc, chunk)`.
+fn walk_webp_chunks(data: &[u8], mut visit: impl FnMut(&[u8], &[u8])) -> Result<()> {
+    if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"WEBP" {
+        return Err(Error::Io("Not a valid WebP file".to_string()));
+    }
+    let mut pos = 12;
+    while pos + 8 <= data.len() {
+        let chunk_size =
+            u32::from_le_bytes([data[pos + 4], data[pos + 5], data[pos + 6], data[pos + 7]])
+                as usize;
+        let chunk_end = pos + 8 + chunk_size + (chunk_size & 1);
+        if chunk_end > data.len() {
+            return Err(Error::Io("Invalid WebP chunk structure".to_string()));
+        }
+        visit(&data[pos..pos + 4], &data[pos..chunk_end]);
+        pos = chunk_end;
+    }
+    Ok(())
+}
+
+fn webp_chunk_block(fourcc: &[u8]) -> Option<MetadataBlock> {
+    match fourcc {
+        b"EXIF" => Some(MetadataBlock::Exif),
+        b"XMP " => Some(MetadataBlock::Xmp),
+        _ => None,
+    }
+}
+
+fn webp_blocks(data: &[u8]) -> Vec<MetadataBlock> {
+    let mut blocks = Vec::new();
+    let _ = walk_webp_chunks(data, |fourcc, _| blocks.extend(webp_chunk_block(fourcc)));
+    blocks
+}
+
+fn strip_webp(data: &[u8]) -> Result<Vec<u8>> {
+    let mut result = Vec::with_capacity(data.len());
+    result.extend_from_slice(&data[..12]);
+    walk_webp_chunks(data, |fourcc, chunk| {
+        if webp_chunk_block(fourcc).is_some() {
+            return;
+        }
+        let start = result.len();
+        result.extend_from_slice(chunk);
+        if fourcc == b"VP8X" && chunk.len() > 8 {
+            result[start + 8] &= !(VP8X_EXIF_FLAG | VP8X_XMP_FLAG);
+        }
+    })?;
+
+    // RIFF size excludes the "RIFF" tag and the size field itself
+    let riff_size = u32::try_from(result.len() - 8)
+        .map_err(|_| Error::Io("WebP file too large".to_string()))?;
+    result[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    Ok(result)
+}
+
+// =============================================================================
+// TIFF
+// =============================================================================
+
+/// IFD0 tags that carry metadata rather than describe the image structure.
+const TIFF_DESCRIPTIVE_TAGS: &[&str] = &[
+    "Make",
+    "Model",
+    "Artist",
+    "Copyright",
+    "DateTime",
+    "ImageDescription",
+    "Software",
+];
+
+fn tiff_blocks(path: &Path) -> Vec<MetadataBlock> {
+    let mut blocks = Vec::new();
+    if crate::media::metadata::extract_image_metadata(path).is_ok_and(|meta| {
+        meta.all_tags.iter().any(|tag| match tag.group {
+            "Exif" | "GPS" | "Interop" => true,
+            "IFD0" => TIFF_DESCRIPTIVE_TAGS.contains(&tag.name.as_str()),
+            _ => false,
+        })
+    }) {
+        blocks.push(MetadataBlock::Exif);
+    }
+    if crate::media::xmp::extract_xmp_from_tiff(path).is_some() {
+        blocks.push(MetadataBlock::Xmp);
+    }
+    blocks
+}
+
+fn strip_tiff(data: &[u8]) -> Result<Vec<u8>> {
+    let image = image_rs::load_from_memory_with_format(data, image_rs::ImageFormat::Tiff)
+        .map_err(|e| Error::Io(e.to_string()))?;
+    let mut output = std::io::Cursor::new(Vec::new());
+    image
+        .write_to(&mut output, image_rs::ImageFormat::Tiff)
+        .map_err(|e| Error::Io(e.to_string()))?;
+    Ok(output.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::media::metadata_writer::{write_exif, EditableMetadata};
+
+    fn tagged_metadata() -> EditableMetadata {
+        EditableMetadata {
+            dc_title: "Secret place".to_string(),
+            dc_creator: "Jane Doe".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn write_image(dir: &Path, name: &str) -> PathBuf {
+        let path = dir.join(name);
+        image_rs::RgbImage::from_pixel(16, 16, image_rs::Rgb([40, 80, 120]))
+            .save(&path)
+            .expect("write image");
+        path
+    }
+
+    #[test]
+    fn jpeg_strip_removes_xmp_and_iptc_in_place() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = write_image(dir.path(), "photo.jpg");
+        write_exif(&path, &tagged_metadata()).expect("write metadata");
+        let before = detect_blocks(&path);
+        assert!(before.contains(&MetadataBlock::Xmp));
+        assert!(before.contains(&MetadataBlock::Iptc));
+
+        strip_metadata(&path, &path).expect("strip");
+
+        assert!(detect_blocks(&path).is_empty());
+        assert!(crate::media::xmp::extract_xmp_from_jpeg(&path).is_none());
+        assert!(image_rs::open(&path).is_ok(), "image still decodes");
+    }
+
+    #[test]
+    fn png_strip_writes_clean_copy_and_keeps_original() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = write_image(dir.path(), "photo.png");
+        write_exif(&path, &tagged_metadata()).expect("write metadata");
+
+        let copy = clean_copy_path(&path);
+        assert_eq!(
+            copy.file_name().and_then(|n| n.to_str()),
+            Some("photo_clean.png")
+        );
+        strip_metadata(&path, &copy).expect("strip");
+
+        assert!(detect_blocks(&path).contains(&MetadataBlock::Xmp));
+        assert!(detect_blocks(&copy).is_empty());
+        let original = image_rs::open(&path).expect("decode original").to_rgb8();
+        let cleaned = image_rs::open(&copy).expect("decode copy").to_rgb8();
+        assert_eq!(original, cleaned, "pixels are untouched");
+
+        assert_eq!(
+            clean_copy_path(&path).file_name().and_then(|n| n.to_str()),
+            Some("photo_clean_2.png")
+        );
+    }
+
+    #[test]
+    fn jpeg_strip_keeps_jfif_and_image_data() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = write_image(dir.path(), "plain.jpg");
+        let original = std::fs::read(&path).expect("read");
+        let mut with_comment = original[..2].to_vec();
+        with_comment.extend_from_slice(&[0xFF, 0xFE, 0x00, 0x06, b'h', b'i', b'!', b'!']);
+        with_comment.extend_from_slice(&original[2..]);
+
+        assert_eq!(jpeg_blocks(&with_comment), vec![MetadataBlock::Comment]);
+        assert_eq!(strip_jpeg(&with_comment).expect("strip"), original);
+    }
+
+    #[test]
+    fn unsupported_format_is_rejected() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("image.bmp");
+        std::fs::write(&path, b"BM").expect("write");
+        assert!(!is_format_supported(&path));
+        assert!(strip_metadata(&path, &path).is_err());
+    }
+}
@@ -3,8 +3,13 @@
 //!
 //! This module provides functionality to write EXIF metadata to image files
 //! using the `little_exif` crate. It supports JPEG, PNG, WebP, TIFF, and HEIF formats.
+//!
+//! Descriptive fields (title, description, keywords, creator, copyright) are
+//! written as XMP Dublin Core, and mirrored into IPTC-IIM for JPEG files so
+//! tools that only read IPTC see the same values.
 
 use crate::error::{Error, Result};
+use crate::media::iptc::{self, IptcFields};
 use little_exif::exif_tag::ExifTag;
 use little_exif::metadata::Metadata;
 use little_exif::rational::uR64;
@@ -39,6 +44,8 @@ pub struct EditableMetadata {
     // GPS info (EXIF)
     pub gps_latitude: String,
     pub gps_longitude: String,
+    /// Remove all GPS tags from the file on save (location stripped in the editor).
+    pub strip_gps: bool,
 
     // Dublin Core / XMP metadata
     /// dc:title - Title of the work
@@ -75,6 +82,7 @@ impl EditableMetadata {
                 .gps_longitude
                 .map(|v| format!("{v:.6}"))
                 .unwrap_or_default(),
+            strip_gps: false,
             dc_title: meta.dc_title.clone().unwrap_or_default(),
             dc_creator: meta.dc_creator.clone().unwrap_or_default(),
             dc_description: meta.dc_description.clone().unwrap_or_default(),
@@ -87,6 +95,12 @@ impl EditableMetadata {
         }
     }
 
+    /// Returns true if a GPS coordinate is set.
+    #[must_use]
+    pub fn has_gps(&self) -> bool {
+        !self.gps_latitude.is_empty() || !self.gps_longitude.is_empty()
+    }
+
     /// Returns true if any EXIF field has a non-empty value.
     #[must_use]
     pub fn has_any_exif_data(&self) -> bool {
@@ -118,6 +132,28 @@ impl EditableMetadata {
     pub fn has_any_data(&self) -> bool {
         self.has_any_exif_data() || self.has_any_xmp_data()
     }
+
+    /// Returns the comma-separated `dc_subject` as a list of keywords.
+    #[must_use]
+    pub fn keywords(&self) -> Vec<&str> {
+        self.dc_subject
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+
+    /// Returns the descriptive fields in their IPTC form.
+    #[must_use]
+    pub fn iptc_fields(&self) -> IptcFields {
+        IptcFields {
+            title: self.dc_title.clone(),
+            caption: self.dc_description.clone(),
+            keywords: self.keywords().into_iter().map(String::from).collect(),
+            creator: self.dc_creator.clone(),
+            copyright: self.dc_rights.clone(),
+        }
+    }
 }
 
 /// Writes EXIF metadata to an image file.
@@ -152,6 +188,15 @@ pub fn write_exif<P: AsRef<Path>>(path: P, metadata: &EditableMetadata) -> Resul
     // Write XMP metadata (JPEG, PNG, WebP, TIFF supported)
     write_xmp_metadata(path, metadata)?;
 
+    // Mirror descriptive fields into IPTC-IIM (JPEG only)
+    if path
+        .extension()
+        .and_then(|s| s.to_str())
+        .is_some_and(|ext| matches!(ext.to_lowercase().as_str(), "jpg" | "jpeg"))
+    {
+        write_iptc_to_jpeg(path, metadata)?;
+    }
+
     Ok(())
 }
 
@@ -174,8 +219,8 @@ fn load_existing_exif(path: &Path, metadata: &EditableMetadata) -> (Metadata, bo
         Ok(Err(e)) => {
             // Only warn if user is trying to write EXIF fields
             if metadata.has_any_exif_data() {
-                eprintln!(
-                    "[WARN] Could not read existing EXIF from '{}': {:?}. EXIF write skipped.",
+                tracing::warn!(
+                    "Could not read existing EXIF from '{}': {:?}. EXIF write skipped.",
                     path.display(),
                     e
                 );
@@ -249,7 +294,9 @@ fn apply_exif_tags(exif_metadata: &mut Metadata, metadata: &EditableMetadata) {
     apply_lens_tags(exif_metadata, metadata);
 
     // GPS info
-    if !metadata.gps_latitude.is_empty() && !metadata.gps_longitude.is_empty() {
+    if metadata.strip_gps {
+        remove_gps_tags(exif_metadata);
+    } else if !metadata.gps_latitude.is_empty() && !metadata.gps_longitude.is_empty() {
         if let (Ok(lat), Ok(lon)) = (
             metadata.gps_latitude.trim().parse::<f64>(),
             metadata.gps_longitude.trim().parse::<f64>(),
@@ -449,6 +496,18 @@ fn set_gps_coordinates(metadata: &mut Metadata, latitude: f64, longitude: f64) {
     metadata.set_tag(ExifTag::GPSLongitude(lon_dms));
 }
 
+/// Removes the location-related GPS tags from EXIF metadata.
+fn remove_gps_tags(metadata: &mut Metadata) {
+    metadata.remove_tag(ExifTag::GPSLatitudeRef(String::new()));
+    metadata.remove_tag(ExifTag::GPSLatitude(Vec::new()));
+    metadata.remove_tag(ExifTag::GPSLongitudeRef(String::new()));
+    metadata.remove_tag(ExifTag::GPSLongitude(Vec::new()));
+    metadata.remove_tag(ExifTag::GPSAltitudeRef(Vec::new()));
+    metadata.remove_tag(ExifTag::GPSAltitude(Vec::new()));
+    metadata.remove_tag(ExifTag::GPSTimeStamp(Vec::new()));
+    metadata.remove_tag(ExifTag::GPSDateStamp(String::new()));
+}
+
 /// Converts decimal degrees to DMS (degrees, minutes, seconds) as EXIF rationals.
 ///
 /// The casts from `f64` to `u32` are intentional: the input `decimal` is expected
@@ -528,7 +587,8 @@ fn write_xmp_to_jpeg<P: AsRef<Path>>(path: P, metadata: &EditableMetadata) -> Re
     }
 
     // Find existing XMP segment or insertion point
-    let (xmp_start, xmp_end, insert_pos) = find_xmp_segment_or_insertion_point(&file_data)?;
+    let (xmp_start, xmp_end, insert_pos) =
+        find_app_segment_or_insertion_point(&file_data, 0xE1, XMP_MARKER)?;
 
     // Build new XMP segment: APP1 marker + length + XMP marker + data
     let xmp_segment = build_xmp_segment(&xmp_data)?;
@@ -750,17 +810,9 @@ fn generate_xmp_packet(metadata: &EditableMetadata) -> Vec<u8> {
         writer.description([(None, metadata.dc_description.as_str())]);
     }
 
-    if !metadata.dc_subject.is_empty() {
-        // Parse comma-separated keywords
-        let keywords: Vec<&str> = metadata
-            .dc_subject
-            .split(',')
-            .map(str::trim)
-            .filter(|s| !s.is_empty())
-            .collect();
-        if !keywords.is_empty() {
-            writer.subject(keywords);
-        }
+    let keywords = metadata.keywords();
+    if !keywords.is_empty() {
+        writer.subject(keywords);
     }
 
     if !metadata.dc_rights.is_empty() {
@@ -770,13 +822,16 @@ fn generate_xmp_packet(metadata: &EditableMetadata) -> Vec<u8> {
     writer.finish(None).into_bytes()
 }
 
-/// Finds the XMP segment boundaries or the insertion point after SOI.
+/// Finds the boundaries of the APPn segment `app_marker` starting with
+/// `signature`, or the insertion point after SOI.
 ///
-/// Returns (`xmp_start`, `xmp_end`, `insertion_point`):
-/// - If XMP exists: `(Some(start), Some(end), _)` where `start..end` is the segment range
-/// - If no XMP: `(None, None, insertion_point)` where `insertion_point` is after the first APP segment
-fn find_xmp_segment_or_insertion_point(
+/// Returns (`start`, `end`, `insertion_point`):
+/// - If the segment exists: `(Some(start), Some(end), _)` where `start..end` is the segment range
+/// - If not: `(None, None, insertion_point)` where `insertion_point` is after the first APP segment
+fn find_app_segment_or_insertion_point(
     data: &[u8],
+    app_marker: u8,
+    signature: &[u8],
 ) -> Result<(Option<usize>, Option<usize>, usize)> {
     let mut pos = 2; // Skip SOI (0xFF 0xD8)
     let mut first_app_end = 2; // Default to after SOI
@@ -815,13 +870,12 @@ fn find_xmp_segment_or_insertion_point(
                     break;
                 }
 
-                // Check if this is an APP1 segment with XMP marker
-                if marker_type == 0xE1 {
+                // Check if this is the requested APP segment with its signature
+                if marker_type == app_marker {
                     let data_start = pos + 4;
-                    if data_start + XMP_MARKER.len() <= segment_end
-                        && &data[data_start..data_start + XMP_MARKER.len()] == XMP_MARKER
+                    if data_start + signature.len() <= segment_end
+                        && &data[data_start..data_start + signature.len()] == signature
                     {
-                        // Found XMP segment
                         return Ok((Some(pos), Some(segment_end), pos));
                     }
                 }
@@ -836,10 +890,80 @@ fn find_xmp_segment_or_insertion_point(
         }
     }
 
-    // No XMP found, return insertion point
+    // Segment not found, return insertion point
     Ok((None, None, first_app_end))
 }
 
+/// APP13 signature of Photoshop image resources (which hold IPTC-IIM).
+const PHOTOSHOP_MARKER: &[u8] = b"Photoshop 3.0\0";
+
+/// Writes the descriptive fields as IPTC-IIM to a JPEG file.
+///
+/// The IPTC resource of the Photoshop APP13 segment is rebuilt; other image
+/// resources and unrelated IPTC datasets are preserved. A new APP13 segment
+/// is inserted if the file has none.
+fn write_iptc_to_jpeg<P: AsRef<Path>>(path: P, metadata: &EditableMetadata) -> Result<()> {
+    let path = path.as_ref();
+
+    // Same rule as XMP: nothing is written when all descriptive fields are empty
+    if !metadata.has_any_xmp_data() {
+        return Ok(());
+    }
+
+    let file_data = std::fs::read(path)
+        .map_err(|e| Error::Io(format!("Failed to read file '{}': {}", path.display(), e)))?;
+
+    if file_data.len() < 2 || file_data[0] != 0xFF || file_data[1] != 0xD8 {
+        return Err(Error::Io("Not a valid JPEG file".to_string()));
+    }
+
+    let (start, end, insert_pos) =
+        find_app_segment_or_insertion_point(&file_data, 0xED, PHOTOSHOP_MARKER)?;
+    let resources = match (start, end) {
+        (Some(start), Some(end)) => &file_data[start + 4 + PHOTOSHOP_MARKER.len()..end],
+        _ => &[],
+    };
+    let existing_iim = iptc::find_iptc_resource(resources).unwrap_or_default();
+    let iim = iptc::encode_iim(&metadata.iptc_fields(), existing_iim);
+    let segment = build_app13_segment(&iptc::replace_iptc_resource(resources, &iim))?;
+
+    let (splice_start, splice_end) = match (start, end) {
+        (Some(start), Some(end)) => (start, end),
+        _ => (insert_pos, insert_pos),
+    };
+    let mut new_data =
+        Vec::with_capacity(file_data.len() - (splice_end - splice_start) + segment.len());
+    new_data.extend_from_slice(&file_data[..splice_start]);
+    new_data.extend_from_slice(&segment);
+    new_data.extend_from_slice(&file_data[splice_end..]);
+
+    std::fs::write(path, new_data).map_err(|e| {
+        Error::Io(format!(
+            "Failed to write IPTC to '{}': {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// Builds a Photoshop APP13 segment for JPEG.
+fn build_app13_segment(resources: &[u8]) -> Result<Vec<u8>> {
+    let total_len = 2 + PHOTOSHOP_MARKER.len() + resources.len();
+    let Ok(len) = u16::try_from(total_len) else {
+        return Err(Error::Io(
+            "IPTC data too large for JPEG APP13 segment".to_string(),
+        ));
+    };
+
+    let mut segment = Vec::with_capacity(2 + total_len);
+    segment.extend_from_slice(&[0xFF, 0xED]);
+    segment.extend_from_slice(&len.to_be_bytes());
+    segment.extend_from_slice(PHOTOSHOP_MARKER);
+    segment.extend_from_slice(resources);
+
+    Ok(segment)
+}
+
 /// Builds an XMP APP1 segment for JPEG.
 ///
 /// The cast from `usize` to `u16` is safe: we explicitly check that
@@ -1298,6 +1422,98 @@ mod tests {
         assert_eq!(chunk[keyword_end], 0);
     }
 
+    fn descriptive_metadata() -> EditableMetadata {
+        EditableMetadata {
+            dc_title: "Harbour at dawn".to_string(),
+            dc_creator: "Jane Doe".to_string(),
+            dc_description: "Fishing boats leaving the harbour".to_string(),
+            dc_subject: "sea, boats, morning".to_string(),
+            dc_rights: "© 2025 Jane Doe".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn write_test_image(dir: &Path, name: &str) -> std::path::PathBuf {
+        let path = dir.join(name);
+        image_rs::RgbImage::from_pixel(4, 4, image_rs::Rgb([40, 90, 160]))
+            .save(&path)
+            .expect("write test image");
+        path
+    }
+
+    fn assert_dublin_core(dc: Option<crate::media::xmp::DublinCoreMetadata>) {
+        let dc = dc.expect("XMP packet present");
+        assert_eq!(dc.title.as_deref(), Some("Harbour at dawn"));
+        assert_eq!(dc.creator.as_deref(), Some("Jane Doe"));
+        assert_eq!(
+            dc.description.as_deref(),
+            Some("Fishing boats leaving the harbour")
+        );
+        assert_eq!(
+            dc.subject,
+            Some(vec![
+                "sea".to_string(),
+                "boats".to_string(),
+                "morning".to_string()
+            ])
+        );
+        assert_eq!(dc.rights.as_deref(), Some("© 2025 Jane Doe"));
+    }
+
+    #[test]
+    fn test_jpeg_descriptive_round_trip() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = write_test_image(dir.path(), "photo.jpg");
+        let metadata = descriptive_metadata();
+
+        write_xmp_to_jpeg(&path, &metadata).expect("write XMP");
+        write_iptc_to_jpeg(&path, &metadata).expect("write IPTC");
+
+        assert_dublin_core(crate::media::xmp::extract_xmp_from_jpeg(&path));
+        let fields = IptcFields::from_datasets(&iptc::extract_iptc_from_jpeg(&path));
+        assert_eq!(fields, metadata.iptc_fields());
+        assert!(image_rs::open(&path).is_ok(), "image still decodes");
+    }
+
+    #[test]
+    fn test_jpeg_iptc_rewrite_replaces_previous_values() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = write_test_image(dir.path(), "photo.jpg");
+        write_iptc_to_jpeg(&path, &descriptive_metadata()).expect("first write");
+
+        let updated = EditableMetadata {
+            dc_title: "Harbour at noon".to_string(),
+            ..Default::default()
+        };
+        write_iptc_to_jpeg(&path, &updated).expect("second write");
+
+        let fields = IptcFields::from_datasets(&iptc::extract_iptc_from_jpeg(&path));
+        assert_eq!(fields.title, "Harbour at noon");
+        assert!(fields.keywords.is_empty());
+    }
+
+    #[test]
+    fn test_png_descriptive_round_trip() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = write_test_image(dir.path(), "photo.png");
+
+        write_xmp_to_png(&path, &descriptive_metadata()).expect("write XMP");
+
+        assert_dublin_core(crate::media::xmp::extract_xmp_from_png(&path));
+        assert!(image_rs::open(&path).is_ok(), "image still decodes");
+    }
+
+    #[test]
+    fn test_tiff_descriptive_round_trip() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = write_test_image(dir.path(), "photo.tiff");
+
+        write_xmp_to_tiff(&path, &descriptive_metadata()).expect("write XMP");
+
+        assert_dublin_core(crate::media::xmp::extract_xmp_from_tiff(&path));
+        assert!(image_rs::open(&path).is_ok(), "image still decodes");
+    }
+
     #[test]
     fn test_editable_metadata_has_any_xmp_data() {
         let empty = EditableMetadata::default();
@@ -4,17 +4,43 @@
 //! This module provides a common interface for loading, displaying, and manipulating
 //! both image and video files.
 
+pub mod auto_enhance;
+pub mod background_removal;
+pub mod capture_day;
+pub mod chapters;
+pub mod clip_export;
+pub mod color_proof;
+pub mod convert;
 pub mod deblur;
+pub mod decoders;
+pub mod denoise;
+pub mod face_detection;
 pub mod filter;
+pub mod frame_encoder;
 pub mod frame_export;
+pub mod hdr;
 pub mod image;
+pub mod image_sequence;
 pub mod image_transform;
+pub mod integrity;
+pub mod iptc;
+pub mod looks;
 pub mod metadata;
+pub mod metadata_batch;
+pub mod metadata_export;
+pub mod metadata_strip;
 pub mod metadata_writer;
+pub mod naming;
 pub mod navigator;
+pub mod prefetch;
+pub mod preview;
+pub mod sandbox;
+pub mod shoot_stats;
+pub mod similarity;
 pub mod skip_attempts;
 pub mod upscale;
 pub mod video;
+pub mod view_export;
 pub mod xmp;
 
 use image_rs::AnimationDecoder;
@@ -24,10 +50,11 @@ use std::path::Path;
 
 // Re-export commonly used types
 pub use extensions::IMAGE_EXTENSIONS;
-pub use filter::{DateFilterField, DateRangeFilter, MediaFilter, MediaTypeFilter};
+pub use filter::{DateFilterField, DateRangeFilter, MediaFilter, MediaTypeFilter, RatingFilter};
 pub use image::{load_image, ImageData};
+pub use image_sequence::ImageSequence;
 pub use image_transform::ResizeScale;
-pub use navigator::MediaNavigator;
+pub use navigator::{MediaNavigator, ScanId};
 pub use skip_attempts::MaxSkipAttempts;
 
 /// Represents different types of media formats
@@ -59,6 +86,45 @@ pub struct VideoData {
     pub fps: f64,
     /// Whether the video has an audio track
     pub has_audio: bool,
+    /// Source frames when this "video" is a numbered image sequence
+    pub sequence: Option<ImageSequence>,
+    /// Chapters, sorted by start time (empty when the container has none)
+    pub chapters: Vec<chapters::Chapter>,
+    /// Whether this is an animated GIF or WebP image rather than a video
+    pub animated_image: bool,
+    /// Clockwise rotation (0, 90, 180 or 270) that displays decoded frames
+    /// upright, as stored by phones in the container metadata
+    pub rotation: u16,
+    /// HDR properties, for HDR10 and HLG videos
+    pub hdr: Option<hdr::HdrInfo>,
+}
+
+impl VideoData {
+    /// Returns true for animated images and image sequences, whose controls
+    /// show a frame counter.
+    #[must_use]
+    pub fn is_frame_based(&self) -> bool {
+        self.animated_image || self.sequence.is_some()
+    }
+
+    /// Returns the number of frames, estimated from the duration and the
+    /// average frame rate (exact for image sequences).
+    #[must_use]
+    pub fn frame_count(&self) -> usize {
+        // Frame counts of supported media are far below usize::MAX
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let count = (self.duration_secs * self.fps).round().max(1.0) as usize;
+        count
+    }
+
+    /// Returns the 1-based number of the frame shown at `position_secs`.
+    #[must_use]
+    pub fn frame_number_at(&self, position_secs: f64) -> usize {
+        // Positions are within the duration, so the index fits usize
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let index = (position_secs.max(0.0) * self.fps + 1e-6).floor() as usize;
+        (index + 1).min(self.frame_count())
+    }
 }
 
 impl MediaData {
@@ -103,6 +169,27 @@ pub mod extensions {
         "mkv", "webm",
     ];
 
+    /// Image formats and their extensions, for per-format dialog filters
+    pub const IMAGE_FORMAT_GROUPS: &[(&str, &[&str])] = &[
+        ("JPEG", &["jpg", "jpeg"]),
+        ("PNG", &["png"]),
+        ("GIF", &["gif"]),
+        ("WebP", &["webp"]),
+        ("TIFF", &["tiff", "tif"]),
+        ("BMP", &["bmp"]),
+        ("ICO", &["ico"]),
+        ("SVG", &["svg"]),
+    ];
+
+    /// Video formats and their extensions, for per-format dialog filters
+    pub const VIDEO_FORMAT_GROUPS: &[(&str, &[&str])] = &[
+        ("MP4", &["mp4", "m4v"]),
+        ("MOV", &["mov"]),
+        ("MKV", &["mkv"]),
+        ("WebM", &["webm"]),
+        ("AVI", &["avi"]),
+    ];
+
     /// Image format filters for save dialogs
     pub const IMAGE_SAVE_FILTERS: &[(&str, &[&str])] = &[
         ("JPEG", &["jpg", "jpeg"]),
@@ -207,7 +294,10 @@ fn count_webp_frames<P: AsRef<Path>>(path: P) -> crate::error::Result<usize> {
 }
 
 /// Detects if a GIF or WebP file is animated (has multiple frames)
-fn is_animated<P: AsRef<Path>>(path: P) -> crate::error::Result<bool> {
+///
+/// # Errors
+/// Returns an error if the file cannot be read.
+pub fn is_animated<P: AsRef<Path>>(path: P) -> crate::error::Result<bool> {
     let path_ref = path.as_ref();
     let extension = path_ref
         .extension()
@@ -240,8 +330,36 @@ fn is_animated<P: AsRef<Path>>(path: P) -> crate::error::Result<bool> {
 /// - The file format is not supported
 /// - The file cannot be read or decoded
 pub fn load_media<P: AsRef<Path>>(path: P) -> crate::error::Result<MediaData> {
+    load_media_with(path, LoadOptions::default())
+}
+
+/// How media files are decoded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadOptions {
+    /// Decode images in a separate helper process (see [`sandbox`]).
+    pub sandboxed: bool,
+    /// Preferred backends for formats with several decoders.
+    pub decoders: decoders::DecoderPreferences,
+}
+
+/// Loads a media file like [`load_media`], with the given decoding options.
+///
+/// # Errors
+/// Returns an error if the file format is not supported, the file cannot be
+/// read or decoded, or the sandboxed decoder crashes or times out.
+pub fn load_media_with<P: AsRef<Path>>(
+    path: P,
+    options: LoadOptions,
+) -> crate::error::Result<MediaData> {
     let path_ref = path.as_ref();
+    let started = std::time::Instant::now();
+    let result = decode_media(path_ref, options);
+    crate::diagnostics::record_load(path_ref, started.elapsed(), result.is_ok());
+    result
+}
 
+/// Decodes the media file at `path_ref`, see [`load_media_with`].
+fn decode_media(path_ref: &Path, options: LoadOptions) -> crate::error::Result<MediaData> {
     // Detect media type
     let media_type = detect_media_type(path_ref)
         .ok_or_else(|| crate::error::Error::Io("Unsupported file format".to_string()))?;
@@ -249,7 +367,11 @@ pub fn load_media<P: AsRef<Path>>(path: P) -> crate::error::Result<MediaData> {
     match media_type {
         MediaType::Image => {
             // Load as image
-            let image_data = image::load_image(path_ref)?;
+            let image_data = if options.sandboxed {
+                sandbox::decode_in_helper(path_ref, options.decoders)?
+            } else {
+                image::load_image_with(path_ref, options.decoders)?
+            };
             Ok(MediaData::Image(image_data))
         }
         MediaType::Video => {
@@ -279,6 +401,11 @@ pub fn load_media<P: AsRef<Path>>(path: P) -> crate::error::Result<MediaData> {
                         duration_secs: metadata.duration_secs,
                         fps: metadata.fps,
                         has_audio: metadata.has_audio,
+                        sequence: None,
+                        chapters: metadata.chapters,
+                        animated_image: extension == "gif",
+                        rotation: metadata.rotation,
+                        hdr: metadata.hdr,
                     };
                     Ok(MediaData::Video(video_data))
                 }
@@ -324,6 +451,42 @@ fn load_animated_webp(path: &Path) -> crate::error::Result<MediaData> {
         duration_secs: metadata.duration_secs,
         fps: metadata.fps,
         has_audio: false, // WebP animations don't have audio
+        sequence: None,
+        chapters: Vec::new(),
+        animated_image: true,
+        rotation: 0,
+        hdr: None,
+    };
+
+    Ok(MediaData::Video(video_data))
+}
+
+/// Loads a numbered image sequence as silent video.
+///
+/// The first frame becomes the thumbnail and defines the playback size;
+/// later frames are decoded on demand by the sequence decoder.
+///
+/// # Errors
+/// Returns an error if the sequence is empty or its first frame cannot be decoded.
+pub fn load_image_sequence(sequence: ImageSequence) -> crate::error::Result<MediaData> {
+    let first_frame = sequence
+        .frames()
+        .first()
+        .ok_or_else(|| crate::error::Error::Io("Image sequence has no frames".to_string()))?;
+    let thumbnail = image::load_image(first_frame)?;
+
+    let video_data = VideoData {
+        width: thumbnail.width,
+        height: thumbnail.height,
+        thumbnail,
+        duration_secs: sequence.duration_secs(),
+        fps: f64::from(sequence.fps().value()),
+        has_audio: false, // Still images carry no audio
+        sequence: Some(sequence),
+        chapters: Vec::new(),
+        animated_image: false,
+        rotation: 0,
+        hdr: None,
     };
 
     Ok(MediaData::Video(video_data))
@@ -360,6 +523,28 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
+    #[test]
+    fn frame_numbers_follow_the_position() {
+        let animation = VideoData {
+            thumbnail: ImageData::from_rgba(1, 1, vec![0; 4]),
+            width: 1,
+            height: 1,
+            duration_secs: 2.0,
+            fps: 10.0,
+            has_audio: false,
+            sequence: None,
+            chapters: Vec::new(),
+            animated_image: true,
+            rotation: 0,
+            hdr: None,
+        };
+        assert!(animation.is_frame_based());
+        assert_eq!(animation.frame_count(), 20);
+        assert_eq!(animation.frame_number_at(0.0), 1);
+        assert_eq!(animation.frame_number_at(0.3), 4);
+        assert_eq!(animation.frame_number_at(5.0), 20);
+    }
+
     #[test]
     fn test_detect_image_formats() {
         assert_eq!(detect_media_type("photo.jpg"), Some(MediaType::Image));
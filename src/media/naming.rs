@@ -0,0 +1,330 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Filename templates for exported files.
+//!
+//! Every export path (frame capture, batch export, …) builds its default
+//! filename from a user-editable template so naming stays consistent. A
+//! template is plain text with tokens in braces:
+//!
+//! | Token         | Value                                           |
+//! |---------------|-------------------------------------------------|
+//! | `{name}`      | File stem of the source media                   |
+//! | `{date}`      | Date as `YYYY-MM-DD`                            |
+//! | `{index}`     | Position in a batch, zero-padded to 3 digits    |
+//! | `{timestamp}` | Media position as `MM-SS-mmm`                   |
+//! | `{w}` / `{h}` | Output width / height in pixels                 |
+//!
+//! Unknown tokens are kept verbatim so typos are visible in the result, and
+//! characters that are invalid in filenames are replaced with `_`.
+//!
+//! [`save_target`] applies the editor's [`SavePolicy`], which may save a
+//! numbered copy (`img_edit1.jpg`) or a copy in an "Edited" subfolder instead
+//! of replacing the original.
+
+use crate::config::{SavePolicy, DEFAULT_EDITED_FOLDER};
+use chrono::{Local, NaiveDate};
+use std::path::{Path, PathBuf};
+
+/// Tokens recognized in filename templates, in documentation order.
+pub const TOKENS: &[&str] = &["{name}", "{date}", "{index}", "{timestamp}", "{w}", "{h}"];
+
+/// Characters replaced with `_` in rendered filenames.
+const INVALID_FILENAME_CHARS: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+
+/// Inserted between the stem and the number of a numbered copy.
+const COPY_SUFFIX: &str = "_edit";
+
+/// Values substituted into a filename template.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamingContext {
+    name: String,
+    date: NaiveDate,
+    index: Option<usize>,
+    timestamp_secs: Option<f64>,
+    dimensions: Option<(u32, u32)>,
+}
+
+impl NamingContext {
+    /// Creates a context for `source`, dated today.
+    #[must_use]
+    pub fn new(source: &Path) -> Self {
+        let name = source
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("export")
+            .to_string();
+        Self {
+            name,
+            date: Local::now().date_naive(),
+            index: None,
+            timestamp_secs: None,
+            dimensions: None,
+        }
+    }
+
+    /// Sets the value of `{date}`.
+    #[must_use]
+    pub fn with_date(mut self, date: NaiveDate) -> Self {
+        self.date = date;
+        self
+    }
+
+    /// Sets the value of `{index}`.
+    #[must_use]
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Sets the media position used by `{timestamp}`.
+    #[must_use]
+    pub fn with_timestamp(mut self, position_secs: f64) -> Self {
+        self.timestamp_secs = Some(position_secs);
+        self
+    }
+
+    /// Sets the values of `{w}` and `{h}`.
+    #[must_use]
+    pub fn with_dimensions(mut self, width: u32, height: u32) -> Self {
+        self.dimensions = Some((width, height));
+        self
+    }
+
+    /// Returns the value of `token` (without braces), or `None` if unknown.
+    ///
+    /// Known tokens without a value in this context render as an empty string.
+    fn value(&self, token: &str) -> Option<String> {
+        let value = match token {
+            "name" => self.name.clone(),
+            "date" => self.date.format("%Y-%m-%d").to_string(),
+            "index" => self.index.map(|i| format!("{i:03}")).unwrap_or_default(),
+            "timestamp" => self
+                .timestamp_secs
+                .map(format_timestamp)
+                .unwrap_or_default(),
+            "w" => self
+                .dimensions
+                .map(|(w, _)| w.to_string())
+                .unwrap_or_default(),
+            "h" => self
+                .dimensions
+                .map(|(_, h)| h.to_string())
+                .unwrap_or_default(),
+            _ => return None,
+        };
+        Some(value)
+    }
+}
+
+/// Renders `template` into a filename stem (without extension).
+///
+/// Falls back to the source name if the template renders to nothing.
+#[must_use]
+pub fn render(template: &str, ctx: &NamingContext) -> String {
+    let mut output = String::with_capacity(template.len() + ctx.name.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        let after_brace = &rest[start + 1..];
+        match after_brace
+            .find('}')
+            .and_then(|end| Some((end, ctx.value(&after_brace[..end])?)))
+        {
+            Some((end, value)) => {
+                output.push_str(&value);
+                rest = &after_brace[end + 1..];
+            }
+            None => {
+                // Unknown token or lone brace: keep the brace and continue after it
+                output.push('{');
+                rest = after_brace;
+            }
+        }
+    }
+    output.push_str(rest);
+
+    let sanitized: String = output
+        .chars()
+        .map(|c| {
+            if INVALID_FILENAME_CHARS.contains(&c) || c.is_control() {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    let trimmed = sanitized.trim();
+
+    if trimmed.is_empty() {
+        ctx.name.clone()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Renders `template` and appends `extension`.
+#[must_use]
+pub fn render_filename(template: &str, ctx: &NamingContext, extension: &str) -> String {
+    format!("{}.{extension}", render(template, ctx))
+}
+
+/// Returns where Save writes an image edited from `path` under `policy`.
+///
+/// Numbered copies never replace an existing file. The subfolder is not
+/// created here.
+#[must_use]
+pub fn save_target(policy: SavePolicy, path: &Path, edited_folder: &str) -> PathBuf {
+    match policy {
+        SavePolicy::Overwrite => path.to_path_buf(),
+        SavePolicy::Copy => numbered_copy(path),
+        SavePolicy::Subfolder => {
+            let folder = edited_folder_of(path, edited_folder);
+            path.file_name()
+                .map_or_else(|| folder.clone(), |name| folder.join(name))
+        }
+    }
+}
+
+/// Returns the subfolder of the folder of `path` named `edited_folder`.
+///
+/// The name is used as a single folder: separators and other invalid
+/// characters are replaced with `_`, and an empty name or `..` falls back to
+/// [`DEFAULT_EDITED_FOLDER`].
+#[must_use]
+pub fn edited_folder_of(path: &Path, edited_folder: &str) -> PathBuf {
+    let name: String = edited_folder
+        .trim()
+        .chars()
+        .map(|c| {
+            if INVALID_FILENAME_CHARS.contains(&c) || c.is_control() {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    let name = match name.as_str() {
+        "" | "." | ".." => DEFAULT_EDITED_FOLDER,
+        name => name,
+    };
+    path.parent().unwrap_or(Path::new("")).join(name)
+}
+
+/// Returns `path` if no file exists there, or else its first free numbered
+/// copy.
+#[must_use]
+pub fn unique_path(path: &Path) -> PathBuf {
+    if path.exists() {
+        numbered_copy(path)
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// Returns the first free `<stem>_edit<n>.<ext>` path next to `path`,
+/// counting from 1.
+#[must_use]
+pub fn numbered_copy(path: &Path) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|extension| format!(".{}", extension.to_string_lossy()))
+        .unwrap_or_default();
+    (1..)
+        .map(|number| path.with_file_name(format!("{stem}{COPY_SUFFIX}{number}{extension}")))
+        .find(|candidate| !candidate.exists())
+        .expect("an unused copy number exists")
+}
+
+/// Formats a media position as `MM-SS-mmm` (minutes-seconds-milliseconds).
+fn format_timestamp(position_secs: f64) -> String {
+    // Video positions are practically bounded (years of video fit in u64 ms), so cast is safe
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let total_ms = (position_secs.max(0.0) * 1000.0).round() as u64;
+    let minutes = total_ms / 60000;
+    let seconds = (total_ms % 60000) / 1000;
+    let millis = total_ms % 1000;
+    format!("{minutes:02}-{seconds:02}-{millis:03}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx() -> NamingContext {
+        NamingContext::new(Path::new("/videos/holiday.mp4"))
+            .with_date(NaiveDate::from_ymd_opt(2025, 7, 14).expect("valid date"))
+    }
+
+    #[test]
+    fn render_substitutes_all_tokens() {
+        let ctx = ctx()
+            .with_index(7)
+            .with_timestamp(125.456)
+            .with_dimensions(1920, 1080);
+        assert_eq!(
+            render("{name}_{date}_{index}_{timestamp}_{w}x{h}", &ctx),
+            "holiday_2025-07-14_007_02-05-456_1920x1080"
+        );
+    }
+
+    #[test]
+    fn render_keeps_unknown_tokens_and_lone_braces() {
+        assert_eq!(render("{nam}_{name}{", &ctx()), "{nam}_holiday{");
+    }
+
+    #[test]
+    fn render_missing_values_are_empty() {
+        assert_eq!(render("{name}{index}", &ctx()), "holiday");
+    }
+
+    #[test]
+    fn render_sanitizes_and_falls_back_to_name() {
+        assert_eq!(render("a/b:c", &ctx()), "a_b_c");
+        assert_eq!(render("  {index} ", &ctx()), "holiday");
+    }
+
+    #[test]
+    fn render_filename_appends_extension() {
+        assert_eq!(
+            render_filename("{name}_frame", &ctx(), "png"),
+            "holiday_frame.png"
+        );
+    }
+
+    #[test]
+    fn numbered_copies_skip_existing_files() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let original = dir.path().join("img.jpg");
+        std::fs::write(&original, b"original").expect("write file");
+
+        let first = save_target(SavePolicy::Copy, &original, DEFAULT_EDITED_FOLDER);
+        assert_eq!(first, dir.path().join("img_edit1.jpg"));
+        std::fs::write(&first, b"copy").expect("write file");
+        assert_eq!(numbered_copy(&original), dir.path().join("img_edit2.jpg"));
+
+        assert_eq!(
+            save_target(SavePolicy::Overwrite, &original, DEFAULT_EDITED_FOLDER),
+            original
+        );
+        let fresh = dir.path().join("new.png");
+        assert_eq!(unique_path(&fresh), fresh);
+    }
+
+    #[test]
+    fn subfolder_target_keeps_the_file_name() {
+        let path = Path::new("/photos/img.jpg");
+        assert_eq!(
+            save_target(SavePolicy::Subfolder, path, "Retouched"),
+            Path::new("/photos/Retouched/img.jpg")
+        );
+        assert_eq!(
+            edited_folder_of(path, "../out"),
+            Path::new("/photos/.._out")
+        );
+        assert_eq!(edited_folder_of(path, " .. "), Path::new("/photos/Edited"));
+    }
+}
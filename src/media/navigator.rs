@@ -5,10 +5,12 @@
 //! the viewer and editor components to maintain a single source of truth
 //! for media list and current media path.
 
-use crate::config::SortOrder;
+use crate::config::MediaSort;
 use crate::directory_scanner::MediaList;
 use crate::error::Result;
+use crate::media::capture_day::CaptureDayIndex;
 use crate::media::filter::MediaFilter;
+use crate::media::similarity::SimilarityIndex;
 use crate::media::{detect_media_type, MediaType};
 use std::path::{Path, PathBuf};
 
@@ -39,6 +41,19 @@ pub struct NavigationInfo {
     pub filtered_count: usize,
     /// Whether a filter is currently active.
     pub filter_active: bool,
+    /// Whether the directory is still being scanned, so the counts may grow.
+    pub scan_in_progress: bool,
+}
+
+/// One background scan of a directory.
+///
+/// Opening the same directory again starts a new scan: the generation tells
+/// the results of the earlier one apart, so they are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanId {
+    /// Directory being scanned.
+    pub directory: PathBuf,
+    generation: u64,
 }
 
 /// Manages navigation through a list of media files in a directory.
@@ -60,6 +75,14 @@ pub struct MediaNavigator {
     current_media_path: Option<PathBuf>,
     /// Current filter criteria for navigation
     filter: MediaFilter,
+    /// Capture-day index of the media list, built on demand
+    capture_days: Option<CaptureDayIndex>,
+    /// Perceptual hash index of the media list, built on demand
+    similarity: Option<SimilarityIndex>,
+    /// Background scan filling the media list, if any
+    scanning: Option<ScanId>,
+    /// Generation of the last background scan started
+    scan_generation: u64,
 }
 
 impl MediaNavigator {
@@ -70,6 +93,10 @@ impl MediaNavigator {
             media_list: MediaList::new(),
             current_media_path: None,
             filter: MediaFilter::default(),
+            capture_days: None,
+            similarity: None,
+            scanning: None,
+            scan_generation: 0,
         }
     }
 
@@ -78,12 +105,73 @@ impl MediaNavigator {
     /// # Errors
     ///
     /// Returns an error if the directory cannot be read or the path has no parent directory.
-    pub fn scan_directory(&mut self, current_file: &Path, sort_order: SortOrder) -> Result<()> {
-        self.media_list = MediaList::scan_directory(current_file, sort_order)?;
+    pub fn scan_directory(
+        &mut self,
+        current_file: &Path,
+        sort: impl Into<MediaSort>,
+    ) -> Result<()> {
+        self.media_list = MediaList::scan_directory(current_file, sort)?;
+        self.capture_days = None;
+        self.similarity = None;
+        self.scanning = None;
         self.current_media_path = Some(current_file.to_path_buf());
         Ok(())
     }
 
+    /// Starts a background scan of the directory of `current_file`.
+    ///
+    /// Until the first result arrives through [`Self::apply_scan`], the list
+    /// holds only `current_file`. Returns the scan to run, or `None` if the
+    /// path has no parent directory. Results of earlier scans are ignored
+    /// from now on, even for the same directory.
+    pub fn begin_scan(&mut self, current_file: &Path) -> Option<ScanId> {
+        let directory = current_file.parent()?.to_path_buf();
+        self.scan_generation += 1;
+        let scan = ScanId {
+            directory,
+            generation: self.scan_generation,
+        };
+        self.media_list =
+            MediaList::from_sorted(vec![current_file.to_path_buf()], Some(current_file));
+        self.capture_days = None;
+        self.similarity = None;
+        self.scanning = Some(scan.clone());
+        self.current_media_path = Some(current_file.to_path_buf());
+        Some(scan)
+    }
+
+    /// Replaces the media list with a sorted snapshot of the background
+    /// `scan`, keeping the current media.
+    ///
+    /// Returns `false`, changing nothing, if `scan` is not the latest one
+    /// (the user opened another file since).
+    pub fn apply_scan(&mut self, scan: &ScanId, files: Vec<PathBuf>, complete: bool) -> bool {
+        if self.scanning.as_ref() != Some(scan) {
+            return false;
+        }
+        self.media_list = MediaList::from_sorted(files, self.current_media_path.as_deref());
+        self.capture_days = None;
+        self.similarity = None;
+        if complete {
+            self.scanning = None;
+        }
+        true
+    }
+
+    /// Stops waiting for the background `scan`, which failed. The files
+    /// found so far are kept.
+    pub fn abort_scan(&mut self, scan: &ScanId) {
+        if self.scanning.as_ref() == Some(scan) {
+            self.scanning = None;
+        }
+    }
+
+    /// Returns true while a background scan is filling the media list.
+    #[must_use]
+    pub fn is_scanning(&self) -> bool {
+        self.scanning.is_some()
+    }
+
     /// Scans a directory directly for media files and selects the first one.
     ///
     /// Returns `Ok(Some(path))` with the first media file path if any media is found,
@@ -97,9 +185,12 @@ impl MediaNavigator {
     pub fn scan_from_directory(
         &mut self,
         directory: &Path,
-        sort_order: SortOrder,
+        sort: impl Into<MediaSort>,
     ) -> Result<Option<PathBuf>> {
-        self.media_list = MediaList::scan_directory_direct(directory, sort_order)?;
+        self.media_list = MediaList::scan_directory_direct(directory, sort)?;
+        self.capture_days = None;
+        self.similarity = None;
+        self.scanning = None;
 
         // Find the first media matching the active filter (or first overall if no filter)
         let first_matching = if self.filter.is_active() {
@@ -314,6 +405,53 @@ impl MediaNavigator {
         self.media_list.current_index()
     }
 
+    /// Returns all media paths in navigation order, ignoring the filter.
+    #[must_use]
+    pub fn media_paths(&self) -> Vec<PathBuf> {
+        (0..self.len())
+            .filter_map(|i| self.media_list.get(i))
+            .map(std::path::Path::to_path_buf)
+            .collect()
+    }
+
+    /// Returns the media paths matching the current filter, in navigation order.
+    ///
+    /// Returns all paths when no filter is active.
+    #[must_use]
+    pub fn filtered_media_paths(&self) -> Vec<PathBuf> {
+        (0..self.len())
+            .filter_map(|i| self.media_list.get(i))
+            .filter(|path| !self.filter.is_active() || self.filter.matches(path))
+            .map(std::path::Path::to_path_buf)
+            .collect()
+    }
+
+    /// Returns the capture-day index of the media list, if built.
+    ///
+    /// The index is dropped whenever the directory is rescanned.
+    #[must_use]
+    pub fn capture_days(&self) -> Option<&CaptureDayIndex> {
+        self.capture_days.as_ref()
+    }
+
+    /// Stores the capture-day index built from [`Self::media_paths`].
+    pub fn set_capture_days(&mut self, index: CaptureDayIndex) {
+        self.capture_days = Some(index);
+    }
+
+    /// Returns the perceptual hash index of the media list, if built.
+    ///
+    /// The index is dropped whenever the directory is rescanned.
+    #[must_use]
+    pub fn similarity(&self) -> Option<&SimilarityIndex> {
+        self.similarity.as_ref()
+    }
+
+    /// Stores the perceptual hash index built from [`Self::media_paths`].
+    pub fn set_similarity(&mut self, index: SimilarityIndex) {
+        self.similarity = Some(index);
+    }
+
     /// Returns a snapshot of the current navigation state for UI rendering.
     ///
     /// This method provides all the information needed by the viewer to render
@@ -329,6 +467,7 @@ impl MediaNavigator {
             total_count: self.len(),
             filtered_count: self.filtered_count(),
             filter_active: self.filter.is_active(),
+            scan_in_progress: self.is_scanning(),
         }
     }
 
@@ -489,6 +628,7 @@ impl Default for MediaNavigator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::SortOrder;
     use std::fs;
     use std::io::Write;
     use tempfile::tempdir;
@@ -878,6 +1018,7 @@ mod tests {
         let filter = MediaFilter {
             media_type: MediaTypeFilter::ImagesOnly,
             date_range: None,
+            rating: None,
         };
 
         nav.set_filter(filter);
@@ -919,11 +1060,35 @@ mod tests {
         nav.set_filter(MediaFilter {
             media_type: MediaTypeFilter::ImagesOnly,
             date_range: None,
+            rating: None,
         });
 
         assert_eq!(nav.filtered_count(), 2); // Only images
     }
 
+    #[test]
+    fn filtered_media_paths_follows_filter() {
+        use crate::media::filter::MediaTypeFilter;
+
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let img1 = create_test_image(temp_dir.path(), "a.jpg");
+        let _vid1 = create_test_video(temp_dir.path(), "b.mp4");
+        let img2 = create_test_image(temp_dir.path(), "c.png");
+
+        let mut nav = MediaNavigator::new();
+        nav.scan_directory(&img1, SortOrder::Alphabetical)
+            .expect("scan failed");
+        assert_eq!(nav.filtered_media_paths().len(), 3);
+
+        nav.set_filter(MediaFilter {
+            media_type: MediaTypeFilter::ImagesOnly,
+            date_range: None,
+            rating: None,
+        });
+
+        assert_eq!(nav.filtered_media_paths(), vec![img1, img2]);
+    }
+
     #[test]
     fn peek_next_filtered_skips_non_matching() {
         use crate::media::filter::MediaTypeFilter;
@@ -941,6 +1106,7 @@ mod tests {
         nav.set_filter(MediaFilter {
             media_type: MediaTypeFilter::ImagesOnly,
             date_range: None,
+            rating: None,
         });
 
         // Should skip b.mp4 and c.mp4, return d.png
@@ -967,6 +1133,7 @@ mod tests {
         nav.set_filter(MediaFilter {
             media_type: MediaTypeFilter::ImagesOnly,
             date_range: None,
+            rating: None,
         });
 
         // Should skip c.mp4 and b.mp4, return a.jpg
@@ -1006,6 +1173,7 @@ mod tests {
         nav.set_filter(MediaFilter {
             media_type: MediaTypeFilter::ImagesOnly,
             date_range: None,
+            rating: None,
         });
 
         // No images in list, should return None
@@ -1041,6 +1209,7 @@ mod tests {
         nav.set_filter(MediaFilter {
             media_type: MediaTypeFilter::ImagesOnly,
             date_range: None,
+            rating: None,
         });
 
         // Current is image, should match
@@ -1075,6 +1244,7 @@ mod tests {
         nav.set_filter(MediaFilter {
             media_type: MediaTypeFilter::ImagesOnly,
             date_range: None,
+            rating: None,
         });
 
         let info = nav.navigation_info();
@@ -1083,6 +1253,64 @@ mod tests {
         assert_eq!(info.total_count, 3);
     }
 
+    #[test]
+    fn background_scan_keeps_current_media_and_ignores_stale_results() {
+        let dir = Path::new("/photos");
+        let current = dir.join("b.jpg");
+        let mut nav = MediaNavigator::new();
+
+        let scan = nav.begin_scan(&current).expect("scan");
+        assert_eq!(scan.directory, dir);
+        let info = nav.navigation_info();
+        assert!(info.scan_in_progress);
+        assert_eq!(info.total_count, 1);
+        assert_eq!(info.current_index, Some(0));
+
+        let files = vec![dir.join("a.jpg"), current.clone()];
+        assert!(nav.apply_scan(&scan, files, false));
+        assert_eq!(nav.current_index(), Some(1));
+        assert_eq!(nav.peek_previous(), Some(dir.join("a.jpg")));
+        assert!(nav.is_scanning());
+
+        let other = ScanId {
+            directory: PathBuf::from("/other"),
+            generation: scan.generation,
+        };
+        assert!(!nav.apply_scan(&other, Vec::new(), true));
+        assert_eq!(nav.len(), 2);
+
+        let files = vec![dir.join("a.jpg"), current.clone(), dir.join("c.jpg")];
+        assert!(nav.apply_scan(&scan, files, true));
+        let info = nav.navigation_info();
+        assert!(!info.scan_in_progress);
+        assert_eq!(info.total_count, 3);
+        assert_eq!(info.current_index, Some(1));
+        assert!(
+            !nav.apply_scan(&scan, Vec::new(), true),
+            "scan already over"
+        );
+    }
+
+    #[test]
+    fn rescanning_a_directory_ignores_the_earlier_scan() {
+        let dir = Path::new("/photos");
+        let current = dir.join("b.jpg");
+        let mut nav = MediaNavigator::new();
+
+        let first = nav.begin_scan(&current).expect("scan");
+        let second = nav.begin_scan(&current).expect("scan");
+        assert_ne!(first, second);
+
+        // The earlier scan finishing neither fills the list nor ends the new one
+        assert!(!nav.apply_scan(&first, vec![dir.join("a.jpg"), current.clone()], true));
+        assert!(nav.is_scanning());
+        assert_eq!(nav.len(), 1);
+
+        assert!(nav.apply_scan(&second, vec![dir.join("a.jpg"), current], true));
+        assert!(!nav.is_scanning());
+        assert_eq!(nav.len(), 2);
+    }
+
     #[test]
     fn scan_from_directory_respects_active_filter() {
         use crate::media::filter::MediaTypeFilter;
@@ -1100,6 +1328,7 @@ mod tests {
         nav.set_filter(MediaFilter {
             media_type: MediaTypeFilter::ImagesOnly,
             date_range: None,
+            rating: None,
         });
 
         let result = nav
@@ -1129,6 +1358,7 @@ mod tests {
         nav.set_filter(MediaFilter {
             media_type: MediaTypeFilter::ImagesOnly,
             date_range: None,
+            rating: None,
         });
 
         let result = nav
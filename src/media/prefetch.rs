@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Cache of decoded neighbors of the current media, for instant navigation.
+//!
+//! Once a file is shown, the next and previous files of the directory are
+//! decoded in the background and kept here, so pressing an arrow key shows
+//! them without waiting for the decoder. The media on screen is kept too,
+//! which makes going back and forth free.
+//!
+//! Entries are keyed by path and remember the modification time of the file
+//! and the options it was decoded with: a file edited since, or decoding
+//! options changed in the settings, make the entry stale.
+
+use super::{LoadOptions, MediaData};
+use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Returns the modification time of `path`, used to detect stale entries.
+#[must_use]
+pub fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).ok()?.modified().ok()
+}
+
+/// A decoded media file.
+#[derive(Debug, Clone)]
+struct Entry {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    options: LoadOptions,
+    media: MediaData,
+}
+
+/// Decoded media keyed by path, evicting the least recently used entry
+/// beyond its capacity.
+#[derive(Debug, Default)]
+pub struct Cache {
+    capacity: usize,
+    /// Least recently used first.
+    entries: VecDeque<Entry>,
+    /// Paths being decoded in the background.
+    pending: HashSet<PathBuf>,
+}
+
+impl Cache {
+    /// Creates a cache holding at most `capacity` media (0 disables it).
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the maximum number of media kept.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Changes the maximum number of media kept, evicting the oldest ones.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict();
+    }
+
+    /// Returns the media decoded for `path` with `options`, if the file
+    /// hasn't changed since.
+    pub fn get(&mut self, path: &Path, options: LoadOptions) -> Option<MediaData> {
+        let media = self.get_if_modified_at(path, options, modified_time(path));
+        if self.capacity > 0 {
+            crate::diagnostics::record_cache_lookup(
+                crate::diagnostics::Cache::Prefetch,
+                media.is_some(),
+            );
+        }
+        media
+    }
+
+    fn get_if_modified_at(
+        &mut self,
+        path: &Path,
+        options: LoadOptions,
+        modified: Option<SystemTime>,
+    ) -> Option<MediaData> {
+        let index = self.entries.iter().position(|entry| entry.path == path)?;
+        let entry = self.entries.remove(index)?;
+        if entry.options != options || entry.modified != modified {
+            return None;
+        }
+        let media = entry.media.clone();
+        self.entries.push_back(entry);
+        Some(media)
+    }
+
+    /// Returns true if `path` should be decoded in the background: the cache
+    /// is enabled and the file is neither cached nor already being decoded.
+    ///
+    /// Callers must report the result with [`Cache::insert`] or
+    /// [`Cache::cancel`].
+    pub fn begin(&mut self, path: &Path, options: LoadOptions) -> bool {
+        if self.capacity == 0
+            || self.pending.contains(path)
+            || self
+                .entries
+                .iter()
+                .any(|entry| entry.path == path && entry.options == options)
+        {
+            return false;
+        }
+        self.pending.insert(path.to_path_buf())
+    }
+
+    /// Forgets a background decode of `path` that failed.
+    pub fn cancel(&mut self, path: &Path) {
+        self.pending.remove(path);
+    }
+
+    /// Stores `media`, decoded from `path` as it was at `modified`.
+    pub fn insert(
+        &mut self,
+        path: PathBuf,
+        modified: Option<SystemTime>,
+        options: LoadOptions,
+        media: MediaData,
+    ) {
+        self.pending.remove(&path);
+        if self.capacity == 0 {
+            return;
+        }
+        self.entries.retain(|entry| entry.path != path);
+        self.entries.push_back(Entry {
+            path,
+            modified,
+            options,
+            media,
+        });
+        self.evict();
+    }
+
+    /// Removes every entry.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the number of cached media.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if no media is cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn evict(&mut self) {
+        while self.entries.len() > self.capacity {
+            self.entries.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::media::ImageData;
+    use std::time::Duration;
+
+    fn media() -> MediaData {
+        MediaData::Image(ImageData::from_rgba(1, 1, vec![0; 4]))
+    }
+
+    fn insert(cache: &mut Cache, path: &str) {
+        cache.insert(
+            PathBuf::from(path),
+            Some(SystemTime::UNIX_EPOCH),
+            LoadOptions::default(),
+            media(),
+        );
+    }
+
+    fn get(cache: &mut Cache, path: &str) -> Option<MediaData> {
+        cache.get_if_modified_at(
+            Path::new(path),
+            LoadOptions::default(),
+            Some(SystemTime::UNIX_EPOCH),
+        )
+    }
+
+    #[test]
+    fn least_recently_used_entry_is_evicted() {
+        let mut cache = Cache::new(2);
+        insert(&mut cache, "a.jpg");
+        insert(&mut cache, "b.jpg");
+        assert!(get(&mut cache, "a.jpg").is_some());
+        insert(&mut cache, "c.jpg");
+
+        assert_eq!(cache.len(), 2);
+        assert!(get(&mut cache, "a.jpg").is_some());
+        assert!(get(&mut cache, "b.jpg").is_none());
+        assert!(get(&mut cache, "c.jpg").is_some());
+
+        cache.set_capacity(1);
+        assert_eq!(cache.len(), 1);
+        assert!(get(&mut cache, "c.jpg").is_some());
+    }
+
+    #[test]
+    fn changed_files_and_options_are_stale() {
+        let mut cache = Cache::new(4);
+        insert(&mut cache, "a.jpg");
+        let later = SystemTime::UNIX_EPOCH + Duration::from_secs(60);
+        assert!(cache
+            .get_if_modified_at(Path::new("a.jpg"), LoadOptions::default(), Some(later))
+            .is_none());
+        assert!(cache.is_empty(), "stale entries are dropped");
+
+        insert(&mut cache, "a.jpg");
+        let sandboxed = LoadOptions {
+            sandboxed: true,
+            ..LoadOptions::default()
+        };
+        assert!(cache
+            .get_if_modified_at(Path::new("a.jpg"), sandboxed, Some(SystemTime::UNIX_EPOCH))
+            .is_none());
+    }
+
+    #[test]
+    fn begin_skips_cached_and_pending_paths() {
+        let mut cache = Cache::new(4);
+        let options = LoadOptions::default();
+        assert!(cache.begin(Path::new("a.jpg"), options));
+        assert!(!cache.begin(Path::new("a.jpg"), options));
+        cache.cancel(Path::new("a.jpg"));
+        assert!(cache.begin(Path::new("a.jpg"), options));
+        insert(&mut cache, "a.jpg");
+        assert!(!cache.begin(Path::new("a.jpg"), options));
+
+        let mut disabled = Cache::new(0);
+        assert!(!disabled.begin(Path::new("a.jpg"), options));
+        insert(&mut disabled, "a.jpg");
+        assert!(disabled.is_empty());
+    }
+}
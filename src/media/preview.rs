@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Quick previews shown while a large image is still being decoded.
+//!
+//! Decoding a 50-megapixel photo, or reading it from a slow network share,
+//! can take seconds. Most cameras embed a small JPEG thumbnail in the EXIF
+//! block at the start of the file, which can be read without touching the
+//! rest of it. The viewer shows that thumbnail, blurred and scaled up, until
+//! the full decode replaces it.
+//!
+//! Files without an embedded thumbnail get no preview: the decoders can only
+//! downscale after a full decode, which is the very cost being avoided.
+
+use super::image::ImageData;
+use super::{detect_media_type, MediaType};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+/// Smallest file, in bytes, worth a preview. Smaller images decode about as
+/// fast as their thumbnail.
+pub const MIN_PREVIEW_FILE_SIZE: u64 = 4 * 1024 * 1024;
+
+/// Blur radius applied to the preview, hiding the thumbnail's compression
+/// artifacts once it is scaled up to the window.
+const PREVIEW_BLUR_SIGMA: f32 = 1.5;
+
+/// Returns a blurred preview of the image at `path`, read from its embedded
+/// EXIF thumbnail.
+///
+/// Returns `None` for videos, small files and images without a thumbnail.
+/// This performs blocking I/O and should run off the UI thread.
+#[must_use]
+pub fn load_preview(path: &Path) -> Option<ImageData> {
+    if detect_media_type(path) != Some(MediaType::Image) {
+        return None;
+    }
+    if std::fs::metadata(path).ok()?.len() < MIN_PREVIEW_FILE_SIZE {
+        return None;
+    }
+
+    let file = File::open(path).ok()?;
+    let exif = exif::Reader::new()
+        .read_from_container(&mut BufReader::new(file))
+        .ok()?;
+    let thumbnail = embedded_thumbnail(&exif)?;
+
+    let preview = image_rs::load_from_memory(thumbnail)
+        .ok()?
+        .blur(PREVIEW_BLUR_SIGMA)
+        .to_rgba8();
+    let (width, height) = preview.dimensions();
+    Some(ImageData::from_rgba(width, height, preview.into_raw()))
+}
+
+/// Returns the JPEG bytes of the IFD1 thumbnail of `exif`, if any.
+fn embedded_thumbnail(exif: &exif::Exif) -> Option<&[u8]> {
+    let value = |tag| {
+        exif.get_field(tag, exif::In::THUMBNAIL)?
+            .value
+            .get_uint(0)
+            .and_then(|value| usize::try_from(value).ok())
+    };
+    let offset = value(exif::Tag::JPEGInterchangeFormat)?;
+    let length = value(exif::Tag::JPEGInterchangeFormatLength)?;
+    thumbnail_slice(exif.buf(), offset, length)
+}
+
+/// Returns `length` bytes of `buf` from `offset`, if they are all in range
+/// and not empty.
+fn thumbnail_slice(buf: &[u8], offset: usize, length: usize) -> Option<&[u8]> {
+    if length == 0 {
+        return None;
+    }
+    buf.get(offset..offset.checked_add(length)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thumbnail_slice_rejects_out_of_range_entries() {
+        let buf = [0_u8, 1, 2, 3, 4, 5];
+        assert_eq!(thumbnail_slice(&buf, 2, 3), Some(&buf[2..5]));
+        assert_eq!(thumbnail_slice(&buf, 2, 4), Some(&buf[2..6]));
+        assert_eq!(thumbnail_slice(&buf, 2, 5), None);
+        assert_eq!(thumbnail_slice(&buf, 0, 0), None);
+        assert_eq!(thumbnail_slice(&buf, usize::MAX, 2), None);
+    }
+
+    #[test]
+    fn small_and_missing_files_have_no_preview() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let small = dir.path().join("small.jpg");
+        image_rs::RgbImage::new(8, 8)
+            .save(&small)
+            .expect("write image");
+        assert!(load_preview(&small).is_none());
+        assert!(load_preview(&dir.path().join("missing.jpg")).is_none());
+    }
+}
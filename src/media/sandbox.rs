@@ -0,0 +1,247 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Out-of-process image decoding for untrusted files.
+//!
+//! In sandboxed mode, images are decoded by a short-lived helper process:
+//! this same executable started with the hidden [`HELPER_COMMAND`]. A decoder
+//! crash, hang or exploit triggered by a malicious file then only affects the
+//! helper, which the viewer reports as a load error.
+//!
+//! The viewer creates a private file with a random name in shared memory
+//! (`/dev/shm` on Linux, the temporary directory elsewhere); the helper writes
+//! the decoded pixels to it and exits, and the viewer reads the pixels back
+//! into an [`ImageData`] and removes the file.
+//!
+//! Only full image decoding moves out of process. Media type detection
+//! (which counts animation frames) and video decoding with `FFmpeg` still
+//! run in the viewer.
+
+use super::decoders::{DecodeInfo, DecoderPreferences};
+use super::image::{load_image_with, ImageData};
+use crate::error::{Error, Result};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Command-line subcommand that runs the decode helper.
+pub const HELPER_COMMAND: &str = "decode-helper";
+
+/// Helper option naming the preferred WebP decoder.
+pub const WEBP_DECODER_OPTION: &str = "--webp-decoder";
+
+/// Time after which a helper that hasn't finished is killed.
+const HELPER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Interval between checks of the helper's exit status.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Identifies a decoded image written by the helper.
+const MAGIC: &[u8; 4] = b"ILDH";
+
+/// Magic, width, height and decode info.
+const HEADER_LEN: usize = 14;
+
+/// Decodes the image at `path` in a helper process, with the `decoders`
+/// backend preferences.
+///
+/// # Errors
+///
+/// Returns an error if the helper can't be started, fails to decode the
+/// image, crashes, or doesn't finish within 30 seconds.
+pub fn decode_in_helper(path: &Path, decoders: DecoderPreferences) -> Result<ImageData> {
+    let executable = std::env::current_exe()
+        .map_err(|e| Error::Io(format!("Failed to locate the decode helper: {e}")))?;
+    // Created exclusively with owner-only permissions (0600), removed on drop
+    let output = tempfile::Builder::new()
+        .prefix("iced_lens-decode-")
+        .suffix(".rgba")
+        .tempfile_in(shared_memory_dir())?;
+
+    let mut child = Command::new(executable)
+        .arg(HELPER_COMMAND)
+        .arg(WEBP_DECODER_OPTION)
+        .arg(decoders.webp.as_str())
+        .arg(path)
+        .arg(output.path())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| Error::Io(format!("Failed to start the decode helper: {e}")))?;
+
+    // Drained while the helper runs, so a chatty decoder can't fill the pipe
+    // and block until the timeout
+    let stderr = child.stderr.take().map(|mut stderr| {
+        std::thread::spawn(move || {
+            let mut message = String::new();
+            let _ = stderr.read_to_string(&mut message);
+            message
+        })
+    });
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if started.elapsed() >= HELPER_TIMEOUT {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(Error::Io("The decode helper timed out".to_string()));
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    };
+
+    if !status.success() {
+        // Without an exit code the helper was killed by a signal (crash)
+        if status.code().is_none() {
+            return Err(Error::Io("The decode helper crashed".to_string()));
+        }
+        let message = stderr
+            .and_then(|reader| reader.join().ok())
+            .unwrap_or_default();
+        return Err(Error::Io(message.trim().to_string()));
+    }
+
+    decode_output(&fs::read(output.path())?)
+}
+
+/// Entry point of the helper process: decodes `input` and writes the pixels
+/// to `output`, which the viewer has already created. Returns the process
+/// exit code.
+#[must_use]
+pub fn run_helper(input: &Path, output: &Path, decoders: DecoderPreferences) -> i32 {
+    let result = load_image_with(input, decoders).and_then(|image| {
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .open(output)?;
+        std::io::Write::write_all(&mut file, &encode_output(&image))?;
+        Ok(())
+    });
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("{e}");
+            1
+        }
+    }
+}
+
+/// Serializes a decoded image for the viewer.
+fn encode_output(image: &ImageData) -> Vec<u8> {
+    let pixels = image.rgba_bytes();
+    let mut bytes = Vec::with_capacity(HEADER_LEN + pixels.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&image.width.to_le_bytes());
+    bytes.extend_from_slice(&image.height.to_le_bytes());
+    // Images loaded from disk always carry decode info
+    bytes.extend_from_slice(
+        &image
+            .decode_info()
+            .map_or([u8::MAX; 2], DecodeInfo::to_bytes),
+    );
+    bytes.extend_from_slice(pixels);
+    bytes
+}
+
+/// Parses the helper's output, checking that the pixel data matches the
+/// announced size (the helper is not trusted either).
+fn decode_output(bytes: &[u8]) -> Result<ImageData> {
+    let invalid = || Error::Io("Invalid output from the decode helper".to_string());
+    if bytes.len() < HEADER_LEN || !bytes.starts_with(MAGIC) {
+        return Err(invalid());
+    }
+    let width = u32::from_le_bytes(bytes[4..8].try_into().map_err(|_| invalid())?);
+    let height = u32::from_le_bytes(bytes[8..12].try_into().map_err(|_| invalid())?);
+    let decode_info = DecodeInfo::from_bytes([bytes[12], bytes[13]]);
+    let expected =
+        usize::try_from(u64::from(width) * u64::from(height) * 4).map_err(|_| invalid())?;
+    let pixels = &bytes[HEADER_LEN..];
+    if width == 0 || height == 0 || pixels.len() != expected {
+        return Err(invalid());
+    }
+    let image = ImageData::from_rgba(width, height, pixels.to_vec());
+    Ok(match decode_info {
+        Some(info) => image.with_decode_info(info),
+        None => image,
+    })
+}
+
+/// Returns the directory for helper output: RAM-backed shared memory when
+/// the platform has it.
+fn shared_memory_dir() -> PathBuf {
+    let shm = Path::new("/dev/shm");
+    if cfg!(target_os = "linux") && shm.is_dir() {
+        shm.to_path_buf()
+    } else {
+        std::env::temp_dir()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::media::decoders::ImageDecoder;
+
+    #[test]
+    fn output_round_trips() {
+        let info = DecodeInfo {
+            decoder: ImageDecoder::Libwebp,
+            fallback: true,
+        };
+        let image = ImageData::from_rgba(2, 1, vec![1, 2, 3, 4, 5, 6, 7, 8]).with_decode_info(info);
+        let decoded = decode_output(&encode_output(&image)).expect("valid output");
+        assert_eq!(decoded.width, 2);
+        assert_eq!(decoded.height, 1);
+        assert_eq!(decoded.rgba_bytes(), image.rgba_bytes());
+        assert_eq!(decoded.decode_info(), Some(info));
+    }
+
+    #[test]
+    fn malformed_output_is_rejected() {
+        let image = ImageData::from_rgba(2, 1, vec![0; 8]);
+        let mut bytes = encode_output(&image);
+        assert!(decode_output(&bytes[..HEADER_LEN + 4]).is_err());
+
+        // Announced size larger than the data
+        bytes[4..8].copy_from_slice(&1000_u32.to_le_bytes());
+        assert!(decode_output(&bytes).is_err());
+
+        assert!(decode_output(b"not an image").is_err());
+        assert!(decode_output(&[]).is_err());
+    }
+
+    #[test]
+    fn helper_writes_decoded_pixels() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let input = temp_dir.path().join("input.png");
+        image_rs::RgbaImage::from_pixel(3, 2, image_rs::Rgba([10, 20, 30, 255]))
+            .save(&input)
+            .expect("write png");
+        let output = temp_dir.path().join("output.rgba");
+        fs::File::create(&output).expect("create output");
+
+        assert_eq!(
+            run_helper(&input, &output, DecoderPreferences::default()),
+            0
+        );
+        let decoded = decode_output(&fs::read(&output).expect("output")).expect("valid");
+        assert_eq!((decoded.width, decoded.height), (3, 2));
+        assert_eq!(&decoded.rgba_bytes()[..4], &[10, 20, 30, 255]);
+        assert_eq!(
+            decoded.decode_info(),
+            Some(DecodeInfo::preferred(ImageDecoder::ImageRs))
+        );
+
+        // Failures exit non-zero without leaving output behind
+        let missing = temp_dir.path().join("missing.png");
+        let failed_output = temp_dir.path().join("failed.rgba");
+        assert_eq!(
+            run_helper(&missing, &failed_output, DecoderPreferences::default()),
+            1
+        );
+        assert!(!failed_output.exists());
+    }
+}
@@ -0,0 +1,320 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Camera and lens statistics for the photos of a directory.
+//!
+//! Summarizes which cameras and lenses were used and how focal lengths and
+//! ISO values are distributed, to review a shoot at a glance. Focal lengths
+//! use the 35mm equivalent when the camera records it, so crop and full-frame
+//! bodies land in comparable buckets.
+
+use crate::media::{detect_media_type, MediaType};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+/// Upper bounds (exclusive, in mm) of the focal length buckets.
+const FOCAL_BOUNDS: &[u32] = &[24, 35, 50, 85, 135, 200];
+/// Upper bounds (inclusive) of the ISO buckets.
+const ISO_BOUNDS: &[u32] = &[100, 200, 400, 800, 1600, 3200, 6400];
+
+/// The EXIF values of one photo used for statistics.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PhotoExif {
+    /// Camera name ("Make Model").
+    pub camera: Option<String>,
+    /// Lens model.
+    pub lens: Option<String>,
+    /// Focal length in mm, 35mm equivalent when available.
+    pub focal_length: Option<f64>,
+    /// ISO sensitivity.
+    pub iso: Option<u32>,
+}
+
+/// A labelled count (a camera, a lens, or a histogram bucket).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatEntry {
+    pub label: String,
+    pub count: usize,
+}
+
+/// Statistics of a set of media files.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShootStats {
+    /// Number of media files examined (including videos).
+    pub files: usize,
+    /// Number of files with camera EXIF data.
+    pub with_exif: usize,
+    /// Cameras, most used first.
+    pub cameras: Vec<StatEntry>,
+    /// Lenses, most used first.
+    pub lenses: Vec<StatEntry>,
+    /// Focal length histogram, in ascending order.
+    pub focal_lengths: Vec<StatEntry>,
+    /// ISO histogram, in ascending order.
+    pub isos: Vec<StatEntry>,
+}
+
+impl ShootStats {
+    /// Reads the EXIF data of every image in `paths` and summarizes it.
+    ///
+    /// This touches every file, so run it off the UI thread for large folders.
+    #[must_use]
+    pub fn collect(paths: &[PathBuf]) -> Self {
+        let records: Vec<PhotoExif> = paths
+            .iter()
+            .map(|path| match detect_media_type(path) {
+                Some(MediaType::Image) => read_photo_exif(path),
+                _ => PhotoExif::default(),
+            })
+            .collect();
+        Self::from_records(&records)
+    }
+
+    /// Summarizes already extracted EXIF records.
+    #[must_use]
+    pub fn from_records(records: &[PhotoExif]) -> Self {
+        let with_exif = records
+            .iter()
+            .filter(|r| r.camera.is_some() || r.focal_length.is_some() || r.iso.is_some())
+            .count();
+
+        Self {
+            files: records.len(),
+            with_exif,
+            cameras: count_labels(records.iter().filter_map(|r| r.camera.as_deref())),
+            lenses: count_labels(records.iter().filter_map(|r| r.lens.as_deref())),
+            focal_lengths: histogram(
+                records.iter().filter_map(|r| r.focal_length),
+                &focal_buckets(),
+                |value, upper| value < f64::from(upper),
+            ),
+            isos: histogram(
+                records.iter().filter_map(|r| r.iso),
+                &iso_buckets(),
+                |value, upper| value <= upper,
+            ),
+        }
+    }
+
+    /// Returns true if no file had usable EXIF data.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.with_exif == 0
+    }
+}
+
+/// Reads the statistics-relevant EXIF fields of an image.
+#[must_use]
+pub fn read_photo_exif(path: &Path) -> PhotoExif {
+    let Ok(file) = File::open(path) else {
+        return PhotoExif::default();
+    };
+    let Ok(exif) = exif::Reader::new().read_from_container(&mut BufReader::new(file)) else {
+        return PhotoExif::default();
+    };
+
+    let ascii = |tag| {
+        exif.get_field(tag, exif::In::PRIMARY)
+            .map(|field| {
+                field
+                    .display_value()
+                    .to_string()
+                    .trim_matches('"')
+                    .trim()
+                    .to_string()
+            })
+            .filter(|value| !value.is_empty())
+    };
+    let uint = |tag| {
+        exif.get_field(tag, exif::In::PRIMARY)
+            .and_then(|field| field.value.get_uint(0))
+            .filter(|value| *value > 0)
+    };
+
+    let focal_length = uint(exif::Tag::FocalLengthIn35mmFilm)
+        .map(f64::from)
+        .or_else(|| {
+            exif.get_field(exif::Tag::FocalLength, exif::In::PRIMARY)
+                .and_then(|field| match &field.value {
+                    exif::Value::Rational(values) => values.first().map(exif::Rational::to_f64),
+                    _ => None,
+                })
+                .filter(|value| value.is_finite() && *value > 0.0)
+        });
+
+    PhotoExif {
+        camera: camera_name(ascii(exif::Tag::Make), ascii(exif::Tag::Model)),
+        lens: ascii(exif::Tag::LensModel),
+        focal_length,
+        iso: uint(exif::Tag::PhotographicSensitivity),
+    }
+}
+
+/// Joins make and model, avoiding "Canon Canon EOS R5".
+fn camera_name(make: Option<String>, model: Option<String>) -> Option<String> {
+    match (make, model) {
+        (Some(make), Some(model)) if model.to_lowercase().starts_with(&make.to_lowercase()) => {
+            Some(model)
+        }
+        (Some(make), Some(model)) => Some(format!("{make} {model}")),
+        (make, model) => make.or(model),
+    }
+}
+
+/// Counts identical labels, most frequent first (ties sorted by name).
+fn count_labels<'a>(labels: impl Iterator<Item = &'a str>) -> Vec<StatEntry> {
+    let mut entries: Vec<StatEntry> = Vec::new();
+    for label in labels {
+        match entries.iter_mut().find(|entry| entry.label == label) {
+            Some(entry) => entry.count += 1,
+            None => entries.push(StatEntry {
+                label: label.to_string(),
+                count: 1,
+            }),
+        }
+    }
+    entries.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.label.cmp(&b.label)));
+    entries
+}
+
+/// Buckets `values` by upper bound; the last bucket (`None`) takes the rest.
+///
+/// Empty buckets before the first and after the last used one are dropped.
+fn histogram<T: Copy>(
+    values: impl Iterator<Item = T>,
+    buckets: &[(String, Option<u32>)],
+    fits: impl Fn(T, u32) -> bool,
+) -> Vec<StatEntry> {
+    let mut counts = vec![0_usize; buckets.len()];
+    for value in values {
+        let index = buckets
+            .iter()
+            .position(|(_, upper)| upper.is_none_or(|upper| fits(value, upper)))
+            .unwrap_or(buckets.len() - 1);
+        counts[index] += 1;
+    }
+
+    let Some(first) = counts.iter().position(|count| *count > 0) else {
+        return Vec::new();
+    };
+    let last = counts.iter().rposition(|count| *count > 0).unwrap_or(first);
+    buckets[first..=last]
+        .iter()
+        .zip(&counts[first..=last])
+        .map(|((label, _), count)| StatEntry {
+            label: label.clone(),
+            count: *count,
+        })
+        .collect()
+}
+
+fn focal_buckets() -> Vec<(String, Option<u32>)> {
+    let mut buckets = Vec::with_capacity(FOCAL_BOUNDS.len() + 1);
+    let mut lower = None;
+    for &upper in FOCAL_BOUNDS {
+        let label = match lower {
+            None => format!("< {upper} mm"),
+            Some(lower) => format!("{lower}–{} mm", upper - 1),
+        };
+        buckets.push((label, Some(upper)));
+        lower = Some(upper);
+    }
+    let last = FOCAL_BOUNDS[FOCAL_BOUNDS.len() - 1];
+    buckets.push((format!("≥ {last} mm"), None));
+    buckets
+}
+
+fn iso_buckets() -> Vec<(String, Option<u32>)> {
+    let mut buckets: Vec<(String, Option<u32>)> = ISO_BOUNDS
+        .iter()
+        .map(|&upper| (format!("≤ {upper}"), Some(upper)))
+        .collect();
+    let last = ISO_BOUNDS[ISO_BOUNDS.len() - 1];
+    buckets.push((format!("> {last}"), None));
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn photo(camera: &str, lens: Option<&str>, focal: f64, iso: u32) -> PhotoExif {
+        PhotoExif {
+            camera: Some(camera.to_string()),
+            lens: lens.map(String::from),
+            focal_length: Some(focal),
+            iso: Some(iso),
+        }
+    }
+
+    #[test]
+    fn cameras_and_lenses_are_ranked_by_count() {
+        let stats = ShootStats::from_records(&[
+            photo("Nikon Z6", Some("24-70mm"), 24.0, 100),
+            photo("Canon EOS R5", Some("RF 50mm"), 50.0, 400),
+            photo("Canon EOS R5", Some("RF 50mm"), 50.0, 800),
+            PhotoExif::default(),
+        ]);
+
+        assert_eq!(stats.files, 4);
+        assert_eq!(stats.with_exif, 3);
+        assert_eq!(stats.cameras[0].label, "Canon EOS R5");
+        assert_eq!(stats.cameras[0].count, 2);
+        assert_eq!(stats.lenses.len(), 2);
+        assert_eq!(stats.lenses[1].label, "24-70mm");
+    }
+
+    #[test]
+    fn histograms_trim_empty_edges_and_keep_gaps() {
+        let stats = ShootStats::from_records(&[
+            photo("A", None, 24.0, 100),
+            photo("A", None, 85.0, 400),
+            photo("A", None, 400.0, 12800),
+        ]);
+
+        let focal: Vec<_> = stats
+            .focal_lengths
+            .iter()
+            .map(|e| (e.label.as_str(), e.count))
+            .collect();
+        assert_eq!(
+            focal,
+            vec![
+                ("24–34 mm", 1),
+                ("35–49 mm", 0),
+                ("50–84 mm", 0),
+                ("85–134 mm", 1),
+                ("135–199 mm", 0),
+                ("≥ 200 mm", 1),
+            ]
+        );
+
+        assert_eq!(stats.isos.first().map(|e| e.label.as_str()), Some("≤ 100"));
+        assert_eq!(stats.isos.last().map(|e| e.label.as_str()), Some("> 6400"));
+    }
+
+    #[test]
+    fn camera_name_avoids_repeating_the_make() {
+        assert_eq!(
+            camera_name(Some("Canon".into()), Some("Canon EOS R5".into())),
+            Some("Canon EOS R5".to_string())
+        );
+        assert_eq!(
+            camera_name(Some("NIKON CORPORATION".into()), Some("Z 6".into())),
+            Some("NIKON CORPORATION Z 6".to_string())
+        );
+        assert_eq!(camera_name(None, None), None);
+    }
+
+    #[test]
+    fn collect_handles_files_without_exif() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("plain.png");
+        image_rs::RgbImage::from_pixel(4, 4, image_rs::Rgb([1, 2, 3]))
+            .save(&path)
+            .expect("write png");
+
+        let stats = ShootStats::collect(&[path]);
+        assert_eq!(stats.files, 1);
+        assert!(stats.is_empty());
+    }
+}
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Similar-image lookup using perceptual hashes.
+//!
+//! Each image of a directory gets a 64-bit difference hash (dHash): the image
+//! is shrunk to 9x8 grayscale pixels and every bit records whether a pixel is
+//! brighter than its right neighbor. Burst shots and re-encodes of the same
+//! scene end up a few bits apart, so the viewer can jump between them
+//! regardless of how the directory is sorted.
+
+use crate::media::{detect_media_type, MediaType};
+use image_rs::{imageops::FilterType, DynamicImage};
+use std::path::{Path, PathBuf};
+
+/// Number of bits in a perceptual hash.
+const HASH_BITS: u32 = 64;
+
+/// Computes the difference hash of an image.
+#[must_use]
+pub fn difference_hash(image: &DynamicImage) -> u64 {
+    let small = image.resize_exact(9, 8, FilterType::Triangle).to_luma8();
+    let mut hash = 0u64;
+    for y in 0..8 {
+        for x in 0..8 {
+            let left = small.get_pixel(x, y)[0];
+            let right = small.get_pixel(x + 1, y)[0];
+            hash = (hash << 1) | u64::from(left > right);
+        }
+    }
+    hash
+}
+
+/// Returns the similarity of two hashes in percent (100 = identical).
+#[must_use]
+pub fn similarity_percent(a: u64, b: u64) -> u32 {
+    (HASH_BITS - (a ^ b).count_ones()) * 100 / HASH_BITS
+}
+
+/// Hashes the image at `path`. Videos and undecodable files have no hash.
+fn hash_file(path: &Path) -> Option<u64> {
+    if detect_media_type(path) != Some(MediaType::Image) {
+        return None;
+    }
+    let image = image_rs::open(path).ok()?;
+    Some(difference_hash(&image))
+}
+
+/// Perceptual hashes of a directory's media, in navigation order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SimilarityIndex {
+    entries: Vec<(PathBuf, Option<u64>)>,
+}
+
+impl SimilarityIndex {
+    /// Builds the index by decoding and hashing every image.
+    ///
+    /// This decodes every file, so run it off the UI thread.
+    #[must_use]
+    pub fn build(paths: Vec<PathBuf>) -> Self {
+        let entries = paths
+            .into_iter()
+            .map(|path| {
+                let hash = hash_file(&path);
+                (path, hash)
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Creates an index from already known hashes.
+    #[must_use]
+    pub fn from_entries(entries: Vec<(PathBuf, Option<u64>)>) -> Self {
+        Self { entries }
+    }
+
+    /// Returns the number of other files at least `threshold` percent
+    /// similar to `current`.
+    #[must_use]
+    pub fn similar_count(&self, current: &Path, threshold: u32) -> usize {
+        self.similar_positions(current, threshold).len()
+    }
+
+    /// Returns the next file (in navigation order, wrapping around) at least
+    /// `threshold` percent similar to `current`.
+    #[must_use]
+    pub fn next_similar(&self, current: &Path, threshold: u32) -> Option<&Path> {
+        let position = self.position_of(current)?;
+        let similar = self.similar_positions(current, threshold);
+        similar
+            .iter()
+            .find(|&&index| index > position)
+            .or_else(|| similar.first())
+            .map(|&index| self.entries[index].0.as_path())
+    }
+
+    /// Returns the previous file (in navigation order, wrapping around) at
+    /// least `threshold` percent similar to `current`.
+    #[must_use]
+    pub fn previous_similar(&self, current: &Path, threshold: u32) -> Option<&Path> {
+        let position = self.position_of(current)?;
+        let similar = self.similar_positions(current, threshold);
+        similar
+            .iter()
+            .rev()
+            .find(|&&index| index < position)
+            .or_else(|| similar.last())
+            .map(|&index| self.entries[index].0.as_path())
+    }
+
+    fn position_of(&self, path: &Path) -> Option<usize> {
+        self.entries.iter().position(|(entry, _)| entry == path)
+    }
+
+    /// Positions of the files similar to `current`, excluding itself.
+    fn similar_positions(&self, current: &Path, threshold: u32) -> Vec<usize> {
+        let Some(position) = self.position_of(current) else {
+            return Vec::new();
+        };
+        let Some(hash) = self.entries[position].1 else {
+            return Vec::new();
+        };
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(|(index, (_, other))| {
+                *index != position
+                    && other.is_some_and(|other| similarity_percent(hash, other) >= threshold)
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image_rs::{GrayImage, Luma};
+
+    fn index() -> SimilarityIndex {
+        // A burst of near-identical shots, with an unrelated image and a video in between
+        SimilarityIndex::from_entries(vec![
+            (PathBuf::from("a.jpg"), Some(0x0000_0000_0000_00FF)),
+            (PathBuf::from("b.jpg"), Some(0xFFFF_0000_0000_0000)),
+            (PathBuf::from("c.jpg"), Some(0x0000_0000_0000_00FE)),
+            (PathBuf::from("d.mp4"), None),
+            (PathBuf::from("e.jpg"), Some(0x0000_0000_0000_01FF)),
+        ])
+    }
+
+    #[test]
+    fn similarity_counts_matching_bits() {
+        assert_eq!(similarity_percent(42, 42), 100);
+        assert_eq!(similarity_percent(0, u64::MAX), 0);
+        assert_eq!(similarity_percent(0, 0xFFFF_FFFF), 50);
+    }
+
+    #[test]
+    fn difference_hash_ignores_brightness_and_scale() {
+        let gradient = |width: u32, offset: u8| {
+            DynamicImage::ImageLuma8(GrayImage::from_fn(width, width / 2, |x, _| {
+                Luma([offset + u8::try_from(x * 100 / width).unwrap_or(0)])
+            }))
+        };
+        let hash = difference_hash(&gradient(90, 0));
+        assert_eq!(difference_hash(&gradient(180, 40)), hash);
+        assert_ne!(difference_hash(&gradient(90, 0).fliph()), hash);
+    }
+
+    #[test]
+    fn next_similar_skips_unrelated_files_and_wraps() {
+        let index = index();
+        assert_eq!(
+            index.next_similar(Path::new("a.jpg"), 90),
+            Some(Path::new("c.jpg"))
+        );
+        assert_eq!(
+            index.next_similar(Path::new("e.jpg"), 90),
+            Some(Path::new("a.jpg"))
+        );
+        assert_eq!(index.next_similar(Path::new("b.jpg"), 90), None);
+        assert_eq!(index.next_similar(Path::new("d.mp4"), 90), None);
+    }
+
+    #[test]
+    fn previous_similar_wraps_around() {
+        let index = index();
+        assert_eq!(
+            index.previous_similar(Path::new("a.jpg"), 90),
+            Some(Path::new("e.jpg"))
+        );
+        assert_eq!(
+            index.previous_similar(Path::new("e.jpg"), 90),
+            Some(Path::new("c.jpg"))
+        );
+    }
+
+    #[test]
+    fn threshold_narrows_matches() {
+        let index = index();
+        assert_eq!(index.similar_count(Path::new("a.jpg"), 90), 2);
+        assert_eq!(index.similar_count(Path::new("a.jpg"), 100), 0);
+        assert_eq!(index.similar_count(Path::new("a.jpg"), 50), 3);
+    }
+}
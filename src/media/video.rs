@@ -2,6 +2,7 @@
 //! Video handling and thumbnail extraction.
 
 use crate::error::{Error, Result};
+use crate::media::chapters::Chapter;
 use crate::media::ImageData;
 use std::path::Path;
 use std::sync::Once;
@@ -51,6 +52,12 @@ pub struct VideoMetadata {
     pub fps: f64,
     /// Whether the video has an audio track
     pub has_audio: bool,
+    /// Chapters, sorted by start time (empty when the container has none)
+    pub chapters: Vec<Chapter>,
+    /// Clockwise rotation (0, 90, 180 or 270) that displays frames upright
+    pub rotation: u16,
+    /// HDR properties, for HDR10 and HLG videos
+    pub hdr: Option<crate::media::hdr::HdrInfo>,
 }
 
 /// Extract thumbnail (first frame) from a video file.
@@ -232,13 +239,97 @@ pub fn extract_video_metadata<P: AsRef<Path>>(path: P) -> Result<VideoMetadata>
         duration_secs,
         fps,
         has_audio,
+        chapters: read_chapters(&ictx),
+        rotation: read_rotation(&video_stream),
+        hdr: crate::media::hdr::read_hdr_info(&video_stream, &decoder),
     })
 }
 
+/// Reads the display rotation of a video stream.
+///
+/// Phones record portrait videos as landscape frames plus a display matrix
+/// telling players to rotate them. Older files use a `rotate` tag instead.
+fn read_rotation(stream: &ffmpeg_next::format::stream::Stream) -> u16 {
+    // SAFETY: the codec parameters belong to the stream, which outlives this
+    // call; the display matrix side data is nine i32 values when present.
+    let matrix_angle = unsafe {
+        let parameters = stream.parameters().as_ptr();
+        let side_data = ffmpeg_next::ffi::av_packet_side_data_get(
+            (*parameters).coded_side_data,
+            (*parameters).nb_coded_side_data,
+            ffmpeg_next::ffi::AVPacketSideDataType::AV_PKT_DATA_DISPLAYMATRIX,
+        );
+        if side_data.is_null() || (*side_data).size < 9 * std::mem::size_of::<i32>() {
+            None
+        } else {
+            Some(ffmpeg_next::ffi::av_display_rotation_get(
+                (*side_data).data.cast::<i32>(),
+            ))
+        }
+    };
+
+    match matrix_angle {
+        // The display matrix angle is counter-clockwise
+        Some(angle) if angle.is_finite() => normalize_rotation(-angle),
+        _ => stream
+            .metadata()
+            .get("rotate")
+            .and_then(|tag| tag.trim().parse::<f64>().ok())
+            .map_or(0, normalize_rotation),
+    }
+}
+
+/// Converts a clockwise angle in degrees to the nearest quarter turn.
+// Rounded to a multiple of 90 in 0..360, so the cast is exact
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn normalize_rotation(clockwise_degrees: f64) -> u16 {
+    let quarter_turns = (clockwise_degrees / 90.0).round().rem_euclid(4.0);
+    quarter_turns as u16 * 90
+}
+
+/// Reads the container's chapters (MP4 chapter tracks, Matroska editions).
+fn read_chapters(ictx: &ffmpeg_next::format::context::Input) -> Vec<Chapter> {
+    let mut chapters: Vec<Chapter> = ictx
+        .chapters()
+        .map(|chapter| {
+            let time_base = f64::from(chapter.time_base());
+            // Chapter timestamps are far below f64's exact integer range
+            #[allow(clippy::cast_precision_loss)]
+            let (start_secs, end_secs) = (
+                chapter.start() as f64 * time_base,
+                chapter.end() as f64 * time_base,
+            );
+            Chapter {
+                start_secs: start_secs.max(0.0),
+                end_secs,
+                title: chapter
+                    .metadata()
+                    .get("title")
+                    .map(str::trim)
+                    .filter(|title| !title.is_empty())
+                    .map(String::from),
+            }
+        })
+        .collect();
+    chapters.sort_by(|a, b| a.start_secs.total_cmp(&b.start_secs));
+    chapters
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn normalize_rotation_snaps_to_quarter_turns() {
+        assert_eq!(normalize_rotation(0.0), 0);
+        assert_eq!(normalize_rotation(90.0), 90);
+        assert_eq!(normalize_rotation(-90.0), 270);
+        assert_eq!(normalize_rotation(180.0), 180);
+        assert_eq!(normalize_rotation(-180.0), 180);
+        assert_eq!(normalize_rotation(269.6), 270);
+        assert_eq!(normalize_rotation(360.0), 0);
+    }
+
     #[test]
     fn test_extract_thumbnail_requires_video() {
         // This test requires an actual video file at tests/data/sample.mp4
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Export of the viewer as displayed.
+//!
+//! Unlike the visible area export, which reads the pixels of the image at
+//! full resolution, this captures the screen pixels of the viewer pane: the
+//! zoomed crop, the rotation, the background and any overlay drawn over the
+//! media. It is meant for bug reports and for sharing what is on screen.
+
+use crate::media::frame_export::ExportableFrame;
+use iced::{window, Rectangle};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Where the exported view goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Destination {
+    /// A PNG file chosen by the user.
+    File,
+    /// The system clipboard, as an image.
+    Clipboard,
+}
+
+/// Crops a window screenshot to the viewer pane.
+///
+/// `bounds` is the pane in logical pixels; `None` keeps the whole window.
+/// Returns `None` if the pane lies outside the screenshot.
+#[must_use]
+pub fn from_screenshot(
+    screenshot: &window::Screenshot,
+    bounds: Option<Rectangle>,
+) -> Option<ExportableFrame> {
+    let shot = match bounds {
+        Some(bounds) => {
+            let region = physical_region(
+                bounds,
+                f64::from(screenshot.scale_factor),
+                screenshot.size.width,
+                screenshot.size.height,
+            )?;
+            screenshot.crop(region).ok()?
+        }
+        None => screenshot.clone(),
+    };
+    Some(ExportableFrame::new(
+        Arc::new(shot.rgba.to_vec()),
+        shot.size.width,
+        shot.size.height,
+    ))
+}
+
+/// Converts logical `bounds` to the physical pixels of a `width` × `height`
+/// screenshot, clamped to it. Returns `None` when nothing is left.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // Clamped to the screenshot size
+fn physical_region(
+    bounds: Rectangle,
+    scale_factor: f64,
+    width: u32,
+    height: u32,
+) -> Option<Rectangle<u32>> {
+    let to_physical = |value: f32, max: u32| {
+        (f64::from(value) * scale_factor)
+            .round()
+            .clamp(0.0, f64::from(max)) as u32
+    };
+    let left = to_physical(bounds.x, width);
+    let top = to_physical(bounds.y, height);
+    let right = to_physical(bounds.x + bounds.width, width);
+    let bottom = to_physical(bounds.y + bounds.height, height);
+    (right > left && bottom > top).then(|| Rectangle {
+        x: left,
+        y: top,
+        width: right - left,
+        height: bottom - top,
+    })
+}
+
+/// Puts `frame` on the system clipboard as an image.
+///
+/// The clipboard handle is kept for the whole session: on Linux the image is
+/// served by this process and would be lost with the handle.
+///
+/// # Errors
+///
+/// Returns an error if the clipboard is unavailable or rejects the image.
+pub fn copy_to_clipboard(frame: &ExportableFrame) -> Result<(), arboard::Error> {
+    static CLIPBOARD: OnceLock<Mutex<Option<arboard::Clipboard>>> = OnceLock::new();
+
+    let mut clipboard = CLIPBOARD
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if clipboard.is_none() {
+        *clipboard = Some(arboard::Clipboard::new()?);
+    }
+    let Some(clipboard) = clipboard.as_mut() else {
+        return Err(arboard::Error::ClipboardNotSupported);
+    };
+    clipboard.set_image(arboard::ImageData {
+        width: frame.width as usize,
+        height: frame.height as usize,
+        bytes: frame.rgba_data.as_slice().into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn physical_region_applies_the_scale_factor() {
+        let bounds = Rectangle {
+            x: 10.0,
+            y: 20.0,
+            width: 100.0,
+            height: 50.0,
+        };
+        let region = physical_region(bounds, 2.0, 1000, 1000).expect("region inside");
+        assert_eq!(
+            region,
+            Rectangle {
+                x: 20,
+                y: 40,
+                width: 200,
+                height: 100,
+            }
+        );
+    }
+
+    #[test]
+    fn physical_region_is_clamped_to_the_screenshot() {
+        let bounds = Rectangle {
+            x: -10.0,
+            y: 0.0,
+            width: 500.0,
+            height: 500.0,
+        };
+        let region = physical_region(bounds, 1.0, 300, 200).expect("region inside");
+        assert_eq!(region.x, 0);
+        assert_eq!(region.width, 300);
+        assert_eq!(region.height, 200);
+
+        let outside = Rectangle {
+            x: 400.0,
+            y: 0.0,
+            width: 10.0,
+            height: 10.0,
+        };
+        assert!(physical_region(outside, 1.0, 300, 200).is_none());
+    }
+}
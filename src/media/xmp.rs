@@ -11,6 +11,9 @@
 //! - dc:description - Description
 //! - dc:subject - Keywords/tags
 //! - dc:rights - Copyright/license
+//!
+//! The XMP basic `xmp:Rating` property is also read (see [`extract_xmp_rating`]),
+//! for filtering media by star rating.
 
 use quick_xml::events::Event;
 use quick_xml::Reader;
@@ -49,18 +52,23 @@ pub struct DublinCoreMetadata {
 const XMP_MARKER: &[u8] = b"http://ns.adobe.com/xap/1.0/";
 const DC_NS: &str = "http://purl.org/dc/elements/1.1/";
 
+/// Qualified name of the XMP basic rating property.
+const XMP_RATING: &[u8] = b"xmp:Rating";
+
 /// Extract XMP data from a JPEG file.
 ///
 /// XMP in JPEG is stored in APP1 segments with the marker `http://ns.adobe.com/xap/1.0/`.
 pub fn extract_xmp_from_jpeg<P: AsRef<Path>>(path: P) -> Option<DublinCoreMetadata> {
+    parse_xmp_xml(&read_jpeg_xmp_packet(path)?)
+}
+
+/// Read the raw XMP packet from a JPEG file.
+fn read_jpeg_xmp_packet<P: AsRef<Path>>(path: P) -> Option<Vec<u8>> {
     let file = File::open(path).ok()?;
     let mut reader = BufReader::new(file);
 
     // Find and extract XMP segment
-    let xmp_data = find_jpeg_xmp_segment(&mut reader)?;
-
-    // Parse XMP XML
-    parse_xmp_xml(&xmp_data)
+    find_jpeg_xmp_segment(&mut reader)
 }
 
 /// Extract XMP data from a PNG file.
@@ -68,6 +76,11 @@ pub fn extract_xmp_from_jpeg<P: AsRef<Path>>(path: P) -> Option<DublinCoreMetada
 /// XMP in PNG is stored in iTXt (international text) chunks with the keyword
 /// "XML:com.adobe.xmp" as specified by the XMP specification.
 pub fn extract_xmp_from_png<P: AsRef<Path>>(path: P) -> Option<DublinCoreMetadata> {
+    parse_xmp_xml(&read_png_xmp_packet(path)?)
+}
+
+/// Read the raw XMP packet from a PNG file.
+fn read_png_xmp_packet<P: AsRef<Path>>(path: P) -> Option<Vec<u8>> {
     let file = File::open(path).ok()?;
     let reader = BufReader::new(file);
 
@@ -81,7 +94,7 @@ pub fn extract_xmp_from_png<P: AsRef<Path>>(path: P) -> Option<DublinCoreMetadat
         if chunk.keyword == PNG_XMP_KEYWORD {
             // Use get_text() to decompress and retrieve the XMP XML data
             if let Ok(text) = chunk.get_text() {
-                return parse_xmp_xml(text.as_bytes());
+                return Some(text.into_bytes());
             }
         }
     }
@@ -94,6 +107,11 @@ pub fn extract_xmp_from_png<P: AsRef<Path>>(path: P) -> Option<DublinCoreMetadat
 /// XMP in WebP is stored in a RIFF chunk with `FourCC` 'XMP ' (note the trailing space).
 /// See RFC 9649 and Google's WebP container specification.
 pub fn extract_xmp_from_webp<P: AsRef<Path>>(path: P) -> Option<DublinCoreMetadata> {
+    parse_xmp_xml(&read_webp_xmp_packet(path)?)
+}
+
+/// Read the raw XMP packet from a WebP file.
+fn read_webp_xmp_packet<P: AsRef<Path>>(path: P) -> Option<Vec<u8>> {
     let file = File::open(path).ok()?;
     let mut reader = BufReader::new(file);
 
@@ -124,7 +142,7 @@ pub fn extract_xmp_from_webp<P: AsRef<Path>>(path: P) -> Option<DublinCoreMetada
             // Found XMP chunk, read its data
             let mut xmp_data = vec![0u8; chunk_size];
             reader.read_exact(&mut xmp_data).ok()?;
-            return parse_xmp_xml(&xmp_data);
+            return Some(xmp_data);
         }
 
         // Skip this chunk (add padding byte if size is odd)
@@ -141,6 +159,11 @@ pub fn extract_xmp_from_webp<P: AsRef<Path>>(path: P) -> Option<DublinCoreMetada
 ///
 /// XMP in TIFF is stored in IFD tag 700 as a byte array containing the XMP packet.
 pub fn extract_xmp_from_tiff<P: AsRef<Path>>(path: P) -> Option<DublinCoreMetadata> {
+    parse_xmp_xml(&read_tiff_xmp_packet(path)?)
+}
+
+/// Read the raw XMP packet from a TIFF file.
+fn read_tiff_xmp_packet<P: AsRef<Path>>(path: P) -> Option<Vec<u8>> {
     let file = File::open(path).ok()?;
     let mut reader = BufReader::new(file);
 
@@ -206,13 +229,45 @@ pub fn extract_xmp_from_tiff<P: AsRef<Path>>(path: P) -> Option<DublinCoreMetada
             let mut xmp_data = vec![0u8; count as usize];
             reader.read_exact(&mut xmp_data).ok()?;
 
-            return parse_xmp_xml(&xmp_data);
+            return Some(xmp_data);
         }
     }
 
     None
 }
 
+/// Extract the `xmp:Rating` value from a supported image file.
+///
+/// Returns the rating as stored: `-1` means rejected, `0` unrated, and
+/// `1`–`5` the number of stars. Returns `None` if the format has no XMP
+/// support or the file carries no rating.
+pub fn extract_xmp_rating<P: AsRef<Path>>(path: P) -> Option<i8> {
+    parse_xmp_rating(&read_xmp_packet(path.as_ref())?)
+}
+
+/// Extract every XMP property of a supported image file as `(name, value)` pairs.
+///
+/// Names are qualified (e.g. `dc:title`, `xmp:Rating`) and listed in packet
+/// order. Array and alternative items are joined with `, `. Returns an empty
+/// list if the format has no XMP support or the file carries no packet.
+pub fn extract_xmp_properties<P: AsRef<Path>>(path: P) -> Vec<(String, String)> {
+    read_xmp_packet(path.as_ref())
+        .map(|packet| parse_xmp_properties(&packet))
+        .unwrap_or_default()
+}
+
+/// Read the raw XMP packet of a supported image file, chosen by extension.
+fn read_xmp_packet(path: &Path) -> Option<Vec<u8>> {
+    let ext = path.extension()?.to_str()?.to_lowercase();
+    match ext.as_str() {
+        "jpg" | "jpeg" => read_jpeg_xmp_packet(path),
+        "png" => read_png_xmp_packet(path),
+        "webp" => read_webp_xmp_packet(path),
+        "tiff" | "tif" => read_tiff_xmp_packet(path),
+        _ => None,
+    }
+}
+
 /// Find XMP APP1 segment in JPEG file.
 fn find_jpeg_xmp_segment<R: Read + Seek>(reader: &mut R) -> Option<Vec<u8>> {
     let mut marker = [0u8; 2];
@@ -285,6 +340,133 @@ fn find_jpeg_xmp_segment<R: Read + Seek>(reader: &mut R) -> Option<Vec<u8>> {
     }
 }
 
+/// Parse XMP XML and extract the `xmp:Rating` value.
+///
+/// The rating may be serialized either as an attribute of
+/// `rdf:Description` or as a child element; both forms are accepted.
+/// The XMP spec types it as a Real, so fractional values are rounded.
+fn parse_xmp_rating(xmp_data: &[u8]) -> Option<i8> {
+    let mut reader = Reader::from_reader(xmp_data);
+    reader.config_mut().trim_text(true);
+
+    let mut buf = Vec::new();
+    let mut in_rating_element = false;
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) => {
+                // Attribute form: <rdf:Description xmp:Rating="4" .../>
+                for attr in e.attributes().flatten() {
+                    if attr.key.as_ref() == XMP_RATING {
+                        let value = attr.unescape_value().ok()?;
+                        return parse_rating_value(&value);
+                    }
+                }
+                in_rating_element = e.name().as_ref() == XMP_RATING;
+            }
+            Ok(Event::Text(ref e)) if in_rating_element => {
+                // Element form: <xmp:Rating>4</xmp:Rating>
+                let text = e.decode().ok()?;
+                return parse_rating_value(&text);
+            }
+            Ok(Event::End(_)) => in_rating_element = false,
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    None
+}
+
+/// Parse XMP XML into flat `(qualified name, value)` pairs.
+///
+/// Simple properties may be serialized as attributes of `rdf:Description`
+/// or as elements; RDF containers (`rdf:Seq`, `rdf:Bag`, `rdf:Alt`) are
+/// flattened into their enclosing property.
+fn parse_xmp_properties(xmp_data: &[u8]) -> Vec<(String, String)> {
+    let mut properties: Vec<(String, String)> = Vec::new();
+    let mut reader = Reader::from_reader(xmp_data);
+    reader.config_mut().trim_text(true);
+
+    let mut buf = Vec::new();
+    let mut open_elements: Vec<String> = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                collect_attribute_properties(e, &mut properties);
+                open_elements.push(String::from_utf8_lossy(e.name().as_ref()).to_string());
+            }
+            Ok(Event::Empty(ref e)) => collect_attribute_properties(e, &mut properties),
+            Ok(Event::Text(ref e)) => {
+                let property = open_elements
+                    .iter()
+                    .rev()
+                    .find(|name| !is_xmp_structural_name(name));
+                if let (Some(property), Ok(text)) = (property, e.decode()) {
+                    push_xmp_property(&mut properties, property, text.trim());
+                }
+            }
+            Ok(Event::End(_)) => {
+                open_elements.pop();
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    properties
+}
+
+/// Add the property attributes of an element (skipping namespace and RDF syntax).
+fn collect_attribute_properties(
+    element: &quick_xml::events::BytesStart<'_>,
+    properties: &mut Vec<(String, String)>,
+) {
+    for attr in element.attributes().flatten() {
+        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
+        if key.starts_with("xmlns") || key.starts_with("xml:") || is_xmp_structural_name(&key) {
+            continue;
+        }
+        if let Ok(value) = attr.unescape_value() {
+            push_xmp_property(properties, &key, value.trim());
+        }
+    }
+}
+
+/// Returns true for RDF syntax and packet wrapper names, which are not properties.
+fn is_xmp_structural_name(name: &str) -> bool {
+    name.starts_with("rdf:") || name.starts_with("x:")
+}
+
+/// Append a property value, merging consecutive items of the same array.
+fn push_xmp_property(properties: &mut Vec<(String, String)>, name: &str, value: &str) {
+    if value.is_empty() {
+        return;
+    }
+    match properties.last_mut() {
+        Some((last_name, last_value)) if last_name == name => {
+            last_value.push_str(", ");
+            last_value.push_str(value);
+        }
+        _ => properties.push((name.to_string(), value.to_string())),
+    }
+}
+
+/// Parse a rating string, clamping to the XMP range (-1 to 5).
+fn parse_rating_value(value: &str) -> Option<i8> {
+    let rating: f32 = value.trim().parse().ok()?;
+    if !rating.is_finite() {
+        return None;
+    }
+    // Clamped to -1..=5 so the cast cannot truncate
+    #[allow(clippy::cast_possible_truncation)]
+    let rating = rating.round().clamp(-1.0, 5.0) as i8;
+    Some(rating)
+}
+
 /// Parse XMP XML and extract Dublin Core metadata.
 fn parse_xmp_xml(xmp_data: &[u8]) -> Option<DublinCoreMetadata> {
     let mut metadata = DublinCoreMetadata::default();
@@ -337,6 +519,8 @@ fn parse_xmp_xml(xmp_data: &[u8]) -> Option<DublinCoreMetadata> {
                             "creator" => metadata.creator = Some(text),
                             "description" => metadata.description = Some(text),
                             "rights" => metadata.rights = Some(text),
+                            // Keywords are serialized as an rdf:Bag (or Seq) of items
+                            "subject" => current_subjects.push(text),
                             "li" if in_rdf_seq => {
                                 current_subjects.push(text);
                             }
@@ -433,6 +617,14 @@ mod tests {
         assert_eq!(metadata.creator, Some("John Doe".to_string()));
         assert_eq!(metadata.description, Some("A beautiful sunset".to_string()));
         assert_eq!(metadata.rights, Some("© 2024 John Doe".to_string()));
+        assert_eq!(
+            metadata.subject,
+            Some(vec![
+                "sunset".to_string(),
+                "nature".to_string(),
+                "landscape".to_string()
+            ])
+        );
     }
 
     #[test]
@@ -457,4 +649,75 @@ mod tests {
         assert!(metadata.subject.is_none());
         assert!(metadata.rights.is_none());
     }
+
+    #[test]
+    fn parse_xmp_rating_reads_attribute_form() {
+        let xmp = br#"<x:xmpmeta xmlns:x="adobe:ns:meta/">
+  <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+    <rdf:Description rdf:about="" xmlns:xmp="http://ns.adobe.com/xap/1.0/"
+        xmp:Rating="4"/>
+  </rdf:RDF>
+</x:xmpmeta>"#;
+
+        assert_eq!(parse_xmp_rating(xmp), Some(4));
+    }
+
+    #[test]
+    fn parse_xmp_rating_reads_element_form() {
+        let xmp = br#"<x:xmpmeta xmlns:x="adobe:ns:meta/">
+  <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+    <rdf:Description rdf:about="" xmlns:xmp="http://ns.adobe.com/xap/1.0/">
+      <xmp:Rating>-1</xmp:Rating>
+    </rdf:Description>
+  </rdf:RDF>
+</x:xmpmeta>"#;
+
+        assert_eq!(parse_xmp_rating(xmp), Some(-1));
+    }
+
+    #[test]
+    fn parse_xmp_rating_returns_none_without_rating() {
+        let xmp = br#"<x:xmpmeta xmlns:x="adobe:ns:meta/">
+  <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+    <rdf:Description rdf:about="" xmlns:dc="http://purl.org/dc/elements/1.1/"/>
+  </rdf:RDF>
+</x:xmpmeta>"#;
+
+        assert_eq!(parse_xmp_rating(xmp), None);
+    }
+
+    #[test]
+    fn parse_xmp_properties_flattens_attributes_and_arrays() {
+        let xmp = br#"<x:xmpmeta xmlns:x="adobe:ns:meta/" x:xmptk="XMP Core 6.0">
+  <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+    <rdf:Description rdf:about="" xmlns:xmp="http://ns.adobe.com/xap/1.0/"
+        xmlns:dc="http://purl.org/dc/elements/1.1/" xmp:Rating="3">
+      <dc:subject>
+        <rdf:Bag>
+          <rdf:li>sunset</rdf:li>
+          <rdf:li>nature</rdf:li>
+        </rdf:Bag>
+      </dc:subject>
+      <xmp:CreatorTool>Darktable</xmp:CreatorTool>
+    </rdf:Description>
+  </rdf:RDF>
+</x:xmpmeta>"#;
+
+        assert_eq!(
+            parse_xmp_properties(xmp),
+            vec![
+                ("xmp:Rating".to_string(), "3".to_string()),
+                ("dc:subject".to_string(), "sunset, nature".to_string()),
+                ("xmp:CreatorTool".to_string(), "Darktable".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_rating_value_rounds_and_clamps() {
+        assert_eq!(parse_rating_value("3.6"), Some(4));
+        assert_eq!(parse_rating_value("12"), Some(5));
+        assert_eq!(parse_rating_value("-7"), Some(-1));
+        assert_eq!(parse_rating_value("abc"), None);
+    }
 }
@@ -40,6 +40,9 @@ pub struct ViewContext<'a> {
 #[derive(Debug, Clone)]
 pub enum Message {
     BackToViewer,
+    ViewLogs,
+    ViewMissingTranslations,
+    CopyDiagnosticReport,
 }
 
 /// Events propagated to the parent application.
@@ -47,6 +50,9 @@ pub enum Message {
 pub enum Event {
     None,
     BackToViewer,
+    ViewLogs,
+    ViewMissingTranslations,
+    CopyDiagnosticReport,
 }
 
 /// Process an about screen message and return the corresponding event.
@@ -54,6 +60,9 @@ pub enum Event {
 pub fn update(message: &Message) -> Event {
     match message {
         Message::BackToViewer => Event::BackToViewer,
+        Message::ViewLogs => Event::ViewLogs,
+        Message::ViewMissingTranslations => Event::ViewMissingTranslations,
+        Message::CopyDiagnosticReport => Event::CopyDiagnosticReport,
     }
 }
 
@@ -75,6 +84,7 @@ pub fn view(ctx: ViewContext<'_>) -> Element<'_, Message> {
     let credits_section = build_credits_section(&ctx);
     let third_party_section = build_third_party_section(&ctx);
     let links_section = build_links_section(&ctx);
+    let troubleshooting_section = build_troubleshooting_section(&ctx);
 
     let content = Column::new()
         .width(Length::Fill)
@@ -88,7 +98,8 @@ pub fn view(ctx: ViewContext<'_>) -> Element<'_, Message> {
         .push(icon_license_section)
         .push(credits_section)
         .push(third_party_section)
-        .push(links_section);
+        .push(links_section)
+        .push(troubleshooting_section);
 
     scrollable(content).into()
 }
@@ -214,6 +225,35 @@ fn build_links_section<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
     )
 }
 
+/// Build the troubleshooting section (logs and diagnostic report).
+fn build_troubleshooting_section<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
+    let content = Column::new()
+        .spacing(spacing::SM)
+        .push(Text::new(ctx.i18n.tr("about-troubleshooting-hint")).size(typography::BODY))
+        .push(
+            Row::new()
+                .spacing(spacing::SM)
+                .push(
+                    button(Text::new(ctx.i18n.tr("about-view-logs-button")))
+                        .on_press(Message::ViewLogs),
+                )
+                .push(
+                    button(Text::new(ctx.i18n.tr("about-copy-report-button")))
+                        .on_press(Message::CopyDiagnosticReport),
+                )
+                .push(
+                    button(Text::new(ctx.i18n.tr("about-missing-translations-button")))
+                        .on_press(Message::ViewMissingTranslations),
+                ),
+        );
+
+    build_section(
+        icons::cog(),
+        ctx.i18n.tr("about-section-troubleshooting"),
+        content.into(),
+    )
+}
+
 /// Build a link item with label and URL.
 fn build_link_item<'a>(label: &str, url: &'a str) -> Element<'a, Message> {
     Row::new()
@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Accessibility options: reduced motion, high-contrast focus outlines,
+//! larger hit targets and always-visible fullscreen controls.
+//!
+//! The settings screen owns these options and saves them in the
+//! `[accessibility]` section. Style functions and widgets only get the theme,
+//! so the options in use are also kept process-wide: the application calls
+//! [`set`] whenever they change and the UI reads them with [`current`].
+
+use crate::config::AccessibilityConfig;
+use crate::ui::design_tokens::{sizing, spacing};
+use iced::Padding;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+const REDUCED_MOTION: u8 = 1;
+const HIGH_CONTRAST_FOCUS: u8 = 1 << 1;
+const LARGE_TARGETS: u8 = 1 << 2;
+const ALWAYS_SHOW_FULLSCREEN_CONTROLS: u8 = 1 << 3;
+
+static OPTIONS: AtomicU8 = AtomicU8::new(0);
+
+/// Accessibility options, all off by default.
+// Allow excessive bools: each option is an independent on/off switch.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Spinners stand still instead of rotating.
+    pub reduced_motion: bool,
+    /// Focused fields get a thick outline in the text color.
+    pub high_contrast_focus: bool,
+    /// Toolbar and video control buttons are larger.
+    pub large_targets: bool,
+    /// Fullscreen controls stay visible instead of hiding after a delay.
+    pub always_show_fullscreen_controls: bool,
+}
+
+impl Options {
+    /// Builds the options from the `[accessibility]` configuration section.
+    #[must_use]
+    pub fn from_config(config: &AccessibilityConfig) -> Self {
+        Self {
+            reduced_motion: config.reduced_motion.unwrap_or(false),
+            high_contrast_focus: config.high_contrast_focus.unwrap_or(false),
+            large_targets: config.large_targets.unwrap_or(false),
+            always_show_fullscreen_controls: config
+                .always_show_fullscreen_controls
+                .unwrap_or(false),
+        }
+    }
+
+    /// Returns the `[accessibility]` configuration section of the options.
+    #[must_use]
+    pub fn to_config(self) -> AccessibilityConfig {
+        AccessibilityConfig {
+            reduced_motion: Some(self.reduced_motion),
+            high_contrast_focus: Some(self.high_contrast_focus),
+            large_targets: Some(self.large_targets),
+            always_show_fullscreen_controls: Some(self.always_show_fullscreen_controls),
+        }
+    }
+
+    fn to_bits(self) -> u8 {
+        [
+            (self.reduced_motion, REDUCED_MOTION),
+            (self.high_contrast_focus, HIGH_CONTRAST_FOCUS),
+            (self.large_targets, LARGE_TARGETS),
+            (
+                self.always_show_fullscreen_controls,
+                ALWAYS_SHOW_FULLSCREEN_CONTROLS,
+            ),
+        ]
+        .into_iter()
+        .filter(|(enabled, _)| *enabled)
+        .fold(0, |bits, (_, flag)| bits | flag)
+    }
+
+    fn from_bits(bits: u8) -> Self {
+        Self {
+            reduced_motion: bits & REDUCED_MOTION != 0,
+            high_contrast_focus: bits & HIGH_CONTRAST_FOCUS != 0,
+            large_targets: bits & LARGE_TARGETS != 0,
+            always_show_fullscreen_controls: bits & ALWAYS_SHOW_FULLSCREEN_CONTROLS != 0,
+        }
+    }
+}
+
+/// Sets the options used by the whole interface.
+pub fn set(options: Options) {
+    OPTIONS.store(options.to_bits(), Ordering::Relaxed);
+}
+
+/// Returns the options used by the whole interface.
+#[must_use]
+pub fn current() -> Options {
+    Options::from_bits(OPTIONS.load(Ordering::Relaxed))
+}
+
+/// Returns the height of toolbar and video control buttons.
+#[must_use]
+pub fn button_height() -> f32 {
+    if current().large_targets {
+        sizing::BUTTON_HEIGHT_LARGE
+    } else {
+        sizing::BUTTON_HEIGHT
+    }
+}
+
+/// Returns the size of the square icon buttons of the viewer toolbar.
+#[must_use]
+pub fn icon_button_size() -> f32 {
+    if current().large_targets {
+        sizing::BUTTON_HEIGHT_LARGE
+    } else {
+        sizing::ICON_LG
+    }
+}
+
+/// Returns `padding` grown by [`spacing::XXS`] on each side when larger hit
+/// targets are on, for buttons whose size follows their content.
+#[must_use]
+pub fn enlarge(padding: impl Into<Padding>) -> Padding {
+    let padding = padding.into();
+    if current().large_targets {
+        Padding {
+            top: padding.top + spacing::XXS,
+            right: padding.right + spacing::XXS,
+            bottom: padding.bottom + spacing::XXS,
+            left: padding.left + spacing::XXS,
+        }
+    } else {
+        padding
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_round_trip_through_bits() {
+        let options = Options {
+            reduced_motion: true,
+            high_contrast_focus: false,
+            large_targets: true,
+            always_show_fullscreen_controls: true,
+        };
+        assert_eq!(Options::from_bits(options.to_bits()), options);
+        assert_eq!(Options::default().to_bits(), 0);
+    }
+
+    #[test]
+    fn options_round_trip_through_config() {
+        let options = Options {
+            high_contrast_focus: true,
+            always_show_fullscreen_controls: true,
+            ..Options::default()
+        };
+        assert_eq!(Options::from_config(&options.to_config()), options);
+        assert_eq!(
+            Options::from_config(&AccessibilityConfig::default()),
+            Options::default()
+        );
+    }
+}
@@ -213,6 +213,30 @@ pub mod video {
         pub fn speed_up() -> Image<Handle> {
             icons::light::triangle_plus()
         }
+
+        /// Jump to the next chapter (light icon for toolbar).
+        #[must_use]
+        pub fn next_chapter() -> Image<Handle> {
+            icons::light::double_triangle_bar_right()
+        }
+
+        /// Jump to the previous chapter (light icon for toolbar).
+        #[must_use]
+        pub fn previous_chapter() -> Image<Handle> {
+            icons::light::double_triangle_bar_left()
+        }
+
+        /// Open the chapter list (light icon for toolbar).
+        #[must_use]
+        pub fn chapter_list() -> Image<Handle> {
+            icons::light::bullet_list()
+        }
+
+        /// Leave the mini player for the main window (light icon for toolbar).
+        #[must_use]
+        pub fn return_to_main() -> Image<Handle> {
+            icons::light::expand()
+        }
     }
 }
 
@@ -372,6 +396,42 @@ pub mod viewer {
             icons::light::rotate_left()
         }
 
+        /// Flip the video horizontally (light icon for toolbar).
+        #[must_use]
+        pub fn mirror() -> Image<Handle> {
+            icons::light::flip_horizontal()
+        }
+
+        /// Export the visible area (light icon for toolbar).
+        #[must_use]
+        pub fn export_visible_area() -> Image<Handle> {
+            icons::light::camera()
+        }
+
+        /// Inspect the pixel under the cursor (light icon for toolbar).
+        #[must_use]
+        pub fn pixel_inspector() -> Image<Handle> {
+            icons::overlay::magnifier()
+        }
+
+        /// Measure distances and angles (light icon for toolbar).
+        #[must_use]
+        pub fn ruler() -> Image<Handle> {
+            icons::light::ruler()
+        }
+
+        /// Show composition guides (light icon for toolbar).
+        #[must_use]
+        pub fn guides() -> Image<Handle> {
+            icons::light::grid()
+        }
+
+        /// Lock the view across navigation (light icon for toolbar).
+        #[must_use]
+        pub fn lock_view() -> Image<Handle> {
+            icons::overlay::crosshair()
+        }
+
         /// Filter icon (light icon for toolbar).
         #[must_use]
         pub fn filter() -> Image<Handle> {
@@ -438,6 +498,17 @@ pub mod navigation {
         }
     }
 
+    /// Remove all metadata from a file.
+    /// Returns dark icon for light theme, light icon for dark theme.
+    #[must_use]
+    pub fn strip_metadata(is_dark_theme: bool) -> Image<Handle> {
+        if is_dark_theme {
+            icons::light::trash()
+        } else {
+            icons::trash()
+        }
+    }
+
     /// Collapse a left-side panel (chevron points left).
     /// Returns dark icon for light theme, light icon for dark theme.
     #[must_use]
@@ -489,7 +560,7 @@ pub mod navigation {
     #[must_use]
     pub fn previous(background: BackgroundTheme) -> Image<Handle> {
         match background {
-            BackgroundTheme::Dark => icons::overlay::chevron_left(),
+            BackgroundTheme::Dark | BackgroundTheme::Auto => icons::overlay::chevron_left(),
             BackgroundTheme::Light | BackgroundTheme::Checkerboard => icons::chevron_left(),
         }
     }
@@ -501,7 +572,7 @@ pub mod navigation {
     #[must_use]
     pub fn next(background: BackgroundTheme) -> Image<Handle> {
         match background {
-            BackgroundTheme::Dark => icons::overlay::chevron_right(),
+            BackgroundTheme::Dark | BackgroundTheme::Auto => icons::overlay::chevron_right(),
             BackgroundTheme::Light | BackgroundTheme::Checkerboard => icons::chevron_right(),
         }
     }
@@ -513,7 +584,7 @@ pub mod navigation {
     #[must_use]
     pub fn loop_indicator(background: BackgroundTheme) -> Image<Handle> {
         match background {
-            BackgroundTheme::Dark => icons::overlay::loop_icon(),
+            BackgroundTheme::Dark | BackgroundTheme::Auto => icons::overlay::loop_icon(),
             BackgroundTheme::Light | BackgroundTheme::Checkerboard => icons::loop_icon(),
         }
     }
@@ -631,6 +702,12 @@ pub mod hud {
     pub fn rotation() -> Image<Handle> {
         icons::overlay::rotate_right()
     }
+
+    /// Matte/alpha preview indicator.
+    #[must_use]
+    pub fn alpha_preview() -> Image<Handle> {
+        icons::overlay::image()
+    }
 }
 
 // =============================================================================
@@ -792,6 +869,9 @@ mod tests {
         let _ = video::toolbar::more_options();
         let _ = video::toolbar::speed_down();
         let _ = video::toolbar::speed_up();
+        let _ = video::toolbar::next_chapter();
+        let _ = video::toolbar::previous_chapter();
+        let _ = video::toolbar::chapter_list();
     }
 
     #[test]
@@ -803,5 +883,7 @@ mod tests {
         let _ = viewer::toolbar::expand();
         let _ = viewer::toolbar::fullscreen();
         let _ = viewer::toolbar::delete();
+        let _ = viewer::toolbar::mirror();
+        let _ = viewer::toolbar::export_visible_area();
     }
 }
@@ -31,6 +31,13 @@ let overlay_bg = Color {
 let padding = spacing::MD; // 16px
 ```
 
+## Interface Scale
+
+Sizes are in logical pixels. The interface scale setting (75%–200%) is
+applied as the scale factor of every window, so it multiplies all spacing,
+typography and sizing tokens at once without changing their values here.
+Media zoom divides it out again, so images are not scaled with the interface.
+
 ## Modification
 
 ⚠️ Tokens are designed to be consistent. Before modifying:
@@ -141,6 +148,8 @@ pub mod sizing {
 
     // Interactive element heights
     pub const BUTTON_HEIGHT: f32 = 36.0;
+    /// Button height with the "larger hit targets" accessibility option.
+    pub const BUTTON_HEIGHT_LARGE: f32 = 44.0;
     pub const INPUT_HEIGHT: f32 = 40.0;
 
     // Video controls
@@ -219,6 +228,9 @@ pub mod border {
 
     /// Medium border - Emphasis borders, toast accents
     pub const WIDTH_MD: f32 = 2.0;
+
+    /// Focus outline width with the "high-contrast focus" accessibility option.
+    pub const WIDTH_FOCUS: f32 = 3.0;
 }
 
 // ============================================================================
@@ -286,6 +298,7 @@ const _: () = {
     // Sizing validation
     assert!(sizing::ICON_XL > sizing::ICON_LG);
     assert!(sizing::ICON_LG > sizing::ICON_MD);
+    assert!(sizing::BUTTON_HEIGHT_LARGE > sizing::BUTTON_HEIGHT);
 
     // Typography validation
     assert!(typography::TITLE_LG > typography::TITLE_MD);
@@ -296,6 +309,7 @@ const _: () = {
 
     // Border validation
     assert!(border::WIDTH_MD > border::WIDTH_SM);
+    assert!(border::WIDTH_FOCUS > border::WIDTH_MD);
 
     // Color validation
     assert!(palette::PRIMARY_500.r >= 0.0 && palette::PRIMARY_500.r <= 1.0);
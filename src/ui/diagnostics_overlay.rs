@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Overlay showing performance figures, toggled with F12.
+//!
+//! Figures come from a [`Snapshot`] of the [`crate::diagnostics`] store,
+//! refreshed by the application tick while the overlay is shown.
+
+use crate::diagnostics::{CacheCounters, Snapshot};
+use crate::i18n::fluent::I18n;
+use crate::ui::design_tokens::{spacing, typography};
+use crate::ui::styles;
+use iced::widget::{Column, Container, Row, Text};
+use iced::{Element, Length};
+
+/// Width of the overlay.
+const OVERLAY_WIDTH: f32 = 300.0;
+
+/// Renders the diagnostics of `snapshot`.
+pub fn view<'a, M: 'a>(i18n: &I18n, snapshot: &Snapshot) -> Element<'a, M> {
+    let row = |label: String, value: String| {
+        Row::new()
+            .spacing(spacing::XS)
+            .push(
+                Text::new(label)
+                    .size(typography::CAPTION)
+                    .width(Length::Fill),
+            )
+            .push(Text::new(value).size(typography::CAPTION))
+    };
+
+    let memory = snapshot
+        .resident_memory_bytes
+        .map_or_else(|| i18n.tr("diagnostics-unavailable"), format_memory);
+
+    let mut content = Column::new()
+        .spacing(spacing::XXS)
+        .push(Text::new(i18n.tr("diagnostics-title")).size(typography::BODY))
+        .push(row(
+            i18n.tr("diagnostics-decode-fps"),
+            snapshot.decode_fps.to_string(),
+        ))
+        .push(row(
+            i18n.tr("diagnostics-dropped-frames"),
+            snapshot.dropped_frames.to_string(),
+        ))
+        .push(row(
+            i18n.tr("diagnostics-frame-queue"),
+            snapshot.frame_queue_depth.to_string(),
+        ))
+        .push(row(
+            i18n.tr("diagnostics-frame-cache"),
+            format_hit_rate(i18n, snapshot.video_frame_cache),
+        ))
+        .push(row(
+            i18n.tr("diagnostics-prefetch-cache"),
+            format_hit_rate(i18n, snapshot.prefetch_cache),
+        ))
+        .push(row(i18n.tr("diagnostics-memory"), memory))
+        .push(Text::new(i18n.tr("diagnostics-recent-loads")).size(typography::CAPTION));
+
+    if snapshot.recent_loads.is_empty() {
+        content =
+            content.push(Text::new(i18n.tr("diagnostics-unavailable")).size(typography::CAPTION));
+    }
+    for load in &snapshot.recent_loads {
+        let duration = if load.succeeded {
+            format!("{} ms", load.duration.as_millis())
+        } else {
+            i18n.tr_with_args(
+                "diagnostics-load-failed",
+                &[("ms", load.duration.as_millis().to_string().as_str())],
+            )
+        };
+        content = content.push(row(load.file_name.clone(), duration));
+    }
+
+    Container::new(content)
+        .padding(spacing::XS)
+        .width(Length::Fixed(OVERLAY_WIDTH))
+        .style(styles::overlay::indicator(4.0))
+        .into()
+}
+
+/// Formats the hit rate of `counters` with the number of lookups.
+fn format_hit_rate(i18n: &I18n, counters: CacheCounters) -> String {
+    counters.hit_rate().map_or_else(
+        || i18n.tr("diagnostics-unavailable"),
+        |rate| format!("{rate:.0}% ({})", counters.hits + counters.misses),
+    )
+}
+
+/// Formats a number of bytes in mebibytes.
+#[allow(clippy::cast_precision_loss)] // Display only
+fn format_memory(bytes: u64) -> String {
+    format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_is_shown_in_mebibytes() {
+        assert_eq!(format_memory(0), "0.0 MiB");
+        assert_eq!(format_memory(3 * 1024 * 1024 + 512 * 1024), "3.5 MiB");
+    }
+}
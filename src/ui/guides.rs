@@ -0,0 +1,292 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Composition guides drawn over the viewer and editor canvas.
+//!
+//! The guides to draw are chosen in the settings (`[guides]` section):
+//! rule-of-thirds grid, golden ratio lines, action-safe and title-safe
+//! frames, custom horizontal and vertical lines, and a pixel grid that only
+//! appears once pixels are large enough to tell apart. The viewer and the
+//! editor show or hide them with a toolbar button. Like the face outlines,
+//! the guides are laid out at the scaled size of the image, so they follow
+//! zoom and panning.
+
+use crate::config::GuidesConfig;
+use crate::ui::design_tokens::{opacity, palette};
+use iced::widget::canvas;
+use iced::{mouse, Color, Element, Length, Point, Rectangle, Size, Theme};
+
+/// Zoom from which the pixel grid is drawn, in percent.
+pub const PIXEL_GRID_MIN_ZOOM_PERCENT: f32 = 800.0;
+
+/// Position of the first golden ratio line, `1 - 1/φ`; the second one is
+/// symmetric.
+const GOLDEN_SECTION: f32 = 0.381_966;
+
+/// Action-safe and title-safe frames, in fractions of each side (SMPTE RP
+/// 2046-2).
+const SAFE_AREAS: [f32; 2] = [0.93, 0.90];
+
+/// Width of the guide lines, in logical pixels.
+const LINE_WIDTH: f32 = 1.0;
+
+/// Guides drawn while they are shown.
+// Allow excessive bools: each guide is shown or hidden independently.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub thirds: bool,
+    pub golden_ratio: bool,
+    /// Outline of each pixel, from [`PIXEL_GRID_MIN_ZOOM_PERCENT`].
+    pub pixel_grid: bool,
+    pub safe_areas: bool,
+    /// Custom horizontal lines, in percent of the height from the top.
+    pub horizontal: Vec<f32>,
+    /// Custom vertical lines, in percent of the width from the left.
+    pub vertical: Vec<f32>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::from_config(&GuidesConfig::default())
+    }
+}
+
+impl Options {
+    /// Builds the options from the `[guides]` configuration section,
+    /// dropping custom lines outside of the image.
+    #[must_use]
+    pub fn from_config(config: &GuidesConfig) -> Self {
+        let positions = |values: &[f32]| {
+            values
+                .iter()
+                .copied()
+                .filter(|value| (0.0..=100.0).contains(value))
+                .collect()
+        };
+        Self {
+            thirds: config.thirds.unwrap_or(true),
+            golden_ratio: config.golden_ratio.unwrap_or(false),
+            pixel_grid: config.pixel_grid.unwrap_or(true),
+            safe_areas: config.safe_areas.unwrap_or(false),
+            horizontal: positions(&config.horizontal),
+            vertical: positions(&config.vertical),
+        }
+    }
+
+    /// Returns the `[guides]` configuration section of the options.
+    #[must_use]
+    pub fn to_config(&self) -> GuidesConfig {
+        GuidesConfig {
+            thirds: Some(self.thirds),
+            golden_ratio: Some(self.golden_ratio),
+            pixel_grid: Some(self.pixel_grid),
+            safe_areas: Some(self.safe_areas),
+            horizontal: self.horizontal.clone(),
+            vertical: self.vertical.clone(),
+        }
+    }
+}
+
+/// Parses custom guide positions such as "25, 50, 75", in percent and
+/// separated by commas, semicolons or spaces. Returns `None` if a value is
+/// not a number from 0 to 100.
+#[must_use]
+pub fn parse_positions(text: &str) -> Option<Vec<f32>> {
+    text.split([',', ';', ' '])
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            value
+                .parse::<f32>()
+                .ok()
+                .filter(|value| (0.0..=100.0).contains(value))
+        })
+        .collect()
+}
+
+/// Formats custom guide positions for editing, such as "25, 50, 75".
+#[must_use]
+pub fn format_positions(positions: &[f32]) -> String {
+    positions
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Builds the guides for an image of `pixels` (width and height as
+/// displayed) shown at `zoom_percent`, laid out at `display_size`.
+pub fn view<'a, Message: 'a>(
+    options: &Options,
+    pixels: (u32, u32),
+    zoom_percent: f32,
+    display_size: Size,
+) -> Element<'a, Message> {
+    canvas::Canvas::new(GuidesProgram {
+        options: options.clone(),
+        pixels,
+        pixel_grid: options.pixel_grid && zoom_percent >= PIXEL_GRID_MIN_ZOOM_PERCENT,
+    })
+    .width(Length::Fixed(display_size.width))
+    .height(Length::Fixed(display_size.height))
+    .into()
+}
+
+struct GuidesProgram {
+    options: Options,
+    /// Width and height of the image in pixels, as displayed.
+    pixels: (u32, u32),
+    /// Whether pixels are large enough for the pixel grid.
+    pixel_grid: bool,
+}
+
+impl GuidesProgram {
+    /// Returns the fractions of the side at which lines are drawn: thirds,
+    /// golden sections, then `custom` lines given in percent.
+    fn fractions(&self, custom: &[f32]) -> Vec<f32> {
+        let mut fractions = Vec::new();
+        if self.options.thirds {
+            fractions.extend([1.0 / 3.0, 2.0 / 3.0]);
+        }
+        if self.options.golden_ratio {
+            fractions.extend([GOLDEN_SECTION, 1.0 - GOLDEN_SECTION]);
+        }
+        fractions.extend(custom.iter().map(|percent| percent / 100.0));
+        fractions
+    }
+}
+
+impl<Message> canvas::Program<Message> for GuidesProgram {
+    type State = ();
+
+    // Pixel counts are far below f32 precision limits
+    #[allow(clippy::cast_precision_loss)]
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let Size { width, height } = bounds.size();
+        let halo = Color {
+            a: opacity::OVERLAY_MEDIUM,
+            ..palette::BLACK
+        };
+
+        if self.pixel_grid {
+            let (columns, rows) = self.pixels;
+            let cell_width = width / columns.max(1) as f32;
+            let cell_height = height / rows.max(1) as f32;
+            let grid = canvas::Path::new(|builder| {
+                for column in 1..columns {
+                    let x = column as f32 * cell_width;
+                    builder.move_to(Point::new(x, 0.0));
+                    builder.line_to(Point::new(x, height));
+                }
+                for row in 1..rows {
+                    let y = row as f32 * cell_height;
+                    builder.move_to(Point::new(0.0, y));
+                    builder.line_to(Point::new(width, y));
+                }
+            });
+            frame.stroke(
+                &grid,
+                canvas::Stroke::default()
+                    .with_width(LINE_WIDTH)
+                    .with_color(Color {
+                        a: opacity::OVERLAY_MEDIUM,
+                        ..palette::GRAY_400
+                    }),
+            );
+        }
+
+        let lines = canvas::Path::new(|builder| {
+            for fraction in self.fractions(&self.options.vertical) {
+                builder.move_to(Point::new(fraction * width, 0.0));
+                builder.line_to(Point::new(fraction * width, height));
+            }
+            for fraction in self.fractions(&self.options.horizontal) {
+                builder.move_to(Point::new(0.0, fraction * height));
+                builder.line_to(Point::new(width, fraction * height));
+            }
+        });
+
+        let safe_areas = canvas::Path::new(|builder| {
+            if self.options.safe_areas {
+                for ratio in SAFE_AREAS {
+                    let size = Size::new(width * ratio, height * ratio);
+                    let origin =
+                        Point::new((width - size.width) / 2.0, (height - size.height) / 2.0);
+                    builder.rectangle(origin, size);
+                }
+            }
+        });
+
+        // Dark halo under white lines keeps them visible on any image
+        for path in [&lines, &safe_areas] {
+            frame.stroke(
+                path,
+                canvas::Stroke::default()
+                    .with_width(LINE_WIDTH + 2.0)
+                    .with_color(halo),
+            );
+        }
+        frame.stroke(
+            &lines,
+            canvas::Stroke::default()
+                .with_width(LINE_WIDTH)
+                .with_color(palette::WHITE),
+        );
+        frame.stroke(
+            &safe_areas,
+            canvas::Stroke {
+                line_dash: canvas::LineDash {
+                    segments: &[6.0, 4.0],
+                    offset: 0,
+                },
+                ..canvas::Stroke::default()
+                    .with_width(LINE_WIDTH)
+                    .with_color(palette::WHITE)
+            },
+        );
+        vec![frame.into_geometry()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_guide_positions() {
+        assert_eq!(
+            parse_positions("25, 50;75 12.5"),
+            Some(vec![25.0, 50.0, 75.0, 12.5])
+        );
+        assert_eq!(parse_positions("  "), Some(Vec::new()));
+        assert_eq!(parse_positions("50, 120"), None);
+        assert_eq!(parse_positions("half"), None);
+        assert_eq!(format_positions(&[25.0, 12.5]), "25, 12.5");
+    }
+
+    #[test]
+    fn options_round_trip_through_config() {
+        let options = Options {
+            thirds: false,
+            golden_ratio: true,
+            pixel_grid: false,
+            safe_areas: true,
+            horizontal: vec![10.0],
+            vertical: vec![50.0, 90.0],
+        };
+        assert_eq!(Options::from_config(&options.to_config()), options);
+
+        // Lines off the image are dropped
+        let config = GuidesConfig {
+            vertical: vec![-5.0, 40.0, 150.0],
+            ..GuidesConfig::default()
+        };
+        assert_eq!(Options::from_config(&config).vertical, vec![40.0]);
+    }
+}
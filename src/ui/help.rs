@@ -8,6 +8,8 @@
 use crate::i18n::fluent::I18n;
 use crate::ui::action_icons;
 use crate::ui::design_tokens::{radius, sizing, spacing, typography};
+use crate::ui::keyboard_layout::KeyboardLayout;
+use crate::ui::keymap::{Action, Keymap};
 use iced::widget::image::{Handle, Image};
 use iced::{
     alignment::{Horizontal, Vertical},
@@ -81,6 +83,10 @@ pub struct ViewContext<'a> {
     pub state: &'a State,
     /// True if the application is using dark theme.
     pub is_dark_theme: bool,
+    /// Key labels of the current keyboard layout.
+    pub keyboard_layout: &'a KeyboardLayout,
+    /// Shortcuts as bound by the user.
+    pub keymap: &'a Keymap,
 }
 
 /// Messages emitted by the help screen.
@@ -299,26 +305,151 @@ fn build_viewer_content<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
     let shortcuts_content = Column::new()
         .spacing(spacing::XXS)
         .push(build_shortcut_row(
+            ctx,
             "← / →",
             ctx.i18n.tr("help-viewer-key-navigate"),
         ))
-        .push(build_shortcut_row("E", ctx.i18n.tr("help-viewer-key-edit")))
-        .push(build_shortcut_row("I", ctx.i18n.tr("help-viewer-key-info")))
         .push(build_shortcut_row(
-            "F11",
+            ctx,
+            &ctx.keymap.label(Action::EnterEditor),
+            ctx.i18n.tr("help-viewer-key-edit"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::ToggleInfoPanel),
+            ctx.i18n.tr("help-viewer-key-info"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::ToggleFullscreen),
             ctx.i18n.tr("help-viewer-key-fullscreen"),
         ))
         .push(build_shortcut_row(
+            ctx,
             "Esc",
             ctx.i18n.tr("help-viewer-key-exit-fullscreen"),
         ))
         .push(build_shortcut_row(
-            "R",
+            ctx,
+            &ctx.keymap.label(Action::RotateClockwise),
             ctx.i18n.tr("help-viewer-key-rotate-cw"),
         ))
         .push(build_shortcut_row(
-            "Shift+R",
+            ctx,
+            &ctx.keymap.label(Action::RotateCounterClockwise),
             ctx.i18n.tr("help-viewer-key-rotate-ccw"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::Mirror),
+            ctx.i18n.tr("help-viewer-key-mirror"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &format!(
+                "{} / {}",
+                ctx.keymap.label(Action::NextMatte),
+                ctx.keymap.label(Action::PreviousMatte)
+            ),
+            ctx.i18n.tr("help-viewer-key-matte"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::ToggleAlpha),
+            ctx.i18n.tr("help-viewer-key-alpha"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::PlaySequence),
+            ctx.i18n.tr("help-viewer-key-sequence"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::ExportView),
+            ctx.i18n.tr("help-viewer-key-export-view"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::CopyView),
+            ctx.i18n.tr("help-viewer-key-copy-view"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::QuickSearch),
+            ctx.i18n.tr("help-viewer-key-quick-search"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            "F3 / Shift+F3",
+            ctx.i18n.tr("help-viewer-key-quick-search-cycle"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            "Alt+PgDn / Alt+PgUp",
+            ctx.i18n.tr("help-viewer-key-capture-day"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &format!(
+                "{} / {}",
+                ctx.keymap.label(Action::NextFace),
+                ctx.keymap.label(Action::PreviousFace)
+            ),
+            ctx.i18n.tr("help-viewer-key-face-zoom"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::ToggleFaceOverlay),
+            ctx.i18n.tr("help-viewer-key-face-overlay"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &format!(
+                "{} / {}",
+                ctx.keymap.label(Action::NextSimilar),
+                ctx.keymap.label(Action::PreviousSimilar)
+            ),
+            ctx.i18n.tr("help-viewer-key-similar"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::ToggleOsd),
+            ctx.i18n.tr("help-viewer-key-osd"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::TogglePixelInspector),
+            ctx.i18n.tr("help-viewer-key-pixel-inspector"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::ToggleRuler),
+            ctx.i18n.tr("help-viewer-key-ruler"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            "Backspace",
+            ctx.i18n.tr("help-viewer-key-ruler-undo"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::ToggleGuides),
+            ctx.i18n.tr("help-viewer-key-guides"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            "Ctrl+1…3",
+            ctx.i18n.tr("help-viewer-key-layout"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            "Ctrl+Shift+1…3",
+            ctx.i18n.tr("help-viewer-key-layout-save"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            "F12",
+            ctx.i18n.tr("help-viewer-key-diagnostics"),
         ));
 
     let mouse_title = build_subsection_title(ctx.i18n.tr("help-mouse-title"));
@@ -390,33 +521,68 @@ fn build_video_content<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
     let shortcuts_content = Column::new()
         .spacing(spacing::XXS)
         .push(build_shortcut_row(
-            "Space",
+            ctx,
+            &ctx.keymap.label(Action::PlayPause),
             ctx.i18n.tr("help-video-key-playpause"),
         ))
-        .push(build_shortcut_row("M", ctx.i18n.tr("help-video-key-mute")))
         .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::Mute),
+            ctx.i18n.tr("help-video-key-mute"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
             "← / →",
             ctx.i18n.tr("help-video-key-seek"),
         ))
         .push(build_shortcut_row(
+            ctx,
+            "Shift + ← / →",
+            ctx.i18n.tr("help-video-key-fine-seek"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
             "↑ / ↓",
             ctx.i18n.tr("help-video-key-volume"),
         ))
         .push(build_shortcut_row(
-            ",",
+            ctx,
+            &ctx.keymap.label(Action::StepBackward),
             ctx.i18n.tr("help-video-key-step-back"),
         ))
         .push(build_shortcut_row(
-            ".",
+            ctx,
+            &ctx.keymap.label(Action::StepForward),
             ctx.i18n.tr("help-video-key-step-forward"),
         ))
         .push(build_shortcut_row(
-            "J",
+            ctx,
+            &ctx.keymap.label(Action::SpeedDown),
             ctx.i18n.tr("help-video-key-speed-down"),
         ))
         .push(build_shortcut_row(
-            "L",
+            ctx,
+            &ctx.keymap.label(Action::SpeedUp),
             ctx.i18n.tr("help-video-key-speed-up"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            "PgDn / PgUp",
+            ctx.i18n.tr("help-video-key-chapter"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &format!(
+                "{} / {}",
+                ctx.keymap.label(Action::LoopStart),
+                ctx.keymap.label(Action::LoopEnd)
+            ),
+            ctx.i18n.tr("help-video-key-loop-points"),
+        ))
+        .push(build_shortcut_row(
+            ctx,
+            &ctx.keymap.label(Action::LoopClear),
+            ctx.i18n.tr("help-video-key-loop-clear"),
         ));
 
     Column::new()
@@ -462,7 +628,11 @@ fn build_editor_content<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
     let (crop_title, crop_content) = build_editor_crop_tool(ctx);
     let (resize_title, resize_content) = build_editor_resize_tool(ctx);
     let (light_title, light_content) = build_editor_light_tool(ctx);
+    let (filters_title, filters_content) = build_editor_filters_tool(ctx);
     let (deblur_title, deblur_content) = build_editor_deblur_tool(ctx);
+    let (background_title, background_content) = build_editor_background_tool(ctx);
+    let (denoise_title, denoise_content) = build_editor_denoise_tool(ctx);
+    let (history_title, history_content) = build_editor_history(ctx);
     let (save_title, save_content) = build_editor_save_options(ctx);
     let (shortcuts_title, shortcuts_content) = build_editor_shortcuts(ctx);
     let (mouse_title, mouse_content) = build_editor_mouse_controls(ctx);
@@ -479,8 +649,16 @@ fn build_editor_content<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
         .push(resize_content)
         .push(light_title)
         .push(light_content)
+        .push(filters_title)
+        .push(filters_content)
         .push(deblur_title)
         .push(deblur_content)
+        .push(background_title)
+        .push(background_content)
+        .push(denoise_title)
+        .push(denoise_content)
+        .push(history_title)
+        .push(history_content)
         .push(save_title)
         .push(save_content)
         .push(shortcuts_title)
@@ -557,13 +735,32 @@ fn build_editor_light_tool<'a>(
     let content = Column::new()
         .spacing(spacing::XXS)
         .push(build_paragraph(ctx.i18n.tr("help-editor-light-desc")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-light-auto")))
         .push(build_bullet(&ctx.i18n.tr("help-editor-light-brightness")))
         .push(build_bullet(&ctx.i18n.tr("help-editor-light-contrast")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-light-grayscale")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-light-sharpen")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-light-copy")))
         .push(build_bullet(&ctx.i18n.tr("help-editor-light-preview")))
         .into();
     (title, content)
 }
 
+/// Build the filters tool section for the editor help.
+fn build_editor_filters_tool<'a>(
+    ctx: &ViewContext<'a>,
+) -> (Element<'a, Message>, Element<'a, Message>) {
+    let title = build_tool_title(ctx.i18n.tr("help-editor-filters-title"));
+    let content = Column::new()
+        .spacing(spacing::XXS)
+        .push(build_paragraph(ctx.i18n.tr("help-editor-filters-desc")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-filters-preview")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-filters-strength")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-filters-undo")))
+        .into();
+    (title, content)
+}
+
 /// Build the AI deblur tool section for the editor help.
 fn build_editor_deblur_tool<'a>(
     ctx: &ViewContext<'a>,
@@ -578,6 +775,48 @@ fn build_editor_deblur_tool<'a>(
     (title, content)
 }
 
+/// Build the AI background removal tool section for the editor help.
+fn build_editor_background_tool<'a>(
+    ctx: &ViewContext<'a>,
+) -> (Element<'a, Message>, Element<'a, Message>) {
+    let title = build_tool_title(ctx.i18n.tr("help-editor-background-title"));
+    let content = Column::new()
+        .spacing(spacing::XXS)
+        .push(build_paragraph(ctx.i18n.tr("help-editor-background-desc")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-background-enable")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-background-refine")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-background-export")))
+        .into();
+    (title, content)
+}
+
+/// Build the denoise tool section for the editor help.
+fn build_editor_denoise_tool<'a>(
+    ctx: &ViewContext<'a>,
+) -> (Element<'a, Message>, Element<'a, Message>) {
+    let title = build_tool_title(ctx.i18n.tr("help-editor-denoise-title"));
+    let content = Column::new()
+        .spacing(spacing::XXS)
+        .push(build_paragraph(ctx.i18n.tr("help-editor-denoise-desc")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-denoise-methods")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-denoise-sliders")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-denoise-model")))
+        .into();
+    (title, content)
+}
+
+/// Build the history section for the editor help.
+fn build_editor_history<'a>(ctx: &ViewContext<'a>) -> (Element<'a, Message>, Element<'a, Message>) {
+    let title = build_tool_title(ctx.i18n.tr("help-editor-history-title"));
+    let content = Column::new()
+        .spacing(spacing::XXS)
+        .push(build_paragraph(ctx.i18n.tr("help-editor-history-desc")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-history-steps")))
+        .push(build_bullet(&ctx.i18n.tr("help-editor-history-snapshots")))
+        .into();
+    (title, content)
+}
+
 /// Build the save options section for the editor help.
 fn build_editor_save_options<'a>(
     ctx: &ViewContext<'a>,
@@ -599,21 +838,30 @@ fn build_editor_shortcuts<'a>(
     let content = Column::new()
         .spacing(spacing::XXS)
         .push(build_shortcut_row(
-            "Ctrl+S",
+            ctx,
+            &ctx.keymap.label(Action::Save),
             ctx.i18n.tr("help-editor-key-save"),
         ))
         .push(build_shortcut_row(
-            "Ctrl+Z",
+            ctx,
+            &ctx.keymap.label(Action::Undo),
             ctx.i18n.tr("help-editor-key-undo"),
         ))
         .push(build_shortcut_row(
-            "Ctrl+Y",
+            ctx,
+            &ctx.keymap.label(Action::Redo),
             ctx.i18n.tr("help-editor-key-redo"),
         ))
         .push(build_shortcut_row(
+            ctx,
             "Esc",
             ctx.i18n.tr("help-editor-key-cancel"),
         ))
+        .push(build_shortcut_row(
+            ctx,
+            "\\",
+            ctx.i18n.tr("help-editor-key-show-original"),
+        ))
         .into();
     (title, content)
 }
@@ -633,6 +881,12 @@ fn build_editor_mouse_controls<'a>(
             ctx.i18n.tr("viewer-click-drag"),
             ctx.i18n.tr("help-editor-mouse-drag"),
         ))
+        .push(build_mouse_row(
+            ctx.i18n.tr("help-editor-mouse-space-drag-label"),
+            ctx.i18n.tr("help-editor-mouse-space-drag"),
+        ))
+        .push(build_paragraph(ctx.i18n.tr("help-editor-mouse-fit")))
+        .push(build_paragraph(ctx.i18n.tr("help-editor-compare")))
         .into();
     (title, content)
 }
@@ -798,8 +1052,13 @@ fn build_numbered_step(number: &str, content: String) -> Element<'static, Messag
 }
 
 /// Build a single shortcut row with key badge and description.
-fn build_shortcut_row(key: &str, description: String) -> Element<'static, Message> {
-    let key_badge = Container::new(Text::new(key.to_owned()).size(typography::CAPTION))
+fn build_shortcut_row(
+    ctx: &ViewContext<'_>,
+    key: &str,
+    description: String,
+) -> Element<'static, Message> {
+    let key_label = ctx.keyboard_layout.display_name(key);
+    let key_badge = Container::new(Text::new(key_label).size(typography::CAPTION))
         .padding([spacing::XXS, spacing::XS])
         .style(|theme: &Theme| container::Style {
             background: Some(theme.extended_palette().background.strong.color.into()),
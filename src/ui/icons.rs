@@ -119,6 +119,24 @@ define_icon!(
     "ellipsis_horizontal.png",
     "Horizontal ellipsis: three dots in a row."
 );
+define_icon!(
+    double_triangle_bar_right,
+    dark,
+    "double_triangle_bar_right.png",
+    "Two triangles with bar on right: skip to next section shape."
+);
+define_icon!(
+    double_triangle_bar_left,
+    dark,
+    "double_triangle_bar_left.png",
+    "Two triangles with bar on left: skip to previous section shape."
+);
+define_icon!(
+    bullet_list,
+    dark,
+    "bullet_list.png",
+    "Bullet list: three dots each followed by a line."
+);
 define_icon!(
     triangle_minus,
     dark,
@@ -237,6 +255,13 @@ define_icon!(
     "flip_vertical.png",
     "Flip vertical icon: mirror top-bottom."
 );
+define_icon!(ruler, dark, "ruler.png", "Ruler icon: measuring tool.");
+define_icon!(
+    grid,
+    dark,
+    "grid.png",
+    "Grid icon: framed three-by-three grid."
+);
 
 // =============================================================================
 // Navigation Icons
@@ -417,6 +442,18 @@ pub mod light {
         "flip_vertical.png",
         "Flip vertical icon (white): for dark theme UI."
     );
+    define_icon!(
+        ruler,
+        light,
+        "ruler.png",
+        "Ruler icon (white): for dark theme UI."
+    );
+    define_icon!(
+        grid,
+        light,
+        "grid.png",
+        "Grid icon (white): for dark theme UI."
+    );
 
     // Navbar icons
     define_icon!(
@@ -476,6 +513,24 @@ pub mod light {
         "ellipsis_horizontal.png",
         "More options icon (white): for toolbar."
     );
+    define_icon!(
+        double_triangle_bar_right,
+        light,
+        "double_triangle_bar_right.png",
+        "Next chapter icon (white): for toolbar."
+    );
+    define_icon!(
+        double_triangle_bar_left,
+        light,
+        "double_triangle_bar_left.png",
+        "Previous chapter icon (white): for toolbar."
+    );
+    define_icon!(
+        bullet_list,
+        light,
+        "bullet_list.png",
+        "Chapter list icon (white): for toolbar."
+    );
 
     // Viewer toolbar icons
     define_icon!(
@@ -611,6 +666,12 @@ pub mod overlay {
         "rotate_right.png",
         "Rotate right icon (white): for HUD on dark backgrounds."
     );
+    define_icon!(
+        image,
+        light,
+        "image.png",
+        "Image icon (white): for HUD on dark backgrounds."
+    );
 }
 
 // =============================================================================
@@ -654,6 +715,8 @@ mod tests {
         let _ = rotate_right();
         let _ = flip_horizontal();
         let _ = flip_vertical();
+        let _ = ruler();
+        let _ = grid();
         let _ = crosshair();
         let _ = magnifier();
         let _ = video_camera();
@@ -665,6 +728,9 @@ mod tests {
         let _ = triangle_bar_right();
         let _ = triangle_bar_left();
         let _ = ellipsis_horizontal();
+        let _ = double_triangle_bar_right();
+        let _ = double_triangle_bar_left();
+        let _ = bullet_list();
         let _ = hamburger();
         let _ = help();
         let _ = info();
@@ -714,6 +780,9 @@ mod tests {
         let _ = light::triangle_bar_right();
         let _ = light::camera();
         let _ = light::ellipsis_horizontal();
+        let _ = light::double_triangle_bar_right();
+        let _ = light::double_triangle_bar_left();
+        let _ = light::bullet_list();
         // Viewer toolbar
         let _ = light::zoom_in();
         let _ = light::zoom_out();
@@ -723,6 +792,8 @@ mod tests {
         let _ = light::fullscreen();
         let _ = light::trash();
         let _ = light::funnel();
+        let _ = light::ruler();
+        let _ = light::grid();
         // Notifications
         let _ = light::checkmark();
     }
@@ -739,5 +810,6 @@ mod tests {
         let _ = overlay::video_camera_audio();
         let _ = overlay::chevron_right();
         let _ = overlay::chevron_left();
+        let _ = overlay::image();
     }
 }
@@ -3,8 +3,12 @@
 
 use crate::config::BackgroundTheme;
 use crate::error::{Error, Result};
+use crate::media::auto_enhance::AutoEnhanceParams;
+use crate::media::background_removal::BackgroundModelStatus;
+use crate::media::color_proof::ProofOptions;
 use crate::media::deblur::ModelStatus;
 use crate::media::frame_export::{ExportFormat, ExportableFrame};
+use crate::media::looks::LookParams;
 use crate::media::upscale::UpscaleModelStatus;
 use crate::media::ImageData;
 use iced::{Element, Rectangle};
@@ -23,8 +27,20 @@ pub struct ViewContext<'a> {
     pub deblur_model_status: &'a ModelStatus,
     /// Current status of the AI upscale model.
     pub upscale_model_status: &'a UpscaleModelStatus,
+    /// Current status of the AI background removal model.
+    pub background_model_status: &'a BackgroundModelStatus,
     /// Whether AI upscaling is enabled for resize operations > 100%.
     pub enable_upscale: bool,
+    /// Whether a denoising model URL is set, offering the model method.
+    pub denoise_model_available: bool,
+    /// True if the session clipboard holds adjustments that can be pasted.
+    pub can_paste_adjustments: bool,
+    /// Labels of the editor tools added by plugins.
+    pub plugin_tools: &'a [String],
+    /// Labels of the export formats added by plugins.
+    pub plugin_exports: &'a [String],
+    /// Composition guides drawn while they are shown.
+    pub guides: &'a crate::ui::guides::Options,
 }
 
 impl State {
@@ -45,22 +61,34 @@ impl State {
             active_tool: None,
             transformation_history: Vec::new(),
             history_index: 0,
+            history_revision: 0,
+            history_panel: state::HistoryPanelState::default(),
+            recovery: None,
             sidebar_expanded: true,
+            guides_visible: false,
+            compare: state::CompareState::default(),
             crop: state::CropState::from_image(image),
             crop_modified: false,
             resize: state::ResizeState::from_image(image),
             adjustment: state::AdjustmentState::default(),
+            filters: state::FilterState::default(),
             deblur: state::DeblurState::default(),
+            background: state::BackgroundState::default(),
+            denoise: state::DenoiseState::default(),
+            plugin_running: false,
             crop_base_image: None,
             crop_base_width: image.width,
             crop_base_height: image.height,
             preview_image: None,
             viewport: crate::ui::state::ViewportState::default(),
             export_format: ExportFormat::Png,
+            proof: ProofOptions::default(),
             zoom: crate::ui::state::ZoomState::default(),
             cursor_position: None,
             cursor_over_canvas: false,
             drag: crate::ui::state::DragState::default(),
+            space_pan: false,
+            background_luminance: image.average_luminance(),
         })
     }
 
@@ -77,7 +105,21 @@ impl State {
         let working_image = frame
             .to_dynamic_image()
             .ok_or_else(|| Error::Io("Failed to convert frame to image".to_string()))?;
-        let image = frame.to_image_data();
+        Self::from_captured_image(working_image, video_path, position_secs)
+    }
+
+    /// Create a new editor state for a video frame decoded at the source
+    /// resolution, edited at its full bit depth.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the image cannot be prepared for display.
+    pub fn from_captured_image(
+        working_image: image_rs::DynamicImage,
+        video_path: PathBuf,
+        position_secs: f64,
+    ) -> Result<Self> {
+        let image = crate::media::image_transform::dynamic_to_image_data(&working_image)?;
 
         Ok(Self {
             image_source: ImageSource::CapturedFrame {
@@ -90,22 +132,34 @@ impl State {
             active_tool: None,
             transformation_history: Vec::new(),
             history_index: 0,
+            history_revision: 0,
+            history_panel: state::HistoryPanelState::default(),
+            recovery: None,
             sidebar_expanded: true,
+            guides_visible: false,
+            compare: state::CompareState::default(),
             crop: state::CropState::from_image(&image),
             crop_modified: false,
             resize: state::ResizeState::from_image(&image),
             adjustment: state::AdjustmentState::default(),
+            filters: state::FilterState::default(),
             deblur: state::DeblurState::default(),
+            background: state::BackgroundState::default(),
+            denoise: state::DenoiseState::default(),
+            plugin_running: false,
             crop_base_image: None,
             crop_base_width: image.width,
             crop_base_height: image.height,
             preview_image: None,
             viewport: crate::ui::state::ViewportState::default(),
             export_format: ExportFormat::Png,
+            proof: ProofOptions::default(),
             zoom: crate::ui::state::ZoomState::default(),
             cursor_position: None,
             cursor_over_canvas: false,
             drag: crate::ui::state::DragState::default(),
+            space_pan: false,
+            background_luminance: image.average_luminance(),
         })
     }
 
@@ -131,14 +185,17 @@ pub enum EditorTool {
     Crop,
     Resize,
     Adjust,
+    Filters,
     Deblur,
+    Background,
+    Denoise,
 }
 
 /// Image transformations that can be applied and undone.
 ///
-/// Note: AI transformations (`Deblur`, `UpscaleResize`) store the result image
-/// because model inference is non-deterministic and expensive to re-run
-/// during undo/redo.
+/// Note: AI transformations (`Deblur`, `UpscaleResize`, `RemoveBackground`),
+/// `Denoise` and plugin tools store the result image because they are
+/// non-deterministic or expensive to re-run during undo/redo.
 #[derive(Debug, Clone)]
 pub enum Transformation {
     RotateLeft,
@@ -163,9 +220,75 @@ pub enum Transformation {
     AdjustContrast {
         value: i32,
     },
+    /// Histogram-based levels, contrast and white balance correction,
+    /// blended by `strength` percent.
+    AutoEnhance {
+        params: AutoEnhanceParams,
+        strength: u32,
+    },
+    /// Black and white conversion through the channel mixer.
+    ChannelMix {
+        /// Red, green and blue weights, in percent.
+        weights: [i32; 3],
+    },
+    /// Unsharp mask sharpening.
+    Sharpen {
+        /// Strength, in percent.
+        amount: u32,
+        /// Blur radius, in pixels.
+        radius: f32,
+        /// Smallest difference with the blurred image that gets sharpened.
+        threshold: u8,
+    },
+    /// One-click look from the Filters tool, blended by `strength` percent.
+    Look {
+        params: LookParams,
+        strength: u32,
+    },
     /// AI deblur transformation with cached result for undo/redo.
     Deblur {
         /// The deblurred image result (boxed to keep enum size small).
         result: Box<image_rs::DynamicImage>,
     },
+    /// AI background removal with cached result for undo/redo.
+    RemoveBackground {
+        /// The cut-out image with transparent background (boxed to keep enum size small).
+        result: Box<image_rs::DynamicImage>,
+    },
+    /// Noise reduction with cached result for undo/redo.
+    Denoise {
+        /// The denoised image (boxed to keep enum size small).
+        result: Box<image_rs::DynamicImage>,
+    },
+    /// Plugin editor tool with cached result for undo/redo.
+    PluginTool {
+        /// The image written by the plugin (boxed to keep enum size small).
+        result: Box<image_rs::DynamicImage>,
+    },
+}
+
+impl Transformation {
+    /// Returns the i18n key naming this step in the history panel.
+    #[must_use]
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            Self::RotateLeft => "image-editor-history-rotate-left",
+            Self::RotateRight => "image-editor-history-rotate-right",
+            Self::FlipHorizontal => "image-editor-history-flip-horizontal",
+            Self::FlipVertical => "image-editor-history-flip-vertical",
+            Self::Crop { .. } => "image-editor-history-crop",
+            Self::Resize { .. } => "image-editor-history-resize",
+            Self::UpscaleResize { .. } => "image-editor-history-upscale",
+            Self::AdjustBrightness { .. } => "image-editor-history-brightness",
+            Self::AdjustContrast { .. } => "image-editor-history-contrast",
+            Self::AutoEnhance { .. } => "image-editor-history-auto-enhance",
+            Self::ChannelMix { .. } => "image-editor-history-black-white",
+            Self::Sharpen { .. } => "image-editor-history-sharpen",
+            Self::Look { .. } => "image-editor-history-look",
+            Self::Deblur { .. } => "image-editor-history-deblur",
+            Self::RemoveBackground { .. } => "image-editor-history-background",
+            Self::Denoise { .. } => "image-editor-history-denoise",
+            Self::PluginTool { .. } => "image-editor-history-plugin",
+        }
+    }
 }
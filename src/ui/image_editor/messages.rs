@@ -1,8 +1,14 @@
 // SPDX-License-Identifier: MPL-2.0
 //! Editor message/event types re-exported by the facade.
 
+use crate::media::color_proof::ProofProfile;
+use crate::media::denoise::{DenoiseMethod, DenoiseParams};
 use crate::media::frame_export::ExportFormat;
-use crate::ui::image_editor::{state::CropRatio, EditorTool};
+use crate::media::looks::FilterPreset;
+use crate::ui::image_editor::{
+    state::{AdjustmentRecipe, BrushMode, CropRatio, GrayscalePreset},
+    EditorTool,
+};
 use iced;
 use iced::widget::scrollable::AbsoluteOffset;
 use iced::Rectangle;
@@ -12,6 +18,20 @@ use std::path::PathBuf;
 #[derive(Debug, Clone)]
 pub enum ToolbarMessage {
     BackToViewer,
+    /// Restore the edits recovered from a previous session.
+    RestoreEdits,
+    /// Discard the edits recovered from a previous session.
+    DiscardRecoveredEdits,
+    /// Show or hide the composition guides.
+    ToggleGuides,
+    /// Switch the canvas between fit to window and 100%.
+    ToggleFitZoom,
+    /// Show the original image while held (`true` on press, `false` on release).
+    ShowOriginal(bool),
+    /// Open or close the split before/after view.
+    ToggleSplitCompare,
+    /// Split view divider moved, in percent of the image width.
+    CompareSplitChanged(u32),
 }
 
 /// Sidebar control messages.
@@ -24,6 +44,8 @@ pub enum SidebarMessage {
     FlipHorizontal,
     FlipVertical,
     SetCropRatio(CropRatio),
+    /// Move the crop rectangle onto the next detected face
+    CropToFace,
     ApplyCrop,
     ScaleChanged(f32),
     WidthInputChanged(String),
@@ -37,20 +59,82 @@ pub enum SidebarMessage {
     ApplyResize,
     /// Toggle AI upscaling for resize enlargements
     ToggleAiUpscale,
+    /// Preview the automatic correction, or drop it if already previewed
+    ToggleAutoEnhance,
+    /// Auto enhance strength slider changed, in percent (live preview)
+    AutoEnhanceStrengthChanged(u32),
     /// Brightness slider changed (live preview)
     BrightnessChanged(i32),
     /// Contrast slider changed (live preview)
     ContrastChanged(i32),
+    /// Black and white conversion toggled (live preview)
+    GrayscaleToggled(bool),
+    /// Load the channel weights of a black and white filter preset
+    GrayscalePresetSelected(GrayscalePreset),
+    /// Channel mixer red weight changed, in percent (live preview)
+    MixerRedChanged(i32),
+    /// Channel mixer green weight changed, in percent (live preview)
+    MixerGreenChanged(i32),
+    /// Channel mixer blue weight changed, in percent (live preview)
+    MixerBlueChanged(i32),
+    /// Sharpen amount slider changed, in percent (live preview)
+    SharpenAmountChanged(u32),
+    /// Sharpen radius slider changed, in pixels (live preview)
+    SharpenRadiusChanged(f32),
+    /// Sharpen threshold slider changed (live preview)
+    SharpenThresholdChanged(u8),
     /// Apply current adjustments to image
     ApplyAdjustments,
     /// Reset adjustments to default
     ResetAdjustments,
+    /// Copy the applied adjustments to the session clipboard
+    CopyAdjustments,
+    /// Paste adjustments from the session clipboard
+    PasteAdjustments,
+    /// Paste the copied recipe onto the other images of the folder
+    PasteAdjustmentsToFolder,
     /// Apply AI deblurring to the image
     ApplyDeblur,
     /// Cancel ongoing deblur operation
     CancelDeblur,
+    /// Cut the subject out of its background with the AI model
+    RemoveBackground,
+    /// Choose whether the refine brush keeps or erases
+    SetBrushMode(BrushMode),
+    /// Refine brush radius changed, in image pixels
+    BrushRadiusChanged(u32),
+    /// Apply the refined cut-out to the image
+    ApplyBackgroundRemoval,
+    /// Preview a look (selecting it again drops the preview)
+    SelectFilter(FilterPreset),
+    /// Filter strength slider changed (live preview)
+    FilterStrengthChanged(u32),
+    /// Apply the previewed look to the image
+    ApplyFilter,
+    /// Choose the noise reduction method
+    SetDenoiseMethod(DenoiseMethod),
+    /// Denoise strength slider changed (live preview)
+    DenoiseStrengthChanged(u32),
+    /// Denoise detail preservation slider changed (live preview)
+    DenoiseDetailChanged(u32),
+    /// Denoise the image at full resolution
+    ApplyDenoise,
+    /// Run the n-th plugin editor tool on the image
+    RunPluginTool(usize),
+    /// Export the image with the n-th plugin export format
+    ExportWithPlugin(usize),
     Undo,
     Redo,
+    /// Show or hide the history panel
+    ToggleHistoryPanel,
+    /// Revert to the state after this many steps (0 is the original image)
+    JumpToHistory(usize),
+    /// Snapshot name input changed
+    SnapshotNameChanged(String),
+    /// Name the current position in the history
+    SaveSnapshot,
+    /// Delete the snapshot at this position in the list
+    DeleteSnapshot(usize),
     NavigateNext,
     NavigatePrevious,
     Save,
@@ -58,6 +142,10 @@ pub enum SidebarMessage {
     Cancel,
     /// Set the export format for Save As.
     SetExportFormat(ExportFormat),
+    /// Embed this color profile when saving (`None` saves without one).
+    SetProofProfile(Option<ProofProfile>),
+    /// Toggle converting the pixels into the proof profile.
+    ToggleProofConversion,
 }
 
 /// Canvas overlay interaction messages.
@@ -72,6 +160,13 @@ pub enum CanvasMessage {
         y: f32,
     },
     CropOverlayMouseUp,
+    /// Refine brush painted at a point, in image pixels
+    BrushStroke {
+        x: f32,
+        y: f32,
+    },
+    /// Refine brush released
+    BrushStrokeEnded,
     /// Cursor moved over the canvas area
     CursorMoved {
         position: iced::Point,
@@ -139,6 +234,16 @@ pub enum Event {
     DeblurRequested,
     /// Request to cancel ongoing deblur operation
     DeblurCancelRequested,
+    /// Request to compute the foreground mask of the current image
+    BackgroundRemovalRequested,
+    /// Request to denoise the working image at full resolution
+    DenoiseRequested(DenoiseParams),
+    /// Request to run the n-th plugin editor tool on the working image
+    PluginToolRequested(usize),
+    /// Request to export the working image with the n-th plugin export format
+    PluginExportRequested(usize),
+    /// Request to detect faces in the crop base image
+    FaceDetectionRequested,
     /// Request to resize the image (scale > 100%, may use AI upscaling if available)
     UpscaleResizeRequested {
         /// Target width in pixels.
@@ -146,6 +251,12 @@ pub enum Event {
         /// Target height in pixels.
         height: u32,
     },
+    /// Adjustments were copied and should be stored in the session clipboard
+    AdjustmentsCopied(AdjustmentRecipe),
+    /// Request to paste the adjustments held in the session clipboard
+    PasteAdjustmentsRequested,
+    /// Request to paste the copied recipe onto the other images of the folder
+    PasteAdjustmentsToFolderRequested,
     /// Request to scroll the canvas to a relative position (for pan)
     ScrollTo {
         /// Relative X offset (0.0 to 1.0)
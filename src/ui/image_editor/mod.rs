@@ -5,8 +5,10 @@
 //! and viewer modules. The editor operates on a copy of the original image and only
 //! modifies the source file when the user explicitly saves.
 
+use crate::media::color_proof::ProofOptions;
 use crate::media::frame_export::ExportFormat;
 use crate::media::ImageData;
+use crate::ui::accessibility;
 use crate::ui::state::{DragState, ViewportState, ZoomState};
 
 mod component;
@@ -16,7 +18,8 @@ mod state;
 mod view;
 
 pub use self::state::{
-    AdjustmentState, CropDragState, CropOverlay, CropRatio, CropState, DeblurState, HandlePosition,
+    AdjustmentRecipe, AdjustmentState, BackgroundState, BrushMode, CropDragState, CropOverlay,
+    CropRatio, CropState, DeblurState, DenoiseState, FilterState, HandlePosition, RecoveredEdits,
     ResizeOverlay, ResizeState,
 };
 pub use component::{EditorTool, Transformation, ViewContext};
@@ -56,8 +59,18 @@ pub struct State {
     transformation_history: Vec<Transformation>,
     /// Current position in history (for undo/redo)
     history_index: usize,
+    /// Bumped whenever the applied history changes (for the edit journal)
+    history_revision: u64,
+    /// History panel with named snapshots
+    history_panel: state::HistoryPanelState,
+    /// Edits from a previous session waiting to be restored or discarded
+    recovery: Option<RecoveredEdits>,
     /// Whether the sidebar is expanded
     sidebar_expanded: bool,
+    /// Whether the composition guides are drawn over the canvas
+    guides_visible: bool,
+    /// Before/after comparison with the original image
+    compare: state::CompareState,
     /// Crop tool state
     crop: CropState,
     /// Track if crop state has been modified (to avoid auto-commit on tool close)
@@ -70,14 +83,24 @@ pub struct State {
     resize: ResizeState,
     /// Adjustment state (brightness/contrast)
     adjustment: AdjustmentState,
+    /// Filters state (one-click looks)
+    filters: FilterState,
     /// Deblur state (AI-powered deblurring)
     deblur: DeblurState,
+    /// Background removal state (AI cut-out refined with a brush)
+    background: BackgroundState,
+    /// Denoise state (noise reduction previewed on a proxy)
+    denoise: DenoiseState,
+    /// Whether a plugin editor tool is running
+    plugin_running: bool,
     /// Optional preview image (used for live adjustments)
     preview_image: Option<ImageData>,
     /// Viewport state for tracking canvas bounds and scroll position
     pub viewport: ViewportState,
     /// Export format for Save As (used when editing captured frames).
     export_format: ExportFormat,
+    /// Color profile embedded (and optionally converted to) when saving.
+    proof: ProofOptions,
     /// Zoom state for the editor canvas
     pub zoom: ZoomState,
     /// Current cursor position (for zoom-on-scroll detection)
@@ -86,6 +109,10 @@ pub struct State {
     cursor_over_canvas: bool,
     /// Drag state for pan navigation
     drag: DragState,
+    /// Whether Space is held, so dragging pans even over the crop and brush overlays
+    space_pan: bool,
+    /// Average luminance of the opened image, used by the auto background.
+    background_luminance: Option<f32>,
 }
 
 impl std::fmt::Debug for State {
@@ -97,6 +124,7 @@ impl std::fmt::Debug for State {
             .field("history_index", &self.history_index)
             .field("sidebar_expanded", &self.sidebar_expanded)
             .field("export_format", &self.export_format)
+            .field("proof", &self.proof)
             .finish_non_exhaustive()
     }
 }
@@ -111,6 +139,7 @@ impl State {
             Message::RawEvent { event, .. } => self.handle_raw_event(event),
             Message::ViewportChanged { bounds, offset } => {
                 self.viewport.update(bounds, offset);
+                self.refresh_fit_zoom();
                 Event::None
             }
             Message::SpinnerTick => {
@@ -120,9 +149,15 @@ impl State {
         }
     }
 
-    /// Returns the subscriptions needed for the editor (spinner animation during AI processing).
+    /// Returns the subscriptions needed for the editor (spinner animation during AI processing,
+    /// unless reduced motion is on).
     pub fn subscription(&self) -> iced::Subscription<Message> {
-        if self.deblur.is_processing || self.resize.is_upscale_processing {
+        if (self.deblur.is_processing
+            || self.resize.is_upscale_processing
+            || self.background.is_processing
+            || self.denoise.is_processing)
+            && !accessibility::current().reduced_motion
+        {
             // Animate spinner at 60 FPS while processing
             iced::time::every(std::time::Duration::from_millis(16)).map(|_| Message::SpinnerTick)
         } else {
@@ -180,6 +215,11 @@ impl State {
         self.export_format = format;
     }
 
+    /// Get the color proof options used when saving.
+    pub fn proof_options(&self) -> ProofOptions {
+        self.proof
+    }
+
     /// Get the resize thumbnail preview (for sidebar display).
     pub fn resize_thumbnail(&self) -> Option<&ImageData> {
         // Only return thumbnail when resize tool is active
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Refine-edges brush overlay for the background removal tool.
+//!
+//! The overlay is laid out at the scaled size of the image, so canvas
+//! coordinates map to image pixels by a plain scale.
+#![allow(clippy::cast_precision_loss)]
+
+use crate::ui::design_tokens::palette;
+use crate::ui::image_editor::state::BrushMode;
+use crate::ui::image_editor::{CanvasMessage, Message};
+use crate::ui::theme;
+
+/// Canvas program used to paint the background removal mask.
+pub struct BrushOverlayRenderer {
+    pub img_width: u32,
+    pub img_height: u32,
+    /// Brush radius, in image pixels.
+    pub radius: u32,
+    pub mode: BrushMode,
+    /// Whether Space is held: presses pan the canvas instead of painting.
+    pub panning: bool,
+}
+
+impl BrushOverlayRenderer {
+    /// Convert canvas coordinates to image coordinates.
+    fn to_image_coords(&self, position: iced::Point, bounds: iced::Rectangle) -> (f32, f32) {
+        (
+            position.x * self.img_width as f32 / bounds.width.max(1.0),
+            position.y * self.img_height as f32 / bounds.height.max(1.0),
+        )
+    }
+
+    fn stroke(
+        &self,
+        position: iced::Point,
+        bounds: iced::Rectangle,
+    ) -> iced::widget::Action<Message> {
+        let (x, y) = self.to_image_coords(position, bounds);
+        iced::widget::Action::publish(Message::Canvas(CanvasMessage::BrushStroke { x, y }))
+            .and_capture()
+    }
+}
+
+impl iced::widget::canvas::Program<Message> for BrushOverlayRenderer {
+    /// Whether the left button is held down over the image.
+    type State = bool;
+
+    fn update(
+        &self,
+        painting: &mut Self::State,
+        event: &iced::Event,
+        bounds: iced::Rectangle,
+        cursor: iced::mouse::Cursor,
+    ) -> Option<iced::widget::Action<Message>> {
+        use iced::widget::Action;
+
+        match event {
+            iced::Event::Mouse(iced::mouse::Event::ButtonPressed(iced::mouse::Button::Left))
+                if !self.panning =>
+            {
+                let position = cursor.position_in(bounds)?;
+                *painting = true;
+                Some(self.stroke(position, bounds))
+            }
+            iced::Event::Mouse(iced::mouse::Event::CursorMoved { .. }) => {
+                match cursor.position_in(bounds) {
+                    Some(position) if *painting => Some(self.stroke(position, bounds)),
+                    // Redraw the brush outline under the cursor
+                    Some(_) => Some(Action::request_redraw()),
+                    None if *painting => {
+                        *painting = false;
+                        Some(Action::publish(Message::Canvas(
+                            CanvasMessage::BrushStrokeEnded,
+                        )))
+                    }
+                    None => None,
+                }
+            }
+            iced::Event::Mouse(iced::mouse::Event::ButtonReleased(iced::mouse::Button::Left))
+                if *painting =>
+            {
+                *painting = false;
+                Some(
+                    Action::publish(Message::Canvas(CanvasMessage::BrushStrokeEnded)).and_capture(),
+                )
+            }
+            _ => None,
+        }
+    }
+
+    fn draw(
+        &self,
+        _painting: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: iced::Rectangle,
+        cursor: iced::mouse::Cursor,
+    ) -> Vec<iced::widget::canvas::Geometry> {
+        use iced::widget::canvas::{Frame, Path, Stroke};
+
+        let mut frame = Frame::new(renderer, bounds.size());
+        if let Some(position) = cursor.position_in(bounds) {
+            let scale = bounds.width / self.img_width.max(1) as f32;
+            let outline = Path::circle(position, (self.radius as f32 * scale).max(1.0));
+            let color = match self.mode {
+                BrushMode::Keep => palette::WHITE,
+                BrushMode::Erase => theme::error_text_color(),
+            };
+            // Dark halo under the outline keeps it visible on bright images
+            frame.stroke(
+                &outline,
+                Stroke::default().with_width(3.0).with_color(palette::BLACK),
+            );
+            frame.stroke(
+                &outline,
+                Stroke::default().with_width(1.5).with_color(color),
+            );
+        }
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        _painting: &Self::State,
+        bounds: iced::Rectangle,
+        cursor: iced::mouse::Cursor,
+    ) -> iced::mouse::Interaction {
+        if cursor.is_over(bounds) && !self.panning {
+            iced::mouse::Interaction::Crosshair
+        } else {
+            iced::mouse::Interaction::default()
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Split before/after overlay drawing the original image left of a divider.
+//!
+//! The overlay is laid out at the scaled size of the edited image, and the
+//! original is stretched over it when a crop or rotation changed the size.
+#![allow(clippy::cast_precision_loss)]
+
+use crate::ui::design_tokens::palette::WHITE;
+use crate::ui::image_editor::Message;
+
+/// Width of the divider line, in screen pixels.
+const DIVIDER_WIDTH: f32 = 2.0;
+
+/// Canvas program used to draw the split comparison.
+pub struct CompareOverlayRenderer {
+    /// Untouched source image.
+    pub original: iced::widget::image::Handle,
+    /// Divider position, in percent of the image width.
+    pub split_percent: u32,
+}
+
+impl iced::widget::canvas::Program<Message> for CompareOverlayRenderer {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: iced::Rectangle,
+        _cursor: iced::mouse::Cursor,
+    ) -> Vec<iced::widget::canvas::Geometry> {
+        use iced::widget::canvas::{Frame, Path, Stroke};
+
+        let mut frame = Frame::new(renderer, bounds.size());
+        let split_x = bounds.width * self.split_percent.min(100) as f32 / 100.0;
+
+        frame.with_clip(
+            iced::Rectangle::new(iced::Point::ORIGIN, iced::Size::new(split_x, bounds.height)),
+            |frame| {
+                frame.draw_image(iced::Rectangle::with_size(bounds.size()), &self.original);
+            },
+        );
+
+        let divider = Path::line(
+            iced::Point::new(split_x, 0.0),
+            iced::Point::new(split_x, bounds.height),
+        );
+        frame.stroke(
+            &divider,
+            Stroke::default()
+                .with_width(DIVIDER_WIDTH)
+                .with_color(WHITE),
+        );
+
+        vec![frame.into_geometry()]
+    }
+}
@@ -19,6 +19,8 @@ pub struct CropOverlayRenderer {
     pub crop_height: u32,
     pub img_width: u32,
     pub img_height: u32,
+    /// Whether Space is held: presses pan the canvas instead of moving the crop.
+    pub panning: bool,
 }
 
 impl CropOverlayRenderer {
@@ -80,7 +82,9 @@ impl iced::widget::canvas::Program<Message> for CropOverlayRenderer {
         use iced::widget::Action;
 
         match event {
-            iced::Event::Mouse(iced::mouse::Event::ButtonPressed(iced::mouse::Button::Left)) => {
+            iced::Event::Mouse(iced::mouse::Event::ButtonPressed(iced::mouse::Button::Left))
+                if !self.panning =>
+            {
                 if let Some(cursor_position) = cursor.position_in(bounds) {
                     let (img_x, img_y) = self.screen_to_image_coords(cursor_position, bounds);
                     return Some(
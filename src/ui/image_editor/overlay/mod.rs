@@ -1,8 +1,12 @@
 // SPDX-License-Identifier: MPL-2.0
 //! Canvas overlay renderers for the editor.
 
+mod brush;
+mod compare;
 mod crop;
 mod resize;
 
+pub use brush::BrushOverlayRenderer;
+pub use compare::CompareOverlayRenderer;
 pub use crop::CropOverlayRenderer;
 pub use resize::ResizeOverlayRenderer;
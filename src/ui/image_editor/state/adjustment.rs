@@ -1,6 +1,14 @@
 // SPDX-License-Identifier: MPL-2.0
-//! Adjustment tool state and helpers for brightness/contrast.
-
+//! Adjustment tool state and helpers for auto enhance, brightness, contrast,
+//! black and white conversion and sharpening.
+//!
+//! Recipes scale crops and resizes between image sizes in f32; precision
+//! loss is acceptable for typical image sizes.
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+
+use crate::media::auto_enhance::{self, AutoEnhanceParams};
 use crate::media::image_transform;
 use crate::ui::image_editor::{State, Transformation};
 
@@ -11,6 +19,19 @@ const MAX_ADJUSTMENT: i32 = 100;
 /// Default (neutral) adjustment value.
 const DEFAULT_ADJUSTMENT: i32 = 0;
 
+/// Largest unsharp mask amount, in percent.
+pub const MAX_SHARPEN_AMOUNT: u32 = 300;
+/// Smallest unsharp mask radius, in pixels.
+pub const MIN_SHARPEN_RADIUS: f32 = 0.5;
+/// Largest unsharp mask radius, in pixels.
+pub const MAX_SHARPEN_RADIUS: f32 = 10.0;
+/// Largest unsharp mask threshold.
+pub const MAX_SHARPEN_THRESHOLD: u8 = 100;
+/// Smallest channel mixer weight, in percent.
+pub const MIN_MIX_WEIGHT: i32 = -200;
+/// Largest channel mixer weight, in percent.
+pub const MAX_MIX_WEIGHT: i32 = 200;
+
 /// Adjustment percentage for brightness/contrast, guaranteed to be within valid range (-100 to +100).
 ///
 /// This type ensures that adjustment values are always valid, eliminating
@@ -45,30 +66,402 @@ impl AdjustmentPercent {
     }
 }
 
-/// Brightness and contrast adjustment state.
+/// Unsharp mask settings. An amount of 0 means no sharpening.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SharpenSettings {
+    /// Strength, in percent of the difference with the blurred image.
+    pub amount: u32,
+    /// Blur radius, in pixels.
+    pub radius: f32,
+    /// Smallest difference with the blurred image that gets sharpened.
+    pub threshold: u8,
+}
+
+impl Default for SharpenSettings {
+    fn default() -> Self {
+        Self {
+            amount: 0,
+            radius: 1.0,
+            threshold: 0,
+        }
+    }
+}
+
+impl SharpenSettings {
+    /// Returns whether these settings leave the image unchanged.
+    pub fn is_neutral(self) -> bool {
+        self.amount == 0
+    }
+
+    /// Returns the transformation recording these settings.
+    fn transformation(self) -> Transformation {
+        Transformation::Sharpen {
+            amount: self.amount,
+            radius: self.radius,
+            threshold: self.threshold,
+        }
+    }
+}
+
+/// Channel weights reproducing the color filters used with black and white film.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrayscalePreset {
+    /// Perceived luminance, like a plain desaturation.
+    Neutral,
+    /// Dark skies and smooth skin.
+    RedFilter,
+    /// Lighter foliage and deeper skin tones.
+    GreenFilter,
+    /// Light skies and pronounced haze.
+    BlueFilter,
+}
+
+impl GrayscalePreset {
+    /// All presets, in display order.
+    pub const ALL: [GrayscalePreset; 4] = [
+        GrayscalePreset::Neutral,
+        GrayscalePreset::RedFilter,
+        GrayscalePreset::GreenFilter,
+        GrayscalePreset::BlueFilter,
+    ];
+
+    /// Returns the red, green and blue weights, in percent.
+    #[must_use]
+    pub fn weights(self) -> [i32; 3] {
+        match self {
+            GrayscalePreset::Neutral => [30, 59, 11],
+            GrayscalePreset::RedFilter => [80, 20, 0],
+            GrayscalePreset::GreenFilter => [20, 70, 10],
+            GrayscalePreset::BlueFilter => [10, 20, 70],
+        }
+    }
+
+    /// Returns the i18n key of the preset name.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            GrayscalePreset::Neutral => "image-editor-grayscale-preset-neutral",
+            GrayscalePreset::RedFilter => "image-editor-grayscale-preset-red",
+            GrayscalePreset::GreenFilter => "image-editor-grayscale-preset-green",
+            GrayscalePreset::BlueFilter => "image-editor-grayscale-preset-blue",
+        }
+    }
+}
+
+/// Black and white conversion through the channel mixer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelMixerSettings {
+    /// Whether the image is converted; colors are kept when false.
+    pub enabled: bool,
+    /// Red, green and blue weights, in percent.
+    pub weights: [i32; 3],
+}
+
+impl Default for ChannelMixerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            weights: GrayscalePreset::Neutral.weights(),
+        }
+    }
+}
+
+impl ChannelMixerSettings {
+    /// Returns whether these settings leave the image unchanged.
+    pub fn is_neutral(self) -> bool {
+        !self.enabled
+    }
+
+    /// Returns the preset matching the current weights, if any.
+    pub fn preset(self) -> Option<GrayscalePreset> {
+        GrayscalePreset::ALL
+            .into_iter()
+            .find(|preset| preset.weights() == self.weights)
+    }
+
+    /// Returns the transformation recording these settings.
+    fn transformation(self) -> Transformation {
+        Transformation::ChannelMix {
+            weights: self.weights,
+        }
+    }
+}
+
+/// Automatic correction previewed from the Auto enhance button.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoEnhanceSettings {
+    /// Correction found from the histogram of the working image.
+    pub params: AutoEnhanceParams,
+    /// How much of the correction is blended over the image, from 0 to 100.
+    pub strength: u32,
+}
+
+impl AutoEnhanceSettings {
+    /// Returns whether these settings leave the image unchanged.
+    pub fn is_neutral(self) -> bool {
+        self.strength == 0 || self.params.is_neutral()
+    }
+}
+
+/// Auto enhance, brightness, contrast, black and white and sharpening
+/// adjustment state.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct AdjustmentState {
+    /// Automatic correction applied before the manual adjustments, if requested.
+    pub auto_enhance: Option<AutoEnhanceSettings>,
     /// Brightness level (guaranteed valid by type).
     pub brightness: AdjustmentPercent,
     /// Contrast level (guaranteed valid by type).
     pub contrast: AdjustmentPercent,
+    /// Black and white conversion applied after brightness and contrast.
+    pub mixer: ChannelMixerSettings,
+    /// Unsharp mask applied last.
+    pub sharpen: SharpenSettings,
 }
 
 impl AdjustmentState {
     /// Returns true if any adjustment has been made (non-neutral values).
     #[must_use]
     pub fn has_changes(&self) -> bool {
-        !self.brightness.is_neutral() || !self.contrast.is_neutral()
+        self.auto_enhance.is_some_and(|auto| !auto.is_neutral())
+            || !self.brightness.is_neutral()
+            || !self.contrast.is_neutral()
+            || !self.mixer.is_neutral()
+            || !self.sharpen.is_neutral()
     }
 
     /// Reset adjustments to default values.
     pub fn reset(&mut self) {
+        self.auto_enhance = None;
         self.brightness = AdjustmentPercent::default();
         self.contrast = AdjustmentPercent::default();
+        self.mixer = ChannelMixerSettings::default();
+        self.sharpen = SharpenSettings::default();
+    }
+}
+
+/// Edits copied from one image so they can be pasted onto others.
+///
+/// The recipe keeps the steps in the order they were applied, so pasting
+/// reproduces the exact same result (clipping included) on an image with
+/// similar exposure. Rotations, flips, crops and resizes are included and
+/// scaled to the size of the image they are pasted onto. Auto enhance is
+/// left out since it is computed from each image's histogram, and so are
+/// AI and plugin results, which belong to the image they were computed on.
+#[derive(Debug, Clone, Default)]
+pub struct AdjustmentRecipe {
+    /// Size of the image the recipe was copied from, before its first step.
+    size: (u32, u32),
+    steps: Vec<Transformation>,
+}
+
+impl AdjustmentRecipe {
+    /// Creates a recipe from steps applied to an image of `size`; steps that
+    /// can't be part of a recipe are dropped.
+    #[must_use]
+    pub fn new(size: (u32, u32), steps: &[Transformation]) -> Self {
+        let mut recipe = Self {
+            size,
+            steps: Vec::new(),
+        };
+        for step in steps {
+            recipe.push(step);
+        }
+        recipe
+    }
+
+    /// Returns the size of the image the recipe was copied from.
+    #[must_use]
+    pub fn size(&self) -> (u32, u32) {
+        self.size
     }
+
+    /// Returns whether the recipe contains no adjustment.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Returns the number of adjustment steps.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns the adjustment steps in application order.
+    #[must_use]
+    pub fn steps(&self) -> &[Transformation] {
+        &self.steps
+    }
+
+    /// Returns the steps to apply to an image of `size`.
+    ///
+    /// Crops and resizes are scaled by the ratio between `size` and the size
+    /// the recipe was copied from, tracked through each step, so a crop of
+    /// the left half keeps the left half of any image.
+    #[must_use]
+    pub fn steps_for(&self, size: (u32, u32)) -> Vec<Transformation> {
+        let (mut source, mut target) = (self.size, size);
+        let mut steps = Vec::with_capacity(self.steps.len());
+        for step in &self.steps {
+            let scale_x = target.0 as f32 / source.0.max(1) as f32;
+            let scale_y = target.1 as f32 / source.1.max(1) as f32;
+            match step {
+                Transformation::RotateLeft | Transformation::RotateRight => {
+                    source = (source.1, source.0);
+                    target = (target.1, target.0);
+                    steps.push(step.clone());
+                }
+                Transformation::Crop { rect } => {
+                    let x = (rect.x * scale_x).round().clamp(0.0, target.0 as f32 - 1.0);
+                    let y = (rect.y * scale_y).round().clamp(0.0, target.1 as f32 - 1.0);
+                    let width = (rect.width * scale_x)
+                        .round()
+                        .clamp(1.0, target.0 as f32 - x);
+                    let height = (rect.height * scale_y)
+                        .round()
+                        .clamp(1.0, target.1 as f32 - y);
+                    source = (rect.width as u32, rect.height as u32);
+                    target = (width as u32, height as u32);
+                    steps.push(Transformation::Crop {
+                        rect: iced::Rectangle {
+                            x,
+                            y,
+                            width,
+                            height,
+                        },
+                    });
+                }
+                Transformation::Resize { width, height } => {
+                    let scaled = (
+                        ((*width as f32 * scale_x).round() as u32).max(1),
+                        ((*height as f32 * scale_y).round() as u32).max(1),
+                    );
+                    source = (*width, *height);
+                    target = scaled;
+                    steps.push(Transformation::Resize {
+                        width: scaled.0,
+                        height: scaled.1,
+                    });
+                }
+                _ => steps.push(step.clone()),
+            }
+        }
+        steps
+    }
+
+    /// Appends a step if it can be part of a recipe; other transformations
+    /// are ignored.
+    fn push(&mut self, transformation: &Transformation) {
+        if is_recipe_step(transformation) {
+            self.steps.push(transformation.clone());
+        }
+    }
+}
+
+/// Returns whether a transformation belongs in an [`AdjustmentRecipe`].
+fn is_recipe_step(transformation: &Transformation) -> bool {
+    matches!(
+        transformation,
+        Transformation::RotateLeft
+            | Transformation::RotateRight
+            | Transformation::FlipHorizontal
+            | Transformation::FlipVertical
+            | Transformation::Crop { .. }
+            | Transformation::Resize { .. }
+            | Transformation::AdjustBrightness { .. }
+            | Transformation::AdjustContrast { .. }
+            | Transformation::ChannelMix { .. }
+            | Transformation::Sharpen { .. }
+            | Transformation::Look { .. }
+    )
 }
 
 impl State {
+    /// Returns true if there are applied or pending adjustments to copy.
+    #[must_use]
+    pub fn has_adjustments_to_copy(&self) -> bool {
+        // A pending auto enhance is not part of recipes
+        let copied = AdjustmentState {
+            auto_enhance: None,
+            ..self.adjustment.clone()
+        };
+        copied.has_changes()
+            || self.transformation_history[..self.history_index]
+                .iter()
+                .any(is_recipe_step)
+    }
+
+    /// Collect the adjustments applied to this image into a recipe.
+    ///
+    /// Includes the applied history (up to the current undo position) followed
+    /// by slider values that have not been applied yet.
+    #[must_use]
+    pub fn copy_adjustments(&self) -> AdjustmentRecipe {
+        let mut recipe = AdjustmentRecipe::new(
+            (self.original_image.width(), self.original_image.height()),
+            &self.transformation_history[..self.history_index],
+        );
+        if !self.adjustment.brightness.is_neutral() {
+            recipe.push(&Transformation::AdjustBrightness {
+                value: self.adjustment.brightness.value(),
+            });
+        }
+        if !self.adjustment.contrast.is_neutral() {
+            recipe.push(&Transformation::AdjustContrast {
+                value: self.adjustment.contrast.value(),
+            });
+        }
+        if !self.adjustment.mixer.is_neutral() {
+            recipe.push(&self.adjustment.mixer.transformation());
+        }
+        if !self.adjustment.sharpen.is_neutral() {
+            recipe.push(&self.adjustment.sharpen.transformation());
+        }
+        recipe
+    }
+
+    /// Apply a copied recipe to this image, one history entry per step.
+    ///
+    /// Pending slider values are committed first so they are not lost, and
+    /// crops and resizes are scaled to this image's size.
+    pub fn paste_adjustments(&mut self, recipe: &AdjustmentRecipe) {
+        self.commit_adjustment_changes();
+
+        let size = (self.working_image.width(), self.working_image.height());
+        for step in recipe.steps_for(size) {
+            let operation = step.clone();
+            self.apply_dynamic_transformation(step, move |image| operation.apply(image));
+        }
+        if (self.working_image.width(), self.working_image.height()) != size {
+            self.sync_crop_state_dimensions();
+        }
+
+        self.adjustment.reset();
+        self.preview_image = None;
+    }
+
+    /// Analyze the working image and preview the automatic correction, or
+    /// drop it when it is already previewed.
+    pub(crate) fn sidebar_toggle_auto_enhance(&mut self) {
+        self.adjustment.auto_enhance = match self.adjustment.auto_enhance {
+            Some(_) => None,
+            None => Some(AutoEnhanceSettings {
+                params: auto_enhance::analyze(&self.working_image),
+                strength: 100,
+            }),
+        };
+        self.update_adjustment_preview();
+    }
+
+    /// Handle auto enhance strength slider change with live preview.
+    pub(crate) fn sidebar_auto_enhance_strength_changed(&mut self, strength: u32) {
+        if let Some(auto) = self.adjustment.auto_enhance.as_mut() {
+            auto.strength = strength.min(100);
+            self.update_adjustment_preview();
+        }
+    }
+
     /// Handle brightness slider change with live preview.
     pub(crate) fn sidebar_brightness_changed(&mut self, value: i32) {
         self.adjustment.brightness = AdjustmentPercent::new(value);
@@ -81,17 +474,71 @@ impl State {
         self.update_adjustment_preview();
     }
 
+    /// Turn the black and white conversion on or off, with live preview.
+    pub(crate) fn sidebar_grayscale_toggled(&mut self, enabled: bool) {
+        self.adjustment.mixer.enabled = enabled;
+        self.update_adjustment_preview();
+    }
+
+    /// Load the channel weights of a filter preset and convert with them.
+    pub(crate) fn sidebar_grayscale_preset_selected(&mut self, preset: GrayscalePreset) {
+        self.adjustment.mixer = ChannelMixerSettings {
+            enabled: true,
+            weights: preset.weights(),
+        };
+        self.update_adjustment_preview();
+    }
+
+    /// Handle a channel mixer slider change (0 = red, 1 = green, 2 = blue).
+    pub(crate) fn sidebar_mixer_weight_changed(&mut self, channel: usize, weight: i32) {
+        if let Some(slot) = self.adjustment.mixer.weights.get_mut(channel) {
+            *slot = weight.clamp(MIN_MIX_WEIGHT, MAX_MIX_WEIGHT);
+            self.update_adjustment_preview();
+        }
+    }
+
+    /// Handle sharpen amount slider change with live preview.
+    pub(crate) fn sidebar_sharpen_amount_changed(&mut self, amount: u32) {
+        self.adjustment.sharpen.amount = amount.min(MAX_SHARPEN_AMOUNT);
+        self.update_adjustment_preview();
+    }
+
+    /// Handle sharpen radius slider change with live preview.
+    pub(crate) fn sidebar_sharpen_radius_changed(&mut self, radius: f32) {
+        self.adjustment.sharpen.radius = radius.clamp(MIN_SHARPEN_RADIUS, MAX_SHARPEN_RADIUS);
+        self.update_adjustment_preview();
+    }
+
+    /// Handle sharpen threshold slider change with live preview.
+    pub(crate) fn sidebar_sharpen_threshold_changed(&mut self, threshold: u8) {
+        self.adjustment.sharpen.threshold = threshold.min(MAX_SHARPEN_THRESHOLD);
+        self.update_adjustment_preview();
+    }
+
     /// Apply current adjustments to the image history.
     pub(crate) fn sidebar_apply_adjustments(&mut self) {
+        let auto = self.adjustment.auto_enhance;
         let brightness = self.adjustment.brightness;
         let contrast = self.adjustment.contrast;
+        let mixer = self.adjustment.mixer;
+        let sharpen = self.adjustment.sharpen;
 
         // Only apply if there are actual changes
-        if brightness.is_neutral() && contrast.is_neutral() {
+        if !self.adjustment.has_changes() {
             return;
         }
 
-        // Apply brightness first if non-neutral
+        // Automatic correction first, as it was computed on the unadjusted image
+        if let Some(AutoEnhanceSettings { params, strength }) =
+            auto.filter(|auto| !auto.is_neutral())
+        {
+            self.apply_dynamic_transformation(
+                Transformation::AutoEnhance { params, strength },
+                move |image| auto_enhance::apply(image, &params, strength),
+            );
+        }
+
+        // Apply brightness if non-neutral
         if !brightness.is_neutral() {
             let value = brightness.value();
             self.apply_dynamic_transformation(
@@ -109,6 +556,26 @@ impl State {
             );
         }
 
+        // Black and white conversion after the tonal adjustments
+        if !mixer.is_neutral() {
+            let weights = mixer.weights;
+            self.apply_dynamic_transformation(mixer.transformation(), move |image| {
+                image_transform::channel_mix_grayscale(image, weights)
+            });
+        }
+
+        // Sharpen last, so the mask sees the final tones
+        if !sharpen.is_neutral() {
+            let SharpenSettings {
+                amount,
+                radius,
+                threshold,
+            } = sharpen;
+            self.apply_dynamic_transformation(sharpen.transformation(), move |image| {
+                image_transform::unsharp_mask(image, amount, radius, threshold)
+            });
+        }
+
         // Reset sliders after applying
         self.adjustment.reset();
         self.preview_image = None;
@@ -122,11 +589,14 @@ impl State {
 
     /// Update the preview image with current adjustment values.
     fn update_adjustment_preview(&mut self) {
+        let auto = self.adjustment.auto_enhance;
         let brightness = self.adjustment.brightness;
         let contrast = self.adjustment.contrast;
+        let mixer = self.adjustment.mixer;
+        let sharpen = self.adjustment.sharpen;
 
         // No adjustments = no preview needed
-        if brightness.is_neutral() && contrast.is_neutral() {
+        if !self.adjustment.has_changes() {
             self.preview_image = None;
             return;
         }
@@ -134,6 +604,10 @@ impl State {
         // Apply adjustments to working image for preview
         let mut preview = self.working_image.clone();
 
+        if let Some(auto) = auto.filter(|auto| !auto.is_neutral()) {
+            preview = auto_enhance::apply(&preview, &auto.params, auto.strength);
+        }
+
         if !brightness.is_neutral() {
             preview = image_transform::adjust_brightness(&preview, brightness.value());
         }
@@ -142,6 +616,19 @@ impl State {
             preview = image_transform::adjust_contrast(&preview, contrast.value());
         }
 
+        if !mixer.is_neutral() {
+            preview = image_transform::channel_mix_grayscale(&preview, mixer.weights);
+        }
+
+        if !sharpen.is_neutral() {
+            preview = image_transform::unsharp_mask(
+                &preview,
+                sharpen.amount,
+                sharpen.radius,
+                sharpen.threshold,
+            );
+        }
+
         if let Ok(image_data) = image_transform::dynamic_to_image_data(&preview) {
             self.preview_image = Some(image_data);
         } else {
@@ -174,6 +661,7 @@ impl State {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ui::image_editor::state::test_support::editor_state;
 
     #[test]
     fn adjustment_state_default_has_no_changes() {
@@ -201,6 +689,7 @@ mod tests {
         let mut state = AdjustmentState {
             brightness: AdjustmentPercent::new(50),
             contrast: AdjustmentPercent::new(-30),
+            ..AdjustmentState::default()
         };
         assert!(state.has_changes());
 
@@ -224,4 +713,168 @@ mod tests {
         assert!(AdjustmentPercent::new(0).is_neutral());
         assert!(!AdjustmentPercent::new(50).is_neutral());
     }
+
+    #[test]
+    fn copy_adjustments_includes_geometry_and_pending_sliders() {
+        let (_dir, mut state) = editor_state(4, 4);
+        assert!(!state.has_adjustments_to_copy());
+
+        state.sidebar_brightness_changed(20);
+        state.sidebar_apply_adjustments();
+        state.record_transformation(Transformation::RotateLeft);
+        state.sidebar_contrast_changed(-10);
+        assert!(state.has_adjustments_to_copy());
+
+        let recipe = state.copy_adjustments();
+        assert_eq!(recipe.len(), 3);
+        assert_eq!(recipe.size(), (4, 4));
+        assert!(matches!(
+            recipe.steps()[0],
+            Transformation::AdjustBrightness { value: 20 }
+        ));
+        assert!(matches!(recipe.steps()[1], Transformation::RotateLeft));
+        assert!(matches!(
+            recipe.steps()[2],
+            Transformation::AdjustContrast { value: -10 }
+        ));
+    }
+
+    #[test]
+    fn recipe_scales_crops_and_resizes_to_the_target_size() {
+        let crop = |x, y, width, height| Transformation::Crop {
+            rect: iced::Rectangle {
+                x,
+                y,
+                width,
+                height,
+            },
+        };
+        let recipe = AdjustmentRecipe::new(
+            (400, 200),
+            &[
+                Transformation::RotateLeft,
+                crop(0.0, 100.0, 200.0, 300.0),
+                Transformation::Resize {
+                    width: 100,
+                    height: 150,
+                },
+                Transformation::Deblur {
+                    result: Box::new(image_rs::DynamicImage::new_rgba8(1, 1)),
+                },
+            ],
+        );
+        assert_eq!(recipe.len(), 3, "AI results are not copied");
+
+        let steps = recipe.steps_for((800, 400));
+        assert!(matches!(steps[0], Transformation::RotateLeft));
+        assert!(matches!(
+            steps[1],
+            Transformation::Crop { rect } if rect == iced::Rectangle::new(
+                iced::Point::new(0.0, 200.0),
+                iced::Size::new(400.0, 600.0),
+            )
+        ));
+        assert!(matches!(
+            steps[2],
+            Transformation::Resize {
+                width: 200,
+                height: 300
+            }
+        ));
+    }
+
+    #[test]
+    fn sharpen_is_applied_last_and_copied() {
+        let (_dir, mut state) = editor_state(4, 4);
+        state.sidebar_sharpen_amount_changed(150);
+        assert!(state.adjustment.has_changes());
+        state.sidebar_sharpen_radius_changed(50.0);
+        assert_eq!(state.adjustment.sharpen.radius, MAX_SHARPEN_RADIUS);
+        state.sidebar_brightness_changed(10);
+        state.sidebar_apply_adjustments();
+
+        assert!(!state.adjustment.has_changes());
+        assert!(matches!(
+            state.transformation_history.last(),
+            Some(Transformation::Sharpen { amount: 150, .. })
+        ));
+        assert_eq!(state.copy_adjustments().len(), 2);
+    }
+
+    #[test]
+    fn grayscale_preset_converts_before_sharpening() {
+        let (_dir, mut state) = editor_state(4, 4);
+        state.sidebar_grayscale_preset_selected(GrayscalePreset::RedFilter);
+        assert!(state.adjustment.has_changes());
+        assert_eq!(
+            state.adjustment.mixer.preset(),
+            Some(GrayscalePreset::RedFilter)
+        );
+
+        state.sidebar_mixer_weight_changed(1, 500);
+        assert_eq!(state.adjustment.mixer.weights, [80, MAX_MIX_WEIGHT, 0]);
+        assert_eq!(state.adjustment.mixer.preset(), None);
+
+        state.sidebar_sharpen_amount_changed(100);
+        state.sidebar_apply_adjustments();
+        assert!(matches!(
+            state.transformation_history[0],
+            Transformation::ChannelMix {
+                weights: [80, MAX_MIX_WEIGHT, 0]
+            }
+        ));
+        assert_eq!(state.copy_adjustments().len(), 2);
+    }
+
+    #[test]
+    fn auto_enhance_is_one_step_before_manual_adjustments() {
+        let (_dir, mut state) = editor_state(4, 4);
+        state.sidebar_toggle_auto_enhance();
+        assert!(state.adjustment.has_changes());
+        assert!(state.preview_image.is_some());
+        state.sidebar_auto_enhance_strength_changed(40);
+        state.sidebar_contrast_changed(10);
+        state.sidebar_apply_adjustments();
+
+        assert_eq!(state.transformation_history.len(), 2);
+        assert!(matches!(
+            state.transformation_history[0],
+            Transformation::AutoEnhance { strength: 40, .. }
+        ));
+        // The correction depends on each image, so only contrast is copied
+        assert_eq!(state.copy_adjustments().len(), 1);
+
+        state.sidebar_toggle_auto_enhance();
+        state.sidebar_toggle_auto_enhance();
+        assert!(!state.adjustment.has_changes());
+    }
+
+    #[test]
+    fn copy_adjustments_ignores_undone_steps() {
+        let (_dir, mut state) = editor_state(4, 4);
+        state.sidebar_brightness_changed(30);
+        state.sidebar_apply_adjustments();
+        state.sidebar_undo();
+
+        assert!(state.copy_adjustments().is_empty());
+        assert!(!state.has_adjustments_to_copy());
+    }
+
+    #[test]
+    fn paste_adjustments_reproduces_source_pixels() {
+        let (_dir, mut source) = editor_state(4, 4);
+        source.sidebar_brightness_changed(25);
+        source.sidebar_contrast_changed(40);
+        source.sidebar_apply_adjustments();
+        let recipe = source.copy_adjustments();
+
+        let (_dir2, mut target) = editor_state(4, 4);
+        target.paste_adjustments(&recipe);
+
+        assert_eq!(target.transformation_history.len(), 2);
+        assert_eq!(
+            target.working_image.to_rgba8().into_raw(),
+            source.working_image.to_rgba8().into_raw()
+        );
+    }
 }
@@ -0,0 +1,286 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Background removal tool state: AI cut-out refined with a brush.
+//!
+//! Image coordinates are converted between f32 (canvas) and u32 (pixels).
+//! Precision loss is acceptable for typical image sizes.
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+
+use crate::media::{background_removal, image_transform};
+use crate::ui::image_editor::{State, Transformation};
+use image_rs::{DynamicImage, GrayImage};
+
+/// Smallest brush radius, in image pixels.
+pub const MIN_BRUSH_RADIUS: u32 = 2;
+
+/// Largest brush radius, in image pixels.
+pub const MAX_BRUSH_RADIUS: u32 = 200;
+
+/// Brush radius when the tool opens, in image pixels.
+const DEFAULT_BRUSH_RADIUS: u32 = 20;
+
+/// Share of the brush radius faded out at its edge, for soft strokes.
+const BRUSH_FEATHER: f32 = 0.3;
+
+/// What the refine-edges brush paints into the mask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BrushMode {
+    /// Brings back parts of the image taken for background.
+    #[default]
+    Keep,
+    /// Removes parts of the image taken for foreground.
+    Erase,
+}
+
+/// State for the background removal tool.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundState {
+    /// Whether the segmentation model is running.
+    pub is_processing: bool,
+    /// Image the mask applies to, kept while the cut-out is refined.
+    pub source: Option<DynamicImage>,
+    /// Foreground mask being refined (255 keeps a pixel, 0 removes it).
+    pub mask: Option<GrayImage>,
+    pub brush_mode: BrushMode,
+    /// Brush radius, in image pixels.
+    pub brush_radius: u32,
+    /// Last point painted in the current stroke, to join dabs.
+    last_point: Option<(f32, f32)>,
+}
+
+impl Default for BackgroundState {
+    fn default() -> Self {
+        Self {
+            is_processing: false,
+            source: None,
+            mask: None,
+            brush_mode: BrushMode::default(),
+            brush_radius: DEFAULT_BRUSH_RADIUS,
+            last_point: None,
+        }
+    }
+}
+
+impl BackgroundState {
+    /// Returns true while the cut-out waits to be refined and applied.
+    #[must_use]
+    pub fn is_refining(&self) -> bool {
+        self.mask.is_some()
+    }
+
+    /// Paints a brush stroke from the previous point of the stroke to
+    /// (`x`, `y`), in image pixels. Returns `false` when there is no mask.
+    pub fn paint(&mut self, x: f32, y: f32) -> bool {
+        let Some(mask) = self.mask.as_mut() else {
+            return false;
+        };
+        let radius = self.brush_radius as f32;
+        let target = match self.brush_mode {
+            BrushMode::Keep => 255.0,
+            BrushMode::Erase => 0.0,
+        };
+
+        // Dabs every half radius along the segment so fast strokes stay solid
+        let (from_x, from_y) = self.last_point.unwrap_or((x, y));
+        let distance = (x - from_x).hypot(y - from_y);
+        let steps = (distance / (radius / 2.0).max(1.0)).ceil().max(1.0) as u32;
+        for step in 1..=steps {
+            let t = step as f32 / steps as f32;
+            paint_dab(
+                mask,
+                from_x + (x - from_x) * t,
+                from_y + (y - from_y) * t,
+                radius,
+                target,
+            );
+        }
+        self.last_point = Some((x, y));
+        true
+    }
+
+    /// Ends the current brush stroke.
+    pub fn end_stroke(&mut self) {
+        self.last_point = None;
+    }
+
+    /// Reset to default state, keeping the brush settings.
+    pub fn reset(&mut self) {
+        *self = Self {
+            brush_mode: self.brush_mode,
+            brush_radius: self.brush_radius,
+            ..Self::default()
+        };
+    }
+}
+
+/// Paints one round dab of `radius` centered on (`cx`, `cy`), blending the
+/// mask towards `target` with a feathered edge.
+fn paint_dab(mask: &mut GrayImage, cx: f32, cy: f32, radius: f32, target: f32) {
+    let (width, height) = mask.dimensions();
+    let feather = (radius * BRUSH_FEATHER).max(1.0);
+    let x_range =
+        (cx - radius).floor().max(0.0) as u32..((cx + radius).ceil().max(0.0) as u32).min(width);
+    for y in
+        (cy - radius).floor().max(0.0) as u32..((cy + radius).ceil().max(0.0) as u32).min(height)
+    {
+        for x in x_range.clone() {
+            let distance = (x as f32 + 0.5 - cx).hypot(y as f32 + 0.5 - cy);
+            let weight = ((radius - distance) / feather).clamp(0.0, 1.0);
+            if weight > 0.0 {
+                let pixel = mask.get_pixel_mut(x, y);
+                let value = f32::from(pixel.0[0]);
+                pixel.0[0] = (value + (target - value) * weight).round() as u8;
+            }
+        }
+    }
+}
+
+impl State {
+    /// Prepare background removal tool when selected.
+    pub(crate) fn prepare_background_tool(&mut self) {
+        self.background.reset();
+    }
+
+    /// Teardown background removal tool when deselected.
+    pub(crate) fn teardown_background_tool(&mut self) {
+        self.background.reset();
+        self.preview_image = None;
+    }
+
+    /// Start computing the foreground mask.
+    ///
+    /// Note: The segmentation runs asynchronously in the parent application,
+    /// which hands the mask back through [`State::apply_background_mask`].
+    pub(crate) fn sidebar_remove_background(&mut self) {
+        self.background.reset();
+        self.background.is_processing = true;
+    }
+
+    /// Show the cut-out made with the computed foreground `mask`, ready to
+    /// be refined with the brush.
+    ///
+    /// The mask is dropped if the tool was closed or the image changed size
+    /// while it was computed.
+    pub fn apply_background_mask(&mut self, mask: GrayImage) {
+        if !self.background.is_processing
+            || mask.dimensions() != (self.working_image.width(), self.working_image.height())
+        {
+            self.background.is_processing = false;
+            return;
+        }
+        self.background.is_processing = false;
+        self.background.source = Some(self.working_image.clone());
+        self.background.mask = Some(mask);
+        self.refresh_background_preview();
+    }
+
+    /// Mark the background removal as failed and reset state.
+    pub fn background_removal_failed(&mut self) {
+        self.background.reset();
+    }
+
+    /// Paint the refine-edges brush at (`x`, `y`), in image pixels.
+    pub(crate) fn paint_background_mask(&mut self, x: f32, y: f32) {
+        if self.background.paint(x, y) {
+            self.refresh_background_preview();
+        }
+    }
+
+    /// Record the refined cut-out in history and make it the working image.
+    pub(crate) fn sidebar_apply_background_removal(&mut self) {
+        let (Some(source), Some(mask)) = (&self.background.source, &self.background.mask) else {
+            return;
+        };
+        let result = background_removal::apply_mask(source, mask);
+        let Ok(image_data) = image_transform::dynamic_to_image_data(&result) else {
+            return;
+        };
+
+        self.record_transformation(Transformation::RemoveBackground {
+            result: Box::new(result.clone()),
+        });
+        self.working_image = result;
+        self.current_image = image_data;
+        self.sync_resize_state_dimensions();
+        self.preview_image = None;
+        self.background.reset();
+    }
+
+    /// Commit a pending cut-out (called when switching tools).
+    pub(crate) fn commit_background_changes(&mut self) {
+        if self.background.is_refining() {
+            self.sidebar_apply_background_removal();
+        }
+    }
+
+    /// Show the cut-out with the current mask on the canvas.
+    fn refresh_background_preview(&mut self) {
+        let (Some(source), Some(mask)) = (&self.background.source, &self.background.mask) else {
+            return;
+        };
+        let preview = background_removal::apply_mask(source, mask);
+        self.preview_image = image_transform::dynamic_to_image_data(&preview).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn refining(mode: BrushMode, fill: u8) -> BackgroundState {
+        BackgroundState {
+            mask: Some(GrayImage::from_pixel(40, 20, image_rs::Luma([fill]))),
+            brush_mode: mode,
+            brush_radius: 4,
+            ..BackgroundState::default()
+        }
+    }
+
+    #[test]
+    fn brush_needs_a_mask() {
+        let mut state = BackgroundState::default();
+        assert!(!state.is_refining());
+        assert!(!state.paint(5.0, 5.0));
+    }
+
+    #[test]
+    fn erase_brush_clears_a_soft_disc() {
+        let mut state = refining(BrushMode::Erase, 255);
+        assert!(state.paint(10.0, 10.0));
+        let mask = state.mask.as_ref().unwrap();
+        assert_eq!(mask.get_pixel(10, 10).0[0], 0);
+        // Feathered edge, untouched outside the radius
+        let edge = mask.get_pixel(13, 10).0[0];
+        assert!(edge > 0 && edge < 255, "edge is {edge}");
+        assert_eq!(mask.get_pixel(20, 10).0[0], 255);
+    }
+
+    #[test]
+    fn strokes_join_successive_points() {
+        let mut state = refining(BrushMode::Keep, 0);
+        state.paint(5.0, 10.0);
+        state.paint(35.0, 10.0);
+        let mask = state.mask.as_ref().unwrap();
+        assert!((5..=35).all(|x| mask.get_pixel(x, 10).0[0] == 255));
+
+        // A new stroke starts where it is painted
+        state.end_stroke();
+        state.brush_mode = BrushMode::Erase;
+        state.paint(20.0, 3.0);
+        let mask = state.mask.as_ref().unwrap();
+        assert_eq!(mask.get_pixel(20, 3).0[0], 0);
+        assert_eq!(mask.get_pixel(5, 10).0[0], 255);
+    }
+
+    #[test]
+    fn reset_keeps_brush_settings() {
+        let mut state = refining(BrushMode::Erase, 128);
+        state.is_processing = true;
+        state.reset();
+        assert!(!state.is_refining());
+        assert!(!state.is_processing);
+        assert_eq!(state.brush_mode, BrushMode::Erase);
+        assert_eq!(state.brush_radius, 4);
+    }
+}
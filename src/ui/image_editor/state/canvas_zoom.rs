@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Canvas zoom of the editor, independent of the viewer's.
+//!
+//! The canvas opens fitted to the window. Wheel zoom and the 100% toggle
+//! switch to a manual zoom, kept until the fit is chosen again.
+#![allow(clippy::cast_precision_loss)]
+
+use crate::ui::image_editor::State;
+use crate::ui::state::zoom::{clamp_zoom, zoom_for_media_scale, DEFAULT_ZOOM_PERCENT};
+
+/// Zoom of the 100% toggle: one image pixel per screen pixel.
+const ACTUAL_SIZE_PERCENT: f32 = 100.0;
+
+/// Returns the zoom fitting an image of `image_size` into `available`, in percent.
+#[must_use]
+pub fn fit_zoom_percent(image_size: (u32, u32), available: iced::Size) -> f32 {
+    let (width, height) = image_size;
+    if width == 0 || height == 0 || available.width <= 0.0 || available.height <= 0.0 {
+        return DEFAULT_ZOOM_PERCENT;
+    }
+    let scale = (available.width / width as f32).min(available.height / height as f32);
+    if !scale.is_finite() || scale <= 0.0 {
+        return DEFAULT_ZOOM_PERCENT;
+    }
+    clamp_zoom(zoom_for_media_scale(scale))
+}
+
+impl State {
+    /// Zoom the canvas is shown at, in percent.
+    ///
+    /// In fit mode this follows the canvas size; until the canvas has been
+    /// laid out the last zoom is used.
+    #[must_use]
+    pub fn canvas_zoom_percent(&self) -> f32 {
+        match self.viewport.bounds {
+            Some(bounds) if self.zoom.fit_to_window => fit_zoom_percent(
+                (self.current_image.width, self.current_image.height),
+                bounds.size(),
+            ),
+            _ => self.zoom.zoom_percent,
+        }
+    }
+
+    /// Switch between fitting the image to the canvas and showing it at 100%.
+    pub(crate) fn toggle_fit_zoom(&mut self) {
+        if self.zoom.fit_to_window {
+            self.zoom.apply_manual_zoom(ACTUAL_SIZE_PERCENT);
+        } else {
+            self.zoom.enable_fit_to_window();
+            self.refresh_fit_zoom();
+        }
+    }
+
+    /// Keep the displayed zoom in sync with the canvas size in fit mode.
+    pub(crate) fn refresh_fit_zoom(&mut self) {
+        if self.zoom.fit_to_window {
+            let percent = self.canvas_zoom_percent();
+            self.zoom.update_zoom_display(percent);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_zoom_uses_the_limiting_side() {
+        let available = iced::Size::new(400.0, 300.0);
+        assert!((fit_zoom_percent((800, 300), available) - 50.0).abs() < f32::EPSILON);
+        assert!((fit_zoom_percent((100, 600), available) - 50.0).abs() < f32::EPSILON);
+        assert!((fit_zoom_percent((100, 100), available) - 300.0).abs() < f32::EPSILON);
+        assert!(
+            (fit_zoom_percent((0, 100), available) - DEFAULT_ZOOM_PERCENT).abs() < f32::EPSILON
+        );
+    }
+}
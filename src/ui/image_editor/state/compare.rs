@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Before/after comparison with the untouched source image.
+//!
+//! Holding "Show original" (or `\`) replaces the canvas with the source
+//! image; the split view shows it left of a divider moved with a slider.
+
+use crate::media::image_transform;
+use crate::media::ImageData;
+use crate::ui::image_editor::State;
+
+/// Split position the comparison opens at, in percent of the image width.
+const DEFAULT_SPLIT_PERCENT: u32 = 50;
+
+/// Comparison state of the editor canvas.
+#[derive(Debug, Clone, Default)]
+pub struct CompareState {
+    /// Whether the original is shown in place of the edited image.
+    pub holding: bool,
+    /// Divider position of the split view, in percent, when it is shown.
+    pub split: Option<u32>,
+    /// Source image prepared for display, kept while a comparison is shown.
+    original: Option<ImageData>,
+}
+
+impl CompareState {
+    /// Returns whether the original is needed on the canvas.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.holding || self.split.is_some()
+    }
+}
+
+impl State {
+    /// Show the original image instead of the edits while `held`.
+    pub(crate) fn set_show_original(&mut self, held: bool) {
+        self.compare.holding = held && self.prepare_compare_original();
+        self.release_compare_original();
+    }
+
+    /// Open or close the split before/after view.
+    pub(crate) fn toggle_split_compare(&mut self) {
+        self.compare.split = if self.compare.split.is_none() && self.prepare_compare_original() {
+            Some(DEFAULT_SPLIT_PERCENT)
+        } else {
+            None
+        };
+        self.release_compare_original();
+    }
+
+    /// Move the divider of the split view.
+    pub(crate) fn set_compare_split(&mut self, percent: u32) {
+        if self.compare.split.is_some() {
+            self.compare.split = Some(percent.min(100));
+        }
+    }
+
+    /// Original image to draw, when a comparison is shown.
+    #[must_use]
+    pub fn compare_original(&self) -> Option<&ImageData> {
+        self.compare
+            .is_active()
+            .then_some(self.compare.original.as_ref())
+            .flatten()
+    }
+
+    /// Returns whether the original replaces the edits on the canvas.
+    #[must_use]
+    pub fn is_showing_original(&self) -> bool {
+        self.compare.holding
+    }
+
+    /// Divider position of the split view, in percent, when it is shown.
+    #[must_use]
+    pub fn compare_split(&self) -> Option<u32> {
+        self.compare.split
+    }
+
+    /// Convert the source image for display, unless already done.
+    fn prepare_compare_original(&mut self) -> bool {
+        if self.compare.original.is_none() {
+            self.compare.original =
+                image_transform::dynamic_to_image_data(&self.original_image).ok();
+        }
+        self.compare.original.is_some()
+    }
+
+    /// Drop the converted source image once no comparison needs it.
+    fn release_compare_original(&mut self) {
+        if !self.compare.is_active() {
+            self.compare.original = None;
+        }
+    }
+}
@@ -10,10 +10,16 @@
 #![allow(clippy::cast_possible_truncation)]
 #![allow(clippy::cast_sign_loss)]
 
+use crate::media::face_detection::FaceBox;
 use crate::media::{image_transform, ImageData};
 use crate::ui::design_tokens::sizing;
 use crate::ui::image_editor::{CanvasMessage, Event, State, Transformation};
 use iced::Rectangle;
+use image_rs::DynamicImage;
+
+/// Space left around a face when cropping to it, as a fraction of the face
+/// size on each side.
+const FACE_CROP_MARGIN: f32 = 0.75;
 
 /// Crop aspect ratio constraints.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -27,6 +33,21 @@ pub enum CropRatio {
     PhotoPortrait, // 3:4
 }
 
+impl CropRatio {
+    /// Returns the width/height ratio enforced by this constraint, if any.
+    #[must_use]
+    pub fn aspect(self) -> Option<f32> {
+        match self {
+            CropRatio::None | CropRatio::Free => None,
+            CropRatio::Square => Some(1.0),
+            CropRatio::Landscape => Some(16.0 / 9.0),
+            CropRatio::Portrait => Some(9.0 / 16.0),
+            CropRatio::Photo => Some(4.0 / 3.0),
+            CropRatio::PhotoPortrait => Some(3.0 / 4.0),
+        }
+    }
+}
+
 /// Position of a resize handle on the crop rectangle
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HandlePosition {
@@ -87,6 +108,12 @@ pub struct CropState {
     pub ratio: CropRatio,
     /// Interactive overlay state
     pub overlay: CropOverlay,
+    /// Faces detected in the crop base image, once detection ran
+    pub faces: Option<Vec<FaceBox>>,
+    /// Index of the face the crop will frame next
+    pub face_index: usize,
+    /// Whether face detection is running for the crop base image
+    pub is_detecting_faces: bool,
 }
 
 impl CropState {
@@ -106,8 +133,51 @@ impl CropState {
                 visible: false,
                 drag_state: CropDragState::None,
             },
+            faces: None,
+            face_index: 0,
+            is_detecting_faces: false,
+        }
+    }
+
+    /// Forgets the detected faces, which only apply to the current base image.
+    fn reset_faces(&mut self) {
+        self.faces = None;
+        self.face_index = 0;
+        self.is_detecting_faces = false;
+    }
+}
+
+/// Returns the crop rectangle `(x, y, width, height)` framing `face` with a
+/// margin, following the `ratio` constraint and fitting the base image.
+fn face_crop_rect(
+    face: &FaceBox,
+    ratio: CropRatio,
+    base_width: u32,
+    base_height: u32,
+) -> (u32, u32, u32, u32) {
+    let mut width = face.width * (1.0 + 2.0 * FACE_CROP_MARGIN);
+    let mut height = face.height * (1.0 + 2.0 * FACE_CROP_MARGIN);
+    if let Some(aspect) = ratio.aspect() {
+        if width / height < aspect {
+            width = height * aspect;
+        } else {
+            height = width / aspect;
         }
     }
+
+    // Shrink uniformly so a large face still fits in the image
+    let base_w = base_width as f32;
+    let base_h = base_height as f32;
+    let fit = (base_w / width).min(base_h / height).min(1.0);
+    let width = (width * fit).floor().max(1.0);
+    let height = (height * fit).floor().max(1.0);
+
+    let (center_x, center_y) = face.center();
+    let x = (center_x - width / 2.0).clamp(0.0, base_w - width).floor();
+    let y = (center_y - height / 2.0)
+        .clamp(0.0, base_h - height)
+        .floor();
+    (x as u32, y as u32, width as u32, height as u32)
 }
 
 impl State {
@@ -141,6 +211,7 @@ impl State {
         self.crop.width = self.current_image.width;
         self.crop.height = self.current_image.height;
         self.crop.ratio = CropRatio::None;
+        self.crop.reset_faces();
         self.hide_crop_overlay();
     }
 
@@ -152,9 +223,70 @@ impl State {
     pub(crate) fn teardown_crop_tool(&mut self) {
         self.crop_modified = false;
         self.crop_base_image = None;
+        self.crop.reset_faces();
         self.hide_crop_overlay();
     }
 
+    /// Frames the next detected face, asking the app to detect faces first
+    /// if needed.
+    pub(crate) fn crop_to_next_face(&mut self) -> Event {
+        if self.crop.is_detecting_faces || self.crop_base_image.is_none() {
+            return Event::None;
+        }
+        if self.crop.faces.is_none() {
+            self.crop.is_detecting_faces = true;
+            return Event::FaceDetectionRequested;
+        }
+        self.frame_current_face();
+        Event::None
+    }
+
+    /// Returns the image faces are detected in for the crop tool.
+    #[must_use]
+    pub fn crop_base_image(&self) -> Option<&DynamicImage> {
+        self.crop_base_image.as_ref()
+    }
+
+    /// Stores the faces detected in the crop base image and frames the first one.
+    ///
+    /// Results arriving after the crop base changed are ignored.
+    pub fn apply_face_detection_result(&mut self, result: Result<Vec<FaceBox>, String>) {
+        if !self.crop.is_detecting_faces {
+            return;
+        }
+        self.crop.is_detecting_faces = false;
+        if let Ok(faces) = result {
+            self.crop.faces = Some(faces);
+            self.crop.face_index = 0;
+            self.frame_current_face();
+        }
+    }
+
+    /// Moves the crop rectangle onto the current face and selects the next one.
+    fn frame_current_face(&mut self) {
+        let Some(faces) = self.crop.faces.as_ref().filter(|faces| !faces.is_empty()) else {
+            return;
+        };
+        let face = faces[self.crop.face_index % faces.len()];
+        self.crop.face_index = (self.crop.face_index + 1) % faces.len();
+
+        if self.crop.ratio == CropRatio::None {
+            self.crop.ratio = CropRatio::Free;
+        }
+        let (x, y, width, height) = face_crop_rect(
+            &face,
+            self.crop.ratio,
+            self.crop_base_width,
+            self.crop_base_height,
+        );
+        self.crop.x = x;
+        self.crop.y = y;
+        self.crop.width = width;
+        self.crop.height = height;
+        self.crop.overlay.visible = true;
+        self.crop_modified = true;
+    }
+
     pub(crate) fn set_crop_ratio_from_sidebar(&mut self, ratio: CropRatio) {
         self.crop.ratio = ratio;
         self.adjust_crop_to_ratio(ratio);
@@ -290,6 +422,7 @@ impl State {
         self.crop.overlay.drag_state = CropDragState::None;
         self.crop_modified = false;
         self.crop.ratio = CropRatio::None;
+        self.crop.reset_faces();
         self.crop.x = 0;
         self.crop.y = 0;
         self.crop.width = self.current_image.width;
@@ -506,13 +639,8 @@ impl State {
     }
 
     fn apply_aspect_ratio_constraint_to_current_crop(&mut self) {
-        let target_ratio = match self.crop.ratio {
-            CropRatio::None | CropRatio::Free => return, // No constraint
-            CropRatio::Square => 1.0,
-            CropRatio::Landscape => 16.0 / 9.0,
-            CropRatio::Portrait => 9.0 / 16.0,
-            CropRatio::Photo => 4.0 / 3.0,
-            CropRatio::PhotoPortrait => 3.0 / 4.0,
+        let Some(target_ratio) = self.crop.ratio.aspect() else {
+            return; // No constraint
         };
 
         // Adjust height to match ratio, keeping width fixed
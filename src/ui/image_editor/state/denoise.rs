@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Denoise tool state: noise reduction previewed on a downscaled proxy.
+
+use crate::media::denoise::{self, DenoiseMethod, DenoiseParams};
+use crate::media::image_transform;
+use crate::ui::image_editor::{Event, State, Transformation};
+use image_rs::DynamicImage;
+
+/// State for the denoise tool.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DenoiseState {
+    pub params: DenoiseParams,
+    /// Whether the full-resolution denoise is running.
+    pub is_processing: bool,
+    /// Downscaled copy of the working image the live preview is computed
+    /// on, with the history revision it was made at.
+    pub proxy: Option<(u64, DynamicImage)>,
+}
+
+impl DenoiseState {
+    /// Reset to default state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl State {
+    /// Prepare denoise tool when selected.
+    pub(crate) fn prepare_denoise_tool(&mut self) {
+        self.denoise.reset();
+        self.update_denoise_preview();
+    }
+
+    /// Teardown denoise tool when deselected.
+    ///
+    /// The previewed settings are not applied: noise reduction at full
+    /// resolution only runs from the Apply button.
+    pub(crate) fn teardown_denoise_tool(&mut self) {
+        self.denoise.reset();
+        self.preview_image = None;
+    }
+
+    pub(crate) fn sidebar_denoise_method_changed(&mut self, method: DenoiseMethod) {
+        self.denoise.params.method = method;
+        self.update_denoise_preview();
+    }
+
+    pub(crate) fn sidebar_denoise_strength_changed(&mut self, strength: u32) {
+        self.denoise.params.strength = strength.min(100);
+        self.update_denoise_preview();
+    }
+
+    pub(crate) fn sidebar_denoise_detail_changed(&mut self, detail: u32) {
+        self.denoise.params.detail = detail.min(100);
+        self.update_denoise_preview();
+    }
+
+    /// Start denoising the working image at full resolution.
+    ///
+    /// Note: The filter runs asynchronously in the parent application, which
+    /// hands the result back through [`State::apply_denoise_result`].
+    pub(crate) fn sidebar_apply_denoise(&mut self) -> Event {
+        if self.denoise.is_processing {
+            return Event::None;
+        }
+        self.denoise.is_processing = true;
+        Event::DenoiseRequested(self.denoise.params)
+    }
+
+    /// Apply the denoised image to the editor state.
+    ///
+    /// The result is dropped if the tool was closed or the image changed
+    /// size while it was computed.
+    pub fn apply_denoise_result(&mut self, denoised: DynamicImage) {
+        if !self.denoise.is_processing
+            || (denoised.width(), denoised.height())
+                != (self.working_image.width(), self.working_image.height())
+        {
+            self.denoise.is_processing = false;
+            return;
+        }
+        let Ok(image_data) = image_transform::dynamic_to_image_data(&denoised) else {
+            self.denoise.is_processing = false;
+            return;
+        };
+
+        self.record_transformation(Transformation::Denoise {
+            result: Box::new(denoised.clone()),
+        });
+        self.working_image = denoised;
+        self.current_image = image_data;
+        self.sync_resize_state_dimensions();
+
+        // Keep the tool open on the new image
+        self.denoise.is_processing = false;
+        self.update_denoise_preview();
+    }
+
+    /// Mark the denoise operation as failed.
+    pub fn denoise_failed(&mut self) {
+        self.denoise.is_processing = false;
+    }
+
+    /// Show the current settings applied to the proxy on the canvas.
+    ///
+    /// The proxy is rebuilt once the working image has changed. The model
+    /// is too slow to run on every slider move, so it has no live preview.
+    fn update_denoise_preview(&mut self) {
+        let params = self.denoise.params;
+        if params.method == DenoiseMethod::Model || params.strength == 0 {
+            self.preview_image = None;
+            return;
+        }
+        let revision = self.history_revision;
+        if self.denoise.proxy.as_ref().map(|(r, _)| *r) != Some(revision) {
+            self.denoise.proxy = Some((revision, denoise::preview_proxy(&self.working_image)));
+        }
+        self.preview_image = self.denoise.proxy.as_ref().and_then(|(_, proxy)| {
+            image_transform::dynamic_to_image_data(&denoise::denoise(proxy, &params)).ok()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset_restores_default_settings() {
+        let mut state = DenoiseState {
+            params: DenoiseParams {
+                method: DenoiseMethod::NonLocalMeans,
+                strength: 90,
+                detail: 10,
+            },
+            is_processing: true,
+            proxy: Some((3, DynamicImage::new_rgb8(4, 4))),
+        };
+        state.reset();
+        assert_eq!(state, DenoiseState::default());
+        assert_eq!(state.params.method, DenoiseMethod::Bilateral);
+    }
+}
@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Filters tool state: one-click looks previewed before they are applied.
+
+use crate::media::image_transform;
+use crate::media::looks::{self, FilterPreset};
+use crate::ui::image_editor::{State, Transformation};
+
+/// Strength a look is selected with, in percent.
+const DEFAULT_STRENGTH: u32 = 100;
+
+/// State for the Filters tool.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterState {
+    /// Look being previewed, if any.
+    pub selected: Option<FilterPreset>,
+    /// How much of the look is blended over the image, from 0 to 100.
+    pub strength: u32,
+}
+
+impl Default for FilterState {
+    fn default() -> Self {
+        Self {
+            selected: None,
+            strength: DEFAULT_STRENGTH,
+        }
+    }
+}
+
+impl FilterState {
+    /// Returns true if a look is previewed and would change the image.
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        self.selected.is_some() && self.strength > 0
+    }
+
+    /// Reset to default state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl State {
+    /// Prepare Filters tool when selected.
+    pub(crate) fn prepare_filters_tool(&mut self) {
+        self.filters.reset();
+        self.preview_image = None;
+    }
+
+    /// Teardown Filters tool when deselected.
+    pub(crate) fn teardown_filters_tool(&mut self) {
+        self.filters.reset();
+        self.preview_image = None;
+    }
+
+    /// Preview a look, or drop the preview when it is selected again.
+    pub(crate) fn sidebar_select_filter(&mut self, preset: FilterPreset) {
+        if self.filters.selected == Some(preset) {
+            self.filters.reset();
+        } else {
+            self.filters.selected = Some(preset);
+        }
+        self.update_filter_preview();
+    }
+
+    /// Handle filter strength slider change with live preview.
+    pub(crate) fn sidebar_filter_strength_changed(&mut self, strength: u32) {
+        self.filters.strength = strength.min(100);
+        self.update_filter_preview();
+    }
+
+    /// Record the previewed look in history as a single step.
+    pub(crate) fn sidebar_apply_filter(&mut self) {
+        let Some(preset) = self.filters.selected else {
+            return;
+        };
+        if self.filters.strength == 0 {
+            return;
+        }
+        let params = preset.params();
+        let strength = self.filters.strength;
+        self.apply_dynamic_transformation(
+            Transformation::Look { params, strength },
+            move |image| looks::apply_look(image, &params, strength),
+        );
+        self.filters.reset();
+        self.preview_image = None;
+    }
+
+    /// Commit the previewed look (called when switching tools).
+    pub(crate) fn commit_filter_changes(&mut self) {
+        if self.filters.has_changes() {
+            self.sidebar_apply_filter();
+        }
+    }
+
+    /// Show the selected look over the working image on the canvas.
+    fn update_filter_preview(&mut self) {
+        self.preview_image = match self.filters.selected {
+            Some(preset) if self.filters.strength > 0 => {
+                let preview =
+                    looks::apply_look(&self.working_image, &preset.params(), self.filters.strength);
+                image_transform::dynamic_to_image_data(&preview).ok()
+            }
+            _ => None,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::image_editor::state::test_support::editor_state;
+
+    #[test]
+    fn selecting_a_look_previews_it_and_again_clears_it() {
+        let (_dir, mut state) = editor_state(4, 4);
+        state.sidebar_select_filter(FilterPreset::Sepia);
+        assert!(state.filters.has_changes());
+        assert!(state.preview_image.is_some());
+
+        state.sidebar_select_filter(FilterPreset::Sepia);
+        assert!(!state.filters.has_changes());
+        assert!(state.preview_image.is_none());
+    }
+
+    #[test]
+    fn applying_records_one_compound_step() {
+        let (_dir, mut state) = editor_state(4, 4);
+        state.sidebar_select_filter(FilterPreset::WarmFilm);
+        state.sidebar_filter_strength_changed(60);
+        state.sidebar_apply_filter();
+
+        assert_eq!(state.transformation_history.len(), 1);
+        assert!(matches!(
+            state.transformation_history[0],
+            Transformation::Look { strength: 60, .. }
+        ));
+        assert!(!state.filters.has_changes());
+        assert!(state.preview_image.is_none());
+    }
+}
@@ -79,5 +79,11 @@ impl State {
         if matches!(self.active_tool, Some(EditorTool::Adjust)) {
             self.commit_adjustment_changes();
         }
+        if matches!(self.active_tool, Some(EditorTool::Filters)) {
+            self.commit_filter_changes();
+        }
+        if matches!(self.active_tool, Some(EditorTool::Background)) {
+            self.commit_background_changes();
+        }
     }
 }
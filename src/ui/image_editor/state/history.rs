@@ -7,8 +7,9 @@
 #![allow(clippy::cast_possible_truncation)]
 #![allow(clippy::cast_sign_loss)]
 
-use crate::media::image_transform;
+use crate::media::{auto_enhance, image_transform, looks};
 use crate::ui::image_editor::{State, Transformation};
+use image_rs::DynamicImage;
 
 impl State {
     /// Returns true when the user has applied at least one transformation since load/save.
@@ -30,6 +31,7 @@ impl State {
         if self.can_undo() {
             self.history_index -= 1;
             self.replay_transformations_up_to_index();
+            self.bump_history_revision();
         }
     }
 
@@ -37,15 +39,18 @@ impl State {
         if self.can_redo() {
             self.history_index += 1;
             self.replay_transformations_up_to_index();
+            self.bump_history_revision();
         }
     }
 
     pub(crate) fn record_transformation(&mut self, transformation: Transformation) {
         if self.history_index < self.transformation_history.len() {
             self.transformation_history.truncate(self.history_index);
+            self.prune_snapshots();
         }
         self.transformation_history.push(transformation);
         self.history_index = self.transformation_history.len();
+        self.bump_history_revision();
     }
 
     pub(crate) fn replay_transformations_up_to_index(&mut self) {
@@ -58,37 +63,7 @@ impl State {
                 break;
             }
 
-            working_image = match &self.transformation_history[i] {
-                Transformation::RotateLeft => image_transform::rotate_left(&working_image),
-                Transformation::RotateRight => image_transform::rotate_right(&working_image),
-                Transformation::FlipHorizontal => image_transform::flip_horizontal(&working_image),
-                Transformation::FlipVertical => image_transform::flip_vertical(&working_image),
-                Transformation::Crop { rect } => {
-                    let x = rect.x.max(0.0) as u32;
-                    let y = rect.y.max(0.0) as u32;
-                    let width = rect.width.max(1.0) as u32;
-                    let height = rect.height.max(1.0) as u32;
-                    image_transform::crop(&working_image, x, y, width, height)
-                        .unwrap_or(working_image)
-                }
-                Transformation::Resize { width, height } => {
-                    image_transform::resize(&working_image, *width, *height)
-                }
-                Transformation::UpscaleResize { result } => {
-                    // Use the cached upscaled image (AI inference is expensive)
-                    result.as_ref().clone()
-                }
-                Transformation::AdjustBrightness { value } => {
-                    image_transform::adjust_brightness(&working_image, *value)
-                }
-                Transformation::AdjustContrast { value } => {
-                    image_transform::adjust_contrast(&working_image, *value)
-                }
-                Transformation::Deblur { result } => {
-                    // Use the cached deblurred image (AI inference is expensive)
-                    result.as_ref().clone()
-                }
-            };
+            working_image = self.transformation_history[i].apply(&working_image);
         }
 
         // Update current state with replayed image
@@ -101,28 +76,61 @@ impl State {
     }
 }
 
+impl Transformation {
+    /// Returns `image` with this step applied, as replayed from the history.
+    #[must_use]
+    pub fn apply(&self, image: &DynamicImage) -> DynamicImage {
+        match self {
+            Self::RotateLeft => image_transform::rotate_left(image),
+            Self::RotateRight => image_transform::rotate_right(image),
+            Self::FlipHorizontal => image_transform::flip_horizontal(image),
+            Self::FlipVertical => image_transform::flip_vertical(image),
+            Self::Crop { rect } => {
+                let x = rect.x.max(0.0) as u32;
+                let y = rect.y.max(0.0) as u32;
+                let width = rect.width.max(1.0) as u32;
+                let height = rect.height.max(1.0) as u32;
+                image_transform::crop(image, x, y, width, height).unwrap_or_else(|| image.clone())
+            }
+            Self::Resize { width, height } => image_transform::resize(image, *width, *height),
+            Self::UpscaleResize { result } => {
+                // Use the cached upscaled image (AI inference is expensive)
+                result.as_ref().clone()
+            }
+            Self::AdjustBrightness { value } => image_transform::adjust_brightness(image, *value),
+            Self::AdjustContrast { value } => image_transform::adjust_contrast(image, *value),
+            Self::AutoEnhance { params, strength } => auto_enhance::apply(image, params, *strength),
+            Self::ChannelMix { weights } => image_transform::channel_mix_grayscale(image, *weights),
+            Self::Sharpen {
+                amount,
+                radius,
+                threshold,
+            } => image_transform::unsharp_mask(image, *amount, *radius, *threshold),
+            Self::Look { params, strength } => looks::apply_look(image, params, *strength),
+            Self::Deblur { result } => {
+                // Use the cached deblurred image (AI inference is expensive)
+                result.as_ref().clone()
+            }
+            Self::RemoveBackground { result } => {
+                // Use the cached cut-out (it was refined by hand)
+                result.as_ref().clone()
+            }
+            Self::Denoise { result } => {
+                // Use the cached denoised image (the filters are slow at full size)
+                result.as_ref().clone()
+            }
+            Self::PluginTool { result } => {
+                // Use the cached plugin output (plugins may not be deterministic)
+                result.as_ref().clone()
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::media::ImageData;
-    use image_rs::{Rgba, RgbaImage};
-    use tempfile::TempDir;
-
-    fn create_test_image(width: u32, height: u32) -> (TempDir, std::path::PathBuf, ImageData) {
-        let temp_dir = tempfile::tempdir().expect("temp dir");
-        let path = temp_dir.path().join("history.png");
-        let rgba = RgbaImage::from_pixel(width, height, Rgba([0, 0, 0, 255]));
-        rgba.save(&path).expect("write png");
-        let pixels = vec![0; (width * height * 4) as usize];
-        let image = ImageData::from_rgba(width, height, pixels);
-        (temp_dir, path, image)
-    }
-
-    fn editor_state(width: u32, height: u32) -> (TempDir, State) {
-        let (dir, path, image) = create_test_image(width, height);
-        let state = State::new(path, &image).expect("editor state");
-        (dir, state)
-    }
+    use crate::ui::image_editor::state::test_support::editor_state;
 
     #[test]
     fn recording_after_undo_discards_redo_stack() {
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MPL-2.0
+//! History panel: every applied step, and named snapshots to jump back to.
+//!
+//! A snapshot is a position in the transformation history. Recording a new
+//! step after undoing drops the snapshots taken on the discarded branch.
+
+use crate::ui::image_editor::State;
+
+/// Named position in the transformation history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    /// Name typed when the snapshot was taken, if any.
+    pub name: Option<String>,
+    /// Number of applied steps the snapshot restores.
+    pub index: usize,
+}
+
+/// History panel state.
+#[derive(Debug, Clone, Default)]
+pub struct HistoryPanelState {
+    /// Whether the panel is expanded in the sidebar.
+    pub expanded: bool,
+    /// Snapshots, oldest first.
+    pub snapshots: Vec<Snapshot>,
+    /// Name typed for the next snapshot.
+    pub name_input: String,
+}
+
+impl State {
+    pub(crate) fn sidebar_toggle_history_panel(&mut self) {
+        self.history_panel.expanded = !self.history_panel.expanded;
+    }
+
+    pub(crate) fn sidebar_snapshot_name_changed(&mut self, name: String) {
+        self.history_panel.name_input = name;
+    }
+
+    /// Name the current position in the history.
+    pub(crate) fn sidebar_save_snapshot(&mut self) {
+        let name = self.history_panel.name_input.trim();
+        let snapshot = Snapshot {
+            name: (!name.is_empty()).then(|| name.to_string()),
+            index: self.history_index,
+        };
+        self.history_panel.name_input.clear();
+        self.history_panel.snapshots.push(snapshot);
+    }
+
+    pub(crate) fn sidebar_delete_snapshot(&mut self, position: usize) {
+        if position < self.history_panel.snapshots.len() {
+            self.history_panel.snapshots.remove(position);
+        }
+    }
+
+    /// Revert to the state after the first `index` steps, keeping the later
+    /// ones available to redo.
+    pub(crate) fn sidebar_jump_to_history(&mut self, index: usize) {
+        let index = index.min(self.transformation_history.len());
+        if index != self.history_index {
+            self.history_index = index;
+            self.replay_transformations_up_to_index();
+            self.bump_history_revision();
+        }
+    }
+
+    /// Drop the snapshots pointing past the current step, whose steps are
+    /// about to be replaced.
+    pub(crate) fn prune_snapshots(&mut self) {
+        let index = self.history_index;
+        self.history_panel
+            .snapshots
+            .retain(|snapshot| snapshot.index <= index);
+    }
+
+    /// Returns the number of steps currently applied.
+    #[must_use]
+    pub fn history_position(&self) -> usize {
+        self.history_index
+    }
+}
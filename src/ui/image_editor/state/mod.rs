@@ -2,17 +2,33 @@
 //! Shared editor sub-state modules (crop, resize, adjustment, deblur, ...).
 
 pub mod adjustment;
+pub mod background;
+pub mod canvas_zoom;
+pub mod compare;
 pub mod crop;
 pub mod deblur;
+pub mod denoise;
+pub mod filters;
 mod helpers;
 pub mod history;
+pub mod history_panel;
 pub mod persistence;
+pub mod plugin;
+pub mod recovery;
 pub mod resize;
 pub mod routing;
 pub mod session;
+#[cfg(test)]
+pub(crate) mod test_support;
 pub mod tools;
 
-pub use adjustment::AdjustmentState;
+pub use adjustment::{AdjustmentRecipe, AdjustmentState, GrayscalePreset};
+pub use background::{BackgroundState, BrushMode};
+pub use compare::CompareState;
 pub use crop::{CropDragState, CropOverlay, CropRatio, CropState, HandlePosition};
 pub use deblur::DeblurState;
+pub use denoise::DenoiseState;
+pub use filters::FilterState;
+pub use history_panel::{HistoryPanelState, Snapshot};
+pub use recovery::RecoveredEdits;
 pub use resize::{ResizeOverlay, ResizeState};
@@ -9,16 +9,19 @@
 
 use super::{CropDragState, CropRatio};
 use crate::error::{Error, Result};
-use crate::media::image_transform;
+use crate::media::{color_proof, image_transform};
 use crate::ui::image_editor::{ImageSource, State};
 
 impl State {
     /// Save the edited image to a file, preserving the original format.
     ///
+    /// When a proof profile is selected, it is embedded in the file and the
+    /// pixels are converted into it if requested.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the image format is unsupported or the file
-    /// cannot be written.
+    /// Returns an error if the image format is unsupported (or can't carry
+    /// the proof profile) or the file cannot be written.
     pub fn save_image(&mut self, path: &std::path::Path) -> Result<()> {
         use image_rs::ImageFormat;
 
@@ -36,14 +39,25 @@ impl State {
             _ => ImageFormat::Png, // Default fallback
         };
 
+        // JPEG has no transparency: put cut-outs on white rather than black
+        let flattened = (format == ImageFormat::Jpeg && self.working_image.color().has_alpha())
+            .then(|| image_transform::flatten_alpha(&self.working_image, [255, 255, 255]));
+        let image = flattened.as_ref().unwrap_or(&self.working_image);
+
         // Save the working image
-        self.working_image
-            .save_with_format(path, format)
-            .map_err(|err| Error::Io(format!("Failed to save image: {err}")))?;
+        if let Some(profile) = self.proof.profile {
+            color_proof::save_proof(image, path, format, profile, self.proof.convert_pixels)?;
+        } else {
+            image
+                .save_with_format(path, format)
+                .map_err(|err| Error::Io(format!("Failed to save image: {err}")))?;
+        }
 
         // Clear transformation history after successful save
         self.transformation_history.clear();
         self.history_index = 0;
+        self.history_panel.snapshots.clear();
+        self.bump_history_revision();
 
         Ok(())
     }
@@ -58,6 +72,7 @@ impl State {
                 // Just clear the transformation history.
                 self.transformation_history.clear();
                 self.history_index = 0;
+                self.history_panel.snapshots.clear();
                 self.preview_image = None;
                 return;
             }
@@ -97,6 +112,8 @@ impl State {
         // Clear transformation history
         self.transformation_history.clear();
         self.history_index = 0;
+        self.history_panel.snapshots.clear();
+        self.bump_history_revision();
 
         // Clear preview but keep tool panel open
         self.preview_image = None;
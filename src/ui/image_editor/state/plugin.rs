@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Editor tools provided by plugins.
+//!
+//! The tool itself runs in the parent application; the editor only records
+//! the image it produced, like the AI deblur result.
+
+use crate::media::image_transform;
+use crate::ui::image_editor::{State, Transformation};
+use image_rs::DynamicImage;
+
+impl State {
+    /// Returns true while a plugin editor tool is running.
+    #[must_use]
+    pub fn is_plugin_running(&self) -> bool {
+        self.plugin_running
+    }
+
+    /// Apply the image written by a plugin editor tool.
+    ///
+    /// The result is cached in history since plugins may not be deterministic.
+    /// Plugins may change the image size, so crop and resize are resynced.
+    pub fn apply_plugin_tool_result(&mut self, image: DynamicImage) {
+        self.plugin_running = false;
+        let Ok(image_data) = image_transform::dynamic_to_image_data(&image) else {
+            return;
+        };
+
+        self.record_transformation(Transformation::PluginTool {
+            result: Box::new(image.clone()),
+        });
+        self.working_image = image;
+        self.current_image = image_data;
+        self.preview_image = None;
+        self.sync_resize_state_dimensions();
+        self.sync_crop_state_dimensions();
+    }
+
+    /// Mark the plugin editor tool as finished without a result.
+    pub fn plugin_tool_failed(&mut self) {
+        self.plugin_running = false;
+    }
+}
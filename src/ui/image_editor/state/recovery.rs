@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Restoring unsaved edits journaled by a previous session.
+//!
+//! The application journals the applied history whenever its revision
+//! changes (see [`crate::app::edit_journal`]). When a journal is found for a
+//! newly opened image, the edits are offered to the user, who restores or
+//! discards them from the toolbar.
+
+use crate::ui::image_editor::{EditorTool, State, Transformation};
+
+/// Edits read back from a journal.
+#[derive(Debug, Clone)]
+pub struct RecoveredEdits {
+    /// Transformations applied to the image, in order.
+    pub transformations: Vec<Transformation>,
+    /// Pending brightness slider value.
+    pub brightness: i32,
+    /// Pending contrast slider value.
+    pub contrast: i32,
+}
+
+impl State {
+    /// Returns a counter bumped whenever the applied history changes, so the
+    /// journal is only rewritten when needed.
+    #[must_use]
+    pub fn history_revision(&self) -> u64 {
+        self.history_revision
+    }
+
+    /// Returns the transformations applied up to the current undo position.
+    #[must_use]
+    pub fn applied_transformations(&self) -> &[Transformation] {
+        &self.transformation_history[..self.history_index]
+    }
+
+    /// Returns the brightness and contrast slider values not applied yet.
+    #[must_use]
+    pub fn pending_adjustment(&self) -> (i32, i32) {
+        (
+            self.adjustment.brightness.value(),
+            self.adjustment.contrast.value(),
+        )
+    }
+
+    /// Offers to restore edits left over by a previous session.
+    pub fn offer_recovery(&mut self, edits: RecoveredEdits) {
+        self.recovery = Some(edits);
+    }
+
+    /// Returns true while recovered edits wait for the user's decision.
+    #[must_use]
+    pub fn has_recovery_offer(&self) -> bool {
+        self.recovery.is_some()
+    }
+
+    pub(crate) fn bump_history_revision(&mut self) {
+        self.history_revision += 1;
+    }
+
+    /// Replaces the history with the offered edits and replays them.
+    pub(crate) fn toolbar_restore_edits(&mut self) {
+        let Some(edits) = self.recovery.take() else {
+            return;
+        };
+        self.history_index = edits.transformations.len();
+        self.transformation_history = edits.transformations;
+        self.history_panel.snapshots.clear();
+        self.replay_transformations_up_to_index();
+
+        if edits.brightness != 0 || edits.contrast != 0 {
+            self.active_tool = Some(EditorTool::Adjust);
+            self.sidebar_brightness_changed(edits.brightness);
+            self.sidebar_contrast_changed(edits.contrast);
+        }
+        self.bump_history_revision();
+    }
+
+    /// Drops the offered edits; the journal is deleted on the next sync.
+    pub(crate) fn toolbar_discard_recovered_edits(&mut self) {
+        if self.recovery.take().is_some() {
+            self.bump_history_revision();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::image_editor::state::test_support::editor_state;
+
+    #[test]
+    fn restoring_replays_recovered_edits() {
+        let (_dir, mut state) = editor_state(4, 2);
+        state.offer_recovery(RecoveredEdits {
+            transformations: vec![Transformation::RotateLeft],
+            brightness: 30,
+            contrast: 0,
+        });
+        assert!(state.has_recovery_offer());
+        let revision = state.history_revision();
+
+        state.toolbar_restore_edits();
+
+        assert!(!state.has_recovery_offer());
+        assert!(state.history_revision() > revision);
+        assert_eq!(state.applied_transformations().len(), 1);
+        assert_eq!(state.current_image.width, 2);
+        assert_eq!(state.pending_adjustment(), (30, 0));
+        assert_eq!(state.active_tool, Some(EditorTool::Adjust));
+    }
+
+    #[test]
+    fn discarding_keeps_the_original_image() {
+        let (_dir, mut state) = editor_state(4, 2);
+        state.offer_recovery(RecoveredEdits {
+            transformations: vec![Transformation::RotateLeft],
+            brightness: 0,
+            contrast: 0,
+        });
+        let revision = state.history_revision();
+
+        state.toolbar_discard_recovered_edits();
+
+        assert!(!state.has_recovery_offer());
+        assert!(state.history_revision() > revision);
+        assert!(state.applied_transformations().is_empty());
+        assert_eq!(state.current_image.width, 4);
+    }
+}
@@ -5,6 +5,7 @@
 use crate::ui::image_editor::{
     CanvasMessage, EditorTool, Event, ImageSource, SidebarMessage, State, ToolbarMessage,
 };
+use crate::ui::keyboard_layout;
 use iced::widget::scrollable::AbsoluteOffset;
 use iced::{self, keyboard, mouse, Point};
 
@@ -12,6 +13,34 @@ impl State {
     pub(crate) fn handle_toolbar_message(&mut self, message: &ToolbarMessage) -> Event {
         match message {
             ToolbarMessage::BackToViewer => self.toolbar_back_to_viewer(),
+            ToolbarMessage::RestoreEdits => {
+                self.toolbar_restore_edits();
+                Event::None
+            }
+            ToolbarMessage::DiscardRecoveredEdits => {
+                self.toolbar_discard_recovered_edits();
+                Event::None
+            }
+            ToolbarMessage::ToggleGuides => {
+                self.guides_visible = !self.guides_visible;
+                Event::None
+            }
+            ToolbarMessage::ToggleFitZoom => {
+                self.toggle_fit_zoom();
+                Event::None
+            }
+            ToolbarMessage::ShowOriginal(held) => {
+                self.set_show_original(*held);
+                Event::None
+            }
+            ToolbarMessage::ToggleSplitCompare => {
+                self.toggle_split_compare();
+                Event::None
+            }
+            ToolbarMessage::CompareSplitChanged(percent) => {
+                self.set_compare_split(*percent);
+                Event::None
+            }
         }
     }
 
@@ -37,7 +66,10 @@ impl State {
                             self.hide_resize_overlay();
                         }
                         EditorTool::Adjust => self.teardown_adjustment_tool(),
+                        EditorTool::Filters => self.teardown_filters_tool(),
                         EditorTool::Deblur => self.teardown_deblur_tool(),
+                        EditorTool::Background => self.teardown_background_tool(),
+                        EditorTool::Denoise => self.teardown_denoise_tool(),
                         EditorTool::Rotate => {}
                     }
                 } else {
@@ -53,16 +85,28 @@ impl State {
                     if self.active_tool == Some(EditorTool::Adjust) {
                         self.teardown_adjustment_tool();
                     }
+                    if self.active_tool == Some(EditorTool::Filters) {
+                        self.teardown_filters_tool();
+                    }
                     if self.active_tool == Some(EditorTool::Deblur) {
                         self.teardown_deblur_tool();
                     }
+                    if self.active_tool == Some(EditorTool::Background) {
+                        self.teardown_background_tool();
+                    }
+                    if self.active_tool == Some(EditorTool::Denoise) {
+                        self.teardown_denoise_tool();
+                    }
                     self.active_tool = Some(tool);
                     self.preview_image = None;
 
                     match tool {
                         EditorTool::Crop => self.prepare_crop_tool(),
                         EditorTool::Adjust => self.prepare_adjustment_tool(),
+                        EditorTool::Filters => self.prepare_filters_tool(),
                         EditorTool::Deblur => self.prepare_deblur_tool(),
+                        EditorTool::Background => self.prepare_background_tool(),
+                        EditorTool::Denoise => self.prepare_denoise_tool(),
                         // Resize and Rotate have no overlay - preview shows directly on canvas
                         EditorTool::Resize | EditorTool::Rotate => {}
                     }
@@ -93,6 +137,7 @@ impl State {
                 self.set_crop_ratio_from_sidebar(ratio);
                 Event::None
             }
+            SidebarMessage::CropToFace => self.crop_to_next_face(),
             SidebarMessage::ApplyCrop => {
                 self.apply_crop_from_sidebar();
                 Event::None
@@ -165,6 +210,46 @@ impl State {
                 self.sidebar_contrast_changed(value);
                 Event::None
             }
+            SidebarMessage::ToggleAutoEnhance => {
+                self.sidebar_toggle_auto_enhance();
+                Event::None
+            }
+            SidebarMessage::AutoEnhanceStrengthChanged(strength) => {
+                self.sidebar_auto_enhance_strength_changed(strength);
+                Event::None
+            }
+            SidebarMessage::GrayscaleToggled(enabled) => {
+                self.sidebar_grayscale_toggled(enabled);
+                Event::None
+            }
+            SidebarMessage::GrayscalePresetSelected(preset) => {
+                self.sidebar_grayscale_preset_selected(preset);
+                Event::None
+            }
+            SidebarMessage::MixerRedChanged(weight) => {
+                self.sidebar_mixer_weight_changed(0, weight);
+                Event::None
+            }
+            SidebarMessage::MixerGreenChanged(weight) => {
+                self.sidebar_mixer_weight_changed(1, weight);
+                Event::None
+            }
+            SidebarMessage::MixerBlueChanged(weight) => {
+                self.sidebar_mixer_weight_changed(2, weight);
+                Event::None
+            }
+            SidebarMessage::SharpenAmountChanged(amount) => {
+                self.sidebar_sharpen_amount_changed(amount);
+                Event::None
+            }
+            SidebarMessage::SharpenRadiusChanged(radius) => {
+                self.sidebar_sharpen_radius_changed(radius);
+                Event::None
+            }
+            SidebarMessage::SharpenThresholdChanged(threshold) => {
+                self.sidebar_sharpen_threshold_changed(threshold);
+                Event::None
+            }
             SidebarMessage::ApplyAdjustments => {
                 self.sidebar_apply_adjustments();
                 Event::None
@@ -173,6 +258,9 @@ impl State {
                 self.sidebar_reset_adjustments();
                 Event::None
             }
+            SidebarMessage::CopyAdjustments => Event::AdjustmentsCopied(self.copy_adjustments()),
+            SidebarMessage::PasteAdjustments => Event::PasteAdjustmentsRequested,
+            SidebarMessage::PasteAdjustmentsToFolder => Event::PasteAdjustmentsToFolderRequested,
             SidebarMessage::ApplyDeblur => {
                 self.sidebar_apply_deblur();
                 Event::DeblurRequested
@@ -181,6 +269,62 @@ impl State {
                 self.sidebar_cancel_deblur();
                 Event::DeblurCancelRequested
             }
+            SidebarMessage::RemoveBackground => {
+                if self.background.is_processing {
+                    return Event::None;
+                }
+                self.sidebar_remove_background();
+                Event::BackgroundRemovalRequested
+            }
+            SidebarMessage::SetBrushMode(mode) => {
+                self.background.brush_mode = mode;
+                Event::None
+            }
+            SidebarMessage::BrushRadiusChanged(radius) => {
+                self.background.brush_radius = radius;
+                Event::None
+            }
+            SidebarMessage::ApplyBackgroundRemoval => {
+                self.sidebar_apply_background_removal();
+                Event::None
+            }
+            SidebarMessage::SelectFilter(preset) => {
+                self.sidebar_select_filter(preset);
+                Event::None
+            }
+            SidebarMessage::FilterStrengthChanged(strength) => {
+                self.sidebar_filter_strength_changed(strength);
+                Event::None
+            }
+            SidebarMessage::ApplyFilter => {
+                self.sidebar_apply_filter();
+                Event::None
+            }
+            SidebarMessage::SetDenoiseMethod(method) => {
+                self.sidebar_denoise_method_changed(method);
+                Event::None
+            }
+            SidebarMessage::DenoiseStrengthChanged(strength) => {
+                self.sidebar_denoise_strength_changed(strength);
+                Event::None
+            }
+            SidebarMessage::DenoiseDetailChanged(detail) => {
+                self.sidebar_denoise_detail_changed(detail);
+                Event::None
+            }
+            SidebarMessage::ApplyDenoise => self.sidebar_apply_denoise(),
+            SidebarMessage::RunPluginTool(index) => {
+                if self.plugin_running {
+                    return Event::None;
+                }
+                self.commit_active_tool_changes();
+                self.plugin_running = true;
+                Event::PluginToolRequested(index)
+            }
+            SidebarMessage::ExportWithPlugin(index) => {
+                self.commit_active_tool_changes();
+                Event::PluginExportRequested(index)
+            }
             SidebarMessage::Undo => {
                 self.commit_active_tool_changes();
                 self.sidebar_undo();
@@ -191,6 +335,28 @@ impl State {
                 self.sidebar_redo();
                 Event::None
             }
+            SidebarMessage::ToggleHistoryPanel => {
+                self.sidebar_toggle_history_panel();
+                Event::None
+            }
+            SidebarMessage::JumpToHistory(index) => {
+                self.commit_active_tool_changes();
+                self.sidebar_jump_to_history(index);
+                Event::None
+            }
+            SidebarMessage::SnapshotNameChanged(name) => {
+                self.sidebar_snapshot_name_changed(name);
+                Event::None
+            }
+            SidebarMessage::SaveSnapshot => {
+                self.commit_active_tool_changes();
+                self.sidebar_save_snapshot();
+                Event::None
+            }
+            SidebarMessage::DeleteSnapshot(position) => {
+                self.sidebar_delete_snapshot(position);
+                Event::None
+            }
             SidebarMessage::NavigateNext => self.sidebar_navigate_next(),
             SidebarMessage::NavigatePrevious => self.sidebar_navigate_previous(),
             SidebarMessage::Save => self.sidebar_save(),
@@ -200,6 +366,14 @@ impl State {
                 self.set_export_format(format);
                 Event::None
             }
+            SidebarMessage::SetProofProfile(profile) => {
+                self.proof.profile = profile;
+                Event::None
+            }
+            SidebarMessage::ToggleProofConversion => {
+                self.proof.convert_pixels = !self.proof.convert_pixels;
+                Event::None
+            }
         }
     }
 
@@ -215,6 +389,14 @@ impl State {
                 self.cursor_over_canvas = false;
                 Event::None
             }
+            CanvasMessage::BrushStroke { x, y } => {
+                self.paint_background_mask(*x, *y);
+                Event::None
+            }
+            CanvasMessage::BrushStrokeEnded => {
+                self.background.end_stroke();
+                Event::None
+            }
             _ => self.handle_crop_canvas_message(message),
         }
     }
@@ -232,11 +414,15 @@ impl State {
                     Event::ExitEditor
                 }
             }
-            iced::Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
-                if modifiers.command() =>
-            {
-                match key {
-                    keyboard::Key::Character(ref c) if c.as_str() == "s" => {
+            iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modified_key,
+                physical_key,
+                modifiers,
+                ..
+            }) if modifiers.command() => {
+                match keyboard_layout::shortcut_char(&key, &modified_key, physical_key) {
+                    Some('s') => {
                         // Ctrl+S only works for file mode, not captured frames
                         if let ImageSource::File(path) = &self.image_source {
                             if self.has_unsaved_changes() {
@@ -248,12 +434,12 @@ impl State {
                         }
                         Event::None
                     }
-                    keyboard::Key::Character(ref c) if c.as_str() == "z" => {
+                    Some('z') => {
                         self.commit_active_tool_changes();
                         self.sidebar_undo();
                         Event::None
                     }
-                    keyboard::Key::Character(ref c) if c.as_str() == "y" => {
+                    Some('y') => {
                         self.commit_active_tool_changes();
                         self.sidebar_redo();
                         Event::None
@@ -261,6 +447,35 @@ impl State {
                     _ => Event::None,
                 }
             }
+            iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Space),
+                ..
+            }) => {
+                self.space_pan = true;
+                Event::None
+            }
+            iced::Event::Keyboard(keyboard::Event::KeyReleased {
+                key: keyboard::Key::Named(keyboard::key::Named::Space),
+                ..
+            }) => {
+                self.space_pan = false;
+                Event::None
+            }
+            // Backslash, or the key in its place, shows the original while held
+            iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                key, physical_key, ..
+            }) if is_compare_key(&key, physical_key) => {
+                if !self.compare.holding {
+                    self.set_show_original(true);
+                }
+                Event::None
+            }
+            iced::Event::Keyboard(keyboard::Event::KeyReleased {
+                key, physical_key, ..
+            }) if is_compare_key(&key, physical_key) => {
+                self.set_show_original(false);
+                Event::None
+            }
             iced::Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
                 self.handle_wheel_zoom(delta);
                 Event::None
@@ -303,7 +518,8 @@ impl State {
             return;
         }
 
-        let new_zoom = self.zoom.zoom_percent + steps * self.zoom.zoom_step.value();
+        // Start from the fitted zoom when leaving fit mode
+        let new_zoom = self.canvas_zoom_percent() + steps * self.zoom.zoom_step.value();
         self.zoom.apply_manual_zoom(new_zoom);
     }
 
@@ -337,12 +553,23 @@ impl State {
             return;
         }
 
+        // Holding Space pans over the overlays, to reach handles at high zoom
+        if self.space_pan {
+            self.drag.start(position, self.viewport.offset);
+            return;
+        }
+
         // Don't start pan if crop tool is active and interacting with overlay
         if self.active_tool == Some(EditorTool::Crop) && self.crop.overlay.visible {
             // Crop overlay handles its own mouse events
             return;
         }
 
+        // Don't start pan while the refine brush paints the cut-out
+        if self.active_tool == Some(EditorTool::Background) && self.background.is_refining() {
+            return;
+        }
+
         // Start drag for panning
         self.drag.start(position, self.viewport.offset);
     }
@@ -364,7 +591,7 @@ impl State {
             return Event::None;
         };
 
-        let zoom_scale = self.zoom.zoom_percent / 100.0;
+        let zoom_scale = crate::ui::state::zoom::media_scale(self.canvas_zoom_percent());
         let scaled_width = self.current_image.width as f32 * zoom_scale;
         let scaled_height = self.current_image.height as f32 * zoom_scale;
 
@@ -411,6 +638,17 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.drag.is_dragging
     }
+
+    /// Check if Space is held to pan over the overlays.
+    pub fn is_space_panning(&self) -> bool {
+        self.space_pan
+    }
+}
+
+/// Returns whether a key event is the backslash showing the original image.
+fn is_compare_key(key: &keyboard::Key, physical_key: keyboard::key::Physical) -> bool {
+    matches!(key, keyboard::Key::Character(c) if c.as_str() == "\\")
+        || physical_key == keyboard::key::Physical::Code(keyboard::key::Code::Backslash)
 }
 
 /// Normalizes mouse wheel units (lines vs. pixels) into abstract step values
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Fixtures shared by the editor state tests.
+
+use crate::media::ImageData;
+use crate::ui::image_editor::State;
+use image_rs::{Rgba, RgbaImage};
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+/// Writes a black `width`×`height` PNG and returns it with matching image data.
+///
+/// The returned `TempDir` must be kept alive for as long as the file is used.
+pub(crate) fn create_test_image(width: u32, height: u32) -> (TempDir, PathBuf, ImageData) {
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let path = temp_dir.path().join("test.png");
+    let rgba = RgbaImage::from_pixel(width, height, Rgba([0, 0, 0, 255]));
+    rgba.save(&path).expect("write png");
+    let pixels = vec![0; (width * height * 4) as usize];
+    let image = ImageData::from_rgba(width, height, pixels);
+    (temp_dir, path, image)
+}
+
+/// Opens an editor on a black `width`×`height` image.
+pub(crate) fn editor_state(width: u32, height: u32) -> (TempDir, State) {
+    let (dir, path, image) = create_test_image(width, height);
+    let state = State::new(path, &image).expect("editor state");
+    (dir, state)
+}
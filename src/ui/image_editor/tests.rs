@@ -1,18 +1,8 @@
 // SPDX-License-Identifier: MPL-2.0
 
+use super::state::test_support::create_test_image;
 use super::*;
 use image_rs::{Rgba, RgbaImage};
-use tempfile::tempdir;
-
-fn create_test_image(width: u32, height: u32) -> (tempfile::TempDir, PathBuf, ImageData) {
-    let temp_dir = tempdir().expect("temp dir");
-    let path = temp_dir.path().join("test.png");
-    let img = RgbaImage::from_pixel(width, height, Rgba([0, 0, 0, 255]));
-    img.save(&path).expect("write png");
-    let pixels = vec![0; (width * height * 4) as usize];
-    let image = ImageData::from_rgba(width, height, pixels);
-    (temp_dir, path, image)
-}
 
 #[test]
 fn new_editor_state_has_no_changes() {
@@ -231,6 +221,56 @@ fn resize_preview_works_with_tool_selected() {
     assert_eq!(preview.width, 75, "Preview should reflect the new scale");
 }
 
+#[test]
+fn crop_to_face_detects_then_frames_faces_in_turn() {
+    use crate::media::face_detection::FaceBox;
+
+    let (_dir, path, img) = create_test_image(400, 300);
+    let mut state = State::new(path, &img).expect("editor state");
+    state.update(Message::Sidebar(SidebarMessage::SelectTool(
+        EditorTool::Crop,
+    )));
+
+    assert!(matches!(
+        state.update(Message::Sidebar(SidebarMessage::CropToFace)),
+        Event::FaceDetectionRequested
+    ));
+    let face = |x: f32| FaceBox {
+        x,
+        y: 100.0,
+        width: 40.0,
+        height: 40.0,
+        score: 0.9,
+    };
+    state.apply_face_detection_result(Ok(vec![face(20.0), face(300.0)]));
+
+    // 40px face with a 30px margin on each side, kept inside the image
+    assert!(state.crop.overlay.visible);
+    assert_eq!(
+        (
+            state.crop.x,
+            state.crop.y,
+            state.crop.width,
+            state.crop.height
+        ),
+        (0, 70, 100, 100)
+    );
+
+    state.update(Message::Sidebar(SidebarMessage::SetCropRatio(
+        CropRatio::Landscape,
+    )));
+    state.update(Message::Sidebar(SidebarMessage::CropToFace));
+    assert_eq!(
+        (
+            state.crop.x,
+            state.crop.y,
+            state.crop.width,
+            state.crop.height
+        ),
+        (223, 70, 177, 100)
+    );
+}
+
 #[test]
 fn crop_handle_detection_with_extended_hit_area() {
     let (_dir, path, img) = create_test_image(200, 200);
@@ -585,3 +625,205 @@ fn flip_combined_with_rotate() {
     assert_eq!(state.current_image.width, 8);
     assert_eq!(state.current_image.height, 6);
 }
+
+#[test]
+fn proof_profile_is_embedded_on_save() {
+    use crate::media::color_proof::ProofProfile;
+
+    let (dir, path, img) = create_test_image(4, 3);
+    let mut state = State::new(path, &img).expect("editor state");
+
+    state.update(Message::Sidebar(SidebarMessage::SetProofProfile(Some(
+        ProofProfile::DisplayP3,
+    ))));
+    state.update(Message::Sidebar(SidebarMessage::ToggleProofConversion));
+    assert_eq!(state.proof_options().profile, Some(ProofProfile::DisplayP3));
+    assert!(state.proof_options().convert_pixels);
+
+    let output = dir.path().join("proof.png");
+    state.save_image(&output).expect("save proof");
+    let bytes = std::fs::read(&output).expect("read proof");
+    assert!(bytes.windows(4).any(|chunk| chunk == b"iCCP"));
+}
+
+#[test]
+fn plugin_tool_result_is_recorded_and_undoable() {
+    let (_dir, path, img) = create_test_image(8, 6);
+    let mut state = State::new(path, &img).expect("editor state");
+
+    let event = state.update(Message::Sidebar(SidebarMessage::RunPluginTool(0)));
+    assert!(matches!(event, Event::PluginToolRequested(0)));
+    assert!(state.is_plugin_running());
+    // A second click while the tool runs is ignored
+    let event = state.update(Message::Sidebar(SidebarMessage::RunPluginTool(0)));
+    assert!(matches!(event, Event::None));
+
+    let output = DynamicImage::ImageRgba8(RgbaImage::from_pixel(4, 4, Rgba([9, 9, 9, 255])));
+    state.apply_plugin_tool_result(output);
+    assert!(!state.is_plugin_running());
+    assert!(state.can_undo());
+    assert_eq!(
+        (state.current_image.width, state.current_image.height),
+        (4, 4)
+    );
+    assert_eq!((state.crop.width, state.crop.height), (4, 4));
+
+    state.update(Message::Sidebar(SidebarMessage::Undo));
+    assert_eq!(
+        (state.current_image.width, state.current_image.height),
+        (8, 6)
+    );
+    state.update(Message::Sidebar(SidebarMessage::Redo));
+    assert_eq!(
+        (state.current_image.width, state.current_image.height),
+        (4, 4)
+    );
+}
+
+#[test]
+fn canvas_opens_fitted_and_toggles_to_actual_size() {
+    let (_dir, path, img) = create_test_image(400, 200);
+    let mut state = State::new(path, &img).expect("editor state");
+    state.update(Message::ViewportChanged {
+        bounds: iced::Rectangle::new(iced::Point::ORIGIN, iced::Size::new(200.0, 200.0)),
+        offset: iced::widget::scrollable::AbsoluteOffset { x: 0.0, y: 0.0 },
+    });
+    assert!(state.zoom.fit_to_window);
+    assert!((state.canvas_zoom_percent() - 50.0).abs() < f32::EPSILON);
+
+    state.update(Message::Toolbar(ToolbarMessage::ToggleFitZoom));
+    assert!(!state.zoom.fit_to_window);
+    assert!((state.canvas_zoom_percent() - 100.0).abs() < f32::EPSILON);
+
+    state.update(Message::Toolbar(ToolbarMessage::ToggleFitZoom));
+    assert!((state.canvas_zoom_percent() - 50.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn space_held_pans_over_the_crop_overlay() {
+    let (_dir, path, img) = create_test_image(400, 200);
+    let mut state = State::new(path, &img).expect("editor state");
+    state.update(Message::Sidebar(SidebarMessage::SelectTool(
+        EditorTool::Crop,
+    )));
+    state.update(Message::ViewportChanged {
+        bounds: iced::Rectangle::new(iced::Point::ORIGIN, iced::Size::new(200.0, 200.0)),
+        offset: iced::widget::scrollable::AbsoluteOffset { x: 0.0, y: 0.0 },
+    });
+    let press = iced::Event::Mouse(iced::mouse::Event::ButtonPressed(iced::mouse::Button::Left));
+    state.update(Message::Canvas(CanvasMessage::CursorMoved {
+        position: iced::Point::new(50.0, 50.0),
+    }));
+
+    state.handle_raw_event(press.clone());
+    assert!(!state.is_dragging(), "the crop overlay keeps the press");
+    state.handle_raw_event(iced::Event::Mouse(iced::mouse::Event::ButtonReleased(
+        iced::mouse::Button::Left,
+    )));
+
+    state.handle_raw_event(iced::Event::Keyboard(iced::keyboard::Event::KeyPressed {
+        key: iced::keyboard::Key::Named(iced::keyboard::key::Named::Space),
+        modified_key: iced::keyboard::Key::Named(iced::keyboard::key::Named::Space),
+        physical_key: iced::keyboard::key::Physical::Code(iced::keyboard::key::Code::Space),
+        location: iced::keyboard::Location::Standard,
+        modifiers: iced::keyboard::Modifiers::empty(),
+        text: None,
+        repeat: false,
+    }));
+    state.handle_raw_event(press);
+    assert!(state.is_dragging());
+}
+
+#[test]
+fn holding_show_original_displays_the_source_until_released() {
+    let (_dir, path, img) = create_test_image(4, 4);
+    let mut state = State::new(path, &img).expect("editor state");
+    state.update(Message::Sidebar(SidebarMessage::RotateLeft));
+
+    state.update(Message::Toolbar(ToolbarMessage::ShowOriginal(true)));
+    assert!(state.is_showing_original());
+    assert!(state.compare_original().is_some());
+
+    state.update(Message::Toolbar(ToolbarMessage::ShowOriginal(false)));
+    assert!(!state.is_showing_original());
+    assert!(state.compare_original().is_none());
+
+    let backslash = iced::keyboard::Key::Character("\\".into());
+    state.handle_raw_event(iced::Event::Keyboard(iced::keyboard::Event::KeyPressed {
+        key: backslash.clone(),
+        modified_key: backslash.clone(),
+        physical_key: iced::keyboard::key::Physical::Code(iced::keyboard::key::Code::Backslash),
+        location: iced::keyboard::Location::Standard,
+        modifiers: iced::keyboard::Modifiers::empty(),
+        text: None,
+        repeat: false,
+    }));
+    assert!(state.is_showing_original());
+    state.handle_raw_event(iced::Event::Keyboard(iced::keyboard::Event::KeyReleased {
+        key: backslash.clone(),
+        modified_key: backslash,
+        physical_key: iced::keyboard::key::Physical::Code(iced::keyboard::key::Code::Backslash),
+        location: iced::keyboard::Location::Standard,
+        modifiers: iced::keyboard::Modifiers::empty(),
+    }));
+    assert!(!state.is_showing_original());
+}
+
+#[test]
+fn split_compare_keeps_the_divider_within_the_image() {
+    let (_dir, path, img) = create_test_image(4, 4);
+    let mut state = State::new(path, &img).expect("editor state");
+
+    state.update(Message::Toolbar(ToolbarMessage::ToggleSplitCompare));
+    assert_eq!(state.compare_split(), Some(50));
+    assert!(state.compare_original().is_some());
+
+    state.update(Message::Toolbar(ToolbarMessage::CompareSplitChanged(150)));
+    assert_eq!(state.compare_split(), Some(100));
+
+    state.update(Message::Toolbar(ToolbarMessage::ToggleSplitCompare));
+    assert_eq!(state.compare_split(), None);
+    assert!(state.compare_original().is_none());
+}
+
+#[test]
+fn history_jumps_keep_later_steps_and_snapshots_follow_the_branch() {
+    let (_dir, path, img) = create_test_image(4, 2);
+    let mut state = State::new(path, &img).expect("editor state");
+    state.update(Message::Sidebar(SidebarMessage::RotateLeft));
+    state.update(Message::Sidebar(SidebarMessage::SnapshotNameChanged(
+        " after rotate ".into(),
+    )));
+    state.update(Message::Sidebar(SidebarMessage::SaveSnapshot));
+    state.update(Message::Sidebar(SidebarMessage::FlipHorizontal));
+
+    state.update(Message::Sidebar(SidebarMessage::JumpToHistory(0)));
+    assert_eq!(state.history_position(), 0);
+    assert_eq!(
+        (state.current_image.width, state.current_image.height),
+        (4, 2)
+    );
+    assert!(state.can_redo());
+
+    let snapshot = state.history_panel.snapshots[0].clone();
+    assert_eq!(snapshot.name.as_deref(), Some("after rotate"));
+    state.update(Message::Sidebar(SidebarMessage::JumpToHistory(
+        snapshot.index,
+    )));
+    assert_eq!(state.history_position(), 1);
+    assert_eq!(
+        (state.current_image.width, state.current_image.height),
+        (2, 4)
+    );
+
+    // A new edit from the original drops the snapshot of the replaced branch
+    state.update(Message::Sidebar(SidebarMessage::JumpToHistory(0)));
+    state.update(Message::Sidebar(SidebarMessage::SaveSnapshot));
+    state.update(Message::Sidebar(SidebarMessage::FlipVertical));
+    assert_eq!(state.history_panel.snapshots.len(), 1);
+    assert_eq!(state.history_panel.snapshots[0].name, None);
+    assert_eq!(state.history_panel.snapshots[0].index, 0);
+
+    state.update(Message::Sidebar(SidebarMessage::DeleteSnapshot(0)));
+    assert!(state.history_panel.snapshots.is_empty());
+}
@@ -6,6 +6,8 @@ use crate::config::BackgroundTheme;
 use crate::media::ImageData;
 use crate::ui::components::checkerboard;
 use crate::ui::design_tokens::{opacity, radius, spacing, typography};
+use crate::ui::guides;
+use crate::ui::state::zoom;
 use crate::ui::theme;
 use crate::ui::widgets::AnimatedSpinner;
 use iced::alignment::Horizontal;
@@ -16,38 +18,84 @@ use iced::widget::{
 use iced::{Background, Color, Element, Length, Padding, Size, Theme};
 
 use super::super::{
-    overlay::{CropOverlayRenderer, ResizeOverlayRenderer},
-    CanvasMessage, CropState, DeblurState, Message, ResizeState, State, ViewContext,
+    overlay::{
+        BrushOverlayRenderer, CompareOverlayRenderer, CropOverlayRenderer, ResizeOverlayRenderer,
+    },
+    BackgroundState, CanvasMessage, CropState, DeblurState, DenoiseState, EditorTool, Message,
+    ResizeState, State, ViewContext,
 };
 use super::scrollable_canvas;
+use crate::ui::image_editor::state::canvas_zoom;
 
 pub struct CanvasModel<'a> {
     pub display_image: &'a ImageData,
+    /// Size the image is laid out at: the working image size, so previews
+    /// computed on a downscaled proxy are stretched over it.
+    pub image_size: (u32, u32),
     pub crop: &'a CropState,
     pub resize: &'a ResizeState,
     pub deblur: &'a DeblurState,
-    /// Zoom scale factor (1.0 = 100%)
+    pub background: &'a BackgroundState,
+    pub denoise: &'a DenoiseState,
+    /// Logical pixels per image pixel, with the interface scale divided out
     pub zoom_scale: f32,
+    /// Whether the image is fitted to the canvas (the zoom follows its size)
+    pub fit_to_window: bool,
+    /// Whether Space is held, so dragging pans over the overlays
+    pub space_panning: bool,
     /// Whether the user is currently dragging to pan
     pub is_dragging: bool,
     /// Whether crop tool is active (disables pan cursor)
     pub crop_active: bool,
+    /// Whether the background removal brush paints the canvas (disables pan cursor)
+    pub brush_active: bool,
     /// Whether AI upscale processing is in progress
     pub upscale_processing: bool,
+    /// Average luminance of the opened image (resolves the auto background).
+    pub background_luminance: Option<f32>,
+    /// Whether the composition guides are shown.
+    pub guides_visible: bool,
+    /// Whether the original image replaces the edits (tool overlays are hidden).
+    pub showing_original: bool,
+    /// Original image drawn left of the split view divider, with its position in percent.
+    pub compare_split: Option<(image::Handle, u32)>,
 }
 
 impl<'a> CanvasModel<'a> {
     pub fn from_state(state: &'a State) -> Self {
-        let display_image = state.display_image();
+        let showing_original = state.is_showing_original();
+        let (display_image, image_size) = match state.compare_original() {
+            Some(original) if showing_original => (original, (original.width, original.height)),
+            _ => (
+                state.display_image(),
+                (state.current_image.width, state.current_image.height),
+            ),
+        };
+        let compare_split = state
+            .compare_split()
+            .zip(state.compare_original())
+            .filter(|_| !showing_original)
+            .map(|(percent, original)| (original.handle.clone(), percent));
         Self {
             display_image,
+            image_size,
             crop: &state.crop,
             resize: &state.resize,
             deblur: &state.deblur,
-            zoom_scale: state.zoom.zoom_percent / 100.0,
+            background: &state.background,
+            denoise: &state.denoise,
+            zoom_scale: zoom::media_scale(state.canvas_zoom_percent()),
+            fit_to_window: state.zoom.fit_to_window,
+            space_panning: state.is_space_panning(),
             is_dragging: state.is_dragging(),
             crop_active: state.crop.overlay.visible,
+            brush_active: state.active_tool == Some(EditorTool::Background)
+                && state.background.is_refining(),
             upscale_processing: state.resize.is_upscale_processing,
+            background_luminance: state.background_luminance,
+            guides_visible: state.guides_visible,
+            showing_original,
+            compare_split,
         }
     }
 }
@@ -65,7 +113,7 @@ fn calculate_centering_padding(content_size: Size, available: Size) -> Padding {
     }
 }
 
-/// Builds the processing overlay with spinner (used during deblur/upscale).
+/// Builds the processing overlay with spinner (used during AI processing).
 fn build_processing_overlay<'a>(
     scaled_width: f32,
     scaled_height: f32,
@@ -109,8 +157,8 @@ fn build_processing_overlay<'a>(
 }
 
 /// Determines cursor interaction based on current state.
-fn determine_cursor_interaction(crop_active: bool, is_dragging: bool) -> mouse::Interaction {
-    if crop_active {
+fn determine_cursor_interaction(overlay_active: bool, is_dragging: bool) -> mouse::Interaction {
+    if overlay_active {
         mouse::Interaction::default()
     } else if is_dragging {
         mouse::Interaction::Grabbing
@@ -133,7 +181,7 @@ fn apply_background<'a>(
     } else {
         let bg_color = match background_theme {
             BackgroundTheme::Light => theme::viewer_light_surface_color(),
-            BackgroundTheme::Dark => theme::viewer_dark_surface_color(),
+            BackgroundTheme::Dark | BackgroundTheme::Auto => theme::viewer_dark_surface_color(),
             BackgroundTheme::Checkerboard => unreachable!(),
         };
 
@@ -142,26 +190,33 @@ fn apply_background<'a>(
 }
 
 pub fn view<'a>(model: &CanvasModel<'a>, ctx: &ViewContext<'a>) -> Element<'a, Message> {
-    let background_theme = ctx.background_theme;
+    let background_theme = ctx.background_theme.resolve(model.background_luminance);
 
     // Clone/copy values needed inside responsive closure
     let image_handle = model.display_image.handle.clone();
-    let img_width = model.display_image.width;
-    let img_height = model.display_image.height;
+    let (img_width, img_height) = model.image_size;
     let zoom_scale = model.zoom_scale;
+    let fit_to_window = model.fit_to_window;
 
     // Capture overlay state
     let deblur_processing = model.deblur.is_processing;
     let upscale_processing = model.upscale_processing;
+    let background_processing = model.background.is_processing;
+    let denoise_processing = model.denoise.is_processing;
     let spinner_rotation = model.deblur.spinner_rotation;
     let processing_text = if deblur_processing {
         ctx.i18n.tr("image-editor-deblur-processing").clone()
     } else if upscale_processing {
         ctx.i18n.tr("image-editor-upscale-processing").clone()
+    } else if background_processing {
+        ctx.i18n.tr("image-editor-background-processing").clone()
+    } else if denoise_processing {
+        ctx.i18n.tr("image-editor-denoise-processing").clone()
     } else {
         String::new()
     };
-    let is_processing = deblur_processing || upscale_processing;
+    let is_processing =
+        deblur_processing || upscale_processing || background_processing || denoise_processing;
 
     let crop_visible = model.crop.overlay.visible;
     let crop_x = model.crop.x;
@@ -175,12 +230,33 @@ pub fn view<'a>(model: &CanvasModel<'a>, ctx: &ViewContext<'a>) -> Element<'a, M
     let resize_width = model.resize.width;
     let resize_height = model.resize.height;
 
+    let brush_active = model.brush_active;
+    let brush_radius = model.background.brush_radius;
+    let brush_mode = model.background.brush_mode;
+
+    let guide_options = model.guides_visible.then(|| ctx.guides.clone());
+
+    let showing_original = model.showing_original;
+    let compare_split = model.compare_split.clone();
+
     // Capture drag state for cursor interaction
     let is_dragging = model.is_dragging;
     let crop_active = model.crop_active;
+    let panning = model.space_panning;
 
     // Use responsive to get available size for centering
     let canvas_content = responsive(move |available_size: Size| {
+        // Fit to the space actually laid out, which is known before the
+        // viewport bounds reach the state
+        let zoom_scale = if fit_to_window {
+            zoom::media_scale(canvas_zoom::fit_zoom_percent(
+                (img_width, img_height),
+                available_size,
+            ))
+        } else {
+            zoom_scale
+        };
+
         // Apply zoom scale to image dimensions
         let scaled_width = (img_width as f32 * zoom_scale).round();
         let scaled_height = (img_height as f32 * zoom_scale).round();
@@ -189,10 +265,24 @@ pub fn view<'a>(model: &CanvasModel<'a>, ctx: &ViewContext<'a>) -> Element<'a, M
         // Calculate centering padding
         let centering_padding = calculate_centering_padding(scaled_size, available_size);
 
-        // Render image at zoomed size
-        let image_widget = image(image_handle.clone())
-            .width(Length::Fixed(scaled_width))
-            .height(Length::Fixed(scaled_height));
+        // Render image at zoomed size, under the guides when shown
+        let image_widget: Element<'_, Message> = {
+            let image_widget = image(image_handle.clone())
+                .width(Length::Fixed(scaled_width))
+                .height(Length::Fixed(scaled_height));
+            match &guide_options {
+                Some(options) => Stack::new()
+                    .push(image_widget)
+                    .push(guides::view(
+                        options,
+                        (img_width, img_height),
+                        zoom::zoom_for_media_scale(zoom_scale),
+                        scaled_size,
+                    ))
+                    .into(),
+                None => image_widget.into(),
+            }
+        };
 
         let image_with_overlay: Element<'_, Message> = if is_processing {
             let overlay = build_processing_overlay(
@@ -202,6 +292,21 @@ pub fn view<'a>(model: &CanvasModel<'a>, ctx: &ViewContext<'a>) -> Element<'a, M
                 processing_text.clone(),
             );
             Stack::new().push(image_widget).push(overlay).into()
+        } else if showing_original {
+            // The edits are hidden, so are the tool overlays drawn over them
+            image_widget
+        } else if let Some((original, split_percent)) = &compare_split {
+            Stack::new()
+                .push(image_widget)
+                .push(
+                    Canvas::new(CompareOverlayRenderer {
+                        original: original.clone(),
+                        split_percent: *split_percent,
+                    })
+                    .width(Length::Fixed(scaled_width))
+                    .height(Length::Fixed(scaled_height)),
+                )
+                .into()
         } else if crop_visible {
             Stack::new()
                 .push(image_widget)
@@ -213,11 +318,27 @@ pub fn view<'a>(model: &CanvasModel<'a>, ctx: &ViewContext<'a>) -> Element<'a, M
                         crop_height,
                         img_width,
                         img_height,
+                        panning,
                     })
                     .width(Length::Fill)
                     .height(Length::Fill),
                 )
                 .into()
+        } else if brush_active {
+            Stack::new()
+                .push(image_widget)
+                .push(
+                    Canvas::new(BrushOverlayRenderer {
+                        img_width,
+                        img_height,
+                        radius: brush_radius,
+                        mode: brush_mode,
+                        panning,
+                    })
+                    .width(Length::Fixed(scaled_width))
+                    .height(Length::Fixed(scaled_height)),
+                )
+                .into()
         } else if resize_visible {
             Stack::new()
                 .push(image_widget)
@@ -242,7 +363,8 @@ pub fn view<'a>(model: &CanvasModel<'a>, ctx: &ViewContext<'a>) -> Element<'a, M
         scrollable_canvas::scrollable_canvas(centered_content.into(), scaled_width, scaled_height)
     });
 
-    let cursor_interaction = determine_cursor_interaction(crop_active, is_dragging);
+    let cursor_interaction =
+        determine_cursor_interaction((crop_active || brush_active) && !panning, is_dragging);
 
     // Wrap canvas in mouse_area for cursor feedback and tracking
     let canvas_with_cursor = mouse_area(canvas_content)
@@ -19,19 +19,20 @@ pub fn render<'a>(state: &'a State, ctx: &ViewContext<'a>) -> Element<'a, Messag
     let toolbar_model = ToolbarModel::from_state(state);
     let toolbar = toolbar::view(&toolbar_model, ctx);
 
-    let mut main_row = Row::new().spacing(0.0);
-
-    if state.sidebar_expanded {
+    let direction = ctx.i18n.layout_direction();
+    let sidebar = if state.sidebar_expanded {
         let sidebar_model = SidebarModel::from_state(state, ctx);
-        let sidebar = sidebar::expanded(&sidebar_model, ctx);
-        main_row = main_row.push(sidebar);
+        sidebar::expanded(&sidebar_model, ctx)
     } else {
-        main_row = main_row.push(sidebar::collapsed(ctx.is_dark_theme));
-    }
+        sidebar::collapsed(ctx)
+    };
 
     let canvas_model = CanvasModel::from_state(state);
     let canvas = canvas::view(&canvas_model, ctx);
-    main_row = main_row.push(canvas);
+
+    // Right-to-left languages have the sidebar on the right
+    let (left, right) = direction.order(sidebar, canvas);
+    let main_row = Row::new().spacing(0.0).push(left).push(right);
 
     let content = Column::new().push(toolbar).push(main_row);
 
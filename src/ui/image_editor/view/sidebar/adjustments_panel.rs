@@ -1,14 +1,20 @@
 // SPDX-License-Identifier: MPL-2.0
-//! Light adjustment tool panel for brightness and contrast controls.
+//! Light adjustment tool panel for auto enhance, brightness, contrast, black
+//! and white and sharpening controls.
 
 use crate::ui::design_tokens::{spacing, typography};
 use crate::ui::styles;
 use crate::ui::styles::button as button_styles;
-use iced::widget::{button, container, slider, text, Column, Row};
-use iced::{Element, Length};
+use crate::ui::theme;
+use iced::widget::{button, checkbox, container, slider, text, Column, Row};
+use iced::{Element, Length, Theme};
 
 use super::super::ViewContext;
-use crate::ui::image_editor::state::AdjustmentState;
+use crate::ui::image_editor::state::adjustment::{
+    MAX_MIX_WEIGHT, MAX_SHARPEN_AMOUNT, MAX_SHARPEN_RADIUS, MAX_SHARPEN_THRESHOLD, MIN_MIX_WEIGHT,
+    MIN_SHARPEN_RADIUS,
+};
+use crate::ui::image_editor::state::{AdjustmentState, GrayscalePreset};
 use crate::ui::image_editor::{Message, SidebarMessage};
 
 /// Format adjustment value with sign and padding for consistent width.
@@ -16,7 +22,48 @@ fn format_value(value: i32) -> String {
     format!("{value:+4}")
 }
 
-pub fn panel<'a>(adjustment: &'a AdjustmentState, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+pub fn panel<'a>(
+    adjustment: &'a AdjustmentState,
+    can_copy: bool,
+    can_paste: bool,
+    ctx: &ViewContext<'a>,
+) -> Element<'a, Message> {
+    // Auto enhance: one button, then the strength of the previewed correction
+    let auto_btn = button(text(ctx.i18n.tr("image-editor-auto-enhance")).size(typography::BODY))
+        .padding(spacing::XS)
+        .width(Length::Fill)
+        .on_press(SidebarMessage::ToggleAutoEnhance.into())
+        .style(if adjustment.auto_enhance.is_some() {
+            button_styles::selected
+        } else {
+            button_styles::unselected
+        });
+    let mut auto_section = Column::new().spacing(spacing::XXS).push(auto_btn);
+    if let Some(auto) = adjustment.auto_enhance {
+        if auto.params.is_neutral() {
+            auto_section = auto_section.push(
+                text(ctx.i18n.tr("image-editor-auto-enhance-neutral"))
+                    .size(typography::BODY_SM)
+                    .style(|_: &Theme| iced::widget::text::Style {
+                        color: Some(theme::muted_text_color()),
+                    }),
+            );
+        } else {
+            auto_section = auto_section
+                .push(
+                    text(ctx.i18n.tr("image-editor-auto-enhance-strength-label"))
+                        .size(typography::BODY_SM),
+                )
+                .push(
+                    slider(0..=100, auto.strength, |value| {
+                        Message::Sidebar(SidebarMessage::AutoEnhanceStrengthChanged(value))
+                    })
+                    .step(1u32),
+                )
+                .push(text(format!("{} %", auto.strength)).size(typography::BODY_SM));
+        }
+    }
+
     // Brightness section - vertical layout: label, slider, value
     let brightness_section = Column::new()
         .spacing(spacing::XXS)
@@ -41,6 +88,105 @@ pub fn panel<'a>(adjustment: &'a AdjustmentState, ctx: &ViewContext<'a>) -> Elem
         )
         .push(text(format_value(adjustment.contrast.value())).size(typography::BODY_SM));
 
+    // Black and white section: on/off, filter presets and per-channel weights
+    let mixer = adjustment.mixer;
+    let grayscale_checkbox = checkbox(mixer.enabled)
+        .label(ctx.i18n.tr("image-editor-grayscale-enable"))
+        .on_toggle(|enabled| Message::Sidebar(SidebarMessage::GrayscaleToggled(enabled)));
+    let mut grayscale_section = Column::new().spacing(spacing::XS).push(grayscale_checkbox);
+    if mixer.enabled {
+        let presets = GrayscalePreset::ALL.into_iter().fold(
+            Row::new().spacing(spacing::XXS),
+            |row, preset| {
+                row.push(
+                    button(text(ctx.i18n.tr(preset.i18n_key())).size(typography::BODY_SM))
+                        .padding(spacing::XXS)
+                        .width(Length::Fill)
+                        .on_press(SidebarMessage::GrayscalePresetSelected(preset).into())
+                        .style(if mixer.preset() == Some(preset) {
+                            button_styles::selected
+                        } else {
+                            button_styles::unselected
+                        }),
+                )
+            },
+        );
+        let weight_section = |label: &str, weight: i32, on_change: fn(i32) -> SidebarMessage| {
+            Column::new()
+                .spacing(spacing::XXS)
+                .push(text(ctx.i18n.tr(label)).size(typography::BODY_SM))
+                .push(
+                    slider(MIN_MIX_WEIGHT..=MAX_MIX_WEIGHT, weight, move |value| {
+                        Message::Sidebar(on_change(value))
+                    })
+                    .step(1),
+                )
+                .push(text(format!("{weight:+4} %")).size(typography::BODY_SM))
+        };
+        let total: i32 = mixer.weights.iter().sum();
+        grayscale_section = grayscale_section
+            .push(presets)
+            .push(weight_section(
+                "image-editor-grayscale-red-label",
+                mixer.weights[0],
+                SidebarMessage::MixerRedChanged,
+            ))
+            .push(weight_section(
+                "image-editor-grayscale-green-label",
+                mixer.weights[1],
+                SidebarMessage::MixerGreenChanged,
+            ))
+            .push(weight_section(
+                "image-editor-grayscale-blue-label",
+                mixer.weights[2],
+                SidebarMessage::MixerBlueChanged,
+            ))
+            .push(
+                text(ctx.i18n.tr_with_args(
+                    "image-editor-grayscale-total",
+                    &[("total", total.to_string().as_str())],
+                ))
+                .size(typography::BODY_SM),
+            );
+    }
+
+    // Unsharp mask sections - same vertical layout as the light sliders
+    let sharpen = adjustment.sharpen;
+    let amount_section = Column::new()
+        .spacing(spacing::XXS)
+        .push(text(ctx.i18n.tr("image-editor-sharpen-amount-label")).size(typography::BODY_SM))
+        .push(
+            slider(0..=MAX_SHARPEN_AMOUNT, sharpen.amount, |value| {
+                Message::Sidebar(SidebarMessage::SharpenAmountChanged(value))
+            })
+            .step(5u32),
+        )
+        .push(text(format!("{} %", sharpen.amount)).size(typography::BODY_SM));
+
+    let radius_section = Column::new()
+        .spacing(spacing::XXS)
+        .push(text(ctx.i18n.tr("image-editor-sharpen-radius-label")).size(typography::BODY_SM))
+        .push(
+            slider(
+                MIN_SHARPEN_RADIUS..=MAX_SHARPEN_RADIUS,
+                sharpen.radius,
+                |value| Message::Sidebar(SidebarMessage::SharpenRadiusChanged(value)),
+            )
+            .step(0.1),
+        )
+        .push(text(format!("{:.1} px", sharpen.radius)).size(typography::BODY_SM));
+
+    let threshold_section = Column::new()
+        .spacing(spacing::XXS)
+        .push(text(ctx.i18n.tr("image-editor-sharpen-threshold-label")).size(typography::BODY_SM))
+        .push(
+            slider(0..=MAX_SHARPEN_THRESHOLD, sharpen.threshold, |value| {
+                Message::Sidebar(SidebarMessage::SharpenThresholdChanged(value))
+            })
+            .step(1u8),
+        )
+        .push(text(sharpen.threshold.to_string()).size(typography::BODY_SM));
+
     // Action buttons row
     let reset_btn = button(text(ctx.i18n.tr("image-editor-light-reset")).size(typography::BODY))
         .padding(spacing::SM)
@@ -65,13 +211,60 @@ pub fn panel<'a>(adjustment: &'a AdjustmentState, ctx: &ViewContext<'a>) -> Elem
         .push(reset_btn)
         .push(apply_btn);
 
+    // Session clipboard row: reuse the same settings on similar photos
+    let copy_btn = button(text(ctx.i18n.tr("image-editor-light-copy")).size(typography::BODY_SM))
+        .padding(spacing::XS)
+        .width(Length::Fill);
+    let copy_btn = if can_copy {
+        copy_btn.on_press(SidebarMessage::CopyAdjustments.into())
+    } else {
+        copy_btn.style(button_styles::disabled())
+    };
+
+    let paste_btn = button(text(ctx.i18n.tr("image-editor-light-paste")).size(typography::BODY_SM))
+        .padding(spacing::XS)
+        .width(Length::Fill);
+    let paste_btn = if can_paste {
+        paste_btn.on_press(SidebarMessage::PasteAdjustments.into())
+    } else {
+        paste_btn.style(button_styles::disabled())
+    };
+
+    let paste_folder_btn =
+        button(text(ctx.i18n.tr("image-editor-light-paste-folder")).size(typography::BODY_SM))
+            .padding(spacing::XS)
+            .width(Length::Fill);
+    let paste_folder_btn = if can_paste {
+        paste_folder_btn.on_press(SidebarMessage::PasteAdjustmentsToFolder.into())
+    } else {
+        paste_folder_btn.style(button_styles::disabled())
+    };
+
+    let clipboard_row = Column::new()
+        .spacing(spacing::XS)
+        .push(
+            Row::new()
+                .spacing(spacing::XS)
+                .push(copy_btn)
+                .push(paste_btn),
+        )
+        .push(paste_folder_btn);
+
     container(
         Column::new()
             .spacing(spacing::SM)
             .push(text(ctx.i18n.tr("image-editor-light-section-title")).size(typography::BODY))
+            .push(auto_section)
             .push(brightness_section)
             .push(contrast_section)
-            .push(buttons_row),
+            .push(text(ctx.i18n.tr("image-editor-grayscale-section-title")).size(typography::BODY))
+            .push(grayscale_section)
+            .push(text(ctx.i18n.tr("image-editor-sharpen-section-title")).size(typography::BODY))
+            .push(amount_section)
+            .push(radius_section)
+            .push(threshold_section)
+            .push(buttons_row)
+            .push(clipboard_row),
     )
     .padding(spacing::SM)
     .width(Length::Fill)
@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: MPL-2.0
+//! AI background removal tool panel.
+
+use crate::media::background_removal::BackgroundModelStatus;
+use crate::ui::design_tokens::{spacing, typography};
+use crate::ui::image_editor::state::background::{MAX_BRUSH_RADIUS, MIN_BRUSH_RADIUS};
+use crate::ui::image_editor::state::{BackgroundState, BrushMode};
+use crate::ui::image_editor::{Message, SidebarMessage};
+use crate::ui::styles;
+use crate::ui::styles::button as button_styles;
+use crate::ui::theme;
+use iced::widget::{button, container, progress_bar, slider, text, Button, Column, Row, Text};
+use iced::{Color, Element, Length, Theme};
+
+use super::super::ViewContext;
+
+/// Creates the disabled button used when background removal is unavailable.
+fn disabled_button<'a>(label: String) -> Button<'a, Message> {
+    button(text(label).size(typography::BODY_LG))
+        .padding(spacing::SM)
+        .width(Length::Fill)
+        .style(button_styles::disabled())
+}
+
+/// Creates a styled status text with the given color.
+fn status_text<'a>(message: String, color: Color) -> Text<'a> {
+    text(message)
+        .size(typography::BODY_SM)
+        .style(move |_: &Theme| iced::widget::text::Style { color: Some(color) })
+}
+
+/// Render the background removal tool panel.
+///
+/// Shows:
+/// - Remove button (or status while the model is unavailable or running)
+/// - Once the cut-out is shown: keep/erase brush, brush size and apply button
+/// - A reminder that JPEG can't keep the transparency
+pub fn panel<'a>(
+    background: &'a BackgroundState,
+    model_status: &'a BackgroundModelStatus,
+    ctx: &ViewContext<'a>,
+) -> Element<'a, Message> {
+    let remove_label = ctx.i18n.tr("image-editor-background-remove");
+    let mut content = Column::new().spacing(spacing::SM);
+
+    if background.is_processing {
+        content = content.push(status_text(
+            ctx.i18n.tr("image-editor-background-processing"),
+            theme::muted_text_color(),
+        ));
+        content = content.push(disabled_button(remove_label));
+    } else if background.is_refining() {
+        content = content.push(refine_section(background, ctx));
+    } else {
+        content = build_model_status_ui(content, model_status, &remove_label, ctx);
+    }
+
+    content = content.push(status_text(
+        ctx.i18n
+            .tr("image-editor-background-transparent-export-hint"),
+        theme::muted_text_color(),
+    ));
+
+    container(content)
+        .padding(spacing::SM)
+        .width(Length::Fill)
+        .style(styles::editor::settings_panel)
+        .into()
+}
+
+/// Builds the refine-edges brush controls shown over a pending cut-out.
+fn refine_section<'a>(
+    background: &'a BackgroundState,
+    ctx: &ViewContext<'a>,
+) -> Column<'a, Message> {
+    let mode_button = |label: String, mode: BrushMode| {
+        button(text(label).size(typography::BODY))
+            .padding(spacing::XS)
+            .width(Length::Fill)
+            .on_press(SidebarMessage::SetBrushMode(mode).into())
+            .style(if background.brush_mode == mode {
+                button_styles::selected
+            } else {
+                button_styles::unselected
+            })
+    };
+    let modes = Row::new()
+        .spacing(spacing::XS)
+        .push(mode_button(
+            ctx.i18n.tr("image-editor-background-brush-keep"),
+            BrushMode::Keep,
+        ))
+        .push(mode_button(
+            ctx.i18n.tr("image-editor-background-brush-erase"),
+            BrushMode::Erase,
+        ));
+
+    let size_section = Column::new()
+        .spacing(spacing::XXS)
+        .push(text(ctx.i18n.tr("image-editor-background-brush-size")).size(typography::BODY_SM))
+        .push(
+            slider(
+                MIN_BRUSH_RADIUS..=MAX_BRUSH_RADIUS,
+                background.brush_radius,
+                |value| Message::Sidebar(SidebarMessage::BrushRadiusChanged(value)),
+            )
+            .step(1u32),
+        )
+        .push(text(format!("{} px", background.brush_radius)).size(typography::BODY_SM));
+
+    let apply_btn =
+        button(text(ctx.i18n.tr("image-editor-background-apply")).size(typography::BODY_LG))
+            .padding(spacing::SM)
+            .width(Length::Fill)
+            .on_press(SidebarMessage::ApplyBackgroundRemoval.into());
+
+    Column::new()
+        .spacing(spacing::SM)
+        .push(status_text(
+            ctx.i18n.tr("image-editor-background-refine-hint"),
+            theme::muted_text_color(),
+        ))
+        .push(modes)
+        .push(size_section)
+        .push(apply_btn)
+}
+
+/// Builds the UI elements based on model status before the cut-out is made.
+fn build_model_status_ui<'a>(
+    mut content: Column<'a, Message>,
+    model_status: &BackgroundModelStatus,
+    remove_label: &str,
+    ctx: &ViewContext<'_>,
+) -> Column<'a, Message> {
+    match model_status {
+        BackgroundModelStatus::Ready => {
+            let remove_btn = button(text(remove_label.to_string()).size(typography::BODY_LG))
+                .padding(spacing::SM)
+                .width(Length::Fill)
+                .on_press(SidebarMessage::RemoveBackground.into());
+            content.push(remove_btn)
+        }
+        BackgroundModelStatus::Validating => {
+            content = content.push(status_text(
+                ctx.i18n.tr("image-editor-background-validating"),
+                theme::muted_text_color(),
+            ));
+            content.push(disabled_button(remove_label.to_string()))
+        }
+        BackgroundModelStatus::Downloading { progress } => {
+            content = content.push(progress_bar(0.0..=1.0, *progress));
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let percent = (*progress * 100.0) as u32;
+            content = content.push(
+                text(ctx.i18n.tr_with_args(
+                    "image-editor-background-downloading",
+                    &[("progress", format!("{percent}").as_str())],
+                ))
+                .size(typography::BODY_SM),
+            );
+            content.push(disabled_button(remove_label.to_string()))
+        }
+        BackgroundModelStatus::NotDownloaded => {
+            content = content.push(status_text(
+                ctx.i18n.tr("image-editor-background-model-not-ready"),
+                theme::error_text_color(),
+            ));
+            content.push(disabled_button(remove_label.to_string()))
+        }
+        BackgroundModelStatus::Error(error_msg) => {
+            content = content.push(status_text(
+                ctx.i18n.tr_with_args(
+                    "image-editor-background-error",
+                    &[("error", error_msg.as_str())],
+                ),
+                theme::error_text_color(),
+            ));
+            content.push(disabled_button(remove_label.to_string()))
+        }
+    }
+}
@@ -56,6 +56,22 @@ pub fn panel<'a>(crop: &'a CropState, ctx: &ViewContext<'a>) -> Element<'a, Mess
 
     let crop_info = text(format!("{}×{} px", crop.width, crop.height)).size(typography::CAPTION);
 
+    let face_btn = {
+        let label = if crop.is_detecting_faces {
+            ctx.i18n.tr("image-editor-crop-face-detecting")
+        } else {
+            ctx.i18n.tr("image-editor-crop-face")
+        };
+        let btn = button(text(label).size(typography::BODY_SM))
+            .padding(spacing::XS)
+            .width(Length::Fill);
+        if crop.is_detecting_faces {
+            btn.style(button_styles::disabled())
+        } else {
+            btn.on_press(SidebarMessage::CropToFace.into())
+        }
+    };
+
     let apply_btn = {
         let btn = button(text(ctx.i18n.tr("image-editor-crop-apply")).size(typography::BODY))
             .padding(spacing::XS)
@@ -67,21 +83,25 @@ pub fn panel<'a>(crop: &'a CropState, ctx: &ViewContext<'a>) -> Element<'a, Mess
         }
     };
 
-    container(
-        Column::new()
-            .spacing(spacing::XS)
-            .push(title)
-            .push(ratio_label)
-            .push(ratios_row1)
-            .push(ratios_row2)
-            .push(ratios_row3)
-            .push(crop_info)
-            .push(apply_btn),
-    )
-    .padding(spacing::SM)
-    .width(Length::Fill)
-    .style(styles::editor::settings_panel)
-    .into()
+    let mut content = Column::new()
+        .spacing(spacing::XS)
+        .push(title)
+        .push(ratio_label)
+        .push(ratios_row1)
+        .push(ratios_row2)
+        .push(ratios_row3)
+        .push(face_btn);
+    if crop.faces.as_ref().is_some_and(Vec::is_empty) {
+        content = content
+            .push(text(ctx.i18n.tr("image-editor-crop-face-none")).size(typography::CAPTION));
+    }
+    content = content.push(crop_info).push(apply_btn);
+
+    container(content)
+        .padding(spacing::SM)
+        .width(Length::Fill)
+        .style(styles::editor::settings_panel)
+        .into()
 }
 
 fn ratio_button(crop: &CropState, label: String, ratio: CropRatio) -> Element<'_, Message> {
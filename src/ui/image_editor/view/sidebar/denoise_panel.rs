@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Denoise tool panel for noise reduction settings.
+
+use crate::media::denoise::DenoiseMethod;
+use crate::ui::design_tokens::{spacing, typography};
+use crate::ui::image_editor::state::DenoiseState;
+use crate::ui::image_editor::{Message, SidebarMessage};
+use crate::ui::styles;
+use crate::ui::styles::button as button_styles;
+use crate::ui::theme;
+use iced::widget::{button, container, slider, text, Column, Row, Text};
+use iced::{Element, Length, Theme};
+
+use super::super::ViewContext;
+
+/// Creates a muted hint text.
+fn hint_text<'a>(message: String) -> Text<'a> {
+    text(message)
+        .size(typography::BODY_SM)
+        .style(|_: &Theme| iced::widget::text::Style {
+            color: Some(theme::muted_text_color()),
+        })
+}
+
+/// Render the denoise tool panel.
+///
+/// Shows:
+/// - Method buttons (the model only when its URL is set)
+/// - Strength and detail sliders for the classical filters
+/// - Apply button (or status while the full-resolution denoise runs)
+pub fn panel<'a>(
+    denoise: &'a DenoiseState,
+    model_available: bool,
+    ctx: &ViewContext<'a>,
+) -> Element<'a, Message> {
+    let params = denoise.params;
+    let method_button = |label: String, method: DenoiseMethod| {
+        button(text(label).size(typography::BODY_SM))
+            .padding(spacing::XS)
+            .width(Length::Fill)
+            .on_press(SidebarMessage::SetDenoiseMethod(method).into())
+            .style(if params.method == method {
+                button_styles::selected
+            } else {
+                button_styles::unselected
+            })
+    };
+    let mut methods = Row::new()
+        .spacing(spacing::XS)
+        .push(method_button(
+            ctx.i18n.tr("image-editor-denoise-method-bilateral"),
+            DenoiseMethod::Bilateral,
+        ))
+        .push(method_button(
+            ctx.i18n.tr("image-editor-denoise-method-nlmeans"),
+            DenoiseMethod::NonLocalMeans,
+        ));
+    if model_available {
+        methods = methods.push(method_button(
+            ctx.i18n.tr("image-editor-denoise-method-model"),
+            DenoiseMethod::Model,
+        ));
+    }
+
+    let mut content = Column::new()
+        .spacing(spacing::SM)
+        .push(text(ctx.i18n.tr("image-editor-denoise-method-label")).size(typography::BODY_SM))
+        .push(methods);
+
+    if params.method == DenoiseMethod::Model {
+        content = content.push(hint_text(ctx.i18n.tr("image-editor-denoise-model-hint")));
+    } else {
+        let strength_section = Column::new()
+            .spacing(spacing::XXS)
+            .push(
+                text(ctx.i18n.tr("image-editor-denoise-strength-label")).size(typography::BODY_SM),
+            )
+            .push(
+                slider(0..=100, params.strength, |value| {
+                    Message::Sidebar(SidebarMessage::DenoiseStrengthChanged(value))
+                })
+                .step(1u32),
+            )
+            .push(text(format!("{}", params.strength)).size(typography::BODY_SM));
+
+        let detail_section = Column::new()
+            .spacing(spacing::XXS)
+            .push(text(ctx.i18n.tr("image-editor-denoise-detail-label")).size(typography::BODY_SM))
+            .push(
+                slider(0..=100, params.detail, |value| {
+                    Message::Sidebar(SidebarMessage::DenoiseDetailChanged(value))
+                })
+                .step(1u32),
+            )
+            .push(text(format!("{}", params.detail)).size(typography::BODY_SM));
+
+        content = content
+            .push(strength_section)
+            .push(detail_section)
+            .push(hint_text(ctx.i18n.tr("image-editor-denoise-preview-hint")));
+    }
+
+    let apply_btn =
+        button(text(ctx.i18n.tr("image-editor-denoise-apply")).size(typography::BODY_LG))
+            .padding(spacing::SM)
+            .width(Length::Fill);
+    // The model has no strength setting
+    let has_effect = params.strength > 0 || params.method == DenoiseMethod::Model;
+    let apply_btn = if denoise.is_processing || !has_effect {
+        apply_btn.style(button_styles::disabled())
+    } else {
+        apply_btn.on_press(SidebarMessage::ApplyDenoise.into())
+    };
+    if denoise.is_processing {
+        content = content.push(hint_text(ctx.i18n.tr("image-editor-denoise-processing")));
+    }
+    content = content.push(apply_btn);
+
+    container(content)
+        .padding(spacing::SM)
+        .width(Length::Fill)
+        .style(styles::editor::settings_panel)
+        .into()
+}
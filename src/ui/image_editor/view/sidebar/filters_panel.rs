@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Filters tool panel with one-click looks.
+
+use crate::media::looks::FilterPreset;
+use crate::ui::design_tokens::{spacing, typography};
+use crate::ui::image_editor::state::FilterState;
+use crate::ui::image_editor::{Message, SidebarMessage};
+use crate::ui::styles;
+use crate::ui::styles::button as button_styles;
+use crate::ui::theme;
+use iced::widget::{button, container, slider, text, Column, Row};
+use iced::{Element, Length, Theme};
+
+use super::super::ViewContext;
+
+/// Render the filters tool panel.
+///
+/// Shows:
+/// - Preset buttons, two per row (clicking the selected one drops it)
+/// - Strength slider once a look is selected
+/// - Apply button
+pub fn panel<'a>(filters: &'a FilterState, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+    let preset_button = |preset: FilterPreset| {
+        button(text(ctx.i18n.tr(preset.i18n_key())).size(typography::BODY_SM))
+            .padding(spacing::XS)
+            .width(Length::Fill)
+            .on_press(SidebarMessage::SelectFilter(preset).into())
+            .style(if filters.selected == Some(preset) {
+                button_styles::selected
+            } else {
+                button_styles::unselected
+            })
+    };
+
+    let mut presets = Column::new().spacing(spacing::XS);
+    for pair in FilterPreset::ALL.chunks(2) {
+        let row = pair
+            .iter()
+            .fold(Row::new().spacing(spacing::XS), |row, preset| {
+                row.push(preset_button(*preset))
+            });
+        presets = presets.push(row);
+    }
+
+    let mut content = Column::new().spacing(spacing::SM).push(presets);
+
+    if filters.selected.is_some() {
+        let strength_section = Column::new()
+            .spacing(spacing::XXS)
+            .push(text(ctx.i18n.tr("image-editor-filter-strength-label")).size(typography::BODY_SM))
+            .push(
+                slider(0..=100, filters.strength, |value| {
+                    Message::Sidebar(SidebarMessage::FilterStrengthChanged(value))
+                })
+                .step(1u32),
+            )
+            .push(text(format!("{}%", filters.strength)).size(typography::BODY_SM));
+        content = content.push(strength_section);
+    } else {
+        content = content.push(
+            text(ctx.i18n.tr("image-editor-filter-hint"))
+                .size(typography::BODY_SM)
+                .style(|_: &Theme| iced::widget::text::Style {
+                    color: Some(theme::muted_text_color()),
+                }),
+        );
+    }
+
+    let apply_btn =
+        button(text(ctx.i18n.tr("image-editor-filter-apply")).size(typography::BODY_LG))
+            .padding(spacing::SM)
+            .width(Length::Fill);
+    let apply_btn = if filters.has_changes() {
+        apply_btn.on_press(SidebarMessage::ApplyFilter.into())
+    } else {
+        apply_btn.style(button_styles::disabled())
+    };
+    content = content.push(apply_btn);
+
+    container(content)
+        .padding(spacing::SM)
+        .width(Length::Fill)
+        .style(styles::editor::settings_panel)
+        .into()
+}
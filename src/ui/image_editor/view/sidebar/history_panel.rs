@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MPL-2.0
+//! History panel listing the applied steps and the named snapshots.
+
+use crate::ui::design_tokens::{spacing, typography};
+use crate::ui::image_editor::state::HistoryPanelState;
+use crate::ui::image_editor::{Message, SidebarMessage, Transformation};
+use crate::ui::styles;
+use crate::ui::styles::button as button_styles;
+use crate::ui::theme;
+use iced::widget::{button, container, text, text_input, tooltip, Column, Row};
+use iced::{Element, Length, Theme};
+
+use super::super::ViewContext;
+
+/// Render the history panel.
+///
+/// Shows:
+/// - Snapshot name input with a Save button
+/// - Snapshots, each jumping back to its step, with a delete button
+/// - Every step from the original image, undone ones dimmed; clicking one
+///   reverts to it
+pub fn panel<'a>(
+    history_panel: &'a HistoryPanelState,
+    steps: &'a [Transformation],
+    position: usize,
+    ctx: &ViewContext<'a>,
+) -> Element<'a, Message> {
+    let placeholder = ctx.i18n.tr("image-editor-snapshot-name-placeholder");
+    let name_input = text_input(placeholder.as_str(), &history_panel.name_input)
+        .on_input(|value| Message::Sidebar(SidebarMessage::SnapshotNameChanged(value)))
+        .on_submit(Message::Sidebar(SidebarMessage::SaveSnapshot))
+        .padding(spacing::XXS)
+        .style(styles::text_input::standard)
+        .size(typography::BODY)
+        .width(Length::Fill);
+    let save_btn = button(text(ctx.i18n.tr("image-editor-snapshot-save")).size(typography::BODY))
+        .on_press(SidebarMessage::SaveSnapshot.into())
+        .padding(spacing::XXS);
+
+    let mut content = Column::new().spacing(spacing::SM).push(
+        Row::new()
+            .spacing(spacing::XS)
+            .push(name_input)
+            .push(save_btn),
+    );
+
+    if !history_panel.snapshots.is_empty() {
+        let mut snapshots = Column::new()
+            .spacing(spacing::XXS)
+            .push(text(ctx.i18n.tr("image-editor-snapshots-title")).size(typography::BODY_SM));
+        for (number, snapshot) in history_panel.snapshots.iter().enumerate() {
+            let label = snapshot.name.clone().unwrap_or_else(|| {
+                ctx.i18n.tr_with_args(
+                    "image-editor-snapshot-unnamed",
+                    &[("step", snapshot.index.to_string().as_str())],
+                )
+            });
+            let jump_btn = step_button(label, snapshot.index, snapshot.index == position);
+            let delete_btn = styles::tooltip::styled(
+                button(text("×").size(typography::BODY_SM))
+                    .on_press(SidebarMessage::DeleteSnapshot(number).into())
+                    .padding(spacing::XXS)
+                    .style(button::text),
+                ctx.i18n.tr("image-editor-snapshot-delete-tooltip"),
+                tooltip::Position::Left,
+            );
+            snapshots = snapshots.push(
+                Row::new()
+                    .spacing(spacing::XXS)
+                    .push(jump_btn)
+                    .push(delete_btn),
+            );
+        }
+        content = content.push(snapshots);
+    }
+
+    let mut steps_list = Column::new()
+        .spacing(spacing::XXS)
+        .push(text(ctx.i18n.tr("image-editor-history-steps-title")).size(typography::BODY_SM))
+        .push(step_button(
+            ctx.i18n.tr("image-editor-history-original"),
+            0,
+            position == 0,
+        ));
+    for (offset, step) in steps.iter().enumerate() {
+        let index = offset + 1;
+        let label = ctx.i18n.tr(step.i18n_key());
+        steps_list = steps_list.push(if index > position {
+            undone_step_button(label, index)
+        } else {
+            step_button(label, index, index == position)
+        });
+    }
+    content = content.push(steps_list);
+
+    container(content)
+        .padding(spacing::SM)
+        .width(Length::Fill)
+        .style(styles::editor::settings_panel)
+        .into()
+}
+
+/// Button reverting to the state after `index` steps.
+fn step_button<'a>(label: String, index: usize, current: bool) -> Element<'a, Message> {
+    button(text(label).size(typography::BODY_SM))
+        .on_press(SidebarMessage::JumpToHistory(index).into())
+        .padding(spacing::XXS)
+        .width(Length::Fill)
+        .style(if current {
+            button_styles::selected
+        } else {
+            button_styles::unselected
+        })
+        .into()
+}
+
+/// Step undone but still available to redo, shown dimmed.
+fn undone_step_button<'a>(label: String, index: usize) -> Element<'a, Message> {
+    button(
+        text(label)
+            .size(typography::BODY_SM)
+            .style(|_: &Theme| iced::widget::text::Style {
+                color: Some(theme::muted_text_color()),
+            }),
+    )
+    .on_press(SidebarMessage::JumpToHistory(index).into())
+    .padding(spacing::XXS)
+    .width(Length::Fill)
+    .style(button::text)
+    .into()
+}
@@ -2,10 +2,17 @@
 //! Sidebar layout composition.
 
 pub mod adjustments_panel;
+pub mod background_panel;
 pub mod crop_panel;
 pub mod deblur_panel;
+pub mod denoise_panel;
+pub mod filters_panel;
+pub mod history_panel;
 pub mod resize_panel;
 
+use crate::i18n::direction::LayoutDirection;
+use crate::media::background_removal::BackgroundModelStatus;
+use crate::media::color_proof::{ProofOptions, ProofProfile};
 use crate::media::deblur::ModelStatus;
 use crate::media::frame_export::ExportFormat;
 use crate::media::upscale::UpscaleModelStatus;
@@ -13,14 +20,17 @@ use crate::media::ImageData;
 use crate::ui::action_icons;
 use crate::ui::design_tokens::{sizing, spacing, typography};
 use crate::ui::icons;
-use crate::ui::image_editor::state::{AdjustmentState, CropState, DeblurState, ResizeState};
+use crate::ui::image_editor::state::{
+    AdjustmentState, BackgroundState, CropState, DeblurState, DenoiseState, FilterState,
+    HistoryPanelState, ResizeState,
+};
 use crate::ui::styles;
 use crate::ui::styles::button as button_styles;
 use iced::widget::scrollable::{Direction, Scrollbar};
-use iced::widget::{button, container, rule, text, tooltip, Column, Row, Scrollable};
+use iced::widget::{button, checkbox, container, rule, text, tooltip, Column, Row, Scrollable};
 use iced::{alignment::Vertical, Element, Length, Padding, Theme};
 
-use super::super::{EditorTool, Message, SidebarMessage, State, ViewContext};
+use super::super::{EditorTool, Message, SidebarMessage, State, Transformation, ViewContext};
 
 /// Helper to create a styled tooltip that follows the cursor.
 fn tip_cursor<'a, Msg: 'a>(
@@ -40,24 +50,49 @@ pub struct SidebarModel<'a> {
     pub crop: &'a CropState,
     pub resize: &'a ResizeState,
     pub adjustment: &'a AdjustmentState,
+    pub filters: &'a FilterState,
     pub deblur: &'a DeblurState,
+    pub background: &'a BackgroundState,
+    pub denoise: &'a DenoiseState,
     pub can_undo: bool,
     pub can_redo: bool,
     pub has_unsaved_changes: bool,
+    /// History panel state (expanded flag and snapshots).
+    pub history_panel: &'a HistoryPanelState,
+    /// Every recorded step, including the undone ones.
+    pub history: &'a [Transformation],
+    /// Number of steps currently applied.
+    pub history_position: usize,
     /// True if editing a captured video frame (no source file).
     pub is_captured_frame: bool,
     /// Selected export format for Save As.
     pub export_format: ExportFormat,
+    /// Color profile embedded (and optionally converted to) when saving.
+    pub proof: ProofOptions,
     /// Current status of the deblur model.
     pub deblur_model_status: &'a ModelStatus,
     /// True if deblur has already been applied to this image.
     pub has_deblur_applied: bool,
+    /// Current status of the background removal model.
+    pub background_model_status: &'a BackgroundModelStatus,
+    /// Whether a denoising model URL is set.
+    pub denoise_model_available: bool,
     /// Thumbnail preview for resize tool (shown in sidebar).
     pub resize_thumbnail: Option<&'a ImageData>,
     /// Current status of the AI upscale model.
     pub upscale_model_status: &'a UpscaleModelStatus,
     /// Whether AI upscaling is enabled globally in settings.
     pub enable_upscale: bool,
+    /// True if the image has applied or pending adjustments to copy.
+    pub can_copy_adjustments: bool,
+    /// True if the session clipboard holds adjustments to paste.
+    pub can_paste_adjustments: bool,
+    /// Labels of the editor tools added by plugins.
+    pub plugin_tools: &'a [String],
+    /// Labels of the export formats added by plugins.
+    pub plugin_exports: &'a [String],
+    /// True while a plugin editor tool is running.
+    pub plugin_running: bool,
 }
 
 impl<'a> SidebarModel<'a> {
@@ -67,17 +102,31 @@ impl<'a> SidebarModel<'a> {
             crop: &state.crop,
             resize: &state.resize,
             adjustment: &state.adjustment,
+            filters: &state.filters,
             deblur: &state.deblur,
+            background: &state.background,
+            denoise: &state.denoise,
             can_undo: state.can_undo(),
             can_redo: state.can_redo(),
             has_unsaved_changes: state.has_unsaved_changes(),
+            history_panel: &state.history_panel,
+            history: &state.transformation_history,
+            history_position: state.history_position(),
             is_captured_frame: state.is_captured_frame(),
             export_format: state.export_format(),
+            proof: state.proof_options(),
             deblur_model_status: ctx.deblur_model_status,
             has_deblur_applied: state.has_deblur_applied(),
+            background_model_status: ctx.background_model_status,
+            denoise_model_available: ctx.denoise_model_available,
             resize_thumbnail: state.resize_thumbnail(),
             upscale_model_status: ctx.upscale_model_status,
             enable_upscale: ctx.enable_upscale,
+            can_copy_adjustments: state.has_adjustments_to_copy(),
+            can_paste_adjustments: ctx.can_paste_adjustments,
+            plugin_tools: ctx.plugin_tools,
+            plugin_exports: ctx.plugin_exports,
+            plugin_running: state.is_plugin_running(),
         }
     }
 }
@@ -90,6 +139,19 @@ pub fn expanded<'a>(model: &SidebarModel<'a>, ctx: &ViewContext<'a>) -> Element<
 
     scrollable_section =
         scrollable_section.push(undo_redo_section(model.can_undo, model.can_redo, ctx));
+    scrollable_section = scrollable_section.push(tool_button(
+        ctx.i18n.tr("image-editor-history-title"),
+        SidebarMessage::ToggleHistoryPanel,
+        model.history_panel.expanded,
+    ));
+    if model.history_panel.expanded {
+        scrollable_section = scrollable_section.push(history_panel::panel(
+            model.history_panel,
+            model.history,
+            model.history_position,
+            ctx,
+        ));
+    }
     scrollable_section = scrollable_section.push(rule::horizontal(1));
     scrollable_section = scrollable_section.push(rotate_section(ctx));
     scrollable_section = scrollable_section.push(rule::horizontal(1));
@@ -129,8 +191,22 @@ pub fn expanded<'a>(model: &SidebarModel<'a>, ctx: &ViewContext<'a>) -> Element<
     );
     scrollable_section = scrollable_section.push(light_button);
     if model.active_tool == Some(EditorTool::Adjust) {
-        scrollable_section =
-            scrollable_section.push(adjustments_panel::panel(model.adjustment, ctx));
+        scrollable_section = scrollable_section.push(adjustments_panel::panel(
+            model.adjustment,
+            model.can_copy_adjustments,
+            model.can_paste_adjustments,
+            ctx,
+        ));
+    }
+
+    let filters_button = tool_button(
+        ctx.i18n.tr("image-editor-tool-filters"),
+        SidebarMessage::SelectTool(EditorTool::Filters),
+        model.active_tool == Some(EditorTool::Filters),
+    );
+    scrollable_section = scrollable_section.push(filters_button);
+    if model.active_tool == Some(EditorTool::Filters) {
+        scrollable_section = scrollable_section.push(filters_panel::panel(model.filters, ctx));
     }
 
     let deblur_button = tool_button(
@@ -148,6 +224,40 @@ pub fn expanded<'a>(model: &SidebarModel<'a>, ctx: &ViewContext<'a>) -> Element<
         ));
     }
 
+    let background_button = tool_button(
+        ctx.i18n.tr("image-editor-tool-background"),
+        SidebarMessage::SelectTool(EditorTool::Background),
+        model.active_tool == Some(EditorTool::Background),
+    );
+    scrollable_section = scrollable_section.push(background_button);
+    if model.active_tool == Some(EditorTool::Background) {
+        scrollable_section = scrollable_section.push(background_panel::panel(
+            model.background,
+            model.background_model_status,
+            ctx,
+        ));
+    }
+
+    let denoise_button = tool_button(
+        ctx.i18n.tr("image-editor-tool-denoise"),
+        SidebarMessage::SelectTool(EditorTool::Denoise),
+        model.active_tool == Some(EditorTool::Denoise),
+    );
+    scrollable_section = scrollable_section.push(denoise_button);
+    if model.active_tool == Some(EditorTool::Denoise) {
+        scrollable_section = scrollable_section.push(denoise_panel::panel(
+            model.denoise,
+            model.denoise_model_available,
+            ctx,
+        ));
+    }
+
+    if !model.plugin_tools.is_empty() || !model.plugin_exports.is_empty() {
+        scrollable_section = scrollable_section
+            .push(rule::horizontal(1))
+            .push(plugins_section(model, ctx));
+    }
+
     let scrollable = Scrollable::new(scrollable_section)
         .direction(Direction::Vertical(Scrollbar::new().margin(spacing::XXS)))
         .height(Length::Fill)
@@ -163,6 +273,7 @@ pub fn expanded<'a>(model: &SidebarModel<'a>, ctx: &ViewContext<'a>) -> Element<
             model.has_unsaved_changes,
             model.is_captured_frame,
             model.export_format,
+            model.proof,
             ctx,
         ));
 
@@ -173,13 +284,19 @@ pub fn expanded<'a>(model: &SidebarModel<'a>, ctx: &ViewContext<'a>) -> Element<
         .into()
 }
 
-pub fn collapsed<'a>(is_dark_theme: bool) -> Element<'a, Message> {
-    let toggle_button = button(action_icons::sized(
-        action_icons::navigation::expand_left_panel(is_dark_theme),
-        sizing::ICON_SM,
-    ))
-    .on_press(SidebarMessage::ToggleSidebar.into())
-    .padding(spacing::XXS);
+pub fn collapsed<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
+    // The sidebar sits on the right in right-to-left languages
+    let icon = if ctx.i18n.layout_direction().is_rtl() {
+        action_icons::navigation::expand_right_panel(ctx.is_dark_theme)
+    } else {
+        action_icons::navigation::expand_left_panel(ctx.is_dark_theme)
+    };
+    let toggle_button = tip_cursor(
+        button(action_icons::sized(icon, sizing::ICON_SM))
+            .on_press(SidebarMessage::ToggleSidebar.into())
+            .padding(spacing::XXS),
+        ctx.i18n.tr("image-editor-sidebar-expand-tooltip"),
+    );
 
     container(toggle_button)
         .width(Length::Fixed(60.0))
@@ -190,22 +307,29 @@ pub fn collapsed<'a>(is_dark_theme: bool) -> Element<'a, Message> {
 }
 
 fn header_section<'a>(ctx: &ViewContext<'a>) -> Column<'a, Message> {
-    let toggle_button = button(action_icons::sized(
-        action_icons::navigation::collapse_left_panel(ctx.is_dark_theme),
-        sizing::ICON_SM,
-    ))
-    .on_press(SidebarMessage::ToggleSidebar.into())
-    .padding(spacing::XXS);
+    let direction = ctx.i18n.layout_direction();
+    let icon = if direction.is_rtl() {
+        action_icons::navigation::collapse_right_panel(ctx.is_dark_theme)
+    } else {
+        action_icons::navigation::collapse_left_panel(ctx.is_dark_theme)
+    };
+    let toggle_button = tip_cursor(
+        button(action_icons::sized(icon, sizing::ICON_SM))
+            .on_press(SidebarMessage::ToggleSidebar.into())
+            .padding(spacing::XXS),
+        ctx.i18n.tr("image-editor-sidebar-collapse-tooltip"),
+    );
+    let title = text(ctx.i18n.tr("image-editor-title")).size(typography::TITLE_SM);
+
+    // The toggle button stays on the outer edge of the sidebar
+    let header: Row<'a, Message> = match direction {
+        LayoutDirection::LeftToRight => Row::new().push(toggle_button).push(title),
+        LayoutDirection::RightToLeft => Row::new().push(title).push(toggle_button),
+    };
 
     Column::new()
         .spacing(spacing::XS)
-        .push(
-            Row::new()
-                .spacing(spacing::XS)
-                .align_y(Vertical::Center)
-                .push(toggle_button)
-                .push(text(ctx.i18n.tr("image-editor-title")).size(typography::TITLE_SM)),
-        )
+        .push(header.spacing(spacing::XS).align_y(Vertical::Center))
         .push(rule::horizontal(1))
 }
 
@@ -303,6 +427,49 @@ fn rotate_section<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
     .into()
 }
 
+/// Editor tools and export formats added by plugins.
+fn plugins_section<'a>(model: &SidebarModel<'a>, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+    let mut content = Column::new()
+        .spacing(spacing::XXS)
+        .push(text(ctx.i18n.tr("image-editor-plugins-section-title")).size(typography::BODY));
+
+    for (index, label) in model.plugin_tools.iter().enumerate() {
+        let tool_btn = button(text(label.as_str()).size(typography::BODY))
+            .padding(spacing::XS)
+            .width(Length::Fill);
+        content = content.push(if model.plugin_running {
+            tool_btn.style(button_styles::disabled())
+        } else {
+            tool_btn.on_press(SidebarMessage::RunPluginTool(index).into())
+        });
+    }
+    if model.plugin_running {
+        content = content
+            .push(text(ctx.i18n.tr("image-editor-plugins-running")).size(typography::BODY_SM));
+    }
+
+    for (index, label) in model.plugin_exports.iter().enumerate() {
+        content = content.push(
+            button(
+                text(
+                    ctx.i18n
+                        .tr_with_args("image-editor-plugins-export", &[("format", label.as_str())]),
+                )
+                .size(typography::BODY),
+            )
+            .padding(spacing::XS)
+            .width(Length::Fill)
+            .on_press(SidebarMessage::ExportWithPlugin(index).into()),
+        );
+    }
+
+    container(content)
+        .padding(spacing::SM)
+        .width(Length::Fill)
+        .style(styles::container::panel)
+        .into()
+}
+
 fn flip_section<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
     let icon_size = 28.0;
 
@@ -350,6 +517,7 @@ fn footer_section<'a>(
     has_changes: bool,
     is_captured_frame: bool,
     export_format: ExportFormat,
+    proof: ProofOptions,
     ctx: &ViewContext<'a>,
 ) -> Column<'a, Message> {
     let mut footer = Column::new().spacing(spacing::XS).push(rule::horizontal(1));
@@ -390,8 +558,14 @@ fn footer_section<'a>(
 
         let nav_row = Row::new()
             .spacing(spacing::XS)
-            .push(prev_btn)
-            .push(next_btn);
+            .push(tip_cursor(
+                prev_btn,
+                ctx.i18n.tr("image-editor-navigate-previous-tooltip"),
+            ))
+            .push(tip_cursor(
+                next_btn,
+                ctx.i18n.tr("image-editor-navigate-next-tooltip"),
+            ));
         footer = footer.push(nav_row).push(rule::horizontal(1));
     }
 
@@ -421,6 +595,7 @@ fn footer_section<'a>(
 
     // Export format selector - shown before Save As button
     footer = footer.push(export_format_section(export_format, ctx));
+    footer = footer.push(proof_section(proof, ctx));
 
     // Save As button
     let save_as_btn = button(text(ctx.i18n.tr("image-editor-save-as")).size(typography::BODY_LG))
@@ -437,6 +612,63 @@ fn footer_section<'a>(
     footer
 }
 
+/// Color proof selector: profile embedded when saving, and whether the
+/// pixels are converted into it.
+fn proof_section<'a>(proof: ProofOptions, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+    let proof_label = text(ctx.i18n.tr("image-editor-proof-label")).size(typography::BODY);
+
+    let profile_buttons: Vec<Element<'a, Message>> = std::iter::once(None)
+        .chain(ProofProfile::ALL.into_iter().map(Some))
+        .map(|profile| {
+            let label = match profile {
+                None => ctx.i18n.tr("image-editor-proof-none"),
+                Some(ProofProfile::Srgb) => "sRGB".to_string(),
+                Some(ProofProfile::DisplayP3) => "P3".to_string(),
+                Some(ProofProfile::AdobeRgb) => "Adobe RGB".to_string(),
+            };
+
+            button(text(label).size(typography::BODY_SM))
+                .padding([spacing::XS, spacing::XS])
+                .width(Length::FillPortion(1))
+                .style(if profile == proof.profile {
+                    button_styles::selected
+                } else {
+                    button_styles::unselected
+                })
+                .on_press(SidebarMessage::SetProofProfile(profile).into())
+                .into()
+        })
+        .collect();
+
+    let profile_row = Row::with_children(profile_buttons)
+        .spacing(spacing::XXS)
+        .width(Length::Fill);
+
+    // Conversion only makes sense once a profile is selected
+    let convert_checkbox =
+        checkbox(proof.convert_pixels).label(ctx.i18n.tr("image-editor-proof-convert"));
+    let convert_checkbox = if proof.profile.is_some() {
+        convert_checkbox.on_toggle(|_| SidebarMessage::ToggleProofConversion.into())
+    } else {
+        convert_checkbox
+    };
+
+    container(
+        Column::new()
+            .spacing(spacing::XXS)
+            .push(proof_label)
+            .push(profile_row)
+            .push(tip_cursor(
+                convert_checkbox,
+                ctx.i18n.tr("image-editor-proof-convert-tooltip"),
+            )),
+    )
+    .padding(spacing::SM)
+    .width(Length::Fill)
+    .style(styles::container::panel)
+    .into()
+}
+
 /// Export format selector for Save As operations.
 fn export_format_section<'a>(
     current_format: ExportFormat,
@@ -71,6 +71,7 @@ pub fn panel<'a>(
         .on_input(|value| Message::Sidebar(SidebarMessage::WidthInputChanged(value)))
         .on_submit(Message::Sidebar(SidebarMessage::WidthInputSubmitted))
         .padding(spacing::XXS)
+        .style(styles::text_input::standard)
         .size(typography::BODY)
         .width(Length::Fill);
 
@@ -80,6 +81,7 @@ pub fn panel<'a>(
         .on_input(|value| Message::Sidebar(SidebarMessage::HeightInputChanged(value)))
         .on_submit(Message::Sidebar(SidebarMessage::HeightInputSubmitted))
         .padding(spacing::XXS)
+        .style(styles::text_input::standard)
         .size(typography::BODY)
         .width(Length::Fill);
 
@@ -1,21 +1,41 @@
 // SPDX-License-Identifier: MPL-2.0
 //! Top toolbar for the editor surface.
 
+use crate::ui::accessibility;
 use crate::ui::design_tokens::{spacing, typography};
 use crate::ui::styles;
-use iced::widget::{button, container, Row, Text};
-use iced::{Alignment, Element, Length};
+use iced::widget::{button, container, mouse_area, slider, Row, Space, Text};
+use iced::{mouse, Alignment, Element, Length};
 
 use super::super::{Message, State, ToolbarMessage, ViewContext};
 
+/// Width of the split view slider.
+const COMPARE_SLIDER_WIDTH: f32 = 120.0;
+
 pub struct ToolbarModel {
     pub has_unsaved_changes: bool,
+    pub has_recovery_offer: bool,
+    pub guides_visible: bool,
+    /// Whether the canvas is fitted to the window (otherwise at a manual zoom).
+    pub fit_to_window: bool,
+    /// Zoom the canvas is shown at, in percent.
+    pub zoom_percent: f32,
+    /// Whether "Show original" is held.
+    pub showing_original: bool,
+    /// Divider position of the split before/after view, when it is shown.
+    pub compare_split: Option<u32>,
 }
 
 impl ToolbarModel {
     pub fn from_state(state: &State) -> Self {
         Self {
             has_unsaved_changes: state.has_unsaved_changes(),
+            has_recovery_offer: state.has_recovery_offer(),
+            guides_visible: state.guides_visible,
+            fit_to_window: state.zoom.fit_to_window,
+            zoom_percent: state.canvas_zoom_percent(),
+            showing_original: state.is_showing_original(),
+            compare_split: state.compare_split(),
         }
     }
 }
@@ -24,21 +44,116 @@ pub fn view<'a>(model: &ToolbarModel, ctx: &ViewContext<'a>) -> Element<'a, Mess
     let has_changes = model.has_unsaved_changes;
     let back_label = format!("← {}", ctx.i18n.tr("image-editor-back-to-viewer"));
 
-    let back_btn =
-        button(Text::new(back_label).size(typography::BODY)).padding([spacing::XS, spacing::SM]);
+    let back_btn = button(Text::new(back_label).size(typography::BODY))
+        .padding(accessibility::enlarge([spacing::XS, spacing::SM]));
     let back_btn = if has_changes {
         back_btn
     } else {
         back_btn.on_press(Message::Toolbar(ToolbarMessage::BackToViewer))
     };
 
+    let guides_btn = button(Text::new(ctx.i18n.tr("image-editor-guides")).size(typography::BODY))
+        .on_press(Message::Toolbar(ToolbarMessage::ToggleGuides))
+        .padding(accessibility::enlarge([spacing::XS, spacing::SM]));
+    let guides_btn = if model.guides_visible {
+        guides_btn.style(styles::button::selected)
+    } else {
+        guides_btn.style(button::text)
+    };
+
+    // Fit / 100% toggle next to the current zoom, which the wheel changes freely
+    let fit_btn = button(Text::new(ctx.i18n.tr("image-editor-zoom-fit")).size(typography::BODY))
+        .on_press(Message::Toolbar(ToolbarMessage::ToggleFitZoom))
+        .padding(accessibility::enlarge([spacing::XS, spacing::SM]));
+    let fit_btn = if model.fit_to_window {
+        fit_btn.style(styles::button::selected)
+    } else {
+        fit_btn.style(button::text)
+    };
+    let zoom_label = Text::new(format!("{:.0}%", model.zoom_percent)).size(typography::BODY_SM);
+
+    let mut row = Row::new()
+        .push(back_btn)
+        .push(guides_btn)
+        .push(fit_btn)
+        .push(zoom_label)
+        .push(show_original_button(model.showing_original, ctx));
+
+    let compare_btn =
+        button(Text::new(ctx.i18n.tr("image-editor-compare-split")).size(typography::BODY))
+            .on_press(Message::Toolbar(ToolbarMessage::ToggleSplitCompare))
+            .padding(accessibility::enlarge([spacing::XS, spacing::SM]));
+    row = match model.compare_split {
+        Some(percent) => row.push(compare_btn.style(styles::button::selected)).push(
+            slider(0..=100, percent, |value| {
+                Message::Toolbar(ToolbarMessage::CompareSplitChanged(value))
+            })
+            .step(1u32)
+            .width(Length::Fixed(COMPARE_SLIDER_WIDTH)),
+        ),
+        None => row.push(compare_btn.style(button::text)),
+    };
+
+    let mut row = row
+        .spacing(spacing::XS)
+        .align_y(Alignment::Center)
+        .padding(spacing::XS);
+    if model.has_recovery_offer {
+        row = row
+            .push(Space::new().width(Length::Fill))
+            .push(recovery_offer(ctx));
+    }
+
+    container(row)
+        .width(Length::Fill)
+        .style(styles::editor::toolbar)
+        .into()
+}
+
+/// "Show original" button, showing the source image while the press lasts.
+///
+/// A plain button only reports clicks once released, so the press and
+/// release are read from a mouse area; leaving it ends the comparison too.
+fn show_original_button<'a>(held: bool, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+    let label =
+        container(Text::new(ctx.i18n.tr("image-editor-show-original")).size(typography::BODY))
+            .padding(accessibility::enlarge([spacing::XS, spacing::SM]));
+    let label = if held {
+        label.style(styles::container::panel)
+    } else {
+        label
+    };
+
+    mouse_area(label)
+        .interaction(mouse::Interaction::Pointer)
+        .on_press(Message::Toolbar(ToolbarMessage::ShowOriginal(true)))
+        .on_release(Message::Toolbar(ToolbarMessage::ShowOriginal(false)))
+        .on_exit(Message::Toolbar(ToolbarMessage::ShowOriginal(false)))
+        .into()
+}
+
+/// Prompt to restore or discard edits recovered from a previous session.
+fn recovery_offer<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
+    let restore =
+        button(Text::new(ctx.i18n.tr("image-editor-recovery-restore")).size(typography::BODY_SM))
+            .on_press(Message::Toolbar(ToolbarMessage::RestoreEdits))
+            .padding(spacing::XXS)
+            .style(styles::button::selected);
+    let discard =
+        button(Text::new(ctx.i18n.tr("image-editor-recovery-discard")).size(typography::BODY_SM))
+            .on_press(Message::Toolbar(ToolbarMessage::DiscardRecoveredEdits))
+            .padding(spacing::XXS)
+            .style(button::text);
+
     container(
         Row::new()
-            .push(back_btn)
-            .align_y(Alignment::Center)
-            .padding(spacing::XS),
+            .push(Text::new(ctx.i18n.tr("image-editor-recovery-prompt")).size(typography::BODY_SM))
+            .push(restore)
+            .push(discard)
+            .spacing(spacing::SM)
+            .align_y(Alignment::Center),
     )
-    .width(Length::Fill)
-    .style(styles::editor::toolbar)
+    .padding(spacing::XS)
+    .style(styles::container::panel)
     .into()
 }
@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Keyboard shortcuts that work on every keyboard layout.
+//!
+//! Shortcuts are identified by the Latin character of their key. A key press
+//! matches when it types that character, so letters follow the labels of
+//! AZERTY or QWERTZ keyboards, and punctuation needing Shift or `AltGr` (such
+//! as `.` on AZERTY) still works. Keys typing non-Latin characters (Cyrillic,
+//! Greek, ...) fall back to the character at the same position on a US
+//! keyboard.
+//!
+//! [`KeyboardLayout`] remembers what those keys type, so the help screen can
+//! show the label printed on the key to press.
+
+use iced::keyboard::key::{Code, Physical};
+use iced::keyboard::Key;
+use std::collections::HashMap;
+
+/// Returns the shortcut character of a key press, in lowercase.
+///
+/// `key` is the key without modifiers, `modified_key` the character typed
+/// with Shift or `AltGr` applied, as reported by `KeyPressed` events.
+#[must_use]
+pub fn shortcut_char(key: &Key, modified_key: &Key, physical_key: Physical) -> Option<char> {
+    if let Some(typed) = single_char(modified_key).filter(char::is_ascii_punctuation) {
+        return Some(typed);
+    }
+    if let Some(typed) = single_char(key).filter(char::is_ascii_graphic) {
+        return Some(typed.to_ascii_lowercase());
+    }
+    match physical_key {
+        Physical::Code(code) => us_character(code),
+        Physical::Unidentified(_) => None,
+    }
+}
+
+/// Labels of the keys typing non-Latin characters, learned from key presses.
+#[derive(Debug, Clone, Default)]
+pub struct KeyboardLayout {
+    /// Character typed by the key of each shortcut character, when not Latin.
+    labels: HashMap<char, char>,
+}
+
+impl KeyboardLayout {
+    /// Records what a key press typed.
+    pub fn observe(&mut self, key: &Key, physical_key: Physical) {
+        let Physical::Code(code) = physical_key else {
+            return;
+        };
+        let (Some(shortcut), Some(typed)) = (us_character(code), single_char(key)) else {
+            return;
+        };
+        if typed.is_ascii() {
+            // Back to a Latin layout
+            self.labels.remove(&shortcut);
+        } else {
+            let label = typed.to_uppercase().next().unwrap_or(typed);
+            self.labels.insert(shortcut, label);
+        }
+    }
+
+    /// Returns a shortcut label such as `Shift+R` with the label of each key
+    /// on the current layout appended when it isn't Latin: `Shift+R (К)`.
+    #[must_use]
+    pub fn display_name(&self, shortcut: &str) -> String {
+        let mut display = String::with_capacity(shortcut.len());
+        for token in shortcut.split_inclusive([' ', '+', '/']) {
+            let name = token.trim_end_matches([' ', '+', '/']);
+            display.push_str(name);
+            let mut chars = name.chars();
+            if let (Some(single), None) = (chars.next(), chars.next()) {
+                if let Some(label) = self.labels.get(&single.to_ascii_lowercase()) {
+                    display.push_str(&format!(" ({label})"));
+                }
+            }
+            display.push_str(&token[name.len()..]);
+        }
+        display
+    }
+}
+
+/// Returns the only character of a character key.
+fn single_char(key: &Key) -> Option<char> {
+    let Key::Character(text) = key else {
+        return None;
+    };
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(single), None) => Some(single),
+        _ => None,
+    }
+}
+
+/// Returns the character typed by a key on a US keyboard, without Shift.
+fn us_character(code: Code) -> Option<char> {
+    let character = match code {
+        Code::KeyA => 'a',
+        Code::KeyB => 'b',
+        Code::KeyC => 'c',
+        Code::KeyD => 'd',
+        Code::KeyE => 'e',
+        Code::KeyF => 'f',
+        Code::KeyG => 'g',
+        Code::KeyH => 'h',
+        Code::KeyI => 'i',
+        Code::KeyJ => 'j',
+        Code::KeyK => 'k',
+        Code::KeyL => 'l',
+        Code::KeyM => 'm',
+        Code::KeyN => 'n',
+        Code::KeyO => 'o',
+        Code::KeyP => 'p',
+        Code::KeyQ => 'q',
+        Code::KeyR => 'r',
+        Code::KeyS => 's',
+        Code::KeyT => 't',
+        Code::KeyU => 'u',
+        Code::KeyV => 'v',
+        Code::KeyW => 'w',
+        Code::KeyX => 'x',
+        Code::KeyY => 'y',
+        Code::KeyZ => 'z',
+        Code::Digit0 => '0',
+        Code::Digit1 => '1',
+        Code::Digit2 => '2',
+        Code::Digit3 => '3',
+        Code::Digit4 => '4',
+        Code::Digit5 => '5',
+        Code::Digit6 => '6',
+        Code::Digit7 => '7',
+        Code::Digit8 => '8',
+        Code::Digit9 => '9',
+        Code::Backquote => '`',
+        Code::Minus => '-',
+        Code::Equal => '=',
+        Code::BracketLeft => '[',
+        Code::BracketRight => ']',
+        Code::Backslash => '\\',
+        Code::Semicolon => ';',
+        Code::Quote => '\'',
+        Code::Comma => ',',
+        Code::Period => '.',
+        Code::Slash => '/',
+        _ => return None,
+    };
+    Some(character)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn character(text: &str) -> Key {
+        Key::Character(text.into())
+    }
+
+    #[test]
+    fn letters_follow_the_key_labels() {
+        // AZERTY: the key labeled A sits where QWERTY has Q
+        let pressed = shortcut_char(&character("a"), &character("A"), Physical::Code(Code::KeyQ));
+        assert_eq!(pressed, Some('a'));
+    }
+
+    #[test]
+    fn punctuation_needing_shift_still_matches() {
+        // AZERTY: Shift+; types a period
+        let pressed = shortcut_char(
+            &character(";"),
+            &character("."),
+            Physical::Code(Code::Comma),
+        );
+        assert_eq!(pressed, Some('.'));
+    }
+
+    #[test]
+    fn non_latin_keys_fall_back_to_their_us_position() {
+        // Russian: the key typing к is R on a US keyboard
+        let pressed = shortcut_char(&character("к"), &character("К"), Physical::Code(Code::KeyR));
+        assert_eq!(pressed, Some('r'));
+    }
+
+    #[test]
+    fn display_name_shows_non_latin_labels() {
+        let mut layout = KeyboardLayout::default();
+        assert_eq!(layout.display_name("Shift+R"), "Shift+R");
+
+        layout.observe(&character("к"), Physical::Code(Code::KeyR));
+        assert_eq!(layout.display_name("Shift+R"), "Shift+R (К)");
+        assert_eq!(layout.display_name("R / F11"), "R (К) / F11");
+
+        layout.observe(&character("r"), Physical::Code(Code::KeyR));
+        assert_eq!(layout.display_name("Shift+R"), "Shift+R");
+    }
+}
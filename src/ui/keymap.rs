@@ -0,0 +1,790 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Customizable keyboard shortcuts.
+//!
+//! Each rebindable [`Action`] has a default [`Binding`], the key the viewer
+//! and editor handlers match. A [`Keymap`] holds the bindings the user
+//! changed, saved in the `[shortcuts]` section of the configuration.
+//!
+//! Handlers keep matching the default keys: [`Keymap::translate`] rewrites
+//! a key press bound by the user into the default key press of its action,
+//! and drops presses of default keys whose action moved to another key. The
+//! application runs every keyboard event through it before dispatching, so
+//! user bindings apply to all handlers alike.
+//!
+//! Keys with a fixed role (Escape, arrows, Page Up/Down, F3, F12,
+//! `Ctrl+digit`) can't be bound.
+
+use crate::config::ShortcutsConfig;
+use crate::ui::keyboard_layout;
+use iced::keyboard::key::{Named, NativeCode, Physical};
+use iced::keyboard::{self, Key, Location, Modifiers};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Where a shortcut applies. Actions of a scope can't share a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    /// The viewer, for images and videos.
+    Viewer,
+    /// The image editor.
+    Editor,
+}
+
+/// Group of actions in the settings and help screens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Group {
+    Viewer,
+    Video,
+    Editor,
+}
+
+impl Group {
+    /// All groups, in display order.
+    pub const ALL: [Group; 3] = [Group::Viewer, Group::Video, Group::Editor];
+
+    /// Returns the i18n key of the group title.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            Group::Viewer => "settings-shortcuts-group-viewer",
+            Group::Video => "settings-shortcuts-group-video",
+            Group::Editor => "settings-shortcuts-group-editor",
+        }
+    }
+
+    /// Returns the scope of the actions of this group.
+    #[must_use]
+    pub fn scope(self) -> Scope {
+        match self {
+            Group::Viewer | Group::Video => Scope::Viewer,
+            Group::Editor => Scope::Editor,
+        }
+    }
+}
+
+/// A command that can be bound to a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Action {
+    EnterEditor,
+    ToggleInfoPanel,
+    ToggleFullscreen,
+    RotateClockwise,
+    RotateCounterClockwise,
+    Mirror,
+    NextMatte,
+    PreviousMatte,
+    ToggleAlpha,
+    PlaySequence,
+    ExportView,
+    CopyView,
+    QuickSearch,
+    NextFace,
+    PreviousFace,
+    ToggleFaceOverlay,
+    NextSimilar,
+    PreviousSimilar,
+    ToggleOsd,
+    TogglePixelInspector,
+    ToggleRuler,
+    ToggleGuides,
+    PlayPause,
+    Mute,
+    StepBackward,
+    StepForward,
+    SpeedDown,
+    SpeedUp,
+    LoopStart,
+    LoopEnd,
+    LoopClear,
+    Save,
+    Undo,
+    Redo,
+}
+
+impl Action {
+    /// All actions, in display order.
+    pub const ALL: [Action; 34] = [
+        Action::EnterEditor,
+        Action::ToggleInfoPanel,
+        Action::ToggleFullscreen,
+        Action::RotateClockwise,
+        Action::RotateCounterClockwise,
+        Action::Mirror,
+        Action::NextMatte,
+        Action::PreviousMatte,
+        Action::ToggleAlpha,
+        Action::PlaySequence,
+        Action::ExportView,
+        Action::CopyView,
+        Action::QuickSearch,
+        Action::NextFace,
+        Action::PreviousFace,
+        Action::ToggleFaceOverlay,
+        Action::NextSimilar,
+        Action::PreviousSimilar,
+        Action::ToggleOsd,
+        Action::TogglePixelInspector,
+        Action::ToggleRuler,
+        Action::ToggleGuides,
+        Action::PlayPause,
+        Action::Mute,
+        Action::StepBackward,
+        Action::StepForward,
+        Action::SpeedDown,
+        Action::SpeedUp,
+        Action::LoopStart,
+        Action::LoopEnd,
+        Action::LoopClear,
+        Action::Save,
+        Action::Undo,
+        Action::Redo,
+    ];
+
+    /// Returns the name of the action in the configuration file.
+    #[must_use]
+    pub fn id(self) -> &'static str {
+        match self {
+            Action::EnterEditor => "enter-editor",
+            Action::ToggleInfoPanel => "toggle-info-panel",
+            Action::ToggleFullscreen => "toggle-fullscreen",
+            Action::RotateClockwise => "rotate-clockwise",
+            Action::RotateCounterClockwise => "rotate-counterclockwise",
+            Action::Mirror => "mirror",
+            Action::NextMatte => "next-matte",
+            Action::PreviousMatte => "previous-matte",
+            Action::ToggleAlpha => "toggle-alpha",
+            Action::PlaySequence => "play-sequence",
+            Action::ExportView => "export-view",
+            Action::CopyView => "copy-view",
+            Action::QuickSearch => "quick-search",
+            Action::NextFace => "next-face",
+            Action::PreviousFace => "previous-face",
+            Action::ToggleFaceOverlay => "toggle-face-overlay",
+            Action::NextSimilar => "next-similar",
+            Action::PreviousSimilar => "previous-similar",
+            Action::ToggleOsd => "toggle-osd",
+            Action::TogglePixelInspector => "toggle-pixel-inspector",
+            Action::ToggleRuler => "toggle-ruler",
+            Action::ToggleGuides => "toggle-guides",
+            Action::PlayPause => "play-pause",
+            Action::Mute => "mute",
+            Action::StepBackward => "step-backward",
+            Action::StepForward => "step-forward",
+            Action::SpeedDown => "speed-down",
+            Action::SpeedUp => "speed-up",
+            Action::LoopStart => "loop-start",
+            Action::LoopEnd => "loop-end",
+            Action::LoopClear => "loop-clear",
+            Action::Save => "save",
+            Action::Undo => "undo",
+            Action::Redo => "redo",
+        }
+    }
+
+    /// Returns the action named `id` in the configuration file.
+    #[must_use]
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.id() == id)
+    }
+
+    /// Returns the i18n key describing the action.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            Action::EnterEditor => "shortcut-action-enter-editor",
+            Action::ToggleInfoPanel => "shortcut-action-toggle-info-panel",
+            Action::ToggleFullscreen => "shortcut-action-toggle-fullscreen",
+            Action::RotateClockwise => "shortcut-action-rotate-clockwise",
+            Action::RotateCounterClockwise => "shortcut-action-rotate-counterclockwise",
+            Action::Mirror => "shortcut-action-mirror",
+            Action::NextMatte => "shortcut-action-next-matte",
+            Action::PreviousMatte => "shortcut-action-previous-matte",
+            Action::ToggleAlpha => "shortcut-action-toggle-alpha",
+            Action::PlaySequence => "shortcut-action-play-sequence",
+            Action::ExportView => "shortcut-action-export-view",
+            Action::CopyView => "shortcut-action-copy-view",
+            Action::QuickSearch => "shortcut-action-quick-search",
+            Action::NextFace => "shortcut-action-next-face",
+            Action::PreviousFace => "shortcut-action-previous-face",
+            Action::ToggleFaceOverlay => "shortcut-action-toggle-face-overlay",
+            Action::NextSimilar => "shortcut-action-next-similar",
+            Action::PreviousSimilar => "shortcut-action-previous-similar",
+            Action::ToggleOsd => "shortcut-action-toggle-osd",
+            Action::TogglePixelInspector => "shortcut-action-toggle-pixel-inspector",
+            Action::ToggleRuler => "shortcut-action-toggle-ruler",
+            Action::ToggleGuides => "shortcut-action-toggle-guides",
+            Action::PlayPause => "shortcut-action-play-pause",
+            Action::Mute => "shortcut-action-mute",
+            Action::StepBackward => "shortcut-action-step-backward",
+            Action::StepForward => "shortcut-action-step-forward",
+            Action::SpeedDown => "shortcut-action-speed-down",
+            Action::SpeedUp => "shortcut-action-speed-up",
+            Action::LoopStart => "shortcut-action-loop-start",
+            Action::LoopEnd => "shortcut-action-loop-end",
+            Action::LoopClear => "shortcut-action-loop-clear",
+            Action::Save => "shortcut-action-save",
+            Action::Undo => "shortcut-action-undo",
+            Action::Redo => "shortcut-action-redo",
+        }
+    }
+
+    /// Returns the group the action is listed in.
+    #[must_use]
+    pub fn group(self) -> Group {
+        match self {
+            Action::PlayPause
+            | Action::Mute
+            | Action::StepBackward
+            | Action::StepForward
+            | Action::SpeedDown
+            | Action::SpeedUp
+            | Action::LoopStart
+            | Action::LoopEnd
+            | Action::LoopClear => Group::Video,
+            Action::Save | Action::Undo | Action::Redo => Group::Editor,
+            _ => Group::Viewer,
+        }
+    }
+
+    /// Returns the key the handlers match for this action.
+    #[must_use]
+    pub fn default_binding(self) -> Binding {
+        let key = |c| Binding::new(BindingKey::Char(c));
+        match self {
+            Action::EnterEditor => key('e'),
+            Action::ToggleInfoPanel => key('i'),
+            Action::ToggleFullscreen => Binding::new(BindingKey::Named(Named::F11)),
+            Action::RotateClockwise => key('r'),
+            Action::RotateCounterClockwise => key('r').with_shift(),
+            Action::Mirror => key('h'),
+            Action::NextMatte => key('b'),
+            Action::PreviousMatte => key('b').with_shift(),
+            Action::ToggleAlpha => key('a'),
+            Action::PlaySequence => key('s'),
+            Action::ExportView => key('e').with_ctrl().with_shift(),
+            Action::CopyView => key('c').with_ctrl().with_shift(),
+            Action::QuickSearch => key('f').with_ctrl(),
+            Action::NextFace => key('f'),
+            Action::PreviousFace => key('f').with_shift(),
+            Action::ToggleFaceOverlay => key('d'),
+            Action::NextSimilar => key('g'),
+            Action::PreviousSimilar => key('g').with_shift(),
+            Action::ToggleOsd => key('o'),
+            Action::TogglePixelInspector => key('p'),
+            Action::ToggleRuler => key('u'),
+            Action::ToggleGuides => key('k'),
+            Action::PlayPause => Binding::new(BindingKey::Named(Named::Space)),
+            Action::Mute => key('m'),
+            Action::StepBackward => key(','),
+            Action::StepForward => key('.'),
+            Action::SpeedDown => key('j'),
+            Action::SpeedUp => key('l'),
+            Action::LoopStart => key('['),
+            Action::LoopEnd => key(']'),
+            Action::LoopClear => key('\\'),
+            Action::Save => key('s').with_ctrl(),
+            Action::Undo => key('z').with_ctrl(),
+            Action::Redo => key('y').with_ctrl(),
+        }
+    }
+}
+
+/// The key of a binding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BindingKey {
+    /// A character key, by its layout-independent shortcut character.
+    Char(char),
+    /// A named key such as Space or F5.
+    Named(Named),
+}
+
+/// Named keys that can be bound, with their names in the configuration.
+///
+/// Esc and Tab are named here so they display, but they are reserved: see
+/// [`Binding::is_reserved`].
+const BINDABLE_NAMED_KEYS: [(Named, &str); 19] = [
+    (Named::Space, "Space"),
+    (Named::Enter, "Enter"),
+    (Named::Tab, "Tab"),
+    (Named::Backspace, "Backspace"),
+    (Named::Delete, "Delete"),
+    (Named::Insert, "Insert"),
+    (Named::Home, "Home"),
+    (Named::End, "End"),
+    (Named::F1, "F1"),
+    (Named::F2, "F2"),
+    (Named::F4, "F4"),
+    (Named::F5, "F5"),
+    (Named::F6, "F6"),
+    (Named::F7, "F7"),
+    (Named::F8, "F8"),
+    (Named::F9, "F9"),
+    (Named::F10, "F10"),
+    (Named::F11, "F11"),
+    (Named::Escape, "Esc"),
+];
+
+/// A key with modifiers, such as `Ctrl+Shift+E`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Binding {
+    pub key: BindingKey,
+    /// Ctrl, or Cmd on macOS.
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl Binding {
+    /// Creates a binding of `key` without modifiers.
+    #[must_use]
+    pub fn new(key: BindingKey) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    #[must_use]
+    pub fn with_shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Returns the binding of a key press, or `None` for keys that can't be
+    /// bound, such as modifiers alone.
+    ///
+    /// Shift is ignored for punctuation, which some layouts type with Shift.
+    #[must_use]
+    pub fn from_key_press(
+        key: &Key,
+        modified_key: &Key,
+        physical_key: Physical,
+        modifiers: Modifiers,
+    ) -> Option<Self> {
+        let key = match key {
+            Key::Named(
+                Named::Shift
+                | Named::Control
+                | Named::Alt
+                | Named::AltGraph
+                | Named::Super
+                | Named::Meta
+                | Named::Hyper
+                | Named::CapsLock
+                | Named::NumLock
+                | Named::Fn,
+            ) => return None,
+            Key::Named(named) => BindingKey::Named(*named),
+            _ => BindingKey::Char(keyboard_layout::shortcut_char(
+                key,
+                modified_key,
+                physical_key,
+            )?),
+        };
+        let shift = match key {
+            BindingKey::Char(c) => modifiers.shift() && !c.is_ascii_punctuation(),
+            BindingKey::Named(_) => modifiers.shift(),
+        };
+        Some(Self {
+            key,
+            ctrl: modifiers.command(),
+            shift,
+            alt: modifiers.alt(),
+        })
+    }
+
+    /// Returns whether this is Escape without modifiers, which cancels the
+    /// capture of a new binding.
+    #[must_use]
+    pub fn is_cancel(&self) -> bool {
+        *self == Binding::new(BindingKey::Named(Named::Escape))
+    }
+
+    /// Returns whether the key has a fixed role and can't be bound.
+    #[must_use]
+    pub fn is_reserved(&self) -> bool {
+        match self.key {
+            // Esc cancels, and Tab moves the keyboard focus before the
+            // keymap sees it
+            BindingKey::Named(named) => {
+                matches!(named, Named::Escape | Named::Tab)
+                    || !BINDABLE_NAMED_KEYS
+                        .iter()
+                        .any(|(bindable, _)| *bindable == named)
+            }
+            // Ctrl+digit switches workspace layouts
+            BindingKey::Char(c) => self.ctrl && c.is_ascii_digit(),
+        }
+    }
+
+    /// Returns a key press the handlers recognize as this binding.
+    fn key_press(self, location: Location, repeat: bool) -> keyboard::Event {
+        let (key, modified_key) = match self.key {
+            BindingKey::Char(c) => {
+                let typed = if self.shift {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                };
+                (
+                    Key::Character(c.to_string().into()),
+                    Key::Character(typed.to_string().into()),
+                )
+            }
+            BindingKey::Named(named) => (Key::Named(named), Key::Named(named)),
+        };
+        let mut modifiers = Modifiers::empty();
+        if self.ctrl {
+            modifiers |= Modifiers::COMMAND;
+        }
+        if self.shift {
+            modifiers |= Modifiers::SHIFT;
+        }
+        if self.alt {
+            modifiers |= Modifiers::ALT;
+        }
+        keyboard::Event::KeyPressed {
+            key,
+            modified_key,
+            physical_key: Physical::Unidentified(NativeCode::Unidentified),
+            location,
+            modifiers,
+            text: None,
+            repeat,
+        }
+    }
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            f.write_str("Ctrl+")?;
+        }
+        if self.alt {
+            f.write_str("Alt+")?;
+        }
+        if self.shift {
+            f.write_str("Shift+")?;
+        }
+        match self.key {
+            BindingKey::Char(c) => write!(f, "{}", c.to_ascii_uppercase()),
+            BindingKey::Named(named) => {
+                let name = BINDABLE_NAMED_KEYS
+                    .iter()
+                    .find(|(bindable, _)| *bindable == named)
+                    .map_or("?", |(_, name)| name);
+                f.write_str(name)
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for Binding {
+    type Err = ();
+
+    /// Parses bindings written like `Ctrl+Shift+E`, `F5` or `.`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (modifiers, key) = match text.trim().rsplit_once('+') {
+            // `Ctrl++` binds the plus key
+            Some((modifiers, "")) => (modifiers.strip_suffix('+').unwrap_or(modifiers), "+"),
+            Some((modifiers, key)) => (modifiers, key),
+            None => ("", text.trim()),
+        };
+        let mut chars = key.chars();
+        let key = match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii_graphic() => BindingKey::Char(c.to_ascii_lowercase()),
+            _ => BINDABLE_NAMED_KEYS
+                .iter()
+                .find(|(_, name)| name.eq_ignore_ascii_case(key))
+                .map(|(named, _)| BindingKey::Named(*named))
+                .ok_or(())?,
+        };
+        let mut binding = Binding::new(key);
+        for modifier in modifiers.split('+').filter(|m| !m.is_empty()) {
+            match modifier.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "cmd" => binding.ctrl = true,
+                "shift" => binding.shift = true,
+                "alt" => binding.alt = true,
+                _ => return Err(()),
+            }
+        }
+        Ok(binding)
+    }
+}
+
+/// Why a binding was refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Conflict {
+    /// The key has a fixed role.
+    Reserved,
+    /// Another action of the same scope uses the key.
+    Action(Action),
+}
+
+/// The shortcuts of all actions, with the user's changes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Keymap {
+    /// Bindings differing from the defaults.
+    overrides: BTreeMap<Action, Binding>,
+}
+
+impl Keymap {
+    /// Reads the user's bindings. Unknown actions, invalid keys and
+    /// conflicting bindings are skipped.
+    #[must_use]
+    pub fn from_config(config: &ShortcutsConfig) -> Self {
+        let mut keymap = Self::default();
+        for (id, text) in &config.bindings {
+            if let (Some(action), Ok(binding)) = (Action::from_id(id), text.parse()) {
+                let _ = keymap.rebind(action, binding);
+            }
+        }
+        keymap
+    }
+
+    /// Returns the configuration section holding the user's bindings.
+    #[must_use]
+    pub fn to_config(&self) -> ShortcutsConfig {
+        ShortcutsConfig {
+            bindings: self
+                .overrides
+                .iter()
+                .map(|(action, binding)| (action.id().to_string(), binding.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Returns the key of `action`.
+    #[must_use]
+    pub fn binding(&self, action: Action) -> Binding {
+        self.overrides
+            .get(&action)
+            .copied()
+            .unwrap_or_else(|| action.default_binding())
+    }
+
+    /// Returns the label of the key of `action`, such as `Shift+R`.
+    #[must_use]
+    pub fn label(&self, action: Action) -> String {
+        self.binding(action).to_string()
+    }
+
+    /// Returns whether any binding differs from the defaults.
+    #[must_use]
+    pub fn is_customized(&self) -> bool {
+        !self.overrides.is_empty()
+    }
+
+    /// Returns why `action` can't use `binding`, if it can't.
+    #[must_use]
+    pub fn conflict(&self, action: Action, binding: Binding) -> Option<Conflict> {
+        if binding.is_reserved() {
+            return Some(Conflict::Reserved);
+        }
+        let scope = action.group().scope();
+        Action::ALL
+            .into_iter()
+            .filter(|other| *other != action && other.group().scope() == scope)
+            .find(|other| self.binding(*other) == binding)
+            .map(Conflict::Action)
+    }
+
+    /// Binds `action` to `binding`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the conflict if the key is reserved or already used.
+    pub fn rebind(&mut self, action: Action, binding: Binding) -> Result<(), Conflict> {
+        if let Some(conflict) = self.conflict(action, binding) {
+            return Err(conflict);
+        }
+        if binding == action.default_binding() {
+            self.overrides.remove(&action);
+        } else {
+            self.overrides.insert(action, binding);
+        }
+        Ok(())
+    }
+
+    /// Restores the default bindings.
+    pub fn reset(&mut self) {
+        self.overrides.clear();
+    }
+
+    /// Rewrites a keyboard event of `scope` for the handlers, which match the
+    /// default bindings. Returns `None` when the event must be dropped.
+    #[must_use]
+    pub fn translate(&self, scope: Scope, event: &keyboard::Event) -> Option<keyboard::Event> {
+        let keyboard::Event::KeyPressed {
+            key,
+            modified_key,
+            physical_key,
+            location,
+            modifiers,
+            repeat,
+            ..
+        } = event
+        else {
+            return Some(event.clone());
+        };
+        if self.overrides.is_empty() {
+            return Some(event.clone());
+        }
+        let Some(pressed) = Binding::from_key_press(key, modified_key, *physical_key, *modifiers)
+        else {
+            return Some(event.clone());
+        };
+
+        let mut actions = Action::ALL
+            .into_iter()
+            .filter(|action| action.group().scope() == scope);
+        if let Some(action) = actions
+            .clone()
+            .find(|action| self.binding(*action) == pressed)
+        {
+            let default = action.default_binding();
+            return Some(if default == pressed {
+                event.clone()
+            } else {
+                default.key_press(*location, *repeat)
+            });
+        }
+        // The default key of an action bound elsewhere does nothing
+        if actions.any(|action| action.default_binding() == pressed) {
+            return None;
+        }
+        Some(event.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::keyboard::key::Code;
+
+    fn press(text: &str, modifiers: Modifiers) -> keyboard::Event {
+        keyboard::Event::KeyPressed {
+            key: Key::Character(text.into()),
+            modified_key: Key::Character(text.into()),
+            physical_key: Physical::Unidentified(NativeCode::Unidentified),
+            location: Location::Standard,
+            modifiers,
+            text: None,
+            repeat: false,
+        }
+    }
+
+    fn shortcut(event: &keyboard::Event) -> Option<(char, Modifiers)> {
+        match event {
+            keyboard::Event::KeyPressed {
+                key,
+                modified_key,
+                physical_key,
+                modifiers,
+                ..
+            } => keyboard_layout::shortcut_char(key, modified_key, *physical_key)
+                .map(|c| (c, *modifiers)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn bindings_round_trip_through_text() {
+        for text in ["Ctrl+Shift+E", "F5", ".", "Space", "Alt+K", "Ctrl++"] {
+            let binding: Binding = text.parse().expect("valid binding");
+            assert_eq!(binding.to_string(), text);
+        }
+        assert!("Hyper+K".parse::<Binding>().is_err());
+        assert!("Banana".parse::<Binding>().is_err());
+    }
+
+    #[test]
+    fn default_keymap_passes_events_through() {
+        let keymap = Keymap::default();
+        let event = press("r", Modifiers::empty());
+        assert_eq!(keymap.translate(Scope::Viewer, &event), Some(event));
+    }
+
+    #[test]
+    fn user_binding_triggers_the_default_key() {
+        let mut keymap = Keymap::default();
+        keymap
+            .rebind(Action::RotateClockwise, "T".parse().expect("binding"))
+            .expect("free key");
+
+        let translated = keymap
+            .translate(Scope::Viewer, &press("t", Modifiers::empty()))
+            .expect("bound key");
+        assert_eq!(shortcut(&translated), Some(('r', Modifiers::empty())));
+
+        // The old key no longer rotates
+        assert_eq!(
+            keymap.translate(Scope::Viewer, &press("r", Modifiers::empty())),
+            None
+        );
+        // Shift+R still rotates the other way
+        assert!(keymap
+            .translate(Scope::Viewer, &press("r", Modifiers::SHIFT))
+            .is_some());
+    }
+
+    #[test]
+    fn conflicts_are_detected_per_scope() {
+        let mut keymap = Keymap::default();
+        let mirror_key = Action::Mirror.default_binding();
+        assert_eq!(
+            keymap.rebind(Action::Mute, mirror_key),
+            Err(Conflict::Action(Action::Mirror))
+        );
+        // The editor doesn't use H
+        assert_eq!(keymap.rebind(Action::Undo, mirror_key), Ok(()));
+        assert_eq!(
+            keymap.rebind(Action::Mute, "Esc".parse().expect("binding")),
+            Err(Conflict::Reserved)
+        );
+        for focus_key in ["Tab", "Shift+Tab"] {
+            assert_eq!(
+                keymap.rebind(Action::Mute, focus_key.parse().expect("binding")),
+                Err(Conflict::Reserved)
+            );
+        }
+        let layout_slot = Binding::from_key_press(
+            &Key::Character("1".into()),
+            &Key::Character("1".into()),
+            Physical::Code(Code::Digit1),
+            Modifiers::COMMAND,
+        )
+        .expect("binding");
+        assert_eq!(
+            keymap.rebind(Action::Mute, layout_slot),
+            Err(Conflict::Reserved)
+        );
+    }
+
+    #[test]
+    fn config_keeps_only_changed_bindings() {
+        let mut keymap = Keymap::default();
+        keymap
+            .rebind(Action::Save, "Ctrl+W".parse().expect("binding"))
+            .expect("free key");
+        keymap
+            .rebind(Action::Undo, Action::Undo.default_binding())
+            .expect("own key");
+
+        let config = keymap.to_config();
+        assert_eq!(config.bindings.len(), 1);
+        assert_eq!(
+            config.bindings.get("save").map(String::as_str),
+            Some("Ctrl+W")
+        );
+        assert_eq!(Keymap::from_config(&config), keymap);
+    }
+}
@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Log viewer screen, opened from the about screen.
+//!
+//! Shows the latest lines of the log files with buttons to open the log
+//! folder and to copy a diagnostic report for bug reports. Lines are read
+//! when the screen opens and on refresh, not watched.
+
+use crate::app::logging;
+use crate::i18n::fluent::I18n;
+use crate::ui::design_tokens::{radius, spacing, typography};
+use iced::{
+    alignment::Horizontal,
+    widget::{button, container, scrollable, text, Column, Row, Text},
+    Border, Element, Font, Length, Theme,
+};
+use std::path::PathBuf;
+
+/// Lines of the log files, as last read.
+#[derive(Debug, Default)]
+pub struct State {
+    dir: Option<PathBuf>,
+    lines: Vec<String>,
+}
+
+impl State {
+    /// Reads the latest log lines.
+    #[must_use]
+    pub fn load() -> Self {
+        let dir = logging::log_dir();
+        let lines = dir
+            .as_deref()
+            .map(|dir| logging::read_recent(dir, logging::VIEWED_LINES))
+            .unwrap_or_default();
+        Self { dir, lines }
+    }
+
+    /// Returns the log folder, if known.
+    #[must_use]
+    pub fn dir(&self) -> Option<&std::path::Path> {
+        self.dir.as_deref()
+    }
+}
+
+/// Contextual data needed to render the log viewer.
+pub struct ViewContext<'a> {
+    pub i18n: &'a I18n,
+    pub state: &'a State,
+}
+
+/// Messages emitted by the log viewer.
+#[derive(Debug, Clone)]
+pub enum Message {
+    BackToViewer,
+    Refresh,
+    OpenFolder,
+    CopyDiagnosticReport,
+}
+
+/// Events propagated to the parent application.
+#[derive(Debug, Clone)]
+pub enum Event {
+    BackToViewer,
+    Refresh,
+    OpenFolder,
+    CopyDiagnosticReport,
+}
+
+/// Process a log viewer message and return the corresponding event.
+#[must_use]
+pub fn update(message: &Message) -> Event {
+    match message {
+        Message::BackToViewer => Event::BackToViewer,
+        Message::Refresh => Event::Refresh,
+        Message::OpenFolder => Event::OpenFolder,
+        Message::CopyDiagnosticReport => Event::CopyDiagnosticReport,
+    }
+}
+
+/// Render the log viewer.
+#[must_use]
+#[allow(clippy::needless_pass_by_value)] // ViewContext is small and consumed
+pub fn view(ctx: ViewContext<'_>) -> Element<'_, Message> {
+    let back_button = button(
+        text(format!("← {}", ctx.i18n.tr("about-back-to-viewer-button"))).size(typography::BODY),
+    )
+    .on_press(Message::BackToViewer);
+
+    let title = Text::new(ctx.i18n.tr("logs-title")).size(typography::TITLE_LG);
+
+    let location = ctx.state.dir.as_ref().map_or_else(
+        || ctx.i18n.tr("logs-folder-unavailable"),
+        |dir| dir.display().to_string(),
+    );
+
+    let actions = Row::new()
+        .spacing(spacing::SM)
+        .push(button(Text::new(ctx.i18n.tr("logs-refresh"))).on_press(Message::Refresh))
+        .push(
+            button(Text::new(ctx.i18n.tr("logs-open-folder")))
+                .on_press_maybe(ctx.state.dir.is_some().then_some(Message::OpenFolder)),
+        )
+        .push(
+            button(Text::new(ctx.i18n.tr("logs-copy-report")))
+                .on_press(Message::CopyDiagnosticReport),
+        );
+
+    let log_text: Element<'_, Message> = if ctx.state.lines.is_empty() {
+        Text::new(ctx.i18n.tr("logs-empty"))
+            .size(typography::BODY)
+            .into()
+    } else {
+        Text::new(ctx.state.lines.join("\n"))
+            .size(typography::CAPTION)
+            .font(Font::MONOSPACE)
+            .into()
+    };
+
+    let log_panel = container(
+        scrollable(container(log_text).padding(spacing::SM).width(Length::Fill))
+            .anchor_bottom()
+            .height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .style(|theme: &Theme| container::Style {
+        background: Some(theme.extended_palette().background.weak.color.into()),
+        border: Border {
+            radius: radius::MD.into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+
+    Column::new()
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .spacing(spacing::MD)
+        .align_x(Horizontal::Left)
+        .padding(spacing::MD)
+        .push(back_button)
+        .push(title)
+        .push(Text::new(location).size(typography::BODY))
+        .push(actions)
+        .push(log_panel)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logs_view_renders() {
+        let i18n = I18n::default();
+        let state = State {
+            dir: Some(PathBuf::from("/tmp/logs")),
+            lines: vec!["INFO started".to_string()],
+        };
+        let _element = view(ViewContext {
+            i18n: &i18n,
+            state: &state,
+        });
+        let _empty = view(ViewContext {
+            i18n: &i18n,
+            state: &State::default(),
+        });
+    }
+}
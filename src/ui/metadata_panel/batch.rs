@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: MPL-2.0
+//! State of the "Batch edit" section, which applies the same metadata changes
+//! to every file of the current folder (or of the active filter).
+
+use crate::media::metadata_batch::BatchMetadataChanges;
+
+/// Messages emitted by the batch edit section.
+#[derive(Debug, Clone)]
+pub enum BatchMessage {
+    /// Expand or collapse the section.
+    Toggle,
+    /// The creator input has changed.
+    CreatorChanged(String),
+    /// The copyright input has changed.
+    CopyrightChanged(String),
+    /// The keywords-to-add input (comma-separated) has changed.
+    KeywordsChanged(String),
+    /// The "Remove GPS location" checkbox was toggled.
+    StripGpsToggled(bool),
+    /// Ask for confirmation before writing.
+    Apply,
+    /// Confirm and start writing.
+    Confirm,
+    /// Go back to editing the changes.
+    CancelConfirm,
+}
+
+/// UI state of the batch edit section, owned by the application so it
+/// persists while navigating between files.
+#[derive(Debug, Clone, Default)]
+pub struct BatchEditState {
+    /// Whether the section is expanded.
+    pub expanded: bool,
+    pub creator: String,
+    pub copyright: String,
+    /// Comma-separated keywords added to each file.
+    pub keywords: String,
+    pub strip_gps: bool,
+    /// Waiting for the user to confirm the write.
+    pub confirming: bool,
+    /// A batch is being written in the background.
+    pub running: bool,
+}
+
+impl BatchEditState {
+    /// Applies a message and returns true when the batch should start.
+    ///
+    /// Editing any input cancels a pending confirmation so the user always
+    /// confirms the changes that will actually be written.
+    pub fn update(&mut self, message: BatchMessage) -> bool {
+        match message {
+            BatchMessage::Toggle => {
+                self.expanded = !self.expanded;
+                self.confirming = false;
+            }
+            BatchMessage::CreatorChanged(value) => {
+                self.creator = value;
+                self.confirming = false;
+            }
+            BatchMessage::CopyrightChanged(value) => {
+                self.copyright = value;
+                self.confirming = false;
+            }
+            BatchMessage::KeywordsChanged(value) => {
+                self.keywords = value;
+                self.confirming = false;
+            }
+            BatchMessage::StripGpsToggled(value) => {
+                self.strip_gps = value;
+                self.confirming = false;
+            }
+            BatchMessage::Apply => {
+                self.confirming = !self.running && !self.changes().is_empty();
+            }
+            BatchMessage::CancelConfirm => self.confirming = false,
+            BatchMessage::Confirm => {
+                if self.confirming && !self.running {
+                    self.confirming = false;
+                    self.running = true;
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Returns the changes described by the inputs.
+    #[must_use]
+    pub fn changes(&self) -> BatchMetadataChanges {
+        BatchMetadataChanges {
+            creator: self.creator.trim().to_string(),
+            copyright: self.copyright.trim().to_string(),
+            add_keywords: self
+                .keywords
+                .split(',')
+                .map(str::trim)
+                .filter(|k| !k.is_empty())
+                .map(String::from)
+                .collect(),
+            strip_gps: self.strip_gps,
+        }
+    }
+
+    /// Marks the running batch as finished.
+    pub fn finish(&mut self) {
+        self.running = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_requires_changes_and_confirmation() {
+        let mut state = BatchEditState::default();
+        assert!(!state.update(BatchMessage::Apply));
+        assert!(!state.confirming, "nothing to apply");
+
+        state.update(BatchMessage::KeywordsChanged("sea, , summer ".to_string()));
+        state.update(BatchMessage::Apply);
+        assert!(state.confirming);
+        assert_eq!(state.changes().add_keywords, vec!["sea", "summer"]);
+
+        assert!(state.update(BatchMessage::Confirm));
+        assert!(state.running);
+        assert!(!state.update(BatchMessage::Apply), "already running");
+
+        state.finish();
+        assert!(!state.running);
+    }
+
+    #[test]
+    fn editing_cancels_pending_confirmation() {
+        let mut state = BatchEditState::default();
+        state.update(BatchMessage::StripGpsToggled(true));
+        state.update(BatchMessage::Apply);
+        assert!(state.confirming);
+
+        state.update(BatchMessage::CreatorChanged("Bob".to_string()));
+        assert!(!state.confirming);
+        assert!(!state.update(BatchMessage::Confirm));
+    }
+}
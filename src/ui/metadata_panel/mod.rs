@@ -5,14 +5,18 @@
 //! GPS coordinates, etc.) and codec/format information for videos. It supports
 //! both view mode (read-only) and edit mode (for modifying EXIF metadata).
 
+pub mod batch;
 pub mod state;
 pub mod view;
 
+pub use batch::{BatchEditState, BatchMessage};
 pub use state::MetadataEditorState;
 pub use view::{ViewContext, PANEL_WIDTH};
 
 use crate::i18n::fluent::I18n;
-use crate::media::metadata::MediaMetadata;
+use crate::media::decoders::MediaDecoder;
+use crate::media::metadata::{map_url, MediaMetadata};
+use crate::media::shoot_stats::ShootStats;
 use std::path::{Path, PathBuf};
 
 /// Identifies which metadata field is being edited.
@@ -116,6 +120,30 @@ pub enum Message {
     ShowField(MetadataField),
     /// Remove/hide a field from the editor (clears value).
     RemoveField(MetadataField),
+    /// Open the GPS location in an online map.
+    OpenInMap { latitude: f64, longitude: f64 },
+    /// Expand or collapse the "All tags" browser.
+    ToggleAllTags,
+    /// The "All tags" filter text has changed.
+    TagFilterChanged(String),
+    /// Copy a tag value to the clipboard.
+    CopyTagValue(String),
+    /// The pending keyword input has changed.
+    KeywordInputChanged(String),
+    /// Add the pending keyword input as keyword chips.
+    KeywordSubmitted,
+    /// Remove a keyword chip.
+    KeywordRemoved(String),
+    /// A message from the batch edit section.
+    Batch(BatchMessage),
+    /// Remove all metadata from the current file (asks for confirmation).
+    StripAllMetadata,
+    /// Expand or collapse the folder statistics section.
+    ToggleShootStats,
+    /// Recompute the folder statistics.
+    RefreshShootStats,
+    /// Export metadata to a JSON or CSV file.
+    ExportMetadata(ExportScope),
 }
 
 /// Events propagated to the parent application.
@@ -133,6 +161,65 @@ pub enum Event {
     SaveRequested(PathBuf),
     /// Request to open Save As dialog.
     SaveAsRequested,
+    /// Request to open a URL in the system browser.
+    OpenUrlRequested(String),
+    /// The "All tags" browser was expanded or collapsed.
+    AllTagsToggled,
+    /// The "All tags" filter text has changed.
+    TagFilterChanged(String),
+    /// Request to copy text to the clipboard.
+    CopyToClipboard(String),
+    /// A batch edit message for the app-owned [`BatchEditState`].
+    Batch(BatchMessage),
+    /// Request to remove all metadata from the current file.
+    StripMetadataRequested,
+    /// The folder statistics section was expanded or collapsed.
+    ShootStatsToggled,
+    /// Request to recompute the folder statistics.
+    ShootStatsRefreshRequested,
+    /// Request to export metadata (app should ask where to save it).
+    ExportMetadataRequested(ExportScope),
+}
+
+/// Files covered by a metadata export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportScope {
+    /// The file currently displayed.
+    CurrentFile,
+    /// Every media file of the current folder.
+    Folder,
+}
+
+/// UI state of the "All tags" browser, owned by the application so it
+/// persists while navigating between files.
+#[derive(Debug, Clone, Default)]
+pub struct TagBrowserState {
+    /// Whether the tag list is expanded.
+    pub expanded: bool,
+    /// Case-insensitive filter applied to group, name, and value.
+    pub filter: String,
+}
+
+/// UI state of the folder statistics section, owned by the application so
+/// the (costly) statistics are not recomputed while navigating in a folder.
+#[derive(Debug, Clone, Default)]
+pub struct ShootStatsState {
+    /// Whether the section is expanded.
+    pub expanded: bool,
+    /// Statistics are being computed in the background.
+    pub loading: bool,
+    /// Last computed statistics.
+    pub stats: Option<ShootStats>,
+    /// Directory the statistics were computed for.
+    pub directory: Option<PathBuf>,
+}
+
+impl ShootStatsState {
+    /// Returns true if the statistics are missing or belong to another directory.
+    #[must_use]
+    pub fn is_stale(&self, directory: &Path) -> bool {
+        self.stats.is_none() || self.directory.as_deref() != Some(directory)
+    }
 }
 
 /// Extended context for rendering the metadata panel with edit support.
@@ -148,6 +235,16 @@ pub struct PanelContext<'a> {
     pub editor_state: Option<&'a MetadataEditorState>,
     /// Whether the media is an image (edit supported) or video (edit not supported).
     pub is_image: bool,
+    /// State of the "All tags" browser (collapsed when `None`).
+    pub tag_browser: Option<&'a TagBrowserState>,
+    /// State of the batch edit section (hidden when `None`).
+    pub batch: Option<&'a BatchEditState>,
+    /// Number of files a batch edit would be applied to.
+    pub batch_file_count: usize,
+    /// State of the folder statistics section (hidden when `None`).
+    pub shoot_stats: Option<&'a ShootStatsState>,
+    /// Backend that decoded the current media (hidden when `None`).
+    pub decoder: Option<MediaDecoder>,
 }
 
 /// Process a metadata panel message and return the corresponding event (new API).
@@ -168,13 +265,22 @@ pub fn update_with_state(
             Event::None
         }
         Message::Save => {
+            // A keyword typed but not yet submitted is still part of the edit
+            if let Some(editor) = state {
+                editor.commit_keyword_input();
+            }
             if let Some(path) = current_path {
                 Event::SaveRequested(path.to_path_buf())
             } else {
                 Event::None
             }
         }
-        Message::SaveAs => Event::SaveAsRequested,
+        Message::SaveAs => {
+            if let Some(editor) = state {
+                editor.commit_keyword_input();
+            }
+            Event::SaveAsRequested
+        }
         Message::ShowField(field) => {
             if let Some(editor) = state {
                 editor.show_field(field);
@@ -187,6 +293,36 @@ pub fn update_with_state(
             }
             Event::None
         }
+        Message::OpenInMap {
+            latitude,
+            longitude,
+        } => Event::OpenUrlRequested(map_url(latitude, longitude)),
+        Message::ToggleAllTags => Event::AllTagsToggled,
+        Message::TagFilterChanged(filter) => Event::TagFilterChanged(filter),
+        Message::CopyTagValue(value) => Event::CopyToClipboard(value),
+        Message::KeywordInputChanged(input) => {
+            if let Some(editor) = state {
+                editor.keyword_input = input;
+            }
+            Event::None
+        }
+        Message::KeywordSubmitted => {
+            if let Some(editor) = state {
+                editor.commit_keyword_input();
+            }
+            Event::None
+        }
+        Message::KeywordRemoved(keyword) => {
+            if let Some(editor) = state {
+                editor.remove_keyword(&keyword);
+            }
+            Event::None
+        }
+        Message::Batch(message) => Event::Batch(message),
+        Message::StripAllMetadata => Event::StripMetadataRequested,
+        Message::ToggleShootStats => Event::ShootStatsToggled,
+        Message::RefreshShootStats => Event::ShootStatsRefreshRequested,
+        Message::ExportMetadata(scope) => Event::ExportMetadataRequested(scope),
     }
 }
 
@@ -199,10 +335,25 @@ pub fn update(message: &Message) -> Event {
         Message::EnterEditMode => Event::EnterEditModeRequested,
         Message::ExitEditMode => Event::ExitEditModeRequested,
         Message::SaveAs => Event::SaveAsRequested,
+        Message::OpenInMap {
+            latitude,
+            longitude,
+        } => Event::OpenUrlRequested(map_url(*latitude, *longitude)),
+        Message::ToggleAllTags => Event::AllTagsToggled,
+        Message::TagFilterChanged(filter) => Event::TagFilterChanged(filter.clone()),
+        Message::CopyTagValue(value) => Event::CopyToClipboard(value.clone()),
+        Message::Batch(message) => Event::Batch(message.clone()),
+        Message::StripAllMetadata => Event::StripMetadataRequested,
+        Message::ToggleShootStats => Event::ShootStatsToggled,
+        Message::RefreshShootStats => Event::ShootStatsRefreshRequested,
+        Message::ExportMetadata(scope) => Event::ExportMetadataRequested(*scope),
         Message::FieldChanged(_, _)
         | Message::Save
         | Message::ShowField(_)
-        | Message::RemoveField(_) => Event::None,
+        | Message::RemoveField(_)
+        | Message::KeywordInputChanged(_)
+        | Message::KeywordSubmitted
+        | Message::KeywordRemoved(_) => Event::None,
     }
 }
 
@@ -230,6 +381,11 @@ pub fn view(ctx: ViewContext<'_>) -> iced::Element<'_, Message> {
         current_path: None,
         editor_state: None,
         is_image,
+        tag_browser: None,
+        batch: None,
+        batch_file_count: 0,
+        shoot_stats: None,
+        decoder: None,
     })
 }
 
@@ -268,9 +424,55 @@ mod tests {
         assert!(matches!(event, Event::None));
     }
 
+    #[test]
+    fn open_in_map_emits_map_url() {
+        let message = Message::OpenInMap {
+            latitude: 48.8566,
+            longitude: 2.3522,
+        };
+        let event = update_with_state(None, message, None);
+        assert!(
+            matches!(event, Event::OpenUrlRequested(url) if url.contains("mlat=48.856600&mlon=2.352200"))
+        );
+    }
+
+    #[test]
+    fn copy_tag_value_emits_clipboard_request() {
+        let message = Message::CopyTagValue("EOS R5".to_string());
+        let event = update_with_state(None, message, None);
+        assert!(matches!(event, Event::CopyToClipboard(value) if value == "EOS R5"));
+    }
+
     #[test]
     fn save_as_emits_request() {
         let event = update_with_state(None, Message::SaveAs, None);
         assert!(matches!(event, Event::SaveAsRequested));
     }
+
+    #[test]
+    fn shoot_stats_are_stale_for_another_directory() {
+        let mut state = ShootStatsState::default();
+        assert!(state.is_stale(Path::new("/photos")));
+
+        state.stats = Some(ShootStats::default());
+        state.directory = Some(PathBuf::from("/photos"));
+        assert!(!state.is_stale(Path::new("/photos")));
+        assert!(state.is_stale(Path::new("/other")));
+    }
+
+    #[test]
+    fn strip_all_metadata_emits_request() {
+        let event = update_with_state(None, Message::StripAllMetadata, None);
+        assert!(matches!(event, Event::StripMetadataRequested));
+    }
+
+    #[test]
+    fn export_metadata_emits_request_with_scope() {
+        let message = Message::ExportMetadata(ExportScope::Folder);
+        let event = update_with_state(None, message, None);
+        assert!(matches!(
+            event,
+            Event::ExportMetadataRequested(ExportScope::Folder)
+        ));
+    }
 }
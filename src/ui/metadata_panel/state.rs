@@ -45,6 +45,8 @@ pub struct MetadataEditorState {
     pub errors: ValidationErrors,
     /// Fields currently visible in the editor (progressive disclosure).
     pub visible_fields: HashSet<MetadataField>,
+    /// Keyword being typed, not yet added to `edited.dc_subject`.
+    pub keyword_input: String,
 }
 
 impl MetadataEditorState {
@@ -58,6 +60,7 @@ impl MetadataEditorState {
             original: editable,
             errors: ValidationErrors::default(),
             visible_fields: visible,
+            keyword_input: String::new(),
         }
     }
 
@@ -69,6 +72,7 @@ impl MetadataEditorState {
             original: EditableMetadata::default(),
             errors: ValidationErrors::default(),
             visible_fields: HashSet::new(),
+            keyword_input: String::new(),
         }
     }
 
@@ -144,6 +148,8 @@ impl MetadataEditorState {
         if let Some(pair) = field.gps_pair() {
             self.visible_fields.remove(&pair);
             self.clear_field_value(pair);
+            // Removing the location strips it from the file instead of keeping the old value
+            self.edited.strip_gps = self.original.has_gps();
         }
     }
 
@@ -244,6 +250,32 @@ impl MetadataEditorState {
         self.edited = self.original.clone();
         self.errors = ValidationErrors::default();
         self.visible_fields = Self::visible_fields_from_data(&self.original);
+        self.keyword_input.clear();
+    }
+
+    /// Adds the keywords of the keyword input (comma-separated) and clears it.
+    ///
+    /// Keywords already in the list are not added twice.
+    pub fn commit_keyword_input(&mut self) {
+        let input = std::mem::take(&mut self.keyword_input);
+        let mut keywords: Vec<&str> = self.edited.keywords();
+        for keyword in input.split(',').map(str::trim).filter(|k| !k.is_empty()) {
+            if !keywords.contains(&keyword) {
+                keywords.push(keyword);
+            }
+        }
+        self.edited.dc_subject = keywords.join(", ");
+    }
+
+    /// Removes a keyword from the keyword list.
+    pub fn remove_keyword(&mut self, keyword: &str) {
+        let keywords: Vec<&str> = self
+            .edited
+            .keywords()
+            .into_iter()
+            .filter(|k| *k != keyword)
+            .collect();
+        self.edited.dc_subject = keywords.join(", ");
     }
 
     /// Sets a field value and validates it.
@@ -306,6 +338,7 @@ impl MetadataEditorState {
                 };
             }
             MetadataField::GpsLatitude => {
+                self.edited.strip_gps = false;
                 self.edited.gps_latitude.clone_from(&value);
                 self.errors.gps_latitude = if value == self.original.gps_latitude {
                     None
@@ -314,6 +347,7 @@ impl MetadataEditorState {
                 };
             }
             MetadataField::GpsLongitude => {
+                self.edited.strip_gps = false;
                 self.edited.gps_longitude.clone_from(&value);
                 self.errors.gps_longitude = if value == self.original.gps_longitude {
                     None
@@ -697,6 +731,61 @@ mod tests {
         assert!(state.edited.gps_longitude.is_empty());
     }
 
+    #[test]
+    fn test_remove_gps_strips_location_until_reentered() {
+        let meta = ImageMetadata {
+            gps_latitude: Some(48.8566),
+            gps_longitude: Some(2.3522),
+            ..Default::default()
+        };
+        let mut state = MetadataEditorState::from_image_metadata(&meta);
+
+        state.remove_field(MetadataField::GpsLatitude);
+        assert!(state.edited.strip_gps);
+        assert!(state.has_changes());
+
+        state.show_field(MetadataField::GpsLatitude);
+        state.set_field(&MetadataField::GpsLatitude, "45.0".to_string());
+        assert!(!state.edited.strip_gps);
+    }
+
+    #[test]
+    fn test_remove_gps_without_original_location_does_not_strip() {
+        let mut state = MetadataEditorState::new_empty();
+        state.show_field(MetadataField::GpsLatitude);
+        state.remove_field(MetadataField::GpsLatitude);
+        assert!(!state.edited.strip_gps);
+    }
+
+    #[test]
+    fn test_commit_keyword_input_adds_unique_keywords() {
+        let meta = ImageMetadata {
+            dc_subject: Some(vec!["sea".to_string()]),
+            ..Default::default()
+        };
+        let mut state = MetadataEditorState::from_image_metadata(&meta);
+
+        state.keyword_input = "boats, sea ,  morning".to_string();
+        state.commit_keyword_input();
+
+        assert_eq!(state.edited.dc_subject, "sea, boats, morning");
+        assert!(state.keyword_input.is_empty());
+        assert!(state.has_changes());
+    }
+
+    #[test]
+    fn test_remove_keyword() {
+        let meta = ImageMetadata {
+            dc_subject: Some(vec!["sea".to_string(), "boats".to_string()]),
+            ..Default::default()
+        };
+        let mut state = MetadataEditorState::from_image_metadata(&meta);
+
+        state.remove_keyword("sea");
+
+        assert_eq!(state.edited.keywords(), vec!["boats"]);
+    }
+
     #[test]
     fn test_available_fields() {
         let meta = ImageMetadata {
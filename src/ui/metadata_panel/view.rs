@@ -1,21 +1,29 @@
 // SPDX-License-Identifier: MPL-2.0
 //! View rendering for the metadata panel.
 
-use super::{Message, MetadataEditorState, MetadataField, PanelContext};
+use super::{
+    BatchEditState, BatchMessage, ExportScope, Message, MetadataEditorState, MetadataField,
+    PanelContext, ShootStatsState,
+};
 use crate::i18n::fluent::I18n;
+use crate::media::decoders::MediaDecoder;
 use crate::media::extensions;
 use crate::media::metadata::{
-    format_bitrate, format_file_size, format_gps_coordinates, ExtendedVideoMetadata, ImageMetadata,
+    format_gps_coordinates, format_gps_dms, group_tags, ExtendedVideoMetadata, ImageMetadata,
     MediaMetadata,
 };
+use crate::media::shoot_stats::StatEntry;
 use crate::ui::action_icons;
 use crate::ui::design_tokens::{palette, radius, sizing, spacing, typography};
 use crate::ui::icons;
 use crate::ui::styles::button as button_styles;
+use crate::ui::styles::text_input as text_input_styles;
 use crate::ui::styles::tooltip as styled_tooltip;
+use crate::ui::theming;
+use crate::video_player::DecodePath;
 use iced::widget::image::{Handle, Image};
 use iced::widget::{
-    button, container, pick_list, rule, scrollable, text, text_input, Column, Row, Text,
+    button, checkbox, container, pick_list, rule, scrollable, text, text_input, Column, Row, Text,
 };
 use iced::{alignment::Vertical, Border, Element, Length, Padding, Theme};
 
@@ -120,6 +128,23 @@ fn build_header_buttons<'a>(
             iced::widget::tooltip::Position::Bottom,
         );
         buttons = buttons.push(edit_button);
+
+        if ctx
+            .current_path
+            .is_some_and(crate::media::metadata_strip::is_format_supported)
+        {
+            let strip_btn = button(action_icons::sized(
+                action_icons::navigation::strip_metadata(ctx.is_dark_theme),
+                sizing::ICON_SM,
+            ))
+            .on_press(Message::StripAllMetadata)
+            .padding(spacing::XXS);
+            buttons = buttons.push(styled_tooltip::styled(
+                strip_btn,
+                ctx.i18n.tr("metadata-strip-button"),
+                iced::widget::tooltip::Position::Bottom,
+            ));
+        }
     } else if !is_editing && !ctx.is_image && ctx.metadata.is_some() {
         // Disabled edit button for videos with tooltip
         let edit_btn = button(action_icons::sized(
@@ -172,10 +197,404 @@ fn build_view_content<'a>(
     ctx: &PanelContext<'a>,
     metadata: &MediaMetadata,
 ) -> Element<'a, Message> {
-    match metadata {
-        MediaMetadata::Image(image_meta) => build_image_metadata_view(ctx.i18n, image_meta),
-        MediaMetadata::Video(video_meta) => build_video_metadata_view(ctx.i18n, video_meta),
+    let decoder = ctx.decoder.map(|decoder| format_decoder(ctx.i18n, decoder));
+    let view = match metadata {
+        MediaMetadata::Image(image_meta) => {
+            let view = build_image_metadata_view(ctx.i18n, image_meta, decoder);
+            if image_meta.all_tags.is_empty() {
+                view
+            } else {
+                Column::new()
+                    .spacing(spacing::MD)
+                    .push(view)
+                    .push(build_all_tags_section(ctx, image_meta))
+                    .into()
+            }
+        }
+        MediaMetadata::Video(video_meta) => {
+            build_video_metadata_view(ctx.i18n, video_meta, decoder)
+        }
+    };
+
+    let mut content = Column::new().spacing(spacing::MD).push(view);
+    if let Some(stats) = ctx.shoot_stats {
+        content = content
+            .push(rule::horizontal(1))
+            .push(build_shoot_stats_section(ctx, stats));
+    }
+    content = content
+        .push(rule::horizontal(1))
+        .push(build_export_section(ctx));
+    if let Some(batch) = ctx.batch {
+        content = content
+            .push(rule::horizontal(1))
+            .push(build_batch_section(ctx, batch));
     }
+    content.into()
+}
+
+/// Build the "Export metadata" section, exporting the current file or the
+/// whole folder to JSON or CSV.
+fn build_export_section<'a>(ctx: &PanelContext<'a>) -> Element<'a, Message> {
+    Column::new()
+        .spacing(spacing::XS)
+        .push(Text::new(ctx.i18n.tr("metadata-export-title")).size(typography::BODY_LG))
+        .push(
+            Text::new(ctx.i18n.tr("metadata-export-hint"))
+                .size(typography::CAPTION)
+                .style(|theme: &Theme| text::Style {
+                    color: Some(theme.extended_palette().background.strong.text),
+                }),
+        )
+        .push(
+            Row::new()
+                .spacing(spacing::XS)
+                .push(
+                    button(Text::new(ctx.i18n.tr("metadata-export-file")).size(typography::BODY))
+                        .on_press(Message::ExportMetadata(ExportScope::CurrentFile)),
+                )
+                .push(
+                    button(Text::new(ctx.i18n.tr("metadata-export-folder")).size(typography::BODY))
+                        .on_press(Message::ExportMetadata(ExportScope::Folder)),
+                ),
+        )
+        .into()
+}
+
+/// Build the expandable "Folder statistics" section summarizing cameras,
+/// lenses, focal lengths and ISO values of the current folder.
+fn build_shoot_stats_section<'a>(
+    ctx: &PanelContext<'a>,
+    state: &'a ShootStatsState,
+) -> Element<'a, Message> {
+    let chevron = if state.expanded {
+        icons::sized(icons::chevron_down(), sizing::ICON_SM)
+    } else {
+        icons::sized(icons::chevron_right(), sizing::ICON_SM)
+    };
+    let toggle = button(
+        Row::new()
+            .spacing(spacing::XS)
+            .align_y(Vertical::Center)
+            .push(chevron)
+            .push(Text::new(ctx.i18n.tr("metadata-stats-title")).size(typography::BODY_LG)),
+    )
+    .on_press(Message::ToggleShootStats)
+    .padding(0)
+    .style(button::text);
+
+    let mut header = Row::new()
+        .width(Length::Fill)
+        .align_y(Vertical::Center)
+        .push(toggle)
+        .push(iced::widget::Space::new().width(Length::Fill));
+    if state.expanded && !state.loading {
+        let refresh_btn = button(icons::sized(icons::refresh(), sizing::ICON_SM))
+            .on_press(Message::RefreshShootStats)
+            .padding(spacing::XXS)
+            .style(button::text);
+        header = header.push(styled_tooltip::styled(
+            refresh_btn,
+            ctx.i18n.tr("metadata-stats-refresh"),
+            iced::widget::tooltip::Position::Left,
+        ));
+    }
+
+    let mut content = Column::new().spacing(spacing::XS).push(header);
+    if !state.expanded {
+        return content.into();
+    }
+
+    if state.loading {
+        return content
+            .push(Text::new(ctx.i18n.tr("metadata-stats-loading")).size(typography::BODY))
+            .into();
+    }
+    let Some(stats) = state.stats.as_ref() else {
+        return content.into();
+    };
+
+    let files = stats.files.to_string();
+    let with_exif = stats.with_exif.to_string();
+    content = content.push(
+        Text::new(ctx.i18n.tr_with_args(
+            "metadata-stats-summary",
+            &[("count", files.as_str()), ("exif", with_exif.as_str())],
+        ))
+        .size(typography::CAPTION),
+    );
+    if stats.is_empty() {
+        return content
+            .push(Text::new(ctx.i18n.tr("metadata-stats-empty")).size(typography::BODY))
+            .into();
+    }
+
+    let groups = [
+        ("metadata-stats-cameras", &stats.cameras),
+        ("metadata-stats-lenses", &stats.lenses),
+        ("metadata-stats-focal-lengths", &stats.focal_lengths),
+        ("metadata-stats-iso", &stats.isos),
+    ];
+    for (title_key, entries) in groups {
+        if entries.is_empty() {
+            continue;
+        }
+        content = content.push(
+            Text::new(ctx.i18n.tr(title_key))
+                .size(typography::BODY)
+                .style(|theme: &Theme| text::Style {
+                    color: Some(theme.extended_palette().primary.base.color),
+                }),
+        );
+        let max = entries.iter().map(|entry| entry.count).max().unwrap_or(1);
+        for entry in entries {
+            content = content.push(build_stat_bar(entry, max));
+        }
+    }
+
+    content.into()
+}
+
+/// Build one histogram row: label, proportional bar, and count.
+fn build_stat_bar(entry: &StatEntry, max: usize) -> Element<'_, Message> {
+    // Bar lengths are relative, so clamping huge counts only affects precision
+    let portion = |count: usize| u16::try_from(count).unwrap_or(u16::MAX);
+    let mut bar = Row::new()
+        .width(Length::FillPortion(3))
+        .height(sizing::ICON_SM);
+    if entry.count > 0 {
+        bar = bar.push(
+            container(iced::widget::Space::new())
+                .width(Length::FillPortion(portion(entry.count)))
+                .height(Length::Fill)
+                .style(|theme: &Theme| container::Style {
+                    background: Some(theming::accent(theme).base.into()),
+                    border: Border {
+                        radius: radius::SM.into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }),
+        );
+    }
+    if max > entry.count {
+        bar = bar.push(
+            iced::widget::Space::new().width(Length::FillPortion(portion(max - entry.count))),
+        );
+    }
+
+    Row::new()
+        .spacing(spacing::XS)
+        .align_y(Vertical::Center)
+        .push(
+            Text::new(entry.label.as_str())
+                .size(typography::BODY_SM)
+                .width(Length::FillPortion(3)),
+        )
+        .push(bar)
+        .push(Text::new(entry.count.to_string()).size(typography::BODY_SM))
+        .into()
+}
+
+/// Build the expandable "Batch edit" section applying changes to every file
+/// of the folder (or of the active filter).
+fn build_batch_section<'a>(ctx: &PanelContext<'a>, batch: &BatchEditState) -> Element<'a, Message> {
+    let chevron = if batch.expanded {
+        icons::sized(icons::chevron_down(), sizing::ICON_SM)
+    } else {
+        icons::sized(icons::chevron_right(), sizing::ICON_SM)
+    };
+    let toggle = button(
+        Row::new()
+            .spacing(spacing::XS)
+            .align_y(Vertical::Center)
+            .push(chevron)
+            .push(Text::new(ctx.i18n.tr("metadata-batch-title")).size(typography::BODY_LG)),
+    )
+    .on_press(Message::Batch(BatchMessage::Toggle))
+    .padding(0)
+    .style(button::text);
+
+    let mut content = Column::new().spacing(spacing::XS).push(toggle);
+    if !batch.expanded {
+        return content.into();
+    }
+
+    let input = |label_key: &str, value: &str, on_input: fn(String) -> BatchMessage| {
+        Column::new()
+            .spacing(spacing::XXS)
+            .push(Text::new(ctx.i18n.tr(label_key)).size(typography::BODY_SM))
+            .push(
+                text_input("", value)
+                    .on_input(move |v| Message::Batch(on_input(v)))
+                    .padding(spacing::XXS)
+                    .style(text_input_styles::standard)
+                    .size(typography::BODY),
+            )
+    };
+
+    let count = ctx.batch_file_count.to_string();
+    content = content
+        .push(
+            Text::new(
+                ctx.i18n
+                    .tr_with_args("metadata-batch-hint", &[("count", count.as_str())]),
+            )
+            .size(typography::CAPTION),
+        )
+        .push(input(
+            "metadata-batch-creator-label",
+            &batch.creator,
+            BatchMessage::CreatorChanged,
+        ))
+        .push(input(
+            "metadata-batch-copyright-label",
+            &batch.copyright,
+            BatchMessage::CopyrightChanged,
+        ))
+        .push(input(
+            "metadata-batch-keywords-label",
+            &batch.keywords,
+            BatchMessage::KeywordsChanged,
+        ))
+        .push(
+            checkbox(batch.strip_gps)
+                .label(ctx.i18n.tr("metadata-batch-strip-gps"))
+                .on_toggle(|value| Message::Batch(BatchMessage::StripGpsToggled(value))),
+        );
+
+    if batch.running {
+        return content
+            .push(Text::new(ctx.i18n.tr("metadata-batch-running")).size(typography::BODY))
+            .into();
+    }
+
+    if batch.confirming {
+        let cancel_btn = button(text(ctx.i18n.tr("metadata-cancel-button")).size(typography::BODY))
+            .on_press(Message::Batch(BatchMessage::CancelConfirm))
+            .padding(spacing::SM)
+            .width(Length::FillPortion(1));
+        let confirm_btn =
+            button(text(ctx.i18n.tr("metadata-batch-confirm-button")).size(typography::BODY))
+                .on_press(Message::Batch(BatchMessage::Confirm))
+                .padding(spacing::SM)
+                .width(Length::FillPortion(1));
+        return content
+            .push(
+                text(ctx.i18n.tr_with_args(
+                    "metadata-batch-confirm-warning",
+                    &[("count", count.as_str())],
+                ))
+                .size(typography::CAPTION)
+                .color(palette::WARNING_500),
+            )
+            .push(
+                Row::new()
+                    .spacing(spacing::XS)
+                    .push(cancel_btn)
+                    .push(confirm_btn),
+            )
+            .into();
+    }
+
+    let apply_btn = button(
+        text(
+            ctx.i18n
+                .tr_with_args("metadata-batch-apply-button", &[("count", count.as_str())]),
+        )
+        .size(typography::BODY),
+    )
+    .padding(spacing::SM)
+    .width(Length::Fill);
+    let apply_btn = if batch.changes().is_empty() || ctx.batch_file_count == 0 {
+        apply_btn.style(button_styles::disabled())
+    } else {
+        apply_btn.on_press(Message::Batch(BatchMessage::Apply))
+    };
+
+    content.push(apply_btn).into()
+}
+
+/// Build the expandable "All tags" browser listing every raw tag by group.
+fn build_all_tags_section<'a>(
+    ctx: &PanelContext<'a>,
+    meta: &ImageMetadata,
+) -> Element<'a, Message> {
+    let browser = ctx.tag_browser.cloned().unwrap_or_default();
+    let count = meta.all_tags.len().to_string();
+
+    let chevron = if browser.expanded {
+        icons::sized(icons::chevron_down(), sizing::ICON_SM)
+    } else {
+        icons::sized(icons::chevron_right(), sizing::ICON_SM)
+    };
+    let toggle = button(
+        Row::new()
+            .spacing(spacing::XS)
+            .align_y(Vertical::Center)
+            .push(chevron)
+            .push(
+                Text::new(
+                    ctx.i18n
+                        .tr_with_args("metadata-all-tags-title", &[("count", count.as_str())]),
+                )
+                .size(typography::BODY_LG),
+            ),
+    )
+    .on_press(Message::ToggleAllTags)
+    .padding(0)
+    .style(button::text);
+
+    let mut content = Column::new().spacing(spacing::XS).push(toggle);
+    if !browser.expanded {
+        return content.into();
+    }
+
+    content = content.push(
+        text_input(
+            &ctx.i18n.tr("metadata-all-tags-filter-placeholder"),
+            &browser.filter,
+        )
+        .on_input(Message::TagFilterChanged)
+        .padding(spacing::XXS)
+        .style(text_input_styles::standard)
+        .size(typography::BODY),
+    );
+
+    let groups = group_tags(&meta.all_tags, &browser.filter);
+    if groups.is_empty() {
+        return content
+            .push(Text::new(ctx.i18n.tr("metadata-all-tags-no-match")).size(typography::BODY))
+            .into();
+    }
+
+    for (group, tags) in groups {
+        content = content.push(
+            Text::new(group)
+                .size(typography::BODY)
+                .style(|theme: &Theme| text::Style {
+                    color: Some(theme.extended_palette().primary.base.color),
+                }),
+        );
+        for tag in tags {
+            let row = button(build_metadata_row(
+                ctx.i18n,
+                tag.name.clone(),
+                tag.value.clone(),
+            ))
+            .on_press(Message::CopyTagValue(tag.value.clone()))
+            .padding(0)
+            .width(Length::Fill)
+            .style(button::text);
+            content = content.push(styled_tooltip::styled(
+                row,
+                ctx.i18n.tr("metadata-all-tags-copy-tooltip"),
+                iced::widget::tooltip::Position::Left,
+            ));
+        }
+    }
+
+    content.into()
 }
 
 /// Build edit mode content for images with progressive disclosure.
@@ -297,6 +716,7 @@ fn build_camera_section_edit<'a>(
     // Camera Make
     if editor.is_field_visible(&MetadataField::CameraMake) {
         rows = rows.push(build_edit_field_with_remove(
+            i18n,
             &i18n.tr("metadata-label-make"),
             &editor.edited.camera_make,
             MetadataField::CameraMake,
@@ -309,6 +729,7 @@ fn build_camera_section_edit<'a>(
     // Camera Model
     if editor.is_field_visible(&MetadataField::CameraModel) {
         rows = rows.push(build_edit_field_with_remove(
+            i18n,
             &i18n.tr("metadata-label-model"),
             &editor.edited.camera_model,
             MetadataField::CameraModel,
@@ -350,6 +771,7 @@ fn build_exposure_section_edit<'a>(
     // Exposure Time
     if editor.is_field_visible(&MetadataField::ExposureTime) {
         rows = rows.push(build_edit_field_with_remove(
+            i18n,
             &i18n.tr("metadata-label-exposure"),
             &editor.edited.exposure_time,
             MetadataField::ExposureTime,
@@ -362,6 +784,7 @@ fn build_exposure_section_edit<'a>(
     // Aperture
     if editor.is_field_visible(&MetadataField::Aperture) {
         rows = rows.push(build_edit_field_with_remove(
+            i18n,
             &i18n.tr("metadata-label-aperture"),
             &editor.edited.aperture,
             MetadataField::Aperture,
@@ -374,6 +797,7 @@ fn build_exposure_section_edit<'a>(
     // ISO
     if editor.is_field_visible(&MetadataField::Iso) {
         rows = rows.push(build_edit_field_with_remove(
+            i18n,
             &i18n.tr("metadata-label-iso"),
             &editor.edited.iso,
             MetadataField::Iso,
@@ -386,6 +810,7 @@ fn build_exposure_section_edit<'a>(
     // Focal Length
     if editor.is_field_visible(&MetadataField::FocalLength) {
         rows = rows.push(build_edit_field_with_remove(
+            i18n,
             &i18n.tr("metadata-label-focal-length"),
             &editor.edited.focal_length,
             MetadataField::FocalLength,
@@ -414,13 +839,24 @@ fn build_gps_section_edit<'a>(
     if !editor.is_field_visible(&MetadataField::GpsLatitude)
         && !editor.is_field_visible(&MetadataField::GpsLongitude)
     {
-        return None;
+        // Keep the section visible to confirm that saving strips the location
+        return editor.edited.strip_gps.then(|| {
+            build_section(
+                icons::globe(),
+                i18n.tr("metadata-section-gps"),
+                text(i18n.tr("metadata-gps-strip-pending"))
+                    .size(typography::CAPTION)
+                    .color(palette::WARNING_500)
+                    .into(),
+            )
+        });
     }
 
     let mut rows = Column::new().spacing(spacing::XS);
 
     // Latitude (with remove button that removes both GPS fields)
     rows = rows.push(build_edit_field_with_remove(
+        i18n,
         &i18n.tr("metadata-label-latitude"),
         &editor.edited.gps_latitude,
         MetadataField::GpsLatitude,
@@ -454,6 +890,7 @@ fn build_dublin_core_section_edit<'a>(
     // Title
     if editor.is_field_visible(&MetadataField::DcTitle) {
         rows = rows.push(build_edit_field_with_remove(
+            i18n,
             &i18n.tr("metadata-label-dc-title"),
             &editor.edited.dc_title,
             MetadataField::DcTitle,
@@ -466,6 +903,7 @@ fn build_dublin_core_section_edit<'a>(
     // Creator
     if editor.is_field_visible(&MetadataField::DcCreator) {
         rows = rows.push(build_edit_field_with_remove(
+            i18n,
             &i18n.tr("metadata-label-dc-creator"),
             &editor.edited.dc_creator,
             MetadataField::DcCreator,
@@ -478,6 +916,7 @@ fn build_dublin_core_section_edit<'a>(
     // Description
     if editor.is_field_visible(&MetadataField::DcDescription) {
         rows = rows.push(build_edit_field_with_remove(
+            i18n,
             &i18n.tr("metadata-label-dc-description"),
             &editor.edited.dc_description,
             MetadataField::DcDescription,
@@ -489,19 +928,14 @@ fn build_dublin_core_section_edit<'a>(
 
     // Subject (Keywords)
     if editor.is_field_visible(&MetadataField::DcSubject) {
-        rows = rows.push(build_edit_field_with_remove(
-            &i18n.tr("metadata-label-dc-subject"),
-            &editor.edited.dc_subject,
-            MetadataField::DcSubject,
-            Some("sunset, nature, landscape".to_string()),
-            None,
-        ));
+        rows = rows.push(build_keywords_field(i18n, editor));
         has_fields = true;
     }
 
     // Rights (Copyright)
     if editor.is_field_visible(&MetadataField::DcRights) {
         rows = rows.push(build_edit_field_with_remove(
+            i18n,
             &i18n.tr("metadata-label-dc-rights"),
             &editor.edited.dc_rights,
             MetadataField::DcRights,
@@ -540,6 +974,7 @@ fn build_edit_field<'a>(
     let input = text_input(&placeholder_str, value)
         .on_input(move |v| Message::FieldChanged(field, v))
         .padding(spacing::XS)
+        .style(text_input_styles::standard)
         .size(typography::BODY);
     col = col.push(input);
 
@@ -555,8 +990,21 @@ fn build_edit_field<'a>(
     col.into()
 }
 
+/// Cross button removing a field from the editor.
+fn remove_field_button<'a>(i18n: &I18n, message: Message) -> Element<'a, Message> {
+    styled_tooltip::styled(
+        button(icons::sized(icons::cross(), sizing::ICON_SM))
+            .on_press(message)
+            .padding(spacing::XXS),
+        i18n.tr("metadata-remove-field-tooltip"),
+        iced::widget::tooltip::Position::Left,
+    )
+    .into()
+}
+
 /// Build an editable field with a remove button.
 fn build_edit_field_with_remove<'a>(
+    i18n: &'a I18n,
     label: &str,
     value: &str,
     field: MetadataField,
@@ -571,11 +1019,7 @@ fn build_edit_field_with_remove<'a>(
         .align_y(Vertical::Center)
         .push(text(format!("{label}:")).size(typography::BODY_SM))
         .push(iced::widget::Space::new().width(Length::Fill))
-        .push(
-            button(icons::sized(icons::cross(), sizing::ICON_SM))
-                .on_press(Message::RemoveField(field))
-                .padding(spacing::XXS),
-        );
+        .push(remove_field_button(i18n, Message::RemoveField(field)));
     col = col.push(label_row);
 
     // Input
@@ -583,6 +1027,7 @@ fn build_edit_field_with_remove<'a>(
     let input = text_input(&placeholder_str, value)
         .on_input(move |v| Message::FieldChanged(field, v))
         .padding(spacing::XS)
+        .style(text_input_styles::standard)
         .size(typography::BODY);
     col = col.push(input);
 
@@ -598,6 +1043,71 @@ fn build_edit_field_with_remove<'a>(
     col.into()
 }
 
+/// Build the keywords field: one removable chip per keyword and an input
+/// that adds keywords on Enter (comma-separated input adds several at once).
+fn build_keywords_field<'a>(i18n: &'a I18n, editor: &MetadataEditorState) -> Element<'a, Message> {
+    let label = i18n.tr("metadata-label-dc-subject");
+    let label_row = Row::new()
+        .spacing(spacing::XS)
+        .align_y(Vertical::Center)
+        .push(text(format!("{label}:")).size(typography::BODY_SM))
+        .push(iced::widget::Space::new().width(Length::Fill))
+        .push(remove_field_button(
+            i18n,
+            Message::RemoveField(MetadataField::DcSubject),
+        ));
+
+    let chips = editor
+        .edited
+        .keywords()
+        .into_iter()
+        .fold(Row::new().spacing(spacing::XXS), |chips, keyword| {
+            let chip = Row::new()
+                .spacing(spacing::XXS)
+                .align_y(Vertical::Center)
+                .push(text(keyword.to_string()).size(typography::BODY_SM))
+                .push(styled_tooltip::styled(
+                    button(icons::sized(icons::cross(), sizing::ICON_SM))
+                        .on_press(Message::KeywordRemoved(keyword.to_string()))
+                        .padding(0)
+                        .style(button::text),
+                    i18n.tr("metadata-remove-keyword-tooltip"),
+                    iced::widget::tooltip::Position::Top,
+                ));
+            chips.push(
+                container(chip)
+                    .padding(Padding::from([spacing::XXS, spacing::XS]))
+                    .style(|theme: &Theme| container::Style {
+                        background: Some(theme.extended_palette().background.strong.color.into()),
+                        border: Border {
+                            radius: radius::FULL.into(),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    }),
+            )
+        })
+        .wrap()
+        .vertical_spacing(spacing::XXS);
+
+    let input = text_input(
+        &i18n.tr("metadata-keyword-input-placeholder"),
+        &editor.keyword_input,
+    )
+    .on_input(Message::KeywordInputChanged)
+    .on_submit(Message::KeywordSubmitted)
+    .padding(spacing::XS)
+    .style(text_input_styles::standard)
+    .size(typography::BODY);
+
+    Column::new()
+        .spacing(spacing::XXS)
+        .push(label_row)
+        .push(chips)
+        .push(input)
+        .into()
+}
+
 /// Build a smart date/time field with single input and intelligent parsing.
 ///
 /// Accepts multiple date formats and converts to EXIF format (YYYY:MM:DD HH:MM:SS).
@@ -616,11 +1126,10 @@ fn build_date_field_with_remove<'a>(
         .align_y(Vertical::Center)
         .push(text(format!("{label}:")).size(typography::BODY_SM))
         .push(iced::widget::Space::new().width(Length::Fill))
-        .push(
-            button(icons::sized(icons::cross(), sizing::ICON_SM))
-                .on_press(Message::RemoveField(MetadataField::DateTaken))
-                .padding(spacing::XXS),
-        );
+        .push(remove_field_button(
+            i18n,
+            Message::RemoveField(MetadataField::DateTaken),
+        ));
     col = col.push(label_row);
 
     // Format the display value for better readability
@@ -638,6 +1147,7 @@ fn build_date_field_with_remove<'a>(
                     Message::FieldChanged(MetadataField::DateTaken, exif_date)
                 })
                 .padding(spacing::XS)
+                .style(text_input_styles::standard)
                 .size(typography::BODY)
                 .width(Length::Fill),
         )
@@ -693,6 +1203,13 @@ fn parse_exif_datetime(value: &str) -> Option<chrono::NaiveDateTime> {
     NaiveDateTime::parse_from_str(value, "%Y:%m:%d %H:%M:%S").ok()
 }
 
+/// Parses the date taken as shown by the EXIF reader (`2024-03-15 14:30:00`)
+/// or as stored in the file (`2024:03:15 14:30:00`).
+fn parse_date_taken(value: &str) -> Option<chrono::NaiveDateTime> {
+    parse_exif_datetime(value)
+        .or_else(|| chrono::NaiveDateTime::parse_from_str(value.trim(), "%Y-%m-%d %H:%M:%S").ok())
+}
+
 /// Parse various date input formats and convert to EXIF format.
 fn parse_date_input(input: &str) -> String {
     use chrono::{NaiveDate, NaiveDateTime};
@@ -811,11 +1328,15 @@ fn build_add_field_picker<'a>(i18n: &'a I18n, available: &[MetadataField]) -> El
 // View Mode Rendering (Read-Only)
 // =============================================================================
 
-fn build_image_metadata_view<'a>(i18n: &'a I18n, meta: &ImageMetadata) -> Element<'a, Message> {
+fn build_image_metadata_view<'a>(
+    i18n: &'a I18n,
+    meta: &ImageMetadata,
+    decoder: Option<String>,
+) -> Element<'a, Message> {
     let mut sections = Column::new().spacing(spacing::MD);
 
     // File section (always first - basic file info)
-    let file_section = build_file_section_image(i18n, meta);
+    let file_section = build_file_section_image(i18n, meta, decoder);
     sections = sections.push(file_section);
 
     // Dublin Core / XMP section (user-facing metadata, shown second)
@@ -858,11 +1379,12 @@ fn build_image_metadata_view<'a>(i18n: &'a I18n, meta: &ImageMetadata) -> Elemen
 fn build_video_metadata_view<'a>(
     i18n: &'a I18n,
     meta: &ExtendedVideoMetadata,
+    decoder: Option<String>,
 ) -> Element<'a, Message> {
     let mut sections = Column::new().spacing(spacing::MD);
 
     // File section
-    let file_section = build_file_section_video(i18n, meta);
+    let file_section = build_file_section_video(i18n, meta, decoder);
     sections = sections.push(file_section);
 
     // Video section
@@ -878,7 +1400,11 @@ fn build_video_metadata_view<'a>(
     sections.into()
 }
 
-fn build_file_section_image<'a>(i18n: &'a I18n, meta: &ImageMetadata) -> Element<'a, Message> {
+fn build_file_section_image<'a>(
+    i18n: &'a I18n,
+    meta: &ImageMetadata,
+    decoder: Option<String>,
+) -> Element<'a, Message> {
     let mut rows = Column::new().spacing(spacing::XS);
 
     if meta.width.is_some() || meta.height.is_some() {
@@ -890,6 +1416,7 @@ fn build_file_section_image<'a>(i18n: &'a I18n, meta: &ImageMetadata) -> Element
                 .map_or_else(|| "?".to_string(), |v| v.to_string())
         );
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-dimensions"),
             dims,
         ));
@@ -897,18 +1424,28 @@ fn build_file_section_image<'a>(i18n: &'a I18n, meta: &ImageMetadata) -> Element
 
     if let Some(size) = meta.file_size {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-file-size"),
-            format_file_size(size),
+            i18n.format_file_size(size),
         ));
     }
 
     if let Some(ref format) = meta.format {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-format"),
             format.clone(),
         ));
     }
 
+    if let Some(decoder) = decoder {
+        rows = rows.push(build_metadata_row(
+            i18n,
+            i18n.tr("metadata-label-decoder"),
+            decoder,
+        ));
+    }
+
     build_section(
         icons::image(),
         i18n.tr("metadata-section-file"),
@@ -919,40 +1456,57 @@ fn build_file_section_image<'a>(i18n: &'a I18n, meta: &ImageMetadata) -> Element
 fn build_file_section_video<'a>(
     i18n: &'a I18n,
     meta: &ExtendedVideoMetadata,
+    decoder: Option<String>,
 ) -> Element<'a, Message> {
     let mut rows = Column::new().spacing(spacing::XS);
 
     rows = rows.push(build_metadata_row(
+        i18n,
         i18n.tr("metadata-label-dimensions"),
         format!("{} x {} px", meta.width, meta.height),
     ));
 
     if let Some(size) = meta.file_size {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-file-size"),
-            format_file_size(size),
+            i18n.format_file_size(size),
         ));
     }
 
     rows = rows.push(build_metadata_row(
+        i18n,
         i18n.tr("metadata-label-duration"),
         format_duration(meta.duration_secs),
     ));
 
     if meta.fps > 0.0 {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-fps"),
-            format!("{:.2} fps", meta.fps),
+            i18n.tr_with_args(
+                "unit-frames-per-second",
+                &[("value", &i18n.format_number(meta.fps, 2))],
+            ),
         ));
     }
 
     if let Some(ref format) = meta.container_format {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-format"),
             format.to_uppercase(),
         ));
     }
 
+    if let Some(decoder) = decoder {
+        rows = rows.push(build_metadata_row(
+            i18n,
+            i18n.tr("metadata-label-decoder"),
+            decoder,
+        ));
+    }
+
     build_section(
         icons::video_camera(),
         i18n.tr("metadata-section-file"),
@@ -960,6 +1514,22 @@ fn build_file_section_video<'a>(
     )
 }
 
+/// Formats the backend that decoded the media, e.g. "libwebp" or
+/// "image-rs (fallback)".
+fn format_decoder(i18n: &I18n, decoder: MediaDecoder) -> String {
+    match decoder {
+        MediaDecoder::Image(info) if info.fallback => i18n.tr_with_args(
+            "metadata-value-decoder-fallback",
+            &[("decoder", info.decoder.name())],
+        ),
+        MediaDecoder::Image(info) => info.decoder.name().to_string(),
+        MediaDecoder::Video(DecodePath::Hardware(backend)) => {
+            i18n.tr_with_args("video-decode-hardware", &[("backend", backend.name())])
+        }
+        MediaDecoder::Video(DecodePath::Software) => i18n.tr("video-decode-software"),
+    }
+}
+
 fn build_camera_section_view<'a>(i18n: &'a I18n, meta: &ImageMetadata) -> Element<'a, Message> {
     let mut rows = Column::new().spacing(spacing::XS);
 
@@ -975,8 +1545,10 @@ fn build_camera_section_view<'a>(i18n: &'a I18n, meta: &ImageMetadata) -> Elemen
 
     if let Some(ref date) = meta.date_taken {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-date-taken"),
-            date.clone(),
+            parse_date_taken(date)
+                .map_or_else(|| date.clone(), |datetime| i18n.format_datetime(&datetime)),
         ));
     }
 
@@ -992,6 +1564,7 @@ fn build_exposure_section_view<'a>(i18n: &'a I18n, meta: &ImageMetadata) -> Elem
 
     if let Some(ref exposure) = meta.exposure_time {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-exposure"),
             exposure.clone(),
         ));
@@ -999,6 +1572,7 @@ fn build_exposure_section_view<'a>(i18n: &'a I18n, meta: &ImageMetadata) -> Elem
 
     if let Some(ref aperture) = meta.aperture {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-aperture"),
             aperture.clone(),
         ));
@@ -1006,6 +1580,7 @@ fn build_exposure_section_view<'a>(i18n: &'a I18n, meta: &ImageMetadata) -> Elem
 
     if let Some(ref iso) = meta.iso {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-iso"),
             iso.clone(),
         ));
@@ -1018,6 +1593,7 @@ fn build_exposure_section_view<'a>(i18n: &'a I18n, meta: &ImageMetadata) -> Elem
             focal.clone()
         };
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-focal-length"),
             focal_str,
         ));
@@ -1035,9 +1611,24 @@ fn build_gps_section_view<'a>(i18n: &'a I18n, meta: &ImageMetadata) -> Element<'
 
     if let (Some(lat), Some(lon)) = (meta.gps_latitude, meta.gps_longitude) {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-gps"),
             format_gps_coordinates(lat, lon),
         ));
+        rows = rows.push(build_metadata_row(
+            i18n,
+            i18n.tr("metadata-label-gps-dms"),
+            format_gps_dms(lat, lon),
+        ));
+        rows = rows.push(
+            button(text(i18n.tr("metadata-open-map-button")).size(typography::BODY))
+                .on_press(Message::OpenInMap {
+                    latitude: lat,
+                    longitude: lon,
+                })
+                .padding(spacing::XS)
+                .width(Length::Fill),
+        );
     }
 
     build_section(icons::globe(), i18n.tr("metadata-section-gps"), rows.into())
@@ -1051,6 +1642,7 @@ fn build_dublin_core_section_view<'a>(
 
     if let Some(ref title) = meta.dc_title {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-dc-title"),
             title.clone(),
         ));
@@ -1058,6 +1650,7 @@ fn build_dublin_core_section_view<'a>(
 
     if let Some(ref creator) = meta.dc_creator {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-dc-creator"),
             creator.clone(),
         ));
@@ -1065,6 +1658,7 @@ fn build_dublin_core_section_view<'a>(
 
     if let Some(ref description) = meta.dc_description {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-dc-description"),
             description.clone(),
         ));
@@ -1073,6 +1667,7 @@ fn build_dublin_core_section_view<'a>(
     if let Some(ref subject) = meta.dc_subject {
         if !subject.is_empty() {
             rows = rows.push(build_metadata_row(
+                i18n,
                 i18n.tr("metadata-label-dc-subject"),
                 subject.join(", "),
             ));
@@ -1081,6 +1676,7 @@ fn build_dublin_core_section_view<'a>(
 
     if let Some(ref rights) = meta.dc_rights {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-dc-rights"),
             rights.clone(),
         ));
@@ -1101,6 +1697,7 @@ fn build_video_codec_section<'a>(
 
     if let Some(ref codec) = meta.video_codec {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-codec"),
             codec.to_uppercase(),
         ));
@@ -1108,11 +1705,56 @@ fn build_video_codec_section<'a>(
 
     if let Some(bitrate) = meta.video_bitrate {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-bitrate"),
-            format_bitrate(bitrate),
+            i18n.format_bitrate(bitrate),
+        ));
+    }
+
+    if let Some(bits) = meta.bit_depth {
+        rows = rows.push(build_metadata_row(
+            i18n,
+            i18n.tr("metadata-label-bit-depth"),
+            i18n.tr_with_args("metadata-value-bit-depth", &[("bits", &bits.to_string())]),
         ));
     }
 
+    if let Some(hdr) = meta.hdr {
+        rows = rows.push(build_metadata_row(
+            i18n,
+            i18n.tr("metadata-label-hdr"),
+            i18n.tr(hdr.transfer.i18n_key()),
+        ));
+        if let Some((min, max)) = hdr.mastering_luminance {
+            rows = rows.push(build_metadata_row(
+                i18n,
+                i18n.tr("metadata-label-mastering-display"),
+                i18n.tr_with_args(
+                    "unit-nits-range",
+                    &[
+                        ("min", &i18n.format_number(min, 4)),
+                        ("max", &i18n.format_number(max, 0)),
+                    ],
+                ),
+            ));
+        }
+        for (key, nits) in [
+            ("metadata-label-max-cll", hdr.max_cll),
+            ("metadata-label-max-fall", hdr.max_fall),
+        ] {
+            if let Some(nits) = nits {
+                rows = rows.push(build_metadata_row(
+                    i18n,
+                    i18n.tr(key),
+                    i18n.tr_with_args(
+                        "unit-nits",
+                        &[("value", &i18n.format_number(f64::from(nits), 0))],
+                    ),
+                ));
+            }
+        }
+    }
+
     build_section(
         icons::video_camera(),
         i18n.tr("metadata-section-video"),
@@ -1125,6 +1767,7 @@ fn build_audio_section<'a>(i18n: &'a I18n, meta: &ExtendedVideoMetadata) -> Elem
 
     if let Some(ref codec) = meta.audio_codec {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-codec"),
             codec.to_uppercase(),
         ));
@@ -1132,8 +1775,9 @@ fn build_audio_section<'a>(i18n: &'a I18n, meta: &ExtendedVideoMetadata) -> Elem
 
     if let Some(bitrate) = meta.audio_bitrate {
         rows = rows.push(build_metadata_row(
+            i18n,
             i18n.tr("metadata-label-bitrate"),
-            format_bitrate(bitrate),
+            i18n.format_bitrate(bitrate),
         ));
     }
 
@@ -1149,19 +1793,20 @@ fn build_audio_section<'a>(i18n: &'a I18n, meta: &ExtendedVideoMetadata) -> Elem
 // =============================================================================
 
 #[allow(clippy::needless_pass_by_value)] // label is consumed by format! and value is needed for Text
-fn build_metadata_row(label: String, value: String) -> Element<'static, Message> {
+/// Builds a "label: value" row, with the label on the right in right-to-left
+/// languages.
+fn build_metadata_row(i18n: &I18n, label: String, value: String) -> Element<'static, Message> {
+    let label = Text::new(format!("{label}:"))
+        .size(typography::BODY)
+        .width(Length::FillPortion(2));
+    let value = Text::new(value)
+        .size(typography::BODY)
+        .width(Length::FillPortion(3));
+    let (left, right) = i18n.layout_direction().order(label, value);
     Row::new()
         .spacing(spacing::SM)
-        .push(
-            Text::new(format!("{label}:"))
-                .size(typography::BODY)
-                .width(Length::FillPortion(2)),
-        )
-        .push(
-            Text::new(value)
-                .size(typography::BODY)
-                .width(Length::FillPortion(3)),
-        )
+        .push(left)
+        .push(right)
         .into()
 }
 
@@ -1211,4 +1856,13 @@ mod tests {
         assert_eq!(format_duration(65.0), "01:05");
         assert_eq!(format_duration(3665.0), "01:01:05");
     }
+
+    #[test]
+    fn date_taken_is_parsed_in_both_notations() {
+        let expected = chrono::NaiveDate::from_ymd_opt(2024, 3, 15)
+            .and_then(|date| date.and_hms_opt(14, 30, 0));
+        assert_eq!(parse_date_taken("2024-03-15 14:30:00"), expected);
+        assert_eq!(parse_date_taken("2024:03:15 14:30:00"), expected);
+        assert_eq!(parse_date_taken("yesterday"), None);
+    }
 }
@@ -11,6 +11,8 @@
 //! - [`settings`] - Application preferences and configuration
 //! - [`help`] - Keyboard shortcuts and usage documentation
 //! - [`about`] - Application version and credits
+//! - [`pip`] - Picture-in-picture mini player window
+//! - [`welcome`] - First-run welcome screen and shortcut tour
 //!
 //! # Shared Infrastructure
 //!
@@ -21,25 +23,40 @@
 //! - [`design_tokens`] - Design system constants (colors, spacing, sizing)
 //! - [`theme`] - Theme colors and styling helpers
 //! - [`theming`] - Light/Dark/System theme mode management
+//! - [`accessibility`] - Reduced motion, focus outlines and hit targets
+//! - [`guides`] - Composition guides over the viewer and editor canvas
 //! - [`icons`] - SVG icon loading and rendering (visual primitives)
+//! - [`keyboard_layout`] - Shortcut matching across keyboard layouts
+//! - [`keymap`] - User-customizable keyboard shortcuts
 //! - [`action_icons`] - Semantic action-to-icon mapping
 //! - [`navbar`] - Navigation bar with hamburger menu
 //! - [`notifications`] - Toast notification system for user feedback
+//! - [`share_panel`] - Address and QR code of the "Share over LAN" gallery
 
 pub mod about;
+pub mod accessibility;
 pub mod action_icons;
 pub mod components;
 pub mod design_tokens;
+pub mod diagnostics_overlay;
+pub mod guides;
 pub mod help;
 pub mod icons;
 pub mod image_editor;
+pub mod keyboard_layout;
+pub mod keymap;
+pub mod logs;
 pub mod metadata_panel;
 pub mod navbar;
 pub mod notifications;
+pub mod pip;
 pub mod settings;
+pub mod share_panel;
 pub mod state;
 pub mod styles;
 pub mod theme;
 pub mod theming;
+pub mod translations;
 pub mod viewer;
+pub mod welcome;
 pub mod widgets;
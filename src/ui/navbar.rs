@@ -3,20 +3,25 @@
 //!
 //! This module provides the hamburger menu and edit button that appear
 //! at the top of the viewer screen. The menu provides access to Settings,
-//! Help, and About screens.
+//! Help, and About screens, to the "Open with / Send to" commands, and to
+//! the window modes (always on top, borderless, compact).
 
+use crate::app::window_mode::WindowMode;
+use crate::config::OpenWithCommand;
 use crate::i18n::fluent::I18n;
 use crate::media::filter::MediaFilter;
+use crate::ui::accessibility;
 use crate::ui::action_icons;
-use crate::ui::design_tokens::{radius, sizing, spacing};
+use crate::ui::design_tokens::{radius, sizing, spacing, typography};
 use crate::ui::icons;
 use crate::ui::styles;
+use crate::ui::theme;
 use crate::ui::viewer::filter_dropdown::{self, FilterDropdownState};
 use iced::widget::image::{Handle, Image};
 use iced::{
     alignment::{Horizontal, Vertical},
-    widget::{button, container, Column, Container, Row, Text},
-    Border, Element, Length, Theme,
+    widget::{button, container, mouse_area, text, tooltip, Column, Container, Row, Space, Text},
+    Border, Element, Length, Padding, Theme,
 };
 
 /// Contextual data needed to render the navbar.
@@ -38,6 +43,12 @@ pub struct ViewContext<'a> {
     pub total_count: usize,
     /// Filtered count of media files.
     pub filtered_count: usize,
+    /// Whether the folder is being shared over the local network.
+    pub sharing: bool,
+    /// External commands of the "Open with / Send to" submenu.
+    pub open_with: &'a [OpenWithCommand],
+    /// Always-on-top, borderless and compact modes of the window.
+    pub window_mode: WindowMode,
 }
 
 /// Messages emitted by the navbar.
@@ -48,8 +59,26 @@ pub enum Message {
     OpenSettings,
     OpenHelp,
     OpenAbout,
+    /// Pick a file to show in a new viewer window.
+    OpenInNewWindow,
+    /// Check the media files of the current folder for corruption.
+    VerifyFolder,
+    /// Serve the current folder as a gallery on the local network, or show
+    /// its address when already shared.
+    ShareOverLan,
+    /// Stop serving the folder on the local network.
+    StopSharing,
+    /// Remove all metadata from the current image.
+    StripMetadata,
+    /// Run the "Open with / Send to" command at this index on the current file.
+    OpenWith(usize),
     EnterEditor,
     ToggleInfoPanel,
+    ToggleAlwaysOnTop,
+    ToggleBorderless,
+    ToggleCompactMode,
+    /// Move the borderless window by dragging the bar.
+    DragWindow,
     /// Filter dropdown messages.
     FilterDropdown(filter_dropdown::Message),
 }
@@ -61,8 +90,18 @@ pub enum Event {
     OpenSettings,
     OpenHelp,
     OpenAbout,
+    OpenInNewWindow,
+    VerifyFolder,
+    ShareOverLan,
+    StopSharing,
+    StripMetadata,
+    OpenWith(usize),
     EnterEditor,
     ToggleInfoPanel,
+    ToggleAlwaysOnTop,
+    ToggleBorderless,
+    ToggleCompactMode,
+    DragWindow,
     /// Filter dropdown message to be handled by the app.
     FilterChanged(filter_dropdown::Message),
 }
@@ -90,6 +129,30 @@ pub fn update(message: Message, menu_open: &mut bool) -> Event {
             *menu_open = false;
             Event::OpenAbout
         }
+        Message::OpenInNewWindow => {
+            *menu_open = false;
+            Event::OpenInNewWindow
+        }
+        Message::VerifyFolder => {
+            *menu_open = false;
+            Event::VerifyFolder
+        }
+        Message::ShareOverLan => {
+            *menu_open = false;
+            Event::ShareOverLan
+        }
+        Message::StopSharing => {
+            *menu_open = false;
+            Event::StopSharing
+        }
+        Message::StripMetadata => {
+            *menu_open = false;
+            Event::StripMetadata
+        }
+        Message::OpenWith(index) => {
+            *menu_open = false;
+            Event::OpenWith(index)
+        }
         Message::EnterEditor => {
             *menu_open = false;
             Event::EnterEditor
@@ -98,6 +161,19 @@ pub fn update(message: Message, menu_open: &mut bool) -> Event {
             *menu_open = false;
             Event::ToggleInfoPanel
         }
+        Message::ToggleAlwaysOnTop => {
+            *menu_open = false;
+            Event::ToggleAlwaysOnTop
+        }
+        Message::ToggleBorderless => {
+            *menu_open = false;
+            Event::ToggleBorderless
+        }
+        Message::ToggleCompactMode => {
+            *menu_open = false;
+            Event::ToggleCompactMode
+        }
+        Message::DragWindow => Event::DragWindow,
         Message::FilterDropdown(filter_msg) => {
             // Close hamburger menu when interacting with filter
             *menu_open = false;
@@ -136,7 +212,7 @@ fn build_top_bar<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
             sizing::ICON_MD,
         ))
         .on_press(Message::ToggleMenu)
-        .padding(spacing::XS)
+        .padding(accessibility::enlarge(spacing::XS))
         .style(styles::button::selected)
     } else {
         button(icons::sized(
@@ -144,17 +220,24 @@ fn build_top_bar<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
             sizing::ICON_MD,
         ))
         .on_press(Message::ToggleMenu)
-        .padding(spacing::XS)
+        .padding(accessibility::enlarge(spacing::XS))
     };
 
+    let text_padding = accessibility::enlarge(button::DEFAULT_PADDING);
     let edit_label = ctx.i18n.tr("navbar-edit-button");
     let edit_button = if ctx.metadata_editor_has_changes {
         // Disabled: metadata editor has unsaved changes
-        button(Text::new(edit_label)).style(styles::button::disabled())
+        button(Text::new(edit_label))
+            .padding(text_padding)
+            .style(styles::button::disabled())
     } else if ctx.can_edit {
-        button(Text::new(edit_label)).on_press(Message::EnterEditor)
+        button(Text::new(edit_label))
+            .padding(text_padding)
+            .on_press(Message::EnterEditor)
     } else {
-        button(Text::new(edit_label)).style(styles::button::disabled())
+        button(Text::new(edit_label))
+            .padding(text_padding)
+            .style(styles::button::disabled())
     };
 
     // Filter button
@@ -174,34 +257,51 @@ fn build_top_bar<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
     let info_label = ctx.i18n.tr("navbar-info-button");
     let info_button = if !ctx.has_media {
         // No media: disabled
-        button(Text::new(info_label)).style(styles::button::disabled())
+        button(Text::new(info_label))
+            .padding(text_padding)
+            .style(styles::button::disabled())
     } else if ctx.info_panel_open && ctx.metadata_editor_has_changes {
         // Panel open with unsaved changes: disabled (can't close)
-        button(Text::new(info_label)).style(styles::button::selected)
+        button(Text::new(info_label))
+            .padding(text_padding)
+            .style(styles::button::selected)
     } else if ctx.info_panel_open {
         // Panel open, no unsaved changes: can close
         button(Text::new(info_label))
+            .padding(text_padding)
             .on_press(Message::ToggleInfoPanel)
             .style(styles::button::selected)
     } else {
         // Panel closed: can open
-        button(Text::new(info_label)).on_press(Message::ToggleInfoPanel)
+        button(Text::new(info_label))
+            .padding(text_padding)
+            .on_press(Message::ToggleInfoPanel)
     };
 
     let row = Row::new()
         .spacing(spacing::SM)
         .padding(spacing::SM)
         .align_y(Vertical::Center)
-        .push(menu_button)
+        .push(styles::tooltip::styled(
+            menu_button,
+            ctx.i18n.tr("navbar-menu-tooltip"),
+            tooltip::Position::Bottom,
+        ))
         .push(edit_button)
         .push(filter_button)
         .push(info_button);
 
-    Container::new(row)
+    let bar = Container::new(row)
         .width(Length::Fill)
         .align_x(Horizontal::Left)
-        .style(styles::editor::toolbar)
-        .into()
+        .style(styles::editor::toolbar);
+
+    // Without a title bar, the bar itself moves the window
+    if ctx.window_mode.borderless {
+        mouse_area(bar).on_press(Message::DragWindow).into()
+    } else {
+        bar.into()
+    }
 }
 
 /// Build the dropdown menu with Settings, Help, and About options.
@@ -212,13 +312,72 @@ fn build_dropdown<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
         Message::OpenSettings,
     );
 
+    let new_window_item = build_menu_item(
+        icons::expand(),
+        ctx.i18n.tr("menu-open-in-new-window"),
+        Message::OpenInNewWindow,
+    );
+
+    let verify_item = build_menu_item(
+        icons::checkmark(),
+        ctx.i18n.tr("menu-verify-folder"),
+        Message::VerifyFolder,
+    );
+
+    let share_item = build_menu_item(
+        icons::globe(),
+        ctx.i18n.tr("menu-share-over-lan"),
+        Message::ShareOverLan,
+    );
+
+    let strip_item = build_menu_item(
+        icons::trash(),
+        ctx.i18n.tr("menu-strip-metadata"),
+        Message::StripMetadata,
+    );
+
     let help_item = build_menu_item(icons::help(), ctx.i18n.tr("menu-help"), Message::OpenHelp);
 
     let about_item = build_menu_item(icons::info(), ctx.i18n.tr("menu-about"), Message::OpenAbout);
 
-    let menu_column = Column::new()
+    let mut menu_column = Column::new()
         .spacing(spacing::XXS)
         .push(settings_item)
+        .push(new_window_item)
+        .push(verify_item)
+        .push(share_item);
+
+    if ctx.sharing {
+        menu_column = menu_column.push(build_menu_item(
+            icons::cross(),
+            ctx.i18n.tr("menu-stop-sharing"),
+            Message::StopSharing,
+        ));
+    }
+
+    let mut menu_column = menu_column.push(strip_item);
+
+    if ctx.has_media && !ctx.open_with.is_empty() {
+        menu_column = menu_column.push(build_open_with_submenu(ctx));
+    }
+
+    let window_mode = ctx.window_mode;
+    let menu_column = menu_column
+        .push(build_menu_toggle(
+            ctx.i18n.tr("menu-always-on-top"),
+            window_mode.always_on_top,
+            Message::ToggleAlwaysOnTop,
+        ))
+        .push(build_menu_toggle(
+            ctx.i18n.tr("menu-borderless"),
+            window_mode.borderless,
+            Message::ToggleBorderless,
+        ))
+        .push(build_menu_toggle(
+            ctx.i18n.tr("menu-compact-mode"),
+            window_mode.compact,
+            Message::ToggleCompactMode,
+        ))
         .push(help_item)
         .push(about_item);
 
@@ -236,6 +395,36 @@ fn build_dropdown<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
         .into()
 }
 
+/// Build the "Open with / Send to" submenu: a caption followed by the
+/// indented commands.
+fn build_open_with_submenu<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
+    let caption = Text::new(ctx.i18n.tr("menu-open-with"))
+        .size(typography::CAPTION)
+        .style(|_: &Theme| text::Style {
+            color: Some(theme::muted_text_color()),
+        });
+
+    let commands = ctx.open_with.iter().enumerate().fold(
+        Column::new().spacing(spacing::XXS),
+        |column, (index, command)| {
+            column.push(build_menu_item(
+                icons::chevron_right(),
+                command.name.clone(),
+                Message::OpenWith(index),
+            ))
+        },
+    );
+
+    Column::new()
+        .spacing(spacing::XXS)
+        .push(Container::new(caption).padding([spacing::XXS, spacing::SM]))
+        .push(Container::new(commands).padding(Padding {
+            left: spacing::MD,
+            ..Padding::ZERO
+        }))
+        .into()
+}
+
 /// Build a single menu item with icon and label.
 fn build_menu_item<'a>(
     icon: Image<Handle>,
@@ -258,6 +447,31 @@ fn build_menu_item<'a>(
         .into()
 }
 
+/// Build a menu item that switches a mode on or off, checked when on.
+fn build_menu_toggle<'a>(label: String, enabled: bool, message: Message) -> Element<'a, Message> {
+    let check: Element<'a, Message> = if enabled {
+        icons::sized(icons::checkmark(), sizing::ICON_SM).into()
+    } else {
+        Space::new()
+            .width(Length::Fixed(sizing::ICON_SM))
+            .height(Length::Fixed(sizing::ICON_SM))
+            .into()
+    };
+
+    let row = Row::new()
+        .spacing(spacing::SM)
+        .align_y(Vertical::Center)
+        .push(check)
+        .push(Text::new(label));
+
+    button(row)
+        .on_press(message)
+        .padding([spacing::XS, spacing::SM])
+        .width(Length::Fill)
+        .style(menu_item_style)
+        .into()
+}
+
 /// Style function for menu items.
 fn menu_item_style(theme: &Theme, status: button::Status) -> button::Style {
     let palette = theme.extended_palette();
@@ -318,6 +532,9 @@ mod tests {
             filter_dropdown: &filter_dropdown,
             total_count: 10,
             filtered_count: 10,
+            sharing: false,
+            open_with: &[],
+            window_mode: WindowMode::default(),
         };
         let _element = view(ctx);
     }
@@ -325,6 +542,10 @@ mod tests {
     #[test]
     fn navbar_view_renders_with_menu_open() {
         let i18n = I18n::default();
+        let open_with = [OpenWithCommand {
+            name: "Open in GIMP".to_string(),
+            command: vec!["gimp".to_string(), "{path}".to_string()],
+        }];
         let filter = MediaFilter::default();
         let filter_dropdown = FilterDropdownState::new();
         let ctx = ViewContext {
@@ -338,6 +559,13 @@ mod tests {
             filter_dropdown: &filter_dropdown,
             total_count: 10,
             filtered_count: 10,
+            sharing: true,
+            open_with: &open_with,
+            window_mode: WindowMode {
+                always_on_top: true,
+                borderless: true,
+                compact: false,
+            },
         };
         let _element = view(ctx);
     }
@@ -358,6 +586,9 @@ mod tests {
             filter_dropdown: &filter_dropdown,
             total_count: 10,
             filtered_count: 10,
+            sharing: false,
+            open_with: &[],
+            window_mode: WindowMode::default(),
         };
         let _element = view(ctx);
     }
@@ -378,6 +609,9 @@ mod tests {
             filter_dropdown: &filter_dropdown,
             total_count: 0,
             filtered_count: 0,
+            sharing: false,
+            open_with: &[],
+            window_mode: WindowMode::default(),
         };
         let _element = view(ctx);
     }
@@ -390,6 +624,20 @@ mod tests {
         assert!(matches!(event, Event::ToggleInfoPanel));
     }
 
+    #[test]
+    fn window_mode_toggles_close_menu() {
+        let mut menu_open = true;
+        let event = update(Message::ToggleCompactMode, &mut menu_open);
+        assert!(!menu_open);
+        assert!(matches!(event, Event::ToggleCompactMode));
+
+        // Dragging the bar leaves the menu as it is
+        menu_open = true;
+        let event = update(Message::DragWindow, &mut menu_open);
+        assert!(menu_open);
+        assert!(matches!(event, Event::DragWindow));
+    }
+
     #[test]
     fn toggle_menu_changes_state() {
         let mut menu_open = false;
@@ -419,5 +667,30 @@ mod tests {
         let event = update(Message::OpenAbout, &mut menu_open);
         assert!(!menu_open);
         assert!(matches!(event, Event::OpenAbout));
+
+        menu_open = true;
+        let event = update(Message::VerifyFolder, &mut menu_open);
+        assert!(!menu_open);
+        assert!(matches!(event, Event::VerifyFolder));
+
+        menu_open = true;
+        let event = update(Message::StripMetadata, &mut menu_open);
+        assert!(!menu_open);
+        assert!(matches!(event, Event::StripMetadata));
+
+        menu_open = true;
+        let event = update(Message::ShareOverLan, &mut menu_open);
+        assert!(!menu_open);
+        assert!(matches!(event, Event::ShareOverLan));
+
+        menu_open = true;
+        let event = update(Message::StopSharing, &mut menu_open);
+        assert!(!menu_open);
+        assert!(matches!(event, Event::StopSharing));
+
+        menu_open = true;
+        let event = update(Message::OpenWith(1), &mut menu_open);
+        assert!(!menu_open);
+        assert!(matches!(event, Event::OpenWith(1)));
     }
 }
@@ -17,6 +17,8 @@ pub enum Message {
     Dismiss(NotificationId),
     /// Tick for checking auto-dismiss timers.
     Tick,
+    /// Open the link of a notification, handled by the application.
+    OpenLink(String),
 }
 
 /// Manages the notification queue and visible notifications.
@@ -95,6 +97,7 @@ impl Manager {
             Message::Tick => {
                 self.tick();
             }
+            Message::OpenLink(_) => {}
         }
     }
 
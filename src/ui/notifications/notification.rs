@@ -4,6 +4,7 @@
 //! This module defines the `Notification` struct and `Severity` enum
 //! used throughout the notification system.
 
+use crate::i18n::fluent::I18n;
 use crate::ui::design_tokens::palette;
 use iced::Color;
 use std::time::{Duration, Instant};
@@ -76,10 +77,15 @@ pub struct Notification {
     message_key: String,
     /// Optional arguments for message interpolation.
     message_args: Vec<(String, String)>,
+    /// Arguments whose value is an i18n key, translated at render time so a
+    /// queued notification follows language changes.
+    translated_args: Vec<(String, String)>,
     /// When this notification was created.
     created_at: Instant,
     /// Custom auto-dismiss duration (overrides severity default).
     custom_dismiss_duration: Option<Duration>,
+    /// Optional link shown under the message: i18n key of its label and URL.
+    link: Option<(String, String)>,
 }
 
 impl Notification {
@@ -93,8 +99,10 @@ impl Notification {
             severity,
             message_key: message_key.into(),
             message_args: Vec::new(),
+            translated_args: Vec::new(),
             created_at: Instant::now(),
             custom_dismiss_duration: None,
+            link: None,
         }
     }
 
@@ -127,6 +135,17 @@ impl Notification {
         self
     }
 
+    /// Adds an argument whose value is the translation of `value_key`.
+    #[must_use]
+    pub fn with_translated_arg(
+        mut self,
+        key: impl Into<String>,
+        value_key: impl Into<String>,
+    ) -> Self {
+        self.translated_args.push((key.into(), value_key.into()));
+        self
+    }
+
     /// Sets a custom auto-dismiss duration, overriding the severity default.
     ///
     /// Useful for notifications that need more time to read (e.g., long file lists).
@@ -136,6 +155,13 @@ impl Notification {
         self
     }
 
+    /// Adds a link under the message, opened in the browser.
+    #[must_use]
+    pub fn with_link(mut self, label_key: impl Into<String>, url: impl Into<String>) -> Self {
+        self.link = Some((label_key.into(), url.into()));
+        self
+    }
+
     /// Returns the notification's unique ID.
     #[must_use]
     pub fn id(&self) -> NotificationId {
@@ -160,6 +186,34 @@ impl Notification {
         &self.message_args
     }
 
+    /// Resolves the message in the current language of `i18n`.
+    #[must_use]
+    pub fn message_text(&self, i18n: &I18n) -> String {
+        if self.message_args.is_empty() && self.translated_args.is_empty() {
+            return i18n.tr(&self.message_key);
+        }
+        let translated: Vec<(&str, String)> = self
+            .translated_args
+            .iter()
+            .map(|(key, value_key)| (key.as_str(), i18n.tr(value_key)))
+            .collect();
+        let args: Vec<(&str, &str)> = self
+            .message_args
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .chain(translated.iter().map(|(key, value)| (*key, value.as_str())))
+            .collect();
+        i18n.tr_with_args(&self.message_key, &args)
+    }
+
+    /// Returns the i18n key of the link label and the URL, if any.
+    #[must_use]
+    pub fn link(&self) -> Option<(&str, &str)> {
+        self.link
+            .as_ref()
+            .map(|(label_key, url)| (label_key.as_str(), url.as_str()))
+    }
+
     /// Returns when this notification was created.
     #[must_use]
     pub fn created_at(&self) -> Instant {
@@ -245,6 +299,32 @@ mod tests {
         assert_eq!(notification.message_args().len(), 2);
     }
 
+    #[test]
+    fn translated_args_follow_the_current_language() {
+        let mut i18n = I18n::default();
+        let notification = Notification::info("notification-layout-applied")
+            .with_translated_arg("name", "layout-name-culling");
+
+        for locale in ["en-US", "fr"] {
+            i18n.set_locale(locale.parse().expect("valid locale"));
+            let name = i18n.tr("layout-name-culling");
+            assert_eq!(
+                notification.message_text(&i18n),
+                i18n.tr_with_args("notification-layout-applied", &[("name", &name)])
+            );
+        }
+    }
+
+    #[test]
+    fn notification_link_is_optional() {
+        assert!(Notification::info("test").link().is_none());
+        let notification = Notification::info("test").with_link("test-link", "https://example.org");
+        assert_eq!(
+            notification.link(),
+            Some(("test-link", "https://example.org"))
+        );
+    }
+
     #[test]
     fn notification_constructors_set_correct_severity() {
         assert_eq!(Notification::success("").severity(), Severity::Success);
@@ -11,8 +11,10 @@ use crate::ui::design_tokens::{
     border, opacity, palette, radius, shadow, sizing, spacing, typography,
 };
 use crate::ui::icons;
+use crate::ui::styles;
+use crate::ui::theming;
 use iced::widget::image::{Handle, Image};
-use iced::widget::{button, container, opaque, text, Column, Container, Row, Text};
+use iced::widget::{button, container, opaque, text, tooltip, Column, Container, Row, Text};
 use iced::{alignment, Color, Element, Length, Theme};
 
 /// Toast widget configuration.
@@ -22,20 +24,9 @@ impl Toast {
     /// Renders a single toast notification.
     pub fn view<'a>(notification: &'a Notification, i18n: &'a I18n) -> Element<'a, Message> {
         let severity = notification.severity();
-        let accent_color = severity.color();
 
-        // Resolve the message text using i18n with optional arguments
-        let message_text = if notification.message_args().is_empty() {
-            i18n.tr(notification.message_key())
-        } else {
-            // Convert (String, String) to (&str, &str) for tr_with_args
-            let args: Vec<(&str, &str)> = notification
-                .message_args()
-                .iter()
-                .map(|(k, v)| (k.as_str(), v.as_str()))
-                .collect();
-            i18n.tr_with_args(notification.message_key(), &args)
-        };
+        // Resolved on every render, so the text follows language changes
+        let message_text = notification.message_text(i18n);
 
         // Severity icon (PNG icons have fixed colors)
         let icon = Self::severity_icon(severity);
@@ -49,12 +40,27 @@ impl Toast {
                     color: Some(theme.palette().text),
                 });
 
+        // Optional link under the message
+        let mut message_column = Column::new().spacing(spacing::XXS).push(message_widget);
+        if let Some((label_key, url)) = notification.link() {
+            message_column = message_column.push(
+                button(Text::new(i18n.tr(label_key)).size(typography::BODY))
+                    .on_press(Message::OpenLink(url.to_string()))
+                    .padding(0)
+                    .style(button::text),
+            );
+        }
+
         // Dismiss button (always visible, uses main text color for good contrast)
         let notification_id = notification.id();
-        let dismiss_button = button(icons::sized(icons::cross(), sizing::ICON_SM))
-            .on_press(Message::Dismiss(notification_id))
-            .padding(spacing::XXS)
-            .style(dismiss_button_style);
+        let dismiss_button = styles::tooltip::styled(
+            button(icons::sized(icons::cross(), sizing::ICON_SM))
+                .on_press(Message::Dismiss(notification_id))
+                .padding(spacing::XXS)
+                .style(dismiss_button_style),
+            i18n.tr("notification-dismiss-tooltip"),
+            tooltip::Position::Left,
+        );
 
         // Layout: [icon] [message] [dismiss]
         let content = Row::new()
@@ -62,7 +68,7 @@ impl Toast {
             .align_y(alignment::Vertical::Center)
             .push(Container::new(icon_widget).padding(spacing::XXS))
             .push(
-                Container::new(message_widget)
+                Container::new(message_column)
                     .width(Length::Fill)
                     .align_x(alignment::Horizontal::Left),
             )
@@ -76,7 +82,9 @@ impl Toast {
             Container::new(content)
                 .width(Length::Fixed(sizing::TOAST_WIDTH))
                 .padding(spacing::SM)
-                .style(move |theme: &Theme| toast_container_style(theme, accent_color)),
+                .style(move |theme: &Theme| {
+                    toast_container_style(theme, severity_color(theme, severity))
+                }),
         )
     }
 
@@ -122,6 +130,20 @@ impl Toast {
     }
 }
 
+/// Returns the border color of a toast: the semantic colors of the theme when
+/// the user picked an accent or a custom palette, the fixed ones otherwise.
+fn severity_color(theme: &Theme, severity: Severity) -> Color {
+    if !matches!(theme, Theme::Custom(_)) {
+        return severity.color();
+    }
+    match severity {
+        Severity::Success => theme.palette().success,
+        Severity::Info => theming::accent(theme).base,
+        Severity::Warning => severity.color(),
+        Severity::Error => theme.palette().danger,
+    }
+}
+
 /// Style function for the toast container.
 fn toast_container_style(theme: &Theme, accent_color: Color) -> container::Style {
     let bg_color = theme.extended_palette().background.base.color;
@@ -204,6 +226,21 @@ mod tests {
         assert!(style.background.is_some());
     }
 
+    #[test]
+    fn severity_color_follows_custom_palettes() {
+        assert_eq!(
+            severity_color(&Theme::Dark, Severity::Info),
+            Severity::Info.color()
+        );
+        let custom = theming::CustomPalette {
+            danger: Color::from_rgb(1.0, 0.0, 1.0),
+            ..theming::CustomPalette::default()
+        };
+        let theme = theming::iced_theme(theming::ThemeMode::Dark, None, Some(&custom));
+        assert_eq!(severity_color(&theme, Severity::Error), custom.danger);
+        assert_eq!(severity_color(&theme, Severity::Info), custom.accent);
+    }
+
     #[test]
     fn severity_icons_are_defined() {
         // Just verify icons don't panic when created
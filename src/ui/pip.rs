@@ -0,0 +1,375 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Picture-in-picture mini player.
+//!
+//! A small frameless window, kept above the other windows, that goes on
+//! playing a video while the main window is used to browse other media. It
+//! has its own player and decoder, so loading media in the main window does
+//! not interrupt it. Controls appear while the cursor is over the window.
+
+use crate::i18n::fluent::I18n;
+use crate::media::VideoData;
+use crate::ui::design_tokens::{sizing, spacing};
+use crate::ui::widgets::{ColorAdjustments, FrameTransform, ToneMapParams, VideoShader};
+use crate::ui::{action_icons, icons, styles};
+use crate::video_player::{CacheConfig, PlaybackMessage, SharedLufsCache, VideoPlayer, Volume};
+use iced::widget::{button, container, mouse_area, responsive, row, text, tooltip, Space, Stack};
+use iced::{alignment, window, Element, Length, Size, Subscription};
+use std::path::{Path, PathBuf};
+
+/// Initial size of the mini player window, in logical pixels.
+pub const WINDOW_SIZE: Size = Size::new(400.0, 225.0);
+
+/// Smallest size of the mini player window, in logical pixels.
+pub const MIN_WINDOW_SIZE: Size = Size::new(160.0, 90.0);
+
+/// Playback session IDs of the mini player start here, so its decoder
+/// subscription never shares an ID with the viewer's.
+const SESSION_ID_BASE: u64 = 1 << 63;
+
+/// Messages of the mini player window.
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// Event from the mini player's decoder.
+    Playback(PlaybackMessage),
+    TogglePlayback,
+    /// Go back to the main window, playing the video there.
+    ReturnToMain,
+    Close,
+    /// The cursor entered (`true`) or left (`false`) the window.
+    Hovered(bool),
+    /// Move the frameless window with the mouse.
+    DragWindow,
+}
+
+/// Events propagated to the application.
+#[derive(Debug, Clone)]
+pub enum Event {
+    None,
+    /// Close the mini player and continue the video in the main window.
+    ReturnToMain {
+        path: PathBuf,
+        position_secs: f64,
+        playing: bool,
+    },
+    /// Close the mini player.
+    Close,
+    /// Move the window with the mouse.
+    DragWindow,
+    /// Playback failed; the message is the decoder's error.
+    Failed(String),
+}
+
+/// Video handed over to the mini player.
+#[derive(Debug, Clone)]
+pub struct Handoff {
+    pub path: PathBuf,
+    pub video: VideoData,
+    pub position_secs: f64,
+    pub playing: bool,
+    pub transform: FrameTransform,
+    pub color_adjustments: ColorAdjustments,
+    pub tone_map: ToneMapParams,
+}
+
+/// State of the mini player window.
+pub struct State {
+    window: window::Id,
+    path: PathBuf,
+    player: VideoPlayer,
+    shader: VideoShader<Message>,
+    session_id: u64,
+    /// Position to seek to once the decoder has started.
+    start_at: Option<f64>,
+    /// Whether playback starts once the decoder has started.
+    play_on_start: bool,
+    transform: FrameTransform,
+    color_adjustments: ColorAdjustments,
+    tone_map: ToneMapParams,
+    volume: f32,
+    muted: bool,
+    hovered: bool,
+}
+
+impl State {
+    /// Creates the mini player of `window`, which plays the handed over
+    /// video from its position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the video player cannot be created.
+    pub fn new(
+        window: window::Id,
+        handoff: Handoff,
+        sequence_number: u64,
+        volume: f32,
+        muted: bool,
+    ) -> crate::error::Result<Self> {
+        let player = VideoPlayer::new(&handoff.video)?;
+        Ok(Self {
+            window,
+            path: handoff.path,
+            player,
+            shader: VideoShader::new(),
+            session_id: SESSION_ID_BASE | sequence_number,
+            start_at: Some(handoff.position_secs),
+            play_on_start: handoff.playing,
+            transform: handoff.transform,
+            color_adjustments: handoff.color_adjustments,
+            tone_map: handoff.tone_map,
+            volume,
+            muted,
+            hovered: false,
+        })
+    }
+
+    /// Returns the mini player's window.
+    #[must_use]
+    pub fn window(&self) -> window::Id {
+        self.window
+    }
+
+    /// Returns the path of the video being played.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Stops playback; call before dropping the mini player.
+    pub fn stop(&mut self) {
+        self.player.stop();
+    }
+
+    /// Returns the window title: the file name of the video.
+    #[must_use]
+    pub fn title(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+
+    /// Decodes the video while the mini player is open.
+    pub fn subscription(
+        &self,
+        lufs_cache: Option<SharedLufsCache>,
+        normalization_enabled: bool,
+        cache_config: CacheConfig,
+        history_mb: u32,
+        hardware_decoding: bool,
+    ) -> Subscription<Message> {
+        crate::video_player::subscription::video_playback(
+            self.path.clone(),
+            self.session_id,
+            lufs_cache,
+            normalization_enabled,
+            cache_config,
+            history_mb,
+            self.player.video_data().sequence.clone(),
+            hardware_decoding,
+        )
+        .map(Message::Playback)
+    }
+
+    /// Processes a mini player message.
+    pub fn update(&mut self, message: Message) -> Event {
+        match message {
+            Message::Playback(event) => return self.handle_playback(event),
+            Message::TogglePlayback => {
+                if self.player.state().is_playing_or_will_resume() {
+                    self.player.pause();
+                } else {
+                    self.player.play();
+                }
+            }
+            Message::ReturnToMain => {
+                let position_secs = self.player.state().position().unwrap_or(0.0);
+                let playing = self.player.state().is_playing_or_will_resume();
+                self.player.stop();
+                return Event::ReturnToMain {
+                    path: self.path.clone(),
+                    position_secs,
+                    playing,
+                };
+            }
+            Message::Close => {
+                self.player.stop();
+                return Event::Close;
+            }
+            Message::Hovered(hovered) => self.hovered = hovered,
+            Message::DragWindow => return Event::DragWindow,
+        }
+        Event::None
+    }
+
+    fn handle_playback(&mut self, event: PlaybackMessage) -> Event {
+        match event {
+            PlaybackMessage::Started(command_sender) => {
+                self.player.set_command_sender(command_sender);
+                self.player.set_volume(Volume::new(self.volume));
+                self.player.set_muted(self.muted);
+                self.player.seek(self.start_at.take().unwrap_or(0.0));
+                if self.play_on_start {
+                    self.player.play();
+                }
+            }
+            PlaybackMessage::FrameReady {
+                rgba_data,
+                width,
+                height,
+                pts_secs,
+            } => {
+                self.shader.set_frame(rgba_data, width, height);
+                self.player.update_position(pts_secs);
+            }
+            PlaybackMessage::Buffering => {
+                if !matches!(
+                    self.player.state(),
+                    crate::video_player::PlaybackState::Seeking { .. }
+                ) {
+                    let position = self.player.state().position().unwrap_or(0.0);
+                    self.player.set_buffering(position);
+                }
+            }
+            PlaybackMessage::EndOfStream => {
+                self.player.set_at_end_of_stream();
+                let duration = self.player.video_data().duration_secs;
+                self.player.pause_at(duration);
+            }
+            PlaybackMessage::Error(message) => {
+                self.player.set_error(message.clone());
+                return Event::Failed(message);
+            }
+            PlaybackMessage::AudioPts(pts_secs) => self.player.update_audio_pts(pts_secs),
+            PlaybackMessage::HistoryExhausted => self.player.reset_history_position(),
+            PlaybackMessage::DecodePath(_) | PlaybackMessage::CacheStats(_) => {}
+        }
+        Event::None
+    }
+
+    /// Renders the video fitted to the window, with the controls on hover.
+    pub fn view<'a>(&'a self, i18n: &I18n) -> Element<'a, Message> {
+        let transform = self.transform;
+        let color_adjustments = self.color_adjustments;
+        let tone_map = self.tone_map;
+        let video = container(responsive(move |size| {
+            let (width, height) = self.fitted_size(size);
+            self.shader
+                .view_sized(width, height, transform, color_adjustments, tone_map)
+        }))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(alignment::Horizontal::Center)
+        .align_y(alignment::Vertical::Center)
+        .style(styles::overlay::controls_container);
+
+        // Dragging anywhere moves the frameless window
+        let mut layers = Stack::new().push(
+            mouse_area(video)
+                .on_press(Message::DragWindow)
+                .on_double_click(Message::ReturnToMain),
+        );
+        if self.hovered {
+            layers = layers.push(self.controls(i18n));
+        }
+
+        mouse_area(layers)
+            .on_enter(Message::Hovered(true))
+            .on_exit(Message::Hovered(false))
+            .into()
+    }
+
+    /// Controls shown at the bottom of the window while it is hovered.
+    fn controls(&self, i18n: &I18n) -> Element<'_, Message> {
+        let icon_size = sizing::ICON_SM;
+        let (play_pause, play_pause_tip) = if self.player.state().is_playing_or_will_resume() {
+            (action_icons::video::toolbar::pause(), "pip-pause-tooltip")
+        } else {
+            (action_icons::video::toolbar::play(), "pip-play-tooltip")
+        };
+        let bar = row![
+            tip(
+                button(icons::sized(play_pause, icon_size))
+                    .on_press(Message::TogglePlayback)
+                    .padding(spacing::XXS),
+                i18n.tr(play_pause_tip),
+            ),
+            Space::new().width(Length::Fill),
+            tip(
+                button(icons::sized(
+                    action_icons::video::toolbar::return_to_main(),
+                    icon_size
+                ))
+                .on_press(Message::ReturnToMain)
+                .padding(spacing::XXS),
+                i18n.tr("pip-return-tooltip"),
+            ),
+            tip(
+                button(text("×").size(icon_size))
+                    .on_press(Message::Close)
+                    .padding(spacing::XXS),
+                i18n.tr("pip-close-tooltip"),
+            ),
+        ]
+        .spacing(spacing::XS)
+        .align_y(iced::Alignment::Center);
+
+        container(
+            container(bar)
+                .width(Length::Fill)
+                .padding(spacing::XS)
+                .style(styles::overlay::controls_container),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Bottom)
+        .into()
+    }
+
+    /// Returns the largest size of the displayed frame that fits `bounds`.
+    #[allow(clippy::cast_precision_loss)] // Frame dimensions are far below f32 precision limits
+    fn fitted_size(&self, bounds: Size) -> (f32, f32) {
+        let video = self.player.video_data();
+        let (width, height) = if self.transform.rotation.swaps_dimensions() {
+            (video.height, video.width)
+        } else {
+            (video.width, video.height)
+        };
+        fit(width as f32, height as f32, bounds)
+    }
+}
+
+/// Scales `width` × `height` to fit `bounds`, keeping the aspect ratio.
+fn fit(width: f32, height: f32, bounds: Size) -> (f32, f32) {
+    if width <= 0.0 || height <= 0.0 {
+        return (bounds.width, bounds.height);
+    }
+    let scale = (bounds.width / width).min(bounds.height / height);
+    (width * scale, height * scale)
+}
+
+/// Tooltip above a control of the mini player.
+fn tip<'a>(content: impl Into<Element<'a, Message>>, label: String) -> Element<'a, Message> {
+    styles::tooltip::styled(content, label, tooltip::Position::Top).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_keeps_the_aspect_ratio() {
+        let (width, height) = fit(1920.0, 1080.0, Size::new(400.0, 400.0));
+        assert!((width - 400.0).abs() < f32::EPSILON);
+        assert!((height - 225.0).abs() < f32::EPSILON);
+
+        let (width, height) = fit(1080.0, 1920.0, Size::new(400.0, 225.0));
+        assert!((height - 225.0).abs() < f32::EPSILON);
+        assert!(width < 400.0);
+    }
+
+    #[test]
+    fn session_ids_never_collide_with_the_viewer() {
+        assert!(SESSION_ID_BASE | 1 > u64::from(u32::MAX));
+        assert_ne!(SESSION_ID_BASE | 1, SESSION_ID_BASE | 2);
+    }
+}
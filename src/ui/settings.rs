@@ -7,33 +7,50 @@
 //! bubble up for the parent application to handle side effects.
 
 use crate::config::{
-    BackgroundTheme, SortOrder, DEFAULT_DEBLUR_MODEL_URL, DEFAULT_FRAME_CACHE_MB,
-    DEFAULT_FRAME_HISTORY_MB, DEFAULT_KEYBOARD_SEEK_STEP_SECS, DEFAULT_MAX_SKIP_ATTEMPTS,
-    DEFAULT_OVERLAY_TIMEOUT_SECS, DEFAULT_UPSCALE_MODEL_URL, DEFAULT_ZOOM_STEP_PERCENT,
-    MAX_FRAME_CACHE_MB, MAX_FRAME_HISTORY_MB, MAX_KEYBOARD_SEEK_STEP_SECS, MAX_MAX_SKIP_ATTEMPTS,
-    MAX_OVERLAY_TIMEOUT_SECS, MIN_FRAME_CACHE_MB, MIN_FRAME_HISTORY_MB,
-    MIN_KEYBOARD_SEEK_STEP_SECS, MIN_MAX_SKIP_ATTEMPTS, MIN_OVERLAY_TIMEOUT_SECS,
+    BackgroundTheme, DragButton, MiddleClickAction, OverlayPosition, ResumePlayback, SavePolicy,
+    SortDirection, SortOrder, ToneMapping, WheelAction, DEFAULT_BACKGROUND_MODEL_URL,
+    DEFAULT_DEBLUR_MODEL_URL, DEFAULT_EDITED_FOLDER, DEFAULT_FRAME_CACHE_MB,
+    DEFAULT_FRAME_HISTORY_MB, DEFAULT_FRAME_NAME_TEMPLATE, DEFAULT_KEYBOARD_SEEK_STEP_SECS,
+    DEFAULT_MAX_SKIP_ATTEMPTS, DEFAULT_OVERLAY_TIMEOUT_SECS, DEFAULT_PREFETCH_CACHE_SIZE,
+    DEFAULT_SEQUENCE_FPS, DEFAULT_SIMILARITY_THRESHOLD, DEFAULT_UI_SCALE_PERCENT,
+    DEFAULT_UPSCALE_MODEL_URL, DEFAULT_ZOOM_STEP_PERCENT, MAX_FRAME_CACHE_MB, MAX_FRAME_HISTORY_MB,
+    MAX_KEYBOARD_SEEK_STEP_SECS, MAX_MAX_SKIP_ATTEMPTS, MAX_OVERLAY_OPACITY_PERCENT,
+    MAX_OVERLAY_TIMEOUT_SECS, MAX_PREFETCH_CACHE_SIZE, MAX_SEQUENCE_FPS, MAX_SIMILARITY_THRESHOLD,
+    MAX_UI_SCALE_PERCENT, MIN_FRAME_CACHE_MB, MIN_FRAME_HISTORY_MB, MIN_KEYBOARD_SEEK_STEP_SECS,
+    MIN_MAX_SKIP_ATTEMPTS, MIN_OVERLAY_OPACITY_PERCENT, MIN_OVERLAY_TIMEOUT_SECS,
+    MIN_PREFETCH_CACHE_SIZE, MIN_SEQUENCE_FPS, MIN_SIMILARITY_THRESHOLD, MIN_UI_SCALE_PERCENT,
+    UI_SCALE_STEP_PERCENT,
 };
 use crate::i18n::fluent::I18n;
+use crate::media::background_removal::BackgroundModelStatus;
 use crate::media::deblur::ModelStatus;
+use crate::media::decoders::{DecoderPreferences, WebpDecoder};
 use crate::media::upscale::UpscaleModelStatus;
+use crate::media::LoadOptions;
+use crate::ui::accessibility;
 use crate::ui::design_tokens::{radius, sizing, spacing, typography};
+use crate::ui::guides;
 use crate::ui::icons;
+use crate::ui::keymap::{Action, Binding, Conflict, Group, Keymap};
 use crate::ui::state::zoom::{
     format_number, MAX_ZOOM_STEP_PERCENT, MIN_ZOOM_STEP_PERCENT, ZOOM_STEP_INVALID_KEY,
     ZOOM_STEP_RANGE_KEY,
 };
 use crate::ui::styles::button as button_styles;
+use crate::ui::styles::text_input as text_input_styles;
 use crate::ui::theme;
-use crate::ui::theming::ThemeMode;
+use crate::ui::theming::{self, CustomPalette, ThemeMode};
+use crate::ui::viewer::component::MouseMapping;
+use crate::ui::viewer::fullscreen_overlay;
+use crate::video_player::audio_output::AudioDevice;
 use iced::widget::image::{Handle, Image};
 use iced::{
     alignment::{Horizontal, Vertical},
     widget::{
         button, container, pick_list, progress_bar, rule, scrollable, text, text_input, Button,
-        Column, Container, Row, Slider, Text,
+        Column, Container, Row, Slider, Space, Text,
     },
-    Border, Element, Length, Theme,
+    Border, Color, Element, Length, Theme,
 };
 use unic_langid::LanguageIdentifier;
 
@@ -54,15 +71,38 @@ pub struct StateConfig {
     pub zoom_step_percent: f32,
     pub background_theme: BackgroundTheme,
     pub sort_order: SortOrder,
+    pub sort_direction: SortDirection,
     pub overlay_timeout_secs: u32,
+    pub fullscreen_overlay: fullscreen_overlay::Options,
     pub theme_mode: ThemeMode,
+    pub accent_color: Option<Color>,
+    pub custom_theme: bool,
+    pub custom_palette: CustomPalette,
+    pub ui_scale_percent: u32,
+    pub accessibility: accessibility::Options,
     pub video_autoplay: bool,
+    pub resume_playback: ResumePlayback,
+    pub tone_mapping: ToneMapping,
+    pub audio_device: Option<String>,
     pub audio_normalization: bool,
+    pub hardware_decoding: bool,
     pub frame_cache_mb: u32,
     pub frame_history_mb: u32,
+    pub frame_cache_auto: bool,
     pub keyboard_seek_step_secs: f64,
+    pub sequence_fps: u32,
+    pub frame_name_template: String,
+    // Editor settings
+    pub save_policy: SavePolicy,
+    pub edited_folder: String,
+    // Composition guides
+    pub guides: guides::Options,
+    // Update settings
+    pub check_updates_weekly: bool,
     // Navigation settings
     pub max_skip_attempts: u32,
+    pub prefetch_cache_size: u32,
+    pub similarity_threshold: u32,
     // AI settings - Deblur
     pub enable_deblur: bool,
     pub deblur_model_url: String,
@@ -71,8 +111,22 @@ pub struct StateConfig {
     pub enable_upscale: bool,
     pub upscale_model_url: String,
     pub upscale_model_status: UpscaleModelStatus,
+    // AI settings - Background removal
+    pub enable_background_removal: bool,
+    pub background_model_url: String,
+    pub background_model_status: BackgroundModelStatus,
     // Filter settings
     pub persist_filters: bool,
+    pub remember_view_per_image: bool,
+    pub show_minimap: bool,
+    // Security settings
+    pub sandboxed_decoding: bool,
+    // Decoder settings
+    pub webp_decoder: WebpDecoder,
+    // Mouse settings
+    pub mouse_mapping: MouseMapping,
+    // Keyboard shortcuts
+    pub keymap: Keymap,
 }
 
 impl Default for StateConfig {
@@ -81,21 +135,50 @@ impl Default for StateConfig {
             zoom_step_percent: DEFAULT_ZOOM_STEP_PERCENT,
             background_theme: BackgroundTheme::default(),
             sort_order: SortOrder::default(),
+            sort_direction: SortDirection::default(),
             overlay_timeout_secs: DEFAULT_OVERLAY_TIMEOUT_SECS,
+            fullscreen_overlay: fullscreen_overlay::Options::default(),
             theme_mode: ThemeMode::System,
+            accent_color: None,
+            custom_theme: false,
+            custom_palette: CustomPalette::default(),
+            ui_scale_percent: DEFAULT_UI_SCALE_PERCENT,
+            accessibility: accessibility::Options::default(),
             video_autoplay: false,
+            resume_playback: ResumePlayback::default(),
+            tone_mapping: ToneMapping::default(),
+            audio_device: None,
             audio_normalization: true,
+            hardware_decoding: true,
             frame_cache_mb: DEFAULT_FRAME_CACHE_MB,
             frame_history_mb: DEFAULT_FRAME_HISTORY_MB,
+            frame_cache_auto: true,
             keyboard_seek_step_secs: DEFAULT_KEYBOARD_SEEK_STEP_SECS,
+            sequence_fps: DEFAULT_SEQUENCE_FPS,
+            frame_name_template: DEFAULT_FRAME_NAME_TEMPLATE.to_string(),
+            save_policy: SavePolicy::default(),
+            edited_folder: DEFAULT_EDITED_FOLDER.to_string(),
+            guides: guides::Options::default(),
+            check_updates_weekly: false,
             max_skip_attempts: DEFAULT_MAX_SKIP_ATTEMPTS,
+            prefetch_cache_size: DEFAULT_PREFETCH_CACHE_SIZE,
+            similarity_threshold: DEFAULT_SIMILARITY_THRESHOLD,
             enable_deblur: false,
             deblur_model_url: DEFAULT_DEBLUR_MODEL_URL.to_string(),
             deblur_model_status: ModelStatus::NotDownloaded,
             enable_upscale: false,
             upscale_model_url: DEFAULT_UPSCALE_MODEL_URL.to_string(),
             upscale_model_status: UpscaleModelStatus::NotDownloaded,
+            enable_background_removal: false,
+            background_model_url: DEFAULT_BACKGROUND_MODEL_URL.to_string(),
+            background_model_status: BackgroundModelStatus::NotDownloaded,
             persist_filters: false,
+            remember_view_per_image: false,
+            show_minimap: true,
+            sandboxed_decoding: false,
+            webp_decoder: WebpDecoder::default(),
+            mouse_mapping: MouseMapping::default(),
+            keymap: Keymap::default(),
         }
     }
 }
@@ -107,19 +190,56 @@ impl Default for StateConfig {
 pub struct State {
     background_theme: BackgroundTheme,
     sort_order: SortOrder,
+    sort_direction: SortDirection,
     theme_mode: ThemeMode,
+    accent_color: Option<Color>,
+    /// Text of the accent hex field, kept while it is not a valid color.
+    accent_input: String,
+    custom_theme: bool,
+    custom_palette: CustomPalette,
+    /// Text of the hex field of each palette color, in [`PaletteColor::ALL`] order.
+    palette_inputs: [String; 5],
+    /// Interface scale in use.
+    ui_scale_percent: u32,
+    /// Interface scale under the slider, applied when it is released so the
+    /// slider doesn't move under the cursor while dragging.
+    ui_scale_slider: u32,
+    accessibility: accessibility::Options,
     zoom_step_percent: f32,
     zoom_step_input: String,
     zoom_step_input_dirty: bool,
     zoom_step_error_key: Option<&'static str>,
     overlay_timeout_secs: u32,
+    fullscreen_overlay: fullscreen_overlay::Options,
     video_autoplay: bool,
+    resume_playback: ResumePlayback,
+    tone_mapping: ToneMapping,
+    /// Id of the audio output device, `None` for the system default.
+    audio_device: Option<String>,
+    /// Output devices found when the screen was opened.
+    audio_devices: Vec<AudioDevice>,
     audio_normalization: bool,
+    hardware_decoding: bool,
     frame_cache_mb: u32,
     frame_history_mb: u32,
+    frame_cache_auto: bool,
     keyboard_seek_step_secs: f64,
+    sequence_fps: u32,
+    frame_name_template: String,
+    // Editor settings
+    save_policy: SavePolicy,
+    edited_folder: String,
+    // Composition guides
+    guides: guides::Options,
+    /// Custom guide positions as typed, kept as is while they don't parse.
+    horizontal_guides_input: String,
+    vertical_guides_input: String,
+    // Update settings
+    check_updates_weekly: bool,
     // Navigation settings
     max_skip_attempts: u32,
+    prefetch_cache_size: u32,
+    similarity_threshold: u32,
     // AI settings - Deblur
     enable_deblur: bool,
     deblur_model_url: String,
@@ -128,8 +248,94 @@ pub struct State {
     enable_upscale: bool,
     upscale_model_url: String,
     upscale_model_status: UpscaleModelStatus,
+    // AI settings - Background removal
+    enable_background_removal: bool,
+    background_model_url: String,
+    background_model_status: BackgroundModelStatus,
     // Filter settings
     persist_filters: bool,
+    remember_view_per_image: bool,
+    show_minimap: bool,
+    // Security settings
+    sandboxed_decoding: bool,
+    // Decoder settings
+    webp_decoder: WebpDecoder,
+    // Mouse settings
+    mouse_mapping: MouseMapping,
+    // Keyboard shortcuts
+    keymap: Keymap,
+    /// Action waiting for its new key, if any.
+    capturing_shortcut: Option<Action>,
+    /// Last refused binding, shown until the next attempt.
+    shortcut_conflict: Option<(Action, Conflict)>,
+    /// Installed plugins, listed in the Plugins section.
+    plugins: Vec<PluginEntry>,
+}
+
+/// An installed plugin as listed in the Plugins section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginEntry {
+    /// Name of the plugin directory.
+    pub id: String,
+    pub name: String,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    /// i18n keys of the hooks the plugin provides.
+    pub hooks: Vec<&'static str>,
+    /// Why the manifest could not be read, for broken plugins.
+    pub error: Option<String>,
+    pub enabled: bool,
+}
+
+/// A color of the custom palette, edited in the General section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteColor {
+    Background,
+    Text,
+    Accent,
+    Success,
+    Danger,
+}
+
+impl PaletteColor {
+    pub const ALL: [Self; 5] = [
+        Self::Background,
+        Self::Text,
+        Self::Accent,
+        Self::Success,
+        Self::Danger,
+    ];
+
+    fn get(self, palette: &CustomPalette) -> Color {
+        match self {
+            Self::Background => palette.background,
+            Self::Text => palette.text,
+            Self::Accent => palette.accent,
+            Self::Success => palette.success,
+            Self::Danger => palette.danger,
+        }
+    }
+
+    fn set(self, palette: &mut CustomPalette, color: Color) {
+        let field = match self {
+            Self::Background => &mut palette.background,
+            Self::Text => &mut palette.text,
+            Self::Accent => &mut palette.accent,
+            Self::Success => &mut palette.success,
+            Self::Danger => &mut palette.danger,
+        };
+        *field = color;
+    }
+
+    fn label_key(self) -> &'static str {
+        match self {
+            Self::Background => "settings-palette-background",
+            Self::Text => "settings-palette-text",
+            Self::Accent => "settings-palette-accent",
+            Self::Success => "settings-palette-success",
+            Self::Danger => "settings-palette-danger",
+        }
+    }
 }
 
 /// Messages emitted directly by the settings widgets.
@@ -141,15 +347,42 @@ pub enum Message {
     ZoomStepSubmitted,
     BackgroundThemeSelected(BackgroundTheme),
     ThemeModeSelected(ThemeMode),
+    /// Accent swatch clicked; `None` restores the default accent.
+    AccentColorSelected(Option<Color>),
+    AccentColorInputChanged(String),
+    CustomThemeChanged(bool),
+    PaletteColorInputChanged(PaletteColor, String),
+    UiScaleSliderMoved(u32),
+    UiScaleSliderReleased,
+    AccessibilityChanged(accessibility::Options),
     SortOrderSelected(SortOrder),
+    SortDirectionSelected(SortDirection),
     OverlayTimeoutChanged(u32),
+    FullscreenOverlayChanged(fullscreen_overlay::Options),
     VideoAutoplayChanged(bool),
+    ResumePlaybackChanged(ResumePlayback),
+    ToneMappingChanged(ToneMapping),
+    AudioDeviceSelected(Option<String>),
+    /// Output devices listed by the application when the screen opens.
+    AudioDevicesListed(Vec<AudioDevice>),
     AudioNormalizationChanged(bool),
+    HardwareDecodingChanged(bool),
     FrameCacheMbChanged(u32),
     FrameHistoryMbChanged(u32),
+    FrameCacheAutoChanged(bool),
     KeyboardSeekStepChanged(f64),
+    SequenceFpsChanged(u32),
+    FrameNameTemplateChanged(String),
+    SavePolicyChanged(SavePolicy),
+    EditedFolderChanged(String),
+    // Guides messages
+    GuidesChanged(guides::Options),
+    HorizontalGuidesInputChanged(String),
+    VerticalGuidesInputChanged(String),
     // Navigation messages
     MaxSkipAttemptsChanged(u32),
+    PrefetchCacheSizeChanged(u32),
+    SimilarityThresholdChanged(u32),
     // AI messages - Deblur
     RequestEnableDeblur,
     DisableDeblur,
@@ -158,8 +391,39 @@ pub enum Message {
     RequestEnableUpscale,
     DisableUpscale,
     UpscaleModelUrlChanged(String),
+    // AI messages - Background removal
+    RequestEnableBackgroundRemoval,
+    DisableBackgroundRemoval,
+    BackgroundModelUrlChanged(String),
     // Filter messages
     PersistFiltersChanged(bool),
+    RememberViewPerImageChanged(bool),
+    ShowMinimapChanged(bool),
+    // Security messages
+    SandboxedDecodingChanged(bool),
+    // Decoder messages
+    WebpDecoderChanged(WebpDecoder),
+    // Mouse messages
+    NavigationButtonsChanged(bool),
+    MiddleClickChanged(MiddleClickAction),
+    WheelActionChanged(WheelAction),
+    DragButtonChanged(DragButton),
+    // Shortcut messages
+    /// Wait for the next key press to bind it to the action.
+    EditShortcut(Action),
+    /// Key pressed while waiting for a new binding.
+    ShortcutKeyPressed(Binding),
+    ResetShortcuts,
+    // Plugin messages
+    PluginToggled(String, bool),
+    OpenPluginsFolder,
+    ReloadPlugins,
+    // Settings file messages
+    ImportSettings,
+    ExportSettings,
+    // Update messages
+    CheckUpdatesWeeklyChanged(bool),
+    CheckForUpdates,
 }
 
 /// Events propagated to the parent application for side effects.
@@ -172,15 +436,35 @@ pub enum Event {
     ZoomStepChanged(f32),
     BackgroundThemeSelected(BackgroundTheme),
     ThemeModeSelected(ThemeMode),
+    AccentColorChanged(Option<Color>),
+    CustomThemeChanged(bool),
+    /// A color of the custom palette changed; the theme file must be saved.
+    CustomPaletteChanged(CustomPalette),
+    UiScaleChanged(u32),
+    AccessibilityChanged(accessibility::Options),
     SortOrderSelected(SortOrder),
+    SortDirectionSelected(SortDirection),
     OverlayTimeoutChanged(u32),
+    FullscreenOverlayChanged(fullscreen_overlay::Options),
     VideoAutoplayChanged(bool),
+    ResumePlaybackChanged(ResumePlayback),
+    ToneMappingChanged(ToneMapping),
+    AudioDeviceChanged(Option<String>),
     AudioNormalizationChanged(bool),
+    HardwareDecodingChanged(bool),
     FrameCacheMbChanged(u32),
     FrameHistoryMbChanged(u32),
+    FrameCacheAutoChanged(bool),
     KeyboardSeekStepChanged(f64),
+    SequenceFpsChanged(u32),
+    FrameNameTemplateChanged(String),
+    SavePolicyChanged(SavePolicy),
+    EditedFolderChanged(String),
+    GuidesChanged(guides::Options),
     // Navigation events
     MaxSkipAttemptsChanged(u32),
+    PrefetchCacheSizeChanged(u32),
+    SimilarityThresholdChanged(u32),
     // AI events - Deblur
     /// User requested to enable deblur - triggers download/validation flow.
     RequestEnableDeblur,
@@ -193,8 +477,49 @@ pub enum Event {
     /// User requested to disable upscale.
     DisableUpscale,
     UpscaleModelUrlChanged(String),
+    // AI events - Background removal
+    /// User requested to enable background removal - triggers download/validation flow.
+    RequestEnableBackgroundRemoval,
+    /// User requested to disable background removal.
+    DisableBackgroundRemoval,
+    BackgroundModelUrlChanged(String),
     // Filter events
     PersistFiltersChanged(bool),
+    RememberViewPerImageChanged(bool),
+    ShowMinimapChanged(bool),
+    SandboxedDecodingChanged(bool),
+    WebpDecoderChanged(WebpDecoder),
+    /// One of the mouse settings changed.
+    MouseMappingChanged(MouseMapping),
+    /// A shortcut was rebound or the defaults were restored.
+    ShortcutsChanged,
+    // Plugin events
+    PluginToggled {
+        id: String,
+        enabled: bool,
+    },
+    OpenPluginsFolder,
+    ReloadPlugins,
+    // Settings file events
+    ImportSettings,
+    ExportSettings,
+    // Update events
+    CheckUpdatesWeeklyChanged(bool),
+    CheckForUpdates,
+}
+
+/// Audio output device option for the `pick_list` widget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioDeviceOption {
+    /// Device id, `None` for the system default.
+    pub id: Option<String>,
+    pub label: String,
+}
+
+impl std::fmt::Display for AudioDeviceOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
 }
 
 /// Language option for the `pick_list` widget.
@@ -212,6 +537,27 @@ impl std::fmt::Display for LanguageOption {
     }
 }
 
+/// Returns the available languages, named in the current language.
+#[must_use]
+pub fn language_options(i18n: &I18n) -> Vec<LanguageOption> {
+    i18n.available_locales
+        .iter()
+        .map(|locale| {
+            let translated_name_key = format!("language-name-{locale}");
+            let translated_name = i18n.tr(&translated_name_key);
+            let display_name = if translated_name.starts_with("MISSING:") {
+                locale.to_string()
+            } else {
+                format!("{translated_name} ({locale})")
+            };
+            LanguageOption {
+                locale: locale.clone(),
+                display_name,
+            }
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum ZoomStepError {
     InvalidInput,
@@ -262,31 +608,88 @@ impl State {
         let clamped_seek_step = config
             .keyboard_seek_step_secs
             .clamp(MIN_KEYBOARD_SEEK_STEP_SECS, MAX_KEYBOARD_SEEK_STEP_SECS);
+        let clamped_sequence_fps = config
+            .sequence_fps
+            .clamp(MIN_SEQUENCE_FPS, MAX_SEQUENCE_FPS);
         let clamped_skip_attempts = config
             .max_skip_attempts
             .clamp(MIN_MAX_SKIP_ATTEMPTS, MAX_MAX_SKIP_ATTEMPTS);
+        let clamped_prefetch = config
+            .prefetch_cache_size
+            .clamp(MIN_PREFETCH_CACHE_SIZE, MAX_PREFETCH_CACHE_SIZE);
+        let clamped_similarity = config
+            .similarity_threshold
+            .clamp(MIN_SIMILARITY_THRESHOLD, MAX_SIMILARITY_THRESHOLD);
+        let clamped_ui_scale = config
+            .ui_scale_percent
+            .clamp(MIN_UI_SCALE_PERCENT, MAX_UI_SCALE_PERCENT);
         Self {
             background_theme: config.background_theme,
             sort_order: config.sort_order,
+            sort_direction: config.sort_direction,
             theme_mode: config.theme_mode,
+            accent_color: config.accent_color,
+            accent_input: config.accent_color.map(theming::to_hex).unwrap_or_default(),
+            custom_theme: config.custom_theme,
+            custom_palette: config.custom_palette,
+            palette_inputs: PaletteColor::ALL
+                .map(|color| theming::to_hex(color.get(&config.custom_palette))),
+            ui_scale_percent: clamped_ui_scale,
+            ui_scale_slider: clamped_ui_scale,
+            accessibility: config.accessibility,
             zoom_step_percent: clamped,
             zoom_step_input: format_number(clamped),
             zoom_step_input_dirty: false,
             zoom_step_error_key: None,
             overlay_timeout_secs: clamped_timeout,
+            fullscreen_overlay: fullscreen_overlay::Options {
+                opacity_percent: config
+                    .fullscreen_overlay
+                    .opacity_percent
+                    .clamp(MIN_OVERLAY_OPACITY_PERCENT, MAX_OVERLAY_OPACITY_PERCENT),
+                ..config.fullscreen_overlay
+            },
             video_autoplay: config.video_autoplay,
+            resume_playback: config.resume_playback,
+            tone_mapping: config.tone_mapping,
+            audio_device: config.audio_device,
+            audio_devices: Vec::new(),
             audio_normalization: config.audio_normalization,
+            hardware_decoding: config.hardware_decoding,
             frame_cache_mb: clamped_cache,
             frame_history_mb: clamped_history,
+            frame_cache_auto: config.frame_cache_auto,
             keyboard_seek_step_secs: clamped_seek_step,
+            sequence_fps: clamped_sequence_fps,
+            frame_name_template: config.frame_name_template,
+            save_policy: config.save_policy,
+            edited_folder: config.edited_folder,
+            horizontal_guides_input: guides::format_positions(&config.guides.horizontal),
+            vertical_guides_input: guides::format_positions(&config.guides.vertical),
+            guides: config.guides,
+            check_updates_weekly: config.check_updates_weekly,
             max_skip_attempts: clamped_skip_attempts,
+            prefetch_cache_size: clamped_prefetch,
+            similarity_threshold: clamped_similarity,
             enable_deblur: config.enable_deblur,
             deblur_model_url: config.deblur_model_url,
             deblur_model_status: config.deblur_model_status,
             enable_upscale: config.enable_upscale,
             upscale_model_url: config.upscale_model_url,
             upscale_model_status: config.upscale_model_status,
+            enable_background_removal: config.enable_background_removal,
+            background_model_url: config.background_model_url,
+            background_model_status: config.background_model_status,
             persist_filters: config.persist_filters,
+            remember_view_per_image: config.remember_view_per_image,
+            show_minimap: config.show_minimap,
+            sandboxed_decoding: config.sandboxed_decoding,
+            webp_decoder: config.webp_decoder,
+            mouse_mapping: config.mouse_mapping,
+            keymap: config.keymap,
+            capturing_shortcut: None,
+            shortcut_conflict: None,
+            plugins: Vec::new(),
         }
     }
 
@@ -300,11 +703,53 @@ impl State {
         self.sort_order
     }
 
+    #[must_use]
+    pub fn sort_direction(&self) -> SortDirection {
+        self.sort_direction
+    }
+
     #[must_use]
     pub fn theme_mode(&self) -> ThemeMode {
         self.theme_mode
     }
 
+    #[must_use]
+    pub fn accent_color(&self) -> Option<Color> {
+        self.accent_color
+    }
+
+    #[must_use]
+    pub fn custom_theme(&self) -> bool {
+        self.custom_theme
+    }
+
+    /// Returns the custom palette when it replaces the light/dark theme.
+    #[must_use]
+    pub fn active_custom_palette(&self) -> Option<&CustomPalette> {
+        self.custom_theme.then_some(&self.custom_palette)
+    }
+
+    /// Returns the interface scale in percent.
+    #[must_use]
+    pub fn ui_scale_percent(&self) -> u32 {
+        self.ui_scale_percent
+    }
+
+    #[must_use]
+    pub fn accessibility(&self) -> accessibility::Options {
+        self.accessibility
+    }
+
+    /// Returns the theme of the windows, as currently configured.
+    #[must_use]
+    pub fn app_theme(&self) -> Theme {
+        theming::iced_theme(
+            self.theme_mode,
+            self.accent_color,
+            self.active_custom_palette(),
+        )
+    }
+
     #[must_use]
     pub fn zoom_step_percent(&self) -> f32 {
         self.zoom_step_percent
@@ -315,21 +760,66 @@ impl State {
         self.overlay_timeout_secs
     }
 
+    #[must_use]
+    pub fn fullscreen_overlay(&self) -> fullscreen_overlay::Options {
+        self.fullscreen_overlay
+    }
+
+    /// Returns the composition guides drawn over the viewer and editor.
+    #[must_use]
+    pub fn guides(&self) -> &guides::Options {
+        &self.guides
+    }
+
     #[must_use]
     pub fn max_skip_attempts(&self) -> u32 {
         self.max_skip_attempts
     }
 
+    /// Returns the number of decoded media kept for instant navigation.
+    #[must_use]
+    pub fn prefetch_cache_size(&self) -> u32 {
+        self.prefetch_cache_size
+    }
+
+    #[must_use]
+    pub fn similarity_threshold(&self) -> u32 {
+        self.similarity_threshold
+    }
+
     #[must_use]
     pub fn video_autoplay(&self) -> bool {
         self.video_autoplay
     }
 
+    /// Returns whether reopened videos resume where they were left.
+    #[must_use]
+    pub fn resume_playback(&self) -> ResumePlayback {
+        self.resume_playback
+    }
+
+    /// Returns the tone mapping curve of HDR videos.
+    #[must_use]
+    pub fn tone_mapping(&self) -> ToneMapping {
+        self.tone_mapping
+    }
+
+    /// Returns the id of the audio output device, `None` for the system default.
+    #[must_use]
+    pub fn audio_device(&self) -> Option<&str> {
+        self.audio_device.as_deref()
+    }
+
     #[must_use]
     pub fn audio_normalization(&self) -> bool {
         self.audio_normalization
     }
 
+    #[must_use]
+    pub fn hardware_decoding(&self) -> bool {
+        self.hardware_decoding
+    }
+
     #[must_use]
     pub fn frame_cache_mb(&self) -> u32 {
         self.frame_cache_mb
@@ -340,11 +830,44 @@ impl State {
         self.frame_history_mb
     }
 
+    #[must_use]
+    pub fn frame_cache_auto(&self) -> bool {
+        self.frame_cache_auto
+    }
+
     #[must_use]
     pub fn keyboard_seek_step_secs(&self) -> f64 {
         self.keyboard_seek_step_secs
     }
 
+    #[must_use]
+    pub fn sequence_fps(&self) -> u32 {
+        self.sequence_fps
+    }
+
+    #[must_use]
+    pub fn frame_name_template(&self) -> &str {
+        &self.frame_name_template
+    }
+
+    /// Returns where the editor saves edited images.
+    #[must_use]
+    pub fn save_policy(&self) -> SavePolicy {
+        self.save_policy
+    }
+
+    /// Returns the subfolder used by [`SavePolicy::Subfolder`].
+    #[must_use]
+    pub fn edited_folder(&self) -> &str {
+        &self.edited_folder
+    }
+
+    /// Returns whether a newer release is looked for once a week.
+    #[must_use]
+    pub fn check_updates_weekly(&self) -> bool {
+        self.check_updates_weekly
+    }
+
     #[must_use]
     pub fn enable_deblur(&self) -> bool {
         self.enable_deblur
@@ -361,6 +884,21 @@ impl State {
         &self.deblur_model_status
     }
 
+    /// Replaces the listed plugins (called from app after loading them).
+    pub fn set_plugins(&mut self, plugins: Vec<PluginEntry>) {
+        self.plugins = plugins;
+    }
+
+    /// Returns the ids of the plugins turned off.
+    #[must_use]
+    pub fn disabled_plugins(&self) -> Vec<String> {
+        self.plugins
+            .iter()
+            .filter(|plugin| !plugin.enabled)
+            .map(|plugin| plugin.id.clone())
+            .collect()
+    }
+
     /// Updates the deblur model status (called from app when status changes).
     pub fn set_deblur_model_status(&mut self, status: ModelStatus) {
         self.deblur_model_status = status;
@@ -403,12 +941,110 @@ impl State {
         self.enable_upscale = enabled;
     }
 
+    #[must_use]
+    pub fn enable_background_removal(&self) -> bool {
+        self.enable_background_removal
+    }
+
+    #[must_use]
+    pub fn background_model_url(&self) -> &str {
+        &self.background_model_url
+    }
+
+    /// Returns the current status of the background removal model.
+    #[must_use]
+    pub fn background_model_status(&self) -> &BackgroundModelStatus {
+        &self.background_model_status
+    }
+
+    /// Updates the background removal model status (called from app when status changes).
+    pub fn set_background_model_status(&mut self, status: BackgroundModelStatus) {
+        self.background_model_status = status;
+    }
+
+    /// Sets the `enable_background_removal` flag (called from app after successful validation).
+    ///
+    /// This should only be called by the application after the model has been
+    /// successfully downloaded and validated, not in response to user UI action.
+    pub fn set_enable_background_removal(&mut self, enabled: bool) {
+        self.enable_background_removal = enabled;
+    }
+
     /// Returns whether filter persistence is enabled.
     #[must_use]
     pub fn persist_filters(&self) -> bool {
         self.persist_filters
     }
 
+    /// Returns whether the view of each image is restored when coming back to it.
+    #[must_use]
+    pub fn remember_view_per_image(&self) -> bool {
+        self.remember_view_per_image
+    }
+
+    /// Returns whether the minimap is shown while zoomed in.
+    #[must_use]
+    pub fn show_minimap(&self) -> bool {
+        self.show_minimap
+    }
+
+    /// Returns whether images are decoded in a sandboxed helper process.
+    #[must_use]
+    pub fn sandboxed_decoding(&self) -> bool {
+        self.sandboxed_decoding
+    }
+
+    /// Returns the library preferred for still WebP images.
+    #[must_use]
+    pub fn webp_decoder(&self) -> WebpDecoder {
+        self.webp_decoder
+    }
+
+    /// Returns what the mouse buttons and wheel do in the viewer.
+    #[must_use]
+    pub fn mouse_mapping(&self) -> MouseMapping {
+        self.mouse_mapping
+    }
+
+    /// Returns the keyboard shortcuts.
+    #[must_use]
+    pub fn keymap(&self) -> &Keymap {
+        &self.keymap
+    }
+
+    /// Returns whether the next key press sets a shortcut, in which case it
+    /// must not reach the other handlers.
+    #[must_use]
+    pub fn is_capturing_shortcut(&self) -> bool {
+        self.capturing_shortcut.is_some()
+    }
+
+    /// Returns how media files should be decoded.
+    #[must_use]
+    pub fn load_options(&self) -> LoadOptions {
+        LoadOptions {
+            sandboxed: self.sandboxed_decoding,
+            decoders: DecoderPreferences {
+                webp: self.webp_decoder,
+            },
+        }
+    }
+
+    /// Applies custom guide positions typed in the settings, ignoring them
+    /// while they don't parse.
+    fn guides_positions_changed(
+        &mut self,
+        positions: Option<Vec<f32>>,
+        set: impl FnOnce(&mut guides::Options, Vec<f32>),
+    ) -> Event {
+        let Some(positions) = positions else {
+            return Event::None;
+        };
+        let mut options = self.guides.clone();
+        set(&mut options, positions);
+        update_if_changed(&mut self.guides, options, Event::GuidesChanged)
+    }
+
     pub(crate) fn zoom_step_input_value(&self) -> &str {
         &self.zoom_step_input
     }
@@ -442,6 +1078,11 @@ impl State {
         // =========================================================================
         let general_section = self.build_general_section(&ctx);
 
+        // =========================================================================
+        // SECTION: Accessibility (Motion, Focus, Hit targets, Fullscreen controls)
+        // =========================================================================
+        let accessibility_section = self.build_accessibility_section(&ctx);
+
         // =========================================================================
         // SECTION: Display (Background, Zoom step, Sort order)
         // =========================================================================
@@ -452,16 +1093,46 @@ impl State {
         // =========================================================================
         let video_section = self.build_video_section(&ctx);
 
+        // =========================================================================
+        // SECTION: Editor (Save policy)
+        // =========================================================================
+        let editor_section = self.build_editor_section(&ctx);
+
         // =========================================================================
         // SECTION: Fullscreen (Overlay timeout)
         // =========================================================================
         let fullscreen_section = self.build_fullscreen_section(&ctx);
 
+        // =========================================================================
+        // SECTION: Guides (Composition guides, Pixel grid)
+        // =========================================================================
+        let guides_section = self.build_guides_section(&ctx);
+
         // =========================================================================
         // SECTION: AI (Deblur model)
         // =========================================================================
         let ai_section = self.build_ai_section(&ctx);
 
+        // =========================================================================
+        // SECTION: Advanced (Decoder backends, Settings file)
+        // =========================================================================
+        let advanced_section = self.build_advanced_section(&ctx);
+
+        // =========================================================================
+        // SECTION: Mouse (Buttons, Wheel)
+        // =========================================================================
+        let mouse_section = self.build_mouse_section(&ctx);
+
+        // =========================================================================
+        // SECTION: Keyboard shortcuts
+        // =========================================================================
+        let shortcuts_section = self.build_shortcuts_section(&ctx);
+
+        // =========================================================================
+        // SECTION: Plugins
+        // =========================================================================
+        let plugins_section = self.build_plugins_section(&ctx);
+
         let content = Column::new()
             .width(Length::Fill)
             .spacing(spacing::LG)
@@ -470,10 +1141,17 @@ impl State {
             .push(back_button)
             .push(title)
             .push(general_section)
+            .push(accessibility_section)
             .push(display_section)
             .push(video_section)
+            .push(editor_section)
             .push(fullscreen_section)
-            .push(ai_section);
+            .push(guides_section)
+            .push(ai_section)
+            .push(advanced_section)
+            .push(mouse_section)
+            .push(shortcuts_section)
+            .push(plugins_section);
 
         scrollable(content).into()
     }
@@ -481,24 +1159,7 @@ impl State {
     /// Build the General section (Language, Theme mode).
     fn build_general_section<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
         // Language selection using pick_list (dropdown)
-        let language_options: Vec<LanguageOption> = ctx
-            .i18n
-            .available_locales
-            .iter()
-            .map(|locale| {
-                let translated_name_key = format!("language-name-{locale}");
-                let translated_name = ctx.i18n.tr(&translated_name_key);
-                let display_name = if translated_name.starts_with("MISSING:") {
-                    locale.to_string()
-                } else {
-                    format!("{translated_name} ({locale})")
-                };
-                LanguageOption {
-                    locale: locale.clone(),
-                    display_name,
-                }
-            })
-            .collect();
+        let language_options = language_options(ctx.i18n);
 
         let current_locale = ctx.i18n.current_locale();
         let selected = language_options
@@ -539,7 +1200,10 @@ impl State {
         let content = Column::new()
             .spacing(spacing::MD)
             .push(language_setting)
-            .push(theme_setting);
+            .push(theme_setting)
+            .push(self.build_ui_scale_setting(ctx))
+            .push(self.build_accent_setting(ctx))
+            .push(self.build_custom_theme_setting(ctx));
 
         build_section(
             icons::globe(),
@@ -548,6 +1212,212 @@ impl State {
         )
     }
 
+    /// Build the Accessibility section (reduced motion, focus outlines, hit
+    /// targets, fullscreen controls).
+    fn build_accessibility_section<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+        let options = self.accessibility;
+        let toggle = |key: &str,
+                      enabled: bool,
+                      set: fn(accessibility::Options, bool) -> accessibility::Options|
+         -> Element<'a, Message> {
+            self.build_setting_row(
+                ctx.i18n.tr(&format!("settings-{key}-label")),
+                Some(
+                    Text::new(ctx.i18n.tr(&format!("settings-{key}-hint")))
+                        .size(typography::BODY_SM)
+                        .into(),
+                ),
+                build_toggle_button_row(
+                    &[
+                        (false, "settings-accessibility-disabled"),
+                        (true, "settings-accessibility-enabled"),
+                    ],
+                    enabled,
+                    move |enabled| Message::AccessibilityChanged(set(options, enabled)),
+                    ctx.i18n,
+                )
+                .into(),
+            )
+        };
+
+        let content = Column::new()
+            .spacing(spacing::MD)
+            .push(toggle(
+                "reduced-motion",
+                options.reduced_motion,
+                |options, reduced_motion| accessibility::Options {
+                    reduced_motion,
+                    ..options
+                },
+            ))
+            .push(toggle(
+                "high-contrast-focus",
+                options.high_contrast_focus,
+                |options, high_contrast_focus| accessibility::Options {
+                    high_contrast_focus,
+                    ..options
+                },
+            ))
+            .push(toggle(
+                "large-targets",
+                options.large_targets,
+                |options, large_targets| accessibility::Options {
+                    large_targets,
+                    ..options
+                },
+            ))
+            .push(toggle(
+                "always-show-fullscreen-controls",
+                options.always_show_fullscreen_controls,
+                |options, always_show_fullscreen_controls| accessibility::Options {
+                    always_show_fullscreen_controls,
+                    ..options
+                },
+            ));
+
+        build_section(
+            icons::magnifier(),
+            ctx.i18n.tr("settings-section-accessibility"),
+            content.into(),
+        )
+    }
+
+    /// Build the interface scale slider.
+    fn build_ui_scale_setting<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+        let slider = Slider::new(
+            MIN_UI_SCALE_PERCENT..=MAX_UI_SCALE_PERCENT,
+            self.ui_scale_slider,
+            Message::UiScaleSliderMoved,
+        )
+        .on_release(Message::UiScaleSliderReleased)
+        .step(UI_SCALE_STEP_PERCENT)
+        .width(Length::Fixed(200.0));
+
+        let control = Row::new()
+            .spacing(spacing::SM)
+            .align_y(Vertical::Center)
+            .push(slider)
+            .push(Text::new(format!("{}%", self.ui_scale_slider)));
+
+        self.build_setting_row(
+            ctx.i18n.tr("settings-ui-scale-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-ui-scale-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            control.into(),
+        )
+    }
+
+    /// Build the accent color swatches and hex field.
+    fn build_accent_setting<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+        let default_button = Button::new(Text::new(ctx.i18n.tr("settings-accent-default")))
+            .on_press(Message::AccentColorSelected(None))
+            .style(if self.accent_color.is_none() {
+                button_styles::selected
+            } else {
+                button_styles::unselected
+            });
+
+        let swatches = theming::ACCENT_PRESETS.iter().fold(
+            Row::new()
+                .spacing(spacing::XS)
+                .align_y(Vertical::Center)
+                .push(default_button),
+            |row, &color| {
+                row.push(
+                    Button::new(Space::new())
+                        .width(Length::Fixed(sizing::ICON_LG))
+                        .height(Length::Fixed(sizing::ICON_LG))
+                        .on_press(Message::AccentColorSelected(Some(color)))
+                        .style(swatch_style(color, self.accent_color == Some(color))),
+                )
+            },
+        );
+
+        let hex_input = text_input("#rrggbb", &self.accent_input)
+            .on_input(Message::AccentColorInputChanged)
+            .padding(spacing::XXS)
+            .style(text_input_styles::standard)
+            .width(Length::Fixed(100.0));
+
+        let hint_key = if self.custom_theme {
+            "settings-accent-custom-hint"
+        } else {
+            "settings-accent-hint"
+        };
+        self.build_setting_row(
+            ctx.i18n.tr("settings-accent-label"),
+            Some(
+                Text::new(ctx.i18n.tr(hint_key))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            swatches.push(hex_input).into(),
+        )
+    }
+
+    /// Build the custom palette toggle and, when on, its color fields.
+    fn build_custom_theme_setting<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+        let toggle = build_toggle_button_row(
+            &[
+                (false, "settings-custom-theme-disabled"),
+                (true, "settings-custom-theme-enabled"),
+            ],
+            self.custom_theme,
+            Message::CustomThemeChanged,
+            ctx.i18n,
+        );
+
+        let mut control = Column::new().spacing(spacing::XS).push(toggle);
+        if self.custom_theme {
+            for slot in PaletteColor::ALL {
+                let input = &self.palette_inputs[slot as usize];
+                let swatch = Container::new(Space::new())
+                    .width(Length::Fixed(sizing::ICON_MD))
+                    .height(Length::Fixed(sizing::ICON_MD))
+                    .style(swatch_container_style(slot.get(&self.custom_palette)));
+                let mut row = Row::new()
+                    .spacing(spacing::SM)
+                    .align_y(Vertical::Center)
+                    .push(
+                        Text::new(ctx.i18n.tr(slot.label_key()))
+                            .size(typography::BODY)
+                            .width(Length::Fixed(120.0)),
+                    )
+                    .push(swatch)
+                    .push(
+                        text_input("#rrggbb", input)
+                            .on_input(move |text| Message::PaletteColorInputChanged(slot, text))
+                            .padding(spacing::XXS)
+                            .style(text_input_styles::standard)
+                            .width(Length::Fixed(100.0)),
+                    );
+                if theming::parse_hex(input).is_none() {
+                    row = row.push(
+                        Text::new(ctx.i18n.tr("settings-palette-invalid"))
+                            .size(typography::BODY_SM)
+                            .style(|_: &Theme| text::Style {
+                                color: Some(theme::error_text_color()),
+                            }),
+                    );
+                }
+                control = control.push(row);
+            }
+        }
+
+        self.build_setting_row(
+            ctx.i18n.tr("settings-custom-theme-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-custom-theme-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            control.into(),
+        )
+    }
+
     /// Build the Display section (Background, Zoom step, Sort order).
     // Allow too_many_lines: declarative UI section with multiple settings.
     // Linear composition of themed widgets without complex logic.
@@ -562,15 +1432,21 @@ impl State {
                     BackgroundTheme::Checkerboard,
                     "settings-background-checkerboard",
                 ),
+                (BackgroundTheme::Auto, "settings-background-auto"),
             ],
             self.background_theme,
             Message::BackgroundThemeSelected,
             ctx.i18n,
         );
 
+        let background_hint = (self.background_theme == BackgroundTheme::Auto).then(|| {
+            Text::new(ctx.i18n.tr("settings-background-auto-hint"))
+                .size(typography::BODY_SM)
+                .into()
+        });
         let background_setting = self.build_setting_row(
             ctx.i18n.tr("settings-background-label"),
-            None,
+            background_hint,
             background_row.into(),
         );
 
@@ -582,6 +1458,7 @@ impl State {
         .on_input(Message::ZoomStepInputChanged)
         .on_submit(Message::ZoomStepSubmitted)
         .padding(spacing::XXS)
+        .style(text_input_styles::standard)
         .width(Length::Fixed(100.0));
 
         let zoom_input_row = Row::new()
@@ -615,16 +1492,37 @@ impl State {
                 (SortOrder::Alphabetical, "settings-sort-alphabetical"),
                 (SortOrder::ModifiedDate, "settings-sort-modified"),
                 (SortOrder::CreatedDate, "settings-sort-created"),
+                (SortOrder::FileSize, "settings-sort-size"),
+                (SortOrder::FileType, "settings-sort-type"),
             ],
             self.sort_order,
             Message::SortOrderSelected,
             ctx.i18n,
         );
 
+        let sort_direction_row = build_toggle_button_row(
+            &[
+                (SortDirection::Ascending, "settings-sort-ascending"),
+                (SortDirection::Descending, "settings-sort-descending"),
+            ],
+            self.sort_direction,
+            Message::SortDirectionSelected,
+            ctx.i18n,
+        );
+
+        let sort_controls = Column::new()
+            .spacing(spacing::XS)
+            .push(sort_row)
+            .push(sort_direction_row);
+
         let sort_setting = self.build_setting_row(
             ctx.i18n.tr("settings-sort-order-label"),
-            None,
-            sort_row.into(),
+            Some(
+                Text::new(ctx.i18n.tr("settings-sort-order-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            sort_controls.into(),
         );
 
         // Max skip attempts slider (for auto-skip during navigation)
@@ -654,10 +1552,64 @@ impl State {
             skip_control.into(),
         );
 
-        // Persist filters toggle
-        let persist_filters_row = build_toggle_button_row(
-            &[
-                (false, "settings-persist-filters-disabled"),
+        // Prefetch cache size slider (decoded neighbors kept for navigation)
+        let prefetch_slider = Slider::new(
+            MIN_PREFETCH_CACHE_SIZE..=MAX_PREFETCH_CACHE_SIZE,
+            self.prefetch_cache_size,
+            Message::PrefetchCacheSizeChanged,
+        )
+        .step(1u32)
+        .width(Length::Fixed(200.0));
+
+        let prefetch_value = Text::new(self.prefetch_cache_size.to_string());
+
+        let prefetch_control = Row::new()
+            .spacing(spacing::SM)
+            .align_y(Vertical::Center)
+            .push(prefetch_slider)
+            .push(prefetch_value);
+
+        let prefetch_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-prefetch-cache-size-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-prefetch-cache-size-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            prefetch_control.into(),
+        );
+
+        // Similarity threshold slider (for "Find similar" navigation)
+        let similarity_slider = Slider::new(
+            MIN_SIMILARITY_THRESHOLD..=MAX_SIMILARITY_THRESHOLD,
+            self.similarity_threshold,
+            Message::SimilarityThresholdChanged,
+        )
+        .step(1u32)
+        .width(Length::Fixed(200.0));
+
+        let similarity_value = Text::new(format!("{}%", self.similarity_threshold));
+
+        let similarity_control = Row::new()
+            .spacing(spacing::SM)
+            .align_y(Vertical::Center)
+            .push(similarity_slider)
+            .push(similarity_value);
+
+        let similarity_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-similarity-threshold-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-similarity-threshold-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            similarity_control.into(),
+        );
+
+        // Persist filters toggle
+        let persist_filters_row = build_toggle_button_row(
+            &[
+                (false, "settings-persist-filters-disabled"),
                 (true, "settings-persist-filters-enabled"),
             ],
             self.persist_filters,
@@ -675,13 +1627,81 @@ impl State {
             persist_filters_row.into(),
         );
 
+        // Remember view per image toggle
+        let remember_view_row = build_toggle_button_row(
+            &[
+                (false, "settings-remember-view-disabled"),
+                (true, "settings-remember-view-enabled"),
+            ],
+            self.remember_view_per_image,
+            Message::RememberViewPerImageChanged,
+            ctx.i18n,
+        );
+
+        let remember_view_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-remember-view-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-remember-view-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            remember_view_row.into(),
+        );
+
+        // Minimap toggle
+        let minimap_row = build_toggle_button_row(
+            &[
+                (false, "settings-minimap-disabled"),
+                (true, "settings-minimap-enabled"),
+            ],
+            self.show_minimap,
+            Message::ShowMinimapChanged,
+            ctx.i18n,
+        );
+
+        let minimap_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-minimap-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-minimap-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            minimap_row.into(),
+        );
+
+        // Sandboxed decoding toggle
+        let sandboxed_decoding_row = build_toggle_button_row(
+            &[
+                (false, "settings-sandboxed-decoding-disabled"),
+                (true, "settings-sandboxed-decoding-enabled"),
+            ],
+            self.sandboxed_decoding,
+            Message::SandboxedDecodingChanged,
+            ctx.i18n,
+        );
+
+        let sandboxed_decoding_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-sandboxed-decoding-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-sandboxed-decoding-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            sandboxed_decoding_row.into(),
+        );
+
         let content = Column::new()
             .spacing(spacing::MD)
             .push(background_setting)
             .push(zoom_setting)
             .push(sort_setting)
             .push(skip_setting)
-            .push(persist_filters_setting);
+            .push(prefetch_setting)
+            .push(similarity_setting)
+            .push(persist_filters_setting)
+            .push(remember_view_setting)
+            .push(minimap_setting)
+            .push(sandboxed_decoding_setting);
 
         build_section(
             icons::image(),
@@ -716,6 +1736,88 @@ impl State {
             autoplay_row.into(),
         );
 
+        // Resume position toggle
+        let resume_row = build_toggle_button_row(
+            &[
+                (ResumePlayback::Ask, "settings-video-resume-ask"),
+                (ResumePlayback::Always, "settings-video-resume-always"),
+                (ResumePlayback::Never, "settings-video-resume-never"),
+            ],
+            self.resume_playback,
+            Message::ResumePlaybackChanged,
+            ctx.i18n,
+        );
+
+        let resume_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-video-resume-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-video-resume-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            resume_row.into(),
+        );
+
+        // HDR tone mapping curve
+        let tone_mapping_row = build_toggle_button_row(
+            &ToneMapping::ALL.map(|operator| (operator, operator.i18n_key())),
+            self.tone_mapping,
+            Message::ToneMappingChanged,
+            ctx.i18n,
+        );
+
+        let tone_mapping_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-tone-mapping-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-tone-mapping-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            tone_mapping_row.into(),
+        );
+
+        // Audio output device: the system default first, then the devices found.
+        // A saved device that is unplugged stays listed so it can be kept.
+        let default_option = AudioDeviceOption {
+            id: None,
+            label: ctx.i18n.tr("settings-audio-device-default"),
+        };
+        let mut device_options = vec![default_option.clone()];
+        device_options.extend(self.audio_devices.iter().map(|device| AudioDeviceOption {
+            id: Some(device.id.clone()),
+            label: device.name.clone(),
+        }));
+        if let Some(id) = &self.audio_device {
+            if !self.audio_devices.iter().any(|device| &device.id == id) {
+                device_options.push(AudioDeviceOption {
+                    id: Some(id.clone()),
+                    label: ctx
+                        .i18n
+                        .tr_with_args("settings-audio-device-missing", &[("id", id.as_str())]),
+                });
+            }
+        }
+        let selected_device = device_options
+            .iter()
+            .find(|option| option.id == self.audio_device)
+            .cloned()
+            .unwrap_or(default_option);
+        let audio_device_picker = pick_list(device_options, Some(selected_device), |option| {
+            Message::AudioDeviceSelected(option.id)
+        })
+        .padding(spacing::XS)
+        .text_size(typography::BODY);
+
+        let audio_device_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-audio-device-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-audio-device-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            audio_device_picker.into(),
+        );
+
         // Audio normalization toggle
         let normalization_row = build_toggle_button_row(
             &[
@@ -737,6 +1839,27 @@ impl State {
             normalization_row.into(),
         );
 
+        // Frame cache sizing: automatic or the two sliders below
+        let cache_auto_row = build_toggle_button_row(
+            &[
+                (false, "settings-frame-cache-auto-disabled"),
+                (true, "settings-frame-cache-auto-enabled"),
+            ],
+            self.frame_cache_auto,
+            Message::FrameCacheAutoChanged,
+            ctx.i18n,
+        );
+
+        let cache_auto_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-frame-cache-auto-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-frame-cache-auto-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            cache_auto_row.into(),
+        );
+
         // Frame cache slider
         let cache_slider = Slider::new(
             MIN_FRAME_CACHE_MB..=MAX_FRAME_CACHE_MB,
@@ -830,13 +1953,69 @@ impl State {
             seek_step_control.into(),
         );
 
-        let content = Column::new()
+        // Image sequence frame rate slider
+        let sequence_fps_slider = Slider::new(
+            MIN_SEQUENCE_FPS..=MAX_SEQUENCE_FPS,
+            self.sequence_fps,
+            Message::SequenceFpsChanged,
+        )
+        .width(Length::Fixed(200.0));
+
+        let sequence_fps_text = self.sequence_fps.to_string();
+        let sequence_fps_value = Text::new(ctx.i18n.tr_with_args(
+            "settings-sequence-fps-value",
+            &[("fps", sequence_fps_text.as_str())],
+        ));
+
+        let sequence_fps_control = Row::new()
+            .spacing(spacing::SM)
+            .align_y(Vertical::Center)
+            .push(sequence_fps_slider)
+            .push(sequence_fps_value);
+
+        let sequence_fps_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-sequence-fps-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-sequence-fps-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            sequence_fps_control.into(),
+        );
+
+        // Filename template for captured frames
+        let frame_name_input = text_input(DEFAULT_FRAME_NAME_TEMPLATE, &self.frame_name_template)
+            .on_input(Message::FrameNameTemplateChanged)
+            .padding(spacing::XXS)
+            .style(text_input_styles::standard)
+            .width(Length::Fixed(300.0));
+
+        let frame_name_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-frame-name-template-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-frame-name-template-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            frame_name_input.into(),
+        );
+
+        let mut content = Column::new()
             .spacing(spacing::MD)
             .push(autoplay_setting)
+            .push(resume_setting)
+            .push(tone_mapping_setting)
+            .push(audio_device_setting)
             .push(normalization_setting)
-            .push(cache_setting)
-            .push(history_setting)
-            .push(seek_step_setting);
+            .push(cache_auto_setting);
+        // Manual limits only apply when auto sizing is off
+        if !self.frame_cache_auto {
+            content = content.push(cache_setting).push(history_setting);
+        }
+        let content = content
+            .push(seek_step_setting)
+            .push(sequence_fps_setting)
+            .push(frame_name_setting);
 
         build_section(
             icons::video_camera(),
@@ -845,7 +2024,515 @@ impl State {
         )
     }
 
-    /// Build the AI section (Deblur and Upscale models).
+    /// Build the Guides section (composition guides, pixel grid).
+    fn build_guides_section<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+        let options = &self.guides;
+        let toggle = |key: &str,
+                      enabled: bool,
+                      set: fn(&mut guides::Options, bool)|
+         -> Element<'a, Message> {
+            self.build_setting_row(
+                ctx.i18n.tr(&format!("settings-guides-{key}-label")),
+                Some(
+                    Text::new(ctx.i18n.tr(&format!("settings-guides-{key}-hint")))
+                        .size(typography::BODY_SM)
+                        .into(),
+                ),
+                build_toggle_button_row(
+                    &[
+                        (false, "settings-overlay-hidden"),
+                        (true, "settings-overlay-shown"),
+                    ],
+                    enabled,
+                    move |enabled| {
+                        let mut options = options.clone();
+                        set(&mut options, enabled);
+                        Message::GuidesChanged(options)
+                    },
+                    ctx.i18n,
+                )
+                .into(),
+            )
+        };
+
+        let positions =
+            |key: &str, input: &'a str, on_input: fn(String) -> Message| -> Element<'a, Message> {
+                let hint = if guides::parse_positions(input).is_some() {
+                    Text::new(ctx.i18n.tr("settings-guides-positions-hint"))
+                        .size(typography::BODY_SM)
+                } else {
+                    Text::new(ctx.i18n.tr("settings-guides-positions-invalid"))
+                        .size(typography::BODY_SM)
+                        .style(move |_theme: &Theme| text::Style {
+                            color: Some(theme::error_text_color()),
+                        })
+                };
+                self.build_setting_row(
+                    ctx.i18n.tr(&format!("settings-guides-{key}-label")),
+                    Some(hint.into()),
+                    text_input("25, 50, 75", input)
+                        .on_input(on_input)
+                        .padding(spacing::XXS)
+                        .style(text_input_styles::standard)
+                        .width(Length::Fixed(300.0))
+                        .into(),
+                )
+            };
+
+        let content = Column::new()
+            .spacing(spacing::MD)
+            .push(toggle("thirds", options.thirds, |options, thirds| {
+                options.thirds = thirds;
+            }))
+            .push(toggle(
+                "golden-ratio",
+                options.golden_ratio,
+                |options, golden_ratio| options.golden_ratio = golden_ratio,
+            ))
+            .push(toggle(
+                "safe-areas",
+                options.safe_areas,
+                |options, safe_areas| options.safe_areas = safe_areas,
+            ))
+            .push(toggle(
+                "pixel-grid",
+                options.pixel_grid,
+                |options, pixel_grid| options.pixel_grid = pixel_grid,
+            ))
+            .push(positions(
+                "horizontal",
+                &self.horizontal_guides_input,
+                Message::HorizontalGuidesInputChanged,
+            ))
+            .push(positions(
+                "vertical",
+                &self.vertical_guides_input,
+                Message::VerticalGuidesInputChanged,
+            ));
+
+        build_section(
+            icons::grid(),
+            ctx.i18n.tr("settings-section-guides"),
+            content.into(),
+        )
+    }
+
+    /// Build the Editor section (save policy).
+    fn build_editor_section<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+        let policy_row = build_toggle_button_row(
+            &[
+                (SavePolicy::Overwrite, "settings-save-policy-overwrite"),
+                (SavePolicy::Copy, "settings-save-policy-copy"),
+                (SavePolicy::Subfolder, "settings-save-policy-subfolder"),
+            ],
+            self.save_policy,
+            Message::SavePolicyChanged,
+            ctx.i18n,
+        );
+
+        let policy_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-save-policy-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-save-policy-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            policy_row.into(),
+        );
+
+        let mut content = Column::new().spacing(spacing::MD).push(policy_setting);
+
+        // The folder name only applies to the subfolder policy
+        if self.save_policy == SavePolicy::Subfolder {
+            let folder_input = text_input(DEFAULT_EDITED_FOLDER, &self.edited_folder)
+                .on_input(Message::EditedFolderChanged)
+                .padding(spacing::XXS)
+                .style(text_input_styles::standard)
+                .width(Length::Fixed(300.0));
+
+            content = content.push(
+                self.build_setting_row(
+                    ctx.i18n.tr("settings-edited-folder-label"),
+                    Some(
+                        Text::new(ctx.i18n.tr("settings-edited-folder-hint"))
+                            .size(typography::BODY_SM)
+                            .into(),
+                    ),
+                    folder_input.into(),
+                ),
+            );
+        }
+
+        build_section(
+            icons::pencil(),
+            ctx.i18n.tr("settings-section-editor"),
+            content.into(),
+        )
+    }
+
+    /// Build the Advanced section (decoder backends).
+    fn build_advanced_section<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+        // Still WebP images: image-rs or libwebp
+        let webp_decoder_row = build_toggle_button_row(
+            &[
+                (WebpDecoder::ImageRs, "settings-webp-decoder-image-rs"),
+                (WebpDecoder::Libwebp, "settings-webp-decoder-libwebp"),
+            ],
+            self.webp_decoder,
+            Message::WebpDecoderChanged,
+            ctx.i18n,
+        );
+
+        let webp_decoder_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-webp-decoder-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-webp-decoder-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            webp_decoder_row.into(),
+        );
+
+        // Videos: hardware decoding with software fallback, or software only
+        let hardware_decoding_row = build_toggle_button_row(
+            &[
+                (false, "settings-hardware-decoding-disabled"),
+                (true, "settings-hardware-decoding-enabled"),
+            ],
+            self.hardware_decoding,
+            Message::HardwareDecodingChanged,
+            ctx.i18n,
+        );
+
+        let hardware_decoding_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-hardware-decoding-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-hardware-decoding-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            hardware_decoding_row.into(),
+        );
+
+        // settings.toml: copy to or replace from another file
+        let settings_file_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-file-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-file-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            Row::new()
+                .spacing(spacing::SM)
+                .push(
+                    button(text(ctx.i18n.tr("settings-file-import")))
+                        .on_press(Message::ImportSettings),
+                )
+                .push(
+                    button(text(ctx.i18n.tr("settings-file-export")))
+                        .on_press(Message::ExportSettings),
+                )
+                .into(),
+        );
+
+        let mut content = Column::new()
+            .spacing(spacing::MD)
+            .push(webp_decoder_setting)
+            .push(hardware_decoding_setting)
+            .push(settings_file_setting);
+
+        // Update checks need a build with the network code
+        if cfg!(feature = "update-check") {
+            let updates_row = Row::new()
+                .spacing(spacing::SM)
+                .align_y(Vertical::Center)
+                .push(build_toggle_button_row(
+                    &[
+                        (false, "settings-check-updates-manual"),
+                        (true, "settings-check-updates-weekly"),
+                    ],
+                    self.check_updates_weekly,
+                    Message::CheckUpdatesWeeklyChanged,
+                    ctx.i18n,
+                ))
+                .push(
+                    button(text(ctx.i18n.tr("settings-check-updates-now")))
+                        .on_press(Message::CheckForUpdates),
+                );
+
+            content = content.push(
+                self.build_setting_row(
+                    ctx.i18n.tr("settings-check-updates-label"),
+                    Some(
+                        Text::new(ctx.i18n.tr("settings-check-updates-hint"))
+                            .size(typography::BODY_SM)
+                            .into(),
+                    ),
+                    updates_row.into(),
+                ),
+            );
+        }
+
+        build_section(
+            icons::cog(),
+            ctx.i18n.tr("settings-section-advanced"),
+            content.into(),
+        )
+    }
+
+    /// Build the Mouse section (buttons and wheel in the viewer).
+    fn build_mouse_section<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+        let hint = |key: &str| -> Option<Element<'a, Message>> {
+            Some(Text::new(ctx.i18n.tr(key)).size(typography::BODY_SM).into())
+        };
+
+        let wheel_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-mouse-wheel-label"),
+            hint("settings-mouse-wheel-hint"),
+            build_toggle_button_row(
+                &[
+                    (WheelAction::Zoom, "settings-mouse-wheel-zoom"),
+                    (WheelAction::Navigate, "settings-mouse-wheel-navigate"),
+                    (WheelAction::Volume, "settings-mouse-wheel-volume"),
+                ],
+                self.mouse_mapping.wheel,
+                Message::WheelActionChanged,
+                ctx.i18n,
+            )
+            .into(),
+        );
+
+        let drag_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-mouse-drag-button-label"),
+            hint("settings-mouse-drag-button-hint"),
+            build_toggle_button_row(
+                &[
+                    (DragButton::Left, "settings-mouse-button-left"),
+                    (DragButton::Middle, "settings-mouse-button-middle"),
+                    (DragButton::Right, "settings-mouse-button-right"),
+                ],
+                self.mouse_mapping.drag_button,
+                Message::DragButtonChanged,
+                ctx.i18n,
+            )
+            .into(),
+        );
+
+        let middle_click_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-mouse-middle-click-label"),
+            hint("settings-mouse-middle-click-hint"),
+            build_toggle_button_row(
+                &[
+                    (
+                        MiddleClickAction::ToggleFit,
+                        "settings-mouse-middle-click-toggle-fit",
+                    ),
+                    (MiddleClickAction::None, "settings-mouse-middle-click-none"),
+                ],
+                self.mouse_mapping.middle_click,
+                Message::MiddleClickChanged,
+                ctx.i18n,
+            )
+            .into(),
+        );
+
+        let navigation_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-mouse-navigation-buttons-label"),
+            hint("settings-mouse-navigation-buttons-hint"),
+            build_toggle_button_row(
+                &[
+                    (false, "settings-mouse-navigation-buttons-disabled"),
+                    (true, "settings-mouse-navigation-buttons-enabled"),
+                ],
+                self.mouse_mapping.navigation_buttons,
+                Message::NavigationButtonsChanged,
+                ctx.i18n,
+            )
+            .into(),
+        );
+
+        let content = Column::new()
+            .spacing(spacing::MD)
+            .push(wheel_setting)
+            .push(drag_setting)
+            .push(middle_click_setting)
+            .push(navigation_setting);
+
+        build_section(
+            icons::crosshair(),
+            ctx.i18n.tr("settings-section-mouse"),
+            content.into(),
+        )
+    }
+
+    /// Build the Keyboard shortcuts section.
+    fn build_shortcuts_section<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+        let mut content = Column::new()
+            .spacing(spacing::MD)
+            .push(Text::new(ctx.i18n.tr("settings-shortcuts-hint")).size(typography::BODY_SM));
+
+        for group in Group::ALL {
+            let mut rows = Column::new()
+                .spacing(spacing::XS)
+                .push(Text::new(ctx.i18n.tr(group.i18n_key())).size(typography::BODY));
+            for action in Action::ALL.into_iter().filter(|a| a.group() == group) {
+                let capturing = self.capturing_shortcut == Some(action);
+                let key_label = if capturing {
+                    ctx.i18n.tr("settings-shortcuts-press-key")
+                } else {
+                    self.keymap.label(action)
+                };
+                let key_button = button(text(key_label).size(typography::BODY_SM))
+                    .on_press(Message::EditShortcut(action))
+                    .style(if capturing {
+                        button_styles::selected
+                    } else {
+                        button_styles::unselected
+                    });
+                rows = rows.push(
+                    Row::new()
+                        .spacing(spacing::SM)
+                        .align_y(Vertical::Center)
+                        .push(
+                            Text::new(ctx.i18n.tr(action.i18n_key()))
+                                .size(typography::BODY_SM)
+                                .width(Length::Fill),
+                        )
+                        .push(key_button),
+                );
+            }
+            content = content.push(rows);
+        }
+
+        if let Some((action, conflict)) = self.shortcut_conflict {
+            let action_name = ctx.i18n.tr(action.i18n_key());
+            let message = match conflict {
+                Conflict::Reserved => ctx.i18n.tr_with_args(
+                    "settings-shortcuts-reserved",
+                    &[("action", action_name.as_str())],
+                ),
+                Conflict::Action(other) => {
+                    let other_name = ctx.i18n.tr(other.i18n_key());
+                    ctx.i18n.tr_with_args(
+                        "settings-shortcuts-conflict",
+                        &[
+                            ("action", action_name.as_str()),
+                            ("other", other_name.as_str()),
+                        ],
+                    )
+                }
+            };
+            content = content.push(Text::new(message).size(typography::BODY_SM).style(
+                |_theme: &Theme| text::Style {
+                    color: Some(theme::error_text_color()),
+                },
+            ));
+        }
+
+        let reset_button = button(text(ctx.i18n.tr("settings-shortcuts-reset"))).on_press_maybe(
+            self.keymap
+                .is_customized()
+                .then_some(Message::ResetShortcuts),
+        );
+        content = content.push(reset_button);
+
+        build_section(
+            icons::help(),
+            ctx.i18n.tr("settings-section-shortcuts"),
+            content.into(),
+        )
+    }
+
+    /// Build the Plugins section (installed plugins and their hooks).
+    fn build_plugins_section<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+        let mut content = Column::new()
+            .spacing(spacing::MD)
+            .push(Text::new(ctx.i18n.tr("settings-plugins-hint")).size(typography::BODY_SM));
+
+        if self.plugins.is_empty() {
+            content = content
+                .push(Text::new(ctx.i18n.tr("settings-plugins-empty")).size(typography::BODY));
+        }
+
+        for plugin in &self.plugins {
+            let title = match &plugin.version {
+                Some(version) => format!("{} {version}", plugin.name),
+                None => plugin.name.clone(),
+            };
+            let mut details = Column::new()
+                .spacing(spacing::XXS)
+                .width(Length::Fill)
+                .push(Text::new(title).size(typography::BODY));
+            if let Some(description) = &plugin.description {
+                details = details.push(Text::new(description.as_str()).size(typography::BODY_SM));
+            }
+            if let Some(error) = &plugin.error {
+                details = details.push(
+                    Text::new(
+                        ctx.i18n
+                            .tr_with_args("settings-plugins-invalid", &[("error", error.as_str())]),
+                    )
+                    .size(typography::BODY_SM)
+                    .style(|_theme: &Theme| text::Style {
+                        color: Some(theme::error_text_color()),
+                    }),
+                );
+            } else if !plugin.hooks.is_empty() {
+                let hooks = plugin
+                    .hooks
+                    .iter()
+                    .map(|key| ctx.i18n.tr(key))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                details = details.push(
+                    Text::new(
+                        ctx.i18n
+                            .tr_with_args("settings-plugins-hooks", &[("hooks", hooks.as_str())]),
+                    )
+                    .size(typography::BODY_SM),
+                );
+            }
+
+            let id = plugin.id.clone();
+            let toggle = build_toggle_button_row(
+                &[
+                    (false, "settings-plugins-disabled"),
+                    (true, "settings-plugins-enabled"),
+                ],
+                plugin.enabled,
+                move |enabled| Message::PluginToggled(id.clone(), enabled),
+                ctx.i18n,
+            );
+            content = content.push(
+                Row::new()
+                    .spacing(spacing::SM)
+                    .align_y(Vertical::Center)
+                    .push(details)
+                    .push(toggle),
+            );
+        }
+
+        content = content.push(
+            Row::new()
+                .spacing(spacing::SM)
+                .push(
+                    button(text(ctx.i18n.tr("settings-plugins-open-folder")))
+                        .on_press(Message::OpenPluginsFolder),
+                )
+                .push(
+                    button(text(ctx.i18n.tr("settings-plugins-reload")))
+                        .on_press(Message::ReloadPlugins),
+                ),
+        );
+
+        build_section(
+            icons::bullet_list(),
+            ctx.i18n.tr("settings-section-plugins"),
+            content.into(),
+        )
+    }
+
+    /// Build the AI section (Deblur, Upscale and Background removal models).
     fn build_ai_section<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
         let mut content = Column::new().spacing(spacing::MD);
 
@@ -862,6 +2549,14 @@ impl State {
         // =========================================================================
         content = content.push(self.build_upscale_subsection(ctx));
 
+        // Add a separator between upscale and background removal
+        content = content.push(rule::horizontal(1));
+
+        // =========================================================================
+        // Background removal subsection
+        // =========================================================================
+        content = content.push(self.build_background_subsection(ctx));
+
         build_section(
             icons::cog(),
             ctx.i18n.tr("settings-section-ai"),
@@ -885,30 +2580,168 @@ impl State {
 
         // "Disabled" button
         let disable_btn = {
-            let btn = Button::new(Text::new(ctx.i18n.tr("settings-deblur-disabled")));
-            if is_busy || !self.enable_deblur {
+            let btn = Button::new(Text::new(ctx.i18n.tr("settings-deblur-disabled")));
+            if is_busy || !self.enable_deblur {
+                btn.style(button_styles::disabled())
+            } else {
+                btn.on_press(Message::DisableDeblur)
+            }
+        };
+        enable_row = enable_row.push(disable_btn);
+
+        // "Enabled" button
+        let enable_btn = {
+            let btn = Button::new(Text::new(ctx.i18n.tr("settings-deblur-enabled")));
+            if is_busy || self.enable_deblur {
+                btn.style(button_styles::disabled())
+            } else {
+                btn.on_press(Message::RequestEnableDeblur)
+            }
+        };
+        enable_row = enable_row.push(enable_btn);
+
+        let enable_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-enable-deblur-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-enable-deblur-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            enable_row.into(),
+        );
+
+        let mut subsection = Column::new().spacing(spacing::MD).push(enable_setting);
+
+        // Model URL input - show when NOT busy
+        if !is_busy {
+            let url_input = text_input(
+                &ctx.i18n.tr("settings-deblur-model-url-placeholder"),
+                &self.deblur_model_url,
+            )
+            .on_input(Message::DeblurModelUrlChanged)
+            .padding(spacing::XXS)
+            .style(text_input_styles::standard)
+            .width(Length::Fixed(400.0));
+
+            let url_setting = self.build_setting_row(
+                ctx.i18n.tr("settings-deblur-model-url-label"),
+                Some(
+                    Text::new(ctx.i18n.tr("settings-deblur-model-url-hint"))
+                        .size(typography::BODY_SM)
+                        .into(),
+                ),
+                url_input.into(),
+            );
+
+            subsection = subsection.push(url_setting);
+        }
+
+        // Show status and progress when enabled OR when an operation is in progress
+        let show_status = self.enable_deblur || is_busy;
+        if show_status {
+            if let ModelStatus::Downloading { progress } = &self.deblur_model_status {
+                let progress_bar_widget = progress_bar(0.0..=1.0, *progress);
+                // Progress is 0.0-1.0, so *100 is 0-100 which fits in u32
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let progress_percent = format!("{}", (progress * 100.0) as u32);
+                let progress_text = Text::new(ctx.i18n.tr_with_args(
+                    "settings-deblur-status-downloading",
+                    &[("progress", progress_percent.as_str())],
+                ))
+                .size(typography::BODY_SM)
+                .style(|_: &Theme| text::Style {
+                    color: Some(theme::muted_text_color()),
+                });
+
+                let progress_column = Column::new()
+                    .spacing(spacing::XS)
+                    .push(progress_bar_widget)
+                    .push(progress_text);
+
+                let progress_setting = self.build_setting_row(
+                    ctx.i18n.tr("settings-deblur-status-label"),
+                    None,
+                    progress_column.into(),
+                );
+                subsection = subsection.push(progress_setting);
+            } else {
+                let status_text = match &self.deblur_model_status {
+                    ModelStatus::NotDownloaded => {
+                        ctx.i18n.tr("settings-deblur-status-not-downloaded")
+                    }
+                    ModelStatus::Downloading { .. } => unreachable!(),
+                    ModelStatus::Validating => ctx.i18n.tr("settings-deblur-status-validating"),
+                    ModelStatus::Ready => ctx.i18n.tr("settings-deblur-status-ready"),
+                    ModelStatus::Error(msg) => ctx
+                        .i18n
+                        .tr_with_args("settings-deblur-status-error", &[("message", msg.as_str())]),
+                };
+
+                let status_style = match &self.deblur_model_status {
+                    ModelStatus::Ready => theme::success_text_color(),
+                    ModelStatus::Error(_) => theme::error_text_color(),
+                    _ => theme::muted_text_color(),
+                };
+
+                let status_display =
+                    Text::new(status_text)
+                        .size(typography::BODY_SM)
+                        .style(move |_: &Theme| text::Style {
+                            color: Some(status_style),
+                        });
+
+                let status_setting = self.build_setting_row(
+                    ctx.i18n.tr("settings-deblur-status-label"),
+                    None,
+                    status_display.into(),
+                );
+                subsection = subsection.push(status_setting);
+            }
+        }
+
+        subsection.into()
+    }
+
+    /// Build the upscale subsection within the AI section.
+    // Allow too_many_lines: declarative UI subsection for AI upscale feature.
+    // Model status handling and toggle widgets logically grouped.
+    #[allow(clippy::too_many_lines)]
+    fn build_upscale_subsection<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+        // Determine if an operation is in progress (downloading or validating)
+        let is_busy = matches!(
+            self.upscale_model_status,
+            UpscaleModelStatus::Downloading { .. } | UpscaleModelStatus::Validating
+        );
+
+        // Enable/disable upscale toggle
+        let mut enable_row = Row::new().spacing(spacing::XS);
+
+        // "Disabled" button
+        let disable_btn = {
+            let btn = Button::new(Text::new(ctx.i18n.tr("settings-upscale-disabled")));
+            if is_busy || !self.enable_upscale {
                 btn.style(button_styles::disabled())
             } else {
-                btn.on_press(Message::DisableDeblur)
+                btn.on_press(Message::DisableUpscale)
             }
         };
         enable_row = enable_row.push(disable_btn);
 
         // "Enabled" button
         let enable_btn = {
-            let btn = Button::new(Text::new(ctx.i18n.tr("settings-deblur-enabled")));
-            if is_busy || self.enable_deblur {
+            let btn = Button::new(Text::new(ctx.i18n.tr("settings-upscale-enabled")));
+            if is_busy || self.enable_upscale {
                 btn.style(button_styles::disabled())
             } else {
-                btn.on_press(Message::RequestEnableDeblur)
+                btn.on_press(Message::RequestEnableUpscale)
             }
         };
         enable_row = enable_row.push(enable_btn);
 
         let enable_setting = self.build_setting_row(
-            ctx.i18n.tr("settings-enable-deblur-label"),
+            ctx.i18n.tr("settings-enable-upscale-label"),
             Some(
-                Text::new(ctx.i18n.tr("settings-enable-deblur-hint"))
+                Text::new(ctx.i18n.tr("settings-enable-upscale-hint"))
                     .size(typography::BODY_SM)
                     .into(),
             ),
@@ -920,17 +2753,18 @@ impl State {
         // Model URL input - show when NOT busy
         if !is_busy {
             let url_input = text_input(
-                &ctx.i18n.tr("settings-deblur-model-url-placeholder"),
-                &self.deblur_model_url,
+                &ctx.i18n.tr("settings-upscale-model-url-placeholder"),
+                &self.upscale_model_url,
             )
-            .on_input(Message::DeblurModelUrlChanged)
+            .on_input(Message::UpscaleModelUrlChanged)
             .padding(spacing::XXS)
+            .style(text_input_styles::standard)
             .width(Length::Fixed(400.0));
 
             let url_setting = self.build_setting_row(
-                ctx.i18n.tr("settings-deblur-model-url-label"),
+                ctx.i18n.tr("settings-upscale-model-url-label"),
                 Some(
-                    Text::new(ctx.i18n.tr("settings-deblur-model-url-hint"))
+                    Text::new(ctx.i18n.tr("settings-upscale-model-url-hint"))
                         .size(typography::BODY_SM)
                         .into(),
                 ),
@@ -941,15 +2775,15 @@ impl State {
         }
 
         // Show status and progress when enabled OR when an operation is in progress
-        let show_status = self.enable_deblur || is_busy;
+        let show_status = self.enable_upscale || is_busy;
         if show_status {
-            if let ModelStatus::Downloading { progress } = &self.deblur_model_status {
+            if let UpscaleModelStatus::Downloading { progress } = &self.upscale_model_status {
                 let progress_bar_widget = progress_bar(0.0..=1.0, *progress);
                 // Progress is 0.0-1.0, so *100 is 0-100 which fits in u32
                 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
                 let progress_percent = format!("{}", (progress * 100.0) as u32);
                 let progress_text = Text::new(ctx.i18n.tr_with_args(
-                    "settings-deblur-status-downloading",
+                    "settings-upscale-status-downloading",
                     &[("progress", progress_percent.as_str())],
                 ))
                 .size(typography::BODY_SM)
@@ -963,27 +2797,30 @@ impl State {
                     .push(progress_text);
 
                 let progress_setting = self.build_setting_row(
-                    ctx.i18n.tr("settings-deblur-status-label"),
+                    ctx.i18n.tr("settings-upscale-status-label"),
                     None,
                     progress_column.into(),
                 );
                 subsection = subsection.push(progress_setting);
             } else {
-                let status_text = match &self.deblur_model_status {
-                    ModelStatus::NotDownloaded => {
-                        ctx.i18n.tr("settings-deblur-status-not-downloaded")
+                let status_text = match &self.upscale_model_status {
+                    UpscaleModelStatus::NotDownloaded => {
+                        ctx.i18n.tr("settings-upscale-status-not-downloaded")
                     }
-                    ModelStatus::Downloading { .. } => unreachable!(),
-                    ModelStatus::Validating => ctx.i18n.tr("settings-deblur-status-validating"),
-                    ModelStatus::Ready => ctx.i18n.tr("settings-deblur-status-ready"),
-                    ModelStatus::Error(msg) => ctx
-                        .i18n
-                        .tr_with_args("settings-deblur-status-error", &[("message", msg.as_str())]),
+                    UpscaleModelStatus::Downloading { .. } => unreachable!(),
+                    UpscaleModelStatus::Validating => {
+                        ctx.i18n.tr("settings-upscale-status-validating")
+                    }
+                    UpscaleModelStatus::Ready => ctx.i18n.tr("settings-upscale-status-ready"),
+                    UpscaleModelStatus::Error(msg) => ctx.i18n.tr_with_args(
+                        "settings-upscale-status-error",
+                        &[("message", msg.as_str())],
+                    ),
                 };
 
-                let status_style = match &self.deblur_model_status {
-                    ModelStatus::Ready => theme::success_text_color(),
-                    ModelStatus::Error(_) => theme::error_text_color(),
+                let status_style = match &self.upscale_model_status {
+                    UpscaleModelStatus::Ready => theme::success_text_color(),
+                    UpscaleModelStatus::Error(_) => theme::error_text_color(),
                     _ => theme::muted_text_color(),
                 };
 
@@ -995,7 +2832,7 @@ impl State {
                         });
 
                 let status_setting = self.build_setting_row(
-                    ctx.i18n.tr("settings-deblur-status-label"),
+                    ctx.i18n.tr("settings-upscale-status-label"),
                     None,
                     status_display.into(),
                 );
@@ -1006,46 +2843,50 @@ impl State {
         subsection.into()
     }
 
-    /// Build the upscale subsection within the AI section.
-    // Allow too_many_lines: declarative UI subsection for AI upscale feature.
+    /// Build the background removal subsection within the AI section.
+    // Allow too_many_lines: declarative UI subsection for AI background removal feature.
     // Model status handling and toggle widgets logically grouped.
     #[allow(clippy::too_many_lines)]
-    fn build_upscale_subsection<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
+    fn build_background_subsection<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
         // Determine if an operation is in progress (downloading or validating)
         let is_busy = matches!(
-            self.upscale_model_status,
-            UpscaleModelStatus::Downloading { .. } | UpscaleModelStatus::Validating
+            self.background_model_status,
+            BackgroundModelStatus::Downloading { .. } | BackgroundModelStatus::Validating
         );
 
-        // Enable/disable upscale toggle
+        // Enable/disable background removal toggle
         let mut enable_row = Row::new().spacing(spacing::XS);
 
         // "Disabled" button
         let disable_btn = {
-            let btn = Button::new(Text::new(ctx.i18n.tr("settings-upscale-disabled")));
-            if is_busy || !self.enable_upscale {
+            let btn = Button::new(Text::new(
+                ctx.i18n.tr("settings-background-removal-disabled"),
+            ));
+            if is_busy || !self.enable_background_removal {
                 btn.style(button_styles::disabled())
             } else {
-                btn.on_press(Message::DisableUpscale)
+                btn.on_press(Message::DisableBackgroundRemoval)
             }
         };
         enable_row = enable_row.push(disable_btn);
 
         // "Enabled" button
         let enable_btn = {
-            let btn = Button::new(Text::new(ctx.i18n.tr("settings-upscale-enabled")));
-            if is_busy || self.enable_upscale {
+            let btn = Button::new(Text::new(
+                ctx.i18n.tr("settings-background-removal-enabled"),
+            ));
+            if is_busy || self.enable_background_removal {
                 btn.style(button_styles::disabled())
             } else {
-                btn.on_press(Message::RequestEnableUpscale)
+                btn.on_press(Message::RequestEnableBackgroundRemoval)
             }
         };
         enable_row = enable_row.push(enable_btn);
 
         let enable_setting = self.build_setting_row(
-            ctx.i18n.tr("settings-enable-upscale-label"),
+            ctx.i18n.tr("settings-enable-background-removal-label"),
             Some(
-                Text::new(ctx.i18n.tr("settings-enable-upscale-hint"))
+                Text::new(ctx.i18n.tr("settings-enable-background-removal-hint"))
                     .size(typography::BODY_SM)
                     .into(),
             ),
@@ -1057,17 +2898,19 @@ impl State {
         // Model URL input - show when NOT busy
         if !is_busy {
             let url_input = text_input(
-                &ctx.i18n.tr("settings-upscale-model-url-placeholder"),
-                &self.upscale_model_url,
+                &ctx.i18n
+                    .tr("settings-background-removal-model-url-placeholder"),
+                &self.background_model_url,
             )
-            .on_input(Message::UpscaleModelUrlChanged)
+            .on_input(Message::BackgroundModelUrlChanged)
             .padding(spacing::XXS)
+            .style(text_input_styles::standard)
             .width(Length::Fixed(400.0));
 
             let url_setting = self.build_setting_row(
-                ctx.i18n.tr("settings-upscale-model-url-label"),
+                ctx.i18n.tr("settings-background-removal-model-url-label"),
                 Some(
-                    Text::new(ctx.i18n.tr("settings-upscale-model-url-hint"))
+                    Text::new(ctx.i18n.tr("settings-background-removal-model-url-hint"))
                         .size(typography::BODY_SM)
                         .into(),
                 ),
@@ -1078,15 +2921,15 @@ impl State {
         }
 
         // Show status and progress when enabled OR when an operation is in progress
-        let show_status = self.enable_upscale || is_busy;
+        let show_status = self.enable_background_removal || is_busy;
         if show_status {
-            if let UpscaleModelStatus::Downloading { progress } = &self.upscale_model_status {
+            if let BackgroundModelStatus::Downloading { progress } = &self.background_model_status {
                 let progress_bar_widget = progress_bar(0.0..=1.0, *progress);
                 // Progress is 0.0-1.0, so *100 is 0-100 which fits in u32
                 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
                 let progress_percent = format!("{}", (progress * 100.0) as u32);
                 let progress_text = Text::new(ctx.i18n.tr_with_args(
-                    "settings-upscale-status-downloading",
+                    "settings-background-removal-status-downloading",
                     &[("progress", progress_percent.as_str())],
                 ))
                 .size(typography::BODY_SM)
@@ -1100,30 +2943,32 @@ impl State {
                     .push(progress_text);
 
                 let progress_setting = self.build_setting_row(
-                    ctx.i18n.tr("settings-upscale-status-label"),
+                    ctx.i18n.tr("settings-background-removal-status-label"),
                     None,
                     progress_column.into(),
                 );
                 subsection = subsection.push(progress_setting);
             } else {
-                let status_text = match &self.upscale_model_status {
-                    UpscaleModelStatus::NotDownloaded => {
-                        ctx.i18n.tr("settings-upscale-status-not-downloaded")
+                let status_text = match &self.background_model_status {
+                    BackgroundModelStatus::NotDownloaded => ctx
+                        .i18n
+                        .tr("settings-background-removal-status-not-downloaded"),
+                    BackgroundModelStatus::Downloading { .. } => unreachable!(),
+                    BackgroundModelStatus::Validating => {
+                        ctx.i18n.tr("settings-background-removal-status-validating")
                     }
-                    UpscaleModelStatus::Downloading { .. } => unreachable!(),
-                    UpscaleModelStatus::Validating => {
-                        ctx.i18n.tr("settings-upscale-status-validating")
+                    BackgroundModelStatus::Ready => {
+                        ctx.i18n.tr("settings-background-removal-status-ready")
                     }
-                    UpscaleModelStatus::Ready => ctx.i18n.tr("settings-upscale-status-ready"),
-                    UpscaleModelStatus::Error(msg) => ctx.i18n.tr_with_args(
-                        "settings-upscale-status-error",
+                    BackgroundModelStatus::Error(msg) => ctx.i18n.tr_with_args(
+                        "settings-background-removal-status-error",
                         &[("message", msg.as_str())],
                     ),
                 };
 
-                let status_style = match &self.upscale_model_status {
-                    UpscaleModelStatus::Ready => theme::success_text_color(),
-                    UpscaleModelStatus::Error(_) => theme::error_text_color(),
+                let status_style = match &self.background_model_status {
+                    BackgroundModelStatus::Ready => theme::success_text_color(),
+                    BackgroundModelStatus::Error(_) => theme::error_text_color(),
                     _ => theme::muted_text_color(),
                 };
 
@@ -1135,7 +2980,7 @@ impl State {
                         });
 
                 let status_setting = self.build_setting_row(
-                    ctx.i18n.tr("settings-upscale-status-label"),
+                    ctx.i18n.tr("settings-background-removal-status-label"),
                     None,
                     status_display.into(),
                 );
@@ -1146,7 +2991,8 @@ impl State {
         subsection.into()
     }
 
-    /// Build the Fullscreen section (Overlay timeout).
+    /// Build the Fullscreen section (overlay timeout, overlay elements,
+    /// position and opacity).
     fn build_fullscreen_section<'a>(&'a self, ctx: &ViewContext<'a>) -> Element<'a, Message> {
         let timeout_slider = Slider::new(
             MIN_OVERLAY_TIMEOUT_SECS..=MAX_OVERLAY_TIMEOUT_SECS,
@@ -1178,7 +3024,109 @@ impl State {
             timeout_control.into(),
         );
 
-        let content = Column::new().spacing(spacing::MD).push(timeout_setting);
+        let options = self.fullscreen_overlay;
+        let toggle = |key: &str,
+                      enabled: bool,
+                      set: fn(fullscreen_overlay::Options, bool) -> fullscreen_overlay::Options|
+         -> Element<'a, Message> {
+            self.build_setting_row(
+                ctx.i18n.tr(&format!("settings-overlay-{key}-label")),
+                None,
+                build_toggle_button_row(
+                    &[
+                        (false, "settings-overlay-hidden"),
+                        (true, "settings-overlay-shown"),
+                    ],
+                    enabled,
+                    move |enabled| Message::FullscreenOverlayChanged(set(options, enabled)),
+                    ctx.i18n,
+                )
+                .into(),
+            )
+        };
+
+        // Top positions above bottom ones, like on screen
+        let position_rows = OverlayPosition::ALL.chunks(3).fold(
+            Column::new().spacing(spacing::XS),
+            |column, positions| {
+                let choices: Vec<_> = positions
+                    .iter()
+                    .map(|&position| (position, position.i18n_key()))
+                    .collect();
+                column.push(build_toggle_button_row(
+                    &choices,
+                    options.position,
+                    move |position| {
+                        Message::FullscreenOverlayChanged(fullscreen_overlay::Options {
+                            position,
+                            ..options
+                        })
+                    },
+                    ctx.i18n,
+                ))
+            },
+        );
+        let position_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-overlay-position-label"),
+            Some(
+                Text::new(ctx.i18n.tr("settings-overlay-position-hint"))
+                    .size(typography::BODY_SM)
+                    .into(),
+            ),
+            position_rows.into(),
+        );
+
+        let opacity_slider = Slider::new(
+            MIN_OVERLAY_OPACITY_PERCENT..=MAX_OVERLAY_OPACITY_PERCENT,
+            options.opacity_percent,
+            move |opacity_percent| {
+                Message::FullscreenOverlayChanged(fullscreen_overlay::Options {
+                    opacity_percent,
+                    ..options
+                })
+            },
+        )
+        .step(5u32)
+        .width(Length::Fixed(200.0));
+        let opacity_control = Row::new()
+            .spacing(spacing::SM)
+            .align_y(Vertical::Center)
+            .push(opacity_slider)
+            .push(Text::new(format!("{}%", options.opacity_percent)));
+        let opacity_setting = self.build_setting_row(
+            ctx.i18n.tr("settings-overlay-opacity-label"),
+            None,
+            opacity_control.into(),
+        );
+
+        let content = Column::new()
+            .spacing(spacing::MD)
+            .push(timeout_setting)
+            .push(toggle("filename", options.filename, |options, filename| {
+                fullscreen_overlay::Options {
+                    filename,
+                    ..options
+                }
+            }))
+            .push(toggle("index", options.index, |options, index| {
+                fullscreen_overlay::Options { index, ..options }
+            }))
+            .push(toggle("clock", options.clock, |options, clock| {
+                fullscreen_overlay::Options { clock, ..options }
+            }))
+            .push(toggle("arrows", options.arrows, |options, arrows| {
+                fullscreen_overlay::Options { arrows, ..options }
+            }))
+            .push(toggle(
+                "histogram",
+                options.histogram,
+                |options, histogram| fullscreen_overlay::Options {
+                    histogram,
+                    ..options
+                },
+            ))
+            .push(position_setting)
+            .push(opacity_setting);
 
         build_section(
             icons::fullscreen(),
@@ -1244,40 +3192,158 @@ impl State {
             Message::SortOrderSelected(order) => {
                 update_if_changed(&mut self.sort_order, order, Event::SortOrderSelected)
             }
+            Message::SortDirectionSelected(direction) => update_if_changed(
+                &mut self.sort_direction,
+                direction,
+                Event::SortDirectionSelected,
+            ),
             Message::OverlayTimeoutChanged(timeout) => update_if_changed(
                 &mut self.overlay_timeout_secs,
                 timeout,
                 Event::OverlayTimeoutChanged,
             ),
+            Message::FullscreenOverlayChanged(options) => update_if_changed(
+                &mut self.fullscreen_overlay,
+                options,
+                Event::FullscreenOverlayChanged,
+            ),
             Message::ThemeModeSelected(mode) => {
                 update_if_changed(&mut self.theme_mode, mode, Event::ThemeModeSelected)
             }
+            Message::AccentColorSelected(color) => {
+                self.accent_input = color.map(theming::to_hex).unwrap_or_default();
+                update_if_changed(&mut self.accent_color, color, Event::AccentColorChanged)
+            }
+            Message::AccentColorInputChanged(input) => {
+                let color = theming::parse_hex(&input);
+                let cleared = input.trim().is_empty();
+                self.accent_input = input;
+                if color.is_some() || cleared {
+                    update_if_changed(&mut self.accent_color, color, Event::AccentColorChanged)
+                } else {
+                    Event::None
+                }
+            }
+            Message::UiScaleSliderMoved(percent) => {
+                self.ui_scale_slider = percent;
+                Event::None
+            }
+            Message::UiScaleSliderReleased => update_if_changed(
+                &mut self.ui_scale_percent,
+                self.ui_scale_slider,
+                Event::UiScaleChanged,
+            ),
+            Message::AccessibilityChanged(options) => update_if_changed(
+                &mut self.accessibility,
+                options,
+                Event::AccessibilityChanged,
+            ),
+            Message::CustomThemeChanged(enabled) => {
+                update_if_changed(&mut self.custom_theme, enabled, Event::CustomThemeChanged)
+            }
+            Message::PaletteColorInputChanged(slot, input) => {
+                let color = theming::parse_hex(&input);
+                self.palette_inputs[slot as usize] = input;
+                match color {
+                    Some(color) if color != slot.get(&self.custom_palette) => {
+                        slot.set(&mut self.custom_palette, color);
+                        Event::CustomPaletteChanged(self.custom_palette)
+                    }
+                    _ => Event::None,
+                }
+            }
             Message::VideoAutoplayChanged(enabled) => update_if_changed(
                 &mut self.video_autoplay,
                 enabled,
                 Event::VideoAutoplayChanged,
             ),
+            Message::ResumePlaybackChanged(mode) => update_if_changed(
+                &mut self.resume_playback,
+                mode,
+                Event::ResumePlaybackChanged,
+            ),
+            Message::ToneMappingChanged(operator) => {
+                update_if_changed(&mut self.tone_mapping, operator, Event::ToneMappingChanged)
+            }
+            Message::AudioDeviceSelected(id) => {
+                update_if_changed(&mut self.audio_device, id, Event::AudioDeviceChanged)
+            }
+            Message::AudioDevicesListed(devices) => {
+                self.audio_devices = devices;
+                Event::None
+            }
             Message::AudioNormalizationChanged(enabled) => update_if_changed(
                 &mut self.audio_normalization,
                 enabled,
                 Event::AudioNormalizationChanged,
             ),
+            Message::HardwareDecodingChanged(enabled) => update_if_changed(
+                &mut self.hardware_decoding,
+                enabled,
+                Event::HardwareDecodingChanged,
+            ),
             Message::FrameCacheMbChanged(mb) => {
                 update_if_changed(&mut self.frame_cache_mb, mb, Event::FrameCacheMbChanged)
             }
             Message::FrameHistoryMbChanged(mb) => {
                 update_if_changed(&mut self.frame_history_mb, mb, Event::FrameHistoryMbChanged)
             }
+            Message::FrameCacheAutoChanged(enabled) => update_if_changed(
+                &mut self.frame_cache_auto,
+                enabled,
+                Event::FrameCacheAutoChanged,
+            ),
             Message::KeyboardSeekStepChanged(step) => update_if_changed(
                 &mut self.keyboard_seek_step_secs,
                 step,
                 Event::KeyboardSeekStepChanged,
             ),
+            Message::SequenceFpsChanged(fps) => {
+                update_if_changed(&mut self.sequence_fps, fps, Event::SequenceFpsChanged)
+            }
+            Message::FrameNameTemplateChanged(template) => {
+                self.frame_name_template.clone_from(&template);
+                Event::FrameNameTemplateChanged(template)
+            }
+            Message::SavePolicyChanged(policy) => {
+                update_if_changed(&mut self.save_policy, policy, Event::SavePolicyChanged)
+            }
+            Message::EditedFolderChanged(folder) => {
+                self.edited_folder.clone_from(&folder);
+                Event::EditedFolderChanged(folder)
+            }
+            Message::GuidesChanged(options) => {
+                update_if_changed(&mut self.guides, options, Event::GuidesChanged)
+            }
+            Message::HorizontalGuidesInputChanged(input) => {
+                let positions = guides::parse_positions(&input);
+                self.horizontal_guides_input = input;
+                self.guides_positions_changed(positions, |options, horizontal| {
+                    options.horizontal = horizontal;
+                })
+            }
+            Message::VerticalGuidesInputChanged(input) => {
+                let positions = guides::parse_positions(&input);
+                self.vertical_guides_input = input;
+                self.guides_positions_changed(positions, |options, vertical| {
+                    options.vertical = vertical;
+                })
+            }
             Message::MaxSkipAttemptsChanged(attempts) => update_if_changed(
                 &mut self.max_skip_attempts,
                 attempts,
                 Event::MaxSkipAttemptsChanged,
             ),
+            Message::PrefetchCacheSizeChanged(size) => update_if_changed(
+                &mut self.prefetch_cache_size,
+                size,
+                Event::PrefetchCacheSizeChanged,
+            ),
+            Message::SimilarityThresholdChanged(threshold) => update_if_changed(
+                &mut self.similarity_threshold,
+                threshold,
+                Event::SimilarityThresholdChanged,
+            ),
             Message::RequestEnableDeblur => {
                 // Don't set enable_deblur here - it will be set after successful validation
                 Event::RequestEnableDeblur
@@ -1304,14 +3370,112 @@ impl State {
                 self.upscale_model_url.clone_from(&url);
                 Event::UpscaleModelUrlChanged(url)
             }
+            Message::RequestEnableBackgroundRemoval => {
+                // Don't set enable_background_removal here - it will be set after successful validation
+                Event::RequestEnableBackgroundRemoval
+            }
+            Message::DisableBackgroundRemoval => {
+                self.enable_background_removal = false;
+                self.background_model_status = BackgroundModelStatus::NotDownloaded;
+                Event::DisableBackgroundRemoval
+            }
+            Message::BackgroundModelUrlChanged(url) => {
+                self.background_model_url.clone_from(&url);
+                Event::BackgroundModelUrlChanged(url)
+            }
             Message::PersistFiltersChanged(enabled) => update_if_changed(
                 &mut self.persist_filters,
                 enabled,
                 Event::PersistFiltersChanged,
             ),
+            Message::RememberViewPerImageChanged(enabled) => update_if_changed(
+                &mut self.remember_view_per_image,
+                enabled,
+                Event::RememberViewPerImageChanged,
+            ),
+            Message::ShowMinimapChanged(enabled) => {
+                update_if_changed(&mut self.show_minimap, enabled, Event::ShowMinimapChanged)
+            }
+            Message::SandboxedDecodingChanged(enabled) => update_if_changed(
+                &mut self.sandboxed_decoding,
+                enabled,
+                Event::SandboxedDecodingChanged,
+            ),
+            Message::WebpDecoderChanged(decoder) => {
+                update_if_changed(&mut self.webp_decoder, decoder, Event::WebpDecoderChanged)
+            }
+            Message::NavigationButtonsChanged(enabled) => self.update_mouse_mapping(MouseMapping {
+                navigation_buttons: enabled,
+                ..self.mouse_mapping
+            }),
+            Message::MiddleClickChanged(action) => self.update_mouse_mapping(MouseMapping {
+                middle_click: action,
+                ..self.mouse_mapping
+            }),
+            Message::WheelActionChanged(action) => self.update_mouse_mapping(MouseMapping {
+                wheel: action,
+                ..self.mouse_mapping
+            }),
+            Message::DragButtonChanged(button) => self.update_mouse_mapping(MouseMapping {
+                drag_button: button,
+                ..self.mouse_mapping
+            }),
+            Message::EditShortcut(action) => {
+                self.capturing_shortcut = Some(action);
+                self.shortcut_conflict = None;
+                Event::None
+            }
+            Message::ShortcutKeyPressed(binding) => {
+                let Some(action) = self.capturing_shortcut.take() else {
+                    return Event::None;
+                };
+                if binding.is_cancel() || binding == self.keymap.binding(action) {
+                    return Event::None;
+                }
+                match self.keymap.rebind(action, binding) {
+                    Ok(()) => Event::ShortcutsChanged,
+                    Err(conflict) => {
+                        self.shortcut_conflict = Some((action, conflict));
+                        Event::None
+                    }
+                }
+            }
+            Message::ResetShortcuts => {
+                self.capturing_shortcut = None;
+                self.shortcut_conflict = None;
+                if self.keymap.is_customized() {
+                    self.keymap.reset();
+                    Event::ShortcutsChanged
+                } else {
+                    Event::None
+                }
+            }
+            Message::PluginToggled(id, enabled) => {
+                match self.plugins.iter_mut().find(|plugin| plugin.id == id) {
+                    Some(plugin) if plugin.enabled != enabled => {
+                        plugin.enabled = enabled;
+                        Event::PluginToggled { id, enabled }
+                    }
+                    _ => Event::None,
+                }
+            }
+            Message::OpenPluginsFolder => Event::OpenPluginsFolder,
+            Message::ReloadPlugins => Event::ReloadPlugins,
+            Message::ImportSettings => Event::ImportSettings,
+            Message::ExportSettings => Event::ExportSettings,
+            Message::CheckUpdatesWeeklyChanged(enabled) => update_if_changed(
+                &mut self.check_updates_weekly,
+                enabled,
+                Event::CheckUpdatesWeeklyChanged,
+            ),
+            Message::CheckForUpdates => Event::CheckForUpdates,
         }
     }
 
+    fn update_mouse_mapping(&mut self, mapping: MouseMapping) -> Event {
+        update_if_changed(&mut self.mouse_mapping, mapping, Event::MouseMappingChanged)
+    }
+
     /// Ensures any pending zoom step edits are validated before leaving the screen.
     pub(crate) fn ensure_zoom_step_committed(&mut self) -> Result<Option<f32>, ZoomStepError> {
         if self.zoom_step_input_dirty {
@@ -1379,7 +3543,7 @@ fn build_section(
 /// Builds a row of toggle buttons for boolean or enum selection.
 ///
 /// Each button represents an option and toggles between selected/unselected style.
-fn build_toggle_button_row<'a, T, M>(
+pub(crate) fn build_toggle_button_row<'a, T, M>(
     options: &[(T, &str)],
     selected: T,
     on_select: impl Fn(T) -> M + 'a,
@@ -1403,6 +3567,39 @@ where
     row
 }
 
+/// Style of an accent swatch button, outlined when selected.
+fn swatch_style(color: Color, selected: bool) -> impl Fn(&Theme, button::Status) -> button::Style {
+    move |theme: &Theme, status: button::Status| {
+        let outline = selected || matches!(status, button::Status::Hovered);
+        button::Style {
+            background: Some(color.into()),
+            border: Border {
+                color: if outline {
+                    theme.palette().text
+                } else {
+                    Color::TRANSPARENT
+                },
+                width: 2.0,
+                radius: radius::SM.into(),
+            },
+            ..button::Style::default()
+        }
+    }
+}
+
+/// Style of a palette color preview.
+fn swatch_container_style(color: Color) -> impl Fn(&Theme) -> container::Style {
+    move |theme: &Theme| container::Style {
+        background: Some(color.into()),
+        border: Border {
+            color: theme.extended_palette().background.strong.color,
+            width: 1.0,
+            radius: radius::SM.into(),
+        },
+        ..container::Style::default()
+    }
+}
+
 fn parse_number(input: &str) -> Option<f32> {
     let trimmed = input.trim();
     if trimmed.is_empty() {
@@ -1427,6 +3624,80 @@ fn parse_number(input: &str) -> Option<f32> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn webp_decoder_change_updates_load_options() {
+        let mut state = State::default();
+        assert_eq!(state.load_options(), LoadOptions::default());
+
+        let event = state.update(Message::WebpDecoderChanged(WebpDecoder::Libwebp));
+        assert!(matches!(
+            event,
+            Event::WebpDecoderChanged(WebpDecoder::Libwebp)
+        ));
+        assert_eq!(state.load_options().decoders.webp, WebpDecoder::Libwebp);
+
+        // Selecting the current decoder again is not a change
+        let event = state.update(Message::WebpDecoderChanged(WebpDecoder::Libwebp));
+        assert!(matches!(event, Event::None));
+    }
+
+    #[test]
+    fn shortcut_capture_rebinds_or_reports_conflicts() {
+        let mut state = State::default();
+        let binding = |text: &str| text.parse::<Binding>().expect("valid binding");
+
+        state.update(Message::EditShortcut(Action::Mute));
+        assert!(state.is_capturing_shortcut());
+        let event = state.update(Message::ShortcutKeyPressed(binding("H")));
+        assert!(matches!(event, Event::None));
+        assert_eq!(
+            state.shortcut_conflict,
+            Some((Action::Mute, Conflict::Action(Action::Mirror)))
+        );
+        assert!(!state.is_capturing_shortcut());
+
+        state.update(Message::EditShortcut(Action::Mute));
+        let event = state.update(Message::ShortcutKeyPressed(binding("U")));
+        assert!(matches!(event, Event::ShortcutsChanged));
+        assert_eq!(state.keymap().label(Action::Mute), "U");
+
+        // Escape cancels without changing the binding
+        state.update(Message::EditShortcut(Action::Mute));
+        let event = state.update(Message::ShortcutKeyPressed(binding("Esc")));
+        assert!(matches!(event, Event::None));
+        assert_eq!(state.keymap().label(Action::Mute), "U");
+
+        let event = state.update(Message::ResetShortcuts);
+        assert!(matches!(event, Event::ShortcutsChanged));
+        assert!(!state.keymap().is_customized());
+    }
+
+    #[test]
+    fn toggling_a_plugin_emits_event_and_tracks_disabled_ids() {
+        let mut state = State::default();
+        state.set_plugins(vec![PluginEntry {
+            id: "posterize".to_string(),
+            name: "Posterize".to_string(),
+            version: None,
+            description: None,
+            hooks: vec!["settings-plugins-hook-editor-tool"],
+            error: None,
+            enabled: true,
+        }]);
+        assert!(state.disabled_plugins().is_empty());
+
+        let event = state.update(Message::PluginToggled("posterize".to_string(), false));
+        assert!(
+            matches!(event, Event::PluginToggled { ref id, enabled: false } if id == "posterize")
+        );
+        assert_eq!(state.disabled_plugins(), ["posterize"]);
+
+        let event = state.update(Message::PluginToggled("posterize".to_string(), false));
+        assert!(matches!(event, Event::None));
+        let event = state.update(Message::PluginToggled("missing".to_string(), true));
+        assert!(matches!(event, Event::None));
+    }
+
     #[test]
     fn new_state_clamps_zoom_step() {
         let config = StateConfig {
@@ -1440,6 +3711,20 @@ mod tests {
         assert_eq!(state.zoom_step_input, format_number(MAX_ZOOM_STEP_PERCENT));
     }
 
+    #[test]
+    fn guide_positions_apply_once_they_parse() {
+        let mut state = State::default();
+        let event = state.update(Message::VerticalGuidesInputChanged("25, 1".into()));
+        assert!(matches!(event, Event::GuidesChanged(_)));
+        assert_eq!(state.guides().vertical, vec![25.0, 1.0]);
+
+        // Typing on keeps the last valid lines until the text parses again
+        let event = state.update(Message::VerticalGuidesInputChanged("25, 1x".into()));
+        assert!(matches!(event, Event::None));
+        assert_eq!(state.guides().vertical, vec![25.0, 1.0]);
+        assert_eq!(state.vertical_guides_input, "25, 1x");
+    }
+
     #[test]
     fn update_zoom_step_changes_dirty_flag() {
         let mut state = State::default();
@@ -1466,4 +3751,103 @@ mod tests {
         assert_eq!(result, Some(15.0));
         assert_eq!(state.zoom_step_percent, 15.0);
     }
+
+    #[test]
+    fn palette_inputs_apply_valid_colors_only() {
+        let mut state = State::default();
+        let event = state.update(Message::PaletteColorInputChanged(
+            PaletteColor::Accent,
+            "#d1458".into(),
+        ));
+        assert!(matches!(event, Event::None));
+        assert_eq!(
+            state.palette_inputs[PaletteColor::Accent as usize],
+            "#d1458"
+        );
+
+        let event = state.update(Message::PaletteColorInputChanged(
+            PaletteColor::Accent,
+            "#d1458a".into(),
+        ));
+        let Event::CustomPaletteChanged(palette) = event else {
+            panic!("expected a palette change");
+        };
+        assert_eq!(theming::to_hex(palette.accent), "#d1458a");
+        assert!(state.active_custom_palette().is_none());
+
+        state.update(Message::CustomThemeChanged(true));
+        assert_eq!(state.active_custom_palette(), Some(&palette));
+    }
+
+    #[test]
+    fn accent_input_clears_to_default() {
+        let mut state = State::default();
+        state.update(Message::AccentColorInputChanged("#d1458a".into()));
+        assert!(state.accent_color().is_some());
+        let event = state.update(Message::AccentColorInputChanged(String::new()));
+        assert!(matches!(event, Event::AccentColorChanged(None)));
+        assert_eq!(state.app_theme(), Theme::Dark);
+    }
+
+    #[test]
+    fn ui_scale_applies_when_slider_is_released() {
+        let mut state = State::default();
+        let event = state.update(Message::UiScaleSliderMoved(150));
+        assert!(matches!(event, Event::None));
+        assert_eq!(state.ui_scale_percent(), DEFAULT_UI_SCALE_PERCENT);
+
+        let event = state.update(Message::UiScaleSliderReleased);
+        assert!(matches!(event, Event::UiScaleChanged(150)));
+        assert_eq!(state.ui_scale_percent(), 150);
+    }
+
+    #[test]
+    fn save_policy_change_is_reported_once() {
+        let mut state = State::default();
+        let event = state.update(Message::SavePolicyChanged(SavePolicy::Copy));
+        assert!(matches!(event, Event::SavePolicyChanged(SavePolicy::Copy)));
+        assert_eq!(state.save_policy(), SavePolicy::Copy);
+        let event = state.update(Message::SavePolicyChanged(SavePolicy::Copy));
+        assert!(matches!(event, Event::None));
+    }
+
+    #[test]
+    fn new_state_clamps_ui_scale() {
+        let state = State::new(StateConfig {
+            ui_scale_percent: 500,
+            ..StateConfig::default()
+        });
+        assert_eq!(state.ui_scale_percent(), MAX_UI_SCALE_PERCENT);
+    }
+
+    #[test]
+    fn new_state_clamps_fullscreen_overlay_opacity() {
+        let state = State::new(StateConfig {
+            fullscreen_overlay: fullscreen_overlay::Options {
+                opacity_percent: 0,
+                ..fullscreen_overlay::Options::default()
+            },
+            ..StateConfig::default()
+        });
+        assert_eq!(
+            state.fullscreen_overlay().opacity_percent,
+            MIN_OVERLAY_OPACITY_PERCENT
+        );
+    }
+
+    #[test]
+    fn accessibility_toggle_emits_changed_options() {
+        let mut state = State::default();
+        let options = accessibility::Options {
+            reduced_motion: true,
+            ..accessibility::Options::default()
+        };
+        let event = state.update(Message::AccessibilityChanged(options));
+        assert!(matches!(event, Event::AccessibilityChanged(o) if o == options));
+        assert_eq!(state.accessibility(), options);
+        assert!(matches!(
+            state.update(Message::AccessibilityChanged(options)),
+            Event::None
+        ));
+    }
 }
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Panel showing where the "Share over LAN" gallery can be opened.
+//!
+//! The address is shown as text and as a QR code, so a phone on the same
+//! network can open the gallery by scanning the screen.
+
+use crate::i18n::fluent::I18n;
+use crate::ui::design_tokens::{palette, radius, spacing, typography};
+use crate::ui::styles;
+use iced::widget::{button, container, qr_code, text, Column, Row, Text};
+use iced::{alignment::Horizontal, Border, Element, Length, Padding, Theme};
+
+/// Size of one QR code module in logical pixels.
+const QR_CELL_SIZE: f32 = 5.0;
+
+/// Width of the panel.
+const PANEL_WIDTH: f32 = 400.0;
+
+/// Messages emitted by the share panel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    /// Hide the panel and keep sharing.
+    Close,
+    /// Stop the server and hide the panel.
+    StopSharing,
+    /// Put the address on the clipboard.
+    CopyUrl,
+    /// No-op message to consume clicks on the panel without closing it.
+    ConsumeClick,
+}
+
+/// Address of a running share server, ready to display.
+#[derive(Debug)]
+pub struct State {
+    url: String,
+    file_count: usize,
+    qr: Option<qr_code::Data>,
+}
+
+impl State {
+    /// Creates the panel for a server reachable at `url` sharing `file_count` files.
+    #[must_use]
+    pub fn new(url: String, file_count: usize) -> Self {
+        let qr = qr_code::Data::new(url.as_bytes()).ok();
+        Self {
+            url,
+            file_count,
+            qr,
+        }
+    }
+
+    /// Returns the address of the gallery.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+/// Contextual data needed to render the panel.
+pub struct ViewContext<'a> {
+    pub i18n: &'a I18n,
+    pub state: &'a State,
+}
+
+/// Renders the share panel.
+pub fn view_panel(ctx: ViewContext<'_>) -> Element<'_, Message> {
+    let mut content = Column::new()
+        .spacing(spacing::SM)
+        .align_x(Horizontal::Center)
+        .push(Text::new(ctx.i18n.tr("share-panel-title")).size(typography::TITLE_SM))
+        .push(
+            Text::new(ctx.i18n.tr_with_args(
+                "share-panel-hint",
+                &[("count", ctx.state.file_count.to_string().as_str())],
+            ))
+            .size(typography::BODY),
+        );
+
+    if let Some(qr) = &ctx.state.qr {
+        // Dark modules on white whatever the theme, as some scanners reject inverted codes
+        content =
+            content.push(
+                qr_code(qr)
+                    .cell_size(QR_CELL_SIZE)
+                    .style(|_: &Theme| qr_code::Style {
+                        cell: palette::BLACK,
+                        background: palette::WHITE,
+                    }),
+            );
+    }
+
+    content = content
+        .push(
+            Text::new(ctx.state.url.as_str())
+                .size(typography::BODY_LG)
+                .style(|theme: &Theme| text::Style {
+                    color: Some(theme.extended_palette().primary.strong.color),
+                }),
+        )
+        .push(
+            Text::new(ctx.i18n.tr("share-panel-read-only"))
+                .size(typography::CAPTION)
+                .style(|theme: &Theme| text::Style {
+                    color: Some(theme.extended_palette().background.strong.text),
+                }),
+        )
+        .push(
+            Row::new()
+                .spacing(spacing::SM)
+                .push(button(Text::new(ctx.i18n.tr("share-panel-copy"))).on_press(Message::CopyUrl))
+                .push(
+                    button(Text::new(ctx.i18n.tr("share-panel-stop")))
+                        .on_press(Message::StopSharing)
+                        .style(styles::button::primary),
+                )
+                .push(button(Text::new(ctx.i18n.tr("share-panel-close"))).on_press(Message::Close)),
+        );
+
+    container(content)
+        .padding(Padding::new(spacing::MD))
+        .width(Length::Fixed(PANEL_WIDTH))
+        .style(|theme: &Theme| {
+            let palette = theme.extended_palette();
+            container::Style {
+                background: Some(palette.background.base.color.into()),
+                border: Border {
+                    color: palette.background.strong.color,
+                    width: 1.0,
+                    radius: radius::MD.into(),
+                },
+                ..Default::default()
+            }
+        })
+        .into()
+}
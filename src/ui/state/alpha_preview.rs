@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Alpha preview domain types for inspecting transparent images.
+//!
+//! The viewer normally shows transparency against the configured background
+//! theme (light, dark, or checkerboard). These types let the user temporarily
+//! override that with a solid matte color and choose how the alpha channel is
+//! interpreted, which helps spot fringes and halos before compositing.
+
+use crate::ui::design_tokens::palette;
+use iced::Color;
+
+/// Solid matte color displayed behind transparent pixels.
+///
+/// `Background` defers to the configured [`BackgroundTheme`](crate::config::BackgroundTheme);
+/// the other variants replace it for the current session. The set is fixed:
+/// there is no user-chosen or persisted matte color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatteColor {
+    /// Use the configured background theme (no override).
+    #[default]
+    Background,
+    Black,
+    White,
+    Gray,
+    Magenta,
+    Green,
+}
+
+impl MatteColor {
+    /// All variants in cycling order.
+    pub const ALL: [MatteColor; 6] = [
+        MatteColor::Background,
+        MatteColor::Black,
+        MatteColor::White,
+        MatteColor::Gray,
+        MatteColor::Magenta,
+        MatteColor::Green,
+    ];
+
+    /// Returns the next matte in cycling order, wrapping around.
+    #[must_use]
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|m| *m == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Returns the previous matte in cycling order, wrapping around.
+    #[must_use]
+    pub fn previous(self) -> Self {
+        let index = Self::ALL.iter().position(|m| *m == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Returns the solid color to paint, or `None` when the background theme applies.
+    #[must_use]
+    pub fn color(self) -> Option<Color> {
+        match self {
+            MatteColor::Background => None,
+            MatteColor::Black => Some(palette::BLACK),
+            MatteColor::White => Some(palette::WHITE),
+            // Mid-gray: neutral reference that reveals both dark and light fringes
+            MatteColor::Gray => Some(Color::from_rgb(0.5, 0.5, 0.5)),
+            // Saturated colors that rarely occur in real content, making
+            // semi-transparent edges stand out
+            MatteColor::Magenta => Some(Color::from_rgb(1.0, 0.0, 1.0)),
+            MatteColor::Green => Some(Color::from_rgb(0.0, 1.0, 0.0)),
+        }
+    }
+
+    /// Returns true if overlays drawn on this matte need dark foreground colors.
+    #[must_use]
+    pub fn is_light(self) -> bool {
+        matches!(self, MatteColor::White | MatteColor::Green)
+    }
+
+    /// Returns the i18n key naming this matte.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            MatteColor::Background => "viewer-matte-background",
+            MatteColor::Black => "viewer-matte-black",
+            MatteColor::White => "viewer-matte-white",
+            MatteColor::Gray => "viewer-matte-gray",
+            MatteColor::Magenta => "viewer-matte-magenta",
+            MatteColor::Green => "viewer-matte-green",
+        }
+    }
+}
+
+/// How the alpha channel of decoded pixels is interpreted for display.
+///
+/// Decoders hand us straight (unassociated) alpha. Some files are written
+/// with premultiplied color anyway; previewing them as premultiplied
+/// un-multiplies the color channels so edges appear as the author intended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlphaInterpretation {
+    /// Color channels are independent of alpha (decoder default).
+    #[default]
+    Straight,
+    /// Color channels are already multiplied by alpha.
+    Premultiplied,
+}
+
+impl AlphaInterpretation {
+    /// Returns the other interpretation.
+    #[must_use]
+    pub fn toggled(self) -> Self {
+        match self {
+            AlphaInterpretation::Straight => AlphaInterpretation::Premultiplied,
+            AlphaInterpretation::Premultiplied => AlphaInterpretation::Straight,
+        }
+    }
+
+    /// Returns true if pixels must be un-premultiplied before display.
+    #[must_use]
+    pub fn is_premultiplied(self) -> bool {
+        self == AlphaInterpretation::Premultiplied
+    }
+
+    /// Returns the i18n key naming this interpretation.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            AlphaInterpretation::Straight => "viewer-alpha-straight",
+            AlphaInterpretation::Premultiplied => "viewer-alpha-premultiplied",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_matte_defers_to_background_theme() {
+        assert_eq!(MatteColor::default(), MatteColor::Background);
+        assert!(MatteColor::default().color().is_none());
+    }
+
+    #[test]
+    fn next_cycles_through_all_mattes() {
+        let mut matte = MatteColor::Background;
+        for expected in MatteColor::ALL.iter().skip(1) {
+            matte = matte.next();
+            assert_eq!(matte, *expected);
+        }
+        assert_eq!(matte.next(), MatteColor::Background);
+    }
+
+    #[test]
+    fn previous_is_inverse_of_next() {
+        for matte in MatteColor::ALL {
+            assert_eq!(matte.next().previous(), matte);
+        }
+        assert_eq!(MatteColor::Background.previous(), MatteColor::Green);
+    }
+
+    #[test]
+    fn every_override_has_a_color() {
+        for matte in MatteColor::ALL.iter().skip(1) {
+            assert!(matte.color().is_some(), "{matte:?} should have a color");
+        }
+    }
+
+    #[test]
+    fn light_mattes_are_detected() {
+        assert!(MatteColor::White.is_light());
+        assert!(!MatteColor::Black.is_light());
+        assert!(!MatteColor::Background.is_light());
+    }
+
+    #[test]
+    fn alpha_interpretation_toggles() {
+        let alpha = AlphaInterpretation::default();
+        assert_eq!(alpha, AlphaInterpretation::Straight);
+        assert!(!alpha.is_premultiplied());
+        assert!(alpha.toggled().is_premultiplied());
+        assert_eq!(alpha.toggled().toggled(), alpha);
+    }
+}
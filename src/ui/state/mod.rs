@@ -4,6 +4,7 @@
 //! This module contains all the UI state logic separated from the main App struct,
 //! following the principle of separation of concerns.
 
+pub mod alpha_preview;
 pub mod drag;
 pub mod overlay_timeout;
 pub mod rotation;
@@ -11,6 +12,7 @@ pub mod viewport;
 pub mod zoom;
 
 // Re-export commonly used types for convenience
+pub use alpha_preview::{AlphaInterpretation, MatteColor};
 pub use drag::DragState;
 pub use overlay_timeout::OverlayTimeout;
 pub use rotation::RotationAngle;
@@ -6,6 +6,10 @@
 //! - Fit-to-window mode
 //! - Zoom step configuration
 //! - Zoom input validation and error handling
+//! - Correction of the media zoom for the interface scale
+
+use crate::config::DEFAULT_UI_SCALE_PERCENT;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 // Re-export zoom constants from centralized config for backward compatibility
 pub use crate::config::{
@@ -13,6 +17,8 @@ pub use crate::config::{
     MIN_ZOOM_PERCENT, MIN_ZOOM_STEP_PERCENT,
 };
 
+static INTERFACE_SCALE_PERCENT: AtomicU32 = AtomicU32::new(DEFAULT_UI_SCALE_PERCENT);
+
 /// Zoom percentage, guaranteed to be within valid range (10%–800%).
 ///
 /// This type ensures that zoom values are always valid, eliminating
@@ -244,6 +250,42 @@ pub fn clamp_zoom(percent: f32) -> f32 {
     ZoomPercent::new(percent).value()
 }
 
+/// Records the interface scale applied to the windows, in percent.
+///
+/// The interface scale enlarges every logical pixel, media included, so the
+/// viewer and the editor divide it out of their zoom with [`media_scale`]:
+/// 100% keeps showing one media pixel per screen pixel. The application calls
+/// this whenever the setting changes.
+pub fn set_interface_scale(percent: u32) {
+    INTERFACE_SCALE_PERCENT.store(percent.max(1), Ordering::Relaxed);
+}
+
+/// Returns the size of one media pixel, in logical pixels, at `zoom_percent`.
+#[must_use]
+pub fn media_scale(zoom_percent: f32) -> f32 {
+    scale_at(
+        zoom_percent,
+        INTERFACE_SCALE_PERCENT.load(Ordering::Relaxed),
+    )
+}
+
+/// Returns the zoom, in percent, at which one media pixel takes `scale`
+/// logical pixels. This is the inverse of [`media_scale`].
+#[must_use]
+pub fn zoom_for_media_scale(scale: f32) -> f32 {
+    zoom_at(scale, INTERFACE_SCALE_PERCENT.load(Ordering::Relaxed))
+}
+
+#[allow(clippy::cast_precision_loss)] // interface scale percentages are small
+fn scale_at(zoom_percent: f32, interface_percent: u32) -> f32 {
+    zoom_percent / interface_percent as f32
+}
+
+#[allow(clippy::cast_precision_loss)] // interface scale percentages are small
+fn zoom_at(scale: f32, interface_percent: u32) -> f32 {
+    scale * interface_percent as f32
+}
+
 /// Formats a number for display (removes unnecessary decimal places)
 #[must_use]
 pub fn format_number(value: f32) -> String {
@@ -264,6 +306,14 @@ pub fn format_number(value: f32) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn media_scale_divides_out_the_interface_scale() {
+        assert!((scale_at(100.0, 100) - 1.0).abs() < f32::EPSILON);
+        assert!((scale_at(100.0, 200) - 0.5).abs() < f32::EPSILON);
+        assert!((scale_at(300.0, 150) - 2.0).abs() < f32::EPSILON);
+        assert!((zoom_at(scale_at(80.0, 125), 125) - 80.0).abs() < 1e-4);
+    }
+
     #[test]
     fn default_zoom_state_is_consistent() {
         let state = ZoomState::default();
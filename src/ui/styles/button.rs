@@ -6,18 +6,21 @@ use crate::ui::design_tokens::{
     palette::{self, BLACK, WHITE},
     radius, shadow,
 };
+use crate::ui::theming;
 use iced::widget::button;
 use iced::{Background, Border, Color, Theme};
 
 /// Style pour bouton primaire (action principale).
 #[must_use]
-pub fn primary(_theme: &Theme, status: button::Status) -> button::Style {
+pub fn primary(theme: &Theme, status: button::Status) -> button::Style {
+    let accent = theming::accent(theme);
+
     match status {
         button::Status::Active | button::Status::Pressed => button::Style {
-            background: Some(Background::Color(palette::PRIMARY_500)),
+            background: Some(Background::Color(accent.base)),
             text_color: WHITE,
             border: Border {
-                color: palette::PRIMARY_600,
+                color: accent.border,
                 width: 1.0,
                 radius: radius::SM.into(),
             },
@@ -25,10 +28,10 @@ pub fn primary(_theme: &Theme, status: button::Status) -> button::Style {
             snap: true,
         },
         button::Status::Hovered => button::Style {
-            background: Some(Background::Color(palette::PRIMARY_400)),
+            background: Some(Background::Color(accent.hover)),
             text_color: WHITE,
             border: Border {
-                color: palette::PRIMARY_500,
+                color: accent.base,
                 width: 1.0,
                 radius: radius::SM.into(),
             },
@@ -114,7 +117,7 @@ pub mod overlay {
 /// Adapts to Light/Dark theme.
 pub fn disabled() -> impl Fn(&Theme, button::Status) -> button::Style {
     move |theme: &Theme, _status: button::Status| {
-        let is_light = matches!(theme, Theme::Light);
+        let is_light = theming::is_light(theme);
 
         // Low contrast appearance, clearly non-interactive
         let (bg_color, text_color, border_color) = if is_light {
@@ -163,14 +166,15 @@ pub fn disabled() -> impl Fn(&Theme, button::Status) -> button::Style {
 /// For on/off toggle buttons, use `toggle_active` instead.
 #[must_use]
 pub fn selected(theme: &Theme, status: button::Status) -> button::Style {
-    let is_light = matches!(theme, Theme::Light);
+    let is_light = theming::is_light(theme);
+    let accent = theming::accent(theme);
 
     match status {
         button::Status::Active | button::Status::Pressed => button::Style {
-            background: Some(Background::Color(palette::PRIMARY_500)),
+            background: Some(Background::Color(accent.base)),
             text_color: WHITE,
             border: Border {
-                color: palette::PRIMARY_600,
+                color: accent.border,
                 width: 1.0,
                 radius: radius::SM.into(),
             },
@@ -178,10 +182,10 @@ pub fn selected(theme: &Theme, status: button::Status) -> button::Style {
             snap: true,
         },
         button::Status::Hovered => button::Style {
-            background: Some(Background::Color(palette::PRIMARY_400)),
+            background: Some(Background::Color(accent.hover)),
             text_color: WHITE,
             border: Border {
-                color: palette::PRIMARY_500,
+                color: accent.base,
                 width: 1.0,
                 radius: radius::SM.into(),
             },
@@ -213,7 +217,7 @@ pub fn selected(theme: &Theme, status: button::Status) -> button::Style {
 /// For on/off toggle buttons in inactive state, use the default button style.
 #[must_use]
 pub fn unselected(theme: &Theme, status: button::Status) -> button::Style {
-    let is_light = matches!(theme, Theme::Light);
+    let is_light = theming::is_light(theme);
 
     // Clear, interactive appearance with solid border (distinct from faded disabled style)
     let (bg_color, text_color, border_color) = if is_light {
@@ -252,7 +256,7 @@ pub fn unselected(theme: &Theme, status: button::Status) -> button::Style {
                 background: Some(Background::Color(hover_bg)),
                 text_color,
                 border: Border {
-                    color: palette::PRIMARY_500,
+                    color: theming::accent(theme).base,
                     width: 1.0,
                     radius: radius::SM.into(),
                 },
@@ -294,6 +298,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn primary_button_follows_accent_color() {
+        let accent = theming::ACCENT_PRESETS[1];
+        let theme = theming::iced_theme(theming::ThemeMode::Dark, Some(accent), None);
+        let style = primary(&theme, button::Status::Active);
+
+        assert_eq!(style.background, Some(Background::Color(accent)));
+    }
+
     #[test]
     fn overlay_navigation_alpha_changes_on_hover() {
         let theme = Theme::Dark;
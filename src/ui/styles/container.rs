@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MPL-2.0
 //! Container styles.
 
-use crate::ui::design_tokens::{opacity, radius};
+use crate::ui::design_tokens::{opacity, palette, radius};
 use iced::widget::container;
 use iced::{Background, Border, Color, Theme};
 
@@ -29,3 +29,61 @@ pub fn panel(theme: &Theme) -> container::Style {
         ..Default::default()
     }
 }
+
+/// A–B loop region band drawn under the video timeline.
+///
+/// Uses the theme's primary color, translucent so the slider rail stays
+/// visible through it.
+#[must_use]
+pub fn loop_region(theme: &Theme) -> container::Style {
+    let primary = theme.extended_palette().primary.base.color;
+
+    container::Style {
+        background: Some(Background::Color(Color {
+            a: opacity::OVERLAY_MEDIUM,
+            ..primary
+        })),
+        border: Border {
+            radius: radius::SM.into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Band under the volume slider marking the range that boosts above 100%.
+///
+/// Uses the warning color, translucent so it reads as a zone rather than
+/// part of the rail.
+#[must_use]
+pub fn boost_zone(_theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color {
+            a: opacity::OVERLAY_MEDIUM,
+            ..palette::WARNING_500
+        })),
+        border: Border {
+            radius: radius::SM.into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Chapter tick mark drawn over the video timeline.
+///
+/// Uses the theme's strong background color so the mark contrasts with both
+/// the filled and the empty part of the slider rail.
+#[must_use]
+pub fn chapter_marker(theme: &Theme) -> container::Style {
+    let palette = theme.extended_palette();
+
+    container::Style {
+        background: Some(Background::Color(palette.background.strong.text)),
+        border: Border {
+            radius: radius::SM.into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
@@ -10,12 +10,14 @@
 //! - [`container`] - Container backgrounds, borders, and shadows
 //! - [`editor`] - Image editor specific styles (crop overlay, resize handles)
 //! - [`overlay`] - Fullscreen overlay and floating UI styles
+//! - [`text_input`] - Text fields, with the high-contrast focus outline
 
 pub mod button;
 pub mod container;
 pub mod editor;
 pub mod overlay;
 pub mod slider;
+pub mod text_input;
 pub mod tooltip;
 
 // Re-exports for convenience
@@ -36,6 +36,33 @@ pub fn indicator(rad: f32) -> impl Fn(&Theme) -> container::Style {
     }
 }
 
+/// Indicator style with the background, border and text faded to `alpha`
+/// (0.0–1.0), for the configurable fullscreen overlay.
+pub fn faded_indicator(rad: f32, alpha: f32) -> impl Fn(&Theme) -> container::Style {
+    move |theme: &Theme| {
+        let style = indicator(rad)(theme);
+        container::Style {
+            background: style
+                .background
+                .map(|background| background.scale_alpha(alpha)),
+            text_color: style.text_color.map(|color| color.scale_alpha(alpha)),
+            border: Border {
+                color: style.border.color.scale_alpha(alpha),
+                ..style.border
+            },
+            ..style
+        }
+    }
+}
+
+/// Style of one bar of the fullscreen histogram.
+pub fn histogram_bar(alpha: f32) -> impl Fn(&Theme) -> container::Style {
+    move |_theme: &Theme| container::Style {
+        background: Some(Background::Color(WHITE.scale_alpha(alpha * 0.8))),
+        ..Default::default()
+    }
+}
+
 /// Style for the overlay controls container in fullscreen mode.
 #[must_use]
 pub fn controls_container(_theme: &Theme) -> container::Style {
@@ -4,6 +4,7 @@
 //! Provides consistent styling for sliders across the application.
 
 use crate::ui::design_tokens::{opacity, palette};
+use crate::ui::theming;
 use iced::widget::slider;
 use iced::{Background, Border, Color, Theme};
 
@@ -13,7 +14,7 @@ use iced::{Background, Border, Color, Theme};
 /// Adapts to Light/Dark theme.
 pub fn disabled() -> impl Fn(&Theme, slider::Status) -> slider::Style {
     move |theme: &Theme, _status: slider::Status| {
-        let is_light = matches!(theme, Theme::Light);
+        let is_light = theming::is_light(theme);
 
         // Low contrast appearance, clearly non-interactive
         let (rail_bg, handle_bg, handle_border) = if is_light {
@@ -68,11 +69,30 @@ pub fn disabled() -> impl Fn(&Theme, slider::Status) -> slider::Style {
     }
 }
 
+/// Style for the volume slider while it boosts above 100%.
+///
+/// The filled part of the rail takes the warning color, matching the boost
+/// zone marked under it.
+#[must_use]
+pub fn boosted(theme: &Theme, status: slider::Status) -> slider::Style {
+    let mut style = slider::default(theme, status);
+    style.rail.backgrounds.0 = Background::Color(palette::WARNING_500);
+    style
+}
+
+/// Returns a text style for the volume percentage while it boosts above 100%.
+#[must_use]
+pub fn boosted_text_style(_theme: &Theme) -> iced::widget::text::Style {
+    iced::widget::text::Style {
+        color: Some(palette::WARNING_500),
+    }
+}
+
 /// Returns a text style for disabled volume percentage.
 /// Matches the disabled slider appearance. Adapts to Light/Dark theme.
 #[must_use]
 pub fn disabled_text_style(theme: &Theme) -> iced::widget::text::Style {
-    let is_light = matches!(theme, Theme::Light);
+    let is_light = theming::is_light(theme);
     // Use palette colors that provide appropriate contrast for each theme
     let color = if is_light {
         palette::GRAY_400 // Darker gray on light background
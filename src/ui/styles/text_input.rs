@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Text input style definitions.
+
+use crate::ui::accessibility;
+use crate::ui::design_tokens::border;
+use iced::widget::text_input;
+use iced::Theme;
+
+/// Default text input style, with the high-contrast focus outline.
+pub fn standard(theme: &Theme, status: text_input::Status) -> text_input::Style {
+    with_focus_outline(theme, status, text_input::default(theme, status))
+}
+
+/// Gives `style` a thick outline in the text color when the field is focused
+/// and the high-contrast focus option is on.
+#[must_use]
+pub fn with_focus_outline(
+    theme: &Theme,
+    status: text_input::Status,
+    style: text_input::Style,
+) -> text_input::Style {
+    match status {
+        text_input::Status::Focused { .. } if accessibility::current().high_contrast_focus => {
+            text_input::Style {
+                border: style
+                    .border
+                    .color(theme.palette().text)
+                    .width(border::WIDTH_FOCUS),
+                ..style
+            }
+        }
+        _ => style,
+    }
+}
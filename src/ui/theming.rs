@@ -1,10 +1,34 @@
 // SPDX-License-Identifier: MPL-2.0
 //! Extensible theming system.
+//!
+//! The built-in light and dark themes can be tinted with an accent color, or
+//! replaced by a [`CustomPalette`] saved in a theme file next to
+//! `settings.toml`. [`iced_theme`] turns these choices into the
+//! [`iced::Theme`] of the windows; styles read the accent back with
+//! [`accent`] so buttons, sliders, overlays and toasts follow it.
 
 use crate::ui::design_tokens::{opacity, palette};
 use dark_light;
-use iced::Color;
+use iced::theme::Palette;
+use iced::{Color, Theme};
 use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Name of the theme file of the custom palette, next to `settings.toml`.
+pub const THEME_FILE: &str = "theme.toml";
+
+/// Accent colors offered as swatches in the settings.
+pub const ACCENT_PRESETS: [Color; 8] = [
+    palette::PRIMARY_500,
+    Color::from_rgb(0.45, 0.36, 0.85), // Violet
+    Color::from_rgb(0.82, 0.27, 0.52), // Pink
+    Color::from_rgb(0.85, 0.25, 0.25), // Red
+    Color::from_rgb(0.90, 0.50, 0.15), // Orange
+    Color::from_rgb(0.75, 0.62, 0.10), // Gold
+    Color::from_rgb(0.20, 0.62, 0.35), // Green
+    Color::from_rgb(0.10, 0.60, 0.65), // Teal
+];
 
 /// Color palette for a theme.
 #[derive(Debug, Clone)]
@@ -102,6 +126,177 @@ impl ColorScheme {
     }
 }
 
+/// Colors of a user-defined theme, saved as a theme file.
+///
+/// Colors are written as `#rrggbb` hex strings:
+///
+/// ```toml
+/// background = "#1a1a1a"
+/// text = "#ffffff"
+/// accent = "#d1458a"
+/// success = "#43b367"
+/// danger = "#e53935"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CustomPalette {
+    #[serde(with = "hex_serde")]
+    pub background: Color,
+    #[serde(with = "hex_serde")]
+    pub text: Color,
+    #[serde(with = "hex_serde")]
+    pub accent: Color,
+    #[serde(with = "hex_serde")]
+    pub success: Color,
+    #[serde(with = "hex_serde")]
+    pub danger: Color,
+}
+
+impl Default for CustomPalette {
+    fn default() -> Self {
+        Self {
+            background: palette::GRAY_900,
+            text: palette::WHITE,
+            accent: palette::PRIMARY_400,
+            success: palette::SUCCESS_500,
+            danger: palette::ERROR_500,
+        }
+    }
+}
+
+impl CustomPalette {
+    /// Reads a theme file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid palette.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        toml::from_str(&content).map_err(|e| e.message().to_string())
+    }
+
+    /// Writes the palette to a theme file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = toml::to_string_pretty(self).map_err(|e| e.to_string())?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(path, content).map_err(|e| e.to_string())
+    }
+}
+
+/// Parses a `#rrggbb` (or `rrggbb`) color.
+#[must_use]
+pub fn parse_hex(text: &str) -> Option<Color> {
+    let hex = text.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some(Color::from_rgb8(channel(0)?, channel(2)?, channel(4)?))
+}
+
+/// Formats a color as `#rrggbb`, ignoring its alpha.
+#[must_use]
+pub fn to_hex(color: Color) -> String {
+    let [r, g, b, _] = color.into_rgba8();
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+mod hex_serde {
+    use iced::Color;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&super::to_hex(*color))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        super::parse_hex(&text)
+            .ok_or_else(|| de::Error::custom(format!("invalid color {text:?}, expected #rrggbb")))
+    }
+}
+
+/// Builds the theme of the windows.
+///
+/// A custom palette replaces the light/dark mode; otherwise an accent color
+/// tints the built-in theme of `mode`. Without either, the plain built-in
+/// themes are used.
+#[must_use]
+pub fn iced_theme(mode: ThemeMode, accent: Option<Color>, custom: Option<&CustomPalette>) -> Theme {
+    if let Some(custom) = custom {
+        return Theme::custom(
+            "Custom".to_string(),
+            Palette {
+                background: custom.background,
+                text: custom.text,
+                primary: custom.accent,
+                success: custom.success,
+                danger: custom.danger,
+                ..Palette::DARK
+            },
+        );
+    }
+
+    let base = match mode {
+        ThemeMode::Light => Theme::Light,
+        ThemeMode::Dark | ThemeMode::System => Theme::Dark,
+    };
+    match accent {
+        Some(accent) => Theme::custom(
+            base.to_string(),
+            Palette {
+                primary: accent,
+                success: palette::SUCCESS_500,
+                danger: palette::ERROR_500,
+                ..base.palette()
+            },
+        ),
+        None => base,
+    }
+}
+
+/// Returns true if `theme` has a light background.
+#[must_use]
+pub fn is_light(theme: &Theme) -> bool {
+    !theme.extended_palette().is_dark
+}
+
+/// Accent colors of a theme, for buttons and highlights.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Accent {
+    /// Fill of selected and primary elements.
+    pub base: Color,
+    /// Fill of hovered elements.
+    pub hover: Color,
+    /// Border around the filled elements.
+    pub border: Color,
+}
+
+/// Returns the accent of `theme`: the brand blues for the built-in themes,
+/// the user's accent or custom palette otherwise.
+#[must_use]
+pub fn accent(theme: &Theme) -> Accent {
+    if matches!(theme, Theme::Custom(_)) {
+        let primary = &theme.extended_palette().primary;
+        Accent {
+            base: primary.base.color,
+            hover: primary.weak.color,
+            border: primary.strong.color,
+        }
+    } else {
+        Accent {
+            base: palette::PRIMARY_500,
+            hover: palette::PRIMARY_400,
+            border: palette::PRIMARY_600,
+        }
+    }
+}
+
 /// Configuration de thème globale.
 #[derive(Debug, Clone)]
 pub struct AppTheme {
@@ -174,6 +369,52 @@ mod tests {
         assert!(dark.brand_primary.b > dark.brand_primary.r);
     }
 
+    #[test]
+    fn hex_colors_round_trip() {
+        let color = parse_hex("#D1458A").expect("valid color");
+        assert_eq!(to_hex(color), "#d1458a");
+        assert_eq!(parse_hex("1a1a1a").map(to_hex), Some("#1a1a1a".to_string()));
+        assert!(parse_hex("#12345").is_none());
+        assert!(parse_hex("#12345g").is_none());
+        assert!(parse_hex("#éé1234").is_none());
+    }
+
+    #[test]
+    fn custom_palette_round_trips_through_theme_file() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let path = temp_dir.path().join("themes").join(THEME_FILE);
+        let palette = CustomPalette {
+            accent: parse_hex("#d1458a").expect("valid color"),
+            ..CustomPalette::default()
+        };
+        palette.save(&path).expect("save theme");
+        let content = std::fs::read_to_string(&path).expect("read theme");
+        assert!(content.contains("accent = \"#d1458a\""));
+        assert_eq!(CustomPalette::load(&path), Ok(palette));
+    }
+
+    #[test]
+    fn accent_follows_the_theme() {
+        let accent_color = ACCENT_PRESETS[2];
+        let tinted = iced_theme(ThemeMode::Light, Some(accent_color), None);
+        assert!(is_light(&tinted));
+        assert_eq!(accent(&tinted).base, accent_color);
+
+        let plain = iced_theme(ThemeMode::Dark, None, None);
+        assert_eq!(plain, Theme::Dark);
+        assert_eq!(accent(&plain).base, palette::PRIMARY_500);
+
+        let custom = CustomPalette {
+            background: palette::WHITE,
+            text: palette::GRAY_900,
+            ..CustomPalette::default()
+        };
+        let themed = iced_theme(ThemeMode::Dark, Some(accent_color), Some(&custom));
+        assert!(is_light(&themed));
+        assert_eq!(themed.palette().background, custom.background);
+        assert_eq!(accent(&themed).base, custom.accent);
+    }
+
     #[test]
     fn theme_mode_is_dark_returns_correct_values() {
         assert!(!ThemeMode::Light.is_dark());
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Missing translations report, opened from the about screen.
+//!
+//! Lists the keys of the English translation that the current language
+//! lacks and shows in English instead. Translators running with
+//! `--watch-i18n` see the list shrink as they save their `.ftl` file.
+
+use crate::i18n::fluent::I18n;
+use crate::ui::design_tokens::{radius, spacing, typography};
+use iced::{
+    alignment::Horizontal,
+    widget::{button, container, scrollable, text, Column, Row, Text},
+    Border, Element, Font, Length, Theme,
+};
+
+/// Contextual data needed to render the report.
+pub struct ViewContext<'a> {
+    pub i18n: &'a I18n,
+}
+
+/// Messages emitted by the report.
+#[derive(Debug, Clone)]
+pub enum Message {
+    BackToViewer,
+    CopyKeys,
+}
+
+/// Events propagated to the parent application.
+#[derive(Debug, Clone)]
+pub enum Event {
+    BackToViewer,
+    /// Copy the missing keys, one per line.
+    CopyKeys,
+}
+
+/// Process a report message and return the corresponding event.
+#[must_use]
+pub fn update(message: &Message) -> Event {
+    match message {
+        Message::BackToViewer => Event::BackToViewer,
+        Message::CopyKeys => Event::CopyKeys,
+    }
+}
+
+/// Render the missing translations report.
+#[must_use]
+#[allow(clippy::needless_pass_by_value)] // ViewContext is small and consumed
+pub fn view(ctx: ViewContext<'_>) -> Element<'_, Message> {
+    let back_button = button(
+        text(format!("← {}", ctx.i18n.tr("about-back-to-viewer-button"))).size(typography::BODY),
+    )
+    .on_press(Message::BackToViewer);
+
+    let title = Text::new(ctx.i18n.tr("translations-title")).size(typography::TITLE_LG);
+
+    let missing = ctx.i18n.missing_translations();
+    let summary = ctx.i18n.tr_with_args(
+        "translations-summary",
+        &[
+            ("locale", &ctx.i18n.current_locale().to_string()),
+            ("count", &missing.len().to_string()),
+            ("dir", ctx.i18n.dir()),
+        ],
+    );
+
+    let actions = Row::new().spacing(spacing::SM).push(
+        button(Text::new(ctx.i18n.tr("translations-copy-keys")))
+            .on_press_maybe((!missing.is_empty()).then_some(Message::CopyKeys)),
+    );
+
+    let keys: Element<'_, Message> = if missing.is_empty() {
+        Text::new(ctx.i18n.tr("translations-complete"))
+            .size(typography::BODY)
+            .into()
+    } else {
+        Text::new(missing.join("\n"))
+            .size(typography::CAPTION)
+            .font(Font::MONOSPACE)
+            .into()
+    };
+
+    let keys_panel = container(
+        scrollable(container(keys).padding(spacing::SM).width(Length::Fill)).height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .style(|theme: &Theme| container::Style {
+        background: Some(theme.extended_palette().background.weak.color.into()),
+        border: Border {
+            radius: radius::MD.into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+
+    Column::new()
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .spacing(spacing::MD)
+        .align_x(Horizontal::Left)
+        .padding(spacing::MD)
+        .push(back_button)
+        .push(title)
+        .push(Text::new(summary).size(typography::BODY))
+        .push(actions)
+        .push(keys_panel)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translations_view_renders() {
+        let i18n = I18n::default();
+        let _element = view(ViewContext { i18n: &i18n });
+    }
+}
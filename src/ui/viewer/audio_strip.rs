@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Audio visualization strip drawn under the video seek bar.
+//!
+//! Shows either the waveform of the whole track, aligned with the seek bar so
+//! loud and quiet sections can be found while scrubbing, or a live VU meter
+//! of the audio being played.
+//!
+//! Cast precision notes:
+//! - Strip widths are a few thousand pixels at most, exact in f32
+
+use crate::ui::design_tokens::palette;
+use crate::ui::theming;
+use crate::video_player::audio_meter::AudioLevel;
+use crate::video_player::Waveform;
+use iced::widget::canvas;
+use iced::{mouse, Color, Element, Length, Point, Rectangle, Size, Theme};
+use std::sync::Arc;
+
+/// Height of the strip in logical pixels.
+pub const STRIP_HEIGHT: f32 = 14.0;
+
+/// Meter fraction above which the VU meter turns yellow (about -12 dBFS).
+const METER_WARNING: f32 = 0.8;
+
+/// Meter fraction above which the VU meter turns red (about -3 dBFS).
+const METER_CLIP: f32 = 0.95;
+
+/// Content of the strip.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AudioStrip {
+    /// Waveform of the track, `None` while it is being analyzed.
+    Waveform(Option<Arc<Waveform>>),
+    /// Live level of the audio being played.
+    VuMeter(AudioLevel),
+}
+
+/// Builds the strip for a timeline of `duration_secs` at `position_secs`.
+pub fn view<'a, Message: 'a>(
+    strip: AudioStrip,
+    position_secs: f64,
+    duration_secs: f64,
+) -> Element<'a, Message> {
+    canvas::Canvas::new(StripProgram {
+        strip,
+        position_secs,
+        duration_secs,
+    })
+    .width(Length::Fill)
+    .height(Length::Fixed(STRIP_HEIGHT))
+    .into()
+}
+
+struct StripProgram {
+    strip: AudioStrip,
+    position_secs: f64,
+    duration_secs: f64,
+}
+
+impl<Message> canvas::Program<Message> for StripProgram {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        match &self.strip {
+            AudioStrip::Waveform(waveform) => self.draw_waveform(
+                &mut frame,
+                waveform.as_deref(),
+                theming::accent(theme).hover,
+            ),
+            AudioStrip::VuMeter(level) => draw_meter(&mut frame, *level),
+        }
+        vec![frame.into_geometry()]
+    }
+}
+
+impl StripProgram {
+    /// Draws one bar per pixel column, mirrored around the middle, brighter
+    /// before the playhead, in `played_color`.
+    fn draw_waveform(
+        &self,
+        frame: &mut canvas::Frame,
+        waveform: Option<&Waveform>,
+        played_color: Color,
+    ) {
+        let size = frame.size();
+        let middle = size.height / 2.0;
+        let Some(waveform) = waveform else {
+            // Still analyzing: a flat line where the waveform will appear
+            frame.fill_rectangle(
+                Point::new(0.0, middle - 0.5),
+                Size::new(size.width, 1.0),
+                palette::GRAY_400,
+            );
+            return;
+        };
+
+        // Canvas widths are small positive numbers
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let columns = size.width.max(0.0) as usize;
+        #[allow(clippy::cast_possible_truncation)]
+        let played = if self.duration_secs > 0.0 {
+            (self.position_secs / self.duration_secs).clamp(0.0, 1.0) as f32 * size.width
+        } else {
+            0.0
+        };
+
+        for (column, peak) in waveform
+            .columns(self.duration_secs, columns)
+            .into_iter()
+            .enumerate()
+        {
+            #[allow(clippy::cast_precision_loss)]
+            let x = column as f32;
+            let half = (peak * middle).max(0.5);
+            let color = if x < played {
+                played_color
+            } else {
+                palette::GRAY_400
+            };
+            frame.fill_rectangle(
+                Point::new(x, middle - half),
+                Size::new(1.0, half * 2.0),
+                color,
+            );
+        }
+    }
+}
+
+/// Draws a horizontal meter: the RMS level as a bar, the peak as a tick.
+fn draw_meter(frame: &mut canvas::Frame, level: AudioLevel) {
+    let size = frame.size();
+    frame.fill_rectangle(
+        Point::ORIGIN,
+        size,
+        Color {
+            a: 0.3,
+            ..palette::GRAY_900
+        },
+    );
+
+    let rms = AudioLevel::meter_fraction(level.rms);
+    let peak = AudioLevel::meter_fraction(level.peak);
+    frame.fill_rectangle(
+        Point::new(0.0, size.height * 0.25),
+        Size::new(rms * size.width, size.height * 0.5),
+        meter_color(rms),
+    );
+    if peak > 0.0 {
+        frame.fill_rectangle(
+            Point::new((peak * size.width - 2.0).max(0.0), 0.0),
+            Size::new(2.0, size.height),
+            meter_color(peak),
+        );
+    }
+}
+
+fn meter_color(fraction: f32) -> Color {
+    if fraction >= METER_CLIP {
+        palette::ERROR_500
+    } else if fraction >= METER_WARNING {
+        palette::WARNING_500
+    } else {
+        palette::SUCCESS_500
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meter_color_follows_level() {
+        assert_eq!(meter_color(0.5), palette::SUCCESS_500);
+        assert_eq!(meter_color(0.9), palette::WARNING_500);
+        assert_eq!(meter_color(1.0), palette::ERROR_500);
+    }
+}
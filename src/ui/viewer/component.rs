@@ -1,22 +1,43 @@
 // SPDX-License-Identifier: MPL-2.0
 //! Viewer component encapsulating state and update logic.
 
+use crate::config::{DragButton, MiddleClickAction, WheelAction};
 use crate::error::{Error, VideoError};
+use crate::i18n::direction::LayoutDirection;
 use crate::i18n::fluent::I18n;
+use crate::media::clip_export::{CancelFlag, ClipExportStatus, ClipMethod};
+use crate::media::decoders::MediaDecoder;
+use crate::media::face_detection::FaceBox;
+use crate::media::frame_encoder::SequenceOptions;
+use crate::media::frame_export::FramesExportStatus;
 use crate::media::navigator::NavigationInfo;
-use crate::media::{MaxSkipAttempts, MediaData};
-use crate::ui::state::{DragState, RotationAngle, ViewportState, ZoomState, ZoomStep};
+use crate::media::{ImageSequence, MaxSkipAttempts, MediaData};
+use crate::ui::accessibility;
+use crate::ui::keyboard_layout;
+use crate::ui::state::{
+    AlphaInterpretation, DragState, MatteColor, RotationAngle, ViewportState, ZoomState, ZoomStep,
+};
 use crate::ui::viewer::{
-    self, controls, filter_dropdown, pane, state as geometry, video_controls, HudIconKind, HudLine,
+    self,
+    audio_strip::AudioStrip,
+    controls, filter_dropdown, fullscreen_overlay,
+    measurement::{self, Measurement, Measurements},
+    pane, pixel_inspector, quick_search, state as geometry, video_controls,
+    view_memory::{ViewMemory, ViewTransform},
+    HudIconKind, HudLine,
 };
-use crate::ui::widgets::VideoShader;
+use crate::ui::widgets::{ColorAdjustments, FrameTransform, ToneMapParams, VideoShader};
+use crate::video_player::fine_scrub::{FineScrub, FineStep, FINE_SEEK_STEP_SECS};
 use crate::video_player::{
-    subscription::PlaybackMessage, KeyboardSeekStep, SharedLufsCache, VideoPlayer, Volume,
+    audio_meter, available_memory_bytes, seek_thumbnails, seek_thumbnails::SeekThumbnails,
+    subscription::PlaybackMessage, CacheBudget, CacheStats, DecodePath, KeyboardSeekStep,
+    PlaybackSpeed, SequenceFps, SharedLufsCache, SubtitleTrack, VideoPlayer, Volume, Waveform,
 };
 use iced::widget::scrollable::{AbsoluteOffset, RelativeOffset};
 use iced::widget::{operation, Id};
 use iced::{event, keyboard, mouse, window, Element, Point, Rectangle, Task};
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 /// Identifier used for the viewer scrollable widget.
@@ -25,12 +46,20 @@ const DOUBLE_CLICK_THRESHOLD: Duration = Duration::from_millis(350);
 const MOUSE_MOVEMENT_THRESHOLD: f32 = 10.0; // Minimum pixels to consider real movement (filter sensor noise)
 const FULLSCREEN_ENTRY_IGNORE_DELAY: Duration = Duration::from_millis(500); // Ignore mouse movements for 500ms after entering fullscreen
 const LOADING_TIMEOUT: Duration = Duration::from_secs(10); // Timeout for media loading
+const WHEEL_NAVIGATION_INTERVAL: Duration = Duration::from_millis(250); // One media per wheel flick
+/// Fraction of the viewport a face should fill after zooming to it.
+const FACE_VIEWPORT_FILL: f32 = 0.4;
 
 /// Messages emitted by viewer-related widgets.
 #[derive(Debug, Clone)]
 pub enum Message {
     StartLoadingMedia,
     MediaLoaded(Result<MediaData, Error>),
+    /// Quick preview of a large image, shown until its full decode is ready.
+    PreviewLoaded {
+        path: PathBuf,
+        image: crate::media::ImageData,
+    },
     /// Clear all media state (used when no media is available, e.g., after deleting last media).
     ClearMedia,
     ToggleErrorDetails,
@@ -40,6 +69,12 @@ pub enum Message {
         bounds: Rectangle,
         offset: AbsoluteOffset,
     },
+    /// The minimap was clicked or dragged: center the view on this point,
+    /// in fractions of the image size.
+    MinimapMoved {
+        x: f32,
+        y: f32,
+    },
     RawEvent {
         window: window::Id,
         event: event::Event,
@@ -50,6 +85,8 @@ pub enum Message {
     OpenSettings,
     EnterEditor,
     InitiatePlayback,
+    /// Play or pause the current video, starting it if needed (Space key).
+    TogglePlayback,
     PlaybackEvent(PlaybackMessage),
     SpinnerTick,
     /// Request to open file dialog from empty state.
@@ -58,8 +95,51 @@ pub enum Message {
     RotateClockwise,
     /// Rotate current media 90° counter-clockwise (temporary, session-only).
     RotateCounterClockwise,
+    /// Flip the current video horizontally (temporary, session-only).
+    ToggleMirror,
+    /// Switch to the next matte color behind transparent pixels (session-only).
+    NextMatte,
+    /// Switch to the previous matte color behind transparent pixels (session-only).
+    PreviousMatte,
+    /// Toggle straight/premultiplied alpha interpretation (session-only).
+    ToggleAlphaInterpretation,
+    /// Play the numbered image sequence containing the current image as video.
+    PlayImageSequence,
+    /// Result of sequence detection: `None` when the image is not part of a sequence.
+    ImageSequenceLoaded(Option<Result<MediaData, Error>>),
     /// Filter dropdown messages (routed from navbar).
     FilterDropdown(filter_dropdown::Message),
+    /// Quick-search overlay messages.
+    QuickSearch(quick_search::Message),
+    /// Show or hide the outlines of the detected faces.
+    ToggleFaceOverlay,
+    /// Detect faces for the overlay once a new image is shown.
+    DetectOverlayFaces,
+    /// Result of face detection on the image at `path`.
+    FacesDetected {
+        path: PathBuf,
+        result: Result<Vec<FaceBox>, String>,
+    },
+    /// Subtitle tracks found for the video at `path`.
+    SubtitlesLoaded {
+        path: PathBuf,
+        tracks: Vec<SubtitleTrack>,
+    },
+    /// Seek bar preview frame of `second` for the video at `path` (`None` if decoding failed).
+    SeekThumbnailReady {
+        path: PathBuf,
+        second: u64,
+        thumbnail: Option<iced::widget::image::Handle>,
+    },
+    /// Progress or result of the running clip export.
+    ClipExport(ClipExportStatus),
+    /// Progress or result of the running frame range export.
+    FramesExport(FramesExportStatus),
+    /// Audio waveform of the video at `path` (`None` if the analysis failed).
+    WaveformReady {
+        path: PathBuf,
+        waveform: Option<Arc<Waveform>>,
+    },
 }
 
 /// Direction of navigation for auto-skip retry.
@@ -107,6 +187,9 @@ pub enum Effect {
         frame: crate::media::frame_export::ExportableFrame,
         video_path: PathBuf,
         position_secs: f64,
+        /// Orientation of the displayed frame, set when the video can be
+        /// decoded again at the source resolution for the editor.
+        full_decode: Option<crate::media::frame_export::FrameOrientation>,
     },
     /// Request to delete the current media file.
     /// App will handle the actual deletion using `media_navigator`.
@@ -147,6 +230,74 @@ pub enum Effect {
     },
     /// Filter changed via dropdown. App should update navigator's filter.
     FilterChanged(filter_dropdown::Message),
+    /// Request to open the quick-search overlay.
+    /// App will provide the directory's media paths via `open_quick_search`.
+    OpenQuickSearch,
+    /// Load the given media file from the current directory (quick-search result).
+    JumpToMedia(PathBuf),
+    /// Jump to the first media of the next/previous capture day.
+    /// App will build the navigator's capture-day index if needed.
+    NavigateCaptureDay(NavigationDirection),
+    /// Jump to the next/previous file visually similar to the current one.
+    /// App will build the navigator's perceptual hash index if needed.
+    FindSimilar(NavigationDirection),
+    /// Run face detection on the current image.
+    /// App will reply with `Message::FacesDetected`.
+    DetectFaces,
+    /// Switch to the workspace layout of the given slot.
+    ApplyLayout(usize),
+    /// Save the current panel setup into the given layout slot.
+    SaveLayout(usize),
+    /// Export a time range of the video to a new file.
+    /// App will ask for the destination and reply with `Message::ClipExport`.
+    ExportClip {
+        video_path: PathBuf,
+        start_secs: f64,
+        end_secs: f64,
+        cancel: CancelFlag,
+    },
+    /// A clip export finished; app shows where it was written.
+    ClipExported {
+        path: PathBuf,
+        method: ClipMethod,
+    },
+    /// Export a time range of the video as images, GIF or WebP.
+    /// App will ask for the destination and reply with `Message::FramesExport`.
+    ExportFrames {
+        video_path: PathBuf,
+        start_secs: f64,
+        end_secs: f64,
+        options: SequenceOptions,
+        cancel: CancelFlag,
+    },
+    /// Capture frames of the video at `timestamps` as separate images.
+    /// App will ask for the folder and reply with `Message::FramesExport`.
+    CaptureBurst {
+        video_path: PathBuf,
+        timestamps: Vec<f64>,
+        cancel: CancelFlag,
+    },
+    /// A frame range export or burst capture finished; app shows where it
+    /// was written.
+    FramesExported {
+        path: PathBuf,
+        frames: usize,
+    },
+    /// Save the part of the image visible in the viewport.
+    /// App will ask for the destination.
+    ExportVisibleArea {
+        frame: crate::media::frame_export::ExportableFrame,
+        source_path: PathBuf,
+    },
+    /// Continue the current video in the mini player window.
+    /// App takes the video with [`State::mini_player_handoff`].
+    OpenMiniPlayer,
+    /// Capture the viewer as displayed, to a file or the clipboard.
+    /// App takes a screenshot of the window and crops it to the viewport.
+    ExportView(crate::media::view_export::Destination),
+    /// Copy a color picked with the pixel inspector (`#RRGGBB`) to the
+    /// clipboard.
+    CopyColor(String),
 }
 
 #[derive(Debug, Clone)]
@@ -174,7 +325,12 @@ pub struct ViewEnv<'a> {
     pub i18n: &'a I18n,
     pub background_theme: crate::config::BackgroundTheme,
     pub is_fullscreen: bool,
+    /// Whether the window is in compact mode: the controls float over the
+    /// media and auto-hide as in fullscreen.
+    pub compact: bool,
     pub overlay_hide_delay: std::time::Duration,
+    /// Elements of the fullscreen overlay.
+    pub fullscreen_overlay: fullscreen_overlay::Options,
     /// Navigation state from the central `MediaNavigator`.
     /// This is the single source of truth for navigation info.
     pub navigation: NavigationInfo,
@@ -182,6 +338,43 @@ pub struct ViewEnv<'a> {
     pub metadata_editor_has_changes: bool,
     /// Current media filter (reference to navigator's filter).
     pub filter: &'a crate::media::filter::MediaFilter,
+    /// Pixel density of the current image, for physical lengths in the
+    /// ruler.
+    pub dots_per_inch: Option<f64>,
+    /// Composition guides drawn while they are shown.
+    pub guides: &'a crate::ui::guides::Options,
+}
+
+/// What the mouse buttons and wheel do in the viewer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MouseMapping {
+    /// Back and forward buttons go to the previous and next media.
+    pub navigation_buttons: bool,
+    pub middle_click: MiddleClickAction,
+    pub wheel: WheelAction,
+    pub drag_button: DragButton,
+}
+
+impl Default for MouseMapping {
+    fn default() -> Self {
+        Self {
+            navigation_buttons: true,
+            middle_click: MiddleClickAction::default(),
+            wheel: WheelAction::default(),
+            drag_button: DragButton::default(),
+        }
+    }
+}
+
+impl MouseMapping {
+    /// Returns the button that pans zoomed media.
+    fn drag_button(self) -> mouse::Button {
+        match self.drag_button {
+            DragButton::Left => mouse::Button::Left,
+            DragButton::Middle => mouse::Button::Middle,
+            DragButton::Right => mouse::Button::Right,
+        }
+    }
 }
 
 /// Complete viewer component state.
@@ -200,11 +393,15 @@ pub struct State {
     last_overlay_interaction: Option<Instant>,
     last_mouse_position: Option<Point>, // Track last position to filter micro-movements
     fullscreen_entered_at: Option<Instant>, // Track when fullscreen was entered to ignore initial movements
+    /// Whether the on-screen display (HUD indicators) is shown; toggled with `O`.
+    osd_visible: bool,
 
     // Loading state
     pub is_loading_media: bool,
     pub loading_started_at: Option<Instant>,
     spinner_rotation: f32, // Rotation angle for animated spinner (in radians)
+    /// Blurred preview of the image being loaded, if one was read in time.
+    loading_preview: Option<crate::media::ImageData>,
 
     /// Origin of the current media load request (for auto-skip behavior).
     pub load_origin: LoadOrigin,
@@ -216,6 +413,8 @@ pub struct State {
     video_shader: VideoShader<Message>,
     current_video_path: Option<PathBuf>,
     playback_session_id: u64, // Incremented each time playback starts, ensures unique subscription ID
+    /// Added to the session IDs, so viewers of other windows never share one.
+    playback_session_base: u64,
 
     /// Fit-to-window setting for videos (separate from images).
     /// Always defaults to true for videos and is NOT persisted.
@@ -225,36 +424,211 @@ pub struct State {
     /// Set during slider drag, cleared on release.
     seek_preview_position: Option<f64>,
 
+    /// Seek slider drag slowed down by holding Shift, if any.
+    fine_scrub: Option<FineScrub>,
+
+    /// Modifier keys currently held.
+    keyboard_modifiers: keyboard::Modifiers,
+
     /// Whether videos should auto-play when loaded.
     video_autoplay: bool,
 
-    /// Video volume level (0.0 to 1.0).
+    /// Video volume level (0.0 to 2.0).
     video_volume: f32,
 
+    /// Volume of videos without a boost of their own (0.0 to 1.0). Volumes
+    /// above 100% are remembered per file instead.
+    base_video_volume: f32,
+
     /// Whether video audio is muted.
     video_muted: bool,
 
     /// Whether video playback should loop.
     video_loop: bool,
 
+    /// Remembered position the resume prompt offers to jump to.
+    resume_offer: Option<f64>,
+
+    /// Remembered position the current video starts from once its decoder
+    /// is ready, when resuming without asking.
+    resume_at: Option<f64>,
+
+    /// Whether the current video plays once its decoder is ready, whatever
+    /// the autoplay setting (set when it comes back from the mini player).
+    play_on_start: bool,
+
+    /// Video coming back from the mini player, continued once loaded:
+    /// `(path, position_secs, playing)`.
+    video_handoff: Option<(PathBuf, f64, bool)>,
+
+    /// Whether the current animated GIF/WebP loops. Animations loop by
+    /// default whatever the video preference, so this isn't persisted.
+    animation_loop: bool,
+
     /// Whether the overflow menu (advanced video controls) is open.
     overflow_menu_open: bool,
 
+    /// Whether the chapter list popup is open.
+    chapter_list_open: bool,
+
+    /// How the current video is decoded, once its first frame arrived.
+    decode_path: Option<DecodePath>,
+
+    /// Frame cache statistics of the current video, updated after paused seeks.
+    cache_stats: Option<CacheStats>,
+
+    /// Cache budgets sized for the current video, used when auto sizing is on.
+    auto_cache_budget: Option<CacheBudget>,
+
+    /// Timeline position under the cursor, while the seek bar is hovered.
+    seek_hover_secs: Option<f64>,
+
+    /// Preview frames shown while hovering the seek bar.
+    seek_thumbnails: SeekThumbnails,
+
+    /// Cancel flag and progress of the running clip export, if any.
+    /// Kept across navigation: the export continues in the background.
+    clip_export: Option<(CancelFlag, f32)>,
+
+    /// Options and progress of the "Export frames" panel.
+    frames_export: video_controls::FramesExportPanel,
+
+    /// Cancel flag of the running frame range export, if any.
+    frames_export_cancel: Option<CancelFlag>,
+
+    /// Subtitle tracks of the current video (sidecar files and embedded streams).
+    subtitle_tracks: Vec<SubtitleTrack>,
+
+    /// Index of the displayed subtitle track, `None` when subtitles are off.
+    subtitle_track: Option<usize>,
+
+    /// Subtitle delay in milliseconds (positive shows subtitles later).
+    /// Resets for each video.
+    subtitle_delay_ms: i32,
+
+    /// Subtitle text size.
+    subtitle_size: crate::config::SubtitleSize,
+
+    /// Whether subtitles are drawn on a translucent box.
+    subtitle_background: bool,
+
+    /// Audio visualization strip shown under the seek bar.
+    audio_visualization: crate::config::AudioVisualization,
+
+    /// Tone mapping curve of HDR videos.
+    tone_mapping: crate::config::ToneMapping,
+
+    /// Waveform of the current video, once analyzed.
+    waveform: Option<Arc<Waveform>>,
+
+    /// Whether the waveform of the current video was requested.
+    waveform_requested: bool,
+
     /// Last time a keyboard seek was triggered (for debouncing).
     last_keyboard_seek: Option<Instant>,
 
     /// Keyboard seek step (arrow keys during video playback).
     keyboard_seek_step: KeyboardSeekStep,
 
+    /// Reading direction of the interface: right-to-left languages swap the
+    /// arrow keys used to browse.
+    layout_direction: LayoutDirection,
+
+    /// Frame rate used when playing numbered image sequences.
+    sequence_fps: SequenceFps,
+
+    /// What the mouse buttons and wheel do.
+    mouse_mapping: MouseMapping,
+
+    /// Last time the wheel moved to another media (for debouncing).
+    last_wheel_navigation: Option<Instant>,
+
     /// Current temporary rotation angle (resets on navigation).
     current_rotation: RotationAngle,
 
-    /// Cached rotated image to avoid recomputing on every render.
-    /// Contains (`rotation_angle`, `rotated_image_data`).
-    rotated_image_cache: Option<(RotationAngle, crate::media::ImageData)>,
+    /// Whether the view of each image is restored when coming back to it.
+    remember_view: bool,
+
+    /// Whether the minimap is shown while zoomed in.
+    show_minimap: bool,
+
+    /// Whether the pixel inspector reads the color under the cursor.
+    inspector: bool,
+
+    /// Whether dragging over an image measures it instead of panning.
+    ruler: bool,
+
+    /// Measurements of the images seen during the session.
+    measurements: Measurements,
+
+    /// Measurement being drawn with the ruler.
+    pending_measurement: Option<Measurement>,
+
+    /// Whether the composition guides are drawn over the media.
+    guides_visible: bool,
+
+    /// Whether navigating between images keeps the zoom and offset, to
+    /// compare a sequence of shots (session only).
+    view_locked: bool,
+
+    /// Views of the images left during the session.
+    view_memory: ViewMemory,
+
+    /// Path of the image on screen, whose view is remembered when leaving it
+    /// (`current_media_path` already points to the next media by then).
+    shown_image_path: Option<PathBuf>,
+
+    /// Whether the current video is flipped horizontally (resets on navigation).
+    video_mirrored: bool,
+
+    /// Brightness, contrast, saturation and gamma of the current video
+    /// (resets on navigation).
+    color_adjustments: ColorAdjustments,
+
+    /// Whether the color adjustment sliders are shown.
+    color_adjustments_open: bool,
+
+    /// Matte color shown behind transparent pixels (persists across navigation).
+    matte: MatteColor,
+
+    /// Average luminance of the current media, used by the auto background.
+    media_luminance: Option<f32>,
+
+    /// Luminance histogram of the current media, for the fullscreen overlay.
+    media_histogram: Option<Vec<u32>>,
+
+    /// Alpha channel interpretation for display (persists across navigation).
+    alpha_interpretation: AlphaInterpretation,
+
+    /// Cached display image to avoid recomputing on every render.
+    /// Contains (`rotation_angle`, `alpha_interpretation`, `mirrored`, `transformed_image_data`).
+    display_image_cache: Option<(
+        RotationAngle,
+        AlphaInterpretation,
+        bool,
+        crate::media::ImageData,
+    )>,
 
     /// Filter dropdown UI state.
     filter_dropdown: filter_dropdown::FilterDropdownState,
+
+    /// Quick-search overlay state (matches persist for `F3` cycling).
+    quick_search: quick_search::QuickSearchState,
+
+    /// Faces detected in the current image (`None` until detection has run).
+    faces: Option<Vec<FaceBox>>,
+
+    /// Index of the face last zoomed to with `F`.
+    face_index: usize,
+
+    /// Whether face detection is running for the current image.
+    is_detecting_faces: bool,
+
+    /// Whether to zoom to the first face once detection completes (`F` key).
+    zoom_to_detected_face: bool,
+
+    /// Whether detected faces are outlined over the image (kept across images).
+    face_overlay: bool,
 }
 
 // Manual Default impl required: video_fit_to_window defaults to true (not false),
@@ -274,29 +648,85 @@ impl Default for State {
             arrows_visible: false,
             last_mouse_move: None,
             last_overlay_interaction: None,
+            osd_visible: true,
             last_mouse_position: None,
             fullscreen_entered_at: None,
             is_loading_media: false,
             loading_started_at: None,
             spinner_rotation: 0.0,
+            loading_preview: None,
             load_origin: LoadOrigin::DirectOpen,
             max_skip_attempts: MaxSkipAttempts::default(),
             video_player: None,
             video_shader: VideoShader::new(),
             current_video_path: None,
             playback_session_id: 0,
+            playback_session_base: 0,
             video_fit_to_window: true, // Videos always fit-to-window by default
             seek_preview_position: None,
+            fine_scrub: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
             video_autoplay: false, // Default to no autoplay
             video_volume: crate::config::DEFAULT_VOLUME,
+            base_video_volume: crate::config::DEFAULT_VOLUME,
             video_muted: false,
             video_loop: false,
+            resume_offer: None,
+            resume_at: None,
+            play_on_start: false,
+            video_handoff: None,
+            animation_loop: true,
             overflow_menu_open: false,
+            chapter_list_open: false,
+            decode_path: None,
+            cache_stats: None,
+            auto_cache_budget: None,
+            seek_hover_secs: None,
+            seek_thumbnails: SeekThumbnails::default(),
+            clip_export: None,
+            frames_export: video_controls::FramesExportPanel::default(),
+            frames_export_cancel: None,
+            subtitle_tracks: Vec::new(),
+            subtitle_track: None,
+            subtitle_delay_ms: 0,
+            subtitle_size: crate::config::SubtitleSize::default(),
+            subtitle_background: true,
+            audio_visualization: crate::config::AudioVisualization::default(),
+            tone_mapping: crate::config::ToneMapping::default(),
+            waveform: None,
+            waveform_requested: false,
             last_keyboard_seek: None,
             keyboard_seek_step: KeyboardSeekStep::default(),
+            layout_direction: LayoutDirection::default(),
+            sequence_fps: SequenceFps::default(),
+            mouse_mapping: MouseMapping::default(),
+            last_wheel_navigation: None,
             current_rotation: RotationAngle::default(),
-            rotated_image_cache: None,
+            remember_view: false,
+            show_minimap: true,
+            inspector: false,
+            ruler: false,
+            measurements: Measurements::default(),
+            pending_measurement: None,
+            guides_visible: false,
+            view_locked: false,
+            view_memory: ViewMemory::default(),
+            shown_image_path: None,
+            video_mirrored: false,
+            color_adjustments: ColorAdjustments::default(),
+            color_adjustments_open: false,
+            matte: MatteColor::default(),
+            media_luminance: None,
+            media_histogram: None,
+            alpha_interpretation: AlphaInterpretation::default(),
+            display_image_cache: None,
             filter_dropdown: filter_dropdown::FilterDropdownState::default(),
+            quick_search: quick_search::QuickSearchState::default(),
+            faces: None,
+            face_index: 0,
+            is_detecting_faces: false,
+            zoom_to_detected_face: false,
+            face_overlay: false,
         }
     }
 }
@@ -315,6 +745,14 @@ impl State {
         self.media.as_ref()
     }
 
+    /// Returns the backend that decoded the current media, if known.
+    pub fn media_decoder(&self) -> Option<MediaDecoder> {
+        match self.media.as_ref()? {
+            MediaData::Image(image) => image.decode_info().map(MediaDecoder::Image),
+            MediaData::Video(_) => self.decode_path.map(MediaDecoder::Video),
+        }
+    }
+
     pub fn error(&self) -> Option<&ErrorState> {
         self.error.as_ref()
     }
@@ -353,6 +791,18 @@ impl State {
         &self.filter_dropdown
     }
 
+    /// Opens the quick-search overlay over the given media paths and focuses its input.
+    pub fn open_quick_search(&mut self, candidates: Vec<PathBuf>) -> Task<Message> {
+        self.filter_dropdown.close();
+        self.quick_search.open(candidates);
+        operation::focus(Id::new(quick_search::INPUT_ID))
+    }
+
+    /// Returns a reference to the quick-search overlay state.
+    pub fn quick_search_state(&self) -> &quick_search::QuickSearchState {
+        &self.quick_search
+    }
+
     /// Returns the current temporary rotation angle.
     pub fn current_rotation(&self) -> RotationAngle {
         self.current_rotation
@@ -363,51 +813,224 @@ impl State {
         matches!(self.media, Some(MediaData::Image(_)))
     }
 
+    /// Returns the rotation applied on screen: the temporary rotation on top
+    /// of the orientation stored in the video container, if any.
+    pub fn display_rotation(&self) -> RotationAngle {
+        let intrinsic = match self.media {
+            Some(MediaData::Video(ref video)) => video.rotation,
+            _ => 0,
+        };
+        RotationAngle::new(self.current_rotation.degrees() + intrinsic)
+    }
+
+    /// Returns true if the current video is flipped horizontally.
+    pub fn is_mirrored(&self) -> bool {
+        self.video_mirrored
+    }
+
+    /// Returns true if navigation keeps the zoom and offset of the view.
+    pub fn is_view_locked(&self) -> bool {
+        self.view_locked
+    }
+
+    /// Returns the pixel under the cursor when the pixel inspector is on,
+    /// read from the image or the paused video frame.
+    // Fractions of the displayed size, truncated to whole pixels
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn inspected_pixel(&self) -> Option<pixel_inspector::Sample> {
+        if !self.inspector {
+            return None;
+        }
+        let rotation = self.display_rotation();
+        let fraction = self
+            .geometry_state()
+            .media_fraction_at(rotation, self.cursor_position?)?;
+        let (rgba, width, height) = match self.media.as_ref()? {
+            MediaData::Image(image) => (image.rgba_bytes(), image.width, image.height),
+            MediaData::Video(_) if !self.is_video_playing_or_will_resume() => {
+                let (width, height) = self.video_shader.dimensions()?;
+                (self.video_shader.raw_rgba_data()?.as_slice(), width, height)
+            }
+            MediaData::Video(_) => return None,
+        };
+        let (displayed_width, displayed_height) = if rotation.swaps_dimensions() {
+            (height, width)
+        } else {
+            (width, height)
+        };
+        pixel_inspector::Sample::read(
+            rgba,
+            width,
+            height,
+            pixel_inspector::Orientation {
+                rotation: rotation.degrees(),
+                mirrored: self.video_mirrored && self.is_video(),
+            },
+            ((fraction.x * displayed_width as f32) as u32).min(displayed_width.saturating_sub(1)),
+            ((fraction.y * displayed_height as f32) as u32).min(displayed_height.saturating_sub(1)),
+        )
+    }
+
+    /// Returns the point of the shown image under the cursor, in pixels of
+    /// the unrotated image, or `None` outside of it.
+    // Image dimensions are far below f32 precision limits
+    #[allow(clippy::cast_precision_loss)]
+    fn measured_point(&self) -> Option<Point> {
+        self.shown_image_path.as_ref()?;
+        let Some(MediaData::Image(image)) = self.media.as_ref() else {
+            return None;
+        };
+        let rotation = self.display_rotation();
+        let fraction = self
+            .geometry_state()
+            .media_fraction_at(rotation, self.cursor_position?)?;
+        let image_size = iced::Size::new(image.width as f32, image.height as f32);
+        let displayed_size = if rotation.swaps_dimensions() {
+            iced::Size::new(image_size.height, image_size.width)
+        } else {
+            image_size
+        };
+        Some(measurement::to_source(
+            Point::new(
+                fraction.x * displayed_size.width,
+                fraction.y * displayed_size.height,
+            ),
+            rotation.degrees(),
+            image_size,
+        ))
+    }
+
+    /// Returns what the ruler draws over the shown image, while it is on.
+    // Image dimensions are far below f32 precision limits
+    #[allow(clippy::cast_precision_loss)]
+    fn ruler_overlay(&self, dots_per_inch: Option<f64>) -> Option<measurement::Overlay> {
+        if !self.ruler {
+            return None;
+        }
+        let path = self.shown_image_path.as_deref()?;
+        let Some(MediaData::Image(image)) = self.media.as_ref() else {
+            return None;
+        };
+        let mut measurements = self.measurements.get(path).to_vec();
+        measurements.extend(self.pending_measurement);
+        Some(measurement::Overlay {
+            measurements,
+            image_size: iced::Size::new(image.width as f32, image.height as f32),
+            rotation: self.display_rotation().degrees(),
+            dots_per_inch,
+        })
+    }
+
     /// Updates the rotation and rebuilds the cache.
     fn apply_rotation(&mut self, new_rotation: RotationAngle) {
         self.current_rotation = new_rotation;
-        self.rebuild_rotation_cache();
+        self.rebuild_display_cache();
+        // Quarter turns change the aspect ratio to fit
+        self.refresh_fit_zoom();
     }
 
-    /// Rebuilds the cached rotated image based on current rotation.
-    fn rebuild_rotation_cache(&mut self) {
-        // Only cache for images, and only when rotation is non-zero
-        if let Some(MediaData::Image(ref image_data)) = self.media {
-            if self.current_rotation.is_rotated() {
-                let rotated = image_data.rotated(self.current_rotation.degrees());
-                self.rotated_image_cache = Some((self.current_rotation, rotated));
-            } else {
-                self.rotated_image_cache = None;
+    /// Rebuilds the cached display image based on rotation, mirroring and
+    /// alpha interpretation.
+    fn rebuild_display_cache(&mut self) {
+        // Only cache when a transformation is active. Videos only cache their
+        // thumbnail, shown until the first frame is decoded.
+        let premultiplied = self.alpha_interpretation.is_premultiplied();
+        let rotation = self.display_rotation();
+        let transformed = match self.media {
+            Some(MediaData::Image(ref image_data)) if rotation.is_rotated() || premultiplied => {
+                // Rotation only moves pixels, so the order of the two
+                // transformations is irrelevant. Cloning `ImageData` and
+                // rotating by 0° are cheap (pixel bytes are shared via Arc).
+                let base = if premultiplied {
+                    image_data.unpremultiplied()
+                } else {
+                    image_data.clone()
+                };
+                Some(base.rotated(rotation.degrees()))
             }
-        } else {
-            self.rotated_image_cache = None;
-        }
+            Some(MediaData::Video(ref video)) if rotation.is_rotated() || self.video_mirrored => {
+                let rotated = video.thumbnail.rotated(rotation.degrees());
+                Some(if self.video_mirrored {
+                    rotated.mirrored()
+                } else {
+                    rotated
+                })
+            }
+            _ => None,
+        };
+        self.display_image_cache = transformed.map(|image| {
+            (
+                self.current_rotation,
+                self.alpha_interpretation,
+                self.video_mirrored,
+                image,
+            )
+        });
     }
 
-    /// Rotates the current media 90° clockwise (images only).
+    /// Rotates the current media 90° clockwise.
     pub fn rotate_clockwise(&mut self) {
-        // Rotation only applies to images, not videos
-        if !self.is_current_media_image() {
+        if self.media.is_none() {
             return;
         }
         self.apply_rotation(self.current_rotation.rotate_clockwise());
     }
 
-    /// Rotates the current media 90° counter-clockwise (images only).
+    /// Rotates the current media 90° counter-clockwise.
     pub fn rotate_counterclockwise(&mut self) {
-        // Rotation only applies to images, not videos
-        if !self.is_current_media_image() {
+        if self.media.is_none() {
             return;
         }
         self.apply_rotation(self.current_rotation.rotate_counterclockwise());
     }
 
-    /// Returns the cached rotated image if available.
-    pub fn rotated_image_cache(&self) -> Option<&crate::media::ImageData> {
-        self.rotated_image_cache
+    /// Flips the current video horizontally (videos only).
+    pub fn toggle_mirror(&mut self) {
+        if !self.is_video() {
+            return;
+        }
+        self.video_mirrored = !self.video_mirrored;
+        self.rebuild_display_cache();
+    }
+
+    /// Returns the cached display image if available.
+    pub fn display_image_cache(&self) -> Option<&crate::media::ImageData> {
+        self.display_image_cache
             .as_ref()
-            .filter(|(angle, _)| *angle == self.current_rotation)
-            .map(|(_, image)| image)
+            .filter(|(angle, alpha, mirrored, _)| {
+                *angle == self.current_rotation
+                    && *alpha == self.alpha_interpretation
+                    && *mirrored == self.video_mirrored
+            })
+            .map(|(_, _, _, image)| image)
+    }
+
+    /// Returns the current matte color.
+    #[must_use]
+    pub fn matte(&self) -> MatteColor {
+        self.matte
+    }
+
+    /// Returns the average luminance of the current media, if known.
+    #[must_use]
+    pub fn media_luminance(&self) -> Option<f32> {
+        self.media_luminance
+    }
+
+    /// Returns the current alpha interpretation.
+    #[must_use]
+    pub fn alpha_interpretation(&self) -> AlphaInterpretation {
+        self.alpha_interpretation
+    }
+
+    /// Toggles between straight and premultiplied alpha interpretation.
+    pub fn toggle_alpha_interpretation(&mut self) {
+        self.alpha_interpretation = self.alpha_interpretation.toggled();
+        self.rebuild_display_cache();
     }
 
     pub fn set_cursor_position(&mut self, position: Option<Point>) {
@@ -471,6 +1094,12 @@ impl State {
             .is_some_and(|p| p.state().is_playing_or_will_resume())
     }
 
+    /// Returns the fine scrubbing step while Shift is held over a video.
+    fn fine_step(&self) -> Option<FineStep> {
+        (self.keyboard_modifiers.shift() && self.has_active_video_session())
+            .then(|| FineStep::for_playback(!self.is_video_playing_or_will_resume()))
+    }
+
     /// Returns true if a video player exists and has an active session.
     ///
     /// An active session means the player is not stopped or in error state.
@@ -501,10 +1130,99 @@ impl State {
         }
     }
 
-    pub fn refresh_error_translation(&mut self, i18n: &I18n) {
+    /// Refreshes the text and layout kept from the previous interface
+    /// language.
+    pub fn apply_locale(&mut self, i18n: &I18n) {
         if let Some(error) = &mut self.error {
             error.refresh_translation(i18n);
         }
+        self.layout_direction = i18n.layout_direction();
+    }
+
+    /// Returns the path, playback position and duration of the current
+    /// video, for remembering where it was left.
+    #[must_use]
+    pub fn video_resume_point(&self) -> Option<(PathBuf, f64, f64)> {
+        let player = self.video_player.as_ref()?;
+        // Frame-based media are short loops, not worth resuming
+        if player.video_data().is_frame_based() {
+            return None;
+        }
+        let path = self.current_video_path.clone()?;
+        let position_secs = player.state().position()?;
+        Some((path, position_secs, player.video_data().duration_secs))
+    }
+
+    /// Returns the position offered by the resume prompt, while unanswered.
+    #[must_use]
+    pub fn resume_offer(&self) -> Option<f64> {
+        self.resume_offer
+    }
+
+    /// Resumes the video that was just loaded from a remembered position,
+    /// either right away or after the user accepts the prompt.
+    ///
+    /// Does nothing when the video already continues from the mini player.
+    pub fn resume_video_from(&mut self, position_secs: f64, ask: bool) {
+        if self.resume_at.is_some() {
+            return;
+        }
+        if ask {
+            self.resume_offer = Some(position_secs);
+        } else {
+            self.resume_at = Some(position_secs);
+        }
+    }
+
+    /// Pauses the current video and returns it for the mini player, with
+    /// its position, rotation and color adjustments.
+    pub fn mini_player_handoff(&mut self) -> Option<crate::ui::pip::Handoff> {
+        let Some(MediaData::Video(video)) = &self.media else {
+            return None;
+        };
+        let path = self.current_video_path.clone()?;
+        let transform = FrameTransform {
+            rotation: self.display_rotation(),
+            mirrored: self.video_mirrored,
+        };
+        let player = self.video_player.as_mut()?;
+        let position_secs = self
+            .seek_preview_position
+            .or_else(|| player.state().position())
+            .unwrap_or(0.0);
+        let playing = player.state().is_playing_or_will_resume();
+        if playing {
+            player.pause();
+        }
+        Some(crate::ui::pip::Handoff {
+            path,
+            video: video.clone(),
+            position_secs,
+            playing,
+            transform,
+            color_adjustments: self.color_adjustments,
+            tone_map: self.tone_map_params(),
+        })
+    }
+
+    /// Continues a video coming back from the mini player at `position_secs`.
+    ///
+    /// Returns false when it isn't the current media: the app must load it,
+    /// and it continues once loaded.
+    pub fn continue_video(&mut self, path: PathBuf, position_secs: f64, playing: bool) -> bool {
+        if self.current_video_path.as_ref() == Some(&path) {
+            if let Some(player) = &mut self.video_player {
+                self.seek_preview_position = None;
+                if playing {
+                    player.seek_and_play(position_secs);
+                } else {
+                    player.seek(position_secs);
+                }
+                return true;
+            }
+        }
+        self.video_handoff = Some((path, position_secs, playing));
+        false
     }
 
     /// Sets whether videos should auto-play when loaded.
@@ -512,9 +1230,119 @@ impl State {
         self.video_autoplay = enabled;
     }
 
-    /// Sets the video volume level (0.0 to 1.0).
+    /// Sets whether the minimap is shown while zoomed in.
+    pub fn set_show_minimap(&mut self, enabled: bool) {
+        self.show_minimap = enabled;
+    }
+
+    /// Sets whether the view of each image is restored when coming back to
+    /// it. Turning it off forgets the remembered views.
+    pub fn set_remember_view(&mut self, enabled: bool) {
+        self.remember_view = enabled;
+        if !enabled {
+            self.view_memory.clear();
+        }
+    }
+
+    /// Remembers the zoom, scroll position and rotation of the image being
+    /// left, if the option is on.
+    fn remember_shown_view(&mut self) {
+        let Some(path) = self.shown_image_path.take() else {
+            return;
+        };
+        if !self.remember_view || !self.is_current_media_image() {
+            return;
+        }
+        let (scroll_x, scroll_y) = self
+            .geometry_state()
+            .scroll_position_percentage()
+            .map_or((0.0, 0.0), |(x, y)| (x / 100.0, y / 100.0));
+        self.view_memory.remember(
+            &path,
+            ViewTransform {
+                fit_to_window: self.zoom.fit_to_window,
+                zoom_percent: self.zoom.zoom_percent,
+                scroll_x,
+                scroll_y,
+                rotation: self.current_rotation,
+            },
+        );
+    }
+
+    /// Moves the viewport to `offset`, kept within the current media, and
+    /// returns the matching position of the scrollable.
+    fn clamp_scroll_offset(&mut self, offset: AbsoluteOffset) -> RelativeOffset {
+        let (Some(viewport), Some(size)) = (
+            self.viewport.bounds,
+            self.geometry_state()
+                .scaled_media_size_rotated(self.display_rotation()),
+        ) else {
+            return RelativeOffset { x: 0.0, y: 0.0 };
+        };
+        let max_offset_x = (size.width - viewport.width).max(0.0);
+        let max_offset_y = (size.height - viewport.height).max(0.0);
+        self.viewport.offset = AbsoluteOffset {
+            x: offset.x.clamp(0.0, max_offset_x),
+            y: offset.y.clamp(0.0, max_offset_y),
+        };
+        RelativeOffset {
+            x: if max_offset_x > 0.0 {
+                self.viewport.offset.x / max_offset_x
+            } else {
+                0.0
+            },
+            y: if max_offset_y > 0.0 {
+                self.viewport.offset.y / max_offset_y
+            } else {
+                0.0
+            },
+        }
+    }
+
+    /// Scrolls so the point at (`x`, `y`), in fractions of the media size,
+    /// is in the middle of the viewport.
+    fn center_view_on(&mut self, x: f32, y: f32) -> Task<Message> {
+        let (Some(viewport), Some(size)) = (
+            self.viewport.bounds,
+            self.geometry_state()
+                .scaled_media_size_rotated(self.display_rotation()),
+        ) else {
+            return Task::none();
+        };
+        let offset = self.clamp_scroll_offset(AbsoluteOffset {
+            x: x * size.width - viewport.width / 2.0,
+            y: y * size.height - viewport.height / 2.0,
+        });
+        operation::snap_to(Id::new(SCROLLABLE_ID), offset)
+    }
+
+    /// Restores the remembered view of the image just loaded. Returns the
+    /// scroll position to snap to, if a view was remembered.
+    fn restore_remembered_view(&mut self) -> Option<RelativeOffset> {
+        if !self.is_current_media_image() {
+            return None;
+        }
+        self.shown_image_path = self.current_media_path.clone();
+        if !self.remember_view {
+            return None;
+        }
+        let transform = self.view_memory.get(self.shown_image_path.as_deref()?)?;
+        if transform.fit_to_window {
+            self.zoom.enable_fit_to_window();
+        } else {
+            self.zoom.apply_manual_zoom(transform.zoom_percent);
+        }
+        self.apply_rotation(transform.rotation);
+        Some(RelativeOffset {
+            x: transform.scroll_x,
+            y: transform.scroll_y,
+        })
+    }
+
+    /// Sets the video volume level (0.0 to 2.0).
     pub fn set_video_volume(&mut self, volume: f32) {
         self.video_volume = volume.clamp(crate::config::MIN_VOLUME, crate::config::MAX_VOLUME);
+        self.base_video_volume = self.video_volume.min(crate::config::UNITY_VOLUME);
     }
 
     /// Returns the current video volume level.
@@ -522,6 +1350,24 @@ impl State {
         self.video_volume
     }
 
+    /// Returns the volume of videos without a boost of their own.
+    #[must_use]
+    pub fn base_video_volume(&self) -> f32 {
+        self.base_video_volume
+    }
+
+    /// Applies the boost remembered for the loaded video, or goes back to
+    /// the base volume when it has none.
+    pub fn apply_volume_boost(&mut self, boost: Option<f32>) {
+        let volume = boost.map_or(self.base_video_volume, |boost| {
+            Volume::new(boost.max(crate::config::UNITY_VOLUME)).value()
+        });
+        self.video_volume = volume;
+        if let Some(player) = &self.video_player {
+            player.set_volume(Volume::new(volume));
+        }
+    }
+
     /// Sets whether video audio is muted.
     pub fn set_video_muted(&mut self, muted: bool) {
         self.video_muted = muted;
@@ -542,23 +1388,252 @@ impl State {
         self.video_loop
     }
 
-    /// Sets the keyboard seek step.
-    pub fn set_keyboard_seek_step(&mut self, step: KeyboardSeekStep) {
-        self.keyboard_seek_step = step;
+    /// Returns whether the current media loops: the per-animation toggle
+    /// for animated images, the video preference otherwise.
+    fn loop_enabled(&self) -> bool {
+        let animated = self
+            .video_player
+            .as_ref()
+            .is_some_and(|player| player.video_data().animated_image);
+        if animated {
+            self.animation_loop
+        } else {
+            self.video_loop
+        }
     }
 
-    /// Sets the maximum number of skip attempts for auto-skip.
-    pub fn set_max_skip_attempts(&mut self, max_attempts: MaxSkipAttempts) {
-        self.max_skip_attempts = max_attempts;
+    /// Sets the subtitle text size.
+    pub fn set_subtitle_size(&mut self, size: crate::config::SubtitleSize) {
+        self.subtitle_size = size;
     }
 
-    /// Sets the origin of the current media load request.
-    ///
-    /// This determines auto-skip behavior when loading fails:
-    /// - `LoadOrigin::Navigation`: Auto-skip to next/previous on failure
-    /// - `LoadOrigin::DirectOpen`: Show error notification, stay on current media
-    pub fn set_load_origin(&mut self, origin: LoadOrigin) {
-        self.load_origin = origin;
+    /// Returns the subtitle text size.
+    pub fn subtitle_size(&self) -> crate::config::SubtitleSize {
+        self.subtitle_size
+    }
+
+    /// Sets whether subtitles are drawn on a translucent box.
+    pub fn set_subtitle_background(&mut self, enabled: bool) {
+        self.subtitle_background = enabled;
+    }
+
+    /// Returns whether subtitles are drawn on a translucent box.
+    pub fn subtitle_background(&self) -> bool {
+        self.subtitle_background
+    }
+
+    /// Sets the audio visualization shown under the seek bar.
+    pub fn set_audio_visualization(&mut self, mode: crate::config::AudioVisualization) {
+        self.audio_visualization = mode;
+    }
+
+    /// Returns the audio visualization shown under the seek bar.
+    pub fn audio_visualization(&self) -> crate::config::AudioVisualization {
+        self.audio_visualization
+    }
+
+    /// Sets the tone mapping curve of HDR videos.
+    pub fn set_tone_mapping(&mut self, operator: crate::config::ToneMapping) {
+        self.tone_mapping = operator;
+    }
+
+    /// Returns how the shader draws the current video: tone mapped for HDR
+    /// videos, as is otherwise.
+    fn tone_map_params(&self) -> ToneMapParams {
+        ToneMapParams {
+            hdr: match self.media {
+                Some(MediaData::Video(ref video)) => video.hdr,
+                _ => None,
+            },
+            operator: self.tone_mapping,
+        }
+    }
+
+    /// Shows or hides the on-screen display.
+    pub fn set_osd_visible(&mut self, visible: bool) {
+        self.osd_visible = visible;
+    }
+
+    /// Returns whether the on-screen display is shown.
+    pub fn osd_visible(&self) -> bool {
+        self.osd_visible
+    }
+
+    /// Returns the subtitle text to display at the current playback position.
+    fn current_subtitle_text(&self) -> Option<String> {
+        let track = self.subtitle_tracks.get(self.subtitle_track?)?;
+        let position = self.playback_position()?;
+        track.text_at(position - f64::from(self.subtitle_delay_ms) / 1000.0)
+    }
+
+    /// Returns the chapters of the current video, if it has any.
+    fn video_chapters(&self) -> Option<&[crate::media::chapters::Chapter]> {
+        match &self.media {
+            Some(MediaData::Video(video)) if !video.chapters.is_empty() => Some(&video.chapters),
+            _ => None,
+        }
+    }
+
+    /// Returns the intended playback position: the pending seek target, or
+    /// the player's reported position.
+    fn playback_position(&self) -> Option<f64> {
+        self.seek_preview_position
+            .or_else(|| self.video_player.as_ref()?.state().position())
+    }
+
+    /// Seeks the active player to `target_secs`.
+    fn seek_to(&mut self, target_secs: f64) {
+        if let Some(player) = &mut self.video_player {
+            // Keep the target as the displayed position until a frame near it arrives
+            self.seek_preview_position = Some(target_secs);
+            player.seek(target_secs);
+        }
+    }
+
+    /// Clears the subtitle tracks of the previous video.
+    /// Starts decoding the preview frame under the cursor, unless it is cached
+    /// or another frame is still being decoded.
+    fn request_seek_thumbnail(&mut self) -> Task<Message> {
+        let (Some(position), Some(path), Some(player)) = (
+            self.seek_hover_secs,
+            self.current_video_path.clone(),
+            &self.video_player,
+        ) else {
+            return Task::none();
+        };
+        // Image sequences have no container to seek in
+        if player.video_data().sequence.is_some() {
+            return Task::none();
+        }
+        let second = seek_thumbnails::second_of(position);
+        if !self.seek_thumbnails.request(second) {
+            return Task::none();
+        }
+
+        let video_path = path.clone();
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || {
+                    seek_thumbnails::decode_thumbnail(&video_path, second)
+                        .ok()
+                        .map(|data| data.handle)
+                })
+                .await
+                .ok()
+                .flatten()
+            },
+            move |thumbnail| Message::SeekThumbnailReady {
+                path: path.clone(),
+                second,
+                thumbnail,
+            },
+        )
+    }
+
+    /// Returns the audio strip to draw under the seek bar, if enabled.
+    fn audio_strip(&self, has_audio: bool) -> Option<AudioStrip> {
+        use crate::config::AudioVisualization;
+        if !has_audio {
+            return None;
+        }
+        match self.audio_visualization {
+            AudioVisualization::Off => None,
+            AudioVisualization::Waveform => Some(AudioStrip::Waveform(self.waveform.clone())),
+            AudioVisualization::VuMeter => Some(AudioStrip::VuMeter(
+                self.video_player
+                    .as_ref()
+                    .filter(|player| player.state().is_playing())
+                    .map(VideoPlayer::audio_level)
+                    .unwrap_or_default(),
+            )),
+        }
+    }
+
+    /// Starts analyzing the waveform of the current video when the waveform
+    /// strip is shown and it wasn't analyzed yet.
+    fn request_waveform(&mut self) -> Task<Message> {
+        if self.audio_visualization != crate::config::AudioVisualization::Waveform
+            || self.waveform_requested
+        {
+            return Task::none();
+        }
+        let (Some(path), Some(player)) = (self.current_video_path.clone(), &self.video_player)
+        else {
+            return Task::none();
+        };
+        if !player.video_data().has_audio {
+            return Task::none();
+        }
+        self.waveform_requested = true;
+
+        let video_path = path.clone();
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || {
+                    audio_meter::analyze_waveform(&video_path)
+                        .ok()
+                        .map(Arc::new)
+                })
+                .await
+                .ok()
+                .flatten()
+            },
+            move |waveform| Message::WaveformReady {
+                path: path.clone(),
+                waveform,
+            },
+        )
+    }
+
+    fn reset_subtitles(&mut self) {
+        self.subtitle_tracks.clear();
+        self.subtitle_track = None;
+        self.subtitle_delay_ms = 0;
+    }
+
+    /// Sets the reading direction of the interface.
+    pub fn set_layout_direction(&mut self, direction: LayoutDirection) {
+        self.layout_direction = direction;
+    }
+
+    /// Returns the reading direction of the interface.
+    #[must_use]
+    pub fn layout_direction(&self) -> LayoutDirection {
+        self.layout_direction
+    }
+
+    /// Sets the keyboard seek step.
+    pub fn set_keyboard_seek_step(&mut self, step: KeyboardSeekStep) {
+        self.keyboard_seek_step = step;
+    }
+
+    /// Sets the frame rate used for image sequence playback.
+    pub fn set_sequence_fps(&mut self, fps: SequenceFps) {
+        self.sequence_fps = fps;
+    }
+
+    /// Sets what the mouse buttons and wheel do.
+    pub fn set_mouse_mapping(&mut self, mapping: MouseMapping) {
+        self.mouse_mapping = mapping;
+    }
+
+    /// Sets the base of the playback session IDs, for viewers of other windows.
+    pub fn set_playback_session_base(&mut self, base: u64) {
+        self.playback_session_base = base;
+    }
+
+    /// Sets the maximum number of skip attempts for auto-skip.
+    pub fn set_max_skip_attempts(&mut self, max_attempts: MaxSkipAttempts) {
+        self.max_skip_attempts = max_attempts;
+    }
+
+    /// Sets the origin of the current media load request.
+    ///
+    /// This determines auto-skip behavior when loading fails:
+    /// - `LoadOrigin::Navigation`: Auto-skip to next/previous on failure
+    /// - `LoadOrigin::DirectOpen`: Show error notification, stay on current media
+    pub fn set_load_origin(&mut self, origin: LoadOrigin) {
+        self.load_origin = origin;
     }
 
     /// Sets the load origin for navigation with initial state.
@@ -585,15 +1660,53 @@ impl State {
     pub fn start_loading(&mut self) {
         self.is_loading_media = true;
         self.loading_started_at = Some(std::time::Instant::now());
+        self.loading_preview = None;
         self.error = None;
         // Clear video shader immediately to prevent stale frame from being rendered
         // with wrong dimensions when navigating to a different media
         self.video_shader.clear();
     }
 
-    /// Returns an exportable frame from the video canvas, if available.
+    /// Returns an exportable frame from the video canvas, if available,
+    /// rotated and mirrored as displayed.
     pub fn exportable_frame(&self) -> Option<crate::media::frame_export::ExportableFrame> {
-        self.video_shader.exportable_frame()
+        let frame = self.video_shader.exportable_frame()?;
+        let rotation = self.display_rotation();
+        if !rotation.is_rotated() && !self.video_mirrored {
+            return Some(frame);
+        }
+
+        let rotated = frame.to_image_data().rotated(rotation.degrees());
+        let displayed = if self.video_mirrored {
+            rotated.mirrored()
+        } else {
+            rotated
+        };
+        Some(crate::media::frame_export::ExportableFrame::new(
+            std::sync::Arc::new(displayed.rgba_bytes().to_vec()),
+            displayed.width,
+            displayed.height,
+        ))
+    }
+
+    /// Returns the part of the current image visible in the viewport, at
+    /// full resolution and as displayed (rotation and alpha preview included).
+    ///
+    /// Returns `None` for videos or when nothing is visible.
+    pub fn visible_area(&self) -> Option<crate::media::frame_export::ExportableFrame> {
+        let Some(MediaData::Image(ref image)) = self.media else {
+            return None;
+        };
+        let displayed = self.display_image_cache().unwrap_or(image);
+        let region = self
+            .geometry_state()
+            .visible_media_region(self.current_rotation)?;
+        let crop = displayed.cropped(region.x, region.y, region.width, region.height)?;
+        Some(crate::media::frame_export::ExportableFrame::new(
+            std::sync::Arc::new(crop.rgba_bytes().to_vec()),
+            crop.width,
+            crop.height,
+        ))
     }
 
     /// Returns true if media is currently being loaded.
@@ -601,6 +1714,13 @@ impl State {
         self.is_loading_media
     }
 
+    /// Returns whether overlay controls were used within `delay` and are
+    /// still waiting to auto-hide.
+    pub fn is_overlay_hide_pending(&self, delay: std::time::Duration) -> bool {
+        self.last_overlay_interaction
+            .is_some_and(|t| t.elapsed() < delay)
+    }
+
     /// Checks if loading has timed out.
     /// Returns `true` if a timeout occurred (caller should show notification).
     pub fn check_loading_timeout(&mut self) -> bool {
@@ -625,20 +1745,29 @@ impl State {
     /// * `normalization_enabled` - Whether to apply audio normalization
     /// * `frame_cache_mb` - Maximum memory for frame cache (seek optimization), in MB
     /// * `history_mb` - Maximum memory for frame history (backward stepping), in MB
+    /// * `frame_cache_auto` - Size both from the video resolution and available
+    ///   memory instead, ignoring the two limits above
     pub fn subscription(
         &self,
         lufs_cache: Option<SharedLufsCache>,
         normalization_enabled: bool,
         frame_cache_mb: u32,
         history_mb: u32,
+        frame_cache_auto: bool,
+        hardware_decoding: bool,
     ) -> iced::Subscription<Message> {
         // Keep subscription active for ALL playback states including Stopped
         // This ensures the decoder stays alive and can receive pause/resume commands
         // The subscription only gets recreated when playback_session_id changes
         // (which happens when navigating to a different video or starting fresh)
-        let video_subscription = if let (Some(_player), Some(ref path)) =
+        let video_subscription = if let (Some(player), Some(ref path)) =
             (&self.video_player, &self.current_video_path)
         {
+            let (frame_cache_mb, history_mb) = match self.auto_cache_budget {
+                Some(budget) if frame_cache_auto => (budget.cache.value(), budget.history.value()),
+                _ => (frame_cache_mb, history_mb),
+            };
+
             // Create cache config from MB setting
             let cache_config = crate::video_player::CacheConfig::new(
                 (frame_cache_mb as usize) * 1024 * 1024,
@@ -649,18 +1778,22 @@ impl State {
             // The decoder will handle pause/resume via commands
             crate::video_player::subscription::video_playback(
                 path.clone(),
-                self.playback_session_id,
+                self.playback_session_base | self.playback_session_id,
                 lufs_cache,
                 normalization_enabled,
                 cache_config,
                 history_mb,
+                player.video_data().sequence.clone(),
+                hardware_decoding,
             )
             .map(Message::PlaybackEvent)
         } else {
             iced::Subscription::none()
         };
 
-        let spinner_subscription = if self.is_loading_media {
+        let spinner_subscription = if self.is_loading_media
+            && !accessibility::current().reduced_motion
+        {
             // Animate spinner at 60 FPS while loading
             iced::time::every(std::time::Duration::from_millis(16)).map(|_| Message::SpinnerTick)
         } else {
@@ -688,10 +1821,15 @@ impl State {
                 }
                 self.video_player = None;
                 self.current_video_path = None;
+                self.remember_shown_view();
+                self.pending_measurement = None;
                 self.video_shader.clear_frame();
+                self.resume_offer = None;
 
                 // Clear media and error state
                 self.media = None;
+                self.media_luminance = None;
+                self.media_histogram = None;
                 self.error = None;
                 self.current_media_path = None;
 
@@ -703,9 +1841,13 @@ impl State {
                 self.zoom = ZoomState::default();
                 self.viewport = ViewportState::default();
 
-                // Reset temporary rotation and cache
+                // Reset temporary rotation, mirroring, color adjustments and cache
                 self.current_rotation = RotationAngle::default();
-                self.rotated_image_cache = None;
+                self.video_mirrored = false;
+                self.color_adjustments = ColorAdjustments::default();
+                self.display_image_cache = None;
+                self.reset_faces();
+                self.reset_subtitles();
 
                 (Effect::None, Task::none())
             }
@@ -713,6 +1855,9 @@ impl State {
                 // Clear loading state
                 self.is_loading_media = false;
                 self.loading_started_at = None;
+                self.loading_preview = None;
+                self.remember_shown_view();
+                self.pending_measurement = None;
 
                 // Clean up previous video state before loading new media
                 // This is important when navigating from one media to another
@@ -726,32 +1871,92 @@ impl State {
                     self.video_shader.clear(); // Clear frame to release memory
                     self.seek_preview_position = None;
                     self.last_keyboard_seek = None;
+                    self.decode_path = None;
+                    self.cache_stats = None;
+                    self.seek_hover_secs = None;
+                    self.seek_thumbnails.clear();
+                    self.waveform = None;
+                    self.waveform_requested = false;
                     self.playback_session_id += 1; // Ensure old subscription is dropped
                 }
+                self.resume_offer = None;
+                self.resume_at = None;
+                self.play_on_start = false;
+                let handoff = self.video_handoff.take();
+                // Animations start looping whatever the previous one was set to
+                self.animation_loop = true;
                 // Reset video fit-to-window to default for new media
                 self.video_fit_to_window = true;
 
                 // Reset temporary rotation and cache for new media
+                // (matte and alpha interpretation intentionally carry over)
                 self.current_rotation = RotationAngle::default();
-                self.rotated_image_cache = None;
+                self.video_mirrored = false;
+                self.color_adjustments = ColorAdjustments::default();
+                self.display_image_cache = None;
+                self.reset_faces();
+                self.reset_subtitles();
 
                 match result {
                     Ok(media) => {
+                        // Look for subtitles in the background while the video loads
+                        let subtitles_task = match (&media, &self.current_media_path) {
+                            (MediaData::Video(_), Some(path)) => {
+                                let video_path = path.clone();
+                                let path = path.clone();
+                                Task::perform(
+                                    async move {
+                                        tokio::task::spawn_blocking(move || {
+                                            crate::video_player::subtitles::load_tracks(&video_path)
+                                        })
+                                        .await
+                                        .unwrap_or_default()
+                                    },
+                                    move |tracks| Message::SubtitlesLoaded {
+                                        path: path.clone(),
+                                        tracks,
+                                    },
+                                )
+                            }
+                            _ => Task::none(),
+                        };
+
                         // Create VideoPlayer if this is a video
                         if let MediaData::Video(ref video_data) = media {
+                            self.auto_cache_budget = Some(CacheBudget::auto(
+                                video_data.width,
+                                video_data.height,
+                                available_memory_bytes(),
+                            ));
                             match VideoPlayer::new(video_data) {
                                 Ok(player) => {
                                     self.video_player = Some(player);
                                     self.current_video_path = self.current_media_path.clone();
+                                    // Back from the mini player: continue where it was
+                                    if let Some((path, position_secs, playing)) = handoff {
+                                        if self.current_video_path.as_ref() == Some(&path) {
+                                            self.resume_at = Some(position_secs);
+                                            self.play_on_start = playing;
+                                        }
+                                    }
                                 }
                                 Err(e) => {
-                                    eprintln!("Failed to create video player: {e}");
+                                    tracing::error!("Failed to create video player: {e}");
                                 }
                             }
                         }
 
+                        // Videos use their first frame for the auto background
+                        // and the fullscreen histogram
+                        let preview = match &media {
+                            MediaData::Image(image) => image,
+                            MediaData::Video(video) => &video.thumbnail,
+                        };
+                        self.media_luminance = preview.average_luminance();
+                        self.media_histogram = preview.luminance_histogram();
                         self.media = Some(media);
                         self.error = None;
+                        self.rebuild_display_cache();
 
                         // Extract skipped files from navigation origin (if any)
                         let skipped_files =
@@ -778,23 +1983,43 @@ impl State {
                             }
                         };
 
+                        // A locked view keeps the zoom and offset of the previous image
+                        let locked_offset = (self.view_locked && self.is_current_media_image())
+                            .then_some(self.viewport.offset);
+
                         // Reset viewport offset for new media (ensures proper centering)
                         self.viewport.reset_offset();
 
                         // Reset zoom to 100% for images when fit-to-window is disabled
-                        if !self.is_video() && !self.image_fit_to_window() {
+                        if !self.is_video()
+                            && !self.image_fit_to_window()
+                            && locked_offset.is_none()
+                        {
                             self.zoom
                                 .apply_manual_zoom(crate::ui::state::zoom::DEFAULT_ZOOM_PERCENT);
                         }
 
                         self.refresh_fit_zoom();
 
-                        // Scroll the widget to origin to match the reset offset
-                        let scroll_task = operation::snap_to(
-                            Id::new(SCROLLABLE_ID),
-                            RelativeOffset { x: 0.0, y: 0.0 },
-                        );
-                        (effect, scroll_task)
+                        // Scroll the widget to origin to match the reset offset,
+                        // or back to where the image was left
+                        let offset = match locked_offset {
+                            Some(offset) => self.clamp_scroll_offset(offset),
+                            None => self
+                                .restore_remembered_view()
+                                .unwrap_or(RelativeOffset { x: 0.0, y: 0.0 }),
+                        };
+                        let scroll_task = operation::snap_to(Id::new(SCROLLABLE_ID), offset);
+                        // Keep outlining faces on the new image
+                        let overlay_task = if self.face_overlay && self.is_current_media_image() {
+                            Task::done(Message::DetectOverlayFaces)
+                        } else {
+                            Task::none()
+                        };
+                        (
+                            effect,
+                            Task::batch([scroll_task, subtitles_task, overlay_task]),
+                        )
                     }
                     Err(error) => {
                         // Get the failed filename for the notification
@@ -872,6 +2097,7 @@ impl State {
                 // No need to sync shader scale - pane calculates display size from zoom at render time
                 self.handle_controls(control)
             }
+            Message::MinimapMoved { x, y } => (Effect::None, self.center_view_on(x, y)),
             Message::ViewportChanged { bounds, offset } => {
                 let bounds_changed = self.viewport.update(bounds, offset);
                 // When viewport size changes significantly (e.g., sidebar toggle), reset to recenter
@@ -921,10 +2147,113 @@ impl State {
                 self.rotate_clockwise();
                 (Effect::None, Task::none())
             }
+            Message::ToggleMirror => {
+                self.toggle_mirror();
+                (Effect::None, Task::none())
+            }
             Message::RotateCounterClockwise => {
                 self.rotate_counterclockwise();
                 (Effect::None, Task::none())
             }
+            Message::NextMatte => {
+                self.matte = self.matte.next();
+                (Effect::None, Task::none())
+            }
+            Message::PreviousMatte => {
+                self.matte = self.matte.previous();
+                (Effect::None, Task::none())
+            }
+            Message::ToggleAlphaInterpretation => {
+                self.toggle_alpha_interpretation();
+                (Effect::None, Task::none())
+            }
+            Message::PlayImageSequence => {
+                // Only still images can start a sequence; a playing sequence is already a video
+                let Some(path) = self.current_media_path.clone().filter(|_| !self.is_video())
+                else {
+                    return (Effect::None, Task::none());
+                };
+                let fps = self.sequence_fps;
+                self.start_loading();
+                let task = Task::perform(
+                    async move {
+                        ImageSequence::detect(&path, fps).map(crate::media::load_image_sequence)
+                    },
+                    Message::ImageSequenceLoaded,
+                );
+                (Effect::None, task)
+            }
+            Message::ToggleFaceOverlay => {
+                self.face_overlay = !self.face_overlay;
+                self.detect_overlay_faces()
+            }
+            Message::DetectOverlayFaces => self.detect_overlay_faces(),
+            Message::FacesDetected { path, result } => self.handle_faces_detected(&path, result),
+            Message::SubtitlesLoaded { path, tracks } => {
+                // Ignore results for a video that is no longer displayed
+                if self.current_media_path.as_ref() == Some(&path) {
+                    self.subtitle_track = (!tracks.is_empty()).then_some(0);
+                    self.subtitle_tracks = tracks;
+                }
+                (Effect::None, Task::none())
+            }
+            Message::SeekThumbnailReady {
+                path,
+                second,
+                thumbnail,
+            } => {
+                // Ignore results for a video that is no longer displayed
+                if self.current_video_path.as_ref() != Some(&path) {
+                    return (Effect::None, Task::none());
+                }
+                self.seek_thumbnails.finish(second, thumbnail);
+                // The cursor may have moved on while this frame was decoded
+                (Effect::None, self.request_seek_thumbnail())
+            }
+            Message::PreviewLoaded { path, image } => {
+                // Ignore previews that arrive after the full decode or for
+                // media the user has already navigated away from
+                if self.is_loading_media && self.current_media_path.as_ref() == Some(&path) {
+                    self.loading_preview = Some(image);
+                }
+                (Effect::None, Task::none())
+            }
+            Message::WaveformReady { path, waveform } => {
+                // Ignore results for a video that is no longer displayed
+                if self.current_video_path.as_ref() == Some(&path) {
+                    self.waveform = waveform;
+                }
+                (Effect::None, Task::none())
+            }
+            Message::ClipExport(status) => self.handle_clip_export_status(status),
+            Message::FramesExport(status) => self.handle_frames_export_status(status),
+            Message::ImageSequenceLoaded(Some(result)) => {
+                self.handle_message(Message::MediaLoaded(result), &I18n::default())
+            }
+            Message::ImageSequenceLoaded(None) => {
+                self.is_loading_media = false;
+                self.loading_started_at = None;
+                (
+                    Effect::ShowErrorNotification {
+                        key: "notification-image-sequence-not-found",
+                        args: Vec::new(),
+                    },
+                    Task::none(),
+                )
+            }
+            Message::TogglePlayback => {
+                if self.has_active_video_session() {
+                    self.handle_message(
+                        Message::VideoControls(video_controls::Message::TogglePlayback),
+                        &I18n::default(),
+                    )
+                } else if matches!(self.media, Some(MediaData::Video(_))) {
+                    // Video loaded but not playing yet - initiate playback
+                    self.handle_message(Message::InitiatePlayback, &I18n::default())
+                } else {
+                    (Effect::None, Task::none())
+                }
+            }
             Message::InitiatePlayback => {
                 // Reset overlay timer on interaction
                 self.last_overlay_interaction = Some(Instant::now());
@@ -960,7 +2289,7 @@ impl State {
                             // No need to sync shader scale - pane calculates display size at render time
                         }
                         Err(e) => {
-                            eprintln!("Failed to create video player: {e}");
+                            tracing::error!("Failed to create video player: {e}");
                         }
                     }
                 }
@@ -1009,17 +2338,47 @@ impl State {
                                     // No need to sync shader scale - pane calculates display size at render time
                                 }
                                 Err(e) => {
-                                    eprintln!("Failed to create video player: {e}");
+                                    tracing::error!("Failed to create video player: {e}");
                                 }
                             }
                         }
                     }
-                    VM::SeekPreview(position) => {
+                    VM::SeekPreview(slider_secs) => {
                         // Just update the preview position for visual feedback
                         // Don't actually seek until release
+                        // Holding Shift slows the drag down for precise positioning
+                        let position = match (self.fine_step(), &self.video_player) {
+                            (Some(step), Some(player)) => {
+                                let video = player.video_data();
+                                let current = self
+                                    .seek_preview_position
+                                    .or_else(|| player.state().position())
+                                    .unwrap_or(slider_secs);
+                                let scrub = *self
+                                    .fine_scrub
+                                    .get_or_insert_with(|| FineScrub::new(slider_secs, current));
+                                scrub.position(
+                                    slider_secs,
+                                    step.secs(video.fps),
+                                    video.duration_secs,
+                                )
+                            }
+                            _ => {
+                                self.fine_scrub = None;
+                                slider_secs
+                            }
+                        };
                         self.seek_preview_position = Some(position);
                     }
+                    VM::SeekHover(position) => {
+                        self.seek_hover_secs = Some(position);
+                        return (Effect::None, self.request_seek_thumbnail());
+                    }
+                    VM::SeekHoverEnd => {
+                        self.seek_hover_secs = None;
+                    }
                     VM::SeekCommit => {
+                        self.fine_scrub = None;
                         // Perform actual seek to preview position
                         // Don't clear seek_preview_position here - it will be cleared
                         // when we receive a frame near the seek target
@@ -1072,6 +2431,9 @@ impl State {
                     VM::SetVolume(volume) => {
                         // Volume type guarantees valid range, no clamp needed
                         self.video_volume = volume.value();
+                        if !volume.is_boosted() {
+                            self.base_video_volume = volume.value();
+                        }
                         // Apply to audio output
                         if let Some(player) = &self.video_player {
                             player.set_volume(volume);
@@ -1087,11 +2449,84 @@ impl State {
                         return (Effect::PersistPreferences, Task::none());
                     }
                     VM::ToggleLoop => {
-                        self.video_loop = !self.video_loop;
+                        let animated = self
+                            .video_player
+                            .as_ref()
+                            .is_some_and(|player| player.video_data().animated_image);
+                        if animated {
+                            self.animation_loop = !self.animation_loop;
+                        } else {
+                            self.video_loop = !self.video_loop;
+                        }
+                        let loop_enabled = self.loop_enabled();
                         if let Some(player) = &mut self.video_player {
-                            player.set_loop(self.video_loop);
+                            player.set_loop(loop_enabled);
+                        }
+                        let effect = if animated {
+                            Effect::None
+                        } else {
+                            Effect::PersistPreferences
+                        };
+                        return (effect, Task::none());
+                    }
+                    VM::SetLoopIn => {
+                        if let Some(player) = &mut self.video_player {
+                            player.set_loop_in();
+                        }
+                    }
+                    VM::SetLoopOut => {
+                        if let Some(player) = &mut self.video_player {
+                            player.set_loop_out();
+                        }
+                    }
+                    VM::ClearLoopRegion => {
+                        if let Some(player) = &mut self.video_player {
+                            player.clear_loop_region();
+                        }
+                    }
+                    VM::ExportClip => {
+                        if let Some(effect) = self.start_clip_export() {
+                            return (effect, Task::none());
+                        }
+                    }
+                    VM::CancelClipExport => {
+                        if let Some((cancel, _)) = &self.clip_export {
+                            cancel.cancel();
+                        }
+                    }
+                    VM::ToggleFramesExport => {
+                        self.frames_export.open = !self.frames_export.open;
+                    }
+                    VM::SetFramesFormat(format) => {
+                        self.frames_export.options.format = format;
+                    }
+                    VM::SetFramesFps(fps) => {
+                        self.frames_export.options.fps = fps;
+                    }
+                    VM::SetFramesScale(scale_percent) => {
+                        self.frames_export.options.scale_percent = scale_percent;
+                    }
+                    VM::SetFramesWindow(window_secs) => {
+                        self.frames_export.window_secs = window_secs;
+                    }
+                    VM::SetFramesMode(mode) => {
+                        self.frames_export.mode = mode;
+                    }
+                    VM::SetBurstCount(count) => {
+                        self.frames_export.burst.count = count;
+                    }
+                    VM::SetBurstInterval(interval_ms) => {
+                        self.frames_export.burst.interval_ms = interval_ms;
+                    }
+                    VM::ExportFrames => {
+                        if let Some(effect) = self.start_frames_export() {
+                            return (effect, Task::none());
+                        }
+                    }
+                    VM::CancelFramesExport => {
+                        if let Some(cancel) = &self.frames_export_cancel {
+                            cancel.cancel();
                         }
-                        return (Effect::PersistPreferences, Task::none());
                     }
                     VM::CaptureFrame => {
                         // Pause the video if playing
@@ -1109,11 +2544,25 @@ impl State {
                                     .as_ref()
                                     .and_then(|p| p.state().position())
                                     .unwrap_or(0.0);
+                                // Image sequences and animated images are
+                                // already shown as decoded
+                                let full_decode = match self.media {
+                                    Some(MediaData::Video(ref video))
+                                        if video.sequence.is_none() && !video.animated_image =>
+                                    {
+                                        Some(crate::media::frame_export::FrameOrientation {
+                                            rotation_degrees: self.display_rotation().degrees(),
+                                            mirrored: self.video_mirrored,
+                                        })
+                                    }
+                                    _ => None,
+                                };
                                 return (
                                     Effect::CaptureFrame {
                                         frame,
                                         video_path: video_path.clone(),
                                         position_secs,
+                                        full_decode,
                                     },
                                     Task::none(),
                                 );
@@ -1145,6 +2594,82 @@ impl State {
                     VM::ToggleOverflowMenu => {
                         self.overflow_menu_open = !self.overflow_menu_open;
                     }
+                    VM::NextChapter | VM::PreviousChapter => {
+                        let forward = matches!(video_msg, VM::NextChapter);
+                        let target = self.video_chapters().and_then(|chapters| {
+                            let position = self.playback_position()?;
+                            if forward {
+                                crate::media::chapters::next_chapter_start(chapters, position)
+                            } else {
+                                crate::media::chapters::previous_chapter_start(chapters, position)
+                            }
+                        });
+                        if let Some(target_secs) = target {
+                            self.seek_to(target_secs);
+                        }
+                    }
+                    VM::ToggleChapterList => {
+                        self.chapter_list_open = !self.chapter_list_open;
+                    }
+                    VM::SeekToChapter(index) => {
+                        let target = self
+                            .video_chapters()
+                            .and_then(|chapters| chapters.get(index))
+                            .map(|chapter| chapter.start_secs);
+                        if let Some(target_secs) = target {
+                            self.seek_to(target_secs);
+                        }
+                    }
+                    VM::SelectSubtitleTrack(track) => {
+                        self.subtitle_track = track.filter(|i| *i < self.subtitle_tracks.len());
+                    }
+                    VM::AdjustSubtitleDelay(delta_ms) => {
+                        let max = crate::config::MAX_SUBTITLE_DELAY_MS;
+                        self.subtitle_delay_ms = self
+                            .subtitle_delay_ms
+                            .saturating_add(delta_ms)
+                            .clamp(-max, max);
+                    }
+                    VM::ResetSubtitleDelay => {
+                        self.subtitle_delay_ms = 0;
+                    }
+                    VM::CycleSubtitleSize => {
+                        self.subtitle_size = self.subtitle_size.next();
+                        return (Effect::PersistPreferences, Task::none());
+                    }
+                    VM::ToggleSubtitleBackground => {
+                        self.subtitle_background = !self.subtitle_background;
+                        return (Effect::PersistPreferences, Task::none());
+                    }
+                    VM::ResumePlayback => {
+                        if let (Some(position), Some(player)) =
+                            (self.resume_offer.take(), &mut self.video_player)
+                        {
+                            self.seek_preview_position = None;
+                            player.seek(position);
+                        }
+                    }
+                    VM::DismissResume => {
+                        self.resume_offer = None;
+                    }
+                    VM::OpenMiniPlayer => {
+                        if self.video_player.is_some() {
+                            return (Effect::OpenMiniPlayer, Task::none());
+                        }
+                    }
+                    VM::ToggleColorAdjustments => {
+                        self.color_adjustments_open = !self.color_adjustments_open;
+                    }
+                    VM::SetColorAdjustments(adjustments) => {
+                        self.color_adjustments = adjustments;
+                    }
+                    VM::ResetColorAdjustments => {
+                        self.color_adjustments = ColorAdjustments::default();
+                    }
+                    VM::CycleAudioVisualization => {
+                        self.audio_visualization = self.audio_visualization.next();
+                        return (Effect::PersistPreferences, self.request_waveform());
+                    }
                     VM::IncreasePlaybackSpeed => {
                         if let Some(player) = &mut self.video_player {
                             player.increase_playback_speed();
@@ -1161,6 +2686,14 @@ impl State {
                             player.set_muted(effective_muted);
                         }
                     }
+                    VM::SetPlaybackSpeed(speed) => {
+                        if let Some(player) = &mut self.video_player {
+                            player.set_playback_speed(PlaybackSpeed::new(speed));
+                            // Apply effective mute: user mute OR speed auto-mute
+                            let effective_muted = self.video_muted || player.is_speed_auto_muted();
+                            player.set_muted(effective_muted);
+                        }
+                    }
                 }
                 (Effect::None, Task::none())
             }
@@ -1168,27 +2701,35 @@ impl State {
                 match event {
                     PlaybackMessage::Started(command_sender) => {
                         // Store the command sender in the player for pause/play/seek
+                        let loop_enabled = self.loop_enabled();
                         if let Some(ref mut player) = self.video_player {
                             player.set_command_sender(command_sender);
 
                             // Apply current volume, mute, and loop state
                             player.set_volume(Volume::new(self.video_volume));
                             player.set_muted(self.video_muted);
-                            player.set_loop(self.video_loop);
+                            player.set_loop(loop_enabled);
 
                             // Load the first frame immediately so capture and step work
                             // without requiring play+pause first.
-                            // This seeks to 0 and decodes the first frame without starting playback.
+                            // This seeks to 0 (or the remembered position) and decodes the
+                            // first frame without starting playback.
                             if matches!(player.state(), crate::video_player::PlaybackState::Stopped)
                             {
-                                player.seek(0.0);
+                                player.seek(self.resume_at.take().unwrap_or(0.0));
                             }
 
-                            // Auto-play if enabled
-                            if self.video_autoplay {
+                            // Auto-play if enabled; image sequences were explicitly
+                            // requested for playback and animated images play like
+                            // in any other viewer, so they always start playing
+                            if self.video_autoplay
+                                || player.video_data().is_frame_based()
+                                || std::mem::take(&mut self.play_on_start)
+                            {
                                 player.play();
                             }
                         }
+                        return (Effect::None, self.request_waveform());
                     }
                     PlaybackMessage::FrameReady {
                         rgba_data,
@@ -1210,9 +2751,13 @@ impl State {
                             }
                         }
 
-                        // Update player position
+                        // Update player position, jumping back to the loop-in
+                        // point once an A–B region's end is passed
                         if let Some(ref mut player) = self.video_player {
                             player.update_position(pts_secs);
+                            if player.enforce_loop_region() {
+                                self.seek_preview_position = None;
+                            }
                         }
 
                         // Clear seek preview if we received a frame near the seek target
@@ -1240,11 +2785,17 @@ impl State {
                     }
                     PlaybackMessage::EndOfStream => {
                         // Handle end of stream
+                        let loop_enabled = self.loop_enabled();
                         if let Some(ref mut player) = self.video_player {
                             // Mark that we've reached the end (for step forward button)
                             player.set_at_end_of_stream();
 
-                            if self.video_loop {
+                            if let Some((loop_start, _)) = player.loop_region() {
+                                // An A–B region ending at the end of the video
+                                // loops regardless of the loop toggle
+                                self.seek_preview_position = None;
+                                player.seek_and_play(loop_start);
+                            } else if loop_enabled {
                                 // Restart playback from beginning
                                 // Clear seek preview so step operations use actual position
                                 self.seek_preview_position = None;
@@ -1288,10 +2839,17 @@ impl State {
                             player.reset_history_position();
                         }
                     }
+                    PlaybackMessage::DecodePath(path) => {
+                        self.decode_path = Some(path);
+                    }
+                    PlaybackMessage::CacheStats(stats) => {
+                        self.cache_stats = Some(stats);
+                    }
                 }
 
                 (Effect::None, Task::none())
             }
+            Message::QuickSearch(msg) => self.handle_quick_search_message(msg),
             Message::FilterDropdown(msg) => {
                 use filter_dropdown::Message as FdMsg;
                 match msg {
@@ -1385,6 +2943,9 @@ impl State {
             None
         };
 
+        let alpha_line =
+            format_alpha_preview_indicator(env.i18n, self.matte, self.alpha_interpretation);
+
         let media_type_line = self
             .media
             .as_ref()
@@ -1394,17 +2955,21 @@ impl State {
             .into_iter()
             .chain(zoom_line)
             .chain(rotation_line)
+            .chain(alpha_line)
             .chain(media_type_line)
             .collect::<Vec<HudLine>>();
 
-        // In fullscreen, overlay auto-hides after delay
+        // In fullscreen and compact mode, overlay auto-hides after delay unless
+        // the accessibility option keeps it visible
         // In windowed mode, controls stay visible but center overlay (pause button) can hide
-        let overlay_should_be_visible = if env.is_fullscreen {
-            self.last_overlay_interaction
-                .is_some_and(|t| t.elapsed() < env.overlay_hide_delay)
-        } else {
-            true
-        };
+        let overlay_controls = env.is_fullscreen || env.compact;
+        let overlay_should_be_visible =
+            if overlay_controls && !accessibility::current().always_show_fullscreen_controls {
+                self.last_overlay_interaction
+                    .is_some_and(|t| t.elapsed() < env.overlay_hide_delay)
+            } else {
+                true
+            };
 
         // For center video overlay (play/pause button), use auto-hide in both modes when playing
         let is_currently_playing = self.video_player.is_some()
@@ -1434,11 +2999,17 @@ impl State {
                 i18n: env.i18n,
                 metadata_editor_has_changes: env.metadata_editor_has_changes,
                 is_video: self.is_video(),
+                is_mirrored: self.video_mirrored,
+                is_view_locked: self.view_locked,
+                is_inspecting: self.inspector,
+                is_measuring: self.ruler,
+                is_showing_guides: self.guides_visible,
             },
             zoom: &self.zoom,
             effective_fit_to_window,
             pane_context: pane::ViewContext {
-                background_theme: env.background_theme,
+                background_theme: env.background_theme.resolve(self.media_luminance),
+                matte: self.matte,
                 hud_lines,
                 scrollable_id: SCROLLABLE_ID,
                 i18n: env.i18n,
@@ -1452,7 +3023,9 @@ impl State {
                 cursor_over_media: geometry_state.is_cursor_over_media(),
                 arrows_visible: if env.is_fullscreen {
                     // In fullscreen, arrows use same auto-hide logic as controls
+                    // and can be turned off in the settings
                     self.arrows_visible
+                        && env.fullscreen_overlay.arrows
                         && env.navigation.total_count > 0
                         && overlay_should_be_visible
                 } else {
@@ -1466,47 +3039,78 @@ impl State {
                 at_last: env.navigation.at_last,
                 current_index: env.navigation.current_index,
                 total_count: env.navigation.total_count,
-                position_counter_visible: if env.is_fullscreen {
-                    // In fullscreen, use same auto-hide logic as arrows and controls
-                    env.navigation.total_count > 0 && overlay_should_be_visible
-                } else {
-                    // In windowed mode, always visible
-                    true
-                },
-                hud_visible: if env.is_fullscreen {
-                    // In fullscreen, auto-hide HUD with other overlay elements
-                    overlay_should_be_visible
-                } else {
-                    // In windowed mode, always visible
-                    true
-                },
+                scan_in_progress: env.navigation.scan_in_progress,
+                // In fullscreen, the configurable overlay replaces the counter
+                // and uses the same auto-hide logic as arrows and controls
+                position_counter_visible: !env.is_fullscreen,
+                fullscreen_overlay: (env.is_fullscreen && overlay_should_be_visible).then(|| {
+                    fullscreen_overlay::Info {
+                        options: env.fullscreen_overlay,
+                        filename: self
+                            .current_media_path
+                            .as_deref()
+                            .and_then(std::path::Path::file_name)
+                            .map(|name| name.to_string_lossy().into_owned()),
+                        current_index: env.navigation.current_index,
+                        total_count: env.navigation.total_count,
+                        scan_in_progress: env.navigation.scan_in_progress,
+                        histogram: self.media_histogram.as_deref(),
+                    }
+                }),
+                hud_visible: self.osd_visible
+                    && if env.is_fullscreen {
+                        // In fullscreen, auto-hide HUD with other overlay elements
+                        overlay_should_be_visible
+                    } else {
+                        // In windowed mode, always visible
+                        true
+                    },
                 video_shader: Some(&self.video_shader),
                 // Use is_playing_or_will_resume() to include Seeking state
                 // This prevents the play button from flashing during seek operations
                 is_video_playing: self.is_video_playing_or_will_resume(),
                 is_loading_media: self.is_loading_media,
                 spinner_rotation: self.spinner_rotation,
+                loading_preview: self.loading_preview.as_ref(),
                 video_error: self
                     .video_player
                     .as_ref()
                     .and_then(|p| p.state().error_message()),
                 metadata_editor_has_changes: env.metadata_editor_has_changes,
-                rotation: self.current_rotation,
-                rotated_image_cache: self.rotated_image_cache(),
+                rotation: self.display_rotation(),
+                mirrored: self.video_mirrored,
+                color_adjustments: self.color_adjustments,
+                tone_map: self.tone_map_params(),
+                display_image_cache: self.display_image_cache(),
+                subtitle: self
+                    .current_subtitle_text()
+                    .map(|text| pane::SubtitleOverlay {
+                        text,
+                        size: self.subtitle_size.font_size(),
+                        background: self.subtitle_background,
+                    }),
+                faces: self.faces.as_deref().filter(|_| self.face_overlay),
+                minimap_offset: self.show_minimap.then_some(self.viewport.offset),
+                pixel_sample: self.inspected_pixel(),
+                ruler: self.ruler_overlay(env.dots_per_inch),
+                guides: self.guides_visible.then_some(env.guides),
             },
-            controls_visible: if env.is_fullscreen {
-                // In fullscreen, auto-hide controls after configured delay
+            controls_visible: if overlay_controls {
+                // In fullscreen and compact mode, auto-hide controls after
+                // configured delay
                 overlay_should_be_visible
             } else {
                 // In windowed mode, always show controls
                 true
             },
             is_fullscreen: env.is_fullscreen,
+            overlay_controls,
             is_video: self.is_video(),
             video_playback_state: self.media.as_ref().and_then(|media| {
                 // Build PlaybackState for video controls
                 // Show controls for any video, not just when VideoPlayer exists
                 if let MediaData::Video(ref video_data) = media {
+                    let loop_enabled = self.loop_enabled();
                     let (
                         is_playing,
                         position_secs,
@@ -1526,7 +3130,7 @@ impl State {
                             | crate::video_player::PlaybackState::Buffering { position_secs } => (
                                 true,
                                 *position_secs,
-                                self.video_loop,
+                                loop_enabled,
                                 false,
                                 false,
                                 speed,
@@ -1535,13 +3139,13 @@ impl State {
                             crate::video_player::PlaybackState::Paused { position_secs } => (
                                 false,
                                 *position_secs,
-                                self.video_loop,
+                                loop_enabled,
                                 can_step_back,
                                 can_step_fwd,
                                 speed,
                                 auto_muted,
                             ),
-                            _ => (false, 0.0, self.video_loop, false, false, 1.0, false),
+                            _ => (false, 0.0, loop_enabled, false, false, 1.0, false),
                         }
                     } else {
                         // No player yet - show initial state (paused at 0)
@@ -1556,12 +3160,57 @@ impl State {
                         muted: self.video_muted,
                         loop_enabled,
                         seek_preview_position: self.seek_preview_position,
+                        fine_step: self.fine_step(),
                         overflow_menu_open: self.overflow_menu_open,
                         can_step_backward,
                         can_step_forward,
                         playback_speed,
                         speed_auto_muted,
                         has_audio: video_data.has_audio,
+                        subtitles: video_controls::SubtitleControls {
+                            tracks: self
+                                .subtitle_tracks
+                                .iter()
+                                .map(|track| track.label.clone())
+                                .collect(),
+                            selected: self.subtitle_track,
+                            delay_ms: self.subtitle_delay_ms,
+                            size: self.subtitle_size,
+                            background: self.subtitle_background,
+                        },
+                        chapters: video_data.chapters.clone(),
+                        chapter_list_open: self.chapter_list_open,
+                        loop_region: self
+                            .video_player
+                            .as_ref()
+                            .and_then(VideoPlayer::loop_region),
+                        clip_export_progress: self
+                            .clip_export
+                            .as_ref()
+                            .map(|(_, progress)| *progress),
+                        frames_export: self.frames_export,
+                        decode_path: self.decode_path,
+                        cache_stats: self.cache_stats,
+                        seek_hover: self.seek_hover_secs.map(|position_secs| {
+                            video_controls::SeekHoverPreview {
+                                position_secs,
+                                thumbnail: self
+                                    .seek_thumbnails
+                                    .get(seek_thumbnails::second_of(position_secs))
+                                    .cloned(),
+                            }
+                        }),
+                        audio_visualization: self.audio_visualization,
+                        audio_strip: self.audio_strip(video_data.has_audio),
+                        resume_offer: self.resume_offer,
+                        color_adjustments_open: self.color_adjustments_open,
+                        color_adjustments: self.color_adjustments,
+                        frame_counter: video_data.is_frame_based().then(|| {
+                            (
+                                video_data.frame_number_at(position_secs),
+                                video_data.frame_count(),
+                            )
+                        }),
                     })
                 } else {
                     None
@@ -1575,6 +3224,7 @@ impl State {
             image,
             is_loading: self.is_loading_media,
             spinner_rotation: self.spinner_rotation,
+            loading_preview: self.loading_preview.as_ref(),
         })
     }
 
@@ -1615,22 +3265,14 @@ impl State {
                 (Effect::PersistPreferences, Task::none())
             }
             ZoomIn => {
-                self.zoom
-                    .apply_manual_zoom(self.zoom.zoom_percent + self.zoom.zoom_step.value());
-                // Also disable video fit-to-window when zooming on a video
-                if self.is_video() {
-                    self.video_fit_to_window = false;
-                }
-                (Effect::PersistPreferences, Task::none())
+                let task =
+                    self.zoom_towards_cursor(self.zoom.zoom_percent + self.zoom.zoom_step.value());
+                (Effect::PersistPreferences, task)
             }
             ZoomOut => {
-                self.zoom
-                    .apply_manual_zoom(self.zoom.zoom_percent - self.zoom.zoom_step.value());
-                // Also disable video fit-to-window when zooming on a video
-                if self.is_video() {
-                    self.video_fit_to_window = false;
-                }
-                (Effect::PersistPreferences, Task::none())
+                let task =
+                    self.zoom_towards_cursor(self.zoom.zoom_percent - self.zoom.zoom_step.value());
+                (Effect::PersistPreferences, task)
             }
             SetFitToWindow(fit) => {
                 // For videos, use video_fit_to_window (not persisted)
@@ -1668,7 +3310,418 @@ impl State {
                 self.rotate_counterclockwise();
                 (Effect::None, Task::none())
             }
+            ToggleMirror => {
+                self.toggle_mirror();
+                (Effect::None, Task::none())
+            }
+            ToggleInspector => {
+                self.inspector = !self.inspector;
+                (Effect::None, Task::none())
+            }
+            ToggleRuler => {
+                self.ruler = !self.ruler;
+                self.pending_measurement = None;
+                (Effect::None, Task::none())
+            }
+            ToggleGuides => {
+                self.guides_visible = !self.guides_visible;
+                (Effect::None, Task::none())
+            }
+            ToggleViewLock => {
+                self.view_locked = !self.view_locked;
+                // Fitting would change the zoom from one image to the next
+                if self.view_locked && self.fit_to_window() {
+                    self.disable_fit_to_window();
+                }
+                (Effect::None, Task::none())
+            }
+            ExportVisibleArea => match (self.visible_area(), &self.current_media_path) {
+                (Some(frame), Some(path)) => (
+                    Effect::ExportVisibleArea {
+                        frame,
+                        source_path: path.clone(),
+                    },
+                    Task::none(),
+                ),
+                _ => (Effect::None, Task::none()),
+            },
+        }
+    }
+
+    fn handle_quick_search_message(
+        &mut self,
+        message: quick_search::Message,
+    ) -> (Effect, Task<Message>) {
+        match message {
+            quick_search::Message::QueryChanged(query) => {
+                self.quick_search.set_query(query);
+                (Effect::None, Task::none())
+            }
+            quick_search::Message::Activate(index) => {
+                self.quick_search.select(index);
+                self.jump_to_quick_search_selection()
+            }
+            quick_search::Message::Submit => self.jump_to_quick_search_selection(),
+            quick_search::Message::Close => {
+                self.quick_search.close();
+                (Effect::None, Task::none())
+            }
+            quick_search::Message::ConsumeClick => (Effect::None, Task::none()),
+        }
+    }
+
+    /// Closes the quick-search overlay and jumps to the selected result.
+    fn jump_to_quick_search_selection(&mut self) -> (Effect, Task<Message>) {
+        let Some(path) = self.quick_search.selected_path().map(PathBuf::from) else {
+            return (Effect::None, Task::none());
+        };
+        self.quick_search.close();
+        (Effect::JumpToMedia(path), Task::none())
+    }
+
+    /// Handles keys while the quick-search overlay is open, plus `Ctrl+F` and `F3`.
+    ///
+    /// While open, the overlay swallows every key it doesn't use so viewer
+    /// shortcuts don't fire when the search input loses focus.
+    fn handle_quick_search_key(
+        &mut self,
+        key: &keyboard::Key,
+        shortcut: Option<char>,
+        modifiers: keyboard::Modifiers,
+    ) -> (Effect, Task<Message>) {
+        use keyboard::key::Named;
+
+        match key {
+            _ if shortcut == Some('f') && modifiers.command() => {
+                if self.quick_search.is_open {
+                    (
+                        Effect::None,
+                        operation::focus(Id::new(quick_search::INPUT_ID)),
+                    )
+                } else {
+                    (Effect::OpenQuickSearch, Task::none())
+                }
+            }
+            keyboard::Key::Named(Named::F3) => {
+                if !self.quick_search.has_matches() {
+                    return (Effect::None, Task::none());
+                }
+                if modifiers.shift() {
+                    self.quick_search.select_previous();
+                } else {
+                    self.quick_search.select_next();
+                }
+                if self.quick_search.is_open {
+                    (Effect::None, Task::none())
+                } else {
+                    // Cycling with the overlay closed jumps straight to the match
+                    let path = self.quick_search.selected_path().map(PathBuf::from);
+                    path.map_or((Effect::None, Task::none()), |path| {
+                        (Effect::JumpToMedia(path), Task::none())
+                    })
+                }
+            }
+            keyboard::Key::Named(Named::ArrowDown) => {
+                self.quick_search.select_next();
+                (Effect::None, Task::none())
+            }
+            keyboard::Key::Named(Named::ArrowUp) => {
+                self.quick_search.select_previous();
+                (Effect::None, Task::none())
+            }
+            keyboard::Key::Named(Named::Enter) => self.jump_to_quick_search_selection(),
+            keyboard::Key::Named(Named::Escape) => {
+                self.quick_search.close();
+                (Effect::None, Task::none())
+            }
+            _ => (Effect::None, Task::none()),
+        }
+    }
+
+    /// Forgets the faces detected in the previous image.
+    fn reset_faces(&mut self) {
+        self.faces = None;
+        self.face_index = 0;
+        self.is_detecting_faces = false;
+        self.zoom_to_detected_face = false;
+    }
+
+    /// Starts face detection for the overlay if it is shown and the current
+    /// image has not been analyzed yet.
+    fn detect_overlay_faces(&mut self) -> (Effect, Task<Message>) {
+        if !self.face_overlay
+            || !self.is_current_media_image()
+            || self.is_detecting_faces
+            || self.faces.is_some()
+        {
+            return (Effect::None, Task::none());
         }
+        self.is_detecting_faces = true;
+        (Effect::DetectFaces, Task::none())
+    }
+
+    /// Zooms to the next/previous face, detecting faces first if needed.
+    fn cycle_faces(&mut self, direction: NavigationDirection) -> (Effect, Task<Message>) {
+        if !self.is_current_media_image() {
+            return (Effect::None, Task::none());
+        }
+        if self.is_detecting_faces {
+            // Detection started by the overlay: zoom once it completes
+            self.zoom_to_detected_face = true;
+            return (Effect::None, Task::none());
+        }
+        let Some(count) = self.faces.as_ref().map(Vec::len) else {
+            self.is_detecting_faces = true;
+            self.zoom_to_detected_face = true;
+            return (Effect::DetectFaces, Task::none());
+        };
+        if count == 0 {
+            return (
+                Effect::ShowErrorNotification {
+                    key: "notification-no-faces-found",
+                    args: Vec::new(),
+                },
+                Task::none(),
+            );
+        }
+        self.face_index = match direction {
+            NavigationDirection::Next => (self.face_index + 1) % count,
+            NavigationDirection::Previous => (self.face_index + count - 1) % count,
+        };
+        (Effect::PersistPreferences, self.zoom_to_face())
+    }
+
+    /// Stores detection results and zooms to the first face if `F` asked for it.
+    fn handle_faces_detected(
+        &mut self,
+        path: &std::path::Path,
+        result: Result<Vec<FaceBox>, String>,
+    ) -> (Effect, Task<Message>) {
+        // Ignore results for an image the user already navigated away from
+        if self.current_media_path.as_deref() != Some(path) {
+            return (Effect::None, Task::none());
+        }
+        self.is_detecting_faces = false;
+        let zoom = std::mem::take(&mut self.zoom_to_detected_face);
+        match result {
+            // The overlay just shows nothing on images without faces
+            Ok(faces) if faces.is_empty() && zoom => {
+                self.faces = Some(faces);
+                (
+                    Effect::ShowErrorNotification {
+                        key: "notification-no-faces-found",
+                        args: Vec::new(),
+                    },
+                    Task::none(),
+                )
+            }
+            Ok(faces) => {
+                let has_faces = !faces.is_empty();
+                self.faces = Some(faces);
+                self.face_index = 0;
+                if zoom && has_faces {
+                    (Effect::PersistPreferences, self.zoom_to_face())
+                } else {
+                    (Effect::None, Task::none())
+                }
+            }
+            Err(error) => {
+                // Don't retry on every image while the model is unavailable
+                self.face_overlay = false;
+                (
+                    Effect::ShowErrorNotification {
+                        key: "notification-face-detection-error",
+                        args: vec![("error", error)],
+                    },
+                    Task::none(),
+                )
+            }
+        }
+    }
+
+    /// Starts exporting the A–B loop region of the current video, unless an
+    /// export is already running. Image sequences can't be exported as clips.
+    fn start_clip_export(&mut self) -> Option<Effect> {
+        if self.clip_export.is_some() {
+            return None;
+        }
+        let Some(MediaData::Video(video_data)) = &self.media else {
+            return None;
+        };
+        if video_data.sequence.is_some() {
+            return None;
+        }
+        let (start_secs, end_secs) = self.video_player.as_ref()?.loop_region()?;
+        let video_path = self.current_video_path.clone()?;
+
+        let cancel = CancelFlag::default();
+        self.clip_export = Some((cancel.clone(), 0.0));
+        Some(Effect::ExportClip {
+            video_path,
+            start_secs,
+            end_secs,
+            cancel,
+        })
+    }
+
+    /// Tracks the progress of the running clip export and reports its result.
+    fn handle_clip_export_status(&mut self, status: ClipExportStatus) -> (Effect, Task<Message>) {
+        let effect = match status {
+            ClipExportStatus::Progress(progress) => {
+                if let Some((_, current)) = &mut self.clip_export {
+                    *current = progress;
+                }
+                return (Effect::None, Task::none());
+            }
+            ClipExportStatus::Finished { path, method } => Effect::ClipExported { path, method },
+            ClipExportStatus::Cancelled => Effect::None,
+            ClipExportStatus::Failed(message) => Effect::ShowErrorNotification {
+                key: "notification-clip-export-failed",
+                args: vec![("message", message)],
+            },
+        };
+        self.clip_export = None;
+        (effect, Task::none())
+    }
+
+    /// Starts exporting frames of the current video, unless an export is
+    /// already running. The A–B loop region is exported when set, otherwise
+    /// the panel's window centered on the playhead. A burst captures the
+    /// panel's number of frames centered on the playhead.
+    fn start_frames_export(&mut self) -> Option<Effect> {
+        if self.frames_export_cancel.is_some() {
+            return None;
+        }
+        let Some(MediaData::Video(video_data)) = &self.media else {
+            return None;
+        };
+        if video_data.sequence.is_some() {
+            return None;
+        }
+        let player = self.video_player.as_ref()?;
+        if self.frames_export.mode == video_controls::FramesExportMode::Burst {
+            let timestamps = self.frames_export.burst.timestamps(
+                player.state().position().unwrap_or(0.0),
+                video_data.duration_secs,
+            );
+            let video_path = self.current_video_path.clone()?;
+            let cancel = CancelFlag::default();
+            self.frames_export_cancel = Some(cancel.clone());
+            self.frames_export.progress = Some(0.0);
+            return Some(Effect::CaptureBurst {
+                video_path,
+                timestamps,
+                cancel,
+            });
+        }
+        let (start_secs, end_secs) = player.loop_region().unwrap_or_else(|| {
+            window_around(
+                player.state().position().unwrap_or(0.0),
+                video_data.duration_secs,
+                f64::from(self.frames_export.window_secs),
+            )
+        });
+        let video_path = self.current_video_path.clone()?;
+
+        let cancel = CancelFlag::default();
+        self.frames_export_cancel = Some(cancel.clone());
+        self.frames_export.progress = Some(0.0);
+        Some(Effect::ExportFrames {
+            video_path,
+            start_secs,
+            end_secs,
+            options: self.frames_export.options,
+            cancel,
+        })
+    }
+
+    /// Tracks the progress of the running frame range export and reports its result.
+    fn handle_frames_export_status(
+        &mut self,
+        status: FramesExportStatus,
+    ) -> (Effect, Task<Message>) {
+        let effect = match status {
+            FramesExportStatus::Progress(progress) => {
+                if self.frames_export_cancel.is_some() {
+                    self.frames_export.progress = Some(progress);
+                }
+                return (Effect::None, Task::none());
+            }
+            FramesExportStatus::Finished { path, frames } => {
+                self.frames_export.open = false;
+                Effect::FramesExported { path, frames }
+            }
+            FramesExportStatus::Cancelled => Effect::None,
+            FramesExportStatus::Failed(message) => Effect::ShowErrorNotification {
+                key: "notification-frames-export-failed",
+                args: vec![("message", message)],
+            },
+        };
+        self.frames_export_cancel = None;
+        self.frames_export.progress = None;
+        (effect, Task::none())
+    }
+
+    /// Zooms so the selected face fills part of the viewport and scrolls it
+    /// to the center, clamped to the image bounds.
+    // Allow cast_precision_loss: u32 to f32 for dimensions is exact up to 16M
+    #[allow(clippy::cast_precision_loss)]
+    fn zoom_to_face(&mut self) -> Task<Message> {
+        let (Some(MediaData::Image(image)), Some(face), Some(viewport)) = (
+            self.media.as_ref(),
+            self.faces
+                .as_ref()
+                .and_then(|faces| faces.get(self.face_index)),
+            self.viewport.bounds,
+        ) else {
+            return Task::none();
+        };
+
+        // Faces are detected on the unrotated image
+        let face = face.rotated(
+            self.current_rotation.degrees(),
+            image.width as f32,
+            image.height as f32,
+        );
+        let scale = (viewport.width * FACE_VIEWPORT_FILL / face.width)
+            .min(viewport.height * FACE_VIEWPORT_FILL / face.height);
+        self.zoom
+            .apply_manual_zoom(crate::ui::state::zoom::zoom_for_media_scale(scale));
+
+        let Some(size) = self
+            .geometry_state()
+            .scaled_media_size_rotated(self.current_rotation)
+        else {
+            return Task::none();
+        };
+        let scale = crate::ui::state::zoom::media_scale(self.zoom.zoom_percent);
+        let (center_x, center_y) = face.center();
+        let max_offset_x = (size.width - viewport.width).max(0.0);
+        let max_offset_y = (size.height - viewport.height).max(0.0);
+
+        self.viewport.offset = AbsoluteOffset {
+            x: (center_x * scale - viewport.width / 2.0).clamp(0.0, max_offset_x),
+            y: (center_y * scale - viewport.height / 2.0).clamp(0.0, max_offset_y),
+        };
+
+        let relative_x = if max_offset_x > 0.0 {
+            self.viewport.offset.x / max_offset_x
+        } else {
+            0.0
+        };
+        let relative_y = if max_offset_y > 0.0 {
+            self.viewport.offset.y / max_offset_y
+        } else {
+            0.0
+        };
+
+        operation::snap_to(
+            Id::new(SCROLLABLE_ID),
+            RelativeOffset {
+                x: relative_x,
+                y: relative_y,
+            },
+        )
     }
 
     #[allow(clippy::too_many_lines)] // Event handler for multiple event types
@@ -1683,13 +3736,16 @@ impl State {
                 (Effect::None, Task::none())
             }
             event::Event::Mouse(mouse_event) => match mouse_event {
-                mouse::Event::WheelScrolled { delta } => {
-                    let effect = if self.handle_wheel_zoom(delta) {
-                        Effect::PersistPreferences
-                    } else {
-                        Effect::None
-                    };
-                    (effect, Task::none())
+                mouse::Event::WheelScrolled { delta } => self.handle_wheel(delta),
+                mouse::Event::ButtonPressed(mouse::Button::Back)
+                    if self.mouse_mapping.navigation_buttons =>
+                {
+                    self.handle_message(Message::NavigatePrevious, &I18n::default())
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Forward)
+                    if self.mouse_mapping.navigation_buttons =>
+                {
+                    self.handle_message(Message::NavigateNext, &I18n::default())
                 }
                 mouse::Event::ButtonPressed(button) => {
                     let effect = if let Some(position) = self.cursor_position {
@@ -1741,6 +3797,14 @@ impl State {
                         }
                     }
 
+                    if self.pending_measurement.is_some() {
+                        if let Some(point) = self.measured_point() {
+                            if let Some(measurement) = &mut self.pending_measurement {
+                                measurement.end = point;
+                            }
+                        }
+                    }
+
                     if self.drag.is_dragging {
                         let task = self.handle_cursor_moved_during_drag(position);
                         (Effect::None, task)
@@ -1751,6 +3815,7 @@ impl State {
                 mouse::Event::CursorLeft => {
                     self.cursor_position = None;
                     self.arrows_visible = false;
+                    self.pending_measurement = None;
                     if self.drag.is_dragging {
                         self.drag.stop();
                     }
@@ -1758,251 +3823,506 @@ impl State {
                 }
                 mouse::Event::CursorEntered => (Effect::None, Task::none()),
             },
-            event::Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Named(keyboard::key::Named::F11),
-                    ..
-                } => {
-                    // Clear overlay timer and position when entering fullscreen to hide controls
-                    self.last_overlay_interaction = None;
-                    self.last_mouse_position = None;
-                    self.fullscreen_entered_at = Some(Instant::now());
-                    (Effect::ToggleFullscreen, Task::none())
-                }
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Named(keyboard::key::Named::Escape),
-                    ..
-                } => (Effect::ExitFullscreen, Task::none()),
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Named(keyboard::key::Named::Space),
-                    ..
-                } => {
-                    // Space: Toggle play/pause (video only)
-                    if self.has_active_video_session() {
-                        self.handle_message(
-                            Message::VideoControls(video_controls::Message::TogglePlayback),
-                            &I18n::default(),
-                        )
-                    } else if matches!(self.media, Some(MediaData::Video(_))) {
-                        // Video loaded but not playing yet - initiate playback
-                        self.handle_message(Message::InitiatePlayback, &I18n::default())
-                    } else {
-                        (Effect::None, Task::none())
+            event::Event::Keyboard(keyboard_event) => {
+                // Layout-independent character of the key, for letter and
+                // punctuation shortcuts
+                let shortcut = match &keyboard_event {
+                    keyboard::Event::KeyPressed {
+                        key,
+                        modified_key,
+                        physical_key,
+                        ..
+                    } => keyboard_layout::shortcut_char(key, modified_key, *physical_key),
+                    _ => None,
+                };
+                match keyboard_event {
+                    keyboard::Event::KeyPressed { key, modifiers, .. }
+                        if self.quick_search.is_open
+                            || is_quick_search_shortcut(&key, shortcut, modifiers) =>
+                    {
+                        self.handle_quick_search_key(&key, shortcut, modifiers)
                     }
-                }
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Named(keyboard::key::Named::ArrowRight),
-                    ..
-                } => {
-                    // ArrowRight: Seek forward if video is playing, otherwise navigate to next media
-                    // Uses is_playing_or_will_resume() to handle rapid key repeats during seek
-                    if self.is_video_playing_or_will_resume() {
-                        let step = self.keyboard_seek_step.value();
-                        self.handle_message(
-                            Message::VideoControls(video_controls::Message::SeekRelative(step)),
-                            &I18n::default(),
+                    keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::PageDown),
+                        modifiers,
+                        ..
+                    } if modifiers.alt() => {
+                        // Alt+PageDown: First media of the next capture day
+                        (
+                            Effect::NavigateCaptureDay(NavigationDirection::Next),
+                            Task::none(),
                         )
-                    } else {
-                        self.handle_message(Message::NavigateNext, &I18n::default())
                     }
-                }
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Named(keyboard::key::Named::ArrowLeft),
-                    ..
-                } => {
-                    // ArrowLeft: Seek backward if video is playing, otherwise navigate to previous media
-                    // Uses is_playing_or_will_resume() to handle rapid key repeats during seek
-                    if self.is_video_playing_or_will_resume() {
-                        let step = self.keyboard_seek_step.value();
-                        self.handle_message(
-                            Message::VideoControls(video_controls::Message::SeekRelative(-step)),
-                            &I18n::default(),
+                    keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::PageUp),
+                        modifiers,
+                        ..
+                    } if modifiers.alt() => {
+                        // Alt+PageUp: First media of the previous capture day
+                        (
+                            Effect::NavigateCaptureDay(NavigationDirection::Previous),
+                            Task::none(),
                         )
-                    } else {
-                        self.handle_message(Message::NavigatePrevious, &I18n::default())
                     }
-                }
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Named(keyboard::key::Named::ArrowUp),
-                    ..
-                } => {
-                    // ArrowUp: Increase volume (only during video playback)
-                    if self.has_active_video_session() {
-                        let new_volume = Volume::new(self.video_volume).increase();
-                        self.handle_message(
-                            Message::VideoControls(video_controls::Message::SetVolume(new_volume)),
-                            &I18n::default(),
-                        )
-                    } else {
-                        (Effect::None, Task::none())
+                    keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::PageDown),
+                        ..
+                    } => {
+                        // PageDown: Next chapter in a video with chapters
+                        if self.video_player.is_some() && self.video_chapters().is_some() {
+                            self.handle_message(
+                                Message::VideoControls(video_controls::Message::NextChapter),
+                                &I18n::default(),
+                            )
+                        } else {
+                            (Effect::None, Task::none())
+                        }
                     }
-                }
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Named(keyboard::key::Named::ArrowDown),
-                    ..
-                } => {
-                    // ArrowDown: Decrease volume (only during video playback)
-                    if self.has_active_video_session() {
-                        let new_volume = Volume::new(self.video_volume).decrease();
-                        self.handle_message(
-                            Message::VideoControls(video_controls::Message::SetVolume(new_volume)),
-                            &I18n::default(),
-                        )
-                    } else {
-                        (Effect::None, Task::none())
+                    keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::PageUp),
+                        ..
+                    } => {
+                        // PageUp: Previous chapter in a video with chapters
+                        if self.video_player.is_some() && self.video_chapters().is_some() {
+                            self.handle_message(
+                                Message::VideoControls(video_controls::Message::PreviousChapter),
+                                &I18n::default(),
+                            )
+                        } else {
+                            (Effect::None, Task::none())
+                        }
                     }
-                }
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Character(ref c),
-                    modifiers,
-                    ..
-                } if (c.as_str() == "m" || c.as_str() == "M")
-                    && !modifiers.command()
-                    && !modifiers.alt() =>
-                {
-                    // M key: Toggle mute (only during video playback)
-                    if self.has_active_video_session() {
-                        self.handle_message(
-                            Message::VideoControls(video_controls::Message::ToggleMute),
-                            &I18n::default(),
-                        )
-                    } else {
-                        (Effect::None, Task::none())
+                    keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::F11),
+                        ..
+                    } => {
+                        // Clear overlay timer and position when entering fullscreen to hide controls
+                        self.last_overlay_interaction = None;
+                        self.last_mouse_position = None;
+                        self.fullscreen_entered_at = Some(Instant::now());
+                        (Effect::ToggleFullscreen, Task::none())
                     }
-                }
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Character(ref c),
-                    modifiers,
-                    ..
-                } if c.as_str() == "e"
-                    && !modifiers.command()
-                    && !modifiers.alt()
-                    && !modifiers.shift() =>
-                {
-                    // E key: Enter edit mode (only if image is loaded and not a video)
-                    // Video editing is not supported in v0.2
-                    if self.current_media_path.is_some() && !self.is_video() {
-                        (Effect::EnterEditor, Task::none())
-                    } else {
-                        (Effect::None, Task::none())
+                    keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                        ..
+                    } => (Effect::ExitFullscreen, Task::none()),
+                    keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::Space),
+                        ..
+                    } => {
+                        // Space: Toggle play/pause (video only)
+                        self.handle_message(Message::TogglePlayback, &I18n::default())
                     }
-                }
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Character(ref c),
-                    modifiers,
-                    ..
-                } if c.as_str() == ","
-                    && !modifiers.command()
-                    && !modifiers.alt()
-                    && !modifiers.shift() =>
-                {
-                    // Comma key: Step backward one frame (only when video is paused)
-                    // Route through VideoControls handler for consistent behavior
-                    if self.video_player.is_some() {
-                        self.handle_message(
-                            Message::VideoControls(video_controls::Message::StepBackward),
-                            &I18n::default(),
-                        )
-                    } else {
-                        (Effect::None, Task::none())
+                    keyboard::Event::KeyPressed {
+                        key:
+                            keyboard::Key::Named(
+                                arrow @ (keyboard::key::Named::ArrowRight
+                                | keyboard::key::Named::ArrowLeft),
+                            ),
+                        modifiers,
+                        ..
+                    } if modifiers.shift() && self.has_active_video_session() => {
+                        // Shift+Arrow: Fine seek, one frame when paused, 0.1s while playing
+                        // (the timeline is never mirrored)
+                        let forward = matches!(arrow, keyboard::key::Named::ArrowRight);
+                        let message = if self.is_video_playing_or_will_resume() {
+                            video_controls::Message::SeekRelative(if forward {
+                                FINE_SEEK_STEP_SECS
+                            } else {
+                                -FINE_SEEK_STEP_SECS
+                            })
+                        } else if forward {
+                            video_controls::Message::StepForward
+                        } else {
+                            video_controls::Message::StepBackward
+                        };
+                        self.handle_message(Message::VideoControls(message), &I18n::default())
                     }
-                }
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Character(ref c),
-                    modifiers,
-                    ..
-                } if c.as_str() == "."
-                    && !modifiers.command()
-                    && !modifiers.alt()
-                    && !modifiers.shift() =>
-                {
-                    // Period key: Step forward one frame (only when video is paused)
-                    // Route through VideoControls handler for consistent behavior
-                    if self.video_player.is_some() {
-                        self.handle_message(
-                            Message::VideoControls(video_controls::Message::StepForward),
-                            &I18n::default(),
-                        )
-                    } else {
-                        (Effect::None, Task::none())
+                    keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::ArrowRight),
+                        ..
+                    } => {
+                        // ArrowRight: Seek forward if video is playing, otherwise navigate to next media
+                        // (previous in right-to-left languages; the timeline is never mirrored)
+                        // Uses is_playing_or_will_resume() to handle rapid key repeats during seek
+                        if self.is_video_playing_or_will_resume() {
+                            let step = self.keyboard_seek_step.value();
+                            self.handle_message(
+                                Message::VideoControls(video_controls::Message::SeekRelative(step)),
+                                &I18n::default(),
+                            )
+                        } else {
+                            let (_, right) = self
+                                .layout_direction
+                                .order(Message::NavigatePrevious, Message::NavigateNext);
+                            self.handle_message(right, &I18n::default())
+                        }
                     }
-                }
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Character(ref c),
-                    modifiers,
-                    ..
-                } if (c.as_str() == "j" || c.as_str() == "J")
-                    && !modifiers.command()
-                    && !modifiers.alt() =>
-                {
-                    // J key: Decrease playback speed (YouTube/VLC style)
-                    if self.video_player.is_some() {
-                        self.handle_message(
-                            Message::VideoControls(video_controls::Message::DecreasePlaybackSpeed),
-                            &I18n::default(),
-                        )
-                    } else {
+                    keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::ArrowLeft),
+                        ..
+                    } => {
+                        // ArrowLeft: Seek backward if video is playing, otherwise navigate to previous media
+                        // (next in right-to-left languages)
+                        // Uses is_playing_or_will_resume() to handle rapid key repeats during seek
+                        if self.is_video_playing_or_will_resume() {
+                            let step = self.keyboard_seek_step.value();
+                            self.handle_message(
+                                Message::VideoControls(video_controls::Message::SeekRelative(
+                                    -step,
+                                )),
+                                &I18n::default(),
+                            )
+                        } else {
+                            let (left, _) = self
+                                .layout_direction
+                                .order(Message::NavigatePrevious, Message::NavigateNext);
+                            self.handle_message(left, &I18n::default())
+                        }
+                    }
+                    keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::ArrowUp),
+                        ..
+                    } => {
+                        // ArrowUp: Increase volume (only during video playback)
+                        if self.has_active_video_session() {
+                            let new_volume = Volume::new(self.video_volume).increase();
+                            self.handle_message(
+                                Message::VideoControls(video_controls::Message::SetVolume(
+                                    new_volume,
+                                )),
+                                &I18n::default(),
+                            )
+                        } else {
+                            (Effect::None, Task::none())
+                        }
+                    }
+                    keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::ArrowDown),
+                        ..
+                    } => {
+                        // ArrowDown: Decrease volume (only during video playback)
+                        if self.has_active_video_session() {
+                            let new_volume = Volume::new(self.video_volume).decrease();
+                            self.handle_message(
+                                Message::VideoControls(video_controls::Message::SetVolume(
+                                    new_volume,
+                                )),
+                                &I18n::default(),
+                            )
+                        } else {
+                            (Effect::None, Task::none())
+                        }
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('m') && !modifiers.command() && !modifiers.alt() =>
+                    {
+                        // M key: Toggle mute (only during video playback)
+                        if self.has_active_video_session() {
+                            self.handle_message(
+                                Message::VideoControls(video_controls::Message::ToggleMute),
+                                &I18n::default(),
+                            )
+                        } else {
+                            (Effect::None, Task::none())
+                        }
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if matches!(shortcut, Some('e' | 'c'))
+                            && modifiers.command()
+                            && modifiers.shift()
+                            && !modifiers.alt() =>
+                    {
+                        // Ctrl+Shift+E: Export the view as displayed to a PNG file
+                        // Ctrl+Shift+C: Copy the view as displayed to the clipboard
+                        if self.has_media() {
+                            let destination = if shortcut == Some('c') {
+                                crate::media::view_export::Destination::Clipboard
+                            } else {
+                                crate::media::view_export::Destination::File
+                            };
+                            (Effect::ExportView(destination), Task::none())
+                        } else {
+                            (Effect::None, Task::none())
+                        }
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('e')
+                            && !modifiers.command()
+                            && !modifiers.alt()
+                            && !modifiers.shift() =>
+                    {
+                        // E key: Enter edit mode (only if image is loaded and not a video)
+                        // Video editing is not supported in v0.2
+                        if self.current_media_path.is_some() && !self.is_video() {
+                            (Effect::EnterEditor, Task::none())
+                        } else {
+                            (Effect::None, Task::none())
+                        }
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some(',') && !modifiers.command() && !modifiers.alt() =>
+                    {
+                        // Comma key: Step backward one frame (only when video is paused)
+                        // Shift is allowed: punctuation needs it on some layouts
+                        // Route through VideoControls handler for consistent behavior
+                        if self.video_player.is_some() {
+                            self.handle_message(
+                                Message::VideoControls(video_controls::Message::StepBackward),
+                                &I18n::default(),
+                            )
+                        } else {
+                            (Effect::None, Task::none())
+                        }
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('.') && !modifiers.command() && !modifiers.alt() =>
+                    {
+                        // Period key: Step forward one frame (only when video is paused)
+                        // Shift is allowed: AZERTY types a period with Shift
+                        // Route through VideoControls handler for consistent behavior
+                        if self.video_player.is_some() {
+                            self.handle_message(
+                                Message::VideoControls(video_controls::Message::StepForward),
+                                &I18n::default(),
+                            )
+                        } else {
+                            (Effect::None, Task::none())
+                        }
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if matches!(shortcut, Some('[' | ']' | '\\')) && !modifiers.command() =>
+                    {
+                        // [ / ] keys: Set the A–B loop-in / loop-out point
+                        // Backslash: Clear the A–B loop region
+                        // Alt is allowed: these characters need AltGr on many layouts
+                        let message = match shortcut {
+                            Some('[') => video_controls::Message::SetLoopIn,
+                            Some(']') => video_controls::Message::SetLoopOut,
+                            _ => video_controls::Message::ClearLoopRegion,
+                        };
+                        if self.video_player.is_some() {
+                            self.handle_message(Message::VideoControls(message), &I18n::default())
+                        } else {
+                            (Effect::None, Task::none())
+                        }
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('j') && !modifiers.command() && !modifiers.alt() =>
+                    {
+                        // J key: Decrease playback speed (YouTube/VLC style)
+                        if self.video_player.is_some() {
+                            self.handle_message(
+                                Message::VideoControls(
+                                    video_controls::Message::DecreasePlaybackSpeed,
+                                ),
+                                &I18n::default(),
+                            )
+                        } else {
+                            (Effect::None, Task::none())
+                        }
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('l') && !modifiers.command() && !modifiers.alt() =>
+                    {
+                        // L key: Increase playback speed (YouTube/VLC style)
+                        if self.video_player.is_some() {
+                            self.handle_message(
+                                Message::VideoControls(
+                                    video_controls::Message::IncreasePlaybackSpeed,
+                                ),
+                                &I18n::default(),
+                            )
+                        } else {
+                            (Effect::None, Task::none())
+                        }
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('i') && !modifiers.command() && !modifiers.alt() =>
+                    {
+                        // I key: Toggle info/metadata panel
+                        (Effect::ToggleInfoPanel, Task::none())
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('r') && !modifiers.command() && !modifiers.alt() =>
+                    {
+                        // R key: Rotate clockwise
+                        // Shift+R: Rotate counter-clockwise
+                        if modifiers.shift() {
+                            self.handle_message(Message::RotateCounterClockwise, &I18n::default())
+                        } else {
+                            self.handle_message(Message::RotateClockwise, &I18n::default())
+                        }
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('h')
+                            && !modifiers.command()
+                            && !modifiers.alt()
+                            && !modifiers.shift() =>
+                    {
+                        // H key: Flip the video horizontally
+                        self.handle_message(Message::ToggleMirror, &I18n::default())
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('b') && !modifiers.command() && !modifiers.alt() =>
+                    {
+                        // B key: Next matte color
+                        // Shift+B: Previous matte color
+                        if modifiers.shift() {
+                            self.handle_message(Message::PreviousMatte, &I18n::default())
+                        } else {
+                            self.handle_message(Message::NextMatte, &I18n::default())
+                        }
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('a')
+                            && !modifiers.command()
+                            && !modifiers.alt()
+                            && !modifiers.shift() =>
+                    {
+                        // A key: Toggle straight/premultiplied alpha preview
+                        self.handle_message(Message::ToggleAlphaInterpretation, &I18n::default())
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('s')
+                            && !modifiers.command()
+                            && !modifiers.alt()
+                            && !modifiers.shift() =>
+                    {
+                        // S key: Play the numbered image sequence as video
+                        self.handle_message(Message::PlayImageSequence, &I18n::default())
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('f') && !modifiers.command() && !modifiers.alt() =>
+                    {
+                        // F key: Zoom to the next detected face
+                        // Shift+F: Zoom to the previous detected face
+                        if modifiers.shift() {
+                            self.cycle_faces(NavigationDirection::Previous)
+                        } else {
+                            self.cycle_faces(NavigationDirection::Next)
+                        }
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('d')
+                            && !modifiers.command()
+                            && !modifiers.alt()
+                            && !modifiers.shift() =>
+                    {
+                        // D key: Outline the detected faces
+                        self.handle_message(Message::ToggleFaceOverlay, &I18n::default())
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('g') && !modifiers.command() && !modifiers.alt() =>
+                    {
+                        // G key: Jump to the next similar image (burst shots)
+                        // Shift+G: Jump to the previous similar image
+                        let direction = if modifiers.shift() {
+                            NavigationDirection::Previous
+                        } else {
+                            NavigationDirection::Next
+                        };
+                        if self.is_current_media_image() {
+                            (Effect::FindSimilar(direction), Task::none())
+                        } else {
+                            (Effect::None, Task::none())
+                        }
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('p')
+                            && !modifiers.command()
+                            && !modifiers.alt()
+                            && !modifiers.shift() =>
+                    {
+                        // P key: Toggle the pixel inspector
+                        self.handle_controls(controls::Message::ToggleInspector)
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('u')
+                            && !modifiers.command()
+                            && !modifiers.alt()
+                            && !modifiers.shift() =>
+                    {
+                        // U key: Toggle the ruler
+                        self.handle_controls(controls::Message::ToggleRuler)
+                    }
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('k')
+                            && !modifiers.command()
+                            && !modifiers.alt()
+                            && !modifiers.shift() =>
+                    {
+                        // K key: Toggle the composition guides
+                        self.handle_controls(controls::Message::ToggleGuides)
+                    }
+                    keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::Backspace),
+                        modifiers,
+                        ..
+                    } if self.ruler && !modifiers.command() && !modifiers.alt() => {
+                        // Backspace: Remove the last measurement of the image
+                        if let Some(path) = &self.shown_image_path {
+                            self.measurements.remove_last(path);
+                        }
                         (Effect::None, Task::none())
                     }
-                }
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Character(ref c),
-                    modifiers,
-                    ..
-                } if (c.as_str() == "l" || c.as_str() == "L")
-                    && !modifiers.command()
-                    && !modifiers.alt() =>
-                {
-                    // L key: Increase playback speed (YouTube/VLC style)
-                    if self.video_player.is_some() {
-                        self.handle_message(
-                            Message::VideoControls(video_controls::Message::IncreasePlaybackSpeed),
-                            &I18n::default(),
-                        )
-                    } else {
+                    keyboard::Event::KeyPressed { modifiers, .. }
+                        if shortcut == Some('o') && !modifiers.command() && !modifiers.alt() =>
+                    {
+                        // O key: Toggle the on-screen display
+                        self.osd_visible = !self.osd_visible;
                         (Effect::None, Task::none())
                     }
-                }
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Character(ref c),
-                    modifiers,
-                    ..
-                } if (c.as_str() == "i" || c.as_str() == "I")
-                    && !modifiers.command()
-                    && !modifiers.alt() =>
-                {
-                    // I key: Toggle info/metadata panel
-                    (Effect::ToggleInfoPanel, Task::none())
-                }
-                keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Character(ref c),
-                    modifiers,
-                    ..
-                } if (c.as_str() == "r" || c.as_str() == "R")
-                    && !modifiers.command()
-                    && !modifiers.alt() =>
-                {
-                    // R key: Rotate clockwise
-                    // Shift+R: Rotate counter-clockwise
-                    if modifiers.shift() {
-                        self.handle_message(Message::RotateCounterClockwise, &I18n::default())
-                    } else {
-                        self.handle_message(Message::RotateClockwise, &I18n::default())
+                    keyboard::Event::KeyPressed {
+                        physical_key: keyboard::key::Physical::Code(code),
+                        modifiers,
+                        ..
+                    } if modifiers.command() && !modifiers.alt() && layout_slot(code).is_some() => {
+                        // Ctrl+1..3: Switch workspace layout
+                        // Ctrl+Shift+1..3: Save the current layout into the slot
+                        // Physical keys, so digits work on layouts needing Shift for them
+                        let effect = layout_slot(code).map_or(Effect::None, |slot| {
+                            if modifiers.shift() {
+                                Effect::SaveLayout(slot)
+                            } else {
+                                Effect::ApplyLayout(slot)
+                            }
+                        });
+                        (effect, Task::none())
                     }
-                }
-                keyboard::Event::ModifiersChanged(modifiers) => {
-                    if modifiers.command() {
-                        // no-op currently, but keep placeholder for shortcut support
+                    keyboard::Event::ModifiersChanged(modifiers) => {
+                        // Shift turns the seek slider into fine scrubbing
+                        self.keyboard_modifiers = modifiers;
+                        (Effect::None, Task::none())
                     }
-                    (Effect::None, Task::none())
+                    _ => (Effect::None, Task::none()),
                 }
-                _ => (Effect::None, Task::none()),
-            },
+            }
             _ => (Effect::None, Task::none()),
         }
-    }
+    }
+
+    fn handle_mouse_button_pressed(&mut self, button: mouse::Button, position: Point) -> Effect {
+        let over_media = self.geometry_state().is_cursor_over_media();
+
+        // The ruler starts a measurement instead of panning
+        if button == mouse::Button::Left && self.ruler {
+            if let Some(point) = self.measured_point() {
+                self.pending_measurement = Some(Measurement {
+                    start: point,
+                    end: point,
+                });
+                return Effect::None;
+            }
+        }
+
+        // The pixel inspector copies the color instead of panning
+        if button == mouse::Button::Left && over_media {
+            if let Some(sample) = self.inspected_pixel() {
+                return Effect::CopyColor(sample.hex());
+            }
+        }
 
-    fn handle_mouse_button_pressed(&mut self, button: mouse::Button, position: Point) -> Effect {
         if button == mouse::Button::Left {
             let now = Instant::now();
             let double_click = self
@@ -2014,28 +4334,105 @@ impl State {
             // This keeps controls visible when user is interacting
             self.last_overlay_interaction = Some(now);
 
-            if self.geometry_state().is_cursor_over_media() {
-                if double_click {
-                    // Clear overlay timer when entering fullscreen (will hide controls initially)
-                    self.last_overlay_interaction = None;
-                    self.last_mouse_position = None;
-                    self.fullscreen_entered_at = Some(Instant::now());
-                    return Effect::ToggleFullscreen;
-                }
-
-                self.drag.start(position, self.viewport.offset);
+            if over_media && double_click {
+                // Clear overlay timer when entering fullscreen (will hide controls initially)
+                self.last_overlay_interaction = None;
+                self.last_mouse_position = None;
+                self.fullscreen_entered_at = Some(Instant::now());
+                return Effect::ToggleFullscreen;
             }
         }
 
+        if !over_media {
+            return Effect::None;
+        }
+        if button == self.mouse_mapping.drag_button() {
+            self.drag.start(position, self.viewport.offset);
+        } else if button == mouse::Button::Middle
+            && self.mouse_mapping.middle_click == MiddleClickAction::ToggleFit
+        {
+            let fit = !self.fit_to_window();
+            let (effect, _) = self.handle_controls(controls::Message::SetFitToWindow(fit));
+            return effect;
+        }
+
         Effect::None
     }
 
     fn handle_mouse_button_released(&mut self, button: mouse::Button) {
         if button == mouse::Button::Left {
+            if let (Some(measurement), Some(path)) =
+                (self.pending_measurement.take(), &self.shown_image_path)
+            {
+                if measurement.length() >= measurement::MIN_LENGTH {
+                    self.measurements.add(path, measurement);
+                }
+            }
+        }
+        if button == self.mouse_mapping.drag_button() {
             self.drag.stop();
         }
     }
 
+    /// Applies the wheel action of the mouse mapping.
+    fn handle_wheel(&mut self, delta: mouse::ScrollDelta) -> (Effect, Task<Message>) {
+        match self.mouse_mapping.wheel {
+            WheelAction::Navigate => self.handle_wheel_navigation(delta),
+            WheelAction::Volume if self.has_active_video_session() => {
+                self.handle_wheel_volume(delta)
+            }
+            WheelAction::Zoom | WheelAction::Volume => match self.handle_wheel_zoom(delta) {
+                Some(task) => (Effect::PersistPreferences, task),
+                None => (Effect::None, Task::none()),
+            },
+        }
+    }
+
+    /// Goes to the previous media when scrolling up over the media and to the
+    /// next one when scrolling down, at most once per wheel flick.
+    fn handle_wheel_navigation(&mut self, delta: mouse::ScrollDelta) -> (Effect, Task<Message>) {
+        let steps = scroll_steps(&delta);
+        if !self.geometry_state().is_cursor_over_media() || steps.abs() < f32::EPSILON {
+            return (Effect::None, Task::none());
+        }
+
+        let now = Instant::now();
+        if self
+            .last_wheel_navigation
+            .is_some_and(|last| now.duration_since(last) < WHEEL_NAVIGATION_INTERVAL)
+        {
+            return (Effect::None, Task::none());
+        }
+        self.last_wheel_navigation = Some(now);
+
+        let message = if steps > 0.0 {
+            Message::NavigatePrevious
+        } else {
+            Message::NavigateNext
+        };
+        self.handle_message(message, &I18n::default())
+    }
+
+    /// Raises the volume when scrolling up over the video and lowers it when
+    /// scrolling down.
+    fn handle_wheel_volume(&mut self, delta: mouse::ScrollDelta) -> (Effect, Task<Message>) {
+        let steps = scroll_steps(&delta);
+        if !self.geometry_state().is_cursor_over_media() || steps.abs() < f32::EPSILON {
+            return (Effect::None, Task::none());
+        }
+
+        let volume = Volume::new(self.video_volume);
+        let new_volume = if steps > 0.0 {
+            volume.increase()
+        } else {
+            volume.decrease()
+        };
+        self.handle_message(
+            Message::VideoControls(video_controls::Message::SetVolume(new_volume)),
+            &I18n::default(),
+        )
+    }
+
     /// Updates the viewport when the user drags the image. Clamps the offset to
     /// the scaled image bounds and mirrors the change to the scrollable widget
     /// so keyboard/scroll interactions stay in sync.
@@ -2048,7 +4445,7 @@ impl State {
         // Use rotation-aware size for correct clamping when image is rotated
         if let (Some(viewport), Some(size)) = (
             self.viewport.bounds,
-            geometry_state.scaled_media_size_rotated(self.current_rotation),
+            geometry_state.scaled_media_size_rotated(self.display_rotation()),
         ) {
             let max_offset_x = (size.width - viewport.width).max(0.0);
             let max_offset_y = (size.height - viewport.height).max(0.0);
@@ -2093,28 +4490,64 @@ impl State {
         }
     }
 
-    /// Applies wheel-based zoom while the cursor is over the image, returning a
-    /// boolean so callers can decide whether to stop event propagation.
-    fn handle_wheel_zoom(&mut self, delta: mouse::ScrollDelta) -> bool {
+    /// Applies wheel-based zoom while the cursor is over the image, returning
+    /// the scroll task keeping the point under the cursor still, or `None`
+    /// when the wheel did not zoom.
+    fn handle_wheel_zoom(&mut self, delta: mouse::ScrollDelta) -> Option<Task<Message>> {
         if !self.geometry_state().is_cursor_over_media() {
-            return false;
+            return None;
         }
 
         let steps = scroll_steps(&delta);
         if steps.abs() < f32::EPSILON {
-            return false;
+            return None;
         }
 
         let new_zoom = self.zoom.zoom_percent + steps * self.zoom.zoom_step.value();
-        self.zoom.apply_manual_zoom(new_zoom);
+        Some(self.zoom_towards_cursor(new_zoom))
+    }
+
+    /// Zooms to `new_zoom` keeping the media point under the cursor still, or
+    /// the center of the viewport when the cursor is not over the media (as
+    /// with the toolbar buttons). Returns the task scrolling the viewport.
+    fn zoom_towards_cursor(&mut self, new_zoom: f32) -> Task<Message> {
+        let rotation = self.display_rotation();
+        let geometry_state = self.geometry_state();
+        let anchor = self
+            .cursor_position
+            .filter(|_| geometry_state.is_cursor_over_media())
+            .or_else(|| self.viewport.bounds.map(|bounds| bounds.center()));
+        let new_zoom = crate::ui::state::zoom::clamp_zoom(new_zoom);
+        let target =
+            anchor.and_then(|anchor| geometry_state.zoom_anchor_offset(rotation, new_zoom, anchor));
 
+        self.zoom.apply_manual_zoom(new_zoom);
         // Also disable video fit-to-window when zooming on a video
         if self.is_video() {
             self.video_fit_to_window = false;
         }
         // No need to sync shader scale - pane calculates display size from zoom at render time
 
-        true
+        let Some(offset) = target else {
+            return Task::none();
+        };
+        self.viewport.offset = offset;
+
+        // The scrollable takes relative offsets, against the new scroll range
+        let (Some(viewport), Some(size)) = (
+            self.viewport.bounds,
+            self.geometry_state().scaled_media_size_rotated(rotation),
+        ) else {
+            return Task::none();
+        };
+        let relative = |offset: f32, max: f32| if max > 0.0 { offset / max } else { 0.0 };
+        operation::snap_to(
+            Id::new(SCROLLABLE_ID),
+            RelativeOffset {
+                x: relative(offset.x, (size.width - viewport.width).max(0.0)),
+                y: relative(offset.y, (size.height - viewport.height).max(0.0)),
+            },
+        )
     }
 
     /// Recomputes the fit-to-window zoom when layout-affecting events occur so
@@ -2150,8 +4583,12 @@ impl State {
             return None;
         }
 
-        let media_width = media.width() as f32;
-        let media_height = media.height() as f32;
+        // Quarter turns swap the dimensions to fit
+        let (media_width, media_height) = if self.display_rotation().swaps_dimensions() {
+            (media.height() as f32, media.width() as f32)
+        } else {
+            (media.width() as f32, media.height() as f32)
+        };
 
         let scale_x = viewport.width / media_width;
         let scale_y = viewport.height / media_height;
@@ -2162,7 +4599,9 @@ impl State {
             return Some(crate::ui::state::zoom::DEFAULT_ZOOM_PERCENT);
         }
 
-        Some(crate::ui::state::zoom::clamp_zoom(scale * 100.0))
+        Some(crate::ui::state::zoom::clamp_zoom(
+            crate::ui::state::zoom::zoom_for_media_scale(scale),
+        ))
     }
 
     /// Provides a lightweight view of geometry-dependent state for hit-testing
@@ -2177,6 +4616,30 @@ impl State {
     }
 }
 
+/// Returns true for the quick-search shortcuts handled while the overlay is closed.
+fn is_quick_search_shortcut(
+    key: &keyboard::Key,
+    shortcut: Option<char>,
+    modifiers: keyboard::Modifiers,
+) -> bool {
+    match key {
+        keyboard::Key::Named(keyboard::key::Named::F3) => true,
+        _ => shortcut == Some('f') && modifiers.command(),
+    }
+}
+
+/// Returns the workspace layout slot bound to a digit key.
+fn layout_slot(code: keyboard::key::Code) -> Option<usize> {
+    use keyboard::key::Code;
+
+    match code {
+        Code::Digit1 | Code::Numpad1 => Some(0),
+        Code::Digit2 | Code::Numpad2 => Some(1),
+        Code::Digit3 | Code::Numpad3 => Some(2),
+        _ => None,
+    }
+}
+
 fn parse_number(input: &str) -> Option<f32> {
     let trimmed = input.trim();
     if trimmed.is_empty() {
@@ -2229,6 +4692,33 @@ fn format_rotation_indicator(rotation: RotationAngle) -> HudLine {
     }
 }
 
+/// Generates HUD indicator for matte and alpha preview overrides.
+///
+/// Returns None when both are at their defaults, so the HUD only appears
+/// while the user is actively inspecting transparency.
+fn format_alpha_preview_indicator(
+    i18n: &I18n,
+    matte: MatteColor,
+    alpha: AlphaInterpretation,
+) -> Option<HudLine> {
+    let parts: Vec<String> = [
+        (matte != MatteColor::Background).then(|| i18n.tr(matte.i18n_key())),
+        alpha.is_premultiplied().then(|| i18n.tr(alpha.i18n_key())),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    if parts.is_empty() {
+        None
+    } else {
+        Some(HudLine {
+            icon: HudIconKind::AlphaPreview,
+            text: parts.join(" · "),
+        })
+    }
+}
+
 /// Generates HUD indicator for videos without audio.
 ///
 /// Only shows an indicator when a video has no audio track.
@@ -2249,6 +4739,13 @@ fn format_media_indicator(i18n: &I18n, media: &MediaData) -> Option<HudLine> {
     }
 }
 
+/// Returns a range of `length` seconds centered on `position`, shifted to
+/// stay within `0..duration`.
+fn window_around(position: f64, duration: f64, length: f64) -> (f64, f64) {
+    let start = (position - length / 2.0).clamp(0.0, (duration - length).max(0.0));
+    (start, (start + length).min(duration.max(0.0)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2282,6 +4779,11 @@ mod tests {
             duration_secs: 125.0,
             fps: 30.0,
             has_audio: false,
+            sequence: None,
+            chapters: Vec::new(),
+            animated_image: false,
+            rotation: 0,
+            hdr: None,
         };
 
         let media = MediaData::Video(video_data);
@@ -2306,6 +4808,11 @@ mod tests {
             duration_secs: 65.0,
             fps: 30.0,
             has_audio: true,
+            sequence: None,
+            chapters: Vec::new(),
+            animated_image: false,
+            rotation: 0,
+            hdr: None,
         };
 
         let media = MediaData::Video(video_data);
@@ -2394,6 +4901,30 @@ mod tests {
         assert!(state.error.is_none(), "no error should be set");
     }
 
+    #[test]
+    fn color_adjustments_reset_on_new_media() {
+        use crate::media::ImageData;
+
+        let i18n = I18n::default();
+        let mut state = State::new();
+        let brighter = ColorAdjustments {
+            brightness: 0.3,
+            ..ColorAdjustments::default()
+        };
+        let _ = state.handle_message(
+            Message::VideoControls(video_controls::Message::SetColorAdjustments(brighter)),
+            &i18n,
+        );
+        assert_eq!(state.color_adjustments, brighter);
+
+        let image_data = ImageData::from_rgba(10, 10, vec![255_u8; 10 * 10 * 4]);
+        let _ = state.handle_message(
+            Message::MediaLoaded(Ok(MediaData::Image(image_data))),
+            &i18n,
+        );
+        assert!(state.color_adjustments.is_neutral());
+    }
+
     #[test]
     fn format_media_indicator_returns_none_for_images() {
         use crate::media::ImageData;
@@ -2597,6 +5128,98 @@ mod tests {
         );
     }
 
+    #[test]
+    fn arrow_keys_are_swapped_in_right_to_left_languages() {
+        let mut state = State::new();
+        let press = |named, code| {
+            event::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                modified_key: keyboard::Key::Named(named),
+                physical_key: keyboard::key::Physical::Code(code),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::default(),
+                text: None,
+                repeat: false,
+            })
+        };
+        let left = || {
+            press(
+                keyboard::key::Named::ArrowLeft,
+                keyboard::key::Code::ArrowLeft,
+            )
+        };
+
+        let (effect, _) = state.handle_raw_event(left());
+        assert_eq!(effect, Effect::NavigatePrevious);
+
+        state.set_layout_direction(LayoutDirection::RightToLeft);
+        let (effect, _) = state.handle_raw_event(left());
+        assert_eq!(effect, Effect::NavigateNext);
+    }
+
+    #[test]
+    fn alt_page_keys_jump_between_capture_days() {
+        let mut state = State::new();
+        let press = |named, code, modifiers| {
+            event::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                modified_key: keyboard::Key::Named(named),
+                physical_key: keyboard::key::Physical::Code(code),
+                location: keyboard::Location::Standard,
+                modifiers,
+                text: None,
+                repeat: false,
+            })
+        };
+        let page_down = |modifiers| {
+            press(
+                keyboard::key::Named::PageDown,
+                keyboard::key::Code::PageDown,
+                modifiers,
+            )
+        };
+        let page_up = |modifiers| {
+            press(
+                keyboard::key::Named::PageUp,
+                keyboard::key::Code::PageUp,
+                modifiers,
+            )
+        };
+
+        let (effect, _) = state.handle_raw_event(page_down(keyboard::Modifiers::ALT));
+        assert_eq!(
+            effect,
+            Effect::NavigateCaptureDay(NavigationDirection::Next)
+        );
+        let (effect, _) = state.handle_raw_event(page_up(keyboard::Modifiers::ALT));
+        assert_eq!(
+            effect,
+            Effect::NavigateCaptureDay(NavigationDirection::Previous)
+        );
+
+        // Plain Page Up/Down are left to video chapters
+        let (effect, _) = state.handle_raw_event(page_down(keyboard::Modifiers::default()));
+        assert_eq!(effect, Effect::None);
+    }
+
+    #[test]
+    fn mouse_navigation_buttons_follow_the_mapping() {
+        let mut state = State::new();
+        let press = |button| event::Event::Mouse(mouse::Event::ButtonPressed(button));
+
+        let (effect, _) = state.handle_raw_event(press(mouse::Button::Back));
+        assert_eq!(effect, Effect::NavigatePrevious);
+        let (effect, _) = state.handle_raw_event(press(mouse::Button::Forward));
+        assert_eq!(effect, Effect::NavigateNext);
+
+        state.set_mouse_mapping(MouseMapping {
+            navigation_buttons: false,
+            ..MouseMapping::default()
+        });
+        let (effect, _) = state.handle_raw_event(press(mouse::Button::Back));
+        assert_eq!(effect, Effect::None);
+    }
+
     #[test]
     fn play_button_interaction_resets_overlay_timer() {
         use std::thread::sleep;
@@ -2629,4 +5252,391 @@ mod tests {
             "Timer should be reset to a newer time"
         );
     }
+
+    #[test]
+    fn alpha_preview_indicator_hidden_at_defaults() {
+        let i18n = I18n::default();
+        let line = format_alpha_preview_indicator(
+            &i18n,
+            MatteColor::Background,
+            AlphaInterpretation::Straight,
+        );
+        assert!(line.is_none());
+
+        let line = format_alpha_preview_indicator(
+            &i18n,
+            MatteColor::Magenta,
+            AlphaInterpretation::Premultiplied,
+        )
+        .expect("indicator should be shown when overrides are active");
+        assert!(matches!(line.icon, HudIconKind::AlphaPreview));
+        assert!(line.text.contains(" · "));
+    }
+
+    #[test]
+    fn premultiplied_preview_survives_navigation() {
+        use crate::media::ImageData;
+
+        let i18n = I18n::default();
+        let mut state = State::new();
+        let load = |state: &mut State| {
+            let image_data = ImageData::from_rgba(1, 1, vec![64, 64, 64, 128]);
+            let _ = state.handle_message(
+                Message::MediaLoaded(Ok(MediaData::Image(image_data))),
+                &i18n,
+            );
+        };
+
+        load(&mut state);
+        assert!(state.display_image_cache().is_none());
+
+        let _ = state.handle_message(Message::ToggleAlphaInterpretation, &i18n);
+        let _ = state.handle_message(Message::NextMatte, &i18n);
+        let cached = state
+            .display_image_cache()
+            .expect("premultiplied preview should be cached");
+        assert_eq!(cached.rgba_bytes()[0], 128);
+
+        // Loading another image keeps the preview mode and rebuilds the cache
+        load(&mut state);
+        assert_eq!(state.matte(), MatteColor::Black);
+        assert!(state.alpha_interpretation().is_premultiplied());
+        assert!(state.display_image_cache().is_some());
+    }
+
+    #[test]
+    fn quick_search_submit_jumps_to_selected_match_and_closes() {
+        let i18n = I18n::default();
+        let mut state = State::new();
+        let _ = state.open_quick_search(vec![
+            PathBuf::from("/photos/beach.jpg"),
+            PathBuf::from("/photos/sunset.jpg"),
+        ]);
+        assert!(state.quick_search_state().is_open);
+
+        let _ = state.handle_message(
+            Message::QuickSearch(quick_search::Message::QueryChanged("sun".into())),
+            &i18n,
+        );
+        let (effect, _) =
+            state.handle_message(Message::QuickSearch(quick_search::Message::Submit), &i18n);
+
+        assert_eq!(
+            effect,
+            Effect::JumpToMedia(PathBuf::from("/photos/sunset.jpg"))
+        );
+        assert!(!state.quick_search_state().is_open);
+    }
+
+    #[test]
+    fn quick_search_submit_without_matches_keeps_overlay_open() {
+        let i18n = I18n::default();
+        let mut state = State::new();
+        let _ = state.open_quick_search(vec![PathBuf::from("/photos/beach.jpg")]);
+        let _ = state.handle_message(
+            Message::QuickSearch(quick_search::Message::QueryChanged("xyz".into())),
+            &i18n,
+        );
+
+        let (effect, _) =
+            state.handle_message(Message::QuickSearch(quick_search::Message::Submit), &i18n);
+
+        assert_eq!(effect, Effect::None);
+        assert!(state.quick_search_state().is_open);
+    }
+
+    #[test]
+    fn face_zoom_detects_then_zooms_and_ignores_stale_results() {
+        use crate::media::ImageData;
+
+        let i18n = I18n::default();
+        let mut state = State::new();
+        let path = PathBuf::from("/photos/portrait.jpg");
+        state.current_media_path = Some(path.clone());
+        let image_data = ImageData::from_rgba(400, 200, vec![0; 400 * 200 * 4]);
+        let _ = state.handle_message(
+            Message::MediaLoaded(Ok(MediaData::Image(image_data))),
+            &i18n,
+        );
+        state.viewport.update(
+            Rectangle::new(Point::ORIGIN, iced::Size::new(200.0, 100.0)),
+            AbsoluteOffset { x: 0.0, y: 0.0 },
+        );
+
+        let (effect, _) = state.cycle_faces(NavigationDirection::Next);
+        assert_eq!(effect, Effect::DetectFaces);
+        // A second press while detection runs does not start another one
+        let (effect, _) = state.cycle_faces(NavigationDirection::Next);
+        assert_eq!(effect, Effect::None);
+
+        let face = FaceBox {
+            x: 300.0,
+            y: 100.0,
+            width: 20.0,
+            height: 20.0,
+            score: 0.9,
+        };
+        let _ = state.handle_message(
+            Message::FacesDetected {
+                path: PathBuf::from("/photos/other.jpg"),
+                result: Ok(vec![face]),
+            },
+            &i18n,
+        );
+        assert!(state.faces.is_none());
+
+        let (effect, _) = state.handle_message(
+            Message::FacesDetected {
+                path,
+                result: Ok(vec![face]),
+            },
+            &i18n,
+        );
+        assert_eq!(effect, Effect::PersistPreferences);
+        // 40% of the 100px viewport height over a 20px face
+        assert!((state.zoom.zoom_percent - 200.0).abs() < 1e-3);
+        // Face center (310, 110) at 2x, centered in the viewport
+        assert!((state.viewport.offset.x - 520.0).abs() < 1e-3);
+        assert!((state.viewport.offset.y - 170.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn locked_view_keeps_zoom_and_offset_across_navigation() {
+        use crate::media::ImageData;
+
+        let i18n = I18n::default();
+        let mut state = State::new();
+        let image = || MediaData::Image(ImageData::from_rgba(400, 200, vec![0; 400 * 200 * 4]));
+        state.current_media_path = Some(PathBuf::from("/burst/001.jpg"));
+        let _ = state.handle_message(Message::MediaLoaded(Ok(image())), &i18n);
+        state.viewport.update(
+            Rectangle::new(Point::ORIGIN, iced::Size::new(200.0, 100.0)),
+            AbsoluteOffset { x: 0.0, y: 0.0 },
+        );
+        state.zoom.apply_manual_zoom(200.0);
+        state.viewport.offset = AbsoluteOffset { x: 300.0, y: 150.0 };
+
+        let _ = state.handle_message(Message::Controls(controls::Message::ToggleViewLock), &i18n);
+        assert!(state.is_view_locked());
+
+        state.current_media_path = Some(PathBuf::from("/burst/002.jpg"));
+        let _ = state.handle_message(Message::MediaLoaded(Ok(image())), &i18n);
+        assert!((state.zoom.zoom_percent - 200.0).abs() < f32::EPSILON);
+        assert!((state.viewport.offset.x - 300.0).abs() < f32::EPSILON);
+        assert!((state.viewport.offset.y - 150.0).abs() < f32::EPSILON);
+
+        let _ = state.handle_message(Message::Controls(controls::Message::ToggleViewLock), &i18n);
+        state.current_media_path = Some(PathBuf::from("/burst/003.jpg"));
+        let _ = state.handle_message(Message::MediaLoaded(Ok(image())), &i18n);
+        assert!((state.viewport.offset.x).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn ruler_drag_measures_the_shown_image() {
+        use crate::media::ImageData;
+
+        let i18n = I18n::default();
+        let mut state = State::new();
+        let path = PathBuf::from("/screens/mockup.png");
+        state.current_media_path = Some(path.clone());
+        let image = MediaData::Image(ImageData::from_rgba(400, 200, vec![0; 400 * 200 * 4]));
+        let _ = state.handle_message(Message::MediaLoaded(Ok(image)), &i18n);
+        state.viewport.update(
+            Rectangle::new(Point::ORIGIN, iced::Size::new(400.0, 200.0)),
+            AbsoluteOffset { x: 0.0, y: 0.0 },
+        );
+        state.zoom.apply_manual_zoom(100.0);
+        let _ = state.handle_message(Message::Controls(controls::Message::ToggleRuler), &i18n);
+
+        let move_to = |state: &mut State, x, y| {
+            let _ = state.handle_raw_event(event::Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(x, y),
+            }));
+        };
+        move_to(&mut state, 10.0, 20.0);
+        let _ = state.handle_raw_event(event::Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        move_to(&mut state, 40.0, 60.0);
+        let _ = state.handle_raw_event(event::Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+
+        let measurements = state.measurements.get(&path);
+        assert_eq!(measurements.len(), 1);
+        assert!((measurements[0].length() - 50.0).abs() < 1e-3);
+        assert!(!state.drag.is_dragging);
+
+        // A click without dragging adds nothing
+        let _ = state.handle_raw_event(event::Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        let _ = state.handle_raw_event(event::Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+        assert_eq!(state.measurements.get(&path).len(), 1);
+    }
+
+    #[test]
+    fn remembered_view_is_restored_when_coming_back_to_an_image() {
+        use crate::media::ImageData;
+
+        let i18n = I18n::default();
+        let mut state = State::new();
+        state.set_remember_view(true);
+        let image = || MediaData::Image(ImageData::from_rgba(40, 20, vec![0; 40 * 20 * 4]));
+        let first = PathBuf::from("/photos/first.jpg");
+        state.current_media_path = Some(first.clone());
+        let _ = state.handle_message(Message::MediaLoaded(Ok(image())), &i18n);
+        state.zoom.apply_manual_zoom(300.0);
+        state.apply_rotation(RotationAngle::new(90));
+
+        state.current_media_path = Some(PathBuf::from("/photos/second.jpg"));
+        let _ = state.handle_message(Message::MediaLoaded(Ok(image())), &i18n);
+        assert_eq!(state.current_rotation, RotationAngle::default());
+
+        state.current_media_path = Some(first);
+        let _ = state.handle_message(Message::MediaLoaded(Ok(image())), &i18n);
+        assert!(!state.zoom.fit_to_window);
+        assert!((state.zoom.zoom_percent - 300.0).abs() < f32::EPSILON);
+        assert_eq!(state.current_rotation, RotationAngle::new(90));
+
+        // Turning the option off forgets the views
+        let second = PathBuf::from("/photos/second.jpg");
+        assert!(state.view_memory.get(&second).is_some());
+        state.set_remember_view(false);
+        assert_eq!(state.view_memory.get(&second), None);
+    }
+
+    #[test]
+    fn face_overlay_detects_without_zooming_and_follows_navigation() {
+        use crate::media::ImageData;
+
+        let i18n = I18n::default();
+        let mut state = State::new();
+        let path = PathBuf::from("/photos/group.jpg");
+        state.current_media_path = Some(path.clone());
+        let image = || MediaData::Image(ImageData::from_rgba(40, 20, vec![0; 40 * 20 * 4]));
+        let _ = state.handle_message(Message::MediaLoaded(Ok(image())), &i18n);
+
+        let (effect, _) = state.handle_message(Message::ToggleFaceOverlay, &i18n);
+        assert_eq!(effect, Effect::DetectFaces);
+
+        let face = FaceBox {
+            x: 10.0,
+            y: 5.0,
+            width: 8.0,
+            height: 8.0,
+            score: 0.9,
+        };
+        let zoom_before = state.zoom.zoom_percent;
+        let (effect, _) = state.handle_message(
+            Message::FacesDetected {
+                path,
+                result: Ok(vec![face]),
+            },
+            &i18n,
+        );
+        assert_eq!(effect, Effect::None);
+        assert!((state.zoom.zoom_percent - zoom_before).abs() < f32::EPSILON);
+        assert_eq!(state.faces.as_deref(), Some(&[face][..]));
+
+        // The next image is analyzed as soon as it is shown
+        state.current_media_path = Some(PathBuf::from("/photos/next.jpg"));
+        let _ = state.handle_message(Message::MediaLoaded(Ok(image())), &i18n);
+        assert!(state.faces.is_none());
+        let (effect, _) = state.handle_message(Message::DetectOverlayFaces, &i18n);
+        assert_eq!(effect, Effect::DetectFaces);
+
+        // A failing detection hides the overlay instead of retrying on every image
+        let (effect, _) = state.handle_message(
+            Message::FacesDetected {
+                path: PathBuf::from("/photos/next.jpg"),
+                result: Err("offline".to_string()),
+            },
+            &i18n,
+        );
+        assert!(matches!(effect, Effect::ShowErrorNotification { .. }));
+        assert!(!state.face_overlay);
+    }
+
+    #[test]
+    fn clip_export_tracks_progress_until_finished() {
+        let i18n = I18n::default();
+        let mut state = State::new();
+        let cancel = CancelFlag::default();
+        state.clip_export = Some((cancel.clone(), 0.0));
+
+        let _ = state.handle_message(
+            Message::VideoControls(video_controls::Message::CancelClipExport),
+            &i18n,
+        );
+        assert!(cancel.is_cancelled());
+
+        let _ = state.handle_message(Message::ClipExport(ClipExportStatus::Progress(0.5)), &i18n);
+        assert_eq!(state.clip_export.as_ref().map(|(_, p)| *p), Some(0.5));
+
+        let path = PathBuf::from("/videos/holiday-clip.mp4");
+        let (effect, _) = state.handle_message(
+            Message::ClipExport(ClipExportStatus::Finished {
+                path: path.clone(),
+                method: ClipMethod::StreamCopy,
+            }),
+            &i18n,
+        );
+        assert_eq!(
+            effect,
+            Effect::ClipExported {
+                path,
+                method: ClipMethod::StreamCopy
+            }
+        );
+        assert!(state.clip_export.is_none());
+    }
+
+    #[test]
+    fn frames_window_stays_within_the_video() {
+        assert_eq!(window_around(30.0, 60.0, 10.0), (25.0, 35.0));
+        assert_eq!(window_around(1.0, 60.0, 10.0), (0.0, 10.0));
+        assert_eq!(window_around(58.0, 60.0, 10.0), (50.0, 60.0));
+        assert_eq!(window_around(2.0, 4.0, 10.0), (0.0, 4.0));
+    }
+
+    #[test]
+    fn frames_export_closes_the_panel_when_finished() {
+        let i18n = I18n::default();
+        let mut state = State::new();
+        let _ = state.handle_message(
+            Message::VideoControls(video_controls::Message::ToggleFramesExport),
+            &i18n,
+        );
+        assert!(state.frames_export.open);
+
+        let cancel = CancelFlag::default();
+        state.frames_export_cancel = Some(cancel.clone());
+        let _ = state.handle_message(
+            Message::VideoControls(video_controls::Message::CancelFramesExport),
+            &i18n,
+        );
+        assert!(cancel.is_cancelled());
+
+        let _ = state.handle_message(
+            Message::FramesExport(FramesExportStatus::Progress(0.25)),
+            &i18n,
+        );
+        assert_eq!(state.frames_export.progress, Some(0.25));
+
+        let path = PathBuf::from("/videos/holiday-frames.gif");
+        let (effect, _) = state.handle_message(
+            Message::FramesExport(FramesExportStatus::Finished {
+                path: path.clone(),
+                frames: 12,
+            }),
+            &i18n,
+        );
+        assert_eq!(effect, Effect::FramesExported { path, frames: 12 });
+        assert!(!state.frames_export.open);
+        assert!(state.frames_export.progress.is_none());
+        assert!(state.frames_export_cancel.is_none());
+    }
 }
@@ -1,7 +1,8 @@
 // SPDX-License-Identifier: MPL-2.0
-//! Viewer controls: zoom inputs, buttons, and fit-to-window toggle.
+//! Viewer controls: zoom inputs, buttons, fit-to-window and view lock toggles.
 
 use crate::i18n::fluent::I18n;
+use crate::ui::accessibility;
 use crate::ui::action_icons;
 use crate::ui::design_tokens::{spacing, typography};
 use crate::ui::icons;
@@ -28,8 +29,19 @@ pub struct ViewContext<'a> {
     pub i18n: &'a I18n,
     /// Whether metadata editor has unsaved changes (disables fullscreen).
     pub metadata_editor_has_changes: bool,
-    /// Whether the current media is a video (rotation is disabled for videos).
+    /// Whether the current media is a video (mirroring is only available
+    /// for videos, exporting the visible area only for images).
     pub is_video: bool,
+    /// Whether the current video is flipped horizontally.
+    pub is_mirrored: bool,
+    /// Whether navigation keeps the zoom and offset of the view.
+    pub is_view_locked: bool,
+    /// Whether the pixel inspector is on.
+    pub is_inspecting: bool,
+    /// Whether the ruler is on.
+    pub is_measuring: bool,
+    /// Whether the composition guides are shown.
+    pub is_showing_guides: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -40,10 +52,16 @@ pub enum Message {
     ZoomIn,
     ZoomOut,
     SetFitToWindow(bool),
+    ToggleViewLock,
+    ToggleInspector,
+    ToggleRuler,
+    ToggleGuides,
     ToggleFullscreen,
     DeleteCurrentImage,
     RotateClockwise,
     RotateCounterClockwise,
+    ToggleMirror,
+    ExportVisibleArea,
 }
 
 #[allow(clippy::too_many_lines)] // UI builder with many widgets, inherent complexity
@@ -55,6 +73,7 @@ pub fn view<'a>(
     effective_fit_to_window: bool,
     is_fullscreen: bool,
 ) -> Element<'a, Message> {
+    let button_size = accessibility::icon_button_size();
     let zoom_placeholder = ctx.i18n.tr("viewer-zoom-input-placeholder");
     let zoom_label = Text::new(ctx.i18n.tr("viewer-zoom-label"));
 
@@ -62,6 +81,7 @@ pub fn view<'a>(
         .on_input(Message::ZoomInputChanged)
         .on_submit(Message::ZoomInputSubmitted)
         .padding(spacing::XXS)
+        .style(styles::text_input::standard)
         .size(typography::BODY_LG)
         .width(Length::Fixed(60.0));
 
@@ -71,8 +91,8 @@ pub fn view<'a>(
         button(icons::fill(action_icons::viewer::toolbar::zoom_reset()))
             .on_press(Message::ResetZoom)
             .padding(spacing::XXS)
-            .width(Length::Fixed(shared_styles::ICON_SIZE))
-            .height(Length::Fixed(shared_styles::ICON_SIZE)),
+            .width(Length::Fixed(button_size))
+            .height(Length::Fixed(button_size)),
         ctx.i18n.tr("viewer-zoom-reset-button"),
     );
 
@@ -80,8 +100,8 @@ pub fn view<'a>(
         button(icons::fill(action_icons::viewer::toolbar::zoom_out()))
             .on_press(Message::ZoomOut)
             .padding(spacing::XXS)
-            .width(Length::Fixed(shared_styles::ICON_SIZE))
-            .height(Length::Fixed(shared_styles::ICON_SIZE)),
+            .width(Length::Fixed(button_size))
+            .height(Length::Fixed(button_size)),
         ctx.i18n.tr("viewer-zoom-out-tooltip"),
     );
 
@@ -89,8 +109,8 @@ pub fn view<'a>(
         button(icons::fill(action_icons::viewer::toolbar::zoom_in()))
             .on_press(Message::ZoomIn)
             .padding(spacing::XXS)
-            .width(Length::Fixed(shared_styles::ICON_SIZE))
-            .height(Length::Fixed(shared_styles::ICON_SIZE)),
+            .width(Length::Fixed(button_size))
+            .height(Length::Fixed(button_size)),
         ctx.i18n.tr("viewer-zoom-in-tooltip"),
     );
 
@@ -102,8 +122,8 @@ pub fn view<'a>(
     let fit_button = button(fit_icon)
         .on_press(Message::SetFitToWindow(!effective_fit_to_window))
         .padding(spacing::XXS)
-        .width(Length::Fixed(shared_styles::ICON_SIZE))
-        .height(Length::Fixed(shared_styles::ICON_SIZE));
+        .width(Length::Fixed(button_size))
+        .height(Length::Fixed(button_size));
 
     // Apply different style when fit is active (highlighted)
     let fit_button_content: Element<'_, Message> = if effective_fit_to_window {
@@ -116,11 +136,28 @@ pub fn view<'a>(
         ctx.i18n.tr("viewer-fit-to-window-toggle"),
     );
 
+    // Locking the view only applies to images, highlighted while active
+    let lock_button = button(icons::fill(action_icons::viewer::toolbar::lock_view()))
+        .padding(spacing::XXS)
+        .width(Length::Fixed(button_size))
+        .height(Length::Fixed(button_size));
+    let lock_content: Element<'_, Message> = if ctx.is_video {
+        lock_button.style(styles::button::disabled()).into()
+    } else if ctx.is_view_locked {
+        lock_button
+            .on_press(Message::ToggleViewLock)
+            .style(styles::button::selected)
+            .into()
+    } else {
+        lock_button.on_press(Message::ToggleViewLock).into()
+    };
+    let lock_toggle = tip(lock_content, ctx.i18n.tr("viewer-lock-view-tooltip"));
+
     // Fullscreen button - disabled when metadata editor has unsaved changes
     let fullscreen_button = button(icons::fill(action_icons::viewer::toolbar::fullscreen()))
         .padding(spacing::XXS)
-        .width(Length::Fixed(shared_styles::ICON_SIZE))
-        .height(Length::Fixed(shared_styles::ICON_SIZE));
+        .width(Length::Fixed(button_size))
+        .height(Length::Fixed(button_size));
 
     let (fullscreen_button_content, fullscreen_tooltip): (Element<'_, Message>, String) =
         if ctx.metadata_editor_has_changes {
@@ -151,55 +188,116 @@ pub fn view<'a>(
         button(icons::fill(action_icons::viewer::toolbar::delete()))
             .on_press(Message::DeleteCurrentImage)
             .padding(spacing::XXS)
-            .width(Length::Fixed(shared_styles::ICON_SIZE))
-            .height(Length::Fixed(shared_styles::ICON_SIZE)),
+            .width(Length::Fixed(button_size))
+            .height(Length::Fixed(button_size)),
         ctx.i18n.tr("viewer-delete-tooltip"),
     );
 
-    // Rotation only works for images, not videos
-    let rotate_ccw_content: Element<'_, Message> = if ctx.is_video {
+    let rotate_ccw_button = tip(
         button(icons::fill(
             action_icons::viewer::toolbar::rotate_counterclockwise(),
         ))
+        .on_press(Message::RotateCounterClockwise)
         .padding(spacing::XXS)
-        .width(Length::Fixed(shared_styles::ICON_SIZE))
-        .height(Length::Fixed(shared_styles::ICON_SIZE))
-        .style(styles::button::disabled())
-        .into()
-    } else {
+        .width(Length::Fixed(button_size))
+        .height(Length::Fixed(button_size)),
+        ctx.i18n.tr("viewer-rotate-ccw-tooltip"),
+    );
+
+    let rotate_cw_button = tip(
         button(icons::fill(
-            action_icons::viewer::toolbar::rotate_counterclockwise(),
+            action_icons::viewer::toolbar::rotate_clockwise(),
         ))
-        .on_press(Message::RotateCounterClockwise)
+        .on_press(Message::RotateClockwise)
         .padding(spacing::XXS)
-        .width(Length::Fixed(shared_styles::ICON_SIZE))
-        .height(Length::Fixed(shared_styles::ICON_SIZE))
-        .into()
+        .width(Length::Fixed(button_size))
+        .height(Length::Fixed(button_size)),
+        ctx.i18n.tr("viewer-rotate-cw-tooltip"),
+    );
+
+    // Mirroring only works for videos, highlighted while active
+    let mirror_button = button(icons::fill(action_icons::viewer::toolbar::mirror()))
+        .padding(spacing::XXS)
+        .width(Length::Fixed(button_size))
+        .height(Length::Fixed(button_size));
+    let mirror_content: Element<'_, Message> = if !ctx.is_video {
+        mirror_button.style(styles::button::disabled()).into()
+    } else if ctx.is_mirrored {
+        mirror_button
+            .on_press(Message::ToggleMirror)
+            .style(styles::button::selected)
+            .into()
+    } else {
+        mirror_button.on_press(Message::ToggleMirror).into()
     };
-    let rotate_ccw_button = tip(rotate_ccw_content, ctx.i18n.tr("viewer-rotate-ccw-tooltip"));
+    let mirror_toggle = tip(mirror_content, ctx.i18n.tr("viewer-mirror-tooltip"));
 
-    let rotate_cw_content: Element<'_, Message> = if ctx.is_video {
+    // Pixel inspector, highlighted while active
+    let inspector_button = button(icons::fill(action_icons::viewer::toolbar::pixel_inspector()))
+        .on_press(Message::ToggleInspector)
+        .padding(spacing::XXS)
+        .width(Length::Fixed(button_size))
+        .height(Length::Fixed(button_size));
+    let inspector_content: Element<'_, Message> = if ctx.is_inspecting {
+        inspector_button.style(styles::button::selected).into()
+    } else {
+        inspector_button.into()
+    };
+    let inspector_toggle = tip(inspector_content, ctx.i18n.tr("viewer-inspector-tooltip"));
+
+    // Ruler, only for images and highlighted while active
+    let ruler_button = button(icons::fill(action_icons::viewer::toolbar::ruler()))
+        .padding(spacing::XXS)
+        .width(Length::Fixed(button_size))
+        .height(Length::Fixed(button_size));
+    let ruler_content: Element<'_, Message> = if ctx.is_video {
+        ruler_button.style(styles::button::disabled()).into()
+    } else if ctx.is_measuring {
+        ruler_button
+            .on_press(Message::ToggleRuler)
+            .style(styles::button::selected)
+            .into()
+    } else {
+        ruler_button.on_press(Message::ToggleRuler).into()
+    };
+    let ruler_toggle = tip(ruler_content, ctx.i18n.tr("viewer-ruler-tooltip"));
+
+    // Composition guides, highlighted while shown
+    let guides_button = button(icons::fill(action_icons::viewer::toolbar::guides()))
+        .on_press(Message::ToggleGuides)
+        .padding(spacing::XXS)
+        .width(Length::Fixed(button_size))
+        .height(Length::Fixed(button_size));
+    let guides_content: Element<'_, Message> = if ctx.is_showing_guides {
+        guides_button.style(styles::button::selected).into()
+    } else {
+        guides_button.into()
+    };
+    let guides_toggle = tip(guides_content, ctx.i18n.tr("viewer-guides-tooltip"));
+
+    // Exporting the visible area only works for images
+    let export_content: Element<'_, Message> = if ctx.is_video {
         button(icons::fill(
-            action_icons::viewer::toolbar::rotate_clockwise(),
+            action_icons::viewer::toolbar::export_visible_area(),
         ))
         .padding(spacing::XXS)
-        .width(Length::Fixed(shared_styles::ICON_SIZE))
-        .height(Length::Fixed(shared_styles::ICON_SIZE))
+        .width(Length::Fixed(button_size))
+        .height(Length::Fixed(button_size))
         .style(styles::button::disabled())
         .into()
     } else {
         button(icons::fill(
-            action_icons::viewer::toolbar::rotate_clockwise(),
+            action_icons::viewer::toolbar::export_visible_area(),
         ))
-        .on_press(Message::RotateClockwise)
+        .on_press(Message::ExportVisibleArea)
         .padding(spacing::XXS)
-        .width(Length::Fixed(shared_styles::ICON_SIZE))
-        .height(Length::Fixed(shared_styles::ICON_SIZE))
+        .width(Length::Fixed(button_size))
+        .height(Length::Fixed(button_size))
         .into()
     };
-    let rotate_cw_button = tip(rotate_cw_content, ctx.i18n.tr("viewer-rotate-cw-tooltip"));
+    let export_button = tip(export_content, ctx.i18n.tr("viewer-export-visible-tooltip"));
 
-    // Layout: [Zoom controls + Fit] | [Rotation] | [Fullscreen] | [Delete]
+    // Layout: [Zoom controls + Fit] | [Rotation, Mirror, Export] | [Fullscreen] | [Delete]
     // Grouped by: Scale → Orientation → Display mode → Destructive action
     // Row fills width with Space::Fill at start to push controls to the right edge.
     let zoom_controls_row = Row::new()
@@ -208,7 +306,7 @@ pub fn view<'a>(
         .padding([0.0, shared_styles::CONTROL_PADDING])
         .align_y(Vertical::Center)
         .push(Space::new().width(Length::Fill))
-        // Scale group: zoom input, +/-, reset, fit-to-window
+        // Scale group: zoom input, +/-, reset, fit-to-window, view lock
         .push(zoom_label)
         .push(zoom_input)
         .push(zoom_percent_label)
@@ -216,10 +314,16 @@ pub fn view<'a>(
         .push(zoom_in_button)
         .push(reset_button)
         .push(fit_toggle)
+        .push(lock_toggle)
         .push(Space::new().width(Length::Fixed(shared_styles::CONTROL_PADDING)))
-        // Orientation group: rotation
+        // Orientation group: rotation, mirroring, pixel inspector, ruler, guides, export of what is shown
         .push(rotate_ccw_button)
         .push(rotate_cw_button)
+        .push(mirror_toggle)
+        .push(inspector_toggle)
+        .push(ruler_toggle)
+        .push(guides_toggle)
+        .push(export_button)
         .push(Space::new().width(Length::Fixed(shared_styles::CONTROL_PADDING)))
         // Display mode
         .push(fullscreen_toggle)
@@ -256,6 +360,11 @@ mod tests {
                 i18n: &i18n,
                 metadata_editor_has_changes: false,
                 is_video: false,
+                is_mirrored: false,
+                is_view_locked: false,
+                is_inspecting: false,
+                is_measuring: false,
+                is_showing_guides: false,
             },
             &zoom,
             true,
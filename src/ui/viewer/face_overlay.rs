@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Rectangles drawn over the detected faces of the displayed image.
+//!
+//! The overlay is laid out at the scaled size of the image, on top of it
+//! inside the scrollable, so it follows zoom and panning for free.
+
+use crate::media::face_detection::FaceBox;
+use crate::ui::design_tokens::palette;
+use crate::ui::theming;
+use iced::widget::canvas;
+use iced::{mouse, Element, Length, Point, Rectangle, Size, Theme};
+
+/// Width of the face outlines in logical pixels.
+const STROKE_WIDTH: f32 = 2.0;
+
+/// Builds the overlay for `faces`, detected on the unrotated image of
+/// `image_size`, shown rotated by `rotation_degrees` at `scale`.
+pub fn view<'a, Message: 'a>(
+    faces: &[FaceBox],
+    image_size: Size,
+    rotation_degrees: u16,
+    scale: f32,
+    display_size: Size,
+) -> Element<'a, Message> {
+    let boxes = faces
+        .iter()
+        .map(|face| {
+            let face = face.rotated(rotation_degrees, image_size.width, image_size.height);
+            Rectangle {
+                x: face.x * scale,
+                y: face.y * scale,
+                width: face.width * scale,
+                height: face.height * scale,
+            }
+        })
+        .collect();
+
+    canvas::Canvas::new(FaceOverlayProgram { boxes })
+        .width(Length::Fixed(display_size.width))
+        .height(Length::Fixed(display_size.height))
+        .into()
+}
+
+struct FaceOverlayProgram {
+    /// Face rectangles in canvas coordinates.
+    boxes: Vec<Rectangle>,
+}
+
+impl<Message> canvas::Program<Message> for FaceOverlayProgram {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        for rect in &self.boxes {
+            let path = canvas::Path::rectangle(Point::new(rect.x, rect.y), rect.size());
+            // Dark halo under the outline keeps it visible on bright skin
+            frame.stroke(
+                &path,
+                canvas::Stroke::default()
+                    .with_color(palette::BLACK)
+                    .with_width(STROKE_WIDTH + 2.0),
+            );
+            frame.stroke(
+                &path,
+                canvas::Stroke::default()
+                    .with_color(theming::accent(theme).hover)
+                    .with_width(STROKE_WIDTH),
+            );
+        }
+        vec![frame.into_geometry()]
+    }
+}
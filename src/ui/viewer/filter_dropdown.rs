@@ -2,10 +2,11 @@
 //! Filter dropdown component for the viewer toolbar.
 //!
 //! Provides a dropdown menu for filtering media during navigation.
-//! Supports filtering by media type (images/videos) and date range.
+//! Supports filtering by media type (images/videos), date range, and
+//! minimum star rating.
 
 use crate::i18n::fluent::I18n;
-use crate::media::filter::{DateFilterField, MediaFilter, MediaTypeFilter};
+use crate::media::filter::{DateFilterField, MediaFilter, MediaTypeFilter, RatingFilter};
 use crate::ui::action_icons;
 use crate::ui::design_tokens::{radius, spacing, typography};
 use crate::ui::icons;
@@ -59,6 +60,8 @@ pub enum Message {
     DateSubmit(DateTarget),
     /// Clear a date (start or end).
     ClearDate(DateTarget),
+    /// Minimum rating changed (`None` disables rating filtering).
+    RatingChanged(Option<RatingFilter>),
     /// Reset all filters to default.
     ResetFilters,
 }
@@ -361,24 +364,29 @@ fn build_tooltip_text(ctx: &ViewContext<'_>) -> String {
         MediaTypeFilter::All => {}
     }
 
+    // Add rating filter description
+    if let Some(rating) = ctx.filter.rating {
+        parts.push(rating_label(ctx.i18n, rating));
+    }
+
     // Add date filter description
     if let Some(ref date_range) = ctx.filter.date_range {
         let date_desc = match (date_range.start, date_range.end) {
             (Some(start), Some(end)) => {
-                let start_str = format_system_time(start);
-                let end_str = format_system_time(end);
+                let start_str = format_display_date(ctx.i18n, start);
+                let end_str = format_display_date(ctx.i18n, end);
                 ctx.i18n.tr_with_args(
                     "filter-tooltip-date-range",
                     &[("start", &start_str), ("end", &end_str)],
                 )
             }
             (Some(start), None) => {
-                let date_str = format_system_time(start);
+                let date_str = format_display_date(ctx.i18n, start);
                 ctx.i18n
                     .tr_with_args("filter-tooltip-date-from", &[("date", &date_str)])
             }
             (None, Some(end)) => {
-                let date_str = format_system_time(end);
+                let date_str = format_display_date(ctx.i18n, end);
                 ctx.i18n
                     .tr_with_args("filter-tooltip-date-to", &[("date", &date_str)])
             }
@@ -438,6 +446,9 @@ fn build_dropdown_panel(ctx: ViewContext<'_>) -> Element<'_, Message> {
     // Media type filter section
     let media_type_section = build_media_type_section(&ctx);
 
+    // Rating filter section
+    let rating_section = build_rating_section(&ctx);
+
     // Date filter section
     let date_section = build_date_section(&ctx);
 
@@ -465,6 +476,7 @@ fn build_dropdown_panel(ctx: ViewContext<'_>) -> Element<'_, Message> {
         .spacing(spacing::SM)
         .push(header)
         .push(media_type_section)
+        .push(rating_section)
         .push(date_section);
 
     if let Some(footer_elem) = footer {
@@ -551,6 +563,47 @@ fn build_media_type_section<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
         .into()
 }
 
+/// Build the minimum rating filter section.
+fn build_rating_section<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
+    let label = Text::new(ctx.i18n.tr("filter-rating-label")).size(typography::BODY);
+
+    let options: Vec<RatingOption> = std::iter::once(RatingOption {
+        rating: None,
+        label: ctx.i18n.tr("filter-rating-any"),
+    })
+    .chain(
+        (RatingFilter::MIN_STARS..=RatingFilter::MAX_STARS).map(|stars| {
+            let rating = RatingFilter::new(stars);
+            RatingOption {
+                rating: Some(rating),
+                label: rating_label(ctx.i18n, rating),
+            }
+        }),
+    )
+    .collect();
+
+    let selected = options
+        .iter()
+        .find(|opt| opt.rating == ctx.filter.rating)
+        .cloned();
+
+    let picker = pick_list(options, selected, |opt| Message::RatingChanged(opt.rating))
+        .padding(spacing::XS)
+        .width(Length::Fill);
+
+    Column::new()
+        .spacing(spacing::XXS)
+        .push(label)
+        .push(picker)
+        .into()
+}
+
+/// Format a minimum rating as stars (e.g., "★★★ and up").
+fn rating_label(i18n: &I18n, rating: RatingFilter) -> String {
+    let stars = "★".repeat(usize::from(rating.min_stars()));
+    i18n.tr_with_args("filter-rating-min", &[("stars", &stars)])
+}
+
 /// Build the date filter section.
 fn build_date_section<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
     let date_filter_enabled = ctx.filter.date_range.is_some();
@@ -788,6 +841,8 @@ fn build_segment_input<'a>(
                 },
             };
 
+            style = styles::text_input::with_focus_outline(theme, status, style);
+
             // Show red border if invalid (and not empty)
             if !is_valid {
                 style.border.color = palette.danger.base.color;
@@ -816,6 +871,19 @@ impl std::fmt::Display for MediaTypeOption {
     }
 }
 
+/// Minimum rating option for the pick list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RatingOption {
+    rating: Option<RatingFilter>,
+    label: String,
+}
+
+impl std::fmt::Display for RatingOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
 /// Date field option for the pick list.
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct DateFieldOption {
@@ -880,6 +948,12 @@ pub fn format_system_time(time: SystemTime) -> String {
     format!("{year:04}-{month:02}-{day:02}")
 }
 
+/// Formats a `SystemTime` as a date in the conventions of the current locale.
+fn format_display_date(i18n: &I18n, time: SystemTime) -> String {
+    let datetime: chrono::DateTime<chrono::Utc> = time.into();
+    i18n.format_date(datetime.date_naive())
+}
+
 /// Convert days since epoch to (year, month, day).
 fn days_to_ymd(days: u64) -> (u32, u32, u32) {
     // Algorithm based on Howard Hinnant's date algorithms
@@ -926,6 +1000,14 @@ fn ymd_to_days(year: u32, month: u32, day: u32) -> Option<u32> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn rating_label_repeats_stars() {
+        let i18n = I18n::default();
+        let label = rating_label(&i18n, RatingFilter::new(3));
+        assert!(label.contains("★★★"));
+        assert!(!label.contains("★★★★"));
+    }
+
     #[test]
     fn filter_dropdown_state_default() {
         let state = FilterDropdownState::new();
@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Information overlay of fullscreen mode.
+//!
+//! In fullscreen the position counter gives way to a box whose elements are
+//! chosen in the settings (`[fullscreen]` section): file name, position in
+//! the directory, clock and luminance histogram, in one corner or edge of
+//! the screen and at a chosen opacity. The overlay shows and hides with the
+//! other fullscreen controls.
+
+use crate::config::{
+    FullscreenConfig, OverlayPosition, DEFAULT_OVERLAY_OPACITY_PERCENT,
+    MAX_OVERLAY_OPACITY_PERCENT, MIN_OVERLAY_OPACITY_PERCENT,
+};
+use crate::ui::design_tokens::{spacing, typography};
+use crate::ui::styles;
+use crate::ui::viewer::component::Message;
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::{Column, Container, Row, Space, Text};
+use iced::{Element, Length, Padding};
+
+/// Height of the histogram, in logical pixels.
+const HISTOGRAM_HEIGHT: f32 = 40.0;
+
+/// Width of one histogram bar, in logical pixels.
+const HISTOGRAM_BAR_WIDTH: f32 = 2.0;
+
+/// Elements, position and opacity of the fullscreen overlay.
+// Allow excessive bools: each element is shown or hidden independently.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub filename: bool,
+    /// Position in the directory ("12/340").
+    pub index: bool,
+    pub clock: bool,
+    /// Previous/next arrows on the sides of the screen.
+    pub arrows: bool,
+    pub histogram: bool,
+    pub position: OverlayPosition,
+    pub opacity_percent: u32,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::from_config(&FullscreenConfig::default())
+    }
+}
+
+impl Options {
+    /// Builds the options from the `[fullscreen]` configuration section.
+    #[must_use]
+    pub fn from_config(config: &FullscreenConfig) -> Self {
+        Self {
+            filename: config.show_filename.unwrap_or(false),
+            index: config.show_index.unwrap_or(true),
+            clock: config.show_clock.unwrap_or(false),
+            arrows: config.show_arrows.unwrap_or(true),
+            histogram: config.show_histogram.unwrap_or(false),
+            position: config.overlay_position.unwrap_or_default(),
+            opacity_percent: config
+                .overlay_opacity_percent
+                .unwrap_or(DEFAULT_OVERLAY_OPACITY_PERCENT)
+                .clamp(MIN_OVERLAY_OPACITY_PERCENT, MAX_OVERLAY_OPACITY_PERCENT),
+        }
+    }
+
+    /// Writes the options to the `[fullscreen]` configuration section,
+    /// leaving the overlay timeout as it is.
+    pub fn apply_to(self, config: &mut FullscreenConfig) {
+        config.show_filename = Some(self.filename);
+        config.show_index = Some(self.index);
+        config.show_clock = Some(self.clock);
+        config.show_arrows = Some(self.arrows);
+        config.show_histogram = Some(self.histogram);
+        config.overlay_position = Some(self.position);
+        config.overlay_opacity_percent = Some(self.opacity_percent);
+    }
+
+    // Percentages are small integers, exact in f32
+    #[allow(clippy::cast_precision_loss)]
+    fn alpha(self) -> f32 {
+        self.opacity_percent as f32 / 100.0
+    }
+}
+
+/// What the overlay shows for the current media.
+pub struct Info<'a> {
+    pub options: Options,
+    pub filename: Option<String>,
+    /// Zero-based position in the directory.
+    pub current_index: Option<usize>,
+    pub total_count: usize,
+    /// Whether the directory is still being scanned (`total_count` grows).
+    pub scan_in_progress: bool,
+    pub histogram: Option<&'a [u32]>,
+}
+
+/// Returns the index text, such as "12/340", with the growing total of a
+/// directory still being scanned marked as incomplete.
+#[must_use]
+pub fn index_text(current_index: usize, total_count: usize, scan_in_progress: bool) -> String {
+    if scan_in_progress {
+        format!("{}/{}…", current_index + 1, total_count)
+    } else {
+        format!("{}/{}", current_index + 1, total_count)
+    }
+}
+
+/// Renders the overlay over the whole pane, or `None` when no element is
+/// enabled or has anything to show.
+#[must_use]
+pub fn view<'a>(info: &Info<'_>) -> Option<Element<'a, Message>> {
+    let options = info.options;
+    let alpha = options.alpha();
+
+    let mut line = Row::new().spacing(spacing::SM).align_y(Vertical::Center);
+    let mut has_line = false;
+    if options.filename {
+        if let Some(filename) = &info.filename {
+            line = line.push(Text::new(filename.clone()).size(typography::BODY));
+            has_line = true;
+        }
+    }
+    if options.index && (info.total_count > 1 || info.scan_in_progress) {
+        if let Some(current) = info.current_index {
+            line = line.push(
+                Text::new(index_text(current, info.total_count, info.scan_in_progress))
+                    .size(typography::BODY),
+            );
+            has_line = true;
+        }
+    }
+    if options.clock {
+        let time = chrono::Local::now().format("%H:%M").to_string();
+        line = line.push(Text::new(time).size(typography::BODY));
+        has_line = true;
+    }
+
+    let histogram = info
+        .histogram
+        .filter(|_| options.histogram)
+        .map(|bins| histogram_view(bins, alpha));
+
+    if !has_line && histogram.is_none() {
+        return None;
+    }
+
+    let horizontal = match options.position {
+        OverlayPosition::TopLeft | OverlayPosition::BottomLeft => Horizontal::Left,
+        OverlayPosition::TopCenter | OverlayPosition::BottomCenter => Horizontal::Center,
+        OverlayPosition::TopRight | OverlayPosition::BottomRight => Horizontal::Right,
+    };
+    let vertical = if options.position.is_top() {
+        Vertical::Top
+    } else {
+        Vertical::Bottom
+    };
+
+    let mut content = Column::new().spacing(spacing::XXS).align_x(horizontal);
+    if has_line {
+        content = content.push(line);
+    }
+    if let Some(histogram) = histogram {
+        content = content.push(histogram);
+    }
+
+    let indicator = Container::new(content)
+        .padding(Padding {
+            top: spacing::XXS,
+            right: spacing::XS,
+            bottom: spacing::XXS,
+            left: spacing::XS,
+        })
+        .style(styles::overlay::faded_indicator(12.0, alpha));
+
+    Some(
+        Container::new(indicator)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(spacing::SM)
+            .align_x(horizontal)
+            .align_y(vertical)
+            .into(),
+    )
+}
+
+/// Draws the histogram as bars scaled to the fullest bucket.
+// Bucket counts and heights are far below f32 precision limits
+#[allow(clippy::cast_precision_loss)]
+fn histogram_view<'a>(bins: &[u32], alpha: f32) -> Element<'a, Message> {
+    let peak = bins.iter().copied().max().unwrap_or(0).max(1) as f32;
+    let bars = bins.iter().fold(
+        Row::new()
+            .height(Length::Fixed(HISTOGRAM_HEIGHT))
+            .align_y(Vertical::Bottom),
+        |row, &count| {
+            let height = (count as f32 / peak * HISTOGRAM_HEIGHT).max(1.0);
+            row.push(
+                Container::new(Space::new())
+                    .width(Length::Fixed(HISTOGRAM_BAR_WIDTH))
+                    .height(Length::Fixed(height))
+                    .style(styles::overlay::histogram_bar(alpha)),
+            )
+        },
+    );
+    bars.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_round_trip_through_config() {
+        let options = Options {
+            filename: true,
+            index: false,
+            clock: true,
+            arrows: false,
+            histogram: true,
+            position: OverlayPosition::TopRight,
+            opacity_percent: 60,
+        };
+        let mut config = FullscreenConfig::default();
+        options.apply_to(&mut config);
+        assert_eq!(Options::from_config(&config), options);
+        assert_eq!(
+            config.overlay_timeout_secs,
+            FullscreenConfig::default().overlay_timeout_secs
+        );
+    }
+
+    #[test]
+    fn missing_settings_keep_the_previous_overlay() {
+        let config = FullscreenConfig {
+            show_filename: None,
+            show_index: None,
+            show_clock: None,
+            show_arrows: None,
+            show_histogram: None,
+            overlay_position: None,
+            overlay_opacity_percent: Some(5),
+            ..FullscreenConfig::default()
+        };
+        let options = Options::from_config(&config);
+        assert!(options.index && options.arrows);
+        assert!(!options.filename && !options.clock && !options.histogram);
+        assert_eq!(options.position, OverlayPosition::BottomCenter);
+        assert_eq!(options.opacity_percent, MIN_OVERLAY_OPACITY_PERCENT);
+    }
+
+    #[test]
+    fn index_text_marks_incomplete_scans() {
+        assert_eq!(index_text(11, 340, false), "12/340");
+        assert_eq!(index_text(0, 50, true), "1/50…");
+    }
+}
@@ -0,0 +1,302 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Ruler overlay.
+//!
+//! With the ruler on, dragging over an image draws a measurement labelled
+//! with its length in image pixels (and in millimeters and inches when the
+//! file records its pixel density) and its angle from the horizontal as
+//! displayed. The measurements of each image are kept for the session and
+//! drawn over it whenever the ruler is on. Like the face outlines, the
+//! overlay is laid out at the scaled size of the image inside the
+//! scrollable, so it follows zoom and panning.
+
+use crate::ui::design_tokens::{opacity, palette, typography};
+use crate::ui::theming;
+use iced::widget::canvas;
+use iced::{mouse, Color, Element, Length, Pixels, Point, Rectangle, Size, Theme};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Shortest measurement kept, in image pixels; shorter drags are clicks.
+pub const MIN_LENGTH: f32 = 1.0;
+
+/// Width of the measurement lines, in logical pixels.
+const STROKE_WIDTH: f32 = 2.0;
+
+/// Radius of the dots marking the ends of a measurement.
+const END_RADIUS: f32 = 3.0;
+
+/// Padding around the label text, in logical pixels.
+const LABEL_PADDING: f32 = 3.0;
+
+/// A line measured on an image, in pixels of the unrotated image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Measurement {
+    /// Returns the length in image pixels.
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        self.start.distance(self.end)
+    }
+
+    /// Returns the angle in degrees, counter-clockwise from the horizontal,
+    /// of the line shown on an image of `image_size` turned clockwise by
+    /// `rotation` degrees.
+    #[must_use]
+    pub fn angle(&self, rotation: u16, image_size: Size) -> f32 {
+        let start = to_displayed(self.start, rotation, image_size);
+        let end = to_displayed(self.end, rotation, image_size);
+        // Screen y grows downwards
+        (start.y - end.y).atan2(end.x - start.x).to_degrees()
+    }
+
+    /// Returns the label of the measurement, with physical lengths when the
+    /// pixel density of the image is known.
+    #[must_use]
+    pub fn label(&self, rotation: u16, image_size: Size, dots_per_inch: Option<f64>) -> String {
+        let length = self.length();
+        let angle = self.angle(rotation, image_size);
+        match dots_per_inch {
+            Some(dpi) => {
+                let inches = f64::from(length) / dpi;
+                format!(
+                    "{length:.1} px · {:.1} mm · {inches:.2} in · {angle:.1}°",
+                    inches * 25.4
+                )
+            }
+            None => format!("{length:.1} px · {angle:.1}°"),
+        }
+    }
+}
+
+/// Returns where `point`, in pixels of an unrotated image of `image_size`,
+/// is shown once the image is turned clockwise by `rotation` degrees.
+#[must_use]
+pub fn to_displayed(point: Point, rotation: u16, image_size: Size) -> Point {
+    let Size { width, height } = image_size;
+    match rotation {
+        90 => Point::new(height - point.y, point.x),
+        180 => Point::new(width - point.x, height - point.y),
+        270 => Point::new(point.y, width - point.x),
+        _ => point,
+    }
+}
+
+/// Returns the point of the unrotated image of `image_size` shown at
+/// `point` once the image is turned clockwise by `rotation` degrees.
+#[must_use]
+pub fn to_source(point: Point, rotation: u16, image_size: Size) -> Point {
+    let Size { width, height } = image_size;
+    match rotation {
+        90 => Point::new(point.y, height - point.x),
+        180 => Point::new(width - point.x, height - point.y),
+        270 => Point::new(width - point.y, point.x),
+        _ => point,
+    }
+}
+
+/// Measurements of the images seen during the session.
+#[derive(Debug, Clone, Default)]
+pub struct Measurements {
+    by_image: HashMap<PathBuf, Vec<Measurement>>,
+}
+
+impl Measurements {
+    /// Returns the measurements of the image at `path`, oldest first.
+    #[must_use]
+    pub fn get(&self, path: &Path) -> &[Measurement] {
+        self.by_image.get(path).map_or(&[], Vec::as_slice)
+    }
+
+    /// Adds a measurement to the image at `path`.
+    pub fn add(&mut self, path: &Path, measurement: Measurement) {
+        self.by_image
+            .entry(path.to_path_buf())
+            .or_default()
+            .push(measurement);
+    }
+
+    /// Removes the last measurement of the image at `path`. Returns `false`
+    /// if it had none.
+    pub fn remove_last(&mut self, path: &Path) -> bool {
+        let Some(measurements) = self.by_image.get_mut(path) else {
+            return false;
+        };
+        let removed = measurements.pop().is_some();
+        if measurements.is_empty() {
+            self.by_image.remove(path);
+        }
+        removed
+    }
+}
+
+/// What the ruler draws over the displayed image.
+#[derive(Debug, Clone)]
+pub struct Overlay {
+    /// Measurements kept for the image, then the one being drawn.
+    pub measurements: Vec<Measurement>,
+    /// Size of the unrotated image, in pixels.
+    pub image_size: Size,
+    /// Clockwise rotation of the image on screen, in degrees.
+    pub rotation: u16,
+    pub dots_per_inch: Option<f64>,
+}
+
+/// Builds the overlay for an image shown at `scale`, laid out at
+/// `display_size`.
+pub fn view<'a, Message: 'a>(
+    overlay: &Overlay,
+    scale: f32,
+    display_size: Size,
+) -> Element<'a, Message> {
+    let lines = overlay
+        .measurements
+        .iter()
+        .map(|measurement| {
+            let scaled = |point| {
+                let point = to_displayed(point, overlay.rotation, overlay.image_size);
+                Point::new(point.x * scale, point.y * scale)
+            };
+            (
+                scaled(measurement.start),
+                scaled(measurement.end),
+                measurement.label(overlay.rotation, overlay.image_size, overlay.dots_per_inch),
+            )
+        })
+        .collect();
+
+    canvas::Canvas::new(RulerProgram { lines })
+        .width(Length::Fixed(display_size.width))
+        .height(Length::Fixed(display_size.height))
+        .into()
+}
+
+struct RulerProgram {
+    /// End points in canvas coordinates, and label of each measurement.
+    lines: Vec<(Point, Point, String)>,
+}
+
+impl<Message> canvas::Program<Message> for RulerProgram {
+    type State = ();
+
+    // Label lengths are a few dozen characters
+    #[allow(clippy::cast_precision_loss)]
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let accent = theming::accent(theme).hover;
+
+        for (start, end, label) in &self.lines {
+            let path = canvas::Path::line(*start, *end);
+            // Dark halo under the line keeps it visible on bright images
+            frame.stroke(
+                &path,
+                canvas::Stroke::default()
+                    .with_color(palette::BLACK)
+                    .with_width(STROKE_WIDTH + 2.0),
+            );
+            frame.stroke(
+                &path,
+                canvas::Stroke::default()
+                    .with_color(accent)
+                    .with_width(STROKE_WIDTH),
+            );
+            for point in [start, end] {
+                frame.fill(&canvas::Path::circle(*point, END_RADIUS), accent);
+            }
+
+            // Label above the middle of the line; the width is estimated as
+            // the canvas can't measure text
+            let text_size = typography::CAPTION;
+            let label_size = Size::new(
+                label.chars().count() as f32 * text_size * 0.55 + 2.0 * LABEL_PADDING,
+                text_size + 2.0 * LABEL_PADDING,
+            );
+            let middle = Point::new((start.x + end.x) / 2.0, (start.y + end.y) / 2.0);
+            let origin = Point::new(
+                middle.x - label_size.width / 2.0,
+                middle.y - label_size.height - END_RADIUS * 2.0,
+            );
+            frame.fill_rectangle(
+                origin,
+                label_size,
+                Color {
+                    a: opacity::OVERLAY_STRONG,
+                    ..palette::BLACK
+                },
+            );
+            frame.fill_text(canvas::Text {
+                content: label.clone(),
+                position: Point::new(origin.x + LABEL_PADDING, origin.y + LABEL_PADDING),
+                color: palette::WHITE,
+                size: Pixels(text_size),
+                ..canvas::Text::default()
+            });
+        }
+        vec![frame.into_geometry()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IMAGE: Size = Size::new(400.0, 300.0);
+
+    #[test]
+    fn rotation_round_trips() {
+        let point = Point::new(10.0, 20.0);
+        for rotation in [0, 90, 180, 270] {
+            let displayed = to_displayed(point, rotation, IMAGE);
+            assert_eq!(to_source(displayed, rotation, IMAGE), point);
+        }
+        // The top-left corner ends up top-right after a quarter turn
+        assert_eq!(
+            to_displayed(Point::ORIGIN, 90, IMAGE),
+            Point::new(300.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn label_shows_physical_length_when_density_is_known() {
+        let measurement = Measurement {
+            start: Point::new(0.0, 100.0),
+            end: Point::new(300.0, 100.0),
+        };
+        assert_eq!(measurement.label(0, IMAGE, None), "300.0 px · 0.0°");
+        assert_eq!(
+            measurement.label(0, IMAGE, Some(300.0)),
+            "300.0 px · 25.4 mm · 1.00 in · 0.0°"
+        );
+        // Turned a quarter clockwise, the line points down
+        assert!((measurement.angle(90, IMAGE) + 90.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn measurements_are_kept_per_image() {
+        let mut measurements = Measurements::default();
+        let a = Path::new("/shots/a.png");
+        let line = Measurement {
+            start: Point::ORIGIN,
+            end: Point::new(3.0, 4.0),
+        };
+        measurements.add(a, line);
+        measurements.add(a, line);
+        assert_eq!(measurements.get(a).len(), 2);
+        assert!(measurements.get(Path::new("/shots/b.png")).is_empty());
+
+        assert!(measurements.remove_last(a));
+        assert!(measurements.remove_last(a));
+        assert!(!measurements.remove_last(a));
+        assert!((line.length() - 5.0).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Navigator inset shown while zoomed in.
+//!
+//! When an image is zoomed past 100% and no longer fits the pane, a
+//! thumbnail of the whole image sits in a corner with the visible region
+//! outlined. Clicking or dragging in the thumbnail centers the view there.
+//! The inset can be turned off in the settings.
+
+use crate::media::ImageData;
+use crate::ui::design_tokens::{opacity, palette};
+use crate::ui::styles;
+use crate::ui::theming;
+use crate::ui::viewer::component::Message;
+use iced::widget::image::FilterMethod;
+use iced::widget::scrollable::AbsoluteOffset;
+use iced::widget::{canvas, Container, Image, Stack};
+use iced::{mouse, Color, ContentFit, Element, Length, Point, Rectangle, Size, Theme};
+
+/// Longest side of the thumbnail, in logical pixels.
+const MAX_SIZE: f32 = 160.0;
+
+/// Width of the outline of the visible region.
+const STROKE_WIDTH: f32 = 1.5;
+
+/// Returns the part of media of `media_size`, scrolled to `offset` in a pane
+/// of `viewport` size, that is visible, in fractions of the media size.
+/// Returns `None` when the media is not zoomed past 100% or fits the pane.
+#[must_use]
+pub fn visible_region(
+    media_size: Size,
+    viewport: Size,
+    offset: AbsoluteOffset,
+    zoom_percent: f32,
+) -> Option<Rectangle> {
+    let overflows = media_size.width > viewport.width || media_size.height > viewport.height;
+    if zoom_percent <= 100.0 || !overflows || media_size.width <= 0.0 || media_size.height <= 0.0 {
+        return None;
+    }
+    let width = (viewport.width / media_size.width).min(1.0);
+    let height = (viewport.height / media_size.height).min(1.0);
+    Some(Rectangle {
+        x: (offset.x / media_size.width).clamp(0.0, 1.0 - width),
+        y: (offset.y / media_size.height).clamp(0.0, 1.0 - height),
+        width,
+        height,
+    })
+}
+
+/// Builds the thumbnail of `image` with the `visible` region outlined.
+#[must_use]
+// Image dimensions are far below f32 precision limits
+#[allow(clippy::cast_precision_loss)]
+pub fn view(image: &ImageData, visible: Rectangle) -> Element<'_, Message> {
+    let scale = MAX_SIZE / image.width.max(image.height).max(1) as f32;
+    let size = Size::new(image.width as f32 * scale, image.height as f32 * scale);
+
+    let thumbnail = Stack::new()
+        .push(
+            Image::new(image.handle.clone())
+                .content_fit(ContentFit::Fill)
+                .filter_method(FilterMethod::Linear)
+                .width(Length::Fixed(size.width))
+                .height(Length::Fixed(size.height)),
+        )
+        .push(
+            canvas::Canvas::new(MinimapProgram { visible })
+                .width(Length::Fixed(size.width))
+                .height(Length::Fixed(size.height)),
+        );
+
+    Container::new(thumbnail)
+        .padding(2.0)
+        .style(styles::overlay::indicator(4.0))
+        .into()
+}
+
+struct MinimapProgram {
+    /// Visible region, in fractions of the thumbnail size.
+    visible: Rectangle,
+}
+
+impl MinimapProgram {
+    /// Returns the message centering the view on `cursor`, kept inside the
+    /// thumbnail so dragging past its edges scrolls to the edge.
+    fn move_to(cursor: Point, bounds: Rectangle) -> canvas::Action<Message> {
+        canvas::Action::publish(Message::MinimapMoved {
+            x: ((cursor.x - bounds.x) / bounds.width).clamp(0.0, 1.0),
+            y: ((cursor.y - bounds.y) / bounds.height).clamp(0.0, 1.0),
+        })
+        .and_capture()
+    }
+}
+
+impl canvas::Program<Message> for MinimapProgram {
+    /// Whether the visible region is being dragged.
+    type State = bool;
+
+    fn update(
+        &self,
+        dragging: &mut Self::State,
+        event: &iced::Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<canvas::Action<Message>> {
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let position = cursor.position_over(bounds)?;
+                *dragging = true;
+                Some(Self::move_to(position, bounds))
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position }) if *dragging => {
+                Some(Self::move_to(*position, bounds))
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) if *dragging => {
+                *dragging = false;
+                Some(canvas::Action::capture())
+            }
+            _ => None,
+        }
+    }
+
+    fn draw(
+        &self,
+        _dragging: &Self::State,
+        renderer: &iced::Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let size = bounds.size();
+        let region = Rectangle {
+            x: self.visible.x * size.width,
+            y: self.visible.y * size.height,
+            width: self.visible.width * size.width,
+            height: self.visible.height * size.height,
+        };
+
+        // Dim what is outside the view: above, below, left and right
+        let shade = Color {
+            a: opacity::OVERLAY_MEDIUM,
+            ..palette::BLACK
+        };
+        frame.fill_rectangle(Point::ORIGIN, Size::new(size.width, region.y), shade);
+        frame.fill_rectangle(
+            Point::new(0.0, region.y + region.height),
+            Size::new(size.width, size.height - region.y - region.height),
+            shade,
+        );
+        frame.fill_rectangle(
+            Point::new(0.0, region.y),
+            Size::new(region.x, region.height),
+            shade,
+        );
+        frame.fill_rectangle(
+            Point::new(region.x + region.width, region.y),
+            Size::new(size.width - region.x - region.width, region.height),
+            shade,
+        );
+
+        frame.stroke(
+            &canvas::Path::rectangle(region.position(), region.size()),
+            canvas::Stroke::default()
+                .with_width(STROKE_WIDTH)
+                .with_color(theming::accent(theme).hover),
+        );
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        dragging: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if *dragging {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visible_region_follows_the_scroll_offset() {
+        let region = visible_region(
+            Size::new(1000.0, 500.0),
+            Size::new(250.0, 250.0),
+            AbsoluteOffset { x: 500.0, y: 0.0 },
+            200.0,
+        )
+        .expect("zoomed image overflows the pane");
+        assert!((region.x - 0.5).abs() < f32::EPSILON);
+        assert!((region.width - 0.25).abs() < f32::EPSILON);
+        assert!((region.height - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn no_region_when_the_image_fits_or_is_not_zoomed_in() {
+        let offset = AbsoluteOffset { x: 0.0, y: 0.0 };
+        assert!(visible_region(
+            Size::new(200.0, 100.0),
+            Size::new(400.0, 400.0),
+            offset,
+            200.0
+        )
+        .is_none());
+        assert!(visible_region(
+            Size::new(1000.0, 500.0),
+            Size::new(400.0, 400.0),
+            offset,
+            100.0
+        )
+        .is_none());
+    }
+}
@@ -1,14 +1,22 @@
 // SPDX-License-Identifier: MPL-2.0
 //! Image viewer module responsible for rendering loaded images and related UI.
 
+pub mod audio_strip;
 pub mod component;
 pub mod controls;
 pub mod empty_state;
+pub mod face_overlay;
 pub mod filter_dropdown;
+pub mod fullscreen_overlay;
+pub mod measurement;
+pub mod minimap;
 pub mod pane;
+pub mod pixel_inspector;
+pub mod quick_search;
 pub mod shared_styles;
 pub mod state;
 pub mod video_controls;
+pub mod view_memory;
 
 use self::component::Message;
 
@@ -32,6 +40,7 @@ pub enum HudIconKind {
     Zoom,
     Video { has_audio: bool },
     Rotation,
+    AlphaPreview,
 }
 
 /// A single HUD entry combining an icon kind and descriptive text.
@@ -71,17 +80,21 @@ pub fn view_media_with_rotation(
             }
         }
         MediaData::Video(video_data) => {
-            // Video rotation not yet supported - show unrotated thumbnail
-            // TODO: Support video rotation in shader
-            (
-                video_data.thumbnail.handle.clone(),
-                video_data.thumbnail.width,
-                video_data.thumbnail.height,
-            )
+            // Frames are rotated by the shader; only the thumbnail is an image
+            if rotation.is_rotated() {
+                let rotated = video_data.thumbnail.rotated(rotation.degrees());
+                (rotated.handle.clone(), rotated.width, rotated.height)
+            } else {
+                (
+                    video_data.thumbnail.handle.clone(),
+                    video_data.thumbnail.width,
+                    video_data.thumbnail.height,
+                )
+            }
         }
     };
 
-    let scale = (zoom_percent / 100.0).max(0.01);
+    let scale = crate::ui::state::zoom::media_scale(zoom_percent).max(0.01);
     let scaled_width = (width as f32 * scale).max(1.0);
     let scaled_height = (height as f32 * scale).max(1.0);
 
@@ -94,7 +107,7 @@ pub fn view_media_with_rotation(
 /// Renders an image directly from `ImageData` (used for cached rotated images).
 #[allow(clippy::cast_precision_loss)] // u32 to f32 for dimensions: f32 is exact up to 16M
 pub fn view_image(image_data: &crate::media::ImageData, zoom_percent: f32) -> Element<'_, Message> {
-    let scale = (zoom_percent / 100.0).max(0.01);
+    let scale = crate::ui::state::zoom::media_scale(zoom_percent).max(0.01);
     let scaled_width = (image_data.width as f32 * scale).max(1.0);
     let scaled_height = (image_data.height as f32 * scale).max(1.0);
 
@@ -110,6 +123,8 @@ pub struct ViewContext<'a> {
     pub image: Option<ImageContext<'a>>,
     pub is_loading: bool,
     pub spinner_rotation: f32,
+    /// Blurred preview of the image being loaded, if available.
+    pub loading_preview: Option<&'a crate::media::ImageData>,
 }
 
 pub struct ErrorContext<'a> {
@@ -129,6 +144,9 @@ pub struct ImageContext<'a> {
     pub pane_model: pane::ViewModel<'a>,
     pub controls_visible: bool,
     pub is_fullscreen: bool,
+    /// Whether the controls float over the media (fullscreen and compact
+    /// mode) instead of sitting above it.
+    pub overlay_controls: bool,
     pub video_playback_state: Option<video_controls::PlaybackState>,
     /// True if the current media is a video (used to disable Edit button).
     pub is_video: bool,
@@ -146,7 +164,7 @@ pub fn view(ctx: ViewContext<'_>) -> Element<'_, Message> {
 
     // Show loading spinner if loading
     if ctx.is_loading {
-        return loading_view(ctx.i18n, ctx.spinner_rotation);
+        return loading_view(ctx.i18n, ctx.spinner_rotation, ctx.loading_preview);
     }
 
     // No media loaded - show empty state
@@ -170,7 +188,11 @@ fn error_view<'a>(i18n: &'a I18n, error: ErrorContext<'a>) -> Element<'a, Messag
     centered_error_view(error_display)
 }
 
-fn loading_view(i18n: &I18n, rotation: f32) -> Element<'_, Message> {
+fn loading_view<'a>(
+    i18n: &'a I18n,
+    rotation: f32,
+    preview: Option<&'a crate::media::ImageData>,
+) -> Element<'a, Message> {
     let spinner = AnimatedSpinner::new(theme::overlay_arrow_light_color(), rotation).into_element();
 
     let loading_text = Text::new(i18n.tr("media-loading")).size(sizing::ICON_SM);
@@ -181,12 +203,19 @@ fn loading_view(i18n: &I18n, rotation: f32) -> Element<'_, Message> {
         .push(spinner)
         .push(loading_text);
 
-    Container::new(loading_content)
+    let spinner_layer = Container::new(loading_content)
         .width(Length::Fill)
         .height(Length::Fill)
         .align_x(alignment::Horizontal::Center)
-        .align_y(alignment::Vertical::Center)
-        .into()
+        .align_y(alignment::Vertical::Center);
+
+    match preview {
+        Some(preview) => Stack::new()
+            .push(pane::loading_preview(preview))
+            .push(spinner_layer)
+            .into(),
+        None => spinner_layer.into(),
+    }
 }
 
 fn image_view(ctx: ImageContext<'_>) -> Element<'_, Message> {
@@ -200,8 +229,8 @@ fn image_view(ctx: ImageContext<'_>) -> Element<'_, Message> {
         None
     };
 
-    // Fullscreen mode: overlay controls on top of pane
-    if ctx.is_fullscreen {
+    // Fullscreen and compact modes: overlay controls on top of pane
+    if ctx.overlay_controls {
         let mut stack = Stack::new().width(Length::Fill).height(Length::Fill);
 
         // Layer 1: Pane (image + navigation arrows)
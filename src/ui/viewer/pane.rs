@@ -3,29 +3,57 @@
 //! background, cursor interaction, and position indicator.
 
 use crate::config::BackgroundTheme;
+use crate::media::face_detection::FaceBox;
 use crate::media::MediaData;
 use crate::ui::action_icons;
 use crate::ui::components::checkerboard;
 use crate::ui::design_tokens::{opacity, radius, sizing, spacing, typography};
+use crate::ui::guides;
 use crate::ui::icons;
-use crate::ui::state::RotationAngle;
+use crate::ui::state::{MatteColor, RotationAngle};
 use crate::ui::styles;
 use crate::ui::theme;
-use crate::ui::viewer::{component::Message, HudIconKind, HudLine};
+use crate::ui::viewer::{
+    component::Message, face_overlay, fullscreen_overlay, measurement, minimap, pixel_inspector,
+    HudIconKind, HudLine,
+};
 use crate::ui::widgets::{wheel_blocking_scrollable::wheel_blocking_scrollable, AnimatedSpinner};
 use iced::mouse;
+use iced::widget::image::FilterMethod;
 use iced::widget::{
-    button, mouse_area, responsive, Column, Container, Row, Scrollable, Stack, Text,
+    button, mouse_area, responsive, Column, Container, Image, Row, Scrollable, Stack, Text,
 };
 use iced::{
     alignment::{Horizontal, Vertical},
-    widget::scrollable::{Direction, Scrollbar, Viewport},
+    widget::scrollable::{AbsoluteOffset, Direction, Scrollbar, Viewport},
     widget::Id,
-    Background, Element, Length, Padding, Size, Theme,
+    Background, ContentFit, Element, Length, Padding, Size, Theme,
 };
 
+/// Renders the blurred preview of an image being loaded, scaled up to fit
+/// the pane.
+pub(super) fn loading_preview(preview: &crate::media::ImageData) -> Element<'_, Message> {
+    let color = theme::viewer_dark_surface_color();
+    Container::new(
+        Image::new(preview.handle.clone())
+            .content_fit(ContentFit::Contain)
+            .filter_method(FilterMethod::Linear)
+            .width(Length::Fill)
+            .height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .style(move |_theme: &Theme| iced::widget::container::Style {
+        background: Some(Background::Color(color)),
+        ..Default::default()
+    })
+    .into()
+}
+
 pub struct ViewContext<'a> {
     pub background_theme: BackgroundTheme,
+    /// Matte override; replaces the background theme when not `Background`.
+    pub matte: MatteColor,
     pub hud_lines: Vec<HudLine>,
     pub scrollable_id: &'static str,
     pub i18n: &'a crate::i18n::fluent::I18n,
@@ -49,19 +77,55 @@ pub struct ViewModel<'a> {
     pub at_last: bool,
     pub current_index: Option<usize>,
     pub total_count: usize,
+    /// Whether the directory is still being scanned (`total_count` grows).
+    pub scan_in_progress: bool,
     pub position_counter_visible: bool,
+    /// Configurable fullscreen overlay, shown in place of the position
+    /// counter.
+    pub fullscreen_overlay: Option<fullscreen_overlay::Info<'a>>,
     pub hud_visible: bool,
     pub video_shader: Option<&'a crate::ui::widgets::VideoShader<super::component::Message>>,
     pub is_video_playing: bool,
     pub is_loading_media: bool,
     pub spinner_rotation: f32,
+    /// Blurred preview shown in place of the media while it loads.
+    pub loading_preview: Option<&'a crate::media::ImageData>,
     pub video_error: Option<&'a str>,
     /// Whether metadata editor has unsaved changes (disables navigation).
     pub metadata_editor_has_changes: bool,
-    /// Current rotation angle for temporary rotation.
+    /// Rotation applied on screen (temporary rotation, plus the container
+    /// rotation for videos).
     pub rotation: RotationAngle,
-    /// Cached rotated image (pre-computed to avoid flickering).
-    pub rotated_image_cache: Option<&'a crate::media::ImageData>,
+    /// Whether the video is flipped horizontally.
+    pub mirrored: bool,
+    /// Color adjustments applied to the video while it plays.
+    pub color_adjustments: crate::ui::widgets::ColorAdjustments,
+    /// HDR tone mapping of the video.
+    pub tone_map: crate::ui::widgets::ToneMapParams,
+    /// Cached rotated and/or un-premultiplied image (pre-computed to avoid flickering).
+    pub display_image_cache: Option<&'a crate::media::ImageData>,
+    /// Subtitle to draw over the video, if any.
+    pub subtitle: Option<SubtitleOverlay>,
+    /// Detected faces to outline over the image, if the overlay is shown.
+    pub faces: Option<&'a [FaceBox]>,
+    /// Scroll offset of the image, when the minimap is enabled.
+    pub minimap_offset: Option<AbsoluteOffset>,
+    /// Pixel under the cursor, while the pixel inspector is on.
+    pub pixel_sample: Option<pixel_inspector::Sample>,
+    /// Measurements to draw over the image, while the ruler is on.
+    pub ruler: Option<measurement::Overlay>,
+    /// Composition guides to draw over the media, if shown.
+    pub guides: Option<&'a guides::Options>,
+}
+
+/// Subtitle text and style for the video overlay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtitleOverlay {
+    pub text: String,
+    /// Font size in logical pixels.
+    pub size: f32,
+    /// Draw the text on a translucent box.
+    pub background: bool,
 }
 
 #[must_use]
@@ -85,7 +149,7 @@ fn calculate_fit_zoom(media_width: u32, media_height: u32, available: Size) -> f
         return crate::ui::state::zoom::DEFAULT_ZOOM_PERCENT;
     }
 
-    crate::ui::state::zoom::clamp_zoom(scale * 100.0)
+    crate::ui::state::zoom::clamp_zoom(crate::ui::state::zoom::zoom_for_media_scale(scale))
 }
 
 /// Calculate padding to center media within available space.
@@ -124,7 +188,7 @@ fn view_inner<'a>(
     };
 
     // Calculate scaled media size (using effective dimensions for rotated media)
-    let scale = effective_zoom / 100.0;
+    let scale = crate::ui::state::zoom::media_scale(effective_zoom);
     let scaled_width = effective_width as f32 * scale;
     let scaled_height = effective_height as f32 * scale;
     let scaled_size = Size::new(scaled_width, scaled_height);
@@ -133,15 +197,22 @@ fn view_inner<'a>(
     // This ensures proper centering even when layout changes
     let effective_padding = calculate_centering_padding(scaled_size, available_size);
 
+    // A matte override replaces the background, so overlays must contrast
+    // with the matte rather than with the configured theme
+    let overlay_theme = match ctx.matte.color() {
+        Some(_) if ctx.matte.is_light() => BackgroundTheme::Light,
+        Some(_) => BackgroundTheme::Dark,
+        None => ctx.background_theme,
+    };
+
     // Determine arrow colors based on background theme for optimal visibility
     // Following UX best practices: semi-transparent backgrounds with strong shadows
-    let (arrow_text_color, arrow_bg_alpha_normal, arrow_bg_alpha_hover) = match ctx.background_theme
-    {
+    let (arrow_text_color, arrow_bg_alpha_normal, arrow_bg_alpha_hover) = match overlay_theme {
         BackgroundTheme::Light => {
             // Light background: dark arrows with light background on hover
             (theme::overlay_arrow_dark_color(), 0.0, 0.2)
         }
-        BackgroundTheme::Dark | BackgroundTheme::Checkerboard => {
+        BackgroundTheme::Dark | BackgroundTheme::Checkerboard | BackgroundTheme::Auto => {
             // Dark/checkerboard: white arrows with dark background on hover
             (theme::overlay_arrow_light_color(), 0.0, 0.5)
         }
@@ -162,13 +233,22 @@ fn view_inner<'a>(
         if shader.has_frame() && is_current_media_video {
             // Show the shader frame (whether playing or paused)
             // Pass the calculated display dimensions - pane owns the sizing logic
-            // Note: Video rotation not supported - rotation is disabled for videos
-            shader.view_sized(scaled_width, scaled_height)
+            // The shader rotates and mirrors the frame while sampling it
+            shader.view_sized(
+                scaled_width,
+                scaled_height,
+                crate::ui::widgets::FrameTransform {
+                    rotation: model.rotation,
+                    mirrored: model.mirrored,
+                },
+                model.color_adjustments,
+                model.tone_map,
+            )
         } else {
             // No frame yet, or current media is an image - show static media
             // Use cached rotated image if available to avoid recomputing on every render
-            if let Some(rotated_image) = model.rotated_image_cache {
-                super::view_image(rotated_image, effective_zoom)
+            if let Some(display_image) = model.display_image_cache {
+                super::view_image(display_image, effective_zoom)
             } else {
                 super::view_media(model.media, effective_zoom)
             }
@@ -176,13 +256,52 @@ fn view_inner<'a>(
     } else {
         // Not a video or no shader, show static media
         // Use cached rotated image if available to avoid recomputing on every render
-        if let Some(rotated_image) = model.rotated_image_cache {
-            super::view_image(rotated_image, effective_zoom)
+        if let Some(display_image) = model.display_image_cache {
+            super::view_image(display_image, effective_zoom)
         } else {
             super::view_media(model.media, effective_zoom)
         }
     };
 
+    // Guides follow the media, so they are layered inside the scrollable;
+    // unlike the image overlays below, they also apply to videos
+    let media_viewer = match model.guides {
+        Some(options) => Stack::new()
+            .push(media_viewer)
+            .push(guides::view(
+                options,
+                (effective_width, effective_height),
+                effective_zoom,
+                scaled_size,
+            ))
+            .into(),
+        None => media_viewer,
+    };
+
+    // Face outlines follow the image, so they are layered inside the scrollable
+    let media_viewer = match model.faces {
+        Some(faces) if !is_current_media_video => Stack::new()
+            .push(media_viewer)
+            .push(face_overlay::view(
+                faces,
+                Size::new(model.media.width() as f32, model.media.height() as f32),
+                model.rotation.degrees(),
+                scale,
+                scaled_size,
+            ))
+            .into(),
+        _ => media_viewer,
+    };
+
+    // Measurements follow the image the same way
+    let media_viewer = match &model.ruler {
+        Some(ruler) if !is_current_media_video => Stack::new()
+            .push(media_viewer)
+            .push(measurement::view(ruler, scale, scaled_size))
+            .into(),
+        _ => media_viewer,
+    };
+
     let media_container = Container::new(media_viewer).padding(effective_padding);
 
     let scrollable = Scrollable::new(media_container)
@@ -205,6 +324,8 @@ fn view_inner<'a>(
 
     let cursor_interaction = if model.is_dragging {
         mouse::Interaction::Grabbing
+    } else if model.pixel_sample.is_some() || (model.ruler.is_some() && model.cursor_over_media) {
+        mouse::Interaction::Crosshair
     } else if model.cursor_over_media {
         mouse::Interaction::Grab
     } else {
@@ -220,8 +341,14 @@ fn view_inner<'a>(
         .align_x(Horizontal::Center)
         .align_y(Vertical::Center);
 
-    let base_surface: Element<'_, Message> = match ctx.background_theme {
-        BackgroundTheme::Light => {
+    let base_surface: Element<'_, Message> = match (ctx.matte.color(), ctx.background_theme) {
+        (Some(color), _) => scrollable_container
+            .style(move |_theme: &Theme| iced::widget::container::Style {
+                background: Some(Background::Color(color)),
+                ..Default::default()
+            })
+            .into(),
+        (None, BackgroundTheme::Light) => {
             let color = theme::viewer_light_surface_color();
             scrollable_container
                 .style(move |_theme: &Theme| iced::widget::container::Style {
@@ -230,7 +357,8 @@ fn view_inner<'a>(
                 })
                 .into()
         }
-        BackgroundTheme::Dark => {
+        // Auto is resolved by the viewer before rendering; dark is its fallback
+        (None, BackgroundTheme::Dark | BackgroundTheme::Auto) => {
             let color = theme::viewer_dark_surface_color();
             scrollable_container
                 .style(move |_theme: &Theme| iced::widget::container::Style {
@@ -239,27 +367,46 @@ fn view_inner<'a>(
                 })
                 .into()
         }
-        BackgroundTheme::Checkerboard => checkerboard::wrap(scrollable_container),
+        (None, BackgroundTheme::Checkerboard) => checkerboard::wrap(scrollable_container),
     };
 
     let mut stack = Stack::new().push(base_surface);
 
+    // Cover the previous media with the preview of the one being loaded
+    if let Some(preview) = model.loading_preview.filter(|_| model.is_loading_media) {
+        stack = stack.push(loading_preview(preview));
+    }
+
     // Add navigation arrows if visible
 
     // Navigation is disabled when metadata editor has unsaved changes
     let nav_enabled = !model.metadata_editor_has_changes;
 
+    // Right-to-left languages browse from right to left: the left arrow goes
+    // to the next media
+    let (
+        (left_has_media, left_at_boundary, left_message),
+        (right_has_media, right_at_boundary, right_message),
+    ) = ctx.i18n.layout_direction().order(
+        (
+            model.has_previous,
+            model.at_first,
+            Message::NavigatePrevious,
+        ),
+        (model.has_next, model.at_last, Message::NavigateNext),
+    );
+
     if model.arrows_visible {
-        if model.has_previous {
+        if left_has_media {
             // Show loop icon at boundaries to indicate wrap-around behavior
             // Choose icon color based on background for optimal visibility
-            let button_content: Element<'_, Message> = if model.at_first {
+            let button_content: Element<'_, Message> = if left_at_boundary {
                 let loop_icon = icons::sized(
-                    action_icons::navigation::loop_indicator(ctx.background_theme),
+                    action_icons::navigation::loop_indicator(overlay_theme),
                     16.0,
                 );
                 let chevron = icons::sized(
-                    action_icons::navigation::previous(ctx.background_theme),
+                    action_icons::navigation::previous(overlay_theme),
                     sizing::ICON_MD,
                 );
                 Row::new()
@@ -270,7 +417,7 @@ fn view_inner<'a>(
                     .into()
             } else {
                 icons::sized(
-                    action_icons::navigation::previous(ctx.background_theme),
+                    action_icons::navigation::previous(overlay_theme),
                     sizing::ICON_LG,
                 )
                 .into()
@@ -283,7 +430,7 @@ fn view_inner<'a>(
                 ),
             );
             let left_arrow = if nav_enabled {
-                left_arrow.on_press(Message::NavigatePrevious)
+                left_arrow.on_press(left_message.clone())
             } else {
                 left_arrow
             };
@@ -303,7 +450,7 @@ fn view_inner<'a>(
             // preventing it from being routed to RawEvent which would start a drag
             let left_zone_clickable = mouse_area(left_zone);
             let left_zone_clickable = if nav_enabled {
-                left_zone_clickable.on_press(Message::NavigatePrevious)
+                left_zone_clickable.on_press(left_message.clone())
             } else {
                 left_zone_clickable
             };
@@ -320,16 +467,16 @@ fn view_inner<'a>(
             );
         }
 
-        if model.has_next {
+        if right_has_media {
             // Show loop icon at boundaries to indicate wrap-around behavior
             // Choose icon color based on background for optimal visibility
-            let button_content: Element<'_, Message> = if model.at_last {
+            let button_content: Element<'_, Message> = if right_at_boundary {
                 let loop_icon = icons::sized(
-                    action_icons::navigation::loop_indicator(ctx.background_theme),
+                    action_icons::navigation::loop_indicator(overlay_theme),
                     16.0,
                 );
                 let chevron = icons::sized(
-                    action_icons::navigation::next(ctx.background_theme),
+                    action_icons::navigation::next(overlay_theme),
                     sizing::ICON_MD,
                 );
                 Row::new()
@@ -340,7 +487,7 @@ fn view_inner<'a>(
                     .into()
             } else {
                 icons::sized(
-                    action_icons::navigation::next(ctx.background_theme),
+                    action_icons::navigation::next(overlay_theme),
                     sizing::ICON_LG,
                 )
                 .into()
@@ -353,7 +500,7 @@ fn view_inner<'a>(
                 ),
             );
             let right_arrow = if nav_enabled {
-                right_arrow.on_press(Message::NavigateNext)
+                right_arrow.on_press(right_message.clone())
             } else {
                 right_arrow
             };
@@ -373,7 +520,7 @@ fn view_inner<'a>(
             // preventing it from being routed to RawEvent which would start a drag
             let right_zone_clickable = mouse_area(right_zone);
             let right_zone_clickable = if nav_enabled {
-                right_zone_clickable.on_press(Message::NavigateNext)
+                right_zone_clickable.on_press(right_message.clone())
             } else {
                 right_zone_clickable
             };
@@ -525,6 +672,54 @@ fn view_inner<'a>(
         }
     }
 
+    // Add subtitles above the bottom overlays
+    if let (MediaData::Video(_), Some(subtitle)) = (model.media, model.subtitle) {
+        let background = subtitle.background;
+        let subtitle_box = Container::new(
+            Text::new(subtitle.text)
+                .size(subtitle.size)
+                .align_x(Horizontal::Center),
+        )
+        .padding(Padding {
+            top: spacing::XXS,
+            right: spacing::SM,
+            bottom: spacing::XXS,
+            left: spacing::SM,
+        })
+        .style(move |_theme: &Theme| iced::widget::container::Style {
+            background: background.then_some(Background::Color(iced::Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: opacity::OVERLAY_STRONG,
+            })),
+            border: iced::Border {
+                radius: radius::SM.into(),
+                ..Default::default()
+            },
+            text_color: Some(theme::overlay_arrow_light_color()),
+            // Keep text readable on bright frames when there is no box
+            shadow: iced::Shadow {
+                color: iced::Color::BLACK,
+                offset: iced::Vector::new(1.0, 1.0),
+                blur_radius: 2.0,
+            },
+            ..Default::default()
+        });
+
+        stack = stack.push(
+            Container::new(subtitle_box)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(Padding {
+                    bottom: spacing::XXL,
+                    ..Padding::new(spacing::LG)
+                })
+                .align_x(Horizontal::Center)
+                .align_y(Vertical::Bottom),
+        );
+    }
+
     // Add HUD indicator if present and visible
     if model.hud_visible && !ctx.hud_lines.is_empty() {
         const HUD_ICON_SIZE: f32 = 14.0;
@@ -542,6 +737,7 @@ fn view_inner<'a>(
                     }
                 }
                 HudIconKind::Rotation => action_icons::hud::rotation(),
+                HudIconKind::AlphaPreview => action_icons::hud::alpha_preview(),
             };
 
             let styled_icon = icons::sized(icon, HUD_ICON_SIZE);
@@ -569,10 +765,12 @@ fn view_inner<'a>(
         );
     }
 
-    // Add position counter at bottom center if there are multiple images and it should be visible
-    if model.position_counter_visible && model.total_count > 1 {
+    // Add position counter at bottom center if there are multiple images and it should be visible.
+    // While a large directory is scanned, the growing total is marked as incomplete.
+    if model.position_counter_visible && (model.total_count > 1 || model.scan_in_progress) {
         if let Some(current) = model.current_index {
-            let position_text = format!("{}/{}", current + 1, model.total_count);
+            let position_text =
+                fullscreen_overlay::index_text(current, model.total_count, model.scan_in_progress);
             let position_indicator =
                 Container::new(Text::new(position_text).size(typography::BODY))
                     .padding(Padding {
@@ -594,5 +792,44 @@ fn view_inner<'a>(
         }
     }
 
+    // Minimap of the whole image while zoomed in past the pane
+    let minimap_image = match model.media {
+        MediaData::Image(image) => Some(model.display_image_cache.unwrap_or(image)),
+        MediaData::Video(_) => None,
+    };
+    if let (Some(image), Some(offset)) = (minimap_image, model.minimap_offset) {
+        if let Some(visible) =
+            minimap::visible_region(scaled_size, available_size, offset, effective_zoom)
+        {
+            stack = stack.push(
+                Container::new(minimap::view(image, visible))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .padding(spacing::SM)
+                    .align_x(Horizontal::Left)
+                    .align_y(Vertical::Bottom),
+            );
+        }
+    }
+
+    if let Some(sample) = &model.pixel_sample {
+        stack = stack.push(
+            Container::new(pixel_inspector::view(sample, ctx.i18n))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(spacing::SM)
+                .align_x(Horizontal::Left)
+                .align_y(Vertical::Top),
+        );
+    }
+
+    if let Some(overlay) = model
+        .fullscreen_overlay
+        .as_ref()
+        .and_then(fullscreen_overlay::view)
+    {
+        stack = stack.push(overlay);
+    }
+
     stack.into()
 }
@@ -0,0 +1,299 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Pixel inspector (eyedropper).
+//!
+//! With the inspector on, the pixel under the cursor is read from the image
+//! or the paused video frame and shown in a corner of the pane: position in
+//! source pixels, color as hex, RGB and HSL, and a magnified loupe of the
+//! pixels around it, oriented as displayed. Clicking the media copies the
+//! hex color instead of starting a pan.
+
+use crate::i18n::fluent::I18n;
+use crate::ui::design_tokens::{palette, spacing, typography};
+use crate::ui::styles;
+use crate::ui::viewer::component::Message;
+use iced::alignment::Vertical;
+use iced::widget::{canvas, Column, Container, Row, Text};
+use iced::{mouse, Color, Element, Font, Length, Point, Rectangle, Size, Theme};
+
+/// Pixels shown on each side of the inspected one in the loupe.
+pub const LOUPE_RADIUS: i64 = 5;
+
+/// Pixels on each side of the loupe.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // small positive constant
+const LOUPE_SIZE: usize = (LOUPE_RADIUS * 2 + 1) as usize;
+
+/// Size of one magnified pixel, in logical pixels.
+const LOUPE_CELL: f32 = 9.0;
+
+/// How the source pixels are turned on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Orientation {
+    /// Clockwise rotation in degrees (0, 90, 180 or 270).
+    pub rotation: u16,
+    /// Flipped horizontally after rotating.
+    pub mirrored: bool,
+}
+
+impl Orientation {
+    /// Returns the source pixel shown at (`x`, `y`) on screen, for a source
+    /// of `width` × `height`, or `None` outside of it.
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)] // image sizes fit in i64
+    #[allow(clippy::cast_possible_truncation)] // checked against the source size
+    fn source_pixel(self, x: i64, y: i64, width: u32, height: u32) -> Option<(u32, u32)> {
+        let (w, h) = (i64::from(width), i64::from(height));
+        let displayed_width = if self.rotation % 180 == 90 { h } else { w };
+        let x = if self.mirrored {
+            displayed_width - 1 - x
+        } else {
+            x
+        };
+        let (sx, sy) = match self.rotation {
+            90 => (y, h - 1 - x),
+            180 => (w - 1 - x, h - 1 - y),
+            270 => (w - 1 - y, x),
+            _ => (x, y),
+        };
+        ((0..w).contains(&sx) && (0..h).contains(&sy)).then_some((sx as u32, sy as u32))
+    }
+}
+
+/// The pixel under the cursor and its surroundings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    /// Position in the source image or frame.
+    pub x: u32,
+    pub y: u32,
+    pub rgba: [u8; 4],
+    /// Pixels around the inspected one, row by row as displayed, `None`
+    /// past the edges.
+    pub loupe: Vec<Option<[u8; 4]>>,
+}
+
+impl Sample {
+    /// Reads the pixel shown at (`x`, `y`) on screen, in pixels of the media
+    /// as displayed, from `rgba` bytes of `width` × `height`.
+    #[must_use]
+    pub fn read(
+        rgba: &[u8],
+        width: u32,
+        height: u32,
+        orientation: Orientation,
+        x: u32,
+        y: u32,
+    ) -> Option<Self> {
+        let pixel_at = |dx: i64, dy: i64| {
+            let (sx, sy) =
+                orientation.source_pixel(i64::from(x) + dx, i64::from(y) + dy, width, height)?;
+            let index = (sy as usize * width as usize + sx as usize) * 4;
+            let bytes = rgba.get(index..index + 4)?;
+            Some(((sx, sy), [bytes[0], bytes[1], bytes[2], bytes[3]]))
+        };
+
+        let ((sx, sy), color) = pixel_at(0, 0)?;
+        let loupe = (-LOUPE_RADIUS..=LOUPE_RADIUS)
+            .flat_map(|dy| (-LOUPE_RADIUS..=LOUPE_RADIUS).map(move |dx| (dx, dy)))
+            .map(|(dx, dy)| pixel_at(dx, dy).map(|(_, color)| color))
+            .collect();
+        Some(Self {
+            x: sx,
+            y: sy,
+            rgba: color,
+            loupe,
+        })
+    }
+
+    /// Returns the color as `#RRGGBB`.
+    #[must_use]
+    pub fn hex(&self) -> String {
+        let [r, g, b, _] = self.rgba;
+        format!("#{r:02X}{g:02X}{b:02X}")
+    }
+
+    /// Returns the color as hue in degrees, saturation and lightness in
+    /// percent.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // rounded 0–360 and 0–100
+    pub fn hsl(&self) -> (u16, u8, u8) {
+        let [r, g, b, _] = self.rgba.map(|channel| f32::from(channel) / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let lightness = (max + min) / 2.0;
+        let delta = max - min;
+        if delta <= f32::EPSILON {
+            return (0, 0, (lightness * 100.0).round() as u8);
+        }
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if (max - r).abs() <= f32::EPSILON {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if (max - g).abs() <= f32::EPSILON {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        (
+            hue.round() as u16 % 360,
+            (saturation * 100.0).round() as u8,
+            (lightness * 100.0).round() as u8,
+        )
+    }
+}
+
+/// Renders the inspector panel for `sample`.
+#[must_use]
+pub fn view<'a>(sample: &Sample, i18n: &I18n) -> Element<'a, Message> {
+    let [r, g, b, a] = sample.rgba;
+    let (hue, saturation, lightness) = sample.hsl();
+    let line = |text: String| {
+        Text::new(text)
+            .size(typography::CAPTION)
+            .font(Font::MONOSPACE)
+    };
+
+    let mut values = Column::new()
+        .spacing(spacing::XXS)
+        .push(
+            Text::new(sample.hex())
+                .size(typography::BODY)
+                .font(Font::MONOSPACE),
+        )
+        .push(line(format!("RGB {r}, {g}, {b}")))
+        .push(line(format!("HSL {hue}°, {saturation}%, {lightness}%")));
+    if a < u8::MAX {
+        values = values.push(line(format!("A {a}")));
+    }
+    values = values
+        .push(line(format!("X {}  Y {}", sample.x, sample.y)))
+        .push(Text::new(i18n.tr("viewer-inspector-copy-hint")).size(typography::CAPTION));
+
+    #[allow(clippy::cast_precision_loss)] // small constant
+    let loupe_side = LOUPE_SIZE as f32 * LOUPE_CELL;
+    let loupe = canvas::Canvas::new(LoupeProgram {
+        pixels: sample.loupe.clone(),
+    })
+    .width(Length::Fixed(loupe_side))
+    .height(Length::Fixed(loupe_side));
+
+    Container::new(
+        Row::new()
+            .spacing(spacing::XS)
+            .align_y(Vertical::Center)
+            .push(loupe)
+            .push(values),
+    )
+    .padding(spacing::XS)
+    .style(styles::overlay::indicator(8.0))
+    .into()
+}
+
+struct LoupeProgram {
+    pixels: Vec<Option<[u8; 4]>>,
+}
+
+impl canvas::Program<Message> for LoupeProgram {
+    type State = ();
+
+    #[allow(clippy::cast_precision_loss)] // loupe coordinates are tiny
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let cell = Size::new(LOUPE_CELL, LOUPE_CELL);
+        for (index, pixel) in self.pixels.iter().enumerate() {
+            let origin = Point::new(
+                (index % LOUPE_SIZE) as f32 * LOUPE_CELL,
+                (index / LOUPE_SIZE) as f32 * LOUPE_CELL,
+            );
+            let color = pixel.map_or(palette::GRAY_900, |[r, g, b, a]| {
+                Color::from_rgba8(r, g, b, f32::from(a) / 255.0)
+            });
+            frame.fill_rectangle(origin, cell, color);
+        }
+
+        // Outline the inspected pixel, in black and white to show on any color
+        let center = LOUPE_SIZE / 2;
+        let origin = Point::new(center as f32 * LOUPE_CELL, center as f32 * LOUPE_CELL);
+        frame.stroke(
+            &canvas::Path::rectangle(origin, cell),
+            canvas::Stroke::default()
+                .with_width(2.0)
+                .with_color(palette::BLACK),
+        );
+        frame.stroke(
+            &canvas::Path::rectangle(origin, cell),
+            canvas::Stroke::default()
+                .with_width(1.0)
+                .with_color(palette::WHITE),
+        );
+        vec![frame.into_geometry()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UPRIGHT: Orientation = Orientation {
+        rotation: 0,
+        mirrored: false,
+    };
+
+    /// 3×2 image whose red channel is the pixel index.
+    fn image() -> Vec<u8> {
+        (0..6).flat_map(|i| [i, 0, 0, 255]).collect()
+    }
+
+    #[test]
+    fn reads_the_pixel_and_its_surroundings() {
+        let sample = Sample::read(&image(), 3, 2, UPRIGHT, 2, 1).expect("inside the image");
+        assert_eq!((sample.x, sample.y), (2, 1));
+        assert_eq!(sample.rgba, [5, 0, 0, 255]);
+        assert_eq!(sample.loupe.len(), LOUPE_SIZE * LOUPE_SIZE);
+        let center = LOUPE_SIZE * LOUPE_SIZE / 2;
+        assert_eq!(sample.loupe[center], Some([5, 0, 0, 255]));
+        // Past the right edge
+        assert_eq!(sample.loupe[center + 1], None);
+        assert!(Sample::read(&image(), 3, 2, UPRIGHT, 3, 0).is_none());
+    }
+
+    #[test]
+    fn maps_rotated_and_mirrored_pixels_to_the_source() {
+        // Rotated 90° clockwise, the top-left pixel on screen is the
+        // bottom-left one of the source
+        let rotated = Orientation {
+            rotation: 90,
+            mirrored: false,
+        };
+        let sample = Sample::read(&image(), 3, 2, rotated, 0, 0).expect("inside the image");
+        assert_eq!((sample.x, sample.y), (0, 1));
+
+        let mirrored = Orientation {
+            rotation: 0,
+            mirrored: true,
+        };
+        let sample = Sample::read(&image(), 3, 2, mirrored, 0, 0).expect("inside the image");
+        assert_eq!((sample.x, sample.y), (2, 0));
+    }
+
+    #[test]
+    fn formats_the_color() {
+        let sample = Sample {
+            x: 0,
+            y: 0,
+            rgba: [255, 128, 0, 255],
+            loupe: Vec::new(),
+        };
+        assert_eq!(sample.hex(), "#FF8000");
+        assert_eq!(sample.hsl(), (30, 100, 50));
+
+        let gray = Sample {
+            rgba: [128, 128, 128, 255],
+            ..sample
+        };
+        assert_eq!(gray.hsl(), (0, 0, 50));
+    }
+}
@@ -0,0 +1,485 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Quick-search overlay for jumping to a file by name.
+//!
+//! Opened with `Ctrl+F`, it fuzzy-matches the typed query against the
+//! filenames of the current directory and jumps to the selected result.
+//! Matches are kept after the overlay closes so `F3` / `Shift+F3` can cycle
+//! through them with wrap-around.
+
+use crate::i18n::fluent::I18n;
+use crate::ui::design_tokens::{radius, spacing, typography};
+use crate::ui::styles;
+use iced::widget::{button, container, text, text_input, Column, Id, Row, Text};
+use iced::{alignment::Vertical, Border, Element, Length, Padding, Theme};
+use std::path::{Path, PathBuf};
+
+/// Identifier of the search text input, used to focus it on open.
+pub const INPUT_ID: &str = "viewer-quick-search-input";
+
+/// Maximum number of results rendered at once.
+const MAX_VISIBLE_RESULTS: usize = 8;
+
+// =============================================================================
+// Messages
+// =============================================================================
+
+/// Messages emitted by the quick-search overlay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    /// The search query was edited.
+    QueryChanged(String),
+    /// Jump to the selected result (Enter).
+    Submit,
+    /// Jump to the result at the given index (click).
+    Activate(usize),
+    /// Close the overlay without jumping.
+    Close,
+    /// No-op message to consume clicks on the panel without closing it.
+    ConsumeClick,
+}
+
+// =============================================================================
+// Fuzzy Matching
+// =============================================================================
+
+/// Score bonus for a matched character that directly follows the previous match.
+const CONSECUTIVE_BONUS: i32 = 5;
+/// Score bonus for a matched character at the start of a word.
+const WORD_START_BONUS: i32 = 8;
+/// Score bonus for each matched character.
+const MATCH_SCORE: i32 = 1;
+
+/// A successful fuzzy match of a query against a filename.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Full path of the matched media file.
+    pub path: PathBuf,
+    /// Filename displayed in the results list.
+    pub name: String,
+    /// Character indices in `name` that matched the query, in ascending order.
+    pub matched_indices: Vec<usize>,
+    /// Match quality (higher is better).
+    pub score: i32,
+}
+
+/// Fuzzy-matches `query` against `candidate`, case-insensitively.
+///
+/// Every query character must appear in the candidate in order, but not
+/// necessarily contiguously. Returns the matched character indices and a score
+/// that favors consecutive runs and word starts (`img_2024` matches `i2`
+/// better on `img_2024` than on `big2`). Whitespace in the query is ignored.
+#[must_use]
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<(i32, Vec<usize>)> {
+    let mut query_chars = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .peekable();
+    query_chars.peek()?;
+
+    let mut score = 0;
+    let mut indices = Vec::new();
+    let mut previous: Option<char> = None;
+    let mut last_match: Option<usize> = None;
+
+    for (index, ch) in candidate.chars().enumerate() {
+        let Some(&wanted) = query_chars.peek() else {
+            break;
+        };
+        if ch.to_lowercase().eq(std::iter::once(wanted)) {
+            score += MATCH_SCORE;
+            if last_match.is_some_and(|last| last + 1 == index) {
+                score += CONSECUTIVE_BONUS;
+            }
+            if is_word_start(previous, ch) {
+                score += WORD_START_BONUS;
+            }
+            indices.push(index);
+            last_match = Some(index);
+            query_chars.next();
+        }
+        previous = Some(ch);
+    }
+
+    if query_chars.peek().is_some() {
+        return None;
+    }
+
+    // Prefer shorter names when scores tie on the matched characters
+    let length_penalty = i32::try_from(candidate.chars().count() / 8).unwrap_or(i32::MAX);
+    Some((score - length_penalty, indices))
+}
+
+/// Returns true if `ch` starts a word after `previous`.
+fn is_word_start(previous: Option<char>, ch: char) -> bool {
+    match previous {
+        None => true,
+        Some(prev) => {
+            !prev.is_alphanumeric()
+                || (prev.is_lowercase() && ch.is_uppercase())
+                || (prev.is_alphabetic() && ch.is_ascii_digit())
+        }
+    }
+}
+
+// =============================================================================
+// State
+// =============================================================================
+
+/// State for the quick-search overlay.
+#[derive(Debug, Clone, Default)]
+pub struct QuickSearchState {
+    /// Whether the overlay is currently open.
+    pub is_open: bool,
+    /// Current query text.
+    query: String,
+    /// Media paths available for searching (snapshot taken on open).
+    candidates: Vec<PathBuf>,
+    /// Results for the current query, best first.
+    matches: Vec<SearchMatch>,
+    /// Index of the selected result in `matches`.
+    selected: usize,
+}
+
+impl QuickSearchState {
+    /// Opens the overlay over the given media paths.
+    ///
+    /// The previous query is kept so reopening refines the last search.
+    pub fn open(&mut self, candidates: Vec<PathBuf>) {
+        self.is_open = true;
+        self.candidates = candidates;
+        self.refresh_matches();
+    }
+
+    /// Closes the overlay, keeping matches for `F3` cycling.
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    /// Returns the current query.
+    #[must_use]
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Updates the query and recomputes matches.
+    pub fn set_query(&mut self, query: String) {
+        self.query = query;
+        self.refresh_matches();
+    }
+
+    /// Returns the results for the current query, best first.
+    #[must_use]
+    pub fn matches(&self) -> &[SearchMatch] {
+        &self.matches
+    }
+
+    /// Returns the index of the selected result.
+    #[must_use]
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    /// Returns the path of the selected result, if any.
+    #[must_use]
+    pub fn selected_path(&self) -> Option<&Path> {
+        self.matches
+            .get(self.selected)
+            .map(|result| result.path.as_path())
+    }
+
+    /// Returns true if there are results to cycle through.
+    #[must_use]
+    pub fn has_matches(&self) -> bool {
+        !self.matches.is_empty()
+    }
+
+    /// Selects the result at `index` if it exists.
+    pub fn select(&mut self, index: usize) {
+        if index < self.matches.len() {
+            self.selected = index;
+        }
+    }
+
+    /// Selects the next result, wrapping to the first.
+    pub fn select_next(&mut self) {
+        if !self.matches.is_empty() {
+            self.selected = (self.selected + 1) % self.matches.len();
+        }
+    }
+
+    /// Selects the previous result, wrapping to the last.
+    pub fn select_previous(&mut self) {
+        if !self.matches.is_empty() {
+            self.selected = (self.selected + self.matches.len() - 1) % self.matches.len();
+        }
+    }
+
+    fn refresh_matches(&mut self) {
+        let mut matches: Vec<SearchMatch> = self
+            .candidates
+            .iter()
+            .filter_map(|path| {
+                let name = path.file_name()?.to_string_lossy().into_owned();
+                let (score, matched_indices) = fuzzy_match(&self.query, &name)?;
+                Some(SearchMatch {
+                    path: path.clone(),
+                    name,
+                    matched_indices,
+                    score,
+                })
+            })
+            .collect();
+        // Stable sort keeps directory order between equal scores
+        matches.sort_by(|a, b| b.score.cmp(&a.score));
+        self.matches = matches;
+        self.selected = 0;
+    }
+}
+
+// =============================================================================
+// View
+// =============================================================================
+
+/// Context for rendering the quick-search overlay.
+pub struct ViewContext<'a> {
+    pub i18n: &'a I18n,
+    pub state: &'a QuickSearchState,
+}
+
+/// Render the quick-search panel as an overlay element.
+/// Returns None if the overlay is closed.
+#[must_use]
+pub fn view_panel(ctx: ViewContext<'_>) -> Option<Element<'_, Message>> {
+    if !ctx.state.is_open {
+        return None;
+    }
+    Some(build_panel(&ctx))
+}
+
+fn build_panel<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
+    let input = text_input(&ctx.i18n.tr("quick-search-placeholder"), ctx.state.query())
+        .id(Id::new(INPUT_ID))
+        .on_input(Message::QueryChanged)
+        .on_submit(Message::Submit)
+        .padding(spacing::XS)
+        .style(styles::text_input::standard)
+        .size(typography::BODY_LG)
+        .width(Length::Fill);
+
+    let count_label = if ctx.state.query().trim().is_empty() {
+        String::new()
+    } else if ctx.state.has_matches() {
+        format!(
+            "{} / {}",
+            ctx.state.selected_index() + 1,
+            ctx.state.matches().len()
+        )
+    } else {
+        ctx.i18n.tr("quick-search-no-results")
+    };
+
+    let header = Row::new()
+        .spacing(spacing::SM)
+        .align_y(Vertical::Center)
+        .push(input)
+        .push(Text::new(count_label).size(typography::BODY));
+
+    let mut content = Column::new().spacing(spacing::XXS).push(header);
+
+    let (start, end) = visible_range(
+        ctx.state.selected_index(),
+        ctx.state.matches().len(),
+        MAX_VISIBLE_RESULTS,
+    );
+    for (index, result) in ctx.state.matches()[start..end].iter().enumerate() {
+        let index = start + index;
+        content = content.push(build_result_row(
+            result,
+            index,
+            index == ctx.state.selected_index(),
+        ));
+    }
+
+    content = content.push(
+        Text::new(ctx.i18n.tr("quick-search-hint"))
+            .size(typography::CAPTION)
+            .style(|theme: &Theme| text::Style {
+                color: Some(theme.extended_palette().background.strong.text),
+            }),
+    );
+
+    container(content)
+        .padding(Padding::new(spacing::SM))
+        .width(Length::Fixed(420.0))
+        .style(|theme: &Theme| {
+            let palette = theme.extended_palette();
+            container::Style {
+                background: Some(palette.background.base.color.into()),
+                border: Border {
+                    color: palette.background.strong.color,
+                    width: 1.0,
+                    radius: radius::MD.into(),
+                },
+                ..Default::default()
+            }
+        })
+        .into()
+}
+
+/// Builds one result row with the matched characters highlighted.
+fn build_result_row(result: &SearchMatch, index: usize, selected: bool) -> Element<'_, Message> {
+    let mut name_row = Row::new();
+    for (segment, highlighted) in highlight_segments(&result.name, &result.matched_indices) {
+        let segment_text = Text::new(segment).size(typography::BODY);
+        name_row = name_row.push(if highlighted {
+            segment_text.style(|theme: &Theme| text::Style {
+                color: Some(theme.extended_palette().primary.strong.color),
+            })
+        } else {
+            segment_text
+        });
+    }
+
+    button(name_row)
+        .on_press(Message::Activate(index))
+        .padding([spacing::XXS, spacing::XS])
+        .width(Length::Fill)
+        .style(move |theme: &Theme, status| {
+            let palette = theme.extended_palette();
+            let background = if selected || matches!(status, button::Status::Hovered) {
+                Some(palette.background.weak.color.into())
+            } else {
+                None
+            };
+            button::Style {
+                background,
+                text_color: palette.background.base.text,
+                border: Border {
+                    radius: radius::SM.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        })
+        .into()
+}
+
+/// Splits `name` into runs of highlighted and plain characters.
+fn highlight_segments(name: &str, matched_indices: &[usize]) -> Vec<(String, bool)> {
+    let mut segments: Vec<(String, bool)> = Vec::new();
+    let mut matched = matched_indices.iter().peekable();
+    for (index, ch) in name.chars().enumerate() {
+        let highlighted = matched.next_if_eq(&&index).is_some();
+        match segments.last_mut() {
+            Some((segment, is_highlighted)) if *is_highlighted == highlighted => segment.push(ch),
+            _ => segments.push((ch.to_string(), highlighted)),
+        }
+    }
+    segments
+}
+
+/// Returns the `[start, end)` window of `len` results that keeps `selected` visible.
+fn visible_range(selected: usize, len: usize, max_visible: usize) -> (usize, usize) {
+    let start = (selected + 1).saturating_sub(max_visible);
+    (start, len.min(start + max_visible))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(names: &[&str]) -> Vec<PathBuf> {
+        names
+            .iter()
+            .map(|n| PathBuf::from("/photos").join(n))
+            .collect()
+    }
+
+    #[test]
+    fn fuzzy_match_requires_characters_in_order() {
+        assert!(fuzzy_match("hlo", "hello.png").is_some());
+        assert!(fuzzy_match("olh", "hello.png").is_none());
+        assert!(fuzzy_match("", "hello.png").is_none());
+    }
+
+    #[test]
+    fn fuzzy_match_is_case_insensitive_and_reports_indices() {
+        let (_, indices) = fuzzy_match("HEL", "hello.png").expect("match");
+        assert_eq!(indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn fuzzy_match_prefers_word_starts_and_runs() {
+        let (word_start, _) = fuzzy_match("sun", "beach_sunset.jpg").expect("match");
+        let (scattered, _) = fuzzy_match("sun", "glass_union.jpg").expect("match");
+        assert!(word_start > scattered);
+    }
+
+    #[test]
+    fn fuzzy_match_ignores_whitespace_in_query() {
+        assert!(fuzzy_match("img 12", "IMG_0012.jpg").is_some());
+    }
+
+    #[test]
+    fn set_query_orders_matches_by_score() {
+        let mut state = QuickSearchState::default();
+        state.open(paths(&[
+            "glass_union.jpg",
+            "beach_sunset.jpg",
+            "portrait.png",
+        ]));
+        state.set_query("sun".to_string());
+
+        let names: Vec<&str> = state.matches().iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["beach_sunset.jpg", "glass_union.jpg"]);
+        assert_eq!(
+            state.selected_path(),
+            Some(Path::new("/photos/beach_sunset.jpg"))
+        );
+    }
+
+    #[test]
+    fn selection_wraps_around() {
+        let mut state = QuickSearchState::default();
+        state.open(paths(&["a1.png", "a2.png", "a3.png"]));
+        state.set_query("a".to_string());
+
+        state.select_previous();
+        assert_eq!(state.selected_index(), 2);
+        state.select_next();
+        assert_eq!(state.selected_index(), 0);
+    }
+
+    #[test]
+    fn close_keeps_matches_for_cycling() {
+        let mut state = QuickSearchState::default();
+        state.open(paths(&["a1.png", "a2.png"]));
+        state.set_query("a".to_string());
+        state.close();
+
+        assert!(!state.is_open);
+        assert!(state.has_matches());
+    }
+
+    #[test]
+    fn highlight_segments_groups_runs() {
+        let segments = highlight_segments("hello", &[0, 1, 4]);
+        assert_eq!(
+            segments,
+            vec![
+                ("he".to_string(), true),
+                ("ll".to_string(), false),
+                ("o".to_string(), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn visible_range_keeps_selection_in_view() {
+        assert_eq!(visible_range(0, 20, 8), (0, 8));
+        assert_eq!(visible_range(7, 20, 8), (0, 8));
+        assert_eq!(visible_range(8, 20, 8), (1, 9));
+        assert_eq!(visible_range(0, 3, 8), (0, 3));
+    }
+}
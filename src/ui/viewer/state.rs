@@ -4,7 +4,8 @@
 use crate::media::MediaData;
 use crate::ui::state::rotation::RotationAngle;
 use crate::ui::state::viewport::ViewportState;
-use crate::ui::state::zoom::{clamp_zoom, DEFAULT_ZOOM_PERCENT};
+use crate::ui::state::zoom::{clamp_zoom, media_scale, zoom_for_media_scale, DEFAULT_ZOOM_PERCENT};
+use iced::widget::scrollable::AbsoluteOffset;
 use iced::{Padding, Point, Rectangle, Size};
 
 /// Extra spacing reserved for the scrollbars area when the image overflows.
@@ -64,7 +65,7 @@ impl<'a> ViewerState<'a> {
             return Some(DEFAULT_ZOOM_PERCENT);
         }
 
-        Some(clamp_zoom(scale * 100.0))
+        Some(clamp_zoom(zoom_for_media_scale(scale)))
     }
 
     /// Returns the scaled media dimensions for the current zoom level.
@@ -72,7 +73,7 @@ impl<'a> ViewerState<'a> {
     #[must_use]
     pub fn scaled_media_size(&self) -> Option<Size> {
         let media = self.media?;
-        let scale = (self.zoom_percent / 100.0).max(0.01);
+        let scale = media_scale(self.zoom_percent).max(0.01);
         let width = (media.width() as f32 * scale).max(1.0);
         let height = (media.height() as f32 * scale).max(1.0);
         Some(Size::new(width, height))
@@ -85,7 +86,7 @@ impl<'a> ViewerState<'a> {
     #[must_use]
     pub fn scaled_media_size_rotated(&self, rotation: RotationAngle) -> Option<Size> {
         let media = self.media?;
-        let scale = (self.zoom_percent / 100.0).max(0.01);
+        let scale = media_scale(self.zoom_percent).max(0.01);
 
         // Get effective dimensions based on rotation
         let (effective_width, effective_height) = if rotation.swaps_dimensions() {
@@ -144,6 +145,127 @@ impl<'a> ViewerState<'a> {
         Some(Rectangle::new(Point::new(left, top), size))
     }
 
+    /// Returns the region of the media, in source pixels, currently visible in
+    /// the viewport.
+    ///
+    /// Coordinates refer to the media as displayed, i.e. after `rotation`.
+    /// Scrollbars are excluded when the media overflows the viewport.
+    #[allow(clippy::cast_precision_loss)] // u32 to f32 for dimensions: f32 is exact up to 16M
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // clamped to media size
+    #[must_use]
+    pub fn visible_media_region(&self, rotation: RotationAngle) -> Option<Rectangle<u32>> {
+        let media = self.media?;
+        let viewport = self.viewport.bounds?;
+        let size = self.scaled_media_size_rotated(rotation)?;
+        let padding = Self::compute_padding(viewport, size);
+        let scale = media_scale(self.zoom_percent).max(0.01);
+
+        let (media_width, media_height) = if rotation.swaps_dimensions() {
+            (media.height(), media.width())
+        } else {
+            (media.width(), media.height())
+        };
+
+        let mut visible_width = viewport.width;
+        let mut visible_height = viewport.height;
+        if size.height > viewport.height {
+            visible_width -= SCROLLBAR_GUTTER;
+        }
+        if size.width > viewport.width {
+            visible_height -= SCROLLBAR_GUTTER;
+        }
+
+        let span = |offset: f32, padding: f32, visible: f32, limit: u32| {
+            let start = ((offset - padding).max(0.0) / scale).floor();
+            let end = ((offset - padding + visible) / scale).ceil();
+            let start = (start as u32).min(limit);
+            let end = (end.max(0.0) as u32).min(limit);
+            (end > start).then_some((start, end - start))
+        };
+
+        let (x, width) = span(
+            self.viewport.offset.x,
+            padding.left,
+            visible_width,
+            media_width,
+        )?;
+        let (y, height) = span(
+            self.viewport.offset.y,
+            padding.top,
+            visible_height,
+            media_height,
+        )?;
+
+        Some(Rectangle {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    /// Returns the scroll offset keeping the media point under `anchor` in
+    /// place when the zoom changes to `new_zoom_percent`.
+    ///
+    /// `anchor` is in window coordinates, like the cursor position. Anchors
+    /// outside the media use its nearest edge. The offset is clamped to what
+    /// the scrollable allows at the new zoom, so a media smaller than the
+    /// viewport stays centered.
+    #[allow(clippy::cast_precision_loss)] // u32 to f32 for dimensions: f32 is exact up to 16M
+    #[must_use]
+    pub fn zoom_anchor_offset(
+        &self,
+        rotation: RotationAngle,
+        new_zoom_percent: f32,
+        anchor: Point,
+    ) -> Option<AbsoluteOffset> {
+        let media = self.media?;
+        let viewport = self.viewport.bounds?;
+        let old_size = self.scaled_media_size_rotated(rotation)?;
+        let old_padding = Self::compute_padding(viewport, old_size);
+        let old_scale = media_scale(self.zoom_percent).max(0.01);
+        let new_scale = media_scale(new_zoom_percent).max(0.01);
+
+        let (media_width, media_height) = if rotation.swaps_dimensions() {
+            (media.height() as f32, media.width() as f32)
+        } else {
+            (media.width() as f32, media.height() as f32)
+        };
+        let new_size = Size::new(
+            (media_width * new_scale).max(1.0),
+            (media_height * new_scale).max(1.0),
+        );
+        let new_padding = Self::compute_padding(viewport, new_size);
+
+        // Anchor relative to the viewport, then in media pixels
+        let view_x = anchor.x - viewport.x;
+        let view_y = anchor.y - viewport.y;
+        let media_x = ((view_x + self.viewport.offset.x - old_padding.left) / old_scale)
+            .clamp(0.0, media_width);
+        let media_y = ((view_y + self.viewport.offset.y - old_padding.top) / old_scale)
+            .clamp(0.0, media_height);
+
+        let max_x = (new_size.width - viewport.width).max(0.0);
+        let max_y = (new_size.height - viewport.height).max(0.0);
+        Some(AbsoluteOffset {
+            x: (media_x * new_scale + new_padding.left - view_x).clamp(0.0, max_x),
+            y: (media_y * new_scale + new_padding.top - view_y).clamp(0.0, max_y),
+        })
+    }
+
+    /// Returns where `point`, in window coordinates, falls on the media as
+    /// displayed after `rotation`, in fractions of its size, or `None`
+    /// outside the media.
+    #[must_use]
+    pub fn media_fraction_at(&self, rotation: RotationAngle, point: Point) -> Option<Point> {
+        let viewport = self.viewport.bounds?;
+        let size = self.scaled_media_size_rotated(rotation)?;
+        let padding = Self::compute_padding(viewport, size);
+        let x = (point.x - viewport.x + self.viewport.offset.x - padding.left) / size.width;
+        let y = (point.y - viewport.y + self.viewport.offset.y - padding.top) / size.height;
+        ((0.0..1.0).contains(&x) && (0.0..1.0).contains(&y)).then_some(Point::new(x, y))
+    }
+
     /// Indicates whether the cursor is currently positioned over the media.
     #[must_use]
     pub fn is_cursor_over_media(&self) -> bool {
@@ -216,7 +338,6 @@ fn intersect_rectangles(a: Rectangle, b: Rectangle) -> Option<Rectangle> {
 mod tests {
     use super::*;
     use crate::media::{ImageData, MediaData};
-    use iced::widget::scrollable::AbsoluteOffset;
 
     fn sample_media() -> MediaData {
         let pixels = vec![255_u8; 4];
@@ -268,4 +389,81 @@ mod tests {
 
         assert!(!state.is_cursor_over_media());
     }
+
+    #[test]
+    fn visible_region_follows_zoom_and_scroll() {
+        let media = MediaData::Image(ImageData::from_rgba(1000, 800, vec![0; 1000 * 800 * 4]));
+        let mut viewport = viewport_with_bounds();
+        viewport.offset = AbsoluteOffset { x: 200.0, y: 100.0 };
+        let state = ViewerState::new(Some(&media), &viewport, 200.0, None);
+
+        let region = state
+            .visible_media_region(RotationAngle::default())
+            .expect("region");
+        assert_eq!((region.x, region.y), (100, 50));
+        // Viewport minus the scrollbar gutters, at half a source pixel per screen pixel
+        assert_eq!((region.width, region.height), (192, 142));
+    }
+
+    #[test]
+    fn visible_region_covers_small_media_entirely() {
+        let media = sample_media();
+        let viewport = viewport_with_bounds();
+        let state = ViewerState::new(Some(&media), &viewport, 100.0, None);
+
+        let region = state
+            .visible_media_region(RotationAngle::default())
+            .expect("region");
+        assert_eq!(
+            (region.x, region.y, region.width, region.height),
+            (0, 0, 1, 1)
+        );
+    }
+
+    #[test]
+    fn media_fraction_accounts_for_centering_and_scroll() {
+        let media = MediaData::Image(ImageData::from_rgba(100, 100, vec![0; 100 * 100 * 4]));
+        let viewport = viewport_with_bounds();
+        let state = ViewerState::new(Some(&media), &viewport, 100.0, None);
+        // The 100px media is centered in the 400x300 viewport
+        let point = state
+            .media_fraction_at(RotationAngle::default(), Point::new(175.0, 175.0))
+            .expect("over the media");
+        assert!((point.x - 0.25).abs() < f32::EPSILON);
+        assert!((point.y - 0.75).abs() < f32::EPSILON);
+        assert!(state
+            .media_fraction_at(RotationAngle::default(), Point::new(10.0, 10.0))
+            .is_none());
+    }
+
+    #[test]
+    fn zoom_keeps_the_point_under_the_anchor_still() {
+        let pixels = vec![255_u8; 1000 * 800 * 4];
+        let media = MediaData::Image(ImageData::from_rgba(1000, 800, pixels));
+        let mut viewport = viewport_with_bounds();
+        viewport.offset = AbsoluteOffset { x: 100.0, y: 50.0 };
+        let state = ViewerState::new(Some(&media), &viewport, 100.0, None);
+
+        // Media pixel (300, 200) is under the anchor at 100%
+        let anchor = Point::new(200.0, 150.0);
+        let offset = state
+            .zoom_anchor_offset(RotationAngle::default(), 200.0, anchor)
+            .expect("offset");
+        assert!((offset.x - (300.0 * 2.0 - 200.0)).abs() < 0.01);
+        assert!((offset.y - (200.0 * 2.0 - 150.0)).abs() < 0.01);
+    }
+
+    #[test]
+    fn zooming_out_below_the_viewport_recenters() {
+        let pixels = vec![255_u8; 1000 * 800 * 4];
+        let media = MediaData::Image(ImageData::from_rgba(1000, 800, pixels));
+        let mut viewport = viewport_with_bounds();
+        viewport.offset = AbsoluteOffset { x: 500.0, y: 400.0 };
+        let state = ViewerState::new(Some(&media), &viewport, 100.0, None);
+
+        let offset = state
+            .zoom_anchor_offset(RotationAngle::default(), 25.0, Point::new(10.0, 10.0))
+            .expect("offset");
+        assert_eq!(offset, AbsoluteOffset { x: 0.0, y: 0.0 });
+    }
 }
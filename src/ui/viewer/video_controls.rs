@@ -6,10 +6,20 @@
 
 use crate::config;
 use crate::i18n::fluent::I18n;
-use crate::ui::design_tokens::{sizing, spacing};
-use crate::ui::{action_icons, icons, styles};
-use crate::video_player::Volume;
-use iced::widget::{button, column, container, row, slider, text, tooltip, Column, Row, Space};
+use crate::media::chapters::{self, Chapter};
+use crate::media::frame_encoder::{SequenceFormat, SequenceOptions, FPS_CHOICES, SCALE_CHOICES};
+use crate::media::frame_export::{BurstOptions, BURST_COUNT_CHOICES, BURST_INTERVAL_CHOICES_MS};
+use crate::ui::design_tokens::{sizing, spacing, typography};
+use crate::ui::viewer::audio_strip::{self, AudioStrip};
+use crate::ui::widgets::ColorAdjustments;
+use crate::ui::{accessibility, action_icons, icons, styles};
+use crate::video_player::fine_scrub::FineStep;
+use crate::video_player::seek_thumbnails::THUMBNAIL_WIDTH;
+use crate::video_player::{CacheStats, DecodePath, Volume};
+use iced::widget::{
+    button, column, container, image, mouse_area, pick_list, responsive, row, scrollable, slider,
+    text, tooltip, Column, Row, Space, Stack,
+};
 use iced::{Element, Length, Theme};
 
 /// Helper to create a styled tooltip positioned above the element.
@@ -87,29 +97,46 @@ fn build_volume_controls<'a>(
 
     // Volume slider - disabled when no audio track
     let current_volume = state.volume;
+    let boosted = Volume::new(current_volume).is_boosted();
     let volume_slider: Element<'_, Message> = if state.has_audio {
-        slider(0.0..=config::MAX_VOLUME, current_volume, |v| {
+        let slider = slider(0.0..=config::MAX_VOLUME, current_volume, |v| {
             Message::SetVolume(Volume::new(v))
         })
-        .width(Length::Fixed(80.0))
-        .step(0.01)
-        .into()
+        .width(Length::Fill)
+        .step(0.01);
+        let slider = if boosted {
+            slider.style(styles::slider::boosted)
+        } else {
+            slider
+        };
+        // The part of the range above 100% boosts the audio: a band under
+        // the rail marks it
+        Stack::new()
+            .push(build_boost_zone())
+            .push(slider)
+            .width(Length::Fixed(VOLUME_SLIDER_WIDTH))
+            .into()
     } else {
         slider(0.0..=config::MAX_VOLUME, current_volume, move |_v| {
             Message::SetVolume(Volume::new(current_volume))
         })
-        .width(Length::Fixed(80.0))
+        .width(Length::Fixed(VOLUME_SLIDER_WIDTH))
         .step(0.01)
         .style(styles::slider::disabled())
         .into()
     };
 
-    // Volume percentage text - grayed when no audio
+    // Volume percentage text - grayed when no audio, warning color when boosted
     let volume_percent: Element<'_, Message> = if state.has_audio {
-        text(format_volume_percent(state.volume))
+        let percent = text(format_volume_percent(state.volume))
             .size(sizing::ICON_SM)
-            .width(Length::Fixed(40.0))
-            .into()
+            .width(Length::Fixed(40.0));
+        if boosted {
+            let percent = percent.style(styles::slider::boosted_text_style);
+            tip(percent, ctx.i18n.tr("video-volume-boost-tooltip")).into()
+        } else {
+            percent.into()
+        }
     } else {
         text(format_volume_percent(state.volume))
             .size(sizing::ICON_SM)
@@ -121,6 +148,47 @@ fn build_volume_controls<'a>(
     (volume_button_content, volume_slider, volume_percent)
 }
 
+/// Width of the volume slider, in logical pixels.
+const VOLUME_SLIDER_WIDTH: f32 = 80.0;
+
+/// Height of the band marking the boost zone of the volume slider, in
+/// logical pixels.
+const BOOST_ZONE_HEIGHT: f32 = 3.0;
+
+/// Builds the band under the volume slider marking the range above 100%.
+fn build_boost_zone<'a>() -> Element<'a, Message> {
+    // Fractions of the slider range, 100% sits at UNITY / MAX
+    let unity = f64::from(config::UNITY_VOLUME / config::MAX_VOLUME);
+    let band = row![
+        Space::new().width(Length::FillPortion(marker_portion(unity))),
+        container(Space::new())
+            .width(Length::FillPortion(marker_portion(1.0 - unity)))
+            .height(Length::Fixed(BOOST_ZONE_HEIGHT))
+            .style(styles::container::boost_zone),
+    ];
+
+    container(band)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_y(iced::alignment::Vertical::Bottom)
+        .into()
+}
+
+/// Width of a chapter marker on the timeline, in logical pixels.
+const CHAPTER_MARKER_WIDTH: f32 = 2.0;
+
+/// Height of a chapter marker on the timeline, in logical pixels.
+const CHAPTER_MARKER_HEIGHT: f32 = 10.0;
+
+/// Height of the A–B loop region band on the timeline, in logical pixels.
+const LOOP_REGION_HEIGHT: f32 = 8.0;
+
+/// Number of `FillPortion` units spanning the whole timeline.
+const CHAPTER_MARKER_RESOLUTION: u16 = 10_000;
+
+/// Maximum height of the chapter list popup before it scrolls.
+const CHAPTER_LIST_MAX_HEIGHT: f32 = 240.0;
+
 /// Slider step in seconds (1ms precision).
 /// f64 has ~15 significant digits, so even for 24h videos (86400s),
 /// we have plenty of precision for millisecond accuracy.
@@ -139,6 +207,12 @@ pub enum Message {
     /// Commit seek - slider released, perform actual seek to preview position.
     SeekCommit,
 
+    /// The cursor hovers the timeline at this position in seconds.
+    SeekHover(f64),
+
+    /// The cursor left the timeline.
+    SeekHoverEnd,
+
     /// Seek relative to current position (in seconds, can be negative).
     /// Used by keyboard shortcuts (e.g., arrow keys for ±5s).
     SeekRelative(f64),
@@ -152,6 +226,51 @@ pub enum Message {
     /// Toggle loop mode.
     ToggleLoop,
 
+    /// Set the A–B loop-in point at the current position.
+    SetLoopIn,
+
+    /// Set the A–B loop-out point at the current position.
+    SetLoopOut,
+
+    /// Clear the A–B loop region.
+    ClearLoopRegion,
+
+    /// Export the A–B loop region to a new video file.
+    ExportClip,
+
+    /// Cancel the running clip export.
+    CancelClipExport,
+
+    /// Show or hide the "Export frames" panel.
+    ToggleFramesExport,
+
+    /// Set the output of the frame range export.
+    SetFramesFormat(SequenceFormat),
+
+    /// Set the frame rate of the frame range export.
+    SetFramesFps(u32),
+
+    /// Set the output scale of the frame range export, in percent.
+    SetFramesScale(u32),
+
+    /// Set the length of the range exported around the playhead, in seconds.
+    SetFramesWindow(u32),
+
+    /// Choose between exporting a range and capturing a burst of frames.
+    SetFramesMode(FramesExportMode),
+
+    /// Set the number of frames of a burst capture.
+    SetBurstCount(u32),
+
+    /// Set the time between two frames of a burst capture, in milliseconds.
+    SetBurstInterval(u32),
+
+    /// Export the frame range with the panel's options.
+    ExportFrames,
+
+    /// Cancel the running frame range export.
+    CancelFramesExport,
+
     /// Capture current frame and export to file.
     CaptureFrame,
 
@@ -169,6 +288,147 @@ pub enum Message {
 
     /// Decrease playback speed to previous preset.
     DecreasePlaybackSpeed,
+
+    /// Set the playback speed from the speed selector.
+    SetPlaybackSpeed(f64),
+
+    /// Seek to the start of the next chapter.
+    NextChapter,
+
+    /// Seek to the start of the current chapter, or the previous one near its start.
+    PreviousChapter,
+
+    /// Toggle the chapter list popup.
+    ToggleChapterList,
+
+    /// Seek to the start of the chapter at this index.
+    SeekToChapter(usize),
+
+    /// Show the subtitle track at this index, or hide subtitles with `None`.
+    SelectSubtitleTrack(Option<usize>),
+
+    /// Shift subtitles by this many milliseconds (positive = later).
+    AdjustSubtitleDelay(i32),
+
+    /// Reset the subtitle delay to zero.
+    ResetSubtitleDelay,
+
+    /// Switch to the next subtitle text size.
+    CycleSubtitleSize,
+
+    /// Switch between no audio strip, the waveform and the VU meter.
+    CycleAudioVisualization,
+
+    /// Show or hide the color adjustment sliders.
+    ToggleColorAdjustments,
+
+    /// Set the brightness, contrast, saturation and gamma of the video.
+    SetColorAdjustments(ColorAdjustments),
+
+    /// Restore the video's original colors.
+    ResetColorAdjustments,
+
+    /// Continue the video in the mini player window.
+    OpenMiniPlayer,
+
+    /// Toggle the translucent box behind subtitles.
+    ToggleSubtitleBackground,
+
+    /// Jump to the position offered by the resume prompt.
+    ResumePlayback,
+
+    /// Close the resume prompt and keep playing from the start.
+    DismissResume,
+}
+
+/// Subtitle state shown in the overflow menu.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SubtitleControls {
+    /// Labels of the available tracks.
+    pub tracks: Vec<String>,
+    /// Index of the displayed track, `None` when subtitles are off.
+    pub selected: Option<usize>,
+    /// Subtitle delay in milliseconds.
+    pub delay_ms: i32,
+    pub size: config::SubtitleSize,
+    pub background: bool,
+}
+
+/// Subtitle track option for the pick list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SubtitleTrackOption {
+    track: Option<usize>,
+    label: String,
+}
+
+impl std::fmt::Display for SubtitleTrackOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+/// Lengths in seconds of the range exported around the playhead when no
+/// A–B loop region is set.
+pub const FRAMES_WINDOW_CHOICES: [u32; 3] = [2, 5, 10];
+
+/// What the "Export frames" panel exports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FramesExportMode {
+    /// A time range as an image sequence, GIF or WebP.
+    #[default]
+    Range,
+    /// A few frames around the playhead, each saved as a full-size image
+    /// named from the frame name template.
+    Burst,
+}
+
+/// State of the "Export frames" panel of the overflow menu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FramesExportPanel {
+    /// Is the panel shown?
+    pub open: bool,
+    pub mode: FramesExportMode,
+    pub options: SequenceOptions,
+    pub burst: BurstOptions,
+    /// Length of the range centered on the playhead, used without an A–B region.
+    pub window_secs: u32,
+    /// Progress of the running export (0.0 to 1.0), if any.
+    pub progress: Option<f32>,
+}
+
+impl Default for FramesExportPanel {
+    fn default() -> Self {
+        Self {
+            open: false,
+            mode: FramesExportMode::default(),
+            options: SequenceOptions::default(),
+            burst: BurstOptions::default(),
+            window_secs: 5,
+            progress: None,
+        }
+    }
+}
+
+/// Labelled value for the pick lists of the controls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Choice<T> {
+    value: T,
+    label: String,
+}
+
+impl<T> std::fmt::Display for Choice<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+/// Preview shown above the timeline while it is hovered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeekHoverPreview {
+    /// Hovered position in seconds.
+    pub position_secs: f64,
+    /// Frame near that position, once decoded.
+    pub thumbnail: Option<image::Handle>,
 }
 
 /// View context for rendering video controls.
@@ -205,6 +465,9 @@ pub struct PlaybackState {
     /// When Some, the slider shows this position instead of actual playback position.
     pub seek_preview_position: Option<f64>,
 
+    /// Fine scrubbing step while Shift is held, shown next to the time.
+    pub fine_step: Option<FineStep>,
+
     /// Is the overflow menu (advanced controls) open?
     pub overflow_menu_open: bool,
 
@@ -219,12 +482,58 @@ pub struct PlaybackState {
     /// Current playback speed (1.0 = normal).
     pub playback_speed: f64,
 
-    /// Whether audio is auto-muted due to high speed (>2x).
+    /// Whether audio is auto-muted because the speed is outside 0.25x–3x.
     pub speed_auto_muted: bool,
 
     /// Whether this media has an audio track.
     /// When false, audio controls (mute button, volume slider) are disabled.
     pub has_audio: bool,
+
+    /// Subtitle tracks and style.
+    pub subtitles: SubtitleControls,
+
+    /// Chapters of the video, sorted by start time.
+    pub chapters: Vec<Chapter>,
+
+    /// Is the chapter list popup open?
+    pub chapter_list_open: bool,
+
+    /// A–B loop region as `(start, end)` in seconds, if set.
+    pub loop_region: Option<(f64, f64)>,
+
+    /// Progress of the running clip export (0.0 to 1.0), if any.
+    pub clip_export_progress: Option<f32>,
+
+    /// Options and progress of the "Export frames" panel.
+    pub frames_export: FramesExportPanel,
+
+    /// How the video is decoded, once known (shown in the overflow menu).
+    pub decode_path: Option<DecodePath>,
+
+    /// Frame cache statistics, once the cache has been looked up.
+    pub cache_stats: Option<CacheStats>,
+
+    /// Hover preview of the timeline, while the cursor is over it.
+    pub seek_hover: Option<SeekHoverPreview>,
+
+    /// Audio visualization mode, shown in the overflow menu.
+    pub audio_visualization: config::AudioVisualization,
+
+    /// Audio strip drawn under the seek bar, if enabled.
+    pub audio_strip: Option<AudioStrip>,
+
+    /// Current and total frame numbers, for animated images and image
+    /// sequences (videos show time only).
+    pub frame_counter: Option<(usize, usize)>,
+
+    /// Remembered position offered to resume from, until answered.
+    pub resume_offer: Option<f64>,
+
+    /// Are the color adjustment sliders shown?
+    pub color_adjustments_open: bool,
+
+    /// Color adjustments applied to the video.
+    pub color_adjustments: ColorAdjustments,
 }
 
 impl Default for PlaybackState {
@@ -237,12 +546,28 @@ impl Default for PlaybackState {
             muted: false,
             loop_enabled: false,
             seek_preview_position: None,
+            fine_step: None,
             overflow_menu_open: false,
             can_step_backward: false,
             can_step_forward: false,
             playback_speed: 1.0,
             speed_auto_muted: false,
             has_audio: true,
+            subtitles: SubtitleControls::default(),
+            chapters: Vec::new(),
+            chapter_list_open: false,
+            loop_region: None,
+            clip_export_progress: None,
+            frames_export: FramesExportPanel::default(),
+            decode_path: None,
+            cache_stats: None,
+            seek_hover: None,
+            audio_visualization: config::AudioVisualization::default(),
+            audio_strip: None,
+            frame_counter: None,
+            resume_offer: None,
+            color_adjustments_open: false,
+            color_adjustments: ColorAdjustments::default(),
         }
     }
 }
@@ -258,7 +583,7 @@ impl Default for PlaybackState {
 pub fn view<'a>(ctx: ViewContext<'a>, state: &PlaybackState) -> Element<'a, Message> {
     // Icon size for control buttons (consistent with design tokens)
     let icon_size = sizing::ICON_SM;
-    let button_height = sizing::BUTTON_HEIGHT;
+    let button_height = accessibility::button_height();
 
     let play_pause_svg = if state.is_playing {
         icons::sized(action_icons::video::toolbar::pause(), icon_size)
@@ -285,22 +610,77 @@ pub fn view<'a>(ctx: ViewContext<'a>, state: &PlaybackState) -> Element<'a, Mess
     // Use preview position during drag, otherwise use actual playback position
     let timeline_position = state.seek_preview_position.unwrap_or(state.position_secs);
 
-    // Use on_change for visual preview, on_release for actual seek
-    let timeline = slider(
-        0.0..=state.duration_secs,
-        timeline_position,
-        Message::SeekPreview,
-    )
-    .on_release(Message::SeekCommit)
+    // The timeline is built inside `responsive` so hover positions can be
+    // converted to seconds using its width
+    let duration_secs = state.duration_secs;
+    let chapters = state.chapters.clone();
+    let loop_region = state.loop_region;
+    let strip = state.audio_strip.clone();
+    let timeline_height = if strip.is_some() {
+        button_height + audio_strip::STRIP_HEIGHT
+    } else {
+        button_height
+    };
+    let timeline = container(responsive(move |size| {
+        // Use on_change for visual preview, on_release for actual seek
+        let timeline_slider = slider(0.0..=duration_secs, timeline_position, Message::SeekPreview)
+            .on_release(Message::SeekCommit)
+            .width(Length::Fill)
+            .step(SLIDER_STEP_SECS);
+
+        // The A–B loop region is drawn under the slider, chapter markers over its rail
+        let mut timeline = Stack::new();
+        if let Some(region) = loop_region.and_then(|r| build_loop_region(r, duration_secs)) {
+            timeline = timeline.push(region);
+        }
+        timeline = timeline.push(timeline_slider);
+        if let Some(markers) = build_chapter_markers(&chapters, duration_secs) {
+            timeline = timeline.push(markers);
+        }
+
+        // The audio strip sits under the slider, aligned with its rail
+        let timeline: Element<'_, Message> = match strip.clone() {
+            Some(strip) => column![
+                timeline.height(Length::Fixed(button_height)),
+                audio_strip::view(strip, timeline_position, duration_secs),
+            ]
+            .into(),
+            None => timeline.into(),
+        };
+
+        mouse_area(timeline)
+            .on_move(move |point| {
+                Message::SeekHover(hover_position(point.x, size.width, duration_secs))
+            })
+            .on_exit(Message::SeekHoverEnd)
+            .into()
+    }))
     .width(Length::FillPortion(1))
-    .step(SLIDER_STEP_SECS);
+    .height(Length::Fixed(timeline_height));
 
     // Format time display - use monospace-like sizing
-    let time_display = text(format!(
+    let time = format!(
         "{} / {}",
         format_time(state.position_secs),
         format_time(state.duration_secs)
-    ))
+    );
+    // Frame-based media also show which frame is on screen
+    let time = match state.frame_counter {
+        Some((current, total)) => {
+            let (current, total) = (current.to_string(), total.to_string());
+            let frame = ctx.i18n.tr_with_args(
+                "video-frame-counter",
+                &[("current", current.as_str()), ("total", total.as_str())],
+            );
+            format!("{time} · {frame}")
+        }
+        None => time,
+    };
+    // Fine scrubbing tells the step it moves by
+    let time_display = text(match state.fine_step {
+        Some(step) => format!("{time} · {}", ctx.i18n.tr(step.i18n_key())),
+        None => time,
+    })
     .size(sizing::ICON_SM);
 
     // Volume controls (button, slider, percentage)
@@ -345,39 +725,282 @@ pub fn view<'a>(ctx: ViewContext<'a>, state: &PlaybackState) -> Element<'a, Mess
     let loop_button = tip(loop_button_content, ctx.i18n.tr("video-loop-tooltip"));
 
     // Main controls row (simplified - advanced controls in overflow menu)
-    let controls: Row<'a, Message> = row![
+    let mut controls: Row<'a, Message> = row![
         play_pause_button,
         timeline,
         time_display,
         volume_button_content,
         volume_slider,
         volume_percent,
-        loop_button,
-        more_button,
     ]
     .spacing(spacing::XS)
     .padding(spacing::XS)
     .align_y(iced::Alignment::Center);
 
-    // Build overflow menu content if open
+    // Chapter buttons only for videos with chapters
+    if !state.chapters.is_empty() {
+        controls = controls.extend(build_chapter_buttons(&ctx, state, icon_size, button_height));
+    }
+    let controls = controls.push(loop_button).push(more_button);
+
+    // Popups stack above the main controls
+    let mut stacked: Column<'a, Message> = Column::new().spacing(spacing::XXS).width(Length::Fill);
+    if let Some(position_secs) = state.resume_offer {
+        stacked = stacked.push(build_resume_offer(&ctx, position_secs));
+    }
+    if state.chapter_list_open && !state.chapters.is_empty() {
+        stacked = stacked.push(build_chapter_list(state));
+    }
     if state.overflow_menu_open {
-        let overflow_content = build_overflow_menu(ctx, state, icon_size, button_height);
+        stacked = stacked.push(build_overflow_menu(ctx, state, icon_size, button_height));
+    }
+    if let Some(hover) = &state.seek_hover {
+        stacked = stacked.push(build_seek_hover_preview(hover, state.duration_secs));
+    }
+    let stacked = stacked.push(controls);
 
-        // Stack: overflow menu above main controls
-        let stacked: Column<'a, Message> = column![overflow_content, controls]
-            .spacing(spacing::XXS)
-            .width(Length::Fill);
+    container(stacked)
+        .width(Length::Fill)
+        .padding(spacing::XXS)
+        .into()
+}
 
-        container(stacked)
-            .width(Length::Fill)
-            .padding(spacing::XXS)
-            .into()
+/// Builds the previous chapter, chapter list and next chapter buttons.
+fn build_chapter_buttons<'a>(
+    ctx: &ViewContext<'a>,
+    state: &PlaybackState,
+    icon_size: f32,
+    button_height: f32,
+) -> Vec<Element<'a, Message>> {
+    let position = state.seek_preview_position.unwrap_or(state.position_secs);
+    let has_previous = chapters::previous_chapter_start(&state.chapters, position).is_some();
+    let has_next = chapters::next_chapter_start(&state.chapters, position).is_some();
+
+    let previous_button = tip(
+        toolbar_button(
+            icons::sized(action_icons::video::toolbar::previous_chapter(), icon_size),
+            has_previous.then_some(Message::PreviousChapter),
+            button_height,
+        ),
+        ctx.i18n.tr("video-chapter-previous-tooltip"),
+    );
+
+    let list_base = button(icons::sized(
+        action_icons::video::toolbar::chapter_list(),
+        icon_size,
+    ))
+    .on_press(Message::ToggleChapterList)
+    .padding(spacing::XS)
+    .width(Length::Shrink)
+    .height(Length::Fixed(button_height));
+    let list_content: Element<'a, Message> = if state.chapter_list_open {
+        list_base.style(styles::button::selected).into()
     } else {
-        container(controls)
-            .width(Length::Fill)
+        list_base.into()
+    };
+    let list_button = tip(list_content, ctx.i18n.tr("video-chapter-list-tooltip"));
+
+    let next_button = tip(
+        toolbar_button(
+            icons::sized(action_icons::video::toolbar::next_chapter(), icon_size),
+            has_next.then_some(Message::NextChapter),
+            button_height,
+        ),
+        ctx.i18n.tr("video-chapter-next-tooltip"),
+    );
+
+    vec![
+        previous_button.into(),
+        list_button.into(),
+        next_button.into(),
+    ]
+}
+
+/// Converts a cursor `x` over a timeline `width` pixels wide into seconds.
+fn hover_position(x: f32, width: f32, duration_secs: f64) -> f64 {
+    if width <= 0.0 {
+        return 0.0;
+    }
+    f64::from((x / width).clamp(0.0, 1.0)) * duration_secs
+}
+
+/// Builds the hover preview (thumbnail and timecode), placed roughly above
+/// the hovered position.
+fn build_seek_hover_preview<'a>(
+    hover: &SeekHoverPreview,
+    duration_secs: f64,
+) -> Element<'a, Message> {
+    let mut card = Column::new()
+        .spacing(spacing::XXS)
+        .align_x(iced::Alignment::Center);
+    if let Some(handle) = &hover.thumbnail {
+        // THUMBNAIL_WIDTH is a small constant, exactly representable as f32
+        #[allow(clippy::cast_precision_loss)]
+        let width = THUMBNAIL_WIDTH as f32;
+        card = card.push(image(handle.clone()).width(Length::Fixed(width)));
+    }
+    let card = card.push(text(format_time(hover.position_secs)).size(typography::BODY_SM));
+
+    let fraction = if duration_secs > 0.0 {
+        (hover.position_secs / duration_secs).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    row![
+        Space::new().width(Length::FillPortion(marker_portion(fraction))),
+        container(card)
             .padding(spacing::XXS)
-            .into()
+            .style(styles::container::panel),
+        Space::new().width(Length::FillPortion(marker_portion(1.0 - fraction))),
+    ]
+    .width(Length::Fill)
+    .into()
+}
+
+/// Builds the "Resume from 12:34?" prompt shown when a video is reopened.
+fn build_resume_offer<'a>(ctx: &ViewContext<'a>, position_secs: f64) -> Element<'a, Message> {
+    let prompt = text(ctx.i18n.tr_with_args(
+        "video-resume-prompt",
+        &[("position", format_time(position_secs).as_str())],
+    ))
+    .size(typography::BODY_SM);
+    let resume = button(text(ctx.i18n.tr("video-resume-button")).size(typography::BODY_SM))
+        .on_press(Message::ResumePlayback)
+        .padding(spacing::XXS)
+        .style(styles::button::selected);
+    let start_over =
+        button(text(ctx.i18n.tr("video-resume-start-over-button")).size(typography::BODY_SM))
+            .on_press(Message::DismissResume)
+            .padding(spacing::XXS)
+            .style(button::text);
+
+    container(
+        row![prompt, resume, start_over]
+            .spacing(spacing::SM)
+            .align_y(iced::Alignment::Center),
+    )
+    .padding(spacing::XS)
+    .style(styles::container::panel)
+    .into()
+}
+
+/// Builds thin tick marks at chapter starts, laid out to match the slider rail.
+fn build_chapter_markers<'a>(
+    chapters: &[Chapter],
+    duration_secs: f64,
+) -> Option<Element<'a, Message>> {
+    if duration_secs <= 0.0 {
+        return None;
+    }
+    let fractions: Vec<f64> = chapters
+        .iter()
+        .map(|chapter| chapter.start_secs / duration_secs)
+        .filter(|fraction| *fraction > 0.0 && *fraction < 1.0)
+        .collect();
+    if fractions.is_empty() {
+        return None;
+    }
+
+    let mut markers = Row::new().align_y(iced::Alignment::Center);
+    let mut previous = 0.0;
+    for fraction in fractions {
+        markers = markers
+            .push(Space::new().width(Length::FillPortion(marker_portion(fraction - previous))))
+            .push(
+                container(Space::new())
+                    .width(Length::Fixed(CHAPTER_MARKER_WIDTH))
+                    .height(Length::Fixed(CHAPTER_MARKER_HEIGHT))
+                    .style(styles::container::chapter_marker),
+            );
+        previous = fraction;
+    }
+    markers = markers.push(Space::new().width(Length::FillPortion(marker_portion(1.0 - previous))));
+
+    Some(
+        container(markers)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_y(iced::alignment::Vertical::Center)
+            .into(),
+    )
+}
+
+/// Builds the highlighted band of the A–B loop region, laid out to match the
+/// slider rail.
+fn build_loop_region<'a>(
+    (start_secs, end_secs): (f64, f64),
+    duration_secs: f64,
+) -> Option<Element<'a, Message>> {
+    if duration_secs <= 0.0 {
+        return None;
     }
+    let start = (start_secs / duration_secs).clamp(0.0, 1.0);
+    let end = (end_secs / duration_secs).clamp(start, 1.0);
+
+    let band = row![
+        Space::new().width(Length::FillPortion(marker_portion(start))),
+        container(Space::new())
+            .width(Length::FillPortion(marker_portion(end - start)))
+            .height(Length::Fixed(LOOP_REGION_HEIGHT))
+            .style(styles::container::loop_region),
+        Space::new().width(Length::FillPortion(marker_portion(1.0 - end))),
+    ]
+    .align_y(iced::Alignment::Center);
+
+    Some(
+        container(band)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_y(iced::alignment::Vertical::Center)
+            .into(),
+    )
+}
+
+/// Converts a fraction of the timeline into a `FillPortion` weight.
+fn marker_portion(fraction: f64) -> u16 {
+    // Fractions are within 0.0–1.0, so the weight fits u16
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let portion = (fraction * f64::from(CHAPTER_MARKER_RESOLUTION)).round() as u16;
+    portion.max(1)
+}
+
+/// Builds the chapter list popup; the current chapter is highlighted.
+fn build_chapter_list<'a>(state: &PlaybackState) -> Element<'a, Message> {
+    let position = state.seek_preview_position.unwrap_or(state.position_secs);
+    let current = chapters::chapter_at(&state.chapters, position);
+
+    let items = state
+        .chapters
+        .iter()
+        .enumerate()
+        .fold(Column::new(), |list, (index, chapter)| {
+            let title = chapter
+                .title
+                .clone()
+                .unwrap_or_else(|| format!("#{}", index + 1));
+            let item = button(
+                row![
+                    text(format_time(chapter.start_secs)).size(sizing::ICON_SM),
+                    text(title).size(sizing::ICON_SM),
+                ]
+                .spacing(spacing::SM),
+            )
+            .on_press(Message::SeekToChapter(index))
+            .padding(spacing::XXS)
+            .width(Length::Fill);
+            let item = if current == Some(index) {
+                item.style(styles::button::selected)
+            } else {
+                item.style(button::text)
+            };
+            list.push(item)
+        });
+
+    container(scrollable(items.spacing(spacing::XXS)).height(Length::Shrink))
+        .max_height(CHAPTER_LIST_MAX_HEIGHT)
+        .width(Length::Fill)
+        .padding(spacing::XS)
+        .into()
 }
 
 /// Builds the overflow menu with advanced controls.
@@ -400,10 +1023,27 @@ fn build_overflow_menu<'a>(
         ctx.i18n.tr("video-speed-down-tooltip"),
     );
 
-    // Speed label (text showing current speed)
-    let speed_label = text(format_playback_speed(state.playback_speed))
-        .size(sizing::ICON_SM)
-        .width(Length::Shrink);
+    // Speed selector (speeds reached with the buttons show as placeholder)
+    let speeds: Vec<Choice<f64>> = config::PLAYBACK_SPEED_SELECTOR_CHOICES
+        .iter()
+        .map(|&speed| Choice {
+            value: speed,
+            label: format_playback_speed(speed),
+        })
+        .collect();
+    let selected_speed = speeds
+        .iter()
+        .find(|option| (option.value - state.playback_speed).abs() < 0.001)
+        .cloned();
+    let speed_label = tip(
+        pick_list(speeds, selected_speed, |option| {
+            Message::SetPlaybackSpeed(option.value)
+        })
+        .placeholder(format_playback_speed(state.playback_speed))
+        .text_size(sizing::ICON_SM)
+        .padding(spacing::XS),
+        ctx.i18n.tr("video-speed-selector-tooltip"),
+    );
 
     // Speed up button (disabled at maximum speed)
     let at_max_speed = state.playback_speed >= config::MAX_PLAYBACK_SPEED;
@@ -450,9 +1090,137 @@ fn build_overflow_menu<'a>(
         ctx.i18n.tr("video-capture-tooltip"),
     );
 
-    // Layout: [Space] [Speed Down] [1x] [Speed Up] | [Step Back] [Step Fwd] [Capture]
+    // A–B loop buttons; clearing is only offered while a region is set
+    let loop_point_button = |label: &'static str, message: Message, tooltip_key: &str| {
+        tip(
+            button(text(label).size(sizing::ICON_SM))
+                .on_press(message)
+                .padding(spacing::XS)
+                .height(Length::Fixed(button_height)),
+            ctx.i18n.tr(tooltip_key),
+        )
+    };
+    let loop_in_button = loop_point_button("A", Message::SetLoopIn, "video-loop-in-tooltip");
+    let loop_out_button = loop_point_button("B", Message::SetLoopOut, "video-loop-out-tooltip");
+    let loop_clear_base = button(text("×").size(sizing::ICON_SM))
+        .padding(spacing::XS)
+        .height(Length::Fixed(button_height));
+    let loop_clear_button = tip(
+        if state.loop_region.is_some() {
+            loop_clear_base.on_press(Message::ClearLoopRegion)
+        } else {
+            loop_clear_base.style(styles::button::disabled())
+        },
+        ctx.i18n.tr("video-loop-clear-tooltip"),
+    );
+
+    // Clip export: exports the loop region, or shows progress and cancels
+    // while an export is running
+    let clip_button = match state.clip_export_progress {
+        Some(progress) => tip(
+            button(text(format_clip_progress(ctx.i18n, progress)).size(sizing::ICON_SM))
+                .on_press(Message::CancelClipExport)
+                .padding(spacing::XS)
+                .height(Length::Fixed(button_height)),
+            ctx.i18n.tr("video-export-clip-cancel-tooltip"),
+        ),
+        None => {
+            let base = button(text(ctx.i18n.tr("video-export-clip")).size(sizing::ICON_SM))
+                .padding(spacing::XS)
+                .height(Length::Fixed(button_height));
+            tip(
+                if state.loop_region.is_some() {
+                    base.on_press(Message::ExportClip)
+                } else {
+                    base.style(styles::button::disabled())
+                },
+                ctx.i18n.tr("video-export-clip-tooltip"),
+            )
+        }
+    };
+
+    // Frame export: shows or hides the "Export frames" panel
+    let frames_base = button(text(ctx.i18n.tr("video-export-frames")).size(sizing::ICON_SM))
+        .on_press(Message::ToggleFramesExport)
+        .padding(spacing::XS)
+        .height(Length::Fixed(button_height));
+    let frames_button = tip(
+        if state.frames_export.open {
+            frames_base.style(styles::button::selected)
+        } else {
+            frames_base
+        },
+        ctx.i18n.tr("video-export-frames-tooltip"),
+    );
+
+    // Audio strip mode (off, waveform, VU meter), only for media with audio
+    let audio_base =
+        button(text(ctx.i18n.tr(state.audio_visualization.i18n_key())).size(sizing::ICON_SM))
+            .on_press_maybe(state.has_audio.then_some(Message::CycleAudioVisualization))
+            .padding(spacing::XS);
+    let audio_button = tip(
+        if state.audio_strip.is_some() {
+            audio_base.style(styles::button::selected)
+        } else {
+            audio_base
+        },
+        ctx.i18n.tr("video-audio-visualization-tooltip"),
+    );
+
+    // Color adjustments: shows or hides the sliders, highlighted while the
+    // colors are changed
+    let adjust_base = button(text(ctx.i18n.tr("video-adjust-button")).size(sizing::ICON_SM))
+        .on_press(Message::ToggleColorAdjustments)
+        .padding(spacing::XS)
+        .height(Length::Fixed(button_height));
+    let adjust_button = tip(
+        if state.color_adjustments_open || !state.color_adjustments.is_neutral() {
+            adjust_base.style(styles::button::selected)
+        } else {
+            adjust_base
+        },
+        ctx.i18n.tr("video-adjust-tooltip"),
+    );
+
+    // Mini player: continues the video in a small window above the others
+    let mini_player_button = tip(
+        button(text(ctx.i18n.tr("video-mini-player")).size(sizing::ICON_SM))
+            .on_press(Message::OpenMiniPlayer)
+            .padding(spacing::XS)
+            .height(Length::Fixed(button_height)),
+        ctx.i18n.tr("video-mini-player-tooltip"),
+    );
+
+    // Decode path diagnostics, e.g. "GPU decoding (VA-API)"
+    let decode_label = text(
+        state
+            .decode_path
+            .map(|path| format_decode_path(ctx.i18n, path))
+            .unwrap_or_default(),
+    )
+    .size(typography::BODY_SM);
+
+    // Frame cache diagnostics, e.g. "Frame cache: 40% hits, 12 frames, 96 MB"
+    let cache_label = text(
+        state
+            .cache_stats
+            .map(|stats| format_cache_stats(ctx.i18n, &stats))
+            .unwrap_or_default(),
+    )
+    .size(typography::BODY_SM);
+
+    // Layout: [Decode path / cache] [Space] [A] [B] [×] [Export clip] [Export frames] [Audio strip] [Adjust] [Mini player] | [Speed Down] [1x] [Speed Up] | [Step Back] [Step Fwd] [Capture]
     let menu_content: Row<'a, Message> = row![
+        column![decode_label, cache_label],
         Space::new().width(Length::Fill),
+        loop_in_button,
+        loop_out_button,
+        loop_clear_button,
+        clip_button,
+        frames_button,
+        audio_button,
+        adjust_button,
+        mini_player_button,
         speed_down_button,
         speed_label,
         speed_up_button,
@@ -464,7 +1232,406 @@ fn build_overflow_menu<'a>(
     .padding(spacing::XS)
     .align_y(iced::Alignment::Center);
 
-    container(menu_content).width(Length::Fill).into()
+    let mut menu: Column<'a, Message> = if state.subtitles.tracks.is_empty() {
+        column![menu_content]
+    } else {
+        column![build_subtitle_menu(&ctx, &state.subtitles), menu_content]
+    };
+    if state.frames_export.open {
+        menu = menu.push(build_frames_export_panel(&ctx, state));
+    }
+    if state.color_adjustments_open {
+        menu = menu.push(build_color_adjustments_panel(&ctx, state.color_adjustments));
+    }
+
+    container(menu).width(Length::Fill).into()
+}
+
+/// Formats the decode path shown in the overflow menu.
+fn format_decode_path(i18n: &I18n, path: DecodePath) -> String {
+    match path {
+        DecodePath::Hardware(backend) => {
+            i18n.tr_with_args("video-decode-hardware", &[("backend", backend.name())])
+        }
+        DecodePath::Software => i18n.tr("video-decode-software"),
+    }
+}
+
+/// Formats the frame cache statistics shown in the overflow menu.
+fn format_cache_stats(i18n: &I18n, stats: &CacheStats) -> String {
+    let rate = format!("{:.0}", stats.hit_rate());
+    let frames = stats.frame_count.to_string();
+    let size = (stats.total_bytes / (1024 * 1024)).to_string();
+    i18n.tr_with_args(
+        "video-cache-stats",
+        &[
+            ("rate", rate.as_str()),
+            ("frames", frames.as_str()),
+            ("size", size.as_str()),
+        ],
+    )
+}
+
+/// Builds the subtitle row of the overflow menu: track selector, delay and style.
+fn build_subtitle_menu<'a>(
+    ctx: &ViewContext<'a>,
+    subtitles: &SubtitleControls,
+) -> Element<'a, Message> {
+    let options: Vec<SubtitleTrackOption> = std::iter::once(SubtitleTrackOption {
+        track: None,
+        label: ctx.i18n.tr("video-subtitles-off"),
+    })
+    .chain(
+        subtitles
+            .tracks
+            .iter()
+            .enumerate()
+            .map(|(index, label)| SubtitleTrackOption {
+                track: Some(index),
+                label: label.clone(),
+            }),
+    )
+    .collect();
+    let selected = options
+        .iter()
+        .find(|option| option.track == subtitles.selected)
+        .cloned();
+    let track_picker = tip(
+        pick_list(options, selected, |option| {
+            Message::SelectSubtitleTrack(option.track)
+        })
+        .text_size(sizing::ICON_SM)
+        .padding(spacing::XS),
+        ctx.i18n.tr("video-subtitles-track-tooltip"),
+    );
+
+    let step = config::SUBTITLE_DELAY_STEP_MS;
+    let delay_button = |label: &'static str, delta: i32| {
+        button(text(label).size(sizing::ICON_SM))
+            .on_press(Message::AdjustSubtitleDelay(delta))
+            .padding(spacing::XS)
+    };
+    let delay_label = tip(
+        button(text(format_subtitle_delay(subtitles.delay_ms)).size(sizing::ICON_SM))
+            .on_press(Message::ResetSubtitleDelay)
+            .padding(spacing::XS)
+            .style(button::text),
+        ctx.i18n.tr("video-subtitles-delay-tooltip"),
+    );
+
+    let size_button = tip(
+        button(text(ctx.i18n.tr(subtitles.size.i18n_key())).size(sizing::ICON_SM))
+            .on_press(Message::CycleSubtitleSize)
+            .padding(spacing::XS),
+        ctx.i18n.tr("video-subtitles-size-tooltip"),
+    );
+
+    let background_base =
+        button(text(ctx.i18n.tr("video-subtitles-background")).size(sizing::ICON_SM))
+            .on_press(Message::ToggleSubtitleBackground)
+            .padding(spacing::XS);
+    let background_button: Element<'a, Message> = if subtitles.background {
+        background_base.style(styles::button::selected).into()
+    } else {
+        background_base.into()
+    };
+
+    row![
+        Space::new().width(Length::Fill),
+        track_picker,
+        delay_button("−", -step),
+        delay_label,
+        delay_button("+", step),
+        size_button,
+        background_button,
+    ]
+    .spacing(spacing::XS)
+    .padding(spacing::XS)
+    .align_y(iced::Alignment::Center)
+    .into()
+}
+
+/// Builds the "Export frames" row of the overflow menu: the mode, then range,
+/// output format, frame rate and scale (or the frame count and interval of a
+/// burst), then the export (or cancel) button.
+fn build_frames_export_panel<'a>(
+    ctx: &ViewContext<'a>,
+    state: &PlaybackState,
+) -> Element<'a, Message> {
+    let panel = &state.frames_export;
+    let picker = |options: Vec<Choice<u32>>,
+                  selected: u32,
+                  on_select: fn(u32) -> Message,
+                  tooltip_key: &str| {
+        let selected = options
+            .iter()
+            .find(|option| option.value == selected)
+            .cloned();
+        tip(
+            pick_list(options, selected, move |option| on_select(option.value))
+                .text_size(sizing::ICON_SM)
+                .padding(spacing::XS),
+            ctx.i18n.tr(tooltip_key),
+        )
+    };
+
+    // The A–B loop region wins over the window around the playhead
+    let range: Element<'a, Message> = if state.loop_region.is_some() {
+        text(ctx.i18n.tr("video-frames-range-loop"))
+            .size(sizing::ICON_SM)
+            .into()
+    } else {
+        let windows = FRAMES_WINDOW_CHOICES
+            .into_iter()
+            .map(|seconds| Choice {
+                value: seconds,
+                label: ctx.i18n.tr_with_args(
+                    "video-frames-range-around",
+                    &[("seconds", seconds.to_string().as_str())],
+                ),
+            })
+            .collect();
+        picker(
+            windows,
+            panel.window_secs,
+            Message::SetFramesWindow,
+            "video-frames-range-tooltip",
+        )
+        .into()
+    };
+
+    let formats: Vec<Choice<SequenceFormat>> = SequenceFormat::ALL
+        .into_iter()
+        .map(|format| Choice {
+            value: format,
+            label: ctx.i18n.tr(match format {
+                SequenceFormat::ImageSequence => "video-frames-format-sequence",
+                SequenceFormat::Gif => "video-frames-format-gif",
+                SequenceFormat::WebP => "video-frames-format-webp",
+            }),
+        })
+        .collect();
+    let selected_format = formats
+        .iter()
+        .find(|option| option.value == panel.options.format)
+        .cloned();
+    let format_picker = tip(
+        pick_list(formats, selected_format, |option| {
+            Message::SetFramesFormat(option.value)
+        })
+        .text_size(sizing::ICON_SM)
+        .padding(spacing::XS),
+        ctx.i18n.tr("video-frames-format-tooltip"),
+    );
+
+    let fps_picker = picker(
+        FPS_CHOICES
+            .into_iter()
+            .map(|fps| Choice {
+                value: fps,
+                label: ctx
+                    .i18n
+                    .tr_with_args("video-frames-fps", &[("fps", fps.to_string().as_str())]),
+            })
+            .collect(),
+        panel.options.fps,
+        Message::SetFramesFps,
+        "video-frames-fps-tooltip",
+    );
+    let scale_picker = picker(
+        SCALE_CHOICES
+            .into_iter()
+            .map(|percent| Choice {
+                value: percent,
+                label: format!("{percent}%"),
+            })
+            .collect(),
+        panel.options.scale_percent,
+        Message::SetFramesScale,
+        "video-frames-scale-tooltip",
+    );
+
+    let modes: Vec<Choice<FramesExportMode>> = [FramesExportMode::Range, FramesExportMode::Burst]
+        .into_iter()
+        .map(|mode| Choice {
+            value: mode,
+            label: ctx.i18n.tr(match mode {
+                FramesExportMode::Range => "video-frames-mode-range",
+                FramesExportMode::Burst => "video-frames-mode-burst",
+            }),
+        })
+        .collect();
+    let selected_mode = modes
+        .iter()
+        .find(|option| option.value == panel.mode)
+        .cloned();
+    let mode_picker = tip(
+        pick_list(modes, selected_mode, |option| {
+            Message::SetFramesMode(option.value)
+        })
+        .text_size(sizing::ICON_SM)
+        .padding(spacing::XS),
+        ctx.i18n.tr("video-frames-mode-tooltip"),
+    );
+
+    let export_tooltip_key = match panel.mode {
+        FramesExportMode::Range => "video-frames-export-tooltip",
+        FramesExportMode::Burst => "video-frames-burst-tooltip",
+    };
+    let export_button = match panel.progress {
+        Some(progress) => tip(
+            button(text(format_clip_progress(ctx.i18n, progress)).size(sizing::ICON_SM))
+                .on_press(Message::CancelFramesExport)
+                .padding(spacing::XS),
+            ctx.i18n.tr("video-frames-cancel-tooltip"),
+        ),
+        None => tip(
+            button(text(ctx.i18n.tr("video-frames-export")).size(sizing::ICON_SM))
+                .on_press(Message::ExportFrames)
+                .padding(spacing::XS),
+            ctx.i18n.tr(export_tooltip_key),
+        ),
+    };
+
+    let panel_row = row![Space::new().width(Length::Fill), mode_picker]
+        .spacing(spacing::XS)
+        .padding(spacing::XS)
+        .align_y(iced::Alignment::Center);
+    let panel_row = match panel.mode {
+        FramesExportMode::Range => panel_row
+            .push(range)
+            .push(format_picker)
+            .push(fps_picker)
+            .push(scale_picker),
+        FramesExportMode::Burst => {
+            let count_picker = picker(
+                BURST_COUNT_CHOICES
+                    .into_iter()
+                    .map(|count| Choice {
+                        value: count,
+                        label: ctx.i18n.tr_with_args(
+                            "video-frames-burst-count",
+                            &[("count", count.to_string().as_str())],
+                        ),
+                    })
+                    .collect(),
+                panel.burst.count,
+                Message::SetBurstCount,
+                "video-frames-burst-count-tooltip",
+            );
+            let interval_picker = picker(
+                BURST_INTERVAL_CHOICES_MS
+                    .into_iter()
+                    .map(|interval_ms| Choice {
+                        value: interval_ms,
+                        label: ctx.i18n.tr_with_args(
+                            "video-frames-burst-interval",
+                            &[("ms", interval_ms.to_string().as_str())],
+                        ),
+                    })
+                    .collect(),
+                panel.burst.interval_ms,
+                Message::SetBurstInterval,
+                "video-frames-burst-interval-tooltip",
+            );
+            panel_row.push(count_picker).push(interval_picker)
+        }
+    };
+    panel_row.push(export_button).into()
+}
+
+/// Builds the color adjustment row of the overflow menu: brightness,
+/// contrast, saturation and gamma sliders, then the reset button.
+fn build_color_adjustments_panel<'a>(
+    ctx: &ViewContext<'a>,
+    adjustments: ColorAdjustments,
+) -> Element<'a, Message> {
+    let adjustment = |label_key: &str,
+                      range: std::ops::RangeInclusive<f32>,
+                      value: f32,
+                      value_label: String,
+                      apply: fn(ColorAdjustments, f32) -> ColorAdjustments| {
+        row![
+            text(ctx.i18n.tr(label_key)).size(sizing::ICON_SM),
+            slider(range, value, move |v| {
+                Message::SetColorAdjustments(apply(adjustments, v))
+            })
+            .width(Length::Fixed(100.0))
+            .step(0.01),
+            text(value_label)
+                .size(sizing::ICON_SM)
+                .width(Length::Fixed(40.0)),
+        ]
+        .spacing(spacing::XXS)
+        .align_y(iced::Alignment::Center)
+    };
+
+    let brightness = adjustment(
+        "video-adjust-brightness",
+        ColorAdjustments::BRIGHTNESS_RANGE,
+        adjustments.brightness,
+        format!("{:+.0}", adjustments.brightness * 100.0),
+        |a, brightness| ColorAdjustments { brightness, ..a },
+    );
+    let contrast = adjustment(
+        "video-adjust-contrast",
+        ColorAdjustments::CONTRAST_RANGE,
+        adjustments.contrast,
+        format!("{:.0}%", adjustments.contrast * 100.0),
+        |a, contrast| ColorAdjustments { contrast, ..a },
+    );
+    let saturation = adjustment(
+        "video-adjust-saturation",
+        ColorAdjustments::SATURATION_RANGE,
+        adjustments.saturation,
+        format!("{:.0}%", adjustments.saturation * 100.0),
+        |a, saturation| ColorAdjustments { saturation, ..a },
+    );
+    let gamma = adjustment(
+        "video-adjust-gamma",
+        ColorAdjustments::GAMMA_RANGE,
+        adjustments.gamma,
+        format!("{:.2}", adjustments.gamma),
+        |a, gamma| ColorAdjustments { gamma, ..a },
+    );
+
+    let reset_base =
+        button(text(ctx.i18n.tr("video-adjust-reset")).size(sizing::ICON_SM)).padding(spacing::XS);
+    let reset_button = tip(
+        if adjustments.is_neutral() {
+            reset_base.style(styles::button::disabled())
+        } else {
+            reset_base.on_press(Message::ResetColorAdjustments)
+        },
+        ctx.i18n.tr("video-adjust-reset-tooltip"),
+    );
+
+    row![
+        Space::new().width(Length::Fill),
+        brightness,
+        contrast,
+        saturation,
+        gamma,
+        reset_button,
+    ]
+    .spacing(spacing::SM)
+    .padding(spacing::XS)
+    .align_y(iced::Alignment::Center)
+    .into()
+}
+
+/// Formats the clip export progress, e.g. "Exporting… 42%".
+fn format_clip_progress(i18n: &I18n, progress: f32) -> String {
+    let percent = format!("{:.0}", progress.clamp(0.0, 1.0) * 100.0);
+    i18n.tr_with_args(
+        "video-export-clip-progress",
+        &[("percent", percent.as_str())],
+    )
+}
+
+/// Formats the subtitle delay in seconds with a sign (e.g., "+0.3 s").
+fn format_subtitle_delay(delay_ms: i32) -> String {
+    format!("{:+.1} s", f64::from(delay_ms) / 1000.0)
 }
 
 /// Formats duration in MM:SS or HH:MM:SS format.
@@ -492,7 +1659,7 @@ fn format_playback_speed(speed: f64) -> String {
 /// Formats volume as percentage for display.
 /// Rounds to integer for cleaner UI (e.g., "75%" not "75.00%").
 fn format_volume_percent(volume: f32) -> String {
-    // Volume is 0.0-1.0, so *100 is 0-100 which fits in u32
+    // Volume is 0.0-2.0, so *100 is 0-200 which fits in u32
     #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     let percent = (volume * 100.0).round() as u32;
     format!("{percent}%")
@@ -501,6 +1668,8 @@ fn format_volume_percent(volume: f32) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::video_player::{AudioLevel, Waveform};
+    use std::sync::Arc;
 
     #[test]
     fn format_time_handles_zero() {
@@ -563,6 +1732,119 @@ mod tests {
         let _element = view(ctx, &state);
     }
 
+    #[test]
+    fn view_renders_loop_region() {
+        let i18n = I18n::default();
+        let state = PlaybackState {
+            duration_secs: 120.0,
+            overflow_menu_open: true,
+            loop_region: Some((10.0, 20.0)),
+            ..PlaybackState::default()
+        };
+        let _element = view(ViewContext { i18n: &i18n }, &state);
+        assert!(build_loop_region((10.0, 20.0), 120.0).is_some());
+        assert!(build_loop_region((10.0, 20.0), 0.0).is_none());
+    }
+
+    #[test]
+    fn view_renders_clip_export_progress() {
+        let i18n = I18n::default();
+        let state = PlaybackState {
+            duration_secs: 120.0,
+            overflow_menu_open: true,
+            loop_region: Some((10.0, 20.0)),
+            clip_export_progress: Some(0.42),
+            ..PlaybackState::default()
+        };
+        let _element = view(ViewContext { i18n: &i18n }, &state);
+        assert!(format_clip_progress(&i18n, 0.42).contains("42"));
+    }
+
+    #[test]
+    fn view_renders_frames_export_panel() {
+        let i18n = I18n::default();
+        for (mode, progress) in [
+            (FramesExportMode::Range, None),
+            (FramesExportMode::Range, Some(0.5)),
+            (FramesExportMode::Burst, None),
+        ] {
+            let state = PlaybackState {
+                duration_secs: 120.0,
+                overflow_menu_open: true,
+                frames_export: FramesExportPanel {
+                    open: true,
+                    mode,
+                    progress,
+                    ..FramesExportPanel::default()
+                },
+                ..PlaybackState::default()
+            };
+            let _element = view(ViewContext { i18n: &i18n }, &state);
+        }
+    }
+
+    #[test]
+    fn view_renders_color_adjustments_panel() {
+        let i18n = I18n::default();
+        let state = PlaybackState {
+            duration_secs: 120.0,
+            overflow_menu_open: true,
+            color_adjustments_open: true,
+            color_adjustments: ColorAdjustments {
+                gamma: 1.5,
+                ..ColorAdjustments::default()
+            },
+            ..PlaybackState::default()
+        };
+        let _element = view(ViewContext { i18n: &i18n }, &state);
+    }
+
+    #[test]
+    fn view_renders_audio_strips() {
+        let i18n = I18n::default();
+        let waveform = Arc::new(Waveform::from_samples([0.5; 100], 1000));
+        for strip in [
+            AudioStrip::Waveform(None),
+            AudioStrip::Waveform(Some(waveform)),
+            AudioStrip::VuMeter(AudioLevel {
+                peak: 0.9,
+                rms: 0.4,
+            }),
+        ] {
+            let state = PlaybackState {
+                duration_secs: 120.0,
+                overflow_menu_open: true,
+                audio_visualization: config::AudioVisualization::Waveform,
+                audio_strip: Some(strip),
+                ..PlaybackState::default()
+            };
+            let _element = view(ViewContext { i18n: &i18n }, &state);
+        }
+    }
+
+    #[test]
+    fn view_renders_resume_offer() {
+        let i18n = I18n::default();
+        let state = PlaybackState {
+            duration_secs: 3600.0,
+            resume_offer: Some(754.0),
+            ..PlaybackState::default()
+        };
+        let _element = view(ViewContext { i18n: &i18n }, &state);
+    }
+
+    #[test]
+    fn view_renders_frame_counter() {
+        let i18n = I18n::default();
+        let state = PlaybackState {
+            duration_secs: 2.0,
+            frame_counter: Some((12, 24)),
+            has_audio: false,
+            ..PlaybackState::default()
+        };
+        let _element = view(ViewContext { i18n: &i18n }, &state);
+    }
+
     #[test]
     fn timeline_position_uses_seconds() {
         let state = PlaybackState {
@@ -573,12 +1855,26 @@ mod tests {
             muted: false,
             loop_enabled: false,
             seek_preview_position: None,
+            fine_step: None,
             overflow_menu_open: false,
             can_step_backward: false,
             can_step_forward: false,
             playback_speed: 1.0,
             speed_auto_muted: false,
             has_audio: true,
+            subtitles: SubtitleControls::default(),
+            chapters: Vec::new(),
+            chapter_list_open: false,
+            loop_region: None,
+            clip_export_progress: None,
+            frames_export: FramesExportPanel::default(),
+            decode_path: None,
+            cache_stats: None,
+            seek_hover: None,
+            audio_visualization: config::AudioVisualization::Off,
+            audio_strip: None,
+            frame_counter: None,
+            resume_offer: None,
         };
 
         // Position is in seconds
@@ -598,12 +1894,26 @@ mod tests {
             muted: false,
             loop_enabled: false,
             seek_preview_position: None,
+            fine_step: None,
             overflow_menu_open: false,
             can_step_backward: false,
             can_step_forward: false,
             playback_speed: 1.0,
             speed_auto_muted: false,
             has_audio: true,
+            subtitles: SubtitleControls::default(),
+            chapters: Vec::new(),
+            chapter_list_open: false,
+            loop_region: None,
+            clip_export_progress: None,
+            frames_export: FramesExportPanel::default(),
+            decode_path: None,
+            cache_stats: None,
+            seek_hover: None,
+            audio_visualization: config::AudioVisualization::Off,
+            audio_strip: None,
+            frame_counter: None,
+            resume_offer: None,
         };
 
         // When duration is zero, position is still valid
@@ -631,6 +1941,19 @@ mod tests {
             playback_speed: 1.0,
             speed_auto_muted: false,
             has_audio: true,
+            subtitles: SubtitleControls::default(),
+            chapters: Vec::new(),
+            chapter_list_open: false,
+            loop_region: None,
+            clip_export_progress: None,
+            frames_export: FramesExportPanel::default(),
+            decode_path: None,
+            cache_stats: None,
+            seek_hover: None,
+            audio_visualization: config::AudioVisualization::Off,
+            audio_strip: None,
+            frame_counter: None,
+            resume_offer: None,
         };
 
         // When seek_preview_position is set, it should be used instead of playback position
@@ -667,6 +1990,94 @@ mod tests {
         assert!(!state.speed_auto_muted);
     }
 
+    #[test]
+    fn format_subtitle_delay_shows_sign() {
+        assert_eq!(format_subtitle_delay(0), "+0.0 s");
+        assert_eq!(format_subtitle_delay(300), "+0.3 s");
+        assert_eq!(format_subtitle_delay(-1500), "-1.5 s");
+    }
+
+    #[test]
+    fn view_renders_subtitle_menu() {
+        let i18n = I18n::default();
+        let ctx = ViewContext { i18n: &i18n };
+        let state = PlaybackState {
+            overflow_menu_open: true,
+            subtitles: SubtitleControls {
+                tracks: vec!["movie.en.srt".to_string()],
+                selected: Some(0),
+                ..SubtitleControls::default()
+            },
+            ..PlaybackState::default()
+        };
+        let _element = view(ctx, &state);
+    }
+
+    #[test]
+    fn view_renders_chapter_markers_and_list() {
+        let i18n = I18n::default();
+        let ctx = ViewContext { i18n: &i18n };
+        let state = PlaybackState {
+            duration_secs: 120.0,
+            chapters: vec![
+                Chapter {
+                    start_secs: 0.0,
+                    end_secs: 60.0,
+                    title: Some("Intro".to_string()),
+                },
+                Chapter {
+                    start_secs: 60.0,
+                    end_secs: 120.0,
+                    title: None,
+                },
+            ],
+            chapter_list_open: true,
+            ..PlaybackState::default()
+        };
+        assert!(build_chapter_markers(&state.chapters, state.duration_secs).is_some());
+        let _element = view(ctx, &state);
+    }
+
+    #[test]
+    fn decode_path_names_the_backend() {
+        let i18n = I18n::default();
+        let hardware = format_decode_path(
+            &i18n,
+            DecodePath::Hardware(crate::video_player::HwBackend::Vaapi),
+        );
+        assert!(hardware.contains("VA-API"));
+        assert_ne!(format_decode_path(&i18n, DecodePath::Software), hardware);
+    }
+
+    #[test]
+    fn cache_stats_show_hit_rate() {
+        let i18n = I18n::default();
+        let stats = CacheStats {
+            frame_count: 12,
+            total_bytes: 96 * 1024 * 1024,
+            hits: 3,
+            misses: 1,
+            ..CacheStats::default()
+        };
+        let label = format_cache_stats(&i18n, &stats);
+        assert!(label.contains("75"));
+        assert!(label.contains("96"));
+    }
+
+    #[test]
+    fn hover_position_maps_cursor_to_time() {
+        assert!((hover_position(50.0, 200.0, 120.0) - 30.0).abs() < f64::EPSILON);
+        assert!((hover_position(-10.0, 200.0, 120.0)).abs() < f64::EPSILON);
+        assert!((hover_position(500.0, 200.0, 120.0) - 120.0).abs() < f64::EPSILON);
+        assert!((hover_position(10.0, 0.0, 120.0)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn marker_portion_is_never_zero() {
+        assert_eq!(marker_portion(0.0), 1);
+        assert_eq!(marker_portion(0.5), CHAPTER_MARKER_RESOLUTION / 2);
+    }
+
     #[test]
     fn format_volume_percent_rounds_to_integer() {
         // Standard values
@@ -674,6 +2085,7 @@ mod tests {
         assert_eq!(format_volume_percent(0.5), "50%");
         assert_eq!(format_volume_percent(1.0), "100%");
         assert_eq!(format_volume_percent(1.5), "150%");
+        assert_eq!(format_volume_percent(2.0), "200%");
 
         // Fractional values round correctly
         assert_eq!(format_volume_percent(0.754), "75%");
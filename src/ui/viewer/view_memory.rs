@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Per-image view memory.
+//!
+//! With the option on, the zoom, scroll position and rotation of an image
+//! are remembered when navigating away and restored when coming back to it.
+//! The memory only lasts for the session and keeps the most recently left
+//! images.
+
+use crate::ui::state::RotationAngle;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+/// Number of images whose view is remembered; the least recently left ones
+/// are forgotten first.
+const MAX_ENTRIES: usize = 100;
+
+/// How an image was shown when it was left.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewTransform {
+    /// Whether the image was fitted to the window (the zoom then follows
+    /// the window size).
+    pub fit_to_window: bool,
+    pub zoom_percent: f32,
+    /// Scroll position, from 0.0 (left/top) to 1.0 (right/bottom).
+    pub scroll_x: f32,
+    pub scroll_y: f32,
+    pub rotation: RotationAngle,
+}
+
+/// Views of recently left images, most recent first.
+#[derive(Debug, Clone, Default)]
+pub struct ViewMemory {
+    entries: VecDeque<(PathBuf, ViewTransform)>,
+}
+
+impl ViewMemory {
+    /// Remembers how the image at `path` was shown.
+    pub fn remember(&mut self, path: &Path, transform: ViewTransform) {
+        self.forget(path);
+        self.entries.push_front((path.to_path_buf(), transform));
+        self.entries.truncate(MAX_ENTRIES);
+    }
+
+    /// Returns how the image at `path` was last shown, if remembered.
+    #[must_use]
+    pub fn get(&self, path: &Path) -> Option<ViewTransform> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| entry == path)
+            .map(|(_, transform)| *transform)
+    }
+
+    /// Forgets the image at `path`.
+    pub fn forget(&mut self, path: &Path) {
+        self.entries.retain(|(entry, _)| entry != path);
+    }
+
+    /// Forgets every image.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zoomed(zoom_percent: f32) -> ViewTransform {
+        ViewTransform {
+            fit_to_window: false,
+            zoom_percent,
+            scroll_x: 0.25,
+            scroll_y: 0.75,
+            rotation: RotationAngle::new(90),
+        }
+    }
+
+    #[test]
+    fn restores_the_last_view_of_an_image() {
+        let mut memory = ViewMemory::default();
+        let path = Path::new("/photos/a.jpg");
+        memory.remember(path, zoomed(200.0));
+        memory.remember(path, zoomed(400.0));
+        assert_eq!(memory.get(path), Some(zoomed(400.0)));
+        assert_eq!(memory.get(Path::new("/photos/b.jpg")), None);
+
+        memory.forget(path);
+        assert_eq!(memory.get(path), None);
+    }
+
+    #[test]
+    fn forgets_least_recently_left_images_first() {
+        let mut memory = ViewMemory::default();
+        for i in 0..=MAX_ENTRIES {
+            memory.remember(&PathBuf::from(format!("/photos/{i}.jpg")), zoomed(150.0));
+        }
+        assert_eq!(memory.entries.len(), MAX_ENTRIES);
+        assert_eq!(memory.get(Path::new("/photos/0.jpg")), None);
+        assert!(memory.get(Path::new("/photos/1.jpg")).is_some());
+    }
+}
@@ -0,0 +1,474 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Welcome screen shown on the first launch.
+//!
+//! It walks through three steps, each of which can be skipped: language and
+//! theme, making IcedLens the default image viewer (where the platform has a
+//! helper, see [`crate::app::file_associations`]), and a short tour of the
+//! main shortcuts in which the user tries each key.
+
+use crate::app::file_associations::{self, Registration};
+use crate::i18n::fluent::I18n;
+use crate::ui::design_tokens::{radius, spacing, typography};
+use crate::ui::keymap::{Action, Binding, BindingKey, Keymap};
+use crate::ui::settings::{self, build_toggle_button_row};
+use crate::ui::styles::button as button_styles;
+use crate::ui::theme;
+use crate::ui::theming::ThemeMode;
+use iced::keyboard::key::Named;
+use iced::{
+    alignment::{Horizontal, Vertical},
+    widget::{button, container, pick_list, text, Column, Row, Space, Text},
+    Border, Element, Length, Theme,
+};
+use unic_langid::LanguageIdentifier;
+
+/// Maximum width of the welcome content, for readable lines.
+const CONTENT_WIDTH: f32 = 560.0;
+
+/// Key tried at a step of the tour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TourKey {
+    /// The left and right arrows, which can't be rebound.
+    Arrows,
+    Action(Action),
+}
+
+impl TourKey {
+    fn label(self, keymap: &Keymap) -> String {
+        match self {
+            TourKey::Arrows => "← / →".to_string(),
+            TourKey::Action(action) => keymap.label(action),
+        }
+    }
+
+    fn matches(self, binding: Binding, keymap: &Keymap) -> bool {
+        match self {
+            TourKey::Arrows => [Named::ArrowLeft, Named::ArrowRight]
+                .into_iter()
+                .any(|named| binding == Binding::new(BindingKey::Named(named))),
+            TourKey::Action(action) => binding == keymap.binding(action),
+        }
+    }
+}
+
+/// A step of the shortcut tour: the key to try and the i18n key prefix of
+/// its `-title` and `-body` texts.
+struct TourStep {
+    key: TourKey,
+    text_key: &'static str,
+}
+
+const TOUR: [TourStep; 5] = [
+    TourStep {
+        key: TourKey::Arrows,
+        text_key: "welcome-tour-navigate",
+    },
+    TourStep {
+        key: TourKey::Action(Action::ToggleFullscreen),
+        text_key: "welcome-tour-fullscreen",
+    },
+    TourStep {
+        key: TourKey::Action(Action::ToggleInfoPanel),
+        text_key: "welcome-tour-info",
+    },
+    TourStep {
+        key: TourKey::Action(Action::EnterEditor),
+        text_key: "welcome-tour-editor",
+    },
+    TourStep {
+        key: TourKey::Action(Action::PlayPause),
+        text_key: "welcome-tour-video",
+    },
+];
+
+/// Pages of the welcome screen, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Page {
+    #[default]
+    Preferences,
+    DefaultViewer,
+    /// A step of the tour, by index.
+    Tour(usize),
+}
+
+impl Page {
+    fn next(self) -> Option<Self> {
+        match self {
+            Page::Preferences if file_associations::is_supported() => Some(Page::DefaultViewer),
+            Page::Preferences | Page::DefaultViewer => Some(Page::Tour(0)),
+            Page::Tour(step) if step + 1 < TOUR.len() => Some(Page::Tour(step + 1)),
+            Page::Tour(_) => None,
+        }
+    }
+
+    fn previous(self) -> Option<Self> {
+        match self {
+            Page::Preferences => None,
+            Page::DefaultViewer => Some(Page::Preferences),
+            Page::Tour(0) if file_associations::is_supported() => Some(Page::DefaultViewer),
+            Page::Tour(0) => Some(Page::Preferences),
+            Page::Tour(step) => Some(Page::Tour(step - 1)),
+        }
+    }
+}
+
+/// Progress of the "set as default image viewer" helper.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Association {
+    #[default]
+    NotRequested,
+    Registering,
+    Registered(Registration),
+    Failed(String),
+}
+
+/// Welcome screen state.
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    page: Page,
+    association: Association,
+    /// Whether the key of the current tour step was pressed.
+    tried: bool,
+}
+
+impl State {
+    /// Returns the page shown.
+    #[must_use]
+    pub fn page(&self) -> Page {
+        self.page
+    }
+
+    /// Records the outcome of the default viewer registration.
+    pub fn set_association_result(&mut self, result: Result<Registration, String>) {
+        self.association = match result {
+            Ok(registration) => Association::Registered(registration),
+            Err(error) => Association::Failed(error),
+        };
+    }
+
+    /// Handles a welcome screen message.
+    pub fn update(&mut self, message: Message, keymap: &Keymap) -> Event {
+        match message {
+            Message::LanguageSelected(locale) => Event::LanguageSelected(locale),
+            Message::ThemeModeSelected(mode) => Event::ThemeModeSelected(mode),
+            Message::SetDefaultViewer => {
+                if self.association == Association::Registering {
+                    return Event::None;
+                }
+                self.association = Association::Registering;
+                Event::SetDefaultViewer
+            }
+            Message::KeyPressed(binding) => {
+                if let Page::Tour(step) = self.page {
+                    self.tried |= TOUR[step].key.matches(binding, keymap);
+                }
+                Event::None
+            }
+            Message::Next => match self.page.next() {
+                Some(page) => {
+                    self.page = page;
+                    self.tried = false;
+                    Event::None
+                }
+                None => Event::Finished,
+            },
+            Message::Back => {
+                if let Some(page) = self.page.previous() {
+                    self.page = page;
+                    self.tried = false;
+                }
+                Event::None
+            }
+            Message::Skip => Event::Finished,
+        }
+    }
+}
+
+/// Contextual data needed to render the welcome screen.
+pub struct ViewContext<'a> {
+    pub i18n: &'a I18n,
+    pub state: &'a State,
+    pub theme_mode: ThemeMode,
+    pub keymap: &'a Keymap,
+}
+
+/// Messages emitted by the welcome screen.
+#[derive(Debug, Clone)]
+pub enum Message {
+    LanguageSelected(LanguageIdentifier),
+    ThemeModeSelected(ThemeMode),
+    SetDefaultViewer,
+    /// Key pressed while the screen is shown.
+    KeyPressed(Binding),
+    Next,
+    Back,
+    Skip,
+}
+
+/// Events propagated to the parent application.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    None,
+    LanguageSelected(LanguageIdentifier),
+    ThemeModeSelected(ThemeMode),
+    SetDefaultViewer,
+    /// The tour was completed or skipped.
+    Finished,
+}
+
+/// Render the welcome screen.
+#[must_use]
+#[allow(clippy::needless_pass_by_value)] // ViewContext is small and consumed
+pub fn view(ctx: ViewContext<'_>) -> Element<'_, Message> {
+    let title = Text::new(ctx.i18n.tr("welcome-title")).size(typography::TITLE_LG);
+
+    let page = match ctx.state.page {
+        Page::Preferences => view_preferences(&ctx),
+        Page::DefaultViewer => view_default_viewer(&ctx),
+        Page::Tour(step) => view_tour_step(&ctx, step),
+    };
+
+    let is_last = ctx.state.page.next().is_none();
+    let next_label = if is_last {
+        ctx.i18n.tr("welcome-finish")
+    } else {
+        ctx.i18n.tr("welcome-next")
+    };
+    let footer = Row::new()
+        .spacing(spacing::SM)
+        .align_y(Vertical::Center)
+        .push(
+            button(text(ctx.i18n.tr("welcome-skip")))
+                .on_press_maybe((!is_last).then_some(Message::Skip)),
+        )
+        .push(Space::new().width(Length::Fill))
+        .push(
+            button(text(ctx.i18n.tr("welcome-back")))
+                .on_press_maybe(ctx.state.page.previous().map(|_| Message::Back)),
+        )
+        .push(
+            button(text(next_label))
+                .style(button_styles::primary)
+                .on_press(Message::Next),
+        );
+
+    let content = Column::new()
+        .width(Length::Fixed(CONTENT_WIDTH))
+        .spacing(spacing::LG)
+        .push(title)
+        .push(page)
+        .push(footer);
+
+    container(content)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(spacing::XL)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+        .into()
+}
+
+/// Language and theme choice.
+fn view_preferences<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
+    let options = settings::language_options(ctx.i18n);
+    let selected = options
+        .iter()
+        .find(|option| &option.locale == ctx.i18n.current_locale())
+        .cloned();
+    let language_picker = pick_list(options, selected, |option| {
+        Message::LanguageSelected(option.locale)
+    })
+    .padding(spacing::XS)
+    .text_size(typography::BODY);
+
+    let theme_row = build_toggle_button_row(
+        &[
+            (ThemeMode::System, "settings-theme-system"),
+            (ThemeMode::Light, "settings-theme-light"),
+            (ThemeMode::Dark, "settings-theme-dark"),
+        ],
+        ctx.theme_mode,
+        Message::ThemeModeSelected,
+        ctx.i18n,
+    );
+
+    Column::new()
+        .spacing(spacing::MD)
+        .push(Text::new(ctx.i18n.tr("welcome-preferences-intro")).size(typography::BODY))
+        .push(Text::new(ctx.i18n.tr("select-language-label")).size(typography::BODY_LG))
+        .push(language_picker)
+        .push(Text::new(ctx.i18n.tr("settings-theme-mode-label")).size(typography::BODY_LG))
+        .push(theme_row)
+        .into()
+}
+
+/// "Set as default image viewer" helper.
+fn view_default_viewer<'a>(ctx: &ViewContext<'a>) -> Element<'a, Message> {
+    let registering = ctx.state.association == Association::Registering;
+    let set_button = button(text(ctx.i18n.tr("welcome-default-viewer-button")))
+        .on_press_maybe((!registering).then_some(Message::SetDefaultViewer));
+
+    let status = match &ctx.state.association {
+        Association::NotRequested => None,
+        Association::Registering => Some(ctx.i18n.tr("welcome-default-viewer-working")),
+        Association::Registered(Registration::Done) => {
+            Some(ctx.i18n.tr("welcome-default-viewer-done"))
+        }
+        Association::Registered(Registration::ConfirmInSettings) => {
+            Some(ctx.i18n.tr("welcome-default-viewer-confirm"))
+        }
+        Association::Failed(error) => Some(ctx.i18n.tr_with_args(
+            "welcome-default-viewer-failed",
+            &[("error", error.as_str())],
+        )),
+    };
+
+    let mut content = Column::new()
+        .spacing(spacing::MD)
+        .push(Text::new(ctx.i18n.tr("welcome-default-viewer-title")).size(typography::TITLE_MD))
+        .push(Text::new(ctx.i18n.tr("welcome-default-viewer-intro")).size(typography::BODY))
+        .push(set_button);
+    if let Some(status) = status {
+        content = content.push(Text::new(status).size(typography::BODY_SM));
+    }
+    content.into()
+}
+
+/// A step of the shortcut tour.
+fn view_tour_step<'a>(ctx: &ViewContext<'a>, step: usize) -> Element<'a, Message> {
+    let TourStep { key, text_key } = &TOUR[step];
+    let key_label = key.label(ctx.keymap);
+
+    let key_cap = container(Text::new(key_label.clone()).size(typography::TITLE_MD))
+        .padding([spacing::XS, spacing::MD])
+        .style(|theme: &Theme| container::Style {
+            background: Some(theme.extended_palette().background.weak.color.into()),
+            border: Border {
+                radius: radius::MD.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+    let progress = ctx.i18n.tr_with_args(
+        "welcome-tour-progress",
+        &[
+            ("current", (step + 1).to_string().as_str()),
+            ("total", TOUR.len().to_string().as_str()),
+        ],
+    );
+    let prompt: Element<'a, Message> = if ctx.state.tried {
+        Text::new(ctx.i18n.tr("welcome-tour-tried"))
+            .size(typography::BODY)
+            .style(|_theme: &Theme| text::Style {
+                color: Some(theme::success_text_color()),
+            })
+            .into()
+    } else {
+        Text::new(
+            ctx.i18n
+                .tr_with_args("welcome-tour-try", &[("key", key_label.as_str())]),
+        )
+        .size(typography::BODY)
+        .into()
+    };
+
+    Column::new()
+        .spacing(spacing::MD)
+        .push(Text::new(progress).size(typography::BODY_SM))
+        .push(
+            Row::new()
+                .spacing(spacing::MD)
+                .align_y(Vertical::Center)
+                .push(key_cap)
+                .push(
+                    Text::new(ctx.i18n.tr(&format!("{text_key}-title"))).size(typography::TITLE_MD),
+                ),
+        )
+        .push(Text::new(ctx.i18n.tr(&format!("{text_key}-body"))).size(typography::BODY))
+        .push(prompt)
+        .push(Text::new(ctx.i18n.tr("welcome-tour-footer")).size(typography::BODY_SM))
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_walks_through_every_page_then_finishes() {
+        let keymap = Keymap::default();
+        let mut state = State::default();
+        let mut pages = vec![state.page()];
+        while state.update(Message::Next, &keymap) != Event::Finished {
+            pages.push(state.page());
+        }
+
+        let helper_pages = usize::from(file_associations::is_supported());
+        assert_eq!(pages.len(), 1 + helper_pages + TOUR.len());
+        assert_eq!(pages.last(), Some(&Page::Tour(TOUR.len() - 1)));
+
+        assert_eq!(state.update(Message::Back, &keymap), Event::None);
+        assert_eq!(state.page(), Page::Tour(TOUR.len() - 2));
+    }
+
+    #[test]
+    fn pressing_the_shown_key_completes_the_step() {
+        let keymap = Keymap::default();
+        let mut state = State {
+            page: Page::Tour(0),
+            ..State::default()
+        };
+
+        state.update(
+            Message::KeyPressed(keymap.binding(Action::EnterEditor)),
+            &keymap,
+        );
+        assert!(!state.tried);
+
+        state.update(
+            Message::KeyPressed(Binding::new(BindingKey::Named(Named::ArrowRight))),
+            &keymap,
+        );
+        assert!(state.tried);
+
+        state.update(Message::Next, &keymap);
+        assert!(!state.tried);
+    }
+
+    #[test]
+    fn registration_is_requested_once_at_a_time() {
+        let keymap = Keymap::default();
+        let mut state = State::default();
+
+        assert_eq!(
+            state.update(Message::SetDefaultViewer, &keymap),
+            Event::SetDefaultViewer
+        );
+        assert_eq!(
+            state.update(Message::SetDefaultViewer, &keymap),
+            Event::None
+        );
+
+        state.set_association_result(Err("xdg-mime not found".to_string()));
+        assert!(matches!(state.association, Association::Failed(_)));
+    }
+
+    #[test]
+    fn welcome_view_renders_every_page() {
+        let i18n = I18n::default();
+        let keymap = Keymap::default();
+        for page in [Page::Preferences, Page::DefaultViewer, Page::Tour(0)] {
+            let state = State {
+                page,
+                ..State::default()
+            };
+            let _element = view(ViewContext {
+                i18n: &i18n,
+                state: &state,
+                theme_mode: ThemeMode::System,
+                keymap: &keymap,
+            });
+        }
+    }
+}
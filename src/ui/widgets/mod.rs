@@ -16,4 +16,4 @@ pub mod video_shader;
 pub mod wheel_blocking_scrollable;
 
 pub use animated_spinner::AnimatedSpinner;
-pub use video_shader::VideoShader;
+pub use video_shader::{ColorAdjustments, FrameTransform, ToneMapParams, VideoShader};
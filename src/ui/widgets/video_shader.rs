@@ -11,8 +11,15 @@
 //! `queue.write_texture()`. This avoids the texture churn that would occur
 //! if creating a new `image::Handle` for each frame (since `Handle::from_rgba()`
 //! generates a unique ID per call, causing GPU texture recreation).
+//!
+//! HDR frames arrive with their PQ or HLG signal untouched; the fragment
+//! shader decodes it to linear light, converts BT.2020 colors to the display
+//! primaries and tone maps the highlights (see [`ToneMapParams`]).
 
+use crate::config::ToneMapping;
 use crate::media::frame_export::ExportableFrame;
+use crate::media::hdr::{HdrInfo, HdrTransfer};
+use crate::ui::state::rotation::RotationAngle;
 use iced::widget::shader::{self, Viewport};
 use iced::{mouse, Element, Length, Rectangle};
 use std::sync::Arc;
@@ -29,6 +36,128 @@ pub struct FrameData {
     pub height: u32,
 }
 
+/// Orientation of the frame on screen, applied when sampling the texture.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrameTransform {
+    /// Clockwise rotation of the frame.
+    pub rotation: RotationAngle,
+    /// Whether the rotated frame is flipped horizontally.
+    pub mirrored: bool,
+}
+
+impl FrameTransform {
+    /// Packs the transform into the shader's uniform layout.
+    fn uniform_bytes(self) -> [u8; 16] {
+        let words = [
+            u32::from(self.rotation.degrees() / 90),
+            u32::from(self.mirrored),
+            0,
+            0,
+        ];
+        let mut bytes = [0; 16];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(words) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+}
+
+/// Color adjustments applied to the frame while it is drawn, to view poorly
+/// exposed footage. The frame itself is left untouched.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorAdjustments {
+    /// Offset added to each channel, from -1.0 to 1.0.
+    pub brightness: f32,
+    /// Contrast factor around mid-gray (1.0 = unchanged).
+    pub contrast: f32,
+    /// Saturation factor (0.0 = grayscale, 1.0 = unchanged).
+    pub saturation: f32,
+    /// Gamma (above 1.0 brightens the shadows, 1.0 = unchanged).
+    pub gamma: f32,
+}
+
+impl ColorAdjustments {
+    /// Range of the brightness offset.
+    pub const BRIGHTNESS_RANGE: std::ops::RangeInclusive<f32> = -1.0..=1.0;
+    /// Range of the contrast factor.
+    pub const CONTRAST_RANGE: std::ops::RangeInclusive<f32> = 0.0..=3.0;
+    /// Range of the saturation factor.
+    pub const SATURATION_RANGE: std::ops::RangeInclusive<f32> = 0.0..=3.0;
+    /// Range of the gamma.
+    pub const GAMMA_RANGE: std::ops::RangeInclusive<f32> = 0.2..=5.0;
+
+    /// Returns true if the adjustments leave the frame unchanged.
+    #[must_use]
+    pub fn is_neutral(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Packs the adjustments into the shader's uniform layout.
+    fn uniform_bytes(self) -> [u8; 16] {
+        let words = [self.brightness, self.contrast, self.saturation, self.gamma];
+        let mut bytes = [0; 16];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(words) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+}
+
+impl Default for ColorAdjustments {
+    fn default() -> Self {
+        Self {
+            brightness: 0.0,
+            contrast: 1.0,
+            saturation: 1.0,
+            gamma: 1.0,
+        }
+    }
+}
+
+/// Brightness of SDR white in HDR signals, in nits (ITU-R BT.2408).
+const SDR_WHITE_NITS: f64 = 203.0;
+
+/// HDR decoding and tone mapping applied while the frame is drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ToneMapParams {
+    /// HDR properties of the video; `None` draws the frame as is.
+    pub hdr: Option<HdrInfo>,
+    /// Curve bringing the highlights into the display's range.
+    pub operator: ToneMapping,
+}
+
+impl ToneMapParams {
+    /// Packs the parameters into the shader's uniform layout.
+    fn uniform_bytes(self) -> [u8; 16] {
+        let (transfer, bt2020, peak_nits) = match self.hdr {
+            Some(hdr) => (
+                match hdr.transfer {
+                    HdrTransfer::Pq => 1u32,
+                    HdrTransfer::Hlg => 2,
+                },
+                u32::from(hdr.bt2020),
+                hdr.peak_nits(),
+            ),
+            None => (0, 0, SDR_WHITE_NITS),
+        };
+        // Peaks below SDR white would brighten the picture
+        #[allow(clippy::cast_possible_truncation)] // A ratio of a few units
+        let peak = (peak_nits / SDR_WHITE_NITS).max(1.0) as f32;
+        let operator: u32 = match self.operator {
+            ToneMapping::Clip => 0,
+            ToneMapping::Reinhard => 1,
+            ToneMapping::Hable => 2,
+            ToneMapping::Aces => 3,
+        };
+        let mut bytes = [0; 16];
+        bytes[0..4].copy_from_slice(&transfer.to_le_bytes());
+        bytes[4..8].copy_from_slice(&operator.to_le_bytes());
+        bytes[8..12].copy_from_slice(&bt2020.to_le_bytes());
+        bytes[12..16].copy_from_slice(&peak.to_le_bytes());
+        bytes
+    }
+}
+
 /// A GPU-accelerated video frame renderer using custom wgpu shaders.
 ///
 /// This widget maintains a persistent GPU texture that is updated in-place
@@ -119,15 +248,26 @@ impl<Message> VideoShader<Message> {
     ///
     /// The caller (pane) is responsible for calculating the correct display dimensions
     /// based on zoom level and fit-to-window settings. This ensures a single source
-    /// of truth for display sizing.
+    /// of truth for display sizing. The display size is that of the transformed
+    /// frame, so width and height are swapped for quarter-turn rotations.
     #[must_use]
-    pub fn view_sized(&self, display_width: f32, display_height: f32) -> Element<'_, Message>
+    pub fn view_sized(
+        &self,
+        display_width: f32,
+        display_height: f32,
+        transform: FrameTransform,
+        adjustments: ColorAdjustments,
+        tone_map: ToneMapParams,
+    ) -> Element<'_, Message>
     where
         Message: 'static,
     {
         if let Some(ref frame) = self.frame {
             let program = VideoFrameProgram {
                 frame: frame.clone(),
+                transform,
+                adjustments,
+                tone_map,
             };
 
             shader::Shader::new(program)
@@ -147,6 +287,9 @@ impl<Message> VideoShader<Message> {
 #[derive(Debug, Clone)]
 struct VideoFrameProgram {
     frame: FrameData,
+    transform: FrameTransform,
+    adjustments: ColorAdjustments,
+    tone_map: ToneMapParams,
 }
 
 impl<Message> shader::Program<Message> for VideoFrameProgram {
@@ -161,6 +304,9 @@ impl<Message> shader::Program<Message> for VideoFrameProgram {
     ) -> Self::Primitive {
         VideoFramePrimitive {
             frame: self.frame.clone(),
+            transform: self.transform,
+            adjustments: self.adjustments,
+            tone_map: self.tone_map,
         }
     }
 }
@@ -169,6 +315,9 @@ impl<Message> shader::Program<Message> for VideoFrameProgram {
 #[derive(Debug, Clone)]
 pub struct VideoFramePrimitive {
     frame: FrameData,
+    transform: FrameTransform,
+    adjustments: ColorAdjustments,
+    tone_map: ToneMapParams,
 }
 
 impl shader::Primitive for VideoFramePrimitive {
@@ -190,6 +339,21 @@ impl shader::Primitive for VideoFramePrimitive {
 
         // Update texture with new frame data
         pipeline.update_frame(device, queue, &self.frame);
+        queue.write_buffer(
+            &pipeline.transform_buffer,
+            0,
+            &self.transform.uniform_bytes(),
+        );
+        queue.write_buffer(
+            &pipeline.transform_buffer,
+            16,
+            &self.adjustments.uniform_bytes(),
+        );
+        queue.write_buffer(
+            &pipeline.transform_buffer,
+            32,
+            &self.tone_map.uniform_bytes(),
+        );
     }
 
     fn render(
@@ -203,15 +367,20 @@ impl shader::Primitive for VideoFramePrimitive {
     }
 }
 
-// Note: No uniform buffer needed - we use viewport-based positioning instead.
+// Note: Positioning needs no uniform - we use viewport-based positioning instead.
 // The viewport transformation handles quad positioning automatically when
-// we render a fullscreen quad (-1 to 1 in NDC).
+// we render a fullscreen quad (-1 to 1 in NDC). The only uniform holds the
+// frame transform, which remaps texture coordinates, the color adjustments
+// and the HDR tone mapping.
 
 /// The wgpu pipeline for rendering video frames.
 pub struct VideoPipeline {
     pipeline: wgpu::RenderPipeline,
     texture_bind_group_layout: wgpu::BindGroupLayout,
     sampler: wgpu::Sampler,
+    // Rotation, mirroring, color adjustments and tone mapping, rewritten
+    // before every render
+    transform_buffer: wgpu::Buffer,
     // Current texture and bind group (recreated when frame size changes)
     texture: Option<wgpu::Texture>,
     texture_bind_group: Option<wgpu::BindGroup>,
@@ -264,10 +433,28 @@ impl shader::Pipeline for VideoPipeline {
                         ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
                         count: None,
                     },
+                    // Frame transform, color adjustments and tone mapping
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
                 ],
             });
 
-        // Create pipeline layout - only texture bind group, no uniforms needed
+        let transform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Video Transform Buffer"),
+            size: 48,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // Create pipeline layout - a single bind group for the texture and transform
         // We use viewport-based positioning which handles quad placement automatically
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Video Pipeline Layout"),
@@ -314,6 +501,7 @@ impl shader::Pipeline for VideoPipeline {
             pipeline,
             texture_bind_group_layout,
             sampler,
+            transform_buffer,
             texture: None,
             texture_bind_group: None,
             current_size: (0, 0),
@@ -401,6 +589,10 @@ impl VideoPipeline {
                     binding: 1,
                     resource: wgpu::BindingResource::Sampler(&self.sampler),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: self.transform_buffer.as_entire_binding(),
+                },
             ],
         });
 
@@ -468,6 +660,31 @@ struct VertexOutput {
     @location(0) tex_coord: vec2<f32>,
 }
 
+struct FrameTransform {
+    // Clockwise quarter turns (0-3)
+    quarter_turns: u32,
+    // Non-zero to flip the rotated frame horizontally
+    mirrored: u32,
+    _padding0: u32,
+    _padding1: u32,
+    // Color adjustments (neutral: 0, 1, 1, 1)
+    brightness: f32,
+    contrast: f32,
+    saturation: f32,
+    gamma: f32,
+    // HDR signal: 0 = SDR (drawn as is), 1 = PQ, 2 = HLG
+    transfer: u32,
+    // Tone mapping curve: 0 = clip, 1 = Reinhard, 2 = Hable, 3 = ACES
+    tone_mapping: u32,
+    // Non-zero for BT.2020 primaries
+    bt2020: u32,
+    // Peak brightness of the content, relative to SDR white
+    peak: f32,
+}
+
+@group(0) @binding(2)
+var<uniform> transform: FrameTransform;
+
 @vertex
 fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
     // Generate a fullscreen quad using triangle strip
@@ -482,7 +699,18 @@ fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
 
     var output: VertexOutput;
     output.position = vec4<f32>(pos_x, pos_y, 0.0, 1.0);
-    output.tex_coord = vec2<f32>(x, y);
+
+    // Find which texel lands on this corner: undo the mirror, then the rotation
+    var corner = vec2<f32>(x, y);
+    if transform.mirrored != 0u {
+        corner.x = 1.0 - corner.x;
+    }
+    switch transform.quarter_turns {
+        case 1u: { output.tex_coord = vec2<f32>(corner.y, 1.0 - corner.x); }
+        case 2u: { output.tex_coord = vec2<f32>(1.0 - corner.x, 1.0 - corner.y); }
+        case 3u: { output.tex_coord = vec2<f32>(1.0 - corner.y, corner.x); }
+        default: { output.tex_coord = corner; }
+    }
     return output;
 }
 
@@ -491,9 +719,105 @@ var video_texture: texture_2d<f32>;
 @group(0) @binding(1)
 var video_sampler: sampler;
 
+// Brightness of SDR white in HDR signals, in nits
+const SDR_WHITE_NITS: f32 = 203.0;
+
+// SMPTE ST 2084 EOTF, relative to SDR white
+fn pq_to_linear(signal: vec3<f32>) -> vec3<f32> {
+    let m1 = 0.1593017578125;
+    let m2 = 78.84375;
+    let c1 = 0.8359375;
+    let c2 = 18.8515625;
+    let c3 = 18.6875;
+    let p = pow(max(signal, vec3<f32>(0.0)), vec3<f32>(1.0 / m2));
+    let linear = pow(max(p - vec3<f32>(c1), vec3<f32>(0.0)) / (vec3<f32>(c2) - c3 * p), vec3<f32>(1.0 / m1));
+    return linear * (10000.0 / SDR_WHITE_NITS);
+}
+
+// ARIB STD-B67 inverse OETF followed by the reference OOTF of a 1000 nit
+// display, relative to SDR white
+fn hlg_to_linear(signal: vec3<f32>) -> vec3<f32> {
+    let a = 0.17883277;
+    let b = 0.28466892;
+    let c = 0.55991073;
+    let e = max(signal, vec3<f32>(0.0));
+    let low = e * e / 3.0;
+    let high = (exp((e - vec3<f32>(c)) / a) + vec3<f32>(b)) / 12.0;
+    let scene = select(high, low, e <= vec3<f32>(0.5));
+    let luma = dot(scene, vec3<f32>(0.2627, 0.6780, 0.0593));
+    return scene * pow(max(luma, 1e-6), 0.2) * (1000.0 / SDR_WHITE_NITS);
+}
+
+fn hable(x: f32) -> f32 {
+    let a = 0.15;
+    let b = 0.50;
+    let c = 0.10;
+    let d = 0.20;
+    let e = 0.02;
+    let f = 0.30;
+    return (x * (a * x + c * b) + d * e) / (x * (a * x + b) + d * f) - e / f;
+}
+
+fn aces(x: f32) -> f32 {
+    return (x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14);
+}
+
+// Maps a light level relative to SDR white into 0-1, the content peak
+// landing on white
+fn tone_map(x: f32) -> f32 {
+    let peak = transform.peak;
+    switch transform.tone_mapping {
+        case 1u: { return x * (1.0 + x / (peak * peak)) / (1.0 + x); }
+        case 2u: { return hable(2.0 * x) / hable(2.0 * peak); }
+        case 3u: { return aces(0.6 * x) / aces(0.6 * peak); }
+        default: { return x; }
+    }
+}
+
+fn linear_to_srgb(linear: vec3<f32>) -> vec3<f32> {
+    let c = clamp(linear, vec3<f32>(0.0), vec3<f32>(1.0));
+    let high = 1.055 * pow(c, vec3<f32>(1.0 / 2.4)) - vec3<f32>(0.055);
+    return select(high, 12.92 * c, c <= vec3<f32>(0.0031308));
+}
+
+// Decodes an HDR signal and brings it to SDR display values
+fn hdr_to_sdr(signal: vec3<f32>) -> vec3<f32> {
+    var linear: vec3<f32>;
+    if transform.transfer == 2u {
+        linear = hlg_to_linear(signal);
+    } else {
+        linear = pq_to_linear(signal);
+    }
+    if transform.bt2020 != 0u {
+        // BT.2020 to BT.709 primaries, in linear light
+        let to_bt709 = mat3x3<f32>(
+            vec3<f32>(1.6605, -0.1246, -0.0182),
+            vec3<f32>(-0.5876, 1.1329, -0.1006),
+            vec3<f32>(-0.0728, -0.0083, 1.1187),
+        );
+        linear = max(to_bt709 * linear, vec3<f32>(0.0));
+    }
+    // Scale by the brightest channel to keep the hue
+    let brightest = max(max(linear.r, linear.g), linear.b);
+    if brightest > 0.0 {
+        linear = linear * (tone_map(brightest) / brightest);
+    }
+    return linear_to_srgb(linear);
+}
+
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    return textureSample(video_texture, video_sampler, input.tex_coord);
+    let color = textureSample(video_texture, video_sampler, input.tex_coord);
+    var signal = color.rgb;
+    if transform.transfer != 0u {
+        signal = hdr_to_sdr(signal);
+    }
+    var rgb = signal + vec3<f32>(transform.brightness);
+    rgb = (rgb - vec3<f32>(0.5)) * transform.contrast + vec3<f32>(0.5);
+    let luma = dot(rgb, vec3<f32>(0.2126, 0.7152, 0.0722));
+    rgb = mix(vec3<f32>(luma), rgb, transform.saturation);
+    rgb = pow(clamp(rgb, vec3<f32>(0.0), vec3<f32>(1.0)), vec3<f32>(1.0 / transform.gamma));
+    return vec4<f32>(rgb, color.a);
 }
 ";
 
@@ -529,6 +853,57 @@ mod tests {
         assert!(shader.dimensions().is_none());
     }
 
+    #[test]
+    fn frame_transform_packs_quarter_turns_and_mirror() {
+        let transform = FrameTransform {
+            rotation: RotationAngle::new(270),
+            mirrored: true,
+        };
+        let bytes = transform.uniform_bytes();
+        assert_eq!(bytes[0..4], 3u32.to_le_bytes());
+        assert_eq!(bytes[4..8], 1u32.to_le_bytes());
+        assert_eq!(FrameTransform::default().uniform_bytes(), [0; 16]);
+    }
+
+    #[test]
+    fn color_adjustments_default_is_neutral() {
+        let adjustments = ColorAdjustments::default();
+        assert!(adjustments.is_neutral());
+        let bytes = adjustments.uniform_bytes();
+        assert_eq!(bytes[0..4], 0.0f32.to_le_bytes());
+        assert_eq!(bytes[12..16], 1.0f32.to_le_bytes());
+
+        let brighter = ColorAdjustments {
+            brightness: 0.2,
+            ..adjustments
+        };
+        assert!(!brighter.is_neutral());
+    }
+
+    #[test]
+    fn tone_map_params_pack_transfer_and_peak() {
+        assert_eq!(
+            ToneMapParams::default().uniform_bytes()[0..4],
+            0u32.to_le_bytes()
+        );
+
+        let params = ToneMapParams {
+            hdr: Some(HdrInfo {
+                transfer: HdrTransfer::Hlg,
+                bt2020: true,
+                mastering_luminance: None,
+                max_cll: Some(406),
+                max_fall: None,
+            }),
+            operator: ToneMapping::Aces,
+        };
+        let bytes = params.uniform_bytes();
+        assert_eq!(bytes[0..4], 2u32.to_le_bytes());
+        assert_eq!(bytes[4..8], 3u32.to_le_bytes());
+        assert_eq!(bytes[8..12], 1u32.to_le_bytes());
+        assert_eq!(bytes[12..16], 2.0f32.to_le_bytes());
+    }
+
     #[test]
     fn video_shader_exportable_frame_returns_data() {
         let mut shader: VideoShader<()> = VideoShader::new();
@@ -7,6 +7,7 @@
 use crate::error::{Error, Result};
 use crate::video_player::audio_output::AudioOutputConfig;
 use crate::video_player::sync::SharedSyncClock;
+use crate::video_player::time_stretch::TimeStretcher;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -113,7 +114,7 @@ pub enum AudioDecoderCommand {
     /// Stop decoding and clean up.
     Stop,
 
-    /// Set volume (guaranteed to be within 0.0–1.0 by Volume type).
+    /// Set volume (guaranteed to be within 0.0–2.0 by Volume type).
     SetVolume(super::Volume),
 
     /// Set mute state.
@@ -150,10 +151,12 @@ struct AudioDecoderState {
     seek_target_secs: Option<f64>,
     /// Counter for frames skipped during seeking.
     seek_frames_skipped: u32,
+    /// Keeps the pitch of audio played faster or slower than normal.
+    stretcher: TimeStretcher,
 }
 
 impl AudioDecoderState {
-    fn new() -> Self {
+    fn new(stretcher: TimeStretcher) -> Self {
         Self {
             is_playing: false,
             volume: 1.0,
@@ -163,6 +166,7 @@ impl AudioDecoderState {
             first_pts: None,
             seek_target_secs: None,
             seek_frames_skipped: 0,
+            stretcher,
         }
     }
 
@@ -215,6 +219,7 @@ fn handle_audio_command(
                 state.seek_target_secs = None;
             } else {
                 decoder.flush();
+                state.stretcher.reset();
                 state.reset_timing();
                 state.seek_target_secs = Some(*target_secs);
                 state.seek_frames_skipped = 0;
@@ -241,6 +246,7 @@ fn handle_audio_command(
             reference_pts,
         } => {
             state.playback_speed = speed.value();
+            state.stretcher.set_speed(speed.value());
             state.playback_start_time = Some(*instant);
             state.first_pts = Some(*reference_pts);
         }
@@ -310,7 +316,7 @@ impl AudioDecoder {
             if let Err(e) =
                 Self::decoder_loop(path, command_rx, event_tx, sync_clock, output_config)
             {
-                eprintln!("Audio decoder task failed: {e}");
+                tracing::error!("Audio decoder task failed: {e}");
             }
         });
 
@@ -458,7 +464,8 @@ impl AudioDecoder {
         let output_sample_rate = output_config.sample_rate;
 
         // Playback state
-        let mut state = AudioDecoderState::new();
+        let mut state =
+            AudioDecoderState::new(TimeStretcher::new(output_sample_rate, output_channels));
 
         // Main loop
         loop {
@@ -593,12 +600,23 @@ impl AudioDecoder {
                         clock.update_audio_pts(pts_secs);
                     }
 
+                    // Time-stretch to the playback speed, keeping the pitch
+                    let (samples, duration_secs) = if state.stretcher.is_passthrough() {
+                        (samples, frame_duration)
+                    } else {
+                        let stretched = state.stretcher.process(&samples);
+                        #[allow(clippy::cast_precision_loss)]
+                        let duration = stretched.len() as f64
+                            / (f64::from(output_sample_rate) * f64::from(output_channels));
+                        (stretched, duration)
+                    };
+
                     let audio = DecodedAudio {
                         samples: Arc::new(samples),
                         sample_rate: output_sample_rate,
                         channels: output_channels,
                         pts_secs,
-                        duration_secs: frame_duration,
+                        duration_secs,
                     };
 
                     if event_tx
@@ -0,0 +1,266 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Audio levels for the visualization strip under the seek bar.
+//!
+//! Two views are supported:
+//! - A [`Waveform`] of the whole track, analyzed in the background with the
+//!   `FFmpeg` command line like the LUFS measurement in
+//!   [`normalization`](super::normalization).
+//! - A live [`AudioLevel`] of the samples being played, published by the
+//!   playback subscription through an [`AudioLevelMeter`].
+
+use std::io::{BufReader, Read};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::error::{Error, Result};
+
+/// Number of waveform buckets per second of audio.
+pub const WAVEFORM_BUCKETS_PER_SEC: u32 = 10;
+
+/// Sample rate the audio is downmixed to for waveform analysis.
+/// Peaks don't need more than this, and it keeps the analysis fast.
+const WAVEFORM_SAMPLE_RATE: u32 = 8000;
+
+/// Lowest level shown by the meters, in dBFS.
+pub const METER_FLOOR_DB: f32 = -60.0;
+
+/// Peak amplitude of a track over time.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Waveform {
+    /// Peak amplitude (0.0 to 1.0) of each bucket.
+    peaks: Vec<f32>,
+}
+
+impl Waveform {
+    /// Builds a waveform from mono samples at `sample_rate`.
+    #[must_use]
+    pub fn from_samples(samples: impl IntoIterator<Item = f32>, sample_rate: u32) -> Self {
+        let bucket_len = (sample_rate / WAVEFORM_BUCKETS_PER_SEC).max(1);
+        let mut peaks = Vec::new();
+        let mut peak = 0.0_f32;
+        let mut count = 0;
+        for sample in samples {
+            peak = peak.max(sample.abs().min(1.0));
+            count += 1;
+            if count == bucket_len {
+                peaks.push(peak);
+                peak = 0.0;
+                count = 0;
+            }
+        }
+        if count > 0 {
+            peaks.push(peak);
+        }
+        Self { peaks }
+    }
+
+    /// Returns true if no audio was analyzed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.peaks.is_empty()
+    }
+
+    /// Returns the peak of each of `columns` equal slices of a timeline
+    /// lasting `duration_secs`, for drawing under a seek bar of that length.
+    #[must_use]
+    pub fn columns(&self, duration_secs: f64, columns: usize) -> Vec<f32> {
+        if columns == 0 || duration_secs <= 0.0 {
+            return Vec::new();
+        }
+        // Bucket indices of a video timeline are far below usize::MAX
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss
+        )]
+        let bucket_at = |column: usize| {
+            let secs = duration_secs * column as f64 / columns as f64;
+            (secs * f64::from(WAVEFORM_BUCKETS_PER_SEC)) as usize
+        };
+        (0..columns)
+            .map(|column| {
+                let start = bucket_at(column);
+                let end = bucket_at(column + 1).max(start + 1).min(self.peaks.len());
+                self.peaks
+                    .get(start..end)
+                    .map_or(0.0, |peaks| peaks.iter().copied().fold(0.0, f32::max))
+            })
+            .collect()
+    }
+}
+
+/// Decodes the audio track of `path` and returns its waveform.
+///
+/// Uses `FFmpeg` to downmix the track to mono at a low sample rate and reads
+/// the samples as they are decoded, so long files are never held in memory.
+///
+/// # Errors
+///
+/// Returns an error if `FFmpeg` can't be run or the file has no audio.
+pub fn analyze_waveform<P: AsRef<Path>>(path: P) -> Result<Waveform> {
+    let path_str = path.as_ref().to_string_lossy();
+    let sample_rate = WAVEFORM_SAMPLE_RATE.to_string();
+
+    let mut child = Command::new("ffmpeg")
+        .args([
+            "-v",
+            "error",
+            "-i",
+            &path_str,
+            "-vn",
+            "-ac",
+            "1",
+            "-ar",
+            &sample_rate,
+            "-f",
+            "f32le",
+            "-",
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| Error::Io(format!("Failed to run FFmpeg: {e}")))?;
+
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| Error::Io("FFmpeg output unavailable".to_string()))?;
+    let mut reader = BufReader::new(stdout);
+    let samples = std::iter::from_fn(move || {
+        let mut bytes = [0u8; 4];
+        reader
+            .read_exact(&mut bytes)
+            .ok()
+            .map(|()| f32::from_le_bytes(bytes))
+    });
+    let waveform = Waveform::from_samples(samples, WAVEFORM_SAMPLE_RATE);
+    let _ = child.wait();
+
+    if waveform.is_empty() {
+        return Err(Error::Io("No audio decoded for waveform".to_string()));
+    }
+    Ok(waveform)
+}
+
+/// Level of a buffer of audio samples.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AudioLevel {
+    /// Highest absolute sample (0.0 to 1.0).
+    pub peak: f32,
+    /// Root mean square of the samples (0.0 to 1.0).
+    pub rms: f32,
+}
+
+impl AudioLevel {
+    /// Measures interleaved samples (all channels are combined).
+    #[must_use]
+    pub fn measure(samples: &[f32]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+        let (peak, sum_squares) = samples.iter().fold((0.0_f32, 0.0_f32), |(peak, sum), s| {
+            (peak.max(s.abs()), sum + s * s)
+        });
+        // Buffer lengths are far below f32's exact integer range
+        #[allow(clippy::cast_precision_loss)]
+        let rms = (sum_squares / samples.len() as f32).sqrt();
+        Self {
+            peak: peak.min(1.0),
+            rms: rms.min(1.0),
+        }
+    }
+
+    /// Maps an amplitude to the 0.0–1.0 range of a meter drawn in decibels,
+    /// from [`METER_FLOOR_DB`] to 0 dBFS.
+    #[must_use]
+    pub fn meter_fraction(amplitude: f32) -> f32 {
+        if amplitude <= 0.0 {
+            return 0.0;
+        }
+        let db = 20.0 * amplitude.log10();
+        ((db - METER_FLOOR_DB) / -METER_FLOOR_DB).clamp(0.0, 1.0)
+    }
+}
+
+/// Latest level of the audio being played, shared between the playback
+/// subscription and the UI.
+#[derive(Debug, Default)]
+pub struct AudioLevelMeter {
+    peak: AtomicU32,
+    rms: AtomicU32,
+}
+
+impl AudioLevelMeter {
+    /// Returns the latest level.
+    #[must_use]
+    pub fn get(&self) -> AudioLevel {
+        AudioLevel {
+            peak: f32::from_bits(self.peak.load(Ordering::Relaxed)),
+            rms: f32::from_bits(self.rms.load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Publishes the level of the buffer being played.
+    pub fn set(&self, level: AudioLevel) {
+        self.peak.store(level.peak.to_bits(), Ordering::Relaxed);
+        self.rms.store(level.rms.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Drops the level back to silence (on pause, seek or stop).
+    pub fn reset(&self) {
+        self.set(AudioLevel::default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waveform_keeps_the_peak_of_each_bucket() {
+        // Two buckets of 100 samples at 1 kHz, the second one louder
+        let samples = (0..200).map(|i| if i < 100 { 0.25 } else { -0.5 });
+        let waveform = Waveform::from_samples(samples, 1000);
+        assert_eq!(waveform.columns(0.2, 2), vec![0.25, 0.5]);
+        // Fewer columns than buckets keep the loudest bucket
+        assert_eq!(waveform.columns(0.2, 1), vec![0.5]);
+    }
+
+    #[test]
+    fn waveform_columns_past_the_audio_are_silent() {
+        let waveform = Waveform::from_samples(std::iter::repeat_n(1.0, 100), 1000);
+        assert_eq!(waveform.columns(0.4, 4), vec![1.0, 0.0, 0.0, 0.0]);
+        assert!(waveform.columns(0.0, 4).is_empty());
+    }
+
+    #[test]
+    fn audio_level_measures_peak_and_rms() {
+        let level = AudioLevel::measure(&[0.5, -0.5, 0.5, -0.5]);
+        assert!((level.peak - 0.5).abs() < f32::EPSILON);
+        assert!((level.rms - 0.5).abs() < f32::EPSILON);
+        assert_eq!(AudioLevel::measure(&[]), AudioLevel::default());
+    }
+
+    #[test]
+    fn meter_fraction_spans_the_decibel_range() {
+        assert!((AudioLevel::meter_fraction(1.0) - 1.0).abs() < 0.001);
+        assert!(AudioLevel::meter_fraction(0.0).abs() < f32::EPSILON);
+        // -30 dBFS is half way up a -60..0 dB meter
+        assert!((AudioLevel::meter_fraction(0.031_62) - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn meter_publishes_and_resets() {
+        let meter = AudioLevelMeter::default();
+        let level = AudioLevel {
+            peak: 0.8,
+            rms: 0.3,
+        };
+        meter.set(level);
+        assert_eq!(meter.get(), level);
+        meter.reset();
+        assert_eq!(meter.get(), AudioLevel::default());
+    }
+}
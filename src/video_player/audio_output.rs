@@ -3,19 +3,93 @@
 //!
 //! This module provides audio playback functionality using the cpal library,
 //! supporting real-time volume control and muting.
+//!
+//! Sound goes to the device chosen in the settings, or to the system default.
+//! The application calls [`set_preferred_device`] whenever the choice changes,
+//! and playing outputs move to it with [`AudioOutput::follow_device`], which
+//! also picks up the new default device when the one in use is unplugged or
+//! the default changes.
 
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use tokio::sync::mpsc;
 
+use super::limiter::Limiter;
 use crate::error::{Error, Result};
 
+/// Id of the device chosen in the settings, `None` for the system default.
+static PREFERRED_DEVICE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Sets the device new and playing outputs use, `None` for the system default.
+pub fn set_preferred_device(id: Option<String>) {
+    if let Ok(mut preferred) = PREFERRED_DEVICE.write() {
+        *preferred = id;
+    }
+}
+
+/// Returns the id of the device outputs use, `None` for the system default.
+#[must_use]
+pub fn preferred_device() -> Option<String> {
+    PREFERRED_DEVICE
+        .read()
+        .ok()
+        .and_then(|preferred| preferred.clone())
+}
+
+/// An audio output device, as listed in the settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioDevice {
+    /// Stable id of the device, saved in the settings.
+    pub id: String,
+    /// Name shown to the user.
+    pub name: String,
+}
+
+/// Lists the audio output devices of the system.
+///
+/// Devices without an id can't be selected again later and are left out.
+#[must_use]
+pub fn output_devices() -> Vec<AudioDevice> {
+    let Ok(devices) = cpal::default_host().output_devices() else {
+        return Vec::new();
+    };
+    devices
+        .filter_map(|device| {
+            let id = device_id(&device)?;
+            let name = device
+                .description()
+                .map_or_else(|_| id.clone(), |description| description.name().to_string());
+            Some(AudioDevice { id, name })
+        })
+        .collect()
+}
+
+fn device_id(device: &cpal::Device) -> Option<String> {
+    device.id().ok().map(|id| id.to_string())
+}
+
+/// Returns the device with the given id, or the default device if `id` is
+/// `None` or the device is gone.
+fn find_device(host: &cpal::Host, id: Option<&str>) -> Result<cpal::Device> {
+    if let Some(id) = id {
+        let device = host.output_devices().ok().and_then(|mut devices| {
+            devices.find(|device| device_id(device).as_deref() == Some(id))
+        });
+        if let Some(device) = device {
+            return Ok(device);
+        }
+        tracing::warn!("Audio device {id} not found, using the default device");
+    }
+    host.default_output_device()
+        .ok_or_else(|| Error::Io("No audio output device found".to_string()))
+}
+
 /// Configuration for audio output device.
 ///
 /// Used to configure the audio decoder's resampler to match the output device.
 /// This ensures audio plays at the correct speed and with proper channel mapping.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AudioOutputConfig {
     /// Sample rate in Hz (e.g., 44100, 48000).
     pub sample_rate: u32,
@@ -24,6 +98,16 @@ pub struct AudioOutputConfig {
     pub channels: u16,
 }
 
+impl AudioOutputConfig {
+    /// Returns whether samples decoded for `other` play correctly with this
+    /// configuration: same sample rate, and both mono or both multichannel
+    /// (the decoder outputs mono or stereo, mapped to the front channels).
+    #[must_use]
+    pub fn decodes_like(&self, other: &Self) -> bool {
+        self.sample_rate == other.sample_rate && (self.channels == 1) == (other.channels == 1)
+    }
+}
+
 /// Audio samples to be played.
 /// Interleaved f32 samples normalized to [-1.0, 1.0].
 pub type AudioSamples = Arc<Vec<f32>>;
@@ -47,11 +131,11 @@ pub enum AudioOutputCommand {
     /// Used during seek to discard old audio without interrupting playback.
     ClearBuffer,
 
-    /// Set volume (0.0–1.5, perceptually scaled).
+    /// Set volume (0.0–2.0, perceptually scaled).
     /// A quadratic curve is applied: actual = slider², so:
     /// - 50% slider → 25% actual (-12 dB)
     /// - 100% slider → 100% actual (0 dB)
-    /// - 150% slider → 225% actual (+7 dB)
+    /// - 200% slider → 400% actual (+12 dB), limited to avoid clipping
     SetVolume(super::Volume),
 
     /// Set mute state.
@@ -68,6 +152,9 @@ struct SharedState {
 
     /// Pause state.
     paused: AtomicBool,
+
+    /// Set when the stream reports that its device is gone.
+    device_lost: AtomicBool,
 }
 
 impl SharedState {
@@ -76,6 +163,7 @@ impl SharedState {
             volume_bits: AtomicU32::new(initial_volume.to_bits()),
             muted: AtomicBool::new(false),
             paused: AtomicBool::new(false),
+            device_lost: AtomicBool::new(false),
         }
     }
 
@@ -102,11 +190,20 @@ impl SharedState {
     fn set_paused(&self, paused: bool) {
         self.paused.store(paused, Ordering::Relaxed);
     }
+
+    fn is_device_lost(&self) -> bool {
+        self.device_lost.load(Ordering::Relaxed)
+    }
+
+    fn set_device_lost(&self, lost: bool) {
+        self.device_lost.store(lost, Ordering::Relaxed);
+    }
 }
 
 /// Audio output stream manager.
 ///
-/// Handles audio playback through the system's default audio device.
+/// Handles audio playback through the preferred device, or the system's
+/// default audio device.
 pub struct AudioOutput {
     /// Channel for sending commands to the audio thread.
     command_tx: mpsc::UnboundedSender<AudioOutputCommand>,
@@ -114,12 +211,21 @@ pub struct AudioOutput {
     /// Shared state for volume/mute control.
     shared_state: Arc<SharedState>,
 
+    /// Samples waiting to be played, kept when the stream moves to another device.
+    buffer: Arc<std::sync::Mutex<Vec<f32>>>,
+
     /// Sample rate of the output device.
     sample_rate: u32,
 
     /// Number of channels of the output device.
     channels: u16,
 
+    /// Preferred device id when the stream was opened, `None` for the default.
+    requested_device: Option<String>,
+
+    /// Id of the device playing the stream.
+    device_id: Option<String>,
+
     /// The audio stream (kept alive to maintain playback).
     _stream: cpal::Stream,
 }
@@ -135,11 +241,9 @@ impl AudioOutput {
     /// Returns an error if no audio output device is found, if the device
     /// configuration cannot be retrieved, or if the audio stream fails to start.
     pub fn new(initial_volume: f32) -> Result<Self> {
-        // Get the default audio host and device
+        let requested_device = preferred_device();
         let host = cpal::default_host();
-        let device = host
-            .default_output_device()
-            .ok_or_else(|| Error::Io("No audio output device found".to_string()))?;
+        let device = find_device(&host, requested_device.as_deref())?;
 
         // Get supported config
         let supported_config = device
@@ -151,7 +255,6 @@ impl AudioOutput {
 
         // Create shared state
         let shared_state = Arc::new(SharedState::new(initial_volume));
-        let shared_state_clone = Arc::clone(&shared_state);
 
         // Create command channel
         let (command_tx, mut command_rx) = mpsc::unbounded_channel::<AudioOutputCommand>();
@@ -161,7 +264,6 @@ impl AudioOutput {
         let max_buffer_size = (sample_rate as usize) * (channels as usize); // 0.5 seconds at stereo
         let buffer: Arc<std::sync::Mutex<Vec<f32>>> =
             Arc::new(std::sync::Mutex::new(Vec::with_capacity(max_buffer_size)));
-        let buffer_clone = Arc::clone(&buffer);
 
         // Spawn a task to process commands
         let buffer_for_task = Arc::clone(&buffer);
@@ -214,28 +316,55 @@ impl AudioOutput {
             }
         });
 
+        let stream = Self::start_stream(
+            &device,
+            supported_config,
+            Arc::clone(&buffer),
+            Arc::clone(&shared_state),
+        )?;
+
+        Ok(Self {
+            command_tx,
+            shared_state,
+            buffer,
+            sample_rate,
+            channels,
+            requested_device,
+            device_id: device_id(&device),
+            _stream: stream,
+        })
+    }
+
+    /// Builds and starts a stream on `device` with the given configuration.
+    fn start_stream(
+        device: &cpal::Device,
+        supported_config: cpal::SupportedStreamConfig,
+        buffer: Arc<std::sync::Mutex<Vec<f32>>>,
+        shared_state: Arc<SharedState>,
+    ) -> Result<cpal::Stream> {
         // Build audio stream based on sample format.
         // Pass the device channel count so the callback can map stereo samples correctly.
+        let channels = supported_config.channels();
         let stream = match supported_config.sample_format() {
             cpal::SampleFormat::F32 => Self::build_stream::<f32>(
-                &device,
+                device,
                 &supported_config.into(),
-                buffer_clone,
-                shared_state_clone,
+                buffer,
+                shared_state,
                 channels,
             )?,
             cpal::SampleFormat::I16 => Self::build_stream::<i16>(
-                &device,
+                device,
                 &supported_config.into(),
-                buffer_clone,
-                shared_state_clone,
+                buffer,
+                shared_state,
                 channels,
             )?,
             cpal::SampleFormat::U16 => Self::build_stream::<u16>(
-                &device,
+                device,
                 &supported_config.into(),
-                buffer_clone,
-                shared_state_clone,
+                buffer,
+                shared_state,
                 channels,
             )?,
             _ => return Err(Error::Io("Unsupported audio sample format".to_string())),
@@ -246,13 +375,108 @@ impl AudioOutput {
             .play()
             .map_err(|e| Error::Io(format!("Failed to start audio stream: {e}")))?;
 
-        Ok(Self {
-            command_tx,
-            shared_state,
+        Ok(stream)
+    }
+
+    /// Returns the configuration of `device` closest to `current`: one with
+    /// the same sample rate and channel count if the device supports it, so
+    /// the decoded samples keep playing as is, else the device default.
+    fn closest_config(
+        device: &cpal::Device,
+        current: AudioOutputConfig,
+    ) -> Result<cpal::SupportedStreamConfig> {
+        let matching = device
+            .supported_output_configs()
+            .ok()
+            .and_then(|mut ranges| {
+                ranges.find(|range| {
+                    range.channels() == current.channels
+                        && (range.min_sample_rate()..=range.max_sample_rate())
+                            .contains(&current.sample_rate)
+                        && matches!(
+                            range.sample_format(),
+                            cpal::SampleFormat::F32
+                                | cpal::SampleFormat::I16
+                                | cpal::SampleFormat::U16
+                        )
+                })
+            });
+        match matching {
+            Some(range) => Ok(range.with_sample_rate(current.sample_rate)),
+            None => device
+                .default_output_config()
+                .map_err(|e| Error::Io(format!("Failed to get audio config: {e}"))),
+        }
+    }
+
+    /// Moves playback to another device if needed: when the preferred device
+    /// changed, when the device in use is gone, or when the default device
+    /// changed while following it. Queued samples, volume, mute and pause
+    /// state carry over.
+    ///
+    /// Returns the new configuration when the stream moved, which may differ
+    /// from the previous one if the new device doesn't support it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no device is available or the new stream fails to
+    /// start. The next call tries again.
+    pub fn follow_device(&mut self) -> Result<Option<AudioOutputConfig>> {
+        let requested_device = preferred_device();
+        let host = cpal::default_host();
+
+        let should_move =
+            if requested_device != self.requested_device || self.shared_state.is_device_lost() {
+                true
+            } else if let Some(id) = &self.requested_device {
+                // Playing on the default device because the preferred one was
+                // missing: move back once it is plugged in again
+                self.device_id.as_ref() != Some(id)
+                    && host.output_devices().is_ok_and(|mut devices| {
+                        devices.any(|device| device_id(&device).as_ref() == Some(id))
+                    })
+            } else {
+                // Following the default device: move when another one becomes the default
+                host.default_output_device()
+                    .is_some_and(|device| device_id(&device) != self.device_id)
+            };
+        if !should_move {
+            return Ok(None);
+        }
+
+        let device = find_device(&host, requested_device.as_deref())?;
+        let supported_config = Self::closest_config(&device, self.config())?;
+        let sample_rate = supported_config.sample_rate();
+        let channels = supported_config.channels();
+        let stream = Self::start_stream(
+            &device,
+            supported_config,
+            Arc::clone(&self.buffer),
+            Arc::clone(&self.shared_state),
+        )?;
+
+        // Samples queued for the old device are dropped if they no longer fit
+        let config = AudioOutputConfig {
             sample_rate,
             channels,
-            _stream: stream,
-        })
+        };
+        if !config.decodes_like(&self.config()) {
+            if let Ok(mut buf) = self.buffer.lock() {
+                buf.clear();
+            }
+        }
+
+        tracing::info!(
+            "Audio output moved to {} ({sample_rate} Hz, {channels} channels)",
+            device_id(&device).as_deref().unwrap_or("unknown device")
+        );
+        self._stream = stream;
+        self.sample_rate = sample_rate;
+        self.channels = channels;
+        self.requested_device = requested_device;
+        self.device_id = device_id(&device);
+        self.shared_state.set_device_lost(false);
+        Ok(Some(config))
     }
 
     /// Builds an audio output stream for a specific sample format.
@@ -267,6 +491,8 @@ impl AudioOutput {
         shared_state: Arc<SharedState>,
         device_channels: u16,
     ) -> Result<cpal::Stream> {
+        let lost_state = Arc::clone(&shared_state);
+        let mut limiter = Limiter::new(config.sample_rate);
         let stream = device
             .build_output_stream(
                 config,
@@ -297,7 +523,7 @@ impl AudioOutput {
                     // Squaring the volume makes the slider perceptually linear:
                     // - 50% slider → 25% actual (-12 dB, sounds like "half")
                     // - 100% slider → 100% actual (0 dB, unchanged)
-                    // - 150% slider → 225% actual (+7 dB, clearly louder)
+                    // - 200% slider → 400% actual (+12 dB, boosted)
                     let perceptual_volume = volume * volume;
 
                     // Map stereo samples to device channels.
@@ -308,18 +534,24 @@ impl AudioOutput {
                     let num_frames = data.len() / dev_ch;
                     let mut buf_idx = 0;
 
+                    let front_ch = dev_ch.min(2);
                     for frame in 0..num_frames {
                         let frame_offset = frame * dev_ch;
-                        for ch in 0..dev_ch {
-                            let sample_value = if ch < 2 && buf_idx < buf.len() {
-                                // Left (ch=0) or Right (ch=1) from stereo buffer
-                                let s = buf[buf_idx];
+
+                        // Left (ch=0) and Right (ch=1) from the stereo buffer, limited
+                        // together so boosted peaks don't clip
+                        let mut front = [0.0f32; 2];
+                        for sample in &mut front[..front_ch] {
+                            if buf_idx < buf.len() {
+                                *sample = buf[buf_idx] * perceptual_volume;
                                 buf_idx += 1;
-                                (s * perceptual_volume).clamp(-1.0, 0.999_999_9)
-                            } else {
-                                // Center, LFE, rear channels, or buffer exhausted → silence
-                                0.0f32
-                            };
+                            }
+                        }
+                        limiter.process(&mut front[..front_ch]);
+
+                        for ch in 0..dev_ch {
+                            // Center, LFE, rear channels, or buffer exhausted → silence
+                            let sample_value = front.get(ch).copied().unwrap_or(0.0);
                             data[frame_offset + ch] = T::from_sample(sample_value);
                         }
                     }
@@ -327,8 +559,14 @@ impl AudioOutput {
                     // Remove consumed samples (we consumed buf_idx samples)
                     buf.drain(..buf_idx);
                 },
-                |err| {
-                    eprintln!("Audio output error: {err}");
+                move |err| {
+                    if matches!(err, cpal::StreamError::DeviceNotAvailable) {
+                        // Unplugged: the playback loop moves to another device
+                        tracing::warn!("Audio output device is no longer available");
+                        lost_state.set_device_lost(true);
+                    } else {
+                        tracing::error!("Audio output error: {err}");
+                    }
                 },
                 None,
             )
@@ -487,6 +725,50 @@ mod tests {
         assert!(!state.is_paused());
     }
 
+    #[test]
+    fn shared_state_device_lost_operations() {
+        let state = SharedState::new(1.0);
+        assert!(!state.is_device_lost());
+
+        state.set_device_lost(true);
+        assert!(state.is_device_lost());
+
+        state.set_device_lost(false);
+        assert!(!state.is_device_lost());
+    }
+
+    #[test]
+    fn decoded_samples_survive_channel_count_changes_but_not_rate_changes() {
+        let stereo = AudioOutputConfig {
+            sample_rate: 48_000,
+            channels: 2,
+        };
+        let surround = AudioOutputConfig {
+            channels: 6,
+            ..stereo
+        };
+        let mono = AudioOutputConfig {
+            channels: 1,
+            ..stereo
+        };
+        let cd = AudioOutputConfig {
+            sample_rate: 44_100,
+            ..stereo
+        };
+        assert!(stereo.decodes_like(&surround));
+        assert!(!stereo.decodes_like(&mono));
+        assert!(!stereo.decodes_like(&cd));
+    }
+
+    #[test]
+    fn preferred_device_round_trips() {
+        set_preferred_device(Some("alsa:front".to_string()));
+        assert_eq!(preferred_device().as_deref(), Some("alsa:front"));
+
+        set_preferred_device(None);
+        assert_eq!(preferred_device(), None);
+    }
+
     #[test]
     fn audio_output_command_debug() {
         let cmd = AudioOutputCommand::SetVolume(Volume::new(0.5));
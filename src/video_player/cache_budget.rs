@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Automatic sizing of the frame cache and frame history.
+//!
+//! In auto mode the budgets follow the video resolution (a fixed number of
+//! frames each), capped to a share of the memory currently available so
+//! large videos don't starve the rest of the system.
+
+use super::{FrameCacheMb, FrameHistoryMb};
+use crate::config::{DEFAULT_FRAME_CACHE_MB, DEFAULT_FRAME_HISTORY_MB};
+
+/// Keyframes the seek cache should hold.
+const CACHE_FRAMES: u64 = 24;
+
+/// Frames the history should hold for backward stepping (~3 s at 30 fps).
+const HISTORY_FRAMES: u64 = 90;
+
+/// Share of the available memory both budgets may use together.
+const AVAILABLE_MEMORY_DIVISOR: u64 = 8;
+
+const BYTES_PER_MB: u64 = 1024 * 1024;
+
+/// Memory budgets for one video.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheBudget {
+    pub cache: FrameCacheMb,
+    pub history: FrameHistoryMb,
+}
+
+impl CacheBudget {
+    /// Computes the budgets for a `width`×`height` video.
+    ///
+    /// `available_bytes` is the memory currently available; when unknown, the
+    /// fixed defaults act as the cap.
+    #[must_use]
+    pub fn auto(width: u32, height: u32, available_bytes: Option<u64>) -> Self {
+        let frame_bytes = u64::from(width) * u64::from(height) * 4;
+        let cache_mb = (frame_bytes * CACHE_FRAMES).div_ceil(BYTES_PER_MB);
+        let history_mb = (frame_bytes * HISTORY_FRAMES).div_ceil(BYTES_PER_MB);
+
+        let cap_mb = available_bytes.map_or(
+            u64::from(DEFAULT_FRAME_CACHE_MB + DEFAULT_FRAME_HISTORY_MB),
+            |bytes| bytes / AVAILABLE_MEMORY_DIVISOR / BYTES_PER_MB,
+        );
+        let wanted_mb = cache_mb + history_mb;
+        let (cache_mb, history_mb) = if wanted_mb > cap_mb {
+            // Shrink both budgets by the same ratio
+            (
+                cache_mb * cap_mb / wanted_mb,
+                history_mb * cap_mb / wanted_mb,
+            )
+        } else {
+            (cache_mb, history_mb)
+        };
+
+        Self {
+            cache: FrameCacheMb::new(u32::try_from(cache_mb).unwrap_or(u32::MAX)),
+            history: FrameHistoryMb::new(u32::try_from(history_mb).unwrap_or(u32::MAX)),
+        }
+    }
+}
+
+/// Returns the memory currently available to applications, if the platform
+/// reports it.
+#[must_use]
+pub fn available_memory_bytes() -> Option<u64> {
+    if cfg!(target_os = "linux") {
+        std::fs::read_to_string("/proc/meminfo")
+            .ok()
+            .as_deref()
+            .and_then(parse_mem_available)
+    } else {
+        None
+    }
+}
+
+/// Extracts `MemAvailable` from the contents of `/proc/meminfo`, in bytes.
+fn parse_mem_available(meminfo: &str) -> Option<u64> {
+    meminfo
+        .lines()
+        .find_map(|line| line.strip_prefix("MemAvailable:"))
+        .and_then(|value| value.trim().strip_suffix("kB"))
+        .and_then(|kb| kb.trim().parse::<u64>().ok())
+        .map(|kb| kb * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{MAX_FRAME_CACHE_MB, MIN_FRAME_CACHE_MB, MIN_FRAME_HISTORY_MB};
+
+    const GB: u64 = 1024 * 1024 * 1024;
+
+    #[test]
+    fn budgets_grow_with_resolution() {
+        let sd = CacheBudget::auto(640, 480, Some(16 * GB));
+        let hd = CacheBudget::auto(1920, 1080, Some(16 * GB));
+        assert!(hd.cache.value() > sd.cache.value());
+        assert!(hd.history.value() > sd.history.value());
+
+        let uhd = CacheBudget::auto(3840, 2160, Some(16 * GB));
+        assert_eq!(uhd.cache.value(), MAX_FRAME_CACHE_MB);
+    }
+
+    #[test]
+    fn budgets_respect_available_memory() {
+        let low_memory = CacheBudget::auto(1920, 1080, Some(GB));
+        assert!(
+            u64::from(low_memory.cache.value() + low_memory.history.value())
+                <= GB / AVAILABLE_MEMORY_DIVISOR / BYTES_PER_MB
+        );
+
+        let starved = CacheBudget::auto(3840, 2160, Some(0));
+        assert_eq!(starved.cache.value(), MIN_FRAME_CACHE_MB);
+        assert_eq!(starved.history.value(), MIN_FRAME_HISTORY_MB);
+    }
+
+    #[test]
+    fn unknown_memory_caps_at_defaults() {
+        let budget = CacheBudget::auto(3840, 2160, None);
+        assert!(
+            budget.cache.value() + budget.history.value()
+                <= DEFAULT_FRAME_CACHE_MB + DEFAULT_FRAME_HISTORY_MB
+        );
+    }
+
+    #[test]
+    fn parses_meminfo() {
+        let meminfo = "MemTotal:       16318412 kB\nMemFree:         1198300 kB\nMemAvailable:    8159206 kB\n";
+        assert_eq!(parse_mem_available(meminfo), Some(8_159_206 * 1024));
+        assert_eq!(parse_mem_available("MemTotal: 1 kB\n"), None);
+    }
+}
@@ -5,7 +5,9 @@
 //! delivering frames through channels for non-blocking UI updates.
 
 use crate::error::{Error, Result};
-use crate::video_player::frame_cache::{CacheConfig, FrameCache};
+use crate::media::frame_export::pixel_format_depth;
+use crate::video_player::frame_cache::{CacheConfig, CacheStats, FrameCache};
+use crate::video_player::hwaccel::{self, DecodePath, HwBackend};
 use crate::video_player::sync::{calculate_sync_action, SharedSyncClock, SyncAction};
 use std::path::Path;
 use std::sync::Arc;
@@ -16,6 +18,9 @@ use tokio::sync::mpsc;
 /// At 30fps, 1000 frames = ~33 seconds of video.
 const MAX_SEEK_FRAMES: u32 = 1000;
 
+/// Timestamps closer than this are the same frame, in seconds.
+const PTS_TOLERANCE_SECS: f64 = 1e-4;
+
 /// Maximum consecutive frames to skip when video is behind audio.
 /// After this many skips, we display the next frame anyway to prevent freezing.
 const MAX_CONSECUTIVE_SKIPS: u32 = 5;
@@ -74,18 +79,17 @@ fn apply_frame_pacing(
                 SyncAction::Skip => {
                     *consecutive_skips += 1;
                     if *consecutive_skips < MAX_CONSECUTIVE_SKIPS {
-                        #[cfg(debug_assertions)]
-                        eprintln!(
-                            "[sync] Skipping frame (video behind by {:.3}s, skip #{})",
+                        crate::diagnostics::record_frame_dropped();
+                        tracing::trace!(
+                            "Skipping frame (video behind by {:.3}s, skip #{})",
                             audio_time - adjusted_pts,
                             *consecutive_skips
                         );
                         return PacingResult::SkipFrame;
                     }
                     // Too many skips, display anyway to prevent freezing
-                    #[cfg(debug_assertions)]
-                    eprintln!(
-                        "[sync] Max skips reached, displaying frame (behind by {:.3}s)",
+                    tracing::debug!(
+                        "Max skips reached, displaying frame (behind by {:.3}s)",
                         audio_time - adjusted_pts
                     );
                     *consecutive_skips = 0;
@@ -129,8 +133,12 @@ struct DecoderLoopState {
     decode_single_frame: bool,
     /// True when user is stepping through frames.
     in_stepping_mode: bool,
-    /// Frame displayed after seek (for stepping history).
-    last_paused_frame: Option<DecodedFrame>,
+    /// Last frame sent to the UI (starts the stepping history).
+    shown_frame: Option<DecodedFrame>,
+    /// Frame a step is decoding toward, if any.
+    step_search: Option<StepSearch>,
+    /// Frame to put back in the history after stepping back past it.
+    frame_after_step_back: Option<DecodedFrame>,
     /// Target PTS for precise seeking.
     seek_target_secs: Option<f64>,
     /// Counter for frames skipped during precise seeking.
@@ -150,7 +158,9 @@ impl DecoderLoopState {
             first_pts: None,
             decode_single_frame: false,
             in_stepping_mode: false,
-            last_paused_frame: None,
+            shown_frame: None,
+            step_search: None,
+            frame_after_step_back: None,
             seek_target_secs: None,
             seek_frames_skipped: 0,
             playback_speed: 1.0,
@@ -168,7 +178,225 @@ impl DecoderLoopState {
     fn clear_stepping(&mut self, frame_history: &mut FrameHistory) {
         self.in_stepping_mode = false;
         frame_history.clear();
-        self.last_paused_frame = None;
+        self.step_search = None;
+        self.frame_after_step_back = None;
+    }
+
+    /// Returns the frame found so far by a backward step, ending the search.
+    fn take_step_back_candidate(&mut self) -> Option<(ffmpeg_next::frame::Video, f64, bool)> {
+        let Some(StepSearch::Previous { shown, candidate }) = self.step_search.take() else {
+            return None;
+        };
+        if candidate.is_some() {
+            self.frame_after_step_back = Some(shown);
+        }
+        candidate
+    }
+}
+
+/// Frame a step is looking for.
+///
+/// Steps go by the timestamps of decoded frames rather than by an assumed
+/// frame duration, so they stay frame-accurate on variable frame rate video.
+enum StepSearch {
+    /// The first frame after the one shown; frames repeating or preceding
+    /// its timestamp are skipped.
+    Next { shown_pts: f64 },
+    /// The latest frame before `shown`, decoded again from the keyframe
+    /// preceding it.
+    Previous {
+        shown: DecodedFrame,
+        candidate: Option<(ffmpeg_next::frame::Video, f64, bool)>,
+    },
+}
+
+/// What to do with a decoded frame while stepping.
+enum StepMatch {
+    /// Show this frame.
+    Show,
+    /// Keep decoding.
+    Skip,
+    /// Show this earlier frame instead: the one preceding the frame shown.
+    ShowEarlier(ffmpeg_next::frame::Video, f64, bool),
+    /// No frame precedes the one shown.
+    AtStart,
+}
+
+/// Checks a decoded frame against the step in progress, if any.
+fn match_step(
+    state: &mut DecoderLoopState,
+    frame: &ffmpeg_next::frame::Video,
+    pts_secs: f64,
+    is_keyframe: bool,
+) -> StepMatch {
+    match state.step_search.take() {
+        None => StepMatch::Show,
+        Some(StepSearch::Next { shown_pts }) => {
+            if pts_secs > shown_pts + PTS_TOLERANCE_SECS {
+                StepMatch::Show
+            } else {
+                state.step_search = Some(StepSearch::Next { shown_pts });
+                StepMatch::Skip
+            }
+        }
+        Some(StepSearch::Previous { shown, candidate }) => {
+            if pts_secs + PTS_TOLERANCE_SECS < shown.pts_secs {
+                // Keep the latest frame by timestamp, whatever the decode order
+                let candidate = match candidate {
+                    Some(kept) if kept.1 > pts_secs => Some(kept),
+                    _ => Some((frame.clone(), pts_secs, is_keyframe)),
+                };
+                state.step_search = Some(StepSearch::Previous { shown, candidate });
+                return StepMatch::Skip;
+            }
+            match candidate {
+                Some((earlier, pts, keyframe)) => {
+                    state.frame_after_step_back = Some(shown);
+                    StepMatch::ShowEarlier(earlier, pts, keyframe)
+                }
+                None => StepMatch::AtStart,
+            }
+        }
+    }
+}
+
+/// Seeks to the keyframe before `pts_secs`, so decoding goes through the
+/// frames preceding it. Returns false if nothing can precede it.
+fn seek_before(
+    ictx: &mut ffmpeg_next::format::context::Input,
+    decoder: &mut ffmpeg_next::decoder::Video,
+    pts_secs: f64,
+) -> bool {
+    #[allow(clippy::cast_possible_truncation)]
+    let timestamp = (pts_secs * 1_000_000.0) as i64 - 1;
+    if timestamp < 0 || ictx.seek(timestamp, ..timestamp).is_err() {
+        return false;
+    }
+    decoder.flush();
+    true
+}
+
+/// Converts decoded frames to RGBA, downloading hardware frames first.
+///
+/// The scaler is created from the first frame's pixel format, since a
+/// hardware decoder only reveals it once frames are downloaded.
+struct FrameConverter {
+    width: u32,
+    height: u32,
+    /// Backend of the attached hardware device, if any.
+    hw_backend: Option<HwBackend>,
+    scaler: Option<(
+        ffmpeg_next::format::Pixel,
+        ffmpeg_next::software::scaling::Context,
+    )>,
+    /// Path of the last converted frame, to report changes.
+    decode_path: Option<DecodePath>,
+    /// Set when `decode_path` changed and hasn't been reported yet.
+    path_changed: bool,
+}
+
+impl FrameConverter {
+    fn new(width: u32, height: u32, hw_backend: Option<HwBackend>) -> Self {
+        Self {
+            width,
+            height,
+            hw_backend,
+            scaler: None,
+            decode_path: None,
+            path_changed: false,
+        }
+    }
+
+    /// Converts `frame` into `output` as RGBA.
+    fn run(
+        &mut self,
+        frame: &ffmpeg_next::frame::Video,
+        output: &mut ffmpeg_next::frame::Video,
+    ) -> std::result::Result<(), ffmpeg_next::Error> {
+        // FFmpeg falls back to software per stream, so check every frame
+        let (downloaded, path) = match self.hw_backend {
+            Some(backend) if hwaccel::is_hardware_frame(frame) => (
+                Some(hwaccel::download_frame(frame)?),
+                DecodePath::Hardware(backend),
+            ),
+            _ => (None, DecodePath::Software),
+        };
+        let frame = downloaded.as_ref().unwrap_or(frame);
+
+        if self.decode_path != Some(path) {
+            self.decode_path = Some(path);
+            self.path_changed = true;
+        }
+
+        let format = frame.format();
+        let scaler = match &mut self.scaler {
+            Some((scaler_format, scaler)) if *scaler_format == format => scaler,
+            slot => {
+                let high_depth = format
+                    .descriptor()
+                    .is_some_and(|descriptor| pixel_format_depth(descriptor.name()) > 8);
+                // 10-bit and deeper frames are reduced to 8 bits: round
+                // accurately and interpolate chroma fully to limit banding
+                let flags = if high_depth {
+                    ffmpeg_next::software::scaling::Flags::BICUBIC
+                        | ffmpeg_next::software::scaling::Flags::ACCURATE_RND
+                        | ffmpeg_next::software::scaling::Flags::FULL_CHR_H_INT
+                } else {
+                    ffmpeg_next::software::scaling::Flags::BILINEAR
+                };
+                let mut scaler = ffmpeg_next::software::scaling::Context::get(
+                    format,
+                    self.width,
+                    self.height,
+                    ffmpeg_next::format::Pixel::RGBA,
+                    self.width,
+                    self.height,
+                    flags,
+                )?;
+                if matches!(
+                    frame.color_space(),
+                    ffmpeg_next::color::Space::BT2020NCL | ffmpeg_next::color::Space::BT2020CL
+                ) {
+                    use_bt2020_matrix(
+                        &mut scaler,
+                        frame.color_range() == ffmpeg_next::color::Range::JPEG,
+                    );
+                }
+                &mut slot.insert((format, scaler)).1
+            }
+        };
+        scaler.run(frame, output)
+    }
+
+    /// Returns the decode path if it changed since the last call.
+    fn take_path_change(&mut self) -> Option<DecodePath> {
+        std::mem::take(&mut self.path_changed)
+            .then_some(self.decode_path)
+            .flatten()
+    }
+}
+
+/// Makes `scaler` read YUV with the BT.2020 matrix of HDR video; swscale
+/// assumes BT.601 otherwise, which shifts the colors.
+fn use_bt2020_matrix(scaler: &mut ffmpeg_next::software::scaling::Context, full_range: bool) {
+    use ffmpeg_next::ffi;
+
+    // SAFETY: the scaler context is valid for the duration of the call, and
+    // the coefficient tables returned by swscale are static.
+    let result = unsafe {
+        ffi::sws_setColorspaceDetails(
+            scaler.as_mut_ptr(),
+            ffi::sws_getCoefficients(ffi::SWS_CS_BT2020.cast_signed()),
+            i32::from(full_range),
+            ffi::sws_getCoefficients(ffi::SWS_CS_DEFAULT.cast_signed()),
+            1,
+            0,
+            1 << 16,
+            1 << 16,
+        )
+    };
+    if result < 0 {
+        tracing::debug!("Scaler kept its default YUV matrix for a BT.2020 video");
     }
 }
 
@@ -209,20 +437,24 @@ enum PacketDecodeResult {
     ChannelClosed,
     /// Error occurred, continue to next packet.
     Error,
+    /// A backward step found no earlier frame, stop decoding.
+    NothingToShow,
 }
 
-/// Handles end-of-stream: emits last decoded frame if seeking beyond EOF.
+/// Handles end-of-stream: emits last decoded frame if seeking beyond EOF, or
+/// the frame found by a backward step.
 ///
 /// Returns true if a frame was emitted, false otherwise.
 fn handle_end_of_stream(
     last_decoded_for_seek: Option<(ffmpeg_next::frame::Video, f64, bool)>,
-    scaler: &mut ffmpeg_next::software::scaling::Context,
+    converter: &mut FrameConverter,
     ctx: &mut EmitContext,
 ) -> bool {
-    if let Some((last_frame, pts_secs, is_keyframe)) = last_decoded_for_seek {
+    let last_decoded = last_decoded_for_seek.or_else(|| ctx.state.take_step_back_candidate());
+    if let Some((last_frame, pts_secs, is_keyframe)) = last_decoded {
         ctx.state.seek_target_secs = None;
         let mut rgb_frame = ffmpeg_next::frame::Video::empty();
-        if scaler.run(&last_frame, &mut rgb_frame).is_ok()
+        if converter.run(&last_frame, &mut rgb_frame).is_ok()
             && emit_frame(&rgb_frame, pts_secs, is_keyframe, ctx)
         {
             return true;
@@ -240,7 +472,7 @@ fn process_packet_frame(
     decoded_frame: &ffmpeg_next::frame::Video,
     time_base_f64: f64,
     state: &mut DecoderLoopState,
-    scaler: &mut ffmpeg_next::software::scaling::Context,
+    converter: &mut FrameConverter,
     frame_cache: &mut FrameCache,
     frame_history: &mut FrameHistory,
     event_tx: &mpsc::Sender<DecoderEvent>,
@@ -257,6 +489,22 @@ fn process_packet_frame(
     };
     let is_keyframe = decoded_frame.is_key();
 
+    // Stepping: look for the frame next to the one shown by timestamp
+    let earlier;
+    let (decoded_frame, pts_secs, is_keyframe) =
+        match match_step(state, decoded_frame, pts_secs, is_keyframe) {
+            StepMatch::Show => (decoded_frame, pts_secs, is_keyframe),
+            StepMatch::Skip => return PacketDecodeResult::ContinueDecoding,
+            StepMatch::ShowEarlier(frame, pts, keyframe) => {
+                earlier = frame;
+                (&earlier, pts, keyframe)
+            }
+            StepMatch::AtStart => {
+                let _ = event_tx.blocking_send(DecoderEvent::HistoryExhausted);
+                return PacketDecodeResult::NothingToShow;
+            }
+        };
+
     // Precise seeking with timeout protection
     if let Some(target) = state.seek_target_secs {
         if pts_secs < target {
@@ -280,7 +528,7 @@ fn process_packet_frame(
 
     // Scale to RGBA
     let mut rgb_frame = ffmpeg_next::frame::Video::empty();
-    if scaler.run(decoded_frame, &mut rgb_frame).is_err() {
+    if converter.run(decoded_frame, &mut rgb_frame).is_err() {
         let _ = event_tx.blocking_send(DecoderEvent::Error("Scaling failed".to_string()));
         return PacketDecodeResult::Error;
     }
@@ -322,12 +570,14 @@ enum FrameProcessingResult {
     Emitted,
     /// Frame was stored for seeking (before target PTS).
     StoredForSeek(ffmpeg_next::frame::Video, f64, bool),
-    /// Frame should be skipped (A/V sync).
+    /// Frame should be skipped (A/V sync, or not the frame a step looks for).
     Skip,
     /// Channel closed, break from loop.
     ChannelClosed,
     /// Scaling failed, continue to next.
     ScalingFailed,
+    /// A backward step found no earlier frame, stop decoding.
+    NothingToShow,
 }
 
 /// Processes a decoded video frame: handles seeking, pacing, scaling, and emission.
@@ -339,7 +589,7 @@ fn process_decoded_frame(
     frame: &ffmpeg_next::frame::Video,
     time_base_f64: f64,
     state: &mut DecoderLoopState,
-    scaler: &mut ffmpeg_next::software::scaling::Context,
+    converter: &mut FrameConverter,
     frame_cache: &mut FrameCache,
     frame_history: &mut FrameHistory,
     event_tx: &mpsc::Sender<DecoderEvent>,
@@ -355,6 +605,21 @@ fn process_decoded_frame(
     };
     let is_keyframe = frame.is_key();
 
+    // Stepping: look for the frame next to the one shown by timestamp
+    let earlier;
+    let (frame, pts_secs, is_keyframe) = match match_step(state, frame, pts_secs, is_keyframe) {
+        StepMatch::Show => (frame, pts_secs, is_keyframe),
+        StepMatch::Skip => return FrameProcessingResult::Skip,
+        StepMatch::ShowEarlier(earlier_frame, pts, keyframe) => {
+            earlier = earlier_frame;
+            (&earlier, pts, keyframe)
+        }
+        StepMatch::AtStart => {
+            let _ = event_tx.blocking_send(DecoderEvent::HistoryExhausted);
+            return FrameProcessingResult::NothingToShow;
+        }
+    };
+
     // Precise seeking: skip frames before target PTS
     if let Some(target) = state.seek_target_secs {
         if pts_secs < target {
@@ -366,7 +631,7 @@ fn process_decoded_frame(
 
     // Scale to RGBA
     let mut rgb_frame = ffmpeg_next::frame::Video::empty();
-    if scaler.run(frame, &mut rgb_frame).is_err() {
+    if converter.run(frame, &mut rgb_frame).is_err() {
         return FrameProcessingResult::ScalingFailed;
     }
 
@@ -421,16 +686,25 @@ fn emit_frame(
     if is_keyframe {
         ctx.frame_cache.insert(output_frame.clone(), true);
     }
-    if !ctx.state.is_playing && !ctx.state.in_stepping_mode {
-        ctx.state.last_paused_frame = Some(output_frame.clone());
-    }
     if ctx.state.in_stepping_mode {
         ctx.frame_history.push(output_frame.clone());
+        // Stepped back past the history: the frame shown before comes next
+        if let Some(next) = ctx.state.frame_after_step_back.take() {
+            ctx.frame_history.push(next);
+            ctx.frame_history.step_back();
+        }
     }
+    ctx.state.shown_frame = Some(output_frame.clone());
 
-    ctx.event_tx
+    let sent = ctx
+        .event_tx
         .blocking_send(DecoderEvent::FrameReady(output_frame))
-        .is_ok()
+        .is_ok();
+    crate::diagnostics::record_frame_decoded();
+    crate::diagnostics::set_frame_queue_depth(
+        ctx.event_tx.max_capacity() - ctx.event_tx.capacity(),
+    );
+    sent
 }
 
 /// Processes a single decoder command.
@@ -442,10 +716,9 @@ fn handle_decoder_command(
     state: &mut DecoderLoopState,
     ictx: &mut ffmpeg_next::format::context::Input,
     decoder: &mut ffmpeg_next::decoder::Video,
+    frame_cache: &mut FrameCache,
     frame_history: &mut FrameHistory,
     event_tx: &mpsc::Sender<DecoderEvent>,
-    width: u32,
-    height: u32,
 ) -> CommandResult {
     match command {
         DecoderCommand::Play { .. } => {
@@ -472,6 +745,13 @@ fn handle_decoder_command(
                 state.seek_frames_skipped = 0;
                 let _ = event_tx.blocking_send(DecoderEvent::Buffering);
                 if !state.is_playing {
+                    // Show a cached keyframe right away; the exact frame follows
+                    if let Some(cached) = frame_cache.get_nearest(target_secs) {
+                        let _ = event_tx.blocking_send(DecoderEvent::FrameReady((*cached).clone()));
+                    }
+                    let stats = frame_cache.stats();
+                    crate::diagnostics::set_video_frame_cache(stats.hits, stats.misses);
+                    let _ = event_tx.blocking_send(DecoderEvent::CacheStats(stats));
                     state.decode_single_frame = true;
                 }
             }
@@ -480,38 +760,49 @@ fn handle_decoder_command(
             if !state.is_playing {
                 state.seek_target_secs = None;
                 if !state.in_stepping_mode {
-                    if let Some(ref initial_frame) = state.last_paused_frame {
+                    if let Some(ref initial_frame) = state.shown_frame {
                         frame_history.push(initial_frame.clone());
                     }
                     state.in_stepping_mode = true;
                 }
                 if let Some(next_frame) = frame_history.step_forward() {
-                    let output_frame = DecodedFrame {
-                        rgba_data: Arc::clone(&next_frame.rgba_data),
-                        width: next_frame.width,
-                        height: next_frame.height,
-                        pts_secs: next_frame.pts_secs,
-                    };
+                    let output_frame = next_frame.clone();
+                    state.shown_frame = Some(output_frame.clone());
                     let _ = event_tx.blocking_send(DecoderEvent::FrameReady(output_frame));
                     return CommandResult::FrameEmitted;
                 }
+                state.step_search = state.shown_frame.as_ref().map(|shown| StepSearch::Next {
+                    shown_pts: shown.pts_secs,
+                });
                 state.decode_single_frame = true;
             }
         }
         DecoderCommand::StepBackward => {
-            if !state.is_playing && state.in_stepping_mode {
+            if !state.is_playing {
                 state.seek_target_secs = None;
-                if let Some(prev_frame) = frame_history.step_back() {
-                    let output_frame = DecodedFrame {
-                        rgba_data: Arc::clone(&prev_frame.rgba_data),
-                        width,
-                        height,
-                        pts_secs: prev_frame.pts_secs,
-                    };
-                    let _ = event_tx.blocking_send(DecoderEvent::FrameReady(output_frame));
-                    return CommandResult::FrameEmitted;
+                if state.in_stepping_mode {
+                    if let Some(prev_frame) = frame_history.step_back() {
+                        let output_frame = prev_frame.clone();
+                        state.shown_frame = Some(output_frame.clone());
+                        let _ = event_tx.blocking_send(DecoderEvent::FrameReady(output_frame));
+                        return CommandResult::FrameEmitted;
+                    }
+                }
+                // Past the history: decode again up to the frame shown
+                match state.shown_frame.clone() {
+                    Some(shown) if seek_before(ictx, decoder, shown.pts_secs) => {
+                        frame_history.clear();
+                        state.in_stepping_mode = true;
+                        state.step_search = Some(StepSearch::Previous {
+                            shown,
+                            candidate: None,
+                        });
+                        state.decode_single_frame = true;
+                    }
+                    _ => {
+                        let _ = event_tx.blocking_send(DecoderEvent::HistoryExhausted);
+                    }
                 }
-                let _ = event_tx.blocking_send(DecoderEvent::HistoryExhausted);
             }
         }
         DecoderCommand::Stop => return CommandResult::Break,
@@ -573,7 +864,8 @@ pub enum DecoderCommand {
     /// Used for frame-by-frame navigation when paused.
     StepFrame,
 
-    /// Step backward one frame (return previous frame from history).
+    /// Step backward one frame (return previous frame from history, or
+    /// decode again from the keyframe before the frame shown).
     /// Used for frame-by-frame backward navigation when paused.
     StepBackward,
 
@@ -608,9 +900,17 @@ pub enum DecoderEvent {
     /// An error occurred during decoding.
     Error(String),
 
-    /// Frame history is exhausted (no more frames to step backward).
-    /// Sent when `StepBackward` is requested but no previous frame is available.
+    /// No frame precedes the one shown (no more frames to step backward).
+    /// Sent when `StepBackward` is requested at the first frame.
     HistoryExhausted,
+
+    /// Frames are now decoded through this path (hardware or software).
+    /// Sent with the first frame and whenever `FFmpeg` falls back to software.
+    DecodePath(DecodePath),
+
+    /// Frame cache statistics, sent after each seek while paused (the only
+    /// time the cache is looked up).
+    CacheStats(CacheStats),
 }
 
 /// Async video decoder that runs in a Tokio task.
@@ -651,6 +951,25 @@ impl AsyncDecoder {
         cache_config: CacheConfig,
         history_mb: u32,
         sync_clock: Option<SharedSyncClock>,
+    ) -> Result<Self> {
+        Self::with_hardware_decoding(video_path, cache_config, history_mb, sync_clock, false)
+    }
+
+    /// Creates a new async decoder, decoding on the GPU when `hardware_decoding`
+    /// is set and a platform backend supports the codec.
+    ///
+    /// Falls back to software decoding when no hardware device is available.
+    /// The path in use is reported with [`DecoderEvent::DecodePath`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`AsyncDecoder::new`].
+    pub fn with_hardware_decoding<P: AsRef<Path>>(
+        video_path: P,
+        cache_config: CacheConfig,
+        history_mb: u32,
+        sync_clock: Option<SharedSyncClock>,
+        hardware_decoding: bool,
     ) -> Result<Self> {
         let path = video_path.as_ref().to_path_buf();
 
@@ -679,8 +998,9 @@ impl AsyncDecoder {
                 cache_config,
                 history_mb,
                 sync_clock,
+                hardware_decoding,
             ) {
-                eprintln!("Decoder task failed: {e}");
+                tracing::error!("Decoder task failed: {e}");
             }
         });
 
@@ -726,6 +1046,9 @@ impl AsyncDecoder {
     ///
     /// If `sync_clock` is provided, frame pacing uses the audio clock for A/V sync.
     /// Otherwise, falls back to wall-clock based timing.
+    ///
+    /// If `hardware_decoding` is set, a hardware device is attached to the
+    /// decoder when the platform supports the codec.
     #[allow(clippy::needless_pass_by_value)] // PathBuf/Sender need ownership
     #[allow(clippy::too_many_lines)] // Core state machine with inherent complexity (154 lines after refactoring from 607)
     fn decoder_loop_blocking(
@@ -735,6 +1058,7 @@ impl AsyncDecoder {
         cache_config: CacheConfig,
         history_mb: u32,
         sync_clock: Option<SharedSyncClock>,
+        hardware_decoding: bool,
     ) -> Result<()> {
         // Initialize FFmpeg (with log level set to suppress warnings)
         crate::media::video::init_ffmpeg()?;
@@ -751,9 +1075,15 @@ impl AsyncDecoder {
         let video_stream_index = input.index();
 
         // Create decoder
-        let context_decoder =
+        let mut context_decoder =
             ffmpeg_next::codec::context::Context::from_parameters(input.parameters())
                 .map_err(|e| Error::Io(format!("Failed to create codec context: {e}")))?;
+        // The device must be attached before the decoder is opened
+        let hw_backend = if hardware_decoding {
+            hwaccel::attach_device(&mut context_decoder)
+        } else {
+            None
+        };
         let mut decoder = context_decoder
             .decoder()
             .video()
@@ -762,17 +1092,8 @@ impl AsyncDecoder {
         let width = decoder.width();
         let height = decoder.height();
 
-        // Setup scaler to convert to RGBA
-        let mut scaler = ffmpeg_next::software::scaling::Context::get(
-            decoder.format(),
-            width,
-            height,
-            ffmpeg_next::format::Pixel::RGBA,
-            width,
-            height,
-            ffmpeg_next::software::scaling::Flags::BILINEAR,
-        )
-        .map_err(|e| Error::Io(format!("Failed to create scaler: {e}")))?;
+        // Converts frames to RGBA (the scaler is created on the first frame)
+        let mut converter = FrameConverter::new(width, height, hw_backend);
 
         // Extract time base for PTS calculation
         let time_base = input.time_base();
@@ -798,6 +1119,11 @@ impl AsyncDecoder {
 
         // Main loop: process commands and decode frames
         loop {
+            // Report the decode path once frames show which one is in use
+            if let Some(path) = converter.take_path_change() {
+                let _ = event_tx.blocking_send(DecoderEvent::DecodePath(path));
+            }
+
             // Process commands (non-blocking)
             match command_rx.try_recv() {
                 Ok(cmd) => {
@@ -806,10 +1132,9 @@ impl AsyncDecoder {
                         &mut state,
                         &mut ictx,
                         &mut decoder,
+                        &mut frame_cache,
                         &mut frame_history,
                         &event_tx,
-                        width,
-                        height,
                     );
                     match result {
                         CommandResult::Break => break,
@@ -838,7 +1163,7 @@ impl AsyncDecoder {
                     &buffered_frame,
                     time_base_f64,
                     &mut state,
-                    &mut scaler,
+                    &mut converter,
                     &mut frame_cache,
                     &mut frame_history,
                     &event_tx,
@@ -846,7 +1171,7 @@ impl AsyncDecoder {
                     width,
                     height,
                 ) {
-                    FrameProcessingResult::Emitted => {
+                    FrameProcessingResult::Emitted | FrameProcessingResult::NothingToShow => {
                         frame_decoded = true;
                         state.decode_single_frame = false;
                     }
@@ -880,7 +1205,7 @@ impl AsyncDecoder {
                         &decoded_frame,
                         time_base_f64,
                         &mut state,
-                        &mut scaler,
+                        &mut converter,
                         &mut frame_cache,
                         &mut frame_history,
                         &event_tx,
@@ -889,7 +1214,7 @@ impl AsyncDecoder {
                         width,
                         height,
                     ) {
-                        PacketDecodeResult::FrameEmitted => {
+                        PacketDecodeResult::FrameEmitted | PacketDecodeResult::NothingToShow => {
                             frame_decoded = true;
                             state.decode_single_frame = false;
                             break 'packet_loop;
@@ -913,7 +1238,7 @@ impl AsyncDecoder {
                     width,
                     height,
                 };
-                let emitted = handle_end_of_stream(last_decoded_for_seek, &mut scaler, &mut ctx);
+                let emitted = handle_end_of_stream(last_decoded_for_seek, &mut converter, &mut ctx);
                 if !emitted {
                     let _ = event_tx.blocking_send(DecoderEvent::EndOfStream);
                 }
@@ -1136,4 +1461,70 @@ mod tests {
         assert_eq!(frame.width, 1920);
         assert_eq!(frame.height, 1080);
     }
+
+    fn shown_frame(pts_secs: f64) -> DecodedFrame {
+        DecodedFrame {
+            rgba_data: Arc::new(vec![0u8; 4]),
+            width: 1,
+            height: 1,
+            pts_secs,
+        }
+    }
+
+    #[test]
+    fn stepping_forward_skips_repeated_timestamps() {
+        let frame = ffmpeg_next::frame::Video::empty();
+        let mut state = DecoderLoopState::new();
+        state.step_search = Some(StepSearch::Next { shown_pts: 1.0 });
+
+        assert!(matches!(
+            match_step(&mut state, &frame, 1.0, false),
+            StepMatch::Skip
+        ));
+        // Variable frame rate: the next frame comes whenever it comes
+        assert!(matches!(
+            match_step(&mut state, &frame, 1.37, false),
+            StepMatch::Show
+        ));
+        assert!(state.step_search.is_none());
+    }
+
+    #[test]
+    fn stepping_backward_finds_the_latest_earlier_frame() {
+        let frame = ffmpeg_next::frame::Video::empty();
+        let mut state = DecoderLoopState::new();
+        state.step_search = Some(StepSearch::Previous {
+            shown: shown_frame(2.0),
+            candidate: None,
+        });
+
+        for pts in [1.0, 1.9, 1.5] {
+            assert!(matches!(
+                match_step(&mut state, &frame, pts, false),
+                StepMatch::Skip
+            ));
+        }
+        match match_step(&mut state, &frame, 2.0, false) {
+            StepMatch::ShowEarlier(_, pts, _) => assert!((pts - 1.9).abs() < f64::EPSILON),
+            _ => panic!("expected the frame before the one shown"),
+        }
+        // The frame shown before goes back into the history after it
+        assert!(state.frame_after_step_back.is_some());
+    }
+
+    #[test]
+    fn stepping_backward_from_the_first_frame_finds_nothing() {
+        let frame = ffmpeg_next::frame::Video::empty();
+        let mut state = DecoderLoopState::new();
+        state.step_search = Some(StepSearch::Previous {
+            shown: shown_frame(0.0),
+            candidate: None,
+        });
+
+        assert!(matches!(
+            match_step(&mut state, &frame, 0.0, true),
+            StepMatch::AtStart
+        ));
+        assert!(state.frame_after_step_back.is_none());
+    }
 }
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Fine scrubbing of the seek slider.
+//!
+//! On a long video, one pixel of the seek slider spans several seconds.
+//! Holding Shift while dragging moves the position ten times slower than the
+//! cursor, snapped to single frames when paused and to tenths of a second
+//! while playing; Shift+arrow keys move by the same step.
+
+/// Seek step of fine scrubbing while playing, in seconds.
+pub const FINE_SEEK_STEP_SECS: f64 = 0.1;
+
+/// How far the position moves for a slider move while Shift is held.
+const FINE_SCRUB_RATIO: f64 = 0.1;
+
+/// Step of fine scrubbing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FineStep {
+    /// One frame, while paused.
+    Frame,
+    /// [`FINE_SEEK_STEP_SECS`], while playing.
+    Tenth,
+}
+
+impl FineStep {
+    /// Returns the step for a video paused or playing.
+    #[must_use]
+    pub fn for_playback(paused: bool) -> Self {
+        if paused {
+            Self::Frame
+        } else {
+            Self::Tenth
+        }
+    }
+
+    /// Returns the step in seconds, one frame lasting `1 / fps` (the average
+    /// frame rate for variable frame rate video).
+    #[must_use]
+    pub fn secs(self, fps: f64) -> f64 {
+        match self {
+            Self::Frame if fps > 0.0 => 1.0 / fps,
+            Self::Frame | Self::Tenth => FINE_SEEK_STEP_SECS,
+        }
+    }
+
+    /// Returns the i18n key of the indicator shown next to the seek slider.
+    #[must_use]
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            Self::Frame => "video-fine-scrub-frame",
+            Self::Tenth => "video-fine-scrub-tenth",
+        }
+    }
+}
+
+/// A seek slider drag with Shift held, anchored where Shift took effect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FineScrub {
+    /// Slider value when the fine drag started.
+    anchor_slider_secs: f64,
+    /// Position when the fine drag started.
+    anchor_position_secs: f64,
+}
+
+impl FineScrub {
+    /// Starts a fine drag with the slider at `slider_secs` and the video at
+    /// `position_secs`.
+    #[must_use]
+    pub fn new(slider_secs: f64, position_secs: f64) -> Self {
+        Self {
+            anchor_slider_secs: slider_secs,
+            anchor_position_secs: position_secs,
+        }
+    }
+
+    /// Returns the position for the slider at `slider_secs`, moved by whole
+    /// steps of `step_secs` and kept within `duration_secs`.
+    #[must_use]
+    pub fn position(&self, slider_secs: f64, step_secs: f64, duration_secs: f64) -> f64 {
+        let delta = (slider_secs - self.anchor_slider_secs) * FINE_SCRUB_RATIO;
+        let steps = if step_secs > 0.0 {
+            (delta / step_secs).round() * step_secs
+        } else {
+            delta
+        };
+        (self.anchor_position_secs + steps).clamp(0.0, duration_secs.max(0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fine_drag_moves_ten_times_slower() {
+        let scrub = FineScrub::new(100.0, 40.0);
+        let position = scrub.position(110.0, FINE_SEEK_STEP_SECS, 600.0);
+        assert!((position - 41.0).abs() < 1e-9);
+        // No move, no jump to the cursor
+        assert!((scrub.position(100.0, FINE_SEEK_STEP_SECS, 600.0) - 40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fine_drag_moves_by_whole_frames() {
+        let scrub = FineScrub::new(10.0, 5.0);
+        let frame = FineStep::Frame.secs(25.0);
+        // 0.3 s of slider is 0.03 s, closest to one frame of 0.04 s
+        let position = scrub.position(10.3, frame, 60.0);
+        assert!((position - 5.04).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fine_drag_stays_within_the_video() {
+        let scrub = FineScrub::new(50.0, 1.0);
+        assert!(scrub.position(0.0, FINE_SEEK_STEP_SECS, 60.0).abs() < f64::EPSILON);
+        let scrub = FineScrub::new(0.0, 59.5);
+        assert!((scrub.position(60.0, FINE_SEEK_STEP_SECS, 60.0) - 60.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn frame_step_falls_back_without_frame_rate() {
+        assert!((FineStep::Frame.secs(0.0) - FINE_SEEK_STEP_SECS).abs() < f64::EPSILON);
+        assert!((FineStep::Tenth.secs(30.0) - FINE_SEEK_STEP_SECS).abs() < f64::EPSILON);
+        assert_eq!(FineStep::for_playback(true), FineStep::Frame);
+    }
+}
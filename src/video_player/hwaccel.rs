@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Hardware-accelerated video decoding.
+//!
+//! `ffmpeg-next` has no safe hwaccel API, so this module sets up a hardware
+//! device on the codec context through the raw `FFmpeg` bindings. Once the
+//! device is attached, `FFmpeg`'s default format negotiation picks the
+//! hardware surface format, and falls back to software by itself when the
+//! hardware decoder can't handle the stream (unsupported profile, driver
+//! failure). Decoded hardware frames are downloaded to system memory before
+//! RGBA conversion.
+
+use ffmpeg_next::ffi;
+
+/// Platform hardware decoding backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HwBackend {
+    /// Video Acceleration API (Linux, BSD).
+    Vaapi,
+    /// Direct3D 11 video acceleration (Windows).
+    D3d11va,
+    /// `DirectX` Video Acceleration 2 (older Windows drivers).
+    Dxva2,
+    /// Apple `VideoToolbox` (macOS).
+    VideoToolbox,
+}
+
+impl HwBackend {
+    /// Returns the backend's display name.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            HwBackend::Vaapi => "VA-API",
+            HwBackend::D3d11va => "D3D11VA",
+            HwBackend::Dxva2 => "DXVA2",
+            HwBackend::VideoToolbox => "VideoToolbox",
+        }
+    }
+
+    fn device_type(self) -> ffi::AVHWDeviceType {
+        match self {
+            HwBackend::Vaapi => ffi::AVHWDeviceType::AV_HWDEVICE_TYPE_VAAPI,
+            HwBackend::D3d11va => ffi::AVHWDeviceType::AV_HWDEVICE_TYPE_D3D11VA,
+            HwBackend::Dxva2 => ffi::AVHWDeviceType::AV_HWDEVICE_TYPE_DXVA2,
+            HwBackend::VideoToolbox => ffi::AVHWDeviceType::AV_HWDEVICE_TYPE_VIDEOTOOLBOX,
+        }
+    }
+}
+
+/// Backends to try on this platform, in order of preference.
+#[must_use]
+pub fn platform_backends() -> &'static [HwBackend] {
+    if cfg!(target_os = "windows") {
+        &[HwBackend::D3d11va, HwBackend::Dxva2]
+    } else if cfg!(target_os = "macos") {
+        &[HwBackend::VideoToolbox]
+    } else {
+        &[HwBackend::Vaapi]
+    }
+}
+
+/// Which path decodes the current video.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodePath {
+    /// Frames are decoded by the GPU through the given backend.
+    Hardware(HwBackend),
+    /// Frames are decoded on the CPU.
+    Software,
+}
+
+/// Attaches the first working hardware device to an unopened codec context.
+///
+/// Returns the attached backend, or `None` when no backend supports the
+/// codec on this machine (the context is then left untouched and decodes
+/// in software).
+pub(crate) fn attach_device(
+    context: &mut ffmpeg_next::codec::context::Context,
+) -> Option<HwBackend> {
+    let codec = ffmpeg_next::codec::decoder::find(context.id())?;
+
+    platform_backends()
+        .iter()
+        .copied()
+        .filter(|backend| codec_supports(&codec, *backend))
+        .find(|backend| {
+            let mut device: *mut ffi::AVBufferRef = std::ptr::null_mut();
+            // SAFETY: `device` is a valid out-pointer; on success FFmpeg hands us
+            // a reference that the codec context takes ownership of and frees
+            // in `avcodec_free_context`.
+            unsafe {
+                let created = ffi::av_hwdevice_ctx_create(
+                    &mut device,
+                    backend.device_type(),
+                    std::ptr::null(),
+                    std::ptr::null_mut(),
+                    0,
+                );
+                if created < 0 || device.is_null() {
+                    return false;
+                }
+                (*context.as_mut_ptr()).hw_device_ctx = device;
+            }
+            true
+        })
+}
+
+/// Returns true if `codec` can decode through a device of `backend`.
+fn codec_supports(codec: &ffmpeg_next::Codec, backend: HwBackend) -> bool {
+    (0..)
+        .map_while(|index| {
+            // SAFETY: FFmpeg returns null past the last configuration.
+            let config = unsafe { ffi::avcodec_get_hw_config(codec.as_ptr(), index) };
+            // SAFETY: non-null configurations are static and valid.
+            (!config.is_null()).then(|| unsafe { &*config })
+        })
+        .any(|config| {
+            config.device_type == backend.device_type()
+                && config.methods.cast_unsigned() & ffi::AV_CODEC_HW_CONFIG_METHOD_HW_DEVICE_CTX
+                    != 0
+        })
+}
+
+/// Returns true if `frame` lives in GPU memory.
+#[must_use]
+pub(crate) fn is_hardware_frame(frame: &ffmpeg_next::frame::Video) -> bool {
+    // SAFETY: reading a field of a valid frame.
+    unsafe { !(*frame.as_ptr()).hw_frames_ctx.is_null() }
+}
+
+/// Copies a hardware frame to system memory, keeping its timestamp and flags.
+///
+/// # Errors
+///
+/// Returns the `FFmpeg` error if the transfer fails.
+pub(crate) fn download_frame(
+    frame: &ffmpeg_next::frame::Video,
+) -> Result<ffmpeg_next::frame::Video, ffmpeg_next::Error> {
+    let mut software = ffmpeg_next::frame::Video::empty();
+    // SAFETY: both frames are valid; FFmpeg allocates the destination buffers.
+    unsafe {
+        let result = ffi::av_hwframe_transfer_data(software.as_mut_ptr(), frame.as_ptr(), 0);
+        if result < 0 {
+            return Err(ffmpeg_next::Error::from(result));
+        }
+        let result = ffi::av_frame_copy_props(software.as_mut_ptr(), frame.as_ptr());
+        if result < 0 {
+            return Err(ffmpeg_next::Error::from(result));
+        }
+    }
+    Ok(software)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_platform_has_a_backend() {
+        assert!(!platform_backends().is_empty());
+        for backend in platform_backends() {
+            assert!(!backend.name().is_empty());
+        }
+    }
+
+    #[test]
+    fn software_frames_are_not_hardware_frames() {
+        let frame = ffmpeg_next::frame::Video::new(ffmpeg_next::format::Pixel::RGBA, 4, 4);
+        assert!(!is_hardware_frame(&frame));
+    }
+}
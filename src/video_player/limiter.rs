@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Peak limiter for boosted audio.
+//!
+//! Volumes above 100% push loud passages past full scale. Clamping each
+//! sample there sounds harsh, so the output stream runs the samples through
+//! this limiter instead: the gain drops at once when a frame would go over
+//! the ceiling and recovers smoothly afterwards.
+
+/// Highest sample level let through, just under full scale.
+const CEILING: f32 = 0.98;
+
+/// Time for the gain to recover most of the way after a peak, in seconds.
+const RELEASE_SECS: f32 = 0.15;
+
+/// Peak limiter over interleaved frames.
+#[derive(Debug, Clone)]
+pub struct Limiter {
+    /// Gain currently applied, 1.0 when not limiting.
+    gain: f32,
+    /// Fraction of the gap to unity gain recovered per frame.
+    release: f32,
+}
+
+impl Limiter {
+    /// Creates a limiter for a stream at `sample_rate` Hz.
+    #[must_use]
+    pub fn new(sample_rate: u32) -> Self {
+        // Sample rates are small integers, exact in f32
+        #[allow(clippy::cast_precision_loss)]
+        let frames = (sample_rate.max(1) as f32) * RELEASE_SECS;
+        Self {
+            gain: 1.0,
+            release: 1.0 - (-1.0 / frames).exp(),
+        }
+    }
+
+    /// Limits one frame of samples in place.
+    pub fn process(&mut self, frame: &mut [f32]) {
+        let peak = frame.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+        let target = if peak > CEILING { CEILING / peak } else { 1.0 };
+        if target < self.gain {
+            // Attack at once so nothing clips
+            self.gain = target;
+        } else {
+            self.gain += (target - self.gain) * self.release;
+        }
+        for sample in frame {
+            *sample = (*sample * self.gain).clamp(-CEILING, CEILING);
+        }
+    }
+
+    /// Returns the gain currently applied.
+    #[must_use]
+    pub fn gain(&self) -> f32 {
+        self.gain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quiet_frames_pass_unchanged() {
+        let mut limiter = Limiter::new(48_000);
+        let mut frame = [0.5, -0.25];
+        limiter.process(&mut frame);
+        assert_eq!(frame, [0.5, -0.25]);
+        assert!((limiter.gain() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn loud_frames_stay_under_the_ceiling() {
+        let mut limiter = Limiter::new(48_000);
+        let mut frame = [3.0, -1.5];
+        limiter.process(&mut frame);
+        assert!(frame.iter().all(|s| s.abs() <= CEILING));
+        // Both channels get the same gain, keeping the stereo image
+        assert!((frame[0] / frame[1] + 2.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn gain_recovers_after_a_peak() {
+        let mut limiter = Limiter::new(48_000);
+        limiter.process(&mut [2.0, 2.0]);
+        let limited = limiter.gain();
+        assert!(limited < 0.5);
+
+        for _ in 0..48_000 {
+            limiter.process(&mut [0.1, 0.1]);
+        }
+        assert!(limiter.gain() > 0.99);
+    }
+}
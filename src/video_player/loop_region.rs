@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MPL-2.0
+//! A–B loop region.
+//!
+//! Either point may be set on its own: a missing loop-in stands for the start
+//! of the video and a missing loop-out for its end. While a region is set,
+//! playback jumps back to its start whenever it reaches the loop-out point.
+
+/// Shortest region accepted, so a loop never degenerates into seeking on
+/// every frame.
+const MIN_REGION_SECS: f64 = 0.1;
+
+/// Loop-in and loop-out points of a video, in seconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LoopRegion {
+    start: Option<f64>,
+    end: Option<f64>,
+}
+
+impl LoopRegion {
+    /// Sets the loop-in point, dropping a loop-out point that isn't after it.
+    pub fn set_start(&mut self, position_secs: f64) {
+        let position_secs = position_secs.max(0.0);
+        if self
+            .end
+            .is_some_and(|end| end - position_secs < MIN_REGION_SECS)
+        {
+            self.end = None;
+        }
+        self.start = Some(position_secs);
+    }
+
+    /// Sets the loop-out point, dropping a loop-in point that isn't before it.
+    pub fn set_end(&mut self, position_secs: f64) {
+        if self
+            .start
+            .is_some_and(|start| position_secs - start < MIN_REGION_SECS)
+        {
+            self.start = None;
+        }
+        self.end = Some(position_secs);
+    }
+
+    /// Removes both points.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns true if at least one point is set.
+    #[must_use]
+    pub fn is_set(&self) -> bool {
+        self.start.is_some() || self.end.is_some()
+    }
+
+    /// Returns the region as `(start, end)` within a video of `duration_secs`,
+    /// or `None` when no usable region is set.
+    #[must_use]
+    pub fn bounds(&self, duration_secs: f64) -> Option<(f64, f64)> {
+        if !self.is_set() {
+            return None;
+        }
+        let start = self.start.unwrap_or(0.0).min(duration_secs);
+        let end = self.end.unwrap_or(duration_secs).min(duration_secs);
+        (end - start >= MIN_REGION_SECS).then_some((start, end))
+    }
+
+    /// Returns where to seek when playback reached `position_secs`: the start
+    /// of the region once its end is passed.
+    #[must_use]
+    pub fn restart_target(&self, position_secs: f64, duration_secs: f64) -> Option<f64> {
+        self.bounds(duration_secs)
+            .filter(|(_, end)| position_secs >= *end)
+            .map(|(start, _)| start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_points_default_to_video_bounds() {
+        let mut region = LoopRegion::default();
+        assert_eq!(region.bounds(60.0), None);
+
+        region.set_start(10.0);
+        assert_eq!(region.bounds(60.0), Some((10.0, 60.0)));
+
+        region.clear();
+        region.set_end(20.0);
+        assert_eq!(region.bounds(60.0), Some((0.0, 20.0)));
+    }
+
+    #[test]
+    fn points_out_of_order_replace_each_other() {
+        let mut region = LoopRegion::default();
+        region.set_start(10.0);
+        region.set_end(20.0);
+        assert_eq!(region.bounds(60.0), Some((10.0, 20.0)));
+
+        // Loop-in after loop-out drops the loop-out point
+        region.set_start(30.0);
+        assert_eq!(region.bounds(60.0), Some((30.0, 60.0)));
+
+        // Loop-out before loop-in drops the loop-in point
+        region.set_end(5.0);
+        assert_eq!(region.bounds(60.0), Some((0.0, 5.0)));
+    }
+
+    #[test]
+    fn restarts_once_the_end_is_reached() {
+        let mut region = LoopRegion::default();
+        region.set_start(10.0);
+        region.set_end(20.0);
+        assert_eq!(region.restart_target(15.0, 60.0), None);
+        assert_eq!(region.restart_target(20.0, 60.0), Some(10.0));
+        assert_eq!(region.restart_target(45.0, 60.0), Some(10.0));
+
+        region.clear();
+        assert_eq!(region.restart_target(45.0, 60.0), None);
+    }
+}
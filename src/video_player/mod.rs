@@ -5,33 +5,51 @@
 //! and async Tokio tasks for non-blocking frame delivery.
 
 pub mod audio;
+pub mod audio_meter;
 pub mod audio_output;
+mod cache_budget;
 mod decoder;
+pub mod fine_scrub;
 pub mod frame_cache;
 mod frame_cache_size;
 mod frame_history_size;
+pub mod hwaccel;
+mod limiter;
+mod loop_region;
 pub mod normalization;
 mod playback_speed;
 mod seek_step;
+pub mod seek_thumbnails;
+mod sequence_decoder;
+mod sequence_fps;
 mod state;
 pub mod subscription;
+pub mod subtitles;
 pub mod sync;
+mod time_stretch;
 pub mod time_units;
 mod volume;
 mod webp_decoder;
 
+pub use audio_meter::{AudioLevel, Waveform};
+pub use cache_budget::{available_memory_bytes, CacheBudget};
 pub use decoder::{AsyncDecoder, DecodedFrame, DecoderCommand, DecoderEvent};
 pub use frame_cache::{CacheConfig, CacheStats, FrameCache};
 pub use frame_cache_size::FrameCacheMb;
 pub use frame_history_size::FrameHistoryMb;
+pub use hwaccel::{DecodePath, HwBackend};
+pub use loop_region::LoopRegion;
 pub use normalization::{
-    create_lufs_cache, LufsAnalyzer, LufsCache, NormalizationSettings, SharedLufsCache,
-    DEFAULT_TARGET_LUFS,
+    create_lufs_cache, preanalyze_loudness, LufsAnalyzer, LufsCache, NormalizationSettings,
+    SharedLufsCache, DEFAULT_TARGET_LUFS,
 };
 pub use playback_speed::PlaybackSpeed;
 pub use seek_step::KeyboardSeekStep;
+pub use sequence_decoder::ImageSequenceDecoder;
+pub use sequence_fps::SequenceFps;
 pub use state::{PlaybackState, VideoPlayer};
 pub use subscription::{video_playback, DecoderCommandSender, PlaybackMessage, VideoPlaybackId};
+pub use subtitles::{SubtitleCue, SubtitleSource, SubtitleTrack};
 pub use sync::{calculate_sync_action, SharedSyncClock, SyncAction, SyncClock};
 pub use volume::Volume;
 pub use webp_decoder::{WebpAnimDecoder, WebpMetadata};
@@ -19,11 +19,24 @@
 //! let measured_lufs = analyzer.analyze_file("video.mp4")?;
 //! let gain = analyzer.calculate_gain(measured_lufs);
 //! ```
-
-use std::collections::HashMap;
-use std::path::Path;
+//!
+//! # Pre-analysis
+//!
+//! Measuring takes a few seconds, so a file analyzed when it starts playing
+//! jumps in loudness once the gain arrives. [`preanalyze_loudness`] measures upcoming
+//! files in the background instead, a few at a time, and the cache keeps the
+//! measurements on disk, keyed by path and modification time.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
 
 use crate::error::{Error, Result};
 
@@ -37,11 +50,35 @@ const MIN_VALID_LUFS: f64 = -70.0;
 /// Maximum gain to apply (to avoid distortion).
 const MAX_GAIN_DB: f64 = 12.0;
 
+/// Files analyzed at the same time in the background.
+const MAX_CONCURRENT_ANALYSES: usize = 2;
+
+/// Background analyses waiting for or holding a slot.
+static ANALYSIS_SLOTS: Semaphore = Semaphore::const_new(MAX_CONCURRENT_ANALYSES);
+
+/// A measurement of one file.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct CachedLufs {
+    /// Integrated loudness, `None` if the file has no measurable audio.
+    lufs: Option<f64>,
+    /// Modification time of the file when measured.
+    modified: Option<SystemTime>,
+}
+
 /// Cache for LUFS measurements to avoid re-analyzing the same file.
+///
+/// Entries are dropped when the file is modified. A cache created with
+/// [`LufsCache::load_from`] is saved back to its file by [`LufsCache::save`].
 #[derive(Debug, Default)]
 pub struct LufsCache {
-    /// Map from file path to measured LUFS value.
-    cache: RwLock<HashMap<String, f64>>,
+    /// Map from file path to its measurement.
+    cache: RwLock<HashMap<String, CachedLufs>>,
+    /// Files being analyzed in the background.
+    pending: RwLock<HashSet<String>>,
+    /// File the cache is saved to, if persistent.
+    file: Option<PathBuf>,
+    /// Held while saving, so concurrent saves don't interleave.
+    save_lock: Mutex<()>,
 }
 
 impl LufsCache {
@@ -51,15 +88,111 @@ impl LufsCache {
         Self::default()
     }
 
+    /// Loads the cache saved in `file`, or starts an empty one saved there.
+    ///
+    /// A missing or unreadable file only loses earlier measurements.
+    #[must_use]
+    pub fn load_from(file: PathBuf) -> Self {
+        let entries = fs::File::open(&file)
+            .ok()
+            .and_then(|reader| ciborium::from_reader(BufReader::new(reader)).ok())
+            .unwrap_or_default();
+        Self {
+            cache: RwLock::new(entries),
+            pending: RwLock::default(),
+            file: Some(file),
+            save_lock: Mutex::default(),
+        }
+    }
+
+    /// Saves the measurements of files that still exist to the cache file.
+    ///
+    /// The background analyses and the playback may save at the same time:
+    /// saves are serialized and written to a temporary file renamed over the
+    /// cache file, so a reader never sees a partly written cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self) -> Result<()> {
+        let Some(file) = &self.file else {
+            return Ok(());
+        };
+        let entries: HashMap<String, CachedLufs> = self
+            .cache
+            .read()
+            .map(|cache| {
+                cache
+                    .iter()
+                    .filter(|(path, _)| Path::new(path).exists())
+                    .map(|(path, entry)| (path.clone(), *entry))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let _saving = self
+            .save_lock
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(parent) = file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let partial = file.with_extension("partial");
+        let writer = BufWriter::new(fs::File::create(&partial)?);
+        ciborium::into_writer(&entries, writer)
+            .map_err(|e| Error::Io(format!("Failed to write LUFS cache: {e}")))?;
+        fs::rename(&partial, file)?;
+        Ok(())
+    }
+
     /// Gets a cached LUFS value for a file path.
+    ///
+    /// Returns `None` if the file was not measured, has no measurable audio,
+    /// or was modified since.
     pub fn get(&self, path: &str) -> Option<f64> {
-        self.cache.read().ok()?.get(path).copied()
+        self.fresh_entry(path)?.lufs
+    }
+
+    /// Returns true if the file was measured since its last modification,
+    /// even if it turned out to have no measurable audio.
+    pub fn contains(&self, path: &str) -> bool {
+        self.fresh_entry(path).is_some()
+    }
+
+    fn fresh_entry(&self, path: &str) -> Option<CachedLufs> {
+        let entry = *self.cache.read().ok()?.get(path)?;
+        (entry.modified == modified_time(path)).then_some(entry)
     }
 
     /// Stores a LUFS value for a file path.
     pub fn insert(&self, path: String, lufs: f64) {
+        self.store(path, Some(lufs));
+    }
+
+    /// Records that a file has no measurable audio, so it isn't analyzed again.
+    pub fn insert_unmeasurable(&self, path: String) {
+        self.store(path, None);
+    }
+
+    fn store(&self, path: String, lufs: Option<f64>) {
+        let modified = modified_time(&path);
         if let Ok(mut cache) = self.cache.write() {
-            cache.insert(path, lufs);
+            cache.insert(path, CachedLufs { lufs, modified });
+        }
+    }
+
+    /// Marks a file as being analyzed. Returns false if it already is, or
+    /// needs no analysis.
+    fn begin(&self, path: &str) -> bool {
+        !self.contains(path)
+            && self
+                .pending
+                .write()
+                .is_ok_and(|mut pending| pending.insert(path.to_string()))
+    }
+
+    fn finish(&self, path: &str) {
+        if let Ok(mut pending) = self.pending.write() {
+            pending.remove(path);
         }
     }
 
@@ -90,6 +223,58 @@ pub fn create_lufs_cache() -> SharedLufsCache {
     Arc::new(LufsCache::new())
 }
 
+fn modified_time(path: impl AsRef<Path>) -> Option<SystemTime> {
+    fs::metadata(path).ok()?.modified().ok()
+}
+
+/// Measures the loudness of `path` in the background unless it is cached or
+/// already being measured, so normalization is right from the first sample
+/// when the file plays.
+///
+/// At most [`MAX_CONCURRENT_ANALYSES`] files are measured at once; the others
+/// wait for a slot. The cache is saved after each measurement.
+pub async fn preanalyze_loudness(cache: SharedLufsCache, path: PathBuf) {
+    let key = path.to_string_lossy().to_string();
+    if !cache.begin(&key) {
+        return;
+    }
+    let Ok(_slot) = ANALYSIS_SLOTS.acquire().await else {
+        cache.finish(&key);
+        return;
+    };
+
+    let measured = {
+        let key = key.clone();
+        let cache = Arc::clone(&cache);
+        tokio::task::spawn_blocking(move || {
+            // Played meanwhile: the playback measured it already
+            if cache.contains(&key) {
+                return;
+            }
+            match LufsAnalyzer::default().measure(&path) {
+                Ok(Some(lufs)) => cache.insert(key, lufs),
+                Ok(None) => {
+                    tracing::debug!("No audio to measure in {}", path.display());
+                    cache.insert_unmeasurable(key);
+                }
+                // Not cached: FFmpeg may be missing or the failure transient
+                Err(e) => {
+                    tracing::debug!("Loudness of {} not measured: {e}", path.display());
+                    return;
+                }
+            }
+            if let Err(e) = cache.save() {
+                tracing::warn!("Failed to save LUFS cache: {e}");
+            }
+        })
+        .await
+    };
+    if let Err(e) = measured {
+        tracing::warn!("Loudness analysis failed: {e}");
+    }
+    cache.finish(&key);
+}
+
 /// LUFS (Loudness Units Full Scale) analyzer for audio normalization.
 ///
 /// Uses `FFmpeg`'s loudnorm filter to measure integrated loudness.
@@ -160,6 +345,25 @@ impl LufsAnalyzer {
         self.parse_loudnorm_output(&stderr)
     }
 
+    /// Measures a media file, returning `None` if it has no audio stream.
+    ///
+    /// Unlike [`Self::analyze_file`], this tells files that can never be
+    /// measured apart from failures that may go away, such as `FFmpeg`
+    /// missing or the file being briefly unreadable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the measurement failed for another reason.
+    pub fn measure<P: AsRef<Path>>(&self, path: P) -> Result<Option<f64>> {
+        match self.analyze_file(&path) {
+            Ok(lufs) => Ok(Some(lufs)),
+            Err(err) => match crate::media::video::extract_video_metadata(&path) {
+                Ok(metadata) if !metadata.has_audio => Ok(None),
+                _ => Err(err),
+            },
+        }
+    }
+
     /// Parses the loudnorm filter's JSON output to extract integrated LUFS.
     fn parse_loudnorm_output(&self, output: &str) -> Result<f64> {
         // Find the JSON block in the output
@@ -304,6 +508,101 @@ mod tests {
         assert_eq!(cache.get("file1.mp4"), None);
     }
 
+    #[test]
+    fn lufs_cache_drops_modified_files() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let video = dir.path().join("video.mp4");
+        fs::write(&video, b"first").expect("write video");
+        let key = video.to_string_lossy().to_string();
+
+        let cache = LufsCache::new();
+        cache.insert(key.clone(), -18.5);
+        assert_eq!(cache.get(&key), Some(-18.5));
+
+        // Rewrite with a clearly different modification time
+        fs::write(&video, b"second").expect("rewrite video");
+        let later = SystemTime::now() + std::time::Duration::from_secs(60);
+        fs::File::options()
+            .write(true)
+            .open(&video)
+            .and_then(|file| file.set_modified(later))
+            .expect("touch video");
+        assert_eq!(cache.get(&key), None);
+        assert!(!cache.contains(&key));
+    }
+
+    #[test]
+    fn lufs_cache_remembers_unmeasurable_files() {
+        let cache = LufsCache::new();
+        cache.insert_unmeasurable("silent.mp4".to_string());
+
+        assert_eq!(cache.get("silent.mp4"), None);
+        assert!(cache.contains("silent.mp4"));
+        // Nothing left to analyze
+        assert!(!cache.begin("silent.mp4"));
+    }
+
+    #[test]
+    fn lufs_cache_analyzes_each_file_once_at_a_time() {
+        let cache = LufsCache::new();
+        assert!(cache.begin("video.mp4"));
+        assert!(!cache.begin("video.mp4"));
+
+        cache.finish("video.mp4");
+        assert!(cache.begin("video.mp4"));
+    }
+
+    #[test]
+    fn lufs_cache_persists_measurements_of_existing_files() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let video = dir.path().join("video.mp4");
+        fs::write(&video, b"video").expect("write video");
+        let key = video.to_string_lossy().to_string();
+        let file = dir.path().join("lufs_cache.cbor");
+
+        let cache = LufsCache::load_from(file.clone());
+        cache.insert(key.clone(), -20.0);
+        cache.insert("/gone/video.mp4".to_string(), -15.0);
+        cache.save().expect("save cache");
+
+        let loaded = LufsCache::load_from(file);
+        assert_eq!(loaded.get(&key), Some(-20.0));
+        assert_eq!(loaded.len(), 1);
+    }
+
+    #[test]
+    fn lufs_cache_survives_concurrent_saves() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let file = dir.path().join("lufs_cache.cbor");
+        let cache = Arc::new(LufsCache::load_from(file.clone()));
+        let keys: Vec<String> = (0..4)
+            .map(|i| {
+                let video = dir.path().join(format!("video{i}.mp4"));
+                fs::write(&video, b"video").expect("write video");
+                video.to_string_lossy().to_string()
+            })
+            .collect();
+
+        let savers: Vec<_> = keys
+            .iter()
+            .map(|key| {
+                let cache = Arc::clone(&cache);
+                let key = key.clone();
+                std::thread::spawn(move || {
+                    cache.insert(key, -16.0);
+                    cache.save().expect("save cache");
+                })
+            })
+            .collect();
+        for saver in savers {
+            saver.join().expect("saver thread");
+        }
+
+        let loaded = LufsCache::load_from(file.clone());
+        assert_eq!(loaded.len(), keys.len());
+        assert!(!file.with_extension("partial").exists());
+    }
+
     #[test]
     fn lufs_analyzer_default_target() {
         let analyzer = LufsAnalyzer::default();
@@ -5,8 +5,8 @@
 //! ensuring they are always within the valid range (0.1x - 8.0x).
 
 use crate::config::{
-    MAX_PLAYBACK_SPEED, MIN_PLAYBACK_SPEED, PLAYBACK_SPEED_AUTO_MUTE_THRESHOLD,
-    PLAYBACK_SPEED_PRESETS,
+    MAX_PLAYBACK_SPEED, MIN_PLAYBACK_SPEED, PLAYBACK_SPEED_AUTO_MUTE_LOW_THRESHOLD,
+    PLAYBACK_SPEED_AUTO_MUTE_THRESHOLD, PLAYBACK_SPEED_PRESETS,
 };
 
 /// Playback speed value, guaranteed to be within valid range (0.1x - 8.0x).
@@ -43,9 +43,13 @@ impl PlaybackSpeed {
     }
 
     /// Returns true if audio should be auto-muted at this speed.
+    ///
+    /// Audio is time-stretched between 0.25x and 3x; outside that range it
+    /// is no longer intelligible.
     #[must_use]
     pub fn should_auto_mute(self) -> bool {
         self.0 > PLAYBACK_SPEED_AUTO_MUTE_THRESHOLD
+            || self.0 < PLAYBACK_SPEED_AUTO_MUTE_LOW_THRESHOLD - 0.001
     }
 
     /// Returns the next higher preset speed, or self if at maximum.
@@ -107,11 +111,13 @@ mod tests {
     }
 
     #[test]
-    fn should_auto_mute_above_threshold() {
+    fn should_auto_mute_outside_stretch_range() {
         assert!(!PlaybackSpeed::new(1.0).should_auto_mute());
-        assert!(!PlaybackSpeed::new(2.0).should_auto_mute());
-        assert!(PlaybackSpeed::new(2.5).should_auto_mute());
+        assert!(!PlaybackSpeed::new(1.5).should_auto_mute());
+        assert!(!PlaybackSpeed::new(3.0).should_auto_mute());
+        assert!(!PlaybackSpeed::new(0.25).should_auto_mute());
         assert!(PlaybackSpeed::new(4.0).should_auto_mute());
+        assert!(PlaybackSpeed::new(0.2).should_auto_mute());
     }
 
     #[test]
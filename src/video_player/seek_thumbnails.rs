@@ -0,0 +1,223 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Low-resolution frames for the seek bar hover preview.
+//!
+//! Thumbnails are decoded one at a time in the background, independently of
+//! the playback decoder, and kept in a small LRU keyed on whole seconds so
+//! hovering back and forth over the timeline doesn't decode twice.
+
+use crate::error::{Error, Result};
+use crate::media::ImageData;
+use iced::widget::image;
+use lru::LruCache;
+use std::num::NonZeroUsize;
+use std::path::Path;
+
+/// Width of preview thumbnails in pixels (height follows the aspect ratio).
+pub const THUMBNAIL_WIDTH: u32 = 160;
+
+/// Number of thumbnails kept per video.
+const CACHE_CAPACITY: NonZeroUsize = NonZeroUsize::new(64).expect("capacity is non-zero");
+
+/// Frames decoded after the seek keyframe before settling for the last one,
+/// bounding the cost of files with long keyframe intervals.
+const MAX_DECODED_FRAMES: usize = 48;
+
+/// Thumbnail cache and request bookkeeping for the current video.
+#[derive(Debug)]
+pub struct SeekThumbnails {
+    cache: LruCache<u64, image::Handle>,
+    /// Second whose thumbnail is being decoded; only one request runs at a time.
+    pending: Option<u64>,
+}
+
+impl Default for SeekThumbnails {
+    fn default() -> Self {
+        Self {
+            cache: LruCache::new(CACHE_CAPACITY),
+            pending: None,
+        }
+    }
+}
+
+impl SeekThumbnails {
+    /// Returns the cached thumbnail of `second`.
+    #[must_use]
+    pub fn get(&self, second: u64) -> Option<&image::Handle> {
+        self.cache.peek(&second)
+    }
+
+    /// Marks `second` as being decoded if it isn't cached and no other
+    /// request is running. Returns true if the caller should start decoding.
+    pub fn request(&mut self, second: u64) -> bool {
+        if self.pending.is_some() || self.cache.contains(&second) {
+            return false;
+        }
+        self.pending = Some(second);
+        true
+    }
+
+    /// Stores the result of the running request (`None` when decoding failed).
+    pub fn finish(&mut self, second: u64, thumbnail: Option<image::Handle>) {
+        if self.pending == Some(second) {
+            self.pending = None;
+        }
+        if let Some(handle) = thumbnail {
+            self.cache.put(second, handle);
+        }
+    }
+
+    /// Forgets all thumbnails (called when the video changes).
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.pending = None;
+    }
+}
+
+/// Returns the cache key of a timeline position.
+#[must_use]
+pub fn second_of(position_secs: f64) -> u64 {
+    // Negative positions clamp to zero; videos are far shorter than u64::MAX seconds
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let second = position_secs.max(0.0).floor() as u64;
+    second
+}
+
+/// Decodes a `THUMBNAIL_WIDTH`-wide frame of the video at `second`.
+///
+/// # Errors
+///
+/// Returns an error if the video can't be opened, seeked or decoded.
+#[allow(clippy::similar_names)] // decoder vs decoded
+pub fn decode_thumbnail(path: &Path, second: u64) -> Result<ImageData> {
+    crate::media::video::init_ffmpeg()?;
+
+    let mut ictx = ffmpeg_next::format::input(&path)
+        .map_err(|e| Error::Io(format!("Failed to open video file: {e}")))?;
+    let input = ictx
+        .streams()
+        .best(ffmpeg_next::media::Type::Video)
+        .ok_or_else(|| Error::Io("No video stream found".to_string()))?;
+    let stream_index = input.index();
+    let time_base = input.time_base();
+    let time_base = f64::from(time_base.numerator()) / f64::from(time_base.denominator());
+
+    let context = ffmpeg_next::codec::context::Context::from_parameters(input.parameters())
+        .map_err(|e| Error::Io(format!("Failed to create codec context: {e}")))?;
+    let mut decoder = context
+        .decoder()
+        .video()
+        .map_err(|e| Error::Io(format!("Failed to create video decoder: {e}")))?;
+    let (width, height) = (decoder.width(), decoder.height());
+    if width == 0 || height == 0 {
+        return Err(Error::Io(format!(
+            "Invalid video dimensions: {width}x{height}"
+        )));
+    }
+    let (thumb_width, thumb_height) = thumbnail_size(width, height);
+
+    let target_secs = i64::try_from(second).unwrap_or(i64::MAX);
+    let timestamp = target_secs.saturating_mul(1_000_000);
+    ictx.seek(timestamp, ..timestamp)
+        .map_err(|e| Error::Io(format!("Failed to seek: {e}")))?;
+
+    let mut scaler = ffmpeg_next::software::scaling::Context::get(
+        decoder.format(),
+        width,
+        height,
+        ffmpeg_next::format::Pixel::RGBA,
+        thumb_width,
+        thumb_height,
+        ffmpeg_next::software::scaling::Flags::FAST_BILINEAR,
+    )
+    .map_err(|e| Error::Io(format!("Failed to create scaler: {e}")))?;
+
+    // Decode from the keyframe towards the target, keeping the latest frame
+    #[allow(clippy::cast_precision_loss)] // whole seconds, far below 2^52
+    let target = second as f64;
+    let mut rgba_frame = ffmpeg_next::frame::Video::empty();
+    let mut decoded_frames = 0;
+    'packets: for (stream, packet) in ictx.packets() {
+        if stream.index() != stream_index || decoder.send_packet(&packet).is_err() {
+            continue;
+        }
+        let mut decoded = ffmpeg_next::frame::Video::empty();
+        while decoder.receive_frame(&mut decoded).is_ok() {
+            scaler
+                .run(&decoded, &mut rgba_frame)
+                .map_err(|e| Error::Io(format!("Failed to scale frame: {e}")))?;
+            decoded_frames += 1;
+            #[allow(clippy::cast_precision_loss)] // PTS values fit f64 in practice
+            let pts_secs = decoded.timestamp().unwrap_or(0) as f64 * time_base;
+            if pts_secs >= target || decoded_frames >= MAX_DECODED_FRAMES {
+                break 'packets;
+            }
+        }
+    }
+
+    if rgba_frame.data(0).is_empty() {
+        return Err(Error::Io("Could not decode a frame".to_string()));
+    }
+
+    let data = rgba_frame.data(0);
+    let stride = rgba_frame.stride(0);
+    let row_bytes = thumb_width as usize * 4;
+    let mut rgba = Vec::with_capacity(row_bytes * thumb_height as usize);
+    for y in 0..thumb_height as usize {
+        let start = y * stride;
+        rgba.extend_from_slice(&data[start..start + row_bytes]);
+    }
+    Ok(ImageData::from_rgba(thumb_width, thumb_height, rgba))
+}
+
+/// Returns the thumbnail size for a `width`×`height` video, keeping the
+/// aspect ratio and never upscaling.
+fn thumbnail_size(width: u32, height: u32) -> (u32, u32) {
+    if width <= THUMBNAIL_WIDTH {
+        return (width, height);
+    }
+    let scaled_height = u64::from(height) * u64::from(THUMBNAIL_WIDTH) / u64::from(width);
+    // scaled_height <= height, so it fits u32
+    let scaled_height = u32::try_from(scaled_height).unwrap_or(height).max(1);
+    (THUMBNAIL_WIDTH, scaled_height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thumbnails_keep_aspect_ratio() {
+        assert_eq!(thumbnail_size(1920, 1080), (160, 90));
+        assert_eq!(thumbnail_size(100, 50), (100, 50));
+        assert_eq!(thumbnail_size(4000, 1), (160, 1));
+    }
+
+    #[test]
+    fn one_request_at_a_time() {
+        let mut thumbnails = SeekThumbnails::default();
+        assert!(thumbnails.request(5));
+        assert!(!thumbnails.request(6));
+
+        let handle = image::Handle::from_rgba(1, 1, vec![0; 4]);
+        thumbnails.finish(5, Some(handle));
+        assert!(thumbnails.get(5).is_some());
+        // Cached seconds are not requested again
+        assert!(!thumbnails.request(5));
+        assert!(thumbnails.request(6));
+    }
+
+    #[test]
+    fn failed_requests_free_the_slot() {
+        let mut thumbnails = SeekThumbnails::default();
+        assert!(thumbnails.request(3));
+        thumbnails.finish(3, None);
+        assert!(thumbnails.get(3).is_none());
+        assert!(thumbnails.request(4));
+    }
+
+    #[test]
+    fn second_of_floors_and_clamps() {
+        assert_eq!(second_of(12.9), 12);
+        assert_eq!(second_of(-1.0), 0);
+    }
+}
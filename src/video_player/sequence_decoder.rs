@@ -0,0 +1,293 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Image sequence decoder for playing numbered stills as video.
+//!
+//! Frames are decoded on demand from their individual files and paced at the
+//! sequence's fixed frame rate. The decoder speaks the same command/event
+//! protocol as `AsyncDecoder`, so the regular playback controls, frame
+//! stepping, and frame capture work unchanged.
+
+use super::decoder::{DecodedFrame, DecoderCommand, DecoderEvent};
+use crate::error::{Error, Result};
+use crate::media::ImageSequence;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Async image sequence decoder that runs in a blocking Tokio task.
+#[derive(Debug)]
+pub struct ImageSequenceDecoder {
+    /// Channel for sending commands to the decoder task.
+    command_tx: mpsc::UnboundedSender<DecoderCommand>,
+
+    /// Channel for receiving events from the decoder task.
+    event_rx: mpsc::Receiver<DecoderEvent>,
+}
+
+impl ImageSequenceDecoder {
+    /// Creates a new decoder for the given image sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sequence has no frames.
+    pub fn new(sequence: ImageSequence) -> Result<Self> {
+        if sequence.is_empty() {
+            return Err(Error::Io("Image sequence has no frames".to_string()));
+        }
+
+        let (command_tx, command_rx) = mpsc::unbounded_channel();
+        let (event_tx, event_rx) = mpsc::channel(2);
+
+        // Image decoding is CPU-bound, so run the loop on a blocking thread
+        tokio::task::spawn_blocking(move || {
+            if let Err(e) = Self::decoder_loop_blocking(&sequence, command_rx, &event_tx) {
+                let _ = event_tx.blocking_send(DecoderEvent::Error(e.to_string()));
+            }
+        });
+
+        Ok(Self {
+            command_tx,
+            event_rx,
+        })
+    }
+
+    /// Sends a command to the decoder task.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the decoder task is not running.
+    pub fn send_command(&self, command: DecoderCommand) -> Result<()> {
+        self.command_tx
+            .send(command)
+            .map_err(|_| Error::Io("Image sequence decoder task is not running".into()))
+    }
+
+    /// Receives the next event from the decoder.
+    pub async fn recv_event(&mut self) -> Option<DecoderEvent> {
+        self.event_rx.recv().await
+    }
+
+    /// Decodes a single frame file to RGBA.
+    fn decode_frame(sequence: &ImageSequence, index: usize) -> Result<DecodedFrame> {
+        let path = &sequence.frames()[index];
+        let image = image_rs::open(path)
+            .map_err(|e| Error::Io(format!("Failed to decode {}: {e}", path.display())))?
+            .to_rgba8();
+        let (width, height) = image.dimensions();
+
+        // Allow cast_precision_loss: frame indices never approach 2^52.
+        #[allow(clippy::cast_precision_loss)]
+        let pts_secs = index as f64 * sequence.fps().frame_duration_secs();
+
+        Ok(DecodedFrame {
+            rgba_data: Arc::new(image.into_raw()),
+            width,
+            height,
+            pts_secs,
+        })
+    }
+
+    /// Main decoder loop running in a blocking thread.
+    // Allow too_many_lines: command handling plus frame pacing, kept in one
+    // place to mirror the WebP decoder loop.
+    #[allow(clippy::too_many_lines)]
+    fn decoder_loop_blocking(
+        sequence: &ImageSequence,
+        mut command_rx: mpsc::UnboundedReceiver<DecoderCommand>,
+        event_tx: &mpsc::Sender<DecoderEvent>,
+    ) -> Result<()> {
+        let frame_count = sequence.len();
+        let frame_duration_secs = sequence.fps().frame_duration_secs();
+
+        let mut is_playing = false;
+        let mut current_frame_idx = 0usize;
+        // Last frame sent, which stepping is relative to once paused
+        let mut displayed_frame_idx = 0usize;
+        // Frame index and instant that pacing is measured from
+        let mut pacing_origin: Option<(usize, std::time::Instant)> = None;
+        let mut decode_single_frame = false;
+        let mut playback_speed: f64 = 1.0;
+
+        loop {
+            match command_rx.try_recv() {
+                Ok(DecoderCommand::Play {
+                    resume_position_secs,
+                }) => {
+                    if let Some(position) = resume_position_secs {
+                        current_frame_idx = sequence.frame_index_at(position);
+                    }
+                    if current_frame_idx >= frame_count {
+                        current_frame_idx = 0;
+                    }
+                    is_playing = true;
+                    pacing_origin = Some((current_frame_idx, std::time::Instant::now()));
+                    let _ = event_tx.blocking_send(DecoderEvent::Buffering);
+                }
+                Ok(DecoderCommand::Pause) => {
+                    is_playing = false;
+                    pacing_origin = None;
+                    current_frame_idx = displayed_frame_idx;
+                }
+                Ok(DecoderCommand::Seek { target_secs }) => {
+                    current_frame_idx = sequence.frame_index_at(target_secs);
+                    if is_playing {
+                        pacing_origin = Some((current_frame_idx, std::time::Instant::now()));
+                    } else {
+                        decode_single_frame = true;
+                    }
+                }
+                Ok(DecoderCommand::StepFrame) => {
+                    if !is_playing && current_frame_idx + 1 < frame_count {
+                        current_frame_idx += 1;
+                        decode_single_frame = true;
+                    }
+                }
+                Ok(DecoderCommand::StepBackward) => {
+                    // Every frame is an independent file, so stepping back is random access
+                    if !is_playing && current_frame_idx > 0 {
+                        current_frame_idx -= 1;
+                        decode_single_frame = true;
+                    }
+                }
+                Ok(DecoderCommand::Stop) | Err(mpsc::error::TryRecvError::Disconnected) => {
+                    break;
+                }
+                Ok(DecoderCommand::SetPlaybackSpeed {
+                    speed,
+                    instant,
+                    reference_pts: _,
+                }) => {
+                    playback_speed = speed.value();
+                    if is_playing {
+                        pacing_origin = Some((current_frame_idx, instant));
+                    }
+                }
+                Err(mpsc::error::TryRecvError::Empty) => {}
+            }
+
+            if !is_playing && !decode_single_frame {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+                continue;
+            }
+
+            if current_frame_idx >= frame_count {
+                // Looping is driven by the player on EndOfStream
+                let _ = event_tx.blocking_send(DecoderEvent::EndOfStream);
+                is_playing = false;
+                pacing_origin = None;
+                current_frame_idx = displayed_frame_idx;
+                continue;
+            }
+
+            // Frame pacing relative to where playback (re)started
+            if let (true, Some((origin_idx, origin_time))) = (is_playing, pacing_origin) {
+                // Allow cast_precision_loss: frame offsets never approach 2^52.
+                #[allow(clippy::cast_precision_loss)]
+                let offset_secs = current_frame_idx.saturating_sub(origin_idx) as f64
+                    * frame_duration_secs
+                    / playback_speed;
+                let target_time = origin_time + std::time::Duration::from_secs_f64(offset_secs);
+                let now = std::time::Instant::now();
+                if target_time > now {
+                    std::thread::sleep(target_time - now);
+                }
+            }
+
+            let decoded = Self::decode_frame(sequence, current_frame_idx)?;
+            if event_tx
+                .blocking_send(DecoderEvent::FrameReady(decoded))
+                .is_err()
+            {
+                break;
+            }
+            displayed_frame_idx = current_frame_idx;
+
+            if is_playing {
+                current_frame_idx += 1;
+            }
+            decode_single_frame = false;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::video_player::SequenceFps;
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use tempfile::tempdir;
+
+    fn write_sequence(dir: &std::path::Path, count: usize) -> ImageSequence {
+        let frames: Vec<PathBuf> = (1..=count)
+            .map(|i| {
+                let path = dir.join(format!("frame_{i:04}.png"));
+                image_rs::RgbaImage::from_pixel(4, 2, image_rs::Rgba([0, 0, 0, 255]))
+                    .save(&path)
+                    .expect("write frame");
+                path
+            })
+            .collect();
+        ImageSequence::new(frames, SequenceFps::new(30))
+    }
+
+    #[tokio::test]
+    async fn decoder_fails_for_empty_sequence() {
+        let sequence = ImageSequence::new(Vec::new(), SequenceFps::default());
+        assert!(ImageSequenceDecoder::new(sequence).is_err());
+    }
+
+    #[tokio::test]
+    async fn seek_while_paused_sends_requested_frame() {
+        let dir = tempdir().expect("temp dir");
+        let sequence = write_sequence(dir.path(), 10);
+        let mut decoder = ImageSequenceDecoder::new(sequence).expect("decoder");
+
+        decoder
+            .send_command(DecoderCommand::Seek { target_secs: 0.2 })
+            .unwrap();
+
+        let event = tokio::time::timeout(Duration::from_secs(2), decoder.recv_event())
+            .await
+            .expect("timeout waiting for frame");
+        match event {
+            Some(DecoderEvent::FrameReady(frame)) => {
+                assert_eq!((frame.width, frame.height), (4, 2));
+                // 0.2s at 30 fps is frame 6
+                assert!((frame.pts_secs - 0.2).abs() < 1e-9);
+            }
+            other => panic!("Expected FrameReady, got: {other:?}"),
+        }
+
+        decoder.send_command(DecoderCommand::Stop).unwrap();
+    }
+
+    #[tokio::test]
+    async fn playback_reaches_end_of_stream() {
+        let dir = tempdir().expect("temp dir");
+        let sequence = write_sequence(dir.path(), 3);
+        let mut decoder = ImageSequenceDecoder::new(sequence).expect("decoder");
+
+        decoder
+            .send_command(DecoderCommand::Play {
+                resume_position_secs: None,
+            })
+            .unwrap();
+
+        let mut frames = 0;
+        loop {
+            let event = tokio::time::timeout(Duration::from_secs(2), decoder.recv_event())
+                .await
+                .expect("timeout waiting for event");
+            match event {
+                Some(DecoderEvent::FrameReady(_)) => frames += 1,
+                Some(DecoderEvent::Buffering) => {}
+                Some(DecoderEvent::EndOfStream) => break,
+                other => panic!("Unexpected event: {other:?}"),
+            }
+        }
+        assert_eq!(frames, 3);
+
+        decoder.send_command(DecoderCommand::Stop).unwrap();
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Image sequence frame rate domain type.
+//!
+//! This module provides a type-safe wrapper for the frame rate used when
+//! playing a numbered image sequence as video.
+
+use crate::config::{DEFAULT_SEQUENCE_FPS, MAX_SEQUENCE_FPS, MIN_SEQUENCE_FPS};
+
+/// Frame rate in frames per second for image sequence playback.
+///
+/// This newtype enforces validity at the type level, ensuring the value
+/// is always within the valid range (1–120 fps).
+///
+/// # Example
+///
+/// ```
+/// use iced_lens::video_player::SequenceFps;
+///
+/// let fps = SequenceFps::new(30);
+/// assert_eq!(fps.value(), 30);
+///
+/// // Values outside range are clamped
+/// let too_high = SequenceFps::new(500);
+/// assert_eq!(too_high.value(), 120); // Clamped to max
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceFps(u32);
+
+impl SequenceFps {
+    /// Creates a new sequence frame rate, clamping to valid range.
+    #[must_use]
+    pub fn new(value: u32) -> Self {
+        Self(value.clamp(MIN_SEQUENCE_FPS, MAX_SEQUENCE_FPS))
+    }
+
+    /// Returns the value as u32.
+    #[must_use]
+    pub fn value(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the duration of a single frame in seconds.
+    #[must_use]
+    pub fn frame_duration_secs(self) -> f64 {
+        1.0 / f64::from(self.0)
+    }
+}
+
+impl Default for SequenceFps {
+    fn default() -> Self {
+        Self(DEFAULT_SEQUENCE_FPS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_clamps_to_valid_range() {
+        assert_eq!(SequenceFps::new(0).value(), MIN_SEQUENCE_FPS);
+        assert_eq!(SequenceFps::new(1000).value(), MAX_SEQUENCE_FPS);
+    }
+
+    #[test]
+    fn new_accepts_valid_values() {
+        assert_eq!(SequenceFps::new(1).value(), 1);
+        assert_eq!(SequenceFps::new(25).value(), 25);
+        assert_eq!(SequenceFps::new(120).value(), 120);
+    }
+
+    #[test]
+    fn default_returns_expected_value() {
+        assert_eq!(SequenceFps::default().value(), DEFAULT_SEQUENCE_FPS);
+    }
+
+    #[test]
+    fn frame_duration_is_inverse_of_fps() {
+        let fps = SequenceFps::new(25);
+        assert!((fps.frame_duration_secs() - 0.04).abs() < f64::EPSILON);
+    }
+}
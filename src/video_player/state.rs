@@ -145,6 +145,9 @@ pub struct VideoPlayer {
     /// Whether the video should loop when it reaches the end.
     loop_enabled: bool,
 
+    /// A–B loop points; while set, playback loops within them.
+    loop_region: super::LoopRegion,
+
     /// Command sender to control the decoder (provided by subscription).
     command_sender: Option<DecoderCommandSender>,
 
@@ -158,6 +161,11 @@ pub struct VideoPlayer {
     /// decremented on `step_backward`.
     history_position: usize,
 
+    /// Whether the decoder reported no frame before the one shown.
+    /// Set when `HistoryExhausted` is received, reset when the frame changes
+    /// by any other means than stepping backward.
+    at_first_frame: bool,
+
     /// Whether we've reached the end of the video stream.
     /// Set to true when `EndOfStream` is received, reset to false on seek/play.
     at_end_of_stream: bool,
@@ -186,9 +194,11 @@ impl VideoPlayer {
             state: PlaybackState::Stopped,
             video_data: video_data.clone(),
             loop_enabled: false,
+            loop_region: super::LoopRegion::default(),
             command_sender: None,
             sync_clock: Arc::new(SyncClock::new()),
             history_position: 0,
+            at_first_frame: false,
             at_end_of_stream: false,
             playback_speed: super::PlaybackSpeed::default(),
             speed_auto_muted: false,
@@ -231,6 +241,48 @@ impl VideoPlayer {
         self.loop_enabled = enabled;
     }
 
+    /// Returns the A–B loop region as `(start, end)` in seconds, if set.
+    pub fn loop_region(&self) -> Option<(f64, f64)> {
+        self.loop_region.bounds(self.video_data.duration_secs)
+    }
+
+    /// Sets the loop-in point to the current position.
+    pub fn set_loop_in(&mut self) {
+        let position = self.state.position().unwrap_or(0.0);
+        self.loop_region.set_start(position);
+    }
+
+    /// Sets the loop-out point to the current position.
+    pub fn set_loop_out(&mut self) {
+        let position = self.state.position().unwrap_or(0.0);
+        self.loop_region.set_end(position);
+    }
+
+    /// Removes the A–B loop region, returning to normal loop behavior.
+    pub fn clear_loop_region(&mut self) {
+        self.loop_region.clear();
+    }
+
+    /// Seeks back to the loop-in point if playback passed the loop-out point.
+    ///
+    /// Returns true if a seek was issued. Only applies while playing, so
+    /// frames still in flight during a seek don't trigger another one.
+    pub fn enforce_loop_region(&mut self) -> bool {
+        let PlaybackState::Playing { position_secs } = self.state else {
+            return false;
+        };
+        match self
+            .loop_region
+            .restart_target(position_secs, self.video_data.duration_secs)
+        {
+            Some(start) => {
+                self.seek(start);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Returns whether the player is in stepping mode.
     ///
     /// Stepping mode is entered when `step_frame()` is called, and exited
@@ -241,10 +293,11 @@ impl VideoPlayer {
 
     /// Returns whether backward stepping is available.
     ///
-    /// Backward stepping is available after at least 1 step forward,
-    /// because the initial frame (shown before stepping) is also added to history.
+    /// Recently stepped frames come from the decoder's history; earlier ones
+    /// are found by decoding again from the keyframe before them, so stepping
+    /// back is available anywhere but at the first frame.
     pub fn can_step_backward(&self) -> bool {
-        self.history_position >= 1
+        !self.at_first_frame && self.state.position().is_some_and(|pos| pos > 0.0)
     }
 
     /// Returns the current history position for frame stepping.
@@ -275,9 +328,10 @@ impl VideoPlayer {
     /// Resets the history position to indicate no backward stepping is available.
     ///
     /// Called when `HistoryExhausted` event is received from the decoder,
-    /// indicating that the frame history buffer has been exhausted.
+    /// indicating that no frame precedes the one shown.
     pub fn reset_history_position(&mut self) {
         self.history_position = 0;
+        self.at_first_frame = true;
     }
 
     /// Starts or resumes playback.
@@ -316,6 +370,7 @@ impl VideoPlayer {
 
         // Exit stepping mode - reset history position
         self.history_position = 0;
+        self.at_first_frame = false;
 
         // Clear end-of-stream flag since we're resuming playback
         self.at_end_of_stream = false;
@@ -372,6 +427,7 @@ impl VideoPlayer {
 
         // Exit stepping mode - reset history position
         self.history_position = 0;
+        self.at_first_frame = false;
 
         // Stop sync clock
         self.sync_clock.stop();
@@ -411,6 +467,7 @@ impl VideoPlayer {
 
         // Exit stepping mode - seek breaks frame continuity
         self.history_position = 0;
+        self.at_first_frame = false;
 
         // Clear end-of-stream flag since we're seeking to a new position
         self.at_end_of_stream = false;
@@ -454,6 +511,7 @@ impl VideoPlayer {
 
         // Exit stepping mode - seek breaks frame continuity
         self.history_position = 0;
+        self.at_first_frame = false;
 
         // Clear end-of-stream flag since we're seeking to a new position
         self.at_end_of_stream = false;
@@ -581,7 +639,7 @@ impl VideoPlayer {
         self.playback_speed.value()
     }
 
-    /// Returns true if audio is auto-muted due to high playback speed.
+    /// Returns true if audio is auto-muted because of the playback speed.
     pub fn is_speed_auto_muted(&self) -> bool {
         self.speed_auto_muted
     }
@@ -589,8 +647,9 @@ impl VideoPlayer {
     /// Sets the playback speed.
     ///
     /// Sends `SetPlaybackSpeed` command to both video and audio decoders.
-    /// Auto-mutes audio when speed > 2.0x (audio becomes unintelligible).
-    fn set_playback_speed(&mut self, speed: super::PlaybackSpeed) {
+    /// Audio is time-stretched to keep its pitch, and auto-muted outside
+    /// 0.25x–3x where it becomes unintelligible.
+    pub fn set_playback_speed(&mut self, speed: super::PlaybackSpeed) {
         self.playback_speed = speed;
         self.speed_auto_muted = speed.should_auto_mute();
 
@@ -632,6 +691,14 @@ impl VideoPlayer {
             .is_some_and(super::subscription::DecoderCommandSender::has_audio)
     }
 
+    /// Returns the level of the audio being played (silence when paused).
+    pub fn audio_level(&self) -> super::AudioLevel {
+        self.command_sender
+            .as_ref()
+            .map(super::subscription::DecoderCommandSender::audio_level)
+            .unwrap_or_default()
+    }
+
     /// Steps forward one frame by decoding the next frame sequentially.
     ///
     /// This sends a `StepFrame` command to the decoder, which decodes the next
@@ -650,6 +717,7 @@ impl VideoPlayer {
 
         // Increment history position - enables backward stepping after 2+ steps
         self.history_position += 1;
+        self.at_first_frame = false;
 
         // Send StepFrame command to decoder
         if let Some(sender) = &self.command_sender {
@@ -657,28 +725,27 @@ impl VideoPlayer {
         }
     }
 
-    /// Steps backward one frame by retrieving from frame history.
+    /// Steps backward one frame.
     ///
     /// This sends a `StepBackward` command to the decoder, which retrieves the
-    /// previous frame from the frame history buffer. Frame history is only
-    /// populated during stepping mode to save memory.
-    /// Decrements history position (minimum 0 - can't go before initial frame).
+    /// previous frame from the frame history buffer, or past the history,
+    /// decodes again from the keyframe before the frame shown and returns the
+    /// frame preceding it by timestamp. This keeps stepping frame-accurate on
+    /// variable frame rate video.
+    /// Decrements history position (minimum 0).
     pub fn step_backward(&mut self) {
-        if !self.state.is_paused() {
+        if !self.state.is_paused() || self.at_first_frame {
             return;
         }
 
-        // Only step backward if we have history to go back to
-        if self.history_position >= 1 {
-            self.history_position -= 1;
+        self.history_position = self.history_position.saturating_sub(1);
 
-            // Clear end-of-stream flag since we're stepping back from the end
-            self.at_end_of_stream = false;
+        // Clear end-of-stream flag since we're stepping back from the end
+        self.at_end_of_stream = false;
 
-            // Send StepBackward command to decoder
-            if let Some(sender) = &self.command_sender {
-                let _ = sender.send(DecoderCommand::StepBackward);
-            }
+        // Send StepBackward command to decoder
+        if let Some(sender) = &self.command_sender {
+            let _ = sender.send(DecoderCommand::StepBackward);
         }
     }
 }
@@ -697,6 +764,11 @@ mod tests {
             duration_secs: 120.0,
             fps: 30.0,
             has_audio: true,
+            sequence: None,
+            chapters: Vec::new(),
+            animated_image: false,
+            rotation: 0,
+            hdr: None,
         }
     }
 
@@ -805,6 +877,41 @@ mod tests {
         assert!(!player.is_loop_enabled());
     }
 
+    #[test]
+    fn loop_region_seeks_back_when_the_end_is_passed() {
+        let video = sample_video_data();
+        let mut player = VideoPlayer::new(&video).unwrap();
+
+        player.play();
+        player.update_position(10.0);
+        player.set_loop_in();
+        player.update_position(20.0);
+        player.set_loop_out();
+        assert_eq!(player.loop_region(), Some((10.0, 20.0)));
+
+        player.update_position(15.0);
+        assert!(!player.enforce_loop_region());
+
+        player.update_position(20.5);
+        assert!(player.enforce_loop_region());
+        assert!(matches!(
+            player.state(),
+            PlaybackState::Seeking {
+                resume_playing: true,
+                ..
+            }
+        ));
+        assert_eq!(player.state().position(), Some(10.0));
+
+        // Frames arriving mid-seek don't trigger another seek
+        assert!(!player.enforce_loop_region());
+
+        player.clear_loop_region();
+        assert_eq!(player.loop_region(), None);
+        player.update_position(30.0);
+        assert!(!player.enforce_loop_region());
+    }
+
     #[test]
     fn error_state_clears_position() {
         let video = sample_video_data();
@@ -1592,4 +1699,23 @@ mod tests {
         player.step_backward();
         assert_eq!(player.history_position(), 0);
     }
+
+    #[test]
+    fn step_backward_stops_at_the_first_frame() {
+        let video = sample_video_data();
+        let mut player = VideoPlayer::new(&video).unwrap();
+        player.seek(10.0);
+        player.pause_at(10.0);
+
+        // Earlier frames are decoded again, no forward steps needed
+        assert!(player.can_step_backward());
+
+        // The decoder found nothing before the frame shown
+        player.reset_history_position();
+        assert!(!player.can_step_backward());
+
+        // Stepping forward leaves the first frame
+        player.step_frame();
+        assert!(player.can_step_backward());
+    }
 }
@@ -8,21 +8,31 @@
 //! - `AudioDecoder` extracts audio samples from the video file
 //! - `AudioOutput` plays the samples through the system audio device
 //! - Synchronization uses audio as the master clock
+//!
+//! The output device is checked periodically so playback follows the device
+//! chosen in the settings and survives the device being unplugged.
 
 use super::audio::{AudioDecoder, AudioDecoderCommand, AudioDecoderEvent};
+use super::audio_meter::{AudioLevel, AudioLevelMeter};
 use super::audio_output::{AudioOutput, AudioSamples};
 use super::frame_cache::CacheConfig;
 use super::normalization::{LufsAnalyzer, SharedLufsCache};
-use super::sync::create_sync_clock;
+use super::sequence_decoder::ImageSequenceDecoder;
+use super::sync::{create_sync_clock, SharedSyncClock};
 use super::webp_decoder::WebpAnimDecoder;
-use super::{AsyncDecoder, DecoderCommand, DecoderEvent};
+use super::{AsyncDecoder, CacheStats, DecodePath, DecoderCommand, DecoderEvent, PlaybackSpeed};
+use crate::media::ImageSequence;
 use iced::futures::SinkExt;
 use iced::stream;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::mpsc;
 
+/// How often the audio output checks for device changes.
+const DEVICE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Checks if a path is an animated WebP file.
 fn is_animated_webp(path: &PathBuf) -> bool {
     path.extension()
@@ -42,6 +52,7 @@ pub struct VideoPlaybackId(u64);
 pub struct DecoderCommandSender {
     video_tx: mpsc::UnboundedSender<DecoderCommand>,
     audio_tx: Option<mpsc::UnboundedSender<AudioDecoderCommand>>,
+    audio_level: Arc<AudioLevelMeter>,
 }
 
 impl DecoderCommandSender {
@@ -93,6 +104,12 @@ impl DecoderCommandSender {
     pub fn has_audio(&self) -> bool {
         self.audio_tx.is_some()
     }
+
+    /// Returns the level of the audio being played.
+    #[must_use]
+    pub fn audio_level(&self) -> AudioLevel {
+        self.audio_level.get()
+    }
 }
 
 impl std::fmt::Debug for DecoderCommandSender {
@@ -133,8 +150,14 @@ pub enum PlaybackMessage {
     /// An error occurred.
     Error(String),
 
-    /// Frame history is exhausted (no more frames to step backward).
+    /// No frame precedes the one shown (no more frames to step backward).
     HistoryExhausted,
+
+    /// Frames are now decoded through this path (hardware or software).
+    DecodePath(DecodePath),
+
+    /// Latest frame cache statistics.
+    CacheStats(CacheStats),
 }
 
 /// Shared normalization gain (stored as f32 bits for atomic access).
@@ -155,12 +178,14 @@ impl NormalizationGain {
     }
 }
 
-/// Abstraction over different video decoder types (`FFmpeg` vs WebP).
+/// Abstraction over different video decoder types (`FFmpeg`, WebP, image sequence).
 enum VideoDecoderKind {
     /// FFmpeg-based decoder for regular videos (MP4, AVI, etc.) and animated GIFs.
     Ffmpeg(AsyncDecoder),
     /// WebP-specific decoder for animated WebP files.
     Webp(WebpAnimDecoder),
+    /// Decoder for numbered image sequences played at a fixed frame rate.
+    Sequence(ImageSequenceDecoder),
 }
 
 impl VideoDecoderKind {
@@ -169,6 +194,7 @@ impl VideoDecoderKind {
         match self {
             VideoDecoderKind::Ffmpeg(dec) => dec.send_command(command),
             VideoDecoderKind::Webp(dec) => dec.send_command(command),
+            VideoDecoderKind::Sequence(dec) => dec.send_command(command),
         }
     }
 
@@ -177,6 +203,7 @@ impl VideoDecoderKind {
         match self {
             VideoDecoderKind::Ffmpeg(dec) => dec.recv_event().await,
             VideoDecoderKind::Webp(dec) => dec.recv_event().await,
+            VideoDecoderKind::Sequence(dec) => dec.recv_event().await,
         }
     }
 }
@@ -195,9 +222,55 @@ enum State {
         audio_cmd_rx: Option<mpsc::UnboundedReceiver<AudioDecoderCommand>>,
         /// Normalization gain to apply to audio samples.
         normalization_gain: Arc<NormalizationGain>,
+        /// Level of the audio being played, read by the VU meter.
+        audio_level: Arc<AudioLevelMeter>,
+        /// Clock shared with the audio decoder, for restarting it.
+        sync_clock: Option<SharedSyncClock>,
+        /// Where the audio decoder is, to restart it at the same place.
+        audio_position: AudioPosition,
     },
 }
 
+/// Playback position of the audio decoder, tracked so it can be recreated
+/// at the same place when the output device changes its sample rate.
+#[derive(Debug, Clone, Copy, Default)]
+struct AudioPosition {
+    playing: bool,
+    pts_secs: f64,
+    speed: Option<PlaybackSpeed>,
+}
+
+impl AudioPosition {
+    /// Updates the position from a command sent to the audio decoder.
+    fn apply(&mut self, command: &AudioDecoderCommand) {
+        match command {
+            AudioDecoderCommand::Play => self.playing = true,
+            AudioDecoderCommand::Pause | AudioDecoderCommand::Stop => self.playing = false,
+            AudioDecoderCommand::Seek { target_secs } => self.pts_secs = *target_secs,
+            AudioDecoderCommand::SetPlaybackSpeed { speed, .. } => self.speed = Some(*speed),
+            AudioDecoderCommand::SetVolume(_) | AudioDecoderCommand::SetMuted(_) => {}
+        }
+    }
+
+    /// Returns the commands that bring a new decoder to this position.
+    fn restore_commands(&self) -> Vec<AudioDecoderCommand> {
+        let mut commands = vec![AudioDecoderCommand::Seek {
+            target_secs: self.pts_secs,
+        }];
+        if let Some(speed) = self.speed {
+            commands.push(AudioDecoderCommand::SetPlaybackSpeed {
+                speed,
+                instant: std::time::Instant::now(),
+                reference_pts: self.pts_secs,
+            });
+        }
+        if self.playing {
+            commands.push(AudioDecoderCommand::Play);
+        }
+        commands
+    }
+}
+
 /// Configuration for video playback subscription.
 /// Used with `run_with` to uniquely identify subscriptions.
 #[derive(Clone)]
@@ -209,6 +282,10 @@ struct VideoPlaybackConfig {
     cache_config: CacheConfig,
     /// Maximum memory for frame history (backward stepping), in MB.
     history_mb: u32,
+    /// Frames to play instead of decoding `video_path`, for image sequences.
+    sequence: Option<ImageSequence>,
+    /// Whether to try hardware-accelerated decoding.
+    hardware_decoding: bool,
 }
 
 impl std::hash::Hash for VideoPlaybackConfig {
@@ -230,6 +307,8 @@ fn create_playback_stream(
         let normalization_enabled = config.normalization_enabled;
         let cache_config = config.cache_config;
         let history_mb = config.history_mb;
+        let sequence = config.sequence;
+        let hardware_decoding = config.hardware_decoding;
         async move {
             run_playback_loop(
                 &mut output,
@@ -238,6 +317,8 @@ fn create_playback_stream(
                 normalization_enabled,
                 cache_config,
                 history_mb,
+                sequence,
+                hardware_decoding,
             )
             .await;
         }
@@ -248,7 +329,7 @@ fn create_playback_stream(
 // Allow too_many_lines: async state machine for A/V playback with sync.
 // Inherent complexity from audio/video synchronization and event handling.
 // Refactoring risks breaking timing-sensitive playback logic.
-#[allow(clippy::too_many_lines)]
+#[allow(clippy::too_many_lines, clippy::too_many_arguments)]
 async fn run_playback_loop(
     output: &mut iced::futures::channel::mpsc::Sender<PlaybackMessage>,
     video_path: PathBuf,
@@ -256,8 +337,12 @@ async fn run_playback_loop(
     normalization_enabled: bool,
     cache_config: CacheConfig,
     history_mb: u32,
+    sequence: Option<ImageSequence>,
+    hardware_decoding: bool,
 ) {
     let mut state = State::Idle;
+    let mut device_check = tokio::time::interval(DEVICE_CHECK_INTERVAL);
+    device_check.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
     loop {
         match &mut state {
@@ -268,19 +353,29 @@ async fn run_playback_loop(
 
                 // Check if this is an animated WebP (requires special decoder)
                 let use_webp_decoder = is_animated_webp(&video_path);
+                // Image sequences and WebP animations are silent
+                let is_silent = use_webp_decoder || sequence.is_some();
 
                 // Create shared sync clock for A/V synchronization
                 // The clock is shared between audio and video decoders:
                 // - Audio decoder updates the clock with its PTS (audio is master)
                 // - Video decoder reads the clock to sync frames to audio
-                let sync_clock = if use_webp_decoder {
-                    None // WebP animations don't have audio, no sync needed
+                let sync_clock = if is_silent {
+                    None // No audio, no sync needed
                 } else {
                     Some(create_sync_clock())
                 };
 
                 // Try to create video decoder
-                let video_decoder: VideoDecoderKind = if use_webp_decoder {
+                let video_decoder: VideoDecoderKind = if let Some(sequence) = sequence.clone() {
+                    match ImageSequenceDecoder::new(sequence) {
+                        Ok(decoder) => VideoDecoderKind::Sequence(decoder),
+                        Err(e) => {
+                            let _ = output.send(PlaybackMessage::Error(e.to_string())).await;
+                            break;
+                        }
+                    }
+                } else if use_webp_decoder {
                     // Use WebP decoder for animated WebP files
                     match WebpAnimDecoder::new(&video_path) {
                         Ok(decoder) => VideoDecoderKind::Webp(decoder),
@@ -291,11 +386,12 @@ async fn run_playback_loop(
                     }
                 } else {
                     // Use FFmpeg decoder for regular videos with A/V sync
-                    match AsyncDecoder::new(
+                    match AsyncDecoder::with_hardware_decoding(
                         &video_path,
                         cache_config,
                         history_mb,
                         sync_clock.clone(),
+                        hardware_decoding,
                     ) {
                         Ok(decoder) => VideoDecoderKind::Ffmpeg(decoder),
                         Err(e) => {
@@ -307,8 +403,8 @@ async fn run_playback_loop(
 
                 // Create audio output first to get the device configuration.
                 // The audio decoder needs this to resample correctly (sample rate + channels).
-                // Note: WebP animations don't have audio, so skip for them.
-                let (audio_decoder, audio_output) = if use_webp_decoder {
+                // Note: WebP animations and image sequences don't have audio, so skip for them.
+                let (audio_decoder, audio_output) = if is_silent {
                     (None, None)
                 } else {
                     // Try to create audio output to get device config
@@ -316,7 +412,8 @@ async fn run_playback_loop(
                         Ok(output) => {
                             let output_config = output.config();
                             // Now create decoder with the correct output configuration
-                            match AudioDecoder::new(&video_path, sync_clock, output_config) {
+                            match AudioDecoder::new(&video_path, sync_clock.clone(), output_config)
+                            {
                                 Ok(Some(decoder)) => (Some(decoder), Some(output)),
                                 Ok(None) => {
                                     // No audio stream in video - this is fine
@@ -324,13 +421,13 @@ async fn run_playback_loop(
                                 }
                                 Err(e) => {
                                     // Log error but continue without audio
-                                    eprintln!("Audio decoder failed: {e}");
+                                    tracing::warn!("Audio decoder failed: {e}");
                                     (None, None)
                                 }
                             }
                         }
                         Err(e) => {
-                            eprintln!("Audio output failed: {e}");
+                            tracing::warn!("Audio output failed: {e}");
                             (None, None)
                         }
                     }
@@ -366,6 +463,9 @@ async fn run_playback_loop(
                             // Cache the result
                             if let Some(ref cache) = cache_clone {
                                 cache.insert(path_str, measured_lufs);
+                                if let Err(e) = cache.save() {
+                                    tracing::warn!("Failed to save LUFS cache: {e}");
+                                }
                             }
 
                             // Calculate and apply gain
@@ -381,6 +481,7 @@ async fn run_playback_loop(
                 }
 
                 // Send the command sender to UI
+                let audio_level = Arc::new(AudioLevelMeter::default());
                 let cmd_sender = DecoderCommandSender {
                     video_tx: external_cmd_tx,
                     audio_tx: if audio_decoder.is_some() {
@@ -388,6 +489,7 @@ async fn run_playback_loop(
                     } else {
                         None
                     },
+                    audio_level: Arc::clone(&audio_level),
                 };
                 let _ = output.send(PlaybackMessage::Started(cmd_sender)).await;
 
@@ -399,6 +501,9 @@ async fn run_playback_loop(
                     external_cmd_rx,
                     audio_cmd_rx: if has_audio { Some(audio_cmd_rx) } else { None },
                     normalization_gain,
+                    audio_level,
+                    sync_clock,
+                    audio_position: AudioPosition::default(),
                 };
             }
 
@@ -409,12 +514,23 @@ async fn run_playback_loop(
                 external_cmd_rx,
                 audio_cmd_rx,
                 normalization_gain,
+                audio_level,
+                sync_clock,
+                audio_position,
             } => {
                 // Use select to handle commands, video events, and audio events
                 tokio::select! {
                     // Check for external commands from UI
                     cmd = external_cmd_rx.recv() => {
                         if let Some(command) = cmd {
+                            // The meter only shows audio that is playing
+                            if matches!(
+                                command,
+                                DecoderCommand::Pause | DecoderCommand::Stop | DecoderCommand::Seek { .. }
+                            ) {
+                                audio_level.reset();
+                            }
+
                             // Handle audio output commands
                             if let Some(ref audio_out) = audio_output {
                                 match &command {
@@ -460,6 +576,7 @@ async fn run_playback_loop(
                                     }
                                 };
                                 if let Some(cmd) = audio_cmd {
+                                    audio_position.apply(&cmd);
                                     let _ = audio_dec.send_command(cmd);
                                 }
                             }
@@ -481,6 +598,7 @@ async fn run_playback_loop(
                     } => {
                         // Forward to audio decoder
                         if let Some(ref audio_dec) = audio_decoder {
+                            audio_position.apply(&audio_cmd);
                             let _ = audio_dec.send_command(audio_cmd.clone());
                         }
 
@@ -528,6 +646,8 @@ async fn run_playback_loop(
                                 DecoderEvent::EndOfStream => PlaybackMessage::EndOfStream,
                                 DecoderEvent::Error(msg) => PlaybackMessage::Error(msg),
                                 DecoderEvent::HistoryExhausted => PlaybackMessage::HistoryExhausted,
+                                DecoderEvent::DecodePath(path) => PlaybackMessage::DecodePath(path),
+                                DecoderEvent::CacheStats(stats) => PlaybackMessage::CacheStats(stats),
                             };
 
                             let _ = output.send(message).await;
@@ -564,10 +684,12 @@ async fn run_playback_loop(
                                         audio.samples
                                     };
 
+                                    audio_level.set(AudioLevel::measure(&samples));
                                     let _ = audio_out.play(samples);
                                 }
 
                                 // Send PTS update for sync tracking
+                                audio_position.pts_secs = audio.pts_secs;
                                 let _ = output.send(PlaybackMessage::AudioPts(audio.pts_secs)).await;
                             }
                             AudioDecoderEvent::StreamInfo(_info) => {
@@ -577,7 +699,32 @@ async fn run_playback_loop(
                                 // Audio finished - video might still be playing
                             }
                             AudioDecoderEvent::Error(msg) => {
-                                eprintln!("Audio error: {msg}");
+                                tracing::warn!("Audio error: {msg}");
+                            }
+                        }
+                    }
+
+                    // Follow the preferred device, or a new default after an unplug
+                    _ = device_check.tick(), if audio_output.is_some() => {
+                        if let Some(audio_out) = audio_output {
+                            let previous_config = audio_out.config();
+                            match audio_out.follow_device() {
+                                Ok(Some(config)) if !config.decodes_like(&previous_config) => {
+                                    // The decoder resamples for the old device: start
+                                    // a new one where the old one was
+                                    match AudioDecoder::new(&video_path, sync_clock.clone(), config) {
+                                        Ok(Some(decoder)) => {
+                                            for command in audio_position.restore_commands() {
+                                                let _ = decoder.send_command(command);
+                                            }
+                                            *audio_decoder = Some(decoder);
+                                        }
+                                        Ok(None) => {}
+                                        Err(e) => tracing::warn!("Audio decoder failed: {e}"),
+                                    }
+                                }
+                                Ok(_) => {}
+                                Err(e) => tracing::debug!("Audio device check failed: {e}"),
                             }
                         }
                     }
@@ -611,6 +758,13 @@ async fn run_playback_loop(
 ///
 /// The `history_mb` parameter controls the maximum memory for frame history
 /// (used for backward frame stepping).
+///
+/// When `sequence` is provided, its frames are played instead of decoding
+/// `video_path`, which is then only used to identify the media.
+///
+/// When `hardware_decoding` is set, `FFmpeg` videos are decoded on the GPU
+/// if possible, falling back to software otherwise.
+#[allow(clippy::too_many_arguments)]
 pub fn video_playback(
     video_path: PathBuf,
     session_id: u64,
@@ -618,6 +772,8 @@ pub fn video_playback(
     normalization_enabled: bool,
     cache_config: CacheConfig,
     history_mb: u32,
+    sequence: Option<ImageSequence>,
+    hardware_decoding: bool,
 ) -> iced::Subscription<PlaybackMessage> {
     let config = VideoPlaybackConfig {
         video_path,
@@ -626,6 +782,8 @@ pub fn video_playback(
         normalization_enabled,
         cache_config,
         history_mb,
+        sequence,
+        hardware_decoding,
     };
     iced::Subscription::run_with(config, create_playback_stream)
 }
@@ -659,6 +817,24 @@ mod tests {
         assert_ne!(id1, id3);
     }
 
+    #[test]
+    fn audio_position_restores_a_playing_decoder() {
+        let mut position = AudioPosition::default();
+        position.apply(&AudioDecoderCommand::Seek { target_secs: 12.0 });
+        position.apply(&AudioDecoderCommand::Play);
+        position.pts_secs = 12.5;
+
+        let commands = position.restore_commands();
+        assert!(matches!(
+            commands.first(),
+            Some(AudioDecoderCommand::Seek { target_secs }) if (target_secs - 12.5).abs() < 0.001
+        ));
+        assert!(matches!(commands.last(), Some(AudioDecoderCommand::Play)));
+
+        position.apply(&AudioDecoderCommand::Pause);
+        assert_eq!(position.restore_commands().len(), 1);
+    }
+
     #[test]
     fn audio_pts_message_can_be_created() {
         let msg = PlaybackMessage::AudioPts(10.5);
@@ -0,0 +1,468 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Subtitle tracks for video playback.
+//!
+//! Tracks come from two places:
+//! - sidecar files next to the video sharing its name (`movie.srt`,
+//!   `movie.en.srt`, `movie.ass`), parsed here;
+//! - text subtitle streams embedded in the container, decoded with `FFmpeg`.
+//!
+//! Bitmap subtitle streams (DVD/Blu-ray) cannot be drawn as text and are
+//! skipped. ASS styling is not reproduced: override tags are removed and
+//! the dialogue is shown with the viewer's subtitle style.
+
+use std::path::{Path, PathBuf};
+
+/// Sidecar subtitle extensions, in lowercase.
+pub const SIDECAR_EXTENSIONS: &[&str] = &["srt", "ass", "ssa"];
+
+/// Display time of an embedded cue whose end is unknown, in seconds.
+const DEFAULT_CUE_DURATION_SECS: f64 = 4.0;
+
+/// A line of dialogue with its display interval.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtitleCue {
+    pub start_secs: f64,
+    pub end_secs: f64,
+    pub text: String,
+}
+
+/// Where a subtitle track comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubtitleSource {
+    /// A subtitle file next to the video.
+    Sidecar(PathBuf),
+    /// A subtitle stream of the video container.
+    Embedded { stream_index: usize },
+}
+
+/// A subtitle track with all its cues, sorted by start time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtitleTrack {
+    /// Name shown in the track selector.
+    pub label: String,
+    pub source: SubtitleSource,
+    pub cues: Vec<SubtitleCue>,
+}
+
+impl SubtitleTrack {
+    /// Returns the text to display at `position_secs`, joining overlapping cues.
+    #[must_use]
+    pub fn text_at(&self, position_secs: f64) -> Option<String> {
+        // Cues are sorted by start, so only those starting before the position can match
+        let candidates = self
+            .cues
+            .partition_point(|cue| cue.start_secs <= position_secs);
+        let lines: Vec<&str> = self.cues[..candidates]
+            .iter()
+            .filter(|cue| position_secs < cue.end_secs)
+            .map(|cue| cue.text.as_str())
+            .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+}
+
+/// Finds and loads every subtitle track of `video`: sidecar files first, then
+/// embedded streams. Tracks without any cue are dropped.
+///
+/// This function performs blocking I/O and decoding and should run off the UI thread.
+#[must_use]
+pub fn load_tracks(video: &Path) -> Vec<SubtitleTrack> {
+    let sidecars = find_sidecar_files(video).into_iter().filter_map(|path| {
+        let content = std::fs::read(&path).ok()?;
+        let content = String::from_utf8_lossy(&content);
+        let is_srt = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case("srt"));
+        let cues = if is_srt {
+            parse_srt(&content)
+        } else {
+            parse_ass(&content)
+        };
+        Some(SubtitleTrack {
+            label: path.file_name()?.to_string_lossy().into_owned(),
+            source: SubtitleSource::Sidecar(path),
+            cues,
+        })
+    });
+
+    sidecars
+        .chain(load_embedded_tracks(video))
+        .filter(|track| !track.cues.is_empty())
+        .collect()
+}
+
+/// Lists the subtitle files next to `video` named after it, sorted by name.
+///
+/// Matches `<stem>.<ext>` and `<stem>.<anything>.<ext>` (language tags such
+/// as `movie.en.srt` or `movie.forced.ass`).
+#[must_use]
+pub fn find_sidecar_files(video: &Path) -> Vec<PathBuf> {
+    let (Some(directory), Some(stem)) = (video.parent(), video.file_stem()) else {
+        return Vec::new();
+    };
+    let stem = stem.to_string_lossy();
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return Vec::new();
+    };
+
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            let is_subtitle = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| SIDECAR_EXTENSIONS.contains(&e.to_ascii_lowercase().as_str()));
+            let name_matches = path.file_stem().is_some_and(|file_stem| {
+                let file_stem = file_stem.to_string_lossy();
+                file_stem == stem
+                    || file_stem
+                        .strip_prefix(stem.as_ref())
+                        .is_some_and(|rest| rest.starts_with('.'))
+            });
+            is_subtitle && name_matches && path.is_file()
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Parses `SubRip` (`.srt`) content.
+///
+/// Malformed blocks are skipped; basic HTML-like formatting tags are removed.
+#[must_use]
+pub fn parse_srt(content: &str) -> Vec<SubtitleCue> {
+    let content = content.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+    let mut cues = Vec::new();
+
+    for block in content.split("\n\n") {
+        let mut lines = block.lines().skip_while(|line| !line.contains("-->"));
+        let Some(timing) = lines.next() else {
+            continue;
+        };
+        let Some((start, end)) = timing.split_once("-->") else {
+            continue;
+        };
+        // The end may be followed by position hints ("X1:... Y1:...")
+        let end = end.split_whitespace().next().unwrap_or_default();
+        let (Some(start_secs), Some(end_secs)) =
+            (parse_srt_timestamp(start.trim()), parse_srt_timestamp(end))
+        else {
+            continue;
+        };
+
+        let text = strip_markup_tags(&lines.collect::<Vec<_>>().join("\n"));
+        if !text.trim().is_empty() {
+            cues.push(SubtitleCue {
+                start_secs,
+                end_secs,
+                text: text.trim().to_string(),
+            });
+        }
+    }
+
+    sort_cues(&mut cues);
+    cues
+}
+
+/// Parses the `[Events]` section of `SubStation Alpha` (`.ass`/`.ssa`) content.
+#[must_use]
+pub fn parse_ass(content: &str) -> Vec<SubtitleCue> {
+    let content = content.trim_start_matches('\u{feff}');
+    let mut in_events = false;
+    // Default v4+ column layout, replaced by the section's `Format:` line
+    let mut start_column = 1;
+    let mut end_column = 2;
+    let mut column_count = 10;
+    let mut cues = Vec::new();
+
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_events = line.eq_ignore_ascii_case("[events]");
+            continue;
+        }
+        if !in_events {
+            continue;
+        }
+
+        if let Some(format) = line.strip_prefix("Format:") {
+            let columns: Vec<String> = format
+                .split(',')
+                .map(|c| c.trim().to_ascii_lowercase())
+                .collect();
+            start_column = columns.iter().position(|c| c == "start").unwrap_or(1);
+            end_column = columns.iter().position(|c| c == "end").unwrap_or(2);
+            column_count = columns.len();
+        } else if let Some(dialogue) = line.strip_prefix("Dialogue:") {
+            // Text is the last column and may itself contain commas
+            let fields: Vec<&str> = dialogue.splitn(column_count, ',').collect();
+            if fields.len() < column_count {
+                continue;
+            }
+            let (Some(start_secs), Some(end_secs)) = (
+                parse_ass_timestamp(fields[start_column].trim()),
+                parse_ass_timestamp(fields[end_column].trim()),
+            ) else {
+                continue;
+            };
+            let text = clean_ass_text(fields[column_count - 1]);
+            if !text.is_empty() {
+                cues.push(SubtitleCue {
+                    start_secs,
+                    end_secs,
+                    text,
+                });
+            }
+        }
+    }
+
+    sort_cues(&mut cues);
+    cues
+}
+
+/// Parses `HH:MM:SS,mmm` (a `.` separator is accepted too).
+fn parse_srt_timestamp(value: &str) -> Option<f64> {
+    let (clock, millis) = value.split_once([',', '.'])?;
+    let millis: u32 = millis.trim().parse().ok()?;
+    Some(parse_clock(clock)? + f64::from(millis) / 1000.0)
+}
+
+/// Parses `H:MM:SS.cc` (centiseconds).
+fn parse_ass_timestamp(value: &str) -> Option<f64> {
+    let (clock, centis) = value.split_once('.')?;
+    let centis: u32 = centis.trim().parse().ok()?;
+    Some(parse_clock(clock)? + f64::from(centis) / 100.0)
+}
+
+/// Parses `H:MM:SS` into seconds.
+fn parse_clock(value: &str) -> Option<f64> {
+    let mut parts = value.trim().split(':');
+    let hours: u32 = parts.next()?.parse().ok()?;
+    let minutes: u32 = parts.next()?.parse().ok()?;
+    let seconds: u32 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || minutes >= 60 || seconds >= 60 {
+        return None;
+    }
+    Some(f64::from(hours * 3600 + minutes * 60 + seconds))
+}
+
+/// Removes `<i>`, `<b>`, `<font ...>` and similar tags.
+fn strip_markup_tags(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => result.push(c),
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Removes ASS override blocks (`{\i1}`) and converts line break escapes.
+fn clean_ass_text(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut in_override = false;
+    for c in text.chars() {
+        match c {
+            '{' => in_override = true,
+            '}' if in_override => in_override = false,
+            _ if !in_override => result.push(c),
+            _ => {}
+        }
+    }
+    result
+        .replace("\\N", "\n")
+        .replace("\\n", "\n")
+        .replace("\\h", "\u{a0}")
+        .trim()
+        .to_string()
+}
+
+fn sort_cues(cues: &mut [SubtitleCue]) {
+    cues.sort_by(|a, b| a.start_secs.total_cmp(&b.start_secs));
+}
+
+/// Decodes every text subtitle stream of `video`.
+fn load_embedded_tracks(video: &Path) -> Vec<SubtitleTrack> {
+    if crate::media::video::init_ffmpeg().is_err() {
+        return Vec::new();
+    }
+    let Ok(mut ictx) = ffmpeg_next::format::input(&video) else {
+        return Vec::new();
+    };
+
+    let mut decoders = Vec::new();
+    for (number, stream) in ictx
+        .streams()
+        .filter(|s| s.parameters().medium() == ffmpeg_next::media::Type::Subtitle)
+        .enumerate()
+    {
+        let Ok(decoder) =
+            ffmpeg_next::codec::context::Context::from_parameters(stream.parameters())
+                .and_then(|context| context.decoder().subtitle())
+        else {
+            continue;
+        };
+        let metadata = stream.metadata();
+        let label = [metadata.get("title"), metadata.get("language")]
+            .into_iter()
+            .flatten()
+            .fold(format!("#{}", number + 1), |label, part| {
+                format!("{label} · {part}")
+            });
+        let track = SubtitleTrack {
+            label,
+            source: SubtitleSource::Embedded {
+                stream_index: stream.index(),
+            },
+            cues: Vec::new(),
+        };
+        decoders.push((
+            stream.index(),
+            track,
+            decoder,
+            f64::from(stream.time_base()),
+        ));
+    }
+    if decoders.is_empty() {
+        return Vec::new();
+    }
+
+    for (stream, packet) in ictx.packets() {
+        let Some((_, track, decoder, time_base)) = decoders
+            .iter_mut()
+            .find(|(index, ..)| *index == stream.index())
+        else {
+            continue;
+        };
+        let mut subtitle = ffmpeg_next::Subtitle::new();
+        if !matches!(decoder.decode(&packet, &mut subtitle), Ok(true)) {
+            continue;
+        }
+
+        #[allow(clippy::cast_precision_loss)] // Timestamps fit f64 precision
+        let packet_secs = packet.pts().unwrap_or(0) as f64 * *time_base;
+        #[allow(clippy::cast_precision_loss)]
+        let packet_duration = packet.duration() as f64 * *time_base;
+        let start_secs = packet_secs + f64::from(subtitle.start()) / 1000.0;
+        let end_secs = if subtitle.end() > subtitle.start() && subtitle.end() != u32::MAX {
+            packet_secs + f64::from(subtitle.end()) / 1000.0
+        } else if packet_duration > 0.0 {
+            packet_secs + packet_duration
+        } else {
+            start_secs + DEFAULT_CUE_DURATION_SECS
+        };
+
+        let text: Vec<String> = subtitle
+            .rects()
+            .filter_map(|rect| match rect {
+                ffmpeg_next::subtitle::Rect::Text(text) => {
+                    Some(strip_markup_tags(text.get()).trim().to_string())
+                }
+                // Decoded ASS events: "ReadOrder,Layer,Style,Name,MarginL,MarginR,MarginV,Effect,Text"
+                ffmpeg_next::subtitle::Rect::Ass(ass) => {
+                    ass.get().splitn(9, ',').nth(8).map(clean_ass_text)
+                }
+                _ => None,
+            })
+            .filter(|line| !line.is_empty())
+            .collect();
+        if !text.is_empty() {
+            track.cues.push(SubtitleCue {
+                start_secs,
+                end_secs,
+                text: text.join("\n"),
+            });
+        }
+    }
+
+    decoders
+        .into_iter()
+        .map(|(_, mut track, ..)| {
+            sort_cues(&mut track.cues);
+            track
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SRT: &str = "\u{feff}1\r\n00:00:01,000 --> 00:00:03,500\r\nHello <i>there</i>\r\n\r\n\
+                       2\r\n00:00:03,000 --> 00:00:05,000 X1:10\r\nSecond\r\nline\r\n\r\n\
+                       3\r\nbroken --> timing\r\nSkipped\r\n";
+
+    const ASS: &str = "[Script Info]\nTitle: Test\n\n[Events]\n\
+                       Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n\
+                       Dialogue: 0,0:00:02.50,0:00:04.00,Default,,0,0,0,,{\\i1}Hi,{\\i0} you\\Nthere\n\
+                       Comment: 0,0:00:05.00,0:00:06.00,Default,,0,0,0,,ignored\n";
+
+    #[test]
+    fn parse_srt_reads_cues_and_strips_tags() {
+        let cues = parse_srt(SRT);
+
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].start_secs, 1.0);
+        assert_eq!(cues[0].end_secs, 3.5);
+        assert_eq!(cues[0].text, "Hello there");
+        assert_eq!(cues[1].end_secs, 5.0);
+        assert_eq!(cues[1].text, "Second\nline");
+    }
+
+    #[test]
+    fn parse_ass_reads_dialogue_with_commas() {
+        let cues = parse_ass(ASS);
+
+        assert_eq!(cues.len(), 1);
+        assert_eq!(cues[0].start_secs, 2.5);
+        assert_eq!(cues[0].end_secs, 4.0);
+        assert_eq!(cues[0].text, "Hi, you\nthere");
+    }
+
+    #[test]
+    fn text_at_joins_overlapping_cues() {
+        let track = SubtitleTrack {
+            label: "test".to_string(),
+            source: SubtitleSource::Embedded { stream_index: 2 },
+            cues: parse_srt(SRT),
+        };
+
+        assert_eq!(track.text_at(0.5), None);
+        assert_eq!(track.text_at(2.0).as_deref(), Some("Hello there"));
+        assert_eq!(
+            track.text_at(3.2).as_deref(),
+            Some("Hello there\nSecond\nline")
+        );
+        assert_eq!(track.text_at(5.0), None);
+    }
+
+    #[test]
+    fn sidecar_files_match_the_video_name() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        std::fs::write(dir.path().join("movie.mp4"), b"not a video").expect("write video");
+        for name in [
+            "movie.srt",
+            "movie.en.SRT",
+            "movie.ass",
+            "movie2.srt",
+            "movie.txt",
+        ] {
+            std::fs::write(dir.path().join(name), SRT).expect("write file");
+        }
+
+        let files = find_sidecar_files(&dir.path().join("movie.mp4"));
+        let names: Vec<_> = files
+            .iter()
+            .filter_map(|p| p.file_name()?.to_str())
+            .collect();
+        assert_eq!(names, vec!["movie.ass", "movie.en.SRT", "movie.srt"]);
+
+        let tracks = load_tracks(&dir.path().join("movie.mp4"));
+        assert_eq!(tracks.len(), 2, "the ASS file holds SRT content");
+        assert_eq!(tracks[0].label, "movie.en.SRT");
+    }
+}
@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Pitch-preserving time stretching for audio played at other speeds.
+//!
+//! Uses WSOLA (waveform similarity overlap-add): the input is cut into
+//! overlapping Hann-windowed segments taken `speed` times further apart than
+//! they are laid out in the output. Each segment start is nudged within a
+//! small search range to the position whose waveform best continues the
+//! previous segment, which avoids the phasing artifacts of plain overlap-add.
+//! Speech stays intelligible and keeps its pitch, unlike resampling.
+
+/// Length of a segment, in seconds.
+const SEGMENT_SECS: f64 = 0.03;
+
+/// How far a segment start may move to match the previous one, in seconds.
+const SEARCH_SECS: f64 = 0.01;
+
+/// Samples skipped between compared frames when searching (faster, and
+/// precise enough for speech and music).
+const SEARCH_STRIDE: usize = 2;
+
+/// Streaming WSOLA time stretcher for interleaved `f32` samples.
+#[derive(Debug, Clone)]
+pub struct TimeStretcher {
+    channels: usize,
+    /// Segment length in frames (even).
+    segment: usize,
+    /// Search range in frames, on each side of the nominal position.
+    search: usize,
+    /// Hann window of `segment` frames.
+    window: Vec<f32>,
+    speed: f64,
+    /// Buffered input frames, interleaved.
+    input: Vec<f32>,
+    /// Nominal start of the next segment, in frames into `input`.
+    position: f64,
+    /// Input frame that naturally follows the audio emitted so far (the
+    /// middle of the previous segment), in frames into `input`.
+    continuation: Option<usize>,
+    /// Windowed second half of the previous segment, added to the next one.
+    tail: Vec<f32>,
+}
+
+impl TimeStretcher {
+    /// Creates a stretcher for audio at `sample_rate` with `channels`
+    /// interleaved channels, at normal speed.
+    #[must_use]
+    pub fn new(sample_rate: u32, channels: u16) -> Self {
+        let channels = usize::from(channels.max(1));
+        // Segment lengths are a few thousand frames at most
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let segment = ((f64::from(sample_rate) * SEGMENT_SECS) as usize).max(16) & !1;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let search = (f64::from(sample_rate) * SEARCH_SECS) as usize;
+        // Periodic Hann: overlapping halves sum to exactly one
+        #[allow(clippy::cast_precision_loss)]
+        let window = (0..segment)
+            .map(|i| {
+                let phase = std::f64::consts::TAU * i as f64 / segment as f64;
+                #[allow(clippy::cast_possible_truncation)]
+                let weight = (0.5 - 0.5 * phase.cos()) as f32;
+                weight
+            })
+            .collect();
+
+        Self {
+            channels,
+            segment,
+            search,
+            window,
+            speed: 1.0,
+            input: Vec::new(),
+            position: 0.0,
+            continuation: None,
+            tail: Vec::new(),
+        }
+    }
+
+    /// Sets the speed (2.0 plays twice as fast). Buffered audio is dropped.
+    pub fn set_speed(&mut self, speed: f64) {
+        if (speed - self.speed).abs() > f64::EPSILON {
+            self.speed = speed;
+            self.reset();
+        }
+    }
+
+    /// Drops buffered audio, e.g. after a seek.
+    pub fn reset(&mut self) {
+        self.input.clear();
+        self.position = 0.0;
+        self.continuation = None;
+        self.tail.clear();
+    }
+
+    /// Returns true if samples pass through unchanged.
+    #[must_use]
+    pub fn is_passthrough(&self) -> bool {
+        (self.speed - 1.0).abs() < 0.001
+    }
+
+    /// Stretches a buffer of interleaved samples. The output is roughly
+    /// `samples.len() / speed` long; a few milliseconds are held back until
+    /// the next call.
+    #[must_use]
+    pub fn process(&mut self, samples: &[f32]) -> Vec<f32> {
+        if self.is_passthrough() {
+            return samples.to_vec();
+        }
+        self.input.extend_from_slice(samples);
+
+        let half = self.segment / 2;
+        // Analysis hop in input frames for each synthesis hop of `half` frames
+        #[allow(clippy::cast_precision_loss)]
+        let hop = half as f64 * self.speed;
+        let mut output = Vec::new();
+
+        loop {
+            // Segment positions are non-negative and well below usize::MAX
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let nominal = self.position.round() as usize;
+            let lowest = nominal.saturating_sub(self.search);
+            if self.frames() < nominal + self.search + self.segment {
+                break;
+            }
+
+            let start = match self.continuation {
+                Some(target) => self.best_match(target, lowest, nominal + self.search),
+                None => nominal,
+            };
+            self.overlap_add(start, &mut output);
+            self.continuation = Some(start + half);
+            self.position += hop;
+        }
+
+        self.discard_consumed();
+        output
+    }
+
+    fn frames(&self) -> usize {
+        self.input.len() / self.channels
+    }
+
+    /// Finds the segment start within `lowest..=highest` whose first half is
+    /// most similar to the input at `target` (the natural continuation of
+    /// the previous segment).
+    fn best_match(&self, target: usize, lowest: usize, highest: usize) -> usize {
+        let half = self.segment / 2;
+        let mut best = (f32::MIN, target.clamp(lowest, highest));
+        for candidate in (lowest..=highest).step_by(SEARCH_STRIDE) {
+            let mut correlation = 0.0;
+            for offset in (0..half).step_by(SEARCH_STRIDE) {
+                correlation += self.mono(target + offset) * self.mono(candidate + offset);
+            }
+            if correlation > best.0 {
+                best = (correlation, candidate);
+            }
+        }
+        best.1
+    }
+
+    /// Sum of the channels of input frame `frame`.
+    fn mono(&self, frame: usize) -> f32 {
+        let start = frame * self.channels;
+        self.input
+            .get(start..start + self.channels)
+            .map_or(0.0, |samples| samples.iter().sum())
+    }
+
+    /// Windows the segment at `start`, adds the previous tail to its first
+    /// half and emits it.
+    fn overlap_add(&mut self, start: usize, output: &mut Vec<f32>) {
+        let half = self.segment / 2;
+        let channels = self.channels;
+        let mut windowed = Vec::with_capacity(self.segment * channels);
+        for (frame, weight) in self.window.iter().enumerate() {
+            let base = (start + frame) * channels;
+            windowed.extend(self.input[base..base + channels].iter().map(|s| s * weight));
+        }
+
+        let (head, tail) = windowed.split_at(half * channels);
+        if self.tail.is_empty() {
+            output.extend_from_slice(head);
+        } else {
+            output.extend(head.iter().zip(&self.tail).map(|(a, b)| a + b));
+        }
+        self.tail = tail.to_vec();
+    }
+
+    /// Drops input frames that no future segment or match can use.
+    fn discard_consumed(&mut self) {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let nominal = self.position.floor() as usize;
+        let mut keep_from = nominal.saturating_sub(self.search);
+        if let Some(continuation) = self.continuation {
+            keep_from = keep_from.min(continuation);
+        }
+        if keep_from == 0 {
+            return;
+        }
+        self.input.drain(..keep_from * self.channels);
+        #[allow(clippy::cast_precision_loss)]
+        let shift = keep_from as f64;
+        self.position -= shift;
+        self.continuation = self.continuation.map(|frame| frame - keep_from);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 8000;
+
+    fn sine(frequency: f32, frames: usize, channels: usize) -> Vec<f32> {
+        #[allow(clippy::cast_precision_loss)]
+        (0..frames)
+            .flat_map(|i| {
+                let value = (std::f32::consts::TAU * frequency * i as f32 / RATE as f32).sin();
+                std::iter::repeat_n(value, channels)
+            })
+            .collect()
+    }
+
+    fn zero_crossings(samples: &[f32]) -> usize {
+        samples
+            .windows(2)
+            .filter(|pair| pair[0] < 0.0 && pair[1] >= 0.0)
+            .count()
+    }
+
+    #[test]
+    fn normal_speed_passes_samples_through() {
+        let mut stretcher = TimeStretcher::new(RATE, 2);
+        let input = sine(440.0, 100, 2);
+        assert_eq!(stretcher.process(&input), input);
+    }
+
+    #[test]
+    fn faster_speed_shortens_audio_and_keeps_pitch() {
+        let mut stretcher = TimeStretcher::new(RATE, 1);
+        stretcher.set_speed(2.0);
+        let input = sine(200.0, RATE as usize * 2, 1);
+        let output: Vec<f32> = input
+            .chunks(1024)
+            .flat_map(|chunk| stretcher.process(chunk))
+            .collect();
+
+        // About one second out of two, minus what is held back
+        #[allow(clippy::cast_precision_loss)]
+        let seconds = output.len() as f32 / RATE as f32;
+        assert!((0.9..=1.05).contains(&seconds), "got {seconds} s");
+
+        // Still a 200 Hz tone: ~200 upward zero crossings per second
+        #[allow(clippy::cast_precision_loss)]
+        let frequency = zero_crossings(&output) as f32 / seconds;
+        assert!((185.0..=215.0).contains(&frequency), "got {frequency} Hz");
+    }
+
+    #[test]
+    fn slower_speed_lengthens_interleaved_audio() {
+        let mut stretcher = TimeStretcher::new(RATE, 2);
+        stretcher.set_speed(0.5);
+        let input = sine(300.0, RATE as usize, 2);
+        let output = stretcher.process(&input);
+        assert_eq!(output.len() % 2, 0);
+        #[allow(clippy::cast_precision_loss)]
+        let seconds = output.len() as f32 / 2.0 / RATE as f32;
+        assert!((1.85..=2.05).contains(&seconds), "got {seconds} s");
+    }
+}
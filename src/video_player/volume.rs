@@ -2,13 +2,13 @@
 //! Volume domain type for audio playback.
 //!
 //! This module provides a type-safe wrapper for volume values,
-//! ensuring they are always within the valid range (0.0–1.5, where 1.0 = 100%).
+//! ensuring they are always within the valid range (0.0–2.0, where 1.0 = 100%).
 
-use crate::config::{DEFAULT_VOLUME, MAX_VOLUME, MIN_VOLUME, VOLUME_STEP};
+use crate::config::{DEFAULT_VOLUME, MAX_VOLUME, MIN_VOLUME, UNITY_VOLUME, VOLUME_STEP};
 
-/// Volume level, guaranteed to be within valid range (0.0–1.5).
+/// Volume level, guaranteed to be within valid range (0.0–2.0).
 ///
-/// Values above 1.0 represent amplification (up to 150%).
+/// Values above 1.0 represent a boost (up to 200%).
 /// This newtype enforces validity at the type level, making it impossible
 /// to create an invalid volume value.
 ///
@@ -21,8 +21,8 @@ use crate::config::{DEFAULT_VOLUME, MAX_VOLUME, MIN_VOLUME, VOLUME_STEP};
 /// assert_eq!(vol.value(), 0.5);
 ///
 /// // Values outside range are clamped
-/// let too_loud = Volume::new(2.0);
-/// assert_eq!(too_loud.value(), 1.5); // Clamped to max (150%)
+/// let too_loud = Volume::new(3.0);
+/// assert_eq!(too_loud.value(), 2.0); // Clamped to max (200%)
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Volume(f32);
@@ -64,6 +64,12 @@ impl Volume {
         self.0 <= MIN_VOLUME
     }
 
+    /// Returns true if the volume boosts audio above 100%.
+    #[must_use]
+    pub fn is_boosted(self) -> bool {
+        self.0 > UNITY_VOLUME
+    }
+
     /// Returns true if this is the maximum volume.
     #[must_use]
     pub fn is_max(self) -> bool {
@@ -84,7 +90,8 @@ mod tests {
     #[test]
     fn new_clamps_to_valid_range() {
         assert_eq!(Volume::new(-0.5).value(), MIN_VOLUME);
-        assert_eq!(Volume::new(1.5).value(), MAX_VOLUME);
+        assert_eq!(Volume::new(2.5).value(), MAX_VOLUME);
+        assert_eq!(Volume::new(1.5).value(), 1.5);
         assert_eq!(Volume::new(0.5).value(), 0.5);
     }
 
@@ -131,4 +138,11 @@ mod tests {
         assert!(Volume::new(MAX_VOLUME).is_max());
         assert!(!Volume::new(0.5).is_max());
     }
+
+    #[test]
+    fn volumes_above_unity_are_boosted() {
+        assert!(!Volume::new(UNITY_VOLUME).is_boosted());
+        assert!(Volume::new(1.05).is_boosted());
+        assert!(Volume::new(MAX_VOLUME).is_boosted());
+    }
 }
@@ -58,7 +58,7 @@ impl WebpAnimDecoder {
         // webp-animation Decoder is not Send, so we use spawn_blocking
         tokio::task::spawn_blocking(move || {
             if let Err(e) = Self::decoder_loop_blocking(webp_data, command_rx, event_tx) {
-                eprintln!("WebP decoder task failed: {e}");
+                tracing::error!("WebP decoder task failed: {e}");
             }
         });
 
@@ -126,11 +126,31 @@ impl WebpAnimDecoder {
             prev_timestamp = *timestamp;
         }
 
-        // Playback state
+        // Start time of each frame, for seeking and pacing
+        let mut frame_starts_ms: Vec<i32> = Vec::with_capacity(frames.len());
+        let mut elapsed_ms = 0;
+        for &duration in &frame_durations_ms {
+            frame_starts_ms.push(elapsed_ms);
+            elapsed_ms += duration;
+        }
+        let frame_index_at = |secs: f64| {
+            // WebP animations are typically short, so i32 ms is sufficient (~24 days max)
+            #[allow(clippy::cast_possible_truncation)]
+            let target_ms = (secs * 1000.0) as i32;
+            frame_starts_ms
+                .partition_point(|&start| start <= target_ms)
+                .saturating_sub(1)
+        };
+
+        // Playback state (mirrors the image sequence decoder: looping is
+        // driven by the player on EndOfStream so the loop toggle applies)
+        let frame_count = frames.len();
         let mut is_playing = false;
         let mut current_frame_idx = 0usize;
-        let mut playback_start_time: Option<std::time::Instant> = None;
-        let loop_enabled = true; // WebP animations typically loop
+        // Last frame sent, which stepping is relative to once paused
+        let mut displayed_frame_idx = 0usize;
+        // Frame index and instant that pacing is measured from
+        let mut pacing_origin: Option<(usize, std::time::Instant)> = None;
         let mut decode_single_frame = false;
         let mut playback_speed: f64 = 1.0;
 
@@ -139,53 +159,39 @@ impl WebpAnimDecoder {
             // Check for commands (non-blocking)
             match command_rx.try_recv() {
                 Ok(DecoderCommand::Play {
-                    resume_position_secs: _,
+                    resume_position_secs,
                 }) => {
-                    // Note: WebP animations don't support precise position resume,
-                    // they restart from current frame index which is preserved on pause
+                    if let Some(position) = resume_position_secs {
+                        current_frame_idx = frame_index_at(position);
+                    }
+                    if current_frame_idx >= frame_count {
+                        current_frame_idx = 0;
+                    }
                     is_playing = true;
-                    playback_start_time = Some(std::time::Instant::now());
+                    pacing_origin = Some((current_frame_idx, std::time::Instant::now()));
                     let _ = event_tx.blocking_send(DecoderEvent::Buffering);
                 }
                 Ok(DecoderCommand::Pause) => {
                     is_playing = false;
-                    playback_start_time = None;
+                    pacing_origin = None;
+                    current_frame_idx = displayed_frame_idx;
                 }
                 Ok(DecoderCommand::Seek { target_secs }) => {
-                    // Find the frame closest to target time
-                    // WebP animations are typically short, so i32 ms is sufficient (~24 days max)
-                    #[allow(clippy::cast_possible_truncation)]
-                    let target_ms = (target_secs * 1000.0) as i32;
-                    let mut accumulated_ms = 0i32;
-                    current_frame_idx = 0;
-
-                    for (idx, &duration) in frame_durations_ms.iter().enumerate() {
-                        if accumulated_ms + duration > target_ms {
-                            current_frame_idx = idx;
-                            break;
-                        }
-                        accumulated_ms += duration;
-                        current_frame_idx = idx;
-                    }
-
-                    // Reset timing for seek
-                    playback_start_time = Some(std::time::Instant::now());
-                    let _ = event_tx.blocking_send(DecoderEvent::Buffering);
-
-                    // If paused, show the seeked frame
-                    if !is_playing {
+                    current_frame_idx = frame_index_at(target_secs);
+                    if is_playing {
+                        pacing_origin = Some((current_frame_idx, std::time::Instant::now()));
+                    } else {
                         decode_single_frame = true;
                     }
                 }
                 Ok(DecoderCommand::StepFrame) => {
-                    // Step forward one frame in WebP animation
-                    if !is_playing {
+                    if !is_playing && current_frame_idx + 1 < frame_count {
+                        current_frame_idx += 1;
                         decode_single_frame = true;
                     }
                 }
                 Ok(DecoderCommand::StepBackward) => {
-                    // Step backward one frame in WebP animation
-                    // WebP animations have random access to all frames, so we can just go back
+                    // All frames are in memory, so stepping back is random access
                     if !is_playing && current_frame_idx > 0 {
                         current_frame_idx -= 1;
                         decode_single_frame = true;
@@ -201,10 +207,9 @@ impl WebpAnimDecoder {
                 }) => {
                     // PlaybackSpeed newtype guarantees valid range
                     playback_speed = speed.value();
-                    // Reset timing references for smooth speed transition
                     // WebP animations don't have audio, so reference_pts is unused
                     if is_playing {
-                        playback_start_time = Some(instant);
+                        pacing_origin = Some((current_frame_idx, instant));
                     }
                 }
                 Err(mpsc::error::TryRecvError::Empty) => {
@@ -218,37 +223,26 @@ impl WebpAnimDecoder {
                 continue;
             }
 
-            // Get current frame
-            if current_frame_idx >= frames.len() {
-                if loop_enabled {
-                    // Loop back to start
-                    current_frame_idx = 0;
-                    playback_start_time = Some(std::time::Instant::now());
-                } else {
-                    // End of stream
-                    let _ = event_tx.blocking_send(DecoderEvent::EndOfStream);
-                    is_playing = false;
-                    playback_start_time = None;
-                    continue;
-                }
+            if current_frame_idx >= frame_count {
+                // Looping is driven by the player on EndOfStream
+                let _ = event_tx.blocking_send(DecoderEvent::EndOfStream);
+                is_playing = false;
+                pacing_origin = None;
+                current_frame_idx = displayed_frame_idx;
+                continue;
             }
 
             let (rgba_data, _) = &frames[current_frame_idx];
+            let pts_secs = f64::from(frame_starts_ms[current_frame_idx]) / 1000.0;
 
-            // Calculate PTS for this frame
-            let pts_secs: f64 = frame_durations_ms[..current_frame_idx]
-                .iter()
-                .map(|&d| f64::from(d) / 1000.0)
-                .sum();
-
-            // Frame pacing: wait until the frame should be displayed
+            // Frame pacing relative to where playback (re)started
             // Divide by playback_speed: at 2x speed, delay is halved
-            if let Some(start_time) = playback_start_time {
-                let adjusted_pts = pts_secs / playback_speed;
-                let target_time = start_time + std::time::Duration::from_secs_f64(adjusted_pts);
+            if let (true, Some((origin_idx, origin_time))) = (is_playing, pacing_origin) {
+                let origin_secs = f64::from(frame_starts_ms[origin_idx]) / 1000.0;
+                let offset_secs = (pts_secs - origin_secs).max(0.0) / playback_speed;
+                let target_time = origin_time + std::time::Duration::from_secs_f64(offset_secs);
                 let now = std::time::Instant::now();
-
-                if target_time > now && is_playing {
+                if target_time > now {
                     std::thread::sleep(target_time - now);
                 }
             }
@@ -268,6 +262,7 @@ impl WebpAnimDecoder {
                 // Event channel closed
                 break;
             }
+            displayed_frame_idx = current_frame_idx;
 
             // Advance to next frame
             if is_playing {
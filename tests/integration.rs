@@ -116,8 +116,10 @@ fn test_isolated_directories_for_state_and_config() {
     let state = AppState {
         last_save_directory: Some(PathBuf::from("/test/isolated/state")),
         last_open_directory: None,
+        last_open_filter: Default::default(),
         enable_deblur: false,
         enable_upscale: false,
+        enable_background_removal: false,
     };
     let state_result = state.save_to(Some(state_dir.path().to_path_buf()));
     assert!(state_result.is_none(), "state save should succeed");
@@ -182,8 +184,10 @@ fn test_parallel_test_isolation() {
     let state_a = AppState {
         last_save_directory: Some(PathBuf::from("/user/a/downloads")),
         last_open_directory: None,
+        last_open_filter: Default::default(),
         enable_deblur: false,
         enable_upscale: false,
+        enable_background_removal: false,
     };
     let _ = state_a.save_to(Some(base_a.clone()));
 
@@ -199,8 +203,10 @@ fn test_parallel_test_isolation() {
     let state_b = AppState {
         last_save_directory: Some(PathBuf::from("/user/b/pictures")),
         last_open_directory: None,
+        last_open_filter: Default::default(),
         enable_deblur: true,
         enable_upscale: false,
+        enable_background_removal: false,
     };
     let _ = state_b.save_to(Some(base_b.clone()));
 
@@ -240,8 +246,10 @@ fn test_explicit_override_takes_precedence_over_env_var() {
     let state = AppState {
         last_save_directory: Some(PathBuf::from("/explicit/path")),
         last_open_directory: None,
+        last_open_filter: Default::default(),
         enable_deblur: false,
         enable_upscale: false,
+        enable_background_removal: false,
     };
     let _ = state.save_to(Some(explicit_dir.path().to_path_buf()));
 
@@ -278,8 +286,10 @@ fn test_ci_friendly_isolated_tests() {
             let state = AppState {
                 last_save_directory: Some(PathBuf::from(format!("/run/{i}/save"))),
                 last_open_directory: None,
+                last_open_filter: Default::default(),
                 enable_deblur: false,
                 enable_upscale: false,
+                enable_background_removal: false,
             };
             let _ = state.save_to(Some(base.clone()));
 
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MPL-2.0
+//! Snapshot tests of the main screens, rendered headlessly.
+//!
+//! Each screen is rendered to an image and compared with its golden file in
+//! `tests/snapshots/`. A missing golden image fails the test. To add a new
+//! screen or accept an intended change, run the tests with
+//! `ICED_LENS_UPDATE_SNAPSHOTS=1`, which rewrites the golden images, then
+//! review and commit them.
+//!
+//! Requires the `ui-snapshots` feature:
+//!
+//! ```bash
+//! cargo test --features ui-snapshots --test ui_snapshots
+//! ```
+
+use iced::{Element, Theme};
+use iced_lens::config::BackgroundTheme;
+use iced_lens::i18n::fluent::I18n;
+use iced_lens::media::background_removal::BackgroundModelStatus;
+use iced_lens::media::deblur::ModelStatus;
+use iced_lens::media::filter::MediaFilter;
+use iced_lens::media::image::load_image;
+use iced_lens::media::navigator::NavigationInfo;
+use iced_lens::media::upscale::UpscaleModelStatus;
+use iced_lens::ui::guides;
+use iced_lens::ui::image_editor::{self, State as EditorState};
+use iced_lens::ui::settings::{self, State as SettingsState, StateConfig};
+use iced_lens::ui::viewer::{self, component, fullscreen_overlay};
+use iced_test::simulator;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Environment variable that makes the tests write their golden images.
+const UPDATE_SNAPSHOTS_ENV: &str = "ICED_LENS_UPDATE_SNAPSHOTS";
+
+/// Renders `element` with `theme` and compares it with the golden image `name`.
+fn assert_snapshot<'a, Message>(
+    name: &str,
+    theme: &Theme,
+    element: impl Into<Element<'a, Message>>,
+) {
+    let golden = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(name);
+    // `matches_image` writes the golden image when it is missing, so only
+    // let it do that when asked to
+    let image = golden.with_extension("png");
+    if std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some() {
+        if let Some(dir) = golden.parent() {
+            std::fs::create_dir_all(dir).expect("failed to create the snapshots directory");
+        }
+        if image.exists() {
+            std::fs::remove_file(&image).expect("failed to remove the outdated golden image");
+        }
+    } else {
+        assert!(
+            image.exists(),
+            "{name} has no golden image in tests/snapshots/, \
+             run with {UPDATE_SNAPSHOTS_ENV}=1 to write it"
+        );
+    }
+
+    let mut ui = simulator(element);
+    let snapshot = ui
+        .snapshot(theme)
+        .unwrap_or_else(|err| panic!("failed to render {name}: {err}"));
+    let matches = snapshot
+        .matches_image(&golden)
+        .unwrap_or_else(|err| panic!("failed to compare {name}: {err}"));
+    assert!(matches, "{name} no longer matches its golden image");
+}
+
+fn sample_image_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("data")
+        .join("sample.png")
+}
+
+#[test]
+fn viewer_empty_state() {
+    let i18n = I18n::default();
+    let filter = MediaFilter::default();
+    let guides = guides::Options::default();
+    let viewer = component::State::new();
+
+    for (name, theme) in [
+        ("viewer_empty_light", Theme::Light),
+        ("viewer_empty_dark", Theme::Dark),
+    ] {
+        let view = viewer.view(component::ViewEnv {
+            i18n: &i18n,
+            background_theme: BackgroundTheme::default(),
+            is_fullscreen: false,
+            compact: false,
+            overlay_hide_delay: Duration::from_secs(3),
+            fullscreen_overlay: fullscreen_overlay::Options::default(),
+            navigation: NavigationInfo::default(),
+            metadata_editor_has_changes: false,
+            filter: &filter,
+            dots_per_inch: None,
+            guides: &guides,
+        });
+        assert_snapshot(name, &theme, view);
+    }
+}
+
+#[test]
+fn viewer_error_state() {
+    let i18n = I18n::default();
+    let friendly_text = i18n.tr("notification-load-error-io");
+
+    let view = viewer::view(viewer::ViewContext {
+        i18n: &i18n,
+        error: Some(viewer::ErrorContext {
+            friendly_text: &friendly_text,
+            details: "No such file or directory (os error 2)",
+            show_details: true,
+        }),
+        image: None,
+        is_loading: false,
+        spinner_rotation: 0.0,
+    });
+    assert_snapshot("viewer_error", &Theme::Dark, view);
+}
+
+#[test]
+fn settings_screen() {
+    let i18n = I18n::default();
+    let settings = SettingsState::new(StateConfig::default());
+
+    let view = settings.view(settings::ViewContext { i18n: &i18n });
+    assert_snapshot("settings", &Theme::Dark, view);
+}
+
+#[test]
+fn editor_screen() {
+    let i18n = I18n::default();
+    let path = sample_image_path();
+    let image = load_image(&path).expect("sample image should load");
+    let editor = EditorState::new(path, &image).expect("editor should open the sample image");
+    let deblur_model_status = ModelStatus::default();
+    let upscale_model_status = UpscaleModelStatus::default();
+    let background_model_status = BackgroundModelStatus::default();
+    let guides = guides::Options::default();
+
+    let view = editor.view(&image_editor::ViewContext {
+        i18n: &i18n,
+        background_theme: BackgroundTheme::default(),
+        is_dark_theme: true,
+        deblur_model_status: &deblur_model_status,
+        upscale_model_status: &upscale_model_status,
+        background_model_status: &background_model_status,
+        enable_upscale: false,
+        denoise_model_available: false,
+        can_paste_adjustments: false,
+        plugin_tools: &[],
+        plugin_exports: &[],
+        guides: &guides,
+    });
+    assert_snapshot("editor", &Theme::Dark, view);
+}
@@ -229,7 +229,10 @@ fn test_video_decoding(path: &str, format_name: &str) {
                         DecoderEvent::EndOfStream => {
                             panic!("{format_name} reached end of stream without producing frames");
                         }
-                        DecoderEvent::Buffering | DecoderEvent::HistoryExhausted => {
+                        DecoderEvent::Buffering
+                        | DecoderEvent::HistoryExhausted
+                        | DecoderEvent::DecodePath(_)
+                        | DecoderEvent::CacheStats(_) => {
                             // Continue waiting
                         }
                     }
@@ -311,7 +314,10 @@ fn test_decode_corrupted_file() {
                             DecoderEvent::Error(_)
                             | DecoderEvent::EndOfStream
                             | DecoderEvent::FrameReady(_) => return true,
-                            DecoderEvent::Buffering | DecoderEvent::HistoryExhausted => {}
+                            DecoderEvent::Buffering
+                            | DecoderEvent::HistoryExhausted
+                            | DecoderEvent::DecodePath(_)
+                            | DecoderEvent::CacheStats(_) => {}
                         }
                     }
                 }